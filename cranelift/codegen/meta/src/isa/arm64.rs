@@ -10,6 +10,33 @@ pub(crate) fn define() -> TargetIsa {
         "",
         false,
     );
+    settings.add_bool(
+        "has_rcpc",
+        "Has Release Consistent processor consistent (FEAT_LRCPC) support; \
+         enables the use of the LDAPR instruction for acquire loads.",
+        "",
+        false,
+    );
+    settings.add_bool(
+        "has_dotprod",
+        "Has the dot product extension (FEAT_DotProd) support; enables the \
+         use of the SDOT and UDOT instructions.",
+        "",
+        false,
+    );
+    settings.add_bool(
+        "outline_atomics",
+        "Use Large System Extensions (FEAT_LSE) atomics when they are \
+         detected at runtime, if FEAT_LSE is not enabled at compile time.",
+        r#"
+            Atomic operations check the byte at the
+            `__aarch64_have_lse_atomics` symbol, as provided by compiler-rt
+            and libgcc, and use LSE instructions if it is non-zero or
+            load-linked/store-conditional loops otherwise. This allows a
+            single artifact compiled for generic aarch64 to use the faster
+            LSE instructions on cores which support them."#,
+        false,
+    );
     settings.add_bool(
         "has_pauth",
         "Has Pointer authentication (FEAT_PAuth) support; enables the use of \
@@ -8,6 +8,11 @@ pub(crate) fn define() -> TargetIsa {
     // so we list only facilities of later processors here.
 
     // z15 (arch13) facilities
+    //
+    // Of Vector-Enhancements Facility 2, cranelift uses the byte- and
+    // element-reversed vector loads and stores, the 32-bit vector float
+    // conversions and VECTOR SHIFT LEFT DOUBLE BY BIT.  The vector string
+    // search instructions have no counterpart in CLIF and are not used.
     let has_mie2 = settings.add_bool(
         "has_mie2",
         "Has Miscellaneous-Instruction-Extensions Facility 2 support.",
@@ -27,6 +32,13 @@ pub(crate) fn define() -> TargetIsa {
         "Thirteenth Edition of the z/Architecture.",
         preset!(has_mie2 && has_vxrs_ext2),
     );
+    // z16 (arch14) adds no facilities that cranelift makes use of,
+    // so it implies the same set of flags as arch13.
+    settings.add_preset(
+        "arch14",
+        "Fourteenth Edition of the z/Architecture.",
+        preset!(has_mie2 && has_vxrs_ext2),
+    );
 
     // Processor presets
     settings.add_preset(
@@ -34,6 +46,11 @@ pub(crate) fn define() -> TargetIsa {
         "IBM z15 processor.",
         preset!(has_mie2 && has_vxrs_ext2),
     );
+    settings.add_preset(
+        "z16",
+        "IBM z16 processor.",
+        preset!(has_mie2 && has_vxrs_ext2),
+    );
 
     TargetIsa::new("s390x", settings.build())
 }
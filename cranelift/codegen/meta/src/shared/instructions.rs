@@ -3675,7 +3675,8 @@ pub(crate) fn define(
         returned.  `p` has the type of the target word size, and `x` may be an integer type of
         8, 16, 32 or 64 bits, even on a 32-bit target.  The type of the returned value is the
         same as the type of `x`.  This operation is sequentially consistent and creates
-        happens-before edges that order normal (non-atomic) loads and stores.
+        happens-before edges that order normal (non-atomic) loads and stores, unless the
+        `relaxed`, `acquire` or `release` memory flag requests a weaker ordering.
         "#,
             &formats.atomic_rmw,
         )
@@ -3704,7 +3705,8 @@ pub(crate) fn define(
         integer type of 8, 16, 32 or 64 bits, even on a 32-bit target.  The type of the returned
         value is the same as the type of `x` and `e`.  This operation is sequentially
         consistent and creates happens-before edges that order normal (non-atomic) loads and
-        stores.
+        stores, unless the `relaxed`, `acquire` or `release` memory flag requests a weaker
+        ordering.
         "#,
             &formats.atomic_cas,
         )
@@ -3731,7 +3733,8 @@ pub(crate) fn define(
         This is a polymorphic instruction that can load any value type which has a memory
         representation.  It should only be used for integer types with 8, 16, 32 or 64 bits.
         This operation is sequentially consistent and creates happens-before edges that order
-        normal (non-atomic) loads and stores.
+        normal (non-atomic) loads and stores, unless the `relaxed` or `acquire` memory flag
+        requests a weaker ordering.
         "#,
             &formats.load_no_offset,
         )
@@ -3755,7 +3758,8 @@ pub(crate) fn define(
         This is a polymorphic instruction that can store any value type with a memory
         representation.  It should only be used for integer types with 8, 16, 32 or 64 bits.
         This operation is sequentially consistent and creates happens-before edges that order
-        normal (non-atomic) loads and stores.
+        normal (non-atomic) loads and stores, unless the `relaxed` or `release` memory flag
+        requests a weaker ordering.
         "#,
             &formats.store_no_offset,
         )
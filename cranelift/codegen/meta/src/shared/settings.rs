@@ -30,6 +30,53 @@ pub(crate) fn define() -> SettingGroup {
         false,
     );
 
+    settings.add_enum(
+        "regalloc_algorithm",
+        "Algorithm to use in register allocation.",
+        r#"
+            Supported options:
+
+            - `backtracking`: regalloc2's backtracking allocator, which
+              produces good code but whose running time grows faster than
+              linearly with the size of a function in pathological cases.
+            - `single_pass`: a fast allocator which keeps every value in its
+              own stack slot and only loads it into a register around each
+              instruction. Its running time is linear in the size of the
+              function, but the code it produces is slow.
+        "#,
+        vec!["backtracking", "single_pass"],
+    );
+
+    settings.add_num(
+        "regalloc_single_pass_insts_log2",
+        "The log2 of the number of instructions above which the single-pass allocator is used.",
+        r#"
+            Functions which lower to more than this many machine instructions
+            are allocated with the `single_pass` algorithm whatever the value
+            of `regalloc_algorithm`, which bounds the time spent in register
+            allocation on any one function without failing to compile it.
+
+            The default is 0, which disables the fallback.
+        "#,
+        0,
+    );
+
+    settings.add_num(
+        "regalloc_max_insts_log2",
+        "The log2 of the maximum number of instructions handed to the register allocator.",
+        r#"
+            The register allocator's running time grows faster than linearly
+            with the size of a function in pathological cases. Functions
+            which lower to more than this many machine instructions fail to
+            compile with `CodegenError::ImplLimitExceeded` before register
+            allocation starts, which bounds the time spent on any one
+            function.
+
+            The default is 0, which disables the limit.
+        "#,
+        0,
+    );
+
     settings.add_enum(
         "opt_level",
         "Optimization level for generated code.",
@@ -53,6 +100,20 @@ pub(crate) fn define() -> SettingGroup {
         true,
     );
 
+    settings.add_bool(
+        "enable_instruction_scheduling",
+        "Reorder machine instructions after register allocation to hide latencies.",
+        r#"
+            This runs a list scheduler over straight-line runs of instructions
+            just before emission, using each backend's instruction latencies.
+            It mostly benefits in-order cores, which stall when an instruction
+            uses a result that isn't ready yet, and costs some compile time.
+            Backends which don't describe their instructions to the scheduler
+            are unaffected.
+        "#,
+        false,
+    );
+
     settings.add_bool(
         "enable_verifier",
         "Run the Cranelift IR verifier at strategic times during compilation.",
@@ -136,6 +197,19 @@ pub(crate) fn define() -> SettingGroup {
         true,
     );
 
+    settings.add_bool(
+        "force_seq_cst_atomics",
+        "Lower all atomic memory accesses as sequentially consistent.",
+        r#"
+            By default atomic loads and stores whose memory flags request a
+            weaker ordering, such as `relaxed`, `acquire` or `release`, are
+            lowered to cheaper instruction sequences where the target allows.
+            This setting ignores those flags and provides sequential
+            consistency for every atomic access, as a conservative fallback.
+        "#,
+        false,
+    );
+
     settings.add_bool(
         "enable_safepoints",
         "Enable safepoint instruction insertions.",
@@ -280,7 +354,13 @@ pub(crate) fn define() -> SettingGroup {
     settings.add_bool(
         "enable_jump_tables",
         "Enable the use of jump tables in generated machine code.",
-        "",
+        r#"
+            When disabled, `br_table` instructions are expanded into a bounds
+            check followed by a binary search over the index. The search is
+            slower to execute than a jump table, but it avoids embedding the
+            table in the code, which for very large functions relieves the
+            pressure on branch ranges and constant islands.
+        "#,
         true,
     );
 
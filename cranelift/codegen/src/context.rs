@@ -18,7 +18,7 @@ use crate::ir::Function;
 use crate::isa::TargetIsa;
 use crate::legalizer::simple_legalize;
 use crate::loop_analysis::LoopAnalysis;
-use crate::machinst::{CompiledCode, CompiledCodeStencil};
+use crate::machinst::{check_deadline, CompiledCode, CompiledCodeStencil};
 use crate::nan_canonicalization::do_nan_canonicalization;
 use crate::remove_constant_phis::do_remove_constant_phis;
 use crate::result::{CodegenResult, CompileResult};
@@ -31,6 +31,7 @@ use crate::{timing, CompileError};
 use alloc::string::String;
 use alloc::vec::Vec;
 use cranelift_control::ControlPlane;
+use std::time::Instant;
 
 #[cfg(feature = "souper-harvest")]
 use crate::souper_harvest::do_souper_harvest;
@@ -54,6 +55,9 @@ pub struct Context {
 
     /// Flag: do we want a disassembly with the CompiledCode?
     pub want_disasm: bool,
+
+    /// The time by which compilation must have finished, if any.
+    deadline: Option<Instant>,
 }
 
 impl Context {
@@ -77,6 +81,7 @@ impl Context {
             loop_analysis: LoopAnalysis::new(),
             compiled_code: None,
             want_disasm: false,
+            deadline: None,
         }
     }
 
@@ -88,6 +93,7 @@ impl Context {
         self.loop_analysis.clear();
         self.compiled_code = None;
         self.want_disasm = false;
+        self.deadline = None;
     }
 
     /// Returns the compilation result for this function, available after any `compile` function
@@ -102,6 +108,16 @@ impl Context {
         self.want_disasm = val;
     }
 
+    /// Set the time by which compiling the function must have finished.
+    ///
+    /// Compilation checks the deadline between its passes and fails with
+    /// [`CodegenError::DeadlineExceeded`](crate::CodegenError::DeadlineExceeded)
+    /// once it has passed. A pass that has already started runs to
+    /// completion, so compilation may end some time after the deadline.
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) {
+        self.deadline = deadline;
+    }
+
     /// Compile the function, and emit machine code into a `Vec<u8>`.
     ///
     /// Run the function through all the passes necessary to generate
@@ -142,8 +158,15 @@ impl Context {
         self.verify_if(isa)?;
 
         self.optimize(isa)?;
+        check_deadline(self.deadline)?;
 
-        isa.compile_function(&self.func, &self.domtree, self.want_disasm, ctrl_plane)
+        isa.compile_function(
+            &self.func,
+            &self.domtree,
+            self.want_disasm,
+            self.deadline,
+            ctrl_plane,
+        )
     }
 
     /// Optimize the function, performing all compilation steps up to
@@ -10,7 +10,12 @@
 //!
 //! The three main primitives are the following:
 //! - `compute_cache_key` is used to compute the cache key associated to a `Function`. This is
-//! basically the content of the function, modulo a few things the caching system is resilient to.
+//! basically the content of the function, modulo a few things the caching system is resilient to:
+//! the actual external names referenced by the function, its base source location, and the
+//! numbering of its user external name references and of its constant pool and immediate
+//! entries, which are all renumbered in the order in which the function first references them.
+//! The values of constants remain part of the key, since they are encoded into the emitted code
+//! and may change instruction selection.
 //! - `serialize_compiled` is used to serialize the result of a compilation, so it can be reused
 //! later on by...
 //! - `try_finish_recompile`, which reads binary blobs serialized with `serialize_compiled`,
@@ -25,7 +30,10 @@ use core::fmt;
 use crate::alloc::string::String;
 use crate::alloc::vec::Vec;
 use crate::ir::function::{FunctionStencil, VersionMarker};
-use crate::ir::Function;
+use crate::ir::{
+    Constant, ConstantPool, ExternalName, Function, GlobalValueData, Immediate, Inst,
+    InstructionData, UserExternalNameRef,
+};
 use crate::machinst::{CompiledCode, CompiledCodeStencil};
 use crate::result::CompileResult;
 use crate::{isa::TargetIsa, timing};
@@ -33,6 +41,7 @@ use crate::{trace, CompileError, Context};
 use alloc::borrow::{Cow, ToOwned as _};
 use alloc::string::ToString as _;
 use cranelift_control::ControlPlane;
+use cranelift_entity::{EntityRef as _, PrimaryMap};
 
 impl Context {
     /// Compile the function, as in `compile`, but tries to reuse compiled artifacts from former
@@ -82,7 +91,7 @@ impl Context {
 
         let stencil = {
             let _tt = timing::store_incremental_cache();
-            let (stencil, res) = serialize_compiled(stencil);
+            let (stencil, res) = serialize_compiled(&self.func, stencil);
             if let Ok(blob) = res {
                 cache_store.insert(&cache_key_hash.0, blob);
             }
@@ -135,7 +144,7 @@ struct CachedFunc {
 /// `FunctionStencil` contains a `VersionMarker` itself.
 #[derive(Hash)]
 struct CacheKey<'a> {
-    stencil: &'a FunctionStencil,
+    stencil: Cow<'a, FunctionStencil>,
     parameters: CompileParameters,
 }
 
@@ -167,13 +176,167 @@ impl<'a> CacheKey<'a> {
     ///
     /// This is a bit expensive to compute, so it should be cached and reused as much as possible.
     fn new(isa: &dyn TargetIsa, f: &'a Function) -> Self {
+        let order = canonical_user_name_order(&f.stencil);
+        let names_canonical = order.iter().enumerate().all(|(i, name)| name.index() == i);
+        let constants = CanonicalConstants::new(&f.stencil);
+        let stencil = if names_canonical && constants.is_identity(&f.stencil) {
+            Cow::Borrowed(&f.stencil)
+        } else {
+            let mut stencil = f.stencil.clone();
+            let to_canonical = invert(&order);
+            for_each_user_name(&mut stencil, |name| *name = to_canonical[name.index()]);
+            constants.apply(&mut stencil);
+            Cow::Owned(stencil)
+        };
         CacheKey {
-            stencil: &f.stencil,
+            stencil,
             parameters: CompileParameters::from_isa(isa),
         }
     }
 }
 
+/// Returns the user external name references of `stencil` in the order that it first references
+/// them, which is the canonical numbering used in cache keys and entries.
+///
+/// Two functions which only differ in how they number their user external names, for example
+/// because they declared them in a different order or declared names that they never reference,
+/// then share a cache key.
+fn canonical_user_name_order(stencil: &FunctionStencil) -> Vec<UserExternalNameRef> {
+    let mut order = Vec::new();
+    let mut seen = crate::HashSet::new();
+    let mut visit = |name: &ExternalName| {
+        if let ExternalName::User(name) = name {
+            if seen.insert(*name) {
+                order.push(*name);
+            }
+        }
+    };
+    for data in stencil.dfg.ext_funcs.values() {
+        visit(&data.name);
+    }
+    for data in stencil.global_values.values() {
+        if let GlobalValueData::Symbol { name, .. } = data {
+            visit(name);
+        }
+    }
+    order
+}
+
+/// Calls `f` on every user external name reference in `stencil`.
+fn for_each_user_name(stencil: &mut FunctionStencil, mut f: impl FnMut(&mut UserExternalNameRef)) {
+    for data in stencil.dfg.ext_funcs.values_mut() {
+        if let ExternalName::User(name) = &mut data.name {
+            f(name);
+        }
+    }
+    for data in stencil.global_values.values_mut() {
+        if let GlobalValueData::Symbol {
+            name: ExternalName::User(name),
+            ..
+        } = data
+        {
+            f(name);
+        }
+    }
+}
+
+/// Inverts the canonical `order` of a function's user names into a map from the function's own
+/// references, by index, to canonical references.
+fn invert(order: &[UserExternalNameRef]) -> Vec<UserExternalNameRef> {
+    let len = order.iter().map(|name| name.index() + 1).max().unwrap_or(0);
+    let mut to_canonical = vec![UserExternalNameRef::new(0); len];
+    for (i, name) in order.iter().enumerate() {
+        to_canonical[name.index()] = UserExternalNameRef::new(i);
+    }
+    to_canonical
+}
+
+/// The canonical numbering of a function's pool constants and immediates, used in cache keys.
+///
+/// Lowering looks constants up by handle and emits their data, so the compiled code only depends
+/// on the data each instruction refers to and not on how the handles are numbered. Handles are
+/// renumbered in the order that instructions first reference them, and entries which no
+/// instruction references are dropped, so that functions which only differ in how they built
+/// their constant pools share a cache key.
+struct CanonicalConstants {
+    constants: Vec<Constant>,
+    immediates: Vec<Immediate>,
+}
+
+impl CanonicalConstants {
+    fn new(stencil: &FunctionStencil) -> Self {
+        let mut constants = Vec::new();
+        let mut immediates = Vec::new();
+        let mut seen_constants = crate::HashSet::new();
+        let mut seen_immediates = crate::HashSet::new();
+        for inst in (0..stencil.dfg.num_insts()).map(Inst::new) {
+            match stencil.dfg.insts[inst] {
+                InstructionData::UnaryConst {
+                    constant_handle, ..
+                } => {
+                    if seen_constants.insert(constant_handle) {
+                        constants.push(constant_handle);
+                    }
+                }
+                InstructionData::Shuffle { imm, .. } => {
+                    if seen_immediates.insert(imm) {
+                        immediates.push(imm);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self {
+            constants,
+            immediates,
+        }
+    }
+
+    /// Returns whether `stencil` already uses the canonical numbering.
+    fn is_identity(&self, stencil: &FunctionStencil) -> bool {
+        self.constants.len() == stencil.dfg.constants.len()
+            && self.immediates.len() == stencil.dfg.immediates.len()
+            && self
+                .constants
+                .iter()
+                .enumerate()
+                .all(|(i, c)| c.index() == i)
+            && self
+                .immediates
+                .iter()
+                .enumerate()
+                .all(|(i, c)| c.index() == i)
+    }
+
+    /// Renumbers the constants and immediates of `stencil` canonically.
+    fn apply(&self, stencil: &mut FunctionStencil) {
+        let mut constants = ConstantPool::new();
+        let mut constant_map = crate::HashMap::new();
+        for (i, &old) in self.constants.iter().enumerate() {
+            let new = Constant::new(i);
+            constants.set(new, stencil.dfg.constants.get(old).clone());
+            constant_map.insert(old, new);
+        }
+        let mut immediates = PrimaryMap::new();
+        let mut immediate_map = crate::HashMap::new();
+        for &old in &self.immediates {
+            let new = immediates.push(stencil.dfg.immediates[old].clone());
+            immediate_map.insert(old, new);
+        }
+        for inst in (0..stencil.dfg.num_insts()).map(Inst::new) {
+            match &mut stencil.dfg.insts[inst] {
+                InstructionData::UnaryConst {
+                    constant_handle, ..
+                } => *constant_handle = constant_map[&*constant_handle],
+                InstructionData::Shuffle { imm, .. } => *imm = immediate_map[&*imm],
+                _ => {}
+            }
+        }
+        stencil.dfg.constants = constants;
+        stencil.dfg.immediates = immediates;
+    }
+}
+
 /// Compute a cache key, and hash it on your behalf.
 ///
 /// Since computing the `CacheKey` is a bit expensive, it should be done as least as possible.
@@ -207,14 +370,56 @@ pub fn compute_cache_key(isa: &dyn TargetIsa, func: &Function) -> CacheKeyHash {
 /// As this function requires ownership on the `CompiledCodeStencil`, it gives it back at the end
 /// of the function call. The value is left untouched.
 pub fn serialize_compiled(
-    result: CompiledCodeStencil,
+    func: &Function,
+    mut result: CompiledCodeStencil,
 ) -> (CompiledCodeStencil, Result<Vec<u8>, bincode::Error>) {
+    // Entries are stored with canonically numbered user names so that they can be reused by any
+    // function with the same cache key.
+    let order = canonical_user_name_order(&func.stencil);
+    let to_canonical = invert(&order);
+    result
+        .buffer
+        .map_user_names(|name| to_canonical[name.index()]);
+    let vcode = result.vcode.take();
+    result.vcode = vcode
+        .as_deref()
+        .map(|vcode| map_disasm_user_names(vcode, |name| to_canonical[name.index()]));
     let cached = CachedFunc {
         version_marker: VersionMarker,
         stencil: result,
     };
     let result = bincode::serialize(&cached);
-    (cached.stencil, result)
+    let mut stencil = cached.stencil;
+    stencil.buffer.map_user_names(|name| order[name.index()]);
+    stencil.vcode = vcode;
+    (stencil, result)
+}
+
+/// Renumbers the user external names that the disassembly `text` refers to through `f`.
+///
+/// The disassembly names them by reference, like `userextname3`, so it has to be renumbered along
+/// with the buffer for the cached disassembly to match what compiling the function would produce.
+fn map_disasm_user_names(
+    text: &str,
+    mut f: impl FnMut(UserExternalNameRef) -> UserExternalNameRef,
+) -> String {
+    const PREFIX: &str = "userextname";
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(PREFIX) {
+        let (before, after) = rest.split_at(start + PREFIX.len());
+        result.push_str(before);
+        let digits = after
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(after.len());
+        match after[..digits].parse() {
+            Ok(index) => result.push_str(&f(UserExternalNameRef::new(index)).index().to_string()),
+            Err(_) => result.push_str(&after[..digits]),
+        }
+        rest = &after[digits..];
+    }
+    result.push_str(rest);
+    result
 }
 
 /// An error returned when recompiling failed.
@@ -248,9 +453,168 @@ pub fn try_finish_recompile(func: &Function, bytes: &[u8]) -> Result<CompiledCod
             if result.version_marker != func.stencil.version_marker {
                 Err(RecompileError::VersionMismatch)
             } else {
-                Ok(result.stencil.apply_params(&func.params))
+                let order = canonical_user_name_order(&func.stencil);
+                let mut stencil = result.stencil;
+                stencil.buffer.map_user_names(|name| order[name.index()]);
+                stencil.vcode = stencil
+                    .vcode
+                    .map(|vcode| map_disasm_user_names(&vcode, |name| order[name.index()]));
+                Ok(stencil.apply_params(&func.params))
             }
         }
         Err(err) => Err(RecompileError::Deserialize(err)),
     }
 }
+
+#[cfg(all(test, feature = "x86"))]
+mod tests {
+    use super::*;
+    use crate::cursor::{Cursor, FuncCursor};
+    use crate::ir::{
+        types, AbiParam, ConstantData, ExtFuncData, InstBuilder, Signature, UserExternalName,
+        UserFuncName,
+    };
+    use crate::isa::{self, CallConv};
+    use crate::settings;
+
+    /// Builds a function calling `callees` in order, declaring their user names in the order
+    /// given by `declared`.
+    fn caller(callees: &[u32], declared: &[u32]) -> Function {
+        let mut func = Function::with_name_signature(
+            UserFuncName::user(0, 0),
+            Signature::new(CallConv::SystemV),
+        );
+        let refs = declared
+            .iter()
+            .map(|&index| {
+                let name = UserExternalName::new(1, index);
+                (index, func.declare_imported_user_function(name))
+            })
+            .collect::<Vec<_>>();
+        let sig = func.import_signature(Signature::new(CallConv::SystemV));
+        let func_refs = callees
+            .iter()
+            .map(|callee| {
+                let (_, name) = refs.iter().find(|(index, _)| index == callee).unwrap();
+                func.import_function(ExtFuncData {
+                    name: ExternalName::user(*name),
+                    signature: sig,
+                    colocated: false,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let block = func.dfg.make_block();
+        let mut pos = FuncCursor::new(&mut func);
+        pos.insert_block(block);
+        for func_ref in func_refs {
+            pos.ins().call(func_ref, &[]);
+        }
+        pos.ins().return_(&[]);
+        func
+    }
+
+    #[test]
+    fn user_name_numbering_is_canonicalized() {
+        let isa = isa::lookup_by_name("x86_64")
+            .unwrap()
+            .finish(settings::Flags::new(settings::builder()))
+            .unwrap();
+
+        let a = caller(&[1, 2], &[1, 2]);
+        let b = caller(&[1, 2], &[2, 1]);
+        assert_eq!(
+            compute_cache_key(&*isa, &a).0,
+            compute_cache_key(&*isa, &b).0
+        );
+
+        // The disassembly names the callees too, so it's compared as well.
+        let compile = |func: &Function| {
+            let mut context = Context::for_function(func.clone());
+            context.set_disasm(true);
+            context
+                .compile_stencil(&*isa, &mut Default::default())
+                .unwrap()
+        };
+
+        let stencil = compile(&a);
+        let (stencil, blob) = serialize_compiled(&a, stencil);
+        let blob = blob.unwrap();
+        assert_eq!(
+            try_finish_recompile(&a, &blob).unwrap(),
+            stencil.apply_params(&a.params)
+        );
+
+        let expected = compile(&b).apply_params(&b.params);
+        assert!(expected.vcode.as_ref().unwrap().contains("userextname1"));
+        assert_eq!(try_finish_recompile(&b, &blob).unwrap(), expected);
+    }
+
+    #[test]
+    fn disasm_user_names_are_renumbered() {
+        let text = "call User(userextname1)\ncall User(userextname10)\nret";
+        let renumbered = map_disasm_user_names(text, |name| {
+            UserExternalNameRef::new(if name.index() == 1 { 10 } else { 1 })
+        });
+        assert_eq!(
+            renumbered,
+            "call User(userextname10)\ncall User(userextname1)\nret"
+        );
+    }
+
+    /// Builds a function returning `values` splatted into vector constants, after inserting the
+    /// splats of `pool` into its constant pool.
+    fn vconsts(values: &[u8], pool: &[u8]) -> Function {
+        let mut sig = Signature::new(CallConv::SystemV);
+        sig.returns = vec![AbiParam::new(types::I8X16); values.len()];
+        let mut func = Function::with_name_signature(UserFuncName::user(0, 0), sig);
+        for &value in pool {
+            func.dfg
+                .constants
+                .insert(ConstantData::from(vec![value; 16]));
+        }
+
+        let block = func.dfg.make_block();
+        let mut pos = FuncCursor::new(&mut func);
+        pos.insert_block(block);
+        let results = values
+            .iter()
+            .map(|&value| {
+                let constant = pos.func.dfg.constants.insert(vec![value; 16].into());
+                pos.ins().vconst(types::I8X16, constant)
+            })
+            .collect::<Vec<_>>();
+        pos.ins().return_(&results);
+        func
+    }
+
+    #[test]
+    fn constant_numbering_is_canonicalized() {
+        let isa = isa::lookup_by_name("x86_64")
+            .unwrap()
+            .finish(settings::Flags::new(settings::builder()))
+            .unwrap();
+
+        // `b` numbers the same constants differently and has one it never uses.
+        let a = vconsts(&[1, 2], &[]);
+        let b = vconsts(&[1, 2], &[2, 7]);
+        assert_eq!(
+            compute_cache_key(&*isa, &a).0,
+            compute_cache_key(&*isa, &b).0
+        );
+        assert_ne!(
+            compute_cache_key(&*isa, &a).0,
+            compute_cache_key(&*isa, &vconsts(&[2, 1], &[])).0
+        );
+
+        let stencil = Context::for_function(a.clone())
+            .compile_stencil(&*isa, &mut Default::default())
+            .unwrap();
+        let (_, blob) = serialize_compiled(&a, stencil);
+        let expected = Context::for_function(b.clone())
+            .compile_stencil(&*isa, &mut Default::default())
+            .unwrap()
+            .apply_params(&b.params);
+        assert_eq!(try_finish_recompile(&b, &blob.unwrap()).unwrap(), expected);
+    }
+}
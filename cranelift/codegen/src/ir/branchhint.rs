@@ -0,0 +1,84 @@
+//! Static branch hints.
+
+use core::fmt;
+
+#[cfg(feature = "enable-serde")]
+use serde_derive::{Deserialize, Serialize};
+
+/// The probability at or below which a branch edge is considered unlikely.
+const UNLIKELY_PERCENT: u8 = 10;
+
+/// A static hint of how likely a conditional branch like `brif` or `br_table`
+/// is to be taken.
+///
+/// The hint is the probability, in percent, that control flows to the
+/// instruction's first destination: the `then` block of a `brif` or the
+/// default block of a `br_table`. The remaining probability is spread over
+/// the other destinations. Hints are stored in
+/// [`DataFlowGraph::branch_hints`](super::DataFlowGraph::branch_hints) and
+/// written before the branch's operands in the textual format:
+///
+/// ```text
+/// brif taken(5) v1, block1, block2
+/// ```
+///
+/// Hints come from frontends, for example from WebAssembly's branch hinting
+/// proposal or from an embedder's profile. They never change the program's
+/// behavior. Unlikely destinations are laid out away from the hot path, and a
+/// `brif` which is unpredictable either way and only chooses between block
+/// arguments is turned into conditional moves.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "enable-serde", derive(Serialize, Deserialize))]
+pub struct BranchHint {
+    taken: u8,
+}
+
+impl BranchHint {
+    /// Create a hint that the first destination is taken with the given
+    /// probability, in percent.
+    ///
+    /// Returns `None` if `percent` is larger than 100.
+    pub fn new(percent: u8) -> Option<Self> {
+        if percent <= 100 {
+            Some(Self { taken: percent })
+        } else {
+            None
+        }
+    }
+
+    /// Create a hint that the first destination is almost always taken.
+    pub fn likely() -> Self {
+        Self { taken: 100 }
+    }
+
+    /// Create a hint that the first destination is almost never taken.
+    pub fn unlikely() -> Self {
+        Self { taken: 0 }
+    }
+
+    /// The probability, in percent, that the first destination is taken.
+    pub fn taken_percent(self) -> u8 {
+        self.taken
+    }
+
+    /// The probability, in percent, that any other destination is taken.
+    pub fn not_taken_percent(self) -> u8 {
+        100 - self.taken
+    }
+
+    /// Test if the first destination is unlikely to be taken.
+    pub fn is_taken_unlikely(self) -> bool {
+        self.taken_percent() <= UNLIKELY_PERCENT
+    }
+
+    /// Test if the other destinations are unlikely to be taken.
+    pub fn is_not_taken_unlikely(self) -> bool {
+        self.not_taken_percent() <= UNLIKELY_PERCENT
+    }
+}
+
+impl fmt::Display for BranchHint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "taken({})", self.taken)
+    }
+}
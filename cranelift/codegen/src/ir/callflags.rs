@@ -0,0 +1,143 @@
+//! Call site flags.
+
+use core::fmt;
+
+#[cfg(feature = "enable-serde")]
+use serde_derive::{Deserialize, Serialize};
+
+enum FlagBit {
+    /// The call is rarely executed.
+    Cold,
+    /// The callee never returns to this call site.
+    Noreturn,
+    /// The callee is a good candidate for inlining at this call site.
+    Inline,
+}
+
+const NAMES: [&str; 3] = ["cold", "noreturn", "inline"];
+
+/// Flags for call instructions like `call` and `call_indirect`.
+///
+/// These flags convey what a frontend knows about an individual call site,
+/// such as a call to a panic handler only being reached on an error path. They
+/// are stored in [`DataFlowGraph::call_flags`](super::DataFlowGraph::call_flags)
+/// and written before the callee in the textual format:
+///
+/// ```text
+/// call cold noreturn fn0(v1)
+/// ```
+///
+/// Only `noreturn` makes an assumption about the program: its callee must
+/// really never return. The other flags are hints which don't change the
+/// program's behavior.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "enable-serde", derive(Serialize, Deserialize))]
+pub struct CallFlags {
+    bits: u8,
+}
+
+impl CallFlags {
+    /// Create a new empty set of flags.
+    pub fn new() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Read a flag bit.
+    fn read(self, bit: FlagBit) -> bool {
+        self.bits & (1 << bit as usize) != 0
+    }
+
+    /// Set a flag bit.
+    fn set(&mut self, bit: FlagBit) {
+        self.bits |= 1 << bit as usize
+    }
+
+    /// Set a flag bit by name.
+    ///
+    /// Returns true if the flag was found and set, false for an unknown flag name.
+    pub fn set_by_name(&mut self, name: &str) -> bool {
+        match NAMES.iter().position(|&s| s == name) {
+            Some(bit) => {
+                self.bits |= 1 << bit;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Test if no flags are set.
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Test if the `cold` flag is set.
+    ///
+    /// A cold call is rarely executed, so the block containing it is laid out
+    /// away from the hot path like the blocks of trapping instructions.
+    pub fn cold(self) -> bool {
+        self.read(FlagBit::Cold)
+    }
+
+    /// Set the `cold` flag.
+    pub fn set_cold(&mut self) {
+        self.set(FlagBit::Cold)
+    }
+
+    /// Set the `cold` flag, returning new flags.
+    pub fn with_cold(mut self) -> Self {
+        self.set_cold();
+        self
+    }
+
+    /// Test if the `noreturn` flag is set.
+    ///
+    /// The callee of a `noreturn` call never returns to it, for example
+    /// because it aborts or unwinds. Such calls are treated as cold, since
+    /// like traps they are expected to end execution on a rarely taken path,
+    /// and anything following them in their block is replaced by a trap.
+    pub fn noreturn(self) -> bool {
+        self.read(FlagBit::Noreturn)
+    }
+
+    /// Set the `noreturn` flag.
+    pub fn set_noreturn(&mut self) {
+        self.set(FlagBit::Noreturn)
+    }
+
+    /// Set the `noreturn` flag, returning new flags.
+    pub fn with_noreturn(mut self) -> Self {
+        self.set_noreturn();
+        self
+    }
+
+    /// Test if the `inline` flag is set.
+    ///
+    /// This is a hint that the callee should be inlined at this call site.
+    /// Cranelift doesn't inline calls itself; the hint is kept for
+    /// passes which do.
+    pub fn inline(self) -> bool {
+        self.read(FlagBit::Inline)
+    }
+
+    /// Set the `inline` flag.
+    pub fn set_inline(&mut self) {
+        self.set(FlagBit::Inline)
+    }
+
+    /// Set the `inline` flag, returning new flags.
+    pub fn with_inline(mut self) -> Self {
+        self.set_inline();
+        self
+    }
+}
+
+impl fmt::Display for CallFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, n) in NAMES.iter().enumerate() {
+            if self.bits & (1 << i) != 0 {
+                write!(f, " {}", n)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -7,9 +7,9 @@ use crate::ir::dynamic_type::{DynamicTypeData, DynamicTypes};
 use crate::ir::instructions::{CallInfo, InstructionData};
 use crate::ir::pcc::Fact;
 use crate::ir::{
-    types, Block, BlockCall, ConstantData, ConstantPool, DynamicType, ExtFuncData, FuncRef,
-    Immediate, Inst, JumpTables, RelSourceLoc, SigRef, Signature, Type, Value,
-    ValueLabelAssignments, ValueList, ValueListPool,
+    types, Block, BlockCall, BranchHint, CallFlags, ConstantData, ConstantPool, DynamicType,
+    ExtFuncData, FuncRef, Immediate, Inst, JumpTables, RelSourceLoc, SigRef, Signature, Type,
+    Value, ValueLabelAssignments, ValueList, ValueListPool,
 };
 use crate::packed_option::ReservedValue;
 use crate::write::write_operands;
@@ -150,6 +150,12 @@ pub struct DataFlowGraph {
 
     /// Jump tables used in this function.
     pub jump_tables: JumpTables,
+
+    /// Flags of call instructions, such as whether the call is cold.
+    pub call_flags: SecondaryMap<Inst, CallFlags>,
+
+    /// Static hints of how likely conditional branches are to be taken.
+    pub branch_hints: SecondaryMap<Inst, Option<BranchHint>>,
 }
 
 impl DataFlowGraph {
@@ -170,6 +176,8 @@ impl DataFlowGraph {
             constants: ConstantPool::new(),
             immediates: PrimaryMap::new(),
             jump_tables: JumpTables::new(),
+            call_flags: SecondaryMap::new(),
+            branch_hints: SecondaryMap::new(),
         }
     }
 
@@ -189,6 +197,8 @@ impl DataFlowGraph {
         self.immediates.clear();
         self.jump_tables.clear();
         self.facts.clear();
+        self.call_flags.clear();
+        self.branch_hints.clear();
     }
 
     /// Get the total number of instructions created in this function, whether they are currently
@@ -966,6 +976,8 @@ impl DataFlowGraph {
             let new_result = self.inst_results(new_inst)[i];
             self.facts[new_result] = self.facts[old_result].clone();
         }
+        self.call_flags[new_inst] = self.call_flags[inst];
+        self.branch_hints[new_inst] = self.branch_hints[inst];
         new_inst
     }
 
@@ -12,6 +12,10 @@ pub enum KnownSymbol {
     /// TLS index symbol for the current thread.
     /// Used in COFF/PE file formats.
     CoffTlsIndex,
+    /// Byte which is non-zero if the aarch64 Large System Extensions
+    /// (FEAT_LSE) atomics are available at runtime, as provided by
+    /// compiler-rt and libgcc.
+    Aarch64HaveLseAtomics,
 }
 
 impl fmt::Display for KnownSymbol {
@@ -27,6 +31,7 @@ impl FromStr for KnownSymbol {
         match s {
             "ElfGlobalOffsetTable" => Ok(Self::ElfGlobalOffsetTable),
             "CoffTlsIndex" => Ok(Self::CoffTlsIndex),
+            "Aarch64HaveLseAtomics" => Ok(Self::Aarch64HaveLseAtomics),
             _ => Err(()),
         }
     }
@@ -43,5 +48,9 @@ mod tests {
             Ok(KnownSymbol::ElfGlobalOffsetTable)
         );
         assert_eq!("CoffTlsIndex".parse(), Ok(KnownSymbol::CoffTlsIndex));
+        assert_eq!(
+            "Aarch64HaveLseAtomics".parse(),
+            Ok(KnownSymbol::Aarch64HaveLseAtomics)
+        );
     }
 }
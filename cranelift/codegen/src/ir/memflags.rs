@@ -34,12 +34,28 @@ enum FlagBit {
     /// `PointsTo` fact attached with a sufficiently large valid range
     /// for the accessed size.
     Checked,
+    /// An atomic access which only needs to be atomic, without ordering other
+    /// memory accesses. Mutually exclusive with "acquire" and "release".
+    Relaxed,
+    /// An atomic load or read-modify-write with acquire ordering. Mutually
+    /// exclusive with "relaxed" and "release".
+    Acquire,
+    /// An atomic store or read-modify-write with release ordering. Mutually
+    /// exclusive with "relaxed" and "acquire".
+    Release,
 }
 
-const NAMES: [&str; 9] = [
+const NAMES: [&str; 12] = [
     "notrap", "aligned", "readonly", "little", "big", "heap", "table", "vmctx", "checked",
+    "relaxed", "acquire", "release",
 ];
 
+/// Bits of the flags which select an `AtomicOrdering`, at most one of which
+/// may be set.
+const ORDERING_BITS: u16 = (1 << FlagBit::Relaxed as usize)
+    | (1 << FlagBit::Acquire as usize)
+    | (1 << FlagBit::Release as usize);
+
 /// Endianness of a memory access.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub enum Endianness {
@@ -49,6 +65,19 @@ pub enum Endianness {
     Big,
 }
 
+/// Memory ordering of an atomic memory access.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum AtomicOrdering {
+    /// The access is atomic but doesn't order any other memory accesses.
+    Relaxed,
+    /// Loads and stores after this access can't be reordered before it.
+    Acquire,
+    /// Loads and stores before this access can't be reordered after it.
+    Release,
+    /// Sequentially consistent.
+    SeqCst,
+}
+
 /// Flags for memory operations like load/store.
 ///
 /// Each of these flags introduce a limited form of undefined behavior. The flags each enable
@@ -102,6 +131,8 @@ impl MemFlags {
                     && (bits & (1 << FlagBit::BigEndian as usize)) != 0
                 {
                     false
+                } else if (bits & ORDERING_BITS).count_ones() > 1 {
+                    false
                 } else {
                     self.bits = bits;
                     true
@@ -305,6 +336,41 @@ impl MemFlags {
         self.set_checked();
         self
     }
+
+    /// Return the memory ordering of an atomic access.
+    ///
+    /// Atomic accesses are sequentially consistent unless one of the
+    /// `relaxed`, `acquire` or `release` flags is set. These flags are only
+    /// valid on atomic instructions, which the verifier checks. Backends are
+    /// always free to provide a stronger ordering than the one requested.
+    pub fn atomic_ordering(self) -> AtomicOrdering {
+        if self.read(FlagBit::Relaxed) {
+            AtomicOrdering::Relaxed
+        } else if self.read(FlagBit::Acquire) {
+            AtomicOrdering::Acquire
+        } else if self.read(FlagBit::Release) {
+            AtomicOrdering::Release
+        } else {
+            AtomicOrdering::SeqCst
+        }
+    }
+
+    /// Set the memory ordering of an atomic access.
+    pub fn set_atomic_ordering(&mut self, ordering: AtomicOrdering) {
+        self.bits &= !ORDERING_BITS;
+        match ordering {
+            AtomicOrdering::Relaxed => self.set(FlagBit::Relaxed),
+            AtomicOrdering::Acquire => self.set(FlagBit::Acquire),
+            AtomicOrdering::Release => self.set(FlagBit::Release),
+            AtomicOrdering::SeqCst => {}
+        }
+    }
+
+    /// Set the memory ordering of an atomic access, returning new flags.
+    pub fn with_atomic_ordering(mut self, ordering: AtomicOrdering) -> Self {
+        self.set_atomic_ordering(ordering);
+        self
+    }
 }
 
 impl fmt::Display for MemFlags {
@@ -1,7 +1,9 @@
 //! Representation of Cranelift IR functions.
 
 mod atomic_rmw_op;
+mod branchhint;
 mod builder;
+mod callflags;
 pub mod condcodes;
 pub mod constant;
 pub mod dfg;
@@ -31,9 +33,11 @@ pub mod types;
 use serde_derive::{Deserialize, Serialize};
 
 pub use crate::ir::atomic_rmw_op::AtomicRmwOp;
+pub use crate::ir::branchhint::BranchHint;
 pub use crate::ir::builder::{
     InsertBuilder, InstBuilder, InstBuilderBase, InstInserterBase, ReplaceBuilder,
 };
+pub use crate::ir::callflags::CallFlags;
 pub use crate::ir::constant::{ConstantData, ConstantPool};
 pub use crate::ir::dfg::{BlockData, DataFlowGraph, ValueDef};
 pub use crate::ir::dynamic_type::{dynamic_to_fixed, DynamicTypeData, DynamicTypes};
@@ -54,7 +58,7 @@ pub use crate::ir::jumptable::JumpTableData;
 pub use crate::ir::known_symbol::KnownSymbol;
 pub use crate::ir::layout::Layout;
 pub use crate::ir::libcall::{get_probestack_funcref, LibCall};
-pub use crate::ir::memflags::{Endianness, MemFlags};
+pub use crate::ir::memflags::{AtomicOrdering, Endianness, MemFlags};
 pub use crate::ir::memtype::{MemoryTypeData, MemoryTypeField};
 pub use crate::ir::pcc::{BaseExpr, Expr, Fact, FactContext, PccError, PccResult};
 pub use crate::ir::progpoint::ProgramPoint;
@@ -0,0 +1,71 @@
+//! Relating printed AArch64 instructions to capstone's disassembly, for
+//! checking emitted encodings.
+
+use crate::machinst::DisasSyntax;
+use std::format;
+use std::string::{String, ToString};
+
+pub(crate) struct AArch64DisasSyntax;
+
+/// Printed mnemonics that capstone decodes as one of their preferred
+/// aliases.
+const ALIASES: &[(&str, &str)] = &[
+    ("adds", "cmn"),
+    ("ands", "tst"),
+    ("bic", "and"),
+    ("bti", "hint"),
+    ("csinc", "cinc"),
+    ("csinc", "cset"),
+    ("csinv", "cinv"),
+    ("csinv", "csetm"),
+    ("csneg", "cneg"),
+    ("eon", "eor"),
+    ("madd", "mul"),
+    ("movn", "mov"),
+    ("movz", "mov"),
+    ("msub", "mneg"),
+    ("orn", "mvn"),
+    ("orn", "orr"),
+    ("orr", "mov"),
+    ("retabsp", "retab"),
+    ("rev32", "rev"),
+    ("rev64", "rev"),
+    ("sub", "neg"),
+    ("subs", "cmp"),
+    ("subs", "negs"),
+    ("sxtl", "sshll"),
+    ("sxtl2", "sshll2"),
+    ("uxtl", "ushll"),
+    ("uxtl2", "ushll2"),
+];
+
+impl DisasSyntax for AArch64DisasSyntax {
+    fn same_mnemonic(&self, printed: &str, decoded: &str) -> bool {
+        if printed == decoded || ALIASES.contains(&(printed, decoded)) {
+            return true;
+        }
+        // Loads and stores with an unscaled offset are printed like scaled
+        // ones, e.g. `ldr` for `ldur`.
+        ["ldr", "str"].iter().any(|op| {
+            printed.strip_prefix(op).map_or(false, |size| {
+                decoded == format!("{}u{}{size}", &op[..2], &op[2..])
+            })
+        })
+    }
+
+    fn canonical_reg(&self, name: &str) -> Option<String> {
+        match name {
+            "fp" => return Some("x29".to_string()),
+            "lr" => return Some("x30".to_string()),
+            "sp" | "wsp" => return Some("sp".to_string()),
+            _ => {}
+        }
+        let (kind, n) = name.split_at(name.len().min(1));
+        let n = n.parse::<u8>().ok().filter(|&n| n < 32)?;
+        match kind {
+            "x" | "w" if n < 31 => Some(format!("x{n}")),
+            "v" | "q" | "d" | "s" | "h" | "b" => Some(format!("v{n}")),
+            _ => None,
+        }
+    }
+}
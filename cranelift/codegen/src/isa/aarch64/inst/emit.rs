@@ -4,7 +4,7 @@ use cranelift_control::ControlPlane;
 use regalloc2::Allocation;
 
 use crate::binemit::{Reloc, StackMap};
-use crate::ir::{self, types::*, MemFlags, RelSourceLoc, TrapCode};
+use crate::ir::{self, types::*, AtomicOrdering, KnownSymbol, MemFlags, RelSourceLoc, TrapCode};
 use crate::isa::aarch64::inst::*;
 use crate::machinst::{ty_bits, Reg, RegClass, Writable};
 use crate::trace;
@@ -532,7 +532,25 @@ fn enc_dmb_ish() -> u32 {
     0xD5033BBF
 }
 
-fn enc_acq_rel(ty: Type, op: AtomicRMWOp, rs: Reg, rt: Writable<Reg>, rn: Reg) -> u32 {
+/// Returns the acquire and release bits of an LSE atomic instruction with the
+/// given ordering. Sequentially consistent accesses set both.
+fn enc_atomic_ordering(ordering: AtomicOrdering) -> (u32, u32) {
+    match ordering {
+        AtomicOrdering::Relaxed => (0, 0),
+        AtomicOrdering::Acquire => (1, 0),
+        AtomicOrdering::Release => (0, 1),
+        AtomicOrdering::SeqCst => (1, 1),
+    }
+}
+
+fn enc_acq_rel(
+    ty: Type,
+    op: AtomicRMWOp,
+    ordering: AtomicOrdering,
+    rs: Reg,
+    rt: Writable<Reg>,
+    rn: Reg,
+) -> u32 {
     assert!(machreg_to_gpr(rt.to_reg()) != 31);
     let sz = match ty {
         I64 => 0b11,
@@ -556,8 +574,11 @@ fn enc_acq_rel(ty: Type, op: AtomicRMWOp, rs: Reg, rt: Writable<Reg>, rn: Reg) -
         AtomicRMWOp::Umin => 0b111,
         AtomicRMWOp::Swp => 0b000,
     };
-    0b00_111_000_111_00000_0_000_00_00000_00000
+    let (acquire, release) = enc_atomic_ordering(ordering);
+    0b00_111_000_001_00000_0_000_00_00000_00000
         | (sz << 30)
+        | (acquire << 23)
+        | (release << 22)
         | (machreg_to_gpr(rs) << 16)
         | bit15 << 15
         | (op << 12)
@@ -579,6 +600,20 @@ fn enc_ldar(ty: Type, rt: Writable<Reg>, rn: Reg) -> u32 {
         | machreg_to_gpr(rt.to_reg())
 }
 
+fn enc_ldapr(ty: Type, rt: Writable<Reg>, rn: Reg) -> u32 {
+    let sz = match ty {
+        I64 => 0b11,
+        I32 => 0b10,
+        I16 => 0b01,
+        I8 => 0b00,
+        _ => unreachable!(),
+    };
+    0b00_111000_1_0_1_11111_1_100_00_00000_00000
+        | (sz << 30)
+        | (machreg_to_gpr(rn) << 5)
+        | machreg_to_gpr(rt.to_reg())
+}
+
 fn enc_stlr(ty: Type, rt: Reg, rn: Reg) -> u32 {
     let sz = match ty {
         I64 => 0b11,
@@ -622,11 +657,14 @@ fn enc_stlxr(ty: Type, rs: Writable<Reg>, rt: Reg, rn: Reg) -> u32 {
         | machreg_to_gpr(rt)
 }
 
-fn enc_cas(size: u32, rs: Writable<Reg>, rt: Reg, rn: Reg) -> u32 {
+fn enc_cas(size: u32, ordering: AtomicOrdering, rs: Writable<Reg>, rt: Reg, rn: Reg) -> u32 {
     debug_assert_eq!(size & 0b11, size);
 
-    0b00_0010001_1_1_00000_1_11111_00000_00000
+    let (acquire, release) = enc_atomic_ordering(ordering);
+    0b00_0010001_0_1_00000_0_11111_00000_00000
         | size << 30
+        | acquire << 22
+        | release << 15
         | machreg_to_gpr(rs.to_reg()) << 16
         | machreg_to_gpr(rn) << 5
         | machreg_to_gpr(rt)
@@ -647,6 +685,53 @@ fn enc_asimd_mod_imm(rd: Writable<Reg>, q_op: u32, cmode: u32, imm: u8) -> u32 {
         | machreg_to_vec(rd.to_reg())
 }
 
+/// Emits a check of whether LSE atomics are available at runtime, branching to
+/// `unavailable` if not. Clobbers x24.
+fn emit_lse_check(
+    unavailable: MachLabel,
+    sink: &mut MachBuffer<Inst>,
+    emit_info: &EmitInfo,
+    state: &mut EmitState,
+) {
+    let x24 = xreg(24);
+    let x24wr = writable_xreg(24);
+
+    // ldr x24, =__aarch64_have_lse_atomics
+    Inst::LoadExtName {
+        rd: x24wr,
+        name: crate::isa::Box::new(ExternalName::KnownSymbol(
+            KnownSymbol::Aarch64HaveLseAtomics,
+        )),
+        offset: 0,
+    }
+    .emit(&[], sink, emit_info, state);
+
+    // ldrb w24, [x24]
+    Inst::ULoad8 {
+        rd: x24wr,
+        mem: AMode::reg(x24),
+        flags: MemFlags::trusted(),
+    }
+    .emit(&[], sink, emit_info, state);
+
+    // cbz x24, unavailable
+    let br_offset = sink.cur_offset();
+    sink.put4(enc_conditional_br(
+        BranchTarget::Label(unavailable),
+        CondBrKind::Zero(x24),
+        &mut AllocationConsumer::default(),
+    ));
+    sink.use_label_at_offset(br_offset, unavailable, LabelUse::Branch19);
+}
+
+/// Emits an unconditional branch to `label` which is internal to a
+/// pseudo-instruction, and so is not subject to branch simplification.
+fn emit_jump_to(label: MachLabel, sink: &mut MachBuffer<Inst>) {
+    let off = sink.cur_offset();
+    sink.use_label_at_offset(off, label, LabelUse::Branch26);
+    sink.put4(enc_jump26(0b000101, 0));
+}
+
 /// State carried between emissions of a sequence of instructions.
 #[derive(Default, Clone, Debug)]
 pub struct EmitState {
@@ -1518,7 +1603,7 @@ impl MachInstEmit for Inst {
                     sink.add_trap(TrapCode::HeapOutOfBounds);
                 }
 
-                sink.put4(enc_acq_rel(ty, op, rs, rt, rn));
+                sink.put4(enc_acq_rel(ty, op, flags.atomic_ordering(), rs, rt, rn));
             }
             &Inst::AtomicRMWLoop { ty, op, flags, .. } => {
                 /* Emit this:
@@ -1726,7 +1811,7 @@ impl MachInstEmit for Inst {
                     sink.add_trap(TrapCode::HeapOutOfBounds);
                 }
 
-                sink.put4(enc_cas(size, rd, rt, rn));
+                sink.put4(enc_cas(size, flags.atomic_ordering(), rd, rt, rn));
             }
             &Inst::AtomicCASLoop { ty, flags, .. } => {
                 /* Emit this:
@@ -1805,6 +1890,190 @@ impl MachInstEmit for Inst {
                 // out:
                 sink.bind_label(out_label, &mut state.ctrl_plane);
             }
+            &Inst::AtomicRMWLoopOrLse {
+                ty,
+                op,
+                flags,
+                addr,
+                operand,
+                oldval,
+                scratch1,
+                scratch2,
+            } => {
+                /* Emit this:
+                      ldr         x24, =__aarch64_have_lse_atomics
+                      ldrb        w24, [x24]
+                      cbz         x24, loop
+                      // maybe negate or invert the operand
+                      neg/mvn     x28, x26
+                      ld<op>al{,b,h} x/w26 or x/w28, x/w27, [x25]
+                      b           out
+                    loop:
+                      <AtomicRMWLoop>
+                    out:
+
+                   Operand conventions are those of AtomicRMWLoop. There is no LSE
+                   instruction for `nand`, so it always uses the loop.
+                */
+                let loop_inst = Inst::AtomicRMWLoop {
+                    ty,
+                    op,
+                    flags,
+                    addr,
+                    operand,
+                    oldval,
+                    scratch1,
+                    scratch2,
+                };
+                let lse_op = match op {
+                    AtomicRMWLoopOp::Add | AtomicRMWLoopOp::Sub => Some(AtomicRMWOp::Add),
+                    AtomicRMWLoopOp::And => Some(AtomicRMWOp::Clr),
+                    AtomicRMWLoopOp::Orr => Some(AtomicRMWOp::Set),
+                    AtomicRMWLoopOp::Eor => Some(AtomicRMWOp::Eor),
+                    AtomicRMWLoopOp::Smin => Some(AtomicRMWOp::Smin),
+                    AtomicRMWLoopOp::Smax => Some(AtomicRMWOp::Smax),
+                    AtomicRMWLoopOp::Umin => Some(AtomicRMWOp::Umin),
+                    AtomicRMWLoopOp::Umax => Some(AtomicRMWOp::Umax),
+                    AtomicRMWLoopOp::Xchg => Some(AtomicRMWOp::Swp),
+                    AtomicRMWLoopOp::Nand => None,
+                };
+                match lse_op {
+                    None => loop_inst.emit(&[], sink, emit_info, state),
+                    Some(lse_op) => {
+                        let xzr = zero_reg();
+                        let x25 = xreg(25);
+                        let x26 = xreg(26);
+                        let x27wr = writable_xreg(27);
+                        let x28 = xreg(28);
+                        let x28wr = writable_xreg(28);
+                        let loop_label = sink.get_label();
+                        let out_label = sink.get_label();
+
+                        emit_lse_check(loop_label, sink, emit_info, state);
+
+                        let size = OperandSize::from_ty(ty);
+                        let rs = match op {
+                            AtomicRMWLoopOp::Sub => {
+                                // sub x28, xzr, x26
+                                Inst::AluRRR {
+                                    alu_op: ALUOp::Sub,
+                                    size,
+                                    rd: x28wr,
+                                    rn: xzr,
+                                    rm: x26,
+                                }
+                                .emit(&[], sink, emit_info, state);
+                                x28
+                            }
+                            AtomicRMWLoopOp::And => {
+                                // orn x28, xzr, x26
+                                Inst::AluRRR {
+                                    alu_op: ALUOp::OrrNot,
+                                    size,
+                                    rd: x28wr,
+                                    rn: xzr,
+                                    rm: x26,
+                                }
+                                .emit(&[], sink, emit_info, state);
+                                x28
+                            }
+                            _ => x26,
+                        };
+
+                        let srcloc = state.cur_srcloc();
+                        if !srcloc.is_default() && !flags.notrap() {
+                            sink.add_trap(TrapCode::HeapOutOfBounds);
+                        }
+                        sink.put4(enc_acq_rel(
+                            ty,
+                            lse_op,
+                            flags.atomic_ordering(),
+                            rs,
+                            x27wr,
+                            x25,
+                        ));
+
+                        emit_jump_to(out_label, sink);
+
+                        // loop:
+                        sink.bind_label(loop_label, &mut state.ctrl_plane);
+                        loop_inst.emit(&[], sink, emit_info, state);
+
+                        // out:
+                        sink.bind_label(out_label, &mut state.ctrl_plane);
+                    }
+                }
+            }
+            &Inst::AtomicCASLoopOrLse {
+                ty,
+                flags,
+                addr,
+                expected,
+                replacement,
+                oldval,
+                scratch,
+            } => {
+                /* Emit this:
+                      ldr         x24, =__aarch64_have_lse_atomics
+                      ldrb        w24, [x24]
+                      cbz         x24, loop
+                      mov         x27, x26
+                      casal{,b,h} x/w27, x/w28, [x25]
+                      b           out
+                    loop:
+                      <AtomicCASLoop>
+                    out:
+
+                   Operand conventions are those of AtomicCASLoop.
+                */
+                let x25 = xreg(25);
+                let x26 = xreg(26);
+                let x27wr = writable_xreg(27);
+                let x28 = xreg(28);
+                let loop_label = sink.get_label();
+                let out_label = sink.get_label();
+
+                emit_lse_check(loop_label, sink, emit_info, state);
+
+                // mov x27, x26
+                Inst::Mov {
+                    size: OperandSize::Size64,
+                    rd: x27wr,
+                    rm: x26,
+                }
+                .emit(&[], sink, emit_info, state);
+
+                let srcloc = state.cur_srcloc();
+                if !srcloc.is_default() && !flags.notrap() {
+                    sink.add_trap(TrapCode::HeapOutOfBounds);
+                }
+                let size = match ty {
+                    I8 => 0b00,
+                    I16 => 0b01,
+                    I32 => 0b10,
+                    I64 => 0b11,
+                    _ => panic!("Unsupported type: {}", ty),
+                };
+                sink.put4(enc_cas(size, flags.atomic_ordering(), x27wr, x28, x25));
+
+                emit_jump_to(out_label, sink);
+
+                // loop:
+                sink.bind_label(loop_label, &mut state.ctrl_plane);
+                Inst::AtomicCASLoop {
+                    ty,
+                    flags,
+                    addr,
+                    expected,
+                    replacement,
+                    oldval,
+                    scratch,
+                }
+                .emit(&[], sink, emit_info, state);
+
+                // out:
+                sink.bind_label(out_label, &mut state.ctrl_plane);
+            }
             &Inst::LoadAcquire {
                 access_ty,
                 rt,
@@ -1821,6 +2090,22 @@ impl MachInstEmit for Inst {
 
                 sink.put4(enc_ldar(access_ty, rt, rn));
             }
+            &Inst::LoadAcquirePc {
+                access_ty,
+                rt,
+                rn,
+                flags,
+            } => {
+                let rn = allocs.next(rn);
+                let rt = allocs.next_writable(rt);
+
+                let srcloc = state.cur_srcloc();
+                if !srcloc.is_default() && !flags.notrap() {
+                    sink.add_trap(TrapCode::HeapOutOfBounds);
+                }
+
+                sink.put4(enc_ldapr(access_ty, rt, rn));
+            }
             &Inst::StoreRelease {
                 access_ty,
                 rt,
@@ -2952,6 +3237,14 @@ impl MachInstEmit for Inst {
                     VecALUModOp::Fmls => {
                         (0b000_01110_10_1 | (size.enc_float_size() << 1), 0b110011)
                     }
+                    VecALUModOp::Sdot => {
+                        debug_assert_eq!(size, VectorSize::Size32x4);
+                        (0b000_01110_10_0, 0b100101)
+                    }
+                    VecALUModOp::Udot => {
+                        debug_assert_eq!(size, VectorSize::Size32x4);
+                        (0b001_01110_10_0, 0b100101)
+                    }
                 };
                 sink.put4(enc_vec_rrr(top11 | q << 9, rm, bit15_10, rn, rd));
             }
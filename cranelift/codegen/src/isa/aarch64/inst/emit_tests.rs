@@ -1,5 +1,5 @@
 use crate::ir::types::*;
-use crate::ir::TrapCode;
+use crate::ir::{AtomicOrdering, TrapCode};
 use crate::isa::aarch64::inst::*;
 use crate::isa::CallConv;
 use crate::settings;
@@ -3683,6 +3683,32 @@ fn test_aarch64_binemit() {
         "bsl v8.16b, v8.16b, v9.16b, v1.16b",
     ));
 
+    insns.push((
+        Inst::VecRRRMod {
+            alu_op: VecALUModOp::Sdot,
+            rd: writable_vreg(0),
+            ri: vreg(0),
+            rn: vreg(1),
+            rm: vreg(2),
+            size: VectorSize::Size32x4,
+        },
+        "2094824E",
+        "sdot v0.4s, v0.4s, v1.16b, v2.16b",
+    ));
+
+    insns.push((
+        Inst::VecRRRMod {
+            alu_op: VecALUModOp::Udot,
+            rd: writable_vreg(5),
+            ri: vreg(5),
+            rn: vreg(30),
+            rm: vreg(17),
+            size: VectorSize::Size32x4,
+        },
+        "C597916E",
+        "udot v5.4s, v5.4s, v30.16b, v17.16b",
+    ));
+
     insns.push((
         Inst::VecRRR {
             alu_op: VecALUOp::Umaxp,
@@ -7679,6 +7705,42 @@ fn test_aarch64_binemit() {
         "0781E6F8",
         "swpal x6, x7, [x8]",
     ));
+    insns.push((
+        Inst::AtomicRMW {
+            ty: I64,
+            op: AtomicRMWOp::Add,
+            rs: xreg(10),
+            rt: writable_xreg(11),
+            rn: xreg(12),
+            flags: MemFlags::trusted().with_atomic_ordering(AtomicOrdering::Relaxed),
+        },
+        "8B012AF8",
+        "ldadd x10, x11, [x12]",
+    ));
+    insns.push((
+        Inst::AtomicRMW {
+            ty: I32,
+            op: AtomicRMWOp::Set,
+            rs: xreg(1),
+            rt: writable_xreg(2),
+            rn: xreg(3),
+            flags: MemFlags::trusted().with_atomic_ordering(AtomicOrdering::Acquire),
+        },
+        "6230A1B8",
+        "ldseta w1, w2, [x3]",
+    ));
+    insns.push((
+        Inst::AtomicRMW {
+            ty: I16,
+            op: AtomicRMWOp::Swp,
+            rs: xreg(4),
+            rt: writable_xreg(5),
+            rn: xreg(6),
+            flags: MemFlags::trusted().with_atomic_ordering(AtomicOrdering::Release),
+        },
+        "C5806478",
+        "swplh w4, w5, [x6]",
+    ));
 
     insns.push((
         Inst::AtomicCAS {
@@ -7728,6 +7790,42 @@ fn test_aarch64_binemit() {
         "6FFFE7C8",
         "casal x7, x7, x15, [x27]",
     ));
+    insns.push((
+        Inst::AtomicCAS {
+            rd: writable_xreg(7),
+            rs: xreg(7),
+            rt: xreg(15),
+            rn: xreg(27),
+            ty: I64,
+            flags: MemFlags::trusted().with_atomic_ordering(AtomicOrdering::Relaxed),
+        },
+        "6F7FA7C8",
+        "cas x7, x7, x15, [x27]",
+    ));
+    insns.push((
+        Inst::AtomicCAS {
+            rd: writable_xreg(0),
+            rs: xreg(0),
+            rt: xreg(1),
+            rn: xreg(2),
+            ty: I32,
+            flags: MemFlags::trusted().with_atomic_ordering(AtomicOrdering::Acquire),
+        },
+        "417CE088",
+        "casa w0, w0, w1, [x2]",
+    ));
+    insns.push((
+        Inst::AtomicCAS {
+            rd: writable_xreg(3),
+            rs: xreg(3),
+            rt: xreg(4),
+            rn: xreg(5),
+            ty: I8,
+            flags: MemFlags::trusted().with_atomic_ordering(AtomicOrdering::Release),
+        },
+        "A4FCA308",
+        "caslb w3, w3, w4, [x5]",
+    ));
     insns.push((
         Inst::AtomicCASLoop {
             ty: I8,
@@ -7828,6 +7926,50 @@ fn test_aarch64_binemit() {
         "ldar x28, [x7]",
     ));
 
+    insns.push((
+        Inst::LoadAcquirePc {
+            access_ty: I8,
+            rt: writable_xreg(7),
+            rn: xreg(28),
+            flags: MemFlags::trusted(),
+        },
+        "87C3BF38",
+        "ldaprb w7, [x28]",
+    ));
+
+    insns.push((
+        Inst::LoadAcquirePc {
+            access_ty: I16,
+            rt: writable_xreg(2),
+            rn: xreg(3),
+            flags: MemFlags::trusted(),
+        },
+        "62C0BF78",
+        "ldaprh w2, [x3]",
+    ));
+
+    insns.push((
+        Inst::LoadAcquirePc {
+            access_ty: I32,
+            rt: writable_xreg(8),
+            rn: xreg(9),
+            flags: MemFlags::trusted(),
+        },
+        "28C1BFB8",
+        "ldapr w8, [x9]",
+    ));
+
+    insns.push((
+        Inst::LoadAcquirePc {
+            access_ty: I64,
+            rt: writable_xreg(10),
+            rn: xreg(11),
+            flags: MemFlags::trusted(),
+        },
+        "6AC1BFF8",
+        "ldapr x10, [x11]",
+    ));
+
     insns.push((
         Inst::StoreRelease {
             access_ty: I8,
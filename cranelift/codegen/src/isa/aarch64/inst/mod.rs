@@ -2,7 +2,7 @@
 
 use crate::binemit::{Addend, CodeOffset, Reloc};
 use crate::ir::types::{F32, F64, I128, I16, I32, I64, I8, I8X16, R32, R64};
-use crate::ir::{types, ExternalName, MemFlags, Opcode, Type};
+use crate::ir::{types, AtomicOrdering, ExternalName, MemFlags, Opcode, Type};
 use crate::isa::{CallConv, FunctionAlignment};
 use crate::machinst::*;
 use crate::{settings, CodegenError, CodegenResult};
@@ -528,6 +528,15 @@ fn aarch64_get_operands<F: Fn(VReg) -> VReg>(inst: &Inst, collector: &mut Operan
             scratch1,
             scratch2,
             ..
+        }
+        | &Inst::AtomicRMWLoopOrLse {
+            op,
+            addr,
+            operand,
+            oldval,
+            scratch1,
+            scratch2,
+            ..
         } => {
             collector.reg_fixed_use(addr, xreg(25));
             collector.reg_fixed_use(operand, xreg(26));
@@ -555,6 +564,14 @@ fn aarch64_get_operands<F: Fn(VReg) -> VReg>(inst: &Inst, collector: &mut Operan
             oldval,
             scratch,
             ..
+        }
+        | &Inst::AtomicCASLoopOrLse {
+            addr,
+            expected,
+            replacement,
+            oldval,
+            scratch,
+            ..
         } => {
             collector.reg_fixed_use(addr, xreg(25));
             collector.reg_fixed_use(expected, xreg(26));
@@ -562,7 +579,7 @@ fn aarch64_get_operands<F: Fn(VReg) -> VReg>(inst: &Inst, collector: &mut Operan
             collector.reg_fixed_def(oldval, xreg(27));
             collector.reg_fixed_def(scratch, xreg(24));
         }
-        &Inst::LoadAcquire { rt, rn, .. } => {
+        &Inst::LoadAcquire { rt, rn, .. } | &Inst::LoadAcquirePc { rt, rn, .. } => {
             collector.reg_use(rn);
             collector.reg_def(rt);
         }
@@ -1119,6 +1136,85 @@ impl MachInst for Inst {
         }
     }
 
+    fn emits_inline_data(&self) -> bool {
+        match self {
+            &Inst::Word4 { .. }
+            | &Inst::Word8 { .. }
+            | &Inst::JTSequence { .. }
+            | &Inst::LoadExtName { .. }
+            | &Inst::AtomicRMWLoopOrLse { .. }
+            | &Inst::AtomicCASLoopOrLse { .. }
+            | &Inst::EmitIsland { .. } => true,
+            _ => false,
+        }
+    }
+
+    fn sched_class(&self) -> SchedClass {
+        // Approximate latencies of in-order cores such as the Cortex-A53 and
+        // Cortex-A55. Instructions which read or write the flags are left in
+        // place, as the flags aren't register operands.
+        let alu_latency = |alu_op: ALUOp, latency: u8| match alu_op {
+            ALUOp::AddS
+            | ALUOp::SubS
+            | ALUOp::AndS
+            | ALUOp::Adc
+            | ALUOp::AdcS
+            | ALUOp::Sbc
+            | ALUOp::SbcS => SchedClass::Barrier,
+            ALUOp::SMulH | ALUOp::UMulH => SchedClass::Pure { latency: 4 },
+            ALUOp::SDiv | ALUOp::UDiv => SchedClass::Pure { latency: 12 },
+            _ => SchedClass::Pure { latency },
+        };
+        // Pre- and post-indexed addressing modes write back to the stack
+        // pointer, which isn't a register operand either.
+        let mem_latency = |mem: &AMode, latency: u8| match mem {
+            AMode::SPPreIndexed { .. } | AMode::SPPostIndexed { .. } => SchedClass::Barrier,
+            _ => SchedClass::Memory { latency },
+        };
+        match self {
+            &Inst::AluRRR { alu_op, .. }
+            | &Inst::AluRRImm12 { alu_op, .. }
+            | &Inst::AluRRImmLogic { alu_op, .. }
+            | &Inst::AluRRImmShift { alu_op, .. } => alu_latency(alu_op, 1),
+            &Inst::AluRRRShift { alu_op, .. } | &Inst::AluRRRExtend { alu_op, .. } => {
+                alu_latency(alu_op, 2)
+            }
+            &Inst::AluRRRR { .. } => SchedClass::Pure { latency: 4 },
+            &Inst::BitRR { .. }
+            | &Inst::Mov { .. }
+            | &Inst::MovWide { .. }
+            | &Inst::MovK { .. }
+            | &Inst::Extend { .. } => SchedClass::Pure { latency: 1 },
+            &Inst::FpuRR { fpu_op, .. } => match fpu_op {
+                FPUOp1::Sqrt => SchedClass::Pure { latency: 18 },
+                _ => SchedClass::Pure { latency: 4 },
+            },
+            &Inst::FpuRRR { fpu_op, .. } => match fpu_op {
+                FPUOp2::Div => SchedClass::Pure { latency: 18 },
+                _ => SchedClass::Pure { latency: 4 },
+            },
+            &Inst::FpuRRRR { .. } => SchedClass::Pure { latency: 4 },
+            &Inst::ULoad8 { ref mem, .. }
+            | &Inst::SLoad8 { ref mem, .. }
+            | &Inst::ULoad16 { ref mem, .. }
+            | &Inst::SLoad16 { ref mem, .. }
+            | &Inst::ULoad32 { ref mem, .. }
+            | &Inst::SLoad32 { ref mem, .. }
+            | &Inst::ULoad64 { ref mem, .. }
+            | &Inst::FpuLoad32 { ref mem, .. }
+            | &Inst::FpuLoad64 { ref mem, .. }
+            | &Inst::FpuLoad128 { ref mem, .. } => mem_latency(mem, 3),
+            &Inst::Store8 { ref mem, .. }
+            | &Inst::Store16 { ref mem, .. }
+            | &Inst::Store32 { ref mem, .. }
+            | &Inst::Store64 { ref mem, .. }
+            | &Inst::FpuStore32 { ref mem, .. }
+            | &Inst::FpuStore64 { ref mem, .. }
+            | &Inst::FpuStore128 { ref mem, .. } => mem_latency(mem, 1),
+            _ => SchedClass::Barrier,
+        }
+    }
+
     fn gen_dummy_use(reg: Reg) -> Inst {
         Inst::DummyUse { reg }
     }
@@ -1170,14 +1266,15 @@ impl MachInst for Inst {
     }
 
     fn worst_case_size() -> CodeOffset {
-        // The maximum size, in bytes, of any `Inst`'s emitted code. We have at least one case of
-        // an 8-instruction sequence (saturating int-to-float conversions) with three embedded
-        // 64-bit f64 constants.
+        // The maximum size, in bytes, of any `Inst`'s emitted code. The largest case is an
+        // atomic read-modify-write which checks for LSE support at runtime: a 64-bit symbol
+        // address load, a 2-instruction check, a 3-instruction LSE sequence and a 6-instruction
+        // load-linked/store-conditional loop.
         //
         // Note that inline jump-tables handle island/pool insertion separately, so we do not need
         // to account for them here (otherwise the worst case would be 2^31 * 4, clearly not
         // feasible for other reasons).
-        44
+        60
     }
 
     fn ref_type_regclass(_: &settings::Flags) -> RegClass {
@@ -1226,6 +1323,17 @@ fn mem_finalize_for_show(mem: &AMode, state: &EmitState) -> (String, AMode) {
     (mem_str, mem)
 }
 
+/// Returns the suffix of an LSE atomic instruction's mnemonic for the atomic
+/// ordering of `flags`.
+fn atomic_ordering_suffix(flags: MemFlags) -> &'static str {
+    match flags.atomic_ordering() {
+        AtomicOrdering::Relaxed => "",
+        AtomicOrdering::Acquire => "a",
+        AtomicOrdering::Release => "l",
+        AtomicOrdering::SeqCst => "al",
+    }
+}
+
 impl Inst {
     fn print_with_state(&self, state: &mut EmitState, allocs: &mut AllocationConsumer) -> String {
         let mut empty_allocs = AllocationConsumer::default();
@@ -1553,19 +1661,25 @@ impl Inst {
                 format!("ccmp {}, {}, {}, {}", rn, imm, nzcv, cond)
             }
             &Inst::AtomicRMW {
-                rs, rt, rn, ty, op, ..
+                rs,
+                rt,
+                rn,
+                ty,
+                op,
+                flags,
             } => {
                 let op = match op {
-                    AtomicRMWOp::Add => "ldaddal",
-                    AtomicRMWOp::Clr => "ldclral",
-                    AtomicRMWOp::Eor => "ldeoral",
-                    AtomicRMWOp::Set => "ldsetal",
-                    AtomicRMWOp::Smax => "ldsmaxal",
-                    AtomicRMWOp::Umax => "ldumaxal",
-                    AtomicRMWOp::Smin => "ldsminal",
-                    AtomicRMWOp::Umin => "lduminal",
-                    AtomicRMWOp::Swp => "swpal",
+                    AtomicRMWOp::Add => "ldadd",
+                    AtomicRMWOp::Clr => "ldclr",
+                    AtomicRMWOp::Eor => "ldeor",
+                    AtomicRMWOp::Set => "ldset",
+                    AtomicRMWOp::Smax => "ldsmax",
+                    AtomicRMWOp::Umax => "ldumax",
+                    AtomicRMWOp::Smin => "ldsmin",
+                    AtomicRMWOp::Umin => "ldumin",
+                    AtomicRMWOp::Swp => "swp",
                 };
+                let ordering = atomic_ordering_suffix(flags);
 
                 let size = OperandSize::from_ty(ty);
                 let rs = pretty_print_ireg(rs, size, allocs);
@@ -1577,7 +1691,7 @@ impl Inst {
                     I16 => "h",
                     _ => "",
                 };
-                format!("{}{} {}, {}, [{}]", op, ty_suffix, rs, rt, rn)
+                format!("{}{}{} {}, {}, [{}]", op, ordering, ty_suffix, rs, rt, rn)
             }
             &Inst::AtomicRMWLoop {
                 ty,
@@ -1588,7 +1702,21 @@ impl Inst {
                 scratch1,
                 scratch2,
                 ..
+            }
+            | &Inst::AtomicRMWLoopOrLse {
+                ty,
+                op,
+                addr,
+                operand,
+                oldval,
+                scratch1,
+                scratch2,
+                ..
             } => {
+                let name = match self {
+                    Inst::AtomicRMWLoopOrLse { .. } => "atomic_rmw_loop_or_lse",
+                    _ => "atomic_rmw_loop",
+                };
                 let op = match op {
                     AtomicRMWLoopOp::Add => "add",
                     AtomicRMWLoopOp::Sub => "sub",
@@ -1608,7 +1736,8 @@ impl Inst {
                 let scratch1 = pretty_print_ireg(scratch1.to_reg(), OperandSize::Size64, allocs);
                 let scratch2 = pretty_print_ireg(scratch2.to_reg(), OperandSize::Size64, allocs);
                 format!(
-                    "atomic_rmw_loop_{}_{} addr={} operand={} oldval={} scratch1={} scratch2={}",
+                    "{}_{}_{} addr={} operand={} oldval={} scratch1={} scratch2={}",
+                    name,
                     op,
                     ty.bits(),
                     addr,
@@ -1619,14 +1748,20 @@ impl Inst {
                 )
             }
             &Inst::AtomicCAS {
-                rd, rs, rt, rn, ty, ..
+                rd,
+                rs,
+                rt,
+                rn,
+                ty,
+                flags,
             } => {
-                let op = match ty {
-                    I8 => "casalb",
-                    I16 => "casalh",
-                    I32 | I64 => "casal",
+                let ty_suffix = match ty {
+                    I8 => "b",
+                    I16 => "h",
+                    I32 | I64 => "",
                     _ => panic!("Unsupported type: {}", ty),
                 };
+                let op = format!("cas{}{}", atomic_ordering_suffix(flags), ty_suffix);
                 let size = OperandSize::from_ty(ty);
                 let rd = pretty_print_ireg(rd.to_reg(), size, allocs);
                 let rs = pretty_print_ireg(rs, size, allocs);
@@ -1643,14 +1778,28 @@ impl Inst {
                 oldval,
                 scratch,
                 ..
+            }
+            | &Inst::AtomicCASLoopOrLse {
+                ty,
+                addr,
+                expected,
+                replacement,
+                oldval,
+                scratch,
+                ..
             } => {
+                let name = match self {
+                    Inst::AtomicCASLoopOrLse { .. } => "atomic_cas_loop_or_lse",
+                    _ => "atomic_cas_loop",
+                };
                 let addr = pretty_print_ireg(addr, OperandSize::Size64, allocs);
                 let expected = pretty_print_ireg(expected, OperandSize::Size64, allocs);
                 let replacement = pretty_print_ireg(replacement, OperandSize::Size64, allocs);
                 let oldval = pretty_print_ireg(oldval.to_reg(), OperandSize::Size64, allocs);
                 let scratch = pretty_print_ireg(scratch.to_reg(), OperandSize::Size64, allocs);
                 format!(
-                    "atomic_cas_loop_{} addr={}, expect={}, replacement={}, oldval={}, scratch={}",
+                    "{}_{} addr={}, expect={}, replacement={}, oldval={}, scratch={}",
+                    name,
                     ty.bits(),
                     addr,
                     expected,
@@ -1674,6 +1823,21 @@ impl Inst {
                 let rt = pretty_print_ireg(rt.to_reg(), size, allocs);
                 format!("{} {}, [{}]", op, rt, rn)
             }
+            &Inst::LoadAcquirePc {
+                access_ty, rt, rn, ..
+            } => {
+                let (op, ty) = match access_ty {
+                    I8 => ("ldaprb", I32),
+                    I16 => ("ldaprh", I32),
+                    I32 => ("ldapr", I32),
+                    I64 => ("ldapr", I64),
+                    _ => panic!("Unsupported type: {}", access_ty),
+                };
+                let size = OperandSize::from_ty(ty);
+                let rn = pretty_print_ireg(rn, OperandSize::Size64, allocs);
+                let rt = pretty_print_ireg(rt.to_reg(), size, allocs);
+                format!("{} {}, [{}]", op, rt, rn)
+            }
             &Inst::StoreRelease {
                 access_ty, rt, rn, ..
             } => {
@@ -2256,15 +2420,17 @@ impl Inst {
                 alu_op,
                 size,
             } => {
-                let (op, size) = match alu_op {
-                    VecALUModOp::Bsl => ("bsl", VectorSize::Size8x16),
-                    VecALUModOp::Fmla => ("fmla", size),
-                    VecALUModOp::Fmls => ("fmls", size),
+                let (op, size, src_size) = match alu_op {
+                    VecALUModOp::Bsl => ("bsl", VectorSize::Size8x16, VectorSize::Size8x16),
+                    VecALUModOp::Fmla => ("fmla", size, size),
+                    VecALUModOp::Fmls => ("fmls", size, size),
+                    VecALUModOp::Sdot => ("sdot", size, VectorSize::Size8x16),
+                    VecALUModOp::Udot => ("udot", size, VectorSize::Size8x16),
                 };
                 let rd = pretty_print_vreg_vector(rd.to_reg(), size, allocs);
                 let ri = pretty_print_vreg_vector(ri, size, allocs);
-                let rn = pretty_print_vreg_vector(rn, size, allocs);
-                let rm = pretty_print_vreg_vector(rm, size, allocs);
+                let rn = pretty_print_vreg_vector(rn, src_size, allocs);
+                let rm = pretty_print_vreg_vector(rm, src_size, allocs);
                 format!("{} {}, {}, {}, {}", op, rd, ri, rn, rm)
             }
             &Inst::VecFmlaElem {
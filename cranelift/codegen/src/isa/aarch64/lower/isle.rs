@@ -149,6 +149,30 @@ impl Context for IsleContext<'_, '_, MInst, AArch64Backend> {
         }
     }
 
+    fn use_dotprod(&mut self) -> Option<()> {
+        if self.backend.isa_flags.has_dotprod() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn use_rcpc(&mut self) -> Option<()> {
+        if self.backend.isa_flags.has_rcpc() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn use_outline_atomics(&mut self) -> Option<()> {
+        if self.backend.isa_flags.outline_atomics() && !self.backend.isa_flags.has_lse() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
     fn use_lse(&mut self, _: Inst) -> Option<()> {
         if self.backend.isa_flags.has_lse() {
             Some(())
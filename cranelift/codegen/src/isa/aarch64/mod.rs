@@ -15,10 +15,13 @@ use crate::settings as shared_settings;
 use alloc::{boxed::Box, vec::Vec};
 use core::fmt;
 use cranelift_control::ControlPlane;
+use std::time::Instant;
 use target_lexicon::{Aarch64Architecture, Architecture, OperatingSystem, Triple};
 
 // New backend:
 mod abi;
+#[cfg(feature = "disas")]
+mod disas;
 pub mod inst;
 mod lower;
 mod pcc;
@@ -53,12 +56,15 @@ impl AArch64Backend {
         &self,
         func: &Function,
         domtree: &DominatorTree,
+        deadline: Option<Instant>,
         ctrl_plane: &mut ControlPlane,
     ) -> CodegenResult<(VCode<inst::Inst>, regalloc2::Output)> {
         let emit_info = EmitInfo::new(self.flags.clone());
         let sigs = SigSet::new::<abi::AArch64MachineDeps>(func, &self.flags)?;
         let abi = abi::AArch64Callee::new(func, self, &self.isa_flags, &sigs)?;
-        compile::compile::<AArch64Backend>(func, domtree, self, abi, emit_info, sigs, ctrl_plane)
+        compile::compile::<AArch64Backend>(
+            func, domtree, self, abi, emit_info, sigs, deadline, ctrl_plane,
+        )
     }
 }
 
@@ -68,9 +74,10 @@ impl TargetIsa for AArch64Backend {
         func: &Function,
         domtree: &DominatorTree,
         want_disasm: bool,
+        deadline: Option<Instant>,
         ctrl_plane: &mut ControlPlane,
     ) -> CodegenResult<CompiledCodeStencil> {
-        let (vcode, regalloc_result) = self.compile_vcode(func, domtree, ctrl_plane)?;
+        let (vcode, regalloc_result) = self.compile_vcode(func, domtree, deadline, ctrl_plane)?;
 
         let emit_result = vcode.emit(&regalloc_result, want_disasm, &self.flags, ctrl_plane);
         let frame_size = emit_result.frame_size;
@@ -92,6 +99,8 @@ impl TargetIsa for AArch64Backend {
             dynamic_stackslot_offsets,
             bb_starts: emit_result.bb_offsets,
             bb_edges: emit_result.bb_edges,
+            inst_ranges: emit_result.inst_ranges,
+            regalloc_stats: emit_result.regalloc_stats,
         })
     }
 
@@ -195,6 +204,11 @@ impl TargetIsa for AArch64Backend {
         Ok(cs)
     }
 
+    #[cfg(feature = "disas")]
+    fn disas_syntax(&self) -> Option<&'static dyn crate::DisasSyntax> {
+        Some(&disas::AArch64DisasSyntax)
+    }
+
     fn has_native_fma(&self) -> bool {
         true
     }
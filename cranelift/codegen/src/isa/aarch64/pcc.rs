@@ -81,6 +81,12 @@ pub(crate) fn check(
             rn,
             flags,
             ..
+        }
+        | Inst::LoadAcquirePc {
+            access_ty,
+            rn,
+            flags,
+            ..
         } => check_load_addr(ctx, flags, rn, vcode, access_ty),
 
         Inst::Store8 { rd, ref mem, flags } => check_store(ctx, Some(rd), flags, mem, vcode, I8),
@@ -58,6 +58,7 @@ use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use core::fmt;
 use core::fmt::{Debug, Formatter};
 use cranelift_control::ControlPlane;
+use std::time::Instant;
 use target_lexicon::{triple, Architecture, PointerWidth, Triple};
 
 // This module is made public here for benchmarking purposes. No guarantees are
@@ -265,11 +266,15 @@ pub trait TargetIsa: fmt::Display + Send + Sync {
     fn dynamic_vector_bytes(&self, dynamic_ty: ir::Type) -> u32;
 
     /// Compile the given function.
+    ///
+    /// Fails with [`CodegenError::DeadlineExceeded`](crate::CodegenError::DeadlineExceeded)
+    /// if `deadline` passes before compilation finishes.
     fn compile_function(
         &self,
         func: &Function,
         domtree: &DominatorTree,
         want_disasm: bool,
+        deadline: Option<Instant>,
         ctrl_plane: &mut ControlPlane,
     ) -> CodegenResult<CompiledCodeStencil>;
 
@@ -330,6 +335,14 @@ pub trait TargetIsa: fmt::Display + Send + Sync {
         Err(capstone::Error::UnsupportedArch)
     }
 
+    /// How this architecture's printed instructions relate to the `Capstone`
+    /// context's disassembly of them, if emitted code can be checked with
+    /// [`CompiledCode::check_encodings`].
+    #[cfg(feature = "disas")]
+    fn disas_syntax(&self) -> Option<&'static dyn crate::DisasSyntax> {
+        None
+    }
+
     /// Returns whether this ISA has a native fused-multiply-and-add instruction
     /// for floats.
     ///
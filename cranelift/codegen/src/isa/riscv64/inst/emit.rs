@@ -1361,9 +1361,9 @@ impl Inst {
                 // We are going to potentially emit a large amount of instructions, so ensure that we emit an island
                 // now if we need one.
                 //
-                // The worse case PC calculations are 12 instructions. And each entry in the jump table is 2 instructions.
+                // The worse case PC calculations are 15 instructions. And each entry in the jump table is 2 instructions.
                 // Check if we need to emit a jump table here to support that jump.
-                let inst_count = 12 + (targets.len() * 2);
+                let inst_count = 15 + (targets.len() * 2);
                 let distance = (inst_count * Inst::UNCOMPRESSED_INSTRUCTION_SIZE as usize) as u32;
                 if sink.island_needed(distance) {
                     let jump_around_label = sink.get_label();
@@ -1388,6 +1388,9 @@ impl Inst {
                 //     auipc   pc, 0
                 //     jalr    zero, pc, default_block
                 // compute_target:
+                //     sltu    tmp, ext_index, tmp
+                //     neg     tmp, tmp
+                //     and     ext_index, ext_index, tmp
                 //     auipc   pc, 0
                 //     slli    tmp, ext_index, 3
                 //     add     pc, pc, tmp
@@ -1438,6 +1441,32 @@ impl Inst {
                 // We need to emit a PC relative offset,
                 sink.bind_label(label_compute_target, &mut state.ctrl_plane);
 
+                // Clamp the index to zero without branching, so that a
+                // misspeculated bounds check cannot be used to index past the
+                // end of the jump table. `tmp2` still holds the number of
+                // entries here.
+                Inst::AluRRR {
+                    alu_op: AluOPRRR::SltU,
+                    rd: tmp1,
+                    rs1: ext_index.to_reg(),
+                    rs2: tmp2.to_reg(),
+                }
+                .emit(&[], sink, emit_info, state);
+                Inst::AluRRR {
+                    alu_op: AluOPRRR::Sub,
+                    rd: tmp1,
+                    rs1: zero_reg(),
+                    rs2: tmp1.to_reg(),
+                }
+                .emit(&[], sink, emit_info, state);
+                Inst::AluRRR {
+                    alu_op: AluOPRRR::And,
+                    rd: ext_index,
+                    rs1: ext_index.to_reg(),
+                    rs2: tmp1.to_reg(),
+                }
+                .emit(&[], sink, emit_info, state);
+
                 // Get the current PC.
                 Inst::Auipc {
                     rd: tmp1,
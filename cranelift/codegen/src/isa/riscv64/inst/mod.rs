@@ -808,6 +808,54 @@ impl MachInst for Inst {
         }
     }
 
+    fn emits_inline_data(&self) -> bool {
+        match self {
+            &Inst::RawData { .. }
+            | &Inst::LoadInlineConst { .. }
+            | &Inst::LoadExtName { .. }
+            | &Inst::BrTable { .. }
+            // Expands to a branch over an inline trap instruction.
+            | &Inst::TrapIf { .. } => true,
+            _ => false,
+        }
+    }
+
+    fn sched_class(&self) -> SchedClass {
+        // Latencies of a typical in-order RV64GC core.
+        let latency = match self {
+            &Inst::Lui { .. } | &Inst::AluRRImm12 { .. } => 1,
+            &Inst::AluRRR { alu_op, .. } => match alu_op {
+                AluOPRRR::Mul
+                | AluOPRRR::Mulh
+                | AluOPRRR::Mulhsu
+                | AluOPRRR::Mulhu
+                | AluOPRRR::Mulw => 3,
+                AluOPRRR::Div
+                | AluOPRRR::DivU
+                | AluOPRRR::Rem
+                | AluOPRRR::RemU
+                | AluOPRRR::Divw
+                | AluOPRRR::Divuw
+                | AluOPRRR::Remw
+                | AluOPRRR::Remuw => 20,
+                _ => 1,
+            },
+            &Inst::FpuRR { alu_op, .. } => match alu_op {
+                FpuOPRR::FsqrtS | FpuOPRR::FsqrtD => 20,
+                _ => 4,
+            },
+            &Inst::FpuRRR { alu_op, .. } => match alu_op {
+                FpuOPRRR::FdivS | FpuOPRRR::FdivD => 20,
+                _ => 5,
+            },
+            &Inst::FpuRRRR { .. } => 5,
+            &Inst::Load { .. } => return SchedClass::Memory { latency: 3 },
+            &Inst::Store { .. } => return SchedClass::Memory { latency: 1 },
+            _ => return SchedClass::Barrier,
+        };
+        SchedClass::Pure { latency }
+    }
+
     fn get_operands<F: Fn(VReg) -> VReg>(&self, collector: &mut OperandCollector<'_, F>) {
         riscv64_get_operands(self, collector);
     }
@@ -0,0 +1,52 @@
+//! Relating printed s390x instructions to capstone's disassembly, for
+//! checking emitted encodings.
+
+use crate::machinst::DisasSyntax;
+use std::format;
+use std::string::String;
+
+pub(crate) struct S390xDisasSyntax;
+
+/// Printed mnemonics that capstone decodes under a different name: extended
+/// mnemonics, and vector instructions that capstone only knows by their
+/// base mnemonic.
+const ALIASES: &[(&str, &str)] = &[
+    ("bcr", "bnor"),
+    ("ledbra", "ledbr"),
+    ("vcefb", "vcdg"),
+    ("vcelfb", "vcdlg"),
+    ("vcfeb", "vcgd"),
+    ("vclfeb", "vclgd"),
+    ("vgbm", "vzero"),
+    ("vuplh", "vuplhw"),
+    ("wcefb", "vcdg"),
+    ("wcelfb", "vcdlg"),
+    ("wcfeb", "vcgd"),
+    ("wclfeb", "vclgd"),
+];
+
+impl DisasSyntax for S390xDisasSyntax {
+    fn same_mnemonic(&self, printed: &str, decoded: &str) -> bool {
+        printed == decoded || ALIASES.contains(&(printed, decoded))
+    }
+
+    fn canonical_reg(&self, name: &str) -> Option<String> {
+        let name = name.strip_prefix('%')?;
+        let (kind, n) = name.split_at(name.len().min(1));
+        let n = n.parse::<u8>().ok()?;
+        match kind {
+            "r" => Some(format!("r{n}")),
+            // The floating-point registers overlay the vector registers.
+            "f" | "v" => Some(format!("v{n}")),
+            _ => None,
+        }
+    }
+
+    fn implicit_regs(&self, printed: &str) -> &'static [&'static str] {
+        match printed {
+            // The register operand of `bcr` is printed as a plain number.
+            "bcr" => &["r0"],
+            _ => &[],
+        }
+    }
+}
@@ -1066,6 +1066,31 @@ fn enc_vri_c(opcode: u16, v1: Reg, i2: u16, v3: Reg, m4: u8) -> [u8; 6] {
     enc
 }
 
+/// VRId-type instructions.
+///
+///   47      39 35 31 27 23 15 11  7
+///   opcode1 v1 v2 v3 -  i4 m5 rxb opcode2
+///        40 36 32 28 24 16 12   8       0
+///
+fn enc_vri_d(opcode: u16, v1: Reg, v2: Reg, v3: Reg, i4: u8, m5: u8) -> [u8; 6] {
+    let opcode1 = ((opcode >> 8) & 0xff) as u8;
+    let opcode2 = (opcode & 0xff) as u8;
+    let rxb = rxb(Some(v1), Some(v2), Some(v3), None);
+    let v1 = machreg_to_vr(v1) & 0x0f;
+    let v2 = machreg_to_vr(v2) & 0x0f;
+    let v3 = machreg_to_vr(v3) & 0x0f;
+    let m5 = m5 & 0x0f;
+
+    let mut enc: [u8; 6] = [0; 6];
+    enc[0] = opcode1;
+    enc[1] = v1 << 4 | v2;
+    enc[2] = v3 << 4;
+    enc[3] = i4;
+    enc[4] = m5 << 4 | rxb;
+    enc[5] = opcode2;
+    enc
+}
+
 /// VRRa-type instructions.
 ///
 ///   47      39 35 31 23 19 15 11  7
@@ -2418,6 +2443,18 @@ impl Inst {
                 let opcode = 0xb9e2; // LOCGR
                 put(sink, &enc_rrf_cde(opcode, rd.to_reg(), rm, cond.bits(), 0));
             }
+            &Inst::Select32 { rd, cond, rn, rm } | &Inst::Select64 { rd, cond, rn, rm } => {
+                let rd = allocs.next_writable(rd);
+                let rn = allocs.next(rn);
+                let rm = allocs.next(rm);
+
+                let opcode = match self {
+                    &Inst::Select32 { .. } => 0xb9f0, // SELR
+                    &Inst::Select64 { .. } => 0xb9e3, // SELGR
+                    _ => unreachable!(),
+                };
+                put(sink, &enc_rrf_ab(opcode, rd.to_reg(), rn, rm, cond.bits()));
+            }
             &Inst::CMov32SImm16 { rd, cond, ri, imm } => {
                 let rd = allocs.next_writable(rd);
                 let ri = allocs.next(ri);
@@ -2994,6 +3031,29 @@ impl Inst {
                 let opcode = 0xe784; // VPDI
                 put(sink, &enc_vrr_c(opcode, rd.to_reg(), rn, rm, m4, 0, 0));
             }
+            &Inst::VecShiftLDoubleByByte {
+                rd,
+                rn,
+                rm,
+                shift_imm,
+            }
+            | &Inst::VecShiftLDoubleByBit {
+                rd,
+                rn,
+                rm,
+                shift_imm,
+            } => {
+                let rd = allocs.next_writable(rd);
+                let rn = allocs.next(rn);
+                let rm = allocs.next(rm);
+
+                let opcode = match self {
+                    &Inst::VecShiftLDoubleByByte { .. } => 0xe777, // VSLDB
+                    &Inst::VecShiftLDoubleByBit { .. } => 0xe786,  // VSLD
+                    _ => unreachable!(),
+                };
+                put(sink, &enc_vri_d(opcode, rd.to_reg(), rn, rm, shift_imm, 0));
+            }
             &Inst::VecIntCmp { op, rd, rn, rm } | &Inst::VecIntCmpS { op, rd, rn, rm } => {
                 let rd = allocs.next_writable(rd);
                 let rn = allocs.next(rn);
@@ -6573,6 +6573,26 @@ fn test_s390x_binemit() {
         "B9E21089",
         "locgro %r8, %r9",
     ));
+    insns.push((
+        Inst::Select32 {
+            rd: writable_gpr(8),
+            cond: Cond::from_mask(1),
+            rn: gpr(9),
+            rm: gpr(10),
+        },
+        "B9F0A189",
+        "selro %r8, %r9, %r10",
+    ));
+    insns.push((
+        Inst::Select64 {
+            rd: writable_gpr(8),
+            cond: Cond::from_mask(1),
+            rn: gpr(9),
+            rm: gpr(10),
+        },
+        "B9E3A189",
+        "selgro %r8, %r9, %r10",
+    ));
 
     insns.push((
         Inst::CMov32SImm16 {
@@ -9796,6 +9816,46 @@ fn test_s390x_binemit() {
         "E74680005884",
         "vpdi %v20, %v6, %v8, 5",
     ));
+    insns.push((
+        Inst::VecShiftLDoubleByByte {
+            rd: writable_vr(20),
+            rn: vr(6),
+            rm: vr(8),
+            shift_imm: 3,
+        },
+        "E74680030877",
+        "vsldb %v20, %v6, %v8, 3",
+    ));
+    insns.push((
+        Inst::VecShiftLDoubleByByte {
+            rd: writable_vr(20),
+            rn: vr(22),
+            rm: vr(24),
+            shift_imm: 15,
+        },
+        "E746800F0E77",
+        "vsldb %v20, %v22, %v24, 15",
+    ));
+    insns.push((
+        Inst::VecShiftLDoubleByBit {
+            rd: writable_vr(20),
+            rn: vr(6),
+            rm: vr(8),
+            shift_imm: 5,
+        },
+        "E74680050886",
+        "vsld %v20, %v6, %v8, 5",
+    ));
+    insns.push((
+        Inst::VecShiftLDoubleByBit {
+            rd: writable_vr(20),
+            rn: vr(22),
+            rm: vr(24),
+            shift_imm: 7,
+        },
+        "E74680070E86",
+        "vsld %v20, %v22, %v24, 7",
+    ));
 
     insns.push((
         Inst::VecIntCmp {
@@ -204,6 +204,7 @@ impl Inst {
             | Inst::VecSelect { .. }
             | Inst::VecPermute { .. }
             | Inst::VecPermuteDWImm { .. }
+            | Inst::VecShiftLDoubleByByte { .. }
             | Inst::VecIntCmp { .. }
             | Inst::VecIntCmpS { .. }
             | Inst::VecFloatCmp { .. }
@@ -262,6 +263,7 @@ impl Inst {
                 UnaryOp::PopcntReg => InstructionSet::MIE2,
                 _ => InstructionSet::Base,
             },
+            Inst::Select32 { .. } | Inst::Select64 { .. } => InstructionSet::MIE2,
             Inst::FpuRound { op, .. } => match op {
                 FpuRoundOp::ToSInt32 | FpuRoundOp::FromSInt32 => InstructionSet::VXRS_EXT2,
                 FpuRoundOp::ToUInt32 | FpuRoundOp::FromUInt32 => InstructionSet::VXRS_EXT2,
@@ -288,7 +290,8 @@ impl Inst {
             | Inst::VecLoadReplicateRev { .. }
             | Inst::VecLoadLaneRev { .. }
             | Inst::VecLoadLaneRevUndef { .. }
-            | Inst::VecStoreLaneRev { .. } => InstructionSet::VXRS_EXT2,
+            | Inst::VecStoreLaneRev { .. }
+            | Inst::VecShiftLDoubleByBit { .. } => InstructionSet::VXRS_EXT2,
 
             Inst::DummyUse { .. } => InstructionSet::Base,
         }
@@ -647,6 +650,11 @@ fn s390x_get_operands<F: Fn(VReg) -> VReg>(inst: &Inst, collector: &mut OperandC
             collector.reg_reuse_def(rd, 1);
             collector.reg_use(ri);
         }
+        &Inst::Select32 { rd, rn, rm, .. } | &Inst::Select64 { rd, rn, rm, .. } => {
+            collector.reg_def(rd);
+            collector.reg_use(rn);
+            collector.reg_use(rm);
+        }
         &Inst::Insert64UImm16Shifted { rd, ri, .. }
         | &Inst::Insert64UImm32Shifted { rd, ri, .. } => {
             collector.reg_reuse_def(rd, 1);
@@ -725,7 +733,9 @@ fn s390x_get_operands<F: Fn(VReg) -> VReg>(inst: &Inst, collector: &mut OperandC
             collector.reg_use(rm);
             collector.reg_use(ra);
         }
-        &Inst::VecPermuteDWImm { rd, rn, rm, .. } => {
+        &Inst::VecPermuteDWImm { rd, rn, rm, .. }
+        | &Inst::VecShiftLDoubleByByte { rd, rn, rm, .. }
+        | &Inst::VecShiftLDoubleByBit { rd, rn, rm, .. } => {
             collector.reg_def(rd);
             collector.reg_use(rn);
             collector.reg_use(rm);
@@ -1081,6 +1091,18 @@ impl MachInst for Inst {
         }
     }
 
+    fn emits_inline_data(&self) -> bool {
+        match self {
+            &Inst::LoadSymbolReloc { .. }
+            | &Inst::LoadFpuConst32 { .. }
+            | &Inst::LoadFpuConst64 { .. }
+            | &Inst::VecLoadConst { .. }
+            | &Inst::VecLoadConstReplicate { .. }
+            | &Inst::JTSequence { .. } => true,
+            _ => false,
+        }
+    }
+
     fn gen_move(to_reg: Writable<Reg>, from_reg: Reg, ty: Type) -> Inst {
         assert!(ty.bits() <= 128);
         if ty.bits() <= 32 {
@@ -2071,6 +2093,18 @@ impl Inst {
                 let cond = cond.pretty_print_default();
                 format!("locgr{} {}, {}", cond, rd, rm)
             }
+            &Inst::Select32 { rd, cond, rn, rm } | &Inst::Select64 { rd, cond, rn, rm } => {
+                let op = match self {
+                    &Inst::Select32 { .. } => "selr",
+                    &Inst::Select64 { .. } => "selgr",
+                    _ => unreachable!(),
+                };
+                let rd = pretty_print_reg(rd.to_reg(), allocs);
+                let rn = pretty_print_reg(rn, allocs);
+                let rm = pretty_print_reg(rm, allocs);
+                let cond = cond.pretty_print_default();
+                format!("{}{} {}, {}, {}", op, cond, rd, rn, rm)
+            }
             &Inst::CMov32SImm16 {
                 rd,
                 cond,
@@ -2594,6 +2628,28 @@ impl Inst {
                 let m4 = (idx1 & 1) * 4 + (idx2 & 1);
                 format!("vpdi {}, {}, {}, {}", rd, rn, rm, m4)
             }
+            &Inst::VecShiftLDoubleByByte {
+                rd,
+                rn,
+                rm,
+                shift_imm,
+            }
+            | &Inst::VecShiftLDoubleByBit {
+                rd,
+                rn,
+                rm,
+                shift_imm,
+            } => {
+                let op = match self {
+                    &Inst::VecShiftLDoubleByByte { .. } => "vsldb",
+                    &Inst::VecShiftLDoubleByBit { .. } => "vsld",
+                    _ => unreachable!(),
+                };
+                let rd = pretty_print_reg(rd.to_reg(), allocs);
+                let rn = pretty_print_reg(rn, allocs);
+                let rm = pretty_print_reg(rm, allocs);
+                format!("{} {}, {}, {}, {}", op, rd, rn, rm, shift_imm)
+            }
             &Inst::VecIntCmp { op, rd, rn, rm } | &Inst::VecIntCmpS { op, rd, rn, rm } => {
                 let op = match op {
                     VecIntCmpOp::CmpEq8x16 => "vceqb",
@@ -15,10 +15,13 @@ use crate::settings as shared_settings;
 use alloc::{boxed::Box, vec::Vec};
 use core::fmt;
 use cranelift_control::ControlPlane;
+use std::time::Instant;
 use target_lexicon::{Architecture, Triple};
 
 // New backend:
 mod abi;
+#[cfg(feature = "disas")]
+mod disas;
 pub(crate) mod inst;
 mod lower;
 mod settings;
@@ -52,12 +55,15 @@ impl S390xBackend {
         &self,
         func: &Function,
         domtree: &DominatorTree,
+        deadline: Option<Instant>,
         ctrl_plane: &mut ControlPlane,
     ) -> CodegenResult<(VCode<inst::Inst>, regalloc2::Output)> {
         let emit_info = EmitInfo::new(self.isa_flags.clone());
         let sigs = SigSet::new::<abi::S390xMachineDeps>(func, &self.flags)?;
         let abi = abi::S390xCallee::new(func, self, &self.isa_flags, &sigs)?;
-        compile::compile::<S390xBackend>(func, domtree, self, abi, emit_info, sigs, ctrl_plane)
+        compile::compile::<S390xBackend>(
+            func, domtree, self, abi, emit_info, sigs, deadline, ctrl_plane,
+        )
     }
 }
 
@@ -67,10 +73,11 @@ impl TargetIsa for S390xBackend {
         func: &Function,
         domtree: &DominatorTree,
         want_disasm: bool,
+        deadline: Option<Instant>,
         ctrl_plane: &mut ControlPlane,
     ) -> CodegenResult<CompiledCodeStencil> {
         let flags = self.flags();
-        let (vcode, regalloc_result) = self.compile_vcode(func, domtree, ctrl_plane)?;
+        let (vcode, regalloc_result) = self.compile_vcode(func, domtree, deadline, ctrl_plane)?;
 
         let emit_result = vcode.emit(&regalloc_result, want_disasm, flags, ctrl_plane);
         let frame_size = emit_result.frame_size;
@@ -92,6 +99,8 @@ impl TargetIsa for S390xBackend {
             dynamic_stackslot_offsets,
             bb_starts: emit_result.bb_offsets,
             bb_edges: emit_result.bb_edges,
+            inst_ranges: emit_result.inst_ranges,
+            regalloc_stats: emit_result.regalloc_stats,
         })
     }
 
@@ -169,6 +178,15 @@ impl TargetIsa for S390xBackend {
         Ok(cs)
     }
 
+    #[cfg(feature = "disas")]
+    fn disas_syntax(&self) -> Option<&'static dyn crate::DisasSyntax> {
+        // Capstone doesn't know the z15 (arch13) instructions.
+        if self.isa_flags.has_mie2() || self.isa_flags.has_vxrs_ext2() {
+            return None;
+        }
+        Some(&disas::S390xDisasSyntax)
+    }
+
     fn has_native_fma(&self) -> bool {
         true
     }
@@ -0,0 +1,120 @@
+//! Relating printed x64 instructions to capstone's AT&T disassembly, for
+//! checking emitted encodings.
+
+use crate::machinst::DisasSyntax;
+use std::format;
+use std::string::{String, ToString};
+
+pub(crate) struct X64DisasSyntax;
+
+/// Printed mnemonics that capstone decodes under a different name: the
+/// sign-extension instructions in AT&T spelling, and pseudo-instructions
+/// that emit a single instruction.
+const ALIASES: &[(&str, &str)] = &[
+    ("cbw", "cbtw"),
+    ("cwd", "cwtd"),
+    ("cdq", "cltd"),
+    ("cqo", "cqto"),
+    ("lea", "addl"),
+    ("lea", "addq"),
+    ("load_ext_name", "movabsq"),
+    ("load_ext_name", "leaq"),
+    ("movabsq", "movq"),
+    ("pinsrd.w", "pinsrq"),
+    ("vpsraqimm", "vpsraq"),
+];
+
+/// Condition codes that capstone spells differently.
+const CONDITION_CODES: &[(&str, &str)] = &[
+    ("z", "e"),
+    ("nz", "ne"),
+    ("nb", "ae"),
+    ("nbe", "a"),
+    ("nl", "ge"),
+    ("nle", "g"),
+];
+
+/// Rename the condition code in a `cmov`, `set` or `j` mnemonic to the
+/// spelling capstone uses.
+fn rename_condition_code(mnemonic: &str) -> String {
+    for prefix in ["cmov", "set", "j"] {
+        let Some(rest) = mnemonic.strip_prefix(prefix) else {
+            continue;
+        };
+        // `cmov` carries an operand size suffix after the condition code.
+        let (cc, suffix) = match prefix {
+            "cmov" if !rest.is_empty() => rest.split_at(rest.len() - 1),
+            _ => (rest, ""),
+        };
+        if let Some((_, renamed)) = CONDITION_CODES.iter().find(|(cc2, _)| *cc2 == cc) {
+            return format!("{prefix}{renamed}{suffix}");
+        }
+    }
+    mnemonic.to_string()
+}
+
+impl DisasSyntax for X64DisasSyntax {
+    fn same_mnemonic(&self, printed: &str, decoded: &str) -> bool {
+        let printed = rename_condition_code(printed);
+        let printed = printed.as_str();
+        if printed == decoded || ALIASES.contains(&(printed, decoded)) {
+            return true;
+        }
+        // AT&T syntax adds an operand size suffix that isn't always printed.
+        if let Some(base) = decoded.strip_suffix(['b', 'w', 'l', 'q']) {
+            if base == printed {
+                return true;
+            }
+        }
+        // Capstone folds a `cmp` instruction's predicate into its mnemonic.
+        ["cmpps", "cmppd", "cmpss", "cmpsd", "vcmpps", "vcmppd"].contains(&printed)
+            && decoded.starts_with(&printed[..printed.len() - 2])
+            && decoded.ends_with(&printed[printed.len() - 2..])
+    }
+
+    fn canonical_reg(&self, name: &str) -> Option<String> {
+        // Stack slots are printed relative to a bare `rsp` or `rbp`.
+        let name = match name.strip_prefix('%') {
+            Some(name) => name,
+            None if name == "rsp" || name == "rbp" => name,
+            None => return None,
+        };
+        // Operands relative to `%rip` are printed as labels or constants.
+        if name == "rip" {
+            return None;
+        }
+        for prefix in ["xmm", "ymm", "zmm"] {
+            if let Some(n) = name.strip_prefix(prefix) {
+                return n.parse::<u8>().ok().map(|n| format!("xmm{n}"));
+            }
+        }
+        if let Some(n) = name.strip_prefix('r') {
+            let n = n.trim_end_matches(['d', 'w', 'b']);
+            if let Ok(n) = n.parse::<u8>() {
+                return Some(format!("r{n}"));
+            }
+        }
+        const LEGACY: &[(&str, &[&str])] = &[
+            ("rax", &["eax", "ax", "al", "ah"]),
+            ("rbx", &["ebx", "bx", "bl", "bh"]),
+            ("rcx", &["ecx", "cx", "cl", "ch"]),
+            ("rdx", &["edx", "dx", "dl", "dh"]),
+            ("rsi", &["esi", "si", "sil"]),
+            ("rdi", &["edi", "di", "dil"]),
+            ("rbp", &["ebp", "bp", "bpl"]),
+            ("rsp", &["esp", "sp", "spl"]),
+        ];
+        LEGACY
+            .iter()
+            .find(|(reg, subregs)| *reg == name || subregs.contains(&name))
+            .map(|(reg, _)| reg.to_string())
+    }
+
+    fn implicit_regs(&self, printed: &str) -> &'static [&'static str] {
+        match printed {
+            // The non-VEX blends take their mask in `%xmm0`.
+            "pblendvb" | "blendvps" | "blendvpd" => &["xmm0"],
+            _ => &[],
+        }
+    }
+}
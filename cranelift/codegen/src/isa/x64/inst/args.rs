@@ -971,6 +971,7 @@ pub(crate) enum InstructionSet {
     AVX512F,
     AVX512VBMI,
     AVX512VL,
+    AVX512VNNI,
 }
 
 /// Some SSE operations requiring 2 operands r/m and r.
@@ -1851,6 +1852,9 @@ impl Avx512Opcode {
                 smallvec![InstructionSet::AVX512VL, InstructionSet::AVX512VBMI]
             }
             Avx512Opcode::Vpmullq => smallvec![InstructionSet::AVX512VL, InstructionSet::AVX512DQ],
+            Avx512Opcode::Vpdpbusd => {
+                smallvec![InstructionSet::AVX512VL, InstructionSet::AVX512VNNI]
+            }
             Avx512Opcode::Vpopcntb => {
                 smallvec![InstructionSet::AVX512VL, InstructionSet::AVX512BITALG]
             }
@@ -1868,7 +1872,7 @@ impl Avx512Opcode {
         use Avx512TupleType::*;
 
         match self {
-            Vcvtudq2ps | Vpabsq | Vpmullq | VpsraqImm => Full,
+            Vcvtudq2ps | Vpabsq | Vpdpbusd | Vpmullq | VpsraqImm => Full,
             Vpermi2b | Vpopcntb => FullMem,
             Vpsraq => Mem128,
         }
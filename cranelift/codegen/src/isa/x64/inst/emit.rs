@@ -135,6 +135,7 @@ pub(crate) fn emit(
             InstructionSet::AVX512F => info.isa_flags.has_avx512f(),
             InstructionSet::AVX512VBMI => info.isa_flags.has_avx512vbmi(),
             InstructionSet::AVX512VL => info.isa_flags.has_avx512vl(),
+            InstructionSet::AVX512VNNI => info.isa_flags.has_avx512vnni(),
         }
     };
 
@@ -2926,6 +2927,7 @@ pub(crate) fn emit(
 
             let (w, opcode, map) = match op {
                 Avx512Opcode::Vpermi2b => (false, 0x75, OpcodeMap::_0F38),
+                Avx512Opcode::Vpdpbusd => (false, 0x50, OpcodeMap::_0F38),
                 Avx512Opcode::Vpmullq => (true, 0x40, OpcodeMap::_0F38),
                 Avx512Opcode::Vpsraq => (true, 0xE2, OpcodeMap::_0F),
                 _ => unimplemented!("Opcode {:?} not implemented", op),
@@ -3911,6 +3913,55 @@ pub(crate) fn emit(
             emit_std_reg_mem(sink, prefix, opcodes, 2, replacement, &amode, rex, 0);
         }
 
+        Inst::LockXadd {
+            ty,
+            operand,
+            mem,
+            dst_old,
+        } => {
+            let operand = allocs.next(*operand);
+            let dst_old = allocs.next(dst_old.to_reg());
+            let mem = mem.with_allocs(allocs);
+            debug_assert_eq!(operand, dst_old);
+
+            // lock xadd{b,w,l,q} %operand, (mem)
+            let (prefix, opcodes) = match *ty {
+                types::I8 => (LegacyPrefixes::_F0, 0x0FC0),
+                types::I16 => (LegacyPrefixes::_66F0, 0x0FC1),
+                types::I32 => (LegacyPrefixes::_F0, 0x0FC1),
+                types::I64 => (LegacyPrefixes::_F0, 0x0FC1),
+                _ => unreachable!(),
+            };
+            let rex = RexFlags::from((OperandSize::from_ty(*ty), operand));
+            let amode = mem.finalize(state, sink);
+            emit_std_reg_mem(sink, prefix, opcodes, 2, operand, &amode, rex, 0);
+        }
+
+        Inst::Xchg {
+            ty,
+            operand,
+            mem,
+            dst_old,
+        } => {
+            let operand = allocs.next(*operand);
+            let dst_old = allocs.next(dst_old.to_reg());
+            let mem = mem.with_allocs(allocs);
+            debug_assert_eq!(operand, dst_old);
+
+            // xchg{b,w,l,q} %operand, (mem)
+            // The lock prefix is implied by the memory operand.
+            let (prefix, opcodes) = match *ty {
+                types::I8 => (LegacyPrefixes::None, 0x86),
+                types::I16 => (LegacyPrefixes::_66, 0x87),
+                types::I32 => (LegacyPrefixes::None, 0x87),
+                types::I64 => (LegacyPrefixes::None, 0x87),
+                _ => unreachable!(),
+            };
+            let rex = RexFlags::from((OperandSize::from_ty(*ty), operand));
+            let amode = mem.finalize(state, sink);
+            emit_std_reg_mem(sink, prefix, opcodes, 1, operand, &amode, rex, 0);
+        }
+
         Inst::AtomicRmwSeq {
             ty,
             op,
@@ -4598,6 +4598,18 @@ fn test_x64_emit() {
         "vpermi2b %xmm0, %xmm1, %xmm2",
     ));
 
+    insns.push((
+        Inst::xmm_rm_r_evex(Avx512Opcode::Vpdpbusd, xmm10, RegMem::reg(xmm14), w_xmm1),
+        "62D22D0850CE",
+        "vpdpbusd %xmm10, %xmm14, %xmm1",
+    ));
+
+    insns.push((
+        Inst::xmm_rm_r_evex(Avx512Opcode::Vpdpbusd, xmm0, RegMem::reg(xmm1), w_xmm2),
+        "62F27D0850D1",
+        "vpdpbusd %xmm0, %xmm1, %xmm2",
+    ));
+
     insns.push((
         Inst::xmm_rm_r(SseOpcode::Pmuludq, RegMem::reg(xmm8), w_xmm9),
         "66450FF4C8",
@@ -5363,6 +5375,90 @@ fn test_x64_emit() {
         "lock cmpxchgq %r10, -12345(%rcx,%rsi,8), expected=%rax, dst_old=%rax",
     ));
 
+    // LockXadd
+    insns.push((
+        Inst::LockXadd {
+            ty: types::I8,
+            mem: am3.clone(),
+            operand: r10,
+            dst_old: _w_r10,
+        },
+        "F0450FC011",
+        "lock xaddb %r10b, 0(%r9), dst_old=%r10b",
+    ));
+    insns.push((
+        Inst::LockXadd {
+            ty: types::I16,
+            mem: am3.clone(),
+            operand: r10,
+            dst_old: _w_r10,
+        },
+        "66F0450FC111",
+        "lock xaddw %r10w, 0(%r9), dst_old=%r10w",
+    ));
+    insns.push((
+        Inst::LockXadd {
+            ty: types::I32,
+            mem: am3.clone(),
+            operand: r10,
+            dst_old: _w_r10,
+        },
+        "F0450FC111",
+        "lock xaddl %r10d, 0(%r9), dst_old=%r10d",
+    ));
+    insns.push((
+        Inst::LockXadd {
+            ty: types::I64,
+            mem: am3.clone(),
+            operand: r10,
+            dst_old: _w_r10,
+        },
+        "F04D0FC111",
+        "lock xaddq %r10, 0(%r9), dst_old=%r10",
+    ));
+
+    // Xchg
+    insns.push((
+        Inst::Xchg {
+            ty: types::I8,
+            mem: am3.clone(),
+            operand: r10,
+            dst_old: _w_r10,
+        },
+        "458611",
+        "xchgb %r10b, 0(%r9), dst_old=%r10b",
+    ));
+    insns.push((
+        Inst::Xchg {
+            ty: types::I16,
+            mem: am3.clone(),
+            operand: r10,
+            dst_old: _w_r10,
+        },
+        "66458711",
+        "xchgw %r10w, 0(%r9), dst_old=%r10w",
+    ));
+    insns.push((
+        Inst::Xchg {
+            ty: types::I32,
+            mem: am3.clone(),
+            operand: r10,
+            dst_old: _w_r10,
+        },
+        "458711",
+        "xchgl %r10d, 0(%r9), dst_old=%r10d",
+    ));
+    insns.push((
+        Inst::Xchg {
+            ty: types::I64,
+            mem: am3.clone(),
+            operand: r10,
+            dst_old: _w_r10,
+        },
+        "4D8711",
+        "xchgq %r10, 0(%r9), dst_old=%r10",
+    ));
+
     // AtomicRmwSeq
     insns.push((
         Inst::AtomicRmwSeq {
@@ -5503,6 +5599,7 @@ fn test_x64_emit() {
     isa_flag_builder.enable("has_avx512f").unwrap();
     isa_flag_builder.enable("has_avx512vbmi").unwrap();
     isa_flag_builder.enable("has_avx512vl").unwrap();
+    isa_flag_builder.enable("has_avx512vnni").unwrap();
     let isa_flags = x64::settings::Flags::new(&flags, &isa_flag_builder);
 
     let emit_info = EmitInfo::new(flags, isa_flags);
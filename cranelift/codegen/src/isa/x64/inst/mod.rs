@@ -122,6 +122,7 @@ impl Inst {
             | Inst::LoadEffectiveAddress { .. }
             | Inst::LoadExtName { .. }
             | Inst::LockCmpxchg { .. }
+            | Inst::LockXadd { .. }
             | Inst::Mov64MR { .. }
             | Inst::MovImmM { .. }
             | Inst::MovRM { .. }
@@ -149,6 +150,7 @@ impl Inst {
             | Inst::TrapIfOr { .. }
             | Inst::Ud2 { .. }
             | Inst::VirtualSPOffsetAdj { .. }
+            | Inst::Xchg { .. }
             | Inst::XmmCmove { .. }
             | Inst::XmmCmpRmR { .. }
             | Inst::XmmMinMaxSeq { .. }
@@ -1821,6 +1823,34 @@ impl PrettyPrint for Inst {
                 )
             }
 
+            Inst::LockXadd {
+                ty,
+                operand,
+                mem,
+                dst_old,
+            } => {
+                let size = ty.bytes() as u8;
+                let operand = pretty_print_reg(*operand, size, allocs);
+                let dst_old = pretty_print_reg(dst_old.to_reg(), size, allocs);
+                let mem = mem.pretty_print(size, allocs);
+                let suffix = suffix_bwlq(OperandSize::from_bytes(size as u32));
+                format!("lock xadd{suffix} {operand}, {mem}, dst_old={dst_old}")
+            }
+
+            Inst::Xchg {
+                ty,
+                operand,
+                mem,
+                dst_old,
+            } => {
+                let size = ty.bytes() as u8;
+                let operand = pretty_print_reg(*operand, size, allocs);
+                let dst_old = pretty_print_reg(dst_old.to_reg(), size, allocs);
+                let mem = mem.pretty_print(size, allocs);
+                let suffix = suffix_bwlq(OperandSize::from_bytes(size as u32));
+                format!("xchg{suffix} {operand}, {mem}, dst_old={dst_old}")
+            }
+
             Inst::AtomicRmwSeq { ty, op, .. } => {
                 let ty = ty.bits();
                 format!(
@@ -2116,7 +2146,10 @@ fn x64_get_operands<F: Fn(VReg) -> VReg>(inst: &Inst, collector: &mut OperandCol
             dst,
             ..
         } => {
-            assert_ne!(*op, Avx512Opcode::Vpermi2b);
+            assert!(!matches!(
+                op,
+                Avx512Opcode::Vpermi2b | Avx512Opcode::Vpdpbusd
+            ));
             collector.reg_use(src1.to_reg());
             src2.get_operands(collector);
             collector.reg_def(dst.to_writable_reg());
@@ -2129,7 +2162,10 @@ fn x64_get_operands<F: Fn(VReg) -> VReg>(inst: &Inst, collector: &mut OperandCol
             dst,
             ..
         } => {
-            assert_eq!(*op, Avx512Opcode::Vpermi2b);
+            assert!(matches!(
+                op,
+                Avx512Opcode::Vpermi2b | Avx512Opcode::Vpdpbusd
+            ));
             collector.reg_use(src1.to_reg());
             collector.reg_use(src2.to_reg());
             src3.get_operands(collector);
@@ -2429,6 +2465,23 @@ fn x64_get_operands<F: Fn(VReg) -> VReg>(inst: &Inst, collector: &mut OperandCol
             mem.get_operands(collector);
         }
 
+        Inst::LockXadd {
+            operand,
+            mem,
+            dst_old,
+            ..
+        }
+        | Inst::Xchg {
+            operand,
+            mem,
+            dst_old,
+            ..
+        } => {
+            collector.reg_use(*operand);
+            collector.reg_reuse_def(*dst_old, 0);
+            mem.get_operands(collector);
+        }
+
         Inst::AtomicRmwSeq {
             operand,
             temp,
@@ -2693,6 +2746,13 @@ impl MachInst for Inst {
         }
     }
 
+    fn emits_inline_data(&self) -> bool {
+        match self {
+            Inst::JmpTableSeq { .. } => true,
+            _ => false,
+        }
+    }
+
     fn function_alignment() -> FunctionAlignment {
         FunctionAlignment {
             minimum: 1,
@@ -257,6 +257,11 @@ impl Context for IsleContext<'_, '_, MInst, X64Backend> {
         self.backend.x64_flags.use_avx512vbmi()
     }
 
+    #[inline]
+    fn use_avx512vnni(&mut self) -> bool {
+        self.backend.x64_flags.use_avx512vnni()
+    }
+
     #[inline]
     fn use_lzcnt(&mut self) -> bool {
         self.backend.x64_flags.use_lzcnt()
@@ -15,12 +15,17 @@ use crate::machinst::{
 };
 use crate::result::CodegenResult;
 use crate::settings::{self as shared_settings, Flags};
+#[cfg(feature = "unwind")]
+use crate::{Final, MachBufferFinalized};
 use alloc::{boxed::Box, vec::Vec};
 use core::fmt;
 use cranelift_control::ControlPlane;
+use std::time::Instant;
 use target_lexicon::Triple;
 
 mod abi;
+#[cfg(feature = "disas")]
+mod disas;
 pub mod encoding;
 mod inst;
 mod lower;
@@ -48,6 +53,7 @@ impl X64Backend {
         &self,
         func: &Function,
         domtree: &DominatorTree,
+        deadline: Option<Instant>,
         ctrl_plane: &mut ControlPlane,
     ) -> CodegenResult<(VCode<inst::Inst>, regalloc2::Output)> {
         // This performs lowering to VCode, register-allocates the code, computes
@@ -55,7 +61,9 @@ impl X64Backend {
         let emit_info = EmitInfo::new(self.flags.clone(), self.x64_flags.clone());
         let sigs = SigSet::new::<abi::X64ABIMachineSpec>(func, &self.flags)?;
         let abi = abi::X64Callee::new(func, self, &self.x64_flags, &sigs)?;
-        compile::compile::<Self>(func, domtree, self, abi, emit_info, sigs, ctrl_plane)
+        compile::compile::<Self>(
+            func, domtree, self, abi, emit_info, sigs, deadline, ctrl_plane,
+        )
     }
 }
 
@@ -65,9 +73,10 @@ impl TargetIsa for X64Backend {
         func: &Function,
         domtree: &DominatorTree,
         want_disasm: bool,
+        deadline: Option<Instant>,
         ctrl_plane: &mut ControlPlane,
     ) -> CodegenResult<CompiledCodeStencil> {
-        let (vcode, regalloc_result) = self.compile_vcode(func, domtree, ctrl_plane)?;
+        let (vcode, regalloc_result) = self.compile_vcode(func, domtree, deadline, ctrl_plane)?;
 
         let emit_result = vcode.emit(&regalloc_result, want_disasm, &self.flags, ctrl_plane);
         let frame_size = emit_result.frame_size;
@@ -89,6 +98,8 @@ impl TargetIsa for X64Backend {
             dynamic_stackslot_offsets,
             bb_starts: emit_result.bb_offsets,
             bb_edges: emit_result.bb_edges,
+            inst_ranges: emit_result.inst_ranges,
+            regalloc_stats: emit_result.regalloc_stats,
         })
     }
 
@@ -118,31 +129,12 @@ impl TargetIsa for X64Backend {
         result: &CompiledCode,
         kind: crate::isa::unwind::UnwindInfoKind,
     ) -> CodegenResult<Option<crate::isa::unwind::UnwindInfo>> {
-        use crate::isa::unwind::UnwindInfo;
-        use crate::isa::unwind::UnwindInfoKind;
-        Ok(match kind {
-            UnwindInfoKind::SystemV => {
-                let mapper = self::inst::unwind::systemv::RegisterMapper;
-                Some(UnwindInfo::SystemV(
-                    crate::isa::unwind::systemv::create_unwind_info_from_insts(
-                        &result.buffer.unwind_info[..],
-                        result.buffer.data().len(),
-                        &mapper,
-                    )?,
-                ))
-            }
-            UnwindInfoKind::Windows => Some(UnwindInfo::WindowsX64(
-                crate::isa::unwind::winx64::create_unwind_info_from_insts::<
-                    self::inst::unwind::winx64::RegisterMapper,
-                >(&result.buffer.unwind_info[..])?,
-            )),
-            _ => None,
-        })
+        emit_unwind_info(&result.buffer, kind)
     }
 
     #[cfg(feature = "unwind")]
     fn create_systemv_cie(&self) -> Option<gimli::write::CommonInformationEntry> {
-        Some(inst::unwind::systemv::create_cie())
+        Some(create_cie())
     }
 
     #[cfg(feature = "unwind")]
@@ -168,6 +160,11 @@ impl TargetIsa for X64Backend {
             .build()
     }
 
+    #[cfg(feature = "disas")]
+    fn disas_syntax(&self) -> Option<&'static dyn crate::DisasSyntax> {
+        Some(&disas::X64DisasSyntax)
+    }
+
     fn has_native_fma(&self) -> bool {
         self.x64_flags.use_fma()
     }
@@ -203,6 +200,46 @@ impl fmt::Display for X64Backend {
     }
 }
 
+/// Creates unwind information of the given `kind` from the unwind
+/// pseudo-instructions recorded in `buffer`.
+///
+/// This is public so that code generators other than Cranelift's own which
+/// emit x64 code into a `MachBuffer`, such as Winch, can describe their
+/// frames in the same way.
+#[cfg(feature = "unwind")]
+pub fn emit_unwind_info(
+    buffer: &MachBufferFinalized<Final>,
+    kind: crate::isa::unwind::UnwindInfoKind,
+) -> CodegenResult<Option<crate::isa::unwind::UnwindInfo>> {
+    use crate::isa::unwind::UnwindInfo;
+    use crate::isa::unwind::UnwindInfoKind;
+    Ok(match kind {
+        UnwindInfoKind::SystemV => {
+            let mapper = self::inst::unwind::systemv::RegisterMapper;
+            Some(UnwindInfo::SystemV(
+                crate::isa::unwind::systemv::create_unwind_info_from_insts(
+                    &buffer.unwind_info[..],
+                    buffer.data().len(),
+                    &mapper,
+                )?,
+            ))
+        }
+        UnwindInfoKind::Windows => Some(UnwindInfo::WindowsX64(
+            crate::isa::unwind::winx64::create_unwind_info_from_insts::<
+                self::inst::unwind::winx64::RegisterMapper,
+            >(&buffer.unwind_info[..])?,
+        )),
+        _ => None,
+    })
+}
+
+/// Creates the System V common information entry shared by the unwind
+/// information of all x64 functions; see [`emit_unwind_info`].
+#[cfg(feature = "unwind")]
+pub fn create_cie() -> gimli::write::CommonInformationEntry {
+    inst::unwind::systemv::create_cie()
+}
+
 /// Create a new `isa::Builder`.
 pub(crate) fn isa_builder(triple: Triple) -> IsaBuilder {
     IsaBuilder {
@@ -733,6 +733,17 @@ pub(crate) fn check(
             Ok(())
         }
 
+        Inst::LockXadd {
+            ref mem, dst_old, ..
+        }
+        | Inst::Xchg {
+            ref mem, dst_old, ..
+        } => {
+            ensure_no_fact(vcode, dst_old.to_reg())?;
+            check_store(ctx, None, mem, vcode, I64)?;
+            Ok(())
+        }
+
         Inst::AtomicRmwSeq {
             ref mem,
             temp,
@@ -15,11 +15,14 @@
 
 use crate::cursor::{Cursor, FuncCursor};
 use crate::flowgraph::ControlFlowGraph;
+use crate::ir::condcodes::IntCC;
 use crate::ir::immediates::Imm64;
 use crate::ir::types::{self, I128, I64};
 use crate::ir::{self, InstBuilder, InstructionData, MemFlags, Value};
 use crate::isa::TargetIsa;
 use crate::trace;
+use alloc::vec::Vec;
+use smallvec::SmallVec;
 
 mod globalvalue;
 mod table;
@@ -74,6 +77,28 @@ pub fn simple_legalize(func: &mut ir::Function, cfg: &mut ControlFlowGraph, isa:
                     expand_cond_trap(inst, &mut pos.func, cfg, opcode, arg, code);
                 }
 
+                InstructionData::BranchTable {
+                    opcode: ir::Opcode::BrTable,
+                    arg,
+                    table,
+                } if !isa.flags().enable_jump_tables() => {
+                    expand_br_table(inst, &mut pos.func, cfg, arg, table);
+                }
+
+                InstructionData::Brif { .. } | InstructionData::BranchTable { .. } => {
+                    if !select_unpredictable_brif(inst, &mut pos.func, cfg) {
+                        mark_unlikely_destinations(inst, &mut pos.func, cfg);
+                    }
+                    prev_pos = pos.position();
+                    continue;
+                }
+
+                InstructionData::Call { .. } | InstructionData::CallIndirect { .. } => {
+                    outline_cold_call(inst, &mut pos.func, cfg);
+                    prev_pos = pos.position();
+                    continue;
+                }
+
                 // memory and constants
                 InstructionData::UnaryGlobalValue {
                     opcode: ir::Opcode::GlobalValue,
@@ -273,6 +298,151 @@ pub fn simple_legalize(func: &mut ir::Function, cfg: &mut ControlFlowGraph, isa:
     trace!("Post-legalization function:\n{}", func.display());
 }
 
+/// Marks the block containing a `cold` or `noreturn` call as cold, so that it
+/// is moved out of line like the blocks of expanded conditional traps.
+///
+/// Nothing after a `noreturn` call is reachable, so its block is also cut off
+/// right after the call with a trap. This turns the block into a trap sequence
+/// like the ones conditional traps expand to, and leaves the code which
+/// followed the call in a new unreachable block that is removed later on.
+fn outline_cold_call(inst: ir::Inst, func: &mut ir::Function, cfg: &mut ControlFlowGraph) {
+    let flags = func.dfg.call_flags[inst];
+    if !flags.cold() && !flags.noreturn() {
+        return;
+    }
+    let block = func.layout.inst_block(inst).unwrap();
+    // The entry block can't be cold, and a call there is as hot as the
+    // function itself anyway.
+    if func.layout.entry_block() != Some(block) {
+        func.layout.set_cold(block);
+    }
+    if !flags.noreturn() {
+        return;
+    }
+
+    let next = func
+        .layout
+        .next_inst(inst)
+        .expect("calls don't terminate blocks");
+    if func.dfg.insts[next].opcode() == ir::Opcode::Trap {
+        return;
+    }
+    let unreachable = func.dfg.make_block();
+    func.layout.split_block(unreachable, next);
+    let mut pos = FuncCursor::new(func).at_bottom(block);
+    pos.use_srcloc(inst);
+    pos.ins().trap(ir::TrapCode::UnreachableCodeReached);
+    cfg.recompute_block(pos.func, block);
+    cfg.recompute_block(pos.func, unreachable);
+}
+
+/// Marks the destinations of a branch which its hint says are unlikely to be
+/// taken as cold, so that they are moved out of line. Destinations which can
+/// also be reached from elsewhere are left alone since the hint only describes
+/// this branch.
+fn mark_unlikely_destinations(inst: ir::Inst, func: &mut ir::Function, cfg: &ControlFlowGraph) {
+    let hint = match func.dfg.branch_hints[inst] {
+        Some(hint) => hint,
+        None => return,
+    };
+    let blocks: SmallVec<[ir::Block; 4]> = func.dfg.insts[inst]
+        .branch_destination(&func.dfg.jump_tables)
+        .iter()
+        .map(|dest| dest.block(&func.dfg.value_lists))
+        .collect();
+    for (i, &block) in blocks.iter().enumerate() {
+        let unlikely = if i == 0 {
+            hint.is_taken_unlikely()
+        } else {
+            hint.is_not_taken_unlikely()
+        };
+        if unlikely
+            && blocks.iter().filter(|&&b| b == block).count() == 1
+            && cfg.pred_iter(block).count() == 1
+        {
+            func.layout.set_cold(block);
+        }
+    }
+}
+
+/// Turns a `brif` which only chooses between the arguments passed to one
+/// block into `select`s feeding a `jump`, when its hint says it's
+/// unpredictable. Backends lower such selects to conditional moves, which
+/// avoid the cost of mispredicting the branch.
+///
+/// The destinations may also be empty blocks that only jump on to the common
+/// block. They become unreachable and are removed later on. Returns whether
+/// the branch was replaced.
+fn select_unpredictable_brif(
+    inst: ir::Inst,
+    func: &mut ir::Function,
+    cfg: &mut ControlFlowGraph,
+) -> bool {
+    let hint = match func.dfg.branch_hints[inst] {
+        Some(hint) => hint,
+        None => return false,
+    };
+    if hint.is_taken_unlikely() || hint.is_not_taken_unlikely() {
+        return false;
+    }
+    let (arg, blocks) = match func.dfg.insts[inst] {
+        InstructionData::Brif { arg, blocks, .. } => (arg, blocks),
+        _ => return false,
+    };
+
+    // Looks through a destination which is an empty block only reachable
+    // from this branch.
+    let forward = |call: ir::BlockCall| {
+        let block = call.block(&func.dfg.value_lists);
+        if func.layout.entry_block() != Some(block)
+            && func.dfg.num_block_params(block) == 0
+            && cfg.pred_iter(block).all(|pred| pred.inst == inst)
+        {
+            if let Some(InstructionData::Jump { destination, .. }) = func
+                .layout
+                .first_inst(block)
+                .map(|first| &func.dfg.insts[first])
+            {
+                return *destination;
+            }
+        }
+        call
+    };
+    let then_call = forward(blocks[0]);
+    let else_call = forward(blocks[1]);
+    let target = then_call.block(&func.dfg.value_lists);
+    if else_call.block(&func.dfg.value_lists) != target {
+        return false;
+    }
+    let then_args: SmallVec<[Value; 4]> = then_call.args_slice(&func.dfg.value_lists).into();
+    let else_args: SmallVec<[Value; 4]> = else_call.args_slice(&func.dfg.value_lists).into();
+    if then_args.iter().any(|&v| {
+        let ty = func.dfg.value_type(v);
+        !ty.is_int() && !ty.is_float()
+    }) {
+        return false;
+    }
+
+    let block = func.layout.inst_block(inst).unwrap();
+    let mut pos = FuncCursor::new(func).at_inst(inst);
+    pos.use_srcloc(inst);
+    let args: SmallVec<[Value; 4]> = then_args
+        .iter()
+        .zip(else_args.iter())
+        .map(|(&a, &b)| {
+            if a == b {
+                a
+            } else {
+                pos.ins().select(arg, a, b)
+            }
+        })
+        .collect();
+    pos.func.dfg.branch_hints[inst] = None;
+    pos.func.dfg.replace(inst).jump(target, &args);
+    cfg.recompute_block(pos.func, block);
+    true
+}
+
 /// Custom expansion for conditional trap instructions.
 fn expand_cond_trap(
     inst: ir::Inst,
@@ -354,3 +524,103 @@ fn expand_cond_trap(
     cfg.recompute_block(pos.func, new_block_resume);
     cfg.recompute_block(pos.func, new_block_trap);
 }
+
+/// Expansion of `br_table` into a bounds check followed by a binary search
+/// over the index, used when jump tables are disabled.
+fn expand_br_table(
+    inst: ir::Inst,
+    func: &mut ir::Function,
+    cfg: &mut ControlFlowGraph,
+    arg: ir::Value,
+    table: ir::JumpTable,
+) {
+    trace!(
+        "expanding br_table: {:?}: {}",
+        inst,
+        func.dfg.display_inst(inst)
+    );
+
+    let old_block = func
+        .layout
+        .inst_block(inst)
+        .expect("Instruction not in layout.");
+
+    let pool = &func.dfg.value_lists;
+    let destination = |call: &ir::BlockCall| (call.block(pool), call.args_slice(pool).to_vec());
+    let jump_table = &func.dfg.jump_tables[table];
+    let default = destination(&jump_table.default_block());
+    let entries: Vec<_> = jump_table.as_slice().iter().map(destination).collect();
+
+    // Returns the destination for the indices in `lo..hi`: the entry itself
+    // if there's only one, or otherwise a new block, searching the range,
+    // which is queued up in `pending`.
+    fn search(
+        func: &mut ir::Function,
+        entries: &[(ir::Block, Vec<Value>)],
+        pending: &mut Vec<(ir::Block, usize, usize)>,
+        lo: usize,
+        hi: usize,
+    ) -> (ir::Block, Vec<Value>) {
+        if hi - lo == 1 {
+            return entries[lo].clone();
+        }
+        let block = func.dfg.make_block();
+        pending.push((block, lo, hi));
+        (block, Vec::new())
+    }
+
+    // The search blocks are inserted right after the original block:
+    //
+    //     br_table index, block_default, [block0, block1, block2]
+    //
+    // Becomes:
+    //
+    //     v0 = icmp_imm uge index, 3
+    //     brif v0, block_default, search_0_3
+    //
+    //   search_0_3:
+    //     v1 = icmp_imm ult index, 1
+    //     brif v1, block0, search_1_3
+    //
+    //   search_1_3:
+    //     v2 = icmp_imm ult index, 2
+    //     brif v2, block1, block2
+    let mut pos = FuncCursor::new(func).at_inst(inst);
+    pos.use_srcloc(inst);
+    if entries.is_empty() {
+        pos.func.dfg.replace(inst).jump(default.0, &default.1);
+    } else {
+        let mut pending = Vec::new();
+        let len = i64::try_from(entries.len()).unwrap();
+        let out_of_bounds = pos
+            .ins()
+            .icmp_imm(IntCC::UnsignedGreaterThanOrEqual, arg, len);
+        let (block, args) = search(pos.func, &entries, &mut pending, 0, entries.len());
+        pos.func
+            .dfg
+            .replace(inst)
+            .brif(out_of_bounds, default.0, &default.1, block, &args);
+
+        pos.goto_after_inst(inst);
+        let mut new_blocks = Vec::new();
+        while let Some((block, lo, hi)) = pending.pop() {
+            pos.insert_block(block);
+            new_blocks.push(block);
+
+            let mid = lo + (hi - lo) / 2;
+            let below =
+                pos.ins()
+                    .icmp_imm(IntCC::UnsignedLessThan, arg, i64::try_from(mid).unwrap());
+            let (then_block, then_args) = search(pos.func, &entries, &mut pending, lo, mid);
+            let (else_block, else_args) = search(pos.func, &entries, &mut pending, mid, hi);
+            pos.ins()
+                .brif(below, then_block, &then_args, else_block, &else_args);
+        }
+
+        for block in new_blocks {
+            cfg.recompute_block(pos.func, block);
+        }
+    }
+
+    cfg.recompute_block(pos.func, old_block);
+}
@@ -59,10 +59,12 @@ pub use crate::machinst::buffer::{
     FinalizedMachReloc, FinalizedRelocTarget, MachCallSite, MachSrcLoc, MachStackMap,
     MachTextSectionBuilder, MachTrap,
 };
+#[cfg(feature = "disas")]
+pub use crate::machinst::DisasSyntax;
 pub use crate::machinst::{
-    CompiledCode, Final, MachBuffer, MachBufferFinalized, MachInst, MachInstEmit,
-    MachInstEmitState, MachLabel, Reg, TextSectionBuilder, VCodeConstantData, VCodeConstants,
-    Writable,
+    CompiledCode, EmittedInst, Final, MachAtomicRmwOp, MachBuffer, MachBufferFinalized, MachInst,
+    MachInstEmit, MachInstEmitState, MachLabel, OpenPatchRegion, PatchRegion, Reg, RegallocStats,
+    TextSectionBuilder, VCodeConstantData, VCodeConstants, Writable,
 };
 
 mod alias_analysis;
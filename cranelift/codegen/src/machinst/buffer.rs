@@ -229,6 +229,24 @@ enum ForceVeneers {
     No,
 }
 
+/// A region of a [`MachBuffer`] which has been started with
+/// [`MachBuffer::start_patchable`] but not yet ended.
+pub struct OpenPatchRegion(usize);
+
+/// A region of code in a [`MachBuffer`] whose bytes may be modified after
+/// they're emitted, created with [`MachBuffer::end_patchable`].
+pub struct PatchRegion {
+    range: std::ops::Range<usize>,
+}
+
+impl PatchRegion {
+    /// Returns a mutable slice of the bytes emitted in this region of
+    /// `buffer`.
+    pub fn patch<I: VCodeInst>(self, buffer: &mut MachBuffer<I>) -> &mut [u8] {
+        &mut buffer.data[self.range]
+    }
+}
+
 /// A buffer of output to be produced, fixed up, and then emitted to a CodeSink
 /// in bulk.
 ///
@@ -314,6 +332,20 @@ pub struct MachBuffer<I: VCodeInst> {
 }
 
 impl MachBufferFinalized<Stencil> {
+    /// Renames the user external names referenced by this buffer's relocations.
+    #[cfg(feature = "incremental-cache")]
+    pub(crate) fn map_user_names(
+        &mut self,
+        mut f: impl FnMut(crate::ir::UserExternalNameRef) -> crate::ir::UserExternalNameRef,
+    ) {
+        for reloc in self.relocs.iter_mut() {
+            if let FinalizedRelocTarget::ExternalName(ExternalName::User(name)) = &mut reloc.target
+            {
+                *name = f(*name);
+            }
+        }
+    }
+
     /// Get a finalized machine buffer by applying the function's base source location.
     pub fn apply_base_srcloc(self, base_srcloc: SourceLoc) -> MachBufferFinalized<Final> {
         MachBufferFinalized {
@@ -490,6 +522,31 @@ impl<I: VCodeInst> MachBuffer<I> {
         // Post-invariant: as for `put1()`.
     }
 
+    /// Begin a region of code whose bytes may be modified after they're
+    /// emitted, for instructions with operands that are only known later.
+    ///
+    /// The code emitted before the matching call to
+    /// [`MachBuffer::end_patchable`] must not contain branches, which may be
+    /// removed from the buffer by branch simplification.
+    pub fn start_patchable(&mut self) -> OpenPatchRegion {
+        OpenPatchRegion(self.data.len())
+    }
+
+    /// End a region of patchable code started with
+    /// [`MachBuffer::start_patchable`], returning a [`PatchRegion`] which
+    /// can later give mutable access to the bytes emitted in it.
+    pub fn end_patchable(&mut self, open: OpenPatchRegion) -> PatchRegion {
+        debug_assert!(
+            self.latest_branches
+                .iter()
+                .all(|b| b.start < open.0 as CodeOffset),
+            "branches can't be emitted in a patchable region"
+        );
+        PatchRegion {
+            range: open.0..self.data.len(),
+        }
+    }
+
     /// Reserve appended space and return a mutable slice referring to it.
     pub fn get_appended_space(&mut self, len: usize) -> &mut [u8] {
         let off = self.data.len();
@@ -5,11 +5,13 @@ use crate::ir::pcc;
 use crate::ir::Function;
 use crate::isa::TargetIsa;
 use crate::machinst::*;
+use crate::settings::RegallocAlgorithm;
 use crate::timing;
 use crate::trace;
 use crate::CodegenError;
 
 use regalloc2::RegallocOptions;
+use std::time::Instant;
 
 /// Compile the given function down to VCode with allocated registers, ready
 /// for binary emission.
@@ -20,6 +22,7 @@ pub fn compile<B: LowerBackend + TargetIsa>(
     abi: Callee<<<B as LowerBackend>::MInst as MachInst>::ABIMachineSpec>,
     emit_info: <B::MInst as MachInstEmit>::Info,
     sigs: SigSet,
+    deadline: Option<Instant>,
     ctrl_plane: &mut ControlPlane,
 ) -> CodegenResult<(VCode<B::MInst>, regalloc2::Output)> {
     // Compute lowered block order.
@@ -53,6 +56,43 @@ pub fn compile<B: LowerBackend + TargetIsa>(
         pcc::check_vcode_facts(f, &mut vcode, b).map_err(CodegenError::Pcc)?;
     }
 
+    check_deadline(deadline)?;
+
+    // Refuse to allocate registers for functions over the configured limit,
+    // rather than spending an unbounded amount of time on them.
+    let max_insts_log2 = b.flags().regalloc_max_insts_log2();
+    if max_insts_log2 != 0 {
+        let max_insts = 1usize
+            .checked_shl(u32::from(max_insts_log2))
+            .unwrap_or(usize::MAX);
+        if vcode.num_insts() > max_insts {
+            log::debug!(
+                "{} vcode instructions exceed the register allocation limit of {}",
+                vcode.num_insts(),
+                max_insts
+            );
+            return Err(CodegenError::ImplLimitExceeded);
+        }
+    }
+
+    // Fall back to the single-pass allocator for functions over its
+    // threshold, whose running time is linear in their size.
+    let mut algorithm = b.flags().regalloc_algorithm();
+    let single_pass_insts_log2 = b.flags().regalloc_single_pass_insts_log2();
+    if single_pass_insts_log2 != 0 {
+        let single_pass_insts = 1usize
+            .checked_shl(u32::from(single_pass_insts_log2))
+            .unwrap_or(usize::MAX);
+        if vcode.num_insts() > single_pass_insts {
+            log::debug!(
+                "{} vcode instructions exceed the single-pass allocation threshold of {}",
+                vcode.num_insts(),
+                single_pass_insts
+            );
+            algorithm = RegallocAlgorithm::SinglePass;
+        }
+    }
+
     // Perform register allocation.
     let regalloc_result = {
         let _tt = timing::regalloc();
@@ -63,7 +103,13 @@ pub fn compile<B: LowerBackend + TargetIsa>(
             options.validate_ssa = true;
         }
 
-        regalloc2::run(&vcode, vcode.machine_env(), &options)
+        let result = match algorithm {
+            RegallocAlgorithm::Backtracking => {
+                regalloc2::run(&vcode, vcode.machine_env(), &options)
+            }
+            RegallocAlgorithm::SinglePass => single_pass::run(&vcode, vcode.machine_env()),
+        };
+        result
             .map_err(|err| {
                 log::error!(
                     "Register allocation error for vcode\n{:?}\nError: {:?}\nCLIF for error:\n{:?}",
@@ -94,5 +140,26 @@ pub fn compile<B: LowerBackend + TargetIsa>(
             .expect("register allocation checker");
     }
 
+    // Check that the stack maps cover every reference-typed value live across
+    // a safepoint: a GC hole otherwise only shows up as heap corruption much
+    // later. This is a liveness analysis over the whole function, so outside
+    // of debug builds it only runs alongside the regalloc checker.
+    if cfg!(debug_assertions) || b.flags().regalloc_checker() {
+        if let Err(err) = safepoints::check(&vcode, &regalloc_result.safepoint_slots) {
+            log::error!("Stack map check error:\n{}\nfor vcode:\n{:?}", err, vcode);
+            return Err(CodegenError::StackMap(err));
+        }
+    }
+
+    check_deadline(deadline)?;
+
     Ok((vcode, regalloc_result))
 }
+
+/// Fails with [`CodegenError::DeadlineExceeded`] if `deadline` has passed.
+pub(crate) fn check_deadline(deadline: Option<Instant>) -> CodegenResult<()> {
+    match deadline {
+        Some(deadline) if Instant::now() >= deadline => Err(CodegenError::DeadlineExceeded),
+        _ => Ok(()),
+    }
+}
@@ -324,6 +324,48 @@ macro_rules! isle_lower_prelude_methods {
             }
         }
 
+        #[inline]
+        fn atomic_relaxed(&mut self, flags: MemFlags) -> Option<()> {
+            if self.backend.flags().force_seq_cst_atomics() {
+                return None;
+            }
+            match flags.atomic_ordering() {
+                crate::ir::AtomicOrdering::Relaxed => Some(()),
+                _ => None,
+            }
+        }
+
+        #[inline]
+        fn atomic_release_or_weaker(&mut self, flags: MemFlags) -> Option<()> {
+            if self.backend.flags().force_seq_cst_atomics() {
+                return None;
+            }
+            match flags.atomic_ordering() {
+                crate::ir::AtomicOrdering::Relaxed | crate::ir::AtomicOrdering::Release => Some(()),
+                _ => None,
+            }
+        }
+
+        #[inline]
+        fn atomic_acquire(&mut self, flags: MemFlags) -> Option<()> {
+            if self.backend.flags().force_seq_cst_atomics() {
+                return None;
+            }
+            match flags.atomic_ordering() {
+                crate::ir::AtomicOrdering::Acquire => Some(()),
+                _ => None,
+            }
+        }
+
+        #[inline]
+        fn atomic_flags(&mut self, flags: MemFlags) -> MemFlags {
+            if self.backend.flags().force_seq_cst_atomics() {
+                flags.with_atomic_ordering(crate::ir::AtomicOrdering::SeqCst)
+            } else {
+                flags
+            }
+        }
+
         #[inline]
         fn func_ref_data(&mut self, func_ref: FuncRef) -> (SigRef, ExternalName, RelocDistance) {
             let funcdata = &self.lower_ctx.dfg().ext_funcs[func_ref];
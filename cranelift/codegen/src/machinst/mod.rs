@@ -87,6 +87,10 @@ pub use reg::*;
 pub use valueregs::*;
 pub mod pcc;
 pub mod reg;
+pub mod safepoints;
+pub mod schedule;
+pub mod single_pass;
+pub use schedule::SchedClass;
 
 /// A machine instruction.
 pub trait MachInst: Clone + Debug {
@@ -185,6 +189,18 @@ pub trait MachInst: Clone + Debug {
     /// Is this a safepoint?
     fn is_safepoint(&self) -> bool;
 
+    /// Does this instruction emit data, such as a jump table or a constant,
+    /// inline in the instruction stream?
+    fn emits_inline_data(&self) -> bool {
+        false
+    }
+
+    /// How the post-regalloc instruction scheduler may move this
+    /// instruction. By default, instructions are never moved.
+    fn sched_class(&self) -> SchedClass {
+        SchedClass::Barrier
+    }
+
     /// Generate an instruction that must appear at the beginning of a basic
     /// block, if any. Note that the return value must not be subject to
     /// register allocation.
@@ -350,6 +366,69 @@ pub struct CompiledCodeBase<T: CompilePhase> {
     /// This info is generated only if the `machine_code_cfg_info`
     /// flag is set.
     pub bb_edges: Vec<(CodeOffset, CodeOffset)>,
+    /// Instruction layout info: the byte range of each emitted instruction
+    /// that contains only machine instructions, i.e. excluding islands,
+    /// traps and instructions with inline data.
+    ///
+    /// This info is generated only if disassembly is requested, and is used
+    /// to check the emitted encodings with a disassembler.
+    pub inst_ranges: Vec<EmittedInst>,
+    /// Statistics about the register allocation of this function.
+    pub regalloc_stats: RegallocStats,
+}
+
+/// The bytes emitted for a single instruction, as recorded in
+/// [`CompiledCodeBase::inst_ranges`].
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "enable-serde", derive(Serialize, Deserialize))]
+pub struct EmittedInst {
+    /// Offset of the instruction's first byte.
+    pub start: CodeOffset,
+    /// Offset just past the instruction's last byte.
+    pub end: CodeOffset,
+    /// The instruction as printed in the disassembly.
+    pub text: String,
+}
+
+/// Statistics reported by the register allocator for a single function.
+///
+/// These are useful to find out why a function took long to compile; the
+/// time spent in register allocation itself is reported by
+/// [`crate::timing::PassTimes::pass_total`].
+///
+/// The single-pass allocator doesn't build bundles, so it only reports the
+/// number of spill slots and edits.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "enable-serde", derive(Serialize, Deserialize))]
+pub struct RegallocStats {
+    /// Number of live-range bundles left after merging.
+    pub bundles: usize,
+    /// Number of times a bundle was processed by the allocation loop.
+    pub processed_bundles: usize,
+    /// Number of bundle splits.
+    pub splits: usize,
+    /// Number of bundles evicted from a register by another bundle.
+    pub evictions: usize,
+    /// Number of bundles which were spilled.
+    pub spilled_bundles: usize,
+    /// Number of spill slots used.
+    pub spill_slots: usize,
+    /// Number of moves inserted by the allocator.
+    pub edits: usize,
+}
+
+impl RegallocStats {
+    pub(crate) fn new(output: &regalloc2::Output) -> Self {
+        Self {
+            bundles: output.stats.final_bundle_count,
+            processed_bundles: output.stats.process_bundle_count,
+            splits: output.stats.splits,
+            evictions: output.stats.evict_bundle_count,
+            spilled_bundles: output.stats.spill_bundle_count,
+            spill_slots: output.num_spillslots,
+            edits: output.edits.len(),
+        }
+    }
 }
 
 impl CompiledCodeStencil {
@@ -364,6 +443,8 @@ impl CompiledCodeStencil {
             dynamic_stackslot_offsets: self.dynamic_stackslot_offsets,
             bb_starts: self.bb_starts,
             bb_edges: self.bb_edges,
+            inst_ranges: self.inst_ranges,
+            regalloc_stats: self.regalloc_stats,
         }
     }
 }
@@ -448,13 +529,145 @@ impl<T: CompilePhase> CompiledCodeBase<T> {
         }
 
         return Ok(buf);
+    }
 
-        fn map_caperr(err: capstone::Error) -> anyhow::Error {
-            anyhow::format_err!("{}", err)
+    /// Check the machine code against its printed form with the given
+    /// capstone context.
+    ///
+    /// Each instruction's bytes, as recorded in `inst_ranges`, must decode
+    /// completely into valid machine instructions, with no invalid bytes and
+    /// no instruction straddling the boundary to the next one. When the bytes
+    /// decode as a single instruction, its opcode must match the printed
+    /// instruction's, and every register operand it decodes with must appear
+    /// in the printed instruction. This catches encoder bugs such as wrong
+    /// instruction lengths, reserved bit patterns, or a wrong opcode or
+    /// register field before they turn into silent miscompiles.
+    ///
+    /// `syntax` describes how the ISA's printed instructions relate to
+    /// capstone's output. Pseudo-instructions that expand into a sequence are
+    /// only checked for clean decoding.
+    ///
+    /// Note that the disassembler must know every instruction in the
+    /// function, so this can't be used with ISA extensions that capstone
+    /// doesn't support.
+    #[cfg(feature = "disas")]
+    pub fn check_encodings(
+        &self,
+        cs: &capstone::Capstone,
+        syntax: &dyn DisasSyntax,
+    ) -> Result<(), anyhow::Error> {
+        let code = self.buffer.data();
+        for inst in &self.inst_ranges {
+            // Data directives, such as an s390x breakpoint, aren't meant to
+            // decode.
+            if inst.text.starts_with('.') {
+                continue;
+            }
+            let bytes = &code[inst.start as usize..inst.end as usize];
+            let insns = cs
+                .disasm_all(bytes, inst.start as u64)
+                .map_err(map_caperr)?;
+            let decoded: usize = insns
+                .iter()
+                .filter(|i| !i.mnemonic().map_or(true, |m| m.starts_with('.')))
+                .map(|i| i.bytes().len())
+                .sum();
+            if decoded != bytes.len() {
+                anyhow::bail!(
+                    "`{}` at offset {:#x} doesn't decode cleanly: only {} of its bytes \
+                     {:02x?} are valid machine instructions",
+                    inst.text,
+                    inst.start,
+                    decoded,
+                    bytes,
+                );
+            }
+
+            let insn = match &insns[..] {
+                [insn] => insn,
+                _ => continue,
+            };
+            let mnemonic = insn.mnemonic().unwrap_or("");
+            let op_str = insn.op_str().unwrap_or("");
+            let decoded = format!("{mnemonic} {op_str}");
+            let (printed, printed_ops) =
+                split_mnemonic(&inst.text, mnemonic.split_whitespace().count());
+            if !syntax.same_mnemonic(&printed, mnemonic) {
+                anyhow::bail!(
+                    "`{}` at offset {:#x} decodes as `{}`, which has a different opcode",
+                    inst.text,
+                    inst.start,
+                    decoded.trim_end(),
+                );
+            }
+            let mut printed_regs: Vec<String> = operand_regs(syntax, printed_ops).collect();
+            printed_regs.extend(syntax.implicit_regs(&printed).iter().map(|&r| r.into()));
+            let unprinted_reg = operand_regs(syntax, op_str).find(|r| !printed_regs.contains(r));
+            if let Some(reg) = unprinted_reg {
+                anyhow::bail!(
+                    "`{}` at offset {:#x} decodes as `{}`, which uses {}, a register that \
+                     isn't printed",
+                    inst.text,
+                    inst.start,
+                    decoded.trim_end(),
+                    reg,
+                );
+            }
         }
+        Ok(())
     }
 }
 
+/// How an ISA's printed instructions relate to capstone's disassembly of
+/// them, for [`CompiledCodeBase::check_encodings`].
+#[cfg(feature = "disas")]
+pub trait DisasSyntax {
+    /// Whether `printed`, the mnemonic Cranelift prints for an instruction,
+    /// names the same opcode as `decoded`, the mnemonic capstone decodes it
+    /// as. The two can differ by assembler aliases and syntax conventions.
+    fn same_mnemonic(&self, printed: &str, decoded: &str) -> bool;
+
+    /// The canonical name of the register operand `name`, mapping
+    /// sub-registers and aliases to a single name, or `None` if `name` isn't
+    /// a register that needs to be checked.
+    fn canonical_reg(&self, name: &str) -> Option<String>;
+
+    /// Canonical names of the registers that the instruction `printed` uses
+    /// without printing them.
+    fn implicit_regs(&self, _printed: &str) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// Split the first `words` whitespace-separated words, the mnemonic, off
+/// the printed instruction `text`.
+#[cfg(feature = "disas")]
+fn split_mnemonic(text: &str, words: usize) -> (String, &str) {
+    let mut rest = text.trim_start();
+    let mut mnemonic = Vec::with_capacity(words);
+    for _ in 0..words {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        mnemonic.push(&rest[..end]);
+        rest = rest[end..].trim_start();
+    }
+    (mnemonic.join(" "), rest)
+}
+
+/// The canonical names of the registers in the operand list `ops`.
+#[cfg(feature = "disas")]
+fn operand_regs<'a>(
+    syntax: &'a dyn DisasSyntax,
+    ops: &'a str,
+) -> impl Iterator<Item = String> + 'a {
+    ops.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '%'))
+        .filter_map(move |word| syntax.canonical_reg(word))
+}
+
+#[cfg(feature = "disas")]
+fn map_caperr(err: capstone::Error) -> anyhow::Error {
+    anyhow::format_err!("{}", err)
+}
+
 /// Result of compiling a `FunctionStencil`, before applying `FunctionParameters` onto it.
 ///
 /// Only used internally, in a transient manner, for the incremental compilation cache.
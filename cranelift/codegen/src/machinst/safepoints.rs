@@ -0,0 +1,361 @@
+//! Checking of stack maps produced by register allocation.
+//!
+//! Reference-typed values which are live across a safepoint must be in stack
+//! slots recorded in the safepoint's stack map, or a moving or precise
+//! collector won't see them. A missing slot is a GC hole: it silently
+//! corrupts the heap only once a collection happens at just the wrong time,
+//! which makes it very hard to track down from its symptoms.
+//!
+//! This module recomputes the liveness of reference-typed virtual registers
+//! from the VCode independently of the register allocator, and checks the
+//! number of stack map slots at each safepoint against it. Regalloc2 gives
+//! every reference-typed value live across a safepoint its own slot, so a
+//! safepoint with fewer slots than values is missing one. A safepoint with
+//! more slots than the values live across it or used by it has stale ones.
+
+use crate::fx::{FxHashMap, FxHashSet};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use regalloc2::{Allocation, Block, Function, Inst, OperandKind, ProgPoint, VReg};
+
+/// Checks that the stack map slots in `safepoint_slots`, the result of
+/// allocating registers for `func`, cover every reference-typed value live
+/// across each safepoint.
+pub fn check<F: Function>(
+    func: &F,
+    safepoint_slots: &[(ProgPoint, Allocation)],
+) -> Result<(), String> {
+    let reftyped: FxHashSet<VReg> = func.reftype_vregs().iter().copied().collect();
+    if reftyped.is_empty() {
+        return Ok(());
+    }
+
+    let mut slots: FxHashMap<Inst, usize> = FxHashMap::default();
+    for (progpoint, _) in safepoint_slots {
+        *slots.entry(progpoint.inst()).or_default() += 1;
+    }
+
+    let live_in = live_in(func, &reftyped);
+    for block in (0..func.num_blocks()).map(Block::new) {
+        let live_out = live_out(func, &live_in, block, &reftyped);
+        scan_block(func, block, live_out, &reftyped, |inst, across, used| {
+            let across = across.len();
+            let num_slots = slots.get(&inst).copied().unwrap_or(0);
+            if num_slots < across {
+                return Err(format!(
+                    "{across} reference-typed values are live across safepoint {inst:?}, \
+                     but its stack map has only {num_slots} slots"
+                ));
+            }
+            if num_slots > across + used {
+                return Err(format!(
+                    "stack map of safepoint {inst:?} has {num_slots} slots, but at most \
+                     {} reference-typed values are live across or used by it",
+                    across + used
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Returns the reference-typed values live across each safepoint of `func`,
+/// in instruction order.
+pub(crate) fn live_across<F: Function>(func: &F) -> Vec<(Inst, Vec<VReg>)> {
+    let reftyped: FxHashSet<VReg> = func.reftype_vregs().iter().copied().collect();
+    let mut result = Vec::new();
+    if reftyped.is_empty() {
+        return result;
+    }
+
+    let live_in = live_in(func, &reftyped);
+    for block in (0..func.num_blocks()).map(Block::new) {
+        let live_out = live_out(func, &live_in, block, &reftyped);
+        scan_block(func, block, live_out, &reftyped, |inst, across, _| {
+            let mut across: Vec<VReg> = across.iter().copied().collect();
+            across.sort_unstable();
+            result.push((inst, across));
+            Ok(())
+        })
+        .unwrap();
+    }
+    result.sort_by_key(|&(inst, _)| inst);
+    result
+}
+
+/// Computes the reference-typed values live on entry to each block by
+/// iterating to a fixpoint. Visiting blocks in reverse order converges
+/// quickly since most edges point forward.
+fn live_in<F: Function>(func: &F, reftyped: &FxHashSet<VReg>) -> Vec<FxHashSet<VReg>> {
+    let mut live_in: Vec<FxHashSet<VReg>> = vec![FxHashSet::default(); func.num_blocks()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for block in (0..func.num_blocks()).rev().map(Block::new) {
+            let live_out = live_out(func, &live_in, block, reftyped);
+            let live = scan_block(func, block, live_out, reftyped, |_, _, _| Ok(())).unwrap();
+            if live != live_in[block.index()] {
+                live_in[block.index()] = live;
+                changed = true;
+            }
+        }
+    }
+    live_in
+}
+
+/// Computes the reference-typed values live at the end of `block`, which
+/// includes those passed to successors as block arguments.
+fn live_out<F: Function>(
+    func: &F,
+    live_in: &[FxHashSet<VReg>],
+    block: Block,
+    reftyped: &FxHashSet<VReg>,
+) -> FxHashSet<VReg> {
+    let mut live = FxHashSet::default();
+    let last = func.block_insns(block).last();
+    for (succ_idx, &succ) in func.block_succs(block).iter().enumerate() {
+        live.extend(live_in[succ.index()].iter().copied());
+        if func.is_branch(last) {
+            live.extend(
+                func.branch_blockparams(block, last, succ_idx)
+                    .iter()
+                    .filter(|&vreg| reftyped.contains(vreg)),
+            );
+        }
+    }
+    live
+}
+
+/// Walks `block` backwards from the values live at its end, calling
+/// `on_safepoint` with the reference-typed values live across each safepoint
+/// and the number used only by it, and returns the values live on entry.
+fn scan_block<F: Function>(
+    func: &F,
+    block: Block,
+    mut live: FxHashSet<VReg>,
+    reftyped: &FxHashSet<VReg>,
+    mut on_safepoint: impl FnMut(Inst, &FxHashSet<VReg>, usize) -> Result<(), String>,
+) -> Result<FxHashSet<VReg>, String> {
+    let insts: Vec<Inst> = func.block_insns(block).iter().collect();
+    for &inst in insts.iter().rev() {
+        let operands = func.inst_operands(inst);
+        let refs = |kind: OperandKind| {
+            operands
+                .iter()
+                .filter(move |op| op.kind() == kind && reftyped.contains(&op.vreg()))
+                .map(|op| op.vreg())
+        };
+
+        for def in refs(OperandKind::Def) {
+            live.remove(&def);
+        }
+        if func.requires_refs_on_stack(inst) {
+            let used = refs(OperandKind::Use)
+                .filter(|vreg| !live.contains(vreg))
+                .collect::<FxHashSet<_>>()
+                .len();
+            on_safepoint(inst, &live, used)?;
+        }
+        live.extend(refs(OperandKind::Use));
+    }
+    for param in func.block_params(block) {
+        live.remove(param);
+    }
+    Ok(live)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regalloc2::{InstRange, Operand, PRegSet, RegClass, SpillSlot};
+
+    #[derive(Default)]
+    struct TestInst {
+        operands: Vec<Operand>,
+        safepoint: bool,
+        branch: bool,
+        ret: bool,
+    }
+
+    /// A function of blocks laid out in order, whose branches don't pass
+    /// block arguments.
+    struct TestFunc {
+        insts: Vec<TestInst>,
+        ranges: Vec<(usize, usize)>,
+        succs: Vec<Vec<Block>>,
+        preds: Vec<Vec<Block>>,
+        reftyped: Vec<VReg>,
+    }
+
+    impl TestFunc {
+        fn new(blocks: Vec<Vec<TestInst>>, edges: &[(usize, usize)]) -> Self {
+            let mut func = TestFunc {
+                insts: Vec::new(),
+                ranges: Vec::new(),
+                succs: vec![Vec::new(); blocks.len()],
+                preds: vec![Vec::new(); blocks.len()],
+                reftyped: vec![vreg(0), vreg(1)],
+            };
+            for block in blocks {
+                let start = func.insts.len();
+                func.insts.extend(block);
+                func.ranges.push((start, func.insts.len()));
+            }
+            for &(from, to) in edges {
+                func.succs[from].push(Block::new(to));
+                func.preds[to].push(Block::new(from));
+            }
+            func
+        }
+    }
+
+    impl Function for TestFunc {
+        fn num_insts(&self) -> usize {
+            self.insts.len()
+        }
+
+        fn num_blocks(&self) -> usize {
+            self.ranges.len()
+        }
+
+        fn entry_block(&self) -> Block {
+            Block::new(0)
+        }
+
+        fn block_insns(&self, block: Block) -> InstRange {
+            let (start, end) = self.ranges[block.index()];
+            InstRange::forward(Inst::new(start), Inst::new(end))
+        }
+
+        fn block_succs(&self, block: Block) -> &[Block] {
+            &self.succs[block.index()]
+        }
+
+        fn block_preds(&self, block: Block) -> &[Block] {
+            &self.preds[block.index()]
+        }
+
+        fn block_params(&self, _block: Block) -> &[VReg] {
+            &[]
+        }
+
+        fn is_ret(&self, insn: Inst) -> bool {
+            self.insts[insn.index()].ret
+        }
+
+        fn is_branch(&self, insn: Inst) -> bool {
+            self.insts[insn.index()].branch
+        }
+
+        fn branch_blockparams(&self, _block: Block, _insn: Inst, _succ_idx: usize) -> &[VReg] {
+            &[]
+        }
+
+        fn requires_refs_on_stack(&self, insn: Inst) -> bool {
+            self.insts[insn.index()].safepoint
+        }
+
+        fn inst_operands(&self, insn: Inst) -> &[Operand] {
+            &self.insts[insn.index()].operands
+        }
+
+        fn inst_clobbers(&self, _insn: Inst) -> PRegSet {
+            PRegSet::default()
+        }
+
+        fn num_vregs(&self) -> usize {
+            4
+        }
+
+        fn reftype_vregs(&self) -> &[VReg] {
+            &self.reftyped
+        }
+
+        fn spillslot_size(&self, _regclass: RegClass) -> usize {
+            1
+        }
+    }
+
+    fn vreg(index: usize) -> VReg {
+        VReg::new(index, RegClass::Int)
+    }
+
+    fn def(index: usize) -> TestInst {
+        TestInst {
+            operands: vec![Operand::reg_def(vreg(index))],
+            ..Default::default()
+        }
+    }
+
+    fn call(uses: &[usize]) -> TestInst {
+        TestInst {
+            operands: uses.iter().map(|&i| Operand::reg_use(vreg(i))).collect(),
+            safepoint: true,
+            ..Default::default()
+        }
+    }
+
+    fn jump() -> TestInst {
+        TestInst {
+            branch: true,
+            ..Default::default()
+        }
+    }
+
+    fn ret(uses: &[usize]) -> TestInst {
+        TestInst {
+            operands: uses.iter().map(|&i| Operand::reg_use(vreg(i))).collect(),
+            ret: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns `count` stack map slots for the instruction `inst`.
+    fn slots(inst: usize, count: usize) -> Vec<(ProgPoint, Allocation)> {
+        (0..count)
+            .map(|i| {
+                let slot = Allocation::stack(SpillSlot::new(i));
+                (ProgPoint::before(Inst::new(inst)), slot)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn live_across_values_need_slots() {
+        // v0 is live across the call, v1 is only passed to it.
+        let func = TestFunc::new(vec![vec![def(0), def(1), call(&[1]), ret(&[0])]], &[]);
+        assert!(check(&func, &slots(2, 1)).is_ok());
+        assert!(check(&func, &slots(2, 2)).is_ok());
+
+        let err = check(&func, &[]).unwrap_err();
+        assert!(err.contains("only 0 slots"), "{err}");
+        let err = check(&func, &slots(2, 3)).unwrap_err();
+        assert!(err.contains("has 3 slots"), "{err}");
+    }
+
+    #[test]
+    fn liveness_flows_across_blocks() {
+        // v0 is defined in the first block and used after a call in the
+        // second.
+        let func = TestFunc::new(
+            vec![vec![def(0), jump()], vec![call(&[]), ret(&[0])]],
+            &[(0, 1)],
+        );
+        assert!(check(&func, &slots(2, 1)).is_ok());
+        assert!(check(&func, &[]).is_err());
+
+        // Nothing is live across a call after the last use.
+        let func = TestFunc::new(
+            vec![
+                vec![def(0), jump()],
+                vec![ret(&[0])],
+                vec![call(&[]), ret(&[])],
+            ],
+            &[(0, 1)],
+        );
+        assert!(check(&func, &[]).is_ok());
+    }
+}
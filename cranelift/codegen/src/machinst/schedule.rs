@@ -0,0 +1,218 @@
+//! Post-regalloc list scheduling of machine instructions.
+//!
+//! Simple in-order cores, such as small RISC-V implementations or the
+//! Cortex-A53/A55 class of AArch64 cores, stall whenever an instruction
+//! consumes a result that isn't ready yet. Lowering emits instructions in
+//! roughly the order of the CLIF, so a load or multiply is often immediately
+//! followed by its first use. This pass reorders straight-line runs of
+//! instructions to hide those latencies.
+//!
+//! Scheduling runs during emission, after register allocation, so that the
+//! dependencies between instructions are exactly the physical registers they
+//! read and write. Each backend describes its instructions with
+//! [`MachInst::sched_class`](super::MachInst::sched_class); instructions it
+//! doesn't describe are [`SchedClass::Barrier`]s and are never moved, so
+//! backends opt in one instruction at a time.
+//!
+//! The scheduler itself is a classic list scheduler over a single-issue
+//! machine model: at each cycle it picks, among the instructions whose
+//! operands are ready, the one with the longest latency-weighted path to the
+//! end of the region.
+
+use crate::fx::FxHashMap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use regalloc2::PReg;
+use smallvec::SmallVec;
+
+/// The maximum number of instructions scheduled together. Longer runs are
+/// split so that scheduling stays linear in the size of the function.
+pub(crate) const MAX_REGION_LEN: usize = 64;
+
+/// How the post-regalloc scheduler may treat an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchedClass {
+    /// The instruction must stay where it is relative to every other
+    /// instruction, for example because it has effects which aren't
+    /// described by its register operands.
+    Barrier,
+    /// The instruction only reads and writes its register operands, and its
+    /// result is available `latency` cycles after it issues.
+    Pure {
+        /// The latency of the instruction, in cycles.
+        latency: u8,
+    },
+    /// Like [`SchedClass::Pure`], but the instruction also accesses memory.
+    /// Memory accesses are kept in their original order.
+    Memory {
+        /// The latency of the instruction, in cycles.
+        latency: u8,
+    },
+}
+
+/// An instruction to be scheduled, with its register dependencies.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SchedNode {
+    /// The latency of the instruction, in cycles.
+    pub latency: u32,
+    /// Whether the instruction accesses memory.
+    pub mem: bool,
+    /// The physical registers read by the instruction.
+    pub uses: SmallVec<[PReg; 4]>,
+    /// The physical registers written by the instruction.
+    pub defs: SmallVec<[PReg; 2]>,
+}
+
+/// Computes a schedule for a region of instructions without barriers,
+/// returning the indices of `nodes` in the order they should be emitted.
+pub(crate) fn schedule(nodes: &[SchedNode]) -> Vec<usize> {
+    let n = nodes.len();
+    if n <= 1 {
+        return (0..n).collect();
+    }
+
+    // Build the dependency graph. Edges always point forward, from an
+    // instruction to a later one that must stay after it, and carry the
+    // number of cycles which must elapse between the two.
+    let mut succs: Vec<SmallVec<[(usize, u32); 4]>> = vec![SmallVec::new(); n];
+    let mut num_preds = vec![0u32; n];
+    let mut last_def: FxHashMap<PReg, usize> = FxHashMap::default();
+    let mut uses_since_def: FxHashMap<PReg, SmallVec<[usize; 4]>> = FxHashMap::default();
+    let mut last_mem = None;
+
+    let mut add_edge = |from: usize, to: usize, latency: u32| {
+        succs[from].push((to, latency));
+        num_preds[to] += 1;
+    };
+
+    for (i, node) in nodes.iter().enumerate() {
+        // Read-after-write: wait for the result.
+        for reg in &node.uses {
+            if let Some(&def) = last_def.get(reg) {
+                add_edge(def, i, nodes[def].latency);
+            }
+        }
+        for reg in &node.defs {
+            // Write-after-write: keep the final value in place.
+            if let Some(&def) = last_def.get(reg) {
+                add_edge(def, i, 0);
+            }
+            // Write-after-read: don't clobber a value that is still needed.
+            if let Some(readers) = uses_since_def.get(reg) {
+                for &reader in readers.iter().filter(|&&r| r != i) {
+                    add_edge(reader, i, 0);
+                }
+            }
+        }
+        if node.mem {
+            if let Some(prev) = last_mem {
+                add_edge(prev, i, 0);
+            }
+            last_mem = Some(i);
+        }
+
+        for &reg in &node.uses {
+            uses_since_def.entry(reg).or_default().push(i);
+        }
+        for &reg in &node.defs {
+            last_def.insert(reg, i);
+            uses_since_def.remove(&reg);
+        }
+    }
+
+    // The priority of an instruction is the length of the longest
+    // latency-weighted path from it to the end of the region.
+    let mut height = vec![0u32; n];
+    for i in (0..n).rev() {
+        height[i] = succs[i]
+            .iter()
+            .map(|&(s, latency)| latency + height[s])
+            .max()
+            .unwrap_or(nodes[i].latency);
+    }
+
+    let mut order = Vec::with_capacity(n);
+    let mut earliest = vec![0u32; n];
+    let mut ready: Vec<usize> = (0..n).filter(|&i| num_preds[i] == 0).collect();
+    let mut cycle = 0;
+    while !ready.is_empty() {
+        // If nothing is ready yet, stall until the first instruction is.
+        let first_ready = ready.iter().map(|&i| earliest[i]).min().unwrap();
+        cycle = cycle.max(first_ready);
+
+        // Among the instructions which can issue now, prefer the one on the
+        // longest path, and otherwise keep the original order.
+        let (pos, &next) = ready
+            .iter()
+            .enumerate()
+            .filter(|&(_, &i)| earliest[i] <= cycle)
+            .min_by_key(|&(_, &i)| (Reverse(height[i]), i))
+            .unwrap();
+        ready.swap_remove(pos);
+        order.push(next);
+
+        for &(succ, latency) in &succs[next] {
+            earliest[succ] = earliest[succ].max(cycle + latency);
+            num_preds[succ] -= 1;
+            if num_preds[succ] == 0 {
+                ready.push(succ);
+            }
+        }
+        cycle += 1;
+    }
+
+    debug_assert_eq!(order.len(), n);
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regalloc2::RegClass;
+
+    fn node(latency: u32, uses: &[usize], defs: &[usize]) -> SchedNode {
+        let preg = |i: &usize| PReg::new(*i, RegClass::Int);
+        SchedNode {
+            latency,
+            mem: false,
+            uses: uses.iter().map(preg).collect(),
+            defs: defs.iter().map(preg).collect(),
+        }
+    }
+
+    #[test]
+    fn hides_load_latency() {
+        // r1 = load; r2 = r1 + 1; r3 = r4 + 1
+        let nodes = [
+            SchedNode {
+                mem: true,
+                ..node(3, &[0], &[1])
+            },
+            node(1, &[1], &[2]),
+            node(1, &[4], &[3]),
+        ];
+        assert_eq!(schedule(&nodes), vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn respects_anti_and_output_dependencies() {
+        // r2 = r1 + 1; r1 = r3 * 5; r2 = r1 + 1
+        let nodes = [
+            node(1, &[1], &[2]),
+            node(4, &[3], &[1]),
+            node(1, &[1], &[2]),
+        ];
+        assert_eq!(schedule(&nodes), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn keeps_memory_order() {
+        let mem = |latency, uses: &[usize], defs: &[usize]| SchedNode {
+            mem: true,
+            ..node(latency, uses, defs)
+        };
+        // store r1; r2 = load; store r3
+        let nodes = [mem(1, &[1], &[]), mem(3, &[4], &[2]), mem(1, &[3], &[])];
+        assert_eq!(schedule(&nodes), vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,351 @@
+//! A single-pass register allocator for functions too large for regalloc2.
+//!
+//! Every virtual register lives in its own spill slot for its whole lifetime.
+//! The operands of each instruction are loaded into registers right before
+//! it and its results are stored back right after it, so no value is ever
+//! held in a register between two instructions. Block parameters are written
+//! to their slots on the edges into the block.
+//!
+//! The allocator never has to reason about live ranges, so it runs in time
+//! linear in the size of the function, which makes it a good fallback when
+//! the backtracking allocator would take too long. The code it produces does
+//! many more loads and stores than necessary, though.
+//!
+//! Since every value is always in its slot, the stack map of a safepoint is
+//! just the slots of the reference-typed values live across it.
+
+use super::safepoints;
+use crate::fx::FxHashMap;
+use alloc::vec::Vec;
+use regalloc2::{
+    Allocation, Block, Edit, Function, Inst, MachineEnv, Operand, OperandConstraint, OperandKind,
+    OperandPos, Output, PReg, PRegSet, ProgPoint, RegAllocError, RegClass, SpillSlot, VReg,
+};
+
+/// Allocates registers for `func`, returning the result in the same form as
+/// `regalloc2::run`.
+pub fn run<F: Function>(func: &F, env: &MachineEnv) -> Result<Output, RegAllocError> {
+    let mut alloc = SinglePass {
+        func,
+        env,
+        slots: vec![None; func.num_vregs()],
+        shadow_slots: FxHashMap::default(),
+        num_spillslots: 0,
+        edits: Vec::new(),
+        allocs: Vec::with_capacity(func.num_insts()),
+        inst_alloc_offsets: Vec::with_capacity(func.num_insts()),
+    };
+
+    for block in (0..func.num_blocks()).map(Block::new) {
+        alloc.block(block)?;
+    }
+
+    let mut safepoint_slots = Vec::new();
+    for (inst, live) in safepoints::live_across(func) {
+        for vreg in live {
+            let slot = alloc.slot(vreg);
+            safepoint_slots.push((ProgPoint::before(inst), Allocation::stack(slot)));
+        }
+    }
+    safepoint_slots.sort_unstable();
+
+    // Edits are generated in program order.
+    debug_assert!(alloc.edits.windows(2).all(|w| w[0].0 <= w[1].0));
+
+    Ok(Output {
+        num_spillslots: alloc.num_spillslots,
+        edits: alloc.edits,
+        allocs: alloc.allocs,
+        inst_alloc_offsets: alloc.inst_alloc_offsets,
+        safepoint_slots,
+        debug_locations: Vec::new(),
+        stats: Default::default(),
+    })
+}
+
+struct SinglePass<'a, F: Function> {
+    func: &'a F,
+    env: &'a MachineEnv,
+    /// The slot of each virtual register, allocated when it is first seen.
+    slots: Vec<Option<SpillSlot>>,
+    /// Slots holding the arguments for block parameters while the edge moves
+    /// into them are in progress; see `edge_moves`.
+    shadow_slots: FxHashMap<VReg, SpillSlot>,
+    num_spillslots: usize,
+    edits: Vec<(ProgPoint, Edit)>,
+    allocs: Vec<Allocation>,
+    inst_alloc_offsets: Vec<u32>,
+}
+
+/// The registers taken at the early and late points of an instruction.
+#[derive(Default)]
+struct Taken {
+    early: PRegSet,
+    late: PRegSet,
+}
+
+impl Taken {
+    fn mark(&mut self, preg: PReg, early: bool, late: bool) {
+        if early {
+            self.early.add(preg);
+        }
+        if late {
+            self.late.add(preg);
+        }
+    }
+}
+
+impl<'a, F: Function> SinglePass<'a, F> {
+    /// Returns the slot of `vreg`, allocating it if needed.
+    fn slot(&mut self, vreg: VReg) -> SpillSlot {
+        if let Some(slot) = self.slots[vreg.vreg()] {
+            return slot;
+        }
+        let slot = self.new_slot(vreg.class());
+        self.slots[vreg.vreg()] = Some(slot);
+        slot
+    }
+
+    fn new_slot(&mut self, class: RegClass) -> SpillSlot {
+        // Multi-slot values are aligned to their size, as regalloc2 does.
+        let size = self.func.spillslot_size(class);
+        let start = (self.num_spillslots + size - 1) / size * size;
+        self.num_spillslots = start + size;
+        if self.func.multi_spillslot_named_by_last_slot() {
+            SpillSlot::new(start + size - 1)
+        } else {
+            SpillSlot::new(start)
+        }
+    }
+
+    /// Returns the first allocatable register of `class` which isn't in
+    /// `avoid`.
+    fn free_reg(&self, class: RegClass, avoid: PRegSet) -> Result<PReg, RegAllocError> {
+        let class = class as usize;
+        self.env.preferred_regs_by_class[class]
+            .iter()
+            .chain(&self.env.non_preferred_regs_by_class[class])
+            .copied()
+            .find(|&preg| !avoid.contains(preg))
+            .ok_or(RegAllocError::TooManyLiveRegs)
+    }
+
+    fn block(&mut self, block: Block) -> Result<(), RegAllocError> {
+        let func = self.func;
+        for &param in func.block_params(block) {
+            self.slot(param);
+        }
+
+        // Critical edges are split, so the edge moves into this block's
+        // parameters go either right before the branch in its predecessor,
+        // when the predecessor has no other successor, or here, at the start
+        // of this block, when this is the predecessor's only successor.
+        if let [pred] = *func.block_preds(block) {
+            let succs = func.block_succs(pred);
+            if succs.len() > 1 {
+                let branch = func.block_insns(pred).last();
+                let succ_idx = succs.iter().position(|&succ| succ == block).unwrap();
+                let first = func.block_insns(block).first();
+                let progpoint = ProgPoint::before(first);
+                self.edge_moves(pred, branch, succ_idx, block, progpoint, PRegSet::empty())?;
+            }
+        }
+
+        for inst in self.func.block_insns(block).iter() {
+            self.inst(block, inst)?;
+        }
+        Ok(())
+    }
+
+    fn inst(&mut self, block: Block, inst: Inst) -> Result<(), RegAllocError> {
+        let func = self.func;
+        let operands = func.inst_operands(inst);
+        let clobbers = func.inst_clobbers(inst);
+        let mut allocs = vec![Allocation::none(); operands.len()];
+        let mut taken = Taken::default();
+
+        // Fixed registers first, so that the other operands can avoid them.
+        for (i, op) in operands.iter().enumerate() {
+            if let OperandConstraint::FixedReg(preg) = op.constraint() {
+                allocs[i] = Allocation::reg(preg);
+                let (early, late) = occupancy(op);
+                taken.mark(preg, early, late);
+            }
+        }
+
+        // Then uses, sharing one register between the early uses of a value,
+        // then defs. A reused input is always an early use, so its register
+        // is already known by the time the def reusing it is reached.
+        let mut shared: FxHashMap<VReg, PReg> = FxHashMap::default();
+        for kind in [OperandKind::Use, OperandKind::Def] {
+            for (i, op) in operands.iter().enumerate() {
+                if op.kind() != kind {
+                    continue;
+                }
+                match op.constraint() {
+                    OperandConstraint::FixedReg(_) => {}
+                    OperandConstraint::Stack => {
+                        allocs[i] = Allocation::stack(self.slot(op.vreg()));
+                    }
+                    OperandConstraint::Reuse(input) => {
+                        let preg = allocs[input].as_reg().unwrap();
+                        allocs[i] = Allocation::reg(preg);
+                        taken.mark(preg, false, true);
+                    }
+                    OperandConstraint::Any | OperandConstraint::Reg => {
+                        let shareable = kind == OperandKind::Use && op.pos() == OperandPos::Early;
+                        if shareable {
+                            if let Some(&preg) = shared.get(&op.vreg()) {
+                                allocs[i] = Allocation::reg(preg);
+                                continue;
+                            }
+                        }
+                        let (early, late) = occupancy(op);
+                        let mut avoid = PRegSet::empty();
+                        if early {
+                            avoid.union_from(taken.early);
+                        }
+                        if late {
+                            avoid.union_from(taken.late);
+                        }
+                        // A clobber is written during the instruction, so it
+                        // can only hold values read before or written after.
+                        if early && late {
+                            avoid.union_from(clobbers);
+                        }
+                        let preg = self.free_reg(op.class(), avoid)?;
+                        allocs[i] = Allocation::reg(preg);
+                        taken.mark(preg, early, late);
+                        if shareable {
+                            shared.insert(op.vreg(), preg);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Load the uses from their slots before the instruction, once per
+        // register, and store the defs to theirs after it. A branch can't
+        // have anything after it, but the only values it defines are
+        // temporaries. The edits must be in program order, so all of the
+        // reloads are pushed before any of the stores, whatever the order of
+        // the operands.
+        let mut reloaded = PRegSet::empty();
+        for kind in [OperandKind::Use, OperandKind::Def] {
+            for (op, alloc) in operands.iter().zip(&allocs) {
+                let Some(preg) = alloc.as_reg() else { continue };
+                if op.kind() != kind || op.vreg().vreg() == VReg::MAX {
+                    continue;
+                }
+                match kind {
+                    OperandKind::Use if !reloaded.contains(preg) => {
+                        reloaded.add(preg);
+                        let from = Allocation::stack(self.slot(op.vreg()));
+                        self.edits
+                            .push((ProgPoint::before(inst), Edit::Move { from, to: *alloc }));
+                    }
+                    OperandKind::Use => {}
+                    OperandKind::Def if !func.is_branch(inst) => {
+                        let to = Allocation::stack(self.slot(op.vreg()));
+                        self.edits
+                            .push((ProgPoint::after(inst), Edit::Move { from: *alloc, to }));
+                    }
+                    OperandKind::Def => {}
+                }
+            }
+        }
+
+        if let [succ] = *func.block_succs(block) {
+            if func.is_branch(inst) {
+                // The edge moves come after the branch's own reloads, so
+                // they must not use any of its registers.
+                let mut avoid = PRegSet::empty();
+                for alloc in &allocs {
+                    if let Some(preg) = alloc.as_reg() {
+                        avoid.add(preg);
+                    }
+                }
+                self.edge_moves(block, inst, 0, succ, ProgPoint::before(inst), avoid)?;
+            }
+        }
+
+        self.inst_alloc_offsets.push(self.allocs.len() as u32);
+        self.allocs.extend(allocs);
+        Ok(())
+    }
+
+    /// Copies the arguments which `branch`, at the end of `block`, passes to
+    /// its successor `succ` into the slots of `succ`'s parameters, at
+    /// `progpoint` and through a register not in `avoid`.
+    fn edge_moves(
+        &mut self,
+        block: Block,
+        branch: Inst,
+        succ_idx: usize,
+        succ: Block,
+        progpoint: ProgPoint,
+        avoid: PRegSet,
+    ) -> Result<(), RegAllocError> {
+        let func = self.func;
+        let args = func.branch_blockparams(block, branch, succ_idx);
+        let params = func.block_params(succ);
+        let moves: Vec<(VReg, VReg)> = args
+            .iter()
+            .copied()
+            .zip(params.iter().copied())
+            .filter(|(arg, param)| arg != param)
+            .collect();
+        if moves.is_empty() {
+            return Ok(());
+        }
+
+        // The moves happen in parallel: if an argument is itself one of the
+        // parameters, such as in a loop which swaps two values, copy all of
+        // the arguments aside before overwriting any parameter.
+        let overlapping = moves.iter().any(|(arg, _)| params.contains(arg));
+        for &(arg, param) in &moves {
+            let preg = Allocation::reg(self.free_reg(arg.class(), avoid)?);
+            let from = Allocation::stack(self.slot(arg));
+            let to = if overlapping {
+                Allocation::stack(self.shadow_slot(param))
+            } else {
+                Allocation::stack(self.slot(param))
+            };
+            self.edits.push((progpoint, Edit::Move { from, to: preg }));
+            self.edits.push((progpoint, Edit::Move { from: preg, to }));
+        }
+        if overlapping {
+            for &(_, param) in &moves {
+                let preg = Allocation::reg(self.free_reg(param.class(), avoid)?);
+                let from = Allocation::stack(self.shadow_slot(param));
+                let to = Allocation::stack(self.slot(param));
+                self.edits.push((progpoint, Edit::Move { from, to: preg }));
+                self.edits.push((progpoint, Edit::Move { from: preg, to }));
+            }
+        }
+        Ok(())
+    }
+
+    fn shadow_slot(&mut self, param: VReg) -> SpillSlot {
+        if let Some(&slot) = self.shadow_slots.get(&param) {
+            return slot;
+        }
+        let slot = self.new_slot(param.class());
+        self.shadow_slots.insert(param, slot);
+        slot
+    }
+}
+
+/// Returns whether `op`'s register is taken at the early point and at the
+/// late point of its instruction.
+fn occupancy(op: &Operand) -> (bool, bool) {
+    match (op.kind(), op.pos()) {
+        (OperandKind::Use, OperandPos::Early) => (true, false),
+        (OperandKind::Def, OperandPos::Late) => (false, true),
+        // A late use must survive until the late point, and an early def
+        // must not be overwritten until then.
+        (OperandKind::Use, OperandPos::Late) | (OperandKind::Def, OperandPos::Early) => {
+            (true, true)
+        }
+    }
+}
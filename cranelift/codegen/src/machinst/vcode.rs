@@ -22,6 +22,7 @@ use crate::fx::FxHashSet;
 use crate::ir::pcc::*;
 use crate::ir::RelSourceLoc;
 use crate::ir::{self, types, Constant, ConstantData, DynamicStackSlot, ValueLabel};
+use crate::machinst::schedule::{self, SchedNode};
 use crate::machinst::*;
 use crate::timing;
 use crate::trace;
@@ -213,6 +214,13 @@ pub struct EmitResult {
 
     /// Stack frame size.
     pub frame_size: u32,
+
+    /// Byte ranges of the emitted instructions which contain only machine
+    /// instructions. Computed only if disassembly is requested.
+    pub inst_ranges: Vec<EmittedInst>,
+
+    /// Statistics reported by the register allocator.
+    pub regalloc_stats: RegallocStats,
 }
 
 /// A builder for a VCode function body.
@@ -660,6 +668,87 @@ impl<I: VCodeInst> VCode<I> {
         &self.block_succs_preds[start as usize..end as usize]
     }
 
+    /// Reorders a block's instructions and regalloc edits with the
+    /// post-regalloc scheduler.
+    ///
+    /// Only runs of instructions which the backend allows to move are
+    /// reordered; regalloc edits and any other barriers stay in place.
+    fn schedule_block(&self, regalloc: &regalloc2::Output, items: &mut Vec<InstOrEdit<'_>>) {
+        let mut ranks: Vec<usize> = (0..items.len()).collect();
+        let mut nodes: Vec<SchedNode> = vec![];
+        let mut start = 0;
+
+        let flush = |start: usize, nodes: &mut Vec<SchedNode>, ranks: &mut Vec<usize>| {
+            for (rank, i) in schedule::schedule(nodes).into_iter().enumerate() {
+                ranks[start + i] = start + rank;
+            }
+            nodes.clear();
+        };
+
+        for (pos, item) in items.iter().enumerate() {
+            if nodes.len() == schedule::MAX_REGION_LEN {
+                flush(start, &mut nodes, &mut ranks);
+            }
+            let node = match item {
+                InstOrEdit::Inst(iix) => self.sched_node(regalloc, *iix),
+                InstOrEdit::Edit(_) => None,
+            };
+            match node {
+                Some(node) => {
+                    if nodes.is_empty() {
+                        start = pos;
+                    }
+                    nodes.push(node);
+                }
+                None => flush(start, &mut nodes, &mut ranks),
+            }
+        }
+        flush(start, &mut nodes, &mut ranks);
+
+        let mut ranked: Vec<_> = ranks.into_iter().zip(items.drain(..)).collect();
+        ranked.sort_unstable_by_key(|(rank, _)| *rank);
+        items.extend(ranked.into_iter().map(|(_, item)| item));
+    }
+
+    /// Describes an instruction to the post-regalloc scheduler, or returns
+    /// `None` if it must not be moved.
+    fn sched_node(&self, regalloc: &regalloc2::Output, iix: InsnIndex) -> Option<SchedNode> {
+        let (latency, mem) = match self.insts[iix.index()].sched_class() {
+            SchedClass::Barrier => return None,
+            SchedClass::Pure { latency } => (latency, false),
+            SchedClass::Memory { latency } => (latency, true),
+        };
+        // Clobbers, e.g. of calls, are effects beyond the operands.
+        if self.clobbers.contains_key(&iix) {
+            return None;
+        }
+
+        let mut node = SchedNode {
+            latency: u32::from(latency),
+            mem,
+            ..SchedNode::default()
+        };
+        for (operand, alloc) in self
+            .inst_operands(iix)
+            .iter()
+            .zip(regalloc.inst_allocs(iix))
+        {
+            // Writes to fixed non-allocatable registers, such as the stack
+            // pointer or the spill temporaries, are collected as uses, so
+            // their dependencies can't be tracked.
+            if operand.as_fixed_nonallocatable().is_some() {
+                return None;
+            }
+            match alloc.as_reg() {
+                Some(preg) if operand.kind() == OperandKind::Def => node.defs.push(preg),
+                Some(preg) => node.uses.push(preg),
+                // Operands in spillslots make this a memory access too.
+                None => node.mem = true,
+            }
+        }
+        Some(node)
+    }
+
     fn compute_clobbers(&self, regalloc: &regalloc2::Output) -> Vec<Writable<RealReg>> {
         // Compute clobbered registers.
         let mut clobbered = vec![];
@@ -786,6 +875,7 @@ impl<I: VCodeInst> VCode<I> {
         let mut state = I::State::new(&self.abi, std::mem::take(ctrl_plane));
 
         let mut disasm = String::new();
+        let mut inst_ranges = vec![];
 
         if !self.debug_value_labels.is_empty() {
             inst_offsets.resize(self.insts.len(), NO_INST_OFFSET);
@@ -814,6 +904,13 @@ impl<I: VCodeInst> VCode<I> {
         };
         let mut total_bb_padding = 0;
 
+        // Scheduling moves instructions relative to each other, which would
+        // break the assumption below that instruction offsets increase with
+        // instruction indices, so it's skipped when producing debug info.
+        let schedule_insts =
+            flags.enable_instruction_scheduling() && self.debug_value_labels.is_empty();
+        let mut block_insts_and_edits = vec![];
+
         for (block_order_idx, &block) in final_order.iter().enumerate() {
             trace!("emitting block {:?}", block);
 
@@ -829,16 +926,32 @@ impl<I: VCodeInst> VCode<I> {
             }
             assert_eq!(buffer.cur_offset(), new_offset);
 
-            let do_emit = |inst: &I,
-                           allocs: &[Allocation],
-                           disasm: &mut String,
-                           buffer: &mut MachBuffer<I>,
-                           state: &mut I::State| {
+            let mut do_emit = |inst: &I,
+                               allocs: &[Allocation],
+                               disasm: &mut String,
+                               buffer: &mut MachBuffer<I>,
+                               state: &mut I::State| {
+                let mut text = String::new();
                 if want_disasm && !inst.is_args() {
                     let mut s = state.clone();
-                    writeln!(disasm, "  {}", inst.pretty_print_inst(allocs, &mut s)).unwrap();
+                    text = inst.pretty_print_inst(allocs, &mut s);
+                    writeln!(disasm, "  {text}").unwrap();
                 }
+                let start = buffer.cur_offset();
                 inst.emit(allocs, buffer, &self.emit_info, state);
+                // Trap opcodes and inline data aren't meant to decode as
+                // instructions, so they can't be checked.
+                let checkable = !inst.is_trap() && !inst.emits_inline_data();
+                if want_disasm && checkable && buffer.cur_offset() > start {
+                    // Branch simplification may have removed instructions
+                    // at the tail of the buffer since they were recorded.
+                    trim_inst_ranges(&mut inst_ranges, start);
+                    inst_ranges.push(EmittedInst {
+                        start,
+                        end: buffer.cur_offset(),
+                        text,
+                    });
+                }
             };
 
             // Is this the first block? Emit the prologue directly if so.
@@ -883,7 +996,13 @@ impl<I: VCodeInst> VCode<I> {
                 do_emit(&block_start, &[], &mut disasm, &mut buffer, &mut state);
             }
 
-            for inst_or_edit in regalloc.block_insts_and_edits(&self, block) {
+            block_insts_and_edits.clear();
+            block_insts_and_edits.extend(regalloc.block_insts_and_edits(&self, block));
+            if schedule_insts {
+                self.schedule_block(regalloc, &mut block_insts_and_edits);
+            }
+
+            for inst_or_edit in block_insts_and_edits.drain(..) {
                 match inst_or_edit {
                     InstOrEdit::Inst(iix) => {
                         if !self.debug_value_labels.is_empty() {
@@ -1030,6 +1149,7 @@ impl<I: VCodeInst> VCode<I> {
             } else {
                 bb_padding.len() as u32 + I::LabelUse::ALIGN - 1
             };
+            trim_inst_ranges(&mut inst_ranges, buffer.cur_offset());
             if buffer.island_needed(padding + worst_case_next_bb) {
                 buffer.emit_island(padding + worst_case_next_bb, ctrl_plane);
             }
@@ -1060,6 +1180,7 @@ impl<I: VCodeInst> VCode<I> {
         *ctrl_plane = state.take_ctrl_plane();
 
         let func_body_len = buffer.cur_offset();
+        trim_inst_ranges(&mut inst_ranges, func_body_len);
 
         // Create `bb_edges` and final (filtered) `bb_starts`.
         let mut bb_edges = vec![];
@@ -1097,6 +1218,8 @@ impl<I: VCodeInst> VCode<I> {
             dynamic_stackslot_offsets: self.abi.dynamic_stackslot_offsets().clone(),
             value_labels_ranges,
             frame_size,
+            inst_ranges,
+            regalloc_stats: RegallocStats::new(regalloc),
         }
     }
 
@@ -1315,6 +1438,14 @@ impl<I: VCodeInst> VCode<I> {
     }
 }
 
+/// Drops the recorded instruction ranges which extend past `offset`, because
+/// the `MachBuffer` has truncated the instructions they describe.
+fn trim_inst_ranges(inst_ranges: &mut Vec<EmittedInst>, offset: CodeOffset) {
+    while inst_ranges.last().map_or(false, |range| range.end > offset) {
+        inst_ranges.pop();
+    }
+}
+
 impl<I: VCodeInst> std::ops::Index<InsnIndex> for VCode<I> {
     type Output = I;
     fn index(&self, idx: InsnIndex) -> &Self::Output {
@@ -45,6 +45,16 @@ pub enum CodegenError {
 
     /// Proof-carrying-code validation error.
     Pcc(PccError),
+
+    /// The deadline set with [`Context::set_deadline`](crate::Context::set_deadline)
+    /// passed before compilation finished.
+    DeadlineExceeded,
+
+    /// A reference-typed value live across a safepoint is missing from that
+    /// safepoint's stack map.
+    ///
+    /// This always represents a bug in Cranelift.
+    StackMap(String),
 }
 
 /// A convenient alias for a `Result` that uses `CodegenError` as the error type.
@@ -63,6 +73,8 @@ impl std::error::Error for CodegenError {
             CodegenError::RegisterMappingError { .. } => None,
             CodegenError::Regalloc(..) => None,
             CodegenError::Pcc(..) => None,
+            CodegenError::DeadlineExceeded => None,
+            CodegenError::StackMap(..) => None,
         }
     }
 }
@@ -78,6 +90,8 @@ impl std::fmt::Display for CodegenError {
             CodegenError::RegisterMappingError(_0) => write!(f, "Register mapping error"),
             CodegenError::Regalloc(errors) => write!(f, "Regalloc validation errors: {:?}", errors),
             CodegenError::Pcc(e) => write!(f, "Proof-carrying-code validation error: {:?}", e),
+            CodegenError::DeadlineExceeded => write!(f, "Compilation deadline exceeded"),
+            CodegenError::StackMap(e) => write!(f, "Stack map validation error: {}", e),
         }
     }
 }
@@ -520,6 +520,9 @@ mod tests {
         let f = Flags::new(b);
         let actual = f.to_string();
         let expected = r#"[shared]
+regalloc_algorithm = "backtracking"
+regalloc_single_pass_insts_log2 = 0
+regalloc_max_insts_log2 = 0
 opt_level = "none"
 tls_model = "none"
 libcall_call_conv = "isa_default"
@@ -529,6 +532,7 @@ bb_padding_log2_minus_one = 0
 regalloc_checker = false
 regalloc_verbose_logs = false
 enable_alias_analysis = true
+enable_instruction_scheduling = false
 enable_verifier = true
 enable_pcc = false
 is_pic = false
@@ -537,6 +541,7 @@ enable_float = true
 enable_nan_canonicalization = false
 enable_pinned_reg = false
 enable_atomics = true
+force_seq_cst_atomics = false
 enable_safepoints = false
 enable_llvm_abi_extensions = false
 unwind_info = true
@@ -170,6 +170,13 @@ impl PassTimes {
         }
     }
 
+    /// Returns the time taken by `pass`, including any passes nested in it.
+    pub fn pass_total(&self, pass: Pass) -> Duration {
+        self.pass
+            .get(pass.idx())
+            .map_or(Duration::default(), |p| p.total)
+    }
+
     /// Returns the total amount of time taken by all the passes measured.
     pub fn total(&self) -> Duration {
         self.pass.iter().map(|p| p.total - p.child).sum()
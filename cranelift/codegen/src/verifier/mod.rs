@@ -71,9 +71,9 @@ use crate::ir::entities::AnyEntity;
 use crate::ir::instructions::{CallInfo, InstructionFormat, ResolvedConstraint};
 use crate::ir::{self, ArgumentExtension};
 use crate::ir::{
-    types, ArgumentPurpose, Block, Constant, DynamicStackSlot, FuncRef, Function, GlobalValue,
-    Inst, JumpTable, MemFlags, MemoryTypeData, Opcode, SigRef, StackSlot, Type, Value, ValueDef,
-    ValueList,
+    types, ArgumentPurpose, AtomicOrdering, Block, Constant, DynamicStackSlot, FuncRef, Function,
+    GlobalValue, Inst, JumpTable, MemFlags, MemoryTypeData, Opcode, SigRef, StackSlot, Type, Value,
+    ValueDef, ValueList,
 };
 use crate::isa::TargetIsa;
 use crate::iterators::IteratorExtras;
@@ -589,6 +589,27 @@ impl<'a> Verifier<'a> {
             ));
         }
 
+        // Only calls which return to their caller can have call flags.
+        if !dfg.call_flags[inst].is_empty()
+            && !matches!(inst_data.opcode(), Opcode::Call | Opcode::CallIndirect)
+        {
+            return errors.fatal((
+                inst,
+                self.context(inst),
+                "call flags are only allowed on `call` and `call_indirect`",
+            ));
+        }
+
+        if dfg.branch_hints[inst].is_some()
+            && !matches!(inst_data.opcode(), Opcode::Brif | Opcode::BrTable)
+        {
+            return errors.fatal((
+                inst,
+                self.context(inst),
+                "branch hints are only allowed on `brif` and `br_table`",
+            ));
+        }
+
         self.verify_entity_references(inst, errors)
     }
 
@@ -1643,6 +1664,29 @@ impl<'a> Verifier<'a> {
         errors.as_result()
     }
 
+    /// Check that the `relaxed`, `acquire` and `release` memory flags are only
+    /// used on atomic instructions, and only on the ones they make sense for.
+    fn verify_atomic_ordering(
+        &self,
+        inst: Inst,
+        opcode: Opcode,
+        flags: MemFlags,
+        errors: &mut VerifierErrors,
+    ) -> VerifierStepResult<()> {
+        let message = match (opcode, flags.atomic_ordering()) {
+            (_, AtomicOrdering::SeqCst)
+            | (Opcode::AtomicRmw | Opcode::AtomicCas, _)
+            | (Opcode::AtomicLoad, AtomicOrdering::Relaxed | AtomicOrdering::Acquire)
+            | (Opcode::AtomicStore, AtomicOrdering::Relaxed | AtomicOrdering::Release) => {
+                return Ok(())
+            }
+            (Opcode::AtomicLoad, _) => "An atomic load cannot have the `release` MemFlag",
+            (Opcode::AtomicStore, _) => "An atomic store cannot have the `acquire` MemFlag",
+            _ => "Only atomic instructions can have an atomic ordering MemFlag",
+        };
+        errors.fatal((inst, self.context(inst), message))
+    }
+
     fn immediate_constraints(
         &self,
         inst: Inst,
@@ -1650,6 +1694,10 @@ impl<'a> Verifier<'a> {
     ) -> VerifierStepResult<()> {
         let inst_data = &self.func.dfg.insts[inst];
 
+        if let Some(flags) = inst_data.memflags() {
+            self.verify_atomic_ordering(inst, inst_data.opcode(), flags, errors)?;
+        }
+
         match *inst_data {
             ir::InstructionData::Store { flags, .. } => {
                 if flags.readonly() {
@@ -391,6 +391,14 @@ fn write_instruction(
     Ok(())
 }
 
+/// Write the branch hint of `inst`, if any, to `w` with a prepended space.
+fn write_branch_hint(w: &mut dyn Write, dfg: &DataFlowGraph, inst: Inst) -> fmt::Result {
+    match dfg.branch_hints[inst] {
+        Some(hint) => write!(w, " {}", hint),
+        None => Ok(()),
+    }
+}
+
 /// Write the operands of `inst` to `w` with a prepended space.
 pub fn write_operands(w: &mut dyn Write, dfg: &DataFlowGraph, inst: Inst) -> fmt::Result {
     let pool = &dfg.value_lists;
@@ -433,6 +441,7 @@ pub fn write_operands(w: &mut dyn Write, dfg: &DataFlowGraph, inst: Inst) -> fmt
         IntAddTrap { args, code, .. } => write!(w, " {}, {}, {}", args[0], args[1], code),
         FloatCompare { cond, args, .. } => write!(w, " {} {}, {}", cond, args[0], args[1]),
         Jump { destination, .. } => {
+            write_branch_hint(w, dfg, inst)?;
             write!(w, " {}", destination.display(pool))
         }
         Brif {
@@ -440,22 +449,31 @@ pub fn write_operands(w: &mut dyn Write, dfg: &DataFlowGraph, inst: Inst) -> fmt
             blocks: [block_then, block_else],
             ..
         } => {
+            write_branch_hint(w, dfg, inst)?;
             write!(w, " {}, {}", arg, block_then.display(pool))?;
             write!(w, ", {}", block_else.display(pool))
         }
         BranchTable { arg, table, .. } => {
+            write_branch_hint(w, dfg, inst)?;
             write!(w, " {}, {}", arg, jump_tables[table].display(pool))
         }
         Call {
             func_ref, ref args, ..
-        } => write!(w, " {}({})", func_ref, DisplayValues(args.as_slice(pool))),
+        } => write!(
+            w,
+            "{} {}({})",
+            dfg.call_flags[inst],
+            func_ref,
+            DisplayValues(args.as_slice(pool))
+        ),
         CallIndirect {
             sig_ref, ref args, ..
         } => {
             let args = args.as_slice(pool);
             write!(
                 w,
-                " {}, {}({})",
+                "{} {}, {}({})",
+                dfg.call_flags[inst],
                 sig_ref,
                 args[0],
                 DisplayValues(&args[1..])
@@ -0,0 +1,59 @@
+;;! target = "x86_64"
+;;! compile = true
+;;! settings = ["enable_table_access_spectre_mitigation=false"]
+
+(module
+  (table 1 externref)
+
+  (func (param i32) (result externref)
+    local.get 0
+    table.get 0)
+
+  (func (param i32 externref)
+    local.get 0
+    local.get 1
+    table.set 0)
+)
+;; function u0:0:
+;;   pushq   %rbp
+;;   unwind PushFrameRegs { offset_upward_to_caller_sp: 16 }
+;;   movq    %rsp, %rbp
+;;   unwind DefineNewFrame { offset_upward_to_caller_sp: 16, offset_downward_to_clobbers: 0 }
+;; block0:
+;;   movl    0(%rsi), %r10d
+;;   cmpl    %r10d, %edi
+;;   jnb     label3; j label1
+;; block1:
+;;   movl    %edi, %r10d
+;;   movq    0(%rsi), %rsi
+;;   shlq    $4, %r10, %r10
+;;   movq    0(%rsi,%r10,1), %rax
+;;   jmp     label2
+;; block2:
+;;   movq    %rbp, %rsp
+;;   popq    %rbp
+;;   ret
+;; block3:
+;;   ud2 table_oob
+;;
+;; function u0:1:
+;;   pushq   %rbp
+;;   unwind PushFrameRegs { offset_upward_to_caller_sp: 16 }
+;;   movq    %rsp, %rbp
+;;   unwind DefineNewFrame { offset_upward_to_caller_sp: 16, offset_downward_to_clobbers: 0 }
+;; block0:
+;;   movl    0(%rdx), %r10d
+;;   cmpl    %r10d, %edi
+;;   jnb     label3; j label1
+;; block1:
+;;   movl    %edi, %r10d
+;;   movq    0(%rdx), %rdi
+;;   shlq    $4, %r10, %r10
+;;   movq    %rsi, 0(%rdi,%r10,1)
+;;   jmp     label2
+;; block2:
+;;   movq    %rbp, %rsp
+;;   popq    %rbp
+;;   ret
+;; block3:
+;;   ud2 table_oob
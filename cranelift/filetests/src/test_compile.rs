@@ -72,6 +72,15 @@ impl SubTest for TestCompile {
 
         info!("Generated {} bytes of code:\n{}", total_size, vcode);
 
+        // Check the emitted machine code against the printed instructions,
+        // on the architectures whose disassembly can be compared.
+        if let Some(syntax) = isa.disas_syntax() {
+            let cs = isa
+                .to_capstone()
+                .map_err(|e| anyhow::format_err!("{}", e))?;
+            compiled_code.check_encodings(&cs, syntax)?;
+        }
+
         if self.precise_output {
             let cs = isa
                 .to_capstone()
@@ -8,18 +8,19 @@ use std::collections::{BTreeMap, HashSet};
 use super::config::TestConfig;
 use cranelift::prelude::EntityRef;
 use cranelift_codegen::{
-    ir,
+    ir::{self, InstBuilder},
     isa::{TargetFrontendConfig, TargetIsa},
 };
 use cranelift_wasm::{
-    DummyEnvironment, FuncEnvironment, FuncIndex, ModuleEnvironment, TargetEnvironment,
-    TypeConvert, TypeIndex, WasmHeapType,
+    prepare_table_addr, DummyEnvironment, FuncEnvironment, FuncIndex, ModuleEnvironment,
+    TargetEnvironment, TypeConvert, TypeIndex, WasmHeapType,
 };
 
 pub struct ModuleEnv {
     pub inner: DummyEnvironment,
     pub config: TestConfig,
     pub heap_access_spectre_mitigation: bool,
+    pub table_access_spectre_mitigation: bool,
     pub proof_carrying_code: bool,
 }
 
@@ -32,6 +33,9 @@ impl ModuleEnv {
             heap_access_spectre_mitigation: target_isa
                 .flags()
                 .enable_heap_access_spectre_mitigation(),
+            table_access_spectre_mitigation: target_isa
+                .flags()
+                .enable_table_access_spectre_mitigation(),
             proof_carrying_code: target_isa.flags().enable_pcc(),
         }
     }
@@ -53,6 +57,7 @@ impl<'data> ModuleEnvironment<'data> for ModuleEnv {
                 self.inner.expected_reachability.clone(),
                 self.config.clone(),
                 self.heap_access_spectre_mitigation,
+                self.table_access_spectre_mitigation,
                 self.proof_carrying_code,
             );
             let func_index = FuncIndex::new(
@@ -247,6 +252,7 @@ pub struct FuncEnv<'a> {
     pub name_to_ir_global: BTreeMap<String, ir::GlobalValue>,
     pub next_heap: usize,
     pub heap_access_spectre_mitigation: bool,
+    pub table_access_spectre_mitigation: bool,
     pub proof_carrying_code: bool,
 }
 
@@ -256,6 +262,7 @@ impl<'a> FuncEnv<'a> {
         expected_reachability: Option<cranelift_wasm::ExpectedReachability>,
         config: TestConfig,
         heap_access_spectre_mitigation: bool,
+        table_access_spectre_mitigation: bool,
         proof_carrying_code: bool,
     ) -> Self {
         let inner = cranelift_wasm::DummyFuncEnvironment::new(mod_info, expected_reachability);
@@ -265,6 +272,7 @@ impl<'a> FuncEnv<'a> {
             name_to_ir_global: Default::default(),
             next_heap: 0,
             heap_access_spectre_mitigation,
+            table_access_spectre_mitigation,
             proof_carrying_code,
         }
     }
@@ -285,6 +293,10 @@ impl<'a> TargetEnvironment for FuncEnv<'a> {
         self.heap_access_spectre_mitigation
     }
 
+    fn table_access_spectre_mitigation(&self) -> bool {
+        self.table_access_spectre_mitigation
+    }
+
     fn proof_carrying_code(&self) -> bool {
         self.proof_carrying_code
     }
@@ -535,20 +547,28 @@ impl<'a> FuncEnvironment for FuncEnv<'a> {
         table: ir::Table,
         index: ir::Value,
     ) -> cranelift_wasm::WasmResult<ir::Value> {
-        self.inner
-            .translate_table_get(builder, table_index, table, index)
+        let heap_type = self.inner.mod_info.tables[table_index]
+            .entity
+            .wasm_ty
+            .heap_type;
+        let ty = self.reference_type(heap_type);
+        let addr = prepare_table_addr(builder, self, table, index);
+        let flags = ir::MemFlags::trusted().with_table();
+        Ok(builder.ins().load(ty, flags, addr, 0))
     }
 
     fn translate_table_set(
         &mut self,
         builder: &mut cranelift_frontend::FunctionBuilder,
-        table_index: cranelift_wasm::TableIndex,
+        _table_index: cranelift_wasm::TableIndex,
         table: ir::Table,
         value: ir::Value,
         index: ir::Value,
     ) -> cranelift_wasm::WasmResult<()> {
-        self.inner
-            .translate_table_set(builder, table_index, table, value, index)
+        let addr = prepare_table_addr(builder, self, table, index);
+        let flags = ir::MemFlags::trusted().with_table();
+        builder.ins().store(flags, value, addr, 0);
+        Ok(())
     }
 
     fn translate_table_copy(
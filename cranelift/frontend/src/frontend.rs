@@ -1,6 +1,7 @@
 //! A frontend for building Cranelift IR from other languages.
 use crate::ssa::{SSABuilder, SideEffects};
 use crate::variable::Variable;
+use alloc::string::String;
 use core::fmt::{self, Debug};
 use cranelift_codegen::cursor::{Cursor, FuncCursor};
 use cranelift_codegen::entity::{EntityRef, EntitySet, SecondaryMap};
@@ -26,6 +27,8 @@ pub struct FunctionBuilderContext {
     ssa: SSABuilder,
     status: SecondaryMap<Block, BlockStatus>,
     types: SecondaryMap<Variable, Type>,
+    var_labels: SecondaryMap<Variable, PackedOption<ValueLabel>>,
+    var_names: SecondaryMap<Variable, Option<String>>,
 }
 
 /// Temporary object used to build a single Cranelift IR `Function`.
@@ -63,10 +66,16 @@ impl FunctionBuilderContext {
         self.ssa.clear();
         self.status.clear();
         self.types.clear();
+        self.var_labels.clear();
+        self.var_names.clear();
     }
 
     fn is_empty(&self) -> bool {
-        self.ssa.is_empty() && self.status.is_empty() && self.types.is_empty()
+        self.ssa.is_empty()
+            && self.status.is_empty()
+            && self.types.is_empty()
+            && self.var_labels.is_empty()
+            && self.var_names.is_empty()
     }
 }
 
@@ -255,6 +264,20 @@ impl fmt::Display for DefVariableError {
     }
 }
 
+/// Debug information about a user variable which has a value label, as returned by
+/// [`FunctionBuilder::var_debug_info`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct VariableDebugInfo<'a> {
+    /// The variable.
+    pub var: Variable,
+    /// The label attached to the values of the variable.
+    pub label: ValueLabel,
+    /// The name given to the variable with [`FunctionBuilder::set_var_name`], if any.
+    pub name: Option<&'a str>,
+    /// The declared type of the variable.
+    pub ty: Type,
+}
+
 /// This module allows you to create a function in Cranelift IR in a straightforward way, hiding
 /// all the complexity of its internal representation.
 ///
@@ -311,6 +334,11 @@ impl<'a> FunctionBuilder<'a> {
         self.srcloc = srcloc;
     }
 
+    /// Get the source location that is assigned to new instructions.
+    pub fn srcloc(&self) -> ir::SourceLoc {
+        self.srcloc
+    }
+
     /// Creates a new `Block` and returns its reference.
     pub fn create_block(&mut self) -> Block {
         let block = self.func.dfg.make_block();
@@ -422,6 +450,9 @@ impl<'a> FunctionBuilder<'a> {
                 .use_var(self.func, var, ty, self.position.unwrap())
         };
         self.handle_ssa_side_effects(side_effects);
+        if let Some(label) = self.func_ctx.var_labels[var].expand() {
+            self.set_val_label(val, label);
+        }
         Ok(val)
     }
 
@@ -450,6 +481,9 @@ impl<'a> FunctionBuilder<'a> {
         }
 
         self.func_ctx.ssa.def_var(var, val, self.position.unwrap());
+        if let Some(label) = self.func_ctx.var_labels[var].expand() {
+            self.set_val_label(val, label);
+        }
         Ok(())
     }
 
@@ -497,6 +531,44 @@ impl<'a> FunctionBuilder<'a> {
         }
     }
 
+    /// Attaches `label` to the values of a user variable.
+    ///
+    /// From now on, every value passed to [`FunctionBuilder::def_var`] or returned by
+    /// [`FunctionBuilder::use_var`] for `var` is labeled with `label` through
+    /// [`FunctionBuilder::set_val_label`], so that debug info can track where the variable
+    /// lives without the frontend labeling each value itself.
+    pub fn set_var_label(&mut self, var: Variable, label: ValueLabel) {
+        self.func_ctx.var_labels[var] = label.into();
+    }
+
+    /// Gives a user variable a name, reported along with its label by
+    /// [`FunctionBuilder::var_debug_info`].
+    pub fn set_var_name(&mut self, var: Variable, name: impl Into<String>) {
+        self.func_ctx.var_names[var] = Some(name.into());
+    }
+
+    /// Returns the label, name and type of every declared variable which has a label.
+    ///
+    /// The builder forgets about variables once it's finalized, so frontends producing debug
+    /// info from this have to call it before [`FunctionBuilder::finalize`].
+    pub fn var_debug_info(&self) -> impl Iterator<Item = VariableDebugInfo<'_>> + '_ {
+        self.func_ctx
+            .var_labels
+            .iter()
+            .filter_map(move |(var, label)| {
+                let ty = *self.func_ctx.types.get(var)?;
+                if ty == types::INVALID {
+                    return None;
+                }
+                Some(VariableDebugInfo {
+                    var,
+                    label: label.expand()?,
+                    name: self.func_ctx.var_names.get(var).and_then(|n| n.as_deref()),
+                    ty,
+                })
+            })
+    }
+
     /// Creates a jump table in the function, to be used by `br_table` instructions.
     pub fn create_jump_table(&mut self, data: JumpTableData) -> JumpTable {
         self.func.create_jump_table(data)
@@ -1111,14 +1183,18 @@ mod tests {
     use super::greatest_divisible_power_of_two;
     use crate::frontend::{
         DeclareVariableError, DefVariableError, FunctionBuilder, FunctionBuilderContext,
-        UseVariableError,
+        UseVariableError, VariableDebugInfo,
     };
     use crate::Variable;
     use alloc::string::ToString;
+    use alloc::vec::Vec;
     use cranelift_codegen::entity::EntityRef;
     use cranelift_codegen::ir::condcodes::IntCC;
     use cranelift_codegen::ir::{types::*, UserFuncName};
-    use cranelift_codegen::ir::{AbiParam, Function, InstBuilder, MemFlags, Signature, Value};
+    use cranelift_codegen::ir::{
+        AbiParam, Function, InstBuilder, MemFlags, Signature, Value, ValueLabel,
+        ValueLabelAssignments,
+    };
     use cranelift_codegen::isa::{CallConv, TargetFrontendConfig, TargetIsa};
     use cranelift_codegen::settings;
     use cranelift_codegen::verifier::verify_function;
@@ -1851,4 +1927,62 @@ block0:
             );
         }
     }
+    #[test]
+    fn var_labels() {
+        let mut sig = Signature::new(CallConv::SystemV);
+        sig.params.push(AbiParam::new(I32));
+        sig.returns.push(AbiParam::new(I32));
+
+        let mut fn_ctx = FunctionBuilderContext::new();
+        let mut func = Function::with_name_signature(UserFuncName::testcase("sample"), sig);
+        func.dfg.collect_debug_info();
+        let (x, y) = (Variable::new(0), Variable::new(1));
+        let (param, sum, used) = {
+            let mut builder = FunctionBuilder::new(&mut func, &mut fn_ctx);
+            builder.declare_var(x, I32);
+            builder.declare_var(y, I32);
+            builder.set_var_label(x, ValueLabel::new(7));
+            builder.set_var_name(x, "x");
+
+            let block0 = builder.create_block();
+            let block1 = builder.create_block();
+            builder.append_block_params_for_function_params(block0);
+            builder.switch_to_block(block0);
+            builder.seal_block(block0);
+            let param = builder.block_params(block0)[0];
+            builder.def_var(x, param);
+            let sum = builder.ins().iadd(param, param);
+            builder.def_var(y, sum);
+            builder.ins().jump(block1, &[]);
+
+            builder.switch_to_block(block1);
+            builder.seal_block(block1);
+            let used = builder.use_var(x);
+            builder.ins().return_(&[used]);
+
+            assert_eq!(
+                builder.var_debug_info().collect::<Vec<_>>(),
+                [VariableDebugInfo {
+                    var: x,
+                    label: ValueLabel::new(7),
+                    name: Some("x"),
+                    ty: I32,
+                }]
+            );
+            builder.finalize();
+            (param, sum, used)
+        };
+        assert!(fn_ctx.is_empty());
+
+        let labels = func.dfg.values_labels.as_ref().unwrap();
+        for val in [param, used] {
+            match &labels[&val] {
+                ValueLabelAssignments::Starts(starts) => {
+                    assert!(starts.iter().all(|s| s.label == ValueLabel::new(7)));
+                }
+                _ => panic!("unexpected value label assignment"),
+            }
+        }
+        assert!(!labels.contains_key(&sum));
+    }
 }
@@ -178,7 +178,9 @@ use hashbrown::HashMap;
 #[cfg(feature = "std")]
 use std::collections::HashMap;
 
-pub use crate::frontend::{FuncInstBuilder, FunctionBuilder, FunctionBuilderContext};
+pub use crate::frontend::{
+    FuncInstBuilder, FunctionBuilder, FunctionBuilderContext, VariableDebugInfo,
+};
 pub use crate::switch::Switch;
 pub use crate::variable::Variable;
 
@@ -2,14 +2,27 @@ use super::HashMap;
 use crate::frontend::FunctionBuilder;
 use alloc::vec::Vec;
 use core::convert::TryFrom;
+use core::ops::RangeInclusive;
 use cranelift_codegen::ir::condcodes::IntCC;
 use cranelift_codegen::ir::*;
 
 type EntryIndex = u128;
 
+/// The minimum number of sparse cases for which a perfect hash is attempted
+/// instead of a binary search.
+const PERFECT_HASH_MIN_CASES: usize = 16;
+
+/// How many multipliers are tried when looking for a perfect hash.
+const PERFECT_HASH_ATTEMPTS: u32 = 512;
+
 /// Unlike with `br_table`, `Switch` cases may be sparse or non-0-based.
 /// They emit efficient code using branches, jump tables, or a combination of both.
 ///
+/// Runs of consecutive entries are dispatched with a jump table and entries
+/// set with [`Switch::set_range`] with a pair of bounds checks. These are then
+/// found with a binary search, or, when there are many entries and all of them
+/// are sparse, with a perfect hash of the value indexing a jump table.
+///
 /// # Example
 ///
 /// ```rust
@@ -29,6 +42,7 @@ type EntryIndex = u128;
 /// let block0 = builder.create_block();
 /// let block1 = builder.create_block();
 /// let block2 = builder.create_block();
+/// let block3 = builder.create_block();
 /// let fallback = builder.create_block();
 ///
 /// let val = builder.ins().iconst(I32, 1);
@@ -37,11 +51,13 @@ type EntryIndex = u128;
 /// switch.set_entry(0, block0);
 /// switch.set_entry(1, block1);
 /// switch.set_entry(7, block2);
+/// switch.set_range(100..=199, block3);
 /// switch.emit(&mut builder, val, fallback);
 /// ```
 #[derive(Debug, Default)]
 pub struct Switch {
     cases: HashMap<EntryIndex, Block>,
+    ranges: Vec<(RangeInclusive<EntryIndex>, Block)>,
 }
 
 impl Switch {
@@ -49,11 +65,17 @@ impl Switch {
     pub fn new() -> Self {
         Self {
             cases: HashMap::new(),
+            ranges: Vec::new(),
         }
     }
 
     /// Set a switch entry
     pub fn set_entry(&mut self, index: EntryIndex, block: Block) {
+        assert!(
+            !self.ranges.iter().any(|(range, _)| range.contains(&index)),
+            "Tried to set the same entry {} twice",
+            index
+        );
         let prev = self.cases.insert(index, block);
         assert!(
             prev.is_none(),
@@ -62,41 +84,81 @@ impl Switch {
         );
     }
 
+    /// Set a switch entry for every index in `range`.
+    ///
+    /// Unlike setting each entry individually, this doesn't grow any jump
+    /// table, so `range` may be arbitrarily wide.
+    pub fn set_range(&mut self, range: RangeInclusive<EntryIndex>, block: Block) {
+        let (first, last) = (*range.start(), *range.end());
+        assert!(first <= last, "Tried to set the empty range {:?}", range);
+        if first == last {
+            return self.set_entry(first, block);
+        }
+        if let Some(index) = self.cases.keys().find(|index| range.contains(index)) {
+            panic!("Tried to set the same entry {} twice", index);
+        }
+        if let Some((other, _)) = self
+            .ranges
+            .iter()
+            .find(|(other, _)| first <= *other.end() && *other.start() <= last)
+        {
+            panic!(
+                "Tried to set the overlapping ranges {:?} and {:?}",
+                other, range
+            );
+        }
+        self.ranges.push((range, block));
+    }
+
     /// Get a reference to all existing entries
+    ///
+    /// This doesn't include the entries set with [`Switch::set_range`].
     pub fn entries(&self) -> &HashMap<EntryIndex, Block> {
         &self.cases
     }
 
-    /// Turn the `cases` `HashMap` into a list of `ContiguousCaseRange`s.
+    /// Turn the `cases` `HashMap` and `ranges` into a list of
+    /// `ContiguousCaseRange`s.
     ///
     /// # Postconditions
     ///
     /// * Every entry will be represented.
-    /// * The `ContiguousCaseRange`s will not overlap.
-    /// * Between two `ContiguousCaseRange`s there will be at least one entry index.
+    /// * The `ContiguousCaseRange`s will not overlap and are sorted.
+    /// * Between two `ContiguousCaseRange`s there will be at least one entry
+    ///   index, unless one of them comes from `ranges`.
     /// * No `ContiguousCaseRange`s will be empty.
     fn collect_contiguous_case_ranges(self) -> Vec<ContiguousCaseRange> {
-        log::trace!("build_contiguous_case_ranges before: {:#?}", self.cases);
-        let mut cases = self.cases.into_iter().collect::<Vec<(_, _)>>();
-        cases.sort_by_key(|&(index, _)| index);
+        log::trace!(
+            "build_contiguous_case_ranges before: {:#?} {:#?}",
+            self.cases,
+            self.ranges
+        );
+        let mut cases = self
+            .cases
+            .into_iter()
+            .map(|(index, block)| (index..=index, block))
+            .chain(self.ranges)
+            .collect::<Vec<(_, _)>>();
+        cases.sort_by_key(|(range, _)| *range.start());
 
         let mut contiguous_case_ranges: Vec<ContiguousCaseRange> = vec![];
-        let mut last_index = None;
-        for (index, block) in cases {
-            match last_index {
-                None => contiguous_case_ranges.push(ContiguousCaseRange::new(index)),
-                Some(last_index) => {
-                    if index > last_index + 1 {
-                        contiguous_case_ranges.push(ContiguousCaseRange::new(index));
-                    }
+        for (range, block) in cases {
+            let (first, last) = range.into_inner();
+            match contiguous_case_ranges.last_mut() {
+                Some(prev)
+                    if first == last
+                        && !prev.is_range()
+                        && prev.last_index.checked_add(1) == Some(first) =>
+                {
+                    prev.last_index = first;
+                    prev.blocks.push(block);
                 }
+                _ => contiguous_case_ranges.push(ContiguousCaseRange {
+                    first_index: first,
+                    last_index: last,
+                    blocks: vec![block],
+                }),
             }
-            contiguous_case_ranges
-                .last_mut()
-                .unwrap()
-                .blocks
-                .push(block);
-            last_index = Some(index);
         }
 
         log::trace!(
@@ -175,13 +237,33 @@ impl Switch {
             if range.first_index == 0 {
                 assert_eq!(alternate, otherwise);
 
-                if let Some(block) = range.single_block() {
+                if range.is_range() {
+                    let is_good_val =
+                        icmp_imm_u128(bx, IntCC::UnsignedLessThanOrEqual, val, range.last_index);
+                    bx.ins()
+                        .brif(is_good_val, range.blocks[0], &[], otherwise, &[]);
+                } else if let Some(block) = range.single_block() {
                     bx.ins().brif(val, otherwise, &[], block, &[]);
                 } else {
                     Self::build_jump_table(bx, val, otherwise, 0, &range.blocks);
                 }
             } else {
-                if let Some(block) = range.single_block() {
+                if range.is_range() {
+                    let is_good_val = icmp_imm_u128(
+                        bx,
+                        IntCC::UnsignedGreaterThanOrEqual,
+                        val,
+                        range.first_index,
+                    );
+                    let check_block = bx.create_block();
+                    bx.ins().brif(is_good_val, check_block, &[], alternate, &[]);
+                    bx.seal_block(check_block);
+                    bx.switch_to_block(check_block);
+                    let is_good_val =
+                        icmp_imm_u128(bx, IntCC::UnsignedLessThanOrEqual, val, range.last_index);
+                    bx.ins()
+                        .brif(is_good_val, range.blocks[0], &[], otherwise, &[]);
+                } else if let Some(block) = range.single_block() {
                     let is_good_val = icmp_imm_u128(bx, IntCC::Equal, val, range.first_index);
                     bx.ins().brif(is_good_val, block, &[], alternate, &[]);
                 } else {
@@ -265,6 +347,74 @@ impl Switch {
         bx.ins().br_table(discr, jump_table);
     }
 
+    /// Dispatches sparse single-entry cases with a multiplicative perfect hash
+    /// of `val` indexing a jump table, whose targets check that `val` is the
+    /// entry hashed to them.
+    ///
+    /// Returns `false`, without emitting anything, if the cases aren't suited
+    /// to hashing or no perfect hash was found.
+    fn build_perfect_hash(
+        bx: &mut FunctionBuilder,
+        val: Value,
+        otherwise: Block,
+        contiguous_case_ranges: &[ContiguousCaseRange],
+    ) -> bool {
+        if contiguous_case_ranges.len() < PERFECT_HASH_MIN_CASES
+            || bx.func.dfg.value_type(val).bits() > 64
+            || contiguous_case_ranges
+                .iter()
+                .any(|range| range.single_block().is_none() || range.is_range())
+        {
+            return false;
+        }
+        let keys = contiguous_case_ranges
+            .iter()
+            .map(|range| range.first_index as u64)
+            .collect::<Vec<_>>();
+        let (mul, bits) = match find_perfect_hash(&keys) {
+            Some(hash) => hash,
+            None => return false,
+        };
+        log::trace!(
+            "perfect hash for {} keys: {:#x} >> {}",
+            keys.len(),
+            mul,
+            64 - bits
+        );
+
+        // Each slot needs its own block call as the SSA builder may append
+        // arguments to them.
+        let mut slots = (0..1 << bits)
+            .map(|_| bx.func.dfg.block_call(otherwise, &[]))
+            .collect::<Vec<_>>();
+        let mut checks = Vec::with_capacity(keys.len());
+        for (range, &key) in contiguous_case_ranges.iter().zip(&keys) {
+            let check_block = bx.create_block();
+            slots[perfect_hash_slot(key, mul, bits)] = bx.func.dfg.block_call(check_block, &[]);
+            checks.push((check_block, range.first_index, range.blocks[0]));
+        }
+        let jt_data = JumpTableData::new(bx.func.dfg.block_call(otherwise, &[]), &slots);
+        let jump_table = bx.create_jump_table(jt_data);
+
+        let wide = if bx.func.dfg.value_type(val) == types::I64 {
+            val
+        } else {
+            bx.ins().uextend(types::I64, val)
+        };
+        let hash = bx.ins().imul_imm(wide, mul as i64);
+        let hash = bx.ins().ushr_imm(hash, i64::from(64 - bits));
+        let slot = bx.ins().ireduce(types::I32, hash);
+        bx.ins().br_table(slot, jump_table);
+
+        for (check_block, index, block) in checks {
+            bx.seal_block(check_block);
+            bx.switch_to_block(check_block);
+            let is_good_val = icmp_imm_u128(bx, IntCC::Equal, val, index);
+            bx.ins().brif(is_good_val, block, &[], otherwise, &[]);
+        }
+        true
+    }
+
     /// Build the switch
     ///
     /// # Arguments
@@ -274,7 +424,13 @@ impl Switch {
     /// * The default block
     pub fn emit(self, bx: &mut FunctionBuilder, val: Value, otherwise: Block) {
         // Validate that the type of `val` is sufficiently wide to address all cases.
-        let max = self.cases.keys().max().copied().unwrap_or(0);
+        let max = self
+            .cases
+            .keys()
+            .copied()
+            .chain(self.ranges.iter().map(|(range, _)| *range.end()))
+            .max()
+            .unwrap_or(0);
         let val_ty = bx.func.dfg.value_type(val);
         let val_ty_max = val_ty.bounds(false).1;
         if max > val_ty_max {
@@ -285,10 +441,45 @@ impl Switch {
         }
 
         let contiguous_case_ranges = self.collect_contiguous_case_ranges();
-        Self::build_search_tree(bx, val, otherwise, &contiguous_case_ranges);
+        if !Self::build_perfect_hash(bx, val, otherwise, &contiguous_case_ranges) {
+            Self::build_search_tree(bx, val, otherwise, &contiguous_case_ranges);
+        }
     }
 }
 
+/// Looks for a multiplier which maps every key to a different slot of a table
+/// with `2^bits` slots, as computed by `perfect_hash_slot`.
+///
+/// The table has room for at least four times as many slots as there are
+/// keys, which makes collisions rare enough for a few random multipliers to
+/// usually find a perfect hash of a few dozen keys.
+fn find_perfect_hash(keys: &[u64]) -> Option<(u64, u32)> {
+    let bits = (keys.len() * 4).next_power_of_two().trailing_zeros();
+    let mut used = vec![false; 1 << bits];
+    // The multipliers are odd numbers from a fixed LCG so that the emitted
+    // code is deterministic.
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    for _ in 0..PERFECT_HASH_ATTEMPTS {
+        let mul = state | 1;
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        used.iter_mut().for_each(|slot| *slot = false);
+        let perfect = keys.iter().all(|&key| {
+            let slot = &mut used[perfect_hash_slot(key, mul, bits)];
+            !core::mem::replace(slot, true)
+        });
+        if perfect {
+            return Some((mul, bits));
+        }
+    }
+    None
+}
+
+fn perfect_hash_slot(key: u64, mul: u64, bits: u32) -> usize {
+    (key.wrapping_mul(mul) >> (64 - bits)) as usize
+}
+
 fn icmp_imm_u128(bx: &mut FunctionBuilder, cond: IntCC, x: Value, y: u128) -> Value {
     if let Ok(index) = u64::try_from(y) {
         bx.ins().icmp_imm(cond, x, index as i64)
@@ -308,24 +499,30 @@ fn icmp_imm_u128(bx: &mut FunctionBuilder, cond: IntCC, x: Value, y: u128) -> Va
 /// ```plain
 /// ContiguousCaseRange {
 ///     first_index: 10,
+///     last_index: 12,
 ///     blocks: vec![Block::from_u32(1), Block::from_u32(2), Block::from_u32(7)]
 /// }
 /// ```
+///
+/// A range set with `Switch::set_range` has a single block for all of its
+/// entries instead.
 #[derive(Debug)]
 struct ContiguousCaseRange {
     /// The entry index of the first case. Eg. 10 when the entry indexes are 10, 11, 12 and 13.
     first_index: EntryIndex,
 
+    /// The entry index of the last case. Eg. 13 when the entry indexes are 10, 11, 12 and 13.
+    last_index: EntryIndex,
+
     /// The blocks to jump to sorted in ascending order of entry index.
     blocks: Vec<Block>,
 }
 
 impl ContiguousCaseRange {
-    fn new(first_index: EntryIndex) -> Self {
-        Self {
-            first_index,
-            blocks: Vec::new(),
-        }
+    /// Returns whether this is a range of several entries all jumping to the
+    /// same block.
+    fn is_range(&self) -> bool {
+        self.first_index != self.last_index && self.blocks.len() == 1
     }
 
     /// Returns `Some` block when there is only a single block in this range.
@@ -477,6 +674,171 @@ block12:
         );
     }
 
+    macro_rules! setup_ranges {
+        ($default:expr, [$($index:expr,)*], [$($range:expr,)*]) => {{
+            let mut func = Function::new();
+            let mut func_ctx = FunctionBuilderContext::new();
+            {
+                let mut bx = FunctionBuilder::new(&mut func, &mut func_ctx);
+                let block = bx.create_block();
+                bx.switch_to_block(block);
+                let val = bx.ins().iconst(types::I32, 0);
+                let mut switch = Switch::new();
+                $(
+                    let block = bx.create_block();
+                    switch.set_entry($index, block);
+                )*
+                $(
+                    let block = bx.create_block();
+                    switch.set_range($range, block);
+                )*
+                switch.emit(&mut bx, val, Block::with_number($default).unwrap());
+            }
+            func
+                .to_string()
+                .trim_start_matches("function u0:0() fast {\n")
+                .trim_end_matches("\n}\n")
+                .to_string()
+        }};
+    }
+
+    #[test]
+    fn switch_range() {
+        let func = setup_ranges!(0, [], [10..=1000,]);
+        assert_eq_output!(
+            func,
+            "block0:
+    v0 = iconst.i32 0
+    v1 = icmp_imm uge v0, 10  ; v0 = 0
+    brif v1, block2, block0
+
+block2:
+    v2 = icmp_imm.i32 ule v0, 1000  ; v0 = 0
+    brif v2, block1, block0"
+        );
+    }
+
+    #[test]
+    fn switch_range_from_zero() {
+        let func = setup_ranges!(0, [1001, 1002,], [0..=1000,]);
+        assert_eq_output!(
+            func,
+            "block0:
+    v0 = iconst.i32 0
+    v1 = icmp_imm uge v0, 1001  ; v0 = 0
+    brif v1, block5, block4
+
+block5:
+    v2 = iadd_imm.i32 v0, -1001  ; v0 = 0
+    br_table v2, block0, [block1, block2]
+
+block4:
+    v3 = icmp_imm.i32 ule v0, 1000  ; v0 = 0
+    brif v3, block3, block0"
+        );
+    }
+
+    #[test]
+    fn switch_ranges_and_entries() {
+        let func = setup_ranges!(0, [5, 6, 7, 300,], [8..=99, 100..=199,]);
+        assert_eq_output!(
+            func,
+            "block0:
+    v0 = iconst.i32 0
+    v1 = icmp_imm uge v0, 100  ; v0 = 0
+    brif v1, block8, block7
+
+block8:
+    v2 = icmp_imm.i32 eq v0, 300  ; v0 = 0
+    brif v2, block4, block9
+
+block9:
+    v3 = icmp_imm.i32 uge v0, 100  ; v0 = 0
+    brif v3, block10, block0
+
+block10:
+    v4 = icmp_imm.i32 ule v0, 199  ; v0 = 0
+    brif v4, block6, block0
+
+block7:
+    v5 = icmp_imm.i32 uge v0, 8  ; v0 = 0
+    brif v5, block12, block11
+
+block12:
+    v6 = icmp_imm.i32 ule v0, 99  ; v0 = 0
+    brif v6, block5, block0
+
+block11:
+    v7 = icmp_imm.i32 uge v0, 5  ; v0 = 0
+    brif v7, block13, block0
+
+block13:
+    v8 = iadd_imm.i32 v0, -5  ; v0 = 0
+    br_table v8, block0, [block1, block2, block3]"
+        );
+    }
+
+    #[test]
+    fn switch_perfect_hash() {
+        let keys = (0..PERFECT_HASH_MIN_CASES as u128)
+            .map(|i| i * i * 1000 + 3)
+            .collect::<Vec<_>>();
+        let mut func = Function::new();
+        let mut func_ctx = FunctionBuilderContext::new();
+        {
+            let mut bx = FunctionBuilder::new(&mut func, &mut func_ctx);
+            let block0 = bx.create_block();
+            bx.switch_to_block(block0);
+            let val = bx.ins().iconst(types::I32, 0);
+            let mut switch = Switch::new();
+            for &key in &keys {
+                let block = bx.create_block();
+                switch.set_entry(key, block);
+            }
+            let otherwise = bx.create_block();
+            switch.emit(&mut bx, val, otherwise);
+        }
+        let func = func.to_string();
+        assert!(func.contains("uextend.i64 v0"), "{}", func);
+        assert!(func.contains("imul_imm"), "{}", func);
+        assert!(func.contains("ushr_imm"), "{}", func);
+        assert_eq!(func.matches("br_table").count(), 1, "{}", func);
+        // Every key is checked once after the hash.
+        assert_eq!(
+            func.matches("icmp_imm.i32 eq v0").count(),
+            keys.len(),
+            "{}",
+            func
+        );
+    }
+
+    #[test]
+    fn switch_finds_perfect_hashes() {
+        for n in [16, 32, 48] {
+            let keys = (0..n).map(|i| i * 0x1_0001 + 7).collect::<Vec<u64>>();
+            let (mul, bits) = find_perfect_hash(&keys).unwrap();
+            let mut slots = keys
+                .iter()
+                .map(|&key| perfect_hash_slot(key, mul, bits))
+                .collect::<Vec<_>>();
+            slots.sort();
+            slots.dedup();
+            assert_eq!(slots.len(), keys.len());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Tried to set the same entry 15 twice")]
+    fn switch_rejects_entry_in_range() {
+        setup_ranges!(0, [15,], [10..=20,]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Tried to set the overlapping ranges 10..=20 and 20..=30")]
+    fn switch_rejects_overlapping_ranges() {
+        setup_ranges!(0, [], [10..=20, 20..=30,]);
+    }
+
     #[test]
     fn switch_min_index_value() {
         let func = setup!(0, [i8::MIN as u8 as u128, 1,]);
@@ -539,7 +901,11 @@ block4:
 
     #[test]
     fn switch_seal_generated_blocks() {
-        let cases = &[vec![0, 1, 2], vec![0, 1, 2, 10, 11, 12, 20, 30, 40, 50]];
+        let cases = &[
+            vec![0, 1, 2],
+            vec![0, 1, 2, 10, 11, 12, 20, 30, 40, 50],
+            (0..20).map(|i| i * 3).collect(),
+        ];
 
         for case in cases {
             for typ in &[types::I8, types::I16, types::I32, types::I64, types::I128] {
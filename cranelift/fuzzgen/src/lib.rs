@@ -175,6 +175,9 @@ where
         let opt = self.u.choose(OptLevel::all())?;
         builder.set("opt_level", &format!("{}", opt)[..])?;
 
+        let regalloc_algorithm = *self.u.choose(&["backtracking", "single_pass"])?;
+        builder.set("regalloc_algorithm", regalloc_algorithm)?;
+
         // Boolean flags
         // TODO: enable_pinned_reg does not work with our current trampolines. See: #4376
         // TODO: is_pic has issues:
@@ -182,6 +185,7 @@ where
         //   aarch64: https://github.com/bytecodealliance/wasmtime/issues/2735
         let bool_settings = [
             "enable_alias_analysis",
+            "enable_instruction_scheduling",
             "enable_safepoints",
             "unwind_info",
             "preserve_frame_pointers",
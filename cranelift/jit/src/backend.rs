@@ -32,6 +32,7 @@ pub struct JITBuilder {
     lookup_symbols: Vec<Box<dyn Fn(&str) -> Option<*const u8>>>,
     libcall_names: Box<dyn Fn(ir::LibCall) -> String + Send + Sync>,
     hotswap_enabled: bool,
+    dual_mapped_code: bool,
 }
 
 impl JITBuilder {
@@ -96,6 +97,7 @@ impl JITBuilder {
             lookup_symbols,
             libcall_names,
             hotswap_enabled: false,
+            dual_mapped_code: false,
         }
     }
 
@@ -155,6 +157,24 @@ impl JITBuilder {
         self.hotswap_enabled = enabled;
         self
     }
+
+    /// Enable or disable dual-mapped code memory.
+    ///
+    /// By default code is written to memory that is later made executable by
+    /// changing its protection, which strict W^X policies forbid. With this
+    /// enabled, code memory is instead mapped twice on Linux: once readable
+    /// and executable and once writable, so no mapping ever changes its
+    /// protection. On macOS, `MAP_JIT` memory is used instead, whose
+    /// writability is toggled per thread; this requires the
+    /// `com.apple.security.cs.allow-jit` entitlement under the hardened
+    /// runtime, and means that code from this module must not run on the
+    /// module's thread between defining a function and calling
+    /// [`JITModule::finalize_definitions`]. Other platforms fail to allocate
+    /// code memory when this is enabled.
+    pub fn dual_mapped_code(&mut self, enabled: bool) -> &mut Self {
+        self.dual_mapped_code = enabled;
+        self
+    }
 }
 
 /// A pending update to the GOT.
@@ -259,8 +279,9 @@ impl JITModule {
             )
             .unwrap()
             .cast::<[u8; 16]>();
+        let plt_write_ptr = self.memory.code.write_view(plt_entry.cast()).cast();
         unsafe {
-            Self::write_plt_entry_bytes(plt_entry, got_entry);
+            Self::write_plt_entry_bytes(plt_entry, plt_write_ptr, got_entry);
         }
         NonNull::new(plt_entry).unwrap()
     }
@@ -285,7 +306,11 @@ impl JITModule {
         self.data_object_got_entries[id] = Some(got_entry);
     }
 
-    unsafe fn write_plt_entry_bytes(plt_ptr: *mut [u8; 16], got_ptr: NonNull<AtomicPtr<u8>>) {
+    unsafe fn write_plt_entry_bytes(
+        plt_ptr: *mut [u8; 16],
+        plt_write_ptr: *mut [u8; 16],
+        got_ptr: NonNull<AtomicPtr<u8>>,
+    ) {
         assert!(
             cfg!(target_arch = "x86_64"),
             "PLT is currently only supported on x86_64"
@@ -297,7 +322,7 @@ impl JITModule {
         let what = got_ptr.as_ptr() as isize - 4;
         let at = plt_ptr as isize + 2;
         plt_val[2..6].copy_from_slice(&i32::to_ne_bytes(i32::try_from(what - at).unwrap()));
-        std::ptr::write(plt_ptr, plt_val);
+        std::ptr::write(plt_write_ptr, plt_val);
     }
 
     fn get_address(&self, name: &ModuleRelocTarget) -> *const u8 {
@@ -342,6 +367,9 @@ impl JITModule {
                 self.lookup_symbol(&sym)
                     .unwrap_or_else(|| panic!("can't resolve libcall {}", sym))
             }
+            ModuleRelocTarget::KnownSymbol(ir::KnownSymbol::Aarch64HaveLseAtomics) => self
+                .lookup_symbol("__aarch64_have_lse_atomics")
+                .unwrap_or_else(aarch64_have_lse_atomics),
             _ => panic!("invalid name"),
         }
     }
@@ -512,7 +540,11 @@ impl JITModule {
             lookup_symbols: builder.lookup_symbols,
             libcall_names: builder.libcall_names,
             memory: MemoryHandle {
-                code: Memory::new(branch_protection),
+                code: if builder.dual_mapped_code {
+                    Memory::new_dual_mapped(branch_protection)
+                } else {
+                    Memory::new(branch_protection)
+                },
                 // Branch protection is not applicable to non-executable memory.
                 readonly: Memory::new(BranchProtection::None),
                 writable: Memory::new(BranchProtection::None),
@@ -703,8 +735,9 @@ impl Module for JITModule {
                 err: e,
             })?;
 
+        let write_ptr = self.memory.code.write_view(ptr);
         {
-            let mem = unsafe { std::slice::from_raw_parts_mut(ptr, size) };
+            let mem = unsafe { std::slice::from_raw_parts_mut(write_ptr, size) };
             mem.copy_from_slice(compiled_code.code_buffer());
         }
 
@@ -716,7 +749,12 @@ impl Module for JITModule {
             .collect();
 
         self.record_function_for_perf(ptr, size, &decl.linkage_name(id));
-        self.compiled_functions[id] = Some(CompiledBlob { ptr, size, relocs });
+        self.compiled_functions[id] = Some(CompiledBlob {
+            ptr,
+            write_ptr,
+            size,
+            relocs,
+        });
 
         if self.isa.flags().is_pic() {
             self.pending_got_updates.push(GotUpdate {
@@ -787,13 +825,15 @@ impl Module for JITModule {
                 err: e,
             })?;
 
+        let write_ptr = self.memory.code.write_view(ptr);
         unsafe {
-            ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, size);
+            ptr::copy_nonoverlapping(bytes.as_ptr(), write_ptr, size);
         }
 
         self.record_function_for_perf(ptr, size, &decl.linkage_name(id));
         self.compiled_functions[id] = Some(CompiledBlob {
             ptr,
+            write_ptr,
             size,
             relocs: relocs
                 .iter()
@@ -840,6 +880,14 @@ impl Module for JITModule {
 
         assert!(!decl.tls, "JIT doesn't yet support TLS");
 
+        // With hotswapping, function addresses resolve to PLT entries, which
+        // an addend would point into the middle of.
+        if self.hotswap_enabled && data.function_relocs.iter().any(|&(_, _, a)| a != 0) {
+            return Err(ModuleError::Backend(anyhow::anyhow!(
+                "function addresses with addends are not supported with hotswapping"
+            )));
+        }
+
         let &DataDescription {
             ref init,
             function_decls: _,
@@ -889,7 +937,12 @@ impl Module for JITModule {
         };
         let relocs = data.all_relocs(pointer_reloc).collect::<Vec<_>>();
 
-        self.compiled_data_objects[id] = Some(CompiledBlob { ptr, size, relocs });
+        self.compiled_data_objects[id] = Some(CompiledBlob {
+            ptr,
+            write_ptr: ptr,
+            size,
+            relocs,
+        });
         self.data_objects_to_finalize.push(id);
         if self.isa.flags().is_pic() {
             self.pending_got_updates.push(GotUpdate {
@@ -978,3 +1031,17 @@ fn use_bti(isa_flags: &Vec<settings::Value>) -> bool {
         .find(|&f| f.name == "use_bti")
         .map_or(false, |f| f.as_bool().unwrap_or(false))
 }
+
+/// Returns the address of a byte which is non-zero if the aarch64 Large System
+/// Extensions atomics are available, standing in for the
+/// `__aarch64_have_lse_atomics` symbol of compiler-rt and libgcc when the host
+/// doesn't provide it.
+fn aarch64_have_lse_atomics() -> *const u8 {
+    static HAVE_LSE_ATOMICS: std::sync::OnceLock<u8> = std::sync::OnceLock::new();
+    HAVE_LSE_ATOMICS.get_or_init(|| {
+        #[cfg(target_arch = "aarch64")]
+        return std::arch::is_aarch64_feature_detected!("lse").into();
+        #[cfg(not(target_arch = "aarch64"))]
+        return 0;
+    })
+}
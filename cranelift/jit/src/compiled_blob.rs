@@ -14,6 +14,9 @@ unsafe fn modify_inst32(iptr: *mut u32, modifier: impl FnOnce(u32) -> u32) {
 #[derive(Clone)]
 pub(crate) struct CompiledBlob {
     pub(crate) ptr: *mut u8,
+    /// The address through which the blob is written, which differs from `ptr`
+    /// for dual-mapped code.
+    pub(crate) write_ptr: *mut u8,
     pub(crate) size: usize,
     pub(crate) relocs: Vec<ModuleReloc>,
 }
@@ -36,49 +39,50 @@ impl CompiledBlob {
         {
             debug_assert!((offset as usize) < self.size);
             let at = unsafe { self.ptr.offset(isize::try_from(offset).unwrap()) };
+            let write_at = unsafe { self.write_ptr.offset(isize::try_from(offset).unwrap()) };
             match kind {
                 Reloc::Abs4 => {
                     let base = get_address(name);
                     let what = unsafe { base.offset(isize::try_from(addend).unwrap()) };
                     unsafe {
-                        write_unaligned(at as *mut u32, u32::try_from(what as usize).unwrap())
+                        write_unaligned(write_at as *mut u32, u32::try_from(what as usize).unwrap())
                     };
                 }
                 Reloc::Abs8 => {
                     let base = get_address(name);
                     let what = unsafe { base.offset(isize::try_from(addend).unwrap()) };
                     unsafe {
-                        write_unaligned(at as *mut u64, u64::try_from(what as usize).unwrap())
+                        write_unaligned(write_at as *mut u64, u64::try_from(what as usize).unwrap())
                     };
                 }
                 Reloc::X86PCRel4 | Reloc::X86CallPCRel4 => {
                     let base = get_address(name);
                     let what = unsafe { base.offset(isize::try_from(addend).unwrap()) };
                     let pcrel = i32::try_from((what as isize) - (at as isize)).unwrap();
-                    unsafe { write_unaligned(at as *mut i32, pcrel) };
+                    unsafe { write_unaligned(write_at as *mut i32, pcrel) };
                 }
                 Reloc::X86GOTPCRel4 => {
                     let base = get_got_entry(name);
                     let what = unsafe { base.offset(isize::try_from(addend).unwrap()) };
                     let pcrel = i32::try_from((what as isize) - (at as isize)).unwrap();
-                    unsafe { write_unaligned(at as *mut i32, pcrel) };
+                    unsafe { write_unaligned(write_at as *mut i32, pcrel) };
                 }
                 Reloc::X86CallPLTRel4 => {
                     let base = get_plt_entry(name);
                     let what = unsafe { base.offset(isize::try_from(addend).unwrap()) };
                     let pcrel = i32::try_from((what as isize) - (at as isize)).unwrap();
-                    unsafe { write_unaligned(at as *mut i32, pcrel) };
+                    unsafe { write_unaligned(write_at as *mut i32, pcrel) };
                 }
                 Reloc::S390xPCRel32Dbl | Reloc::S390xPLTRel32Dbl => {
                     let base = get_address(name);
                     let what = unsafe { base.offset(isize::try_from(addend).unwrap()) };
                     let pcrel = i32::try_from(((what as isize) - (at as isize)) >> 1).unwrap();
-                    unsafe { write_unaligned(at as *mut i32, pcrel) };
+                    unsafe { write_unaligned(write_at as *mut i32, pcrel) };
                 }
                 Reloc::Arm64Call => {
                     let base = get_address(name);
                     // The instruction is 32 bits long.
-                    let iptr = at as *mut u32;
+                    let iptr = write_at as *mut u32;
                     // The offset encoded in the `bl` instruction is the
                     // number of bytes divided by 4.
                     let diff = ((base as isize) - (at as isize)) >> 2;
@@ -121,11 +125,11 @@ impl CompiledBlob {
 
                     unsafe {
                         // Do a R_RISCV_PCREL_HI20 on the `auipc`
-                        let auipc_addr = at as *mut u32;
+                        let auipc_addr = write_at as *mut u32;
                         modify_inst32(auipc_addr, |auipc| (auipc & 0xFFF) | hi20);
 
                         // Do a R_RISCV_PCREL_LO12_I on the `jalr`
-                        let jalr_addr = at.offset(4) as *mut u32;
+                        let jalr_addr = write_at.offset(4) as *mut u32;
                         modify_inst32(jalr_addr, |jalr| (jalr & 0xFFFFF) | (lo12 << 20));
                     }
                 }
@@ -19,6 +19,10 @@ struct PtrLen {
 
     ptr: *mut u8,
     len: usize,
+
+    /// The address through which the memory at `ptr` is written, if it was
+    /// allocated with `PtrLen::with_size_dual_mapped`.
+    write_view: Option<*mut u8>,
 }
 
 impl PtrLen {
@@ -30,6 +34,7 @@ impl PtrLen {
 
             ptr: ptr::null_mut(),
             len: 0,
+            write_view: None,
         }
     }
 
@@ -45,6 +50,7 @@ impl PtrLen {
                 ptr: mmap.as_mut_ptr(),
                 map: Some(mmap),
                 len: alloc_size,
+                write_view: None,
             }
         })
     }
@@ -62,6 +68,7 @@ impl PtrLen {
             Ok(Self {
                 ptr,
                 len: alloc_size,
+                write_view: None,
             })
         } else {
             Err(io::Error::from(io::ErrorKind::OutOfMemory))
@@ -87,17 +94,123 @@ impl PtrLen {
             Ok(Self {
                 ptr: ptr as *mut u8,
                 len: region::page::ceil(size),
+                write_view: None,
             })
         } else {
             Err(io::Error::last_os_error())
         }
     }
+
+    /// Create a new `PtrLen` pointing to at least `size` bytes of readable and
+    /// executable memory, along with a second, writable mapping of the same
+    /// pages. Neither mapping ever changes its protection, which keeps the
+    /// memory usable under strict W^X policies.
+    ///
+    /// With `bti` the executable mapping is created with BTI enforcement if
+    /// the CPU supports it, so it doesn't need an `mprotect` afterwards.
+    #[cfg(target_os = "linux")]
+    fn with_size_dual_mapped(size: usize, bti: bool) -> io::Result<Self> {
+        let alloc_size = region::page::ceil(size);
+        #[allow(unused_mut)]
+        let mut exec_prot = libc::PROT_READ | libc::PROT_EXEC;
+        #[cfg(target_arch = "aarch64")]
+        if bti && std::arch::is_aarch64_feature_detected!("bti") {
+            exec_prot |= /* PROT_BTI */ 0x10;
+        }
+        #[cfg(not(target_arch = "aarch64"))]
+        let _ = bti;
+        unsafe {
+            let fd = libc::memfd_create(b"cranelift-jit\0".as_ptr().cast(), libc::MFD_CLOEXEC);
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let map = |prot| {
+                let ptr = libc::mmap(ptr::null_mut(), alloc_size, prot, libc::MAP_SHARED, fd, 0);
+                if ptr == libc::MAP_FAILED {
+                    Err(io::Error::last_os_error())
+                } else {
+                    Ok(ptr.cast::<u8>())
+                }
+            };
+            let result = (|| {
+                if libc::ftruncate(fd, alloc_size as libc::off_t) < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                let write_view = map(libc::PROT_READ | libc::PROT_WRITE)?;
+                let ptr = map(exec_prot).map_err(|e| {
+                    libc::munmap(write_view.cast(), alloc_size);
+                    e
+                })?;
+                Ok(Self {
+                    #[cfg(feature = "selinux-fix")]
+                    map: None,
+
+                    ptr,
+                    len: alloc_size,
+                    write_view: Some(write_view),
+                })
+            })();
+            // The mappings keep the memory alive, the descriptor isn't needed
+            // anymore.
+            libc::close(fd);
+            result
+        }
+    }
+
+    /// Create a new `PtrLen` pointing to at least `size` bytes of `MAP_JIT`
+    /// memory. macOS doesn't allow mapping such memory twice; instead whether
+    /// it is writable or executable is toggled per thread with
+    /// `set_jit_write_protect`, which doesn't require a system call.
+    #[cfg(target_os = "macos")]
+    fn with_size_dual_mapped(size: usize, _bti: bool) -> io::Result<Self> {
+        let alloc_size = region::page::ceil(size);
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                alloc_size,
+                libc::PROT_READ | libc::PROT_WRITE | libc::PROT_EXEC,
+                libc::MAP_PRIVATE | libc::MAP_ANON | libc::MAP_JIT,
+                -1,
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            #[cfg(feature = "selinux-fix")]
+            map: None,
+
+            ptr: ptr.cast(),
+            len: alloc_size,
+            write_view: Some(ptr.cast()),
+        })
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    fn with_size_dual_mapped(_size: usize, _bti: bool) -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "dual-mapped code memory is not supported on this platform",
+        ))
+    }
 }
 
-// `MMapMut` from `cfg(feature = "selinux-fix")` already deallocates properly.
-#[cfg(all(not(target_os = "windows"), not(feature = "selinux-fix")))]
+#[cfg(not(target_os = "windows"))]
 impl Drop for PtrLen {
     fn drop(&mut self) {
+        if let Some(write_view) = self.write_view {
+            unsafe {
+                libc::munmap(self.ptr.cast(), self.len);
+                if write_view != self.ptr {
+                    libc::munmap(write_view.cast(), self.len);
+                }
+            }
+            return;
+        }
+
+        // `MMapMut` from `cfg(feature = "selinux-fix")` already deallocates properly.
+        #[cfg(not(feature = "selinux-fix"))]
         if !self.ptr.is_null() {
             let page_size = region::page::size();
             let layout = alloc::Layout::from_size_align(self.len, page_size).unwrap();
@@ -121,6 +234,18 @@ pub(crate) enum BranchProtection {
     BTI,
 }
 
+/// Toggles whether `MAP_JIT` memory is writable (`false`) or executable
+/// (`true`) for the current thread. This is a no-op where memory doesn't need
+/// to be toggled.
+fn set_jit_write_protect(enabled: bool) {
+    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+    unsafe {
+        libc::pthread_jit_write_protect_np(enabled as libc::c_int)
+    };
+    #[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+    let _ = enabled;
+}
+
 /// JIT memory manager. This manages pages of suitably aligned and
 /// accessible memory. Memory will be leaked by default to have
 /// function pointers remain valid for the remainder of the
@@ -131,6 +256,7 @@ pub(crate) struct Memory {
     current: PtrLen,
     position: usize,
     branch_protection: BranchProtection,
+    dual_mapped: bool,
 }
 
 impl Memory {
@@ -141,9 +267,19 @@ impl Memory {
             current: PtrLen::new(),
             position: 0,
             branch_protection,
+            dual_mapped: false,
         }
     }
 
+    /// Create a `Memory` whose allocations are executable as soon as they are
+    /// made and are written through a separate, writable view instead. See
+    /// `Memory::write_view`.
+    pub(crate) fn new_dual_mapped(branch_protection: BranchProtection) -> Self {
+        let mut memory = Self::new(branch_protection);
+        memory.dual_mapped = true;
+        memory
+    }
+
     fn finish_current(&mut self) {
         self.allocations
             .push(mem::replace(&mut self.current, PtrLen::new()));
@@ -157,6 +293,10 @@ impl Memory {
             debug_assert!(self.position % align == 0);
         }
 
+        if self.dual_mapped {
+            set_jit_write_protect(false);
+        }
+
         if size <= self.current.len - self.position {
             // TODO: Ensure overflow is not possible.
             let ptr = unsafe { self.current.ptr.add(self.position) };
@@ -167,16 +307,39 @@ impl Memory {
         self.finish_current();
 
         // TODO: Allocate more at a time.
-        self.current = PtrLen::with_size(size)?;
+        self.current = if self.dual_mapped {
+            PtrLen::with_size_dual_mapped(size, self.branch_protection == BranchProtection::BTI)?
+        } else {
+            PtrLen::with_size(size)?
+        };
         self.position = size;
 
         Ok(self.current.ptr)
     }
 
+    /// Returns the address through which the memory at `ptr` can be written.
+    /// `ptr` must have been returned by the most recent call to `allocate`.
+    ///
+    /// This is `ptr` itself unless the memory is dual mapped.
+    pub(crate) fn write_view(&self, ptr: *mut u8) -> *mut u8 {
+        match self.current.write_view {
+            Some(write_view) => {
+                debug_assert!(ptr >= self.current.ptr);
+                debug_assert!((ptr as usize) < self.current.ptr as usize + self.current.len);
+                unsafe { write_view.offset(ptr.offset_from(self.current.ptr)) }
+            }
+            None => ptr,
+        }
+    }
+
     /// Set all memory allocated in this `Memory` up to now as readable and executable.
     pub(crate) fn set_readable_and_executable(&mut self) -> ModuleResult<()> {
         self.finish_current();
 
+        if self.dual_mapped {
+            set_jit_write_protect(true);
+        }
+
         // Clear all the newly allocated code from cache if the processor requires it
         //
         // Do this before marking the memory as R+X, technically we should be able to do it after
@@ -189,6 +352,13 @@ impl Memory {
         }
 
         let set_region_readable_and_executable = |ptr, len| -> ModuleResult<()> {
+            // Dual-mapped memory is readable and executable from the start,
+            // including BTI enforcement when requested, so it never needs
+            // its protection changed.
+            if self.dual_mapped {
+                return Ok(());
+            }
+
             if self.branch_protection == BranchProtection::BTI {
                 #[cfg(all(target_arch = "aarch64", target_os = "linux"))]
                 if std::arch::is_aarch64_feature_detected!("bti") {
@@ -251,7 +421,14 @@ impl Memory {
         let iter = self.allocations[self.already_protected..].iter();
 
         #[cfg(all(not(target_os = "windows"), feature = "selinux-fix"))]
-        return iter.filter(|&PtrLen { ref map, len, .. }| *len != 0 && map.is_some());
+        return iter.filter(
+            |&PtrLen {
+                 ref map,
+                 len,
+                 write_view,
+                 ..
+             }| { *len != 0 && (map.is_some() || write_view.is_some()) },
+        );
 
         #[cfg(any(target_os = "windows", not(feature = "selinux-fix")))]
         return iter.filter(|&PtrLen { len, .. }| *len != 0);
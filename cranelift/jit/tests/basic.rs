@@ -213,3 +213,108 @@ fn libcall_function() {
 
     module.finalize_definitions().unwrap();
 }
+
+#[test]
+fn function_addr_in_data() {
+    let mut flag_builder = settings::builder();
+    flag_builder.set("use_colocated_libcalls", "false").unwrap();
+    // FIXME set back to true once the x64 backend supports it.
+    flag_builder.set("is_pic", "false").unwrap();
+    let isa_builder = cranelift_native::builder().unwrap_or_else(|msg| {
+        panic!("host machine is not supported: {}", msg);
+    });
+    let isa = isa_builder
+        .finish(settings::Flags::new(flag_builder))
+        .unwrap();
+    let mut module = JITModule::new(JITBuilder::with_isa(isa, default_libcall_names()));
+
+    let func_id = define_simple_function(&mut module);
+    let data_id = module
+        .declare_data("table", Linkage::Local, false, false)
+        .unwrap();
+
+    let ptr_size = module.target_config().pointer_bytes() as usize;
+    let mut data = DataDescription::new();
+    data.define(vec![0; 2 * ptr_size].into_boxed_slice());
+    let func_ref = module.declare_func_in_data(func_id, &mut data);
+    data.write_function_addr(0, func_ref);
+    data.write_function_addr_with_addend(ptr_size as u32, func_ref, 16);
+    module.define_data(data_id, &data).unwrap();
+
+    module.finalize_definitions().unwrap();
+
+    let func = module.get_finalized_function(func_id) as usize;
+    let (table, size) = module.get_finalized_data(data_id);
+    assert_eq!(size, 2 * ptr_size);
+    let table = unsafe { std::slice::from_raw_parts(table.cast::<usize>(), 2) };
+    assert_eq!(table, [func, func + 16]);
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn dual_mapped_code() {
+    let mut flag_builder = settings::builder();
+    flag_builder.set("use_colocated_libcalls", "false").unwrap();
+    // FIXME set back to true once the x64 backend supports it.
+    flag_builder.set("is_pic", "false").unwrap();
+    let isa_builder = cranelift_native::builder().unwrap_or_else(|msg| {
+        panic!("host machine is not supported: {}", msg);
+    });
+    let isa = isa_builder
+        .finish(settings::Flags::new(flag_builder))
+        .unwrap();
+    let mut builder = JITBuilder::with_isa(isa, default_libcall_names());
+    builder.dual_mapped_code(true);
+    let mut module = JITModule::new(builder);
+
+    let sig = Signature {
+        params: vec![],
+        returns: vec![AbiParam::new(types::I32)],
+        call_conv: CallConv::triple_default(module.isa().triple()),
+    };
+    let callee_id = module
+        .declare_function("callee", Linkage::Local, &sig)
+        .unwrap();
+    let caller_id = module
+        .declare_function("caller", Linkage::Local, &sig)
+        .unwrap();
+
+    let mut ctx = Context::new();
+    let mut func_ctx = FunctionBuilderContext::new();
+
+    ctx.func =
+        Function::with_name_signature(UserFuncName::user(0, callee_id.as_u32()), sig.clone());
+    {
+        let mut bcx = FunctionBuilder::new(&mut ctx.func, &mut func_ctx);
+        let block = bcx.create_block();
+        bcx.switch_to_block(block);
+        let value = bcx.ins().iconst(types::I32, 42);
+        bcx.ins().return_(&[value]);
+        bcx.seal_all_blocks();
+        bcx.finalize();
+    }
+    module.define_function(callee_id, &mut ctx).unwrap();
+    module.clear_context(&mut ctx);
+
+    ctx.func = Function::with_name_signature(UserFuncName::user(0, caller_id.as_u32()), sig);
+    {
+        let mut bcx = FunctionBuilder::new(&mut ctx.func, &mut func_ctx);
+        let block = bcx.create_block();
+        bcx.switch_to_block(block);
+        let callee = module.declare_func_in_func(callee_id, &mut bcx.func);
+        let call = bcx.ins().call(callee, &[]);
+        let value = bcx.inst_results(call)[0];
+        let one = bcx.ins().iconst(types::I32, 1);
+        let value = bcx.ins().iadd(value, one);
+        bcx.ins().return_(&[value]);
+        bcx.seal_all_blocks();
+        bcx.finalize();
+    }
+    module.define_function(caller_id, &mut ctx).unwrap();
+
+    module.finalize_definitions().unwrap();
+
+    let caller = module.get_finalized_function(caller_id);
+    let caller = unsafe { std::mem::transmute::<_, extern "C" fn() -> i32>(caller) };
+    assert_eq!(caller(), 43);
+}
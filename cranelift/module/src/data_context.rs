@@ -57,7 +57,7 @@ pub struct DataDescription {
     /// External data object declarations.
     pub data_decls: PrimaryMap<ir::GlobalValue, ModuleRelocTarget>,
     /// Function addresses to write at specified offsets.
-    pub function_relocs: Vec<(CodeOffset, ir::FuncRef)>,
+    pub function_relocs: Vec<(CodeOffset, ir::FuncRef, Addend)>,
     /// Data addresses to write at specified offsets.
     pub data_relocs: Vec<(CodeOffset, ir::GlobalValue, Addend)>,
     /// Object file section
@@ -139,7 +139,23 @@ impl DataDescription {
 
     /// Write the address of `func` into the data at offset `offset`.
     pub fn write_function_addr(&mut self, offset: CodeOffset, func: ir::FuncRef) {
-        self.function_relocs.push((offset, func))
+        self.write_function_addr_with_addend(offset, func, 0)
+    }
+
+    /// Write the address of `func` plus `addend` into the data at offset
+    /// `offset`.
+    ///
+    /// The address is resolved directly to the function's code, so tables of
+    /// function pointers such as vtables don't have to go through PLT entries,
+    /// except when the JIT has hotswapping enabled, which rejects nonzero
+    /// addends.
+    pub fn write_function_addr_with_addend(
+        &mut self,
+        offset: CodeOffset,
+        func: ir::FuncRef,
+        addend: Addend,
+    ) {
+        self.function_relocs.push((offset, func, addend))
     }
 
     /// Write the address of `data` into the data at offset `offset`.
@@ -155,11 +171,11 @@ impl DataDescription {
         let func_relocs = self
             .function_relocs
             .iter()
-            .map(move |&(offset, id)| ModuleReloc {
+            .map(move |&(offset, id, addend)| ModuleReloc {
                 kind: pointer_reloc,
                 offset,
                 name: self.function_decls[id].clone(),
-                addend: 0,
+                addend,
             });
         let data_relocs = self
             .data_relocs
@@ -100,6 +100,9 @@ pub fn infer_native_flags(isa_builder: &mut dyn Configurable) -> Result<(), &'st
         if std::is_x86_feature_detected!("avx512vbmi") {
             isa_builder.enable("has_avx512vbmi").unwrap();
         }
+        if std::is_x86_feature_detected!("avx512vnni") {
+            isa_builder.enable("has_avx512vnni").unwrap();
+        }
         if std::is_x86_feature_detected!("lzcnt") {
             isa_builder.enable("has_lzcnt").unwrap();
         }
@@ -111,6 +114,14 @@ pub fn infer_native_flags(isa_builder: &mut dyn Configurable) -> Result<(), &'st
             isa_builder.enable("has_lse").unwrap();
         }
 
+        if std::arch::is_aarch64_feature_detected!("dotprod") {
+            isa_builder.enable("has_dotprod").unwrap();
+        }
+
+        if std::arch::is_aarch64_feature_detected!("rcpc") {
+            isa_builder.enable("has_rcpc").unwrap();
+        }
+
         if std::arch::is_aarch64_feature_detected!("paca") {
             isa_builder.enable("has_pauth").unwrap();
         }
@@ -602,6 +602,16 @@ impl ObjectModule {
                             section: SymbolSection::Undefined,
                             flags: SymbolFlags::None,
                         },
+                        ir::KnownSymbol::Aarch64HaveLseAtomics => Symbol {
+                            name: b"__aarch64_have_lse_atomics".to_vec(),
+                            value: 0,
+                            size: 0,
+                            kind: SymbolKind::Data,
+                            scope: SymbolScope::Unknown,
+                            weak: false,
+                            section: SymbolSection::Undefined,
+                            flags: SymbolFlags::None,
+                        },
                     });
                     self.known_symbols.insert(*known_symbol, symbol);
                     symbol
@@ -239,3 +239,44 @@ fn reject_nul_byte_symbol_for_data() {
         )
         .unwrap();
 }
+
+#[test]
+fn function_addr_in_data() {
+    use object::{Object, ObjectSection, ObjectSymbol, RelocationTarget};
+
+    let flag_builder = settings::builder();
+    let isa_builder = cranelift_codegen::isa::lookup_by_name("x86_64-unknown-linux-gnu").unwrap();
+    let isa = isa_builder
+        .finish(settings::Flags::new(flag_builder))
+        .unwrap();
+    let mut module =
+        ObjectModule::new(ObjectBuilder::new(isa, "foo", default_libcall_names()).unwrap());
+
+    let func_id = define_simple_function(&mut module);
+    let data_id = module
+        .declare_data("table", Linkage::Local, false, false)
+        .unwrap();
+
+    let mut data = DataDescription::new();
+    data.define(vec![0; 16].into_boxed_slice());
+    let func_ref = module.declare_func_in_data(func_id, &mut data);
+    data.write_function_addr(0, func_ref);
+    data.write_function_addr_with_addend(8, func_ref, 16);
+    module.define_data(data_id, &data).unwrap();
+
+    let bytes = module.finish().emit().unwrap();
+    let obj = object::File::parse(&bytes[..]).unwrap();
+    let func_symbol = obj.symbol_by_name("abc").unwrap().index();
+    let table = obj.symbol_by_name("table").unwrap();
+    let section = obj
+        .section_by_index(table.section_index().unwrap())
+        .unwrap();
+    let relocs = section
+        .relocations()
+        .map(|(offset, reloc)| {
+            assert_eq!(reloc.target(), RelocationTarget::Symbol(func_symbol));
+            (offset, reloc.addend())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(relocs, [(0, 0), (8, 16)]);
+}
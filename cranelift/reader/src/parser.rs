@@ -18,11 +18,11 @@ use cranelift_codegen::ir::types::INVALID;
 use cranelift_codegen::ir::types::*;
 use cranelift_codegen::ir::{self, UserExternalNameRef};
 use cranelift_codegen::ir::{
-    AbiParam, ArgumentExtension, ArgumentPurpose, Block, Constant, ConstantData, DynamicStackSlot,
-    DynamicStackSlotData, DynamicTypeData, ExtFuncData, ExternalName, FuncRef, Function,
-    GlobalValue, GlobalValueData, JumpTableData, MemFlags, MemoryTypeData, MemoryTypeField, Opcode,
-    SigRef, Signature, StackSlot, StackSlotData, StackSlotKind, Table, TableData, Type,
-    UserFuncName, Value,
+    AbiParam, ArgumentExtension, ArgumentPurpose, Block, BranchHint, CallFlags, Constant,
+    ConstantData, DynamicStackSlot, DynamicStackSlotData, DynamicTypeData, ExtFuncData,
+    ExternalName, FuncRef, Function, GlobalValue, GlobalValueData, JumpTableData, MemFlags,
+    MemoryTypeData, MemoryTypeField, Opcode, SigRef, Signature, StackSlot, StackSlotData,
+    StackSlotKind, Table, TableData, Type, UserFuncName, Value,
 };
 use cranelift_codegen::isa::{self, CallConv};
 use cranelift_codegen::packed_option::ReservedValue;
@@ -936,6 +936,40 @@ impl<'a> Parser<'a> {
         flags
     }
 
+    // Match and consume a possibly empty sequence of call flags.
+    fn optional_call_flags(&mut self) -> CallFlags {
+        let mut flags = CallFlags::new();
+        loop {
+            match self.token() {
+                // `cold` is also a block flag, so it has its own token.
+                Some(Token::Cold) => flags.set_cold(),
+                Some(Token::Identifier(text)) if flags.set_by_name(text) => {}
+                _ => break,
+            }
+            self.consume();
+        }
+        flags
+    }
+
+    // Match and consume an optional branch hint.
+    //
+    // branch-hint ::= "taken" "(" uimm8(percent) ")"
+    fn optional_branch_hint(&mut self) -> ParseResult<Option<BranchHint>> {
+        if self.token() != Some(Token::Identifier("taken")) {
+            return Ok(None);
+        }
+        self.consume();
+        self.match_token(Token::LPar, "expected '(' before branch probability")?;
+        let loc = self.loc;
+        let percent = self.match_uimm8("expected branch probability in percent")?;
+        let hint = match BranchHint::new(percent) {
+            Some(hint) => hint,
+            None => return err!(loc, "branch probability must be at most 100"),
+        };
+        self.match_token(Token::RPar, "expected ')' after branch probability")?;
+        Ok(Some(hint))
+    }
+
     // Match and consume an identifier.
     fn match_any_identifier(&mut self, err_msg: &str) -> ParseResult<&'a str> {
         if let Some(Token::Identifier(text)) = self.token() {
@@ -2512,7 +2546,7 @@ impl<'a> Parser<'a> {
         self.start_gathering_comments();
 
         // instruction ::=  [inst-results "="] * Opcode(opc) ["." Type] ...
-        let opcode = if let Some(Token::Identifier(text)) = self.token() {
+        let opcode: Opcode = if let Some(Token::Identifier(text)) = self.token() {
             match text.parse() {
                 Ok(opc) => opc,
                 Err(msg) => return err!(self.loc, "{}: '{}'", msg, text),
@@ -2536,7 +2570,20 @@ impl<'a> Parser<'a> {
             None
         };
 
-        // instruction ::=  [inst-results "="] Opcode(opc) ["." Type] * ...
+        // Calls may be preceded by flags and branches by a hint.
+        // instruction ::=  [inst-results "="] Opcode(opc) ["." Type] * [flags] ...
+        let call_flags = if opcode.is_call() {
+            self.optional_call_flags()
+        } else {
+            CallFlags::new()
+        };
+        let branch_hint = if opcode.is_branch() {
+            self.optional_branch_hint()?
+        } else {
+            None
+        };
+
+        // instruction ::=  [inst-results "="] Opcode(opc) ["." Type] [flags] * ...
         let inst_data = self.parse_inst_operands(ctx, opcode, explicit_ctrl_type)?;
 
         // We're done parsing the instruction now.
@@ -2559,6 +2606,11 @@ impl<'a> Parser<'a> {
             ctx.function.set_srcloc(inst, srcloc);
         }
 
+        if !call_flags.is_empty() {
+            ctx.function.dfg.call_flags[inst] = call_flags;
+        }
+        ctx.function.dfg.branch_hints[inst] = branch_hint;
+
         if results.len() != num_results {
             return err!(
                 self.loc,
@@ -86,7 +86,7 @@ use cranelift_codegen::ir::condcodes::{FloatCC, IntCC};
 use cranelift_codegen::ir::immediates::Offset32;
 use cranelift_codegen::ir::types::*;
 use cranelift_codegen::ir::{
-    self, AtomicRmwOp, ConstantData, InstBuilder, JumpTableData, MemFlags, Value, ValueLabel,
+    self, AtomicRmwOp, ConstantData, InstBuilder, JumpTableData, MemFlags, Value,
 };
 use cranelift_codegen::packed_option::ReservedValue;
 use cranelift_frontend::{FunctionBuilder, Variable};
@@ -139,8 +139,6 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
         Operator::LocalGet { local_index } => {
             let val = builder.use_var(Variable::from_u32(*local_index));
             state.push1(val);
-            let label = ValueLabel::from_u32(*local_index);
-            builder.set_val_label(val, label);
         }
         Operator::LocalSet { local_index } => {
             let mut val = state.pop1();
@@ -152,8 +150,6 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
             }
 
             builder.def_var(Variable::from_u32(*local_index), val);
-            let label = ValueLabel::from_u32(*local_index);
-            builder.set_val_label(val, label);
         }
         Operator::LocalTee { local_index } => {
             let mut val = state.peek1();
@@ -165,8 +161,6 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
             }
 
             builder.def_var(Variable::from_u32(*local_index), val);
-            let label = ValueLabel::from_u32(*local_index);
-            builder.set_val_label(val, label);
         }
         /********************************** Globals ****************************************
          *  `get_global` and `set_global` are handled by the environment.
@@ -305,6 +299,7 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
                     destination,
                     state.peekn(params.len()),
                 );
+                set_branch_hint(branch_inst, builder, environ);
                 (
                     destination,
                     ElseData::NoElse {
@@ -317,7 +312,7 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
                 // so we eagerly allocate the `else` block here.
                 let destination = block_with_params(builder, results.clone(), environ)?;
                 let else_block = block_with_params(builder, params.clone(), environ)?;
-                canonicalise_brif(
+                let branch_inst = canonicalise_brif(
                     builder,
                     val,
                     next_block,
@@ -325,6 +320,7 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
                     else_block,
                     state.peekn(params.len()),
                 );
+                set_branch_hint(branch_inst, builder, environ);
                 builder.seal_block(else_block);
                 (destination, ElseData::WithElse { else_block })
             };
@@ -490,7 +486,9 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
             state.popn(return_count);
             state.reachable = false;
         }
-        Operator::BrIf { relative_depth } => translate_br_if(*relative_depth, builder, state),
+        Operator::BrIf { relative_depth } => {
+            translate_br_if(*relative_depth, builder, state, environ)
+        }
         Operator::BrTable { targets } => {
             let default = targets.default();
             let mut min_depth = default;
@@ -857,51 +855,56 @@ pub fn translate_operator<FE: FuncEnvironment + ?Sized>(
             );
         }
         Operator::V128Load8x8S { memarg } => {
-            //TODO(#6829): add before_load() and before_store() hooks for SIMD loads and stores.
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            before_memory_access(memarg, false, 8, index, builder, environ);
             let loaded = builder.ins().sload8x8(flags, base, 0);
             state.push1(loaded);
         }
         Operator::V128Load8x8U { memarg } => {
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            before_memory_access(memarg, false, 8, index, builder, environ);
             let loaded = builder.ins().uload8x8(flags, base, 0);
             state.push1(loaded);
         }
         Operator::V128Load16x4S { memarg } => {
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            before_memory_access(memarg, false, 8, index, builder, environ);
             let loaded = builder.ins().sload16x4(flags, base, 0);
             state.push1(loaded);
         }
         Operator::V128Load16x4U { memarg } => {
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            before_memory_access(memarg, false, 8, index, builder, environ);
             let loaded = builder.ins().uload16x4(flags, base, 0);
             state.push1(loaded);
         }
         Operator::V128Load32x2S { memarg } => {
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            before_memory_access(memarg, false, 8, index, builder, environ);
             let loaded = builder.ins().sload32x2(flags, base, 0);
             state.push1(loaded);
         }
         Operator::V128Load32x2U { memarg } => {
-            let (flags, _, base) = unwrap_or_return_unreachable_state!(
+            let (flags, index, base) = unwrap_or_return_unreachable_state!(
                 state,
                 prepare_addr(memarg, 8, builder, state, environ)?
             );
+            before_memory_access(memarg, false, 8, index, builder, environ);
             let loaded = builder.ins().uload32x2(flags, base, 0);
             state.push1(loaded);
         }
@@ -2909,6 +2912,7 @@ fn translate_load<FE: FuncEnvironment + ?Sized>(
         };
 
     environ.before_load(builder, mem_op_size, wasm_index, memarg.offset);
+    before_memory_access(memarg, false, mem_op_size, wasm_index, builder, environ);
 
     let (load, dfg) = builder
         .ins()
@@ -2935,6 +2939,7 @@ fn translate_store<FE: FuncEnvironment + ?Sized>(
     );
 
     environ.before_store(builder, mem_op_size, wasm_index, memarg.offset);
+    before_memory_access(memarg, true, mem_op_size, wasm_index, builder, environ);
 
     builder
         .ins()
@@ -2942,6 +2947,26 @@ fn translate_store<FE: FuncEnvironment + ?Sized>(
     Ok(())
 }
 
+/// Reports an access of `access_size` bytes at the wasm address `index` to
+/// `environ`.
+fn before_memory_access<FE: FuncEnvironment + ?Sized>(
+    memarg: &MemArg,
+    is_store: bool,
+    access_size: u8,
+    index: Value,
+    builder: &mut FunctionBuilder,
+    environ: &mut FE,
+) {
+    environ.before_memory_access(
+        builder,
+        MemoryIndex::from_u32(memarg.memory),
+        is_store,
+        access_size,
+        index,
+        memarg.offset,
+    );
+}
+
 fn mem_op_size(opcode: ir::Opcode, ty: Type) -> u8 {
     match opcode {
         ir::Opcode::Istore8 | ir::Opcode::Sload8 | ir::Opcode::Uload8 => 1,
@@ -2992,16 +3017,13 @@ fn translate_atomic_rmw<FE: FuncEnvironment + ?Sized>(
         arg2 = builder.ins().ireduce(access_ty, arg2);
     }
 
-    let (flags, _, addr) = unwrap_or_return_unreachable_state!(
+    let access_size = u8::try_from(access_ty.bytes()).unwrap();
+    let (flags, index, addr) = unwrap_or_return_unreachable_state!(
         state,
-        prepare_atomic_addr(
-            memarg,
-            u8::try_from(access_ty.bytes()).unwrap(),
-            builder,
-            state,
-            environ,
-        )?
+        prepare_atomic_addr(memarg, access_size, builder, state, environ)?
     );
+    before_memory_access(memarg, false, access_size, index, builder, environ);
+    before_memory_access(memarg, true, access_size, index, builder, environ);
 
     let mut res = builder.ins().atomic_rmw(access_ty, flags, op, addr, arg2);
     if access_ty != widened_ty {
@@ -3049,16 +3071,13 @@ fn translate_atomic_cas<FE: FuncEnvironment + ?Sized>(
         replacement = builder.ins().ireduce(access_ty, replacement);
     }
 
-    let (flags, _, addr) = unwrap_or_return_unreachable_state!(
+    let access_size = u8::try_from(access_ty.bytes()).unwrap();
+    let (flags, index, addr) = unwrap_or_return_unreachable_state!(
         state,
-        prepare_atomic_addr(
-            memarg,
-            u8::try_from(access_ty.bytes()).unwrap(),
-            builder,
-            state,
-            environ,
-        )?
+        prepare_atomic_addr(memarg, access_size, builder, state, environ)?
     );
+    before_memory_access(memarg, false, access_size, index, builder, environ);
+    before_memory_access(memarg, true, access_size, index, builder, environ);
     let mut res = builder.ins().atomic_cas(flags, addr, expected, replacement);
     if access_ty != widened_ty {
         res = builder.ins().uextend(widened_ty, res);
@@ -3092,16 +3111,12 @@ fn translate_atomic_load<FE: FuncEnvironment + ?Sized>(
     };
     assert!(w_ty_ok && widened_ty.bytes() >= access_ty.bytes());
 
-    let (flags, _, addr) = unwrap_or_return_unreachable_state!(
+    let access_size = u8::try_from(access_ty.bytes()).unwrap();
+    let (flags, index, addr) = unwrap_or_return_unreachable_state!(
         state,
-        prepare_atomic_addr(
-            memarg,
-            u8::try_from(access_ty.bytes()).unwrap(),
-            builder,
-            state,
-            environ,
-        )?
+        prepare_atomic_addr(memarg, access_size, builder, state, environ)?
     );
+    before_memory_access(memarg, false, access_size, index, builder, environ);
     let mut res = builder.ins().atomic_load(access_ty, flags, addr);
     if access_ty != widened_ty {
         res = builder.ins().uextend(widened_ty, res);
@@ -3141,16 +3156,12 @@ fn translate_atomic_store<FE: FuncEnvironment + ?Sized>(
         data = builder.ins().ireduce(access_ty, data);
     }
 
-    let (flags, _, addr) = unwrap_or_return_unreachable_state!(
+    let access_size = u8::try_from(access_ty.bytes()).unwrap();
+    let (flags, index, addr) = unwrap_or_return_unreachable_state!(
         state,
-        prepare_atomic_addr(
-            memarg,
-            u8::try_from(access_ty.bytes()).unwrap(),
-            builder,
-            state,
-            environ,
-        )?
+        prepare_atomic_addr(memarg, access_size, builder, state, environ)?
     );
+    before_memory_access(memarg, true, access_size, index, builder, environ);
     builder.ins().atomic_store(flags, data, addr);
     Ok(())
 }
@@ -3185,20 +3196,32 @@ fn translate_vector_fcmp(
     state.push1(builder.ins().fcmp(cc, bitcast_a, bitcast_b))
 }
 
-fn translate_br_if(
+fn translate_br_if<FE: FuncEnvironment + ?Sized>(
     relative_depth: u32,
     builder: &mut FunctionBuilder,
     state: &mut FuncTranslationState,
+    environ: &FE,
 ) {
     let val = state.pop1();
     let (br_destination, inputs) = translate_br_if_args(relative_depth, state);
     let next_block = builder.create_block();
-    canonicalise_brif(builder, val, br_destination, inputs, next_block, &[]);
+    let branch_inst = canonicalise_brif(builder, val, br_destination, inputs, next_block, &[]);
+    set_branch_hint(branch_inst, builder, environ);
 
     builder.seal_block(next_block); // The only predecessor is the current block.
     builder.switch_to_block(next_block);
 }
 
+/// Attaches the environment's hint for the operator being translated, if any,
+/// to the conditional `branch` it was translated to.
+fn set_branch_hint<FE: FuncEnvironment + ?Sized>(
+    branch: ir::Inst,
+    builder: &mut FunctionBuilder,
+    environ: &FE,
+) {
+    builder.func.dfg.branch_hints[branch] = environ.branch_hint(builder.srcloc());
+}
+
 fn translate_br_if_args(
     relative_depth: u32,
     state: &mut FuncTranslationState,
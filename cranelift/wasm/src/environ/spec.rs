@@ -51,6 +51,9 @@ pub trait TargetEnvironment: TypeConvert {
     /// Whether to enable Spectre mitigations for heap accesses.
     fn heap_access_spectre_mitigation(&self) -> bool;
 
+    /// Whether to enable Spectre mitigations for table accesses.
+    fn table_access_spectre_mitigation(&self) -> bool;
+
     /// Whether to add proof-carrying-code facts to verify memory accesses.
     fn proof_carrying_code(&self) -> bool;
 
@@ -547,6 +550,12 @@ pub trait FuncEnvironment: TargetEnvironment {
         Ok(())
     }
 
+    /// Returns a hint of how likely the conditional branch of the `br_if` or `if` operator at
+    /// `srcloc` is to be taken, for example from a branch hinting custom section.
+    fn branch_hint(&self, _srcloc: ir::SourceLoc) -> Option<ir::BranchHint> {
+        None
+    }
+
     /// Optional callback for the `FunctionEnvironment` performing this translation to maintain
     /// internal state or prepare custom state for the operator to translate
     fn before_translate_operator(
@@ -670,6 +679,23 @@ pub trait FuncEnvironment: TargetEnvironment {
     ) {
     }
 
+    /// Inserts code before any linear memory access of `val_size` bytes at
+    /// `addr + offset` in `memory`.
+    ///
+    /// Unlike `before_load` and `before_store` this is also invoked for SIMD
+    /// and atomic accesses. Atomic read-modify-write and compare-and-swap
+    /// instructions are reported as a load followed by a store.
+    fn before_memory_access(
+        &mut self,
+        _builder: &mut FunctionBuilder,
+        _memory: MemoryIndex,
+        _is_store: bool,
+        _val_size: u8,
+        _addr: ir::Value,
+        _offset: u64,
+    ) {
+    }
+
     /// Inserts code before updating a global.
     fn update_global(
         &mut self,
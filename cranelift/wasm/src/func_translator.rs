@@ -139,6 +139,7 @@ fn declare_wasm_parameters<FE: FuncEnvironment + ?Sized>(
             // This is a normal WebAssembly signature parameter, so create a local for it.
             let local = Variable::new(next_local);
             builder.declare_var(local, param_type.value_type);
+            builder.set_var_label(local, ValueLabel::new(next_local));
             next_local += 1;
 
             let param_value = builder.block_params(entry_block)[i];
@@ -231,9 +232,9 @@ fn declare_locals<FE: FuncEnvironment + ?Sized>(
     for _ in 0..count {
         let local = Variable::new(*next_local);
         builder.declare_var(local, ty);
+        builder.set_var_label(local, ValueLabel::new(*next_local));
         if let Some(init) = init {
             builder.def_var(local, init);
-            builder.set_val_label(init, ValueLabel::new(*next_local));
         }
         *next_local += 1;
     }
@@ -41,6 +41,7 @@ mod heap;
 mod module_translator;
 mod sections_translator;
 mod state;
+mod table;
 mod translation_utils;
 
 pub use crate::environ::{
@@ -51,6 +52,7 @@ pub use crate::func_translator::FuncTranslator;
 pub use crate::heap::{Heap, HeapData, HeapStyle};
 pub use crate::module_translator::translate_module;
 pub use crate::state::FuncTranslationState;
+pub use crate::table::prepare_table_addr;
 pub use crate::translation_utils::*;
 pub use cranelift_frontend::FunctionBuilder;
 pub use wasmtime_types::*;
@@ -0,0 +1,65 @@
+//! Bounds checking and address computation for WebAssembly table accesses.
+
+use crate::TargetEnvironment;
+use cranelift_codegen::ir::{self, condcodes::IntCC, InstBuilder};
+use cranelift_frontend::FunctionBuilder;
+
+/// Bounds check `index` against `table` and compute the native address of the
+/// table element it refers to.
+///
+/// This is the table counterpart of the bounds checks emitted for heap
+/// accesses: an out-of-bounds index traps with `TableOutOfBounds`, and if
+/// [`TargetEnvironment::table_access_spectre_mitigation`] is enabled the
+/// computed address is additionally replaced with null through a
+/// `select_spectre_guard`, so that a misspeculated bounds check can't be used
+/// to read past the end of the table.
+pub fn prepare_table_addr<Env>(
+    builder: &mut FunctionBuilder,
+    env: &Env,
+    table: ir::Table,
+    index: ir::Value,
+) -> ir::Value
+where
+    Env: TargetEnvironment + ?Sized,
+{
+    let addr_ty = env.pointer_type();
+    let index_ty = builder.func.dfg.value_type(index);
+    let ir::TableData {
+        base_gv,
+        bound_gv,
+        element_size,
+        ..
+    } = builder.func.tables[table];
+
+    // `index + 1 > bound` is the same as `index >= bound`.
+    let bound = builder.ins().global_value(index_ty, bound_gv);
+    let oob = builder
+        .ins()
+        .icmp(IntCC::UnsignedGreaterThanOrEqual, index, bound);
+    builder.ins().trapnz(oob, ir::TrapCode::TableOutOfBounds);
+
+    let index = if index_ty == addr_ty {
+        index
+    } else {
+        builder.ins().uextend(addr_ty, index)
+    };
+    let base = builder.ins().global_value(addr_ty, base_gv);
+    let element_size = u64::from(element_size);
+    let offset = if element_size == 1 {
+        index
+    } else if element_size.is_power_of_two() {
+        builder
+            .ins()
+            .ishl_imm(index, i64::from(element_size.trailing_zeros()))
+    } else {
+        builder.ins().imul_imm(index, element_size as i64)
+    };
+    let addr = builder.ins().iadd(base, offset);
+
+    if env.table_access_spectre_mitigation() {
+        let null = builder.ins().iconst(addr_ty, 0);
+        builder.ins().select_spectre_guard(oob, null, addr)
+    } else {
+        addr
+    }
+}
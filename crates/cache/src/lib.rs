@@ -83,11 +83,7 @@ impl<'config> ModuleCacheEntry<'config> {
             None => return compute(state),
         };
 
-        let mut hasher = Sha256Hasher(Sha256::new());
-        state.hash(&mut hasher);
-        let hash: [u8; 32] = hasher.0.finalize().into();
-        // standard encoding uses '/' which can't be used for filename
-        let hash = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&hash);
+        let hash = content_hash(state);
 
         if let Some(cached_val) = inner.get_data(&hash) {
             if let Some(val) = deserialize(state, cached_val) {
@@ -201,6 +197,18 @@ impl<'config> ModuleCacheEntryInner<'config> {
     }
 }
 
+/// Returns the content hash used to key the cached data for `state`.
+///
+/// The hash is stable across processes, so it is also suitable for keying
+/// content-addressed caches shared between hosts.
+pub fn content_hash<T: Hash + ?Sized>(state: &T) -> String {
+    let mut hasher = Sha256Hasher(Sha256::new());
+    state.hash(&mut hasher);
+    let hash: [u8; 32] = hasher.0.finalize().into();
+    // standard encoding uses '/' which can't be used for filename
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&hash)
+}
+
 impl Hasher for Sha256Hasher {
     fn finish(&self) -> u64 {
         panic!("Sha256Hasher doesn't support finish!");
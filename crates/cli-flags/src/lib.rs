@@ -126,6 +126,9 @@ wasmtime_option_group! {
         pub log_to_files: Option<bool>,
         /// Enable coredump generation to this file after a WebAssembly trap.
         pub coredump: Option<String>,
+        /// Collect code coverage and write a report to this file when the
+        /// program exits.
+        pub coverage: Option<String>,
     }
 
     enum Debug {
@@ -411,6 +414,9 @@ impl CommonOptions {
             #[cfg(not(feature = "coredump"))]
             anyhow::bail!("support for coredumps disabled at compile time");
         }
+        if self.debug.coverage.is_some() {
+            config.coverage(true);
+        }
         match_feature! {
             ["cranelift" : self.opts.opt_level]
             level => config.cranelift_opt_level(level),
@@ -2,6 +2,7 @@ use syn::{parse_macro_input, DeriveInput, Error};
 
 mod bindgen;
 mod component;
+mod wasm_results;
 
 #[proc_macro_derive(Lift, attributes(component))]
 pub fn lift(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -33,6 +34,13 @@ pub fn component_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     .into()
 }
 
+#[proc_macro_derive(WasmResults)]
+pub fn wasm_results(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    wasm_results::expand(&parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
 #[proc_macro]
 pub fn flags(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     component::expand_flags(&parse_macro_input!(input as component::Flags))
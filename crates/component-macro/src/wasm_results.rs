@@ -0,0 +1,122 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DeriveInput, Error, Result};
+
+/// Expands `#[derive(WasmResults)]` for a struct whose fields are core wasm
+/// types.
+///
+/// The struct is passed to and from wasm exactly like the tuple of its field
+/// types, so all the generated impls convert between the two and forward to
+/// the tuple's impls.
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(body) => &body.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`WasmResults` can only be derived for structs",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let tys = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let members = fields.members().collect::<Vec<_>>();
+    let vars = (0..members.len())
+        .map(|i| format_ident!("field{}", i))
+        .collect::<Vec<_>>();
+    let tuple = quote!((#(#tys,)*));
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#tuple: wasmtime::WasmResults + wasmtime::WasmRet));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let internal = quote!(wasmtime::__internal);
+
+    Ok(quote! {
+        unsafe impl #impl_generics wasmtime::WasmParams for #name #ty_generics #where_clause {
+            type Abi = <#tuple as wasmtime::WasmParams>::Abi;
+
+            fn typecheck(
+                params: impl ExactSizeIterator<Item = wasmtime::ValType>,
+            ) -> wasmtime::Result<()> {
+                <#tuple as wasmtime::WasmParams>::typecheck(params)
+            }
+
+            #[inline]
+            fn externrefs_count(&self) -> usize {
+                0 #(+ <#tys as wasmtime::WasmTy>::is_externref(&self.#members) as usize)*
+            }
+
+            #[inline]
+            fn into_abi(self, store: &mut #internal::StoreOpaque) -> Option<Self::Abi> {
+                let Self { #(#members: #vars,)* } = self;
+                <#tuple as wasmtime::WasmParams>::into_abi((#(#vars,)*), store)
+            }
+
+            unsafe fn invoke<R: wasmtime::WasmResults>(
+                func: ::core::ptr::NonNull<#internal::VMNativeCallFunction>,
+                vmctx1: *mut #internal::VMOpaqueContext,
+                vmctx2: *mut #internal::VMContext,
+                abi: Self::Abi,
+            ) -> R::ResultAbi {
+                <#tuple as wasmtime::WasmParams>::invoke::<R>(func, vmctx1, vmctx2, abi)
+            }
+        }
+
+        unsafe impl #impl_generics wasmtime::WasmResults for #name #ty_generics #where_clause {
+            type ResultAbi = <#tuple as wasmtime::WasmResults>::ResultAbi;
+
+            #[inline]
+            unsafe fn from_abi(store: &mut #internal::StoreOpaque, abi: Self::ResultAbi) -> Self {
+                let (#(#vars,)*) = <#tuple as wasmtime::WasmResults>::from_abi(store, abi);
+                Self { #(#members: #vars,)* }
+            }
+        }
+
+        unsafe impl #impl_generics wasmtime::WasmRet for #name #ty_generics #where_clause {
+            type Abi = <#tuple as wasmtime::WasmRet>::Abi;
+            type Retptr = <#tuple as wasmtime::WasmRet>::Retptr;
+            type Fallible = wasmtime::Result<Self>;
+
+            #[inline]
+            fn compatible_with_store(&self, _store: &#internal::StoreOpaque) -> bool {
+                true #(&& <#tys as wasmtime::WasmTy>::compatible_with_store(&self.#members, _store))*
+            }
+
+            #[inline]
+            unsafe fn into_abi_for_ret(
+                self,
+                store: &mut #internal::StoreOpaque,
+                ptr: Self::Retptr,
+            ) -> wasmtime::Result<Self::Abi> {
+                let Self { #(#members: #vars,)* } = self;
+                <#tuple as wasmtime::WasmRet>::into_abi_for_ret((#(#vars,)*), store, ptr)
+            }
+
+            fn func_type(params: impl Iterator<Item = wasmtime::ValType>) -> wasmtime::FuncType {
+                <#tuple as wasmtime::WasmRet>::func_type(params)
+            }
+
+            unsafe fn wrap_trampoline(
+                ptr: *mut wasmtime::ValRaw,
+                f: impl FnOnce(Self::Retptr) -> Self::Abi,
+            ) {
+                <#tuple as wasmtime::WasmRet>::wrap_trampoline(ptr, f)
+            }
+
+            #[inline]
+            fn into_fallible(self) -> wasmtime::Result<Self> {
+                Ok(self)
+            }
+
+            #[inline]
+            fn fallible_from_error(error: wasmtime::Error) -> wasmtime::Result<Self> {
+                Err(error)
+            }
+        }
+    })
+}
@@ -107,6 +107,132 @@ mod with_key_and_resources {
     }
 }
 
+mod async_resource_drops {
+    use anyhow::Result;
+    use wasmtime::component::Resource;
+
+    // Making every import async makes resource destructors async too.
+    mod all {
+        wasmtime::component::bindgen!({
+            inline: "
+                package demo:pkg;
+
+                interface i {
+                    resource a;
+                    f: func();
+                }
+
+                world foo {
+                    import i;
+                }
+            ",
+            async: true,
+        });
+    }
+
+    // Destructors can be kept synchronous individually.
+    mod except {
+        wasmtime::component::bindgen!({
+            inline: "
+                package demo:pkg;
+
+                interface i {
+                    resource a;
+                    resource b;
+                    f: func();
+                }
+
+                world foo {
+                    import i;
+                }
+            ",
+            async: {
+                except_imports: ["[resource-drop]b"],
+            },
+        });
+    }
+
+    // Or made async individually.
+    mod only {
+        wasmtime::component::bindgen!({
+            inline: "
+                package demo:pkg;
+
+                interface i {
+                    resource a;
+                    resource b;
+                    f: func();
+                }
+
+                world foo {
+                    import i;
+                }
+            ",
+            async: {
+                only_imports: ["f", "[resource-drop]a"],
+            },
+        });
+    }
+
+    struct MyComponent;
+
+    #[wasmtime::component::__internal::async_trait]
+    impl all::demo::pkg::i::Host for MyComponent {
+        async fn f(&mut self) -> Result<()> {
+            loop {}
+        }
+    }
+
+    #[wasmtime::component::__internal::async_trait]
+    impl all::demo::pkg::i::HostA for MyComponent {
+        async fn drop(&mut self, _: Resource<all::demo::pkg::i::A>) -> Result<()> {
+            loop {}
+        }
+    }
+
+    #[wasmtime::component::__internal::async_trait]
+    impl except::demo::pkg::i::Host for MyComponent {
+        async fn f(&mut self) -> Result<()> {
+            loop {}
+        }
+    }
+
+    #[wasmtime::component::__internal::async_trait]
+    impl except::demo::pkg::i::HostA for MyComponent {
+        async fn drop(&mut self, _: Resource<except::demo::pkg::i::A>) -> Result<()> {
+            loop {}
+        }
+    }
+
+    #[wasmtime::component::__internal::async_trait]
+    impl except::demo::pkg::i::HostB for MyComponent {
+        fn drop(&mut self, _: Resource<except::demo::pkg::i::B>) -> Result<()> {
+            loop {}
+        }
+    }
+
+    #[wasmtime::component::__internal::async_trait]
+    impl only::demo::pkg::i::Host for MyComponent {
+        async fn f(&mut self) -> Result<()> {
+            loop {}
+        }
+    }
+
+    #[wasmtime::component::__internal::async_trait]
+    impl only::demo::pkg::i::HostA for MyComponent {
+        async fn drop(&mut self, _: Resource<only::demo::pkg::i::A>) -> Result<()> {
+            loop {}
+        }
+    }
+
+    #[wasmtime::component::__internal::async_trait]
+    impl only::demo::pkg::i::HostB for MyComponent {
+        fn drop(&mut self, _: Resource<only::demo::pkg::i::B>) -> Result<()> {
+            loop {}
+        }
+    }
+}
+
 mod trappable_errors {
     wasmtime::component::bindgen!({
         inline: "
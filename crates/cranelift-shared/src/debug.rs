@@ -0,0 +1,106 @@
+//! Debug utils for WebAssembly compiled by Cranelift or Winch.
+
+use crate::CompiledFunctionMetadata;
+use anyhow::{Context as _, Result};
+use cranelift_codegen::isa::TargetIsa;
+use object::write::{Object, StandardSegment, SymbolId};
+use object::{RelocationEncoding, RelocationKind, SectionKind};
+use std::collections::HashMap;
+use wasmtime_environ::{
+    DefinedFuncIndex, EntityRef, MemoryIndex, ModuleTranslation, OwnedMemoryIndex, PrimaryMap,
+    VMOffsets,
+};
+
+/// Memory definition offset in the VMContext structure.
+#[derive(Debug, Clone)]
+pub enum ModuleMemoryOffset {
+    /// Not available.
+    None,
+    /// Offset to the defined memory.
+    Defined(u32),
+    /// Offset to the imported memory.
+    Imported(u32),
+}
+
+pub use write_debuginfo::{emit_dwarf, DwarfSection, DwarfSectionRelocTarget};
+
+mod gc;
+mod transform;
+mod write_debuginfo;
+
+/// Translates the DWARF of the module in `translation` to describe the
+/// compiled `funcs` and appends the resulting sections to `obj`.
+///
+/// The line tables are generated from the address maps of `funcs`, which map
+/// the generated code back to wasm offsets, so this works for any compiler
+/// which records them, not just Cranelift.
+pub fn append_dwarf(
+    isa: &dyn TargetIsa,
+    obj: &mut Object<'_>,
+    translation: &ModuleTranslation<'_>,
+    funcs: &PrimaryMap<DefinedFuncIndex, (SymbolId, &CompiledFunctionMetadata)>,
+) -> Result<()> {
+    let ofs = VMOffsets::new(
+        isa.triple().architecture.pointer_width().unwrap().bytes(),
+        &translation.module,
+    );
+
+    let memory_offset = if ofs.num_imported_memories > 0 {
+        ModuleMemoryOffset::Imported(ofs.vmctx_vmmemory_import(MemoryIndex::new(0)))
+    } else if ofs.num_defined_memories > 0 {
+        // The addition of shared memory makes the following assumption,
+        // "owned memory index = 0", possibly false. If the first memory
+        // is a shared memory, the base pointer will not be stored in
+        // the `owned_memories` array. The following code should
+        // eventually be fixed to not only handle shared memories but
+        // also multiple memories.
+        assert_eq!(
+            ofs.num_defined_memories, ofs.num_owned_memories,
+            "the memory base pointer may be incorrect due to sharing memory"
+        );
+        ModuleMemoryOffset::Defined(ofs.vmctx_vmmemory_definition_base(OwnedMemoryIndex::new(0)))
+    } else {
+        ModuleMemoryOffset::None
+    };
+    let functions_info = funcs.values().map(|(_, metadata)| *metadata).collect();
+    let dwarf_sections = emit_dwarf(isa, &translation.debuginfo, &functions_info, &memory_offset)
+        .with_context(|| "failed to emit DWARF debug information")?;
+
+    let (debug_bodies, debug_relocs): (Vec<_>, Vec<_>) = dwarf_sections
+        .iter()
+        .map(|s| ((s.name, &s.body), (s.name, &s.relocs)))
+        .unzip();
+    let mut dwarf_sections_ids = HashMap::new();
+    for (name, body) in debug_bodies {
+        let segment = obj.segment_name(StandardSegment::Debug).to_vec();
+        let section_id = obj.add_section(segment, name.as_bytes().to_vec(), SectionKind::Debug);
+        dwarf_sections_ids.insert(name, section_id);
+        obj.append_section_data(section_id, &body, 1);
+    }
+
+    // Write all debug data relocations.
+    for (name, relocs) in debug_relocs {
+        let section_id = *dwarf_sections_ids.get(name).unwrap();
+        for reloc in relocs {
+            let target_symbol = match reloc.target {
+                DwarfSectionRelocTarget::Func(index) => funcs[DefinedFuncIndex::new(index)].0,
+                DwarfSectionRelocTarget::Section(name) => {
+                    obj.section_symbol(dwarf_sections_ids[name])
+                }
+            };
+            obj.add_relocation(
+                section_id,
+                object::write::Relocation {
+                    offset: u64::from(reloc.offset),
+                    size: reloc.size << 3,
+                    kind: RelocationKind::Absolute,
+                    encoding: RelocationEncoding::Generic,
+                    symbol: target_symbol,
+                    addend: i64::from(reloc.addend),
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
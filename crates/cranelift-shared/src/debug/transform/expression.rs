@@ -843,7 +843,7 @@ mod tests {
 
     #[test]
     fn test_debug_parse_expressions() {
-        use cranelift_entity::EntityRef;
+        use wasmtime_environ::EntityRef;
 
         let (val1, val3, val20) = (ValueLabel::new(1), ValueLabel::new(3), ValueLabel::new(20));
 
@@ -1113,9 +1113,9 @@ mod tests {
     }
 
     fn create_mock_address_transform() -> AddressTransform {
-        use cranelift_entity::PrimaryMap;
-        use wasmtime_cranelift_shared::FunctionAddressMap;
+        use crate::FunctionAddressMap;
         use wasmtime_environ::InstructionAddressMap;
+        use wasmtime_environ::PrimaryMap;
         use wasmtime_environ::WasmFileInfo;
 
         let mut module_map = PrimaryMap::new();
@@ -1160,8 +1160,8 @@ mod tests {
 
     fn create_mock_value_ranges() -> (ValueLabelsRanges, (ValueLabel, ValueLabel, ValueLabel)) {
         use cranelift_codegen::{LabelValueLoc, ValueLocRange};
-        use cranelift_entity::EntityRef;
         use std::collections::HashMap;
+        use wasmtime_environ::EntityRef;
         let mut value_ranges = HashMap::new();
         let value_0 = ValueLabel::new(0);
         let value_1 = ValueLabel::new(1);
@@ -6,10 +6,10 @@ use cranelift_codegen::isa::{
     unwind::{CfaUnwindInfo, UnwindInfo},
     TargetIsa,
 };
-use cranelift_entity::EntityRef;
 use gimli::write::{Address, Dwarf, EndianVec, FrameTable, Result, Sections, Writer};
 use gimli::{RunTimeEndian, SectionId};
 use wasmtime_environ::DebugInfoData;
+use wasmtime_environ::EntityRef;
 
 #[allow(missing_docs)]
 pub struct DwarfSection {
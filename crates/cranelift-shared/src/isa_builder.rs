@@ -94,6 +94,20 @@ impl<T> IsaBuilder<T> {
             .finish(settings::Flags::new(self.shared_flags.clone()))
     }
 
+    /// Builds the ISA with the same settings as [`IsaBuilder::build`] except
+    /// that Cranelift's optimizations are disabled and registers are allocated
+    /// by the single-pass allocator, so that compilation time is bounded.
+    pub fn build_unoptimized(&self) -> T {
+        let mut shared_flags = self.shared_flags.clone();
+        shared_flags
+            .set("opt_level", "none")
+            .expect("should be valid flag");
+        shared_flags
+            .set("regalloc_algorithm", "single_pass")
+            .expect("should be valid flag");
+        self.inner.finish(settings::Flags::new(shared_flags))
+    }
+
     pub fn shared_flags(&self) -> Flags {
         settings::Flags::new(self.shared_flags.clone())
     }
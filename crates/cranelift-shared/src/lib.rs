@@ -1,15 +1,22 @@
 use cranelift_codegen::{
     binemit,
     ir::{self, ExternalName, UserExternalNameRef},
-    settings, FinalizedMachReloc, FinalizedRelocTarget, MachTrap,
+    settings, FinalizedMachReloc, FinalizedRelocTarget, MachStackMap, MachTrap,
+};
+use wasmtime_environ::{
+    DefinedFuncIndex, FlagValue, FuncIndex, PrimaryMap, StackMapInformation, Trap, TrapInformation,
+    FIRST_RESERVED_TRAP_CODE,
 };
-use wasmtime_environ::{FlagValue, FuncIndex, Trap, TrapInformation};
 
 pub mod isa_builder;
 mod obj;
 pub use obj::*;
 mod compiled_function;
 pub use compiled_function::*;
+mod debug;
+pub use debug::append_dwarf;
+
+type CompiledFunctionsMetadata<'a> = PrimaryMap<DefinedFuncIndex, &'a CompiledFunctionMetadata>;
 
 /// A record of a relocation to perform.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -31,6 +38,8 @@ pub enum RelocationTarget {
     UserFunc(FuncIndex),
     /// A compiler-generated libcall.
     LibCall(ir::LibCall),
+    /// A symbol known to the linker, resolved by the runtime.
+    KnownSymbol(ir::KnownSymbol),
 }
 
 /// Converts cranelift_codegen settings to the wasmtime_environ equivalent.
@@ -59,12 +68,12 @@ const DEBUG_ASSERT_TRAP_CODE: u16 = u16::MAX;
 /// indicates that, as expected, the always-trapping function indeed did trap.
 /// This effectively provides a better error message as opposed to a bland
 /// "unreachable code reached"
-pub const ALWAYS_TRAP_CODE: u16 = 100;
+pub const ALWAYS_TRAP_CODE: u16 = FIRST_RESERVED_TRAP_CODE;
 
 /// A custom code with `TrapCode::User` corresponding to being unable to reenter
 /// a component due to its reentrance limitations. This is used in component
 /// adapters to provide a more useful error message in such situations.
-pub const CANNOT_ENTER_CODE: u16 = 101;
+pub const CANNOT_ENTER_CODE: u16 = FIRST_RESERVED_TRAP_CODE + 1;
 
 /// Converts machine traps to trap information.
 pub fn mach_trap_to_trap(trap: &MachTrap) -> Option<TrapInformation> {
@@ -92,12 +101,41 @@ pub fn mach_trap_to_trap(trap: &MachTrap) -> Option<TrapInformation> {
             // these, we let the signal crash the process.
             ir::TrapCode::User(DEBUG_ASSERT_TRAP_CODE) => return None,
 
+            // Codes below the reserved ones are defined by embedders.
+            ir::TrapCode::User(code) if code < FIRST_RESERVED_TRAP_CODE => Trap::User(code),
+
             // these should never be emitted by wasmtime-cranelift
             ir::TrapCode::User(_) => unreachable!(),
         },
     })
 }
 
+/// Converts machine stack maps to stack map information, sorted by code
+/// offset.
+pub fn mach_stack_maps_to_stack_maps(mach_stack_maps: &[MachStackMap]) -> Vec<StackMapInformation> {
+    // This is converting from Cranelift's representation of a stack map to
+    // Wasmtime's representation. They happen to align today but that may
+    // not always be true in the future.
+    let mut stack_maps = Vec::new();
+    for &MachStackMap {
+        offset_end,
+        ref stack_map,
+        ..
+    } in mach_stack_maps
+    {
+        let stack_map = wasmtime_environ::StackMap::new(
+            stack_map.mapped_words(),
+            stack_map.as_slice().iter().map(|a| a.0),
+        );
+        stack_maps.push(StackMapInformation {
+            code_offset: offset_end,
+            stack_map,
+        });
+    }
+    stack_maps.sort_unstable_by_key(|info| info.code_offset);
+    stack_maps
+}
+
 /// Converts machine relocations to relocation information
 /// to perform.
 fn mach_reloc_to_reloc<F>(reloc: &FinalizedMachReloc, transform_user_func_ref: F) -> Relocation
@@ -119,6 +157,9 @@ where
         FinalizedRelocTarget::ExternalName(ExternalName::LibCall(libcall)) => {
             RelocationTarget::LibCall(libcall)
         }
+        FinalizedRelocTarget::ExternalName(ExternalName::KnownSymbol(symbol)) => {
+            RelocationTarget::KnownSymbol(symbol)
+        }
         _ => panic!("unrecognized external name"),
     };
     Relocation {
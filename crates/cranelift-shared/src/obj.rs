@@ -13,10 +13,10 @@
 //! function body, the imported wasm function do not. The trampolines symbol
 //! names have format "_trampoline_N", where N is `SignatureIndex`.
 
-use crate::{CompiledFuncEnv, CompiledFunction, RelocationTarget};
+use crate::{CompiledFuncEnv, CompiledFunction, Relocation, RelocationTarget};
 use anyhow::Result;
 use cranelift_codegen::binemit::Reloc;
-use cranelift_codegen::ir::LibCall;
+use cranelift_codegen::ir::{KnownSymbol, LibCall};
 use cranelift_codegen::isa::unwind::{systemv, UnwindInfo};
 use cranelift_codegen::TextSectionBuilder;
 use cranelift_control::ControlPlane;
@@ -54,12 +54,12 @@ pub struct ModuleTextBuilder<'a> {
     /// build to resolve relocations (calls) between functions.
     text: Box<dyn TextSectionBuilder>,
 
-    /// Symbols defined in the object for libcalls that relocations are applied
-    /// against.
+    /// Symbols defined in the object for libcalls and known symbols that
+    /// relocations are applied against, keyed by their name.
     ///
     /// Note that this isn't typically used. It's only used for SSE-disabled
-    /// builds without SIMD on x86_64 right now.
-    libcall_symbols: HashMap<LibCall, SymbolId>,
+    /// builds without SIMD on x86_64 and for aarch64 outline atomics right now.
+    libcall_symbols: HashMap<&'static str, SymbolId>,
 
     ctrl_plane: ControlPlane,
 }
@@ -172,45 +172,64 @@ impl<'a> ModuleTextBuilder<'a> {
                 // directly into the object as a normal object relocation. This
                 // is processed at module load time to resolve the relocations.
                 RelocationTarget::LibCall(call) => {
-                    let symbol = *self.libcall_symbols.entry(call).or_insert_with(|| {
-                        self.obj.add_symbol(Symbol {
-                            name: libcall_name(call).as_bytes().to_vec(),
-                            value: 0,
-                            size: 0,
-                            kind: SymbolKind::Text,
-                            scope: SymbolScope::Linkage,
-                            weak: false,
-                            section: SymbolSection::Undefined,
-                            flags: SymbolFlags::None,
-                        })
-                    });
-                    let (encoding, kind, size) = match r.reloc {
-                        Reloc::Abs8 => (
-                            object::RelocationEncoding::Generic,
-                            object::RelocationKind::Absolute,
-                            8,
-                        ),
-                        other => unimplemented!("unimplemented relocation kind {other:?}"),
-                    };
-                    self.obj
-                        .add_relocation(
-                            self.text_section,
-                            object::write::Relocation {
-                                symbol,
-                                size,
-                                kind,
-                                encoding,
-                                offset: off + u64::from(r.offset),
-                                addend: r.addend,
-                            },
-                        )
-                        .unwrap();
+                    self.add_symbol_relocation(libcall_name(call), off, &r);
+                }
+
+                // Symbols known to the linker are resolved the same way as
+                // libcalls, by the runtime when the module is loaded. These
+                // only show up in non-default configurations such as aarch64
+                // with `outline_atomics` enabled.
+                RelocationTarget::KnownSymbol(symbol) => {
+                    self.add_symbol_relocation(known_symbol_name(symbol), off, &r);
                 }
             };
         }
         (symbol_id, off..off + body_len)
     }
 
+    /// Records `r`, of the function at `off` in the text section, as a
+    /// relocation against the undefined symbol `name` in the final object.
+    ///
+    /// Currently these relocations are all expected to be absolute 8-byte
+    /// relocations so that's asserted here and then encoded directly into the
+    /// object as a normal object relocation. This is processed at module load
+    /// time to resolve the relocations.
+    fn add_symbol_relocation(&mut self, name: &'static str, off: u64, r: &Relocation) {
+        let symbol = *self.libcall_symbols.entry(name).or_insert_with(|| {
+            self.obj.add_symbol(Symbol {
+                name: name.as_bytes().to_vec(),
+                value: 0,
+                size: 0,
+                kind: SymbolKind::Text,
+                scope: SymbolScope::Linkage,
+                weak: false,
+                section: SymbolSection::Undefined,
+                flags: SymbolFlags::None,
+            })
+        });
+        let (encoding, kind, size) = match r.reloc {
+            Reloc::Abs8 => (
+                object::RelocationEncoding::Generic,
+                object::RelocationKind::Absolute,
+                8,
+            ),
+            other => unimplemented!("unimplemented relocation kind {other:?}"),
+        };
+        self.obj
+            .add_relocation(
+                self.text_section,
+                object::write::Relocation {
+                    symbol,
+                    size,
+                    kind,
+                    encoding,
+                    offset: off + u64::from(r.offset),
+                    addend: r.addend,
+                },
+            )
+            .unwrap();
+    }
+
     /// Forces "veneers" to be used for inter-function calls in the text
     /// section which means that in-bounds optimized addresses are never used.
     ///
@@ -563,3 +582,12 @@ fn libcall_name(call: LibCall) -> &'static str {
     };
     other.symbol()
 }
+
+fn known_symbol_name(symbol: KnownSymbol) -> &'static str {
+    use wasmtime_environ::obj::LibCall as LC;
+    let other = match symbol {
+        KnownSymbol::Aarch64HaveLseAtomics => LC::Aarch64HaveLseAtomics,
+        _ => panic!("unknown symbol to give a name to: {symbol:?}"),
+    };
+    other.symbol()
+}
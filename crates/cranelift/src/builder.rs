@@ -11,6 +11,7 @@ use cranelift_codegen::{
 use std::fmt;
 use std::path;
 use std::sync::Arc;
+use std::time::Duration;
 use wasmtime_cranelift_shared::isa_builder::IsaBuilder;
 use wasmtime_environ::{CacheStore, CompilerBuilder, Setting, Tunables};
 
@@ -21,6 +22,7 @@ struct Builder {
     cache_store: Option<Arc<dyn CacheStore>>,
     clif_dir: Option<path::PathBuf>,
     wmemcheck: bool,
+    compile_time_budget: Option<Duration>,
 }
 
 #[derive(Clone, Default)]
@@ -44,6 +46,7 @@ pub fn builder() -> Box<dyn CompilerBuilder> {
         cache_store: None,
         clif_dir: None,
         wmemcheck: false,
+        compile_time_budget: None,
     })
 }
 
@@ -87,6 +90,13 @@ impl CompilerBuilder for Builder {
 
     fn build(&self) -> Result<Box<dyn wasmtime_environ::Compiler>> {
         let isa = self.inner.build()?;
+        let compile_budget = match self.compile_time_budget {
+            Some(limit) => Some(crate::compiler::CompileBudget {
+                limit,
+                fallback_isa: self.inner.build_unoptimized()?,
+            }),
+            None => None,
+        };
         Ok(Box::new(crate::compiler::Compiler::new(
             self.tunables.clone(),
             isa,
@@ -94,6 +104,7 @@ impl CompilerBuilder for Builder {
             self.linkopts.clone(),
             self.clif_dir.clone(),
             self.wmemcheck,
+            compile_budget,
         )))
     }
 
@@ -112,6 +123,11 @@ impl CompilerBuilder for Builder {
     fn wmemcheck(&mut self, enable: bool) {
         self.wmemcheck = enable;
     }
+
+    fn compile_time_budget(&mut self, budget: Option<Duration>) -> Result<()> {
+        self.compile_time_budget = budget;
+        Ok(())
+    }
 }
 
 impl fmt::Debug for Builder {
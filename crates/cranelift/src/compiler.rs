@@ -1,8 +1,7 @@
-use crate::debug::{DwarfSectionRelocTarget, ModuleMemoryOffset};
 use crate::func_environ::FuncEnvironment;
 use crate::{array_call_signature, native_call_signature, DEBUG_ASSERT_TRAP_CODE};
 use crate::{builder::LinkOptions, value_type, wasm_call_signature};
-use anyhow::{Context as _, Result};
+use anyhow::Result;
 use cranelift_codegen::ir::{
     self, InstBuilder, MemFlags, UserExternalName, UserExternalNameRef, UserFuncName, Value,
 };
@@ -12,28 +11,26 @@ use cranelift_codegen::isa::{
 };
 use cranelift_codegen::print_errors::pretty_error;
 use cranelift_codegen::Context;
-use cranelift_codegen::{CompiledCode, MachStackMap};
-use cranelift_entity::{EntityRef, PrimaryMap};
+use cranelift_codegen::{CodegenError, CompiledCode};
+use cranelift_entity::PrimaryMap;
 use cranelift_frontend::FunctionBuilder;
-use cranelift_wasm::{
-    DefinedFuncIndex, FuncIndex, FuncTranslator, MemoryIndex, OwnedMemoryIndex, WasmFuncType,
-    WasmType,
-};
-use object::write::{Object, StandardSegment, SymbolId};
-use object::{RelocationEncoding, RelocationKind, SectionKind};
+use cranelift_wasm::{DefinedFuncIndex, FuncIndex, FuncTranslator, WasmFuncType, WasmType};
+use object::write::{Object, SymbolId};
 use std::any::Any;
 use std::cmp;
-use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::mem;
 use std::path;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use wasmparser::{FuncValidatorAllocations, FunctionBody};
-use wasmtime_cranelift_shared::{CompiledFunction, ModuleTextBuilder};
+use wasmtime_cranelift_shared::{
+    mach_stack_maps_to_stack_maps, CompiledFunction, ModuleTextBuilder,
+};
 use wasmtime_environ::{
-    AddressMapSection, CacheStore, CompileError, FlagValue, FunctionBodyData, FunctionLoc,
-    ModuleTranslation, ModuleTypes, PtrSize, StackMapInformation, TrapEncodingBuilder, Tunables,
-    VMOffsets, WasmFunctionInfo,
+    AddressMapGranularity, AddressMapSection, CacheStore, CompileError, FlagValue,
+    FunctionBodyData, FunctionLoc, InstructionAddressMap, ModuleTranslation, ModuleTypes, PtrSize,
+    TrapEncodingBuilder, Tunables, VMOffsets, WasmFunctionInfo,
 };
 
 #[cfg(feature = "component-model")]
@@ -74,6 +71,16 @@ pub(crate) struct Compiler {
     cache_store: Option<Arc<dyn CacheStore>>,
     clif_dir: Option<path::PathBuf>,
     wmemcheck: bool,
+    compile_budget: Option<CompileBudget>,
+}
+
+/// A limit on how long Cranelift may spend compiling a single Wasm function.
+pub(crate) struct CompileBudget {
+    /// How long the optimizing compilation of a function may take.
+    pub limit: Duration,
+    /// The ISA, with optimizations disabled, used to compile functions whose
+    /// optimizing compilation exceeds `limit`.
+    pub fallback_isa: OwnedTargetIsa,
 }
 
 impl Drop for Compiler {
@@ -112,6 +119,7 @@ impl Compiler {
         linkopts: LinkOptions,
         clif_dir: Option<path::PathBuf>,
         wmemcheck: bool,
+        compile_budget: Option<CompileBudget>,
     ) -> Compiler {
         Compiler {
             contexts: Default::default(),
@@ -121,6 +129,7 @@ impl Compiler {
             cache_store,
             clif_dir,
             wmemcheck,
+            compile_budget,
         }
     }
 }
@@ -139,6 +148,20 @@ impl wasmtime_environ::Compiler for Compiler {
         let sig = translation.module.functions[func_index].signature;
         let wasm_func_ty = &types[sig];
 
+        let FunctionBodyData { validator, body } = input;
+        if let Some(limit) = self.tunables.max_function_body_size {
+            let size = body.range().len();
+            if size > limit {
+                return Err(CompileError::FunctionTooLarge {
+                    func_index: func_index.as_u32(),
+                    reason: format!(
+                        "the function body is {size} bytes, larger than the configured \
+                         maximum of {limit} bytes"
+                    ),
+                });
+            }
+        }
+
         let mut compiler = self.function_compiler();
 
         let context = &mut compiler.cx.codegen_context;
@@ -154,6 +177,7 @@ impl wasmtime_environ::Compiler for Compiler {
 
         let mut func_env =
             FuncEnvironment::new(isa, translation, types, &self.tunables, self.wmemcheck);
+        func_env.use_branch_hints(func_index, body.range().start);
 
         // The `stack_limit` global value below is the implementation of stack
         // overflow checks in Wasmtime.
@@ -207,7 +231,6 @@ impl wasmtime_environ::Compiler for Compiler {
             flags: MemFlags::trusted(),
         });
         context.func.stack_limit = Some(stack_limit);
-        let FunctionBodyData { validator, body } = input;
         let mut validator =
             validator.into_validator(mem::take(&mut compiler.cx.validator_allocations));
         compiler.cx.func_translator.translate_body(
@@ -232,6 +255,11 @@ impl wasmtime_environ::Compiler for Compiler {
 
         let timing = cranelift_codegen::timing::take_current();
         log::debug!("{:?} translated in {:?}", func_index, timing.total());
+        log::debug!(
+            "{:?} spent {:?} in register allocation",
+            func_index,
+            timing.pass_total(cranelift_codegen::timing::Pass::regalloc)
+        );
         log::trace!("{:?} timing info\n{}", func_index, timing);
 
         Ok((info, Box::new(func)))
@@ -465,9 +493,20 @@ impl wasmtime_environ::Compiler for Compiler {
                 .downcast_ref::<CompiledFunction<CompiledFuncEnv>>()
                 .unwrap();
             let (sym, range) = builder.append_func(&sym, func, |idx| resolve_reloc(i, idx));
-            if self.tunables.generate_address_map {
-                let addr = func.address_map();
-                addrs.push(range.clone(), &addr.instructions);
+            match self.tunables.address_map_granularity {
+                AddressMapGranularity::Full => {
+                    let addr = func.address_map();
+                    addrs.push(range.clone(), &addr.instructions);
+                }
+                AddressMapGranularity::FunctionEntry => {
+                    let addr = func.address_map();
+                    let entry = InstructionAddressMap {
+                        srcloc: addr.start_srcloc,
+                        code_offset: 0,
+                    };
+                    addrs.push(range.clone(), &[entry]);
+                }
+                AddressMapGranularity::None => {}
             }
             traps.push(range.clone(), &func.traps().collect::<Vec<_>>());
             builder.append_padding(self.linkopts.padding_between_functions);
@@ -480,8 +519,8 @@ impl wasmtime_environ::Compiler for Compiler {
 
         builder.finish();
 
-        if self.tunables.generate_address_map {
-            addrs.append_to(obj);
+        if self.tunables.address_map_granularity != AddressMapGranularity::None {
+            addrs.append_to(obj, self.tunables.compress_artifacts);
         }
         traps.append_to(obj);
 
@@ -544,87 +583,14 @@ impl wasmtime_environ::Compiler for Compiler {
         translation: &ModuleTranslation<'_>,
         funcs: &PrimaryMap<DefinedFuncIndex, (SymbolId, &(dyn Any + Send))>,
     ) -> Result<()> {
-        let ofs = VMOffsets::new(
-            self.isa
-                .triple()
-                .architecture
-                .pointer_width()
-                .unwrap()
-                .bytes(),
-            &translation.module,
-        );
-
-        let memory_offset = if ofs.num_imported_memories > 0 {
-            ModuleMemoryOffset::Imported(ofs.vmctx_vmmemory_import(MemoryIndex::new(0)))
-        } else if ofs.num_defined_memories > 0 {
-            // The addition of shared memory makes the following assumption,
-            // "owned memory index = 0", possibly false. If the first memory
-            // is a shared memory, the base pointer will not be stored in
-            // the `owned_memories` array. The following code should
-            // eventually be fixed to not only handle shared memories but
-            // also multiple memories.
-            assert_eq!(
-                ofs.num_defined_memories, ofs.num_owned_memories,
-                "the memory base pointer may be incorrect due to sharing memory"
-            );
-            ModuleMemoryOffset::Defined(
-                ofs.vmctx_vmmemory_definition_base(OwnedMemoryIndex::new(0)),
-            )
-        } else {
-            ModuleMemoryOffset::None
-        };
-        let functions_info = funcs
+        let funcs = funcs
             .iter()
-            .map(|(_, (_, func))| {
+            .map(|(_, (sym, func))| {
                 let f: &CompiledFunction<CompiledFuncEnv> = func.downcast_ref().unwrap();
-                f.metadata()
+                (*sym, f.metadata())
             })
             .collect();
-        let dwarf_sections = crate::debug::emit_dwarf(
-            &*self.isa,
-            &translation.debuginfo,
-            &functions_info,
-            &memory_offset,
-        )
-        .with_context(|| "failed to emit DWARF debug information")?;
-
-        let (debug_bodies, debug_relocs): (Vec<_>, Vec<_>) = dwarf_sections
-            .iter()
-            .map(|s| ((s.name, &s.body), (s.name, &s.relocs)))
-            .unzip();
-        let mut dwarf_sections_ids = HashMap::new();
-        for (name, body) in debug_bodies {
-            let segment = obj.segment_name(StandardSegment::Debug).to_vec();
-            let section_id = obj.add_section(segment, name.as_bytes().to_vec(), SectionKind::Debug);
-            dwarf_sections_ids.insert(name, section_id);
-            obj.append_section_data(section_id, &body, 1);
-        }
-
-        // Write all debug data relocations.
-        for (name, relocs) in debug_relocs {
-            let section_id = *dwarf_sections_ids.get(name).unwrap();
-            for reloc in relocs {
-                let target_symbol = match reloc.target {
-                    DwarfSectionRelocTarget::Func(index) => funcs[DefinedFuncIndex::new(index)].0,
-                    DwarfSectionRelocTarget::Section(name) => {
-                        obj.section_symbol(dwarf_sections_ids[name])
-                    }
-                };
-                obj.add_relocation(
-                    section_id,
-                    object::write::Relocation {
-                        offset: u64::from(reloc.offset),
-                        size: reloc.size << 3,
-                        kind: RelocationKind::Absolute,
-                        encoding: RelocationEncoding::Generic,
-                        symbol: target_symbol,
-                        addend: i64::from(reloc.addend),
-                    },
-                )?;
-            }
-        }
-
-        Ok(())
+        wasmtime_cranelift_shared::append_dwarf(&*self.isa, obj, translation, &funcs)
     }
 
     fn create_systemv_cie(&self) -> Option<gimli::write::CommonInformationEntry> {
@@ -660,7 +626,7 @@ mod incremental_cache {
         let mut cache_store = CraneliftCacheStore(cache_ctx.cache_store.clone());
         let (compiled_code, from_cache) = context
             .compile_with_cache(isa, &mut cache_store, &mut Default::default())
-            .map_err(|error| CompileError::Codegen(pretty_error(&error.func, error.inner)))?;
+            .map_err(|error| codegen_error(&error.func, error.inner))?;
 
         if from_cache {
             cache_ctx.num_hits += 1;
@@ -684,6 +650,33 @@ fn compile_maybe_cached<'a>(
     compile_uncached(context, isa)
 }
 
+/// Converts a Cranelift error for `func` into a [`CompileError`].
+///
+/// Wasm functions which exceed the backend's limits are reported along with
+/// their function index rather than as a plain codegen error.
+fn codegen_error(func: &ir::Function, error: CodegenError) -> CompileError {
+    let reason = match error {
+        CodegenError::CodeTooLarge => {
+            "the generated code exceeds the limits of the compiler backend"
+        }
+        CodegenError::ImplLimitExceeded => {
+            "the function exceeds an implementation limit of the compiler backend, \
+             such as the configured `regalloc_max_insts_log2`"
+        }
+        _ => return CompileError::Codegen(pretty_error(func, error)),
+    };
+    match func.name {
+        UserFuncName::User(UserExternalName {
+            namespace: 0,
+            index,
+        }) => CompileError::FunctionTooLarge {
+            func_index: index,
+            reason: reason.to_string(),
+        },
+        _ => CompileError::Codegen(pretty_error(func, error)),
+    }
+}
+
 fn compile_uncached<'a>(
     context: &'a mut Context,
     isa: &dyn TargetIsa,
@@ -691,10 +684,51 @@ fn compile_uncached<'a>(
     let mut code_buf = Vec::new();
     let compiled_code = context
         .compile_and_emit(isa, &mut code_buf, &mut Default::default())
-        .map_err(|error| CompileError::Codegen(pretty_error(&error.func, error.inner)))?;
+        .map_err(|error| codegen_error(&error.func, error.inner))?;
     Ok((compiled_code, code_buf))
 }
 
+/// Compiles the function in `context` with `isa`, unless that takes longer
+/// than the `budget` allows, in which case the function is compiled again
+/// with the budget's fallback ISA.
+///
+/// Returns the ISA that produced the final compiled code, and whether that
+/// was the fallback ISA. Cranelift checks the deadline between its passes,
+/// so the optimizing compilation stops at the first pass boundary after the
+/// budget runs out. Functions compiled this way don't go through the
+/// incremental compilation cache.
+fn compile_with_budget<'a>(
+    context: &mut Context,
+    isa: &'a dyn TargetIsa,
+    budget: &'a CompileBudget,
+) -> Result<(&'a dyn TargetIsa, bool), CompileError> {
+    // Compilation transforms the function in place, so keep the original
+    // around in case it needs to be compiled again.
+    let func = context.func.clone();
+    context.set_deadline(Some(Instant::now() + budget.limit));
+    let result = context
+        .compile(isa, &mut Default::default())
+        .map(|_| ())
+        .map_err(|error| error.inner);
+    context.set_deadline(None);
+
+    match result {
+        Ok(()) => Ok((isa, false)),
+        Err(CodegenError::DeadlineExceeded) => {
+            log::debug!(
+                "compiling {} took longer than the budget of {:?}, \
+                 recompiling it with the fallback configuration",
+                context.func.name,
+                budget.limit,
+            );
+            context.func = func;
+            compile_uncached(context, &*budget.fallback_isa)?;
+            Ok((&*budget.fallback_isa, true))
+        }
+        Err(error) => Err(codegen_error(&context.func, error)),
+    }
+}
+
 impl Compiler {
     /// Creates a trampoline for calling a host function callee defined with the
     /// "array" calling convention from a native calling convention caller.
@@ -1022,10 +1056,25 @@ impl FunctionCompiler<'_> {
         body_and_tunables: Option<(&FunctionBody<'_>, &Tunables)>,
     ) -> Result<(WasmFunctionInfo, CompiledFunction<CompiledFuncEnv>), CompileError> {
         let context = &mut self.cx.codegen_context;
-        let isa = &*self.compiler.isa;
-        let (_, _code_buf) =
-            compile_maybe_cached(context, isa, self.cx.incremental_cache_ctx.as_mut())?;
+        let (isa, exceeded_compile_budget) =
+            match (&self.compiler.compile_budget, body_and_tunables) {
+                (Some(budget), Some(_)) => {
+                    compile_with_budget(context, &*self.compiler.isa, budget)?
+                }
+                _ => {
+                    let isa = &*self.compiler.isa;
+                    compile_maybe_cached(context, isa, self.cx.incremental_cache_ctx.as_mut())?;
+                    (isa, false)
+                }
+            };
         let compiled_code = context.compiled_code().unwrap();
+        if body_and_tunables.is_some() {
+            log::debug!(
+                "{} register allocation stats: {:?}",
+                context.func.name,
+                compiled_code.regalloc_stats
+            );
+        }
 
         // Give wasm functions, user defined code, a "preferred" alignment
         // instead of the minimum alignment as this can help perf in niche
@@ -1050,14 +1099,14 @@ impl FunctionCompiler<'_> {
             compiled_function.set_address_map(
                 offset as u32,
                 len as u32,
-                tunables.generate_address_map,
+                tunables.address_map_granularity == AddressMapGranularity::Full,
             );
         }
 
         if isa.flags().unwind_info() {
             let unwind = compiled_code
                 .create_unwind_info(isa)
-                .map_err(|error| CompileError::Codegen(pretty_error(&context.func, error)))?;
+                .map_err(|error| codegen_error(&context.func, error))?;
 
             if let Some(unwind_info) = unwind {
                 compiled_function.set_unwind_info(unwind_info);
@@ -1077,7 +1126,7 @@ impl FunctionCompiler<'_> {
             ) {
                 let cfa_unwind = compiled_code
                     .create_unwind_info_of_kind(isa, UnwindInfoKind::SystemV)
-                    .map_err(|error| CompileError::Codegen(pretty_error(&context.func, error)))?;
+                    .map_err(|error| codegen_error(&context.func, error))?;
 
                 if let Some(UnwindInfo::SystemV(cfa_unwind_info)) = cfa_unwind {
                     compiled_function.set_cfa_unwind_info(cfa_unwind_info);
@@ -1094,36 +1143,14 @@ impl FunctionCompiler<'_> {
             WasmFunctionInfo {
                 start_srcloc: compiled_function.metadata().address_map.start_srcloc,
                 stack_maps: stack_maps.into(),
+                codegen_stats: None,
+                exceeded_compile_budget,
             },
             compiled_function,
         ))
     }
 }
 
-fn mach_stack_maps_to_stack_maps(mach_stack_maps: &[MachStackMap]) -> Vec<StackMapInformation> {
-    // This is converting from Cranelift's representation of a stack map to
-    // Wasmtime's representation. They happen to align today but that may
-    // not always be true in the future.
-    let mut stack_maps = Vec::new();
-    for &MachStackMap {
-        offset_end,
-        ref stack_map,
-        ..
-    } in mach_stack_maps
-    {
-        let stack_map = wasmtime_environ::StackMap::new(
-            stack_map.mapped_words(),
-            stack_map.as_slice().iter().map(|a| a.0),
-        );
-        stack_maps.push(StackMapInformation {
-            code_offset: offset_end,
-            stack_map,
-        });
-    }
-    stack_maps.sort_unstable_by_key(|info| info.code_offset);
-    stack_maps
-}
-
 fn declare_and_call(
     builder: &mut FunctionBuilder,
     signature: ir::Signature,
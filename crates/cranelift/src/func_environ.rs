@@ -13,16 +13,16 @@ use cranelift_entity::{EntityRef, PrimaryMap};
 use cranelift_frontend::FunctionBuilder;
 use cranelift_frontend::Variable;
 use cranelift_wasm::{
-    self, FuncIndex, FuncTranslationState, GlobalIndex, GlobalVariable, Heap, HeapData, HeapStyle,
-    MemoryIndex, TableIndex, TargetEnvironment, TypeIndex, WasmHeapType, WasmRefType, WasmResult,
-    WasmType,
+    self, prepare_table_addr, FuncIndex, FuncTranslationState, GlobalIndex, GlobalVariable, Heap,
+    HeapData, HeapStyle, MemoryIndex, TableIndex, TargetEnvironment, TypeIndex, WasmHeapType,
+    WasmRefType, WasmResult, WasmType,
 };
 use std::convert::TryFrom;
 use std::mem;
 use wasmparser::Operator;
 use wasmtime_environ::{
-    BuiltinFunctionIndex, MemoryPlan, MemoryStyle, Module, ModuleTranslation, ModuleTypes, PtrSize,
-    TableStyle, Tunables, TypeConvert, VMOffsets, WASM_PAGE_SIZE,
+    is_coverage_point, BuiltinFunctionIndex, MemoryPlan, MemoryStyle, Module, ModuleTranslation,
+    ModuleTypes, PtrSize, TableStyle, Tunables, TypeConvert, VMOffsets, WASM_PAGE_SIZE,
 };
 use wasmtime_environ::{FUNCREF_INIT_BIT, FUNCREF_MASK};
 
@@ -90,6 +90,8 @@ macro_rules! declare_function_signatures {
             }
 
             $(
+                // Some builtins are only called from Winch.
+                #[allow(dead_code)]
                 fn $name(&mut self, func: &mut Function) -> ir::SigRef {
                     let sig = self.$name.unwrap_or_else(|| {
                         func.import_signature(Signature {
@@ -159,6 +161,26 @@ pub struct FuncEnvironment<'module_environment> {
     /// spill, and this isn't any worse than reloading each time.
     epoch_ptr_var: cranelift_frontend::Variable,
 
+    /// A cached pointer to the module's code coverage counters, when
+    /// collecting coverage. Initialized in the function prologue.
+    coverage_counters_var: cranelift_frontend::Variable,
+
+    /// The index of the next code coverage counter to increment.
+    coverage_counter: u32,
+
+    /// Whether the operator being translated starts a new basic block whose
+    /// entry is reported to the store, when tracing block entries.
+    block_trace_pending: bool,
+
+    /// Hints of how likely the conditional branches of the function being
+    /// translated are to be taken, keyed by the offset of the branch
+    /// relative to `body_offset`.
+    branch_hints: Vec<(u32, ir::BranchHint)>,
+
+    /// The offset of the function's body in the wasm binary, which the
+    /// offsets of `branch_hints` are relative to.
+    body_offset: u32,
+
     fuel_consumed: i64,
 
     #[cfg(feature = "wmemcheck")]
@@ -200,6 +222,11 @@ impl<'module_environment> FuncEnvironment<'module_environment> {
             fuel_var: Variable::new(0),
             epoch_deadline_var: Variable::new(0),
             epoch_ptr_var: Variable::new(0),
+            coverage_counters_var: Variable::new(0),
+            coverage_counter: 0,
+            block_trace_pending: false,
+            branch_hints: Vec::new(),
+            body_offset: 0,
             vmruntime_limits_ptr: Variable::new(0),
             translation: translation,
 
@@ -211,6 +238,25 @@ impl<'module_environment> FuncEnvironment<'module_environment> {
         }
     }
 
+    /// Uses the module's branch hints for `func`, whose body starts at
+    /// `body_offset` in the wasm binary, when translating it.
+    pub(crate) fn use_branch_hints(&mut self, func: FuncIndex, body_offset: usize) {
+        if let Some(hints) = self.translation.branch_hints.get(&func) {
+            self.branch_hints = hints
+                .iter()
+                .map(|&(offset, likely)| {
+                    let hint = if likely {
+                        ir::BranchHint::likely()
+                    } else {
+                        ir::BranchHint::unlikely()
+                    };
+                    (offset, hint)
+                })
+                .collect();
+            self.body_offset = body_offset as u32;
+        }
+    }
+
     fn pointer_type(&self) -> ir::Type {
         self.isa.pointer_type()
     }
@@ -645,6 +691,111 @@ impl<'module_environment> FuncEnvironment<'module_environment> {
         self.epoch_check(builder);
     }
 
+    fn coverage_function_entry(&mut self, builder: &mut FunctionBuilder<'_>) {
+        let func_index = match &builder.func.name {
+            UserFuncName::User(user) => FuncIndex::from_u32(user.index),
+            _ => panic!("function name not a UserFuncName::User as expected"),
+        };
+        let def_index = self.module.defined_func_index(func_index).unwrap();
+        self.coverage_counter = self.module.coverage.functions[def_index];
+
+        let pointer_type = self.pointer_type();
+        builder.declare_var(self.coverage_counters_var, pointer_type);
+        let vmctx = self.vmctx(builder.func);
+        let base = builder.ins().global_value(pointer_type, vmctx);
+        let offset = i32::try_from(self.offsets.vmctx_coverage_counters()).unwrap();
+        let counters = builder.ins().load(
+            pointer_type,
+            ir::MemFlags::trusted().with_readonly(),
+            base,
+            offset,
+        );
+        builder.def_var(self.coverage_counters_var, counters);
+
+        // The first counter counts calls to this function.
+        self.coverage_increment(builder);
+    }
+
+    /// Increments the next coverage counter at the current position.
+    ///
+    /// Counters are shared by all instances of a module, which may run on
+    /// different threads, but this is a plain load/add/store: coverage only
+    /// cares about whether a block ran, so an occasionally lost increment is
+    /// preferable to the cost of an atomic add.
+    fn coverage_increment(&mut self, builder: &mut FunctionBuilder<'_>) {
+        let counters = builder.use_var(self.coverage_counters_var);
+        let offset = i32::try_from(self.coverage_counter * 8).unwrap();
+        let count = builder
+            .ins()
+            .load(ir::types::I64, ir::MemFlags::trusted(), counters, offset);
+        let count = builder.ins().iadd_imm(count, 1);
+        builder
+            .ins()
+            .store(ir::MemFlags::trusted(), count, counters, offset);
+        self.coverage_counter += 1;
+    }
+
+    /// Reports a load or store of `val_size` bytes at `addr + offset` in
+    /// `memory` to the store, if memory tracing is enabled.
+    fn memory_trace(
+        &mut self,
+        builder: &mut FunctionBuilder<'_>,
+        memory: MemoryIndex,
+        is_store: bool,
+        val_size: u8,
+        addr: ir::Value,
+        offset: u64,
+    ) {
+        if !self.tunables.memory_trace {
+            return;
+        }
+        let memory_trace_sig = self.builtin_function_signatures.memory_trace(builder.func);
+        let (vmctx, memory_trace) = self.translate_load_builtin_function_address(
+            &mut builder.cursor(),
+            BuiltinFunctionIndex::memory_trace(),
+        );
+        let addr = if builder.func.dfg.value_type(addr) == I64 {
+            addr
+        } else {
+            builder.ins().uextend(I64, addr)
+        };
+        let addr = builder.ins().iadd_imm(addr, offset as i64);
+        let memory = builder.ins().iconst(I32, i64::from(memory.as_u32()));
+        let is_store = builder.ins().iconst(I32, i64::from(is_store));
+        let num_bytes = builder.ins().iconst(I32, i64::from(val_size));
+        // Source locations are offsets in the original wasm module.
+        let wasm_offset = i64::from(builder.srcloc().bits());
+        let wasm_offset = builder.ins().iconst(I32, wasm_offset);
+        builder.ins().call_indirect(
+            memory_trace_sig,
+            memory_trace,
+            &[vmctx, memory, is_store, addr, num_bytes, wasm_offset],
+        );
+    }
+
+    /// Reports the entry into the basic block starting at the current
+    /// operator to the store.
+    fn block_trace(&mut self, builder: &mut FunctionBuilder<'_>) {
+        let func_index = match &builder.func.name {
+            UserFuncName::User(user) => user.index,
+            _ => panic!("function name not a UserFuncName::User as expected"),
+        };
+        let block_trace_sig = self.builtin_function_signatures.block_trace(builder.func);
+        let (vmctx, block_trace) = self.translate_load_builtin_function_address(
+            &mut builder.cursor(),
+            BuiltinFunctionIndex::block_trace(),
+        );
+        let func_index = builder.ins().iconst(I32, i64::from(func_index));
+        // Source locations are offsets in the original wasm module.
+        let wasm_offset = i64::from(builder.srcloc().bits());
+        let wasm_offset = builder.ins().iconst(I32, wasm_offset);
+        builder.ins().call_indirect(
+            block_trace_sig,
+            block_trace,
+            &[vmctx, func_index, wasm_offset],
+        );
+    }
+
     #[cfg(feature = "wmemcheck")]
     fn hook_malloc_exit(&mut self, builder: &mut FunctionBuilder, retvals: &[Value]) {
         let check_malloc_sig = self.builtin_function_signatures.check_malloc(builder.func);
@@ -865,7 +1016,7 @@ impl<'module_environment> FuncEnvironment<'module_environment> {
         // contents, we check for a null entry here, and
         // if null, we take a slow-path that invokes a
         // libcall.
-        let table_entry_addr = builder.ins().table_addr(pointer_type, table, index, 0);
+        let table_entry_addr = prepare_table_addr(builder, self, table, index);
         let flags = ir::MemFlags::trusted().with_table();
         let value = builder.ins().load(pointer_type, flags, table_entry_addr, 0);
         // Mask off the "initialized bit". See documentation on
@@ -1222,6 +1373,10 @@ impl<'module_environment> TargetEnvironment for FuncEnvironment<'module_environm
         self.isa.flags().enable_heap_access_spectre_mitigation()
     }
 
+    fn table_access_spectre_mitigation(&self) -> bool {
+        self.isa.flags().enable_table_access_spectre_mitigation()
+    }
+
     fn proof_carrying_code(&self) -> bool {
         self.isa.flags().enable_pcc()
     }
@@ -1243,6 +1398,7 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         self.fuel_var = Variable::new(num_locals + 1);
         self.epoch_deadline_var = Variable::new(num_locals + 2);
         self.epoch_ptr_var = Variable::new(num_locals + 3);
+        self.coverage_counters_var = Variable::new(num_locals + 4);
     }
 
     fn make_table(&mut self, func: &mut ir::Function, index: TableIndex) -> WasmResult<ir::Table> {
@@ -1389,7 +1545,7 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
                 builder.insert_block_after(continue_block, gc_block);
 
                 // Load the table element.
-                let elem_addr = builder.ins().table_addr(pointer_type, table, index, 0);
+                let elem_addr = prepare_table_addr(builder, self, table, index);
                 let flags = ir::MemFlags::trusted().with_table();
                 let elem = builder.ins().load(reference_type, flags, elem_addr, 0);
 
@@ -1486,7 +1642,7 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         match plan.table.wasm_ty.heap_type {
             WasmHeapType::Func | WasmHeapType::TypedFunc(_) => match plan.style {
                 TableStyle::CallerChecksSignature => {
-                    let table_entry_addr = builder.ins().table_addr(pointer_type, table, index, 0);
+                    let table_entry_addr = prepare_table_addr(builder, self, table, index);
                     // Set the "initialized bit". See doc-comment on
                     // `FUNCREF_INIT_BIT` in
                     // crates/environ/src/ref_bits.rs for details.
@@ -1547,7 +1703,7 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
                 // bounds checks. This is the first thing we do, because we
                 // don't want to modify any ref counts if this `table.set` is
                 // going to trap.
-                let table_entry_addr = builder.ins().table_addr(pointer_type, table, index, 0);
+                let table_entry_addr = prepare_table_addr(builder, self, table, index);
 
                 // If value is not null, increment `value`'s ref count.
                 //
@@ -2532,12 +2688,24 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         Ok(())
     }
 
+    fn branch_hint(&self, srcloc: ir::SourceLoc) -> Option<ir::BranchHint> {
+        let offset = srcloc.bits().checked_sub(self.body_offset)?;
+        let i = self
+            .branch_hints
+            .binary_search_by_key(&offset, |(offset, _)| *offset)
+            .ok()?;
+        Some(self.branch_hints[i].1)
+    }
+
     fn before_translate_operator(
         &mut self,
         op: &Operator,
         builder: &mut FunctionBuilder,
         state: &FuncTranslationState,
     ) -> WasmResult<()> {
+        if std::mem::take(&mut self.block_trace_pending) && state.reachable() {
+            self.block_trace(builder);
+        }
         if self.tunables.consume_fuel {
             self.fuel_before_op(op, builder, state.reachable());
         }
@@ -2553,6 +2721,20 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         if self.tunables.consume_fuel && state.reachable() {
             self.fuel_after_op(op, builder);
         }
+        if self.tunables.coverage && is_coverage_point(op) {
+            if state.reachable() {
+                self.coverage_increment(builder);
+            } else {
+                // Keep counters in step with `CoverageMap`, which has one
+                // for every coverage point whether it's reachable or not.
+                self.coverage_counter += 1;
+            }
+        }
+        // Block entries are reported before the first operator of the new
+        // block, so that they carry its offset.
+        if self.tunables.block_trace && is_coverage_point(op) {
+            self.block_trace_pending = true;
+        }
         Ok(())
     }
 
@@ -2585,6 +2767,10 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         if self.tunables.epoch_interruption {
             self.epoch_function_entry(builder);
         }
+        if self.tunables.coverage {
+            self.coverage_function_entry(builder);
+        }
+        self.block_trace_pending = self.tunables.block_trace;
 
         let func_name = self.current_func_name(builder);
         if func_name == Some("malloc") {
@@ -2604,6 +2790,8 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         if self.tunables.consume_fuel && state.reachable() {
             self.fuel_function_exit(builder);
         }
+        // The block following the function's final `end` only returns.
+        self.block_trace_pending = false;
         if let Some(pcc_vmctx_memtype) = self.pcc_vmctx_memtype {
             // Sort the fields by offset in the struct definition for
             // vmctx, now that we've completed it.
@@ -2645,6 +2833,18 @@ impl<'module_environment> cranelift_wasm::FuncEnvironment for FuncEnvironment<'m
         self.isa.has_x86_pmaddubsw_lowering()
     }
 
+    fn before_memory_access(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        memory: MemoryIndex,
+        is_store: bool,
+        val_size: u8,
+        addr: ir::Value,
+        offset: u64,
+    ) {
+        self.memory_trace(builder, memory, is_store, val_size, addr, offset);
+    }
+
     cfg_if! {
         if #[cfg(feature = "wmemcheck")] {
             fn handle_before_return(
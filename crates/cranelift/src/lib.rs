@@ -5,21 +5,16 @@
 
 use cranelift_codegen::ir;
 use cranelift_codegen::isa::{CallConv, TargetIsa};
-use cranelift_entity::PrimaryMap;
-use cranelift_wasm::{DefinedFuncIndex, WasmFuncType, WasmType};
+use cranelift_wasm::{WasmFuncType, WasmType};
 use target_lexicon::Architecture;
-use wasmtime_cranelift_shared::CompiledFunctionMetadata;
 
 pub use builder::builder;
 use wasmtime_environ::Tunables;
 
 mod builder;
 mod compiler;
-mod debug;
 mod func_environ;
 
-type CompiledFunctionsMetadata<'a> = PrimaryMap<DefinedFuncIndex, &'a CompiledFunctionMetadata>;
-
 /// Trap code used for debug assertions we emit in our JIT code.
 const DEBUG_ASSERT_TRAP_CODE: u16 = u16::MAX;
 
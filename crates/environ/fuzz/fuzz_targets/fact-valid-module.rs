@@ -104,7 +104,7 @@ fn target(module: GenAdapterModule) {
                 let ty = types.intern_component_type(&ty.unwrap()).unwrap();
                 types.push_component_typedef(ty);
                 let ty = match ty {
-                    TypeDef::ComponentFunc(ty) => ty,
+                    TypeDef::ComponentFunc(ty, _) => ty,
                     _ => continue,
                 };
                 adapters.push(Adapter {
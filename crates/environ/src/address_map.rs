@@ -1,6 +1,6 @@
 //! Data structures to provide transformation of the source
 
-use crate::obj::ELF_WASMTIME_ADDRMAP;
+use crate::obj::{maybe_compress_section, ELF_WASMTIME_ADDRMAP};
 use object::write::{Object, StandardSegment};
 use object::{Bytes, LittleEndian, SectionKind, U32Bytes};
 use serde_derive::{Deserialize, Serialize};
@@ -47,12 +47,47 @@ impl FilePos {
     }
 }
 
+/// How much detail the address map of a compiled module records.
+///
+/// The address map is what translates a native program counter back to an
+/// offset in the original wasm module, which is used for backtraces and
+/// symbolication. For large modules it's one of the larger contributors to
+/// the size of a compiled artifact, so it can be trimmed down when precise
+/// source offsets aren't needed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AddressMapGranularity {
+    /// Record a mapping for every instruction that originated from a wasm
+    /// instruction. This is the default.
+    Full,
+    /// Record only the start of each function, so lookups resolve to the
+    /// offset of the enclosing function in the original wasm module rather
+    /// than to the precise instruction.
+    FunctionEntry,
+    /// Don't emit an address map at all.
+    None,
+}
+
+impl Default for AddressMapGranularity {
+    fn default() -> AddressMapGranularity {
+        AddressMapGranularity::Full
+    }
+}
+
 impl Default for FilePos {
     fn default() -> FilePos {
         FilePos(u32::MAX)
     }
 }
 
+/// Number of entries in the address map which are grouped together into one
+/// block of the encoded section.
+///
+/// The first entry of each block is stored uncompressed in a lookup table so
+/// that it can be binary searched, and the remaining entries are delta-encoded
+/// relative to their predecessor. Larger blocks compress better while smaller
+/// blocks make lookups cheaper.
+const BLOCK_SIZE: usize = 16;
+
 /// Builder for the address map section of a wasmtime compilation image.
 ///
 /// This builder is used to conveniently built the `ELF_WASMTIME_ADDRMAP`
@@ -60,9 +95,13 @@ impl Default for FilePos {
 /// into an `Object`.
 #[derive(Default)]
 pub struct AddressMapSection {
-    offsets: Vec<U32Bytes<LittleEndian>>,
-    positions: Vec<U32Bytes<LittleEndian>>,
+    count: u32,
+    block_offsets: Vec<U32Bytes<LittleEndian>>,
+    block_positions: Vec<U32Bytes<LittleEndian>>,
+    block_starts: Vec<U32Bytes<LittleEndian>>,
+    deltas: Vec<u8>,
     last_offset: u32,
+    last_entry: (u32, u32),
 }
 
 impl AddressMapSection {
@@ -85,52 +124,171 @@ impl AddressMapSection {
         let func_start = u32::try_from(func.start).unwrap();
         let func_end = u32::try_from(func.end).unwrap();
 
-        self.offsets.reserve(instrs.len());
-        self.positions.reserve(instrs.len());
         for map in instrs {
             // Sanity-check to ensure that functions are pushed in-order, otherwise
             // the `offsets` array won't be sorted which is our goal.
             let pos = func_start + map.code_offset;
             assert!(pos >= self.last_offset);
-            self.offsets.push(U32Bytes::new(LittleEndian, pos));
-            self.positions
-                .push(U32Bytes::new(LittleEndian, map.srcloc.0));
+            self.push_entry(pos, map.srcloc.0);
             self.last_offset = pos;
         }
         self.last_offset = func_end;
     }
 
+    fn push_entry(&mut self, offset: u32, position: u32) {
+        if self.count as usize % BLOCK_SIZE == 0 {
+            self.block_offsets.push(U32Bytes::new(LittleEndian, offset));
+            self.block_positions
+                .push(U32Bytes::new(LittleEndian, position));
+            let start = u32::try_from(self.deltas.len()).unwrap();
+            self.block_starts.push(U32Bytes::new(LittleEndian, start));
+        } else {
+            let (last_offset, last_position) = self.last_entry;
+            write_uleb(&mut self.deltas, offset - last_offset);
+            write_uleb(
+                &mut self.deltas,
+                zigzag(position.wrapping_sub(last_position) as i32),
+            );
+        }
+        self.last_entry = (offset, position);
+        self.count += 1;
+    }
+
     /// Finishes encoding this section into the `Object` provided.
-    pub fn append_to(self, obj: &mut Object) {
+    ///
+    /// If `compress` is `true` then the section's contents are compressed, see
+    /// `obj::ELF_WASMTIME_COMPRESSED`.
+    pub fn append_to(self, obj: &mut Object, compress: bool) {
         let section = obj.add_section(
             obj.segment_name(StandardSegment::Data).to_vec(),
             ELF_WASMTIME_ADDRMAP.as_bytes().to_vec(),
             SectionKind::ReadOnlyData,
         );
 
-        // NB: this matches the encoding expected by `lookup` below.
-        let amt = u32::try_from(self.offsets.len()).unwrap();
-        obj.append_section_data(section, &amt.to_le_bytes(), 1);
-        obj.append_section_data(section, object::bytes_of_slice(&self.offsets), 1);
-        obj.append_section_data(section, object::bytes_of_slice(&self.positions), 1);
+        let data = self.encode();
+        obj.set_section_data(section, maybe_compress_section(data, compress), 1);
+    }
+
+    fn encode(self) -> Vec<u8> {
+        // NB: this matches the encoding expected by `parse_address_map` below.
+        let blocks = u32::try_from(self.block_offsets.len()).unwrap();
+        let mut data = Vec::new();
+        data.extend_from_slice(&self.count.to_le_bytes());
+        data.extend_from_slice(&blocks.to_le_bytes());
+        data.extend_from_slice(object::bytes_of_slice(&self.block_offsets));
+        data.extend_from_slice(object::bytes_of_slice(&self.block_positions));
+        data.extend_from_slice(object::bytes_of_slice(&self.block_starts));
+        data.extend_from_slice(&self.deltas);
+        data
+    }
+}
+
+fn zigzag(val: i32) -> u32 {
+    ((val << 1) ^ (val >> 31)) as u32
+}
+
+fn unzigzag(val: u32) -> i32 {
+    ((val >> 1) as i32) ^ -((val & 1) as i32)
+}
+
+fn write_uleb(dst: &mut Vec<u8>, mut val: u32) {
+    loop {
+        let byte = (val & 0x7f) as u8;
+        val >>= 7;
+        if val == 0 {
+            dst.push(byte);
+            break;
+        }
+        dst.push(byte | 0x80);
+    }
+}
+
+fn read_uleb(src: &mut &[u8]) -> Option<u32> {
+    let mut result = 0u32;
+    let mut shift = 0;
+    loop {
+        let (&byte, rest) = src.split_first()?;
+        *src = rest;
+        if shift >= 32 {
+            return None;
+        }
+        result |= u32::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(result);
+        }
+        shift += 7;
     }
 }
 
-/// Parse an `ELF_WASMTIME_ADDRMAP` section, returning the slice of code offsets
-/// and the slice of associated file positions for each offset.
-fn parse_address_map(
-    section: &[u8],
-) -> Option<(&[U32Bytes<LittleEndian>], &[U32Bytes<LittleEndian>])> {
+/// A parsed view of an `ELF_WASMTIME_ADDRMAP` section.
+struct AddressMap<'a> {
+    count: usize,
+    block_offsets: &'a [U32Bytes<LittleEndian>],
+    block_positions: &'a [U32Bytes<LittleEndian>],
+    block_starts: &'a [U32Bytes<LittleEndian>],
+    deltas: &'a [u8],
+}
+
+impl<'a> AddressMap<'a> {
+    /// Returns an iterator over the entries of the `index`th block.
+    fn block(&self, index: usize) -> Option<impl Iterator<Item = (u32, FilePos)> + 'a> {
+        let offset = self.block_offsets.get(index)?.get(LittleEndian);
+        let position = self.block_positions.get(index)?.get(LittleEndian);
+        let start = usize::try_from(self.block_starts.get(index)?.get(LittleEndian)).ok()?;
+        let mut deltas = self.deltas.get(start..)?;
+        let len = (self.count - index * BLOCK_SIZE).min(BLOCK_SIZE);
+
+        let mut cur = Some((offset, position));
+        let mut remaining = len;
+        Some(std::iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+            remaining -= 1;
+            let (offset, position) = cur?;
+            cur = if remaining > 0 {
+                let offset_delta = read_uleb(&mut deltas);
+                let position_delta = read_uleb(&mut deltas);
+                match (offset_delta, position_delta) {
+                    (Some(o), Some(p)) => Some((
+                        offset.checked_add(o)?,
+                        position.wrapping_add(unzigzag(p) as u32),
+                    )),
+                    _ => None,
+                }
+            } else {
+                None
+            };
+            Some((offset, FilePos(position)))
+        }))
+    }
+}
+
+/// Parse an `ELF_WASMTIME_ADDRMAP` section into its block index and the
+/// delta-encoded entries that follow it.
+fn parse_address_map(section: &[u8]) -> Option<AddressMap<'_>> {
     let mut section = Bytes(section);
     // NB: this matches the encoding written by `append_to` above.
     let count = section.read::<U32Bytes<LittleEndian>>().ok()?;
     let count = usize::try_from(count.get(LittleEndian)).ok()?;
-    let (offsets, section) =
-        object::slice_from_bytes::<U32Bytes<LittleEndian>>(section.0, count).ok()?;
-    let (positions, section) =
-        object::slice_from_bytes::<U32Bytes<LittleEndian>>(section, count).ok()?;
-    debug_assert!(section.is_empty());
-    Some((offsets, positions))
+    let blocks = section.read::<U32Bytes<LittleEndian>>().ok()?;
+    let blocks = usize::try_from(blocks.get(LittleEndian)).ok()?;
+    if blocks != (count + BLOCK_SIZE - 1) / BLOCK_SIZE {
+        return None;
+    }
+    let (block_offsets, section) =
+        object::slice_from_bytes::<U32Bytes<LittleEndian>>(section.0, blocks).ok()?;
+    let (block_positions, section) =
+        object::slice_from_bytes::<U32Bytes<LittleEndian>>(section, blocks).ok()?;
+    let (block_starts, deltas) =
+        object::slice_from_bytes::<U32Bytes<LittleEndian>>(section, blocks).ok()?;
+    Some(AddressMap {
+        count,
+        block_offsets,
+        block_positions,
+        block_starts,
+        deltas,
+    })
 }
 
 /// Lookup an `offset` within an encoded address map section, returning the
@@ -144,15 +302,18 @@ fn parse_address_map(
 /// section of the pc that is being looked up. If `offset` is out of range or
 /// doesn't correspond to anything in this file then `None` is returned.
 pub fn lookup_file_pos(section: &[u8], offset: usize) -> Option<FilePos> {
-    let (offsets, positions) = parse_address_map(section)?;
+    let map = parse_address_map(section)?;
 
-    // First perform a binary search on the `offsets` array. This is a sorted
-    // array of offsets within the text section, which is conveniently what our
-    // `offset` also is. Note that we are somewhat unlikely to find a precise
-    // match on the element in the array, so we're largely interested in which
-    // "bucket" the `offset` falls into.
+    // First perform a binary search on the first offset of each block. This
+    // is a sorted array of offsets within the text section, which is
+    // conveniently what our `offset` also is. Note that we are somewhat
+    // unlikely to find a precise match on the element in the array, so we're
+    // largely interested in which "bucket" the `offset` falls into.
     let offset = u32::try_from(offset).ok()?;
-    let index = match offsets.binary_search_by_key(&offset, |v| v.get(LittleEndian)) {
+    let index = match map
+        .block_offsets
+        .binary_search_by_key(&offset, |v| v.get(LittleEndian))
+    {
         // Exact hit!
         Ok(i) => i,
 
@@ -164,10 +325,13 @@ pub fn lookup_file_pos(section: &[u8], offset: usize) -> Option<FilePos> {
         Err(n) => n - 1,
     };
 
-    // Using the `index` we found of which bucket `offset` corresponds to we can
-    // lookup the actual `FilePos` value in the `positions` array.
-    let pos = positions.get(index)?;
-    Some(FilePos(pos.get(LittleEndian)))
+    // Using the `index` we found decode the entries of that block, and the
+    // last one which starts at or before `offset` is the one we're looking
+    // for.
+    map.block(index)?
+        .take_while(|(o, _)| *o <= offset)
+        .last()
+        .map(|(_, pos)| pos)
 }
 
 /// Iterate over the address map contained in the given address map section.
@@ -181,12 +345,80 @@ pub fn lookup_file_pos(section: &[u8], offset: usize) -> Option<FilePos> {
 pub fn iterate_address_map<'a>(
     section: &'a [u8],
 ) -> Option<impl Iterator<Item = (u32, FilePos)> + 'a> {
-    let (offsets, positions) = parse_address_map(section)?;
+    let map = parse_address_map(section)?;
+    let blocks = map.block_offsets.len();
+    let blocks = (0..blocks)
+        .map(|i| map.block(i))
+        .collect::<Option<Vec<_>>>()?;
+    Some(blocks.into_iter().flatten())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Some(
-        offsets
+    fn encode(funcs: &[(Range<u64>, Vec<InstructionAddressMap>)]) -> Vec<u8> {
+        let mut builder = AddressMapSection::default();
+        for (range, instrs) in funcs {
+            builder.push(range.clone(), instrs);
+        }
+        builder.encode()
+    }
+
+    fn instr(code_offset: u32, srcloc: u32) -> InstructionAddressMap {
+        InstructionAddressMap {
+            srcloc: FilePos::new(srcloc),
+            code_offset,
+        }
+    }
+
+    #[test]
+    fn lookup_and_iterate() {
+        // Enough entries to span several blocks, with source positions that
+        // move both forwards and backwards as well as default positions.
+        let mut instrs = Vec::new();
+        for i in 0..100u32 {
+            let srcloc = match i % 5 {
+                0 => InstructionAddressMap {
+                    srcloc: FilePos::default(),
+                    code_offset: i * 4,
+                },
+                1 => instr(i * 4, 1000 - i),
+                _ => instr(i * 4, 10 + i * 3),
+            };
+            instrs.push(srcloc);
+        }
+        let funcs = vec![
+            (0x10..0x200, instrs.clone()),
+            (0x300..0x310, vec![instr(0, 7), instr(8, 9)]),
+        ];
+        let section = encode(&funcs);
+
+        let expected = funcs
             .iter()
-            .map(|o| o.get(LittleEndian))
-            .zip(positions.iter().map(|pos| FilePos(pos.get(LittleEndian)))),
-    )
+            .flat_map(|(range, instrs)| {
+                instrs
+                    .iter()
+                    .map(move |i| (range.start as u32 + i.code_offset, i.srcloc))
+            })
+            .collect::<Vec<_>>();
+        let actual = iterate_address_map(&section).unwrap().collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+
+        assert_eq!(lookup_file_pos(&section, 0), None);
+        assert_eq!(lookup_file_pos(&section, 0x10), Some(FilePos::default()));
+        assert_eq!(lookup_file_pos(&section, 0x16), Some(FilePos::new(999)));
+        assert_eq!(
+            lookup_file_pos(&section, 0x10 + 4 * 43 + 2),
+            Some(FilePos::new(10 + 43 * 3))
+        );
+        assert_eq!(lookup_file_pos(&section, 0x305), Some(FilePos::new(7)));
+    }
+
+    #[test]
+    fn empty() {
+        let section = encode(&[]);
+        assert_eq!(lookup_file_pos(&section, 0), None);
+        assert_eq!(iterate_address_map(&section).unwrap().count(), 0);
+    }
 }
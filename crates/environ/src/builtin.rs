@@ -67,6 +67,14 @@ macro_rules! foreach_builtin_function {
             update_stack_pointer(vmctx: vmctx, value: i32);
             /// Invoked before memory.grow is called.
             update_mem_size(vmctx: vmctx, num_bytes: i32);
+            /// Invoked before a load or store when tracing memory accesses.
+            memory_trace(vmctx: vmctx, memory: i32, is_store: i32, addr: i64, num_bytes: i32, offset: i32);
+            /// Invoked on entry to a basic block when tracing block entries.
+            block_trace(vmctx: vmctx, func: i32, offset: i32);
+            /// Returns an index for Wasm's `table.get` instruction for `externref`s.
+            table_get_externref(vmctx: vmctx, table: i32, index: i32) -> reference;
+            /// Returns an index for Wasm's `table.set` instruction for `externref`s.
+            table_set_externref(vmctx: vmctx, table: i32, index: i32, val: reference);
         }
     };
 }
@@ -0,0 +1,56 @@
+//! Parsing of the capability manifest custom section, which declares the host
+//! imports a core wasm module or component expects to be linked against.
+
+use crate::{WasmError, WasmResult};
+use serde_derive::{Deserialize, Serialize};
+use wasmparser::BinaryReader;
+
+/// The name of the custom section holding a [`CapabilityManifest`].
+pub const CAPABILITIES_SECTION: &str = "wasmtime-capabilities";
+
+/// The host capabilities declared by a module or component.
+///
+/// The section is encoded as a `vec(capability)` where each capability is
+/// either `0x00 module:name`, declaring every item of `module`, or
+/// `0x01 module:name field:name`, declaring the single item `field` of
+/// `module`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapabilityManifest {
+    /// The declared `(module, field)` pairs, where a `field` of `None` covers
+    /// every item of `module`.
+    pub entries: Vec<(String, Option<String>)>,
+}
+
+impl CapabilityManifest {
+    /// Parses the contents of a [`CAPABILITIES_SECTION`] which starts at
+    /// `offset` in the original input.
+    pub fn parse(data: &[u8], offset: usize) -> WasmResult<CapabilityManifest> {
+        let mut reader = BinaryReader::new_with_offset(data, offset);
+        let count = reader.read_var_u32()?;
+        let mut entries = Vec::new();
+        for _ in 0..count {
+            let position = reader.original_position();
+            let entry = match reader.read_u8()? {
+                0x00 => (reader.read_string()?.to_string(), None),
+                0x01 => (
+                    reader.read_string()?.to_string(),
+                    Some(reader.read_string()?.to_string()),
+                ),
+                kind => {
+                    return Err(WasmError::InvalidWebAssembly {
+                        message: format!("invalid capability kind 0x{kind:02x}"),
+                        offset: position,
+                    })
+                }
+            };
+            entries.push(entry);
+        }
+        if !reader.eof() {
+            return Err(WasmError::InvalidWebAssembly {
+                message: format!("trailing bytes at end of `{CAPABILITIES_SECTION}` section"),
+                offset: reader.original_position(),
+            });
+        }
+        Ok(CapabilityManifest { entries })
+    }
+}
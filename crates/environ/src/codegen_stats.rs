@@ -0,0 +1,65 @@
+//! Statistics about the machine code generated for wasm functions.
+//!
+//! When [`Tunables::codegen_stats`](crate::Tunables::codegen_stats) is
+//! enabled, compilers which support it record how much code each wasm opcode
+//! expanded to and how often values had to be moved between registers and the
+//! stack, along with the size of each function's machine code and how long
+//! it took to compile. These are stored in the compiled artifact's metadata
+//! and are meant to guide optimization work on baseline code generation and
+//! tier selection, not for use at runtime.
+
+use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Code generation statistics of a single wasm function.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionCodegenStats {
+    /// Statistics for each opcode that appeared in the function, keyed by
+    /// the opcode's name as spelled by `wasmparser`, for example `I32Add`.
+    pub opcodes: BTreeMap<String, OpcodeStats>,
+    /// The number of values spilled from registers or locals to the machine
+    /// stack.
+    pub spills: u32,
+    /// The number of spilled values that were loaded back into a register.
+    pub reloads: u32,
+    /// The size, in bytes, of the function's machine code, including its
+    /// constant pool.
+    pub machine_code_size: u32,
+    /// The size, in bytes, of the constants the function's code refers to.
+    pub constant_pool_size: u32,
+    /// The number of relocations in the function's machine code, each of
+    /// which is resolved when the module is linked.
+    pub relocations: u32,
+    /// How long the function took to compile, including validation of its
+    /// body.
+    pub compile_time: Duration,
+}
+
+/// Code generation statistics of one opcode within a function.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpcodeStats {
+    /// How many times the opcode was compiled.
+    pub count: u32,
+    /// The total size, in bytes, of the machine code emitted for it.
+    pub code_size: u32,
+}
+
+impl FunctionCodegenStats {
+    /// Records that `code_size` bytes of machine code were emitted for one
+    /// occurrence of `opcode`.
+    pub fn record_opcode(&mut self, opcode: &str, code_size: u32) {
+        let stats = match self.opcodes.get_mut(opcode) {
+            Some(stats) => stats,
+            None => self.opcodes.entry(opcode.to_string()).or_default(),
+        };
+        stats.count += 1;
+        stats.code_size += code_size;
+    }
+
+    /// Returns the total size, in bytes, of the machine code attributed to
+    /// opcodes, which excludes the function's prologue and epilogue.
+    pub fn code_size(&self) -> u32 {
+        self.opcodes.values().map(|s| s.code_size).sum()
+    }
+}
@@ -3,8 +3,8 @@
 
 use crate::{obj, Tunables};
 use crate::{
-    DefinedFuncIndex, FilePos, FuncIndex, FunctionBodyData, ModuleTranslation, ModuleTypes,
-    PrimaryMap, StackMap, WasmError, WasmFuncType,
+    DefinedFuncIndex, FilePos, FuncIndex, FunctionBodyData, FunctionCodegenStats,
+    ModuleTranslation, ModuleTypes, PrimaryMap, StackMap, WasmError, WasmFuncType,
 };
 use anyhow::Result;
 use object::write::{Object, SymbolId};
@@ -15,6 +15,7 @@ use std::borrow::Cow;
 use std::fmt;
 use std::path;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Information about a function, such as trap information, address map,
@@ -24,6 +25,13 @@ use thiserror::Error;
 pub struct WasmFunctionInfo {
     pub start_srcloc: FilePos,
     pub stack_maps: Box<[StackMapInformation]>,
+    /// Only recorded by compilers which support
+    /// [`Tunables::codegen_stats`], and only when it's enabled.
+    pub codegen_stats: Option<Box<FunctionCodegenStats>>,
+    /// Whether compiling the function took longer than the compiler's
+    /// compile-time budget, so that it was compiled again with the
+    /// compiler's cheaper fallback configuration.
+    pub exceeded_compile_budget: bool,
 }
 
 /// Description of where a function is located in the text section of a
@@ -63,6 +71,17 @@ pub enum CompileError {
     /// A compilation error occured.
     #[error("Debug info is not supported with this configuration")]
     DebugInfoNotSupported,
+
+    /// A function exceeded either the configured maximum function size or the
+    /// limits of the compiler backend.
+    #[error("Compilation error: Code for function is too large (function {func_index}): {reason}")]
+    FunctionTooLarge {
+        /// The index of the offending function in the module's function index
+        /// space.
+        func_index: u32,
+        /// A description of the limit that was exceeded.
+        reason: String,
+    },
 }
 
 /// Implementation of an incremental compilation's key/value cache store.
@@ -129,6 +148,18 @@ pub trait CompilerBuilder: Send + Sync + fmt::Debug {
 
     /// Enables or disables wmemcheck during runtime according to the wmemcheck CLI flag.
     fn wmemcheck(&mut self, _enable: bool) {}
+
+    /// Sets how long the compiler may spend optimizing a single function
+    /// before it gives up and compiles that function with a cheaper strategy.
+    ///
+    /// Compilers which have no cheaper strategy to fall back to return an
+    /// error when a budget is configured.
+    fn compile_time_budget(&mut self, budget: Option<Duration>) -> Result<()> {
+        match budget {
+            Some(_) => anyhow::bail!("compile-time budgets are not supported by this compiler"),
+            None => Ok(()),
+        }
+    }
 }
 
 /// Description of compiler settings returned by [`CompilerBuilder::settings`].
@@ -159,6 +190,7 @@ pub enum SettingKind {
 }
 
 /// Types of objects that can be created by `Compiler::object`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ObjectKind {
     /// A core wasm compilation artifact
     Module,
@@ -37,6 +37,9 @@ use std::ops::Index;
 #[derive(Default)]
 #[allow(missing_docs)]
 pub struct ComponentDfg {
+    /// Same as `Component::ty`, filled in once inlining has finished.
+    pub ty: Option<TypeComponentIndex>,
+
     /// Same as `Component::import_types`
     pub import_types: PrimaryMap<ImportIndex, (String, TypeDef)>,
 
@@ -180,6 +183,7 @@ pub enum Instance {
 pub enum Export {
     LiftedFunction {
         ty: TypeFuncIndex,
+        param_names: TypeFuncParamNamesIndex,
         func: CoreDef,
         options: CanonicalOptions,
     },
@@ -373,6 +377,7 @@ impl ComponentDfg {
         ComponentTranslation {
             trampolines: linearize.trampoline_defs,
             component: Component {
+                ty: self.ty.expect("component type recorded during inlining"),
                 exports,
                 initializers: linearize.initializers,
                 trampolines: linearize.trampolines,
@@ -393,6 +398,7 @@ impl ComponentDfg {
                     .iter()
                     .map(|(_, r)| r.instance)
                     .collect(),
+                capabilities: None,
             },
         }
     }
@@ -477,7 +483,9 @@ impl LinearizeDfg<'_> {
 
     fn export(&mut self, export: &Export) -> info::Export {
         match export {
-            Export::LiftedFunction { ty, func, options } => {
+            Export::LiftedFunction {
+                ty, func, options, ..
+            } => {
                 let func = self.core_def(func);
                 let options = self.options(options);
                 info::Export::LiftedFunction {
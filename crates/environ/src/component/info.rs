@@ -47,7 +47,7 @@
 // requirements of embeddings change over time.
 
 use crate::component::*;
-use crate::{EntityIndex, PrimaryMap, SignatureIndex, WasmType};
+use crate::{CapabilityManifest, EntityIndex, PrimaryMap, SignatureIndex, WasmType};
 use indexmap::IndexMap;
 use serde_derive::{Deserialize, Serialize};
 
@@ -69,8 +69,11 @@ pub struct ComponentTranslation {
 ///
 /// NB: Lots of the component model is not yet implemented in the runtime so
 /// this is going to undergo a lot of churn.
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Component {
+    /// The type of this component, describing its imports and exports.
+    pub ty: TypeComponentIndex,
+
     /// A list of typed values that this component imports.
     ///
     /// Note that each name is given an `ImportIndex` here for the next map to
@@ -172,6 +175,13 @@ pub struct Component {
     /// This is used to determine which set of instance flags are inspected when
     /// testing reentrance.
     pub defined_resource_instances: PrimaryMap<DefinedResourceIndex, RuntimeComponentInstanceIndex>,
+
+    /// The host capabilities declared by the top-level component's
+    /// [`CAPABILITIES_SECTION`](crate::CAPABILITIES_SECTION), if it has one.
+    ///
+    /// Each entry's module is the name of a top-level import and its field, if
+    /// any, is the name of an item within that imported instance.
+    pub capabilities: Option<CapabilityManifest>,
 }
 
 impl Component {
@@ -1,8 +1,9 @@
 use crate::component::*;
 use crate::ScopeVec;
 use crate::{
-    EntityIndex, ModuleEnvironment, ModuleTranslation, ModuleTypesBuilder, PrimaryMap,
-    SignatureIndex, Tunables, TypeConvert, WasmHeapType, WasmType,
+    CapabilityManifest, EntityIndex, ModuleEnvironment, ModuleTranslation, ModuleTypesBuilder,
+    PrimaryMap, SignatureIndex, Tunables, TypeConvert, WasmHeapType, WasmType,
+    CAPABILITIES_SECTION,
 };
 use anyhow::{bail, Result};
 use indexmap::IndexMap;
@@ -65,6 +66,9 @@ pub struct Translator<'a, 'data> {
     /// As frames are popped from `lexical_scopes` their completed component
     /// will be pushed onto this list.
     static_components: PrimaryMap<StaticComponentIndex, Translation<'data>>,
+
+    /// The capability manifest of the outermost component, if any.
+    capabilities: Option<CapabilityManifest>,
 }
 
 /// Representation of the syntactic scope of a component meaning where it is
@@ -277,6 +281,7 @@ impl<'a, 'data> Translator<'a, 'data> {
             static_components: Default::default(),
             static_modules: Default::default(),
             scope_vec,
+            capabilities: None,
         }
     }
 
@@ -351,7 +356,9 @@ impl<'a, 'data> Translator<'a, 'data> {
             &self.static_components,
         )?;
         self.partition_adapter_modules(&mut component);
-        Ok((component.finish(), self.static_modules))
+        let mut translation = component.finish();
+        translation.component.capabilities = self.capabilities;
+        Ok((translation, self.static_modules))
     }
 
     fn translate_payload(
@@ -655,7 +662,18 @@ impl<'a, 'data> Translator<'a, 'data> {
                 }
             }
 
-            // All custom sections are ignored by Wasmtime at this time.
+            // The capability manifest is only meaningful for the outermost
+            // component since that's the one whose imports the host provides.
+            Payload::CustomSection(s)
+                if s.name() == CAPABILITIES_SECTION && self.lexical_scopes.is_empty() =>
+            {
+                if self.capabilities.is_some() {
+                    bail!("multiple `{CAPABILITIES_SECTION}` custom sections");
+                }
+                self.capabilities = Some(CapabilityManifest::parse(s.data(), s.data_offset())?);
+            }
+
+            // All other custom sections are ignored by Wasmtime at this time.
             //
             // FIXME(WebAssembly/component-model#14): probably want to specify
             // and parse a `name` section here.
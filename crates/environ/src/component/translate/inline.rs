@@ -138,6 +138,18 @@ pub(super) fn run(
     inliner.result.exports = export_map;
     inliner.result.num_resource_tables = types.num_resource_tables();
 
+    // Finally the type of the root component itself is assembled from the
+    // types of its imports and exports so it can be inspected at runtime.
+    let mut ty = TypeComponent::default();
+    for (_, (name, def)) in inliner.result.import_types.iter() {
+        ty.imports.insert(name.clone(), *def);
+    }
+    for (name, export) in inliner.result.exports.iter() {
+        ty.exports
+            .insert(name.clone(), inliner.export_type(types, export));
+    }
+    inliner.result.ty = Some(types.add_component_type(ty));
+
     Ok(inliner.result)
 }
 
@@ -307,6 +319,7 @@ enum ComponentFuncDef<'a> {
     /// A core wasm function was lifted into a component function.
     Lifted {
         ty: TypeFuncIndex,
+        param_names: TypeFuncParamNamesIndex,
         func: dfg::CoreDef,
         options: AdapterOptions,
     },
@@ -584,6 +597,7 @@ impl<'a> Inliner<'a> {
                         ty: lift_ty,
                         func,
                         options: options_lift,
+                        ..
                     } => {
                         let adapter_idx = self.result.adapters.push(Adapter {
                             lift_ty: *lift_ty,
@@ -602,10 +616,13 @@ impl<'a> Inliner<'a> {
             // some metadata about the lifting is simply recorded. This'll get
             // plumbed through to exports or a fused adapter later on.
             Lift(ty, func, options) => {
-                let ty = types.convert_component_func_type(frame.translation.types_ref(), *ty)?;
+                let types_ref = frame.translation.types_ref();
+                let param_names = types.convert_component_func_param_names(types_ref, *ty);
+                let ty = types.convert_component_func_type(types_ref, *ty)?;
                 let options = self.adapter_options(frame, types, options);
                 frame.component_funcs.push(ComponentFuncDef::Lifted {
                     ty,
+                    param_names,
                     func: frame.funcs[*func].clone(),
                     options,
                 });
@@ -1019,7 +1036,7 @@ impl<'a> Inliner<'a> {
         &mut self,
         name: &str,
         def: ComponentItemDef<'a>,
-        types: &'a ComponentTypesBuilder,
+        types: &ComponentTypesBuilder,
         map: &mut IndexMap<String, dfg::Export>,
     ) -> Result<()> {
         let export = match def {
@@ -1036,9 +1053,19 @@ impl<'a> Inliner<'a> {
                 // If this is a lifted function from something lowered in this
                 // component then the configured options are plumbed through
                 // here.
-                ComponentFuncDef::Lifted { ty, func, options } => {
+                ComponentFuncDef::Lifted {
+                    ty,
+                    param_names,
+                    func,
+                    options,
+                } => {
                     let options = self.canonical_options(options);
-                    dfg::Export::LiftedFunction { ty, func, options }
+                    dfg::Export::LiftedFunction {
+                        ty,
+                        param_names,
+                        func,
+                        options,
+                    }
                 }
 
                 // Currently reexported functions from an import are not
@@ -1062,7 +1089,7 @@ impl<'a> Inliner<'a> {
                     // module-exporting instances.
                     ComponentInstanceDef::Import(path, ty) => {
                         for (name, ty) in types[ty].exports.iter() {
-                            let path = path.push(name);
+                            let path = path.push(name.clone());
                             let def = ComponentItemDef::from_import(path, *ty)?;
                             self.record_export(name, def, types, &mut result)?;
                         }
@@ -1092,6 +1119,50 @@ impl<'a> Inliner<'a> {
         map.insert(name.to_string(), export);
         Ok(())
     }
+
+    /// Returns the type of an item exported from the root component.
+    fn export_type(&self, types: &mut ComponentTypesBuilder, export: &dfg::Export) -> TypeDef {
+        match export {
+            dfg::Export::LiftedFunction {
+                ty, param_names, ..
+            } => TypeDef::ComponentFunc(*ty, *param_names),
+            dfg::Export::ModuleStatic(idx) => {
+                let module = &self.nested_modules[*idx].module;
+                let ty = TypeModule {
+                    imports: module
+                        .imports()
+                        .map(|(module, name, ty)| ((module.to_string(), name.to_string()), ty))
+                        .collect(),
+                    exports: module
+                        .exports
+                        .iter()
+                        .map(|(name, index)| (name.clone(), module.type_of(*index)))
+                        .collect(),
+                };
+                TypeDef::Module(types.add_module_type(ty))
+            }
+            dfg::Export::ModuleImport(idx) => {
+                let (import, path) = &self.result.imports[*idx];
+                let mut ty = self.result.import_types[*import].1;
+                for name in path {
+                    ty = match ty {
+                        TypeDef::ComponentInstance(i) => types[i].exports[name.as_str()],
+                        _ => unreachable!(),
+                    };
+                }
+                ty
+            }
+            dfg::Export::Instance(map) => {
+                let mut ty = TypeComponentInstance::default();
+                for (name, export) in map {
+                    ty.exports
+                        .insert(name.clone(), self.export_type(types, export));
+                }
+                TypeDef::ComponentInstance(types.add_component_instance_type(ty))
+            }
+            dfg::Export::Type(def) => *def,
+        }
+    }
 }
 
 impl<'a> InlinerFrame<'a> {
@@ -1262,7 +1333,7 @@ impl<'a> ComponentItemDef<'a> {
             TypeDef::ComponentInstance(ty) => {
                 ComponentItemDef::Instance(ComponentInstanceDef::Import(path, ty))
             }
-            TypeDef::ComponentFunc(_ty) => ComponentItemDef::Func(ComponentFuncDef::Import(path)),
+            TypeDef::ComponentFunc(..) => ComponentItemDef::Func(ComponentFuncDef::Import(path)),
             // FIXME(#4283) should commit one way or another to how this
             // should be treated.
             TypeDef::Component(_ty) => bail!("root-level component imports are not supported"),
@@ -90,6 +90,10 @@ indices! {
     /// as interface types.
     pub struct TypeFuncIndex(u32);
 
+    /// Index pointing to the names of a component model function's
+    /// parameters.
+    pub struct TypeFuncParamNamesIndex(u32);
+
     /// Index pointing to a record type in the component model (aka a struct).
     pub struct TypeRecordIndex(u32);
     /// Index pointing to a variant type in the component model (aka an enum).
@@ -244,6 +248,7 @@ pub struct ComponentTypes {
     components: PrimaryMap<TypeComponentIndex, TypeComponent>,
     component_instances: PrimaryMap<TypeComponentInstanceIndex, TypeComponentInstance>,
     functions: PrimaryMap<TypeFuncIndex, TypeFunc>,
+    func_param_names: PrimaryMap<TypeFuncParamNamesIndex, TypeFuncParamNames>,
     lists: PrimaryMap<TypeListIndex, TypeList>,
     records: PrimaryMap<TypeRecordIndex, TypeRecord>,
     variants: PrimaryMap<TypeVariantIndex, TypeVariant>,
@@ -332,6 +337,7 @@ impl_index! {
     impl Index<TypeComponentIndex> for ComponentTypes { TypeComponent => components }
     impl Index<TypeComponentInstanceIndex> for ComponentTypes { TypeComponentInstance => component_instances }
     impl Index<TypeFuncIndex> for ComponentTypes { TypeFunc => functions }
+    impl Index<TypeFuncParamNamesIndex> for ComponentTypes { TypeFuncParamNames => func_param_names }
     impl Index<TypeRecordIndex> for ComponentTypes { TypeRecord => records }
     impl Index<TypeVariantIndex> for ComponentTypes { TypeVariant => variants }
     impl Index<TypeTupleIndex> for ComponentTypes { TypeTuple => tuples }
@@ -362,6 +368,7 @@ where
 #[derive(Default)]
 pub struct ComponentTypesBuilder {
     functions: HashMap<TypeFunc, TypeFuncIndex>,
+    func_param_names: HashMap<TypeFuncParamNames, TypeFuncParamNamesIndex>,
     lists: HashMap<TypeList, TypeListIndex>,
     records: HashMap<TypeRecord, TypeRecordIndex>,
     variants: HashMap<TypeVariant, TypeVariantIndex>,
@@ -460,6 +467,26 @@ impl ComponentTypesBuilder {
         Ok(self.add_func_type(ty))
     }
 
+    /// Records the parameter names of a wasmparser `ComponentFuncType`.
+    pub fn convert_component_func_param_names(
+        &mut self,
+        types: types::TypesRef<'_>,
+        id: types::ComponentFuncTypeId,
+    ) -> TypeFuncParamNamesIndex {
+        let names = TypeFuncParamNames {
+            names: types[id]
+                .params
+                .iter()
+                .map(|(name, _)| name.to_string())
+                .collect(),
+        };
+        intern(
+            &mut self.func_param_names,
+            &mut self.component_types.func_param_names,
+            names,
+        )
+    }
+
     /// Converts a wasmparser `ComponentEntityType` into Wasmtime's type
     /// representation.
     pub fn convert_component_entity_type(
@@ -477,9 +504,10 @@ impl ComponentTypesBuilder {
             types::ComponentEntityType::Instance(id) => {
                 TypeDef::ComponentInstance(self.convert_instance(types, id)?)
             }
-            types::ComponentEntityType::Func(id) => {
-                TypeDef::ComponentFunc(self.convert_component_func_type(types, id)?)
-            }
+            types::ComponentEntityType::Func(id) => TypeDef::ComponentFunc(
+                self.convert_component_func_type(types, id)?,
+                self.convert_component_func_param_names(types, id),
+            ),
             types::ComponentEntityType::Type { created, .. } => match created {
                 types::ComponentAnyTypeId::Defined(id) => {
                     TypeDef::Interface(self.defined_type(types, id)?)
@@ -509,9 +537,10 @@ impl ComponentTypesBuilder {
             types::ComponentAnyTypeId::Instance(id) => {
                 TypeDef::ComponentInstance(self.convert_instance(types, id)?)
             }
-            types::ComponentAnyTypeId::Func(id) => {
-                TypeDef::ComponentFunc(self.convert_component_func_type(types, id)?)
-            }
+            types::ComponentAnyTypeId::Func(id) => TypeDef::ComponentFunc(
+                self.convert_component_func_type(types, id)?,
+                self.convert_component_func_param_names(types, id),
+            ),
             types::ComponentAnyTypeId::Resource(id) => {
                 TypeDef::Resource(self.resource_id(id.resource()))
             }
@@ -780,6 +809,24 @@ impl ComponentTypesBuilder {
         self.resources.convert(id, &mut self.component_types)
     }
 
+    /// Adds a new module type to this type information.
+    pub fn add_module_type(&mut self, ty: TypeModule) -> TypeModuleIndex {
+        self.component_types.modules.push(ty)
+    }
+
+    /// Adds a new component type to this type information.
+    pub fn add_component_type(&mut self, ty: TypeComponent) -> TypeComponentIndex {
+        self.component_types.components.push(ty)
+    }
+
+    /// Adds a new component instance type to this type information.
+    pub fn add_component_instance_type(
+        &mut self,
+        ty: TypeComponentInstance,
+    ) -> TypeComponentInstanceIndex {
+        self.component_types.component_instances.push(ty)
+    }
+
     /// Interns a new function type within this type information.
     pub fn add_func_type(&mut self, ty: TypeFunc) -> TypeFuncIndex {
         intern(&mut self.functions, &mut self.component_types.functions, ty)
@@ -938,8 +985,9 @@ pub enum TypeDef {
     Component(TypeComponentIndex),
     /// An instance of a component.
     ComponentInstance(TypeComponentInstanceIndex),
-    /// A component function, not to be confused with a core wasm function.
-    ComponentFunc(TypeFuncIndex),
+    /// A component function, not to be confused with a core wasm function,
+    /// and the names of its parameters.
+    ComponentFunc(TypeFuncIndex, TypeFuncParamNamesIndex),
     /// An interface type.
     Interface(InterfaceType),
     /// A core wasm module and its type.
@@ -1008,6 +1056,16 @@ pub struct TypeFunc {
     pub results: TypeTupleIndex,
 }
 
+/// The names of a component function's parameters, in order.
+///
+/// These are kept apart from [`TypeFunc`] so that functions which only differ
+/// in the names of their parameters share the same type.
+#[derive(Serialize, Deserialize, Clone, Hash, Eq, PartialEq, Debug)]
+pub struct TypeFuncParamNames {
+    /// The names of the parameters.
+    pub names: Vec<String>,
+}
+
 /// All possible interface types that values can have.
 ///
 /// This list represents an exhaustive listing of interface types and the
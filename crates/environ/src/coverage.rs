@@ -0,0 +1,87 @@
+//! Instrumentation points for code coverage.
+//!
+//! When [`Tunables::coverage`](crate::Tunables::coverage) is enabled each
+//! defined function gets a run of 64-bit counters: the first one counts calls
+//! to the function and each following one counts how often the code right
+//! after a control-flow operator (see [`is_coverage_point`]) was entered. The
+//! counters of all functions are laid out back to back in a single array
+//! which compiled code reaches through the `VMContext`.
+
+use crate::{DefinedFuncIndex, FilePos, PrimaryMap, WasmResult};
+use serde_derive::{Deserialize, Serialize};
+use std::ops::Range;
+use wasmparser::{FunctionBody, Operator};
+
+/// The coverage counters of a module and the wasm offsets they're attributed
+/// to.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CoverageMap {
+    /// The position in the original wasm file of the operator each counter is
+    /// attributed to, indexed by counter.
+    ///
+    /// A function's first counter is attributed to the first operator of its
+    /// body.
+    pub positions: Vec<FilePos>,
+
+    /// The index of each defined function's first counter.
+    pub functions: PrimaryMap<DefinedFuncIndex, u32>,
+}
+
+impl CoverageMap {
+    /// Allocates counters for the next defined function, whose body is
+    /// `body`.
+    pub(crate) fn push_function(&mut self, body: &FunctionBody<'_>) -> WasmResult<()> {
+        self.functions
+            .push(u32::try_from(self.positions.len()).unwrap());
+        let mut reader = body.get_operators_reader()?;
+        self.positions.push(FilePos::new(
+            u32::try_from(reader.original_position()).unwrap(),
+        ));
+        while !reader.eof() {
+            let pos = reader.original_position();
+            if is_coverage_point(&reader.read()?) {
+                self.positions
+                    .push(FilePos::new(u32::try_from(pos).unwrap()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the number of counters for the whole module.
+    pub fn num_counters(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns the range of counters belonging to the function `index`.
+    pub fn function_counters(&self, index: DefinedFuncIndex) -> Range<usize> {
+        let start = self.functions[index] as usize;
+        let end = match self
+            .functions
+            .get(DefinedFuncIndex::from_u32(index.as_u32() + 1))
+        {
+            Some(next) => *next as usize,
+            None => self.positions.len(),
+        };
+        start..end
+    }
+}
+
+/// Returns whether a coverage counter is incremented right after `op`, at the
+/// start of the code that executes next.
+///
+/// These are the operators after which the code executed next may be reached
+/// from somewhere other than the operator before it: loop headers, both arms
+/// of an `if`, the code following a block and the fallthrough of conditional
+/// branches.
+pub fn is_coverage_point(op: &Operator<'_>) -> bool {
+    matches!(
+        op,
+        Operator::Loop { .. }
+            | Operator::If { .. }
+            | Operator::Else
+            | Operator::End
+            | Operator::BrIf { .. }
+            | Operator::BrOnNull { .. }
+            | Operator::BrOnNonNull { .. }
+    )
+}
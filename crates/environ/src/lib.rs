@@ -8,7 +8,10 @@
 
 mod address_map;
 mod builtin;
+mod capabilities;
+mod codegen_stats;
 mod compilation;
+mod coverage;
 mod module;
 mod module_environ;
 mod module_types;
@@ -22,7 +25,10 @@ mod vmoffsets;
 
 pub use crate::address_map::*;
 pub use crate::builtin::*;
+pub use crate::capabilities::*;
+pub use crate::codegen_stats::*;
 pub use crate::compilation::*;
+pub use crate::coverage::*;
 pub use crate::module::*;
 pub use crate::module_environ::*;
 pub use crate::module_types::*;
@@ -1,6 +1,9 @@
 //! Data structures for representing decoded wasm modules.
 
-use crate::{ModuleTranslation, PrimaryMap, Tunables, WasmHeapType, WASM_PAGE_SIZE};
+use crate::{
+    CapabilityManifest, CoverageMap, ModuleTranslation, PrimaryMap, Tunables, WasmHeapType,
+    WASM_PAGE_SIZE,
+};
 use cranelift_entity::{packed_option::ReservedValue, EntityRef};
 use indexmap::IndexMap;
 use serde_derive::{Deserialize, Serialize};
@@ -860,6 +863,14 @@ pub struct Module {
 
     /// WebAssembly global initializers for locally-defined globals.
     pub global_initializers: PrimaryMap<DefinedGlobalIndex, GlobalInit>,
+
+    /// Code coverage counters, empty unless coverage instrumentation is
+    /// enabled.
+    pub coverage: CoverageMap,
+
+    /// The host capabilities declared by this module's
+    /// [`CAPABILITIES_SECTION`](crate::CAPABILITIES_SECTION), if it has one.
+    pub capabilities: Option<CapabilityManifest>,
 }
 
 /// Initialization routines for creating an instance, encompassing imports,
@@ -3,10 +3,10 @@ use crate::module::{
     ModuleType, TablePlan, TableSegment,
 };
 use crate::{
-    DataIndex, DefinedFuncIndex, ElemIndex, EntityIndex, EntityType, FuncIndex, GlobalIndex,
-    GlobalInit, MemoryIndex, ModuleTypesBuilder, PrimaryMap, SignatureIndex, TableIndex,
-    TableInitialValue, Tunables, TypeConvert, TypeIndex, WasmError, WasmFuncType, WasmHeapType,
-    WasmResult, WasmType,
+    CapabilityManifest, DataIndex, DefinedFuncIndex, ElemIndex, EntityIndex, EntityType, FuncIndex,
+    GlobalIndex, GlobalInit, MemoryIndex, ModuleTypesBuilder, PrimaryMap, SignatureIndex,
+    TableIndex, TableInitialValue, Tunables, TypeConvert, TypeIndex, WasmError, WasmFuncType,
+    WasmHeapType, WasmResult, WasmType, CAPABILITIES_SECTION,
 };
 use cranelift_entity::packed_option::ReservedValue;
 use std::borrow::Cow;
@@ -15,9 +15,9 @@ use std::convert::TryFrom;
 use std::path::PathBuf;
 use std::sync::Arc;
 use wasmparser::{
-    types::Types, CustomSectionReader, DataKind, ElementItems, ElementKind, Encoding, ExternalKind,
-    FuncToValidate, FunctionBody, NameSectionReader, Naming, Operator, Parser, Payload, TypeRef,
-    Validator, ValidatorResources,
+    types::Types, BinaryReader, CustomSectionReader, DataKind, ElementItems, ElementKind, Encoding,
+    ExternalKind, FuncToValidate, FunctionBody, NameSectionReader, Naming, Operator, Parser,
+    Payload, TypeRef, Validator, ValidatorResources,
 };
 
 /// Object containing the standalone environment information.
@@ -59,6 +59,14 @@ pub struct ModuleTranslation<'data> {
     /// DWARF debug information, if enabled, parsed from the module.
     pub debuginfo: DebugInfoData<'data>,
 
+    /// Hints from the branch hinting custom section of how likely the
+    /// conditional branches of each function are to be taken.
+    ///
+    /// Each hint is the offset of a `br_if` or `if` instruction relative to
+    /// the start of its function's body, along with whether its branch is
+    /// likely to be taken. Hints are sorted by offset.
+    pub branch_hints: HashMap<FuncIndex, Vec<(u32, bool)>>,
+
     /// Set if debuginfo was found but it was not parsed due to `Tunables`
     /// configuration.
     pub has_unparsed_debuginfo: bool,
@@ -558,6 +566,9 @@ impl<'a, 'data> ModuleEnvironment<'a, 'data> {
                         });
                 }
                 body.allow_memarg64(self.validator.features().memory64);
+                if self.tunables.coverage {
+                    self.result.module.coverage.push_function(&body)?;
+                }
                 self.result
                     .function_body_inputs
                     .push(FunctionBodyData { validator, body });
@@ -658,6 +669,25 @@ impl<'a, 'data> ModuleEnvironment<'a, 'data> {
                 }
             }
 
+            Payload::CustomSection(s) if s.name() == "metadata.code.branch_hint" => {
+                let reader = BinaryReader::new_with_offset(s.data(), s.data_offset());
+                if let Err(e) = self.branch_hint_section(reader) {
+                    log::warn!("failed to parse branch hint section {:?}", e);
+                    self.result.branch_hints.clear();
+                }
+            }
+
+            Payload::CustomSection(s) if s.name() == CAPABILITIES_SECTION => {
+                if self.result.module.capabilities.is_some() {
+                    return Err(WasmError::InvalidWebAssembly {
+                        message: format!("multiple `{CAPABILITIES_SECTION}` custom sections"),
+                        offset: s.data_offset(),
+                    });
+                }
+                self.result.module.capabilities =
+                    Some(CapabilityManifest::parse(s.data(), s.data_offset())?);
+            }
+
             Payload::CustomSection(s)
                 if s.name() == "webidl-bindings" || s.name() == "wasm-interface-types" =>
             {
@@ -800,6 +830,36 @@ and for re-adding support for interface types you can see this issue:
         Ok(())
     }
 
+    /// Parses the branch hint section of the wasm module.
+    ///
+    /// The section is encoded as a `vec(funcidx vec(hint))` where each hint
+    /// is `offset:u32 size:u32 value:u8`, a size of 1 and a value of 1 if the
+    /// branch is likely to be taken or 0 if it isn't.
+    fn branch_hint_section(&mut self, mut reader: BinaryReader<'data>) -> WasmResult<()> {
+        for _ in 0..reader.read_var_u32()? {
+            let func = FuncIndex::from_u32(reader.read_var_u32()?);
+            let mut hints = Vec::new();
+            for _ in 0..reader.read_var_u32()? {
+                let position = reader.original_position();
+                let offset = reader.read_var_u32()?;
+                let likely = match (reader.read_var_u32()?, reader.read_u8()?) {
+                    (1, 0) => false,
+                    (1, 1) => true,
+                    _ => {
+                        return Err(WasmError::InvalidWebAssembly {
+                            message: "invalid branch hint".to_string(),
+                            offset: position,
+                        })
+                    }
+                };
+                hints.push((offset, likely));
+            }
+            hints.sort_by_key(|(offset, _)| *offset);
+            self.result.branch_hints.insert(func, hints);
+        }
+        Ok(())
+    }
+
     /// Parses the Name section of the wasm module.
     fn name_section(&mut self, names: NameSectionReader<'data>) -> WasmResult<()> {
         for subsection in names {
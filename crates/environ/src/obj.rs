@@ -19,25 +19,32 @@ pub const EF_WASMTIME_COMPONENT: u32 = 1 << 1;
 /// mapping data from offsets in the image to offset in the original wasm
 /// binary.
 ///
-/// This section has a custom binary encoding. Currently its encoding is:
+/// This section has a custom binary encoding. Entries are grouped into blocks
+/// of 16, and currently its encoding is:
 ///
 /// * The section starts with a 32-bit little-endian integer. This integer is
-///   how many entries are in the following two arrays.
-/// * Next is an array with the previous count number of 32-bit little-endian
-///   integers. This array is a sorted list of relative offsets within the text
-///   section. This is intended to be a lookup array to perform a binary search
-///   on an offset within the text section on this array.
-/// * Finally there is another array, with the same count as before, also of
-///   32-bit little-endian integers. These integers map 1:1 with the previous
-///   array of offsets, and correspond to what the original offset was in the
-///   wasm file.
-///
-/// Decoding this section is intentionally simple, it only requires loading a
-/// 32-bit little-endian integer plus some bounds checks. Reading this section
-/// is done with the `lookup_file_pos` function below. Reading involves
-/// performing a binary search on the first array using the index found for the
-/// native code offset to index into the second array and find the wasm code
-/// offset.
+///   how many entries are in the address map in total.
+/// * Next is another 32-bit little-endian integer which is how many blocks
+///   there are, which is the entry count divided by 16 rounded up.
+/// * Next is an array, with the block count number of 32-bit little-endian
+///   integers, of the text section offset of the first entry in each block.
+///   This array is sorted and is used to perform a binary search for the block
+///   containing an offset within the text section.
+/// * Next is another array, with the same count as before, of 32-bit
+///   little-endian integers which are the original offsets in the wasm file of
+///   the first entry in each block.
+/// * Next is another array, with the same count as before, of 32-bit
+///   little-endian integers which are the byte offsets of each block's
+///   remaining entries within the trailing data.
+/// * Finally the rest of the section is the delta-encoded entries of each
+///   block, excluding the first. Each entry is a ULEB128-encoded increase of
+///   its text section offset relative to the previous entry followed by a
+///   zigzag ULEB128-encoded difference of its wasm offset relative to the
+///   previous entry.
+///
+/// Reading this section is done with the `lookup_file_pos` function, which
+/// binary searches the block index and then decodes at most one block to find
+/// the wasm code offset.
 ///
 /// At this time this section has an alignment of 1, which means all reads of it
 /// are unaligned. Additionally at this time the 32-bit encodings chosen here
@@ -58,14 +65,15 @@ pub const ELF_WASMTIME_ADDRMAP: &str = ".wasmtime.addrmap";
 /// * Next is an array, of the same length as read before, of 32-bit
 ///   little-endian integers. These integers are offsets into the text section
 ///   of the compilation image.
-/// * Finally is the same count number of bytes. Each of these bytes corresponds
-///   to a trap code.
+/// * Finally is an array, of the same count, of 16-bit little-endian integers.
+///   Each of these corresponds to a trap code: codes of user-defined traps are
+///   stored as is, and other traps are stored as reserved codes.
 ///
 /// This section is decoded by `lookup_trap_code` below which will read the
 /// section count, slice some bytes to get the various arrays, and then perform
 /// a binary search on the offsets array to find the index corresponding to
-/// the pc being looked up. If found the same index in the trap array (the array
-/// of bytes) is the trap code for that offset.
+/// the pc being looked up. If found the same index in the trap array is the
+/// trap code for that offset.
 ///
 /// Note that at this time this section has an alignment of 1. Additionally due
 /// to the 32-bit encodings for offsets this doesn't support images >=4gb.
@@ -129,6 +137,61 @@ pub const ELF_NAME_DATA: &'static str = ".name.wasm";
 /// metadata.
 pub const ELF_WASMTIME_DWARF: &str = ".wasmtime.dwarf";
 
+/// This is the name of the section in the final ELF image which lists the
+/// sections whose contents have been compressed with zstd.
+///
+/// The section is only present when `Tunables::compress_artifacts` was
+/// enabled at compile time and contains the names of the compressed sections,
+/// each terminated with a nul byte. Only non-executable sections which are
+/// read through owned copies at runtime, such as `ELF_WASMTIME_ADDRMAP`,
+/// `ELF_WASMTIME_INFO` and `ELF_WASMTIME_DWARF`, are ever compressed. Loaders
+/// decompress these sections transparently, so the rest of the runtime never
+/// observes the compressed encoding.
+pub const ELF_WASMTIME_COMPRESSED: &str = ".wasmtime.compressed";
+
+/// The zstd compression level used for compressed sections.
+///
+/// This is zstd's default level. Higher levels shrink artifacts only slightly
+/// further while making compilation much slower.
+#[cfg(feature = "zstd")]
+const ZSTD_LEVEL: i32 = 3;
+
+/// Compresses `data` for storage in a section listed in
+/// `ELF_WASMTIME_COMPRESSED` if `compress` is `true`, otherwise returns `data`
+/// unmodified.
+///
+/// # Panics
+///
+/// Panics if `compress` is `true` but the `zstd` feature of this crate is
+/// disabled. `Tunables::compress_artifacts` can only be enabled when the
+/// feature is active.
+pub fn maybe_compress_section(data: Vec<u8>, compress: bool) -> Vec<u8> {
+    if !compress {
+        return data;
+    }
+    #[cfg(feature = "zstd")]
+    return zstd::bulk::compress(&data, ZSTD_LEVEL).expect("failed to compress section");
+    #[cfg(not(feature = "zstd"))]
+    panic!("compressing sections requires the `zstd` feature");
+}
+
+/// Decompresses the contents of a section listed in `ELF_WASMTIME_COMPRESSED`.
+///
+/// Returns an error if the data is not a valid zstd frame or if the `zstd`
+/// feature of this crate is disabled.
+pub fn decompress_section(data: &[u8]) -> anyhow::Result<Vec<u8>> {
+    #[cfg(feature = "zstd")]
+    return Ok(zstd::stream::decode_all(data)?);
+    #[cfg(not(feature = "zstd"))]
+    {
+        let _ = data;
+        anyhow::bail!(
+            "compiled artifact contains compressed sections but support for \
+             them was disabled at compile time (the `zstd` feature)"
+        )
+    }
+}
+
 macro_rules! libcalls {
     ($($rust:ident = $sym:tt)*) => (
         #[allow(missing_docs)]
@@ -169,4 +232,5 @@ libcalls! {
     FmaF32 = "libcall_fmaf32"
     FmaF64 = "libcall_fmaf64"
     X86Pshufb = "libcall_x86_pshufb"
+    Aarch64HaveLseAtomics = "libcall_aarch64_have_lse_atomics"
 }
@@ -1,6 +1,6 @@
 use crate::obj::ELF_WASMTIME_TRAPS;
 use object::write::{Object, StandardSegment};
-use object::{Bytes, LittleEndian, SectionKind, U32Bytes};
+use object::{Bytes, LittleEndian, SectionKind, U16Bytes, U32Bytes};
 use std::convert::TryFrom;
 use std::fmt;
 use std::ops::Range;
@@ -14,7 +14,7 @@ use std::ops::Range;
 #[derive(Default)]
 pub struct TrapEncodingBuilder {
     offsets: Vec<U32Bytes<LittleEndian>>,
-    traps: Vec<u8>,
+    traps: Vec<U16Bytes<LittleEndian>>,
     last_offset: u32,
 }
 
@@ -96,9 +96,43 @@ pub enum Trap {
     /// would have violated the reentrance rules of the component model,
     /// triggering a trap instead.
     CannotEnterComponent,
-    // if adding a variant here be sure to update the `check!` macro below
+
+    /// A trap defined by the embedder or by a Wasmtime extension, raised by
+    /// compiled code trapping with `TrapCode::User` and the given code.
+    ///
+    /// Codes from [`FIRST_RESERVED_TRAP_CODE`] onwards are used by Wasmtime
+    /// itself and are never reported this way.
+    User(u16),
+    // if adding a variant here be sure to update `BUILTIN_TRAPS` below
 }
 
+/// The first `TrapCode::User` code reserved for Wasmtime's own traps.
+///
+/// Codes below this one are free for embedders and extensions to define
+/// their own trapping conditions with, and are reported as [`Trap::User`].
+pub const FIRST_RESERVED_TRAP_CODE: u16 = 0xff00;
+
+/// All traps other than [`Trap::User`], in the order of their encoding in the
+/// trap section.
+const BUILTIN_TRAPS: &[Trap] = &[
+    Trap::StackOverflow,
+    Trap::MemoryOutOfBounds,
+    Trap::HeapMisaligned,
+    Trap::TableOutOfBounds,
+    Trap::IndirectCallToNull,
+    Trap::BadSignature,
+    Trap::IntegerOverflow,
+    Trap::IntegerDivisionByZero,
+    Trap::BadConversionToInteger,
+    Trap::UnreachableCodeReached,
+    Trap::Interrupt,
+    Trap::AlwaysTrapAdapter,
+    Trap::OutOfFuel,
+    Trap::AtomicWaitNonSharedMemory,
+    Trap::NullReference,
+    Trap::CannotEnterComponent,
+];
+
 impl fmt::Display for Trap {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Trap::*;
@@ -120,6 +154,7 @@ impl fmt::Display for Trap {
             AtomicWaitNonSharedMemory => "atomic wait on non-shared memory",
             NullReference => "null reference",
             CannotEnterComponent => "cannot enter component instance",
+            User(code) => return write!(f, "wasm trap: user-defined trap {code}"),
         };
         write!(f, "wasm trap: {desc}")
     }
@@ -127,6 +162,163 @@ impl fmt::Display for Trap {
 
 impl std::error::Error for Trap {}
 
+impl Trap {
+    /// Returns the broad category that this trap falls into, see
+    /// [`TrapCategory`].
+    pub fn category(&self) -> TrapCategory {
+        use Trap::*;
+
+        match self {
+            MemoryOutOfBounds
+            | HeapMisaligned
+            | TableOutOfBounds
+            | IndirectCallToNull
+            | BadSignature
+            | IntegerOverflow
+            | IntegerDivisionByZero
+            | BadConversionToInteger
+            | UnreachableCodeReached
+            | AlwaysTrapAdapter
+            | AtomicWaitNonSharedMemory
+            | NullReference
+            | CannotEnterComponent
+            | User(_) => TrapCategory::GuestBug,
+            StackOverflow | OutOfFuel => TrapCategory::ResourceExhaustion,
+            Interrupt => TrapCategory::Deadline,
+        }
+    }
+
+    /// Encodes this trap as stored in the trap section: user-defined traps
+    /// are stored as their code, which leaves the reserved codes to encode
+    /// all other traps.
+    fn encode(self) -> u16 {
+        match self {
+            Trap::User(code) => {
+                assert!(code < FIRST_RESERVED_TRAP_CODE);
+                code
+            }
+            trap => {
+                let index = BUILTIN_TRAPS.iter().position(|t| *t == trap).unwrap();
+                FIRST_RESERVED_TRAP_CODE + u16::try_from(index).unwrap()
+            }
+        }
+    }
+
+    /// The inverse of `encode`.
+    fn decode(bits: u16) -> Option<Trap> {
+        match bits.checked_sub(FIRST_RESERVED_TRAP_CODE) {
+            Some(index) => BUILTIN_TRAPS.get(usize::from(index)).copied(),
+            None => Some(Trap::User(bits)),
+        }
+    }
+}
+
+/// A coarse classification of why execution of WebAssembly failed.
+///
+/// Hosts which run guests on behalf of clients, such as HTTP or gRPC
+/// services, generally want to report guest failures with a small and stable
+/// set of error categories rather than exposing the individual [`Trap`] that
+/// was hit. This enum provides that classification along with conventional
+/// mappings to HTTP status codes and gRPC status codes.
+///
+/// New [`Trap`] variants are assigned to one of these categories when they're
+/// added, so the set of categories itself is stable.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum TrapCategory {
+    /// The guest did something invalid, such as accessing memory out of
+    /// bounds or executing an `unreachable` instruction.
+    GuestBug,
+
+    /// The guest ran out of a resource it's been given, such as stack space or
+    /// fuel.
+    ResourceExhaustion,
+
+    /// The host cancelled the call, for example through its cancellation
+    /// token, see [`CallCancelled`].
+    HostCancellation,
+
+    /// The guest ran past its deadline and was interrupted, for example
+    /// through epoch-based interruption.
+    Deadline,
+
+    /// The call failed with an error that isn't a trap, such as an error
+    /// returned from a host function or from a resource limiter, so there's
+    /// nothing to say about its cause.
+    Unclassified,
+}
+
+impl TrapCategory {
+    /// Classifies an error returned from calling into WebAssembly.
+    ///
+    /// Calls cancelled by the host fail with a [`Trap::Interrupt`] carrying
+    /// [`CallCancelled`] as context and are classified as
+    /// [`TrapCategory::HostCancellation`]. Otherwise, if the error is a
+    /// [`Trap`] then its [`Trap::category`] is returned, and any other error
+    /// is [`TrapCategory::Unclassified`].
+    pub fn of_error(error: &anyhow::Error) -> TrapCategory {
+        if error.downcast_ref::<CallCancelled>().is_some() {
+            return TrapCategory::HostCancellation;
+        }
+        match error.downcast_ref::<Trap>() {
+            Some(trap) => trap.category(),
+            None => TrapCategory::Unclassified,
+        }
+    }
+
+    /// Returns the HTTP status code conventionally used for this category.
+    ///
+    /// This follows the mapping from gRPC status codes to HTTP status codes
+    /// used by Google APIs, see [`TrapCategory::grpc_code`].
+    pub fn http_status(&self) -> u16 {
+        match self {
+            TrapCategory::GuestBug => 500,
+            TrapCategory::ResourceExhaustion => 429,
+            TrapCategory::HostCancellation => 499,
+            TrapCategory::Deadline => 504,
+            TrapCategory::Unclassified => 500,
+        }
+    }
+
+    /// Returns the gRPC status code for this category.
+    ///
+    /// These are `INTERNAL`, `RESOURCE_EXHAUSTED`, `CANCELLED`,
+    /// `DEADLINE_EXCEEDED` and `UNKNOWN` respectively.
+    pub fn grpc_code(&self) -> i32 {
+        match self {
+            TrapCategory::GuestBug => 13,
+            TrapCategory::ResourceExhaustion => 8,
+            TrapCategory::HostCancellation => 1,
+            TrapCategory::Deadline => 4,
+            TrapCategory::Unclassified => 2,
+        }
+    }
+}
+
+impl fmt::Display for TrapCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let desc = match self {
+            TrapCategory::GuestBug => "guest bug",
+            TrapCategory::ResourceExhaustion => "resource exhaustion",
+            TrapCategory::HostCancellation => "host cancellation",
+            TrapCategory::Deadline => "deadline exceeded",
+            TrapCategory::Unclassified => "unclassified error",
+        };
+        f.write_str(desc)
+    }
+}
+
+/// Context attached to the [`Trap::Interrupt`] that a call fails with when
+/// the host cancels it, which distinguishes it from the same trap raised when
+/// the call's deadline is reached.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CallCancelled;
+
+impl fmt::Display for CallCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("call cancelled by the host")
+    }
+}
+
 impl TrapEncodingBuilder {
     /// Appends trap information about a function into this section.
     ///
@@ -155,7 +347,8 @@ impl TrapEncodingBuilder {
             let pos = func_start + info.code_offset;
             assert!(pos >= self.last_offset);
             self.offsets.push(U32Bytes::new(LittleEndian, pos));
-            self.traps.push(info.trap_code as u8);
+            self.traps
+                .push(U16Bytes::new(LittleEndian, info.trap_code.encode()));
             self.last_offset = pos;
         }
 
@@ -169,12 +362,17 @@ impl TrapEncodingBuilder {
             ELF_WASMTIME_TRAPS.as_bytes().to_vec(),
             SectionKind::ReadOnlyData,
         );
+        obj.append_section_data(section, &self.encode(), 1);
+    }
 
+    /// Encodes this section into a byte vector.
+    fn encode(&self) -> Vec<u8> {
         // NB: this matches the encoding expected by `lookup` below.
         let amt = u32::try_from(self.traps.len()).unwrap();
-        obj.append_section_data(section, &amt.to_le_bytes(), 1);
-        obj.append_section_data(section, object::bytes_of_slice(&self.offsets), 1);
-        obj.append_section_data(section, &self.traps, 1);
+        let mut data = amt.to_le_bytes().to_vec();
+        data.extend_from_slice(object::bytes_of_slice(&self.offsets));
+        data.extend_from_slice(object::bytes_of_slice(&self.traps));
+        data
     }
 }
 
@@ -191,7 +389,7 @@ pub fn lookup_trap_code(section: &[u8], offset: usize) -> Option<Trap> {
     let count = usize::try_from(count.get(LittleEndian)).ok()?;
     let (offsets, traps) =
         object::slice_from_bytes::<U32Bytes<LittleEndian>>(section.0, count).ok()?;
-    debug_assert_eq!(traps.len(), count);
+    let (traps, _) = object::slice_from_bytes::<U16Bytes<LittleEndian>>(traps, count).ok()?;
 
     // The `offsets` table is sorted in the trap section so perform a binary
     // search of the contents of this section to find whether `offset` is an
@@ -200,47 +398,57 @@ pub fn lookup_trap_code(section: &[u8], offset: usize) -> Option<Trap> {
     // we expect an exact match to correspond to a trap opcode.
     //
     // Once an index is found within the `offsets` array then that same index is
-    // used to lookup from the `traps` list of bytes to get the trap code byte
-    // corresponding to this offset.
+    // used to lookup from the `traps` list to get the trap code corresponding
+    // to this offset.
     let offset = u32::try_from(offset).ok()?;
     let index = offsets
         .binary_search_by_key(&offset, |val| val.get(LittleEndian))
         .ok()?;
     debug_assert!(index < traps.len());
-    let trap = *traps.get(index)?;
+    let trap = traps.get(index)?.get(LittleEndian);
 
-    // FIXME: this could use some sort of derive-like thing to avoid having to
-    // deduplicate the names here.
-    //
-    // This simply converts from the `trap`, a `u8`, to the `Trap` enum.
-    macro_rules! check {
-        ($($name:ident)*) => ($(if trap == Trap::$name as u8 {
-            return Some(Trap::$name);
-        })*);
+    match Trap::decode(trap) {
+        Some(trap) => Some(trap),
+        None if cfg!(debug_assertions) => panic!("missing mapping for {}", trap),
+        None => None,
     }
+}
 
-    check! {
-        StackOverflow
-        MemoryOutOfBounds
-        HeapMisaligned
-        TableOutOfBounds
-        IndirectCallToNull
-        BadSignature
-        IntegerOverflow
-        IntegerDivisionByZero
-        BadConversionToInteger
-        UnreachableCodeReached
-        Interrupt
-        AlwaysTrapAdapter
-        OutOfFuel
-        AtomicWaitNonSharedMemory
-        NullReference
-        CannotEnterComponent
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_and_lookup() {
+        let traps = BUILTIN_TRAPS
+            .iter()
+            .copied()
+            .chain([
+                Trap::User(0),
+                Trap::User(7),
+                Trap::User(FIRST_RESERVED_TRAP_CODE - 1),
+            ])
+            .enumerate()
+            .map(|(i, trap_code)| TrapInformation {
+                code_offset: u32::try_from(i).unwrap() * 4,
+                trap_code,
+            })
+            .collect::<Vec<_>>();
+        let mut builder = TrapEncodingBuilder::default();
+        builder.push(0x10..0x100, &traps);
+        let section = builder.encode();
+
+        for info in traps.iter() {
+            let offset = 0x10 + usize::try_from(info.code_offset).unwrap();
+            assert_eq!(lookup_trap_code(&section, offset), Some(info.trap_code));
+        }
+        assert_eq!(lookup_trap_code(&section, 0x12), None);
+        assert_eq!(lookup_trap_code(&section, 0x200), None);
     }
 
-    if cfg!(debug_assertions) {
-        panic!("missing mapping for {}", trap);
-    } else {
-        None
+    #[test]
+    #[should_panic]
+    fn reserved_user_code() {
+        Trap::User(FIRST_RESERVED_TRAP_CODE).encode();
     }
 }
@@ -1,3 +1,4 @@
+use crate::AddressMapGranularity;
 use serde_derive::{Deserialize, Serialize};
 
 /// Tunable parameters for WebAssembly compilation.
@@ -40,9 +41,9 @@ pub struct Tunables {
     /// beginning of the allocation in addition to the end.
     pub guard_before_linear_memory: bool,
 
-    /// Indicates whether an address map from compiled native code back to wasm
-    /// offsets in the original file is generated.
-    pub generate_address_map: bool,
+    /// How much detail the address map from compiled native code back to wasm
+    /// offsets in the original file records, if it's generated at all.
+    pub address_map_granularity: AddressMapGranularity,
 
     /// Flag for the component module whether adapter modules have debug
     /// assertions baked into them.
@@ -54,6 +55,31 @@ pub struct Tunables {
 
     /// Whether or not Wasm functions can be tail-called or not.
     pub tail_callable: bool,
+
+    /// Whether or not non-executable sections of compiled artifacts, such as
+    /// the address map, DWARF and module metadata, are compressed.
+    pub compress_artifacts: bool,
+
+    /// The maximum size, in bytes, of a function body that will be compiled.
+    /// Larger functions fail to compile with
+    /// [`CompileError::FunctionTooLarge`](crate::CompileError::FunctionTooLarge).
+    pub max_function_body_size: Option<usize>,
+
+    /// Whether or not compiled code counts how often each function and block
+    /// is executed, for code coverage.
+    pub coverage: bool,
+
+    /// Whether or not compiled code reports every linear memory load and
+    /// store to the store, for tracing memory accesses.
+    pub memory_trace: bool,
+
+    /// Whether or not compiled code reports the entry into every basic block
+    /// to the store, for tracing execution.
+    pub block_trace: bool,
+
+    /// Whether or not compilers record per-function code generation
+    /// statistics in the compiled artifact. Only Winch records them.
+    pub codegen_stats: bool,
 }
 
 impl Default for Tunables {
@@ -108,10 +134,16 @@ impl Default for Tunables {
             epoch_interruption: false,
             static_memory_bound_is_maximum: false,
             guard_before_linear_memory: true,
-            generate_address_map: true,
+            address_map_granularity: AddressMapGranularity::Full,
             debug_adapter_modules: false,
             relaxed_simd_deterministic: false,
             tail_callable: false,
+            compress_artifacts: false,
+            max_function_body_size: None,
+            coverage: false,
+            memory_trace: false,
+            block_trace: false,
+            codegen_stats: false,
         }
     }
 }
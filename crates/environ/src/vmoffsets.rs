@@ -21,6 +21,7 @@
 //      owned_memories: [VMMemoryDefinition; module.num_owned_memories],
 //      globals: [VMGlobalDefinition; module.num_defined_globals],
 //      func_refs: [VMFuncRef; module.num_escaped_funcs],
+//      coverage_counters: *mut u64, // only with coverage instrumentation
 // }
 
 use crate::{
@@ -71,6 +72,9 @@ pub struct VMOffsets<P> {
     /// The number of escaped functions in the module, the size of the func_refs
     /// array.
     pub num_escaped_funcs: u32,
+    /// Whether the module's code is instrumented for code coverage, in which
+    /// case the `VMContext` holds a pointer to the coverage counters.
+    pub coverage: bool,
 
     // precalculated offsets of various member fields
     magic: u32,
@@ -90,6 +94,7 @@ pub struct VMOffsets<P> {
     owned_memories: u32,
     defined_globals: u32,
     defined_func_refs: u32,
+    coverage_counters: u32,
     size: u32,
 }
 
@@ -266,6 +271,8 @@ pub struct VMOffsetsFields<P> {
     /// The number of escaped functions in the module, the size of the function
     /// references array.
     pub num_escaped_funcs: u32,
+    /// Whether the module's code is instrumented for code coverage.
+    pub coverage: bool,
 }
 
 impl<P: PtrSize> VMOffsets<P> {
@@ -292,6 +299,7 @@ impl<P: PtrSize> VMOffsets<P> {
             num_owned_memories,
             num_defined_globals: cast_to_u32(module.globals.len() - module.num_imported_globals),
             num_escaped_funcs: cast_to_u32(module.num_escaped_funcs),
+            coverage: module.coverage.num_counters() != 0,
         })
     }
 
@@ -321,6 +329,7 @@ impl<P: PtrSize> VMOffsets<P> {
                     num_defined_memories: _,
                     num_owned_memories: _,
                     num_escaped_funcs: _,
+                    coverage: _,
 
                     // used as the initial size below
                     size,
@@ -346,6 +355,7 @@ impl<P: PtrSize> VMOffsets<P> {
         }
 
         calculate_sizes! {
+            coverage_counters: "coverage counters pointer",
             defined_func_refs: "module functions",
             defined_globals: "defined globals",
             owned_memories: "owned memories",
@@ -380,6 +390,7 @@ impl<P: PtrSize> From<VMOffsetsFields<P>> for VMOffsets<P> {
             num_owned_memories: fields.num_owned_memories,
             num_defined_globals: fields.num_defined_globals,
             num_escaped_funcs: fields.num_escaped_funcs,
+            coverage: fields.coverage,
             magic: 0,
             runtime_limits: 0,
             callee: 0,
@@ -397,6 +408,7 @@ impl<P: PtrSize> From<VMOffsetsFields<P>> for VMOffsets<P> {
             owned_memories: 0,
             defined_globals: 0,
             defined_func_refs: 0,
+            coverage_counters: 0,
             size: 0,
         };
 
@@ -460,6 +472,7 @@ impl<P: PtrSize> From<VMOffsetsFields<P>> for VMOffsets<P> {
                 ret.num_escaped_funcs,
                 ret.ptr.size_of_vm_func_ref(),
             ),
+            size(coverage_counters) = if ret.coverage { ret.ptr.size() } else { 0 },
         }
 
         ret.size = next_field_offset;
@@ -703,6 +716,17 @@ impl<P: PtrSize> VMOffsets<P> {
         self.defined_func_refs
     }
 
+    /// The offset of the `*mut u64` pointer to the module's coverage
+    /// counters.
+    ///
+    /// This field is only present if the module is instrumented for code
+    /// coverage.
+    #[inline]
+    pub fn vmctx_coverage_counters(&self) -> u32 {
+        assert!(self.coverage);
+        self.coverage_counters
+    }
+
     /// The offset of the builtin functions array.
     #[inline]
     pub fn vmctx_builtin_functions(&self) -> u32 {
@@ -117,12 +117,15 @@ impl<'a> Arbitrary<'a> for CodegenSettings {
                     std:"avx512f" => clif:"has_avx512f" ratio: 1 in 1000,
                     std:"avx512vl" => clif:"has_avx512vl" ratio: 1 in 1000,
                     std:"avx512vbmi" => clif:"has_avx512vbmi" ratio: 1 in 1000,
+                    std:"avx512vnni" => clif:"has_avx512vnni" ratio: 1 in 1000,
                 },
                 "aarch64" => {
                     test: is_aarch64_feature_detected,
 
                     std: "bti" => clif: "use_bti",
+                    std: "dotprod" => clif: "has_dotprod",
                     std: "lse" => clif: "has_lse",
+                    std: "rcpc" => clif: "has_rcpc",
                     // even though the natural correspondence seems to be
                     // between "paca" and "has_pauth", the latter has no effect
                     // in isolation, so we actually use the setting that affects
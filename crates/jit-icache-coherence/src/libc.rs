@@ -132,18 +132,51 @@ fn riscv_flush_icache(start: u64, end: u64) -> Result<()> {
     }
 }
 
+#[cfg(all(target_arch = "aarch64", target_os = "macos"))]
+extern "C" {
+    // Provided by libSystem, see `man 3 sys_icache_invalidate`.
+    fn sys_icache_invalidate(start: *mut c_void, len: usize);
+}
+
+/// Removes any pointer authentication code from `ptr`, which
+/// `sys_icache_invalidate` expects to operate on plain addresses.
+#[cfg(all(target_arch = "aarch64", target_os = "macos"))]
+fn strip_pointer_authentication(ptr: *const c_void) -> *const c_void {
+    let mut ptr = ptr as u64;
+    unsafe {
+        // `xpaclri` lives in the hint space, so this is a no-op on processors
+        // without pointer authentication.
+        std::arch::asm!(
+            "mov x30, {ptr}",
+            "hint #7", // xpaclri
+            "mov {ptr}, x30",
+            ptr = inout(reg) ptr,
+            out("x30") _,
+            options(nomem, nostack, preserves_flags),
+        );
+    }
+    ptr as *const c_void
+}
+
 pub(crate) use details::*;
 
 /// See docs on [crate::clear_cache] for a description of what this function is trying to do.
 #[inline]
 pub(crate) fn clear_cache(_ptr: *const c_void, _len: usize) -> Result<()> {
-    // TODO: On AArch64 we currently rely on the `mprotect` call that switches the memory from W+R
-    // to R+X to do this for us, however that is an implementation detail and should not be relied
-    // upon.
+    // TODO: On AArch64 Linux we currently rely on the `mprotect` call that switches the memory
+    // from W+R to R+X to do this for us, however that is an implementation detail and should not
+    // be relied upon.
     // We should call some implementation of `clear_cache` here.
     //
     // See: https://github.com/bytecodealliance/wasmtime/issues/3310
     #[cfg(all(target_arch = "riscv64", target_os = "linux"))]
     riscv_flush_icache(_ptr as u64, (_ptr as u64) + (_len as u64))?;
+
+    // Code in memory mapped with `MAP_JIT` on macOS may be written without any `mprotect` in
+    // between, so the cache needs to be invalidated explicitly.
+    #[cfg(all(target_arch = "aarch64", target_os = "macos"))]
+    unsafe {
+        sys_icache_invalidate(strip_pointer_authentication(_ptr).cast_mut(), _len)
+    };
     Ok(())
 }
@@ -18,12 +18,19 @@ use wasmtime_runtime::MmapVec;
 /// executable permissions of the contained JIT code as necessary.
 pub struct CodeMemory {
     // NB: these are `ManuallyDrop` because `unwind_registration` must be
-    // dropped first since it refers to memory owned by `mmap`.
+    // dropped first since it refers to memory owned by `mmap` or `jit_copy`.
     mmap: ManuallyDrop<MmapVec>,
     unwind_registration: ManuallyDrop<Option<UnwindRegistration>>,
     published: bool,
     enable_branch_protection: bool,
 
+    // A copy of the text section, followed by the unwind information which
+    // refers to it relative to its own address, in memory mapped with
+    // `MAP_JIT`. Made when publishing if `use_map_jit` is set.
+    jit_copy: ManuallyDrop<Option<MmapVec>>,
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+    use_map_jit: bool,
+
     relocations: Vec<(usize, obj::LibCall)>,
 
     // Ranges within `self.mmap` of where the particular sections lie.
@@ -31,17 +38,36 @@ pub struct CodeMemory {
     unwind: Range<usize>,
     trap_data: Range<usize>,
     wasm_data: Range<usize>,
-    address_map_data: Range<usize>,
+    address_map_data: SectionData,
     func_name_data: Range<usize>,
-    info_data: Range<usize>,
-    dwarf: Range<usize>,
+    info_data: SectionData,
+    dwarf: SectionData,
+}
+
+/// The contents of a section which may have been compressed in the original
+/// artifact, see `obj::ELF_WASMTIME_COMPRESSED`.
+enum SectionData {
+    /// The section is stored uncompressed at this range within `mmap`.
+    Mapped(Range<usize>),
+    /// The section was compressed and has been decompressed into this buffer.
+    Decompressed(Box<[u8]>),
+}
+
+impl SectionData {
+    fn get<'a>(&'a self, mmap: &'a MmapVec) -> &'a [u8] {
+        match self {
+            SectionData::Mapped(range) => &mmap[range.clone()],
+            SectionData::Decompressed(data) => data,
+        }
+    }
 }
 
 impl Drop for CodeMemory {
     fn drop(&mut self) {
-        // Drop `unwind_registration` before `self.mmap`
+        // Drop `unwind_registration` before the memory it refers to
         unsafe {
             ManuallyDrop::drop(&mut self.unwind_registration);
+            ManuallyDrop::drop(&mut self.jit_copy);
             ManuallyDrop::drop(&mut self.mmap);
         }
     }
@@ -62,16 +88,37 @@ impl CodeMemory {
         let obj = File::parse(&mmap[..])
             .with_context(|| "failed to parse internal compilation artifact")?;
 
+        // Determine up-front which sections, if any, were compressed when this
+        // artifact was created.
+        let compressed = match obj.section_by_name(obj::ELF_WASMTIME_COMPRESSED) {
+            Some(section) => section
+                .data()?
+                .split(|b| *b == 0)
+                .filter(|name| !name.is_empty())
+                .map(|name| std::str::from_utf8(name))
+                .collect::<Result<Vec<_>, _>>()
+                .with_context(|| format!("invalid `{}` section", obj::ELF_WASMTIME_COMPRESSED))?,
+            None => Vec::new(),
+        };
+        let section_data = |name: &str, data: &[u8], range: Range<usize>| -> Result<SectionData> {
+            if !compressed.contains(&name) {
+                return Ok(SectionData::Mapped(range));
+            }
+            let data = obj::decompress_section(data)
+                .with_context(|| format!("failed to decompress section `{name}`"))?;
+            Ok(SectionData::Decompressed(data.into()))
+        };
+
         let mut relocations = Vec::new();
         let mut text = 0..0;
         let mut unwind = 0..0;
         let mut enable_branch_protection = None;
         let mut trap_data = 0..0;
         let mut wasm_data = 0..0;
-        let mut address_map_data = 0..0;
+        let mut address_map_data = SectionData::Mapped(0..0);
         let mut func_name_data = 0..0;
-        let mut info_data = 0..0;
-        let mut dwarf = 0..0;
+        let mut info_data = SectionData::Mapped(0..0);
+        let mut dwarf = SectionData::Mapped(0..0);
         for section in obj.sections() {
             let data = section.data()?;
             let name = section.name()?;
@@ -121,11 +168,13 @@ impl CodeMemory {
                 }
                 UnwindRegistration::SECTION_NAME => unwind = range,
                 obj::ELF_WASM_DATA => wasm_data = range,
-                obj::ELF_WASMTIME_ADDRMAP => address_map_data = range,
+                obj::ELF_WASMTIME_ADDRMAP => {
+                    address_map_data = section_data(name, data, range)?;
+                }
                 obj::ELF_WASMTIME_TRAPS => trap_data = range,
                 obj::ELF_NAME_DATA => func_name_data = range,
-                obj::ELF_WASMTIME_INFO => info_data = range,
-                obj::ELF_WASMTIME_DWARF => dwarf = range,
+                obj::ELF_WASMTIME_INFO => info_data = section_data(name, data, range)?,
+                obj::ELF_WASMTIME_DWARF => dwarf = section_data(name, data, range)?,
 
                 _ => log::debug!("ignoring section {name}"),
             }
@@ -136,6 +185,8 @@ impl CodeMemory {
             published: false,
             enable_branch_protection: enable_branch_protection
                 .ok_or_else(|| anyhow!("missing `{}` section", obj::ELF_WASM_BTI))?,
+            jit_copy: ManuallyDrop::new(None),
+            use_map_jit: false,
             text,
             unwind,
             trap_data,
@@ -154,17 +205,43 @@ impl CodeMemory {
         &self.mmap
     }
 
+    /// Configures whether `publish` copies the text section into memory
+    /// mapped with `MAP_JIT` on macOS, which the hardened runtime requires
+    /// for executable memory. Does nothing on other platforms.
+    ///
+    /// Only the text section and the unwind information following it are
+    /// copied, the rest of the image stays where it is. The copy of the text
+    /// section is made read/execute like the original otherwise would be.
+    pub fn use_map_jit(&mut self, enable: bool) {
+        assert!(!self.published);
+        self.use_map_jit = enable;
+    }
+
     /// Returns the contents of the text section of the ELF executable this
     /// represents.
     #[inline]
     pub fn text(&self) -> &[u8] {
-        &self.mmap[self.text.clone()]
+        match &*self.jit_copy {
+            Some(copy) => &copy[..self.text.len()],
+            None => &self.mmap[self.text.clone()],
+        }
+    }
+
+    /// Returns the unwind information for the text section, which is copied
+    /// along with it.
+    fn unwind(&self) -> &[u8] {
+        match &*self.jit_copy {
+            Some(copy) => {
+                &copy[self.unwind.start - self.text.start..self.unwind.end - self.text.start]
+            }
+            None => &self.mmap[self.unwind.clone()],
+        }
     }
 
     /// Returns the contents of the `ELF_WASMTIME_DWARF` section.
     #[inline]
     pub fn dwarf(&self) -> &[u8] {
-        &self.mmap[self.dwarf.clone()]
+        self.dwarf.get(&self.mmap)
     }
 
     /// Returns the data in the `ELF_NAME_DATA` section.
@@ -187,14 +264,14 @@ impl CodeMemory {
     /// `wasmtime_environ::lookup_file_pos`.
     #[inline]
     pub fn address_map_data(&self) -> &[u8] {
-        &self.mmap[self.address_map_data.clone()]
+        self.address_map_data.get(&self.mmap)
     }
 
     /// Returns the contents of the `ELF_WASMTIME_INFO` section, or an empty
     /// slice if it wasn't found.
     #[inline]
     pub fn wasmtime_info(&self) -> &[u8] {
-        &self.mmap[self.info_data.clone()]
+        self.info_data.get(&self.mmap)
     }
 
     /// Returns the contents of the `ELF_WASMTIME_TRAPS` section, or an empty
@@ -231,12 +308,34 @@ impl CodeMemory {
         //   both the actual unwinding tables as well as the validity of the
         //   pointers we pass in itself.
         unsafe {
+            // Under macOS's hardened runtime only memory mapped with
+            // `MAP_JIT` may be made executable, so if requested copy the text
+            // section into such memory first. The unwind information encodes
+            // function addresses relative to itself, so it's copied along
+            // with the text section it follows.
+            #[cfg(target_os = "macos")]
+            if self.use_map_jit {
+                let end = if self.unwind.is_empty() {
+                    self.text.end
+                } else {
+                    assert!(self.unwind.start >= self.text.end);
+                    self.unwind.end
+                };
+                let copy = MmapVec::jit_from_slice(&self.mmap[self.text.start..end])?;
+                *self.jit_copy = Some(copy);
+            }
+
             // First, if necessary, apply relocations. This can happen for
             // things like libcalls which happen late in the lowering process
             // that don't go through the Wasm-based libcalls layer that's
             // indirected through the `VMContext`. Note that most modules won't
             // have relocations, so this typically doesn't do anything.
-            self.apply_relocations()?;
+            #[cfg(target_os = "macos")]
+            wasmtime_runtime::set_jit_writable(true);
+            let result = self.apply_relocations();
+            #[cfg(target_os = "macos")]
+            wasmtime_runtime::set_jit_writable(false);
+            result?;
 
             // Next freeze the contents of this image by making all of the
             // memory readonly. Nothing after this point should ever be modified
@@ -246,6 +345,9 @@ impl CodeMemory {
             // there weren't any relocations because nothing should have
             // otherwise written to the image at any point either.
             self.mmap.make_readonly(0..self.mmap.len())?;
+            if let Some(copy) = &*self.jit_copy {
+                copy.make_readonly(0..copy.len())?;
+            }
 
             let text = self.text();
 
@@ -257,9 +359,13 @@ impl CodeMemory {
                 .expect("Failed cache clear");
 
             // Switch the executable portion from readonly to read/execute.
-            self.mmap
-                .make_executable(self.text.clone(), self.enable_branch_protection)
-                .context("unable to make memory executable")?;
+            match &*self.jit_copy {
+                Some(copy) => copy.make_executable(0..text.len(), self.enable_branch_protection),
+                None => self
+                    .mmap
+                    .make_executable(self.text.clone(), self.enable_branch_protection),
+            }
+            .context("unable to make memory executable")?;
 
             // Flush any in-flight instructions from the pipeline
             icache_coherence::pipeline_flush_mt().expect("Failed pipeline flush");
@@ -279,8 +385,11 @@ impl CodeMemory {
             return Ok(());
         }
 
+        let text = match &mut *self.jit_copy {
+            Some(copy) => copy.as_mut_ptr(),
+            None => self.mmap.as_mut_ptr().add(self.text.start),
+        };
         for (offset, libcall) in self.relocations.iter() {
-            let offset = self.text.start + offset;
             let libcall = match libcall {
                 obj::LibCall::FloorF32 => libcalls::relocs::floorf32 as usize,
                 obj::LibCall::FloorF64 => libcalls::relocs::floorf64 as usize,
@@ -296,12 +405,14 @@ impl CodeMemory {
                 obj::LibCall::X86Pshufb => libcalls::relocs::x86_pshufb as usize,
                 #[cfg(not(target_arch = "x86_64"))]
                 obj::LibCall::X86Pshufb => unreachable!(),
+                #[cfg(target_arch = "aarch64")]
+                obj::LibCall::Aarch64HaveLseAtomics => {
+                    libcalls::relocs::aarch64_have_lse_atomics() as usize
+                }
+                #[cfg(not(target_arch = "aarch64"))]
+                obj::LibCall::Aarch64HaveLseAtomics => unreachable!(),
             };
-            self.mmap
-                .as_mut_ptr()
-                .add(offset)
-                .cast::<usize>()
-                .write_unaligned(libcall);
+            text.add(*offset).cast::<usize>().write_unaligned(libcall);
         }
         Ok(())
     }
@@ -311,7 +422,7 @@ impl CodeMemory {
             return Ok(());
         }
         let text = self.text();
-        let unwind_info = &self.mmap[self.unwind.clone()];
+        let unwind_info = self.unwind();
         let registration =
             UnwindRegistration::new(text.as_ptr(), unwind_info.as_ptr(), unwind_info.len())
                 .context("failed to create unwind info registration")?;
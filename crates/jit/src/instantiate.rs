@@ -125,11 +125,11 @@ pub struct ObjectBuilder<'a> {
     /// This is optional and lazily created on demand.
     names: Option<SectionId>,
 
-    /// The section identifier for dwarf information copied from the original
-    /// wasm files.
+    /// Dwarf information copied from the original wasm files.
     ///
-    /// This is optional and lazily created on demand.
-    dwarf: Option<SectionId>,
+    /// This is buffered here and only placed in the `ELF_WASMTIME_DWARF`
+    /// section in `finish` so the whole section can be compressed at once.
+    dwarf: Vec<u8>,
 }
 
 impl<'a> ObjectBuilder<'a> {
@@ -145,7 +145,7 @@ impl<'a> ObjectBuilder<'a> {
             tunables,
             data,
             names: None,
-            dwarf: None,
+            dwarf: Vec::new(),
         }
     }
 
@@ -324,14 +324,8 @@ impl<'a> ObjectBuilder<'a> {
         if data.is_empty() {
             return;
         }
-        let section_id = *self.dwarf.get_or_insert_with(|| {
-            self.obj.add_section(
-                self.obj.segment_name(StandardSegment::Debug).to_vec(),
-                obj::ELF_WASMTIME_DWARF.as_bytes().to_vec(),
-                SectionKind::Debug,
-            )
-        });
-        let offset = self.obj.append_section_data(section_id, data, 1);
+        let offset = self.dwarf.len() as u64;
+        self.dwarf.extend_from_slice(data);
         dwarf.push((T::id() as u8, offset..offset + data.len() as u64));
     }
 
@@ -347,6 +341,7 @@ impl<'a> ObjectBuilder<'a> {
             SectionKind::ReadOnlyData,
         );
         let data = bincode::serialize(info).unwrap();
+        let data = obj::maybe_compress_section(data, self.tunables.compress_artifacts);
         self.obj.set_section_data(section, data, 1);
     }
 
@@ -354,7 +349,39 @@ impl<'a> ObjectBuilder<'a> {
     ///
     /// The returned `MmapVec` will contain the serialized version of `self`
     /// and is sized appropriately to the exact size of the object serialized.
-    pub fn finish(self) -> Result<MmapVec> {
+    pub fn finish(mut self) -> Result<MmapVec> {
+        let compress = self.tunables.compress_artifacts;
+        if !self.dwarf.is_empty() {
+            let section = self.obj.add_section(
+                self.obj.segment_name(StandardSegment::Debug).to_vec(),
+                obj::ELF_WASMTIME_DWARF.as_bytes().to_vec(),
+                SectionKind::Debug,
+            );
+            let dwarf = std::mem::take(&mut self.dwarf);
+            let data = obj::maybe_compress_section(dwarf, compress);
+            self.obj.set_section_data(section, data, 1);
+        }
+
+        // Record which sections were compressed so loading the artifact can
+        // transparently decompress them.
+        if compress {
+            let section = self.obj.add_section(
+                self.obj.segment_name(StandardSegment::Data).to_vec(),
+                obj::ELF_WASMTIME_COMPRESSED.as_bytes().to_vec(),
+                SectionKind::ReadOnlyData,
+            );
+            let mut names = Vec::new();
+            for name in [
+                obj::ELF_WASMTIME_ADDRMAP,
+                obj::ELF_WASMTIME_INFO,
+                obj::ELF_WASMTIME_DWARF,
+            ] {
+                names.extend_from_slice(name.as_bytes());
+                names.push(0);
+            }
+            self.obj.set_section_data(section, names, 1);
+        }
+
         let mut result = ObjectMmap::default();
         return match self.obj.emit(&mut result) {
             Ok(()) => {
@@ -52,20 +52,16 @@ pub trait ProfilingAgent: Send + Sync + 'static {
     fn register_function(&self, name: &str, addr: *const u8, size: usize);
 
     fn register_module(&self, code: &CodeMemory, custom_name: &dyn Fn(usize) -> Option<String>) {
-        use object::{File, Object as _, ObjectSection, ObjectSymbol, SectionKind, SymbolKind};
+        use object::{File, Object as _, ObjectSymbol, SymbolKind};
 
         let image = match File::parse(&code.mmap()[..]) {
             Ok(image) => image,
             Err(_) => return,
         };
 
-        let text_base = match image.sections().find(|s| s.kind() == SectionKind::Text) {
-            Some(section) => match section.data() {
-                Ok(data) => data.as_ptr() as usize,
-                Err(_) => return,
-            },
-            None => return,
-        };
+        // The text section may have been moved out of the image when it was
+        // published, so ask `code` for its address.
+        let text_base = code.text().as_ptr() as usize;
 
         for sym in image.symbols() {
             if !sym.is_definition() {
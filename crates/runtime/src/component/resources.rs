@@ -282,6 +282,27 @@ impl ResourceTable {
         self.next as usize
     }
 
+    /// Returns the number of handles, owned or borrowed, in this table.
+    pub fn num_handles(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| !matches!(slot, Slot::Free { .. }))
+            .count()
+    }
+
+    /// Removes all owned handles which aren't currently lent out from this
+    /// table, returning the representations of their resources.
+    pub fn take_owned(&mut self) -> Vec<u32> {
+        let mut reps = Vec::new();
+        for idx in 0..self.slots.len() {
+            if let Slot::Own { rep, lend_count: 0 } = self.slots[idx] {
+                reps.push(rep);
+                self.remove(u32::try_from(idx).unwrap()).unwrap();
+            }
+        }
+        reps
+    }
+
     fn insert(&mut self, new: Slot) -> u32 {
         let next = self.next();
         if next == self.slots.len() {
@@ -95,6 +95,24 @@
 //! "borrowed" from the `VMExternRefActivationsTable` and the reference count
 //! from the table will be dropped at the next GC).
 //!
+//! ## Incremental Release
+//!
+//! Tracing the stack is bounded by the depth of the Wasm stack, but sweeping
+//! can drop an arbitrary number of references and run arbitrary destructors.
+//! To bound pause times, the activations table may be configured with a
+//! release budget via `VMExternRefActivationsTable::set_release_budget`. When
+//! a budget is configured, references that a GC cycle determined are no longer
+//! rooted by Wasm frames are moved into a pending-release queue instead of
+//! being dropped immediately, and at most `budget` of them are released per GC
+//! safepoint. The remainder is released incrementally by subsequent GCs or by
+//! explicit calls to `VMExternRefActivationsTable::release_pending`. Delaying
+//! the release only delays reference count decrements, so this never frees a
+//! reference that is still in use. The queue is bounded: if it holds more than
+//! `VMExternRefActivationsTable::MAX_PENDING_RELEASE` references (512 on
+//! 64-bit hosts, 1024 on 32-bit ones) after a sweep then the excess is
+//! released regardless of the budget, so a small (or zero) budget can't make
+//! the queue grow without bound.
+//!
 //! For more general information on deferred reference counting, see *An
 //! Examination of Deferred Reference Counting and Cycle Detection* by Quinane:
 //! <https://openresearch-repository.anu.edu.au/bitstream/1885/42030/2/hon-thesis.pdf>
@@ -413,6 +431,17 @@ impl VMExternRef {
         self.extern_data().ref_count.load(Ordering::SeqCst)
     }
 
+    /// Returns the size, in bytes, of the heap allocation holding this
+    /// reference's value and its reference count.
+    pub fn allocation_size(&self) -> usize {
+        unsafe {
+            let value = self.extern_data().value_ptr.as_ref();
+            let (layout, _) =
+                VMExternData::layout_for(mem::size_of_val(value), mem::align_of_val(value));
+            layout.size()
+        }
+    }
+
     #[inline]
     fn extern_data(&self) -> &VMExternData {
         unsafe { self.0.as_ref() }
@@ -525,6 +554,15 @@ pub struct VMExternRefActivationsTable {
     /// than create a new hash set every GC.
     precise_stack_roots: HashSet<VMExternRefWithTraits>,
 
+    /// References which a previous GC cycle found to be unrooted by Wasm
+    /// frames but which have not yet had their reference count released
+    /// because the release budget was exhausted.
+    pending_release: Vec<VMExternRef>,
+
+    /// The maximum number of references released per GC safepoint, or `None`
+    /// to release everything eagerly during the sweep.
+    release_budget: Option<usize>,
+
     /// A debug-only field for asserting that we are in a region of code where
     /// GC is okay to preform.
     #[cfg(debug_assertions)]
@@ -566,6 +604,11 @@ fn _assert_send_sync() {
 impl VMExternRefActivationsTable {
     const CHUNK_SIZE: usize = 4096 / mem::size_of::<usize>();
 
+    /// The maximum number of references left pending release after a GC,
+    /// whatever the release budget is: one chunk's worth, i.e. 512 on 64-bit
+    /// hosts and 1024 on 32-bit ones.
+    pub const MAX_PENDING_RELEASE: usize = Self::CHUNK_SIZE;
+
     /// Create a new `VMExternRefActivationsTable`.
     pub fn new() -> Self {
         // Start with an empty chunk in case this activations table isn't used.
@@ -585,6 +628,8 @@ impl VMExternRefActivationsTable {
             },
             over_approximated_stack_roots: HashSet::new(),
             precise_stack_roots: HashSet::new(),
+            pending_release: Vec::new(),
+            release_budget: None,
             #[cfg(debug_assertions)]
             gc_okay: true,
         }
@@ -727,8 +772,8 @@ impl VMExternRefActivationsTable {
             *self.alloc.next.get() = self.alloc.end;
         }
         for slot in self.alloc.chunk.iter().take(num_filled) {
-            unsafe {
-                *slot.get() = None;
+            if let Some(r) = unsafe { (*slot.get()).take() } {
+                self.pending_release.push(r);
             }
         }
         debug_assert!(
@@ -762,15 +807,82 @@ impl VMExternRefActivationsTable {
             &mut self.over_approximated_stack_roots,
         );
 
-        // And finally, the new `precise_stack_roots` should be cleared and
-        // remain empty until the next GC cycle.
-        //
+        // And finally, the new `precise_stack_roots` should be emptied and
+        // remain empty until the next GC cycle. Its references are queued for
+        // release rather than dropped here so that the release can be spread
+        // across multiple GCs when a budget is configured.
+        self.pending_release
+            .extend(self.precise_stack_roots.drain().map(|r| r.0));
+
         // Note that this may run arbitrary code as we run externref
         // destructors. Because of our `&mut` borrow above on this table,
         // though, we're guaranteed that nothing will touch this table.
-        self.precise_stack_roots.clear();
+        //
+        // Whatever the budget is, release enough references that no more than
+        // `MAX_PENDING_RELEASE` remain queued.
+        let excess = self
+            .pending_release
+            .len()
+            .saturating_sub(Self::MAX_PENDING_RELEASE);
+        let budget = self.release_budget.map(|b| cmp::max(b, excess));
+        let remaining = self.release_pending(budget);
+
+        log::trace!("end GC sweep ({remaining} references pending release)");
+    }
+
+    /// Configure the maximum number of references released per GC.
+    ///
+    /// With `None`, the default, every reference that a GC cycle finds to be
+    /// unrooted is released before the GC returns. With `Some(n)` at most `n`
+    /// references are released per GC and the rest are left in a queue to be
+    /// released by later GCs or by `release_pending`. The queue never holds
+    /// more than `MAX_PENDING_RELEASE` references after a GC.
+    pub fn set_release_budget(&mut self, budget: Option<usize>) {
+        self.release_budget = budget;
+    }
+
+    /// Returns the release budget configured with `set_release_budget`.
+    pub fn release_budget(&self) -> Option<usize> {
+        self.release_budget
+    }
+
+    /// Returns the number of references that are queued for release.
+    pub fn num_pending_release(&self) -> usize {
+        self.pending_release.len()
+    }
+
+    /// Returns the number of references currently held by this table: those
+    /// found on the stack by the last GC plus those inserted since.
+    pub fn num_roots(&self) -> usize {
+        self.over_approximated_stack_roots.len() + self.num_filled_in_bump_chunk()
+    }
+
+    /// Returns the total size, in bytes, of the values referenced by the roots
+    /// counted by `num_roots`, counting each distinct reference once.
+    pub fn roots_size_in_bytes(&self) -> usize {
+        let mut seen = HashSet::new();
+        let mut bytes = 0;
+        self.elements(|externref| {
+            if seen.insert(externref.as_raw()) {
+                bytes += externref.allocation_size();
+            }
+        });
+        bytes
+    }
+
+    /// Release up to `budget` references which a previous GC found to be
+    /// unrooted, or all of them if `budget` is `None`.
+    ///
+    /// This does not walk the stack and is therefore always safe to call.
+    /// Returns the number of references which are still pending release.
+    pub fn release_pending(&mut self, budget: Option<usize>) -> usize {
+        let len = self.pending_release.len();
+        let n = budget.map_or(len, |b| cmp::min(b, len));
+
+        // Release the oldest entries first.
+        self.pending_release.drain(..n);
 
-        log::trace!("end GC sweep");
+        self.pending_release.len()
     }
 
     /// Set whether it is okay to GC or not right now.
@@ -1010,6 +1122,7 @@ mod tests {
             num_owned_memories: 0,
             num_defined_globals: 0,
             num_escaped_funcs: 0,
+            coverage: false,
         });
         assert_eq!(
             offsets.vm_extern_data_ref_count(),
@@ -1037,6 +1150,7 @@ mod tests {
             num_owned_memories: 0,
             num_defined_globals: 0,
             num_escaped_funcs: 0,
+            coverage: false,
         });
         assert_eq!(
             offsets.vm_extern_ref_activation_table_next() as usize,
@@ -1064,6 +1178,7 @@ mod tests {
             num_owned_memories: 0,
             num_defined_globals: 0,
             num_escaped_funcs: 0,
+            coverage: false,
         });
         assert_eq!(
             offsets.vm_extern_ref_activation_table_end() as usize,
@@ -262,7 +262,8 @@ impl Instance {
             .cast()
     }
 
-    pub(crate) fn module(&self) -> &Arc<Module> {
+    /// Return a reference to the module this is an instance of.
+    pub fn module(&self) -> &Arc<Module> {
         self.runtime_info.module()
     }
 
@@ -1127,6 +1128,13 @@ impl Instance {
         *self.vmctx_plus_offset_mut(offsets.vmctx_builtin_functions()) =
             &VMBuiltinFunctionsArray::INIT;
 
+        // Initialize the coverage counters, shared by all instances of the
+        // module.
+        if offsets.coverage {
+            *self.vmctx_plus_offset_mut(offsets.vmctx_coverage_counters()) =
+                self.runtime_info.coverage_counters();
+        }
+
         // Initialize the imports
         debug_assert_eq!(imports.functions.len(), module.num_imported_funcs);
         ptr::copy_nonoverlapping(
@@ -50,11 +50,13 @@ pub use crate::instance::{
 pub use crate::memory::{
     DefaultMemoryCreator, Memory, RuntimeLinearMemory, RuntimeMemoryCreator, SharedMemory,
 };
+#[cfg(target_os = "macos")]
+pub use crate::mmap::set_jit_writable;
 pub use crate::mmap::Mmap;
 pub use crate::mmap_vec::MmapVec;
 pub use crate::mpk::MpkEnabled;
 pub use crate::store_box::*;
-pub use crate::table::{Table, TableElement};
+pub use crate::table::{Table, TableElement, TableElementType};
 pub use crate::traphandlers::*;
 pub use crate::vmcontext::{
     VMArrayCallFunction, VMArrayCallHostFuncContext, VMContext, VMFuncRef, VMFunctionBody,
@@ -121,6 +123,9 @@ pub unsafe trait Store {
     ///
     /// Note that this is not invoked if `memory_growing` returns an error.
     fn memory_grow_failed(&mut self, error: Error) -> Result<()>;
+    /// Callback invoked after a memory grow operation has succeeded, with the
+    /// memory's old and new size in bytes. Not invoked for grows by zero.
+    fn memory_grown(&mut self, old: usize, new: usize);
     /// Callback invoked to allow the store's resource limiter to reject a
     /// table grow operation.
     fn table_growing(
@@ -134,6 +139,9 @@ pub unsafe trait Store {
     ///
     /// Note that this is not invoked if `table_growing` returns an error.
     fn table_grow_failed(&mut self, error: Error) -> Result<()>;
+    /// Callback invoked after a table grow operation has succeeded, with the
+    /// table's old and new size in elements. Not invoked for grows by zero.
+    fn table_grown(&mut self, element: TableElementType, old: u32, new: u32);
     /// Callback invoked whenever fuel runs out by a wasm instance. If an error
     /// is returned that's raised as a trap. Otherwise wasm execution will
     /// continue as normal.
@@ -142,6 +150,23 @@ pub unsafe trait Store {
     /// number. Cannot fail; cooperative epoch-based yielding is
     /// completely semantically transparent. Returns the new deadline.
     fn new_epoch(&mut self) -> Result<u64, Error>;
+    /// Callback invoked before a linear memory load or store when compiled
+    /// code traces memory accesses, with the index of the memory, the address
+    /// and size of the access and the offset of the instruction in the
+    /// original wasm module. If an error is returned that's raised as a trap.
+    fn memory_accessed(
+        &mut self,
+        memory: u32,
+        is_store: bool,
+        addr: u64,
+        num_bytes: u32,
+        wasm_offset: u32,
+    ) -> Result<(), Error>;
+    /// Callback invoked on entry to a basic block when compiled code traces
+    /// block entries, with the index of the function and the offset of the
+    /// block's first instruction in the original wasm module. If an error is
+    /// returned that's raised as a trap.
+    fn block_entered(&mut self, func_index: u32, wasm_offset: u32) -> Result<(), Error>;
 
     /// Metadata required for resources for the component model.
     #[cfg(feature = "component-model")]
@@ -211,6 +236,10 @@ pub trait ModuleRuntimeInfo: Send + Sync + 'static {
 
     /// Offset information for the current host.
     fn offsets(&self) -> &VMOffsets<HostPtr>;
+
+    /// Returns a pointer to the module's code coverage counters, or null if
+    /// the module wasn't compiled with coverage instrumentation.
+    fn coverage_counters(&self) -> *mut u64;
 }
 
 /// Returns the host OS page size, in bytes.
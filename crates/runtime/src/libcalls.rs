@@ -55,7 +55,7 @@
 //! ```
 
 use crate::externref::VMExternRef;
-use crate::table::{Table, TableElementType};
+use crate::table::{Table, TableElement, TableElementType};
 use crate::vmcontext::VMFuncRef;
 use crate::{Instance, TrapReason};
 #[cfg(feature = "wmemcheck")]
@@ -440,6 +440,50 @@ unsafe fn externref_global_set(instance: &mut Instance, index: u32, externref: *
     drop(old);
 }
 
+// Perform a Wasm `table.get` for `externref` tables.
+unsafe fn table_get_externref(
+    instance: &mut Instance,
+    table_index: u32,
+    index: u32,
+) -> Result<*mut u8, Trap> {
+    let table_index = TableIndex::from_u32(table_index);
+    let limits = *instance.runtime_limits();
+    let table = &*instance.get_table(table_index);
+    match table.get(index) {
+        Some(TableElement::ExternRef(None)) => Ok(ptr::null_mut()),
+        Some(TableElement::ExternRef(Some(externref))) => {
+            // Like `global.get`, root the reference in the activations table
+            // so that it stays alive while it is on the Wasm stack.
+            let raw = externref.as_raw();
+            let (activations_table, module_info_lookup) =
+                (*instance.store()).externref_activations_table();
+            activations_table.insert_with_gc(limits, externref, module_info_lookup);
+            Ok(raw)
+        }
+        Some(_) => unreachable!("not an `externref` table"),
+        None => Err(Trap::TableOutOfBounds),
+    }
+}
+
+// Perform a Wasm `table.set` for `externref` tables.
+unsafe fn table_set_externref(
+    instance: &mut Instance,
+    table_index: u32,
+    index: u32,
+    externref: *mut u8,
+) -> Result<(), Trap> {
+    let externref = if externref.is_null() {
+        None
+    } else {
+        Some(VMExternRef::clone_from_raw(externref))
+    };
+    let table_index = TableIndex::from_u32(table_index);
+    let table = &mut *instance.get_table(table_index);
+    table
+        .set(index, TableElement::ExternRef(externref))
+        .map_err(|()| Trap::TableOutOfBounds)
+}
+
 // Implementation of `memory.atomic.notify` for locally defined memories.
 fn memory_atomic_notify(
     instance: &mut Instance,
@@ -636,6 +680,24 @@ cfg_if! {
     }
 }
 
+// Hook for reporting a load or store to the store when tracing memory accesses.
+unsafe fn memory_trace(
+    instance: &mut Instance,
+    memory: u32,
+    is_store: u32,
+    addr: u64,
+    num_bytes: u32,
+    offset: u32,
+) -> Result<()> {
+    (*instance.store()).memory_accessed(memory, is_store != 0, addr, num_bytes, offset)
+}
+
+// Hook for reporting the entry into a basic block to the store when tracing
+// block entries.
+unsafe fn block_trace(instance: &mut Instance, func: u32, offset: u32) -> Result<()> {
+    (*instance.store()).block_entered(func, offset)
+}
+
 /// This module contains functions which are used for resolving relocations at
 /// runtime if necessary.
 ///
@@ -643,7 +705,8 @@ cfg_if! {
 /// they're used for is on x86_64 when SIMD is disabled and then SSE features
 /// are further disabled. In these configurations Cranelift isn't allowed to use
 /// native CPU instructions so it falls back to libcalls and we rely on the Rust
-/// standard library generally for implementing these.
+/// standard library generally for implementing these. On aarch64 this also
+/// provides the feature byte checked by Cranelift's `outline_atomics`.
 #[allow(missing_docs)]
 pub mod relocs {
     pub extern "C" fn floorf32(f: f32) -> f32 {
@@ -772,4 +835,16 @@ pub mod relocs {
             .reg
         }
     }
+
+    /// Returns the address of a byte which is non-zero if the aarch64 Large
+    /// System Extensions (FEAT_LSE) atomics are available, for the atomic
+    /// sequences compiled with Cranelift's `outline_atomics` setting to check.
+    ///
+    /// This plays the part of `__aarch64_have_lse_atomics` from compiler-rt
+    /// and libgcc, which isn't guaranteed to be linked into the host.
+    #[cfg(target_arch = "aarch64")]
+    pub fn aarch64_have_lse_atomics() -> *const u8 {
+        static HAVE_LSE_ATOMICS: std::sync::OnceLock<u8> = std::sync::OnceLock::new();
+        HAVE_LSE_ATOMICS.get_or_init(|| std::arch::is_aarch64_feature_detected!("lse").into())
+    }
 }
@@ -123,7 +123,12 @@ pub trait RuntimeLinearMemory: Send + Sync {
         }
 
         match self.grow_to(new_byte_size) {
-            Ok(_) => Ok(Some((old_byte_size, new_byte_size))),
+            Ok(_) => {
+                if let Some(store) = store {
+                    store.memory_grown(old_byte_size, new_byte_size);
+                }
+                Ok(Some((old_byte_size, new_byte_size)))
+            }
             Err(e) => {
                 // FIXME: shared memories may not have an associated store to
                 // report the growth failure to but the error should not be
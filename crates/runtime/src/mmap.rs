@@ -37,6 +37,25 @@ impl Mmap {
         Self::accessible_reserved(rounded_size, rounded_size)
     }
 
+    /// Create a new `Mmap` pointing to at least `size` bytes of page-aligned
+    /// memory mapped with `MAP_JIT`, which is the only memory that may be made
+    /// executable under macOS's hardened runtime.
+    ///
+    /// The memory is mapped readable, writable and executable. On AArch64
+    /// whether the current thread may write or execute it is controlled with
+    /// [`set_jit_writable`] until its protections are narrowed with
+    /// [`make_executable`](Mmap::make_executable).
+    #[cfg(target_os = "macos")]
+    pub fn jit_with_at_least(size: usize) -> Result<Self> {
+        let page_size = crate::page_size();
+        let rounded_size = (size + (page_size - 1)) & !(page_size - 1);
+        Ok(Mmap {
+            sys: sys::Mmap::new_jit(rounded_size)
+                .context(format!("mmap failed to allocate {rounded_size:#x} bytes"))?,
+            file: None,
+        })
+    }
+
     /// Creates a new `Mmap` by opening the file located at `path` and mapping
     /// it into memory.
     ///
@@ -220,6 +239,22 @@ impl Mmap {
     }
 }
 
+/// Enables or disables writes to memory mapped with `MAP_JIT` for the current
+/// thread.
+///
+/// On AArch64 macOS such memory is never writable and executable at the same
+/// time: while writes are enabled the current thread can't execute it, but
+/// other threads are unaffected. Elsewhere this does nothing.
+#[cfg(target_os = "macos")]
+pub fn set_jit_writable(writable: bool) {
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        libc::pthread_jit_write_protect_np(!writable as libc::c_int);
+    }
+    #[cfg(not(target_arch = "aarch64"))]
+    let _ = writable;
+}
+
 fn _assert() {
     fn _assert_send_sync<T: Send + Sync>() {}
     _assert_send_sync::<Mmap>();
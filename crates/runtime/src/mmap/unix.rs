@@ -32,6 +32,26 @@ impl Mmap {
         Ok(Mmap { memory })
     }
 
+    #[cfg(target_os = "macos")]
+    pub fn new_jit(size: usize) -> Result<Self> {
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                size,
+                libc::PROT_READ | libc::PROT_WRITE | libc::PROT_EXEC,
+                libc::MAP_PRIVATE | libc::MAP_ANON | libc::MAP_JIT,
+                -1,
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        let memory = std::ptr::slice_from_raw_parts_mut(ptr.cast(), size);
+        let memory = SendSyncPtr::new(NonNull::new(memory).unwrap());
+        Ok(Mmap { memory })
+    }
+
     pub fn reserve(size: usize) -> Result<Self> {
         let ptr = unsafe {
             rustix::mm::mmap_anonymous(
@@ -90,6 +90,17 @@ impl MmapVec {
         return ret;
     }
 
+    /// Creates a new `MmapVec` backed by memory mapped with `MAP_JIT` from
+    /// the contents of an existing `slice`, see [`Mmap::jit_with_at_least`].
+    #[cfg(target_os = "macos")]
+    pub fn jit_from_slice(slice: &[u8]) -> Result<MmapVec> {
+        let mut result = MmapVec::new(Mmap::jit_with_at_least(slice.len())?, slice.len());
+        crate::mmap::set_jit_writable(true);
+        result.copy_from_slice(slice);
+        crate::mmap::set_jit_writable(false);
+        Ok(result)
+    }
+
     /// Makes the specified `range` within this `mmap` to be read/execute.
     pub unsafe fn make_executable(
         &self,
@@ -190,4 +201,16 @@ mod tests {
         assert_eq!(&mmap.split_off(0)[..], &vec.split_off(0)[..]);
         assert_eq!(&mmap[..], &vec[..]);
     }
+
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn jit_from_slice() {
+        let jit = MmapVec::jit_from_slice(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(&jit[..], &[1, 2, 3, 4]);
+        assert!(jit.original_file().is_none());
+        unsafe {
+            jit.make_executable(0..jit.len(), false).unwrap();
+        }
+        assert_eq!(&jit[..], &[1, 2, 3, 4]);
+    }
 }
@@ -29,9 +29,12 @@ pub enum TableElement {
     UninitFunc,
 }
 
+/// The type of the elements stored in a table.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum TableElementType {
+    /// Elements are `funcref`s.
     Func,
+    /// Elements are `externref`s.
     Extern,
 }
 
@@ -401,6 +404,10 @@ impl Table {
         self.fill(old_size, init_value, delta)
             .expect("table should not be out of bounds");
 
+        if delta != 0 {
+            store.table_grown(self.element_type(), old_size, new_size);
+        }
+
         Ok(Some(old_size))
     }
 
@@ -195,13 +195,24 @@ pub unsafe fn raise_lib_trap(trap: wasmtime_environ::Trap) -> ! {
 /// Carries a Rust panic across wasm code and resumes the panic on the other
 /// side.
 ///
+/// If the enclosing `catch_traps` was asked to catch panics then the panic is
+/// instead returned from it as a trap with [`TrapReason::Panic`].
+///
 /// # Safety
 ///
 /// Only safe to call when wasm code is on the stack, aka `catch_traps` must
 /// have been previously called. Additionally no Rust destructors can be on the
 /// stack. They will be skipped and not executed.
 pub unsafe fn resume_panic(payload: Box<dyn Any + Send>) -> ! {
-    tls::with(|info| info.unwrap().unwind_with(UnwindReason::Panic(payload)))
+    tls::with(|info| {
+        let info = info.unwrap();
+        let reason = if info.catch_panics {
+            UnwindReason::Trap(TrapReason::Panic(payload))
+        } else {
+            UnwindReason::Panic(payload)
+        };
+        info.unwind_with(reason)
+    })
 }
 
 /// Stores trace message with backtrace.
@@ -248,6 +259,10 @@ pub enum TrapReason {
 
     /// A trap raised from a wasm libcall
     Wasm(wasmtime_environ::Trap),
+
+    /// A panic raised by host code called from wasm, caught because
+    /// `catch_traps` was asked to catch panics.
+    Panic(Box<dyn Any + Send>),
 }
 
 impl TrapReason {
@@ -288,11 +303,15 @@ impl From<wasmtime_environ::Trap> for TrapReason {
 /// Catches any wasm traps that happen within the execution of `closure`,
 /// returning them as a `Result`.
 ///
+/// Panics raised with [`resume_panic`] are resumed once wasm has been unwound,
+/// unless `catch_panics` is set, in which case they are returned as a trap.
+///
 /// Highly unsafe since `closure` won't have any dtors run.
 pub unsafe fn catch_traps<'a, F>(
     signal_handler: Option<*const SignalHandler<'static>>,
     capture_backtrace: bool,
     capture_coredump: bool,
+    catch_panics: bool,
     caller: *mut VMContext,
     mut closure: F,
 ) -> Result<(), Box<Trap>>
@@ -301,15 +320,21 @@ where
 {
     let limits = Instance::from_vmctx(caller, |i| i.runtime_limits());
 
-    let result = CallThreadState::new(signal_handler, capture_backtrace, capture_coredump, *limits)
-        .with(|cx| {
-            wasmtime_setjmp(
-                cx.jmp_buf.as_ptr(),
-                call_closure::<F>,
-                &mut closure as *mut F as *mut u8,
-                caller,
-            )
-        });
+    let result = CallThreadState::new(
+        signal_handler,
+        capture_backtrace,
+        capture_coredump,
+        catch_panics,
+        *limits,
+    )
+    .with(|cx| {
+        wasmtime_setjmp(
+            cx.jmp_buf.as_ptr(),
+            call_closure::<F>,
+            &mut closure as *mut F as *mut u8,
+            caller,
+        )
+    });
 
     return match result {
         Ok(x) => Ok(x),
@@ -343,6 +368,7 @@ mod call_thread_state {
         pub(super) signal_handler: Option<*const SignalHandler<'static>>,
         pub(super) capture_backtrace: bool,
         pub(super) capture_coredump: bool,
+        pub(super) catch_panics: bool,
 
         pub(crate) limits: *const VMRuntimeLimits,
 
@@ -377,6 +403,7 @@ mod call_thread_state {
             signal_handler: Option<*const SignalHandler<'static>>,
             capture_backtrace: bool,
             capture_coredump: bool,
+            catch_panics: bool,
             limits: *const VMRuntimeLimits,
         ) -> CallThreadState {
             CallThreadState {
@@ -385,6 +412,7 @@ mod call_thread_state {
                 signal_handler,
                 capture_backtrace,
                 capture_coredump,
+                catch_panics,
                 limits,
                 prev: Cell::new(ptr::null()),
                 old_last_wasm_exit_fp: Cell::new(unsafe { *(*limits).last_wasm_exit_fp.get() }),
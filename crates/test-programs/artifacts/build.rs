@@ -70,7 +70,9 @@ fn build_and_generate_tests() {
         // Bucket, based on the name of the test, into a "kind" which generates
         // a `foreach_*` macro below.
         let kind = match target.as_str() {
+            s if s.starts_with("blobstore_") => "blobstore",
             s if s.starts_with("http_") => "http",
+            s if s.starts_with("messaging_") => "messaging",
             s if s.starts_with("preview1_") => "preview1",
             s if s.starts_with("preview2_") => "preview2",
             s if s.starts_with("cli_") => "cli",
@@ -0,0 +1,70 @@
+use test_programs::blobstore::wasi::blobstore::{blobstore, types};
+
+fn main() {
+    let photos = blobstore::create_container("photos").unwrap();
+    assert_eq!(photos.name().unwrap(), "photos");
+    assert_eq!(photos.info().unwrap().name, "photos");
+    assert!(blobstore::container_exists("photos").unwrap());
+    assert!(blobstore::create_container("photos").is_err());
+    assert!(blobstore::get_container("missing").is_err());
+
+    // Write an object through an output stream.
+    let value = types::OutgoingValue::new_outgoing_value();
+    let body = value.outgoing_value_write_body().unwrap();
+    assert!(value.outgoing_value_write_body().is_err());
+    body.blocking_write_and_flush(b"meow meow").unwrap();
+    drop(body);
+    photos.write_data("cat.png", &value).unwrap();
+    drop(value);
+    assert_eq!(photos.object_info("cat.png").unwrap().size, 9);
+
+    // Read it back whole, and a range of it through an input stream.
+    let data = photos.get_data("cat.png", 0, u64::MAX).unwrap();
+    assert_eq!(data.size(), 9);
+    assert_eq!(data.incoming_value_consume_sync().unwrap(), b"meow meow");
+    let data = photos.get_data("cat.png", 5, 6).unwrap();
+    let stream = data.incoming_value_consume_async().unwrap();
+    assert_eq!(stream.blocking_read(100).unwrap(), b"me");
+    drop(stream);
+    drop(data);
+
+    // Copy it to another container through a handle from `get-container`.
+    drop(blobstore::create_container("backup").unwrap());
+    let src = types::ObjectId {
+        container: "photos".to_string(),
+        object: "cat.png".to_string(),
+    };
+    let dest = types::ObjectId {
+        container: "backup".to_string(),
+        object: "kitten.png".to_string(),
+    };
+    blobstore::copy_object(&src, &dest).unwrap();
+    let backup = blobstore::get_container("backup").unwrap();
+    assert!(backup.has_object("kitten.png").unwrap());
+
+    photos
+        .write_data("dog.png", &types::OutgoingValue::new_outgoing_value())
+        .unwrap();
+    let names = photos.list_objects().unwrap();
+    assert_eq!(names.skip_stream_object_names(1).unwrap(), (1, false));
+    assert_eq!(
+        names.read_stream_object_names(10).unwrap(),
+        (vec!["dog.png".to_string()], true)
+    );
+    drop(names);
+
+    photos.delete_objects(&["cat.png".to_string()]).unwrap();
+    assert!(!photos.has_object("cat.png").unwrap());
+    assert!(photos.object_info("cat.png").is_err());
+    photos.clear().unwrap();
+    let (names, end) = photos
+        .list_objects()
+        .unwrap()
+        .read_stream_object_names(10)
+        .unwrap();
+    assert!(names.is_empty() && end);
+
+    blobstore::delete_container("photos").unwrap();
+    assert!(!blobstore::container_exists("photos").unwrap());
+    assert!(photos.info().is_err());
+}
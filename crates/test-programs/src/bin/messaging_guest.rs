@@ -0,0 +1,74 @@
+wit_bindgen::generate!({
+    path: "../wasi-messaging/wit",
+    world: "wasi:messaging/messaging",
+    exports: {
+        "wasi:messaging/messaging-guest": T,
+    }
+});
+
+use exports::wasi::messaging::messaging_guest::Guest;
+use wasi::messaging::consumer;
+use wasi::messaging::producer;
+use wasi::messaging::types::{Client, Error, FormatSpec, GuestConfiguration, Message};
+
+struct T;
+
+// The client keeps the subscriptions made through `consumer` alive between
+// calls to the handler.
+static mut CLIENT: Option<Client> = None;
+
+fn client() -> Result<&'static Client, Error> {
+    unsafe {
+        if CLIENT.is_none() {
+            CLIENT = Some(Client::connect("default")?);
+        }
+        Ok(CLIENT.as_ref().unwrap())
+    }
+}
+
+impl Guest for T {
+    fn configure() -> Result<GuestConfiguration, Error> {
+        Ok(GuestConfiguration {
+            channels: vec!["orders".to_string()],
+            extensions: None,
+        })
+    }
+
+    fn handler(ms: Vec<Message>) -> Result<(), Error> {
+        let client = client()?;
+        for m in ms {
+            match m.data.as_slice() {
+                // Start receiving from `inbox`; nothing was published to it
+                // yet.
+                b"subscribe" => {
+                    assert!(consumer::subscribe_try_receive(client, "inbox", 0)?.is_none());
+                }
+                // Forward whatever arrived on `inbox` since subscribing.
+                b"poll" => {
+                    let received = consumer::subscribe_try_receive(client, "inbox", 0)?;
+                    producer::send(client, "receipts", &received.unwrap_or_default())?;
+                }
+                b"reconfigure" => {
+                    consumer::update_guest_configuration(&GuestConfiguration {
+                        channels: vec!["orders".to_string(), "more".to_string()],
+                        extensions: None,
+                    })?;
+                }
+                data => {
+                    let receipt = Message {
+                        data: data.to_ascii_uppercase(),
+                        format: FormatSpec::Raw,
+                        metadata: m.metadata.clone(),
+                    };
+                    producer::send(client, "receipts", &[receipt])?;
+                }
+            }
+            consumer::complete_message(&m)?;
+        }
+        Ok(())
+    }
+}
+
+// Technically this should not be here for a reactor, but given the current
+// framework for tests it's required since this file is built as a `bin`
+fn main() {}
@@ -0,0 +1,4 @@
+wit_bindgen::generate!({
+    path: "../wasi-blobstore/wit",
+    world: "wasi:blobstore/imports",
+});
@@ -1,3 +1,4 @@
+pub mod blobstore;
 pub mod http;
 pub mod preview1;
 pub mod sockets;
@@ -0,0 +1,245 @@
+//! A [`BlobBackend`] which stores containers as directories of a local path.
+
+use super::{BackendError, BlobBackend};
+use crate::wit::types::{ContainerMetadata, ObjectId, ObjectMetadata};
+use anyhow::Context;
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+/// Stores each container as a directory beneath a root path and each object as
+/// a file within its container's directory.
+///
+/// Container and object names must be valid single path components: empty
+/// names, `.`, `..` and names containing path separators are rejected.
+pub struct FilesystemBackend {
+    root: PathBuf,
+}
+
+impl FilesystemBackend {
+    /// Creates a backend storing containers beneath `root`, creating it if it
+    /// does not yet exist.
+    pub fn new(root: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let root = root.into();
+        fs::create_dir_all(&root)
+            .with_context(|| format!("failed to create blobstore root {}", root.display()))?;
+        Ok(Self { root })
+    }
+
+    fn container_path(&self, name: &str) -> Result<PathBuf, BackendError> {
+        validate_name(name)?;
+        Ok(self.root.join(name))
+    }
+
+    fn existing_container_path(&self, name: &str) -> Result<PathBuf, BackendError> {
+        let path = self.container_path(name)?;
+        if !path.is_dir() {
+            return Err(BackendError::NotFound(format!("container `{name}`")));
+        }
+        Ok(path)
+    }
+
+    fn object_path(&self, container: &str, name: &str) -> Result<PathBuf, BackendError> {
+        let path = self.existing_container_path(container)?;
+        validate_name(name)?;
+        Ok(path.join(name))
+    }
+}
+
+impl BlobBackend for FilesystemBackend {
+    fn create_container(&mut self, name: &str) -> Result<ContainerMetadata, BackendError> {
+        let path = self.container_path(name)?;
+        match fs::create_dir(&path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                return Err(BackendError::AlreadyExists(format!("container `{name}`")));
+            }
+            Err(e) => return Err(anyhow::Error::from(e).into()),
+        }
+        self.container_info(name)
+    }
+
+    fn delete_container(&mut self, name: &str) -> Result<(), BackendError> {
+        let path = self.existing_container_path(name)?;
+        fs::remove_dir_all(path).map_err(|e| anyhow::Error::from(e).into())
+    }
+
+    fn container_info(&self, name: &str) -> Result<ContainerMetadata, BackendError> {
+        let path = self.existing_container_path(name)?;
+        let metadata = fs::metadata(path).map_err(anyhow::Error::from)?;
+        Ok(ContainerMetadata {
+            name: name.to_string(),
+            created_at: created_at(&metadata),
+        })
+    }
+
+    fn read_object(
+        &self,
+        container: &str,
+        name: &str,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<u8>, BackendError> {
+        let path = self.object_path(container, name)?;
+        let mut file = File::open(path).map_err(|e| not_found(e, container, name))?;
+        let mut data = Vec::new();
+        file.seek(SeekFrom::Start(start))
+            .and_then(|_| file.take(end.saturating_sub(start)).read_to_end(&mut data))
+            .map_err(anyhow::Error::from)?;
+        Ok(data)
+    }
+
+    fn write_object(
+        &mut self,
+        container: &str,
+        name: &str,
+        data: &[u8],
+    ) -> Result<(), BackendError> {
+        let path = self.object_path(container, name)?;
+        fs::write(path, data).map_err(|e| anyhow::Error::from(e).into())
+    }
+
+    fn list_objects(&self, container: &str) -> Result<Vec<String>, BackendError> {
+        let path = self.existing_container_path(container)?;
+        let mut names = Vec::new();
+        for entry in fs::read_dir(path).map_err(anyhow::Error::from)? {
+            let entry = entry.map_err(anyhow::Error::from)?;
+            if !entry.file_type().map_err(anyhow::Error::from)?.is_file() {
+                continue;
+            }
+            // Objects are only ever created with valid UTF-8 names, so skip
+            // anything else which was placed in the directory externally.
+            if let Ok(name) = entry.file_name().into_string() {
+                names.push(name);
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    fn delete_object(&mut self, container: &str, name: &str) -> Result<(), BackendError> {
+        let path = self.object_path(container, name)?;
+        match fs::remove_file(path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(anyhow::Error::from(e).into()),
+        }
+    }
+
+    fn object_info(&self, container: &str, name: &str) -> Result<ObjectMetadata, BackendError> {
+        let path = self.object_path(container, name)?;
+        let metadata = fs::metadata(path).map_err(|e| not_found(e, container, name))?;
+        if !metadata.is_file() {
+            return Err(BackendError::NotFound(format!(
+                "object `{name}` in container `{container}`"
+            )));
+        }
+        Ok(ObjectMetadata {
+            name: name.to_string(),
+            container: container.to_string(),
+            created_at: created_at(&metadata),
+            size: metadata.len(),
+        })
+    }
+
+    fn move_object(&mut self, src: &ObjectId, dest: &ObjectId) -> Result<(), BackendError> {
+        let from = self.object_path(&src.container, &src.object)?;
+        let to = self.object_path(&dest.container, &dest.object)?;
+        fs::rename(from, to).map_err(|e| not_found(e, &src.container, &src.object))
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), BackendError> {
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\', '\0']) {
+        return Err(BackendError::InvalidName(name.to_string()));
+    }
+    Ok(())
+}
+
+fn not_found(e: io::Error, container: &str, name: &str) -> BackendError {
+    if e.kind() == io::ErrorKind::NotFound {
+        BackendError::NotFound(format!("object `{name}` in container `{container}`"))
+    } else {
+        anyhow::Error::from(e).into()
+    }
+}
+
+/// Returns the creation time of a file in seconds since the Unix epoch,
+/// falling back to its modification time on platforms which do not record
+/// creation times.
+fn created_at(metadata: &fs::Metadata) -> u64 {
+    metadata
+        .created()
+        .or_else(|_| metadata.modified())
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |duration| duration.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objects_round_trip() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut backend = FilesystemBackend::new(dir.path())?;
+
+        backend.create_container("photos")?;
+        assert!(backend.container_exists("photos")?);
+        assert!(matches!(
+            backend.create_container("photos"),
+            Err(BackendError::AlreadyExists(_))
+        ));
+
+        backend.write_object("photos", "cat.png", b"meow meow")?;
+        assert_eq!(
+            backend.read_object("photos", "cat.png", 0, u64::MAX)?,
+            b"meow meow"
+        );
+        assert_eq!(backend.read_object("photos", "cat.png", 5, 7)?, b"me");
+        assert_eq!(backend.object_info("photos", "cat.png")?.size, 9);
+
+        let src = ObjectId {
+            container: "photos".to_string(),
+            object: "cat.png".to_string(),
+        };
+        let dest = ObjectId {
+            container: "photos".to_string(),
+            object: "kitten.png".to_string(),
+        };
+        backend.copy_object(&src, &dest)?;
+        assert_eq!(backend.list_objects("photos")?, ["cat.png", "kitten.png"]);
+        backend.delete_object("photos", "cat.png")?;
+        assert!(!backend.has_object("photos", "cat.png")?);
+
+        backend.clear("photos")?;
+        assert!(backend.list_objects("photos")?.is_empty());
+        backend.delete_container("photos")?;
+        assert!(!backend.container_exists("photos")?);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_names() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut backend = FilesystemBackend::new(dir.path())?;
+        for name in ["", ".", "..", "a/b"] {
+            assert!(matches!(
+                backend.create_container(name),
+                Err(BackendError::InvalidName(_))
+            ));
+        }
+        backend.create_container("c")?;
+        assert!(matches!(
+            backend.write_object("c", "../escape", b""),
+            Err(BackendError::InvalidName(_))
+        ));
+        assert!(matches!(
+            backend.read_object("missing", "x", 0, 1),
+            Err(BackendError::NotFound(_))
+        ));
+        Ok(())
+    }
+}
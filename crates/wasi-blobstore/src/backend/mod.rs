@@ -0,0 +1,109 @@
+//! Define the Rust interface a backend must implement in order to store the
+//! blobs of a [`WasiBlobstoreCtx`](crate::WasiBlobstoreCtx).
+
+mod filesystem;
+mod s3;
+
+pub use self::filesystem::FilesystemBackend;
+pub use self::s3::{S3Backend, S3Config};
+use crate::wit::types::{ContainerMetadata, ObjectId, ObjectMetadata};
+use thiserror::Error;
+
+/// Storage for containers of blobs.
+///
+/// Only the primitive operations must be implemented; composite operations
+/// such as [`BlobBackend::copy_object`] have default implementations in terms
+/// of them which backends may override with native equivalents, for example a
+/// server-side copy in an S3-compatible object store.
+pub trait BlobBackend: Send + Sync {
+    /// Creates a new, empty container.
+    fn create_container(&mut self, name: &str) -> Result<ContainerMetadata, BackendError>;
+
+    /// Deletes a container and all objects within it.
+    fn delete_container(&mut self, name: &str) -> Result<(), BackendError>;
+
+    /// Returns information about a container.
+    fn container_info(&self, name: &str) -> Result<ContainerMetadata, BackendError>;
+
+    /// Returns the bytes of an object in the range `[start, end)`, clamped to
+    /// the size of the object.
+    fn read_object(
+        &self,
+        container: &str,
+        name: &str,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<u8>, BackendError>;
+
+    /// Creates or replaces an object.
+    fn write_object(
+        &mut self,
+        container: &str,
+        name: &str,
+        data: &[u8],
+    ) -> Result<(), BackendError>;
+
+    /// Returns the names of all objects in a container.
+    fn list_objects(&self, container: &str) -> Result<Vec<String>, BackendError>;
+
+    /// Deletes an object, succeeding if it does not exist.
+    fn delete_object(&mut self, container: &str, name: &str) -> Result<(), BackendError>;
+
+    /// Returns information about an object.
+    fn object_info(&self, container: &str, name: &str) -> Result<ObjectMetadata, BackendError>;
+
+    /// Returns whether a container exists.
+    fn container_exists(&self, name: &str) -> Result<bool, BackendError> {
+        match self.container_info(name) {
+            Ok(_) => Ok(true),
+            Err(BackendError::NotFound(_)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns whether an object exists.
+    fn has_object(&self, container: &str, name: &str) -> Result<bool, BackendError> {
+        match self.object_info(container, name) {
+            Ok(_) => Ok(true),
+            Err(BackendError::NotFound(_)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Copies an object, overwriting the destination if it exists.
+    fn copy_object(&mut self, src: &ObjectId, dest: &ObjectId) -> Result<(), BackendError> {
+        let data = self.read_object(&src.container, &src.object, 0, u64::MAX)?;
+        self.write_object(&dest.container, &dest.object, &data)
+    }
+
+    /// Moves an object, overwriting the destination if it exists.
+    fn move_object(&mut self, src: &ObjectId, dest: &ObjectId) -> Result<(), BackendError> {
+        if src.container == dest.container && src.object == dest.object {
+            return Ok(());
+        }
+        self.copy_object(src, dest)?;
+        self.delete_object(&src.container, &src.object)
+    }
+
+    /// Deletes all objects in a container.
+    fn clear(&mut self, container: &str) -> Result<(), BackendError> {
+        for name in self.list_objects(container)? {
+            self.delete_object(container, &name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Errors returned by a backend; [BackendError::Backend] is a catch-all for
+/// failures interacting with the underlying storage.
+#[derive(Debug, Error)]
+pub enum BackendError {
+    #[error("{0} not found")]
+    NotFound(String),
+    #[error("{0} already exists")]
+    AlreadyExists(String),
+    #[error("invalid name `{0}`")]
+    InvalidName(String),
+    #[error("Failed while accessing backend")]
+    Backend(#[from] anyhow::Error),
+}
@@ -0,0 +1,68 @@
+//! Implements the host state for the `wasi-blobstore` API: [WasiBlobstoreCtx],
+//! and the host representations of its resources.
+
+use crate::backend::BlobBackend;
+use bytes::Bytes;
+use std::collections::VecDeque;
+use wasmtime_wasi::preview2::{pipe::MemoryOutputPipe, Table};
+
+/// Capture the state necessary for calling into a blobstore backend.
+pub struct WasiBlobstoreCtx {
+    pub(crate) backend: Box<dyn BlobBackend>,
+    pub(crate) max_value_size: usize,
+}
+
+impl WasiBlobstoreCtx {
+    /// The default limit on the size of a value written by a guest: 64 MiB.
+    pub const DEFAULT_MAX_VALUE_SIZE: usize = 64 << 20;
+
+    /// Make a new context which stores blobs in `backend`.
+    pub fn new(backend: impl BlobBackend + 'static) -> Self {
+        Self {
+            backend: Box::new(backend),
+            max_value_size: Self::DEFAULT_MAX_VALUE_SIZE,
+        }
+    }
+
+    /// Returns the backend that this context stores blobs in.
+    pub fn backend(&mut self) -> &mut dyn BlobBackend {
+        &mut *self.backend
+    }
+
+    /// Limits the number of bytes a guest may write to the body of an
+    /// `outgoing-value`; writes beyond this limit trap.
+    pub fn set_max_value_size(&mut self, bytes: usize) {
+        self.max_value_size = bytes;
+    }
+}
+
+/// Projects the blobstore state and resource table out of a store's data.
+pub trait WasiBlobstoreView: Send {
+    fn ctx(&mut self) -> &mut WasiBlobstoreCtx;
+    fn table(&mut self) -> &mut Table;
+}
+
+/// The host representation of a `container` resource: a handle to a container
+/// of the backend, which is not checked to still exist until it is used.
+pub struct HostContainer {
+    pub(crate) name: String,
+}
+
+/// The host representation of an `incoming-value` resource, holding the bytes
+/// read from an object.
+pub struct HostIncomingValue {
+    pub(crate) data: Bytes,
+}
+
+/// The host representation of an `outgoing-value` resource, buffering the
+/// bytes a guest writes to its body until they are stored with `write-data`.
+pub struct HostOutgoingValue {
+    pub(crate) body: MemoryOutputPipe,
+    pub(crate) body_taken: bool,
+}
+
+/// The host representation of a `stream-object-names` resource, holding the
+/// names of the objects which have not yet been read.
+pub struct HostStreamObjectNames {
+    pub(crate) names: VecDeque<String>,
+}
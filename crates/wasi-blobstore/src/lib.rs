@@ -0,0 +1,19 @@
+//! Implements the [wasi-blobstore] API for Wasmtime components.
+//!
+//! Guests access blobs stored in named containers through the
+//! `wasi:blobstore/blobstore` and `wasi:blobstore/container` interfaces, reading
+//! and writing values through `wasi:io` streams. The host state, a
+//! [`WasiBlobstoreCtx`], delegates all storage to a [`backend::BlobBackend`]:
+//! [`backend::FilesystemBackend`] stores containers as directories on the local
+//! filesystem, [`backend::S3Backend`] stores them as buckets of an
+//! S3-compatible object store, and embedders can implement the trait for other
+//! stores.
+//!
+//! [wasi-blobstore]: https://github.com/WebAssembly/wasi-blobstore
+
+pub mod backend;
+mod ctx;
+pub mod wit;
+
+pub use ctx::{WasiBlobstoreCtx, WasiBlobstoreView};
+pub use wit::add_to_linker;
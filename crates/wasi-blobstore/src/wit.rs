@@ -0,0 +1,351 @@
+//! Implements the `wasi-blobstore` API for the WIT ("preview2") ABI.
+//!
+//! The WIT glue code is generated into a `gen::*` namespace and wired up to a
+//! [`WasiBlobstoreView`], whose [`WasiBlobstoreCtx`](crate::WasiBlobstoreCtx)
+//! delegates all storage to its backend.
+
+use crate::backend::BackendError;
+use crate::ctx::{
+    HostContainer, HostIncomingValue, HostOutgoingValue, HostStreamObjectNames, WasiBlobstoreView,
+};
+use std::collections::VecDeque;
+use wasmtime::component::Resource;
+use wasmtime_wasi::preview2::pipe::{MemoryInputPipe, MemoryOutputPipe};
+use wasmtime_wasi::preview2::{InputStream, OutputStream};
+
+/// Generate the traits and types from the `wasi-blobstore` WIT specification.
+mod gen_ {
+    wasmtime::component::bindgen!({
+        path: "wit",
+        world: "wasi:blobstore/imports",
+        tracing: true,
+        async: false,
+        with: {
+            "wasi:io/streams": wasmtime_wasi::preview2::bindings::io::streams,
+            "wasi:io/poll": wasmtime_wasi::preview2::bindings::io::poll,
+
+            "wasi:blobstore/types/outgoing-value": crate::ctx::HostOutgoingValue,
+            "wasi:blobstore/types/incoming-value": crate::ctx::HostIncomingValue,
+            "wasi:blobstore/container/container": crate::ctx::HostContainer,
+            "wasi:blobstore/container/stream-object-names": crate::ctx::HostStreamObjectNames,
+        }
+    });
+}
+use gen_::wasi::blobstore as gen; // Shortcut to the module containing the types we need.
+
+// Export the `types` used in this crate.
+pub mod types {
+    use super::gen;
+    pub use gen::types::{ContainerMetadata, Error, ObjectId, ObjectMetadata};
+}
+use types::{ContainerMetadata, Error, ObjectId, ObjectMetadata};
+
+/// Adds the `wasi:blobstore` interfaces to `linker`.
+///
+/// The `wasi:io` interfaces which values are read and written through are not
+/// added here; add them with `wasmtime_wasi`, sharing the resource table
+/// returned by [`WasiBlobstoreView::table`].
+pub fn add_to_linker<T: WasiBlobstoreView>(
+    linker: &mut wasmtime::component::Linker<T>,
+) -> anyhow::Result<()> {
+    gen::types::add_to_linker(linker, |t| t)?;
+    gen::container::add_to_linker(linker, |t| t)?;
+    gen::blobstore::add_to_linker(linker, |t| t)?;
+    Ok(())
+}
+
+impl<T: WasiBlobstoreView> gen::blobstore::Host for T {
+    fn create_container(
+        &mut self,
+        name: String,
+    ) -> wasmtime::Result<Result<Resource<HostContainer>, Error>> {
+        if let Err(e) = self.ctx().backend.create_container(&name) {
+            return Ok(Err(e.into()));
+        }
+        Ok(Ok(self.table().push(HostContainer { name })?))
+    }
+
+    fn get_container(
+        &mut self,
+        name: String,
+    ) -> wasmtime::Result<Result<Resource<HostContainer>, Error>> {
+        match self.ctx().backend.container_exists(&name) {
+            Ok(true) => Ok(Ok(self.table().push(HostContainer { name })?)),
+            Ok(false) => Ok(Err(
+                BackendError::NotFound(format!("container `{name}`")).into()
+            )),
+            Err(e) => Ok(Err(e.into())),
+        }
+    }
+
+    fn delete_container(&mut self, name: String) -> wasmtime::Result<Result<(), Error>> {
+        Ok(self
+            .ctx()
+            .backend
+            .delete_container(&name)
+            .map_err(Error::from))
+    }
+
+    fn container_exists(&mut self, name: String) -> wasmtime::Result<Result<bool, Error>> {
+        Ok(self
+            .ctx()
+            .backend
+            .container_exists(&name)
+            .map_err(Error::from))
+    }
+
+    fn copy_object(
+        &mut self,
+        src: ObjectId,
+        dest: ObjectId,
+    ) -> wasmtime::Result<Result<(), Error>> {
+        Ok(self
+            .ctx()
+            .backend
+            .copy_object(&src, &dest)
+            .map_err(Error::from))
+    }
+
+    fn move_object(
+        &mut self,
+        src: ObjectId,
+        dest: ObjectId,
+    ) -> wasmtime::Result<Result<(), Error>> {
+        Ok(self
+            .ctx()
+            .backend
+            .move_object(&src, &dest)
+            .map_err(Error::from))
+    }
+}
+
+impl<T: WasiBlobstoreView> gen::container::Host for T {}
+
+impl<T: WasiBlobstoreView> gen::container::HostContainer for T {
+    fn name(
+        &mut self,
+        container: Resource<HostContainer>,
+    ) -> wasmtime::Result<Result<String, Error>> {
+        Ok(Ok(self.table().get(&container)?.name.clone()))
+    }
+
+    fn info(
+        &mut self,
+        container: Resource<HostContainer>,
+    ) -> wasmtime::Result<Result<ContainerMetadata, Error>> {
+        let name = self.table().get(&container)?.name.clone();
+        Ok(self
+            .ctx()
+            .backend
+            .container_info(&name)
+            .map_err(Error::from))
+    }
+
+    fn get_data(
+        &mut self,
+        container: Resource<HostContainer>,
+        name: String,
+        start: u64,
+        end: u64,
+    ) -> wasmtime::Result<Result<Resource<HostIncomingValue>, Error>> {
+        let container = self.table().get(&container)?.name.clone();
+        // The range requested by the guest includes `end`.
+        let end = end.saturating_add(1);
+        let data = match self
+            .ctx()
+            .backend
+            .read_object(&container, &name, start, end)
+        {
+            Ok(data) => data,
+            Err(e) => return Ok(Err(e.into())),
+        };
+        Ok(Ok(self
+            .table()
+            .push(HostIncomingValue { data: data.into() })?))
+    }
+
+    fn write_data(
+        &mut self,
+        container: Resource<HostContainer>,
+        name: String,
+        data: Resource<HostOutgoingValue>,
+    ) -> wasmtime::Result<Result<(), Error>> {
+        let container = self.table().get(&container)?.name.clone();
+        let data = self.table().get(&data)?.body.contents();
+        Ok(self
+            .ctx()
+            .backend
+            .write_object(&container, &name, &data)
+            .map_err(Error::from))
+    }
+
+    fn list_objects(
+        &mut self,
+        container: Resource<HostContainer>,
+    ) -> wasmtime::Result<Result<Resource<HostStreamObjectNames>, Error>> {
+        let container = self.table().get(&container)?.name.clone();
+        let names = match self.ctx().backend.list_objects(&container) {
+            Ok(names) => VecDeque::from(names),
+            Err(e) => return Ok(Err(e.into())),
+        };
+        Ok(Ok(self.table().push(HostStreamObjectNames { names })?))
+    }
+
+    fn delete_object(
+        &mut self,
+        container: Resource<HostContainer>,
+        name: String,
+    ) -> wasmtime::Result<Result<(), Error>> {
+        let container = self.table().get(&container)?.name.clone();
+        Ok(self
+            .ctx()
+            .backend
+            .delete_object(&container, &name)
+            .map_err(Error::from))
+    }
+
+    fn delete_objects(
+        &mut self,
+        container: Resource<HostContainer>,
+        names: Vec<String>,
+    ) -> wasmtime::Result<Result<(), Error>> {
+        let container = self.table().get(&container)?.name.clone();
+        for name in names {
+            if let Err(e) = self.ctx().backend.delete_object(&container, &name) {
+                return Ok(Err(e.into()));
+            }
+        }
+        Ok(Ok(()))
+    }
+
+    fn has_object(
+        &mut self,
+        container: Resource<HostContainer>,
+        name: String,
+    ) -> wasmtime::Result<Result<bool, Error>> {
+        let container = self.table().get(&container)?.name.clone();
+        Ok(self
+            .ctx()
+            .backend
+            .has_object(&container, &name)
+            .map_err(Error::from))
+    }
+
+    fn object_info(
+        &mut self,
+        container: Resource<HostContainer>,
+        name: String,
+    ) -> wasmtime::Result<Result<ObjectMetadata, Error>> {
+        let container = self.table().get(&container)?.name.clone();
+        Ok(self
+            .ctx()
+            .backend
+            .object_info(&container, &name)
+            .map_err(Error::from))
+    }
+
+    fn clear(&mut self, container: Resource<HostContainer>) -> wasmtime::Result<Result<(), Error>> {
+        let container = self.table().get(&container)?.name.clone();
+        Ok(self.ctx().backend.clear(&container).map_err(Error::from))
+    }
+
+    fn drop(&mut self, container: Resource<HostContainer>) -> wasmtime::Result<()> {
+        self.table().delete(container)?;
+        Ok(())
+    }
+}
+
+impl<T: WasiBlobstoreView> gen::container::HostStreamObjectNames for T {
+    fn read_stream_object_names(
+        &mut self,
+        stream: Resource<HostStreamObjectNames>,
+        len: u64,
+    ) -> wasmtime::Result<Result<(Vec<String>, bool), Error>> {
+        let names = &mut self.table().get_mut(&stream)?.names;
+        let len = usize::try_from(len).unwrap_or(usize::MAX).min(names.len());
+        let read = names.drain(..len).collect();
+        Ok(Ok((read, names.is_empty())))
+    }
+
+    fn skip_stream_object_names(
+        &mut self,
+        stream: Resource<HostStreamObjectNames>,
+        num: u64,
+    ) -> wasmtime::Result<Result<(u64, bool), Error>> {
+        let names = &mut self.table().get_mut(&stream)?.names;
+        let num = usize::try_from(num).unwrap_or(usize::MAX).min(names.len());
+        names.drain(..num);
+        Ok(Ok((num as u64, names.is_empty())))
+    }
+
+    fn drop(&mut self, stream: Resource<HostStreamObjectNames>) -> wasmtime::Result<()> {
+        self.table().delete(stream)?;
+        Ok(())
+    }
+}
+
+impl<T: WasiBlobstoreView> gen::types::Host for T {}
+
+impl<T: WasiBlobstoreView> gen::types::HostOutgoingValue for T {
+    fn new_outgoing_value(&mut self) -> wasmtime::Result<Resource<HostOutgoingValue>> {
+        let body = MemoryOutputPipe::new(self.ctx().max_value_size);
+        Ok(self.table().push(HostOutgoingValue {
+            body,
+            body_taken: false,
+        })?)
+    }
+
+    fn outgoing_value_write_body(
+        &mut self,
+        value: Resource<HostOutgoingValue>,
+    ) -> wasmtime::Result<Result<Resource<OutputStream>, ()>> {
+        let entry = self.table().get_mut(&value)?;
+        if entry.body_taken {
+            return Ok(Err(()));
+        }
+        entry.body_taken = true;
+        let stream: OutputStream = Box::new(entry.body.clone());
+        Ok(Ok(self.table().push_child(stream, &value)?))
+    }
+
+    fn drop(&mut self, value: Resource<HostOutgoingValue>) -> wasmtime::Result<()> {
+        self.table().delete(value)?;
+        Ok(())
+    }
+}
+
+impl<T: WasiBlobstoreView> gen::types::HostIncomingValue for T {
+    fn incoming_value_consume_sync(
+        &mut self,
+        value: Resource<HostIncomingValue>,
+    ) -> wasmtime::Result<Result<Vec<u8>, Error>> {
+        Ok(Ok(self.table().get(&value)?.data.to_vec()))
+    }
+
+    fn incoming_value_consume_async(
+        &mut self,
+        value: Resource<HostIncomingValue>,
+    ) -> wasmtime::Result<Result<Resource<InputStream>, Error>> {
+        let data = self.table().get(&value)?.data.clone();
+        let stream = InputStream::Host(Box::new(MemoryInputPipe::new(data)));
+        Ok(Ok(self.table().push_child(stream, &value)?))
+    }
+
+    fn size(&mut self, value: Resource<HostIncomingValue>) -> wasmtime::Result<u64> {
+        Ok(self.table().get(&value)?.data.len() as u64)
+    }
+
+    fn drop(&mut self, value: Resource<HostIncomingValue>) -> wasmtime::Result<()> {
+        self.table().delete(value)?;
+        Ok(())
+    }
+}
+
+impl From<BackendError> for Error {
+    fn from(error: BackendError) -> Self {
+        if let BackendError::Backend(e) = &error {
+            tracing::warn!("blobstore backend failure: {e:?}");
+        }
+        error.to_string()
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::Result;
+use test_programs_artifacts::*;
+use wasmtime::{
+    component::{Component, Linker},
+    Config, Engine, Store,
+};
+use wasmtime_wasi::preview2::{command::sync::Command, Table, WasiCtx, WasiCtxBuilder, WasiView};
+use wasmtime_wasi_blobstore::{backend::FilesystemBackend, WasiBlobstoreCtx, WasiBlobstoreView};
+
+struct Ctx {
+    table: Table,
+    wasi: WasiCtx,
+    blobstore: WasiBlobstoreCtx,
+}
+
+impl WasiView for Ctx {
+    fn table(&self) -> &Table {
+        &self.table
+    }
+    fn table_mut(&mut self) -> &mut Table {
+        &mut self.table
+    }
+    fn ctx(&self) -> &WasiCtx {
+        &self.wasi
+    }
+    fn ctx_mut(&mut self) -> &mut WasiCtx {
+        &mut self.wasi
+    }
+}
+
+impl WasiBlobstoreView for Ctx {
+    fn ctx(&mut self) -> &mut WasiBlobstoreCtx {
+        &mut self.blobstore
+    }
+    fn table(&mut self) -> &mut Table {
+        &mut self.table
+    }
+}
+
+macro_rules! assert_test_exists {
+    ($name:ident) => {
+        #[allow(unused_imports)]
+        use self::$name as _;
+    };
+}
+
+foreach_blobstore!(assert_test_exists);
+
+fn run(path: &str) -> Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let mut config = Config::new();
+    config.wasm_backtrace_details(wasmtime::WasmBacktraceDetails::Enable);
+    config.wasm_component_model(true);
+    let engine = Engine::new(&config)?;
+    let component = Component::from_file(&engine, path)?;
+
+    let ctx = Ctx {
+        table: Table::new(),
+        wasi: WasiCtxBuilder::new().inherit_stdio().build(),
+        blobstore: WasiBlobstoreCtx::new(FilesystemBackend::new(dir.path())?),
+    };
+    let mut store = Store::new(&engine, ctx);
+    let mut linker = Linker::new(&engine);
+    wasmtime_wasi::preview2::command::sync::add_to_linker(&mut linker)?;
+    wasmtime_wasi_blobstore::add_to_linker(&mut linker)?;
+    let (command, _instance) = Command::instantiate(&mut store, &component, &linker)?;
+    let result = command.wasi_cli_run().call_run(&mut store)?;
+    result.map_err(|()| anyhow::anyhow!("run returned an error"))?;
+
+    // The guest's objects were stored through the filesystem backend.
+    assert_eq!(std::fs::read_dir(dir.path().join("backup"))?.count(), 1);
+    assert!(!dir.path().join("photos").exists());
+    Ok(())
+}
+
+#[test]
+fn blobstore_roundtrip() -> Result<()> {
+    run(BLOBSTORE_ROUNDTRIP_COMPONENT)
+}
@@ -79,6 +79,71 @@ impl Body for BodyWithTimeout {
     }
 }
 
+/// Wraps `body` so that it fails once more than `max_body_size` bytes of data
+/// have been transferred or once `deadline` has passed.
+///
+/// Returns `body` unchanged if neither limit is configured.
+pub fn limit_body(
+    body: HyperIncomingBody,
+    max_body_size: Option<u64>,
+    deadline: Option<tokio::time::Instant>,
+) -> HyperIncomingBody {
+    if max_body_size.is_none() && deadline.is_none() {
+        return body;
+    }
+    LimitedBody {
+        inner: body,
+        max_body_size,
+        transferred: 0,
+        deadline: deadline.map(|deadline| {
+            Box::pin(preview2::with_ambient_tokio_runtime(|| {
+                tokio::time::sleep_until(deadline)
+            }))
+        }),
+    }
+    .boxed()
+}
+
+/// Body wrapper created by [`limit_body`].
+struct LimitedBody {
+    inner: HyperIncomingBody,
+    max_body_size: Option<u64>,
+    /// Number of data bytes which have passed through this body so far.
+    transferred: u64,
+    deadline: Option<Pin<Box<tokio::time::Sleep>>>,
+}
+
+impl Body for LimitedBody {
+    type Data = Bytes;
+    type Error = types::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, types::Error>>> {
+        let me = Pin::into_inner(self);
+
+        if let Some(deadline) = &mut me.deadline {
+            if let Poll::Ready(()) = deadline.as_mut().poll(cx) {
+                return Poll::Ready(Some(Err(crate::types::timeout_error("request"))));
+            }
+        }
+
+        let result = Pin::new(&mut me.inner).poll_frame(cx);
+        if let (Poll::Ready(Some(Ok(frame))), Some(max)) = (&result, me.max_body_size) {
+            if let Some(data) = frame.data_ref() {
+                me.transferred += data.len() as u64;
+                if me.transferred > max {
+                    return Poll::Ready(Some(Err(types::Error::ProtocolError(format!(
+                        "body exceeds the maximum size of {max} bytes"
+                    )))));
+                }
+            }
+        }
+        result
+    }
+}
+
 pub struct HostIncomingBody {
     body: IncomingBodyState,
     /// An optional worker task to keep alive while this body is being read.
@@ -376,7 +441,10 @@ pub struct HostOutgoingBody {
 }
 
 impl HostOutgoingBody {
-    pub fn new() -> (Self, HyperOutgoingBody) {
+    /// Creates a body written by the guest, along with the hyper body which
+    /// receives its data. If `max_size` is given, writing more than that many
+    /// bytes fails both the guest's write and the hyper body.
+    pub fn new(max_size: Option<u64>) -> (Self, HyperOutgoingBody) {
         use tokio::sync::oneshot::error::RecvError;
         struct BodyImpl {
             body_receiver: mpsc::Receiver<Bytes>,
@@ -427,10 +495,12 @@ impl HostOutgoingBody {
             finish_receiver: Some(finish_receiver),
         }
         .boxed();
+        let body_impl = limit_body(body_impl, max_size, None);
+        // TODO: this capacity constant is arbitrary, and should be configurable
+        let stream = BodyWriteStream::new(1024 * 1024, body_sender).with_max_size(max_size);
         (
             Self {
-                // TODO: this capacity constant is arbitrary, and should be configurable
-                body_output_stream: Some(Box::new(BodyWriteStream::new(1024 * 1024, body_sender))),
+                body_output_stream: Some(Box::new(stream)),
                 finish_sender: Some(finish_sender),
             },
             body_impl,
@@ -442,6 +512,9 @@ impl HostOutgoingBody {
 pub struct BodyWriteStream {
     writer: mpsc::Sender<Bytes>,
     write_budget: usize,
+    max_size: Option<u64>,
+    /// Number of bytes written to this stream so far.
+    written: u64,
 }
 
 impl BodyWriteStream {
@@ -452,34 +525,60 @@ impl BodyWriteStream {
         BodyWriteStream {
             writer,
             write_budget,
+            max_size: None,
+            written: 0,
         }
     }
+
+    /// Limits the total amount of data written to this stream to `max_size`
+    /// bytes, if given.
+    ///
+    /// The write which exceeds the limit fails with
+    /// [`StreamError::LastOperationFailed`] and the stream is closed
+    /// afterwards. That write's data is still sent, so that a receiving body
+    /// wrapped by [`limit_body`] with the same limit fails too, rather than
+    /// ending as though the data were complete.
+    pub fn with_max_size(mut self, max_size: Option<u64>) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    fn exceeded_max_size(&self) -> Option<u64> {
+        self.max_size.filter(|max| self.written > *max)
+    }
 }
 
 #[async_trait::async_trait]
 impl HostOutputStream for BodyWriteStream {
     fn write(&mut self, bytes: Bytes) -> Result<(), StreamError> {
+        self.written += bytes.len() as u64;
         match self.writer.try_send(bytes) {
             // If the message was sent then it's queued up now in hyper to get
             // received.
-            Ok(()) => Ok(()),
+            Ok(()) => {}
 
             // If this channel is full then that means `check_write` wasn't
             // called. The call to `check_write` always guarantees that there's
             // at least one capacity if a write is allowed.
             Err(mpsc::error::TrySendError::Full(_)) => {
-                Err(StreamError::Trap(anyhow!("write exceeded budget")))
+                return Err(StreamError::Trap(anyhow!("write exceeded budget")))
             }
 
             // Hyper is gone so this stream is now closed.
-            Err(mpsc::error::TrySendError::Closed(_)) => Err(StreamError::Closed),
+            Err(mpsc::error::TrySendError::Closed(_)) => return Err(StreamError::Closed),
+        }
+        match self.exceeded_max_size() {
+            Some(max) => Err(StreamError::LastOperationFailed(anyhow!(
+                "body exceeds the maximum size of {max} bytes"
+            ))),
+            None => Ok(()),
         }
     }
 
     fn flush(&mut self) -> Result<(), StreamError> {
         // Flushing doesn't happen in this body stream since we're currently
         // only tracking sending bytes over to hyper.
-        if self.writer.is_closed() {
+        if self.writer.is_closed() || self.exceeded_max_size().is_some() {
             Err(StreamError::Closed)
         } else {
             Ok(())
@@ -487,7 +586,7 @@ impl HostOutputStream for BodyWriteStream {
     }
 
     fn check_write(&mut self) -> Result<usize, StreamError> {
-        if self.writer.is_closed() {
+        if self.writer.is_closed() || self.exceeded_max_size().is_some() {
             Err(StreamError::Closed)
         } else if self.writer.capacity() == 0 {
             // If there is no more capacity in this sender channel then don't
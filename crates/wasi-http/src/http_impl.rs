@@ -2,6 +2,7 @@ use crate::bindings::http::{
     outgoing_handler,
     types::{self as http_types, Scheme},
 };
+use crate::body;
 use crate::types::{self, HostFutureIncomingResponse, OutgoingRequest};
 use crate::WasiHttpView;
 use bytes::Bytes;
@@ -31,8 +32,14 @@ impl<T: WasiHttpView> outgoing_handler::Host for T {
             .and_then(|opts| opts.between_bytes_timeout)
             .unwrap_or(std::time::Duration::from_millis(600 * 1000));
 
+        let limits = self.limits();
+
         let req = self.table().delete(request_id)?;
 
+        if let Err(e) = limits.check_headers(&req.headers) {
+            return Ok(Err(e));
+        }
+
         let method = match req.method {
             crate::bindings::http::types::Method::Get => Method::GET,
             crate::bindings::http::types::Method::Head => Method::HEAD,
@@ -85,6 +92,7 @@ impl<T: WasiHttpView> outgoing_handler::Host for T {
         let body = req
             .body
             .unwrap_or_else(|| Empty::<Bytes>::new().map_err(|_| unreachable!()).boxed());
+        let body = body::limit_body(body, limits.max_body_size, limits.deadline());
 
         let request = builder.body(body).map_err(types::http_protocol_error)?;
 
@@ -95,6 +103,7 @@ impl<T: WasiHttpView> outgoing_handler::Host for T {
             connect_timeout,
             first_byte_timeout,
             between_bytes_timeout,
+            limits,
         })?))
     }
 }
@@ -3,7 +3,7 @@
 
 use crate::{
     bindings::http::types::{self, Method, Scheme},
-    body::{HostIncomingBody, HyperIncomingBody, HyperOutgoingBody},
+    body::{self, HostIncomingBody, HyperIncomingBody, HyperOutgoingBody},
 };
 use http_body_util::BodyExt;
 use hyper::header::HeaderName;
@@ -25,8 +25,82 @@ pub struct OutgoingRequest {
     pub connect_timeout: Duration,
     pub first_byte_timeout: Duration,
     pub between_bytes_timeout: Duration,
+    pub limits: HttpLimits,
 }
 
+/// Host-enforced limits on a single request and its response.
+///
+/// Limits are chosen per request through [`WasiHttpView::limits`] and can be
+/// adjusted for an individual outgoing request by overriding
+/// [`WasiHttpView::send_request`]. Violating a limit on an outgoing request or
+/// its response surfaces to the guest as a wasi-http `protocol-error` or
+/// `timeout-error` rather than trapping. An incoming request with too many
+/// headers is rejected by [`WasiHttpView::new_incoming_request`] with a
+/// [`RequestHeadersTooLarge`] error before the guest sees it, which hosts
+/// should answer with `431 Request Header Fields Too Large`.
+#[derive(Clone, Debug, Default)]
+pub struct HttpLimits {
+    /// Maximum number of bytes of body data in an incoming request, an
+    /// outgoing request or the response to an outgoing request.
+    pub max_body_size: Option<u64>,
+    /// Maximum number of bytes of body data in a response the guest sends
+    /// through `response-outparam`, as a `wasi:http/proxy` component does.
+    /// The guest's write which exceeds the limit fails and the response body
+    /// is aborted with a `protocol-error`.
+    pub max_response_body_size: Option<u64>,
+    /// Maximum number of header fields on a request or response.
+    pub max_header_count: Option<usize>,
+    /// Maximum duration from when a request is started until its response
+    /// body has been fully received.
+    pub total_timeout: Option<Duration>,
+}
+
+impl HttpLimits {
+    /// Returns an error if `headers` has more fields than allowed.
+    pub fn check_headers(&self, headers: &FieldMap) -> Result<(), types::Error> {
+        self.check_header_count(headers)
+            .map_err(|e| types::Error::ProtocolError(e.to_string()))
+    }
+
+    fn check_header_count(&self, headers: &FieldMap) -> Result<(), RequestHeadersTooLarge> {
+        match self.max_header_count {
+            Some(max) if headers.len() > max => Err(RequestHeadersTooLarge {
+                count: headers.len(),
+                max,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the deadline for a request started now, if any.
+    pub fn deadline(&self) -> Option<tokio::time::Instant> {
+        self.total_timeout
+            .map(|timeout| tokio::time::Instant::now() + timeout)
+    }
+}
+
+/// Error returned by [`WasiHttpView::new_incoming_request`] for a request with
+/// more header fields than [`HttpLimits::max_header_count`] allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestHeadersTooLarge {
+    /// The number of header fields in the request.
+    pub count: usize,
+    /// The maximum number of header fields allowed.
+    pub max: usize,
+}
+
+impl std::fmt::Display for RequestHeadersTooLarge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "header count {} exceeds the maximum of {}",
+            self.count, self.max
+        )
+    }
+}
+
+impl std::error::Error for RequestHeadersTooLarge {}
+
 pub trait WasiHttpView: Send {
     fn ctx(&mut self) -> &mut WasiHttpCtx;
     fn table(&mut self) -> &mut Table;
@@ -36,6 +110,9 @@ pub trait WasiHttpView: Send {
         req: hyper::Request<HyperIncomingBody>,
     ) -> wasmtime::Result<Resource<HostIncomingRequest>> {
         let (parts, body) = req.into_parts();
+        let limits = self.limits();
+        limits.check_header_count(&parts.headers)?;
+        let body = body::limit_body(body, limits.max_body_size, limits.deadline());
         let body = HostIncomingBody::new(
             body,
             // TODO: this needs to be plumbed through
@@ -70,6 +147,14 @@ pub trait WasiHttpView: Send {
     fn is_forbidden_header(&mut self, _name: &HeaderName) -> bool {
         false
     }
+
+    /// Returns the limits to enforce on the next request, either incoming
+    /// through [`WasiHttpView::new_incoming_request`] or outgoing through the
+    /// `outgoing-handler` interface, and on the body of the next response the
+    /// guest creates. By default nothing is limited.
+    fn limits(&mut self) -> HttpLimits {
+        HttpLimits::default()
+    }
 }
 
 pub fn default_send_request(
@@ -81,6 +166,7 @@ pub fn default_send_request(
         connect_timeout,
         first_byte_timeout,
         between_bytes_timeout,
+        limits,
     }: OutgoingRequest,
 ) -> wasmtime::Result<Resource<HostFutureIncomingResponse>> {
     let handle = preview2::spawn(async move {
@@ -91,6 +177,7 @@ pub fn default_send_request(
             first_byte_timeout,
             request,
             between_bytes_timeout,
+            limits,
         )
         .await;
         Ok(resp)
@@ -108,7 +195,39 @@ async fn handler(
     first_byte_timeout: Duration,
     request: http::Request<HyperOutgoingBody>,
     between_bytes_timeout: Duration,
+    limits: HttpLimits,
 ) -> Result<IncomingResponseInternal, types::Error> {
+    let deadline = limits.deadline();
+    let send = send(
+        authority,
+        use_tls,
+        connect_timeout,
+        first_byte_timeout,
+        request,
+    );
+    let (resp, worker) = match deadline {
+        Some(deadline) => tokio::time::timeout_at(deadline, send)
+            .await
+            .map_err(|_| timeout_error("request"))??,
+        None => send.await?,
+    };
+    limits.check_headers(resp.headers())?;
+    let resp = resp.map(|body| body::limit_body(body, limits.max_body_size, deadline));
+
+    Ok(IncomingResponseInternal {
+        resp,
+        worker: Arc::new(worker),
+        between_bytes_timeout,
+    })
+}
+
+async fn send(
+    authority: String,
+    use_tls: bool,
+    connect_timeout: Duration,
+    first_byte_timeout: Duration,
+    request: http::Request<HyperOutgoingBody>,
+) -> Result<(http::Response<HyperIncomingBody>, AbortOnDropJoinHandle<()>), types::Error> {
     let tcp_stream = TcpStream::connect(authority.clone())
         .await
         .map_err(invalid_url)?;
@@ -191,11 +310,7 @@ async fn handler(
         .map_err(hyper_protocol_error)?
         .map(|body| body.map_err(|e| e.into()).boxed());
 
-    Ok(IncomingResponseInternal {
-        resp,
-        worker: Arc::new(worker),
-        between_bytes_timeout,
-    })
+    Ok((resp, worker))
 }
 
 pub fn timeout_error(kind: &str) -> types::Error {
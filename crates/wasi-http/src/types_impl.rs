@@ -351,7 +351,7 @@ impl<T: WasiHttpView> crate::bindings::http::types::HostOutgoingRequest for T {
             return Ok(Err(()));
         }
 
-        let (host_body, hyper_body) = HostOutgoingBody::new();
+        let (host_body, hyper_body) = HostOutgoingBody::new(None);
 
         req.body = Some(hyper_body);
 
@@ -684,13 +684,14 @@ impl<T: WasiHttpView> crate::bindings::http::types::HostOutgoingResponse for T {
         &mut self,
         id: Resource<HostOutgoingResponse>,
     ) -> wasmtime::Result<Result<Resource<HostOutgoingBody>, ()>> {
+        let max_size = self.limits().max_response_body_size;
         let resp = self.table().get_mut(&id)?;
 
         if resp.body.is_some() {
             return Ok(Err(()));
         }
 
-        let (host, body) = HostOutgoingBody::new();
+        let (host, body) = HostOutgoingBody::new(max_size);
 
         resp.body.replace(body);
 
@@ -478,6 +478,121 @@ async fn do_wasi_http_echo(uri: &str, url_header: Option<&str>) -> Result<()> {
     Ok(())
 }
 
+#[test_log::test(tokio::test)]
+async fn body_size_and_deadline_limits() -> Result<()> {
+    use http_body_util::BodyExt;
+    use std::time::Duration;
+    use wasmtime_wasi_http::body::limit_body;
+
+    let small = limit_body(body::full(Bytes::from_static(b"hello")), Some(5), None);
+    assert_eq!(small.collect().await?.to_bytes(), "hello");
+
+    let large = limit_body(body::full(Bytes::from_static(b"hello!")), Some(5), None);
+    assert!(matches!(
+        large.collect().await,
+        Err(Error::ProtocolError(_))
+    ));
+
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(10);
+    let pending = BoxBody::new(StreamBody::new(stream::pending::<
+        Result<Frame<Bytes>, Error>,
+    >()));
+    let stalled = limit_body(pending, None, Some(deadline));
+    assert!(matches!(
+        stalled.collect().await,
+        Err(Error::TimeoutError(_))
+    ));
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn response_body_size_limit() -> Result<()> {
+    use http_body_util::BodyExt;
+    use wasmtime_wasi::preview2::StreamError;
+    use wasmtime_wasi_http::body::HostOutgoingBody;
+
+    let (mut host_body, hyper_body) = HostOutgoingBody::new(Some(5));
+    let mut stream = host_body.body_output_stream.take().unwrap();
+
+    assert!(stream.check_write()? >= 5);
+    stream.write(Bytes::from_static(b"hello"))?;
+    stream.check_write()?;
+    assert!(matches!(
+        stream.write(Bytes::from_static(b"!")),
+        Err(StreamError::LastOperationFailed(_))
+    ));
+    assert!(matches!(stream.check_write(), Err(StreamError::Closed)));
+
+    // The receiving end fails rather than yielding a truncated body.
+    assert!(matches!(
+        hyper_body.collect().await,
+        Err(Error::ProtocolError(_))
+    ));
+
+    Ok(())
+}
+
+#[test_log::test(tokio::test)]
+async fn header_count_limits() -> Result<()> {
+    use wasmtime_wasi_http::types::{HttpLimits, RequestHeadersTooLarge};
+
+    struct LimitedCtx {
+        table: Table,
+        http: WasiHttpCtx,
+    }
+
+    impl WasiHttpView for LimitedCtx {
+        fn ctx(&mut self) -> &mut WasiHttpCtx {
+            &mut self.http
+        }
+
+        fn table(&mut self) -> &mut Table {
+            &mut self.table
+        }
+
+        fn limits(&mut self) -> HttpLimits {
+            HttpLimits {
+                max_header_count: Some(1),
+                ..HttpLimits::default()
+            }
+        }
+    }
+
+    let mut ctx = LimitedCtx {
+        table: Table::new(),
+        http: WasiHttpCtx {},
+    };
+
+    // An incoming request over the limit is rejected with an error the host
+    // can turn into a 431 response before the guest is involved.
+    let req = hyper::Request::get("/")
+        .header("a", "1")
+        .header("b", "2")
+        .body(body::empty())?;
+    let err = ctx.new_incoming_request(req).err().unwrap();
+    assert_eq!(
+        err.downcast_ref::<RequestHeadersTooLarge>(),
+        Some(&RequestHeadersTooLarge { count: 2, max: 1 })
+    );
+
+    let req = hyper::Request::get("/")
+        .header("a", "1")
+        .body(body::empty())?;
+    assert!(ctx.new_incoming_request(req).is_ok());
+
+    // Outgoing requests and responses get a wasi-http error instead.
+    let mut headers = types::FieldMap::new();
+    headers.insert("a", "1".parse()?);
+    headers.insert("b", "2".parse()?);
+    assert!(matches!(
+        ctx.limits().check_headers(&headers),
+        Err(Error::ProtocolError(_))
+    ));
+
+    Ok(())
+}
+
 mod body {
     use http_body_util::{combinators::BoxBody, BodyExt, Empty, Full};
     use hyper::body::Bytes;
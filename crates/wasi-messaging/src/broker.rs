@@ -0,0 +1,276 @@
+//! Define the Rust interface a broker must implement in order to route the
+//! messages of a [`WasiMessagingCtx`](crate::WasiMessagingCtx).
+
+use crate::wit::types::Message;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Routes messages between publishers and subscribers of channels.
+///
+/// Adapters for external messaging systems map channels onto that system's
+/// topics, subjects or queues.
+pub trait Broker: Send + Sync {
+    /// Publishes `messages`, in order, to every current subscriber of
+    /// `channel`.
+    fn publish(&self, channel: &str, messages: &[Message]) -> Result<(), BrokerError>;
+
+    /// Subscribes to `channel`, receiving messages published from now on.
+    fn subscribe(&self, channel: &str) -> Result<Box<dyn Subscription>, BrokerError>;
+
+    /// Acknowledges that a received message has been processed.
+    ///
+    /// Messaging systems which don't track delivery, like [`InMemoryBroker`],
+    /// can ignore this.
+    fn complete(&self, message: &Message) -> Result<(), BrokerError> {
+        let _ = message;
+        Ok(())
+    }
+
+    /// Reports that a received message could not be processed.
+    ///
+    /// Messaging systems which don't track delivery, like [`InMemoryBroker`],
+    /// can ignore this.
+    fn abandon(&self, message: &Message) -> Result<(), BrokerError> {
+        let _ = message;
+        Ok(())
+    }
+}
+
+/// A subscription to a channel created by [`Broker::subscribe`].
+///
+/// Dropping the subscription unsubscribes from the channel.
+pub trait Subscription: Send + Sync {
+    /// Returns up to `max` messages, waiting up to `timeout` for at least one
+    /// to arrive. Returns no messages if none arrived in time.
+    fn receive(&mut self, max: usize, timeout: Duration) -> Result<Vec<Message>, BrokerError>;
+
+    /// Returns up to `max` messages which have already arrived, without
+    /// blocking.
+    fn try_receive(&mut self, max: usize) -> Result<Vec<Message>, BrokerError> {
+        self.receive(max, Duration::ZERO)
+    }
+}
+
+/// Errors returned by a broker; [BrokerError::Broker] is a catch-all for
+/// failures interacting with the underlying messaging system.
+#[derive(Debug, Error)]
+pub enum BrokerError {
+    #[error("invalid channel `{0}`")]
+    InvalidChannel(String),
+    #[error("no broker named `{0}`")]
+    UnknownBroker(String),
+    #[error("timed out waiting for messages on channel `{0}`")]
+    TimedOut(String),
+    #[error("a subscriber of channel `{0}` has no room for the messages")]
+    QueueFull(String),
+    #[error("Failed while accessing broker")]
+    Broker(#[from] anyhow::Error),
+}
+
+#[derive(Default)]
+struct Queue {
+    messages: Mutex<VecDeque<Message>>,
+    arrived: Condvar,
+}
+
+/// A [`Broker`] which routes messages within the current process.
+///
+/// Each subscriber has its own queue holding at most a fixed number of
+/// messages. Publishing is all-or-nothing: if any subscriber's queue lacks
+/// room for every message, none are delivered and [`BrokerError::QueueFull`]
+/// is returned, so producers can back off until consumers catch up.
+///
+/// Clones of an `InMemoryBroker` share the same channels, so a clone can be
+/// given to each store whose guests should communicate with each other.
+#[derive(Clone)]
+pub struct InMemoryBroker {
+    channels: Arc<Mutex<HashMap<String, Vec<Weak<Queue>>>>>,
+    capacity: usize,
+}
+
+impl InMemoryBroker {
+    /// The number of messages a subscriber's queue holds by default.
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Creates a broker whose subscribers each queue at most `capacity`
+    /// messages.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            channels: Default::default(),
+            capacity,
+        }
+    }
+}
+
+impl Default for InMemoryBroker {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl Broker for InMemoryBroker {
+    fn publish(&self, channel: &str, messages: &[Message]) -> Result<(), BrokerError> {
+        let mut channels = self.channels.lock().unwrap();
+        let subscribers = match channels.get_mut(channel) {
+            Some(subscribers) => subscribers,
+            None => return Ok(()),
+        };
+        subscribers.retain(|subscriber| subscriber.strong_count() > 0);
+        let queues = subscribers
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect::<Vec<_>>();
+        if queues.is_empty() {
+            channels.remove(channel);
+            return Ok(());
+        }
+
+        // Check every queue before delivering to any of them. Receivers only
+        // ever lock a single queue, so holding all of these can't deadlock.
+        let mut locked = queues
+            .iter()
+            .map(|queue| queue.messages.lock().unwrap())
+            .collect::<Vec<_>>();
+        if locked
+            .iter()
+            .any(|queue| self.capacity - queue.len() < messages.len())
+        {
+            return Err(BrokerError::QueueFull(channel.to_string()));
+        }
+        for queue in locked.iter_mut() {
+            queue.extend(messages.iter().cloned());
+        }
+        drop(locked);
+        for queue in &queues {
+            queue.arrived.notify_all();
+        }
+        Ok(())
+    }
+
+    fn subscribe(&self, channel: &str) -> Result<Box<dyn Subscription>, BrokerError> {
+        if channel.is_empty() {
+            return Err(BrokerError::InvalidChannel(channel.to_string()));
+        }
+        let queue = Arc::new(Queue::default());
+        self.channels
+            .lock()
+            .unwrap()
+            .entry(channel.to_string())
+            .or_default()
+            .push(Arc::downgrade(&queue));
+        Ok(Box::new(InMemorySubscription { queue }))
+    }
+}
+
+struct InMemorySubscription {
+    queue: Arc<Queue>,
+}
+
+impl Subscription for InMemorySubscription {
+    fn receive(&mut self, max: usize, timeout: Duration) -> Result<Vec<Message>, BrokerError> {
+        let start = Instant::now();
+        let mut messages = self.queue.messages.lock().unwrap();
+        while messages.is_empty() {
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                break;
+            }
+            messages = self
+                .queue
+                .arrived
+                .wait_timeout(messages, timeout - elapsed)
+                .unwrap()
+                .0;
+        }
+        let n = max.min(messages.len());
+        Ok(messages.drain(..n).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wit::types::FormatSpec;
+
+    fn message(data: &[u8]) -> Message {
+        Message {
+            data: data.to_vec(),
+            format: FormatSpec::Raw,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn fans_out_to_subscribers() -> anyhow::Result<()> {
+        let broker = InMemoryBroker::default();
+        broker.publish("events", &[message(b"lost")])?;
+
+        let mut a = broker.subscribe("events")?;
+        let mut b = broker.clone().subscribe("events")?;
+        broker.publish("events", &[message(b"one"), message(b"two")])?;
+        broker.publish("other", &[message(b"elsewhere")])?;
+
+        let received = a.try_receive(1)?;
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].data, b"one");
+        assert_eq!(a.try_receive(10)?.len(), 1);
+        assert!(a.try_receive(10)?.is_empty());
+        assert_eq!(b.try_receive(10)?.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn dropped_subscriptions_are_pruned() -> anyhow::Result<()> {
+        let broker = InMemoryBroker::default();
+        let subscription = broker.subscribe("events")?;
+        drop(subscription);
+        broker.publish("events", &[message(b"nobody")])?;
+        assert!(broker.channels.lock().unwrap().is_empty());
+        assert!(matches!(
+            broker.subscribe(""),
+            Err(BrokerError::InvalidChannel(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn full_queues_reject_publishes() -> anyhow::Result<()> {
+        let broker = InMemoryBroker::new(2);
+        let mut fast = broker.subscribe("events")?;
+        let mut slow = broker.subscribe("events")?;
+        broker.publish("events", &[message(b"one")])?;
+        assert_eq!(fast.try_receive(10)?.len(), 1);
+
+        // `slow` only has room for one more message, so nobody gets either.
+        assert!(matches!(
+            broker.publish("events", &[message(b"two"), message(b"three")]),
+            Err(BrokerError::QueueFull(_))
+        ));
+        assert!(fast.try_receive(10)?.is_empty());
+        broker.publish("events", &[message(b"two")])?;
+        assert_eq!(slow.try_receive(10)?.len(), 2);
+        assert_eq!(fast.try_receive(10)?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn receive_waits_for_messages() -> anyhow::Result<()> {
+        let broker = InMemoryBroker::default();
+        let mut subscription = broker.subscribe("events")?;
+        assert!(subscription
+            .receive(10, Duration::from_millis(10))?
+            .is_empty());
+
+        let publisher = broker.clone();
+        let thread = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(10));
+            publisher.publish("events", &[message(b"late")])
+        });
+        let received = subscription.receive(10, Duration::from_secs(10))?;
+        assert_eq!(received[0].data, b"late");
+        thread.join().unwrap()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+//! Implements the host state for the `wasi-messaging` API: [WasiMessagingCtx],
+//! and the host representations of its resources.
+
+use crate::{Broker, BrokerError, Subscription};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use wasmtime_wasi::preview2::Table;
+
+/// Capture the state necessary for routing messages through a broker.
+pub struct WasiMessagingCtx {
+    /// The broker messages are pushed to the guest's handler from.
+    pub(crate) broker: Arc<dyn Broker>,
+    /// The brokers guests can connect a `client` to, by name.
+    pub(crate) brokers: HashMap<String, Arc<dyn Broker>>,
+    pub(crate) receive_timeout: Duration,
+    pub(crate) max_receive_batch: usize,
+    /// Channels the guest asked to have messages pushed from, in the order of
+    /// its configuration.
+    pub(crate) handler_subscriptions: Vec<(String, Box<dyn Subscription>)>,
+    /// The message of the most recent error returned to the guest, reported
+    /// by `error.trace`.
+    pub(crate) last_error: Option<String>,
+}
+
+impl WasiMessagingCtx {
+    /// The name under which guests connect to the broker passed to
+    /// [`WasiMessagingCtx::new`].
+    pub const DEFAULT_BROKER: &'static str = "default";
+
+    /// The default limit on how long `subscribe-receive` waits for a message:
+    /// 30 seconds.
+    pub const DEFAULT_RECEIVE_TIMEOUT: Duration = Duration::from_secs(30);
+
+    /// The default limit on the number of messages returned by a single
+    /// receive.
+    pub const DEFAULT_MAX_RECEIVE_BATCH: usize = 256;
+
+    /// Make a new context which routes messages through `broker`.
+    ///
+    /// Guests connect to `broker` under the name
+    /// [`DEFAULT_BROKER`](Self::DEFAULT_BROKER), and messages pushed to the
+    /// guest's handler come from it.
+    pub fn new(broker: impl Broker + 'static) -> Self {
+        let broker: Arc<dyn Broker> = Arc::new(broker);
+        Self {
+            brokers: HashMap::from([(Self::DEFAULT_BROKER.to_string(), broker.clone())]),
+            broker,
+            receive_timeout: Self::DEFAULT_RECEIVE_TIMEOUT,
+            max_receive_batch: Self::DEFAULT_MAX_RECEIVE_BATCH,
+            handler_subscriptions: Vec::new(),
+            last_error: None,
+        }
+    }
+
+    /// Makes `broker` available to guests connecting a `client` to `name`,
+    /// replacing any broker previously added under that name. Connecting to
+    /// a name no broker was added under fails.
+    pub fn add_broker(&mut self, name: impl Into<String>, broker: impl Broker + 'static) {
+        self.brokers.insert(name.into(), Arc::new(broker));
+    }
+
+    /// Limits how long `subscribe-receive` blocks waiting for a message
+    /// before returning an error to the guest. This also bounds the timeout
+    /// a guest passes to `subscribe-try-receive`.
+    pub fn set_receive_timeout(&mut self, timeout: Duration) {
+        self.receive_timeout = timeout;
+    }
+
+    /// Limits the number of messages a single `subscribe-receive` or
+    /// `subscribe-try-receive` returns; the remainder stay queued for the
+    /// next call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is zero.
+    pub fn set_max_receive_batch(&mut self, max: usize) {
+        assert!(max > 0, "the receive batch size must be at least one");
+        self.max_receive_batch = max;
+    }
+
+    /// Returns the message of the most recent error returned to the guest.
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    /// Replaces the channels messages are pushed to the guest's handler from,
+    /// keeping the subscriptions, and so the pending messages, of channels
+    /// which remain.
+    pub(crate) fn set_handler_channels(
+        &mut self,
+        channels: Vec<String>,
+    ) -> Result<(), BrokerError> {
+        // Subscribe to the new channels first so that a failure leaves the
+        // previous configuration in place.
+        let mut added = HashMap::new();
+        for channel in &channels {
+            let subscribed = self.handler_subscriptions.iter().any(|(c, _)| c == channel);
+            if !subscribed && !added.contains_key(channel) {
+                added.insert(channel.clone(), self.broker.subscribe(channel)?);
+            }
+        }
+        let mut old = std::mem::take(&mut self.handler_subscriptions);
+        for channel in channels {
+            let subscription = match old.iter().position(|(c, _)| *c == channel) {
+                Some(i) => old.swap_remove(i).1,
+                None => match added.remove(&channel) {
+                    Some(subscription) => subscription,
+                    // A repeated channel.
+                    None => continue,
+                },
+            };
+            self.handler_subscriptions.push((channel, subscription));
+        }
+        Ok(())
+    }
+}
+
+/// Projects the messaging state and resource table out of a store's data.
+pub trait WasiMessagingView: Send {
+    fn ctx(&mut self) -> &mut WasiMessagingCtx;
+    fn table(&mut self) -> &mut Table;
+}
+
+/// The host representation of a `client` resource.
+///
+/// Every client routes messages through the broker it connected to; the
+/// channels it receives from through the `consumer` interface stay subscribed
+/// until the client is dropped, so messages published between two calls are
+/// not lost.
+pub struct HostClient {
+    pub(crate) broker: Arc<dyn Broker>,
+    pub(crate) subscriptions: HashMap<String, Box<dyn Subscription>>,
+}
+
+/// The host representation of an `error` resource.
+pub struct HostError {
+    pub(crate) message: String,
+}
@@ -0,0 +1,20 @@
+//! Implements the [wasi-messaging] API for Wasmtime components.
+//!
+//! Guests publish messages with `wasi:messaging/producer` and pull them from
+//! channels with `wasi:messaging/consumer`. Guests exporting
+//! `wasi:messaging/messaging-guest` can instead have messages pushed to them:
+//! [`configure`] subscribes to the channels the guest asks for and [`deliver`]
+//! passes pending messages to its handler. All routing is delegated to a
+//! [`Broker`]; [`InMemoryBroker`] is provided for use within a single process,
+//! and embedders can implement the trait to adapt NATS, Kafka or other
+//! messaging systems.
+//!
+//! [wasi-messaging]: https://github.com/WebAssembly/wasi-messaging
+
+mod broker;
+mod ctx;
+pub mod wit;
+
+pub use broker::{Broker, BrokerError, InMemoryBroker, Subscription};
+pub use ctx::{WasiMessagingCtx, WasiMessagingView};
+pub use wit::{add_to_linker, configure, deliver, Messaging};
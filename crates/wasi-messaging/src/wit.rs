@@ -0,0 +1,262 @@
+//! Implements the `wasi-messaging` API for the WIT ("preview2") ABI.
+//!
+//! The WIT glue code is generated into a `gen::*` namespace. The imported
+//! interfaces are wired up to a [`WasiMessagingView`], and the exported
+//! `messaging-guest` interface is driven by [`configure`] and [`deliver`].
+
+use crate::ctx::{HostClient, HostError, WasiMessagingView};
+use crate::BrokerError;
+use anyhow::anyhow;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::time::Duration;
+use wasmtime::component::Resource;
+use wasmtime::AsContextMut;
+
+/// Generate the traits and types from the `wasi-messaging` WIT specification.
+mod gen_ {
+    wasmtime::component::bindgen!({
+        path: "wit",
+        world: "wasi:messaging/messaging",
+        tracing: true,
+        async: false,
+        with: {
+            "wasi:messaging/types/client": crate::ctx::HostClient,
+            "wasi:messaging/types/error": crate::ctx::HostError,
+        }
+    });
+}
+use gen_::wasi::messaging as gen; // Shortcut to the module containing the types we need.
+
+// Export the `types` used in this crate as well as the `Messaging` world.
+pub mod types {
+    use super::gen;
+    pub use gen::types::{FormatSpec, GuestConfiguration, Message};
+}
+pub use gen_::Messaging;
+use types::{GuestConfiguration, Message};
+
+/// Adds the `wasi:messaging/producer` and `wasi:messaging/consumer`
+/// interfaces to `linker`.
+pub fn add_to_linker<T: WasiMessagingView>(
+    linker: &mut wasmtime::component::Linker<T>,
+) -> anyhow::Result<()> {
+    gen::types::add_to_linker(linker, |t| t)?;
+    gen::producer::add_to_linker(linker, |t| t)?;
+    gen::consumer::add_to_linker(linker, |t| t)?;
+    Ok(())
+}
+
+/// Asks the guest which channels it wants messages from, by calling its
+/// exported `configure` function, and subscribes to them on its behalf.
+///
+/// Messages published to those channels afterwards are passed to the guest's
+/// handler by [`deliver`]. The guest may change the channels later with
+/// `update-guest-configuration`.
+pub fn configure<T: WasiMessagingView>(
+    mut store: impl AsContextMut<Data = T>,
+    guest: &Messaging,
+) -> anyhow::Result<()> {
+    let mut store = store.as_context_mut();
+    let configuration = guest
+        .wasi_messaging_messaging_guest()
+        .call_configure(&mut store)?;
+    let configuration = match configuration {
+        Ok(configuration) => configuration,
+        Err(e) => {
+            let e = store.data_mut().table().delete(e)?;
+            return Err(anyhow!(
+                "guest failed to provide its configuration: {}",
+                e.message
+            ));
+        }
+    };
+    store
+        .data_mut()
+        .ctx()
+        .set_handler_channels(configuration.channels)?;
+    Ok(())
+}
+
+/// Passes messages which have arrived on the channels subscribed to by
+/// [`configure`] to the guest's exported `handler`, in batches of at most
+/// `max_batch` messages per channel.
+///
+/// Returns the number of messages delivered, which is zero when nothing was
+/// pending; embedders typically call this in a loop, waiting for new messages
+/// in between. Errors returned by the guest's handler are logged and do not
+/// stop delivery, while traps are returned.
+pub fn deliver<T: WasiMessagingView>(
+    mut store: impl AsContextMut<Data = T>,
+    guest: &Messaging,
+    max_batch: usize,
+) -> anyhow::Result<usize> {
+    let mut store = store.as_context_mut();
+    let mut delivered = 0;
+    // The handler may reconfigure the channels while they're being iterated.
+    let mut i = 0;
+    while let Some((channel, subscription)) =
+        store.data_mut().ctx().handler_subscriptions.get_mut(i)
+    {
+        let channel = channel.clone();
+        let messages = subscription.try_receive(max_batch)?;
+        i += 1;
+        if messages.is_empty() {
+            continue;
+        }
+        delivered += messages.len();
+        let result = guest
+            .wasi_messaging_messaging_guest()
+            .call_handler(&mut store, &messages)?;
+        if let Err(e) = result {
+            let e = store.data_mut().table().delete(e)?;
+            tracing::warn!(
+                "guest failed to handle messages from `{channel}`: {}",
+                e.message
+            );
+        }
+    }
+    Ok(delivered)
+}
+
+/// Records `error` as the most recent error and returns it as a resource.
+fn push_error<T: WasiMessagingView>(
+    view: &mut T,
+    error: BrokerError,
+) -> wasmtime::Result<Resource<HostError>> {
+    if let BrokerError::Broker(e) = &error {
+        tracing::warn!("messaging broker failure: {e:?}");
+    }
+    let message = error.to_string();
+    view.ctx().last_error = Some(message.clone());
+    Ok(view.table().push(HostError { message })?)
+}
+
+impl<T: WasiMessagingView> gen::types::Host for T {}
+
+impl<T: WasiMessagingView> gen::types::HostClient for T {
+    fn connect(
+        &mut self,
+        name: String,
+    ) -> wasmtime::Result<Result<Resource<HostClient>, Resource<HostError>>> {
+        let broker = match self.ctx().brokers.get(&name) {
+            Some(broker) => broker.clone(),
+            None => return Ok(Err(push_error(self, BrokerError::UnknownBroker(name))?)),
+        };
+        Ok(Ok(self.table().push(HostClient {
+            broker,
+            subscriptions: HashMap::new(),
+        })?))
+    }
+
+    fn drop(&mut self, client: Resource<HostClient>) -> wasmtime::Result<()> {
+        self.table().delete(client)?;
+        Ok(())
+    }
+}
+
+impl<T: WasiMessagingView> gen::types::HostError for T {
+    fn trace(&mut self) -> wasmtime::Result<String> {
+        Ok(self.ctx().last_error.clone().unwrap_or_default())
+    }
+
+    fn drop(&mut self, error: Resource<HostError>) -> wasmtime::Result<()> {
+        self.table().delete(error)?;
+        Ok(())
+    }
+}
+
+impl<T: WasiMessagingView> gen::producer::Host for T {
+    fn send(
+        &mut self,
+        client: Resource<HostClient>,
+        ch: String,
+        m: Vec<Message>,
+    ) -> wasmtime::Result<Result<(), Resource<HostError>>> {
+        let broker = self.table().get(&client)?.broker.clone();
+        match broker.publish(&ch, &m) {
+            Ok(()) => Ok(Ok(())),
+            Err(e) => Ok(Err(push_error(self, e)?)),
+        }
+    }
+}
+
+impl<T: WasiMessagingView> gen::consumer::Host for T {
+    fn subscribe_try_receive(
+        &mut self,
+        client: Resource<HostClient>,
+        ch: String,
+        t_milliseconds: u32,
+    ) -> wasmtime::Result<Result<Option<Vec<Message>>, Resource<HostError>>> {
+        // The guest can't block for longer than `subscribe-receive` could.
+        let timeout = Duration::from_millis(t_milliseconds.into()).min(self.ctx().receive_timeout);
+        match receive(self, &client, ch, timeout)? {
+            Ok(messages) if messages.is_empty() => Ok(Ok(None)),
+            Ok(messages) => Ok(Ok(Some(messages))),
+            Err(e) => Ok(Err(push_error(self, e)?)),
+        }
+    }
+
+    fn subscribe_receive(
+        &mut self,
+        client: Resource<HostClient>,
+        ch: String,
+    ) -> wasmtime::Result<Result<Vec<Message>, Resource<HostError>>> {
+        let timeout = self.ctx().receive_timeout;
+        match receive(self, &client, ch.clone(), timeout)? {
+            Ok(messages) if messages.is_empty() => {
+                Ok(Err(push_error(self, BrokerError::TimedOut(ch))?))
+            }
+            Ok(messages) => Ok(Ok(messages)),
+            Err(e) => Ok(Err(push_error(self, e)?)),
+        }
+    }
+
+    fn update_guest_configuration(
+        &mut self,
+        gc: GuestConfiguration,
+    ) -> wasmtime::Result<Result<(), Resource<HostError>>> {
+        match self.ctx().set_handler_channels(gc.channels) {
+            Ok(()) => Ok(Ok(())),
+            Err(e) => Ok(Err(push_error(self, e)?)),
+        }
+    }
+
+    fn complete_message(
+        &mut self,
+        m: Message,
+    ) -> wasmtime::Result<Result<(), Resource<HostError>>> {
+        match self.ctx().broker.complete(&m) {
+            Ok(()) => Ok(Ok(())),
+            Err(e) => Ok(Err(push_error(self, e)?)),
+        }
+    }
+
+    fn abandon_message(&mut self, m: Message) -> wasmtime::Result<Result<(), Resource<HostError>>> {
+        match self.ctx().broker.abandon(&m) {
+            Ok(()) => Ok(Ok(())),
+            Err(e) => Ok(Err(push_error(self, e)?)),
+        }
+    }
+}
+
+/// Receives up to the context's batch size of messages from `channel`
+/// through the subscription `client` holds, subscribing first if this is the
+/// first time the client receives from it.
+fn receive<T: WasiMessagingView>(
+    view: &mut T,
+    client: &Resource<HostClient>,
+    channel: String,
+    timeout: Duration,
+) -> wasmtime::Result<Result<Vec<Message>, BrokerError>> {
+    let max = view.ctx().max_receive_batch;
+    let client = view.table().get_mut(client)?;
+    let subscription = match client.subscriptions.entry(channel) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => match client.broker.subscribe(entry.key()) {
+            Ok(subscription) => entry.insert(subscription),
+            Err(e) => return Ok(Err(e)),
+        },
+    };
+    Ok(subscription.receive(max, timeout))
+}
@@ -0,0 +1,138 @@
+use anyhow::Result;
+use test_programs_artifacts::*;
+use wasmtime::{
+    component::{Component, Linker},
+    Config, Engine, Store,
+};
+use wasmtime_wasi::preview2::{Table, WasiCtx, WasiCtxBuilder, WasiView};
+use wasmtime_wasi_messaging::{
+    wit::types::{FormatSpec, Message},
+    Broker, InMemoryBroker, Messaging, WasiMessagingCtx, WasiMessagingView,
+};
+
+struct Ctx {
+    table: Table,
+    wasi: WasiCtx,
+    messaging: WasiMessagingCtx,
+}
+
+impl WasiView for Ctx {
+    fn table(&self) -> &Table {
+        &self.table
+    }
+    fn table_mut(&mut self) -> &mut Table {
+        &mut self.table
+    }
+    fn ctx(&self) -> &WasiCtx {
+        &self.wasi
+    }
+    fn ctx_mut(&mut self) -> &mut WasiCtx {
+        &mut self.wasi
+    }
+}
+
+impl WasiMessagingView for Ctx {
+    fn ctx(&mut self) -> &mut WasiMessagingCtx {
+        &mut self.messaging
+    }
+    fn table(&mut self) -> &mut Table {
+        &mut self.table
+    }
+}
+
+macro_rules! assert_test_exists {
+    ($name:ident) => {
+        #[allow(unused_imports)]
+        use self::$name as _;
+    };
+}
+
+foreach_messaging!(assert_test_exists);
+
+fn message(data: &[u8]) -> Message {
+    Message {
+        data: data.to_vec(),
+        format: FormatSpec::Raw,
+        metadata: None,
+    }
+}
+
+/// Instantiates the `messaging_guest` component with messages routed through
+/// `broker`, and subscribes to the channels it asks for.
+fn instantiate(broker: &InMemoryBroker) -> Result<(Store<Ctx>, Messaging)> {
+    let mut config = Config::new();
+    config.wasm_backtrace_details(wasmtime::WasmBacktraceDetails::Enable);
+    config.wasm_component_model(true);
+    let engine = Engine::new(&config)?;
+    let component = Component::from_file(&engine, MESSAGING_GUEST_COMPONENT)?;
+
+    let ctx = Ctx {
+        table: Table::new(),
+        wasi: WasiCtxBuilder::new().inherit_stdio().build(),
+        messaging: WasiMessagingCtx::new(broker.clone()),
+    };
+    let mut store = Store::new(&engine, ctx);
+    let mut linker = Linker::new(&engine);
+    wasmtime_wasi::preview2::command::sync::add_to_linker(&mut linker)?;
+    wasmtime_wasi_messaging::add_to_linker(&mut linker)?;
+    let (guest, _instance) = Messaging::instantiate(&mut store, &component, &linker)?;
+    wasmtime_wasi_messaging::configure(&mut store, &guest)?;
+    Ok((store, guest))
+}
+
+#[test]
+fn messaging_guest() -> Result<()> {
+    let broker = InMemoryBroker::default();
+    let (mut store, guest) = instantiate(&broker)?;
+
+    let mut receipts = broker.subscribe("receipts")?;
+
+    // Messages on the configured channel are pushed to the handler, which
+    // publishes through the producer interface.
+    broker.publish("orders", &[message(b"hello"), message(b"world")])?;
+    assert_eq!(wasmtime_wasi_messaging::deliver(&mut store, &guest, 16)?, 2);
+    let received = receipts.try_receive(10)?;
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].data, b"HELLO");
+    assert_eq!(received[1].data, b"WORLD");
+    assert_eq!(wasmtime_wasi_messaging::deliver(&mut store, &guest, 16)?, 0);
+
+    // The guest's consumer subscription outlives a single handler call.
+    broker.publish("orders", &[message(b"subscribe")])?;
+    wasmtime_wasi_messaging::deliver(&mut store, &guest, 16)?;
+    broker.publish("inbox", &[message(b"mail")])?;
+    broker.publish("orders", &[message(b"poll")])?;
+    wasmtime_wasi_messaging::deliver(&mut store, &guest, 16)?;
+    assert_eq!(receipts.try_receive(10)?[0].data, b"mail");
+
+    // The guest can change the channels it's pushed messages from.
+    broker.publish("more", &[message(b"ignored")])?;
+    broker.publish("orders", &[message(b"reconfigure")])?;
+    wasmtime_wasi_messaging::deliver(&mut store, &guest, 16)?;
+    broker.publish("more", &[message(b"again")])?;
+    assert_eq!(wasmtime_wasi_messaging::deliver(&mut store, &guest, 16)?, 1);
+    assert_eq!(receipts.try_receive(10)?[0].data, b"AGAIN");
+    Ok(())
+}
+
+#[test]
+fn full_queues_surface_as_guest_errors() -> Result<()> {
+    let broker = InMemoryBroker::new(1);
+    let (mut store, guest) = instantiate(&broker)?;
+
+    // The first receipt fills the queue, so the second one is rejected and
+    // the handler returns the error instead of trapping.
+    let mut receipts = broker.subscribe("receipts")?;
+    broker.publish("orders", &[message(b"one")])?;
+    wasmtime_wasi_messaging::deliver(&mut store, &guest, 16)?;
+    broker.publish("orders", &[message(b"two")])?;
+    wasmtime_wasi_messaging::deliver(&mut store, &guest, 16)?;
+    assert!(store
+        .data()
+        .messaging
+        .last_error()
+        .unwrap()
+        .contains("receipts"));
+    assert_eq!(receipts.try_receive(10)?[0].data, b"ONE");
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use super::clocks::host::{monotonic_clock, wall_clock};
 use crate::preview2::{
     clocks::{self, HostMonotonicClock, HostWallClock},
-    filesystem::Dir,
+    filesystem::{Dir, FsQuota, FsUsage},
     pipe, random, stdio,
     stdio::{StdinStream, StdoutStream},
     DirPerms, FilePerms, Table,
@@ -12,6 +12,7 @@ use cap_std::net::Pool;
 use cap_std::{ambient_authority, AmbientAuthority};
 use std::mem;
 use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
 
 pub struct WasiCtxBuilder {
     stdin: Box<dyn StdinStream>,
@@ -20,6 +21,7 @@ pub struct WasiCtxBuilder {
     env: Vec<(String, String)>,
     args: Vec<String>,
     preopens: Vec<(Dir, String)>,
+    fs_quota: FsQuota,
 
     pool: Pool,
     random: Box<dyn RngCore + Send + Sync>,
@@ -71,6 +73,7 @@ impl WasiCtxBuilder {
             env: Vec::new(),
             args: Vec::new(),
             preopens: Vec::new(),
+            fs_quota: FsQuota::default(),
             pool: Pool::new(),
             random: random::thread_rng(),
             insecure_random,
@@ -149,6 +152,15 @@ impl WasiCtxBuilder {
         self
     }
 
+    /// Configures limits on the filesystem resources the guest may consume.
+    ///
+    /// By default no limits are enforced. See [`FsQuota`] for the available
+    /// limits and the errors reported when they're exceeded.
+    pub fn fs_quota(&mut self, quota: FsQuota) -> &mut Self {
+        self.fs_quota = quota;
+        self
+    }
+
     /// Set the generator for the secure random number generator to the custom
     /// generator specified.
     ///
@@ -272,6 +284,7 @@ impl WasiCtxBuilder {
             env,
             args,
             preopens,
+            fs_quota,
             pool,
             random,
             insecure_random,
@@ -290,6 +303,7 @@ impl WasiCtxBuilder {
             env,
             args,
             preopens,
+            fs_usage: Arc::new(FsUsage::new(fs_quota)),
             pool,
             random,
             insecure_random,
@@ -317,6 +331,7 @@ pub struct WasiCtx {
     pub(crate) env: Vec<(String, String)>,
     pub(crate) args: Vec<String>,
     pub(crate) preopens: Vec<(Dir, String)>,
+    pub(crate) fs_usage: Arc<FsUsage>,
     pub(crate) stdin: Box<dyn StdinStream>,
     pub(crate) stdout: Box<dyn StdoutStream>,
     pub(crate) stderr: Box<dyn StdoutStream>,
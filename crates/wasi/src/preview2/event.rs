@@ -0,0 +1,263 @@
+//! Pollables backed by event sources which are owned by the embedder.
+//!
+//! These let an embedder expose its own events to guests as
+//! `wasi:io/poll.pollable`s, for example completions reported by a foreign
+//! reactor or readiness of a file descriptor the guest manipulates through
+//! custom host functions.
+
+use crate::preview2::{subscribe, Pollable, Subscribe, Table};
+use anyhow::Result;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::Notify;
+use wasmtime::component::Resource;
+
+/// Pushes `source` into `table` and returns a `pollable` subscribed to it.
+///
+/// The returned pollable owns `source`, which is dropped along with it.
+pub fn push_pollable<T>(table: &mut Table, source: T) -> Result<Resource<Pollable>>
+where
+    T: Subscribe,
+{
+    let resource = table.push(source)?;
+    subscribe(table, resource)
+}
+
+/// A level-triggered event which the embedder signals from any thread.
+///
+/// A `pollable` subscribed to a `HostEvent` is ready whenever the event is
+/// signalled, and stays ready until the event is reset through its
+/// [`HostEventHandle`].
+pub struct HostEvent {
+    state: Arc<EventState>,
+}
+
+/// The embedder's side of a [`HostEvent`].
+///
+/// Handles are cheap to clone and may be moved to other threads, for example
+/// into the completion callback of a foreign reactor.
+#[derive(Clone)]
+pub struct HostEventHandle {
+    state: Arc<EventState>,
+}
+
+struct EventState {
+    signalled: AtomicBool,
+    notify: Notify,
+}
+
+impl HostEvent {
+    /// Creates a new, unsignalled event along with the handle used to signal
+    /// it.
+    pub fn new() -> (HostEvent, HostEventHandle) {
+        let state = Arc::new(EventState {
+            signalled: AtomicBool::new(false),
+            notify: Notify::new(),
+        });
+        (
+            HostEvent {
+                state: state.clone(),
+            },
+            HostEventHandle { state },
+        )
+    }
+}
+
+impl HostEventHandle {
+    /// Signals the event, waking up any guest waiting on it.
+    pub fn signal(&self) {
+        self.state.signalled.store(true, Ordering::SeqCst);
+        self.state.notify.notify_waiters();
+    }
+
+    /// Resets the event so pollables subscribed to it are no longer ready.
+    pub fn reset(&self) {
+        self.state.signalled.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns whether the event is currently signalled.
+    pub fn is_signalled(&self) -> bool {
+        self.state.signalled.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait::async_trait]
+impl Subscribe for HostEvent {
+    async fn ready(&mut self) {
+        loop {
+            // Create the `Notified` future before checking the flag: it
+            // receives `notify_waiters` calls from the moment it's created, so
+            // a signal between the check and the await isn't lost.
+            let notified = self.state.notify.notified();
+            if self.state.signalled.load(Ordering::SeqCst) {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(unix)]
+pub use self::fd::{FdInterest, FdPollable};
+
+#[cfg(unix)]
+mod fd {
+    use crate::preview2::{with_ambient_tokio_runtime, Subscribe};
+    use rustix::event::{PollFd, PollFlags};
+    use std::io;
+    use std::os::fd::{AsRawFd, BorrowedFd};
+    use tokio::io::unix::{AsyncFd, AsyncFdReadyGuard};
+
+    /// The kind of readiness an [`FdPollable`] waits for.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum FdInterest {
+        /// Ready when the descriptor is readable.
+        Readable,
+        /// Ready when the descriptor is writable.
+        Writable,
+    }
+
+    /// A `pollable` source which is ready when a file descriptor owned by the
+    /// embedder is readable or writable.
+    ///
+    /// The descriptor is registered with Tokio's reactor, so it must be in
+    /// non-blocking mode. Readiness is confirmed with a zero-timeout `poll`
+    /// each time the pollable is checked, so the pollable stops being ready
+    /// once the guest or the embedder has drained the descriptor.
+    pub struct FdPollable<T: AsRawFd> {
+        fd: AsyncFd<T>,
+        interest: FdInterest,
+    }
+
+    impl<T: AsRawFd> FdPollable<T> {
+        /// Registers `fd` with the ambient Tokio runtime.
+        pub fn new(fd: T, interest: FdInterest) -> io::Result<Self> {
+            let tokio_interest = match interest {
+                FdInterest::Readable => tokio::io::Interest::READABLE,
+                FdInterest::Writable => tokio::io::Interest::WRITABLE,
+            };
+            let fd = with_ambient_tokio_runtime(|| AsyncFd::with_interest(fd, tokio_interest))?;
+            Ok(FdPollable { fd, interest })
+        }
+
+        /// Returns a reference to the underlying descriptor.
+        pub fn get_ref(&self) -> &T {
+            self.fd.get_ref()
+        }
+
+        /// Deregisters the descriptor and returns it.
+        pub fn into_inner(self) -> T {
+            self.fd.into_inner()
+        }
+
+        fn poll_flags(&self) -> PollFlags {
+            match self.interest {
+                FdInterest::Readable => PollFlags::IN,
+                FdInterest::Writable => PollFlags::OUT,
+            }
+        }
+
+        /// Returns whether the descriptor is ready right now, according to
+        /// the operating system rather than Tokio's cached readiness.
+        fn is_ready_now(&self) -> bool {
+            // SAFETY: the descriptor is owned by `self.fd` and stays open for
+            // the duration of this borrow.
+            let fd = unsafe { BorrowedFd::borrow_raw(self.fd.as_raw_fd()) };
+            let mut fds = [PollFd::new(&fd, self.poll_flags())];
+            match rustix::event::poll(&mut fds, 0) {
+                Ok(n) => n > 0,
+                // Let the caller find out about the error when it performs
+                // the actual I/O.
+                Err(_) => true,
+            }
+        }
+
+        fn confirm(&self, mut guard: AsyncFdReadyGuard<'_, T>) -> bool {
+            if self.is_ready_now() {
+                return true;
+            }
+            guard.clear_ready();
+            false
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<T> Subscribe for FdPollable<T>
+    where
+        T: AsRawFd + Send + Sync + 'static,
+    {
+        async fn ready(&mut self) {
+            loop {
+                let guard = match self.interest {
+                    FdInterest::Readable => self.fd.readable().await,
+                    FdInterest::Writable => self.fd.writable().await,
+                };
+                match guard {
+                    Ok(guard) => {
+                        if self.confirm(guard) {
+                            return;
+                        }
+                    }
+                    // Report the descriptor as ready so the error surfaces
+                    // when it's used.
+                    Err(_) => return,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn host_event_is_level_triggered() {
+        let (mut event, handle) = HostEvent::new();
+        let waiter = tokio::time::timeout(Duration::from_millis(10), event.ready()).await;
+        assert!(waiter.is_err(), "unsignalled event should not be ready");
+
+        let signaller = std::thread::spawn({
+            let handle = handle.clone();
+            move || handle.signal()
+        });
+        tokio::time::timeout(Duration::from_secs(2), event.ready())
+            .await
+            .expect("signalled event should be ready");
+        signaller.join().unwrap();
+
+        // Stays ready until reset.
+        tokio::time::timeout(Duration::from_secs(2), event.ready())
+            .await
+            .expect("event should stay ready");
+        handle.reset();
+        let waiter = tokio::time::timeout(Duration::from_millis(10), event.ready()).await;
+        assert!(waiter.is_err(), "reset event should not be ready");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn fd_pollable_tracks_readability() {
+        use std::io::{Read, Write};
+        use std::os::unix::net::UnixStream;
+
+        let (reader, mut writer) = UnixStream::pair().unwrap();
+        reader.set_nonblocking(true).unwrap();
+        let mut pollable = FdPollable::new(reader, FdInterest::Readable).unwrap();
+
+        let waiter = tokio::time::timeout(Duration::from_millis(10), pollable.ready()).await;
+        assert!(waiter.is_err(), "empty socket should not be readable");
+
+        writer.write_all(b"x").unwrap();
+        tokio::time::timeout(Duration::from_secs(2), pollable.ready())
+            .await
+            .expect("socket with data should be readable");
+
+        let mut buf = [0; 1];
+        let mut stream = pollable.get_ref();
+        stream.read_exact(&mut buf).unwrap();
+        let waiter = tokio::time::timeout(Duration::from_millis(10), pollable.ready()).await;
+        assert!(waiter.is_err(), "drained socket should not be readable");
+    }
+}
@@ -7,6 +7,7 @@ use anyhow::anyhow;
 use bytes::{Bytes, BytesMut};
 use std::io;
 use std::mem;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 
 pub type FsResult<T> = Result<T, FsError>;
@@ -25,6 +26,118 @@ impl From<io::Error> for FsError {
     }
 }
 
+/// Limits on the filesystem resources a guest may consume through a
+/// [`WasiCtx`](crate::preview2::WasiCtx).
+///
+/// Quotas are configured with
+/// [`WasiCtxBuilder::fs_quota`](crate::preview2::WasiCtxBuilder::fs_quota)
+/// and each limit is disabled when `None`. Operations which would exceed a
+/// limit fail with a distinct error code:
+///
+/// * exceeding `max_bytes_written` fails with `error-code.quota`,
+/// * exceeding `max_open_descriptors` fails with
+///   `error-code.insufficient-memory`,
+/// * exceeding `max_path_depth` fails with `error-code.name-too-long`.
+#[derive(Clone, Debug, Default)]
+pub struct FsQuota {
+    /// The maximum total number of bytes the guest may write to files, across
+    /// all descriptors and streams of the context.
+    pub max_bytes_written: Option<u64>,
+    /// The maximum number of descriptors the guest may have open at once,
+    /// not counting preopened directories.
+    pub max_open_descriptors: Option<usize>,
+    /// The maximum number of components in a path passed to a filesystem
+    /// operation, after resolving `.` and `..` components.
+    pub max_path_depth: Option<usize>,
+}
+
+/// Tracks usage of a context's filesystem resources against its [`FsQuota`].
+#[derive(Debug, Default)]
+pub(crate) struct FsUsage {
+    quota: FsQuota,
+    bytes_written: AtomicU64,
+    open_descriptors: AtomicUsize,
+}
+
+impl FsUsage {
+    pub(crate) fn new(quota: FsQuota) -> Self {
+        FsUsage {
+            quota,
+            ..FsUsage::default()
+        }
+    }
+
+    /// Reserves `n` bytes of the write quota, to be refunded with
+    /// `refund_write` for any bytes which end up not being written.
+    pub(crate) fn charge_write(&self, n: u64) -> Result<(), types::ErrorCode> {
+        let max = match self.quota.max_bytes_written {
+            Some(max) => max,
+            None => return Ok(()),
+        };
+        self.bytes_written
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |written| {
+                written.checked_add(n).filter(|total| *total <= max)
+            })
+            .map(|_| ())
+            .map_err(|_| types::ErrorCode::Quota)
+    }
+
+    pub(crate) fn refund_write(&self, n: u64) {
+        if self.quota.max_bytes_written.is_some() {
+            self.bytes_written.fetch_sub(n, Ordering::SeqCst);
+        }
+    }
+
+    /// Accounts for a newly opened descriptor, returning a slot which releases
+    /// it from the count when dropped.
+    pub(crate) fn open_descriptor(
+        self: &Arc<Self>,
+    ) -> Result<Option<Arc<OpenDescriptorSlot>>, types::ErrorCode> {
+        let max = match self.quota.max_open_descriptors {
+            Some(max) => max,
+            None => return Ok(None),
+        };
+        self.open_descriptors
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |open| {
+                Some(open + 1).filter(|open| *open <= max)
+            })
+            .map_err(|_| types::ErrorCode::InsufficientMemory)?;
+        Ok(Some(Arc::new(OpenDescriptorSlot(self.clone()))))
+    }
+
+    pub(crate) fn check_path(&self, path: &str) -> Result<(), types::ErrorCode> {
+        let max = match self.quota.max_path_depth {
+            Some(max) => max,
+            None => return Ok(()),
+        };
+        let mut depth = 0usize;
+        for component in path.split('/') {
+            match component {
+                "" | "." => {}
+                ".." => depth = depth.saturating_sub(1),
+                _ => {
+                    depth += 1;
+                    if depth > max {
+                        return Err(types::ErrorCode::NameTooLong);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A descriptor counted against [`FsQuota::max_open_descriptors`], released
+/// when the last descriptor referencing it is dropped.
+#[derive(Debug)]
+pub(crate) struct OpenDescriptorSlot(Arc<FsUsage>);
+
+impl Drop for OpenDescriptorSlot {
+    fn drop(&mut self) {
+        self.0.open_descriptors.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 pub enum Descriptor {
     File(File),
     Dir(Dir),
@@ -75,6 +188,8 @@ pub struct File {
     /// [`spawn_blocking`]: Self::spawn_blocking
     pub file: Arc<cap_std::fs::File>,
     pub perms: FilePerms,
+    /// Keeps this descriptor counted against the open descriptor quota.
+    pub(crate) open_slot: Option<Arc<OpenDescriptorSlot>>,
 }
 
 impl File {
@@ -82,6 +197,7 @@ impl File {
         Self {
             file: Arc::new(file),
             perms,
+            open_slot: None,
         }
     }
 
@@ -110,6 +226,8 @@ pub struct Dir {
     pub dir: Arc<cap_std::fs::Dir>,
     pub perms: DirPerms,
     pub file_perms: FilePerms,
+    /// Keeps this descriptor counted against the open descriptor quota.
+    pub(crate) open_slot: Option<Arc<OpenDescriptorSlot>>,
 }
 
 impl Dir {
@@ -118,6 +236,7 @@ impl Dir {
             dir: Arc::new(dir),
             perms,
             file_perms,
+            open_slot: None,
         }
     }
 
@@ -183,31 +302,35 @@ pub(crate) struct FileOutputStream {
     file: Arc<cap_std::fs::File>,
     mode: FileOutputMode,
     state: OutputState,
+    usage: Arc<FsUsage>,
 }
 
 enum OutputState {
     Ready,
     /// Allows join future to be awaited in a cancellable manner. Gone variant indicates
-    /// no task is currently outstanding.
-    Waiting(AbortOnDropJoinHandle<io::Result<usize>>),
+    /// no task is currently outstanding. Also records the number of bytes
+    /// charged to the write quota for the write.
+    Waiting(AbortOnDropJoinHandle<io::Result<usize>>, usize),
     /// The last I/O operation failed with this error.
     Error(io::Error),
     Closed,
 }
 
 impl FileOutputStream {
-    pub fn write_at(file: Arc<cap_std::fs::File>, position: u64) -> Self {
+    pub fn write_at(file: Arc<cap_std::fs::File>, position: u64, usage: Arc<FsUsage>) -> Self {
         Self {
             file,
             mode: FileOutputMode::Position(position),
             state: OutputState::Ready,
+            usage,
         }
     }
-    pub fn append(file: Arc<cap_std::fs::File>) -> Self {
+    pub fn append(file: Arc<cap_std::fs::File>, usage: Arc<FsUsage>) -> Self {
         Self {
             file,
             mode: FileOutputMode::Append,
             state: OutputState::Ready,
+            usage,
         }
     }
 }
@@ -221,7 +344,7 @@ impl HostOutputStream for FileOutputStream {
         match self.state {
             OutputState::Ready => {}
             OutputState::Closed => return Err(StreamError::Closed),
-            OutputState::Waiting(_) | OutputState::Error(_) => {
+            OutputState::Waiting(..) | OutputState::Error(_) => {
                 // a write is pending - this call was not permitted
                 return Err(StreamError::Trap(anyhow!(
                     "write not permitted: check_write not called first"
@@ -229,6 +352,11 @@ impl HostOutputStream for FileOutputStream {
             }
         }
 
+        let charged = buf.len();
+        if let Err(code) = self.usage.charge_write(charged as u64) {
+            return Err(StreamError::LastOperationFailed(code.into()));
+        }
+
         let f = Arc::clone(&self.file);
         let m = self.mode;
         let task = spawn_blocking(move || match m {
@@ -255,7 +383,7 @@ impl HostOutputStream for FileOutputStream {
                 Ok(total)
             }
         });
-        self.state = OutputState::Waiting(task);
+        self.state = OutputState::Waiting(task, charged);
         Ok(())
     }
     fn flush(&mut self) -> Result<(), StreamError> {
@@ -263,7 +391,7 @@ impl HostOutputStream for FileOutputStream {
             // Only userland buffering of file writes is in the blocking task,
             // so there's nothing extra that needs to be done to request a
             // flush.
-            OutputState::Ready | OutputState::Waiting(_) => Ok(()),
+            OutputState::Ready | OutputState::Waiting(..) => Ok(()),
             OutputState::Closed => Err(StreamError::Closed),
             OutputState::Error(_) => match mem::replace(&mut self.state, OutputState::Closed) {
                 OutputState::Error(e) => Err(StreamError::LastOperationFailed(e.into())),
@@ -279,7 +407,7 @@ impl HostOutputStream for FileOutputStream {
                 OutputState::Error(e) => Err(StreamError::LastOperationFailed(e.into())),
                 _ => unreachable!(),
             },
-            OutputState::Waiting(_) => Ok(0),
+            OutputState::Waiting(..) => Ok(0),
         }
     }
 }
@@ -287,15 +415,20 @@ impl HostOutputStream for FileOutputStream {
 #[async_trait::async_trait]
 impl Subscribe for FileOutputStream {
     async fn ready(&mut self) {
-        if let OutputState::Waiting(task) = &mut self.state {
+        if let OutputState::Waiting(task, charged) = &mut self.state {
+            let charged = *charged;
             self.state = match task.await {
                 Ok(nwritten) => {
                     if let FileOutputMode::Position(ref mut p) = &mut self.mode {
                         *p += nwritten as u64;
                     }
+                    self.usage.refund_write((charged - nwritten) as u64);
                     OutputState::Ready
                 }
-                Err(e) => OutputState::Error(e),
+                Err(e) => {
+                    self.usage.refund_write(charged as u64);
+                    OutputState::Error(e)
+                }
             };
         }
     }
@@ -324,3 +457,56 @@ impl IntoIterator for ReaddirIterator {
         self.0.into_inner().unwrap()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::ErrorCode;
+
+    #[test]
+    fn bytes_written_quota() {
+        let usage = FsUsage::new(FsQuota {
+            max_bytes_written: Some(10),
+            ..FsQuota::default()
+        });
+        usage.charge_write(6).unwrap();
+        assert_eq!(usage.charge_write(5), Err(ErrorCode::Quota));
+        usage.refund_write(2);
+        usage.charge_write(6).unwrap();
+        assert_eq!(usage.charge_write(1), Err(ErrorCode::Quota));
+    }
+
+    #[test]
+    fn open_descriptor_quota() {
+        let usage = Arc::new(FsUsage::new(FsQuota {
+            max_open_descriptors: Some(2),
+            ..FsQuota::default()
+        }));
+        let a = usage.open_descriptor().unwrap();
+        let b = usage.open_descriptor().unwrap();
+        assert!(a.is_some() && b.is_some());
+        assert_eq!(
+            usage.open_descriptor().err(),
+            Some(ErrorCode::InsufficientMemory)
+        );
+        drop(a);
+        assert!(usage.open_descriptor().unwrap().is_some());
+
+        // Without a limit no slots are handed out.
+        let unlimited = Arc::new(FsUsage::new(FsQuota::default()));
+        assert!(unlimited.open_descriptor().unwrap().is_none());
+    }
+
+    #[test]
+    fn path_depth_quota() {
+        let usage = FsUsage::new(FsQuota {
+            max_path_depth: Some(2),
+            ..FsQuota::default()
+        });
+        usage.check_path("a/b").unwrap();
+        usage.check_path("./a//b/").unwrap();
+        usage.check_path("a/../b/c").unwrap();
+        assert_eq!(usage.check_path("a/b/c"), Err(ErrorCode::NameTooLong));
+        assert_eq!(usage.check_path("a/b/../c/d"), Err(ErrorCode::NameTooLong));
+    }
+}
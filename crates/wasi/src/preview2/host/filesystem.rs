@@ -46,6 +46,11 @@ impl<T: WasiView> types::Host for T {
             return Ok(Some(ErrorCode::from(err)));
         }
 
+        // Errors from enforcing the context's `FsQuota` are reported directly.
+        if let Some(code) = err.downcast_ref::<ErrorCode>() {
+            return Ok(Some(*code));
+        }
+
         Ok(None)
     }
 }
@@ -258,17 +263,30 @@ impl<T: WasiView> HostDescriptor for T {
         use std::io::IoSlice;
         use system_interface::fs::FileIoExt;
 
+        let usage = self.ctx().fs_usage.clone();
         let table = self.table();
         let f = table.get(&fd)?.file()?;
         if !f.perms.contains(FilePerms::WRITE) {
             return Err(ErrorCode::NotPermitted.into());
         }
 
-        let bytes_written = f
+        let charged = buf.len() as u64;
+        usage.charge_write(charged)?;
+        let bytes_written = match f
             .spawn_blocking(move |f| f.write_vectored_at(&[IoSlice::new(&buf)], offset))
-            .await?;
+            .await
+        {
+            Ok(n) => n,
+            Err(e) => {
+                usage.refund_write(charged);
+                return Err(e.into());
+            }
+        };
+        let bytes_written =
+            types::Filesize::try_from(bytes_written).expect("usize fits in Filesize");
+        usage.refund_write(charged - bytes_written);
 
-        Ok(types::Filesize::try_from(bytes_written).expect("usize fits in Filesize"))
+        Ok(bytes_written)
     }
 
     async fn read_directory(
@@ -368,6 +386,7 @@ impl<T: WasiView> HostDescriptor for T {
         fd: Resource<types::Descriptor>,
         path: String,
     ) -> FsResult<()> {
+        self.ctx().fs_usage.check_path(&path)?;
         let table = self.table();
         let d = table.get(&fd)?.dir()?;
         if !d.perms.contains(DirPerms::MUTATE) {
@@ -461,6 +480,7 @@ impl<T: WasiView> HostDescriptor for T {
         new_descriptor: Resource<types::Descriptor>,
         new_path: String,
     ) -> FsResult<()> {
+        self.ctx().fs_usage.check_path(&new_path)?;
         let table = self.table();
         let old_dir = table.get(&fd)?.dir()?;
         if !old_dir.perms.contains(DirPerms::MUTATE) {
@@ -495,6 +515,8 @@ impl<T: WasiView> HostDescriptor for T {
         use system_interface::fs::{FdFlags, GetSetFdFlags};
         use types::{DescriptorFlags, OpenFlags};
 
+        let usage = self.ctx().fs_usage.clone();
+        usage.check_path(&path)?;
         let table = self.table_mut();
         let d = table.get(&fd)?.dir()?;
         if !d.perms.contains(DirPerms::READ) {
@@ -565,6 +587,11 @@ impl<T: WasiView> HostDescriptor for T {
             NotDir,
         }
 
+        // Reserve the descriptor before opening anything so that an open
+        // which would exceed the quota can't create or truncate a file. If the
+        // open fails the slot is released when it's dropped.
+        let open_slot = usage.open_descriptor()?;
+
         let opened = d
             .spawn_blocking::<_, std::io::Result<OpenResult>>(move |d| {
                 let mut opened = d.open_with(&path, &opts)?;
@@ -586,13 +613,16 @@ impl<T: WasiView> HostDescriptor for T {
 
         match opened {
             OpenResult::Dir(dir) => {
-                Ok(table.push(Descriptor::Dir(Dir::new(dir, d.perms, d.file_perms)))?)
+                let mut dir = Dir::new(dir, d.perms, d.file_perms);
+                dir.open_slot = open_slot;
+                Ok(table.push(Descriptor::Dir(dir))?)
             }
 
-            OpenResult::File(file) => Ok(table.push(Descriptor::File(File::new(
-                file,
-                mask_file_perms(d.file_perms, flags),
-            )))?),
+            OpenResult::File(file) => {
+                let mut file = File::new(file, mask_file_perms(d.file_perms, flags));
+                file.open_slot = open_slot;
+                Ok(table.push(Descriptor::File(file))?)
+            }
 
             OpenResult::NotDir => Err(ErrorCode::NotDirectory.into()),
         }
@@ -648,6 +678,7 @@ impl<T: WasiView> HostDescriptor for T {
         new_fd: Resource<types::Descriptor>,
         new_path: String,
     ) -> FsResult<()> {
+        self.ctx().fs_usage.check_path(&new_path)?;
         let table = self.table();
         let old_dir = table.get(&fd)?.dir()?;
         if !old_dir.perms.contains(DirPerms::MUTATE) {
@@ -673,6 +704,7 @@ impl<T: WasiView> HostDescriptor for T {
         #[cfg(windows)]
         use cap_fs_ext::DirExt;
 
+        self.ctx().fs_usage.check_path(&dest_path)?;
         let table = self.table();
         let d = table.get(&fd)?.dir()?;
         if !d.perms.contains(DirPerms::MUTATE) {
@@ -756,6 +788,8 @@ impl<T: WasiView> HostDescriptor for T {
         fd: Resource<types::Descriptor>,
         offset: types::Filesize,
     ) -> FsResult<Resource<OutputStream>> {
+        let usage = self.ctx().fs_usage.clone();
+
         // Trap if fd lookup fails:
         let f = self.table().get(&fd)?.file()?;
 
@@ -767,7 +801,7 @@ impl<T: WasiView> HostDescriptor for T {
         let clone = std::sync::Arc::clone(&f.file);
 
         // Create a stream view for it.
-        let writer = FileOutputStream::write_at(clone, offset);
+        let writer = FileOutputStream::write_at(clone, offset, usage);
         let writer: OutputStream = Box::new(writer);
 
         // Insert the stream view into the table. Trap if the table is full.
@@ -780,6 +814,8 @@ impl<T: WasiView> HostDescriptor for T {
         &mut self,
         fd: Resource<types::Descriptor>,
     ) -> FsResult<Resource<OutputStream>> {
+        let usage = self.ctx().fs_usage.clone();
+
         // Trap if fd lookup fails:
         let f = self.table().get(&fd)?.file()?;
 
@@ -790,7 +826,7 @@ impl<T: WasiView> HostDescriptor for T {
         let clone = std::sync::Arc::clone(&f.file);
 
         // Create a stream view for it.
-        let appender = FileOutputStream::append(clone);
+        let appender = FileOutputStream::append(clone, usage);
         let appender: OutputStream = Box::new(appender);
 
         // Insert the stream view into the table. Trap if the table is full.
@@ -23,6 +23,7 @@ mod clocks;
 pub mod command;
 mod ctx;
 mod error;
+mod event;
 mod filesystem;
 mod host;
 mod ip_name_lookup;
@@ -42,7 +43,10 @@ mod write_stream;
 pub use self::clocks::{HostMonotonicClock, HostWallClock};
 pub use self::ctx::{WasiCtx, WasiCtxBuilder, WasiView};
 pub use self::error::{I32Exit, TrappableError};
-pub use self::filesystem::{DirPerms, FilePerms, FsError, FsResult};
+pub use self::event::{push_pollable, HostEvent, HostEventHandle};
+#[cfg(unix)]
+pub use self::event::{FdInterest, FdPollable};
+pub use self::filesystem::{DirPerms, FilePerms, FsError, FsQuota, FsResult};
 pub use self::network::{Network, SocketError, SocketResult};
 pub use self::poll::{subscribe, ClosureFuture, MakeFuture, Pollable, PollableFuture, Subscribe};
 pub use self::random::{thread_rng, Deterministic};
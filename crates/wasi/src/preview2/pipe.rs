@@ -206,6 +206,175 @@ impl Subscribe for AsyncReadStream {
     }
 }
 
+/// Provides a [`HostInputStream`] impl reading chunks of bytes sent over a
+/// [`tokio::sync::mpsc`] channel.
+///
+/// Each message received on the channel becomes readable by the guest, and
+/// the stream reports [`StreamError::Closed`] once all senders are dropped
+/// and all buffered messages have been read.
+pub struct ChannelInputStream {
+    closed: bool,
+    buffer: Option<Bytes>,
+    receiver: mpsc::Receiver<Bytes>,
+}
+
+impl ChannelInputStream {
+    /// Create a [`ChannelInputStream`] reading from `receiver`.
+    pub fn new(receiver: mpsc::Receiver<Bytes>) -> Self {
+        ChannelInputStream {
+            closed: false,
+            buffer: None,
+            receiver,
+        }
+    }
+
+    fn take(&mut self, mut bytes: Bytes, size: usize) -> Bytes {
+        let len = bytes.len().min(size);
+        let rest = bytes.split_off(len);
+        if !rest.is_empty() {
+            self.buffer = Some(rest);
+        }
+        bytes
+    }
+}
+
+#[async_trait::async_trait]
+impl HostInputStream for ChannelInputStream {
+    fn read(&mut self, size: usize) -> Result<Bytes, StreamError> {
+        use mpsc::error::TryRecvError;
+
+        if let Some(bytes) = self.buffer.take() {
+            return Ok(self.take(bytes, size));
+        }
+        if self.closed {
+            return Err(StreamError::Closed);
+        }
+        match self.receiver.try_recv() {
+            Ok(bytes) => Ok(self.take(bytes, size)),
+            Err(TryRecvError::Empty) => Ok(Bytes::new()),
+            Err(TryRecvError::Disconnected) => {
+                self.closed = true;
+                Err(StreamError::Closed)
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Subscribe for ChannelInputStream {
+    async fn ready(&mut self) {
+        if self.buffer.is_some() || self.closed {
+            return;
+        }
+        match self.receiver.recv().await {
+            Some(bytes) => self.buffer = Some(bytes),
+            None => self.closed = true,
+        }
+    }
+}
+
+/// Provides a [`HostOutputStream`] impl sending each write as a message over a
+/// [`tokio::sync::mpsc`] channel.
+///
+/// Backpressure is provided by the channel's capacity: the stream is only
+/// ready for writing once a slot has been reserved in the channel, and each
+/// write may contain at most `write_budget` bytes, or any number of bytes if
+/// `write_budget` is `None`. The stream reports [`StreamError::Closed`] once
+/// the receiver is dropped.
+pub struct ChannelOutputStream {
+    write_budget: usize,
+    permit: Option<mpsc::OwnedPermit<Bytes>>,
+    sender: Option<mpsc::Sender<Bytes>>,
+}
+
+impl ChannelOutputStream {
+    /// Create a [`ChannelOutputStream`] sending to `sender`, with each write
+    /// limited to `write_budget` bytes if given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `write_budget` is `Some(0)`, since no write could make
+    /// progress.
+    pub fn new(write_budget: Option<usize>, sender: mpsc::Sender<Bytes>) -> Self {
+        assert!(write_budget != Some(0), "write budget must be non-zero");
+        ChannelOutputStream {
+            write_budget: write_budget.unwrap_or(usize::MAX),
+            permit: None,
+            sender: Some(sender),
+        }
+    }
+}
+
+impl HostOutputStream for ChannelOutputStream {
+    fn write(&mut self, bytes: Bytes) -> Result<(), StreamError> {
+        if bytes.len() > self.write_budget {
+            return Err(StreamError::Trap(anyhow!(
+                "write beyond budget of ChannelOutputStream"
+            )));
+        }
+        if bytes.is_empty() {
+            return Ok(());
+        }
+        match self.permit.take() {
+            Some(permit) => {
+                permit.send(bytes);
+                Ok(())
+            }
+            None => Err(StreamError::Trap(anyhow!(
+                "write not permitted: check_write not called first"
+            ))),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), StreamError> {
+        // Messages are delivered to the channel as soon as they're written.
+        if self.sender.is_none() {
+            return Err(StreamError::Closed);
+        }
+        Ok(())
+    }
+
+    fn check_write(&mut self) -> Result<usize, StreamError> {
+        use mpsc::error::TrySendError;
+
+        if self.permit.is_some() {
+            return Ok(self.write_budget);
+        }
+        let sender = match &self.sender {
+            Some(sender) => sender.clone(),
+            None => return Err(StreamError::Closed),
+        };
+        match sender.try_reserve_owned() {
+            Ok(permit) => {
+                self.permit = Some(permit);
+                Ok(self.write_budget)
+            }
+            Err(TrySendError::Full(_)) => Ok(0),
+            Err(TrySendError::Closed(_)) => {
+                self.sender = None;
+                Err(StreamError::Closed)
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Subscribe for ChannelOutputStream {
+    async fn ready(&mut self) {
+        if self.permit.is_some() {
+            return;
+        }
+        let sender = match &self.sender {
+            Some(sender) => sender.clone(),
+            None => return,
+        };
+        match sender.reserve_owned().await {
+            Ok(permit) => self.permit = Some(permit),
+            Err(_) => self.sender = None,
+        }
+    }
+}
+
 /// An output stream that consumes all input written to it, and is always ready.
 #[derive(Copy, Clone)]
 pub struct SinkOutputStream;
@@ -823,4 +992,82 @@ mod test {
             .expect("ready is ok");
         assert_eq!(permit, 1024);
     }
+
+    #[test_log::test(tokio::test(flavor = "multi_thread"))]
+    async fn channel_input_stream() {
+        let (sender, receiver) = mpsc::channel(2);
+        let mut reader = ChannelInputStream::new(receiver);
+
+        // Nothing has been sent yet.
+        assert!(reader.read(10).unwrap().is_empty());
+        never_resolves(reader.ready()).await;
+
+        sender
+            .send(Bytes::from_static(b"hello world"))
+            .await
+            .unwrap();
+        resolves_immediately(reader.ready()).await;
+        assert_eq!(reader.read(5).unwrap(), Bytes::from_static(b"hello"));
+        assert_eq!(reader.read(10).unwrap(), Bytes::from_static(b" world"));
+
+        // Dropping the sender closes the stream once buffered data is read.
+        sender.send(Bytes::from_static(b"!")).await.unwrap();
+        drop(sender);
+        resolves_immediately(reader.ready()).await;
+        assert_eq!(reader.read(10).unwrap(), Bytes::from_static(b"!"));
+        resolves_immediately(reader.ready()).await;
+        assert!(matches!(reader.read(10), Err(StreamError::Closed)));
+    }
+
+    #[test_log::test(tokio::test(flavor = "multi_thread"))]
+    async fn channel_output_stream() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let mut writer = ChannelOutputStream::new(Some(16), sender);
+
+        // Writes must be permitted first.
+        assert!(writer.write(Bytes::from_static(b"x")).is_err());
+
+        assert_eq!(writer.check_write().unwrap(), 16);
+        writer.write(Bytes::from_static(b"hello")).unwrap();
+        writer.flush().unwrap();
+
+        // The channel is full, so no more writes are permitted until the
+        // receiver catches up.
+        assert_eq!(writer.check_write().unwrap(), 0);
+        never_resolves(writer.ready()).await;
+        assert_eq!(receiver.recv().await.unwrap(), Bytes::from_static(b"hello"));
+        let permit = resolves_immediately(writer.write_ready())
+            .await
+            .expect("ready is ok");
+        assert_eq!(permit, 16);
+
+        // Writes over the budget trap.
+        assert!(matches!(
+            writer.write(Bytes::from(vec![0; 17])),
+            Err(StreamError::Trap(_))
+        ));
+
+        // Dropping the receiver closes the stream.
+        drop(receiver);
+        resolves_immediately(writer.ready()).await;
+        writer.write(Bytes::from_static(b"lost")).unwrap();
+        assert!(matches!(writer.check_write(), Err(StreamError::Closed)));
+    }
+
+    #[test_log::test(tokio::test(flavor = "multi_thread"))]
+    async fn channel_output_stream_unlimited_budget() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let mut writer = ChannelOutputStream::new(None, sender);
+
+        assert_eq!(writer.check_write().unwrap(), usize::MAX);
+        writer.write(Bytes::from(vec![0; 1 << 20])).unwrap();
+        assert_eq!(receiver.recv().await.unwrap().len(), 1 << 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "write budget must be non-zero")]
+    fn channel_output_stream_zero_budget() {
+        let (sender, _receiver) = mpsc::channel(1);
+        ChannelOutputStream::new(Some(0), sender);
+    }
 }
@@ -1,6 +1,9 @@
 use std::any::Any;
+use std::backtrace::Backtrace;
 use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
 use wasmtime::component::Resource;
+use wasmtime::StoreResourceReport;
 
 #[derive(thiserror::Error, Debug)]
 pub enum TableError {
@@ -25,6 +28,7 @@ pub enum TableError {
 pub struct Table {
     map: HashMap<u32, TableEntry>,
     next_key: u32,
+    track_origins: bool,
 }
 
 /// This structure tracks parent and child relationships for a given table entry.
@@ -44,14 +48,25 @@ struct TableEntry {
     parent: Option<u32>,
     /// The indicies of any children of this entry.
     children: BTreeSet<u32>,
+    /// The name of the type of the entry, for `Table::report_resources`.
+    type_name: &'static str,
+    /// Where the entry was inserted, if known.
+    created_at: Option<Arc<Backtrace>>,
 }
 
 impl TableEntry {
-    fn new(entry: Box<dyn Any + Send + Sync>, parent: Option<u32>) -> Self {
+    fn new(
+        entry: Box<dyn Any + Send + Sync>,
+        type_name: &'static str,
+        parent: Option<u32>,
+        created_at: Option<Arc<Backtrace>>,
+    ) -> Self {
         Self {
             entry,
             parent,
             children: BTreeSet::new(),
+            type_name,
+            created_at,
         }
     }
     fn add_child(&mut self, child: u32) {
@@ -74,16 +89,37 @@ impl Table {
             // Once we have a full implementation of resources, this confusion should hopefully be
             // impossible :)
             next_key: 3,
+            track_origins: false,
         }
     }
 
+    /// Configures whether entries record a backtrace of where they were
+    /// inserted, which [`Table::report_resources`] then includes.
+    ///
+    /// This is disabled by default since capturing a backtrace on every insert
+    /// is expensive; enable it while tracking down a leak.
+    pub fn track_origins(&mut self, enabled: bool) {
+        self.track_origins = enabled;
+    }
+
+    fn capture_origin(&self) -> Option<Arc<Backtrace>> {
+        self.track_origins.then(StoreResourceReport::capture_origin)
+    }
+
     /// Inserts a new value `T` into this table, returning a corresponding
     /// `Resource<T>` which can be used to refer to it after it was inserted.
     pub fn push<T>(&mut self, entry: T) -> Result<Resource<T>, TableError>
     where
         T: Send + Sync + 'static,
     {
-        let idx = self.push_(TableEntry::new(Box::new(entry), None))?;
+        let type_name = std::any::type_name::<T>();
+        let created_at = self.capture_origin();
+        let idx = self.push_(TableEntry::new(
+            Box::new(entry),
+            type_name,
+            None,
+            created_at,
+        ))?;
         Ok(Resource::new_own(idx))
     }
 
@@ -131,19 +167,22 @@ impl Table {
         T: Send + Sync + 'static,
         U: 'static,
     {
-        let idx = self.push_child_(Box::new(entry), parent.rep())?;
+        let type_name = std::any::type_name::<T>();
+        let idx = self.push_child_(Box::new(entry), type_name, parent.rep())?;
         Ok(Resource::new_own(idx))
     }
 
     fn push_child_(
         &mut self,
         entry: Box<dyn Any + Send + Sync>,
+        type_name: &'static str,
         parent: u32,
     ) -> Result<u32, TableError> {
         if !self.map.contains_key(&parent) {
             return Err(TableError::NotPresent);
         }
-        let child = self.push_(TableEntry::new(entry, Some(parent)))?;
+        let created_at = self.capture_origin();
+        let child = self.push_(TableEntry::new(entry, type_name, Some(parent), created_at))?;
         self.map
             .get_mut(&parent)
             .expect("parent existence assured above")
@@ -249,6 +288,24 @@ impl Table {
             child.entry.as_ref()
         }))
     }
+
+    /// Adds every entry of this table, such as open descriptors and streams,
+    /// to `report` to help track down leaked resources.
+    ///
+    /// With [`Table::track_origins`] enabled, each entry also records where it
+    /// was inserted into the table.
+    pub fn report_resources(&self, report: &mut StoreResourceReport) {
+        let mut keys = self.map.keys().copied().collect::<Vec<_>>();
+        keys.sort_unstable();
+        for key in keys {
+            let entry = &self.map[&key];
+            report.push(
+                "wasi table entry",
+                format!("{key}: {}", entry.type_name),
+                entry.created_at.clone(),
+            );
+        }
+    }
 }
 
 impl Default for Table {
@@ -216,3 +216,64 @@ async fn api_reactor() -> Result<()> {
 
     Ok(())
 }
+
+#[test_log::test(tokio::test(flavor = "multi_thread"))]
+async fn open_at_over_quota_does_not_create() -> Result<()> {
+    use filesystem::{DescriptorFlags, HostDescriptor, Modes, OpenFlags, PathFlags};
+    use wasmtime_wasi::preview2::bindings::wasi::filesystem::preopens::Host as _;
+
+    let dir = tempfile::tempdir()?;
+    std::fs::write(dir.path().join("existing.txt"), b"contents")?;
+
+    let open_dir = Dir::open_ambient_dir(dir.path(), ambient_authority())?;
+    let wasi = WasiCtxBuilder::new()
+        .preopened_dir(open_dir, DirPerms::all(), FilePerms::all(), "/")
+        .fs_quota(preview2::FsQuota {
+            max_open_descriptors: Some(1),
+            ..preview2::FsQuota::default()
+        })
+        .build();
+    let mut ctx = CommandCtx {
+        table: Table::new(),
+        wasi,
+    };
+    let (preopen, _) = ctx.get_directories()?.pop().unwrap();
+    let preopen_rep = preopen.rep();
+
+    // The first open takes the only descriptor slot.
+    let first = ctx
+        .open_at(
+            wasmtime::component::Resource::new_borrow(preopen_rep),
+            PathFlags::empty(),
+            "first.txt".to_string(),
+            OpenFlags::CREATE,
+            DescriptorFlags::READ | DescriptorFlags::WRITE,
+            Modes::empty(),
+        )
+        .await;
+    assert!(first.is_ok());
+
+    // Over the quota neither a new file is created nor an existing one
+    // truncated.
+    for (path, oflags) in [
+        ("second.txt", OpenFlags::CREATE),
+        ("existing.txt", OpenFlags::TRUNCATE),
+    ] {
+        let err = ctx
+            .open_at(
+                wasmtime::component::Resource::new_borrow(preopen_rep),
+                PathFlags::empty(),
+                path.to_string(),
+                oflags,
+                DescriptorFlags::READ | DescriptorFlags::WRITE,
+                Modes::empty(),
+            )
+            .await
+            .err()
+            .expect("open over the quota should fail");
+        assert_eq!(err.downcast()?, filesystem::ErrorCode::InsufficientMemory);
+    }
+    assert!(!dir.path().join("second.txt").exists());
+    assert_eq!(std::fs::read(dir.path().join("existing.txt"))?, b"contents");
+    Ok(())
+}
@@ -0,0 +1,355 @@
+//! Engine-wide limit on concurrent instantiations and compilations.
+//!
+//! See [`Config::max_concurrent_instantiations`](crate::Config::max_concurrent_instantiations)
+//! for an overview.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::ops::Bound;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::task::Waker;
+use std::time::{Duration, Instant};
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Statistics about the admission of instantiations and compilations into an
+/// [`Engine`](crate::Engine) which limits their concurrency.
+///
+/// Returned by [`Engine::admission_stats`](crate::Engine::admission_stats).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AdmissionStats {
+    /// The number of instantiations admitted so far.
+    pub instantiations: u64,
+    /// The number of compilations admitted so far.
+    pub compilations: u64,
+    /// How many of the admitted requests had to wait in the queue.
+    pub queued: u64,
+    /// The total time admitted requests have spent waiting in the queue.
+    pub total_queue_time: Duration,
+    /// The longest time a single admitted request has spent waiting in the
+    /// queue.
+    pub max_queue_time: Duration,
+    /// The number of instantiations and compilations currently running.
+    pub active: usize,
+    /// The number of requests currently waiting to be admitted.
+    pub waiting: usize,
+}
+
+/// What an admission request is for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum AdmissionKind {
+    Instantiation,
+    Compilation,
+}
+
+/// Who an admission request is made on behalf of. Waiting requests are
+/// admitted round-robin between tenants so that one tenant queueing many
+/// requests can't starve the others.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum Tenant {
+    /// Compilations, which aren't associated with a store.
+    Engine,
+    /// A tenant configured with `Store::set_admission_tenant`.
+    Explicit(u64),
+    /// A store without an explicit tenant, identified by its id.
+    Store(u64),
+}
+
+pub(crate) struct AdmissionControl {
+    limit: usize,
+    state: Mutex<State>,
+    admitted: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    active: usize,
+    /// Tickets of waiting requests, per tenant. Tenants without waiting
+    /// requests are removed.
+    queues: BTreeMap<Tenant, VecDeque<u64>>,
+    waiters: HashMap<u64, Waiter>,
+    /// The tenant most recently admitted, where the round-robin scan for the
+    /// next one starts.
+    last_admitted: Option<Tenant>,
+    next_ticket: u64,
+    stats: AdmissionStats,
+}
+
+struct Waiter {
+    kind: AdmissionKind,
+    enqueued: Instant,
+    admitted: bool,
+    waker: Option<Waker>,
+}
+
+impl AdmissionControl {
+    pub(crate) fn new(limit: usize) -> AdmissionControl {
+        assert!(limit > 0);
+        AdmissionControl {
+            limit,
+            state: Mutex::new(State::default()),
+            admitted: Condvar::new(),
+        }
+    }
+
+    pub(crate) fn stats(&self) -> AdmissionStats {
+        let state = self.state.lock().unwrap();
+        AdmissionStats {
+            active: state.active,
+            waiting: state.waiters.values().filter(|w| !w.admitted).count(),
+            ..state.stats.clone()
+        }
+    }
+
+    /// Blocks the current thread until the request is admitted.
+    pub(crate) fn acquire(&self, tenant: Tenant, kind: AdmissionKind) -> AdmissionPermit<'_> {
+        let mut state = self.state.lock().unwrap();
+        let ticket = match self.try_admit(&mut state, tenant, kind) {
+            Some(permit) => return permit,
+            None => state.enqueue(tenant, kind),
+        };
+        loop {
+            if state.waiters[&ticket].admitted {
+                state.waiters.remove(&ticket);
+                return AdmissionPermit { control: self };
+            }
+            state = self.admitted.wait(state).unwrap();
+        }
+    }
+
+    /// Returns a future which resolves once the request is admitted.
+    #[cfg(feature = "async")]
+    pub(crate) fn acquire_async(&self, tenant: Tenant, kind: AdmissionKind) -> Acquire<'_> {
+        Acquire {
+            control: self,
+            tenant,
+            kind,
+            ticket: None,
+            done: false,
+        }
+    }
+
+    /// Admits the request right away if there's capacity and nobody else is
+    /// waiting.
+    fn try_admit(
+        &self,
+        state: &mut State,
+        tenant: Tenant,
+        kind: AdmissionKind,
+    ) -> Option<AdmissionPermit<'_>> {
+        if state.active < self.limit && state.queues.is_empty() {
+            state.active += 1;
+            state.last_admitted = Some(tenant);
+            state.record(kind, Duration::ZERO, false);
+            Some(AdmissionPermit { control: self })
+        } else {
+            None
+        }
+    }
+
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.active -= 1;
+        self.dispatch(state);
+    }
+
+    /// Admits waiting requests, round-robin between tenants, while there's
+    /// capacity.
+    fn dispatch(&self, mut state: MutexGuard<'_, State>) {
+        let mut any = false;
+        while state.active < self.limit {
+            let tenant = match state.next_tenant() {
+                Some(tenant) => tenant,
+                None => break,
+            };
+            let queue = state.queues.get_mut(&tenant).unwrap();
+            let ticket = queue.pop_front().unwrap();
+            if queue.is_empty() {
+                state.queues.remove(&tenant);
+            }
+            state.active += 1;
+            state.last_admitted = Some(tenant);
+
+            let waiter = state.waiters.get_mut(&ticket).unwrap();
+            waiter.admitted = true;
+            let kind = waiter.kind;
+            let queue_time = waiter.enqueued.elapsed();
+            if let Some(waker) = waiter.waker.take() {
+                waker.wake();
+            }
+            state.record(kind, queue_time, true);
+            any = true;
+        }
+        drop(state);
+        if any {
+            self.admitted.notify_all();
+        }
+    }
+}
+
+impl State {
+    fn enqueue(&mut self, tenant: Tenant, kind: AdmissionKind) -> u64 {
+        let ticket = self.next_ticket;
+        self.next_ticket += 1;
+        self.queues.entry(tenant).or_default().push_back(ticket);
+        self.waiters.insert(
+            ticket,
+            Waiter {
+                kind,
+                enqueued: Instant::now(),
+                admitted: false,
+                waker: None,
+            },
+        );
+        ticket
+    }
+
+    /// Returns the first tenant with waiting requests after the one admitted
+    /// most recently, wrapping around.
+    fn next_tenant(&self) -> Option<Tenant> {
+        let after = match self.last_admitted {
+            Some(last) => self
+                .queues
+                .range((Bound::Excluded(last), Bound::Unbounded))
+                .next(),
+            None => None,
+        };
+        after
+            .or_else(|| self.queues.iter().next())
+            .map(|(tenant, _)| *tenant)
+    }
+
+    fn record(&mut self, kind: AdmissionKind, queue_time: Duration, queued: bool) {
+        match kind {
+            AdmissionKind::Instantiation => self.stats.instantiations += 1,
+            AdmissionKind::Compilation => self.stats.compilations += 1,
+        }
+        if queued {
+            self.stats.queued += 1;
+            self.stats.total_queue_time += queue_time;
+            self.stats.max_queue_time = self.stats.max_queue_time.max(queue_time);
+        }
+    }
+}
+
+/// Capacity held by an admitted instantiation or compilation, released when
+/// dropped.
+pub(crate) struct AdmissionPermit<'a> {
+    control: &'a AdmissionControl,
+}
+
+impl Drop for AdmissionPermit<'_> {
+    fn drop(&mut self) {
+        self.control.release();
+    }
+}
+
+/// Future returned by [`AdmissionControl::acquire_async`].
+#[cfg(feature = "async")]
+pub(crate) struct Acquire<'a> {
+    control: &'a AdmissionControl,
+    tenant: Tenant,
+    kind: AdmissionKind,
+    ticket: Option<u64>,
+    done: bool,
+}
+
+#[cfg(feature = "async")]
+impl<'a> Future for Acquire<'a> {
+    type Output = AdmissionPermit<'a>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<AdmissionPermit<'a>> {
+        let control = self.control;
+        let mut state = control.state.lock().unwrap();
+        let ticket = match self.ticket {
+            Some(ticket) => ticket,
+            None => {
+                if let Some(permit) = control.try_admit(&mut state, self.tenant, self.kind) {
+                    self.done = true;
+                    return Poll::Ready(permit);
+                }
+                let ticket = state.enqueue(self.tenant, self.kind);
+                self.ticket = Some(ticket);
+                ticket
+            }
+        };
+        let waiter = state.waiters.get_mut(&ticket).unwrap();
+        if waiter.admitted {
+            state.waiters.remove(&ticket);
+            self.done = true;
+            return Poll::Ready(AdmissionPermit { control });
+        }
+        waiter.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+#[cfg(feature = "async")]
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        let ticket = match self.ticket {
+            Some(ticket) if !self.done => ticket,
+            _ => return,
+        };
+        let mut state = self.control.state.lock().unwrap();
+        let waiter = state.waiters.remove(&ticket).unwrap();
+        if waiter.admitted {
+            // Admitted but never picked up: hand the capacity to someone
+            // else.
+            state.active -= 1;
+            self.control.dispatch(state);
+        } else if let Some(queue) = state.queues.get_mut(&self.tenant) {
+            queue.retain(|t| *t != ticket);
+            if queue.is_empty() {
+                state.queues.remove(&self.tenant);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn waiting_requests_are_admitted_round_robin() {
+        let control = Arc::new(AdmissionControl::new(1));
+        let permit = control.acquire(Tenant::Store(0), AdmissionKind::Instantiation);
+
+        // Tenant 1 queues three requests before tenant 2 queues one. Tenant 2
+        // should still be admitted second, not fourth.
+        let (tx, rx) = mpsc::channel();
+        let mut threads = Vec::new();
+        for tenant in [1, 1, 1, 2] {
+            let control = control.clone();
+            let tx = tx.clone();
+            threads.push(thread::spawn(move || {
+                let _permit =
+                    control.acquire(Tenant::Explicit(tenant), AdmissionKind::Instantiation);
+                tx.send(tenant).unwrap();
+            }));
+            while control.stats().waiting < threads.len() {
+                thread::yield_now();
+            }
+        }
+
+        drop(permit);
+        let order: Vec<u64> = rx.iter().take(4).collect();
+        assert_eq!(order, [1, 2, 1, 1]);
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let stats = control.stats();
+        assert_eq!(stats.instantiations, 5);
+        assert_eq!(stats.queued, 4);
+        assert_eq!(stats.active, 0);
+        assert_eq!(stats.waiting, 0);
+    }
+}
@@ -0,0 +1,213 @@
+//! Profiling of the memory guests allocate within a [`Store`](crate::Store).
+
+use crate::store::StoreOpaque;
+use crate::WasmBacktrace;
+use std::num::NonZeroU32;
+use wasmtime_runtime::TableElementType;
+
+/// Records the allocations guests make within a [`Store`](crate::Store).
+///
+/// An allocation profiler records an [`AllocationEvent`] every time a linear
+/// memory or table in the store grows, whether through `memory.grow` or
+/// `table.grow` in wasm or through the embedding API, along with a sample of
+/// the wasm backtraces of the code growing them. This helps find out which
+/// code is responsible for instances using a lot of memory.
+///
+/// Only the first [`max_events`](AllocationProfiler::max_events) events are
+/// kept so that a long-running store can't grow the profile without bound;
+/// later allocations are still counted in
+/// [`totals`](AllocationProfiler::totals).
+///
+/// Installed with
+/// [`Store::set_allocation_profiler`](crate::Store::set_allocation_profiler)
+/// and retrieved with
+/// [`Store::take_allocation_profiler`](crate::Store::take_allocation_profiler).
+#[derive(Debug)]
+pub struct AllocationProfiler {
+    backtrace_interval: Option<NonZeroU32>,
+    until_backtrace: u32,
+    max_events: usize,
+    events: Vec<AllocationEvent>,
+    dropped_events: u64,
+    totals: [AllocationTotals; 3],
+}
+
+impl Default for AllocationProfiler {
+    fn default() -> AllocationProfiler {
+        AllocationProfiler {
+            backtrace_interval: None,
+            until_backtrace: 0,
+            max_events: AllocationProfiler::DEFAULT_MAX_EVENTS,
+            events: Vec::new(),
+            dropped_events: 0,
+            totals: Default::default(),
+        }
+    }
+}
+
+/// What kind of storage an [`AllocationEvent`] allocated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AllocationKind {
+    /// A linear memory grew; sizes are in bytes.
+    Memory,
+    /// A table of `funcref`s grew; sizes are in elements.
+    FuncRefTable,
+    /// A table of `externref`s, which are managed by the store's garbage
+    /// collector, grew; sizes are in elements.
+    ExternRefTable,
+}
+
+/// A single allocation recorded by an [`AllocationProfiler`].
+#[derive(Debug)]
+pub struct AllocationEvent {
+    kind: AllocationKind,
+    old_size: u64,
+    new_size: u64,
+    backtrace: Option<WasmBacktrace>,
+}
+
+/// The total allocations of one [`AllocationKind`], as returned by
+/// [`AllocationProfiler::totals`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocationTotals {
+    /// The number of allocations.
+    pub count: u64,
+    /// The combined size of all allocations, in bytes for memories and in
+    /// elements for tables.
+    pub size: u64,
+}
+
+impl AllocationProfiler {
+    /// The number of events kept by default, see
+    /// [`max_events`](AllocationProfiler::max_events).
+    pub const DEFAULT_MAX_EVENTS: usize = 10_000;
+
+    /// Creates a new profiler which doesn't capture any backtraces.
+    pub fn new() -> AllocationProfiler {
+        AllocationProfiler::default()
+    }
+
+    /// Captures the wasm backtrace of every `interval`th allocation.
+    ///
+    /// Capturing a backtrace walks the stack, so sampling only some
+    /// allocations keeps the overhead of profiling programs which allocate
+    /// frequently down. An `interval` of 1 captures a backtrace for every
+    /// allocation and 0 disables backtraces, which is the default.
+    ///
+    /// Backtraces are captured regardless of
+    /// [`Config::wasm_backtrace`](crate::Config::wasm_backtrace).
+    pub fn sample_backtraces(mut self, interval: u32) -> AllocationProfiler {
+        self.backtrace_interval = NonZeroU32::new(interval);
+        self.until_backtrace = 0;
+        self
+    }
+
+    /// Keeps at most `max` events, defaulting to
+    /// [`DEFAULT_MAX_EVENTS`](AllocationProfiler::DEFAULT_MAX_EVENTS).
+    ///
+    /// Once the limit is reached further allocations are only added to the
+    /// [`totals`](AllocationProfiler::totals) and counted by
+    /// [`dropped_events`](AllocationProfiler::dropped_events), and no more
+    /// backtraces are captured.
+    pub fn max_events(mut self, max: usize) -> AllocationProfiler {
+        self.max_events = max;
+        self
+    }
+
+    /// Returns the allocations recorded so far, in the order they happened.
+    pub fn events(&self) -> &[AllocationEvent] {
+        &self.events
+    }
+
+    /// Returns the number of allocations which weren't kept in
+    /// [`events`](AllocationProfiler::events) because the limit set with
+    /// [`max_events`](AllocationProfiler::max_events) was reached.
+    pub fn dropped_events(&self) -> u64 {
+        self.dropped_events
+    }
+
+    /// Returns the total allocations of each kind recorded so far, including
+    /// those dropped from [`events`](AllocationProfiler::events).
+    pub fn totals(&self, kind: AllocationKind) -> AllocationTotals {
+        self.totals[kind as usize]
+    }
+
+    pub(crate) fn memory_grown(&mut self, store: &StoreOpaque, old: usize, new: usize) {
+        self.record(store, AllocationKind::Memory, old as u64, new as u64);
+    }
+
+    pub(crate) fn table_grown(
+        &mut self,
+        store: &StoreOpaque,
+        element: TableElementType,
+        old: u32,
+        new: u32,
+    ) {
+        let kind = match element {
+            TableElementType::Func => AllocationKind::FuncRefTable,
+            TableElementType::Extern => AllocationKind::ExternRefTable,
+        };
+        self.record(store, kind, old.into(), new.into());
+    }
+
+    fn record(&mut self, store: &StoreOpaque, kind: AllocationKind, old: u64, new: u64) {
+        debug_assert!(new > old);
+        let totals = &mut self.totals[kind as usize];
+        totals.count += 1;
+        totals.size += new - old;
+
+        if self.events.len() >= self.max_events {
+            self.dropped_events += 1;
+            return;
+        }
+
+        let backtrace = match self.backtrace_interval {
+            Some(interval) if self.until_backtrace == 0 => {
+                self.until_backtrace = interval.get() - 1;
+                Some(WasmBacktrace::force_capture_opaque(store))
+            }
+            Some(_) => {
+                self.until_backtrace -= 1;
+                None
+            }
+            None => None,
+        };
+        self.events.push(AllocationEvent {
+            kind,
+            old_size: old,
+            new_size: new,
+            backtrace,
+        });
+    }
+}
+
+impl AllocationEvent {
+    /// Returns what kind of storage grew.
+    pub fn kind(&self) -> AllocationKind {
+        self.kind
+    }
+
+    /// Returns the size of the memory or table before it grew.
+    pub fn old_size(&self) -> u64 {
+        self.old_size
+    }
+
+    /// Returns the size of the memory or table after it grew.
+    pub fn new_size(&self) -> u64 {
+        self.new_size
+    }
+
+    /// Returns by how much the memory or table grew.
+    pub fn delta(&self) -> u64 {
+        self.new_size - self.old_size
+    }
+
+    /// Returns the wasm backtrace of the code which grew the memory or
+    /// table, if one was sampled for this allocation.
+    ///
+    /// The backtrace is empty if the allocation was made by the embedder
+    /// rather than by wasm.
+    pub fn backtrace(&self) -> Option<&WasmBacktrace> {
+        self.backtrace.as_ref()
+    }
+}
@@ -0,0 +1,35 @@
+//! Tracing of the basic blocks executed by modules compiled with
+//! [`Config::block_trace`](crate::Config::block_trace).
+
+/// The entry into a basic block reported to the hook installed with
+/// [`Store::block_trace`](crate::Store::block_trace).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BlockEntry {
+    func_index: u32,
+    wasm_offset: u32,
+}
+
+impl BlockEntry {
+    pub(crate) fn new(func_index: u32, wasm_offset: u32) -> BlockEntry {
+        BlockEntry {
+            func_index,
+            wasm_offset,
+        }
+    }
+
+    /// Returns the index of the function containing the block, in the
+    /// module's function index space, which includes imported functions.
+    pub fn func_index(&self) -> u32 {
+        self.func_index
+    }
+
+    /// Returns the offset of the block's first instruction in the original
+    /// wasm module.
+    ///
+    /// Together with [`BlockEntry::func_index`] this identifies the block
+    /// within its module, so pairs of consecutive entries can be used as
+    /// edges for coverage-guided fuzzing.
+    pub fn wasm_offset(&self) -> u32 {
+        self.wasm_offset
+    }
+}
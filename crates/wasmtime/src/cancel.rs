@@ -0,0 +1,169 @@
+//! Cancellation signals for the host futures of an async guest call.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A signal that an async call into WebAssembly has been cancelled.
+///
+/// Each top-level async call into a [`Store`](crate::Store), such as
+/// [`Func::call_async`](crate::Func::call_async), gets its own token which
+/// host functions can retrieve with
+/// [`Caller::cancel_token`](crate::Caller::cancel_token) and hand to any I/O
+/// they start. The token is cancelled when:
+///
+/// * the future returned for the call is dropped before it completes,
+/// * the guest traps because its epoch deadline was reached or it ran out of
+///   fuel, or
+/// * [`CancelToken::cancel`] is called explicitly.
+///
+/// Once cancelled, a host future which is currently blocking the guest is
+/// dropped and the call fails with [`Trap::Interrupt`](crate::Trap::Interrupt),
+/// with [`CallCancelled`](crate::CallCancelled) as context.
+/// Work the host future spawned elsewhere, for example on a runtime's task
+/// pool, can wait on [`CancelToken::cancelled`] to abort promptly instead of
+/// running to completion unobserved.
+///
+/// Tokens are cheap to clone and clones share their state.
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    inner: Arc<TokenInner>,
+}
+
+#[derive(Default)]
+struct TokenInner {
+    cancelled: AtomicBool,
+    wakers: Mutex<Wakers>,
+}
+
+/// The wakers of everything currently waiting on a token.
+///
+/// Each waiter owns a key so that polling it again replaces its waker rather
+/// than adding another, and so that it can remove its waker once it stops
+/// waiting. Otherwise a token outliving its call would accumulate a waker
+/// for every poll of every waiter.
+#[derive(Default)]
+struct Wakers {
+    next_key: u64,
+    waiting: HashMap<u64, Waker>,
+}
+
+impl CancelToken {
+    /// Creates a new token which hasn't been cancelled.
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /// Cancels the call associated with this token, waking up anything
+    /// waiting on it.
+    pub fn cancel(&self) {
+        if self.inner.cancelled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let wakers = std::mem::take(&mut self.inner.wakers.lock().unwrap().waiting);
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    /// Returns whether this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns a future which resolves once this token is cancelled.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            registration: Registration::new(self.clone()),
+        }
+    }
+}
+
+/// A waiter on a [`CancelToken`], whose waker is unregistered when this is
+/// dropped.
+pub(crate) struct Registration {
+    token: CancelToken,
+    key: Option<u64>,
+}
+
+impl Registration {
+    pub(crate) fn new(token: CancelToken) -> Registration {
+        Registration { token, key: None }
+    }
+
+    /// Arranges for `waker` to be woken when the token is cancelled,
+    /// replacing the waker from any previous call.
+    ///
+    /// Returns `true` if the token is already cancelled, in which case the
+    /// waker isn't registered.
+    pub(crate) fn register(&mut self, waker: &Waker) -> bool {
+        if self.token.is_cancelled() {
+            return true;
+        }
+        let mut wakers = self.token.inner.wakers.lock().unwrap();
+        // Check again with the lock held so a concurrent `cancel` can't take
+        // the wakers between our check above and the insertion below.
+        if self.token.is_cancelled() {
+            return true;
+        }
+        let key = *self.key.get_or_insert_with(|| {
+            wakers.next_key += 1;
+            wakers.next_key
+        });
+        let registered = wakers.waiting.entry(key).or_insert_with(|| waker.clone());
+        if !registered.will_wake(waker) {
+            *registered = waker.clone();
+        }
+        false
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.token.inner.wakers.lock().unwrap().waiting.remove(&key);
+        }
+    }
+}
+
+/// Future returned by [`CancelToken::cancelled`].
+pub struct Cancelled {
+    registration: Registration,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.registration.register(cx.waker()) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Cancels a token when dropped unless [`CancelOnDrop::disarm`] is called
+/// first, used to cancel a call whose future is dropped mid-flight.
+pub(crate) struct CancelOnDrop(Option<CancelToken>);
+
+impl CancelOnDrop {
+    pub(crate) fn new(token: CancelToken) -> CancelOnDrop {
+        CancelOnDrop(Some(token))
+    }
+
+    pub(crate) fn disarm(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some(token) = &self.0 {
+            token.cancel();
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! Checking the host imports of modules and components against a policy
+//! supplied by the embedder.
+//!
+//! See [`CapabilityPolicy`] for an overview.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use wasmtime_environ::CapabilityManifest;
+
+pub use wasmtime_environ::CAPABILITIES_SECTION;
+
+/// A host capability, named by the module and field of an import.
+///
+/// For components the module is the name of a top-level import, such as
+/// `wasi:filesystem/types`, and the field is the name of an item within that
+/// imported instance.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Capability {
+    module: String,
+    name: Option<String>,
+}
+
+impl Capability {
+    fn new(module: &str, name: Option<&str>) -> Capability {
+        Capability {
+            module: module.to_string(),
+            name: name.map(|s| s.to_string()),
+        }
+    }
+
+    /// Returns the module this capability is imported from.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Returns the field this capability refers to, or `None` if it refers to
+    /// every item of [`Capability::module`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "`{}::{}`", self.module, name),
+            None => write!(f, "`{}::*`", self.module),
+        }
+    }
+}
+
+/// A set of host capabilities which modules and components instantiated by a
+/// [`Linker`](crate::Linker) are permitted to use.
+///
+/// A module or component may declare the capabilities it needs in a
+/// [`CAPABILITIES_SECTION`] custom section. When a policy is configured with
+/// [`Linker::capability_policy`](crate::Linker::capability_policy) then
+/// instantiation fails with a [`CapabilityDenied`] error if:
+///
+/// * the manifest declares a capability the policy doesn't permit,
+/// * the module imports a capability the policy doesn't permit, or
+/// * the module has a manifest, or one is required with
+///   [`CapabilityPolicy::require_manifest`], and it imports a capability its
+///   manifest doesn't declare.
+///
+/// These checks happen before imports are resolved, so a denied capability is
+/// reported even if the linker defines it.
+#[derive(Clone, Debug, Default)]
+pub struct CapabilityPolicy {
+    allowed: HashMap<String, Option<HashSet<String>>>,
+    require_manifest: bool,
+}
+
+impl CapabilityPolicy {
+    /// Creates a policy which permits no capabilities.
+    pub fn new() -> CapabilityPolicy {
+        CapabilityPolicy::default()
+    }
+
+    /// Permits the single item `name` of `module`.
+    pub fn allow(&mut self, module: &str, name: &str) -> &mut Self {
+        if let Some(names) = self
+            .allowed
+            .entry(module.to_string())
+            .or_insert_with(|| Some(HashSet::new()))
+        {
+            names.insert(name.to_string());
+        }
+        self
+    }
+
+    /// Permits every item of `module`.
+    pub fn allow_module(&mut self, module: &str) -> &mut Self {
+        self.allowed.insert(module.to_string(), None);
+        self
+    }
+
+    /// Configures whether modules and components without a capability
+    /// manifest are rejected.
+    ///
+    /// When enabled a missing manifest is treated as one which declares
+    /// nothing, so only modules and components without any imports can be
+    /// instantiated without one.
+    ///
+    /// By default this is `false`.
+    pub fn require_manifest(&mut self, require: bool) -> &mut Self {
+        self.require_manifest = require;
+        self
+    }
+
+    fn permits(&self, module: &str, name: Option<&str>) -> bool {
+        match (self.allowed.get(module), name) {
+            (Some(None), _) => true,
+            (Some(Some(names)), Some(name)) => names.contains(name),
+            _ => false,
+        }
+    }
+
+    /// Checks the `manifest` and `imports` of a module or component against
+    /// this policy.
+    pub(crate) fn check<'a>(
+        &self,
+        manifest: Option<&CapabilityManifest>,
+        imports: impl IntoIterator<Item = (&'a str, Option<&'a str>)>,
+    ) -> Result<(), CapabilityDenied> {
+        let mut denials = Vec::new();
+        let mut deny = |module: &str, name: Option<&str>, reason: DenialReason| {
+            let capability = Capability::new(module, name);
+            if !denials
+                .iter()
+                .any(|d: &CapabilityDenial| d.capability == capability)
+            {
+                denials.push(CapabilityDenial { capability, reason });
+            }
+        };
+
+        let declared = match manifest {
+            Some(manifest) => {
+                for (module, name) in manifest.entries.iter() {
+                    if !self.permits(module, name.as_deref()) {
+                        deny(module, name.as_deref(), DenialReason::NotPermitted);
+                    }
+                }
+                Some(&manifest.entries[..])
+            }
+            None if self.require_manifest => Some(&[][..]),
+            None => None,
+        };
+
+        for (module, name) in imports {
+            if !self.permits(module, name) {
+                deny(module, name, DenialReason::NotPermitted);
+                continue;
+            }
+            let covered = declared.map_or(true, |entries| {
+                entries
+                    .iter()
+                    .any(|(m, n)| m == module && (n.is_none() || n.as_deref() == name))
+            });
+            if !covered {
+                deny(module, name, DenialReason::Undeclared);
+            }
+        }
+
+        if denials.is_empty() {
+            Ok(())
+        } else {
+            Err(CapabilityDenied { denials })
+        }
+    }
+}
+
+/// Why a [`Capability`] was denied.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DenialReason {
+    /// The capability is not permitted by the [`CapabilityPolicy`].
+    NotPermitted,
+    /// The capability is imported but not declared in the capability manifest.
+    Undeclared,
+}
+
+/// A single capability which failed a [`CapabilityPolicy`] check.
+#[derive(Clone, Debug)]
+pub struct CapabilityDenial {
+    capability: Capability,
+    reason: DenialReason,
+}
+
+impl CapabilityDenial {
+    /// Returns the capability which was denied.
+    pub fn capability(&self) -> &Capability {
+        &self.capability
+    }
+
+    /// Returns why the capability was denied.
+    pub fn reason(&self) -> DenialReason {
+        self.reason
+    }
+}
+
+/// Error for a module or component which uses capabilities its
+/// [`CapabilityPolicy`] does not allow.
+///
+/// Returned - wrapped in an [`anyhow::Error`] - by
+/// [`Linker::instantiate`](crate::Linker::instantiate) and related methods when
+/// a policy is configured with
+/// [`Linker::capability_policy`](crate::Linker::capability_policy).
+#[derive(Clone, Debug)]
+pub struct CapabilityDenied {
+    denials: Vec<CapabilityDenial>,
+}
+
+impl CapabilityDenied {
+    /// Returns every capability which was denied, in the order they were
+    /// found.
+    pub fn denials(&self) -> &[CapabilityDenial] {
+        &self.denials
+    }
+}
+
+impl fmt::Display for CapabilityDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "capability policy denied instantiation:")?;
+        for denial in self.denials.iter() {
+            match denial.reason {
+                DenialReason::NotPermitted => {
+                    write!(f, "\n    {} is not permitted", denial.capability)?
+                }
+                DenialReason::Undeclared => write!(
+                    f,
+                    "\n    {} is imported but not declared in the capability manifest",
+                    denial.capability
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for CapabilityDenied {}
@@ -0,0 +1,282 @@
+//! Progress reporting and cancellation for in-flight compilations.
+
+use crate::{Engine, Module};
+use anyhow::Result;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// Observes and controls the compilation of a module.
+///
+/// A monitor is passed to [`Module::from_binary_with_monitor`] or
+/// [`Engine::compile_module_in_background`]. It reports progress as each unit
+/// of compilation work, such as a function or a trampoline, finishes and lets
+/// the compilation be cancelled from any thread with
+/// [`CompileMonitor::cancel`].
+///
+/// Cancellation is cooperative: functions which are already being compiled
+/// finish, but no new functions are started and the compilation fails with a
+/// [`CompilationCancelled`] error.
+///
+/// Monitors are cheap to clone and clones share their state.
+#[derive(Clone, Default)]
+pub struct CompileMonitor {
+    inner: Arc<MonitorInner>,
+}
+
+#[derive(Default)]
+struct MonitorInner {
+    cancelled: AtomicBool,
+    total: AtomicUsize,
+    completed: AtomicUsize,
+    on_progress: Option<Box<dyn Fn(CompileProgress) + Send + Sync>>,
+}
+
+/// The progress of a compilation, as reported to a [`CompileMonitor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompileProgress {
+    /// The number of units of compilation work which have finished.
+    pub completed: usize,
+    /// The total number of units of compilation work, or zero if compilation
+    /// has not yet reached the point where this is known.
+    pub total: usize,
+}
+
+impl CompileMonitor {
+    /// Creates a new monitor which doesn't report progress.
+    pub fn new() -> CompileMonitor {
+        CompileMonitor::default()
+    }
+
+    /// Creates a new monitor which calls `on_progress` each time a unit of
+    /// compilation work finishes.
+    ///
+    /// The callback may be invoked concurrently from the threads performing
+    /// compilation, so it should be quick and not block.
+    pub fn with_progress(on_progress: impl Fn(CompileProgress) + Send + Sync + 'static) -> Self {
+        CompileMonitor {
+            inner: Arc::new(MonitorInner {
+                on_progress: Some(Box::new(on_progress)),
+                ..MonitorInner::default()
+            }),
+        }
+    }
+
+    /// Requests that the compilation observed by this monitor stops as soon
+    /// as possible.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns whether [`CompileMonitor::cancel`] has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns the current progress of compilation.
+    pub fn progress(&self) -> CompileProgress {
+        CompileProgress {
+            completed: self.inner.completed.load(Ordering::SeqCst),
+            total: self.inner.total.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Returns an error if compilation has been cancelled.
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(CompilationCancelled(()).into());
+        }
+        Ok(())
+    }
+
+    /// Returns an error if compilation has been cancelled, either through this
+    /// monitor or by shutting down `engine`.
+    pub(crate) fn check_for(monitor: Option<&Self>, engine: &Engine) -> Result<()> {
+        if engine.is_shutting_down() {
+            return Err(CompilationCancelled(()).into());
+        }
+        match monitor {
+            Some(monitor) => monitor.check(),
+            None => Ok(()),
+        }
+    }
+
+    /// Records that compilation is about to start `total` units of work.
+    pub(crate) fn start(&self, total: usize) {
+        self.inner.total.fetch_add(total, Ordering::SeqCst);
+        self.report();
+    }
+
+    /// Records that a unit of work has finished.
+    pub(crate) fn finish_one(&self) {
+        self.inner.completed.fetch_add(1, Ordering::SeqCst);
+        self.report();
+    }
+
+    fn report(&self) {
+        if let Some(on_progress) = &self.inner.on_progress {
+            on_progress(self.progress());
+        }
+    }
+}
+
+impl fmt::Debug for CompileMonitor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompileMonitor")
+            .field("cancelled", &self.is_cancelled())
+            .field("progress", &self.progress())
+            .finish()
+    }
+}
+
+/// The error returned by a compilation which was cancelled through its
+/// [`CompileMonitor`].
+///
+/// This can be detected with `error.is::<CompilationCancelled>()`.
+#[derive(Debug)]
+pub struct CompilationCancelled(());
+
+impl fmt::Display for CompilationCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("compilation was cancelled")
+    }
+}
+
+impl std::error::Error for CompilationCancelled {}
+
+/// A compilation running on a background thread, created with
+/// [`Engine::compile_module_in_background`].
+///
+/// The result can be retrieved by blocking with [`BackgroundCompile::wait`]
+/// or by awaiting this value as a [`Future`]. Dropping the handle cancels the
+/// compilation.
+pub struct BackgroundCompile<T> {
+    monitor: CompileMonitor,
+    state: Arc<(Mutex<BackgroundState<T>>, Condvar)>,
+}
+
+struct BackgroundState<T> {
+    finished: bool,
+    result: Option<Result<T>>,
+    waker: Option<Waker>,
+}
+
+impl<T: Send + 'static> BackgroundCompile<T> {
+    fn spawn(
+        monitor: CompileMonitor,
+        compile: impl FnOnce() -> Result<T> + Send + 'static,
+    ) -> Self {
+        let state = Arc::new((
+            Mutex::new(BackgroundState {
+                finished: false,
+                result: None,
+                waker: None,
+            }),
+            Condvar::new(),
+        ));
+        let thread_state = state.clone();
+        std::thread::spawn(move || {
+            let result = compile();
+            let (lock, cvar) = &*thread_state;
+            let mut state = lock.lock().unwrap();
+            state.finished = true;
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+            cvar.notify_all();
+        });
+        BackgroundCompile { monitor, state }
+    }
+}
+
+impl<T> BackgroundCompile<T> {
+    /// Returns the monitor observing this compilation.
+    pub fn monitor(&self) -> &CompileMonitor {
+        &self.monitor
+    }
+
+    /// Requests that this compilation stops as soon as possible.
+    ///
+    /// The result of a cancelled compilation is a [`CompilationCancelled`]
+    /// error, unless compilation had already finished.
+    pub fn cancel(&self) {
+        self.monitor.cancel();
+    }
+
+    /// Returns whether the compilation has finished, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        self.state.0.lock().unwrap().finished
+    }
+
+    /// Blocks the current thread until the compilation finishes and returns
+    /// its result.
+    pub fn wait(self) -> Result<T> {
+        let (lock, cvar) = &*self.state;
+        let mut state = lock.lock().unwrap();
+        loop {
+            if let Some(result) = state.result.take() {
+                return result;
+            }
+            state = cvar.wait(state).unwrap();
+        }
+    }
+}
+
+impl<T> Future for BackgroundCompile<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<T>> {
+        let mut state = self.state.0.lock().unwrap();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> Drop for BackgroundCompile<T> {
+    fn drop(&mut self) {
+        if !self.is_finished() {
+            self.monitor.cancel();
+        }
+    }
+}
+
+impl<T> fmt::Debug for BackgroundCompile<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BackgroundCompile")
+            .field("monitor", &self.monitor)
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+impl Engine {
+    /// Compiles a WebAssembly module on a new background thread, as with
+    /// [`Module::new`], returning a handle to the in-flight compilation.
+    ///
+    /// Progress is reported to `monitor`, which can also be used to cancel
+    /// the compilation, for example when an interactive host no longer needs
+    /// the module.
+    pub fn compile_module_in_background(
+        &self,
+        bytes: impl Into<Vec<u8>>,
+        monitor: CompileMonitor,
+    ) -> BackgroundCompile<Module> {
+        let engine = self.clone();
+        let bytes = bytes.into();
+        let thread_monitor = monitor.clone();
+        BackgroundCompile::spawn(monitor, move || {
+            #[cfg(feature = "wat")]
+            let bytes = wat::parse_bytes(&bytes)?;
+            Module::from_binary_with_monitor(&engine, &bytes, &thread_monitor)
+        })
+    }
+}
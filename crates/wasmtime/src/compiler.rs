@@ -22,7 +22,7 @@
 //!   functions. It is up to the caller to serialize the relevant parts of the
 //!   `Artifacts` into the ELF file.
 
-use crate::Engine;
+use crate::{CompileMonitor, Engine};
 use anyhow::Result;
 use std::collections::{btree_map, BTreeMap, BTreeSet};
 use std::{any::Any, collections::HashMap};
@@ -336,11 +336,30 @@ impl<'a> CompileInputs<'a> {
 
     /// Compile these `CompileInput`s (maybe in parallel) and return the
     /// resulting `UnlinkedCompileOutput`s.
-    pub fn compile(self, engine: &Engine) -> Result<UnlinkedCompileOutputs> {
+    ///
+    /// If a `monitor` is given then it's notified as each input finishes, and
+    /// inputs which haven't started yet fail once it has been cancelled or
+    /// once the engine starts shutting down.
+    pub fn compile(
+        self,
+        engine: &Engine,
+        monitor: Option<&CompileMonitor>,
+    ) -> Result<UnlinkedCompileOutputs> {
         let compiler = engine.compiler();
 
+        if let Some(monitor) = monitor {
+            monitor.start(self.inputs.len());
+        }
+
         // Compile each individual input in parallel.
-        let raw_outputs = engine.run_maybe_parallel(self.inputs, |f| f(compiler))?;
+        let raw_outputs = engine.run_maybe_parallel(self.inputs, |f| -> Result<_> {
+            CompileMonitor::check_for(monitor, engine)?;
+            let output = f(compiler)?;
+            if let Some(monitor) = monitor {
+                monitor.finish_one();
+            }
+            Ok(output)
+        })?;
 
         // Bucket the outputs by kind.
         let mut outputs: BTreeMap<u32, Vec<CompileOutput>> = BTreeMap::new();
@@ -1,4 +1,6 @@
 use crate::code::CodeObject;
+use crate::component::matching::InstanceType;
+use crate::component::{types, ResourceType};
 use crate::signatures::SignatureCollection;
 use crate::{Engine, Module, ResourcesRequired};
 use anyhow::{bail, Context, Result};
@@ -9,15 +11,15 @@ use std::path::Path;
 use std::ptr::NonNull;
 use std::sync::Arc;
 use wasmtime_environ::component::{
-    AllCallFunc, ComponentTypes, GlobalInitializer, InstantiateModule, StaticModuleIndex,
-    TrampolineIndex, Translator, VMComponentOffsets,
+    AllCallFunc, ComponentTypes, GlobalInitializer, InstantiateModule, ResourceIndex,
+    StaticModuleIndex, TrampolineIndex, Translator, VMComponentOffsets,
 };
 use wasmtime_environ::{FunctionLoc, HostPtr, ObjectKind, PrimaryMap, ScopeVec};
 use wasmtime_jit::{CodeMemory, CompiledModuleInfo};
 use wasmtime_runtime::component::ComponentRuntimeInfo;
 use wasmtime_runtime::{
-    MmapVec, VMArrayCallFunction, VMFuncRef, VMFunctionBody, VMNativeCallFunction,
-    VMWasmCallFunction,
+    CompiledModuleId, MmapVec, VMArrayCallFunction, VMFuncRef, VMFunctionBody,
+    VMNativeCallFunction, VMWasmCallFunction,
 };
 
 /// A compiled WebAssembly Component.
@@ -29,6 +31,10 @@ pub struct Component {
 }
 
 struct ComponentInner {
+    /// A unique id for this component, which tells apart the resource types
+    /// of different components in [`Component::component_type`].
+    id: CompiledModuleId,
+
     /// Core wasm modules that the component defined internally, indexed by the
     /// compile-time-assigned `ModuleUpvarIndex`.
     static_modules: PrimaryMap<StaticModuleIndex, Module>,
@@ -130,10 +136,22 @@ impl Component {
         engine
             .check_compatible_with_native_host()
             .context("compilation settings are not compatible with the native host")?;
+        engine.verify_input(binary, ObjectKind::Component, false)?;
+
+        #[cfg(feature = "cache")]
+        if let Some(store) = &engine.config().artifact_cache_store {
+            let (code, artifacts) = crate::module::compile_with_cache_store(
+                engine,
+                &**store,
+                ObjectKind::Component,
+                binary,
+                || Component::build_artifacts(engine, binary),
+            )?;
+            return Component::from_parts(engine, code, artifacts);
+        }
 
         let (mmap, artifacts) = Component::build_artifacts(engine, binary)?;
-        let mut code_memory = CodeMemory::new(mmap)?;
-        code_memory.publish()?;
+        let code_memory = engine.publish_code(mmap)?;
         Component::from_parts(engine, Arc::new(code_memory), Some(artifacts))
     }
 
@@ -147,6 +165,7 @@ impl Component {
     ///
     /// [`Module::deserialize`]: crate::Module::deserialize
     pub unsafe fn deserialize(engine: &Engine, bytes: impl AsRef<[u8]>) -> Result<Component> {
+        engine.verify_input(bytes.as_ref(), ObjectKind::Component, true)?;
         let code = engine.load_code_bytes(bytes.as_ref(), ObjectKind::Component)?;
         Component::from_parts(engine, code, None)
     }
@@ -174,8 +193,10 @@ impl Component {
         engine: &Engine,
         binary: &[u8],
     ) -> Result<(MmapVec, ComponentArtifacts)> {
+        use crate::admission::{AdmissionKind, Tenant};
         use crate::compiler::CompileInputs;
 
+        let _permit = engine.admit(Tenant::Engine, AdmissionKind::Compilation);
         let tunables = &engine.config().tunables;
         let compiler = engine.compiler();
 
@@ -197,7 +218,7 @@ impl Component {
                 (i, &*translation, functions)
             }),
         );
-        let unlinked_compile_outputs = compile_inputs.compile(&engine)?;
+        let unlinked_compile_outputs = compile_inputs.compile(&engine, None)?;
         let (compiled_funcs, function_indices) = unlinked_compile_outputs.pre_link();
 
         let mut object = compiler.object(ObjectKind::Component)?;
@@ -275,6 +296,7 @@ impl Component {
 
         Ok(Component {
             inner: Arc::new(ComponentInner {
+                id: engine.unique_id_allocator().alloc(),
                 static_modules,
                 code,
                 info,
@@ -342,6 +364,24 @@ impl Component {
         Ok(self.code_object().code_memory().mmap().to_vec())
     }
 
+    /// Returns the type of this component, describing everything it imports
+    /// and exports.
+    ///
+    /// Resources defined by this component are reported with types which
+    /// belong to no instance, since each instantiation defines its own.
+    pub fn component_type(&self) -> types::Component {
+        let resources = Arc::new(
+            (0..self.env_component().num_resources)
+                .map(|i| ResourceType::uninstantiated(self.inner.id, ResourceIndex::from_u32(i)))
+                .collect(),
+        );
+        let ty = InstanceType {
+            types: self.types(),
+            resources: &resources,
+        };
+        types::Component::from(self.env_component().ty, &ty)
+    }
+
     pub(crate) fn runtime_info(&self) -> Arc<dyn ComponentRuntimeInfo> {
         self.inner.clone()
     }
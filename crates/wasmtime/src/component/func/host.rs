@@ -22,6 +22,15 @@ pub struct HostFunc {
     entrypoint: VMLoweringCallee,
     typecheck: Box<dyn (Fn(TypeFuncIndex, &InstanceType<'_>) -> Result<()>) + Send + Sync>,
     func: Box<dyn Any + Send + Sync>,
+    dynamic: Option<Dynamic>,
+}
+
+/// The type of a function created with [`HostFunc::new_dynamic`] along with a
+/// type-erased `call_dynamic::<T, F>`, which lets it be called with [`Val`]s.
+struct Dynamic {
+    index: TypeFuncIndex,
+    types: Arc<ComponentTypes>,
+    call: unsafe fn(),
 }
 
 impl HostFunc {
@@ -36,6 +45,7 @@ impl HostFunc {
             entrypoint,
             typecheck: Box::new(typecheck::<P, R>),
             func: Box::new(func),
+            dynamic: None,
         })
     }
 
@@ -93,9 +103,51 @@ impl HostFunc {
                 }
             }),
             func: Box::new(func),
+            dynamic: Some(Dynamic {
+                index,
+                types: types.clone(),
+                call: unsafe {
+                    mem::transmute::<
+                        unsafe fn(
+                            *const u8,
+                            StoreContextMut<'_, T>,
+                            &[Val],
+                            &mut [Val],
+                        ) -> Result<()>,
+                        unsafe fn(),
+                    >(call_dynamic::<T, F>)
+                },
+            }),
         })
     }
 
+    /// Returns the type of this function if it was created with
+    /// [`HostFunc::new_dynamic`].
+    pub(crate) fn dynamic_ty(&self) -> Option<(TypeFuncIndex, &Arc<ComponentTypes>)> {
+        self.dynamic.as_ref().map(|d| (d.index, &d.types))
+    }
+
+    /// Calls a function created with [`HostFunc::new_dynamic`] directly with
+    /// `params` and `results` of its own type.
+    ///
+    /// # Unsafety
+    ///
+    /// `T` must be the store type this function was created with.
+    pub(crate) unsafe fn call_dynamic<T>(
+        &self,
+        store: StoreContextMut<'_, T>,
+        params: &[Val],
+        results: &mut [Val],
+    ) -> Result<()> {
+        let dynamic = self.dynamic.as_ref().expect("not a dynamic host function");
+        let call = mem::transmute::<
+            unsafe fn(),
+            unsafe fn(*const u8, StoreContextMut<'_, T>, &[Val], &mut [Val]) -> Result<()>,
+        >(dynamic.call);
+        let data = &*self.func as *const (dyn Any + Send + Sync) as *const u8;
+        call(data, store, params, results)
+    }
+
     pub fn typecheck(&self, ty: TypeFuncIndex, types: &InstanceType<'_>) -> Result<()> {
         (self.typecheck)(ty, types)
     }
@@ -425,6 +477,18 @@ fn validate_inbounds_dynamic(abi: &CanonicalAbiInfo, memory: &[u8], ptr: &ValRaw
     Ok(ptr)
 }
 
+unsafe fn call_dynamic<T, F>(
+    data: *const u8,
+    store: StoreContextMut<'_, T>,
+    params: &[Val],
+    results: &mut [Val],
+) -> Result<()>
+where
+    F: Fn(StoreContextMut<'_, T>, &[Val], &mut [Val]) -> Result<()> + Send + Sync + 'static,
+{
+    (*(data as *const F))(store, params, results)
+}
+
 extern "C" fn dynamic_entrypoint<T, F>(
     cx: *mut VMOpaqueContext,
     data: *mut u8,
@@ -4,7 +4,7 @@ use crate::component::{Component, ComponentNamedList, Func, Lift, Lower, Resourc
 use crate::instance::OwnedImports;
 use crate::linker::DefinitionType;
 use crate::store::{StoreOpaque, Stored};
-use crate::{AsContextMut, Module, StoreContextMut};
+use crate::{AsContextMut, Module, StoreContextMut, ValRaw};
 use anyhow::{anyhow, Context, Result};
 use indexmap::IndexMap;
 use std::marker;
@@ -22,7 +22,7 @@ use wasmtime_runtime::VMFuncRef;
 /// the two behave similarly.
 //
 // FIXME: need to write more docs here.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Instance(pub(crate) Stored<Option<Box<InstanceData>>>);
 
 pub(crate) struct InstanceData {
@@ -143,6 +143,106 @@ impl Instance {
     pub fn get_resource(&self, mut store: impl AsContextMut, name: &str) -> Option<ResourceType> {
         self.exports(store.as_context_mut()).root().resource(name)
     }
+
+    /// Runs the destructors of all host-defined resources which this instance
+    /// still owns.
+    ///
+    /// Dropping a [`Store`](crate::Store) doesn't run any resource
+    /// destructors, so the host resources which a component never dropped are
+    /// otherwise leaked. This method passes each of them to the destructor
+    /// given to [`LinkerInstance::resource`](crate::component::LinkerInstance::resource)
+    /// and is intended to be called when tearing down the instance, before
+    /// its store is dropped. The resources are removed from the instance's
+    /// handle tables, so the instance shouldn't be used afterwards.
+    ///
+    /// All destructors are run even if some of them fail, in which case the
+    /// first error is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this instance, or if the store has
+    /// [`Config::async_support`](crate::Config::async_support) enabled.
+    pub fn drop_host_resources(&self, mut store: impl AsContextMut) -> Result<()> {
+        let mut store = store.as_context_mut();
+        assert!(
+            !store.0.async_support(),
+            "must use `drop_host_resources_async` when async support is enabled on the config"
+        );
+        self.drop_host_resources_impl(&mut store)
+    }
+
+    /// Same as [`Instance::drop_host_resources`] except for use with async
+    /// stores, which also runs destructors defined with
+    /// [`LinkerInstance::resource_async`](crate::component::LinkerInstance::resource_async).
+    ///
+    /// The returned future completes once every destructor has, so awaiting
+    /// it before dropping the store sequences asynchronous cleanup with store
+    /// teardown.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this instance, or if the store does not
+    /// have [`Config::async_support`](crate::Config::async_support) enabled.
+    #[cfg(feature = "async")]
+    #[cfg_attr(nightlydoc, doc(cfg(feature = "async")))]
+    pub async fn drop_host_resources_async<T>(
+        &self,
+        mut store: impl AsContextMut<Data = T>,
+    ) -> Result<()>
+    where
+        T: Send,
+    {
+        let mut store = store.as_context_mut();
+        assert!(
+            store.0.async_support(),
+            "cannot use `drop_host_resources_async` without enabling async support in the config"
+        );
+        store
+            .on_fiber(|store| self.drop_host_resources_impl(store))
+            .await?
+    }
+
+    fn drop_host_resources_impl<T>(&self, store: &mut StoreContextMut<'_, T>) -> Result<()> {
+        let instance = store.0[self.0].as_ref().unwrap().instance_ptr();
+
+        // Take all the resources out of the tables before running any
+        // destructors, since destructors need exclusive access to the store.
+        let mut to_drop = Vec::new();
+        unsafe {
+            let tables = (*instance)
+                .component_resource_tables()
+                .keys()
+                .collect::<Vec<_>>();
+            for ty in tables {
+                // Resources defined by the component have instance flags and
+                // are the component's own business, only host-defined
+                // resources are destroyed here.
+                let dtor = match (*instance).dtor_and_flags(ty) {
+                    (Some(dtor), None) => dtor,
+                    _ => continue,
+                };
+                for rep in (*instance).component_resource_tables()[ty].take_owned() {
+                    to_drop.push((dtor, rep));
+                }
+            }
+        }
+
+        let mut result = Ok(());
+        for (dtor, rep) in to_drop {
+            let mut args = [ValRaw::u32(rep)];
+
+            // This should be safe because `dtor` is owned by this instance,
+            // which `store` owns, and destructors always take one i32 argument
+            // and return no results.
+            let ret = unsafe {
+                crate::Func::call_unchecked_raw(store, dtor, args.as_mut_ptr(), args.len())
+            };
+            if result.is_ok() {
+                result = ret;
+            }
+        }
+        result
+    }
 }
 
 impl InstanceData {
@@ -214,6 +314,40 @@ impl InstanceData {
         InstanceType::new(self.instance())
     }
 
+    /// Returns the path under which this instance exports the resource type
+    /// `id` that it defines, such as `foo:bar/baz#t`, if it's exported at all.
+    pub fn resource_export_path(&self, id: DefinedResourceIndex) -> Option<String> {
+        fn find(
+            component: &wasmtime_environ::component::Component,
+            types: &ComponentTypes,
+            exports: &IndexMap<String, Export>,
+            id: DefinedResourceIndex,
+        ) -> Option<String> {
+            for (name, export) in exports {
+                match export {
+                    Export::Type(TypeDef::Resource(idx)) => {
+                        if component.defined_resource_index(types[*idx].ty) == Some(id) {
+                            return Some(name.clone());
+                        }
+                    }
+                    Export::Instance(exports) => {
+                        if let Some(item) = find(component, types, exports, id) {
+                            return Some(format!("{name}#{item}"));
+                        }
+                    }
+                    Export::Type(_)
+                    | Export::LiftedFunction { .. }
+                    | Export::ModuleStatic(_)
+                    | Export::ModuleImport(_) => {}
+                }
+            }
+            None
+        }
+
+        let component = self.component.env_component();
+        find(component, self.component_types(), &component.exports, id)
+    }
+
     // NB: This method is only intended to be called during the instantiation
     // process because the `Arc::get_mut` here is fallible and won't generally
     // succeed once the instance has been handed to the embedder. Before that
@@ -229,6 +363,7 @@ impl InstanceData {
 
 struct Instantiator<'a> {
     component: &'a Component,
+    instance: Instance,
     data: InstanceData,
     core_imports: OwnedImports,
     imports: &'a PrimaryMap<RuntimeImportIndex, RuntimeImport>,
@@ -265,6 +400,7 @@ impl<'a> Instantiator<'a> {
         component: &'a Component,
         store: &mut StoreOpaque,
         imports: &'a Arc<PrimaryMap<RuntimeImportIndex, RuntimeImport>>,
+        instance: Instance,
     ) -> Instantiator<'a> {
         let env_component = component.env_component();
         store.modules_mut().register_component(component);
@@ -272,6 +408,7 @@ impl<'a> Instantiator<'a> {
             PrimaryMap::with_capacity(env_component.imported_resources.len());
         Instantiator {
             component,
+            instance,
             imports,
             core_imports: OwnedImports::empty(),
             data: InstanceData {
@@ -410,7 +547,7 @@ impl<'a> Instantiator<'a> {
             .env_component()
             .resource_index(resource.index);
         self.data.state.set_resource_destructor(index, dtor);
-        let ty = ResourceType::guest(store.id(), &self.data.state, resource.index);
+        let ty = ResourceType::guest(self.instance, resource.index);
         let i = self.data.resource_types_mut().push(ty);
         debug_assert_eq!(i, index);
     }
@@ -591,20 +728,38 @@ impl<T> InstancePre<T> {
 
     fn instantiate_impl(&self, mut store: impl AsContextMut<Data = T>) -> Result<Instance> {
         let mut store = store.as_context_mut();
+        store.engine().check_not_shutting_down()?;
         store
             .engine()
             .allocator()
             .increment_component_instance_count()?;
-        let mut instantiator = Instantiator::new(&self.component, store.0, &self.imports);
-        instantiator.run(&mut store).map_err(|e| {
+        // Reserve the instance's slot in the store up front so resource types
+        // defined during instantiation can refer back to their instance.
+        let instance = Instance(store.0.store_data_mut().insert(None));
+        let mut instantiator = Instantiator::new(&self.component, store.0, &self.imports, instance);
+        if let Err(e) = instantiator.run(&mut store) {
             store
                 .engine()
                 .allocator()
                 .decrement_component_instance_count();
-            e
-        })?;
-        let data = Box::new(instantiator.data);
-        let instance = Instance(store.0.store_data_mut().insert(Some(data)));
+            // Release the reserved slot unless a resource type defined by this
+            // instance, which refers to the slot, may have escaped to the
+            // embedder. Keeping the slot then ensures it's never reused by
+            // another instance.
+            let escaped = instantiator
+                .data
+                .state
+                .resource_types()
+                .downcast_ref::<ImportedResources>()
+                .unwrap()
+                .values()
+                .any(|ty| ty.instance() == Some(instance));
+            if !escaped {
+                store.0.store_data_mut().remove_last(instance.0);
+            }
+            return Err(e);
+        }
+        store.0[instance.0] = Some(Box::new(instantiator.data));
         store.0.push_component_instance(instance);
         Ok(instance)
     }
@@ -1,19 +1,21 @@
 use crate::component::func::HostFunc;
 use crate::component::instance::RuntimeImport;
-use crate::component::matching::TypeChecker;
+use crate::component::matching::{InstanceType, TypeChecker};
 use crate::component::{
-    Component, ComponentNamedList, Instance, InstancePre, Lift, Lower, ResourceType, Val,
+    types, Component, ComponentNamedList, Instance, InstancePre, Lift, Lower, ResourceType, Type,
+    Val,
 };
-use crate::{AsContextMut, Engine, Module, StoreContextMut};
+use crate::{AsContextMut, CapabilityPolicy, Engine, Module, StoreContextMut};
 use anyhow::{anyhow, bail, Context, Result};
 use indexmap::IndexMap;
+use std::borrow::Cow;
 use std::collections::hash_map::{Entry, HashMap};
 use std::future::Future;
 use std::marker;
 use std::ops::Deref;
 use std::pin::Pin;
 use std::sync::Arc;
-use wasmtime_environ::component::TypeDef;
+use wasmtime_environ::component::{ComponentTypes, InterfaceType, TypeDef, TypeFuncIndex};
 use wasmtime_environ::PrimaryMap;
 
 /// A type used to instantiate [`Component`]s.
@@ -28,9 +30,145 @@ pub struct Linker<T> {
     map: NameMap,
     path: Vec<usize>,
     allow_shadowing: bool,
+    version_policy: VersionPolicy,
+    capability_policy: Option<Arc<CapabilityPolicy>>,
+    adapters: HashMap<String, Arc<Adapter>>,
     _marker: marker::PhantomData<fn() -> T>,
 }
 
+/// Policy used by a [`Linker`] to match the versions of imported interfaces
+/// against the versions of interfaces defined in the linker.
+///
+/// Versions are the semver suffix of an interface name, for example `0.2.1`
+/// in `wasi:http/types@0.2.1`. Regardless of policy an import always resolves
+/// to a definition of exactly the same name if there is one, and pre-release
+/// versions such as `0.2.0-rc-2023-11-05` only ever match exactly.
+///
+/// Resolved definitions are still type-checked against the import, so a
+/// definition with a compatible version but a different signature is an
+/// error.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum VersionPolicy {
+    /// Imports only resolve to definitions with exactly the same name.
+    #[default]
+    Exact,
+
+    /// An import resolves to the newest definition of the same interface
+    /// whose version is semver-compatible with, and no older than, the
+    /// imported version.
+    ///
+    /// For example an import of `wasi:http/types@0.2.1` resolves to a
+    /// definition of `wasi:http/types@0.2.3`, but not to one of
+    /// `wasi:http/types@0.2.0` or `wasi:http/types@0.3.0`.
+    Compatible,
+
+    /// Like [`VersionPolicy::Compatible`] except that definitions older than
+    /// the imported version are also considered, which relies on the
+    /// type-check to catch items added in the newer version.
+    Lenient,
+}
+
+/// Creates a dynamically-typed host function of the given type.
+type FuncFactory = Arc<dyn Fn(TypeFuncIndex, &Arc<ComponentTypes>) -> Arc<HostFunc> + Send + Sync>;
+
+/// A mapping from an interface imported by components onto an interface
+/// defined in a [`Linker`] under a different name or with a different shape.
+///
+/// Adapters let hosts evolve the interfaces they implement without
+/// recompiling the guests which import older versions of them. An import
+/// adapted with [`Linker::adapt`] resolves to the adapter's target instance,
+/// with:
+///
+/// * items renamed with [`InterfaceAdapter::rename`] taken from their new
+///   name in the target, and
+/// * functions given record field defaults with
+///   [`InterfaceAdapter::field_default`] called with their arguments and
+///   results converted between the guest's and the target's types, and
+/// * items defined with [`InterfaceAdapter::func_new`] implemented by a
+///   dynamically-typed function using the guest's view of the function's
+///   type.
+///
+/// Every other item is taken from the target as-is and the result is
+/// type-checked against the import like any other definition.
+pub struct InterfaceAdapter<T> {
+    target: String,
+    renames: Vec<(String, String)>,
+    funcs: Vec<(String, FuncFactory)>,
+    defaults: Vec<(String, String, Val)>,
+    _marker: marker::PhantomData<fn() -> T>,
+}
+
+impl<T> InterfaceAdapter<T> {
+    /// Creates an adapter which bridges to the instance named `target` in the
+    /// linker, such as `wasi:http/types@0.3.0`.
+    pub fn new(target: &str) -> InterfaceAdapter<T> {
+        InterfaceAdapter {
+            target: target.to_string(),
+            renames: Vec::new(),
+            funcs: Vec::new(),
+            defaults: Vec::new(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Provides the imported item `import` with the target's item named
+    /// `target`.
+    pub fn rename(&mut self, import: &str, target: &str) -> &mut Self {
+        self.renames.push((import.to_string(), target.to_string()));
+        self
+    }
+
+    /// Declares that records passed to or returned from the imported function
+    /// `import` which lack a field named `field` have it filled in with
+    /// `default`.
+    ///
+    /// Calls to `import` are forwarded to the target's function of the same
+    /// name, or of the name it was renamed to, with the arguments converted
+    /// to the target's types and the results converted back to the guest's.
+    /// Records are matched up field by field by name and every other type
+    /// must have the same shape in both. The target's function must have
+    /// been defined with [`LinkerInstance::func_new`] and its type may not
+    /// mention resources.
+    pub fn field_default(&mut self, import: &str, field: &str, default: Val) -> &mut Self {
+        self.defaults
+            .push((import.to_string(), field.to_string(), default));
+        self
+    }
+
+    /// Provides the imported function `import` with the dynamically-typed
+    /// `func`, which sees the parameters and results of the function as
+    /// imported by the guest.
+    ///
+    /// This takes precedence over both the target's items and renames.
+    pub fn func_new<F>(&mut self, import: &str, func: F) -> &mut Self
+    where
+        F: Fn(StoreContextMut<'_, T>, &[Val], &mut [Val]) -> Result<()> + Send + Sync + 'static,
+    {
+        let func = Arc::new(func);
+        let factory: FuncFactory =
+            Arc::new(move |index: TypeFuncIndex, types: &Arc<ComponentTypes>| {
+                let func = func.clone();
+                HostFunc::new_dynamic(
+                    move |store: StoreContextMut<'_, T>, params: &[Val], results: &mut [Val]| {
+                        func(store, params, results)
+                    },
+                    index,
+                    types,
+                )
+            });
+        self.funcs.push((import.to_string(), factory));
+        self
+    }
+}
+
+/// An [`InterfaceAdapter`] with its names interned in a [`Linker`].
+struct Adapter {
+    target: usize,
+    renames: Vec<(usize, usize)>,
+    funcs: Vec<(usize, FuncFactory)>,
+    defaults: Vec<(usize, Arc<[(String, Val)]>)>,
+}
+
 impl<T> Clone for Linker<T> {
     fn clone(&self) -> Linker<T> {
         Linker {
@@ -39,6 +177,9 @@ impl<T> Clone for Linker<T> {
             map: self.map.clone(),
             path: self.path.clone(),
             allow_shadowing: self.allow_shadowing,
+            version_policy: self.version_policy,
+            capability_policy: self.capability_policy.clone(),
+            adapters: self.adapters.clone(),
             _marker: self._marker,
         }
     }
@@ -84,6 +225,9 @@ impl<T> Linker<T> {
             strings: Strings::default(),
             map: NameMap::default(),
             allow_shadowing: false,
+            version_policy: VersionPolicy::Exact,
+            capability_policy: None,
+            adapters: HashMap::new(),
             path: Vec::new(),
             _marker: marker::PhantomData,
         }
@@ -103,6 +247,75 @@ impl<T> Linker<T> {
         self
     }
 
+    /// Configures how the versions of a component's imported interfaces are
+    /// matched against the interfaces defined in this linker.
+    ///
+    /// By default this is [`VersionPolicy::Exact`].
+    pub fn version_policy(&mut self, policy: VersionPolicy) -> &mut Self {
+        self.version_policy = policy;
+        self
+    }
+
+    /// Restricts the imports of components instantiated through this linker
+    /// to the capabilities permitted by `policy`.
+    ///
+    /// A component's capabilities are named by a top-level import and the
+    /// name of the item used from it, if the import is an instance. Components
+    /// which use, or declare in their capability manifest, a capability the
+    /// policy doesn't permit fail to instantiate with a
+    /// [`CapabilityDenied`](crate::CapabilityDenied) error. See
+    /// [`CapabilityPolicy`] for details.
+    ///
+    /// By default no policy is configured and any import may be linked.
+    pub fn capability_policy(&mut self, policy: CapabilityPolicy) -> &mut Self {
+        self.capability_policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Resolves the instance imported by components as `import` with
+    /// `adapter` instead of a definition of that name.
+    ///
+    /// The adapter's target is looked up when components are instantiated,
+    /// so it may be defined before or after calling this method. See
+    /// [`InterfaceAdapter`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `import` is already adapted and shadowing is not
+    /// allowed.
+    pub fn adapt(&mut self, import: &str, adapter: InterfaceAdapter<T>) -> Result<()> {
+        if !self.allow_shadowing && self.adapters.contains_key(import) {
+            bail!("import of `{import}` adapted twice");
+        }
+        let mut defaults = Vec::<(usize, Vec<(String, Val)>)>::new();
+        for (import, field, default) in adapter.defaults {
+            let import = self.strings.intern(&import);
+            match defaults.iter_mut().find(|(name, _)| *name == import) {
+                Some((_, fields)) => fields.push((field, default)),
+                None => defaults.push((import, vec![(field, default)])),
+            }
+        }
+        let adapter = Adapter {
+            target: self.strings.intern(&adapter.target),
+            renames: adapter
+                .renames
+                .iter()
+                .map(|(import, target)| (self.strings.intern(import), self.strings.intern(target)))
+                .collect(),
+            funcs: adapter
+                .funcs
+                .into_iter()
+                .map(|(import, func)| (self.strings.intern(&import), func))
+                .collect(),
+            defaults: defaults
+                .into_iter()
+                .map(|(import, fields)| (import, fields.into()))
+                .collect(),
+        };
+        self.adapters.insert(import.to_string(), Arc::new(adapter));
+        Ok(())
+    }
+
     /// Returns the "root instance" of this linker, used to define names into
     /// the root namespace.
     pub fn root(&mut self) -> LinkerInstance<'_, T> {
@@ -138,6 +351,8 @@ impl<T> Linker<T> {
     /// Note that unlike internally in components where subtyping at the
     /// interface-types layer is supported this is not supported here. Items
     /// defined in this linker must match the component's imports precisely.
+    /// Import names are matched according to this linker's
+    /// [`VersionPolicy`].
     ///
     /// # Errors
     ///
@@ -145,6 +360,17 @@ impl<T> Linker<T> {
     /// `component` imports or if a name defined doesn't match the type of the
     /// item imported by the `component` provided.
     pub fn instantiate_pre(&self, component: &Component) -> Result<InstancePre<T>> {
+        if let Some(policy) = &self.capability_policy {
+            let env_component = component.env_component();
+            policy.check(
+                env_component.capabilities.as_ref(),
+                env_component.imports.values().map(|(import, names)| {
+                    let (module, _) = &env_component.import_types[*import];
+                    (module.as_str(), names.first().map(|s| s.as_str()))
+                }),
+            )?;
+        }
+
         let mut cx = TypeChecker {
             component: component.env_component(),
             types: component.types(),
@@ -156,13 +382,21 @@ impl<T> Linker<T> {
         // the definition within this linker that it corresponds to. When found
         // perform a typecheck against the component's expected type.
         let env_component = component.env_component();
+        let mut roots = PrimaryMap::with_capacity(env_component.import_types.len());
         for (_idx, (name, ty)) in env_component.import_types.iter() {
-            let import = self
-                .strings
-                .lookup(name)
-                .and_then(|name| self.map.get(&name));
-            cx.definition(ty, import)
+            let import = match self.adapters.get(name) {
+                Some(adapter) => Some(Cow::Owned(
+                    self.adapted_definition(adapter, ty, component)
+                        .with_context(|| format!("failed to adapt import `{name}`"))?,
+                )),
+                None => self
+                    .resolve_import(name)
+                    .and_then(|root| self.map.get(&root))
+                    .map(Cow::Borrowed),
+            };
+            cx.definition(ty, import.as_deref())
                 .with_context(|| format!("import `{name}` has the wrong type"))?;
+            roots.push(import);
         }
 
         // Now that all imports are known to be defined and satisfied by this
@@ -171,13 +405,10 @@ impl<T> Linker<T> {
         // component-compile-time.
         let mut imports = PrimaryMap::with_capacity(env_component.imports.len());
         for (idx, (import, names)) in env_component.imports.iter() {
-            let (root, _) = &env_component.import_types[*import];
-            let root = self.strings.lookup(root).unwrap();
-
             // This is the flattening process where we go from a definition
             // optionally through a list of exported names to get to the final
             // item.
-            let mut cur = &self.map[&root];
+            let mut cur = roots[*import].as_deref().unwrap();
             for name in names {
                 let name = self.strings.lookup(name).unwrap();
                 cur = match cur {
@@ -256,6 +487,299 @@ impl<T> Linker<T> {
             .instantiate_async(store)
             .await
     }
+
+    /// Builds the instance which an import adapted by `adapter` resolves to.
+    fn adapted_definition(
+        &self,
+        adapter: &Adapter,
+        ty: &TypeDef,
+        component: &Component,
+    ) -> Result<Definition> {
+        let target = &self.strings.strings[adapter.target];
+        let mut map = match self.map.get(&adapter.target) {
+            Some(Definition::Instance(map)) => map.clone(),
+            Some(_) => bail!("adapter target `{target}` is not an instance"),
+            None => bail!("adapter target `{target}` is not defined"),
+        };
+        for (import, name) in adapter.renames.iter() {
+            let item = map.get(name).cloned().ok_or_else(|| {
+                anyhow!(
+                    "`{}` is not defined in adapter target `{target}`",
+                    self.strings.strings[*name]
+                )
+            })?;
+            map.insert(*import, item);
+        }
+        let exports = match ty {
+            TypeDef::ComponentInstance(index) => &component.types()[*index].exports,
+            _ => bail!("only instance imports can be adapted"),
+        };
+        for (import, defaults) in adapter.defaults.iter() {
+            let name = &self.strings.strings[*import];
+            let index = match exports.get(&**name) {
+                Some(TypeDef::ComponentFunc(index, _)) => *index,
+                Some(_) => bail!("`{name}` is not a function"),
+                None => bail!("`{name}` is not imported"),
+            };
+            let func = match map.get(import) {
+                Some(Definition::Func(func)) => func.clone(),
+                Some(_) => bail!("`{name}` in adapter target `{target}` is not a function"),
+                None => bail!("`{name}` is not defined in adapter target `{target}`"),
+            };
+            map.insert(
+                *import,
+                Definition::Func(
+                    adapt_func::<T>(func, index, component.types(), defaults.clone())
+                        .with_context(|| format!("failed to adapt `{name}`"))?,
+                ),
+            );
+        }
+        for (import, factory) in adapter.funcs.iter() {
+            let name = &self.strings.strings[*import];
+            let index = match exports.get(&**name) {
+                Some(TypeDef::ComponentFunc(index, _)) => *index,
+                Some(_) => bail!("`{name}` is not a function"),
+                None => bail!("`{name}` is not imported"),
+            };
+            map.insert(*import, Definition::Func(factory(index, component.types())));
+        }
+        Ok(Definition::Instance(map))
+    }
+
+    /// Returns the root definition that the import `name` resolves to under
+    /// this linker's [`VersionPolicy`].
+    fn resolve_import(&self, name: &str) -> Option<usize> {
+        if let Some(idx) = self.strings.lookup(name) {
+            if self.map.contains_key(&idx) {
+                return Some(idx);
+            }
+        }
+        if self.version_policy == VersionPolicy::Exact {
+            return None;
+        }
+        let (interface, wanted) = split_version(name)?;
+        if !wanted.pre.is_empty() {
+            return None;
+        }
+        let mut best: Option<(Version<'_>, usize)> = None;
+        for idx in self.map.keys() {
+            let (candidate, version) = match split_version(&self.strings.strings[*idx]) {
+                Some(pair) => pair,
+                None => continue,
+            };
+            if candidate != interface || !version.pre.is_empty() || !version.same_track(&wanted) {
+                continue;
+            }
+            if self.version_policy == VersionPolicy::Compatible && version < wanted {
+                continue;
+            }
+            if best.as_ref().map_or(true, |(b, _)| version > *b) {
+                best = Some((version, *idx));
+            }
+        }
+        best.map(|(_, idx)| idx)
+    }
+}
+
+/// The `major.minor.patch[-pre]` version at the end of an interface name.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Version<'a> {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: &'a str,
+}
+
+impl Version<'_> {
+    /// Returns whether `self` and `other` are semver-compatible, ignoring
+    /// which one is newer.
+    fn same_track(&self, other: &Version<'_>) -> bool {
+        match (self.major, self.minor) {
+            (0, 0) => other.major == 0 && other.minor == 0 && self.patch == other.patch,
+            (0, minor) => other.major == 0 && other.minor == minor,
+            (major, _) => other.major == major,
+        }
+    }
+}
+
+/// Splits `name` into the interface name and its version, if it has one.
+fn split_version(name: &str) -> Option<(&str, Version<'_>)> {
+    let (interface, version) = name.rsplit_once('@')?;
+    let (version, pre) = version.split_once('-').unwrap_or((version, ""));
+    let mut parts = version.split('.');
+    let mut next = || parts.next()?.parse().ok();
+    let version = Version {
+        major: next()?,
+        minor: next()?,
+        patch: next()?,
+        pre,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((interface, version))
+}
+
+/// Wraps the dynamic host function `func` into a function of the guest's
+/// type `index`, converting values between the two with `defaults` filling in
+/// the record fields missing on either side.
+fn adapt_func<T>(
+    func: Arc<HostFunc>,
+    index: TypeFuncIndex,
+    types: &Arc<ComponentTypes>,
+    defaults: Arc<[(String, Val)]>,
+) -> Result<Arc<HostFunc>> {
+    let (target_index, target_types) = match func.dynamic_ty() {
+        Some((index, types)) => (index, types.clone()),
+        None => bail!("field defaults require a target function defined with `func_new`"),
+    };
+    let guest_params = types[types[index].params].types.clone();
+    let guest_results = types[types[index].results].types.clone();
+    let target_params = target_types[target_types[target_index].params]
+        .types
+        .clone();
+    let target_results = target_types[target_types[target_index].results]
+        .types
+        .clone();
+    if guest_params.len() != target_params.len() || guest_results.len() != target_results.len() {
+        bail!("function signatures differ in their number of parameters or results");
+    }
+    // Resources are rejected while converting, so neither side needs the
+    // resources of an instance.
+    let resources = Arc::new(PrimaryMap::new());
+    let guest_types = types.clone();
+    Ok(HostFunc::new_dynamic(
+        move |store: StoreContextMut<'_, T>, params: &[Val], results: &mut [Val]| {
+            let target = InstanceType {
+                types: &target_types,
+                resources: &resources,
+            };
+            let params = params
+                .iter()
+                .zip(target_params.iter())
+                .map(|(val, ty)| adapt_val(val, *ty, &target, &defaults))
+                .collect::<Result<Vec<_>>>()?;
+            let mut target_vals = vec![Val::Bool(false); target_results.len()];
+            // SAFETY: every definition in a `Linker<T>` is created for `T`.
+            unsafe { func.call_dynamic(store, &params, &mut target_vals)? };
+            let guest = InstanceType {
+                types: &guest_types,
+                resources: &resources,
+            };
+            for ((result, val), ty) in results
+                .iter_mut()
+                .zip(&target_vals)
+                .zip(guest_results.iter())
+            {
+                *result = adapt_val(val, *ty, &guest, &defaults)?;
+            }
+            Ok(())
+        },
+        index,
+        types,
+    ))
+}
+
+/// Converts `val` into a value of the type `ty` of `instance`, matching record
+/// fields by name and taking those missing from `val` from `defaults`.
+fn adapt_val(
+    val: &Val,
+    ty: InterfaceType,
+    instance: &InstanceType<'_>,
+    defaults: &[(String, Val)],
+) -> Result<Val> {
+    let types = instance.types;
+    Ok(match (ty, val) {
+        (InterfaceType::Own(_) | InterfaceType::Borrow(_), _) => {
+            bail!("resources cannot be adapted")
+        }
+        (InterfaceType::List(index), Val::List(list)) => {
+            let element = types[index].element;
+            let values = list
+                .iter()
+                .map(|val| adapt_val(val, element, instance, defaults))
+                .collect::<Result<_>>()?;
+            types::List::from(index, instance).new_val(values)?
+        }
+        (InterfaceType::Record(index), Val::Record(record)) => {
+            let mut values = Vec::new();
+            for field in types[index].fields.iter() {
+                let val = record
+                    .fields()
+                    .find(|(name, _)| *name == field.name)
+                    .map(|(_, val)| val)
+                    .or_else(|| {
+                        defaults
+                            .iter()
+                            .find(|(name, _)| *name == field.name)
+                            .map(|(_, val)| val)
+                    })
+                    .ok_or_else(|| anyhow!("no value or default for field `{}`", field.name))?;
+                let val = adapt_val(val, field.ty, instance, defaults)
+                    .with_context(|| format!("failed to adapt field `{}`", field.name))?;
+                values.push((field.name.as_str(), val));
+            }
+            types::Record::from(index, instance).new_val(values)?
+        }
+        (InterfaceType::Tuple(index), Val::Tuple(tuple)) => {
+            let tys = &types[index].types;
+            if tys.len() != tuple.values().len() {
+                bail!("tuple lengths differ");
+            }
+            let values = tuple
+                .values()
+                .iter()
+                .zip(tys.iter())
+                .map(|(val, ty)| adapt_val(val, *ty, instance, defaults))
+                .collect::<Result<_>>()?;
+            types::Tuple::from(index, instance).new_val(values)?
+        }
+        (InterfaceType::Variant(index), Val::Variant(variant)) => {
+            let name = variant.discriminant();
+            let case = types[index]
+                .cases
+                .iter()
+                .find(|case| case.name == name)
+                .ok_or_else(|| anyhow!("no case named `{name}`"))?;
+            let payload = match (case.ty, variant.payload()) {
+                (Some(ty), Some(val)) => Some(adapt_val(val, ty, instance, defaults)?),
+                (None, None) => None,
+                _ => bail!("payloads of case `{name}` differ"),
+            };
+            types::Variant::from(index, instance).new_val(name, payload)?
+        }
+        (InterfaceType::Enum(index), Val::Enum(e)) => {
+            types::Enum::from(index, instance).new_val(e.discriminant())?
+        }
+        (InterfaceType::Option(index), Val::Option(option)) => {
+            let value = option
+                .value()
+                .map(|val| adapt_val(val, types[index].ty, instance, defaults))
+                .transpose()?;
+            types::OptionType::from(index, instance).new_val(value)?
+        }
+        (InterfaceType::Result(index), Val::Result(result)) => {
+            let ty = &types[index];
+            let adapt = |ty: Option<InterfaceType>, val: Option<&Val>| match (ty, val) {
+                (Some(ty), Some(val)) => adapt_val(val, ty, instance, defaults).map(Some),
+                (None, None) => Ok(None),
+                _ => bail!("payloads of result differ"),
+            };
+            let value = match result.value() {
+                Ok(val) => Ok(adapt(ty.ok, val)?),
+                Err(val) => Err(adapt(ty.err, val)?),
+            };
+            types::ResultType::from(index, instance).new_val(value)?
+        }
+        (InterfaceType::Flags(index), Val::Flags(flags)) => {
+            let names = flags.flags().collect::<Vec<_>>();
+            types::Flags::from(index, instance).new_val(&names)?
+        }
+        (ty, val) => {
+            Type::from(&ty, instance).check(val)?;
+            val.clone()
+        }
+    })
 }
 
 impl<T> LinkerInstance<'_, T> {
@@ -364,7 +888,7 @@ impl<T> LinkerInstance<'_, T> {
         }
 
         if let Some(ty) = map.get(name) {
-            if let TypeDef::ComponentFunc(index) = ty {
+            if let TypeDef::ComponentFunc(index, _) = ty {
                 let name = self.strings.intern(name);
                 return self.insert(
                     name,
@@ -455,6 +979,45 @@ impl<T> LinkerInstance<'_, T> {
         self.insert(name, Definition::Resource(ResourceType::host::<U>(), dtor))
     }
 
+    /// Same as [`LinkerInstance::resource`], except with an asynchronous
+    /// destructor.
+    ///
+    /// This allows destroying a resource to await, for example, flushing or
+    /// closing an underlying I/O object, without blocking the thread. Like
+    /// other async host functions the destructor runs on the fiber of the
+    /// guest call which dropped the resource, or of
+    /// [`ResourceAny::resource_drop_async`](crate::component::ResourceAny::resource_drop_async),
+    /// so it completes before that call returns. Resources which the guest
+    /// still owns when it's torn down are destroyed by
+    /// [`Instance::drop_host_resources_async`](crate::component::Instance::drop_host_resources_async).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the [`Engine`] was not configured with
+    /// [`Config::async_support`](crate::Config::async_support) enabled.
+    #[cfg(feature = "async")]
+    #[cfg_attr(nightlydoc, doc(cfg(feature = "async")))]
+    pub fn resource_async<U: 'static, F>(&mut self, name: &str, dtor: F) -> Result<()>
+    where
+        F: for<'a> Fn(
+                StoreContextMut<'a, T>,
+                u32,
+            ) -> Box<dyn Future<Output = Result<()>> + Send + 'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        assert!(
+            self.engine.config().async_support,
+            "cannot use `resource_async` without enabling async support in the config"
+        );
+        self.resource::<U>(name, move |mut store, rep| {
+            let async_cx = store.as_context_mut().0.async_cx().expect("async cx");
+            let mut future = Pin::from(dtor(store.as_context_mut(), rep));
+            unsafe { async_cx.block_on(future.as_mut()) }?
+        })
+    }
+
     /// Defines a nested instance within this instance.
     ///
     /// This can be used to describe arbitrarily nested levels of instances
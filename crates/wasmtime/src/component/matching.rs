@@ -43,7 +43,7 @@ impl TypeChecker<'_> {
                 None => self.instance(&self.types[t], None),
                 _ => bail!("expected instance found {}", desc(actual)),
             },
-            TypeDef::ComponentFunc(t) => match actual {
+            TypeDef::ComponentFunc(t, _) => match actual {
                 Some(Definition::Func(actual)) => self.func(t, actual),
                 _ => bail!("expected func found {}", desc(actual)),
             },
@@ -91,7 +91,7 @@ impl TypeChecker<'_> {
                     // it's supplied, so test for equality.
                     Some(expected) => {
                         if expected != actual {
-                            bail!("mismatched resource types");
+                            return Err(expected.mismatch(actual));
                         }
                     }
                 }
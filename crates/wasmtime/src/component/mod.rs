@@ -20,7 +20,7 @@ pub use self::func::{
     ComponentNamedList, ComponentType, Func, Lift, Lower, TypedFunc, WasmList, WasmStr,
 };
 pub use self::instance::{ExportInstance, Exports, Instance, InstancePre};
-pub use self::linker::{Linker, LinkerInstance};
+pub use self::linker::{InterfaceAdapter, Linker, LinkerInstance, VersionPolicy};
 pub use self::resources::{Resource, ResourceAny};
 pub use self::types::{ResourceType, Type};
 pub use self::values::{Enum, Flags, List, OptionVal, Record, ResultVal, Tuple, Val, Variant};
@@ -278,6 +278,10 @@ pub(crate) use self::store::ComponentStoreData;
 ///     // Note that this is only async for the host as the guest will still
 ///     // appear as if it's invoking blocking functions.
 ///     //
+///     // The `drop` methods of imported resources are async in this mode too,
+///     // and those resources are defined with
+///     // `LinkerInstance::resource_async`.
+///     //
 ///     // This option defaults to `false`.
 ///     async: true,
 ///
@@ -286,6 +290,9 @@ pub(crate) use self::store::ComponentStoreData;
 ///     // provided over which imports are async and which aren't.
 ///     //
 ///     // Note that in this mode all exports are still async.
+///     //
+///     // The `drop` method of an imported resource `foo` is named
+///     // `[resource-drop]foo` in these lists.
 ///     async: {
 ///         // All imports are async except for functions with these names
 ///         except_imports: ["foo", "bar"],
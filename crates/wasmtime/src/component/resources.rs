@@ -1,17 +1,19 @@
 use crate::component::func::{bad_type_info, desc, LiftContext, LowerContext};
 use crate::component::matching::InstanceType;
-use crate::component::{ComponentType, Lift, Lower};
+use crate::component::{ComponentType, Instance, Lift, Lower};
 use crate::store::{StoreId, StoreOpaque};
 use crate::{AsContextMut, StoreContextMut, Trap};
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use std::any::TypeId;
 use std::fmt;
 use std::marker;
 use std::mem::MaybeUninit;
 use std::sync::atomic::{AtomicU32, Ordering::Relaxed};
-use wasmtime_environ::component::{CanonicalAbiInfo, DefinedResourceIndex, InterfaceType};
-use wasmtime_runtime::component::{ComponentInstance, InstanceFlags, ResourceTables};
-use wasmtime_runtime::{SendSyncPtr, VMFuncRef, ValRaw};
+use wasmtime_environ::component::{
+    CanonicalAbiInfo, DefinedResourceIndex, InterfaceType, ResourceIndex,
+};
+use wasmtime_runtime::component::{InstanceFlags, ResourceTables};
+use wasmtime_runtime::{CompiledModuleId, SendSyncPtr, VMFuncRef, ValRaw};
 
 /// Representation of a resource type in the component model.
 ///
@@ -48,36 +50,120 @@ impl ResourceType {
     /// of the value produced by `Resource::<T>::new_{own,borrow}`.
     pub fn host<T: 'static>() -> ResourceType {
         ResourceType {
-            kind: ResourceTypeKind::Host(TypeId::of::<T>()),
+            kind: ResourceTypeKind::Host {
+                id: TypeId::of::<T>(),
+                name: std::any::type_name::<T>(),
+            },
         }
     }
 
-    pub(crate) fn guest(
-        store: StoreId,
-        instance: &ComponentInstance,
-        id: DefinedResourceIndex,
+    pub(crate) fn guest(instance: Instance, id: DefinedResourceIndex) -> ResourceType {
+        ResourceType {
+            kind: ResourceTypeKind::Guest { instance, id },
+        }
+    }
+
+    /// Creates the type of a resource in a component which hasn't been
+    /// instantiated, as seen through [`Component::component_type`].
+    ///
+    /// Such types are only equal to themselves, and `component` is the unique
+    /// id of the component they belong to.
+    ///
+    /// [`Component::component_type`]: crate::component::Component::component_type
+    pub(crate) fn uninstantiated(
+        component: CompiledModuleId,
+        index: ResourceIndex,
     ) -> ResourceType {
         ResourceType {
-            kind: ResourceTypeKind::Guest {
-                store,
-                instance: instance as *const _ as usize,
-                id,
-            },
+            kind: ResourceTypeKind::Uninstantiated { component, index },
+        }
+    }
+
+    /// Returns the component instance which defined this resource type, or
+    /// `None` if this is a host-defined resource type or belongs to a
+    /// component which hasn't been instantiated.
+    ///
+    /// Each instantiation of a component defines its own resource types, so
+    /// this can be used to tell which instantiation a resource came from.
+    pub fn instance(&self) -> Option<Instance> {
+        match self.kind {
+            ResourceTypeKind::Host { .. } | ResourceTypeKind::Uninstantiated { .. } => None,
+            ResourceTypeKind::Guest { instance, .. } => Some(instance),
+        }
+    }
+
+    /// Returns an error describing a value of type `actual` being used where
+    /// a value of type `self` was expected.
+    pub(crate) fn mismatch(&self, actual: &ResourceType) -> anyhow::Error {
+        anyhow!("mismatched resource types: expected {self}, found {actual}")
+    }
+
+    /// Same as [`ResourceType::mismatch`], but additionally names guest
+    /// resource types by the path their instance exports them under.
+    pub(crate) fn mismatch_in(&self, store: &StoreOpaque, actual: &ResourceType) -> anyhow::Error {
+        anyhow!(
+            "mismatched resource types: expected {}, found {}",
+            self.describe(store),
+            actual.describe(store),
+        )
+    }
+
+    fn describe(&self, store: &StoreOpaque) -> String {
+        let path = match self.kind {
+            ResourceTypeKind::Guest { instance, id } if store.store_data().contains(instance.0) => {
+                // The instance's data is absent while it's still being
+                // instantiated.
+                store[instance.0]
+                    .as_ref()
+                    .and_then(|data| data.resource_export_path(id))
+            }
+            _ => None,
+        };
+        match path {
+            Some(path) => format!("{self} (exported as `{path}`)"),
+            None => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ResourceTypeKind::Host { name, .. } => write!(f, "host resource `{name}`"),
+            ResourceTypeKind::Guest { instance, id } => write!(
+                f,
+                "resource {} defined by component instance {}",
+                id.as_u32(),
+                instance.0.index()
+            ),
+            ResourceTypeKind::Uninstantiated { index, .. } => {
+                write!(
+                    f,
+                    "resource {} of an uninstantiated component",
+                    index.as_u32()
+                )
+            }
         }
     }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum ResourceTypeKind {
-    Host(TypeId),
+    Host {
+        id: TypeId,
+        // The name of the Rust type, used only for diagnostics.
+        name: &'static str,
+    },
     Guest {
-        store: StoreId,
-        // For now this is the `*mut ComponentInstance` pointer within the store
-        // that this guest corresponds to. It's used to distinguish different
-        // instantiations of the same component within the store.
-        instance: usize,
+        // The instance that defined this type, which distinguishes different
+        // instantiations of the same component within a store.
+        instance: Instance,
         id: DefinedResourceIndex,
     },
+    Uninstantiated {
+        component: CompiledModuleId,
+        index: ResourceIndex,
+    },
 }
 
 /// A host-defined resource in the component model.
@@ -406,9 +492,10 @@ unsafe impl<T: 'static> ComponentType for Resource<T> {
             InterfaceType::Own(t) | InterfaceType::Borrow(t) => *t,
             other => bail!("expected `own` or `borrow`, found `{}`", desc(other)),
         };
-        match types.resource_type(resource).kind {
-            ResourceTypeKind::Host(id) if TypeId::of::<T>() == id => {}
-            _ => bail!("resource type mismatch"),
+        let expected = ResourceType::host::<T>();
+        let actual = types.resource_type(resource);
+        if actual != expected {
+            return Err(expected.mismatch(&actual));
         }
 
         Ok(())
@@ -513,6 +600,15 @@ impl ResourceAny {
         self.ty
     }
 
+    /// Returns the component instance which defined this resource's type, or
+    /// `None` if it's a host-defined resource.
+    ///
+    /// This is a shorthand for [`ResourceType::instance`] on
+    /// [`ResourceAny::ty`].
+    pub fn instance(&self) -> Option<Instance> {
+        self.ty.instance()
+    }
+
     /// Returns whether this is an owned resource, and if not it's a borrowed
     /// resource.
     pub fn owned(&self) -> bool {
@@ -609,15 +705,17 @@ impl ResourceAny {
     fn lower_to_index<U>(&self, cx: &mut LowerContext<'_, U>, ty: InterfaceType) -> Result<u32> {
         match ty {
             InterfaceType::Own(t) => {
-                if cx.resource_type(t) != self.ty {
-                    bail!("mismatched resource types")
+                let expected = cx.resource_type(t);
+                if expected != self.ty {
+                    return Err(expected.mismatch_in(cx.store.0, &self.ty));
                 }
                 let rep = cx.host_resource_lift_own(self.idx)?;
                 Ok(cx.guest_resource_lower_own(t, rep))
             }
             InterfaceType::Borrow(t) => {
-                if cx.resource_type(t) != self.ty {
-                    bail!("mismatched resource types")
+                let expected = cx.resource_type(t);
+                if expected != self.ty {
+                    return Err(expected.mismatch_in(cx.store.0, &self.ty));
                 }
                 let rep = cx.host_resource_lift_borrow(self.idx)?;
                 Ok(cx.guest_resource_lower_borrow(t, rep))
@@ -2,15 +2,17 @@
 
 use crate::component::matching::InstanceType;
 use crate::component::values::{self, Val};
+use crate::{ExternType, FuncType};
 use anyhow::{anyhow, Result};
 use std::fmt;
 use std::mem;
 use std::ops::Deref;
 use std::sync::Arc;
 use wasmtime_environ::component::{
-    CanonicalAbiInfo, ComponentTypes, InterfaceType, ResourceIndex, TypeEnumIndex, TypeFlagsIndex,
-    TypeListIndex, TypeOptionIndex, TypeRecordIndex, TypeResourceTableIndex, TypeResultIndex,
-    TypeTupleIndex, TypeVariantIndex,
+    CanonicalAbiInfo, ComponentTypes, InterfaceType, ResourceIndex, TypeComponentIndex,
+    TypeComponentInstanceIndex, TypeDef, TypeEnumIndex, TypeFlagsIndex, TypeFuncIndex,
+    TypeFuncParamNamesIndex, TypeListIndex, TypeModuleIndex, TypeOptionIndex, TypeRecordIndex,
+    TypeResourceTableIndex, TypeResultIndex, TypeTupleIndex, TypeVariantIndex,
 };
 use wasmtime_environ::PrimaryMap;
 
@@ -517,6 +519,243 @@ impl PartialEq for Flags {
 
 impl Eq for Flags {}
 
+/// The type of a component, describing the items it imports and exports.
+///
+/// Returned by [`Component::component_type`](crate::component::Component::component_type).
+#[derive(Clone, Debug)]
+pub struct Component(Handle<TypeComponentIndex>);
+
+impl Component {
+    pub(crate) fn from(index: TypeComponentIndex, ty: &InstanceType<'_>) -> Self {
+        Component(Handle::new(index, ty))
+    }
+
+    /// Returns the type of the import named `name`, if any.
+    pub fn get_import(&self, name: &str) -> Option<ComponentItem> {
+        self.0.types[self.0.index]
+            .imports
+            .get(name)
+            .map(|ty| ComponentItem::from(ty, &self.0.instance()))
+    }
+
+    /// Retrieve the imports of this component in declaration order.
+    pub fn imports(&self) -> impl ExactSizeIterator<Item = (&str, ComponentItem)> {
+        self.0.types[self.0.index]
+            .imports
+            .iter()
+            .map(|(name, ty)| (name.as_str(), ComponentItem::from(ty, &self.0.instance())))
+    }
+
+    /// Returns the type of the export named `name`, if any.
+    pub fn get_export(&self, name: &str) -> Option<ComponentItem> {
+        self.0.types[self.0.index]
+            .exports
+            .get(name)
+            .map(|ty| ComponentItem::from(ty, &self.0.instance()))
+    }
+
+    /// Retrieve the exports of this component in declaration order.
+    pub fn exports(&self) -> impl ExactSizeIterator<Item = (&str, ComponentItem)> {
+        self.0.types[self.0.index]
+            .exports
+            .iter()
+            .map(|(name, ty)| (name.as_str(), ComponentItem::from(ty, &self.0.instance())))
+    }
+}
+
+/// The type of a component instance, such as an imported or exported
+/// interface.
+#[derive(Clone, Debug)]
+pub struct ComponentInstance(Handle<TypeComponentInstanceIndex>);
+
+impl ComponentInstance {
+    pub(crate) fn from(index: TypeComponentInstanceIndex, ty: &InstanceType<'_>) -> Self {
+        ComponentInstance(Handle::new(index, ty))
+    }
+
+    /// Returns the type of the export named `name`, if any.
+    pub fn get_export(&self, name: &str) -> Option<ComponentItem> {
+        self.0.types[self.0.index]
+            .exports
+            .get(name)
+            .map(|ty| ComponentItem::from(ty, &self.0.instance()))
+    }
+
+    /// Retrieve the exports of this instance in declaration order.
+    pub fn exports(&self) -> impl ExactSizeIterator<Item = (&str, ComponentItem)> {
+        self.0.types[self.0.index]
+            .exports
+            .iter()
+            .map(|(name, ty)| (name.as_str(), ComponentItem::from(ty, &self.0.instance())))
+    }
+}
+
+/// The type of a component function.
+#[derive(Clone, Debug)]
+pub struct ComponentFunc(Handle<TypeFuncIndex>, TypeFuncParamNamesIndex);
+
+impl ComponentFunc {
+    pub(crate) fn from(
+        index: TypeFuncIndex,
+        param_names: TypeFuncParamNamesIndex,
+        ty: &InstanceType<'_>,
+    ) -> Self {
+        ComponentFunc(Handle::new(index, ty), param_names)
+    }
+
+    /// Retrieve the names and types of this function's parameters in
+    /// declaration order.
+    pub fn params(&self) -> impl ExactSizeIterator<Item = (&str, Type)> {
+        let func = &self.0.types[self.0.index];
+        self.0.types[self.1]
+            .names
+            .iter()
+            .zip(self.0.types[func.params].types.iter())
+            .map(|(name, ty)| (name.as_str(), Type::from(ty, &self.0.instance())))
+    }
+
+    /// Retrieve the types of this function's results in declaration order.
+    pub fn results(&self) -> impl ExactSizeIterator<Item = Type> + '_ {
+        let results = self.0.types[self.0.index].results;
+        self.0.types[results]
+            .types
+            .iter()
+            .map(|ty| Type::from(ty, &self.0.instance()))
+    }
+}
+
+/// The type of a core wasm module imported or exported by a component.
+#[derive(Clone, Debug)]
+pub struct Module(Handle<TypeModuleIndex>);
+
+impl Module {
+    pub(crate) fn from(index: TypeModuleIndex, ty: &InstanceType<'_>) -> Self {
+        Module(Handle::new(index, ty))
+    }
+
+    /// Retrieve the `(module, name)` pairs and types of this module's imports.
+    pub fn imports(&self) -> impl ExactSizeIterator<Item = ((&str, &str), ExternType)> {
+        self.0.types[self.0.index]
+            .imports
+            .iter()
+            .map(|((module, name), ty)| {
+                (
+                    (module.as_str(), name.as_str()),
+                    ExternType::from_wasmtime(self.0.types.module_types(), ty),
+                )
+            })
+    }
+
+    /// Retrieve the names and types of this module's exports.
+    pub fn exports(&self) -> impl ExactSizeIterator<Item = (&str, ExternType)> {
+        self.0.types[self.0.index].exports.iter().map(|(name, ty)| {
+            (
+                name.as_str(),
+                ExternType::from_wasmtime(self.0.types.module_types(), ty),
+            )
+        })
+    }
+}
+
+/// The type of an item imported or exported by a component or exported by a
+/// component instance.
+#[derive(Clone, Debug)]
+pub enum ComponentItem {
+    /// A component function.
+    ComponentFunc(ComponentFunc),
+    /// A core wasm function.
+    CoreFunc(FuncType),
+    /// A core wasm module.
+    Module(Module),
+    /// A component.
+    Component(Component),
+    /// A component instance, such as a WIT interface.
+    ComponentInstance(ComponentInstance),
+    /// A named interface type.
+    Type(Type),
+    /// A resource type.
+    ///
+    /// Resources defined by the component itself don't belong to an instance
+    /// yet, so they're only equal to other types of the same resource taken
+    /// from the same [`Component`](crate::component::Component).
+    Resource(ResourceType),
+}
+
+impl ComponentItem {
+    pub(crate) fn from(def: &TypeDef, ty: &InstanceType<'_>) -> Self {
+        match def {
+            TypeDef::Component(index) => ComponentItem::Component(Component::from(*index, ty)),
+            TypeDef::ComponentInstance(index) => {
+                ComponentItem::ComponentInstance(ComponentInstance::from(*index, ty))
+            }
+            TypeDef::ComponentFunc(index, param_names) => {
+                ComponentItem::ComponentFunc(ComponentFunc::from(*index, *param_names, ty))
+            }
+            TypeDef::Interface(iface) => ComponentItem::Type(Type::from(iface, ty)),
+            TypeDef::Module(index) => ComponentItem::Module(Module::from(*index, ty)),
+            TypeDef::CoreFunc(index) => ComponentItem::CoreFunc(FuncType::from_wasm_func_type(
+                ty.types.module_types()[*index].clone(),
+            )),
+            TypeDef::Resource(index) => ComponentItem::Resource(ty.resource_type(*index)),
+        }
+    }
+}
+
+/// The name of a WIT interface, such as `wasi:http/types@0.2.0`, as used to
+/// name the imports and exports of a component.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InterfaceName<'a> {
+    namespace: &'a str,
+    package: &'a str,
+    interface: &'a str,
+    version: Option<&'a str>,
+}
+
+impl<'a> InterfaceName<'a> {
+    /// Parses `name` as a `namespace:package/interface@version` name, where
+    /// the version is optional.
+    ///
+    /// Returns `None` for plain names, such as `run`, which don't refer to an
+    /// interface of a package.
+    pub fn parse(name: &'a str) -> Option<InterfaceName<'a>> {
+        let (path, version) = match name.split_once('@') {
+            Some((path, version)) => (path, Some(version)),
+            None => (name, None),
+        };
+        let (namespace, rest) = path.split_once(':')?;
+        let (package, interface) = rest.split_once('/')?;
+        if [namespace, package, interface].iter().any(|s| s.is_empty()) || version == Some("") {
+            return None;
+        }
+        Some(InterfaceName {
+            namespace,
+            package,
+            interface,
+            version,
+        })
+    }
+
+    /// Returns the namespace of the package, such as `wasi`.
+    pub fn namespace(&self) -> &'a str {
+        self.namespace
+    }
+
+    /// Returns the name of the package, such as `http`.
+    pub fn package(&self) -> &'a str {
+        self.package
+    }
+
+    /// Returns the name of the interface within the package, such as `types`.
+    pub fn interface(&self) -> &'a str {
+        self.interface
+    }
+
+    /// Returns the version of the package, such as `0.2.0`, if any.
+    pub fn version(&self) -> Option<&'a str> {
+        self.version
+    }
+}
+
 /// Represents a component model interface type
 #[derive(Clone, PartialEq, Eq, Debug)]
 #[allow(missing_docs)]
@@ -1,3 +1,4 @@
+use crate::executor::CompilationExecutor;
 use crate::memory::MemoryCreator;
 use crate::trampoline::MemoryCreatorProxy;
 use anyhow::{bail, ensure, Result};
@@ -8,6 +9,8 @@ use std::fmt;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
+#[cfg(any(feature = "cranelift", feature = "winch"))]
+use std::time::Duration;
 use target_lexicon::Architecture;
 use wasmparser::WasmFeatures;
 #[cfg(feature = "cache")]
@@ -21,7 +24,7 @@ use crate::stack::{StackCreator, StackCreatorProxy};
 #[cfg(feature = "async")]
 use wasmtime_fiber::RuntimeFiberStackCreator;
 
-pub use wasmtime_environ::CacheStore;
+pub use wasmtime_environ::{AddressMapGranularity, CacheStore, CompileError};
 pub use wasmtime_runtime::MpkEnabled;
 
 /// Represents the module instance allocation strategy to use.
@@ -101,6 +104,8 @@ pub struct Config {
     pub(crate) tunables: Tunables,
     #[cfg(feature = "cache")]
     pub(crate) cache_config: CacheConfig,
+    #[cfg(feature = "cache")]
+    pub(crate) artifact_cache_store: Option<Arc<dyn CacheStore>>,
     pub(crate) mem_creator: Option<Arc<dyn RuntimeMemoryCreator>>,
     pub(crate) allocation_strategy: InstanceAllocationStrategy,
     pub(crate) max_wasm_stack: usize,
@@ -115,12 +120,18 @@ pub struct Config {
     pub(crate) async_support: bool,
     pub(crate) module_version: ModuleVersionStrategy,
     pub(crate) parallel_compilation: bool,
+    pub(crate) compilation_executor: Option<Arc<dyn CompilationExecutor>>,
+    #[cfg(feature = "module-verification")]
+    pub(crate) module_verifier: Option<Arc<dyn crate::ModuleVerifier>>,
+    pub(crate) max_concurrent_instantiations: Option<usize>,
     pub(crate) memory_init_cow: bool,
     pub(crate) memory_guaranteed_dense_image_size: u64,
     pub(crate) force_memory_init_memfd: bool,
     pub(crate) wmemcheck: bool,
     pub(crate) coredump_on_trap: bool,
+    pub(crate) catch_host_panics: bool,
     pub(crate) macos_use_mach_ports: bool,
+    pub(crate) macos_use_map_jit: bool,
 }
 
 /// User-provided configuration for the compiler.
@@ -135,6 +146,7 @@ struct CompilerConfig {
     cache_store: Option<Arc<dyn CacheStore>>,
     clif_dir: Option<std::path::PathBuf>,
     wmemcheck: bool,
+    compile_time_budget: Option<Duration>,
 }
 
 #[cfg(any(feature = "cranelift", feature = "winch"))]
@@ -148,6 +160,7 @@ impl CompilerConfig {
             cache_store: None,
             clif_dir: None,
             wmemcheck: false,
+            compile_time_budget: None,
         }
     }
 
@@ -188,6 +201,8 @@ impl Config {
             compiler_config: CompilerConfig::default(),
             #[cfg(feature = "cache")]
             cache_config: CacheConfig::new_cache_disabled(),
+            #[cfg(feature = "cache")]
+            artifact_cache_store: None,
             profiling_strategy: ProfilingStrategy::None,
             mem_creator: None,
             allocation_strategy: InstanceAllocationStrategy::OnDemand,
@@ -211,12 +226,18 @@ impl Config {
             async_support: false,
             module_version: ModuleVersionStrategy::default(),
             parallel_compilation: !cfg!(miri),
+            compilation_executor: None,
+            #[cfg(feature = "module-verification")]
+            module_verifier: None,
+            max_concurrent_instantiations: None,
             memory_init_cow: true,
             memory_guaranteed_dense_image_size: 16 << 20,
             force_memory_init_memfd: false,
             wmemcheck: false,
             coredump_on_trap: false,
+            catch_host_panics: false,
             macos_use_mach_ports: true,
+            macos_use_map_jit: false,
         };
         #[cfg(any(feature = "cranelift", feature = "winch"))]
         {
@@ -936,6 +957,52 @@ impl Config {
         self
     }
 
+    /// Configures the register allocation algorithm used by Cranelift.
+    ///
+    /// For more information see the documentation of [`RegallocAlgorithm`].
+    ///
+    /// The default value for this is `RegallocAlgorithm::Backtracking`.
+    #[cfg(any(feature = "cranelift", feature = "winch"))]
+    #[cfg_attr(nightlydoc, doc(cfg(any(feature = "cranelift", feature = "winch"))))]
+    pub fn cranelift_regalloc_algorithm(&mut self, algorithm: RegallocAlgorithm) -> &mut Self {
+        let val = match algorithm {
+            RegallocAlgorithm::Backtracking => "backtracking",
+            RegallocAlgorithm::SinglePass => "single_pass",
+        };
+        self.compiler_config
+            .settings
+            .insert("regalloc_algorithm".to_string(), val.to_string());
+        self
+    }
+
+    /// Configures how long Cranelift may spend compiling a single function
+    /// before falling back to compiling it without optimizations.
+    ///
+    /// Some modules hit pathological cases in Cranelift's optimizer or
+    /// register allocator which can make compilation take a very long time.
+    /// When a budget is configured, Cranelift checks it between its passes,
+    /// and a function whose compilation runs out of budget is compiled again
+    /// with [`OptLevel::None`] and [`RegallocAlgorithm::SinglePass`], whose
+    /// running time is linear in the size of the function. Functions compiled
+    /// this way are listed by
+    /// [`Module::functions_over_compile_budget`](crate::Module::functions_over_compile_budget).
+    ///
+    /// A pass that has already started runs to completion, so a function can
+    /// overrun the budget by the duration of its longest pass. Functions
+    /// compiled under a budget also bypass the incremental compilation cache.
+    ///
+    /// Winch has no cheaper configuration to fall back to, so
+    /// [`Engine::new`](crate::Engine::new) fails if a budget is configured
+    /// together with the Winch strategy.
+    ///
+    /// The default value for this is `None`, meaning no budget.
+    #[cfg(any(feature = "cranelift", feature = "winch"))]
+    #[cfg_attr(nightlydoc, doc(cfg(any(feature = "cranelift", feature = "winch"))))]
+    pub fn cranelift_compile_time_budget(&mut self, budget: Option<Duration>) -> &mut Self {
+        self.compiler_config.compile_time_budget = budget;
+        self
+    }
+
     /// Configures whether Cranelift should perform a NaN-canonicalization pass.
     ///
     /// When Cranelift is used as a code generation backend this will configure
@@ -943,6 +1010,9 @@ impl Config {
     /// requiring entirely deterministic WebAssembly computation.
     /// This is not required by the WebAssembly spec, so it is not enabled by default.
     ///
+    /// Winch honors this setting too, canonicalizing the results of its float
+    /// arithmetic.
+    ///
     /// The default value for this is `false`
     #[cfg(any(feature = "cranelift", feature = "winch"))]
     #[cfg_attr(nightlydoc, doc(cfg(any(feature = "cranelift", feature = "winch"))))]
@@ -954,6 +1024,24 @@ impl Config {
         self
     }
 
+    /// Configures whether Cranelift may use jump tables for `br_table`.
+    ///
+    /// When disabled, each `br_table` is instead compiled to a binary search
+    /// over its index. This is slower to execute, but keeps the tables out of
+    /// the generated code, which can help very large functions that would
+    /// otherwise exceed the branch ranges of the target.
+    ///
+    /// The default value for this is `true`.
+    #[cfg(any(feature = "cranelift", feature = "winch"))]
+    #[cfg_attr(nightlydoc, doc(cfg(any(feature = "cranelift", feature = "winch"))))]
+    pub fn cranelift_jump_tables(&mut self, enable: bool) -> &mut Self {
+        let val = if enable { "true" } else { "false" };
+        self.compiler_config
+            .settings
+            .insert("enable_jump_tables".to_string(), val.to_string());
+        self
+    }
+
     /// Controls whether proof-carrying code (PCC) is used to validate
     /// lowering of Wasm sandbox checks.
     ///
@@ -1098,6 +1186,39 @@ impl Config {
         Ok(self)
     }
 
+    /// Caches whole compiled modules and components in the provided
+    /// [`CacheStore`].
+    ///
+    /// Unlike the filesystem cache configured with
+    /// [`Config::cache_config_load`], the store can be implemented by the
+    /// embedder on top of any storage, such as a shared remote key/value
+    /// store, so that a fleet of hosts only compiles each input once. Keys
+    /// are content hashes of the input bytes along with the compilation
+    /// settings of the [`Engine`](crate::Engine), and values are the same
+    /// artifacts as produced by [`Engine::precompile_module`] and
+    /// [`Engine::precompile_component`]. When a store is configured it is
+    /// consulted instead of the filesystem cache.
+    ///
+    /// Entries which fail to load, for example because they were produced by
+    /// an incompatible version of Wasmtime, are ignored and the input is
+    /// recompiled and reinserted.
+    ///
+    /// Artifacts read from the store are loaded in the same manner as
+    /// [`Module::deserialize`](crate::Module::deserialize), so the store must
+    /// be trusted to return exactly what was inserted into it.
+    ///
+    /// This method is only available when the `cache` feature of this crate is
+    /// enabled.
+    ///
+    /// [`Engine::precompile_module`]: crate::Engine::precompile_module
+    /// [`Engine::precompile_component`]: crate::Engine::precompile_component
+    #[cfg(feature = "cache")]
+    #[cfg_attr(nightlydoc, doc(cfg(feature = "cache")))]
+    pub fn artifact_cache_store(&mut self, store: Arc<dyn CacheStore>) -> &mut Self {
+        self.artifact_cache_store = Some(store);
+        self
+    }
+
     /// Sets a custom memory creator.
     ///
     /// Custom memory creators are used when creating host `Memory` objects or when
@@ -1430,6 +1551,86 @@ impl Config {
         self
     }
 
+    /// Configures the executor used to run compilation work, instead of
+    /// rayon's global thread pool.
+    ///
+    /// This allows embedders to compile on their own threads, for example so
+    /// that compilation doesn't compete with the application's own thread
+    /// pools or runs at a lower priority. [`SerialExecutor`](crate::SerialExecutor) compiles
+    /// deterministically on the calling thread.
+    ///
+    /// When an executor is configured it is used regardless of
+    /// [`Config::parallel_compilation`] and of whether the
+    /// `parallel-compilation` feature is enabled.
+    ///
+    /// By default no executor is configured.
+    pub fn compilation_executor(&mut self, executor: Arc<dyn CompilationExecutor>) -> &mut Self {
+        self.compilation_executor = Some(executor);
+        self
+    }
+
+    /// Configures a hook which must accept untrusted bytes before they are
+    /// compiled or deserialized.
+    ///
+    /// The `verifier` is invoked by [`Module::new`](crate::Module::new),
+    /// [`Module::from_binary`](crate::Module::from_binary),
+    /// [`Module::deserialize`](crate::Module::deserialize),
+    /// [`Engine::precompile_module`](crate::Engine::precompile_module), and
+    /// their file-based and component counterparts, before any other
+    /// processing of the input. It's handed a
+    /// [`VerificationInput`](crate::VerificationInput) with the SHA-256 hash of
+    /// the input and the contents of its
+    /// [`SIGNATURE_SECTION`](crate::SIGNATURE_SECTION), if any, and an error
+    /// returned from it fails the operation. This allows embeddings to enforce
+    /// provenance policies, such as only running signed modules, in-process.
+    /// [`Ed25519Verifier`](crate::Ed25519Verifier) is a ready-made verifier
+    /// for modules signed with [`Ed25519Signer`](crate::Ed25519Signer).
+    ///
+    /// Artifacts which Wasmtime itself loads from a cache are not passed to
+    /// the verifier again; the wasm they were compiled from already was.
+    ///
+    /// By default no verifier is configured and all inputs are accepted.
+    ///
+    /// This method is only available when the `module-verification` feature
+    /// of this crate is enabled.
+    #[cfg(feature = "module-verification")]
+    #[cfg_attr(nightlydoc, doc(cfg(feature = "module-verification")))]
+    pub fn module_verifier(&mut self, verifier: Arc<dyn crate::ModuleVerifier>) -> &mut Self {
+        self.module_verifier = Some(verifier);
+        self
+    }
+
+    /// Configures the maximum number of instantiations and compilations which
+    /// may run concurrently within an [`Engine`](crate::Engine).
+    ///
+    /// Once `limit` instantiations and compilations are in flight, further
+    /// ones wait for a running one to finish before they start. This keeps a
+    /// burst of instantiations from starving guests which are already running
+    /// of CPU and memory bandwidth. Synchronous callers block their thread
+    /// while waiting, while [`Instance::new_async`](crate::Instance::new_async)
+    /// and friends wait without blocking.
+    ///
+    /// Waiting requests are admitted round-robin between tenants rather than
+    /// first-come first-served, so a tenant which queues many instantiations
+    /// delays others by at most one instantiation per turn. Each
+    /// [`Store`](crate::Store) is its own tenant unless several are grouped
+    /// together with
+    /// [`Store::set_admission_tenant`](crate::Store::set_admission_tenant),
+    /// and all compilations share a single tenant. Queue times are reported by
+    /// [`Engine::admission_stats`](crate::Engine::admission_stats).
+    ///
+    /// Only allocating and initializing an instance counts against the limit:
+    /// the capacity is released before the module's start function runs, so
+    /// a long-running start function doesn't hold up other instantiations,
+    /// and it may instantiate further modules itself. Instantiating a
+    /// component admits each of its core instances separately.
+    ///
+    /// A limit of zero is invalid. By default there is no limit.
+    pub fn max_concurrent_instantiations(&mut self, limit: Option<usize>) -> &mut Self {
+        self.max_concurrent_instantiations = limit;
+        self
+    }
+
     /// Configures whether compiled artifacts will contain information to map
     /// native program addresses back to the original wasm module.
     ///
@@ -1439,8 +1640,164 @@ impl Config {
     /// program counters in backtraces as well as generating filenames/line
     /// numbers if so configured as well (and the original wasm module has DWARF
     /// debugging information present).
+    ///
+    /// This is a shorthand for [`Config::address_map_granularity`] with
+    /// [`AddressMapGranularity::Full`] when `true` and
+    /// [`AddressMapGranularity::None`] when `false`.
     pub fn generate_address_map(&mut self, generate: bool) -> &mut Self {
-        self.tunables.generate_address_map = generate;
+        self.tunables.address_map_granularity = if generate {
+            AddressMapGranularity::Full
+        } else {
+            AddressMapGranularity::None
+        };
+        self
+    }
+
+    /// Configures how precise the address map of compiled artifacts is.
+    ///
+    /// The address map translates native program addresses back to offsets in
+    /// the original wasm module, and for large modules it's a significant
+    /// fraction of the size of serialized artifacts. With
+    /// [`AddressMapGranularity::FunctionEntry`] only the start of each
+    /// function is recorded, so backtraces still identify the function a frame
+    /// belongs to but [`FrameInfo::module_offset`] reports the offset of the
+    /// function rather than of the instruction being executed. With
+    /// [`AddressMapGranularity::None`] no address map is generated at all,
+    /// which is equivalent to `generate_address_map(false)`.
+    ///
+    /// The default is [`AddressMapGranularity::Full`].
+    ///
+    /// [`FrameInfo::module_offset`]: crate::FrameInfo::module_offset
+    pub fn address_map_granularity(&mut self, granularity: AddressMapGranularity) -> &mut Self {
+        self.tunables.address_map_granularity = granularity;
+        self
+    }
+
+    /// Configures whether the non-executable portions of compiled artifacts
+    /// are compressed with zstd.
+    ///
+    /// Serialized modules and components are frequently dominated in size by
+    /// data which isn't executable code, notably the address map configured
+    /// with [`Config::generate_address_map`], DWARF debugging information
+    /// retained from the original wasm module, and Wasmtime's own metadata
+    /// about the module. When this option is enabled these sections are
+    /// compressed, which shrinks the output of [`Module::serialize`] and
+    /// [`Engine::precompile_module`] at the cost of extra work when compiling
+    /// and when loading the artifact. Executable code and wasm data segments
+    /// are never compressed so they can still be mapped directly from disk.
+    ///
+    /// Compressed sections are decompressed transparently when the artifact
+    /// is loaded, and artifacts produced with this option enabled may be
+    /// loaded by an [`Engine`] with it disabled and vice versa, so long as
+    /// the `zstd` feature of this crate is enabled in the loading process.
+    ///
+    /// This option is `false` by default.
+    ///
+    /// [`Module::serialize`]: crate::Module::serialize
+    /// [`Engine::precompile_module`]: crate::Engine::precompile_module
+    #[cfg(feature = "zstd")]
+    #[cfg_attr(nightlydoc, doc(cfg(feature = "zstd")))]
+    pub fn compress_artifacts(&mut self, enable: bool) -> &mut Self {
+        self.tunables.compress_artifacts = enable;
+        self
+    }
+
+    /// Configures the maximum size, in bytes, of a wasm function body that
+    /// will be compiled.
+    ///
+    /// Very large functions, such as those produced by relooped interpreters
+    /// or other code generators, can take a long time to compile and may
+    /// exceed limits of the compiler backend. When a limit is configured here
+    /// modules with a larger function fail to compile, and the returned error
+    /// is a [`CompileError::FunctionTooLarge`] identifying the offending
+    /// function. The same error is also returned for functions which exceed
+    /// the backend's own limits.
+    ///
+    /// This option is currently only used by Cranelift, and is `None`
+    /// (unlimited) by default.
+    pub fn max_function_body_size(&mut self, size: Option<usize>) -> &mut Self {
+        self.tunables.max_function_body_size = size;
+        self
+    }
+
+    /// Configures whether compiled code is instrumented to collect code
+    /// coverage.
+    ///
+    /// When enabled every function counts how often it's called and how often
+    /// each of its blocks is entered, where blocks start after every `loop`,
+    /// `if`, `else`, `end` and conditional branch. The counts are shared by
+    /// all instances of a module and can be retrieved with
+    /// [`Module::coverage`](crate::Module::coverage), which maps them back to
+    /// offsets in the original wasm module and, if
+    /// [`Config::wasm_backtrace_details`] is enabled and the module has DWARF
+    /// debug information, to source lines.
+    ///
+    /// Instrumentation slows down execution, so this is intended for
+    /// measuring the test coverage of wasm modules rather than for production
+    /// use. This option is currently only supported by Cranelift.
+    ///
+    /// By default this option is `false`.
+    pub fn coverage(&mut self, enable: bool) -> &mut Self {
+        self.tunables.coverage = enable;
+        self
+    }
+
+    /// Configures whether compiled code reports every linear memory load and
+    /// store to the hook installed with
+    /// [`Store::memory_trace`](crate::Store::memory_trace).
+    ///
+    /// Each access is reported with the index of the memory, its address, its
+    /// size and the offset of the instruction in the original wasm module,
+    /// before the access is performed. SIMD and atomic accesses are reported
+    /// as well. This is intended for diagnosing memory corruption in a guest
+    /// when attaching a debugger isn't practical: calling into the host on
+    /// every access slows down execution by orders of magnitude. This option
+    /// is currently only supported by Cranelift.
+    ///
+    /// By default this option is `false`.
+    pub fn memory_trace(&mut self, enable: bool) -> &mut Self {
+        self.tunables.memory_trace = enable;
+        self
+    }
+
+    /// Configures whether compiled code reports the entry into every basic
+    /// block to the hook installed with
+    /// [`Store::block_trace`](crate::Store::block_trace).
+    ///
+    /// Blocks are the same as those counted by [`Config::coverage`]: the
+    /// start of a function, loop headers, both arms of an `if`, the code
+    /// following a block and the fallthrough of conditional branches. Each
+    /// entry is reported with the index of the function and the offset of
+    /// the block's first instruction in the original wasm module, which is
+    /// enough to build coverage-guided fuzzers or execution visualizers
+    /// without external binary instrumentation. Calling into the host on
+    /// every block entry is slow, so this is meant for debugging and
+    /// fuzzing rather than production use. This option is currently only
+    /// supported by Cranelift.
+    ///
+    /// By default this option is `false`.
+    pub fn block_trace(&mut self, enable: bool) -> &mut Self {
+        self.tunables.block_trace = enable;
+        self
+    }
+
+    /// Configures whether the compiler records code generation statistics
+    /// for each function in the compiled artifact.
+    ///
+    /// The statistics include how many times each wasm opcode was compiled,
+    /// how many bytes of machine code it expanded to, and how many values
+    /// were spilled to and reloaded from the stack, along with each
+    /// function's machine code and constant pool sizes, relocation count and
+    /// compile time. They can be read back with
+    /// [`Module::codegen_stats`](crate::Module::codegen_stats), including
+    /// from precompiled modules, and are meant to guide tuning of the
+    /// baseline compiler and of when to use it. They don't change the
+    /// generated code. This option
+    /// is currently only supported by Winch; Cranelift ignores it.
+    ///
+    /// By default this option is `false`.
+    pub fn codegen_stats(&mut self, enable: bool) -> &mut Self {
+        self.tunables.codegen_stats = enable;
         self
     }
 
@@ -1524,6 +1881,34 @@ impl Config {
         self
     }
 
+    /// Configures whether panics in host functions called from WebAssembly
+    /// are turned into traps.
+    ///
+    /// By default a panic in a host function unwinds through the WebAssembly
+    /// frames which called it and resumes in the host which called into
+    /// WebAssembly, which usually takes the whole thread, or the process, down
+    /// with it. With this option enabled the panic instead stops at the
+    /// innermost call into WebAssembly, which returns an error whose root
+    /// cause is a [`HostPanic`](crate::HostPanic) holding the panic's payload.
+    /// If the host function was itself called from WebAssembly which a host
+    /// function re-entered, only that nested call fails and the host function
+    /// which made it sees the error like any other trap.
+    /// As with other traps, a [`WasmBacktrace`](crate::WasmBacktrace) of the
+    /// WebAssembly frames which led to the host function is attached.
+    ///
+    /// This is meant for embedders running host functions from crates they
+    /// don't control, where a bug in one of them should only fail the guest
+    /// call which triggered it. The store may not be in a consistent state
+    /// after such a failure, so it's generally best to drop it along with its
+    /// instances. Panics can only be caught if they unwind, so this has no
+    /// effect when compiling with `panic = "abort"`.
+    ///
+    /// This option is disabled by default.
+    pub fn catch_host_panics(&mut self, enable: bool) -> &mut Self {
+        self.catch_host_panics = enable;
+        self
+    }
+
     /// Enables memory error checking for wasm programs.
     ///
     /// This option is disabled by default.
@@ -1588,6 +1973,9 @@ impl Config {
         if self.max_wasm_stack == 0 {
             bail!("max_wasm_stack size cannot be zero");
         }
+        if self.max_concurrent_instantiations == Some(0) {
+            bail!("max_concurrent_instantiations cannot be zero");
+        }
         if self.tunables.static_memory_offset_guard_size
             < self.tunables.dynamic_memory_offset_guard_size
         {
@@ -1754,6 +2142,7 @@ impl Config {
 
         compiler.set_tunables(self.tunables.clone())?;
         compiler.wmemcheck(self.compiler_config.wmemcheck);
+        compiler.compile_time_budget(self.compiler_config.compile_time_budget)?;
 
         Ok((self, compiler.build()?))
     }
@@ -1805,6 +2194,28 @@ impl Config {
         self.macos_use_mach_ports = mach_ports;
         self
     }
+
+    /// Configures whether, when on macOS, compiled code is executed from
+    /// memory mapped with `MAP_JIT`.
+    ///
+    /// Processes running under macOS's hardened runtime without the
+    /// `com.apple.security.cs.allow-unsigned-executable-memory` entitlement
+    /// may only make `MAP_JIT` memory executable, and need this option along
+    /// with the `com.apple.security.cs.allow-jit` entitlement.
+    ///
+    /// When enabled the text section of each module is copied into `MAP_JIT`
+    /// memory when it's loaded, which is then made read/execute. This costs
+    /// a copy of the code and means it's no longer backed by the file of
+    /// a module deserialized with
+    /// [`Module::deserialize_file`](crate::Module::deserialize_file), and
+    /// [`Module::image_range`](crate::Module::image_range) no longer covers
+    /// it.
+    ///
+    /// This option defaults to `false` and does nothing on other platforms.
+    pub fn macos_use_map_jit(&mut self, map_jit: bool) -> &mut Self {
+        self.macos_use_map_jit = map_jit;
+        self
+    }
 }
 
 fn round_up_to_pages(val: u64) -> u64 {
@@ -1853,7 +2264,16 @@ impl fmt::Debug for Config {
                 "guard_before_linear_memory",
                 &self.tunables.guard_before_linear_memory,
             )
-            .field("parallel_compilation", &self.parallel_compilation);
+            .field("parallel_compilation", &self.parallel_compilation)
+            .field("compilation_executor", &self.compilation_executor.is_some())
+            .field(
+                "max_concurrent_instantiations",
+                &self.max_concurrent_instantiations,
+            );
+        #[cfg(feature = "module-verification")]
+        {
+            f.field("module_verifier", &self.module_verifier.is_some());
+        }
         #[cfg(any(feature = "cranelift", feature = "winch"))]
         {
             f.field("compiler_config", &self.compiler_config);
@@ -1902,6 +2322,19 @@ pub enum OptLevel {
     SpeedAndSize,
 }
 
+/// Possible register allocation algorithms for the Cranelift codegen backend.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum RegallocAlgorithm {
+    /// Generates the fastest possible code, but may take a long time to
+    /// compile very large functions.
+    Backtracking,
+    /// Keeps every value on the stack between instructions. Compilation time
+    /// is linear in the size of a function, but the generated code is much
+    /// slower.
+    SinglePass,
+}
+
 /// Select which profiling technique to support.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ProfilingStrategy {
@@ -1,4 +1,7 @@
-use std::{collections::HashMap, fmt};
+use std::{collections::HashMap, fmt, io::Write};
+
+use anyhow::Result;
+use wasm_encoder::Encode;
 
 use crate::{
     store::StoreOpaque, AsContextMut, FrameInfo, Global, Instance, Memory, Module, StoreContextMut,
@@ -95,21 +98,62 @@ impl WasmCoreDump {
     /// dumps.
     ///
     /// [spec]: https://github.com/WebAssembly/tool-conventions/blob/main/Coredump.md
-    pub fn serialize(&self, mut store: impl AsContextMut, name: &str) -> Vec<u8> {
+    pub fn serialize(&self, store: impl AsContextMut, name: &str) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.serialize_to(store, name, &mut bytes)
+            .expect("writing to a `Vec` cannot fail");
+        bytes
+    }
+
+    /// Serialize this core dump into [the standard core dump binary
+    /// format][spec] as with [`WasmCoreDump::serialize`], writing it to
+    /// `writer` as it is produced.
+    ///
+    /// Linear memory contents are copied straight from the store to `writer`,
+    /// so no buffer as large as the core dump itself is ever allocated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    ///
+    /// [spec]: https://github.com/WebAssembly/tool-conventions/blob/main/Coredump.md
+    pub fn serialize_to(
+        &self,
+        mut store: impl AsContextMut,
+        name: &str,
+        mut writer: impl Write,
+    ) -> Result<()> {
         let store = store.as_context_mut();
-        self._serialize(store, name)
+        self._serialize_to(store, name, &mut writer)
     }
 
-    fn _serialize<T>(&self, mut store: StoreContextMut<'_, T>, name: &str) -> Vec<u8> {
-        let mut core_dump = wasm_encoder::Module::new();
+    fn _serialize_to<T>(
+        &self,
+        mut store: StoreContextMut<'_, T>,
+        name: &str,
+        out: &mut dyn Write,
+    ) -> Result<()> {
+        // Sections other than the data section are small, so they are encoded
+        // in memory and then written out one at a time.
+        fn section(out: &mut dyn Write, section: &impl wasm_encoder::Section) -> Result<()> {
+            let mut bytes = vec![section.id()];
+            section.encode(&mut bytes);
+            out.write_all(&bytes)?;
+            Ok(())
+        }
 
-        core_dump.section(&wasm_encoder::CoreDumpSection::new(name));
+        out.write_all(&wasm_encoder::Module::new().finish())?;
+        section(out, &wasm_encoder::CoreDumpSection::new(name))?;
 
         // A map from each memory to its index in the core dump's memories
         // section.
         let mut memory_to_idx = HashMap::new();
 
-        let mut data = wasm_encoder::DataSection::new();
+        // The active data segments holding memory contents, each as its
+        // encoded header along with the memory and byte range it copies from.
+        // The contents themselves are only read when the data section is
+        // written out below.
+        let mut segments = Vec::new();
 
         {
             let mut memories = wasm_encoder::MemorySection::new();
@@ -144,11 +188,21 @@ impl WasmCoreDump {
                         let end = chunk.iter().rposition(|byte| *byte != 0).unwrap() + 1;
                         let offset = (i as u32) * CHUNK_SIZE + (start as u32);
                         let offset = wasm_encoder::ConstExpr::i32_const(offset as i32);
-                        data.active(memory_idx, &offset, chunk[start..end].iter().copied());
+                        let mut header = Vec::new();
+                        if memory_idx == 0 {
+                            header.push(0x00);
+                        } else {
+                            header.push(0x02);
+                            memory_idx.encode(&mut header);
+                        }
+                        offset.encode(&mut header);
+                        ((end - start) as u32).encode(&mut header);
+                        let chunk_start = i * CHUNK_SIZE as usize;
+                        segments.push((*mem, header, chunk_start + start..chunk_start + end));
                     }
                 }
             }
-            core_dump.section(&memories);
+            section(out, &memories)?;
         }
 
         // A map from each global to its index in the core dump's globals
@@ -189,11 +243,26 @@ impl WasmCoreDump {
                 };
                 globals.global(wasm_encoder::GlobalType { val_type, mutable }, &init);
             }
-            core_dump.section(&globals);
+            section(out, &globals)?;
         }
 
-        core_dump.section(&data);
-        drop(data);
+        {
+            let mut prefix = Vec::new();
+            (segments.len() as u32).encode(&mut prefix);
+            let size = segments
+                .iter()
+                .fold(prefix.len(), |size, (_, header, range)| {
+                    size + header.len() + range.len()
+                });
+            let mut header = vec![wasm_encoder::SectionId::Data as u8];
+            (size as u32).encode(&mut header);
+            header.extend_from_slice(&prefix);
+            out.write_all(&header)?;
+            for (mem, header, range) in segments {
+                out.write_all(&header)?;
+                out.write_all(&mem.data(&store)[range])?;
+            }
+        }
 
         // A map from module id to its index within the core dump's modules
         // section.
@@ -208,7 +277,7 @@ impl WasmCoreDump {
                     None => modules.module(&format!("<anonymous-module-{}>", modules.len())),
                 };
             }
-            core_dump.section(&modules);
+            section(out, &modules)?;
         }
 
         // TODO: We can't currently recover instances from stack frames. We can
@@ -243,7 +312,7 @@ impl WasmCoreDump {
 
                 instances.instance(module_index, memories, globals);
             }
-            core_dump.section(&instances);
+            section(out, &instances)?;
         }
 
         {
@@ -269,10 +338,10 @@ impl WasmCoreDump {
 
                 stack.frame(instance, func, offset, locals, operand_stack);
             }
-            core_dump.section(&stack);
+            section(out, &stack)?;
         }
 
-        core_dump.finish()
+        Ok(())
     }
 }
 
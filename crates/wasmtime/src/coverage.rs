@@ -0,0 +1,155 @@
+//! Code coverage collected by modules compiled with
+//! [`Config::coverage`](crate::Config::coverage).
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use wasmtime_jit::CompiledModule;
+
+/// Code coverage of a [`Module`](crate::Module), as returned by
+/// [`Module::coverage`](crate::Module::coverage).
+#[derive(Clone, Debug)]
+pub struct ModuleCoverage {
+    functions: Vec<FunctionCoverage>,
+}
+
+/// Code coverage of a single function defined in a module.
+#[derive(Clone, Debug)]
+pub struct FunctionCoverage {
+    index: u32,
+    name: Option<String>,
+    blocks: Vec<BlockCoverage>,
+}
+
+/// How often one block of a function was entered.
+///
+/// Blocks start at the function entry and after each `loop`, `if`, `else`,
+/// `end` and conditional branch operator.
+#[derive(Clone, Debug)]
+pub struct BlockCoverage {
+    offset: u32,
+    count: u64,
+    file: Option<String>,
+    line: Option<u32>,
+    column: Option<u32>,
+}
+
+impl ModuleCoverage {
+    pub(crate) fn new(module: &CompiledModule, counters: &[AtomicU64]) -> ModuleCoverage {
+        #[cfg(feature = "addr2line")]
+        let symbolize = module.symbolize_context().ok().and_then(|c| c);
+
+        let env_module = module.module();
+        let map = &env_module.coverage;
+        let functions = map
+            .functions
+            .keys()
+            .map(|def_index| {
+                let index = env_module.func_index(def_index);
+                let blocks = map
+                    .function_counters(def_index)
+                    .map(|counter| {
+                        let offset = map.positions[counter].file_offset().unwrap();
+                        #[allow(unused_mut)]
+                        let mut block = BlockCoverage {
+                            offset,
+                            count: counters[counter].load(Ordering::Relaxed),
+                            file: None,
+                            line: None,
+                            column: None,
+                        };
+                        // DWARF addresses are relative to the code section.
+                        #[cfg(feature = "addr2line")]
+                        if let Some(s) = &symbolize {
+                            let to_lookup = u64::from(offset) - s.code_section_offset();
+                            if let Ok(Some(location)) = s.addr2line().find_location(to_lookup) {
+                                block.file = location.file.map(|s| s.to_string());
+                                block.line = location.line;
+                                block.column = location.column;
+                            }
+                        }
+                        block
+                    })
+                    .collect();
+                FunctionCoverage {
+                    index: index.as_u32(),
+                    name: module.func_name(index).map(|s| s.to_string()),
+                    blocks,
+                }
+            })
+            .collect();
+        ModuleCoverage { functions }
+    }
+
+    /// Returns the coverage of each function defined in the module, in
+    /// function index order.
+    pub fn functions(&self) -> &[FunctionCoverage] {
+        &self.functions
+    }
+
+    /// Returns the coverage of the function with the given index, if it's
+    /// defined in the module.
+    pub fn function(&self, index: u32) -> Option<&FunctionCoverage> {
+        let first = self.functions.first()?.index;
+        // Defined functions are numbered contiguously after imported ones.
+        let i = index.checked_sub(first)?;
+        self.functions.get(usize::try_from(i).unwrap())
+    }
+}
+
+impl FunctionCoverage {
+    /// Returns the index of this function in the module's function index
+    /// space.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the name of this function from the `name` section, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the number of times this function was called.
+    pub fn calls(&self) -> u64 {
+        self.blocks[0].count
+    }
+
+    /// Returns the coverage of each block of this function, in the order they
+    /// appear in the module. The first block is the function entry.
+    pub fn blocks(&self) -> &[BlockCoverage] {
+        &self.blocks
+    }
+}
+
+impl BlockCoverage {
+    /// Returns the offset, in the original wasm module, of the operator this
+    /// block starts after, or of the first operator of the function for the
+    /// entry block.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the number of times this block was entered.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the source file this block comes from, according to the
+    /// module's DWARF debug information.
+    ///
+    /// This requires [`Config::wasm_backtrace_details`](crate::Config::wasm_backtrace_details)
+    /// to be enabled.
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    /// Returns the source line this block comes from, according to the
+    /// module's DWARF debug information.
+    pub fn line(&self) -> Option<u32> {
+        self.line
+    }
+
+    /// Returns the source column this block comes from, according to the
+    /// module's DWARF debug information.
+    pub fn column(&self) -> Option<u32> {
+        self.column
+    }
+}
@@ -1,3 +1,5 @@
+use crate::admission::{AdmissionControl, AdmissionKind, AdmissionPermit, AdmissionStats, Tenant};
+use crate::shutdown::ShutdownState;
 use crate::signatures::SignatureRegistry;
 use crate::Config;
 use anyhow::{Context, Result};
@@ -9,6 +11,7 @@ use rayon::prelude::*;
 use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use wasmparser::{Encoding, Parser, Payload, Validator, WasmFeatures};
 #[cfg(feature = "cache")]
 use wasmtime_cache::CacheConfig;
 use wasmtime_environ::obj;
@@ -52,8 +55,12 @@ struct EngineInner {
     allocator: Box<dyn InstanceAllocator + Send + Sync>,
     profiler: Box<dyn ProfilingAgent>,
     signatures: SignatureRegistry,
+    #[cfg(any(feature = "cranelift", feature = "winch"))]
+    host_trampolines: crate::trampoline::HostTrampolineCache,
     epoch: AtomicU64,
     unique_id_allocator: CompiledModuleIdAllocator,
+    admission: Option<AdmissionControl>,
+    shutdown: Arc<ShutdownState>,
 
     // One-time check of whether the compiler's settings, if present, are
     // compatible with the native host.
@@ -90,6 +97,9 @@ impl Engine {
 
         let allocator = config.build_allocator()?;
         let profiler = config.build_profiler()?;
+        let admission = config
+            .max_concurrent_instantiations
+            .map(AdmissionControl::new);
 
         Ok(Engine {
             inner: Arc::new(EngineInner {
@@ -99,8 +109,12 @@ impl Engine {
                 allocator,
                 profiler,
                 signatures: registry,
+                #[cfg(any(feature = "cranelift", feature = "winch"))]
+                host_trampolines: Default::default(),
                 epoch: AtomicU64::new(0),
                 unique_id_allocator: CompiledModuleIdAllocator::new(),
+                admission,
+                shutdown: Default::default(),
                 compatible_with_native_host: OnceCell::new(),
             }),
         })
@@ -160,6 +174,11 @@ impl Engine {
         &self.inner.signatures
     }
 
+    #[cfg(any(feature = "cranelift", feature = "winch"))]
+    pub(crate) fn host_trampolines(&self) -> &crate::trampoline::HostTrampolineCache {
+        &self.inner.host_trampolines
+    }
+
     pub(crate) fn epoch_counter(&self) -> &AtomicU64 {
         &self.inner.epoch
     }
@@ -191,10 +210,44 @@ impl Engine {
         self.inner.epoch.fetch_add(1, Ordering::Relaxed);
     }
 
+    pub(crate) fn shutdown_state(&self) -> &Arc<ShutdownState> {
+        &self.inner.shutdown
+    }
+
     pub(crate) fn unique_id_allocator(&self) -> &CompiledModuleIdAllocator {
         &self.inner.unique_id_allocator
     }
 
+    /// Returns statistics about how instantiations and compilations have been
+    /// admitted, or `None` if
+    /// [`Config::max_concurrent_instantiations`](crate::Config::max_concurrent_instantiations)
+    /// isn't configured.
+    pub fn admission_stats(&self) -> Option<AdmissionStats> {
+        self.inner.admission.as_ref().map(|a| a.stats())
+    }
+
+    /// Waits, blocking the current thread, until an instantiation or
+    /// compilation on behalf of `tenant` may start.
+    pub(crate) fn admit(&self, tenant: Tenant, kind: AdmissionKind) -> Option<AdmissionPermit<'_>> {
+        self.inner
+            .admission
+            .as_ref()
+            .map(|a| a.acquire(tenant, kind))
+    }
+
+    /// Asynchronous version of [`Engine::admit`].
+    #[cfg(feature = "async")]
+    pub(crate) async fn admit_async(
+        &self,
+        tenant: Tenant,
+        kind: AdmissionKind,
+    ) -> Option<AdmissionPermit<'_>> {
+        match &self.inner.admission {
+            Some(a) => Some(a.acquire_async(tenant, kind).await),
+            None => None,
+        }
+    }
+
     /// Ahead-of-time (AOT) compiles a WebAssembly module.
     ///
     /// The `bytes` provided must be in one of two formats:
@@ -222,7 +275,8 @@ impl Engine {
     pub fn precompile_module(&self, bytes: &[u8]) -> Result<Vec<u8>> {
         #[cfg(feature = "wat")]
         let bytes = wat::parse_bytes(&bytes)?;
-        let (mmap, _) = crate::Module::build_artifacts(self, &bytes)?;
+        self.verify_input(&bytes, ObjectKind::Module, false)?;
+        let (mmap, _) = crate::Module::build_artifacts(self, &bytes, None)?;
         Ok(mmap.to_vec())
     }
 
@@ -235,6 +289,7 @@ impl Engine {
     pub fn precompile_component(&self, bytes: &[u8]) -> Result<Vec<u8>> {
         #[cfg(feature = "wat")]
         let bytes = wat::parse_bytes(&bytes)?;
+        self.verify_input(&bytes, ObjectKind::Component, false)?;
         let (mmap, _) = crate::component::Component::build_artifacts(self, &bytes)?;
         Ok(mmap.to_vec())
     }
@@ -261,6 +316,17 @@ impl Engine {
         input: Vec<A>,
         f: F,
     ) -> Result<Vec<B>, E> {
+        if let Some(executor) = &self.config().compilation_executor {
+            let f = &f;
+            let tasks: Vec<Box<dyn FnOnce() -> Result<B, E> + Send + '_>> = input
+                .into_iter()
+                .map(|a| Box::new(move || f(a)) as _)
+                .collect();
+            return crate::executor::execute(&**executor, tasks)
+                .into_iter()
+                .collect();
+        }
+
         if self.config().parallel_compilation {
             #[cfg(feature = "parallel-compilation")]
             return input
@@ -279,6 +345,9 @@ impl Engine {
 
     /// Executes `f1` and `f2` in parallel if parallel compilation is enabled at
     /// both runtime and compile time, otherwise runs them synchronously.
+    ///
+    /// A configured compilation executor is always given both closures to
+    /// run.
     #[allow(dead_code)] // only used for the component-model feature right now
     pub(crate) fn join_maybe_parallel<T, U>(
         &self,
@@ -289,6 +358,15 @@ impl Engine {
         T: Send,
         U: Send,
     {
+        if let Some(executor) = &self.config().compilation_executor {
+            let mut t = None;
+            let mut u = None;
+            let tasks: Vec<Box<dyn FnOnce() + Send + '_>> =
+                vec![Box::new(|| t = Some(f1())), Box::new(|| u = Some(f2()))];
+            crate::executor::execute(&**executor, tasks);
+            return (t.unwrap(), u.unwrap());
+        }
+
         if self.config().parallel_compilation {
             #[cfg(feature = "parallel-compilation")]
             return rayon::join(f1, f2);
@@ -420,16 +498,21 @@ impl Engine {
             | "enable_pcc"
             | "regalloc_checker"
             | "regalloc_verbose_logs"
+            | "regalloc_max_insts_log2" // only decides whether a function compiles
+            | "regalloc_algorithm" // shouldn't change semantics
+            | "regalloc_single_pass_insts_log2" // shouldn't change semantics
             | "is_pic"
             | "bb_padding_log2_minus_one"
             | "machine_code_cfg_info"
             | "tls_model" // wasmtime doesn't use tls right now
             | "opt_level" // opt level doesn't change semantics
             | "enable_alias_analysis" // alias analysis-based opts don't change semantics
+            | "enable_instruction_scheduling" // scheduling doesn't change semantics
             | "probestack_func_adjusts_sp" // probestack above asserted disabled
             | "probestack_size_log2" // probestack above asserted disabled
             | "regalloc" // shouldn't change semantics
             | "enable_incremental_compilation_cache_checks" // shouldn't change semantics
+            | "force_seq_cst_atomics" // only ever strengthens atomics
             | "enable_atomics" => return Ok(()),
 
             // Everything else is unknown and needs to be added somewhere to
@@ -482,6 +565,11 @@ impl Engine {
         {
             enabled = match flag {
                 "has_lse" => Some(std::arch::is_aarch64_feature_detected!("lse")),
+                "has_dotprod" => Some(std::arch::is_aarch64_feature_detected!("dotprod")),
+                "has_rcpc" => Some(std::arch::is_aarch64_feature_detected!("rcpc")),
+                // Atomics check at runtime whether LSE is available, so this
+                // is safe to enable on any host.
+                "outline_atomics" => Some(true),
                 // No effect on its own, but in order to simplify the code on a
                 // platform without pointer authentication support we fail if
                 // "has_pauth" is enabled, but "sign_return_address" is not.
@@ -551,6 +639,7 @@ impl Engine {
                 "has_avx512f" => Some(std::is_x86_feature_detected!("avx512f")),
                 "has_avx512vl" => Some(std::is_x86_feature_detected!("avx512vl")),
                 "has_avx512vbmi" => Some(std::is_x86_feature_detected!("avx512vbmi")),
+                "has_avx512vnni" => Some(std::is_x86_feature_detected!("avx512vnni")),
                 "has_lzcnt" => Some(std::is_x86_feature_detected!("lzcnt")),
 
                 // fall through to the very bottom to indicate that support is
@@ -612,24 +701,58 @@ impl Engine {
     }
 
     /// Like `load_code_bytes`, but creates a mmap from a file on disk.
+    ///
+    /// Unlike `load_code_bytes` the file's contents are passed to the
+    /// configured module verifier, if any, since they're only available here.
     pub(crate) fn load_code_file(
         &self,
         path: &Path,
         expected: ObjectKind,
     ) -> Result<Arc<CodeMemory>> {
-        self.load_code(
-            MmapVec::from_file(path).with_context(|| {
-                format!("failed to create file mapping for: {}", path.display())
-            })?,
-            expected,
-        )
+        let mmap = MmapVec::from_file(path)
+            .with_context(|| format!("failed to create file mapping for: {}", path.display()))?;
+        self.verify_input(&mmap, expected, true)?;
+        self.load_code(mmap, expected)
+    }
+
+    /// Passes untrusted `bytes` to the configured
+    /// [`ModuleVerifier`](crate::ModuleVerifier), if any.
+    ///
+    /// The `precompiled` flag is whether `bytes` is a precompiled artifact as
+    /// opposed to wasm which is about to be compiled.
+    pub(crate) fn verify_input(
+        &self,
+        bytes: &[u8],
+        expected: ObjectKind,
+        precompiled: bool,
+    ) -> Result<()> {
+        #[cfg(feature = "module-verification")]
+        if let Some(verifier) = &self.config().module_verifier {
+            use crate::verification::{VerificationInput, VerificationKind};
+            let kind = match (expected, precompiled) {
+                (ObjectKind::Module, false) => VerificationKind::Module,
+                (ObjectKind::Component, false) => VerificationKind::Component,
+                (ObjectKind::Module, true) => VerificationKind::PrecompiledModule,
+                (ObjectKind::Component, true) => VerificationKind::PrecompiledComponent,
+            };
+            verifier.verify(&VerificationInput::new(bytes, kind)?)?;
+        }
+        #[cfg(not(feature = "module-verification"))]
+        let _ = (bytes, expected, precompiled);
+        Ok(())
     }
 
     pub(crate) fn load_code(&self, mmap: MmapVec, expected: ObjectKind) -> Result<Arc<CodeMemory>> {
         serialization::check_compatible(self, &mmap, expected)?;
+        Ok(Arc::new(self.publish_code(mmap)?))
+    }
+
+    /// Makes the compiled image in `mmap` ready for execution.
+    pub(crate) fn publish_code(&self, mmap: MmapVec) -> Result<CodeMemory> {
         let mut code = CodeMemory::new(mmap)?;
+        code.use_map_jit(self.config().macos_use_map_jit);
         code.publish()?;
-        Ok(Arc::new(code))
+        Ok(code)
     }
 
     /// Detects whether the bytes provided are a precompiled object produced by
@@ -656,8 +779,85 @@ impl Engine {
     pub fn detect_precompiled_file(&self, path: impl AsRef<Path>) -> Result<Option<Precompiled>> {
         serialization::detect_precompiled_file(path)
     }
+
+    /// Validates a WebAssembly module or component without compiling it, and
+    /// reports which WebAssembly features it requires.
+    ///
+    /// The `bytes` provided must be in the [binary format][binary], or in the
+    /// [text format][text] if the `wat` feature of this crate is enabled.
+    /// Validation is performed with the features enabled in this engine's
+    /// [`Config`], so `Ok` means that compiling `bytes` with this engine will
+    /// not fail validation.
+    ///
+    /// Determining the required features revalidates `bytes` once for each
+    /// enabled feature, which is still much cheaper than compilation and lets
+    /// embedders such as gateways reject or route inputs up front.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not valid with the features enabled in
+    /// this engine.
+    ///
+    /// [binary]: https://webassembly.github.io/spec/core/binary/index.html
+    /// [text]: https://webassembly.github.io/spec/core/text/index.html
+    pub fn validate(&self, bytes: &[u8]) -> Result<ValidationReport> {
+        #[cfg(feature = "wat")]
+        let bytes = wat::parse_bytes(&bytes)?;
+        let enabled = self.config().features;
+        Validator::new_with_features(enabled).validate_all(&bytes)?;
+
+        let component = matches!(
+            Parser::new(0).parse_all(&bytes).next(),
+            Some(Ok(Payload::Version {
+                encoding: Encoding::Component,
+                ..
+            }))
+        );
+
+        // A feature is required if the input no longer validates without it.
+        let mut required_features = Vec::new();
+        for (name, flag) in PROBED_FEATURES {
+            let mut features = enabled;
+            if !*flag(&mut features) {
+                continue;
+            }
+            *flag(&mut features) = false;
+            if Validator::new_with_features(features)
+                .validate_all(&bytes)
+                .is_err()
+            {
+                required_features.push(*name);
+            }
+        }
+
+        Ok(ValidationReport {
+            component,
+            required_features,
+        })
+    }
 }
 
+/// WebAssembly features reported by [`Engine::validate`], along with the
+/// names used for them by the `wasmtime` CLI.
+const PROBED_FEATURES: &[(&str, fn(&mut WasmFeatures) -> &mut bool)] = &[
+    ("mutable-global", |f| &mut f.mutable_global),
+    ("saturating-float-to-int", |f| {
+        &mut f.saturating_float_to_int
+    }),
+    ("sign-extension", |f| &mut f.sign_extension),
+    ("bulk-memory", |f| &mut f.bulk_memory),
+    ("multi-value", |f| &mut f.multi_value),
+    ("reference-types", |f| &mut f.reference_types),
+    ("function-references", |f| &mut f.function_references),
+    ("simd", |f| &mut f.simd),
+    ("relaxed-simd", |f| &mut f.relaxed_simd),
+    ("threads", |f| &mut f.threads),
+    ("tail-call", |f| &mut f.tail_call),
+    ("multi-memory", |f| &mut f.multi_memory),
+    ("memory64", |f| &mut f.memory64),
+    ("component-model", |f| &mut f.component_model),
+];
+
 impl Default for Engine {
     fn default() -> Engine {
         Engine::new(&Config::default()).unwrap()
@@ -673,6 +873,36 @@ pub enum Precompiled {
     Component,
 }
 
+/// Return value from the [`Engine::validate`] API.
+#[derive(Clone, Debug)]
+pub struct ValidationReport {
+    component: bool,
+    required_features: Vec<&'static str>,
+}
+
+impl ValidationReport {
+    /// Returns whether the validated input is a component rather than a core
+    /// wasm module.
+    pub fn is_component(&self) -> bool {
+        self.component
+    }
+
+    /// Returns the names of the WebAssembly features which the validated
+    /// input uses, such as `"simd"` or `"reference-types"`.
+    ///
+    /// Names match those accepted by the `-W` option of the `wasmtime` CLI.
+    /// Only features enabled in the engine that performed validation can be
+    /// reported.
+    pub fn required_features(&self) -> &[&'static str] {
+        &self.required_features
+    }
+
+    /// Returns whether the validated input requires the named feature.
+    pub fn requires(&self, feature: &str) -> bool {
+        self.required_features.contains(&feature)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -346,18 +346,34 @@ impl Metadata<'_> {
             guard_before_linear_memory,
             relaxed_simd_deterministic,
             tail_callable,
+            coverage,
+            memory_trace,
+            block_trace,
 
             // This doesn't affect compilation, it's just a runtime setting.
             dynamic_memory_growth_reserve: _,
 
             // This does technically affect compilation but modules with/without
-            // trap information can be loaded into engines with the opposite
-            // setting just fine (it's just a section in the compiled file and
-            // whether it's present or not)
-            generate_address_map: _,
+            // address map information of any granularity can be loaded into
+            // engines with a different setting just fine (it's just a section
+            // in the compiled file and whether it's present or not)
+            address_map_granularity: _,
 
             // Just a debugging aid, doesn't affect functionality at all.
             debug_adapter_modules: _,
+
+            // Compressed sections are recorded in the artifact itself and are
+            // transparently decompressed when loaded, so artifacts with and
+            // without compression can be loaded into any engine.
+            compress_artifacts: _,
+
+            // Only determines whether a module compiles at all, not how it's
+            // compiled.
+            max_function_body_size: _,
+
+            // Statistics are only metadata in the compiled artifact and don't
+            // change the generated code.
+            codegen_stats: _,
         } = self.tunables;
 
         Self::check_int(
@@ -407,6 +423,9 @@ impl Metadata<'_> {
             "relaxed simd deterministic semantics",
         )?;
         Self::check_bool(tail_callable, other.tail_callable, "WebAssembly tail calls")?;
+        Self::check_bool(coverage, other.coverage, "code coverage")?;
+        Self::check_bool(memory_trace, other.memory_trace, "memory tracing")?;
+        Self::check_bool(block_trace, other.block_trace, "block tracing")?;
 
         Ok(())
     }
@@ -0,0 +1,163 @@
+//! Pluggable execution of parallel compilation work.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// A unit of compilation work handed to a [`CompilationExecutor`].
+///
+/// Tasks borrow from the compilation in progress, so they must have finished
+/// running by the time [`CompilationExecutor::execute`] returns.
+pub type CompilationTask<'a> = Box<dyn FnOnce() + Send + 'a>;
+
+/// An executor for the work of compiling modules and components.
+///
+/// By default Wasmtime compiles functions in parallel on rayon's global thread
+/// pool when the `parallel-compilation` feature is enabled. Embedders which
+/// manage their own threads can instead configure an executor with
+/// [`Config::compilation_executor`](crate::Config::compilation_executor), so
+/// that compilation doesn't compete with the application's own pools.
+///
+/// Wasmtime provides a [`SerialExecutor`], which compiles deterministically on
+/// the calling thread, and a [`ScopedThreadsExecutor`], which compiles on a
+/// fixed number of threads. Executors over an existing thread pool are
+/// typically a few lines long; for example with a rayon pool:
+///
+/// ```ignore
+/// struct RayonExecutor(rayon::ThreadPool);
+///
+/// impl CompilationExecutor for RayonExecutor {
+///     fn execute<'a>(&self, tasks: Vec<CompilationTask<'a>>) {
+///         self.0.scope(|s| {
+///             for task in tasks {
+///                 s.spawn(move |_| task());
+///             }
+///         });
+///     }
+/// }
+/// ```
+pub trait CompilationExecutor: Send + Sync {
+    /// Runs all of `tasks` to completion, in any order and on any threads,
+    /// before returning.
+    ///
+    /// Tasks are independent of each other, so an executor may run them
+    /// concurrently. Tasks are listed in the order which compiles most
+    /// deterministically, which is the order a serial executor should use.
+    ///
+    /// # Panics
+    ///
+    /// Wasmtime panics if this method returns without having run every task.
+    fn execute<'a>(&self, tasks: Vec<CompilationTask<'a>>);
+}
+
+/// A [`CompilationExecutor`] which runs all compilation on the calling thread,
+/// in order.
+///
+/// Compiling serially is deterministic and doesn't spawn any threads.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SerialExecutor;
+
+impl CompilationExecutor for SerialExecutor {
+    fn execute<'a>(&self, tasks: Vec<CompilationTask<'a>>) {
+        for task in tasks {
+            task();
+        }
+    }
+}
+
+/// A [`CompilationExecutor`] which spawns a fixed number of scoped threads for
+/// each batch of compilation work.
+///
+/// Threads can be configured, for example to lower their scheduling priority,
+/// with [`ScopedThreadsExecutor::on_thread_start`].
+#[derive(Clone)]
+pub struct ScopedThreadsExecutor {
+    threads: usize,
+    on_thread_start: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl ScopedThreadsExecutor {
+    /// Creates an executor which uses up to `threads` threads for each batch
+    /// of compilation work.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero.
+    pub fn new(threads: usize) -> ScopedThreadsExecutor {
+        assert!(
+            threads > 0,
+            "compilation executor needs at least one thread"
+        );
+        ScopedThreadsExecutor {
+            threads,
+            on_thread_start: None,
+        }
+    }
+
+    /// Configures a function to run at the start of each spawned thread,
+    /// before it runs any compilation work.
+    ///
+    /// This is where embedders can adjust the thread's priority or affinity
+    /// with the platform's APIs.
+    pub fn on_thread_start(&mut self, f: impl Fn() + Send + Sync + 'static) -> &mut Self {
+        self.on_thread_start = Some(Arc::new(f));
+        self
+    }
+}
+
+impl CompilationExecutor for ScopedThreadsExecutor {
+    fn execute<'a>(&self, tasks: Vec<CompilationTask<'a>>) {
+        let threads = self.threads.min(tasks.len());
+        if threads <= 1 && self.on_thread_start.is_none() {
+            return SerialExecutor.execute(tasks);
+        }
+        let tasks = tasks
+            .into_iter()
+            .map(|task| Mutex::new(Some(task)))
+            .collect::<Vec<_>>();
+        let next = AtomicUsize::new(0);
+        std::thread::scope(|s| {
+            for _ in 0..threads {
+                s.spawn(|| {
+                    if let Some(f) = &self.on_thread_start {
+                        f();
+                    }
+                    while let Some(task) = tasks.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        if let Some(task) = task.lock().unwrap().take() {
+                            task();
+                        }
+                    }
+                });
+            }
+        });
+    }
+}
+
+impl std::fmt::Debug for ScopedThreadsExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScopedThreadsExecutor")
+            .field("threads", &self.threads)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Runs `tasks` with `executor`, returning the output of each task in order.
+pub(crate) fn execute<'a, T: Send + 'a>(
+    executor: &dyn CompilationExecutor,
+    tasks: Vec<Box<dyn FnOnce() -> T + Send + 'a>>,
+) -> Vec<T> {
+    let slots = tasks.iter().map(|_| Mutex::new(None)).collect::<Vec<_>>();
+    let wrapped: Vec<CompilationTask<'_>> = tasks
+        .into_iter()
+        .zip(&slots)
+        .map(|(task, slot)| Box::new(move || *slot.lock().unwrap() = Some(task())) as _)
+        .collect();
+    executor.execute(wrapped);
+    slots
+        .into_iter()
+        .map(|slot| {
+            slot.into_inner()
+                .unwrap()
+                .expect("compilation executor returned without running all tasks")
+        })
+        .collect()
+}
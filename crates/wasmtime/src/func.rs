@@ -1,4 +1,4 @@
-use crate::store::{StoreData, StoreOpaque, Stored};
+use crate::store::{HostCall, StoreData, StoreOpaque, Stored};
 use crate::{
     AsContext, AsContextMut, CallHook, Engine, Extern, FuncType, Instance, Module, StoreContext,
     StoreContextMut, Val, ValRaw, ValType,
@@ -69,6 +69,11 @@ use wasmtime_runtime::{
 ///   This eschews runtime checks as much as possible to get into wasm as fast
 ///   as possible.
 ///
+/// If the signature isn't statically known but the same function is called
+/// many times, [`Func::call_buffered`] sits in between the two: the type is
+/// looked up once to create a [`CallBuffer`] which is then reused for each
+/// call.
+///
 /// # Examples
 ///
 /// One way to get a `Func` is from an [`Instance`] after you've instantiated
@@ -179,6 +184,61 @@ use wasmtime_runtime::{
 #[repr(transparent)] // here for the C API
 pub struct Func(Stored<FuncData>);
 
+/// Reusable storage for the arguments and results of a function, used with
+/// [`Func::call_buffered`].
+///
+/// A `CallBuffer` is created with [`Func::call_buffer`] and caches the
+/// signature of that function, so that calling it repeatedly doesn't need to
+/// look up its type or allocate storage each time. Parameters are written
+/// with [`CallBuffer::params_mut`] before each call, and after a successful
+/// call the results can be read with [`CallBuffer::results`].
+pub struct CallBuffer {
+    func: Func,
+    param_tys: Box<[ValType]>,
+    result_tys: Box<[ValType]>,
+    params: Box<[Val]>,
+    results: Box<[Val]>,
+    raw: Vec<ValRaw>,
+    externref_params: usize,
+}
+
+impl CallBuffer {
+    /// Returns the parameters which will be passed to the next call.
+    pub fn params(&self) -> &[Val] {
+        &self.params
+    }
+
+    /// Returns the parameters which will be passed to the next call, for
+    /// writing.
+    ///
+    /// Values written here must have the same types as the function's
+    /// parameters, or the next call will return an error.
+    pub fn params_mut(&mut self) -> &mut [Val] {
+        &mut self.params
+    }
+
+    /// Returns the results of the last successful call.
+    ///
+    /// Before the first call, or if the last call failed, the contents of
+    /// the results are unspecified.
+    pub fn results(&self) -> &[Val] {
+        &self.results
+    }
+}
+
+/// Returns the zero value of `ty`, or null for reference types.
+fn default_val(ty: &ValType) -> Val {
+    match ty {
+        ValType::I32 => Val::I32(0),
+        ValType::I64 => Val::I64(0),
+        ValType::F32 => Val::F32(0),
+        ValType::F64 => Val::F64(0),
+        ValType::V128 => Val::V128(0u128.into()),
+        ValType::FuncRef => Val::FuncRef(None),
+        ValType::ExternRef => Val::ExternRef(None),
+    }
+}
+
 pub(crate) struct FuncData {
     kind: FuncKind,
 
@@ -271,6 +331,31 @@ macro_rules! for_each_function_signature {
     };
 }
 
+// Multi-value results, and the parameters of typed functions, support more
+// elements than host function parameters, each arity of which also generates a
+// `wrapN_async` method and an `IntoFunc` impl.
+macro_rules! for_each_result_signature {
+    ($mac:ident) => {
+        for_each_function_signature!($mac);
+        $mac!(17 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17);
+        $mac!(18 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18);
+        $mac!(19 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19);
+        $mac!(20 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20);
+        $mac!(21 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21);
+        $mac!(22 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22);
+        $mac!(23 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22 A23);
+        $mac!(24 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22 A23 A24);
+        $mac!(25 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22 A23 A24 A25);
+        $mac!(26 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22 A23 A24 A25 A26);
+        $mac!(27 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22 A23 A24 A25 A26 A27);
+        $mac!(28 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22 A23 A24 A25 A26 A27 A28);
+        $mac!(29 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22 A23 A24 A25 A26 A27 A28 A29);
+        $mac!(30 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22 A23 A24 A25 A26 A27 A28 A29 A30);
+        $mac!(31 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22 A23 A24 A25 A26 A27 A28 A29 A30 A31);
+        $mac!(32 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11 A12 A13 A14 A15 A16 A17 A18 A19 A20 A21 A22 A23 A24 A25 A26 A27 A28 A29 A30 A31 A32);
+    };
+}
+
 mod typed;
 pub use typed::*;
 
@@ -543,6 +628,11 @@ impl Func {
     /// | `T`               | `T`                     | a single return value |
     /// | `(T1, T2, ...)`   | `T1 T2 ...`             | multiple returns      |
     ///
+    /// Tuples of up to 32 elements are supported as return types. Structs
+    /// whose fields are all wasm types can also be returned by deriving
+    /// [`WasmResults`](derive@crate::WasmResults) for them, in which case the
+    /// fields are the results in declaration order.
+    ///
     /// Note that all return types can also be wrapped in `Result<_>` to
     /// indicate that the host function can generate a trap as well as possibly
     /// returning a value.
@@ -1077,6 +1167,159 @@ impl Func {
         Ok(())
     }
 
+    /// Creates a [`CallBuffer`] for calling this function with
+    /// [`Func::call_buffered`].
+    ///
+    /// The buffer's parameters are initialized to zero, or null for
+    /// reference types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this function.
+    pub fn call_buffer(&self, store: impl AsContext) -> CallBuffer {
+        let ty = self.ty(store);
+        let param_tys: Box<[ValType]> = ty.params().collect();
+        let result_tys: Box<[ValType]> = ty.results().collect();
+        CallBuffer {
+            func: *self,
+            params: param_tys.iter().map(default_val).collect(),
+            results: result_tys.iter().map(|_| Val::null()).collect(),
+            raw: vec![ValRaw::i32(0); param_tys.len().max(result_tys.len())],
+            externref_params: ty.as_wasm_func_type().externref_params_count(),
+            param_tys,
+            result_tys,
+        }
+    }
+
+    /// Invokes this function with the parameters in `buffer`, writing the
+    /// results back into `buffer`.
+    ///
+    /// This is a faster alternative to [`Func::call`] for hosts which call a
+    /// function repeatedly without knowing its signature statically. The
+    /// function's type is looked up only once, when `buffer` is created with
+    /// [`Func::call_buffer`], and the storage for arguments and results is
+    /// reused from call to call rather than being allocated each time. Each
+    /// call still checks that the parameters in `buffer` have the right types,
+    /// but against the cached signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buffer` was created for a different function or
+    /// if its parameters have the wrong types. For other errors see the
+    /// [`Func::call`] documentation.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if called on a function belonging to an async
+    /// store, in which case [`Func::call_buffered_async`] must be used. Also
+    /// panics if `store` does not own this function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use wasmtime::*;
+    /// # fn main() -> anyhow::Result<()> {
+    /// let engine = Engine::default();
+    /// let mut store = Store::new(&engine, ());
+    /// let add = Func::wrap(&mut store, |a: i32, b: i32| a + b);
+    ///
+    /// let mut buffer = add.call_buffer(&store);
+    /// for i in 0..10 {
+    ///     buffer.params_mut()[0] = Val::I32(i);
+    ///     buffer.params_mut()[1] = Val::I32(1);
+    ///     add.call_buffered(&mut store, &mut buffer)?;
+    ///     assert_eq!(buffer.results()[0].unwrap_i32(), i + 1);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn call_buffered(
+        &self,
+        mut store: impl AsContextMut,
+        buffer: &mut CallBuffer,
+    ) -> Result<()> {
+        assert!(
+            !store.as_context().async_support(),
+            "must use `call_buffered_async` when async support is enabled on the config",
+        );
+        self.call_buffered_impl(&mut store.as_context_mut(), buffer)
+    }
+
+    /// Same as [`Func::call_buffered`], except for use with asynchronous
+    /// stores.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is called on a function in a synchronous store. Also
+    /// panics if `store` does not own this function.
+    #[cfg(feature = "async")]
+    #[cfg_attr(nightlydoc, doc(cfg(feature = "async")))]
+    pub async fn call_buffered_async<T>(
+        &self,
+        mut store: impl AsContextMut<Data = T>,
+        buffer: &mut CallBuffer,
+    ) -> Result<()>
+    where
+        T: Send,
+    {
+        let mut store = store.as_context_mut();
+        assert!(
+            store.0.async_support(),
+            "cannot use `call_buffered_async` without enabling async support in the config",
+        );
+        store
+            .on_fiber(|store| self.call_buffered_impl(store, buffer))
+            .await?
+    }
+
+    fn call_buffered_impl<T>(
+        &self,
+        store: &mut StoreContextMut<'_, T>,
+        buffer: &mut CallBuffer,
+    ) -> Result<()> {
+        if buffer.func.0 != self.0 {
+            bail!("call buffer was created for a different function");
+        }
+        for (ty, arg) in buffer.param_tys.iter().zip(&buffer.params) {
+            if arg.ty() != *ty {
+                bail!(
+                    "argument type mismatch: found {} but expected {}",
+                    arg.ty(),
+                    ty
+                );
+            }
+            if !arg.comes_from_same_store(store.0) {
+                bail!("cross-`Store` values are not currently supported");
+            }
+        }
+
+        // See `call_impl` for why this GC may be necessary.
+        if buffer.externref_params
+            > store
+                .0
+                .externref_activations_table()
+                .bump_capacity_remaining()
+        {
+            store.gc();
+        }
+
+        for (arg, slot) in buffer.params.iter().zip(&mut buffer.raw) {
+            unsafe {
+                *slot = arg.to_raw(&mut *store);
+            }
+        }
+
+        unsafe {
+            self.call_unchecked(&mut *store, buffer.raw.as_mut_ptr(), buffer.raw.len())?;
+        }
+
+        let results = buffer.results.iter_mut().zip(buffer.result_tys.iter());
+        for ((slot, ty), raw) in results.zip(&buffer.raw) {
+            *slot = unsafe { Val::from_raw(&mut *store, *raw, ty.clone()) };
+        }
+        Ok(())
+    }
+
     #[inline]
     pub(crate) fn vm_func_ref(&self, store: &mut StoreOpaque) -> NonNull<VMFuncRef> {
         let func_data = &mut store.store_data_mut()[self.0];
@@ -1362,6 +1605,7 @@ pub(crate) fn invoke_wasm_and_catch_traps<T>(
     closure: impl FnMut(*mut VMContext),
 ) -> Result<()> {
     unsafe {
+        let _active = store.0.wasm_activity().enter_wasm();
         let exit = enter_wasm(store);
 
         if let Err(trap) = store.0.call_hook(CallHook::CallingWasm) {
@@ -1372,6 +1616,7 @@ pub(crate) fn invoke_wasm_and_catch_traps<T>(
             store.0.signal_handler(),
             store.0.engine().config().wasm_backtrace,
             store.0.engine().config().coredump_on_trap,
+            store.0.engine().config().catch_host_panics,
             store.0.default_caller(),
             closure,
         );
@@ -1669,7 +1914,7 @@ macro_rules! impl_wasm_host_results {
     )
 }
 
-for_each_function_signature!(impl_wasm_host_results);
+for_each_result_signature!(impl_wasm_host_results);
 
 // Internal trait representing how to communicate tuples of return values across
 // an ABI boundary. This internally corresponds to the "wasmtime" ABI inside of
@@ -1776,7 +2021,7 @@ macro_rules! impl_host_abi {
     }};
 }
 
-for_each_function_signature!(impl_host_abi);
+for_each_result_signature!(impl_host_abi);
 
 /// Internal trait implemented for all arguments that can be passed to
 /// [`Func::wrap`] and [`Linker::func_wrap`](crate::Linker::func_wrap).
@@ -1788,6 +2033,20 @@ pub trait IntoFunc<T, Params, Results>: Send + Sync + 'static {
     /// internal `VMFuncRef`.
     #[doc(hidden)]
     fn into_func(self, engine: &Engine) -> HostContext;
+
+    /// Same as `into_func`, but additionally runs the hooks registered with
+    /// [`Store::host_call_hook`](crate::Store::host_call_hook) for the
+    /// function's `(module, name)` before each call.
+    #[doc(hidden)]
+    fn into_named_func(self, engine: &Engine, module: &str, name: &str) -> HostContext;
+}
+
+/// The state stored in a `VMNativeCallHostFuncContext` for a function created
+/// through `IntoFunc`: the closure itself and, for functions defined in a
+/// `Linker`, the name that host call hooks match against.
+struct WrappedHostFunc<F> {
+    func: F,
+    hook_name: Option<Box<(String, String)>>,
 }
 
 /// A structure representing the caller's context when creating a function
@@ -1812,7 +2071,7 @@ pub trait IntoFunc<T, Params, Results>: Send + Sync + 'static {
 /// recommended to use this type.
 pub struct Caller<'a, T> {
     pub(crate) store: StoreContextMut<'a, T>,
-    caller: &'a wasmtime_runtime::Instance,
+    pub(crate) caller: &'a wasmtime_runtime::Instance,
 }
 
 impl<T> Caller<'_, T> {
@@ -1902,6 +2161,15 @@ impl<T> Caller<'_, T> {
         self.store.get_fuel()
     }
 
+    /// Returns the cancellation token of the async call which invoked this
+    /// host function, or `None` if it wasn't invoked from an async call.
+    ///
+    /// For more information see [`CancelToken`](crate::CancelToken).
+    #[cfg(feature = "async")]
+    pub fn cancel_token(&self) -> Option<crate::CancelToken> {
+        self.store.cancel_token()
+    }
+
     /// Set the amount of fuel in this store to be consumed when executing wasm code.
     ///
     /// For more information see [`Store::set_fuel`](crate::Store::set_fuel)
@@ -1950,6 +2218,14 @@ macro_rules! impl_into_func {
 
                 f.into_func(engine)
             }
+
+            fn into_named_func(self, engine: &Engine, module: &str, name: &str) -> HostContext {
+                let f = move |_: Caller<'_, T>, $($args:$args),*| {
+                    self($($args),*)
+                };
+
+                f.into_named_func(engine, module, name)
+            }
         }
 
         #[allow(non_snake_case)]
@@ -1960,6 +2236,34 @@ macro_rules! impl_into_func {
             R: WasmRet,
         {
             fn into_func(self, engine: &Engine) -> HostContext {
+                <Self as IntoHostContext<T, (Caller<'_, T>, $($args,)*), R>>::into_host_context(
+                    self,
+                    engine,
+                    None,
+                )
+            }
+
+            fn into_named_func(self, engine: &Engine, module: &str, name: &str) -> HostContext {
+                <Self as IntoHostContext<T, (Caller<'_, T>, $($args,)*), R>>::into_host_context(
+                    self,
+                    engine,
+                    Some(Box::new((module.to_string(), name.to_string()))),
+                )
+            }
+        }
+
+        #[allow(non_snake_case)]
+        impl<T, F, $($args,)* R> IntoHostContext<T, (Caller<'_, T>, $($args,)*), R> for F
+        where
+            F: Fn(Caller<'_, T>, $($args),*) -> R + Send + Sync + 'static,
+            $($args: WasmTy,)*
+            R: WasmRet,
+        {
+            fn into_host_context(
+                self,
+                engine: &Engine,
+                hook_name: Option<Box<(String, String)>>,
+            ) -> HostContext {
                 /// This shim is a regular, non-closure function we can stuff
                 /// inside `VMFuncRef::native_call`.
                 ///
@@ -1999,14 +2303,30 @@ macro_rules! impl_into_func {
                         // Double-check ourselves in debug mode, but we control
                         // the `Any` here so an unsafe downcast should also
                         // work.
-                        debug_assert!(state.is::<F>());
-                        let func = &*(state as *const _ as *const F);
+                        debug_assert!(state.is::<WrappedHostFunc<F>>());
+                        let state = &*(state as *const _ as *const WrappedHostFunc<F>);
+                        let func = &state.func;
 
                         let ret = {
                             panic::catch_unwind(AssertUnwindSafe(|| {
                                 if let Err(trap) = caller.store.0.call_hook(CallHook::CallingHost) {
                                     return R::fallible_from_error(trap);
                                 }
+                                if let Some(hook_name) = &state.hook_name {
+                                    let (module, name) = &**hook_name;
+                                    let caller_module = caller.caller.module();
+                                    if caller.store.0.has_host_call_hook(caller_module, module, name) {
+                                        let params: [Val; $num] = [$({
+                                            let mut raw = ValRaw::i32(0);
+                                            $args::abi_into_raw($args, &mut raw);
+                                            Val::from_raw(&mut caller, raw, $args::valtype())
+                                        }),*];
+                                        let call = HostCall::new(module, name, &params);
+                                        if let Err(trap) = caller.store.0.host_call_hooks(caller_module, &call) {
+                                            return R::fallible_from_error(trap);
+                                        }
+                                    }
+                                }
                                 $(let $args = $args::from_abi($args, caller.store.0);)*
                                 let r = func(
                                     caller.sub_caller(),
@@ -2101,7 +2421,10 @@ macro_rules! impl_into_func {
                             type_index: shared_signature_id,
                             vmctx: ptr::null_mut(),
                         },
-                        Box::new(self),
+                        Box::new(WrappedHostFunc {
+                            func: self,
+                            hook_name,
+                        }),
                     )
                 };
 
@@ -2111,6 +2434,16 @@ macro_rules! impl_into_func {
     }
 }
 
+/// Implementation detail of `IntoFunc` for closures taking a leading
+/// `Caller`, shared by `into_func` and `into_named_func`.
+trait IntoHostContext<T, Params, Results> {
+    fn into_host_context(
+        self,
+        engine: &Engine,
+        hook_name: Option<Box<(String, String)>>,
+    ) -> HostContext;
+}
+
 for_each_function_signature!(impl_into_func);
 
 #[doc(hidden)]
@@ -2194,6 +2527,18 @@ impl HostFunc {
         HostFunc::_new(engine, ctx)
     }
 
+    /// Same as [`HostFunc::wrap`], but calls to the function run the host
+    /// call hooks registered for `module` and `name`.
+    pub fn wrap_named<T, Params, Results>(
+        engine: &Engine,
+        module: &str,
+        name: &str,
+        func: impl IntoFunc<T, Params, Results>,
+    ) -> Self {
+        let ctx = func.into_named_func(engine, module, name);
+        HostFunc::_new(engine, ctx)
+    }
+
     /// Requires that this function's signature is already registered within
     /// `Engine`. This happens automatically during the above two constructors.
     fn _new(engine: &Engine, ctx: HostContext) -> Self {
@@ -2425,4 +2770,67 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "cranelift")]
+    #[cfg_attr(miri, ignore)]
+    fn host_func_trampolines_are_shared() -> Result<()> {
+        let mut strategies = vec![crate::Strategy::Cranelift];
+        if cfg!(all(feature = "winch", target_arch = "x86_64")) {
+            strategies.push(crate::Strategy::Winch);
+        }
+        for strategy in strategies {
+            let mut config = crate::Config::new();
+            config.strategy(strategy);
+            let engine = crate::Engine::new(&config)?;
+            let mut store = Store::new(&engine, ());
+            let wasm_call = |store: &mut Store<()>, f: &Func| unsafe {
+                f.vm_func_ref(store.as_context_mut().0).as_ref().wasm_call
+            };
+
+            // Functions of the same signature and closure type share their
+            // trampolines.
+            let ty = FuncType::new([ValType::I32], [ValType::I32]);
+            let funcs = (0..2)
+                .map(|i| {
+                    Func::new(&mut store, ty.clone(), move |_, params, results| {
+                        results[0] = Val::I32(params[0].unwrap_i32() + i);
+                        Ok(())
+                    })
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                wasm_call(&mut store, &funcs[0]),
+                wasm_call(&mut store, &funcs[1])
+            );
+            for (i, f) in funcs.iter().enumerate() {
+                let f = f.typed::<i32, i32>(&store)?;
+                assert_eq!(f.call(&mut store, 1)?, 1 + i as i32);
+            }
+
+            // Other signatures get their own.
+            let other = Func::new(
+                &mut store,
+                FuncType::new([ValType::I64], [ValType::I32]),
+                |_, _, results| {
+                    results[0] = Val::I32(0);
+                    Ok(())
+                },
+            );
+            assert_ne!(
+                wasm_call(&mut store, &funcs[0]),
+                wasm_call(&mut store, &other)
+            );
+
+            // The cache doesn't keep trampolines alive by itself.
+            drop(store);
+            assert!(engine
+                .host_trampolines()
+                .lock()
+                .unwrap()
+                .values()
+                .all(|t| t.strong_count() == 0));
+        }
+        Ok(())
+    }
 }
@@ -591,14 +591,14 @@ macro_rules! impl_wasm_params {
     };
 }
 
-for_each_function_signature!(impl_wasm_params);
+for_each_result_signature!(impl_wasm_params);
 
 /// A trait used for [`Func::typed`] and with [`TypedFunc`] to represent the set of
 /// results for wasm functions.
 ///
-/// This is currently only implemented for `()` and for bare types that can be
-/// returned. This is not yet implemented for tuples because a multi-value
-/// `TypedFunc` is not currently supported.
+/// This is implemented for `()`, for bare types that can be returned, and for
+/// tuples of up to 32 of those types. It can also be derived for structs with
+/// [`#[derive(WasmResults)]`](derive@crate::WasmResults).
 pub unsafe trait WasmResults: WasmParams {
     #[doc(hidden)]
     type ResultAbi: HostAbi;
@@ -635,4 +635,4 @@ macro_rules! impl_wasm_results {
     };
 }
 
-for_each_function_signature!(impl_wasm_results);
+for_each_result_signature!(impl_wasm_results);
@@ -1,3 +1,4 @@
+use crate::admission::{AdmissionKind, AdmissionPermit};
 use crate::linker::{Definition, DefinitionType};
 use crate::store::{InstanceId, StoreOpaque, Stored};
 use crate::types::matching;
@@ -202,7 +203,17 @@ impl Instance {
         module: &Module,
         imports: Imports<'_>,
     ) -> Result<Instance> {
-        let (instance, start) = Instance::new_raw(store.0, module, imports)?;
+        store.0.engine().check_not_shutting_down()?;
+
+        // Only allocation and initialization count against the engine's
+        // limit on concurrent instantiations. The start function can run for
+        // arbitrarily long, or instantiate other modules itself, so the
+        // permit is released before it runs.
+        let (instance, start) = {
+            let engine = store.0.engine().clone();
+            let _permit = Self::admit(store.0, &engine)?;
+            Instance::new_raw(store.0, module, imports)?
+        };
         if let Some(start) = start {
             instance.start_raw(store, start)?;
         }
@@ -231,6 +242,29 @@ impl Instance {
             .await?
     }
 
+    /// Waits until `engine` admits an instantiation into `store`, if it
+    /// limits concurrent instantiations.
+    ///
+    /// In async mode this must be called on a fiber, where it suspends
+    /// instead of blocking the thread.
+    fn admit<'a>(
+        store: &mut StoreOpaque,
+        engine: &'a Engine,
+    ) -> Result<Option<AdmissionPermit<'a>>> {
+        let tenant = store.admission_tenant();
+        #[cfg(feature = "async")]
+        if store.async_support() {
+            let mut future = Box::pin(engine.admit_async(tenant, AdmissionKind::Instantiation));
+            return unsafe {
+                store
+                    .async_cx()
+                    .ok_or_else(|| anyhow!("couldn't grab async_cx for instantiation"))?
+                    .block_on(future.as_mut())
+            };
+        }
+        Ok(engine.admit(tenant, AdmissionKind::Instantiation))
+    }
+
     /// Internal function to create an instance which doesn't have its `start`
     /// function run yet.
     ///
@@ -761,7 +795,7 @@ impl<T> InstancePre<T> {
         let mut host_funcs = 0;
         for item in &items {
             match item {
-                Definition::Extern(_, _) => {}
+                Definition::Extern(_, _) | Definition::Intercept(_) => {}
                 Definition::HostFunc(f) => {
                     host_funcs += 1;
                     if f.func_ref().wasm_call.is_none() {
@@ -911,6 +945,7 @@ fn pre_instantiate_raw(
                 )
                 .into()
             },
+            Definition::Intercept(_) => unsafe { import.to_extern(store) },
         };
         imports.push(&item, store, module);
     }
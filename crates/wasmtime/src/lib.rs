@@ -407,55 +407,103 @@
 #[macro_use]
 mod func;
 
+mod admission;
+mod allocation_profiler;
+mod block_trace;
+#[cfg(feature = "async")]
+mod cancel;
+mod capabilities;
+#[cfg(any(feature = "cranelift", feature = "winch"))]
+mod compile_monitor;
 #[cfg(any(feature = "cranelift", feature = "winch"))]
 mod compiler;
 
 mod code;
 mod config;
+mod coverage;
 mod engine;
+mod executor;
 mod externals;
 mod instance;
 mod limits;
 mod linker;
 mod memory;
+mod memory_trace;
 mod module;
 #[cfg(feature = "profiling")]
 mod profiling;
 mod r#ref;
 mod resources;
+mod shutdown;
 mod signatures;
+mod snapshot;
 mod store;
 mod trampoline;
 mod trap;
 mod types;
 mod v128;
 mod values;
+#[cfg(feature = "module-verification")]
+mod verification;
 
 #[cfg(feature = "async")]
 mod stack;
 
+pub use crate::admission::AdmissionStats;
+pub use crate::allocation_profiler::{
+    AllocationEvent, AllocationKind, AllocationProfiler, AllocationTotals,
+};
+pub use crate::block_trace::BlockEntry;
+#[cfg(feature = "async")]
+pub use crate::cancel::{CancelToken, Cancelled};
+pub use crate::capabilities::{
+    Capability, CapabilityDenial, CapabilityDenied, CapabilityPolicy, DenialReason,
+    CAPABILITIES_SECTION,
+};
+#[cfg(any(feature = "cranelift", feature = "winch"))]
+pub use crate::compile_monitor::{
+    BackgroundCompile, CompilationCancelled, CompileMonitor, CompileProgress,
+};
 pub use crate::config::*;
+pub use crate::coverage::{BlockCoverage, FunctionCoverage, ModuleCoverage};
 pub use crate::engine::*;
+pub use crate::executor::{
+    CompilationExecutor, CompilationTask, ScopedThreadsExecutor, SerialExecutor,
+};
 pub use crate::externals::*;
 pub use crate::func::*;
 pub use crate::instance::{Instance, InstancePre};
 pub use crate::limits::*;
 pub use crate::linker::*;
 pub use crate::memory::*;
-pub use crate::module::Module;
+pub use crate::memory_trace::{MemoryAccess, MemoryAccessKind};
+pub use crate::module::{FunctionCodegenStats, Module, OpcodeStats};
 #[cfg(feature = "profiling")]
 pub use crate::profiling::GuestProfiler;
 pub use crate::r#ref::ExternRef;
 pub use crate::resources::*;
+pub use crate::shutdown::{EngineShutdown, ShutdownHandle};
+pub use crate::snapshot::{
+    DiffingSnapshot, InstanceSnapshot, SnapshotCodec, SnapshotDelta, SnapshotEncoding,
+    SnapshotSink, SnapshotTimeline,
+};
+#[cfg(feature = "zstd")]
+pub use crate::snapshot::ZstdCodec;
 #[cfg(feature = "async")]
 pub use crate::store::CallHookHandler;
 pub use crate::store::{
-    AsContext, AsContextMut, CallHook, Store, StoreContext, StoreContextMut, UpdateDeadline,
+    AsContext, AsContextMut, CallHook, EpochDeadlineScope, HostCall, ReportedResource, Store,
+    StoreContext, StoreContextMut, StoreResourceReport, UpdateDeadline,
 };
 pub use crate::trap::*;
 pub use crate::types::*;
 pub use crate::v128::V128;
 pub use crate::values::*;
+#[cfg(feature = "module-verification")]
+pub use crate::verification::{
+    Ed25519Signer, Ed25519Verifier, ModuleVerifier, VerificationInput, VerificationKind,
+    SIGNATURE_SECTION,
+};
 
 #[cfg(feature = "async")]
 pub use crate::stack::*;
@@ -475,6 +523,46 @@ pub use anyhow::{Error, Result};
 #[cfg(feature = "component-model")]
 pub mod component;
 
+/// Derives [`WasmResults`] and [`WasmRet`] for a struct whose fields are all
+/// core wasm types, so that it can be used as the results of a [`TypedFunc`]
+/// or returned from a closure passed to [`Func::wrap`].
+///
+/// The struct is passed to and from wasm exactly like the tuple of its field
+/// types, in declaration order.
+///
+/// ```
+/// use wasmtime::*;
+///
+/// #[derive(WasmResults, Debug, PartialEq)]
+/// struct DivRem {
+///     quotient: i32,
+///     remainder: i32,
+/// }
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let mut store = Store::<()>::default();
+/// let div_rem = Func::wrap(&mut store, |a: i32, b: i32| DivRem {
+///     quotient: a / b,
+///     remainder: a % b,
+/// });
+/// let div_rem = div_rem.typed::<(i32, i32), DivRem>(&store)?;
+/// assert_eq!(
+///     div_rem.call(&mut store, (7, 2))?,
+///     DivRem { quotient: 3, remainder: 1 },
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub use wasmtime_component_macro::WasmResults;
+
+// Items referenced by the code generated by `#[derive(WasmResults)]`, they are
+// not part of Wasmtime's API stability guarantees
+#[doc(hidden)]
+pub mod __internal {
+    pub use crate::store::StoreOpaque;
+    pub use wasmtime_runtime::{VMContext, VMNativeCallFunction, VMOpaqueContext};
+}
+
 cfg_if::cfg_if! {
     if #[cfg(unix)] {
         pub mod unix;
@@ -1,4 +1,6 @@
 use anyhow::{bail, Result};
+use std::fmt;
+use std::sync::Arc;
 
 /// Value returned by [`ResourceLimiter::instances`] default method
 pub const DEFAULT_INSTANCE_LIMIT: usize = 10000;
@@ -237,6 +239,35 @@ impl StoreLimitsBuilder {
         self
     }
 
+    /// A threshold, below the hard [`memory_size`](Self::memory_size) limit,
+    /// past which growing a linear memory first consults `callback`.
+    ///
+    /// Whenever a linear memory would grow beyond `limit` bytes, `callback` is
+    /// invoked with the memory's current and desired sizes in bytes before the
+    /// growth happens. This gives embedders a chance to react to memory
+    /// pressure, for example by logging, releasing host-side caches, or
+    /// approving the growth on a case-by-case basis. The callback's return
+    /// value determines the outcome:
+    ///
+    /// * `Ok(true)` allows the growth to proceed,
+    /// * `Ok(false)` makes the growth fail as if a hard limit had been hit,
+    /// * `Err(_)` traps, with the error propagated to the caller.
+    ///
+    /// The callback is only consulted for growth that the hard limits would
+    /// otherwise allow; growth beyond [`memory_size`](Self::memory_size) or
+    /// the memory's own maximum is always denied. Like `memory_size`, this
+    /// limit applies to each linear memory individually.
+    ///
+    /// By default, no soft limit is configured.
+    pub fn memory_soft_limit(
+        mut self,
+        limit: usize,
+        callback: impl Fn(usize, usize) -> Result<bool> + Send + Sync + 'static,
+    ) -> Self {
+        self.0.memory_soft_limit = Some((limit, Arc::new(callback)));
+        self
+    }
+
     /// The maximum number of elements in a table.
     ///
     /// Growing a table beyond this limit will fail. This limit is applied to
@@ -307,9 +338,10 @@ impl StoreLimitsBuilder {
 /// This is a convenience type included to avoid needing to implement the
 /// [`ResourceLimiter`] trait if your use case fits in the static configuration
 /// that this [`StoreLimits`] provides.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct StoreLimits {
     memory_size: Option<usize>,
+    memory_soft_limit: Option<(usize, MemorySoftLimitCallback)>,
     table_elements: Option<u32>,
     instances: usize,
     tables: usize,
@@ -317,10 +349,13 @@ pub struct StoreLimits {
     trap_on_grow_failure: bool,
 }
 
+type MemorySoftLimitCallback = Arc<dyn Fn(usize, usize) -> Result<bool> + Send + Sync>;
+
 impl Default for StoreLimits {
     fn default() -> Self {
         Self {
             memory_size: None,
+            memory_soft_limit: None,
             table_elements: None,
             instances: DEFAULT_INSTANCE_LIMIT,
             tables: DEFAULT_TABLE_LIMIT,
@@ -330,10 +365,27 @@ impl Default for StoreLimits {
     }
 }
 
+impl fmt::Debug for StoreLimits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StoreLimits")
+            .field("memory_size", &self.memory_size)
+            .field(
+                "memory_soft_limit",
+                &self.memory_soft_limit.as_ref().map(|(limit, _)| limit),
+            )
+            .field("table_elements", &self.table_elements)
+            .field("instances", &self.instances)
+            .field("tables", &self.tables)
+            .field("memories", &self.memories)
+            .field("trap_on_grow_failure", &self.trap_on_grow_failure)
+            .finish()
+    }
+}
+
 impl ResourceLimiter for StoreLimits {
     fn memory_growing(
         &mut self,
-        _current: usize,
+        current: usize,
         desired: usize,
         maximum: Option<usize>,
     ) -> Result<bool> {
@@ -341,7 +393,10 @@ impl ResourceLimiter for StoreLimits {
             Some(limit) if desired > limit => false,
             _ => match maximum {
                 Some(max) if desired > max => false,
-                _ => true,
+                _ => match &self.memory_soft_limit {
+                    Some((limit, callback)) if desired > *limit => callback(current, desired)?,
+                    _ => true,
+                },
             },
         };
         if !allow && self.trap_on_grow_failure {
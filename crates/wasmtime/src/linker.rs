@@ -1,9 +1,9 @@
 use crate::func::HostFunc;
 use crate::instance::InstancePre;
-use crate::store::StoreOpaque;
+use crate::store::{HostCall, StoreOpaque};
 use crate::{
-    AsContext, AsContextMut, Caller, Engine, Extern, ExternType, Func, FuncType, ImportType,
-    Instance, IntoFunc, Module, StoreContextMut, Val, ValRaw, ValType,
+    AsContext, AsContextMut, Caller, CapabilityPolicy, Engine, Extern, ExternType, Func, FuncType,
+    ImportType, Instance, IntoFunc, Module, StoreContextMut, Val, ValRaw, ValType,
 };
 use anyhow::{bail, Context, Result};
 use log::warn;
@@ -13,6 +13,7 @@ use std::future::Future;
 use std::marker;
 #[cfg(feature = "async")]
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
 use std::sync::Arc;
 
 /// Structure used to link wasm modules/instances together.
@@ -88,6 +89,7 @@ pub struct Linker<T> {
     map: HashMap<ImportKey, Definition>,
     allow_shadowing: bool,
     allow_unknown_exports: bool,
+    capability_policy: Option<Arc<CapabilityPolicy>>,
     _marker: marker::PhantomData<fn() -> T>,
 }
 
@@ -100,6 +102,7 @@ impl<T> Clone for Linker<T> {
             map: self.map.clone(),
             allow_shadowing: self.allow_shadowing,
             allow_unknown_exports: self.allow_unknown_exports,
+            capability_policy: self.capability_policy.clone(),
             _marker: self._marker,
         }
     }
@@ -115,6 +118,33 @@ struct ImportKey {
 pub(crate) enum Definition {
     Extern(Extern, DefinitionType),
     HostFunc(Arc<HostFunc>),
+    #[cfg_attr(not(any(feature = "cranelift", feature = "winch")), allow(dead_code))]
+    Intercept(Arc<Intercept>),
+}
+
+/// A function defined with [`Linker::func_intercept`].
+///
+/// The `original` definition is resolved to a [`Func`] once per store, the
+/// first time this is instantiated in that store, and recorded in the store
+/// under `id` where the interceptor's host function looks it up.
+pub(crate) struct Intercept {
+    id: u64,
+    original: Definition,
+    func: Arc<HostFunc>,
+}
+
+impl Intercept {
+    /// Same as `Definition::to_extern`.
+    unsafe fn to_func(&self, store: &mut StoreOpaque) -> Func {
+        if store.intercepted_func(self.id).is_none() {
+            let original = match self.original.to_extern(store) {
+                Extern::Func(func) => func,
+                _ => unreachable!(),
+            };
+            store.set_intercepted_func(self.id, original);
+        }
+        self.func.to_func(store)
+    }
 }
 
 /// This is a sort of slimmed down `ExternType` which notably doesn't have a
@@ -186,6 +216,7 @@ impl<T> Linker<T> {
             strings: Vec::new(),
             allow_shadowing: false,
             allow_unknown_exports: false,
+            capability_policy: None,
             _marker: marker::PhantomData,
         }
     }
@@ -252,6 +283,38 @@ impl<T> Linker<T> {
         self
     }
 
+    /// Restricts the imports of modules instantiated through this linker to
+    /// the capabilities permitted by `policy`.
+    ///
+    /// Modules which import, or declare in their capability manifest, a
+    /// capability the policy doesn't permit fail to instantiate with a
+    /// [`CapabilityDenied`] error. See [`CapabilityPolicy`] for details.
+    ///
+    /// By default no policy is configured and any import may be linked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use wasmtime::*;
+    /// # fn main() -> anyhow::Result<()> {
+    /// # let engine = Engine::default();
+    /// # let mut store = Store::new(&engine, ());
+    /// let mut linker = Linker::new(&engine);
+    /// linker.func_wrap("host", "log", |_: i32| {})?;
+    /// linker.func_wrap("host", "exit", |_: i32| {})?;
+    /// linker.capability_policy(CapabilityPolicy::new().allow("host", "log").clone());
+    ///
+    /// let module = Module::new(&engine, r#"(module (import "host" "exit" (func (param i32))))"#)?;
+    /// let err = linker.instantiate(&mut store, &module).unwrap_err();
+    /// assert!(err.downcast_ref::<CapabilityDenied>().is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn capability_policy(&mut self, policy: CapabilityPolicy) -> &mut Self {
+        self.capability_policy = Some(Arc::new(policy));
+        self
+    }
+
     /// Implement any imports of the given [`Module`] with a function which traps.
     ///
     /// By default a [`Linker`] will error when unknown imports are encountered
@@ -424,7 +487,7 @@ impl<T> Linker<T> {
         ty: FuncType,
         func: impl Fn(Caller<'_, T>, &[Val], &mut [Val]) -> Result<()> + Send + Sync + 'static,
     ) -> Result<&mut Self> {
-        let func = HostFunc::new(&self.engine, ty, func);
+        let func = HostFunc::new(&self.engine, ty, with_host_call_hooks(module, name, func));
         let key = self.import_key(module, Some(name));
         self.insert(key, Definition::HostFunc(Arc::new(func)))?;
         Ok(self)
@@ -442,7 +505,24 @@ impl<T> Linker<T> {
         ty: FuncType,
         func: impl Fn(Caller<'_, T>, &mut [ValRaw]) -> Result<()> + Send + Sync + 'static,
     ) -> Result<&mut Self> {
-        let func = HostFunc::new_unchecked(&self.engine, ty, func);
+        let (hook_module, hook_name, hook_ty) = (module.to_string(), name.to_string(), ty.clone());
+        let func = HostFunc::new_unchecked(&self.engine, ty, move |mut caller, values| {
+            let caller_module = caller.caller.module();
+            if caller
+                .store
+                .0
+                .has_host_call_hook(caller_module, &hook_module, &hook_name)
+            {
+                let params = hook_ty
+                    .params()
+                    .zip(values.iter())
+                    .map(|(ty, raw)| Val::from_raw(&mut caller, *raw, ty))
+                    .collect::<Vec<_>>();
+                let call = HostCall::new(&hook_module, &hook_name, &params);
+                caller.store.0.host_call_hooks(caller_module, &call)?;
+            }
+            func(caller, values)
+        });
         let key = self.import_key(module, Some(name));
         self.insert(key, Definition::HostFunc(Arc::new(func)))?;
         Ok(self)
@@ -551,7 +631,7 @@ impl<T> Linker<T> {
         name: &str,
         func: impl IntoFunc<T, Params, Args>,
     ) -> Result<&mut Self> {
-        let func = HostFunc::wrap(&self.engine, func);
+        let func = HostFunc::wrap_named(&self.engine, module, name, func);
         let key = self.import_key(module, Some(name));
         self.insert(key, Definition::HostFunc(Arc::new(func)))?;
         Ok(self)
@@ -941,6 +1021,83 @@ impl<T> Linker<T> {
         Ok(self)
     }
 
+    /// Wraps an already-defined function with middleware.
+    ///
+    /// The function previously defined as `module::name` is replaced with a
+    /// new function of the same type which invokes `interceptor` instead. The
+    /// `interceptor` receives the original function as its second argument,
+    /// along with the arguments and result slots of the call. It may forward
+    /// the call by invoking [`Func::call`] on the original function, inspect
+    /// or modify the arguments before doing so and the results afterwards, or
+    /// short-circuit the call entirely by filling in `results` itself (or
+    /// returning an error, which is raised as a trap to WebAssembly).
+    ///
+    /// Interceptors may be layered: intercepting a function which was itself
+    /// intercepted passes the previous interceptor as the original function.
+    /// This makes it possible to build capability brokers or record/replay
+    /// tooling on top of existing host APIs without modifying them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `module::name` is not defined or is not a
+    /// function. Additionally if the original function was defined with
+    /// [`Linker::define`] as a [`Func`] belonging to a particular
+    /// [`Store`](crate::Store), instantiating with the intercepted function in
+    /// any other store fails.
+    ///
+    /// The original function is resolved once per store, when the intercepted
+    /// function is first instantiated in it. The interceptor itself doesn't
+    /// run the hooks registered with
+    /// [`Store::host_call_hook`](crate::Store::host_call_hook): they run once
+    /// when the interceptor forwards the call to an original function defined
+    /// with this linker, as they would without the interceptor.
+    #[cfg(any(feature = "cranelift", feature = "winch"))]
+    #[cfg_attr(nightlydoc, doc(cfg(any(feature = "cranelift", feature = "winch"))))]
+    pub fn func_intercept(
+        &mut self,
+        module: &str,
+        name: &str,
+        interceptor: impl Fn(Caller<'_, T>, &Func, &[Val], &mut [Val]) -> Result<()>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Result<&mut Self> {
+        let key = self.import_key(module, Some(name));
+        let original = match self.map.get(&key) {
+            Some(item) => item.clone(),
+            None => bail!("no item named `{}::{}` defined", module, name),
+        };
+        let ty = match original.ty() {
+            DefinitionType::Func(sig) => FuncType::from_wasm_func_type(
+                self.engine
+                    .signatures()
+                    .lookup_type(sig)
+                    .expect("signature should be registered"),
+            ),
+            _ => bail!("`{}::{}` is not a function", module, name),
+        };
+        static NEXT_INTERCEPT_ID: AtomicU64 = AtomicU64::new(0);
+        let id = NEXT_INTERCEPT_ID.fetch_add(1, Relaxed);
+        let func = HostFunc::new(&self.engine, ty, move |caller, params, results| {
+            // The original function is resolved when this function is
+            // instantiated in a store, which must happen before it's called.
+            let func = caller
+                .store
+                .0
+                .intercepted_func(id)
+                .expect("intercepted function should be resolved at instantiation");
+            interceptor(caller, &func, params, results)
+        });
+        let intercept = Intercept {
+            id,
+            original,
+            func: Arc::new(func),
+        };
+        self.map
+            .insert(key, Definition::Intercept(Arc::new(intercept)));
+        Ok(self)
+    }
+
     /// Aliases one item's name as another.
     ///
     /// This method will alias an item with the specified `module` and `name`
@@ -1179,6 +1336,12 @@ impl<T> Linker<T> {
         module: &Module,
         store: Option<&StoreOpaque>,
     ) -> Result<InstancePre<T>> {
+        if let Some(policy) = &self.capability_policy {
+            policy.check(
+                module.env_module().capabilities.as_ref(),
+                module.imports().map(|i| (i.module(), Some(i.name()))),
+            )?;
+        }
         let mut imports = module
             .imports()
             .map(|import| self._get_by_import(&import))
@@ -1316,6 +1479,30 @@ impl<T> Default for Linker<T> {
     }
 }
 
+/// Wraps a [`Func::new`]-style host function so that the hooks registered
+/// with [`Store::host_call_hook`](crate::Store::host_call_hook) for
+/// `module::name` run before it's called.
+#[cfg(any(feature = "cranelift", feature = "winch"))]
+fn with_host_call_hooks<T>(
+    module: &str,
+    name: &str,
+    func: impl Fn(Caller<'_, T>, &[Val], &mut [Val]) -> Result<()> + Send + Sync + 'static,
+) -> impl Fn(Caller<'_, T>, &[Val], &mut [Val]) -> Result<()> + Send + Sync + 'static {
+    let (module, name) = (module.to_string(), name.to_string());
+    move |caller, params, results| {
+        let caller_module = caller.caller.module();
+        if caller
+            .store
+            .0
+            .has_host_call_hook(caller_module, &module, &name)
+        {
+            let call = HostCall::new(&module, &name, params);
+            caller.store.0.host_call_hooks(caller_module, &call)?;
+        }
+        func(caller, params, results)
+    }
+}
+
 impl Definition {
     fn new(store: &StoreOpaque, item: Extern) -> Definition {
         let ty = DefinitionType::from(store, &item);
@@ -1326,6 +1513,7 @@ impl Definition {
         match self {
             Definition::Extern(_, ty) => ty.clone(),
             Definition::HostFunc(func) => DefinitionType::Func(func.sig_index()),
+            Definition::Intercept(i) => DefinitionType::Func(i.func.sig_index()),
         }
     }
 
@@ -1336,6 +1524,7 @@ impl Definition {
         match self {
             Definition::Extern(e, _) => e.clone(),
             Definition::HostFunc(func) => func.to_func(store).into(),
+            Definition::Intercept(i) => i.to_func(store).into(),
         }
     }
 
@@ -1343,6 +1532,7 @@ impl Definition {
         match self {
             Definition::Extern(e, _) => e.comes_from_same_store(store),
             Definition::HostFunc(_func) => true,
+            Definition::Intercept(i) => i.original.comes_from_same_store(store),
         }
     }
 
@@ -0,0 +1,77 @@
+//! Tracing of the linear memory accesses made by modules compiled with
+//! [`Config::memory_trace`](crate::Config::memory_trace).
+
+/// Whether a [`MemoryAccess`] reads or writes memory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MemoryAccessKind {
+    /// A load instruction read memory. Atomic read-modify-write and
+    /// compare-and-swap instructions report a load followed by a store.
+    Load,
+    /// A store instruction wrote memory.
+    Store,
+}
+
+/// A linear memory access reported to the hook installed with
+/// [`Store::memory_trace`](crate::Store::memory_trace).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryAccess {
+    memory: u32,
+    kind: MemoryAccessKind,
+    address: u64,
+    size: u32,
+    wasm_offset: u32,
+}
+
+impl MemoryAccess {
+    pub(crate) fn new(
+        memory: u32,
+        is_store: bool,
+        address: u64,
+        size: u32,
+        wasm_offset: u32,
+    ) -> MemoryAccess {
+        MemoryAccess {
+            memory,
+            kind: if is_store {
+                MemoryAccessKind::Store
+            } else {
+                MemoryAccessKind::Load
+            },
+            address,
+            size,
+            wasm_offset,
+        }
+    }
+
+    /// Returns the index of the accessed memory within the module, counting
+    /// imported memories first.
+    pub fn memory(&self) -> u32 {
+        self.memory
+    }
+
+    /// Returns whether this access is a load or a store.
+    pub fn kind(&self) -> MemoryAccessKind {
+        self.kind
+    }
+
+    /// Returns the address accessed in linear memory, including the static
+    /// offset of the instruction.
+    ///
+    /// Accesses are reported before they're performed. Bounds checks are
+    /// often left to guard pages and thus to the access itself, so an
+    /// out-of-bounds access may be reported right before it traps.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// Returns the number of bytes accessed.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Returns the offset of the load or store instruction in the original
+    /// wasm module.
+    pub fn wasm_offset(&self) -> u32 {
+        self.wasm_offset
+    }
+}
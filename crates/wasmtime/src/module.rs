@@ -1,5 +1,6 @@
 use crate::{
     code::CodeObject,
+    coverage::ModuleCoverage,
     resources::ResourcesRequired,
     signatures::SignatureCollection,
     types::{ExportType, ExternType, ImportType},
@@ -12,6 +13,7 @@ use std::mem;
 use std::ops::Range;
 use std::path::Path;
 use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use wasmparser::{Parser, ValidPayload, Validator};
 use wasmtime_environ::{
@@ -26,6 +28,8 @@ use wasmtime_runtime::{
 
 mod registry;
 
+pub use wasmtime_environ::{FunctionCodegenStats, OpcodeStats};
+
 pub use registry::{
     is_wasm_trap_pc, register_code, unregister_code, ModuleRegistry, RegisteredModuleId,
 };
@@ -130,6 +134,10 @@ struct ModuleInner {
 
     /// Runtime offset information for `VMContext`.
     offsets: VMOffsets<HostPtr>,
+
+    /// Code coverage counters incremented by instrumented code, shared by all
+    /// instances of this module. Empty unless `Config::coverage` is enabled.
+    coverage_counters: Box<[AtomicU64]>,
 }
 
 impl std::fmt::Debug for Module {
@@ -301,13 +309,54 @@ impl Module {
     #[cfg(any(feature = "cranelift", feature = "winch"))]
     #[cfg_attr(nightlydoc, doc(cfg(any(feature = "cranelift", feature = "winch"))))]
     pub fn from_binary(engine: &Engine, binary: &[u8]) -> Result<Module> {
+        Self::compile(engine, binary, None)
+    }
+
+    /// Same as [`Module::from_binary`], but reports the progress of
+    /// compilation to `monitor` and stops early if it is cancelled.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of [`Module::from_binary`], returns a
+    /// [`CompilationCancelled`](crate::CompilationCancelled) error if
+    /// `monitor` is cancelled before compilation finishes.
+    #[cfg(any(feature = "cranelift", feature = "winch"))]
+    #[cfg_attr(nightlydoc, doc(cfg(any(feature = "cranelift", feature = "winch"))))]
+    pub fn from_binary_with_monitor(
+        engine: &Engine,
+        binary: &[u8],
+        monitor: &crate::CompileMonitor,
+    ) -> Result<Module> {
+        monitor.check()?;
+        Self::compile(engine, binary, Some(monitor))
+    }
+
+    #[cfg(any(feature = "cranelift", feature = "winch"))]
+    fn compile(
+        engine: &Engine,
+        binary: &[u8],
+        monitor: Option<&crate::CompileMonitor>,
+    ) -> Result<Module> {
         engine
             .check_compatible_with_native_host()
             .context("compilation settings are not compatible with the native host")?;
+        engine.verify_input(binary, ObjectKind::Module, false)?;
+
+        #[cfg(feature = "cache")]
+        if let Some(store) = &engine.config().artifact_cache_store {
+            let (code, info_and_types) =
+                compile_with_cache_store(engine, &**store, ObjectKind::Module, binary, || {
+                    Module::build_artifacts(engine, binary, monitor)
+                })?;
+            let info_and_types = info_and_types
+                .flatten()
+                .map(|(info, types)| (info, types.into()));
+            return Self::from_parts(engine, code, info_and_types);
+        }
 
         cfg_if::cfg_if! {
             if #[cfg(feature = "cache")] {
-                let state = (HashedEngineCompileEnv(engine), binary);
+                let state = (HashedEngineCompileEnv(engine), binary, Unhashed(monitor));
                 let (code, info_and_types) = wasmtime_cache::ModuleCacheEntry::new(
                     "wasmtime",
                     engine.cache_config(),
@@ -316,37 +365,31 @@ impl Module {
                     &state,
 
                     // Cache miss, compute the actual artifacts
-                    |(engine, wasm)| -> Result<_> {
-                        let (mmap, info) = Module::build_artifacts(engine.0, wasm)?;
-                        let code = publish_mmap(mmap)?;
+                    |(engine, wasm, monitor)| -> Result<_> {
+                        let (mmap, info) = Module::build_artifacts(engine.0, wasm, monitor.0)?;
+                        let code = Arc::new(engine.0.publish_code(mmap)?);
                         Ok((code, info))
                     },
 
                     // Implementation of how to serialize artifacts
-                    |(_engine, _wasm), (code, _info_and_types)| {
+                    |(_engine, _wasm, _monitor), (code, _info_and_types)| {
                         Some(code.mmap().to_vec())
                     },
 
                     // Cache hit, deserialize the provided artifacts
-                    |(engine, _wasm), serialized_bytes| {
+                    |(engine, _wasm, _monitor), serialized_bytes| {
                         let code = engine.0.load_code_bytes(&serialized_bytes, ObjectKind::Module).ok()?;
                         Some((code, None))
                     },
                 )?;
             } else {
-                let (mmap, info_and_types) = Module::build_artifacts(engine, binary)?;
-                let code = publish_mmap(mmap)?;
+                let (mmap, info_and_types) = Module::build_artifacts(engine, binary, monitor)?;
+                let code = Arc::new(engine.publish_code(mmap)?);
             }
         };
 
         let info_and_types = info_and_types.map(|(info, types)| (info, types.into()));
         return Self::from_parts(engine, code, info_and_types);
-
-        fn publish_mmap(mmap: MmapVec) -> Result<Arc<CodeMemory>> {
-            let mut code = CodeMemory::new(mmap)?;
-            code.publish()?;
-            Ok(Arc::new(code))
-        }
     }
 
     /// Creates a new WebAssembly `Module` from the contents of the given `file`
@@ -375,6 +418,7 @@ impl Module {
     pub unsafe fn from_trusted_file(engine: &Engine, file: impl AsRef<Path>) -> Result<Module> {
         let mmap = MmapVec::from_file(file.as_ref())?;
         if &mmap[0..4] == b"\x7fELF" {
+            engine.verify_input(&mmap, ObjectKind::Module, true)?;
             let code = engine.load_code(mmap, ObjectKind::Module)?;
             return Module::from_parts(engine, code, None);
         }
@@ -398,9 +442,12 @@ impl Module {
     pub(crate) fn build_artifacts(
         engine: &Engine,
         wasm: &[u8],
+        monitor: Option<&crate::CompileMonitor>,
     ) -> Result<(MmapVec, Option<(CompiledModuleInfo, ModuleTypes)>)> {
+        use crate::admission::{AdmissionKind, Tenant};
         use crate::compiler::CompileInputs;
 
+        let _permit = engine.admit(Tenant::Engine, AdmissionKind::Compilation);
         let tunables = &engine.config().tunables;
 
         // First a `ModuleEnvironment` is created which records type information
@@ -418,7 +465,7 @@ impl Module {
         let types = types.finish();
 
         let compile_inputs = CompileInputs::for_module(&types, &translation, functions);
-        let unlinked_compile_outputs = compile_inputs.compile(engine)?;
+        let unlinked_compile_outputs = compile_inputs.compile(engine, monitor)?;
         let (compiled_funcs, function_indices) = unlinked_compile_outputs.pre_link();
 
         // Emplace all compiled functions into the object file with any other
@@ -493,6 +540,7 @@ impl Module {
     /// blobs across versions of wasmtime you can be safely guaranteed that
     /// future versions of wasmtime will reject old cache entries).
     pub unsafe fn deserialize(engine: &Engine, bytes: impl AsRef<[u8]>) -> Result<Module> {
+        engine.verify_input(bytes.as_ref(), ObjectKind::Module, true)?;
         let code = engine.load_code_bytes(bytes.as_ref(), ObjectKind::Module)?;
         Module::from_parts(engine, code, None)
     }
@@ -580,6 +628,10 @@ impl Module {
             .allocator()
             .validate_module(module.module(), &offsets)?;
 
+        let coverage_counters = (0..module.module().coverage.num_counters())
+            .map(|_| AtomicU64::new(0))
+            .collect();
+
         Ok(Self {
             inner: Arc::new(ModuleInner {
                 engine: engine.clone(),
@@ -588,6 +640,7 @@ impl Module {
                 module,
                 serializable,
                 offsets,
+                coverage_counters,
             }),
         })
     }
@@ -900,6 +953,32 @@ impl Module {
         &self.inner.engine
     }
 
+    /// Returns the code coverage collected so far by all instances of this
+    /// module.
+    ///
+    /// Returns `None` if the module wasn't compiled with
+    /// [`Config::coverage`] enabled.
+    ///
+    /// Counts are updated without synchronization, so while instances of this
+    /// module are running concurrently on multiple threads some increments may
+    /// be lost.
+    pub fn coverage(&self) -> Option<ModuleCoverage> {
+        if !self.engine().config().tunables.coverage {
+            return None;
+        }
+        Some(ModuleCoverage::new(
+            self.compiled_module(),
+            &self.inner.coverage_counters,
+        ))
+    }
+
+    /// Resets the code coverage counters of this module to zero.
+    pub fn reset_coverage(&self) {
+        for counter in self.inner.coverage_counters.iter() {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+
     /// Returns a summary of the resources required to instantiate this
     /// [`Module`].
     ///
@@ -1088,6 +1167,41 @@ impl Module {
         })
     }
 
+    /// Get the code generation statistics recorded for this module's
+    /// functions.
+    ///
+    /// Statistics are only recorded when [`Config::codegen_stats`] was
+    /// enabled while compiling the module, and only by Winch. Each item is the
+    /// index of a function in the module's function index space along with
+    /// its statistics; functions without statistics are skipped.
+    ///
+    /// [`Config::codegen_stats`]: crate::Config::codegen_stats
+    pub fn codegen_stats<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (u32, &'a FunctionCodegenStats)> + 'a {
+        let compiled = self.compiled_module();
+        compiled.finished_functions().filter_map(move |(f, _)| {
+            let stats = compiled.wasm_func_info(f).codegen_stats.as_deref()?;
+            Some((compiled.module().func_index(f).as_u32(), stats))
+        })
+    }
+
+    /// Get the indices of the functions in this module which ran out of
+    /// their compile-time budget.
+    ///
+    /// Each of these functions was compiled again with optimizations
+    /// disabled, see [`Config::cranelift_compile_time_budget`]. Indices are
+    /// in the module's function index space.
+    ///
+    /// [`Config::cranelift_compile_time_budget`]: crate::Config::cranelift_compile_time_budget
+    pub fn functions_over_compile_budget<'a>(&'a self) -> impl Iterator<Item = u32> + 'a {
+        let compiled = self.compiled_module();
+        compiled
+            .finished_functions()
+            .filter(move |(f, _)| compiled.wasm_func_info(*f).exceeded_compile_budget)
+            .map(move |(f, _)| compiled.module().func_index(f).as_u32())
+    }
+
     pub(crate) fn id(&self) -> CompiledModuleId {
         self.inner.module.unique_id()
     }
@@ -1120,6 +1234,49 @@ fn _assert_send_sync() {
     _assert::<Module>();
 }
 
+/// Compiles `binary` with `compile`, unless its artifacts are already present
+/// in the [`Config::artifact_cache_store`](crate::Config::artifact_cache_store)
+/// `store`, in which case they are loaded from there instead.
+///
+/// Returns the published code along with the value returned by `compile`
+/// besides the artifacts, which is `None` when the store was hit.
+#[cfg(all(feature = "cache", any(feature = "cranelift", feature = "winch")))]
+pub(crate) fn compile_with_cache_store<U>(
+    engine: &Engine,
+    store: &dyn crate::CacheStore,
+    kind: ObjectKind,
+    binary: &[u8],
+    compile: impl FnOnce() -> Result<(MmapVec, U)>,
+) -> Result<(Arc<CodeMemory>, Option<U>)> {
+    let kind_name = match kind {
+        ObjectKind::Module => "module",
+        ObjectKind::Component => "component",
+    };
+    let key = wasmtime_cache::content_hash(&(HashedEngineCompileEnv(engine), kind_name, binary));
+    if let Some(bytes) = store.get(key.as_bytes()) {
+        match engine.load_code_bytes(&bytes, kind) {
+            Ok(code) => return Ok((code, None)),
+            Err(e) => log::debug!("ignoring artifact cache entry {key}: {e:?}"),
+        }
+    }
+    let (mmap, extra) = compile()?;
+    if !store.insert(key.as_bytes(), mmap.to_vec()) {
+        log::debug!("failed to insert artifact cache entry {key}");
+    }
+    let code = engine.publish_code(mmap)?;
+    Ok((Arc::new(code), Some(extra)))
+}
+
+/// Compilation state which is passed through the cache without being part of
+/// its key, such as the monitor observing the compilation.
+#[cfg(all(feature = "cache", any(feature = "cranelift", feature = "winch")))]
+struct Unhashed<T>(T);
+
+#[cfg(all(feature = "cache", any(feature = "cranelift", feature = "winch")))]
+impl<T> std::hash::Hash for Unhashed<T> {
+    fn hash<H: std::hash::Hasher>(&self, _hasher: &mut H) {}
+}
+
 /// This is a helper struct used when caching to hash the state of an `Engine`
 /// used for module compilation.
 ///
@@ -1216,6 +1373,13 @@ impl wasmtime_runtime::ModuleRuntimeInfo for ModuleInner {
     fn offsets(&self) -> &VMOffsets<HostPtr> {
         &self.offsets
     }
+
+    fn coverage_counters(&self) -> *mut u64 {
+        if self.coverage_counters.is_empty() {
+            return std::ptr::null_mut();
+        }
+        self.coverage_counters.as_ptr().cast::<u64>().cast_mut()
+    }
 }
 
 impl wasmtime_runtime::ModuleInfo for ModuleInner {
@@ -1324,6 +1488,10 @@ impl wasmtime_runtime::ModuleRuntimeInfo for BareModuleInfo {
     fn offsets(&self) -> &VMOffsets<HostPtr> {
         &self.offsets
     }
+
+    fn coverage_counters(&self) -> *mut u64 {
+        std::ptr::null_mut()
+    }
 }
 
 /// Helper method to construct a `ModuleMemoryImages` for an associated
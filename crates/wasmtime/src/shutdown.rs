@@ -0,0 +1,218 @@
+//! Graceful shutdown of an engine and all of the stores using it.
+
+use crate::Engine;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use std::task::{Context, Poll, Waker};
+
+/// How far the epoch is advanced when shutdown is initiated.
+///
+/// This is far beyond any deadline a store sets in practice while leaving
+/// plenty of room for deadlines computed relative to the new epoch.
+const SHUTDOWN_EPOCH_JUMP: u64 = 1 << 62;
+
+/// Per-engine bookkeeping for [`Engine::initiate_shutdown`].
+#[derive(Default)]
+pub(crate) struct ShutdownState {
+    initiated: AtomicBool,
+    /// The activity of every store created with this engine.
+    ///
+    /// Each store counts its own calls into wasm so that entering and leaving
+    /// wasm never touches state shared between threads. The counts are only
+    /// looked at here, while waiting for shutdown to complete.
+    stores: Mutex<Vec<Weak<StoreActivity>>>,
+    wakers: Mutex<Vec<Waker>>,
+    exited: Condvar,
+}
+
+impl ShutdownState {
+    pub(crate) fn is_initiated(&self) -> bool {
+        self.initiated.load(Ordering::SeqCst)
+    }
+
+    /// Creates the activity counter of a new store using this engine.
+    pub(crate) fn register_store(self: &Arc<Self>) -> Arc<StoreActivity> {
+        let activity = Arc::new(StoreActivity {
+            active: AtomicUsize::new(0),
+            shutdown: self.clone(),
+        });
+        let mut stores = self.stores.lock().unwrap();
+        // Forget about dropped stores before the list would need to grow, so
+        // that it's proportional to the number of live stores.
+        if stores.len() == stores.capacity() {
+            stores.retain(|store| store.strong_count() > 0);
+        }
+        stores.push(Arc::downgrade(&activity));
+        activity
+    }
+
+    fn is_idle(&self) -> bool {
+        self.stores
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .all(|store| store.active.load(Ordering::SeqCst) == 0)
+    }
+
+    fn notify_exited(&self) {
+        // Take the lock so a waiter can't miss this notification between
+        // checking whether stores are idle and registering itself.
+        let wakers = std::mem::take(&mut *self.wakers.lock().unwrap());
+        for waker in wakers {
+            waker.wake();
+        }
+        self.exited.notify_all();
+    }
+}
+
+/// The number of calls into wasm made by a store which haven't returned yet.
+pub(crate) struct StoreActivity {
+    active: AtomicUsize,
+    shutdown: Arc<ShutdownState>,
+}
+
+impl StoreActivity {
+    /// Records a call into wasm, which lasts until the returned guard is
+    /// dropped.
+    pub(crate) fn enter_wasm(self: &Arc<Self>) -> ActiveWasm {
+        self.active.fetch_add(1, Ordering::SeqCst);
+        ActiveWasm(self.clone())
+    }
+}
+
+/// A call into wasm recorded with [`StoreActivity::enter_wasm`].
+pub(crate) struct ActiveWasm(Arc<StoreActivity>);
+
+impl Drop for ActiveWasm {
+    fn drop(&mut self) {
+        let activity = &self.0;
+        if activity.active.fetch_sub(1, Ordering::SeqCst) == 1 && activity.shutdown.is_initiated() {
+            activity.shutdown.notify_exited();
+        }
+    }
+}
+
+/// The error returned when instantiating after
+/// [`Engine::initiate_shutdown`] has been called.
+///
+/// This can be detected with `error.is::<EngineShutdown>()`.
+#[derive(Debug)]
+pub struct EngineShutdown(());
+
+impl fmt::Display for EngineShutdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("engine is shutting down")
+    }
+}
+
+impl std::error::Error for EngineShutdown {}
+
+/// Waits for the shutdown of an [`Engine`], created with
+/// [`Engine::initiate_shutdown`].
+///
+/// Shutdown is complete once every store using the engine has returned from
+/// wasm. This can be waited for by blocking with [`ShutdownHandle::wait`] or
+/// by awaiting this value as a [`Future`].
+pub struct ShutdownHandle {
+    engine: Engine,
+}
+
+impl ShutdownHandle {
+    /// Returns whether no store using the engine is currently executing wasm.
+    pub fn is_complete(&self) -> bool {
+        self.engine.shutdown_state().is_idle()
+    }
+
+    /// Blocks the current thread until no store using the engine is executing
+    /// wasm.
+    pub fn wait(self) {
+        let state = self.engine.shutdown_state();
+        let mut wakers = state.wakers.lock().unwrap();
+        while !state.is_idle() {
+            wakers = state.exited.wait(wakers).unwrap();
+        }
+    }
+}
+
+impl Future for ShutdownHandle {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let state = self.engine.shutdown_state();
+        if state.is_idle() {
+            return Poll::Ready(());
+        }
+        let mut wakers = state.wakers.lock().unwrap();
+        // Check again with the lock held so the last call to return from
+        // wasm can't take the list between our check above and the push
+        // below.
+        if state.is_idle() {
+            return Poll::Ready(());
+        }
+        wakers.push(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl fmt::Debug for ShutdownHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShutdownHandle")
+            .field("complete", &self.is_complete())
+            .finish()
+    }
+}
+
+impl Engine {
+    /// Starts shutting down this engine and every store using it.
+    ///
+    /// This is meant for hosts running many tenants on one engine which want
+    /// to stop all of them before the process exits. Once called:
+    ///
+    /// * The epoch is advanced past every store's deadline, and stores which
+    ///   reach an epoch check trap with
+    ///   [`Trap::Interrupt`](crate::Trap::Interrupt) regardless of their
+    ///   configured deadline behavior. This only interrupts code compiled
+    ///   with [`Config::epoch_interruption`](crate::Config::epoch_interruption).
+    /// * Compilations which are in progress, including those started with
+    ///   [`Engine::compile_module_in_background`], stop before compiling any
+    ///   further functions and fail with a
+    ///   [`CompilationCancelled`](crate::CompilationCancelled) error, as do
+    ///   new compilations.
+    /// * Instantiating modules or components fails with an [`EngineShutdown`]
+    ///   error.
+    ///
+    /// Calling into existing instances is still allowed, but with epoch
+    /// interruption enabled such calls trap at their first epoch check.
+    ///
+    /// The returned handle resolves once no store using this engine is
+    /// executing wasm. Calling this method again returns another handle for
+    /// the same shutdown.
+    pub fn initiate_shutdown(&self) -> ShutdownHandle {
+        let state = self.shutdown_state();
+        if !state.initiated.swap(true, Ordering::SeqCst) {
+            self.epoch_counter()
+                .fetch_add(SHUTDOWN_EPOCH_JUMP, Ordering::Relaxed);
+        }
+        ShutdownHandle {
+            engine: self.clone(),
+        }
+    }
+
+    /// Returns whether [`Engine::initiate_shutdown`] has been called.
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutdown_state().is_initiated()
+    }
+
+    /// Returns an error if this engine is shutting down and may no longer
+    /// instantiate modules or components.
+    pub(crate) fn check_not_shutting_down(&self) -> anyhow::Result<()> {
+        if self.is_shutting_down() {
+            return Err(EngineShutdown(()).into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,870 @@
+//! Capturing and restoring the mutable state of an instance.
+//!
+//! An [`InstanceSnapshot`] is a copy of the contents of an instance's linear
+//! memories and mutable globals. Restoring a snapshot puts the instance back
+//! into the captured state, which is the building block for time-travel
+//! debugging: a [`SnapshotTimeline`] records snapshots at positions of an
+//! embedder-defined execution log (for example the number of calls made into
+//! the instance), and [`SnapshotTimeline::rewind`] jumps back to the nearest
+//! snapshot at or before a target position so the embedder can replay its log
+//! forward from there.
+//!
+//! Wasmtime itself provides neither the execution log nor a debugger
+//! interface to drive the timeline; both are up to the embedder. Host calls
+//! can be recorded and replayed by wrapping imports with
+//! [`Linker::func_intercept`](crate::Linker::func_intercept). Snapshots don't
+//! capture the WebAssembly call stack either, so they can only be taken and
+//! restored at points where none of the instance's frames are live, such as
+//! between calls into it; replay then resumes by re-running the calls made
+//! after the restored snapshot.
+//!
+//! For frequent checkpoints, a [`DiffingSnapshot`] keeps the most recent
+//! state and produces [`SnapshotDelta`]s which only contain the pages of
+//! linear memory that differ from the previous checkpoint. Changes are found
+//! by comparing all of linear memory rather than by tracking which pages were
+//! written, so computing a delta costs time proportional to the size of
+//! memory, even though its size is proportional to the changes.
+//!
+//! Snapshots can also be persisted with a [`SnapshotEncoding`], which streams
+//! the serialized state through a chain of embedder-provided
+//! [`SnapshotCodec`]s such as compression or encryption. A zstd compression
+//! codec is provided as [`ZstdCodec`] with the `zstd` feature. Linear memory
+//! routinely contains secrets, so images written to disk should generally be
+//! encrypted.
+
+use crate::store::StoreOpaque;
+use crate::{AsContextMut, Instance, Memory, Mutability, Val};
+use anyhow::{bail, Context, Result};
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::sync::Arc;
+use wasmtime_environ::WASM_PAGE_SIZE;
+
+/// Leading bytes of a serialized [`InstanceSnapshot`], including a format
+/// version.
+const SNAPSHOT_MAGIC: &[u8; 8] = b"WTSNAP01";
+
+/// Leading bytes of a serialized [`SnapshotDelta`], including a format
+/// version.
+const DELTA_MAGIC: &[u8; 8] = b"WTDELT01";
+
+/// The granularity at which [`SnapshotDelta`]s track changes to linear
+/// memory.
+///
+/// This matches the smallest page size of common hosts, but is fixed so that
+/// deltas don't depend on the host that produced them.
+const DELTA_PAGE_SIZE: usize = 4096;
+
+/// A copy of the mutable state of an [`Instance`] at a point in time.
+///
+/// Snapshots capture the contents of all linear memories, both defined and
+/// imported, as well as the values of all mutable globals. Tables and the
+/// state of host objects are not captured.
+///
+/// Snapshots are created with [`Instance::snapshot`] and applied with
+/// [`Instance::restore`].
+#[derive(Clone)]
+pub struct InstanceSnapshot {
+    memories: Vec<Vec<u8>>,
+    globals: Vec<Option<Val>>,
+}
+
+impl InstanceSnapshot {
+    /// Returns the total number of bytes of linear memory captured in this
+    /// snapshot.
+    pub fn memory_bytes(&self) -> usize {
+        self.memories.iter().map(|m| m.len()).sum()
+    }
+
+    /// Returns the captured contents of each linear memory, in memory index
+    /// order.
+    pub fn memories(&self) -> impl ExactSizeIterator<Item = &[u8]> + '_ {
+        self.memories.iter().map(|m| &m[..])
+    }
+
+    /// Computes the changes from this snapshot to the `newer` one.
+    ///
+    /// Applying the returned delta to a copy of this snapshot with
+    /// [`InstanceSnapshot::apply`] yields the contents of `newer`. This
+    /// compares every page of the two snapshots, so it takes time
+    /// proportional to their size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshots were not taken from instances with
+    /// the same memories and globals.
+    pub fn delta_to(&self, newer: &InstanceSnapshot) -> Result<SnapshotDelta> {
+        self.check_shape(newer.memories.len(), newer.globals.len())?;
+        let memories = self
+            .memories
+            .iter()
+            .zip(&newer.memories)
+            .map(|(base, current)| MemoryDelta::diff(base, current))
+            .collect();
+        Ok(SnapshotDelta {
+            memories,
+            globals: newer.globals.clone(),
+        })
+    }
+
+    /// Updates this snapshot with the changes recorded in `delta`.
+    ///
+    /// Deltas must be applied to the snapshot they were computed against, in
+    /// the order they were produced; this is not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `delta` was not computed for an instance with the
+    /// same memories and globals as this snapshot.
+    pub fn apply(&mut self, delta: &SnapshotDelta) -> Result<()> {
+        self.check_shape(delta.memories.len(), delta.globals.len())?;
+        for (memory, delta) in self.memories.iter_mut().zip(&delta.memories) {
+            memory.resize(delta.len, 0);
+            for (index, page) in delta.pages.iter() {
+                let start = index * DELTA_PAGE_SIZE;
+                memory[start..][..page.len()].copy_from_slice(page);
+            }
+        }
+        self.globals.clone_from(&delta.globals);
+        Ok(())
+    }
+
+    fn check_shape(&self, memories: usize, globals: usize) -> Result<()> {
+        if self.memories.len() != memories || self.globals.len() != globals {
+            bail!("snapshots do not have the same memories and globals");
+        }
+        Ok(())
+    }
+
+    fn write(&self, out: &mut dyn Write) -> Result<()> {
+        out.write_all(SNAPSHOT_MAGIC)?;
+        out.write_all(&u32::try_from(self.memories.len())?.to_le_bytes())?;
+        for memory in self.memories.iter() {
+            out.write_all(&u64::try_from(memory.len())?.to_le_bytes())?;
+            out.write_all(memory)?;
+        }
+        write_globals(&self.globals, out)
+    }
+
+    fn read(input: &mut dyn Read) -> Result<InstanceSnapshot> {
+        if bytes::<8>(input).context("failed to read snapshot header")? != *SNAPSHOT_MAGIC {
+            bail!("not a serialized instance snapshot, or decoded with the wrong codecs");
+        }
+        let count = u32::from_le_bytes(bytes(input)?);
+        let mut memories = Vec::new();
+        for _ in 0..count {
+            let len = u64::from_le_bytes(bytes(input)?);
+            if len % u64::from(WASM_PAGE_SIZE) != 0 {
+                bail!("snapshot memory length is not a multiple of the page size");
+            }
+            let mut memory = Vec::new();
+            (&mut *input).take(len).read_to_end(&mut memory)?;
+            if memory.len() as u64 != len {
+                bail!("snapshot memory is truncated");
+            }
+            memories.push(memory);
+        }
+        let globals = read_globals(input)?;
+        Ok(InstanceSnapshot { memories, globals })
+    }
+}
+
+/// The changes to the state of an [`Instance`] between two snapshots.
+///
+/// A delta contains the pages of each linear memory which differ from the
+/// older snapshot, along with the values of all mutable globals. Deltas are
+/// produced by [`DiffingSnapshot::checkpoint`],
+/// [`Instance::snapshot_delta`] and [`InstanceSnapshot::delta_to`], and are
+/// applied to the older snapshot with [`InstanceSnapshot::apply`].
+#[derive(Clone)]
+pub struct SnapshotDelta {
+    memories: Vec<MemoryDelta>,
+    globals: Vec<Option<Val>>,
+}
+
+#[derive(Clone)]
+struct MemoryDelta {
+    /// The size of the memory, in bytes.
+    len: usize,
+    /// The contents of the pages which changed, keyed by page index.
+    pages: Vec<(usize, Vec<u8>)>,
+}
+
+impl MemoryDelta {
+    fn diff(base: &[u8], current: &[u8]) -> MemoryDelta {
+        let mut pages = Vec::new();
+        for (index, page) in current.chunks(DELTA_PAGE_SIZE).enumerate() {
+            let start = index * DELTA_PAGE_SIZE;
+            // Applying the delta zero-fills memory which grew, so new pages
+            // only need to be recorded if they were written to.
+            let changed = match base.get(start..start + page.len()) {
+                Some(old) => old != page,
+                None => page.iter().any(|b| *b != 0),
+            };
+            if changed {
+                pages.push((index, page.to_vec()));
+            }
+        }
+        MemoryDelta {
+            len: current.len(),
+            pages,
+        }
+    }
+}
+
+impl SnapshotDelta {
+    /// Returns the number of pages of linear memory recorded in this delta,
+    /// i.e. the pages whose contents differ from the older snapshot.
+    ///
+    /// Pages are 4 KiB, regardless of the host's page size.
+    pub fn changed_pages(&self) -> usize {
+        self.memories.iter().map(|m| m.pages.len()).sum()
+    }
+
+    /// Returns the total number of bytes of linear memory recorded in this
+    /// delta.
+    pub fn memory_bytes(&self) -> usize {
+        self.memories
+            .iter()
+            .flat_map(|m| m.pages.iter())
+            .map(|(_, page)| page.len())
+            .sum()
+    }
+
+    fn write(&self, out: &mut dyn Write) -> Result<()> {
+        out.write_all(DELTA_MAGIC)?;
+        out.write_all(&u32::try_from(self.memories.len())?.to_le_bytes())?;
+        for memory in self.memories.iter() {
+            out.write_all(&u64::try_from(memory.len)?.to_le_bytes())?;
+            out.write_all(&u64::try_from(memory.pages.len())?.to_le_bytes())?;
+            for (index, page) in memory.pages.iter() {
+                out.write_all(&u64::try_from(*index)?.to_le_bytes())?;
+                out.write_all(page)?;
+            }
+        }
+        write_globals(&self.globals, out)
+    }
+
+    fn read(input: &mut dyn Read) -> Result<SnapshotDelta> {
+        if bytes::<8>(input).context("failed to read snapshot delta header")? != *DELTA_MAGIC {
+            bail!("not a serialized snapshot delta, or decoded with the wrong codecs");
+        }
+        let count = u32::from_le_bytes(bytes(input)?);
+        let mut memories = Vec::new();
+        for _ in 0..count {
+            let len = u64::from_le_bytes(bytes(input)?);
+            if len % u64::from(WASM_PAGE_SIZE) != 0 {
+                bail!("snapshot delta memory length is not a multiple of the page size");
+            }
+            let len = usize::try_from(len)?;
+            let count = u64::from_le_bytes(bytes(input)?);
+            let mut pages = Vec::new();
+            for _ in 0..count {
+                let index = usize::try_from(u64::from_le_bytes(bytes(input)?))?;
+                if index >= len / DELTA_PAGE_SIZE {
+                    bail!("snapshot delta page is out of bounds of its memory");
+                }
+                let mut page = vec![0; DELTA_PAGE_SIZE];
+                input
+                    .read_exact(&mut page)
+                    .context("snapshot delta page is truncated")?;
+                pages.push((index, page));
+            }
+            memories.push(MemoryDelta { len, pages });
+        }
+        let globals = read_globals(input)?;
+        Ok(SnapshotDelta { memories, globals })
+    }
+}
+
+fn bytes<const N: usize>(input: &mut dyn Read) -> Result<[u8; N]> {
+    let mut bytes = [0; N];
+    input.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn write_globals(globals: &[Option<Val>], out: &mut dyn Write) -> Result<()> {
+    out.write_all(&u32::try_from(globals.len())?.to_le_bytes())?;
+    for global in globals.iter() {
+        match global {
+            None => out.write_all(&[0])?,
+            Some(Val::I32(i)) => {
+                out.write_all(&[1])?;
+                out.write_all(&i.to_le_bytes())?;
+            }
+            Some(Val::I64(i)) => {
+                out.write_all(&[2])?;
+                out.write_all(&i.to_le_bytes())?;
+            }
+            Some(Val::F32(bits)) => {
+                out.write_all(&[3])?;
+                out.write_all(&bits.to_le_bytes())?;
+            }
+            Some(Val::F64(bits)) => {
+                out.write_all(&[4])?;
+                out.write_all(&bits.to_le_bytes())?;
+            }
+            Some(Val::V128(v)) => {
+                out.write_all(&[5])?;
+                out.write_all(&v.as_u128().to_le_bytes())?;
+            }
+            Some(Val::FuncRef(_) | Val::ExternRef(_)) => {
+                bail!("cannot serialize a snapshot with a mutable reference-typed global")
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_globals(input: &mut dyn Read) -> Result<Vec<Option<Val>>> {
+    let count = u32::from_le_bytes(bytes(input)?);
+    let mut globals = Vec::new();
+    for _ in 0..count {
+        globals.push(match bytes::<1>(input)?[0] {
+            0 => None,
+            1 => Some(Val::I32(i32::from_le_bytes(bytes(input)?))),
+            2 => Some(Val::I64(i64::from_le_bytes(bytes(input)?))),
+            3 => Some(Val::F32(u32::from_le_bytes(bytes(input)?))),
+            4 => Some(Val::F64(u64::from_le_bytes(bytes(input)?))),
+            5 => Some(Val::V128(u128::from_le_bytes(bytes(input)?).into())),
+            tag => bail!("invalid snapshot global tag {tag}"),
+        });
+    }
+    Ok(globals)
+}
+
+impl Instance {
+    /// Captures the current contents of this instance's linear memories and
+    /// mutable globals.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instance uses a shared memory, whose contents
+    /// can be concurrently modified and therefore cannot be consistently
+    /// captured.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this instance.
+    pub fn snapshot(&self, mut store: impl AsContextMut) -> Result<InstanceSnapshot> {
+        let mut store = store.as_context_mut();
+        let memories = self.snapshot_memories(store.0)?;
+        let globals = self.snapshot_globals(&mut store);
+        let memories = memories
+            .into_iter()
+            .map(|memory| memory.data(&store).to_vec())
+            .collect();
+        Ok(InstanceSnapshot { memories, globals })
+    }
+
+    /// Computes the changes to this instance's state since `base` was taken.
+    ///
+    /// This compares linear memory against `base` page by page and only
+    /// copies the pages which differ. That is much cheaper than taking a full
+    /// snapshot when few pages changed, but still reads all of linear memory:
+    /// pages aren't tracked as they are written, so the cost is proportional
+    /// to the size of memory rather than to the number of changes. Applying the returned
+    /// delta to `base` with [`InstanceSnapshot::apply`] yields the same
+    /// state as [`Instance::snapshot`] would have captured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `base` was not taken from an instance with the
+    /// same memories and globals as this one, or if the instance uses a
+    /// shared memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this instance.
+    pub fn snapshot_delta(
+        &self,
+        mut store: impl AsContextMut,
+        base: &InstanceSnapshot,
+    ) -> Result<SnapshotDelta> {
+        let mut store = store.as_context_mut();
+        let memories = self.snapshot_memories(store.0)?;
+        let globals = self.snapshot_globals(&mut store);
+        base.check_shape(memories.len(), globals.len())?;
+        let memories = memories
+            .iter()
+            .zip(&base.memories)
+            .map(|(memory, base)| MemoryDelta::diff(base, memory.data(&store)))
+            .collect();
+        Ok(SnapshotDelta { memories, globals })
+    }
+
+    /// Restores this instance to the state captured in `snapshot`.
+    ///
+    /// Linear memories which have grown since the snapshot was taken keep
+    /// their current size, since memories cannot shrink, but their contents
+    /// beyond the captured size are zeroed. Memories which are smaller than
+    /// the snapshot are grown to fit it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `snapshot` was not taken from an instance with the
+    /// same memories and globals as this one, or if growing a memory fails.
+    /// Everything that can fail is checked before any state is overwritten,
+    /// so on error the contents of memories and globals are unchanged (though
+    /// memories grown before the failure stay grown).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this instance.
+    pub fn restore(&self, mut store: impl AsContextMut, snapshot: &InstanceSnapshot) -> Result<()> {
+        let mut store = store.as_context_mut();
+        let memories = self.snapshot_memories(store.0)?;
+        let globals = self.all_globals(store.0).collect::<Vec<_>>();
+        if memories.len() != snapshot.memories.len() || globals.len() != snapshot.globals.len() {
+            bail!("snapshot does not match the shape of this instance");
+        }
+        for ((_, global), value) in globals.iter().zip(&snapshot.globals) {
+            let ty = global.ty(&store);
+            let expected = match ty.mutability() {
+                Mutability::Var => Some(ty.content()),
+                Mutability::Const => None,
+            };
+            if value.as_ref().map(|v| v.ty()).as_ref() != expected {
+                bail!("snapshot does not match the shape of this instance");
+            }
+            if let Some(value) = value {
+                if !value.comes_from_same_store(store.0) {
+                    bail!("cross-`Store` values are not supported");
+                }
+            }
+        }
+
+        // Grow every memory that's too small before overwriting anything, so
+        // that a failure to grow doesn't leave a partially restored instance.
+        for (memory, contents) in memories.iter().zip(&snapshot.memories) {
+            let current = memory.data_size(&store);
+            if current < contents.len() {
+                let delta = (contents.len() - current) / WASM_PAGE_SIZE as usize;
+                memory.grow(&mut store, u64::try_from(delta).unwrap())?;
+            }
+        }
+
+        for (memory, contents) in memories.iter().zip(&snapshot.memories) {
+            let data = memory.data_mut(&mut store);
+            data[..contents.len()].copy_from_slice(contents);
+            data[contents.len()..].fill(0);
+        }
+
+        for ((_, global), value) in globals.into_iter().zip(&snapshot.globals) {
+            if let Some(value) = value {
+                global
+                    .set(&mut store, value.clone())
+                    .expect("global type was checked above");
+            }
+        }
+        Ok(())
+    }
+
+    fn snapshot_globals(&self, mut store: impl AsContextMut) -> Vec<Option<Val>> {
+        let mut store = store.as_context_mut();
+        let mut globals = Vec::new();
+        for (_, global) in self.all_globals(store.0).collect::<Vec<_>>() {
+            globals.push(match global.ty(&store).mutability() {
+                Mutability::Var => Some(global.get(&mut store)),
+                Mutability::Const => None,
+            });
+        }
+        globals
+    }
+
+    fn snapshot_memories(&self, store: &mut StoreOpaque) -> Result<Vec<Memory>> {
+        let memories = self
+            .all_memories(store)
+            .map(|(_, memory)| memory)
+            .collect::<Vec<_>>();
+        for memory in memories.iter() {
+            if memory.wasmtime_ty(store.store_data()).shared {
+                bail!("cannot snapshot an instance with a shared memory");
+            }
+        }
+        Ok(memories)
+    }
+}
+
+/// A history of [`InstanceSnapshot`]s keyed by position in an execution log.
+///
+/// The timeline does not define what a position is: embedders typically use
+/// the number of calls made into the instance, and call
+/// [`SnapshotTimeline::record`] between calls as execution progresses.
+/// Positions must be points where no WebAssembly frames of the instance are
+/// live, since snapshots don't capture the call stack. Snapshots are
+/// only actually taken every `interval` positions, and at most `capacity`
+/// snapshots are retained, discarding the oldest first.
+///
+/// To travel backwards in time to position `p`, call
+/// [`SnapshotTimeline::rewind`] which restores the most recent snapshot taken
+/// at or before `p` and returns its position; replaying the log forward from
+/// that position reaches `p`.
+pub struct SnapshotTimeline {
+    interval: u64,
+    capacity: usize,
+    last_recorded: Option<u64>,
+    snapshots: BTreeMap<u64, InstanceSnapshot>,
+}
+
+impl SnapshotTimeline {
+    /// Creates a new, empty timeline which takes a snapshot at most once
+    /// every `interval` positions and retains up to `capacity` snapshots.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` or `capacity` is zero.
+    pub fn new(interval: u64, capacity: usize) -> SnapshotTimeline {
+        assert!(interval > 0, "snapshot interval must be non-zero");
+        assert!(capacity > 0, "snapshot capacity must be non-zero");
+        SnapshotTimeline {
+            interval,
+            capacity,
+            last_recorded: None,
+            snapshots: BTreeMap::new(),
+        }
+    }
+
+    /// Notifies the timeline that execution of `instance` reached `position`,
+    /// taking a snapshot if at least `interval` positions have passed since
+    /// the previous one.
+    ///
+    /// Returns whether a snapshot was taken.
+    pub fn record(
+        &mut self,
+        store: impl AsContextMut,
+        instance: &Instance,
+        position: u64,
+    ) -> Result<bool> {
+        if let Some(last) = self.last_recorded {
+            if position < last.saturating_add(self.interval) {
+                return Ok(false);
+            }
+        }
+        let snapshot = instance.snapshot(store)?;
+        self.snapshots.insert(position, snapshot);
+        self.last_recorded = Some(position);
+        while self.snapshots.len() > self.capacity {
+            self.snapshots.pop_first();
+        }
+        Ok(true)
+    }
+
+    /// Restores `instance` to the most recent snapshot taken at or before
+    /// `target` and returns the position of that snapshot.
+    ///
+    /// Snapshots after the restored one are discarded, since execution will
+    /// diverge from them once replay resumes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no snapshot at or before `target` is retained, or
+    /// if restoring the snapshot fails.
+    pub fn rewind(
+        &mut self,
+        store: impl AsContextMut,
+        instance: &Instance,
+        target: u64,
+    ) -> Result<u64> {
+        let (position, snapshot) = match self.snapshots.range(..=target).next_back() {
+            Some((position, snapshot)) => (*position, snapshot),
+            None => bail!("no snapshot is retained at or before position {target}"),
+        };
+        instance.restore(store, snapshot)?;
+        if let Some(next) = position.checked_add(1) {
+            self.snapshots.split_off(&next);
+        }
+        self.last_recorded = Some(position);
+        Ok(position)
+    }
+
+    /// Returns the positions of all retained snapshots, oldest first.
+    pub fn positions(&self) -> impl Iterator<Item = u64> + '_ {
+        self.snapshots.keys().copied()
+    }
+
+    /// Returns the snapshot taken at exactly `position`, if retained.
+    pub fn get(&self, position: u64) -> Option<&InstanceSnapshot> {
+        self.snapshots.get(&position)
+    }
+}
+
+/// The state of an [`Instance`] as of its most recent checkpoint, for
+/// taking frequent snapshots as deltas.
+///
+/// Each [`DiffingSnapshot::checkpoint`] compares the instance against the
+/// previous checkpoint and returns a [`SnapshotDelta`] with just the pages of
+/// linear memory that changed. Embedders such as actor frameworks typically
+/// persist the initial [`DiffingSnapshot::snapshot`] once and then only the
+/// deltas; replaying the deltas onto the initial snapshot with
+/// [`InstanceSnapshot::apply`] recovers the state at any checkpoint.
+///
+/// # Cost
+///
+/// Changed pages are found by comparing every page of linear memory with
+/// the previous checkpoint, not by tracking writes with page protection or
+/// the operating system's dirty bits. Each checkpoint therefore takes time
+/// proportional to the size of linear memory, however few pages changed;
+/// only copying and retaining the changes is proportional to their size. The
+/// previous checkpoint is also kept in full, so memory use is twice the size
+/// of the instance's linear memory.
+pub struct DiffingSnapshot {
+    current: InstanceSnapshot,
+}
+
+impl DiffingSnapshot {
+    /// Takes an initial, full snapshot of `instance` to track changes
+    /// against.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`Instance::snapshot`].
+    pub fn new(store: impl AsContextMut, instance: &Instance) -> Result<DiffingSnapshot> {
+        Ok(DiffingSnapshot {
+            current: instance.snapshot(store)?,
+        })
+    }
+
+    /// Records the changes to `instance` since the previous checkpoint and
+    /// returns them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `instance` is not the instance this was created
+    /// for, or one of the same shape.
+    pub fn checkpoint(
+        &mut self,
+        store: impl AsContextMut,
+        instance: &Instance,
+    ) -> Result<SnapshotDelta> {
+        let delta = instance.snapshot_delta(store, &self.current)?;
+        self.current.apply(&delta)?;
+        Ok(delta)
+    }
+
+    /// Returns the state of the instance as of the most recent checkpoint.
+    pub fn snapshot(&self) -> &InstanceSnapshot {
+        &self.current
+    }
+}
+
+/// A stream transformation applied to serialized snapshots, such as
+/// compression or encryption.
+///
+/// Codecs are registered with a [`SnapshotEncoding`], which chains them
+/// together when writing and reading snapshots.
+pub trait SnapshotCodec: Send + Sync {
+    /// Wraps `output` in a sink which encodes the bytes written to it before
+    /// passing them on to `output`.
+    ///
+    /// The returned sink's [`SnapshotSink::finish`] must finish `output` once
+    /// its own trailing data, if any, has been written.
+    fn encoder<'a>(&self, output: Box<dyn SnapshotSink + 'a>)
+        -> Result<Box<dyn SnapshotSink + 'a>>;
+
+    /// Wraps `input` in a reader which decodes the bytes read from `input`,
+    /// reversing [`SnapshotCodec::encoder`].
+    fn decoder<'a>(&self, input: Box<dyn Read + 'a>) -> Result<Box<dyn Read + 'a>>;
+}
+
+/// A [`Write`] stream produced by a [`SnapshotEncoding`] which needs to be
+/// explicitly completed.
+pub trait SnapshotSink: Write {
+    /// Writes any buffered or trailing data, such as a compression footer or
+    /// an authentication tag, and then finishes the sink this one wraps.
+    fn finish(self: Box<Self>) -> Result<()>;
+}
+
+struct WriterSink<W>(W);
+
+impl<W: Write> Write for WriterSink<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl<W: Write> SnapshotSink for WriterSink<W> {
+    fn finish(mut self: Box<Self>) -> Result<()> {
+        self.0.flush()?;
+        Ok(())
+    }
+}
+
+/// Configuration for persisting [`InstanceSnapshot`]s and core dumps through
+/// a chain of [`SnapshotCodec`]s.
+///
+/// Codecs are applied in the order they are added when writing: the first
+/// codec sees the raw serialized bytes and the last one's output reaches the
+/// underlying writer. Reading undoes them in reverse, so the same
+/// configuration must be used on both ends. Compression codecs should be
+/// added before encryption codecs, since encrypted data does not compress.
+#[derive(Clone, Default)]
+pub struct SnapshotEncoding {
+    codecs: Vec<Arc<dyn SnapshotCodec>>,
+}
+
+impl SnapshotEncoding {
+    /// Creates a new encoding which writes snapshots without transforming
+    /// them.
+    pub fn new() -> SnapshotEncoding {
+        SnapshotEncoding::default()
+    }
+
+    /// Appends `codec` to the chain of codecs applied to serialized data.
+    pub fn codec(mut self, codec: impl SnapshotCodec + 'static) -> SnapshotEncoding {
+        self.codecs.push(Arc::new(codec));
+        self
+    }
+
+    /// Returns a sink which encodes everything written to it with the
+    /// configured codecs before writing it to `writer`.
+    ///
+    /// The returned sink must be completed with [`SnapshotSink::finish`].
+    pub fn encoder<'a>(&self, writer: impl Write + 'a) -> Result<Box<dyn SnapshotSink + 'a>> {
+        let mut sink: Box<dyn SnapshotSink + 'a> = Box::new(WriterSink(writer));
+        for codec in self.codecs.iter().rev() {
+            sink = codec.encoder(sink)?;
+        }
+        Ok(sink)
+    }
+
+    /// Returns a reader which decodes the data read from `reader` with the
+    /// configured codecs.
+    pub fn decoder<'a>(&self, reader: impl Read + 'a) -> Result<Box<dyn Read + 'a>> {
+        let mut source: Box<dyn Read + 'a> = Box::new(reader);
+        for codec in self.codecs.iter().rev() {
+            source = codec.decoder(source)?;
+        }
+        Ok(source)
+    }
+
+    /// Serializes `snapshot` through the configured codecs into `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing or encoding fails, or if the snapshot
+    /// captured a mutable `funcref` or `externref` global, which has no
+    /// serialized representation.
+    pub fn write_snapshot(&self, snapshot: &InstanceSnapshot, writer: impl Write) -> Result<()> {
+        let mut sink = self.encoder(writer)?;
+        snapshot.write(&mut sink)?;
+        sink.finish()
+    }
+
+    /// Reads a snapshot written by [`SnapshotEncoding::write_snapshot`] with
+    /// the same codecs from `reader`.
+    pub fn read_snapshot(&self, reader: impl Read) -> Result<InstanceSnapshot> {
+        let mut source = self.decoder(reader)?;
+        InstanceSnapshot::read(&mut source)
+    }
+
+    /// Serializes `delta` through the configured codecs into `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as
+    /// [`SnapshotEncoding::write_snapshot`].
+    pub fn write_delta(&self, delta: &SnapshotDelta, writer: impl Write) -> Result<()> {
+        let mut sink = self.encoder(writer)?;
+        delta.write(&mut sink)?;
+        sink.finish()
+    }
+
+    /// Reads a delta written by [`SnapshotEncoding::write_delta`] with the
+    /// same codecs from `reader`.
+    pub fn read_delta(&self, reader: impl Read) -> Result<SnapshotDelta> {
+        let mut source = self.decoder(reader)?;
+        SnapshotDelta::read(&mut source)
+    }
+
+    /// Serializes `coredump` as with [`WasmCoreDump::serialize`](crate::WasmCoreDump::serialize) and writes it
+    /// through the configured codecs into `writer`.
+    ///
+    /// The core dump is streamed through the codecs as it is serialized, so
+    /// linear memory contents are never buffered in full. The original core
+    /// dump can be recovered by reading all of [`SnapshotEncoding::decoder`].
+    #[cfg(feature = "coredump")]
+    pub fn write_coredump(
+        &self,
+        store: impl AsContextMut,
+        coredump: &crate::WasmCoreDump,
+        name: &str,
+        writer: impl Write,
+    ) -> Result<()> {
+        let mut sink = self.encoder(writer)?;
+        coredump.serialize_to(store, name, &mut sink)?;
+        sink.finish()
+    }
+}
+
+/// A [`SnapshotCodec`] which compresses data with zstd.
+#[cfg(feature = "zstd")]
+#[cfg_attr(nightlydoc, doc(cfg(feature = "zstd")))]
+#[derive(Clone, Debug)]
+pub struct ZstdCodec {
+    level: i32,
+}
+
+#[cfg(feature = "zstd")]
+impl ZstdCodec {
+    /// Creates a codec compressing at zstd's default level.
+    pub fn new() -> ZstdCodec {
+        ZstdCodec { level: 0 }
+    }
+
+    /// Sets the zstd compression level, where `0` selects zstd's default.
+    ///
+    /// Higher levels produce smaller output but compress more slowly. The
+    /// level doesn't need to match when decoding.
+    pub fn level(mut self, level: i32) -> ZstdCodec {
+        self.level = level;
+        self
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl Default for ZstdCodec {
+    fn default() -> ZstdCodec {
+        ZstdCodec::new()
+    }
+}
+
+#[cfg(feature = "zstd")]
+struct ZstdSink<'a>(zstd::stream::write::Encoder<'static, Box<dyn SnapshotSink + 'a>>);
+
+#[cfg(feature = "zstd")]
+impl Write for ZstdSink<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl SnapshotSink for ZstdSink<'_> {
+    fn finish(self: Box<Self>) -> Result<()> {
+        self.0.finish()?.finish()
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl SnapshotCodec for ZstdCodec {
+    fn encoder<'a>(
+        &self,
+        output: Box<dyn SnapshotSink + 'a>,
+    ) -> Result<Box<dyn SnapshotSink + 'a>> {
+        let encoder = zstd::stream::write::Encoder::new(output, self.level)?;
+        Ok(Box::new(ZstdSink(encoder)))
+    }
+
+    fn decoder<'a>(&self, input: Box<dyn Read + 'a>) -> Result<Box<dyn Read + 'a>> {
+        Ok(Box::new(zstd::stream::read::Decoder::new(input)?))
+    }
+}
@@ -76,14 +76,20 @@
 //! contents of `StoreOpaque`. This is an invariant that we, as the authors of
 //! `wasmtime`, must uphold for the public interface to be safe.
 
+use crate::admission::Tenant;
+#[cfg(feature = "async")]
+use crate::cancel::{CancelOnDrop, CancelToken, Registration};
 use crate::instance::InstanceData;
 use crate::linker::Definition;
 use crate::module::{BareModuleInfo, RegisteredModuleId};
+use crate::shutdown::StoreActivity;
 use crate::trampoline::VMHostGlobalContext;
-use crate::{module::ModuleRegistry, Engine, Module, Trap, Val, ValRaw};
-use crate::{Global, Instance, Memory};
+use crate::{module::ModuleRegistry, CallCancelled, Engine, Func, Module, Trap, Val, ValRaw};
+use crate::{AllocationProfiler, BlockEntry, Global, Instance, Memory, MemoryAccess};
 use anyhow::{anyhow, bail, Result};
+use std::backtrace::Backtrace;
 use std::cell::UnsafeCell;
+use std::collections::HashMap;
 use std::fmt;
 use std::future::Future;
 use std::marker;
@@ -97,8 +103,8 @@ use std::sync::Arc;
 use std::task::{Context, Poll};
 use wasmtime_runtime::{
     mpk::ProtectionKey, ExportGlobal, InstanceAllocationRequest, InstanceAllocator, InstanceHandle,
-    ModuleInfo, OnDemandInstanceAllocator, SignalHandler, StoreBox, StorePtr, VMContext,
-    VMExternRef, VMExternRefActivationsTable, VMFuncRef, VMRuntimeLimits, WasmFault,
+    ModuleInfo, OnDemandInstanceAllocator, SignalHandler, StoreBox, StorePtr, TableElementType,
+    VMContext, VMExternRef, VMExternRefActivationsTable, VMFuncRef, VMRuntimeLimits, WasmFault,
 };
 
 mod context;
@@ -107,6 +113,8 @@ mod data;
 pub use self::data::*;
 mod func_refs;
 use func_refs::FuncRefs;
+mod report;
+pub use self::report::*;
 
 /// A [`Store`] is a collection of WebAssembly instances and host-defined state.
 ///
@@ -194,6 +202,76 @@ impl CallHook {
     }
 }
 
+/// Information about a call to a host function passed to hooks registered
+/// with [`Store::host_call_hook`].
+#[derive(Debug)]
+pub struct HostCall<'a> {
+    module: &'a str,
+    name: &'a str,
+    params: &'a [Val],
+}
+
+impl<'a> HostCall<'a> {
+    pub(crate) fn new(module: &'a str, name: &'a str, params: &'a [Val]) -> HostCall<'a> {
+        HostCall {
+            module,
+            name,
+            params,
+        }
+    }
+
+    /// Returns the module name the called host function was defined under.
+    pub fn module(&self) -> &'a str {
+        self.module
+    }
+
+    /// Returns the name the called host function was defined under.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns the arguments the host function is being called with.
+    pub fn params(&self) -> &'a [Val] {
+        self.params
+    }
+}
+
+struct HostCallHook<T> {
+    filter: HostCallFilter,
+    hook: Box<dyn FnMut(&mut T, &HostCall<'_>) -> Result<()> + Send + Sync>,
+}
+
+/// Which host calls a [`HostCallHook`] is invoked for.
+enum HostCallFilter {
+    /// Calls to functions defined under `module`, or only to `module::name`.
+    Name {
+        module: String,
+        name: Option<String>,
+    },
+    /// Calls made by instances of `caller` through a function import, which
+    /// is imported as `module::name`.
+    Import {
+        caller: Arc<wasmtime_environ::Module>,
+        module: String,
+        name: String,
+    },
+}
+
+impl<T> HostCallHook<T> {
+    fn matches(&self, caller: &Arc<wasmtime_environ::Module>, module: &str, name: &str) -> bool {
+        match &self.filter {
+            HostCallFilter::Name { module: m, name: n } => {
+                m == module && n.as_deref().map_or(true, |n| n == name)
+            }
+            HostCallFilter::Import {
+                caller: c,
+                module: m,
+                name: n,
+            } => Arc::ptr_eq(c, caller) && m == module && n == name,
+        }
+    }
+}
+
 /// Internal contents of a `Store<T>` that live on the heap.
 ///
 /// The members of this struct are those that need to be generic over `T`, the
@@ -205,12 +283,21 @@ pub struct StoreInner<T> {
 
     limiter: Option<ResourceLimiterInner<T>>,
     call_hook: Option<CallHookInner<T>>,
-    epoch_deadline_behavior:
-        Option<Box<dyn FnMut(StoreContextMut<T>) -> Result<UpdateDeadline> + Send + Sync>>,
+    memory_trace: Option<MemoryTraceHook<T>>,
+    block_trace: Option<BlockTraceHook<T>>,
+    host_call_hooks: Vec<HostCallHook<T>>,
+    epoch_deadline_behavior: Option<EpochDeadlineBehavior<T>>,
     // for comments about `ManuallyDrop`, see `Store::into_data`
     data: ManuallyDrop<T>,
 }
 
+type MemoryTraceHook<T> = Box<dyn FnMut(&mut T, MemoryAccess) -> Result<()> + Send + Sync>;
+
+type BlockTraceHook<T> = Box<dyn FnMut(&mut T, BlockEntry) -> Result<()> + Send + Sync>;
+
+type EpochDeadlineBehavior<T> =
+    Box<dyn FnMut(StoreContextMut<T>) -> Result<UpdateDeadline> + Send + Sync>;
+
 enum ResourceLimiterInner<T> {
     Sync(Box<dyn FnMut(&mut T) -> &mut (dyn crate::ResourceLimiter) + Send + Sync>),
     #[cfg(feature = "async")]
@@ -298,6 +385,9 @@ pub struct StoreOpaque {
     modules: ModuleRegistry,
     func_refs: FuncRefs,
     host_globals: Vec<StoreBox<VMHostGlobalContext>>,
+    /// Whether instances record where they were created, for
+    /// `Store::resource_report`.
+    track_resource_origins: bool,
 
     // Numbers of resources instantiated in this store, and their limits
     instance_count: usize,
@@ -322,6 +412,15 @@ pub struct StoreOpaque {
     /// otherwise kept alive by the `Arc` references in `rooted_host_funcs`.
     store_data: ManuallyDrop<StoreData>,
     default_caller: InstanceHandle,
+    /// The tenant this store's instantiations are admitted on behalf of, if
+    /// configured with `Store::set_admission_tenant`.
+    admission_tenant: Option<u64>,
+    /// Records memory and table growth, if configured with
+    /// `Store::set_allocation_profiler`.
+    allocation_profiler: Option<AllocationProfiler>,
+    /// Calls into wasm from this store which haven't returned yet, for
+    /// `Engine::initiate_shutdown`.
+    wasm_activity: Arc<StoreActivity>,
 
     /// Used to optimzed wasm->host calls when the host function is defined with
     /// `Func::new` to avoid allocating a new vector each time a function is
@@ -345,6 +444,11 @@ pub struct StoreOpaque {
     /// `store_data` above, where the function pointers are stored.
     rooted_host_funcs: ManuallyDrop<Vec<Arc<[Definition]>>>,
 
+    /// The original functions of `Linker::func_intercept` interceptors used in
+    /// this store, keyed by the interceptor's id. These are resolved once, when
+    /// the interceptor is first instantiated in this store.
+    intercepted_funcs: HashMap<u64, Func>,
+
     /// Keep track of what protection key is being used during allocation so
     /// that the right memory pages can be enabled when entering WebAssembly
     /// guest code.
@@ -363,6 +467,12 @@ pub struct StoreOpaque {
 struct AsyncState {
     current_suspend: UnsafeCell<*const wasmtime_fiber::Suspend<Result<()>, (), Result<()>>>,
     current_poll_cx: UnsafeCell<*mut Context<'static>>,
+    /// Cancellation signal for the outermost async call currently running in
+    /// this store, if any.
+    cancel_token: Option<CancelToken>,
+    /// The number of fiber stacks allocated for this store which haven't been
+    /// deallocated yet, for `Store::resource_report`.
+    fiber_stacks: Arc<std::sync::atomic::AtomicUsize>,
 }
 
 // Lots of pesky unsafe cells and pointers in this structure. This means we need
@@ -441,6 +551,8 @@ where
 struct StoreInstance {
     handle: InstanceHandle,
     kind: StoreInstanceKind,
+    /// Where this instance was created, for `Store::resource_report`.
+    created_at: Option<Arc<Backtrace>>,
 }
 
 enum StoreInstanceKind {
@@ -485,6 +597,7 @@ impl<T> Store<T> {
                 modules: ModuleRegistry::default(),
                 func_refs: FuncRefs::default(),
                 host_globals: Vec::new(),
+                track_resource_origins: false,
                 instance_count: 0,
                 instance_limit: crate::DEFAULT_INSTANCE_LIMIT,
                 memory_count: 0,
@@ -495,14 +608,20 @@ impl<T> Store<T> {
                 async_state: AsyncState {
                     current_suspend: UnsafeCell::new(ptr::null()),
                     current_poll_cx: UnsafeCell::new(ptr::null_mut()),
+                    cancel_token: None,
+                    fiber_stacks: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
                 },
                 fuel_reserve: 0,
                 fuel_yield_interval: None,
                 store_data: ManuallyDrop::new(StoreData::new()),
                 default_caller: InstanceHandle::null(),
+                admission_tenant: None,
+                allocation_profiler: None,
+                wasm_activity: engine.shutdown_state().register_store(),
                 hostcall_val_storage: Vec::new(),
                 wasm_val_raw_storage: Vec::new(),
                 rooted_host_funcs: ManuallyDrop::new(Vec::new()),
+                intercepted_funcs: HashMap::new(),
                 pkey,
                 #[cfg(feature = "component-model")]
                 component_host_table: Default::default(),
@@ -511,6 +630,9 @@ impl<T> Store<T> {
             },
             limiter: None,
             call_hook: None,
+            memory_trace: None,
+            block_trace: None,
+            host_call_hooks: Vec::new(),
             epoch_deadline_behavior: None,
             data: ManuallyDrop::new(data),
         });
@@ -766,6 +888,116 @@ impl<T> Store<T> {
         self.inner.call_hook = Some(CallHookInner::Sync(Box::new(hook)));
     }
 
+    /// Configures a function that runs before every linear memory load and
+    /// store made by modules compiled with
+    /// [`Config::memory_trace`](crate::Config::memory_trace).
+    ///
+    /// The function is passed the [`MemoryAccess`] about to be performed. If
+    /// it returns an error that's raised as a trap at the load or store.
+    ///
+    /// Accesses are silently ignored if no function is configured, and
+    /// modules compiled without memory tracing never call it.
+    pub fn memory_trace(
+        &mut self,
+        hook: impl FnMut(&mut T, MemoryAccess) -> Result<()> + Send + Sync + 'static,
+    ) {
+        self.inner.memory_trace = Some(Box::new(hook));
+    }
+
+    /// Configures a function that runs on entry to every basic block of
+    /// modules compiled with
+    /// [`Config::block_trace`](crate::Config::block_trace).
+    ///
+    /// The function is passed the [`BlockEntry`] being executed. If it
+    /// returns an error that's raised as a trap at the start of the block.
+    ///
+    /// Block entries are silently ignored if no function is configured, and
+    /// modules compiled without block tracing never call it.
+    pub fn block_trace(
+        &mut self,
+        hook: impl FnMut(&mut T, BlockEntry) -> Result<()> + Send + Sync + 'static,
+    ) {
+        self.inner.block_trace = Some(Box::new(hook));
+    }
+
+    /// Registers a hook to be invoked with the arguments of calls to selected
+    /// host functions.
+    ///
+    /// The `hook` is invoked just before a host function defined under the
+    /// module name `module` is called from WebAssembly, and only for the
+    /// function named `name` if one is given. It receives a [`HostCall`]
+    /// describing the callee and giving read-only access to its arguments,
+    /// which makes it suitable for audit logging. Returning an error from the
+    /// hook prevents the host function from being called and the error is
+    /// propagated as a trap to WebAssembly, which can be used to enforce
+    /// policies.
+    ///
+    /// Hooks are invoked for every host function defined in a
+    /// [`Linker`](crate::Linker) under a name, whether through
+    /// [`Linker::func_new`](crate::Linker::func_new),
+    /// [`Linker::func_new_unchecked`](crate::Linker::func_new_unchecked),
+    /// [`Linker::func_wrap`](crate::Linker::func_wrap) or their async
+    /// variants. Arguments of typed functions are converted to [`Val`]s only
+    /// when a matching hook is registered. Functions created with
+    /// [`Func::new`](crate::Func::new) or [`Func::wrap`](crate::Func::wrap)
+    /// have no name and never invoke these hooks. Multiple hooks may be
+    /// registered and matching hooks run in registration order after the hook
+    /// configured with [`Store::call_hook`].
+    pub fn host_call_hook(
+        &mut self,
+        module: &str,
+        name: Option<&str>,
+        hook: impl FnMut(&mut T, &HostCall<'_>) -> Result<()> + Send + Sync + 'static,
+    ) {
+        self.inner.host_call_hooks.push(HostCallHook {
+            filter: HostCallFilter::Name {
+                module: module.to_string(),
+                name: name.map(|n| n.to_string()),
+            },
+            hook: Box::new(hook),
+        });
+    }
+
+    /// Registers a hook to be invoked with the arguments of calls that
+    /// instances of `module` make through one of their function imports.
+    ///
+    /// `index` is the index of the imported function in `module`'s function
+    /// index space, in which imported functions come before defined ones.
+    /// Otherwise this behaves like [`Store::host_call_hook`], and hooks
+    /// registered with either method run together in registration order.
+    ///
+    /// The import is identified by the name it's imported under, so the hook
+    /// only runs if the host function was defined in the
+    /// [`Linker`](crate::Linker) under that same name, and calls through
+    /// other imports of `module` with the same name also invoke it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the function at `index` in `module` isn't
+    /// imported.
+    pub fn host_call_hook_for_import(
+        &mut self,
+        module: &Module,
+        index: u32,
+        hook: impl FnMut(&mut T, &HostCall<'_>) -> Result<()> + Send + Sync + 'static,
+    ) -> Result<()> {
+        let (import_module, import_name, _) = module
+            .env_module()
+            .imports()
+            .filter(|(_, _, ty)| matches!(ty, wasmtime_environ::EntityType::Function(_)))
+            .nth(usize::try_from(index).unwrap())
+            .ok_or_else(|| anyhow!("function {index} is not imported"))?;
+        self.inner.host_call_hooks.push(HostCallHook {
+            filter: HostCallFilter::Import {
+                caller: module.compiled_module().module().clone(),
+                module: import_module.to_string(),
+                name: import_name.to_string(),
+            },
+            hook: Box::new(hook),
+        });
+        Ok(())
+    }
+
     /// Returns the [`Engine`] that this store is associated with.
     pub fn engine(&self) -> &Engine {
         self.inner.engine()
@@ -780,6 +1012,37 @@ impl<T> Store<T> {
         self.inner.gc()
     }
 
+    /// Configures the maximum number of `ExternRef`s released per garbage
+    /// collection.
+    ///
+    /// Each garbage collection walks the Wasm stack to find live `ExternRef`s
+    /// and then releases every reference that is no longer live. Releasing a
+    /// reference may run arbitrary destructors, so for stores holding many
+    /// `ExternRef`s the release phase can dominate GC pause times.
+    ///
+    /// With a budget of `Some(n)` at most `n` references are released by each
+    /// GC safepoint, and the remaining unreachable references are released
+    /// incrementally by subsequent GCs or by [`Store::gc_incremental_step`].
+    /// The number of references left pending is bounded, though: if more
+    /// than 512 are queued (1024 on 32-bit hosts) then a GC releases the
+    /// excess even when that exceeds the budget. With `None`, the default,
+    /// every unreachable reference is released before the GC returns.
+    pub fn gc_release_budget(&mut self, budget: Option<usize>) {
+        self.inner.gc_release_budget(budget)
+    }
+
+    /// Performs one incremental step of `ExternRef` garbage collection.
+    ///
+    /// This releases up to the budget configured with
+    /// [`Store::gc_release_budget`] of the references that a previous GC found
+    /// to be unreachable, without walking the stack again. At least one
+    /// reference is released per step, even with a budget of `Some(0)`.
+    /// Returns `true` if there are still references pending release
+    /// afterwards.
+    pub fn gc_incremental_step(&mut self) -> bool {
+        self.inner.gc_incremental_step()
+    }
+
     /// Returns the amount fuel in this [`Store`].
     ///
     /// If fuel consumption is not enabled via
@@ -967,6 +1230,147 @@ impl<T> Store<T> {
     pub fn epoch_deadline_async_yield_and_update(&mut self, delta: u64) {
         self.inner.epoch_deadline_async_yield_and_update(delta);
     }
+
+    /// Groups this store's instantiations with those of other stores using
+    /// the same `tenant` when limiting concurrent instantiations.
+    ///
+    /// When [`Config::max_concurrent_instantiations`] is configured, waiting
+    /// instantiations are admitted round-robin between tenants. By default
+    /// each store is its own tenant; stores which belong to the same customer,
+    /// for example, can share a tenant so that creating many stores doesn't
+    /// give that customer a larger share of the engine.
+    ///
+    /// Has no effect if the engine doesn't limit concurrent instantiations.
+    pub fn set_admission_tenant(&mut self, tenant: u64) {
+        self.inner.admission_tenant = Some(tenant);
+    }
+
+    /// Starts recording the growth of memories and tables in this store with
+    /// `profiler`, replacing any previously installed profiler.
+    ///
+    /// See [`AllocationProfiler`] for more information.
+    pub fn set_allocation_profiler(&mut self, profiler: AllocationProfiler) {
+        self.inner.allocation_profiler = Some(profiler);
+    }
+
+    /// Stops recording allocations and returns the profiler installed with
+    /// [`Store::set_allocation_profiler`], if any, with the allocations it
+    /// recorded.
+    pub fn take_allocation_profiler(&mut self) -> Option<AllocationProfiler> {
+        self.inner.allocation_profiler.take()
+    }
+
+    /// Returns the profiler installed with
+    /// [`Store::set_allocation_profiler`], if any.
+    pub fn allocation_profiler(&self) -> Option<&AllocationProfiler> {
+        self.inner.allocation_profiler.as_ref()
+    }
+
+    /// Temporarily overrides the epoch deadline and what happens when it is
+    /// reached, for example for a single call.
+    ///
+    /// The returned [`EpochDeadlineScope`] dereferences to this store and can
+    /// be used in its place, for example to call functions. Within the scope
+    /// the store traps when the epoch deadline is reached, as a new store
+    /// does, and the deadline itself is unchanged. Both can be reconfigured
+    /// through the scope with the usual methods such as
+    /// [`Store::set_epoch_deadline`] and [`Store::epoch_deadline_callback`].
+    ///
+    /// When the scope is dropped the deadline behavior that the store had
+    /// before the scope was created is restored, and the epoch deadline is
+    /// set as far ahead of the current epoch as it was ahead of the epoch when
+    /// the scope was created. This allows,
+    /// for example, request handlers which should trap at their deadline and
+    /// background jobs which should yield to share a store.
+    ///
+    /// ```
+    /// # use wasmtime::*;
+    /// # fn main() -> anyhow::Result<()> {
+    /// # let mut config = Config::new();
+    /// # config.epoch_interruption(true);
+    /// # let engine = Engine::new(&config)?;
+    /// let mut store = Store::new(&engine, ());
+    /// store.set_epoch_deadline(100);
+    /// store.epoch_deadline_callback(|_| Ok(UpdateDeadline::Continue(100)));
+    ///
+    /// let module = Module::new(&engine, r#"(module (func (export "run")))"#)?;
+    /// let instance = Instance::new(&mut store, &module, &[])?;
+    /// let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+    /// {
+    ///     // This call traps if it takes more than 10 ticks.
+    ///     let mut scope = store.epoch_deadline_scope();
+    ///     scope.set_epoch_deadline(10);
+    ///     run.call(&mut scope, ())?;
+    /// }
+    /// // Later calls use the callback again.
+    /// run.call(&mut store, ())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// See documentation on
+    /// [`Config::epoch_interruption()`](crate::Config::epoch_interruption)
+    /// for an introduction to epoch-based interruption.
+    pub fn epoch_deadline_scope(&mut self) -> EpochDeadlineScope<'_, T> {
+        let behavior = self.inner.epoch_deadline_behavior.take();
+        let remaining = self
+            .inner
+            .get_epoch_deadline()
+            .saturating_sub(self.engine().current_epoch());
+        EpochDeadlineScope {
+            store: self,
+            behavior,
+            remaining,
+        }
+    }
+}
+
+/// A scope in which the epoch deadline behavior of a [`Store`] is overridden,
+/// created with [`Store::epoch_deadline_scope`].
+///
+/// This dereferences to the [`Store`] and can be used wherever a store
+/// context is expected. Dropping it restores the store's previous epoch
+/// deadline and deadline behavior.
+pub struct EpochDeadlineScope<'a, T> {
+    store: &'a mut Store<T>,
+    behavior: Option<EpochDeadlineBehavior<T>>,
+    remaining: u64,
+}
+
+impl<T> Deref for EpochDeadlineScope<'_, T> {
+    type Target = Store<T>;
+    fn deref(&self) -> &Store<T> {
+        self.store
+    }
+}
+
+impl<T> DerefMut for EpochDeadlineScope<'_, T> {
+    fn deref_mut(&mut self) -> &mut Store<T> {
+        self.store
+    }
+}
+
+impl<T> AsContext for EpochDeadlineScope<'_, T> {
+    type Data = T;
+
+    #[inline]
+    fn as_context(&self) -> StoreContext<'_, T> {
+        self.store.as_context()
+    }
+}
+
+impl<T> AsContextMut for EpochDeadlineScope<'_, T> {
+    #[inline]
+    fn as_context_mut(&mut self) -> StoreContextMut<'_, T> {
+        self.store.as_context_mut()
+    }
+}
+
+impl<T> Drop for EpochDeadlineScope<'_, T> {
+    fn drop(&mut self) {
+        self.store.inner.epoch_deadline_behavior = self.behavior.take();
+        self.store.inner.restore_epoch_deadline(self.remaining);
+    }
 }
 
 impl<'a, T> StoreContext<'a, T> {
@@ -1021,6 +1425,21 @@ impl<'a, T> StoreContextMut<'a, T> {
         self.0.gc()
     }
 
+    /// Configures the maximum number of `ExternRef`s released per garbage
+    /// collection.
+    ///
+    /// Same as [`Store::gc_release_budget`].
+    pub fn gc_release_budget(&mut self, budget: Option<usize>) {
+        self.0.gc_release_budget(budget)
+    }
+
+    /// Performs one incremental step of `ExternRef` garbage collection.
+    ///
+    /// Same as [`Store::gc_incremental_step`].
+    pub fn gc_incremental_step(&mut self) -> bool {
+        self.0.gc_incremental_step()
+    }
+
     /// Returns remaining fuel in this store.
     ///
     /// For more information see [`Store::get_fuel`]
@@ -1028,6 +1447,15 @@ impl<'a, T> StoreContextMut<'a, T> {
         self.0.get_fuel()
     }
 
+    /// Returns the cancellation token of the async call currently running in
+    /// this store, or `None` if no async call is running.
+    ///
+    /// For more information see [`CancelToken`].
+    #[cfg(feature = "async")]
+    pub fn cancel_token(&self) -> Option<CancelToken> {
+        self.0.cancel_token()
+    }
+
     /// Set the amount of fuel in this store.
     ///
     /// For more information see [`Store::set_fuel`]
@@ -1105,6 +1533,30 @@ impl<T> StoreInner<T> {
             None => Ok(()),
         }
     }
+
+    pub(crate) fn has_host_call_hook(
+        &self,
+        caller: &Arc<wasmtime_environ::Module>,
+        module: &str,
+        name: &str,
+    ) -> bool {
+        self.host_call_hooks
+            .iter()
+            .any(|h| h.matches(caller, module, name))
+    }
+
+    pub(crate) fn host_call_hooks(
+        &mut self,
+        caller: &Arc<wasmtime_environ::Module>,
+        call: &HostCall<'_>,
+    ) -> Result<()> {
+        for hook in self.host_call_hooks.iter_mut() {
+            if hook.matches(caller, call.module, call.name) {
+                (hook.hook)(&mut self.data, call)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 fn get_fuel(injected_fuel: i64, fuel_reserve: u64) -> u64 {
@@ -1152,6 +1604,13 @@ impl StoreOpaque {
         self.store_data.id()
     }
 
+    pub(crate) fn admission_tenant(&self) -> Tenant {
+        match self.admission_tenant {
+            Some(tenant) => Tenant::Explicit(tenant),
+            None => Tenant::Store(self.id().as_u64()),
+        }
+    }
+
     pub fn bump_resource_counts(&mut self, module: &Module) -> Result<()> {
         fn bump(slot: &mut usize, max: usize, amt: usize, desc: &str) -> Result<()> {
             let new = slot.saturating_add(amt);
@@ -1192,6 +1651,11 @@ impl StoreOpaque {
         &self.engine
     }
 
+    #[inline]
+    pub(crate) fn wasm_activity(&self) -> &Arc<StoreActivity> {
+        &self.wasm_activity
+    }
+
     #[inline]
     pub fn store_data(&self) -> &StoreData {
         &self.store_data
@@ -1249,6 +1713,9 @@ impl StoreOpaque {
         self.instances.push(StoreInstance {
             handle: handle.clone(),
             kind: StoreInstanceKind::Real { module_id },
+            created_at: self
+                .track_resource_origins
+                .then(StoreResourceReport::capture_origin),
         });
         InstanceId(self.instances.len() - 1)
     }
@@ -1262,6 +1729,7 @@ impl StoreOpaque {
         self.instances.push(StoreInstance {
             handle: handle.clone(),
             kind: StoreInstanceKind::Dummy,
+            created_at: None,
         });
         InstanceId(self.instances.len() - 1)
     }
@@ -1362,6 +1830,18 @@ impl StoreOpaque {
         }
     }
 
+    pub fn gc_release_budget(&mut self, budget: Option<usize>) {
+        self.externref_activations_table.set_release_budget(budget);
+    }
+
+    pub fn gc_incremental_step(&mut self) -> bool {
+        let budget = self
+            .externref_activations_table
+            .release_budget()
+            .map(|b| b.max(1));
+        self.externref_activations_table.release_pending(budget) > 0
+    }
+
     /// Yields the async context, assuming that we are executing on a fiber and
     /// that fiber is not in the process of dying. This function will return
     /// None in the latter case (the fiber is dying), and panic if
@@ -1384,9 +1864,26 @@ impl StoreOpaque {
         Some(AsyncCx {
             current_suspend: self.async_state.current_suspend.get(),
             current_poll_cx: poll_cx_box_ptr,
+            cancel_token: self.async_state.cancel_token.clone(),
         })
     }
 
+    /// Returns the cancellation token of the async call currently running in
+    /// this store, if any.
+    #[cfg(feature = "async")]
+    pub fn cancel_token(&self) -> Option<CancelToken> {
+        self.async_state.cancel_token.clone()
+    }
+
+    /// Cancels the async call currently running in this store, if any, because
+    /// it's about to trap.
+    #[cfg(feature = "async")]
+    fn cancel_current_call(&self) {
+        if let Some(token) = &self.async_state.cancel_token {
+            token.cancel();
+        }
+    }
+
     pub fn get_fuel(&self) -> Result<u64> {
         anyhow::ensure!(
             self.engine().config().tunables.consume_fuel,
@@ -1544,6 +2041,14 @@ impl StoreOpaque {
         self.rooted_host_funcs.push(funcs);
     }
 
+    pub(crate) fn intercepted_func(&self, id: u64) -> Option<Func> {
+        self.intercepted_funcs.get(&id).copied()
+    }
+
+    pub(crate) fn set_intercepted_func(&mut self, id: u64, func: Func) {
+        self.intercepted_funcs.insert(id, func);
+    }
+
     /// Translates a WebAssembly fault at the native `pc` and native `addr` to a
     /// WebAssembly-relative fault.
     ///
@@ -1656,11 +2161,25 @@ impl<T> StoreContextMut<'_, T> {
         debug_assert!(self.0.async_support());
         debug_assert!(config.async_stack_size > 0);
 
+        // The outermost call gets a fresh cancellation token which is cancelled
+        // if this future is dropped or fails before completing. Nested calls
+        // made from host functions share the token of their caller.
+        let cancel = match &self.0.async_state.cancel_token {
+            Some(token) if !token.is_cancelled() => None,
+            _ => {
+                let token = CancelToken::new();
+                self.0.async_state.cancel_token = Some(token.clone());
+                Some(CancelOnDrop::new(token))
+            }
+        };
+
+        let owns_token = cancel.is_some();
         let mut slot = None;
         let future = {
             let current_poll_cx = self.0.async_state.current_poll_cx.get();
             let current_suspend = self.0.async_state.current_suspend.get();
             let stack = self.engine().allocator().allocate_fiber_stack()?;
+            let fiber_stacks = self.0.async_state.fiber_stacks.clone();
 
             let engine = self.engine().clone();
             let slot = &mut slot;
@@ -1682,6 +2201,12 @@ impl<T> StoreContextMut<'_, T> {
                     *current_suspend = suspend;
 
                     *slot = Some(func(self));
+
+                    // The store is only reachable through the fiber, so the
+                    // token this call installed is cleared here once it's done.
+                    if owns_token {
+                        self.0.async_state.cancel_token = None;
+                    }
                     Ok(())
                 }
             })?;
@@ -1689,21 +2214,28 @@ impl<T> StoreContextMut<'_, T> {
             // Once we have the fiber representing our synchronous computation, we
             // wrap that in a custom future implementation which does the
             // translation from the future protocol to our fiber API.
+            fiber_stacks.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
             FiberFuture {
                 fiber,
                 current_poll_cx,
                 engine,
+                fiber_stacks,
                 state: Some(wasmtime_runtime::AsyncWasmCallState::new()),
             }
         };
         future.await?;
 
+        if let Some(cancel) = cancel {
+            cancel.disarm();
+        }
+
         return Ok(slot.unwrap());
 
         struct FiberFuture<'a> {
             fiber: wasmtime_fiber::Fiber<'a, Result<()>, (), Result<()>>,
             current_poll_cx: *mut *mut Context<'static>,
             engine: Engine,
+            fiber_stacks: Arc<std::sync::atomic::AtomicUsize>,
             // See comments in `FiberFuture::resume` for this
             state: Option<wasmtime_runtime::AsyncWasmCallState>,
         }
@@ -1907,6 +2439,8 @@ impl<T> StoreContextMut<'_, T> {
                         .allocator()
                         .deallocate_fiber_stack(self.fiber.stack());
                 }
+                self.fiber_stacks
+                    .fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
             }
         }
     }
@@ -1916,6 +2450,7 @@ impl<T> StoreContextMut<'_, T> {
 pub struct AsyncCx {
     current_suspend: *mut *const wasmtime_fiber::Suspend<Result<()>, (), Result<()>>,
     current_poll_cx: *mut *mut Context<'static>,
+    cancel_token: Option<CancelToken>,
 }
 
 #[cfg(feature = "async")]
@@ -1939,7 +2474,10 @@ impl AsyncCx {
     /// The return value here is either the output of the future `T`, or a trap
     /// which represents that the asynchronous computation was cancelled. It is
     /// not recommended to catch the trap and try to keep executing wasm, so
-    /// we've tried to liberally document this.
+    /// we've tried to liberally document this. The computation is also
+    /// abandoned with a [`Trap::Interrupt`], with [`CallCancelled`] as
+    /// context, if the [`CancelToken`] of the current call is cancelled while
+    /// `future` is pending.
     pub unsafe fn block_on<U>(
         &self,
         mut future: Pin<&mut (dyn Future<Output = U> + Send)>,
@@ -1962,12 +2500,21 @@ impl AsyncCx {
         *self.current_suspend = ptr::null();
         assert!(!suspend.is_null());
 
+        // Dropping this when the host call returns, however it does so,
+        // unregisters the waker from the call's token.
+        let mut cancel = self.cancel_token.clone().map(Registration::new);
+
         loop {
             let future_result = {
                 let poll_cx = *self.current_poll_cx;
                 let _reset = Reset(self.current_poll_cx, poll_cx);
                 *self.current_poll_cx = ptr::null_mut();
                 assert!(!poll_cx.is_null());
+                if let Some(cancel) = &mut cancel {
+                    if cancel.register((*poll_cx).waker()) {
+                        return Err(anyhow::Error::from(Trap::Interrupt).context(CallCancelled));
+                    }
+                }
                 future.as_mut().poll(&mut *poll_cx)
             };
 
@@ -2025,6 +2572,13 @@ unsafe impl<T> wasmtime_runtime::Store for StoreInner<T> {
         }
     }
 
+    fn memory_grown(&mut self, old: usize, new: usize) {
+        if let Some(mut profiler) = self.inner.allocation_profiler.take() {
+            profiler.memory_grown(&self.inner, old, new);
+            self.inner.allocation_profiler = Some(profiler);
+        }
+    }
+
     fn memory_grow_failed(&mut self, error: anyhow::Error) -> Result<()> {
         match self.limiter {
             Some(ResourceLimiterInner::Sync(ref mut limiter)) => {
@@ -2075,6 +2629,13 @@ unsafe impl<T> wasmtime_runtime::Store for StoreInner<T> {
         }
     }
 
+    fn table_grown(&mut self, element: TableElementType, old: u32, new: u32) {
+        if let Some(mut profiler) = self.inner.allocation_profiler.take() {
+            profiler.table_grown(&self.inner, element, old, new);
+            self.inner.allocation_profiler = Some(profiler);
+        }
+    }
+
     fn table_grow_failed(&mut self, error: anyhow::Error) -> Result<()> {
         match self.limiter {
             Some(ResourceLimiterInner::Sync(ref mut limiter)) => {
@@ -2093,6 +2654,8 @@ unsafe impl<T> wasmtime_runtime::Store for StoreInner<T> {
 
     fn out_of_gas(&mut self) -> Result<()> {
         if !self.refuel() {
+            #[cfg(feature = "async")]
+            self.cancel_current_call();
             return Err(Trap::OutOfFuel.into());
         }
         #[cfg(feature = "async")]
@@ -2103,11 +2666,23 @@ unsafe impl<T> wasmtime_runtime::Store for StoreInner<T> {
     }
 
     fn new_epoch(&mut self) -> Result<u64, anyhow::Error> {
+        // Shutting down the engine interrupts all of its stores, whatever
+        // their configured behavior is.
+        if self.engine().is_shutting_down() {
+            #[cfg(feature = "async")]
+            self.cancel_current_call();
+            return Err(Trap::Interrupt.into());
+        }
+
         // Temporarily take the configured behavior to avoid mutably borrowing
         // multiple times.
         let mut behavior = self.epoch_deadline_behavior.take();
         let delta_result = match &mut behavior {
-            None => Err(Trap::Interrupt.into()),
+            None => {
+                #[cfg(feature = "async")]
+                self.cancel_current_call();
+                Err(Trap::Interrupt.into())
+            }
             Some(callback) => callback((&mut *self).as_context_mut()).and_then(|update| {
                 let delta = match update {
                     UpdateDeadline::Continue(delta) => delta,
@@ -2137,6 +2712,30 @@ unsafe impl<T> wasmtime_runtime::Store for StoreInner<T> {
         delta_result
     }
 
+    fn memory_accessed(
+        &mut self,
+        memory: u32,
+        is_store: bool,
+        addr: u64,
+        num_bytes: u32,
+        wasm_offset: u32,
+    ) -> Result<(), anyhow::Error> {
+        match &mut self.memory_trace {
+            Some(hook) => hook(
+                &mut self.data,
+                MemoryAccess::new(memory, is_store, addr, num_bytes, wasm_offset),
+            ),
+            None => Ok(()),
+        }
+    }
+
+    fn block_entered(&mut self, func_index: u32, wasm_offset: u32) -> Result<(), anyhow::Error> {
+        match &mut self.block_trace {
+            Some(hook) => hook(&mut self.data, BlockEntry::new(func_index, wasm_offset)),
+            None => Ok(()),
+        }
+    }
+
     #[cfg(feature = "component-model")]
     fn component_calls(&mut self) -> &mut wasmtime_runtime::component::CallContexts {
         &mut self.component_calls
@@ -2158,14 +2757,20 @@ impl<T> StoreInner<T> {
         *epoch_deadline = self.engine().current_epoch() + delta;
     }
 
+    fn restore_epoch_deadline(&mut self, remaining: u64) {
+        // Unlike `set_epoch_deadline` this saturates, as the saved delta may
+        // come from a deadline that's effectively infinite.
+        let deadline = self.engine().current_epoch().saturating_add(remaining);
+        // Safety: see `set_epoch_deadline` above.
+        let epoch_deadline = unsafe { (*self.vmruntime_limits()).epoch_deadline.get_mut() };
+        *epoch_deadline = deadline;
+    }
+
     fn epoch_deadline_trap(&mut self) {
         self.epoch_deadline_behavior = None;
     }
 
-    fn epoch_deadline_callback(
-        &mut self,
-        callback: Box<dyn FnMut(StoreContextMut<T>) -> Result<UpdateDeadline> + Send + Sync>,
-    ) {
+    fn epoch_deadline_callback(&mut self, callback: EpochDeadlineBehavior<T>) {
         self.epoch_deadline_behavior = Some(callback);
     }
 
@@ -2185,7 +2790,8 @@ impl<T> StoreInner<T> {
     fn get_epoch_deadline(&self) -> u64 {
         // Safety: this is safe because, as above, it is only invoked
         // from within `new_epoch` which is called from guest Wasm
-        // code, which will have an exclusive borrow on the Store.
+        // code, which will have an exclusive borrow on the Store, or from
+        // `Store::epoch_deadline_scope` which has one itself.
         let epoch_deadline = unsafe { (*self.vmruntime_limits()).epoch_deadline.get_mut() };
         *epoch_deadline
     }
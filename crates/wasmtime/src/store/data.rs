@@ -82,6 +82,24 @@ impl StoreData {
         Stored::new(self.id, index)
     }
 
+    /// Removes the most recently inserted item, `id`, returning whether it
+    /// was removed.
+    ///
+    /// Items which aren't last can't be removed without invalidating the
+    /// indices of later items, so they're left in place.
+    pub fn remove_last<T>(&mut self, id: Stored<T>) -> bool
+    where
+        T: StoredData,
+    {
+        id.assert_belongs_to(self.id);
+        let list = T::list_mut(self);
+        if id.index() + 1 != list.len() {
+            return false;
+        }
+        list.pop();
+        true
+    }
+
     pub fn next_id<T>(&self) -> Stored<T>
     where
         T: StoredData,
@@ -228,6 +246,10 @@ impl StoreId {
         StoreId(NonZeroU64::new(id + 1).unwrap())
     }
 
+    pub(crate) fn as_u64(&self) -> u64 {
+        self.0.get()
+    }
+
     #[inline]
     pub fn assert_belongs_to(&self, store: StoreId) {
         if *self == store {
@@ -258,7 +280,7 @@ impl<T> Stored<T> {
         self.store_id.assert_belongs_to(store)
     }
 
-    fn index(&self) -> usize {
+    pub fn index(&self) -> usize {
         self.index
     }
 }
@@ -274,6 +296,8 @@ impl<T> PartialEq for Stored<T> {
     }
 }
 
+impl<T> Eq for Stored<T> {}
+
 impl<T> Copy for Stored<T> {}
 
 impl<T> Clone for Stored<T> {
@@ -0,0 +1,198 @@
+//! Reports of the resources owned by a store, for tracking down leaks.
+
+use super::{StoreInstanceKind, StoreOpaque};
+use crate::{Caller, Store};
+use std::backtrace::Backtrace;
+use std::borrow::Cow;
+use std::fmt;
+#[cfg(feature = "async")]
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+/// A snapshot of the resources owned by a [`Store`], created with
+/// [`Store::resource_report`].
+///
+/// Long-lived stores which are reused for many requests can accumulate
+/// resources, such as instances or handles, which are never released until
+/// the store itself is dropped. Comparing reports taken over time helps to
+/// find out what is leaking and, with
+/// [`Store::track_resource_origins`], where it was created.
+///
+/// Resources owned by the host rather than by Wasmtime, such as open WASI
+/// descriptors, can be added to the report with
+/// [`StoreResourceReport::push`]. The [`Display`](fmt::Display)
+/// implementation renders the whole report in a human-readable form.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct StoreResourceReport {
+    /// The number of core wasm instances created in the store.
+    pub instances: usize,
+    /// The number of component instances created in the store.
+    pub component_instances: usize,
+    /// The number of linear memories created in the store, by instances or
+    /// by the host.
+    pub memories: usize,
+    /// The number of tables created in the store, by instances or by the
+    /// host.
+    pub tables: usize,
+    /// The number of globals created by the host in the store.
+    pub host_globals: usize,
+    /// The number of `externref`s currently rooted by the store on behalf of
+    /// running or recently run wasm.
+    pub externref_roots: usize,
+    /// The total size, in bytes, of the heap allocations of the `externref`s
+    /// rooted by the store, counting each distinct `externref` once.
+    pub externref_bytes: usize,
+    /// The number of component resource handles currently held by the host.
+    pub component_host_resources: usize,
+    /// The number of fiber stacks allocated for async calls into the store
+    /// which haven't been released yet.
+    ///
+    /// Every async call in progress holds one, including nested calls made
+    /// from host functions, and a call whose future is leaked instead of
+    /// dropped holds on to its stack forever.
+    pub fiber_stacks_in_use: usize,
+    /// Individual resources, such as each instance and any resources added
+    /// by the host.
+    pub resources: Vec<ReportedResource>,
+}
+
+/// A single resource in a [`StoreResourceReport`].
+#[derive(Debug, Clone)]
+pub struct ReportedResource {
+    /// What kind of resource this is, for example `"instance"`.
+    pub kind: Cow<'static, str>,
+    /// A description of this particular resource.
+    pub description: String,
+    /// Where the resource was created, if known.
+    ///
+    /// Wasmtime only captures creation backtraces when explicitly asked to,
+    /// with [`Store::track_resource_origins`].
+    pub created_at: Option<Arc<Backtrace>>,
+}
+
+impl StoreResourceReport {
+    /// Adds a resource to this report, typically one owned by the host.
+    pub fn push(
+        &mut self,
+        kind: impl Into<Cow<'static, str>>,
+        description: impl Into<String>,
+        created_at: Option<Arc<Backtrace>>,
+    ) {
+        self.resources.push(ReportedResource {
+            kind: kind.into(),
+            description: description.into(),
+            created_at,
+        });
+    }
+
+    /// Captures a backtrace to record where a resource was created, suitable
+    /// for [`ReportedResource::created_at`].
+    ///
+    /// Capturing a backtrace is expensive, so this should only be called
+    /// when tracking origins was explicitly enabled, as with
+    /// [`Store::track_resource_origins`].
+    pub fn capture_origin() -> Arc<Backtrace> {
+        Arc::new(Backtrace::force_capture())
+    }
+}
+
+impl fmt::Display for StoreResourceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "instances: {}", self.instances)?;
+        writeln!(f, "component instances: {}", self.component_instances)?;
+        writeln!(f, "memories: {}", self.memories)?;
+        writeln!(f, "tables: {}", self.tables)?;
+        writeln!(f, "host globals: {}", self.host_globals)?;
+        writeln!(
+            f,
+            "externref roots: {} ({} bytes)",
+            self.externref_roots, self.externref_bytes
+        )?;
+        writeln!(
+            f,
+            "component host resources: {}",
+            self.component_host_resources
+        )?;
+        writeln!(f, "fiber stacks in use: {}", self.fiber_stacks_in_use)?;
+        for resource in &self.resources {
+            writeln!(f, "{}: {}", resource.kind, resource.description)?;
+            if let Some(backtrace) = &resource.created_at {
+                writeln!(f, "  created at:\n{backtrace}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Store<T> {
+    /// Returns a report of the resources currently owned by this store.
+    ///
+    /// This is a diagnostic for finding leaks in long-lived stores. See
+    /// [`StoreResourceReport`] for more information.
+    pub fn resource_report(&self) -> StoreResourceReport {
+        self.inner.resource_report()
+    }
+
+    /// Configures whether instances created in this store record a backtrace
+    /// of where they were created, for [`Store::resource_report`].
+    ///
+    /// This is disabled by default since capturing a backtrace for every
+    /// instance is expensive; enable it while tracking down a leak.
+    pub fn track_resource_origins(&mut self, enabled: bool) {
+        self.inner.track_resource_origins = enabled;
+    }
+}
+
+impl<T> Caller<'_, T> {
+    /// Returns a report of the resources currently owned by this caller's
+    /// store, as with [`Store::resource_report`].
+    ///
+    /// Unlike the store itself, this is available while an async call is in
+    /// progress, and so includes its fiber stacks.
+    pub fn resource_report(&self) -> StoreResourceReport {
+        self.store.0.resource_report()
+    }
+}
+
+impl StoreOpaque {
+    fn resource_report(&self) -> StoreResourceReport {
+        let mut report = StoreResourceReport {
+            host_globals: self.host_globals.len(),
+            externref_roots: self.externref_activations_table.num_roots(),
+            externref_bytes: self.externref_activations_table.roots_size_in_bytes(),
+            ..StoreResourceReport::default()
+        };
+
+        #[cfg(feature = "component-model")]
+        {
+            report.component_instances = self.num_component_instances;
+            report.component_host_resources = self.component_host_table.num_handles();
+        }
+
+        #[cfg(feature = "async")]
+        {
+            report.fiber_stacks_in_use = self.async_state.fiber_stacks.load(Ordering::Relaxed);
+        }
+
+        for instance in &self.instances {
+            // Host-created memories and tables live in dummy instances, so
+            // count those but don't report the instances themselves.
+            let module = instance.handle.module();
+            report.memories += module.memory_plans.len() - module.num_imported_memories;
+            report.tables += module.table_plans.len() - module.num_imported_tables;
+            if let StoreInstanceKind::Dummy = instance.kind {
+                continue;
+            }
+            report.instances += 1;
+            let name = module.name.as_deref();
+            report.push(
+                "instance",
+                name.unwrap_or("<unnamed module>"),
+                instance.created_at.clone(),
+            );
+        }
+
+        report
+    }
+}
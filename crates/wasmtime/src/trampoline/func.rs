@@ -2,19 +2,47 @@
 
 use crate::{Engine, FuncType, ValRaw};
 use anyhow::Result;
+#[cfg(any(feature = "cranelift", feature = "winch"))]
+use std::collections::HashMap;
 use std::panic::{self, AssertUnwindSafe};
 use std::ptr::NonNull;
+#[cfg(any(feature = "cranelift", feature = "winch"))]
+use std::sync::{Arc, Mutex, Weak};
+#[cfg(any(feature = "cranelift", feature = "winch"))]
 use wasmtime_jit::CodeMemory;
+#[cfg(any(feature = "cranelift", feature = "winch"))]
+use wasmtime_runtime::VMSharedSignatureIndex;
 use wasmtime_runtime::{
     StoreBox, VMArrayCallHostFuncContext, VMContext, VMFuncRef, VMOpaqueContext,
 };
 
 struct TrampolineState<F> {
     func: F,
+    #[cfg(any(feature = "cranelift", feature = "winch"))]
     #[allow(dead_code)]
+    trampolines: Arc<HostTrampolines>,
+}
+
+/// The wasm-to-array and native-to-array trampolines of host functions with
+/// one signature which call into one shim.
+///
+/// These are compiled by whichever compiler the engine uses, and only depend
+/// on the signature and the address of the shim that they call, so all host
+/// functions of an engine with the same signature and closure type share one
+/// copy of them.
+#[cfg(any(feature = "cranelift", feature = "winch"))]
+pub struct HostTrampolines {
     code_memory: CodeMemory,
+    wasm_call: usize,
+    native_call: usize,
 }
 
+/// An engine's cache of [`HostTrampolines`], keyed by signature and shim
+/// address. Entries are dropped along with the last host function using them.
+#[cfg(any(feature = "cranelift", feature = "winch"))]
+pub type HostTrampolineCache =
+    Mutex<HashMap<(VMSharedSignatureIndex, usize), Weak<HostTrampolines>>>;
+
 /// Shim to call a host-defined function that uses the array calling convention.
 ///
 /// Together with `VMArrayCallHostFuncContext`, this implements the transition
@@ -83,41 +111,24 @@ where
 {
     use std::ptr;
 
-    let mut obj = engine
-        .compiler()
-        .object(wasmtime_environ::ObjectKind::Module)?;
-    let (wasm_call_range, native_call_range) = engine
-        .compiler()
-        .emit_trampolines_for_array_call_host_func(
-            ft.as_wasm_func_type(),
-            array_call_shim::<F> as usize,
-            &mut obj,
-        )?;
-    engine.append_bti(&mut obj);
-    let obj = wasmtime_jit::ObjectBuilder::new(obj, &engine.config().tunables).finish()?;
-
-    // Copy the results of JIT compilation into executable memory, and this will
-    // also take care of unwind table registration.
-    let mut code_memory = CodeMemory::new(obj)?;
-    code_memory.publish()?;
-
-    engine.profiler().register_module(&code_memory, &|_| None);
-
-    // Extract the host/wasm trampolines from the results of compilation since
-    // we know their start/length.
-
-    let text = code_memory.text();
+    let sig = engine.signatures().register(ft.as_wasm_func_type());
+    let trampolines = match host_trampolines(engine, ft, sig, array_call_shim::<F> as usize) {
+        Ok(trampolines) => trampolines,
+        Err(e) => {
+            unsafe { engine.signatures().unregister(sig) };
+            return Err(e);
+        }
+    };
+    let text = trampolines.code_memory.text();
 
     let array_call = array_call_shim::<F>;
 
-    let wasm_call = text[wasm_call_range.start as usize..].as_ptr() as *mut _;
+    let wasm_call = text[trampolines.wasm_call..].as_ptr() as *mut _;
     let wasm_call = Some(NonNull::new(wasm_call).unwrap());
 
-    let native_call = text[native_call_range.start as usize..].as_ptr() as *mut _;
+    let native_call = text[trampolines.native_call..].as_ptr() as *mut _;
     let native_call = NonNull::new(native_call).unwrap();
 
-    let sig = engine.signatures().register(ft.as_wasm_func_type());
-
     unsafe {
         Ok(VMArrayCallHostFuncContext::new(
             VMFuncRef {
@@ -127,7 +138,57 @@ where
                 type_index: sig,
                 vmctx: ptr::null_mut(),
             },
-            Box::new(TrampolineState { func, code_memory }),
+            Box::new(TrampolineState { func, trampolines }),
         ))
     }
 }
+
+/// Returns the trampolines for host functions of type `ft`, registered as
+/// `sig`, which call `host_fn`, compiling them if no other live host function
+/// of the engine uses them.
+#[cfg(any(feature = "cranelift", feature = "winch"))]
+fn host_trampolines(
+    engine: &Engine,
+    ft: &FuncType,
+    sig: VMSharedSignatureIndex,
+    host_fn: usize,
+) -> Result<Arc<HostTrampolines>> {
+    let key = (sig, host_fn);
+    if let Some(trampolines) = engine
+        .host_trampolines()
+        .lock()
+        .unwrap()
+        .get(&key)
+        .and_then(|t| t.upgrade())
+    {
+        return Ok(trampolines);
+    }
+
+    let mut obj = engine
+        .compiler()
+        .object(wasmtime_environ::ObjectKind::Module)?;
+    let (wasm_call_range, native_call_range) = engine
+        .compiler()
+        .emit_trampolines_for_array_call_host_func(ft.as_wasm_func_type(), host_fn, &mut obj)?;
+    engine.append_bti(&mut obj);
+    let obj = wasmtime_jit::ObjectBuilder::new(obj, &engine.config().tunables).finish()?;
+
+    // Copy the results of JIT compilation into executable memory, and this will
+    // also take care of unwind table registration.
+    let code_memory = engine.publish_code(obj)?;
+
+    engine.profiler().register_module(&code_memory, &|_| None);
+
+    let trampolines = Arc::new(HostTrampolines {
+        code_memory,
+        wasm_call: wasm_call_range.start as usize,
+        native_call: native_call_range.start as usize,
+    });
+
+    // Another thread may have compiled the same trampolines in the meantime,
+    // in which case either copy works equally well.
+    let mut cache = engine.host_trampolines().lock().unwrap();
+    cache.retain(|_, t| t.strong_count() > 0);
+    cache.insert(key, Arc::downgrade(&trampolines));
+    Ok(trampolines)
+}
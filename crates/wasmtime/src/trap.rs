@@ -3,7 +3,9 @@ use crate::coredump::WasmCoreDump;
 use crate::store::StoreOpaque;
 use crate::{AsContext, Module};
 use anyhow::Error;
+use std::any::Any;
 use std::fmt;
+use std::sync::Mutex;
 use wasmtime_environ::{EntityRef, FilePos};
 use wasmtime_jit::{demangle_function_name, demangle_function_name_or_index};
 
@@ -68,6 +70,7 @@ use wasmtime_jit::{demangle_function_name, demangle_function_name_or_index};
 /// # }
 /// ```
 pub use wasmtime_environ::Trap;
+pub use wasmtime_environ::{CallCancelled, TrapCategory};
 
 // Same safety requirements and caveats as
 // `wasmtime_runtime::raise_user_trap`.
@@ -126,6 +129,7 @@ pub(crate) fn from_runtime_box(
             (err, Some(pc))
         }
         wasmtime_runtime::TrapReason::Wasm(trap_code) => (trap_code.into(), None),
+        wasmtime_runtime::TrapReason::Panic(payload) => (HostPanic::new(payload).into(), None),
     };
 
     if let Some(bt) = backtrace {
@@ -146,6 +150,62 @@ pub(crate) fn from_runtime_box(
     error
 }
 
+/// A panic in a host function called from WebAssembly, returned as an error
+/// when [`Config::catch_host_panics`](crate::Config::catch_host_panics) is
+/// enabled.
+///
+/// This can be detected with `error.is::<HostPanic>()`, and the original
+/// payload can be recovered, for example to resume the panic with
+/// [`std::panic::resume_unwind`], with [`HostPanic::into_payload`].
+pub struct HostPanic {
+    message: Option<String>,
+    // The payload isn't `Sync`, which errors must be.
+    payload: Mutex<Box<dyn Any + Send>>,
+}
+
+impl HostPanic {
+    fn new(payload: Box<dyn Any + Send>) -> HostPanic {
+        let message = match payload.downcast_ref::<&str>() {
+            Some(s) => Some(s.to_string()),
+            None => payload.downcast_ref::<String>().cloned(),
+        };
+        HostPanic {
+            message,
+            payload: Mutex::new(payload),
+        }
+    }
+
+    /// Returns the panic's message, if its payload is a string as is the case
+    /// for panics raised with `panic!`.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Returns the payload the host function panicked with.
+    pub fn into_payload(self) -> Box<dyn Any + Send> {
+        self.payload.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl fmt::Display for HostPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "host function panicked: {message}"),
+            None => f.write_str("host function panicked"),
+        }
+    }
+}
+
+impl fmt::Debug for HostPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HostPanic")
+            .field("message", &self.message)
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::error::Error for HostPanic {}
+
 /// Representation of a backtrace of function frames in a WebAssembly module for
 /// where an error happened.
 ///
@@ -274,10 +334,13 @@ impl WasmBacktrace {
     /// [`Config::wasm_backtrace`](crate::Config::wasm_backtrace) setting and
     /// always captures a backtrace.
     pub fn force_capture(store: impl AsContext) -> WasmBacktrace {
-        let store = store.as_context();
+        Self::force_capture_opaque(store.as_context().0)
+    }
+
+    pub(crate) fn force_capture_opaque(store: &StoreOpaque) -> WasmBacktrace {
         Self::from_captured(
-            store.0,
-            wasmtime_runtime::Backtrace::new(store.0.runtime_limits()),
+            store,
+            wasmtime_runtime::Backtrace::new(store.runtime_limits()),
             None,
         )
     }
@@ -557,7 +620,9 @@ impl FrameInfo {
     /// compiled with mapping information to yield this information. This is
     /// controlled by the
     /// [`Config::generate_address_map`](crate::Config::generate_address_map)
-    /// configuration option.
+    /// and
+    /// [`Config::address_map_granularity`](crate::Config::address_map_granularity)
+    /// configuration options.
     pub fn module_offset(&self) -> Option<usize> {
         Some(self.instr?.file_offset()? as usize)
     }
@@ -573,7 +638,9 @@ impl FrameInfo {
     /// compiled with mapping information to yield this information. This is
     /// controlled by the
     /// [`Config::generate_address_map`](crate::Config::generate_address_map)
-    /// configuration option.
+    /// and
+    /// [`Config::address_map_granularity`](crate::Config::address_map_granularity)
+    /// configuration options.
     pub fn func_offset(&self) -> Option<usize> {
         let instr_offset = self.instr?.file_offset()?;
         Some((instr_offset - self.func_start.file_offset()?) as usize)
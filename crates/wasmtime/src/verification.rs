@@ -0,0 +1,279 @@
+//! Hooks for checking the provenance of untrusted inputs before they are
+//! compiled or deserialized.
+//!
+//! See [`Config::module_verifier`](crate::Config::module_verifier) for an
+//! overview.
+
+use anyhow::{anyhow, bail, Result};
+use ring::digest::{Context, SHA256};
+use ring::signature::{Ed25519KeyPair, KeyPair, UnparsedPublicKey, ED25519};
+use std::ops::Range;
+use wasmparser::{Chunk, Parser, Payload};
+
+/// The name of the custom section which carries the signature of a core wasm
+/// module or component.
+///
+/// Only a top-level custom section with this name is recognized; sections of
+/// the same name within nested modules or components are part of the signed
+/// contents like any other bytes.
+pub const SIGNATURE_SECTION: &str = "wasmtime-signature";
+
+/// A hook which decides whether untrusted bytes may be compiled or
+/// deserialized by an [`Engine`](crate::Engine).
+///
+/// Configured with [`Config::module_verifier`](crate::Config::module_verifier).
+pub trait ModuleVerifier: Send + Sync {
+    /// Checks `input`, returning an error if it must not be loaded.
+    ///
+    /// The error returned here is propagated as-is out of the method that
+    /// was handed the bytes, such as
+    /// [`Module::new`](crate::Module::new) or
+    /// [`Module::deserialize`](crate::Module::deserialize).
+    fn verify(&self, input: &VerificationInput<'_>) -> Result<()>;
+}
+
+/// What kind of input a [`ModuleVerifier`] is being asked about.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VerificationKind {
+    /// A binary-encoded core wasm module about to be compiled.
+    ///
+    /// Text-format inputs are verified after they are converted to the
+    /// binary format.
+    Module,
+    /// A binary-encoded component about to be compiled.
+    Component,
+    /// An artifact produced by [`Engine::precompile_module`] or
+    /// [`Module::serialize`] about to be deserialized.
+    ///
+    /// [`Engine::precompile_module`]: crate::Engine::precompile_module
+    /// [`Module::serialize`]: crate::Module::serialize
+    PrecompiledModule,
+    /// An artifact produced by `Engine::precompile_component` or
+    /// `Component::serialize` about to be deserialized.
+    PrecompiledComponent,
+}
+
+impl VerificationKind {
+    /// Returns whether this is a precompiled artifact rather than wasm.
+    pub fn is_precompiled(&self) -> bool {
+        matches!(
+            self,
+            VerificationKind::PrecompiledModule | VerificationKind::PrecompiledComponent
+        )
+    }
+}
+
+/// The bytes handed to a [`ModuleVerifier`] along with their content hash and
+/// embedded signature, if any.
+pub struct VerificationInput<'a> {
+    bytes: &'a [u8],
+    kind: VerificationKind,
+    sha256: [u8; 32],
+    signature: Option<&'a [u8]>,
+}
+
+impl<'a> VerificationInput<'a> {
+    pub(crate) fn new(bytes: &'a [u8], kind: VerificationKind) -> Result<Self> {
+        let signature = if kind.is_precompiled() {
+            None
+        } else {
+            find_signature(bytes)?
+        };
+        let sha256 = match &signature {
+            Some((range, _)) => sha256(&[&bytes[..range.start], &bytes[range.end..]]),
+            None => sha256(&[bytes]),
+        };
+        Ok(VerificationInput {
+            bytes,
+            kind,
+            sha256,
+            signature: signature.map(|(_, data)| data),
+        })
+    }
+
+    /// Returns the full input, including any signature section.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns what kind of input this is.
+    pub fn kind(&self) -> VerificationKind {
+        self.kind
+    }
+
+    /// Returns the SHA-256 hash of the input with its [`SIGNATURE_SECTION`]
+    /// removed.
+    ///
+    /// This is the hash of exactly the bytes that were signed, so it is stable
+    /// across adding, replacing, or removing a signature.
+    pub fn sha256(&self) -> &[u8; 32] {
+        &self.sha256
+    }
+
+    /// Returns the contents of the input's [`SIGNATURE_SECTION`], if present.
+    ///
+    /// Precompiled artifacts never carry an embedded signature.
+    pub fn signature(&self) -> Option<&'a [u8]> {
+        self.signature
+    }
+}
+
+/// Locates the top-level signature section in `bytes`, returning the byte
+/// range of the whole section and its contents.
+fn find_signature(bytes: &[u8]) -> Result<Option<(Range<usize>, &[u8])>> {
+    let mut parser = Parser::new(0);
+    let mut offset = 0;
+    let mut found = None;
+    loop {
+        let (payload, consumed) = match parser.parse(&bytes[offset..], true)? {
+            Chunk::NeedMoreData(_) => bail!("unexpected end of wasm input"),
+            Chunk::Parsed { payload, consumed } => (payload, consumed),
+        };
+        match payload {
+            Payload::CustomSection(reader) if reader.name() == SIGNATURE_SECTION => {
+                if found.is_some() {
+                    bail!("multiple `{SIGNATURE_SECTION}` custom sections found");
+                }
+                found = Some((offset..offset + consumed, reader.data()));
+            }
+            Payload::CodeSectionStart { size, .. } => {
+                parser.skip_section();
+                offset += size as usize;
+            }
+            // Nested modules and components are opaque here, so skip over
+            // them entirely and resume with the outer parser.
+            Payload::ModuleSection { range, .. } | Payload::ComponentSection { range, .. } => {
+                offset = range.end;
+                continue;
+            }
+            Payload::End(_) => break,
+            _ => {}
+        }
+        offset += consumed;
+    }
+    Ok(found)
+}
+
+fn sha256(parts: &[&[u8]]) -> [u8; 32] {
+    let mut context = Context::new(&SHA256);
+    for part in parts {
+        context.update(part);
+    }
+    let mut hash = [0; 32];
+    hash.copy_from_slice(context.finish().as_ref());
+    hash
+}
+
+/// A [`ModuleVerifier`] which requires wasm inputs to be signed with ed25519
+/// by one of a set of trusted keys.
+///
+/// The [`SIGNATURE_SECTION`] of a signed input holds a 64-byte ed25519
+/// signature of the input's [`VerificationInput::sha256`], as produced by
+/// [`Ed25519Signer`].
+///
+/// Precompiled artifacts carry no signature and are rejected unless
+/// [`Ed25519Verifier::allow_precompiled`] is enabled, in which case they are
+/// assumed to have been checked when they were produced.
+#[derive(Clone, Debug, Default)]
+pub struct Ed25519Verifier {
+    public_keys: Vec<[u8; 32]>,
+    allow_precompiled: bool,
+}
+
+impl Ed25519Verifier {
+    /// Creates a verifier which trusts no keys and so rejects every input
+    /// until keys are added with [`Ed25519Verifier::trust`].
+    pub fn new() -> Ed25519Verifier {
+        Ed25519Verifier::default()
+    }
+
+    /// Accepts inputs signed by `public_key`.
+    pub fn trust(&mut self, public_key: [u8; 32]) -> &mut Self {
+        self.public_keys.push(public_key);
+        self
+    }
+
+    /// Configures whether precompiled artifacts are accepted.
+    ///
+    /// By default this is `false`.
+    pub fn allow_precompiled(&mut self, allow: bool) -> &mut Self {
+        self.allow_precompiled = allow;
+        self
+    }
+}
+
+impl ModuleVerifier for Ed25519Verifier {
+    fn verify(&self, input: &VerificationInput<'_>) -> Result<()> {
+        if input.kind().is_precompiled() {
+            if self.allow_precompiled {
+                return Ok(());
+            }
+            bail!("precompiled artifacts are not accepted without a signature");
+        }
+        let signature = input
+            .signature()
+            .ok_or_else(|| anyhow!("input is not signed: no `{SIGNATURE_SECTION}` section"))?;
+        let trusted = self.public_keys.iter().any(|key| {
+            UnparsedPublicKey::new(&ED25519, key)
+                .verify(input.sha256(), signature)
+                .is_ok()
+        });
+        if !trusted {
+            bail!("input signature does not match any trusted ed25519 key");
+        }
+        Ok(())
+    }
+}
+
+/// Signs core wasm modules and components so they are accepted by an
+/// [`Ed25519Verifier`] trusting [`Ed25519Signer::public_key`].
+pub struct Ed25519Signer {
+    key_pair: Ed25519KeyPair,
+}
+
+impl Ed25519Signer {
+    /// Creates a signer from a 32-byte ed25519 private key seed.
+    pub fn from_seed(seed: &[u8; 32]) -> Result<Ed25519Signer> {
+        let key_pair = Ed25519KeyPair::from_seed_unchecked(seed)
+            .map_err(|e| anyhow!("invalid ed25519 seed: {e}"))?;
+        Ok(Ed25519Signer { key_pair })
+    }
+
+    /// Returns the public key to pass to [`Ed25519Verifier::trust`].
+    pub fn public_key(&self) -> [u8; 32] {
+        let mut key = [0; 32];
+        key.copy_from_slice(self.key_pair.public_key().as_ref());
+        key
+    }
+
+    /// Returns a copy of the binary-encoded `wasm` with a
+    /// [`SIGNATURE_SECTION`] appended, replacing any existing one.
+    pub fn sign(&self, wasm: &[u8]) -> Result<Vec<u8>> {
+        let mut signed = match find_signature(wasm)? {
+            Some((range, _)) => [&wasm[..range.start], &wasm[range.end..]].concat(),
+            None => wasm.to_vec(),
+        };
+        let signature = self.key_pair.sign(&sha256(&[&signed]));
+
+        let mut section = Vec::new();
+        leb128(&mut section, SIGNATURE_SECTION.len());
+        section.extend_from_slice(SIGNATURE_SECTION.as_bytes());
+        section.extend_from_slice(signature.as_ref());
+        signed.push(0);
+        leb128(&mut signed, section.len());
+        signed.extend_from_slice(&section);
+        Ok(signed)
+    }
+}
+
+fn leb128(dst: &mut Vec<u8>, mut value: usize) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            dst.push(byte);
+            break;
+        }
+        dst.push(byte | 0x80);
+    }
+}
@@ -1,16 +1,24 @@
 use anyhow::Result;
+use cranelift_codegen::isa::unwind::UnwindInfoKind;
 use object::write::{Object, SymbolId};
 use std::any::Any;
 use std::mem;
 use std::sync::Mutex;
+use std::time::Instant;
 use wasmparser::FuncValidatorAllocations;
-use wasmtime_cranelift_shared::{CompiledFunction, ModuleTextBuilder};
+use wasmtime_cranelift_shared::{
+    mach_stack_maps_to_stack_maps, CompiledFunction, ModuleTextBuilder,
+};
 use wasmtime_environ::{
-    CompileError, DefinedFuncIndex, FilePos, FuncIndex, FunctionBodyData, FunctionLoc,
-    ModuleTranslation, ModuleTypes, PrimaryMap, TrapEncodingBuilder, VMOffsets, WasmFunctionInfo,
+    AddressMapGranularity, CompileError, DefinedFuncIndex, FilePos, FuncIndex, FunctionBodyData,
+    FunctionCodegenStats, FunctionLoc, ModuleTranslation, ModuleTypes, PrimaryMap,
+    TrapEncodingBuilder, Tunables, VMOffsets, WasmFunctionInfo,
 };
 use winch_codegen::{BuiltinFunctions, TargetIsa, TrampolineKind};
 
+#[cfg(feature = "component-model")]
+mod component;
+
 /// Function compilation context.
 /// This struct holds information that can be shared globally across
 /// all function compilations.
@@ -23,6 +31,7 @@ struct CompilationContext {
 
 pub(crate) struct Compiler {
     isa: Box<dyn TargetIsa>,
+    tunables: Tunables,
     contexts: Mutex<Vec<CompilationContext>>,
 }
 
@@ -38,9 +47,10 @@ impl wasmtime_cranelift_shared::CompiledFuncEnv for CompiledFuncEnv {
 }
 
 impl Compiler {
-    pub fn new(isa: Box<dyn TargetIsa>) -> Self {
+    pub fn new(isa: Box<dyn TargetIsa>, tunables: Tunables) -> Self {
         Self {
             isa,
+            tunables,
             contexts: Mutex::new(Vec::new()),
         }
     }
@@ -57,6 +67,53 @@ impl Compiler {
         })
     }
 
+    /// Attaches unwind information to `compiled_function`, if enabled, so
+    /// that unwinders can walk through its frame.
+    fn emit_unwind_info(
+        &self,
+        compiled_function: &mut CompiledFunction<CompiledFuncEnv>,
+    ) -> Result<(), CompileError> {
+        if !self.isa.flags().unwind_info() {
+            return Ok(());
+        }
+        let kind = match self.isa.triple().operating_system {
+            target_lexicon::OperatingSystem::Windows => UnwindInfoKind::Windows,
+            _ => UnwindInfoKind::SystemV,
+        };
+        let unwind_info = self
+            .isa
+            .emit_unwind_info(&compiled_function.buffer, kind)
+            .map_err(|e| CompileError::Codegen(format!("{e:?}")))?;
+        if let Some(unwind_info) = unwind_info {
+            compiled_function.set_unwind_info(unwind_info);
+        }
+        Ok(())
+    }
+
+    /// Compiles a trampoline to the array-call host function `host_fn`, called
+    /// either from Wasm or from native code.
+    fn compile_array_call_host_trampoline(
+        &self,
+        ty: &wasmtime_environ::WasmFuncType,
+        host_fn: usize,
+        from_wasm: bool,
+    ) -> Result<CompiledFunction<CompiledFuncEnv>, CompileError> {
+        let kind = if from_wasm {
+            TrampolineKind::WasmToArray(host_fn)
+        } else {
+            TrampolineKind::NativeToArray(host_fn)
+        };
+        let buffer = self
+            .isa
+            .compile_trampoline(ty, kind)
+            .map_err(|e| CompileError::Codegen(format!("{:?}", e)))?;
+        let mut compiled_function =
+            CompiledFunction::new(buffer, CompiledFuncEnv {}, self.isa.function_alignment());
+        self.emit_unwind_info(&mut compiled_function)?;
+
+        Ok(compiled_function)
+    }
+
     /// Save a compilation context.
     fn save_context(&self, mut context: CompilationContext, allocs: FuncValidatorAllocations) {
         context.allocations = allocs;
@@ -72,6 +129,7 @@ impl wasmtime_environ::Compiler for Compiler {
         data: FunctionBodyData<'_>,
         types: &ModuleTypes,
     ) -> Result<(WasmFunctionInfo, Box<dyn Any + Send>), CompileError> {
+        let start = Instant::now();
         let index = translation.module.func_index(index);
         let sig = translation.module.functions[index].signature;
         let ty = &types[sig];
@@ -84,6 +142,10 @@ impl wasmtime_environ::Compiler for Compiler {
         );
         let mut context = self.get_context(translation);
         let mut validator = validator.into_validator(mem::take(&mut context.allocations));
+        let mut stats = self
+            .tunables
+            .codegen_stats
+            .then(|| Box::new(FunctionCodegenStats::default()));
         let buffer = self
             .isa
             .compile_function(
@@ -93,17 +155,35 @@ impl wasmtime_environ::Compiler for Compiler {
                 types,
                 &mut context.builtins,
                 &mut validator,
+                stats.as_deref_mut(),
             )
             .map_err(|e| CompileError::Codegen(format!("{e:?}")));
         self.save_context(context, validator.into_allocations());
         let buffer = buffer?;
-        let compiled_function =
+        let stack_maps = mach_stack_maps_to_stack_maps(buffer.stack_maps());
+        if let Some(stats) = stats.as_deref_mut() {
+            stats.machine_code_size = buffer.data().len() as u32;
+            stats.relocations = buffer.relocs().len() as u32;
+        }
+        let mut compiled_function =
             CompiledFunction::new(buffer, CompiledFuncEnv {}, self.isa.function_alignment());
+        let data = body.get_binary_reader();
+        compiled_function.set_address_map(
+            data.original_position() as u32,
+            data.bytes_remaining() as u32,
+            self.tunables.address_map_granularity == AddressMapGranularity::Full,
+        );
+        self.emit_unwind_info(&mut compiled_function)?;
+        if let Some(stats) = stats.as_deref_mut() {
+            stats.compile_time = start.elapsed();
+        }
 
         Ok((
             WasmFunctionInfo {
                 start_srcloc,
-                stack_maps: Box::new([]),
+                stack_maps: stack_maps.into(),
+                codegen_stats: stats,
+                exceeded_compile_budget: false,
             },
             Box::new(compiled_function),
         ))
@@ -122,8 +202,9 @@ impl wasmtime_environ::Compiler for Compiler {
             .isa
             .compile_trampoline(&ty, TrampolineKind::ArrayToWasm(func_index))
             .map_err(|e| CompileError::Codegen(format!("{:?}", e)))?;
-        let compiled_function =
+        let mut compiled_function =
             CompiledFunction::new(buffer, CompiledFuncEnv {}, self.isa.function_alignment());
+        self.emit_unwind_info(&mut compiled_function)?;
 
         Ok(Box::new(compiled_function))
     }
@@ -143,8 +224,9 @@ impl wasmtime_environ::Compiler for Compiler {
             .compile_trampoline(ty, TrampolineKind::NativeToWasm(func_index))
             .map_err(|e| CompileError::Codegen(format!("{:?}", e)))?;
 
-        let compiled_function =
+        let mut compiled_function =
             CompiledFunction::new(buffer, CompiledFuncEnv {}, self.isa.function_alignment());
+        self.emit_unwind_info(&mut compiled_function)?;
 
         Ok(Box::new(compiled_function))
     }
@@ -158,8 +240,9 @@ impl wasmtime_environ::Compiler for Compiler {
             .compile_trampoline(wasm_func_ty, TrampolineKind::WasmToNative)
             .map_err(|e| CompileError::Codegen(format!("{:?}", e)))?;
 
-        let compiled_function =
+        let mut compiled_function =
             CompiledFunction::new(buffer, CompiledFuncEnv {}, self.isa.function_alignment());
+        self.emit_unwind_info(&mut compiled_function)?;
 
         Ok(Box::new(compiled_function))
     }
@@ -202,8 +285,27 @@ impl wasmtime_environ::Compiler for Compiler {
         host_fn: usize,
         obj: &mut Object<'static>,
     ) -> Result<(FunctionLoc, FunctionLoc)> {
-        drop((ty, host_fn, obj));
-        todo!()
+        let mut wasm_to_array = self.compile_array_call_host_trampoline(ty, host_fn, true)?;
+        let mut native_to_array = self.compile_array_call_host_trampoline(ty, host_fn, false)?;
+
+        let mut builder = ModuleTextBuilder::new(obj, self, self.isa.text_section_builder(2));
+
+        let (_, wasm_to_array) =
+            builder.append_func("wasm_to_array", &mut wasm_to_array, |_| unreachable!());
+        let (_, native_to_array) =
+            builder.append_func("native_to_array", &mut native_to_array, |_| unreachable!());
+
+        let wasm_to_array = FunctionLoc {
+            start: u32::try_from(wasm_to_array.start).unwrap(),
+            length: u32::try_from(wasm_to_array.end - wasm_to_array.start).unwrap(),
+        };
+        let native_to_array = FunctionLoc {
+            start: u32::try_from(native_to_array.start).unwrap(),
+            length: u32::try_from(native_to_array.end - native_to_array.start).unwrap(),
+        };
+
+        builder.finish();
+        Ok((wasm_to_array, native_to_array))
     }
 
     fn triple(&self) -> &target_lexicon::Triple {
@@ -224,16 +326,28 @@ impl wasmtime_environ::Compiler for Compiler {
 
     #[cfg(feature = "component-model")]
     fn component_compiler(&self) -> &dyn wasmtime_environ::component::ComponentCompiler {
-        todo!()
+        self
     }
 
     fn append_dwarf(
         &self,
-        _obj: &mut Object<'_>,
-        _translation: &ModuleTranslation<'_>,
-        _funcs: &PrimaryMap<DefinedFuncIndex, (SymbolId, &(dyn Any + Send))>,
+        obj: &mut Object<'_>,
+        translation: &ModuleTranslation<'_>,
+        funcs: &PrimaryMap<DefinedFuncIndex, (SymbolId, &(dyn Any + Send))>,
     ) -> Result<()> {
-        todo!()
+        // The DWARF transform only needs the ISA for target properties like
+        // the pointer size and the DWARF register numbering, which Winch
+        // shares with Cranelift's backend for the same target.
+        let isa = cranelift_codegen::isa::lookup(self.isa.triple().clone())?
+            .finish(self.isa.flags().clone())?;
+        let funcs = funcs
+            .iter()
+            .map(|(_, (sym, func))| {
+                let f: &CompiledFunction<CompiledFuncEnv> = func.downcast_ref().unwrap();
+                (*sym, f.metadata())
+            })
+            .collect();
+        wasmtime_cranelift_shared::append_dwarf(&*isa, obj, translation, &funcs)
     }
 
     fn create_systemv_cie(&self) -> Option<gimli::write::CommonInformationEntry> {
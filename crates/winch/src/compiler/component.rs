@@ -0,0 +1,36 @@
+//! Compilation support for the component model.
+
+use super::{CompiledFuncEnv, Compiler};
+use anyhow::Result;
+use std::any::Any;
+use wasmtime_cranelift_shared::CompiledFunction;
+use wasmtime_environ::component::{
+    AllCallFunc, ComponentCompiler, ComponentTranslation, ComponentTypes, TrampolineIndex,
+};
+use winch_codegen::ComponentAbi;
+
+impl ComponentCompiler for Compiler {
+    fn compile_trampoline(
+        &self,
+        component: &ComponentTranslation,
+        types: &ComponentTypes,
+        index: TrampolineIndex,
+    ) -> Result<AllCallFunc<Box<dyn Any + Send>>> {
+        let compile = |abi: ComponentAbi| -> Result<Box<dyn Any + Send>> {
+            let buffer = self
+                .isa
+                .compile_component_trampoline(component, types, index, abi)?;
+            let mut compiled_function =
+                CompiledFunction::new(buffer, CompiledFuncEnv {}, self.isa.function_alignment());
+            self.emit_unwind_info(&mut compiled_function)?;
+
+            Ok(Box::new(compiled_function))
+        };
+
+        Ok(AllCallFunc {
+            wasm_call: compile(ComponentAbi::Wasm)?,
+            array_call: compile(ComponentAbi::Array)?,
+            native_call: compile(ComponentAbi::Native)?,
+        })
+    }
+}
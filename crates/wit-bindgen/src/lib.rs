@@ -145,6 +145,12 @@ impl AsyncConfig {
         }
     }
 
+    /// Returns whether the destructor of the imported resource `name` is
+    /// async, which is configured with the name `[resource-drop]{name}`.
+    pub fn is_resource_drop_async(&self, name: &str) -> bool {
+        self.is_import_async(&format!("[resource-drop]{name}"))
+    }
+
     pub fn maybe_async(&self) -> bool {
         match self {
             AsyncConfig::None => false,
@@ -804,16 +810,8 @@ impl Wasmtime {
             ",
         );
         for name in get_world_resources(resolve, world) {
-            let camel = name.to_upper_camel_case();
-            uwriteln!(
-                self.src,
-                "linker.resource::<{camel}>(
-                    \"{name}\",
-                    move |mut store, rep| -> wasmtime::Result<()> {{
-                        Host{camel}::drop(get(store.data_mut()), wasmtime::component::Resource::new_own(rep))
-                    }},
-                )?;"
-            )
+            let add = resource_to_linker(&self.opts, "linker", name);
+            self.src.push_str(&add);
         }
 
         for f in self.import_functions.iter() {
@@ -1004,9 +1002,14 @@ impl<'a> InterfaceGenerator<'a> {
                 self.generate_function_trait_sig(func);
             }
 
+            let maybe_async = if self.gen.opts.async_.is_resource_drop_async(name) {
+                "async "
+            } else {
+                ""
+            };
             uwrite!(
                 self.src,
-                "fn drop(&mut self, rep: wasmtime::component::Resource<{camel}>) -> wasmtime::Result<()>;");
+                "{maybe_async}fn drop(&mut self, rep: wasmtime::component::Resource<{camel}>) -> wasmtime::Result<()>;");
 
             uwriteln!(self.src, "}}");
         } else {
@@ -1601,16 +1604,8 @@ impl<'a> InterfaceGenerator<'a> {
         uwriteln!(self.src, "let mut inst = linker.instance(\"{name}\")?;");
 
         for name in get_resources(self.resolve, id) {
-            let camel = name.to_upper_camel_case();
-            uwriteln!(
-                self.src,
-                "inst.resource::<{camel}>(
-                    \"{name}\",
-                    move |mut store, rep| -> wasmtime::Result<()> {{
-                        Host{camel}::drop(get(store.data_mut()), wasmtime::component::Resource::new_own(rep))
-                    }},
-                )?;"
-            )
+            let add = resource_to_linker(&self.gen.opts, "inst", name);
+            self.src.push_str(&add);
         }
 
         for (_, func) in iface.functions.iter() {
@@ -2065,6 +2060,31 @@ fn func_field_name(resolve: &Resolve, func: &Function) -> String {
     name.to_snake_case()
 }
 
+/// Generates the code to define the host resource `name` in `linker`, with a
+/// destructor which calls the `drop` method of its host trait.
+fn resource_to_linker(opts: &Opts, linker: &str, name: &str) -> String {
+    let camel = name.to_upper_camel_case();
+    if opts.async_.is_resource_drop_async(name) {
+        format!(
+            "{linker}.resource_async::<{camel}>(
+                \"{name}\",
+                move |mut store, rep| Box::new(async move {{
+                    Host{camel}::drop(get(store.data_mut()), wasmtime::component::Resource::new_own(rep)).await
+                }}),
+            )?;\n"
+        )
+    } else {
+        format!(
+            "{linker}.resource::<{camel}>(
+                \"{name}\",
+                move |mut store, rep| -> wasmtime::Result<()> {{
+                    Host{camel}::drop(get(store.data_mut()), wasmtime::component::Resource::new_own(rep))
+                }},
+            )?;\n"
+        )
+    }
+}
+
 fn get_resources<'a>(resolve: &'a Resolve, id: InterfaceId) -> impl Iterator<Item = &'a str> + 'a {
     resolve.interfaces[id]
         .types
@@ -108,7 +108,7 @@ fuzz_target!(|func: FunctionWithIsa| {
         Err(_) => return,
     };
 
-    let (prev_stencil, serialized) = icache::serialize_compiled(prev_stencil);
+    let (prev_stencil, serialized) = icache::serialize_compiled(&func, prev_stencil);
     let serialized = serialized.expect("serialization should work");
     let prev_result = prev_stencil.apply_params(&func.params);
 
@@ -70,6 +70,8 @@ const CRATES_TO_PUBLISH: &[&str] = &[
     "wasmtime-wasi",
     "wasmtime-wasi-http",
     "wasmtime-wasi-nn",
+    "wasmtime-wasi-blobstore",
+    "wasmtime-wasi-messaging",
     "wasmtime-wasi-threads",
     "wasmtime-wast",
     "wasmtime-cli-flags",
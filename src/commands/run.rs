@@ -10,10 +10,13 @@ use crate::common::{Profile, RunCommon, RunTarget};
 use anyhow::{anyhow, bail, Context as _, Error, Result};
 use clap::Parser;
 use std::ffi::OsString;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::num::NonZeroU64;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::thread;
-use wasmtime::{Engine, Func, Module, Store, StoreLimits, Val, ValType};
+use wasmtime::{Engine, Func, MemoryAccessKind, Module, Store, StoreLimits, Val, ValType};
 use wasmtime_wasi::maybe_exit_on_error;
 use wasmtime_wasi::preview2;
 use wasmtime_wasi::sync::{ambient_authority, Dir, TcpListener, WasiCtxBuilder};
@@ -53,6 +56,31 @@ fn parse_preloads(s: &str) -> Result<(String, PathBuf)> {
     Ok((parts[0].into(), parts[1].into()))
 }
 
+fn parse_mem_trace_range(s: &str) -> Result<(u64, u64)> {
+    fn parse_addr(s: &str) -> Result<u64> {
+        Ok(match s.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16)?,
+            None => s.parse()?,
+        })
+    }
+    let (start, end) = s
+        .split_once("..")
+        .ok_or_else(|| anyhow!("must be of the form `START..END`"))?;
+    let (start, end) = (parse_addr(start)?, parse_addr(end)?);
+    if start >= end {
+        bail!("range start must be below its end");
+    }
+    Ok((start, end))
+}
+
+fn parse_mem_trace_kind(s: &str) -> Result<MemoryAccessKind> {
+    match s {
+        "load" => Ok(MemoryAccessKind::Load),
+        "store" => Ok(MemoryAccessKind::Store),
+        _ => bail!("must be `load` or `store`"),
+    }
+}
+
 /// Runs a WebAssembly module
 #[derive(Parser, PartialEq)]
 #[structopt(name = "run")]
@@ -93,6 +121,40 @@ pub struct RunCommand {
     )]
     pub preloads: Vec<(String, PathBuf)>,
 
+    /// Trace every linear memory load and store to this file.
+    ///
+    /// Each access is written as a line containing whether it's a load or a
+    /// store, the index of the memory, the address, the number of bytes accessed and the offset of the
+    /// instruction in the wasm module. This slows down execution a lot and
+    /// is intended for diagnosing memory corruption in a guest.
+    #[clap(long, value_name = "FILE")]
+    pub mem_trace: Option<PathBuf>,
+
+    /// Only trace one in every N memory accesses that match the filters.
+    #[clap(long, value_name = "N", default_value = "1", requires = "mem_trace")]
+    pub mem_trace_sample: NonZeroU64,
+
+    /// Only trace memory accesses which overlap this address range.
+    ///
+    /// Addresses may be decimal or hexadecimal with a `0x` prefix, and the
+    /// end is exclusive. May be given multiple times.
+    #[clap(
+        long,
+        value_name = "START..END",
+        value_parser = parse_mem_trace_range,
+        requires = "mem_trace"
+    )]
+    pub mem_trace_range: Vec<(u64, u64)>,
+
+    /// Only trace memory accesses of this kind, either `load` or `store`.
+    #[clap(
+        long,
+        value_name = "KIND",
+        value_parser = parse_mem_trace_kind,
+        requires = "mem_trace"
+    )]
+    pub mem_trace_kind: Option<MemoryAccessKind>,
+
     /// The WebAssembly module to run and arguments to pass to it.
     ///
     /// Arguments passed to the wasm module will be configured as WASI CLI
@@ -118,6 +180,9 @@ impl RunCommand {
         if self.run.common.wasm.timeout.is_some() {
             config.epoch_interruption(true);
         }
+        if self.mem_trace.is_some() {
+            config.memory_trace(true);
+        }
         match self.run.profile {
             Some(Profile::Native(s)) => {
                 config.profiler(s);
@@ -175,6 +240,11 @@ impl RunCommand {
             store.set_fuel(fuel)?;
         }
 
+        let mem_trace = match &self.mem_trace {
+            Some(path) => Some(self.setup_mem_trace(&mut store, path)?),
+            None => None,
+        };
+
         // Load the preload wasm modules.
         let mut modules = Vec::new();
         if let RunTarget::Core(m) = &main {
@@ -210,15 +280,40 @@ impl RunCommand {
             }
         }
 
+        // Keep the modules around to report their coverage once the program
+        // has finished, successfully or not.
+        let coverage = match &self.run.common.debug.coverage {
+            Some(path) => {
+                if !matches!(main, RunTarget::Core(_)) {
+                    bail!("code coverage is not supported with components");
+                }
+                Some((path, modules.clone()))
+            }
+            None => None,
+        };
+
         // Load the main wasm module.
-        match self
+        let result = self
             .load_main_module(&mut store, &mut linker, &main, modules)
             .with_context(|| {
                 format!(
                     "failed to run main module `{}`",
                     self.module_and_args[0].to_string_lossy()
                 )
-            }) {
+            });
+
+        if let Some((path, modules)) = coverage {
+            if let Err(e) = write_coverage(path, &modules) {
+                eprintln!("warning: failed writing coverage at {path}: {e:#}");
+            }
+        }
+        if let Some(mem_trace) = mem_trace {
+            if let Err(e) = mem_trace.lock().unwrap().flush() {
+                eprintln!("warning: failed writing memory trace: {e}");
+            }
+        }
+
+        match result {
             Ok(()) => (),
             Err(e) => {
                 // Exit the process if Wasmtime understands the error;
@@ -231,6 +326,58 @@ impl RunCommand {
         Ok(())
     }
 
+    /// Installs a hook on `store` writing the memory accesses selected by the
+    /// `--mem-trace-*` options to `path`.
+    ///
+    /// The returned writer is shared with the hook so that the trace can be
+    /// flushed before the process exits, which may happen without dropping
+    /// the store.
+    fn setup_mem_trace(
+        &self,
+        store: &mut Store<Host>,
+        path: &Path,
+    ) -> Result<Arc<Mutex<BufWriter<File>>>> {
+        let file = File::create(path)
+            .with_context(|| format!("failed to create memory trace `{}`", path.display()))?;
+        let out = Arc::new(Mutex::new(BufWriter::new(file)));
+        let ranges = self.mem_trace_range.clone();
+        let kind = self.mem_trace_kind;
+        let sample = self.mem_trace_sample.get();
+        let mut matched = 0u64;
+        let writer = out.clone();
+        store.memory_trace(move |_, access| {
+            if kind.map_or(false, |kind| kind != access.kind()) {
+                return Ok(());
+            }
+            let end = access.address() + u64::from(access.size());
+            if !ranges.is_empty()
+                && !ranges
+                    .iter()
+                    .any(|&(start, stop)| access.address() < stop && start < end)
+            {
+                return Ok(());
+            }
+            matched += 1;
+            if (matched - 1) % sample != 0 {
+                return Ok(());
+            }
+            let kind = match access.kind() {
+                MemoryAccessKind::Load => "load",
+                MemoryAccessKind::Store => "store",
+            };
+            writeln!(
+                writer.lock().unwrap(),
+                "{kind} {} {:#x} {} {:#x}",
+                access.memory(),
+                access.address(),
+                access.size(),
+                access.wasm_offset()
+            )
+            .context("failed writing memory trace")
+        });
+        Ok(out)
+    }
+
     fn compute_preopen_dirs(&self) -> Result<Vec<(String, Dir)>> {
         let mut preopen_dirs = Vec::new();
 
@@ -872,6 +1019,53 @@ fn ctx_set_listenfd(mut num_fd: usize, builder: &mut WasiCtxBuilder) -> Result<u
     Ok(num_fd)
 }
 
+/// Writes a report of the code coverage collected by `modules` to `path`.
+///
+/// The report lists, for every function, how often each of its blocks was
+/// entered, identified by the offset in the wasm module of the operator it
+/// starts after and, if the module has DWARF debug information, its source
+/// location.
+fn write_coverage(path: &str, modules: &[(String, Module)]) -> Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    for (name, module) in modules {
+        let coverage = match module.coverage() {
+            Some(coverage) => coverage,
+            None => continue,
+        };
+        let name: &str = if name.is_empty() {
+            module.name().unwrap_or("<main>")
+        } else {
+            name
+        };
+        writeln!(out, "module {name}")?;
+        for func in coverage.functions() {
+            let covered = func.blocks().iter().filter(|b| b.count() > 0).count();
+            write!(out, "  func[{}]", func.index())?;
+            if let Some(name) = func.name() {
+                write!(out, " {name}")?;
+            }
+            writeln!(
+                out,
+                ": {} calls, {covered}/{} blocks covered",
+                func.calls(),
+                func.blocks().len()
+            )?;
+            for block in func.blocks() {
+                write!(out, "    {:#08x}: {}", block.offset(), block.count())?;
+                if let (Some(file), Some(line)) = (block.file(), block.line()) {
+                    write!(out, " {file}:{line}")?;
+                    if let Some(column) = block.column() {
+                        write!(out, ":{column}")?;
+                    }
+                }
+                writeln!(out)?;
+            }
+        }
+    }
+    out.flush()?;
+    Ok(())
+}
+
 #[cfg(feature = "coredump")]
 fn write_core_dump(
     store: &mut Store<Host>,
@@ -14,7 +14,11 @@ use wasmtime::{Engine, Store, StoreLimits};
 use wasmtime_wasi::preview2::{
     self, StreamError, StreamResult, Table, WasiCtx, WasiCtxBuilder, WasiView,
 };
-use wasmtime_wasi_http::{body::HyperOutgoingBody, WasiHttpCtx, WasiHttpView};
+use wasmtime_wasi_http::{
+    body::HyperOutgoingBody,
+    types::{HttpLimits, RequestHeadersTooLarge},
+    WasiHttpCtx, WasiHttpView,
+};
 
 #[cfg(feature = "wasi-nn")]
 use wasmtime_wasi_nn::WasiNnCtx;
@@ -23,6 +27,7 @@ struct Host {
     table: Table,
     ctx: WasiCtx,
     http: WasiHttpCtx,
+    http_limits: HttpLimits,
 
     limits: StoreLimits,
 
@@ -56,6 +61,10 @@ impl WasiHttpView for Host {
     fn ctx(&mut self) -> &mut WasiHttpCtx {
         &mut self.http
     }
+
+    fn limits(&mut self) -> HttpLimits {
+        self.http_limits.clone()
+    }
 }
 
 const DEFAULT_ADDR: std::net::SocketAddr = std::net::SocketAddr::new(
@@ -74,6 +83,11 @@ pub struct ServeCommand {
     #[clap(long = "addr", value_name = "SOCKADDR", default_value_t = DEFAULT_ADDR )]
     addr: std::net::SocketAddr,
 
+    /// Maximum size, in bytes, of the body of each response sent by the
+    /// component. A response whose body grows past this is aborted.
+    #[clap(long = "max-response-body-size", value_name = "BYTES")]
+    max_response_body_size: Option<u64>,
+
     /// The WebAssembly component to run.
     #[clap(value_name = "WASM", required = true)]
     component: PathBuf,
@@ -156,6 +170,10 @@ impl ServeCommand {
             table: Table::new(),
             ctx: builder.build(),
             http: WasiHttpCtx,
+            http_limits: HttpLimits {
+                max_response_body_size: self.max_response_body_size,
+                ..HttpLimits::default()
+            },
 
             limits: StoreLimits::default(),
 
@@ -363,9 +381,25 @@ impl hyper::service::Service<Request> for ProxyHandler {
 
             let mut store = inner.cmd.new_store(&inner.engine, req_id)?;
 
-            let req = store
+            let req = match store
                 .data_mut()
-                .new_incoming_request(req.map(|body| body.map_err(|e| e.into()).boxed()))?;
+                .new_incoming_request(req.map(|body| body.map_err(|e| e.into()).boxed()))
+            {
+                Ok(req) => req,
+                Err(e) if e.is::<RequestHeadersTooLarge>() => {
+                    log::warn!("[{req_id}] :: rejecting request: {e}");
+                    let resp = hyper::Response::builder()
+                        .status(hyper::StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE)
+                        .body(
+                            http_body_util::Empty::new()
+                                .map_err(|never| match never {})
+                                .boxed(),
+                        )?;
+                    let _ = sender.send(Ok(resp));
+                    return Ok(());
+                }
+                Err(e) => return Err(e),
+            };
 
             let out = store.data_mut().new_response_outparam(sender)?;
 
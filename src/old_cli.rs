@@ -5,6 +5,7 @@ use clap::builder::{OsStringValueParser, TypedValueParser};
 use clap::Parser;
 use std::collections::HashMap;
 use std::ffi::OsString;
+use std::num::NonZeroU64;
 use std::path::PathBuf;
 use std::time::Duration;
 
@@ -826,6 +827,10 @@ impl RunCommand {
             vars,
             invoke,
             preloads,
+            mem_trace: None,
+            mem_trace_sample: NonZeroU64::MIN,
+            mem_trace_range: Vec::new(),
+            mem_trace_kind: None,
             module_and_args,
         }
     }
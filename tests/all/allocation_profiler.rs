@@ -0,0 +1,104 @@
+use anyhow::Result;
+use wasmtime::*;
+
+#[test]
+fn records_memory_and_table_growth() -> Result<()> {
+    let mut config = Config::new();
+    config.wasm_backtrace(true);
+    let engine = Engine::new(&config)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (memory (export "memory") 1)
+                (table 0 externref)
+                (func $grow_memory (param i32) (result i32)
+                    (memory.grow (local.get 0)))
+                (func (export "grow-memory") (param i32) (result i32)
+                    (call $grow_memory (local.get 0)))
+                (func (export "grow-table") (param i32) (result i32)
+                    (table.grow (ref.null extern) (local.get 0)))
+            )
+        "#,
+    )?;
+
+    let mut store = Store::new(&engine, ());
+    store.set_allocation_profiler(AllocationProfiler::new().sample_backtraces(2));
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let grow_memory = instance.get_typed_func::<i32, i32>(&mut store, "grow-memory")?;
+    let grow_table = instance.get_typed_func::<i32, i32>(&mut store, "grow-table")?;
+
+    assert_eq!(grow_memory.call(&mut store, 2)?, 1);
+    assert_eq!(grow_table.call(&mut store, 10)?, 0);
+    assert_eq!(grow_memory.call(&mut store, 1)?, 3);
+    // Grows by zero don't allocate anything.
+    assert_eq!(grow_memory.call(&mut store, 0)?, 4);
+    assert_eq!(grow_table.call(&mut store, 0)?, 10);
+    // Growth by the embedder is recorded as well.
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    memory.grow(&mut store, 1)?;
+
+    let profiler = store.take_allocation_profiler().unwrap();
+    let events = profiler.events();
+    assert_eq!(events.len(), 4);
+
+    assert_eq!(events[0].kind(), AllocationKind::Memory);
+    assert_eq!(events[0].old_size(), 0x1_0000);
+    assert_eq!(events[0].new_size(), 0x3_0000);
+    assert_eq!(events[1].kind(), AllocationKind::ExternRefTable);
+    assert_eq!(events[1].delta(), 10);
+
+    // Every other event has a backtrace, starting with the first.
+    let frames = events[0].backtrace().unwrap().frames();
+    assert_eq!(frames[0].func_index(), 0);
+    assert_eq!(frames[1].func_index(), 1);
+    assert!(events[1].backtrace().is_none());
+    assert!(events[2].backtrace().is_some());
+    assert!(events[3].backtrace().is_none());
+
+    let totals = profiler.totals(AllocationKind::Memory);
+    assert_eq!(totals.count, 3);
+    assert_eq!(totals.size, 4 * 0x1_0000);
+    assert_eq!(
+        profiler.totals(AllocationKind::FuncRefTable),
+        AllocationTotals::default()
+    );
+
+    // Nothing is recorded once the profiler is removed.
+    memory.grow(&mut store, 1)?;
+    assert!(store.allocation_profiler().is_none());
+    Ok(())
+}
+
+#[test]
+fn bounds_recorded_events() -> Result<()> {
+    let engine = Engine::default();
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (memory (export "memory") 0)
+                (func (export "grow") (param i32) (result i32)
+                    (memory.grow (local.get 0)))
+            )
+        "#,
+    )?;
+
+    let mut store = Store::new(&engine, ());
+    store.set_allocation_profiler(AllocationProfiler::new().max_events(2));
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let grow = instance.get_typed_func::<i32, i32>(&mut store, "grow")?;
+    for _ in 0..5 {
+        grow.call(&mut store, 1)?;
+    }
+
+    let profiler = store.take_allocation_profiler().unwrap();
+    assert_eq!(profiler.events().len(), 2);
+    assert_eq!(profiler.events()[1].new_size(), 0x2_0000);
+    assert_eq!(profiler.dropped_events(), 3);
+    // The totals still account for every allocation.
+    let totals = profiler.totals(AllocationKind::Memory);
+    assert_eq!(totals.count, 5);
+    assert_eq!(totals.size, 5 * 0x1_0000);
+    Ok(())
+}
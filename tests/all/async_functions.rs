@@ -307,6 +307,116 @@ async fn cancel_during_run() {
     }
 }
 
+#[tokio::test]
+async fn cancel_token_signalled_when_call_dropped() {
+    let mut store = async_store();
+    let token = Arc::new(Mutex::new(None));
+    let token2 = token.clone();
+    let func = Func::wrap0_async(&mut store, move |caller| {
+        let cancel = caller.cancel_token().unwrap();
+        assert!(!cancel.is_cancelled());
+        *token2.lock().unwrap() = Some(cancel.clone());
+        Box::new(async move {
+            cancel.cancelled().await;
+            Ok(())
+        })
+    });
+
+    let future = Box::pin(func.call_async(&mut store, &[], &mut []));
+    let future = PollOnce::new(future).await;
+    assert!(future.is_err());
+    let token = token.lock().unwrap().take().unwrap();
+    assert!(!token.is_cancelled());
+
+    drop(future);
+    assert!(token.is_cancelled());
+
+    // The next call gets a fresh token.
+    let func = Func::wrap0_async(&mut store, |caller| {
+        assert!(!caller.cancel_token().unwrap().is_cancelled());
+        Box::new(async { Ok(()) })
+    });
+    func.call_async(&mut store, &[], &mut []).await.unwrap();
+}
+
+#[test]
+fn cancel_token_releases_wakers() {
+    struct Noop;
+
+    impl std::task::Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let noop = Arc::new(Noop);
+    let waker = Waker::from(noop.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    // Waiters which are polled repeatedly and then dropped don't leave their
+    // wakers behind in the token.
+    let token = CancelToken::new();
+    for _ in 0..10 {
+        let mut cancelled = Box::pin(token.cancelled());
+        assert!(cancelled.as_mut().poll(&mut cx).is_pending());
+        assert!(cancelled.as_mut().poll(&mut cx).is_pending());
+    }
+    assert_eq!(Arc::strong_count(&noop), 2);
+
+    // Neither does a finished call whose token outlives it.
+    let mut store = async_store();
+    let token = Arc::new(Mutex::new(None));
+    let token2 = token.clone();
+    let func = Func::wrap0_async(&mut store, move |caller| {
+        *token2.lock().unwrap() = caller.cancel_token();
+        Box::new(async {
+            let mut yielded = false;
+            std::future::poll_fn(|cx| {
+                if yielded {
+                    Poll::Ready(())
+                } else {
+                    yielded = true;
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            })
+            .await;
+            Ok(())
+        })
+    });
+    let mut future = Box::pin(func.call_async(&mut store, &[], &mut []));
+    loop {
+        if let Poll::Ready(result) = future.as_mut().poll(&mut cx) {
+            result.unwrap();
+            break;
+        }
+    }
+    drop(future);
+    assert!(token.lock().unwrap().is_some());
+    drop(waker);
+    assert_eq!(Arc::strong_count(&noop), 1);
+}
+
+#[tokio::test]
+async fn cancel_token_interrupts_pending_host_future() {
+    let mut store = async_store();
+    let func = Func::wrap0_async(&mut store, |caller| {
+        let cancel = caller.cancel_token().unwrap();
+        Box::new(async move {
+            tokio::spawn({
+                let cancel = cancel.clone();
+                async move { cancel.cancel() }
+            });
+            // Never completes on its own; only cancellation gets the guest
+            // unstuck.
+            std::future::pending::<()>().await;
+            Ok(())
+        })
+    });
+
+    let err = func.call_async(&mut store, &[], &mut []).await.unwrap_err();
+    assert_eq!(TrapCategory::of_error(&err), TrapCategory::HostCancellation);
+    assert_eq!(err.downcast::<Trap>().unwrap(), Trap::Interrupt);
+}
+
 #[tokio::test]
 async fn iloop_with_fuel() {
     let engine = Engine::new(Config::new().async_support(true).consume_fuel(true)).unwrap();
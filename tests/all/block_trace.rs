@@ -0,0 +1,102 @@
+#![cfg(not(miri))]
+
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use wasmtime::*;
+
+const WAT: &str = r#"
+    (module
+        (import "host" "f" (func))
+        (func (export "run") (param i32) (result i32)
+            (local i32)
+            (block
+                (loop
+                    (br_if 1 (i32.eqz (local.get 0)))
+                    (local.set 1 (i32.add (local.get 1) (local.get 0)))
+                    (local.set 0 (i32.sub (local.get 0) (i32.const 1)))
+                    (br 0)))
+            (if (result i32) (local.get 1)
+                (then (i32.const 1))
+                (else (i32.const 2))))
+    )
+"#;
+
+fn trace(config: &Config) -> Result<(Store<()>, TypedFunc<i32, i32>, Arc<Mutex<Vec<BlockEntry>>>)> {
+    let engine = Engine::new(config)?;
+    let module = Module::new(&engine, WAT)?;
+    let mut store = Store::new(&engine, ());
+    let entries = Arc::new(Mutex::new(Vec::new()));
+    let recorded = entries.clone();
+    store.block_trace(move |_, entry| {
+        recorded.lock().unwrap().push(entry);
+        Ok(())
+    });
+    let f = Func::wrap(&mut store, || {});
+    let instance = Instance::new(&mut store, &module, &[f.into()])?;
+    let run = instance.get_typed_func::<i32, i32>(&mut store, "run")?;
+    Ok((store, run, entries))
+}
+
+#[test]
+fn reports_block_entries() -> Result<()> {
+    let mut config = Config::new();
+    config.block_trace(true);
+    let (mut store, run, entries) = trace(&config)?;
+    assert_eq!(run.call(&mut store, 2)?, 1);
+
+    let entries = entries.lock().unwrap();
+    // The imported function comes first in the function index space.
+    assert!(entries.iter().all(|e| e.func_index() == 1));
+    // The function entry, three loop iterations, two of which fall through
+    // the `br_if`, then the code after the loop, the `then` arm and the code
+    // after the `if`.
+    assert_eq!(entries.len(), 9);
+    let offsets = entries.iter().map(|e| e.wasm_offset()).collect::<Vec<_>>();
+    let header = offsets[1];
+    let fallthrough = offsets[2];
+    assert!(offsets[0] < header && header < fallthrough);
+    assert_eq!(
+        offsets[1..6],
+        [header, fallthrough, header, fallthrough, header]
+    );
+    assert!(offsets[5] < offsets[6] && offsets[6] < offsets[7] && offsets[7] < offsets[8]);
+
+    // The `else` arm is a different block than the `then` arm.
+    drop(entries);
+    let (mut store, run, entries) = trace(&config)?;
+    assert_eq!(run.call(&mut store, 0)?, 2);
+    let entries = entries.lock().unwrap();
+    assert_eq!(entries.len(), 5);
+    assert_ne!(entries[3].wasm_offset(), offsets[7]);
+    assert_eq!(entries[4].wasm_offset(), offsets[8]);
+    let blocks = entries.iter().collect::<HashSet<_>>();
+    assert_eq!(blocks.len(), 5);
+    Ok(())
+}
+
+#[test]
+fn hook_errors_trap() -> Result<()> {
+    let mut config = Config::new();
+    config.block_trace(true);
+    let (mut store, run, _) = trace(&config)?;
+    let mut remaining = 3;
+    store.block_trace(move |_, _| {
+        if remaining == 0 {
+            bail!("out of blocks");
+        }
+        remaining -= 1;
+        Ok(())
+    });
+    let err = run.call(&mut store, 10).unwrap_err();
+    assert!(format!("{err:?}").contains("out of blocks"), "{err:?}");
+    Ok(())
+}
+
+#[test]
+fn disabled_by_default() -> Result<()> {
+    let (mut store, run, entries) = trace(&Config::new())?;
+    run.call(&mut store, 2)?;
+    assert!(entries.lock().unwrap().is_empty());
+    Ok(())
+}
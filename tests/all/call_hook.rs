@@ -544,6 +544,170 @@ fn trapping() -> Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn host_call_hook_filters_and_sees_params() -> Result<(), Error> {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, Vec::<(String, Vec<i32>)>::new());
+    store.host_call_hook("host", Some("f"), |log, call| {
+        let params = call.params().iter().map(|v| v.unwrap_i32()).collect();
+        log.push((call.name().to_string(), params));
+        Ok(())
+    });
+    store.host_call_hook("host", Some("deny"), |_, call| {
+        bail!("call to {} denied", call.name())
+    });
+    let mut linker = Linker::new(&engine);
+
+    let ty = FuncType::new([ValType::I32, ValType::I32], []);
+    linker.func_new("host", "f", ty.clone(), |_, _, _| Ok(()))?;
+    linker.func_new("host", "g", ty.clone(), |_, _, _| Ok(()))?;
+    linker.func_new("host", "deny", ty, |_, _, _| {
+        panic!("host function should not be called")
+    })?;
+
+    let wat = r#"
+        (module
+            (import "host" "f" (func $f (param i32 i32)))
+            (import "host" "g" (func $g (param i32 i32)))
+            (import "host" "deny" (func $deny (param i32 i32)))
+            (func (export "run")
+                (call $f (i32.const 1) (i32.const 2))
+                (call $g (i32.const 3) (i32.const 4))
+                (call $f (i32.const 5) (i32.const 6)))
+            (func (export "deny")
+                (call $deny (i32.const 0) (i32.const 0)))
+        )
+    "#;
+    let module = Module::new(&engine, wat)?;
+    let inst = linker.instantiate(&mut store, &module)?;
+
+    let run = inst.get_typed_func::<(), ()>(&mut store, "run")?;
+    run.call(&mut store, ())?;
+    let expected = vec![("f".to_string(), vec![1, 2]), ("f".to_string(), vec![5, 6])];
+    assert_eq!(store.data(), &expected);
+
+    let deny = inst.get_typed_func::<(), ()>(&mut store, "deny")?;
+    let err = deny.call(&mut store, ()).unwrap_err();
+    assert!(
+        format!("{err:?}").contains("call to deny denied"),
+        "{err:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn host_call_hook_typed_and_unchecked_funcs() -> Result<(), Error> {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, Vec::<(String, Vec<i64>)>::new());
+    store.host_call_hook("host", None, |log, call| {
+        let params = call
+            .params()
+            .iter()
+            .map(|v| match v {
+                Val::I32(i) => i64::from(*i),
+                Val::I64(i) => *i,
+                _ => unreachable!(),
+            })
+            .collect();
+        log.push((call.name().to_string(), params));
+        Ok(())
+    });
+    store.host_call_hook("host", Some("deny"), |_, call| {
+        bail!("call to {} denied", call.name())
+    });
+    let mut linker = Linker::new(&engine);
+
+    linker.func_wrap("host", "wrap", |a: i32, b: i64| {
+        assert_eq!((a, b), (1, 2));
+    })?;
+    linker.func_wrap("host", "deny", |_: i32| -> i32 {
+        panic!("host function should not be called")
+    })?;
+    unsafe {
+        let ty = FuncType::new([ValType::I32], []);
+        linker.func_new_unchecked("host", "unchecked", ty, |_, _| Ok(()))?;
+    }
+
+    let wat = r#"
+        (module
+            (import "host" "wrap" (func $wrap (param i32 i64)))
+            (import "host" "deny" (func $deny (param i32) (result i32)))
+            (import "host" "unchecked" (func $unchecked (param i32)))
+            (func (export "run")
+                (call $wrap (i32.const 1) (i64.const 2))
+                (call $unchecked (i32.const 3)))
+            (func (export "deny")
+                (drop (call $deny (i32.const 0))))
+        )
+    "#;
+    let module = Module::new(&engine, wat)?;
+    let inst = linker.instantiate(&mut store, &module)?;
+
+    let run = inst.get_typed_func::<(), ()>(&mut store, "run")?;
+    run.call(&mut store, ())?;
+    let expected = vec![
+        ("wrap".to_string(), vec![1, 2]),
+        ("unchecked".to_string(), vec![3]),
+    ];
+    assert_eq!(store.data(), &expected);
+
+    let deny = inst.get_typed_func::<(), ()>(&mut store, "deny")?;
+    let err = deny.call(&mut store, ()).unwrap_err();
+    assert!(
+        format!("{err:?}").contains("call to deny denied"),
+        "{err:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn host_call_hook_for_import_index() -> Result<(), Error> {
+    let engine = Engine::default();
+    let mut linker = Linker::new(&engine);
+    linker.func_wrap("host", "f", |_: i32| {})?;
+    linker.func_wrap("host", "g", |_: i32| {})?;
+
+    let wat = r#"
+        (module
+            (import "host" "f" (func $f (param i32)))
+            (import "host" "g" (func $g (param i32)))
+            (func (export "run")
+                (call $f (i32.const 1))
+                (call $g (i32.const 2))
+                (call $f (i32.const 3)))
+        )
+    "#;
+    let module = Module::new(&engine, wat)?;
+    let other = Module::new(&engine, wat)?;
+
+    let mut store = Store::new(&engine, Vec::<(String, i32)>::new());
+    store.host_call_hook_for_import(&module, 1, |log, call| {
+        log.push((call.name().to_string(), call.params()[0].unwrap_i32()));
+        Ok(())
+    })?;
+    assert!(store
+        .host_call_hook_for_import(&module, 2, |_, _| Ok(()))
+        .is_err());
+
+    let run = linker
+        .instantiate(&mut store, &module)?
+        .get_typed_func::<(), ()>(&mut store, "run")?;
+    run.call(&mut store, ())?;
+    assert_eq!(store.data(), &[("g".to_string(), 2)]);
+
+    // Calls from instances of other modules don't match, even if they import
+    // the same function at the same index.
+    let run = linker
+        .instantiate(&mut store, &other)?
+        .get_typed_func::<(), ()>(&mut store, "run")?;
+    run.call(&mut store, ())?;
+    assert_eq!(store.data().len(), 1);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn basic_async_hook() -> Result<(), Error> {
     struct HandlerR;
@@ -0,0 +1,217 @@
+#![cfg(not(miri))]
+
+use anyhow::Result;
+use wasmtime::*;
+
+fn linker(engine: &Engine, policy: &CapabilityPolicy) -> Result<Linker<()>> {
+    let mut linker = Linker::new(engine);
+    linker.func_wrap("host", "log", |_: i32| {})?;
+    linker.func_wrap("host", "exit", |_: i32| {})?;
+    linker.func_wrap("env", "random", || 4)?;
+    linker.capability_policy(policy.clone());
+    Ok(linker)
+}
+
+fn denials(err: &anyhow::Error) -> Vec<(String, Option<String>, DenialReason)> {
+    err.downcast_ref::<CapabilityDenied>()
+        .expect("expected a `CapabilityDenied` error")
+        .denials()
+        .iter()
+        .map(|d| {
+            let capability = d.capability();
+            (
+                capability.module().to_string(),
+                capability.name().map(|s| s.to_string()),
+                d.reason(),
+            )
+        })
+        .collect()
+}
+
+fn denial(
+    module: &str,
+    name: &str,
+    reason: DenialReason,
+) -> (String, Option<String>, DenialReason) {
+    (module.to_string(), Some(name.to_string()), reason)
+}
+
+#[test]
+fn imports_are_checked_against_policy() -> Result<()> {
+    let engine = Engine::default();
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (import "host" "log" (func (param i32)))
+                (import "host" "exit" (func (param i32)))
+                (import "env" "random" (func (result i32)))
+            )
+        "#,
+    )?;
+
+    let mut policy = CapabilityPolicy::new();
+    policy.allow_module("host").allow("env", "random");
+    let mut store = Store::new(&engine, ());
+    linker(&engine, &policy)?.instantiate(&mut store, &module)?;
+
+    let mut policy = CapabilityPolicy::new();
+    policy.allow("host", "log");
+    let err = linker(&engine, &policy)?
+        .instantiate(&mut store, &module)
+        .unwrap_err();
+    assert_eq!(
+        denials(&err),
+        [
+            denial("host", "exit", DenialReason::NotPermitted),
+            denial("env", "random", DenialReason::NotPermitted),
+        ]
+    );
+    assert_eq!(
+        err.to_string(),
+        "capability policy denied instantiation:\n    \
+         `host::exit` is not permitted\n    \
+         `env::random` is not permitted"
+    );
+    Ok(())
+}
+
+#[test]
+fn manifest_must_be_permitted() -> Result<()> {
+    let engine = Engine::default();
+    // Declares all of `host` and `env::random` while only importing
+    // `host::log`.
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (import "host" "log" (func (param i32)))
+                (@custom "wasmtime-capabilities"
+                    "\02\00\04host\01\03env\06random")
+            )
+        "#,
+    )?;
+
+    let mut policy = CapabilityPolicy::new();
+    policy.allow("host", "log").allow("env", "random");
+    let mut store = Store::new(&engine, ());
+    let err = linker(&engine, &policy)?
+        .instantiate_pre(&module)
+        .err()
+        .unwrap();
+    assert_eq!(
+        denials(&err),
+        [("host".to_string(), None, DenialReason::NotPermitted)]
+    );
+
+    policy.allow_module("host");
+    linker(&engine, &policy)?.instantiate(&mut store, &module)?;
+    Ok(())
+}
+
+#[test]
+fn imports_must_be_declared() -> Result<()> {
+    let engine = Engine::default();
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (import "host" "log" (func (param i32)))
+                (import "host" "exit" (func (param i32)))
+                (@custom "wasmtime-capabilities" "\01\01\04host\03log")
+            )
+        "#,
+    )?;
+
+    let mut policy = CapabilityPolicy::new();
+    policy.allow_module("host");
+    let err = linker(&engine, &policy)?
+        .instantiate_pre(&module)
+        .err()
+        .unwrap();
+    assert_eq!(
+        denials(&err),
+        [denial("host", "exit", DenialReason::Undeclared)]
+    );
+    Ok(())
+}
+
+#[test]
+fn require_manifest() -> Result<()> {
+    let engine = Engine::default();
+    let without_imports = Module::new(&engine, "(module)")?;
+    let with_imports = Module::new(
+        &engine,
+        r#"(module (import "host" "log" (func (param i32))))"#,
+    )?;
+
+    let mut policy = CapabilityPolicy::new();
+    policy.allow_module("host").require_manifest(true);
+    let linker = linker(&engine, &policy)?;
+    linker.instantiate_pre(&without_imports)?;
+    let err = linker.instantiate_pre(&with_imports).err().unwrap();
+    assert_eq!(
+        denials(&err),
+        [denial("host", "log", DenialReason::Undeclared)]
+    );
+    Ok(())
+}
+
+#[test]
+fn malformed_manifest() -> Result<()> {
+    let engine = Engine::default();
+    for manifest in [r#""\01\02\04host""#, r#""\01\00\04host\00""#] {
+        let wat = format!(r#"(module (@custom "wasmtime-capabilities" {manifest}))"#);
+        assert!(Module::new(&engine, &wat).is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn component_imports_are_checked_against_policy() -> Result<()> {
+    use wasmtime::component::{Component, Linker};
+
+    let engine = Engine::default();
+    let component = Component::new(
+        &engine,
+        r#"
+            (component
+                (import "host" (instance $i
+                    (export "log" (func))
+                    (export "exit" (func))
+                ))
+                (core module $m
+                    (import "" "log" (func))
+                    (import "" "exit" (func))
+                )
+                (core func $log (canon lower (func $i "log")))
+                (core func $exit (canon lower (func $i "exit")))
+                (core instance (instantiate $m
+                    (with "" (instance
+                        (export "log" (func $log))
+                        (export "exit" (func $exit))
+                    ))
+                ))
+            )
+        "#,
+    )?;
+
+    let mut linker = Linker::<()>::new(&engine);
+    let mut host = linker.instance("host")?;
+    host.func_wrap("log", |_, _: ()| -> Result<()> { Ok(()) })?;
+    host.func_wrap("exit", |_, _: ()| -> Result<()> { Ok(()) })?;
+
+    let mut policy = CapabilityPolicy::new();
+    policy.allow("host", "log");
+    linker.capability_policy(policy.clone());
+    let err = linker.instantiate_pre(&component).err().unwrap();
+    assert_eq!(
+        denials(&err),
+        [denial("host", "exit", DenialReason::NotPermitted)]
+    );
+
+    policy.allow("host", "exit");
+    linker.capability_policy(policy);
+    linker.instantiate_pre(&component)?;
+    Ok(())
+}
@@ -126,6 +126,50 @@ fn run_coredump_smoketest() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn run_mem_trace() -> Result<()> {
+    let trace_file = NamedTempFile::new()?;
+    let trace = trace_file.path().to_str().unwrap();
+    let wasm = "tests/all/cli_tests/mem-trace.wat";
+    // Drops the instruction offset at the end of each line.
+    let read_trace = || -> Result<Vec<String>> {
+        Ok(std::fs::read_to_string(trace)?
+            .lines()
+            .map(|line| line.rsplit_once(' ').unwrap().0.to_string())
+            .collect())
+    };
+
+    run_wasmtime(&["run", "--invoke", "run", "--mem-trace", trace, wasm])?;
+    assert_eq!(
+        read_trace()?,
+        ["store 0 0x10 4", "store 0 0x18 8", "load 0 0x12c 1"]
+    );
+
+    run_wasmtime(&[
+        "run",
+        "--invoke",
+        "run",
+        "--mem-trace",
+        trace,
+        "--mem-trace-kind=store",
+        "--mem-trace-sample=2",
+        wasm,
+    ])?;
+    assert_eq!(read_trace()?, ["store 0 0x10 4"]);
+
+    run_wasmtime(&[
+        "run",
+        "--invoke",
+        "run",
+        "--mem-trace",
+        trace,
+        "--mem-trace-range=0x1c..0x200",
+        wasm,
+    ])?;
+    assert_eq!(read_trace()?, ["store 0 0x18 8", "load 0 0x12c 1"]);
+    Ok(())
+}
+
 // Running simple wat
 #[test]
 fn run_wasmtime_simple_wat() -> Result<()> {
@@ -0,0 +1,8 @@
+(module
+  (memory 1)
+  (func (export "run")
+    (i32.store (i32.const 16) (i32.const 1))
+    (i64.store offset=8 (i32.const 16) (i64.const 2))
+    (drop (i32.load8_u (i32.const 300)))
+  )
+)
@@ -34,3 +34,162 @@ fn code_too_large_without_panic() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn function_over_configured_size_limit() -> Result<()> {
+    let wat = r#"
+        (module
+          (func (result i32)
+            i32.const 1)
+          (func (result i32)
+            i32.const 1
+            i32.const 2
+            i32.add
+            i32.const 3
+            i32.add))
+    "#;
+
+    let mut config = Config::new();
+    config.max_function_body_size(Some(8));
+    let engine = Engine::new(&config)?;
+    let err = Module::new(&engine, wat).unwrap_err();
+    match err.downcast_ref::<CompileError>() {
+        Some(CompileError::FunctionTooLarge { func_index, .. }) => assert_eq!(*func_index, 1),
+        _ => panic!("unexpected error: {err:?}"),
+    }
+
+    // Without a limit, the same module compiles fine.
+    Module::new(&Engine::default(), wat)?;
+    Ok(())
+}
+
+#[test]
+fn function_over_regalloc_limit() -> Result<()> {
+    let mut s = String::new();
+    s.push_str("(module\n");
+    s.push_str("(func (result i32)\n");
+    s.push_str("i32.const 1)\n");
+    s.push_str("(func (param i32) (result i32)\n");
+    s.push_str("local.get 0\n");
+    for i in 0..1000 {
+        s.push_str("local.get 0\n");
+        s.push_str("i32.xor\n");
+        s.push_str(&format!("i32.const {i}\n"));
+        s.push_str("i32.add\n");
+    }
+    s.push_str("))\n");
+
+    let mut config = Config::new();
+    unsafe {
+        config.cranelift_flag_set("regalloc_max_insts_log2", "8");
+    }
+    let engine = Engine::new(&config)?;
+    let err = Module::new(&engine, &s).unwrap_err();
+    match err.downcast_ref::<CompileError>() {
+        Some(CompileError::FunctionTooLarge { func_index, .. }) => assert_eq!(*func_index, 1),
+        _ => panic!("unexpected error: {err:?}"),
+    }
+    Ok(())
+}
+
+/// Returns a function with more values live at once than there are
+/// registers, which swaps values around a loop and calls another function.
+fn regalloc_stress_wat() -> String {
+    const N: usize = 40;
+    let mut s = String::new();
+    s.push_str("(module\n");
+    s.push_str("(func $mul3 (param i64) (result i64) local.get 0 i64.const 3 i64.mul)\n");
+    s.push_str("(func (export \"f\") (param i64) (result i64)\n");
+    s.push_str("(local $i i32) (local $a i64) (local $b i64)\n");
+    s.push_str(&"(local i64)\n".repeat(N));
+    s.push_str("i64.const 1 local.set $b\n");
+    for k in 0..N {
+        s.push_str(&format!(
+            "local.get 0 i64.const {} i64.mul i64.const {k} i64.add local.set {}\n",
+            k + 1,
+            k + 4
+        ));
+    }
+    s.push_str("(loop $l\n");
+    s.push_str("local.get $b local.get $a local.get $b i64.add local.set $b local.set $a\n");
+    s.push_str("local.get $i i32.const 1 i32.add local.tee $i i32.const 20 i32.lt_u br_if $l)\n");
+    s.push_str("local.get $a call $mul3\n");
+    for k in 0..N {
+        s.push_str(&format!("local.get {} i64.xor\n", k + 4));
+    }
+    s.push_str("))\n");
+    s
+}
+
+fn call_stress(engine: &Engine, wat: &str, arg: u64) -> Result<u64> {
+    let module = Module::new(engine, wat)?;
+    let mut store = Store::new(engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let f = instance.get_typed_func::<u64, u64>(&mut store, "f")?;
+    f.call(&mut store, arg)
+}
+
+#[test]
+fn single_pass_regalloc() -> Result<()> {
+    let wat = regalloc_stress_wat();
+    let mut config = Config::new();
+    config.cranelift_regalloc_algorithm(RegallocAlgorithm::SinglePass);
+    unsafe {
+        config.cranelift_flag_set("regalloc_checker", "true");
+    }
+    let engine = Engine::new(&config)?;
+    for arg in [0, 1, 12345, u64::MAX] {
+        assert_eq!(
+            call_stress(&engine, &wat, arg)?,
+            call_stress(&Engine::default(), &wat, arg)?,
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn function_over_single_pass_threshold() -> Result<()> {
+    let wat = regalloc_stress_wat();
+    let mut config = Config::new();
+    unsafe {
+        config.cranelift_flag_set("regalloc_single_pass_insts_log2", "6");
+        config.cranelift_flag_set("regalloc_checker", "true");
+    }
+    let engine = Engine::new(&config)?;
+    assert_eq!(
+        call_stress(&engine, &wat, 7)?,
+        call_stress(&Engine::default(), &wat, 7)?,
+    );
+    Ok(())
+}
+
+#[test]
+fn br_table_without_jump_tables() -> Result<()> {
+    let wat = r#"
+        (module
+          (func (export "f") (param i32) (result i32)
+            (block $default
+              (block $b3
+                (block $b2
+                  (block $b1
+                    (block $b0
+                      (br_table $b0 $b1 $b2 $b3 $default (local.get 0)))
+                    (return (i32.const 10)))
+                  (return (i32.const 11)))
+                (return (i32.const 12)))
+              (return (i32.const 13)))
+            i32.const 14))
+    "#;
+
+    let mut config = Config::new();
+    config.cranelift_jump_tables(false);
+    let engine = Engine::new(&config)?;
+    let module = Module::new(&engine, wat)?;
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let f = instance.get_typed_func::<u32, u32>(&mut store, "f")?;
+    for (index, expected) in [(0, 10), (1, 11), (2, 12), (3, 13), (4, 14), (u32::MAX, 14)] {
+        assert_eq!(f.call(&mut store, index)?, expected);
+    }
+    Ok(())
+}
@@ -243,3 +243,67 @@ async fn poll_through_wasm_activation() -> Result<()> {
     .await?;
     Ok(())
 }
+
+/// Host resources can have async destructors which run to completion when the
+/// guest drops a resource, when the host does, and when the instance is torn
+/// down.
+#[tokio::test]
+async fn resource_async_dtor() -> Result<()> {
+    let component = r#"
+        (component
+            (import "t" (type $t (sub resource)))
+            (core func $drop (canon resource.drop $t))
+            (func (export "drop") (param "x" (own $t))
+                (canon lift (core func $drop)))
+            (core module $m
+                (func (export "pass") (param i32) (result i32) local.get 0)
+                (func (export "keep") (param i32)))
+            (core instance $i (instantiate $m))
+            (func (export "pass") (param "x" (own $t)) (result (own $t))
+                (canon lift (core func $i "pass")))
+            (func (export "keep") (param "x" (own $t))
+                (canon lift (core func $i "keep")))
+        )
+    "#;
+
+    struct MyType;
+
+    let engine = super::async_engine();
+    let component = Component::new(&engine, component)?;
+    let mut store = Store::new(&engine, Vec::new());
+    let mut linker = Linker::new(&engine);
+    linker.root().resource_async::<MyType, _>(
+        "t",
+        |mut cx: StoreContextMut<'_, Vec<u32>>, rep| {
+            Box::new(async move {
+                tokio::task::yield_now().await;
+                cx.data_mut().push(rep);
+                Ok(())
+            })
+        },
+    )?;
+    let instance = linker.instantiate_async(&mut store, &component).await?;
+    let drop = instance.get_typed_func::<(Resource<MyType>,), ()>(&mut store, "drop")?;
+    let pass =
+        instance.get_typed_func::<(Resource<MyType>,), (ResourceAny,)>(&mut store, "pass")?;
+
+    drop.call_async(&mut store, (Resource::new_own(1),)).await?;
+    drop.post_return_async(&mut store).await?;
+    assert_eq!(*store.data(), [1]);
+
+    let (t,) = pass.call_async(&mut store, (Resource::new_own(2),)).await?;
+    pass.post_return_async(&mut store).await?;
+    t.resource_drop_async(&mut store).await?;
+    assert_eq!(*store.data(), [1, 2]);
+
+    // Resources which the guest still owns are destroyed when the instance is
+    // torn down.
+    let keep = instance.get_typed_func::<(Resource<MyType>,), ()>(&mut store, "keep")?;
+    keep.call_async(&mut store, (Resource::new_own(3),)).await?;
+    keep.post_return_async(&mut store).await?;
+    assert_eq!(*store.data(), [1, 2]);
+    instance.drop_host_resources_async(&mut store).await?;
+    assert_eq!(*store.data(), [1, 2, 3]);
+
+    Ok(())
+}
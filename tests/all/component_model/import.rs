@@ -1,6 +1,6 @@
 #![cfg(not(miri))]
 
-use super::REALLOC_AND_FREE;
+use super::{TypedFuncExt, REALLOC_AND_FREE};
 use anyhow::Result;
 use std::ops::Deref;
 use wasmtime::component::*;
@@ -985,3 +985,257 @@ fn use_types_across_component_boundaries() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn versioned_import_resolution() -> Result<()> {
+    let engine = super::engine();
+    let component = Component::new(
+        &engine,
+        r#"
+            (component
+                (import (interface "test:test/foo@0.2.1") (instance
+                    (export "f" (func))
+                ))
+            )
+        "#,
+    )?;
+
+    let mut linker = Linker::<()>::new(&engine);
+    linker
+        .instance("test:test/foo@0.2.0")?
+        .func_wrap("f", |_, ()| Ok(()))?;
+    linker
+        .instance("test:test/foo@0.2.3")?
+        .func_wrap("f", |_, ()| Ok(()))?;
+    linker.instance("test:test/foo@0.3.0")?;
+
+    // By default versions must match exactly.
+    assert!(linker.instantiate_pre(&component).is_err());
+
+    linker.version_policy(VersionPolicy::Compatible);
+    linker.instantiate_pre(&component)?;
+    linker.version_policy(VersionPolicy::Lenient);
+    linker.instantiate_pre(&component)?;
+
+    // Only an older version is available, which is only accepted by the
+    // lenient policy.
+    let mut linker = Linker::<()>::new(&engine);
+    linker
+        .instance("test:test/foo@0.2.0")?
+        .func_wrap("f", |_, ()| Ok(()))?;
+    linker.version_policy(VersionPolicy::Compatible);
+    assert!(linker.instantiate_pre(&component).is_err());
+    linker.version_policy(VersionPolicy::Lenient);
+    linker.instantiate_pre(&component)?;
+
+    // Incompatible versions are never used.
+    let mut linker = Linker::<()>::new(&engine);
+    linker
+        .instance("test:test/foo@0.3.0")?
+        .func_wrap("f", |_, ()| Ok(()))?;
+    linker
+        .instance("test:test/foo@1.2.1")?
+        .func_wrap("f", |_, ()| Ok(()))?;
+    linker.version_policy(VersionPolicy::Lenient);
+    assert!(linker.instantiate_pre(&component).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn interface_adapters() -> Result<()> {
+    let engine = super::engine();
+    let component = Component::new(
+        &engine,
+        r#"
+            (component
+                (import (interface "test:test/points@1.0.0") (instance $i
+                    (type $p (record (field "x" u32)))
+                    (export "point" (type $p' (eq $p)))
+                    (export "get" (func (result u32)))
+                    (export "norm" (func (param "p" $p') (result u32)))
+                ))
+                (core func $get (canon lower (func $i "get")))
+                (core func $norm (canon lower (func $i "norm")))
+                (core module $m
+                    (import "" "get" (func $get (result i32)))
+                    (import "" "norm" (func $norm (param i32) (result i32)))
+                    (func (export "run") (result i32)
+                        call $get
+                        call $norm)
+                )
+                (core instance $i (instantiate $m
+                    (with "" (instance
+                        (export "get" (func $get))
+                        (export "norm" (func $norm))
+                    ))
+                ))
+                (func (export "run") (result u32)
+                    (canon lift (core func $i "run")))
+            )
+        "#,
+    )?;
+
+    // The host implements a newer version of the interface where `get` was
+    // renamed to `fetch` and `point` gained a `y` field.
+    fn norm(x: u32, y: u32) -> u32 {
+        x * 10 + y
+    }
+    let mut linker = Linker::<()>::new(&engine);
+    linker
+        .instance("test:test/points@2.0.0")?
+        .func_wrap("fetch", |_, ()| Ok((7u32,)))?;
+    assert!(linker.instantiate_pre(&component).is_err());
+
+    let mut adapter = InterfaceAdapter::new("test:test/points@2.0.0");
+    adapter
+        .rename("get", "fetch")
+        .func_new("norm", |_, params, results| {
+            let x = match &params[0] {
+                Val::Record(r) => match r.fields().next() {
+                    Some(("x", Val::U32(x))) => *x,
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+            results[0] = Val::U32(norm(x, 1));
+            Ok(())
+        });
+    linker.adapt("test:test/points@1.0.0", adapter)?;
+    assert!(linker
+        .adapt(
+            "test:test/points@1.0.0",
+            InterfaceAdapter::new("test:test/points@2.0.0")
+        )
+        .is_err());
+
+    let mut store = Store::new(&engine, ());
+    let instance = linker.instantiate(&mut store, &component)?;
+    let run = instance.get_typed_func::<(), (u32,)>(&mut store, "run")?;
+    assert_eq!(run.call_and_post_return(&mut store, ())?, (71,));
+
+    // Adapters are checked when instantiating.
+    let mut linker = Linker::<()>::new(&engine);
+    let mut adapter = InterfaceAdapter::new("test:test/points@2.0.0");
+    adapter.rename("get", "fetch");
+    linker.adapt("test:test/points@1.0.0", adapter)?;
+    let err = linker.instantiate_pre(&component).err().unwrap();
+    assert!(
+        format!("{err:?}").contains("adapter target `test:test/points@2.0.0` is not defined"),
+        "{err:?}"
+    );
+    linker.instance("test:test/points@2.0.0")?;
+    let err = linker.instantiate_pre(&component).err().unwrap();
+    assert!(
+        format!("{err:?}").contains("`fetch` is not defined in adapter target"),
+        "{err:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn interface_adapter_field_defaults() -> Result<()> {
+    let engine = super::engine();
+    let component = Component::new(
+        &engine,
+        r#"
+            (component
+                (import (interface "test:test/points@1.0.0") (instance $i
+                    (type $p (record (field "x" u32)))
+                    (export "point" (type $p' (eq $p)))
+                    (export "norm" (func (param "p" $p') (result u32)))
+                ))
+                (core func $norm (canon lower (func $i "norm")))
+                (core module $m
+                    (import "" "norm" (func $norm (param i32) (result i32)))
+                    (func (export "run") (result i32)
+                        i32.const 7
+                        call $norm)
+                )
+                (core instance $i (instantiate $m
+                    (with "" (instance (export "norm" (func $norm))))
+                ))
+                (func (export "run") (result u32)
+                    (canon lift (core func $i "run")))
+            )
+        "#,
+    )?;
+
+    // The newer version of the interface, as implemented by the host, where
+    // `point` gained a `y` field.
+    let newer = Component::new(
+        &engine,
+        r#"
+            (component
+                (import (interface "test:test/points@2.0.0") (instance
+                    (type $p (record (field "x" u32) (field "y" u32)))
+                    (export "point" (type $p' (eq $p)))
+                    (export "measure" (func (param "p" $p') (result u32)))
+                ))
+            )
+        "#,
+    )?;
+
+    let mut linker = Linker::<()>::new(&engine);
+    linker.instance("test:test/points@2.0.0")?.func_new(
+        &newer,
+        "measure",
+        |_, params, results| {
+            let mut fields = match &params[0] {
+                Val::Record(r) => r.fields(),
+                _ => unreachable!(),
+            };
+            match (fields.next(), fields.next()) {
+                (Some(("x", Val::U32(x))), Some(("y", Val::U32(y)))) => {
+                    results[0] = Val::U32(x * 10 + y);
+                }
+                _ => unreachable!(),
+            }
+            Ok(())
+        },
+    )?;
+    let mut adapter = InterfaceAdapter::new("test:test/points@2.0.0");
+    adapter
+        .rename("norm", "measure")
+        .field_default("norm", "y", Val::U32(1));
+    linker.adapt("test:test/points@1.0.0", adapter)?;
+
+    let mut store = Store::new(&engine, ());
+    let instance = linker.instantiate(&mut store, &component)?;
+    let run = instance.get_typed_func::<(), (u32,)>(&mut store, "run")?;
+    assert_eq!(run.call_and_post_return(&mut store, ())?, (71,));
+
+    // Without a default the field can't be filled in.
+    let mut adapter = InterfaceAdapter::new("test:test/points@2.0.0");
+    adapter
+        .rename("norm", "measure")
+        .field_default("norm", "z", Val::U32(1));
+    linker.allow_shadowing(true);
+    linker.adapt("test:test/points@1.0.0", adapter)?;
+    let instance = linker.instantiate(&mut store, &component)?;
+    let run = instance.get_typed_func::<(), (u32,)>(&mut store, "run")?;
+    let err = run.call(&mut store, ()).unwrap_err();
+    assert!(
+        format!("{err:?}").contains("no value or default for field `y`"),
+        "{err:?}"
+    );
+
+    // Statically-typed targets can't be called with converted values.
+    let mut linker = Linker::<()>::new(&engine);
+    linker
+        .instance("test:test/points@2.0.0")?
+        .func_wrap("measure", |_, (x,): (u32,)| Ok((x,)))?;
+    let mut adapter = InterfaceAdapter::new("test:test/points@2.0.0");
+    adapter
+        .rename("norm", "measure")
+        .field_default("norm", "y", Val::U32(1));
+    linker.adapt("test:test/points@1.0.0", adapter)?;
+    let err = linker.instantiate_pre(&component).err().unwrap();
+    assert!(
+        format!("{err:?}").contains("defined with `func_new`"),
+        "{err:?}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use anyhow::Result;
+use wasmtime::component::types::{ComponentItem, InterfaceName};
+use wasmtime::component::*;
+use wasmtime::{ExternType, Store, ValType};
+
+#[test]
+fn component_imports_and_exports() -> Result<()> {
+    let engine = super::engine();
+    let component = Component::new(
+        &engine,
+        r#"
+            (component
+                (import (interface "wasi:example/points@0.2.0") (instance $i
+                    (type $point (record (field "x" u32) (field "y" u32)))
+                    (export "point" (type $p (eq $point)))
+                    (export "distance" (func (param "a" $p) (param "b" $p) (result float64)))
+                ))
+                (import "run" (func $run))
+
+                (core module $m
+                    (func (export "f") (param i32) (result i32) local.get 0)
+                )
+                (core instance $m (instantiate $m))
+                (func $f (param "value" u32) (result u32)
+                    (canon lift (core func $m "f")))
+
+                (instance $e (export "g" (func $f)))
+
+                (export "m" (core module $m))
+                (export "f" (func $f))
+                (export "i" (instance $e))
+            )
+        "#,
+    )?;
+    let ty = component.component_type();
+
+    let imports = ty.imports().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(imports, ["wasi:example/points@0.2.0", "run"]);
+    let exports = ty.exports().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(exports, ["m", "f", "i"]);
+    assert!(ty.get_import("missing").is_none());
+
+    let name = InterfaceName::parse("wasi:example/points@0.2.0").unwrap();
+    assert_eq!(name.namespace(), "wasi");
+    assert_eq!(name.package(), "example");
+    assert_eq!(name.interface(), "points");
+    assert_eq!(name.version(), Some("0.2.0"));
+    assert!(InterfaceName::parse("run").is_none());
+
+    let points = match ty.get_import("wasi:example/points@0.2.0") {
+        Some(ComponentItem::ComponentInstance(i)) => i,
+        other => panic!("unexpected import {other:?}"),
+    };
+    let point = match points.get_export("point") {
+        Some(ComponentItem::Type(Type::Record(r))) => r,
+        other => panic!("unexpected export {other:?}"),
+    };
+    let fields = point
+        .fields()
+        .map(|f| (f.name.to_string(), f.ty))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fields,
+        [("x".to_string(), Type::U32), ("y".to_string(), Type::U32)]
+    );
+    let distance = match points.get_export("distance") {
+        Some(ComponentItem::ComponentFunc(f)) => f,
+        other => panic!("unexpected export {other:?}"),
+    };
+    let params = distance
+        .params()
+        .map(|(name, ty)| (name.to_string(), ty))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        params,
+        [
+            ("a".to_string(), Type::Record(point.clone())),
+            ("b".to_string(), Type::Record(point.clone())),
+        ]
+    );
+    assert_eq!(distance.results().collect::<Vec<_>>(), [Type::Float64]);
+
+    match ty.get_import("run") {
+        Some(ComponentItem::ComponentFunc(f)) => {
+            assert_eq!(f.params().len(), 0);
+            assert_eq!(f.results().len(), 0);
+        }
+        other => panic!("unexpected import {other:?}"),
+    }
+
+    match ty.get_export("f") {
+        Some(ComponentItem::ComponentFunc(f)) => {
+            let params = f.params().collect::<Vec<_>>();
+            assert_eq!(params, [("value", Type::U32)]);
+            assert_eq!(f.results().collect::<Vec<_>>(), [Type::U32]);
+        }
+        other => panic!("unexpected export {other:?}"),
+    }
+
+    match ty.get_export("m") {
+        Some(ComponentItem::Module(m)) => {
+            assert_eq!(m.imports().len(), 0);
+            let exports = m.exports().collect::<Vec<_>>();
+            assert_eq!(exports.len(), 1);
+            assert_eq!(exports[0].0, "f");
+            match &exports[0].1 {
+                ExternType::Func(f) => {
+                    assert_eq!(f.params().collect::<Vec<_>>(), [ValType::I32]);
+                    assert_eq!(f.results().collect::<Vec<_>>(), [ValType::I32]);
+                }
+                other => panic!("unexpected export {other:?}"),
+            }
+        }
+        other => panic!("unexpected export {other:?}"),
+    }
+
+    match ty.get_export("i") {
+        Some(ComponentItem::ComponentInstance(i)) => {
+            let exports = i.exports().map(|(name, _)| name).collect::<Vec<_>>();
+            assert_eq!(exports, ["g"]);
+        }
+        other => panic!("unexpected export {other:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+fn component_resources() -> Result<()> {
+    let engine = super::engine();
+    let wat = r#"
+        (component
+            (import "t" (type $t (sub resource)))
+            (import "make" (func (result (own $t))))
+
+            (type $u (resource (rep i32)))
+            (export "u" (type $u))
+        )
+    "#;
+    let component = Component::new(&engine, wat)?;
+    let ty = component.component_type();
+
+    let imported = match ty.get_import("t") {
+        Some(ComponentItem::Resource(r)) => r,
+        other => panic!("unexpected import {other:?}"),
+    };
+    match ty.get_import("make") {
+        Some(ComponentItem::ComponentFunc(f)) => {
+            assert_eq!(f.results().collect::<Vec<_>>(), [Type::Own(imported)]);
+        }
+        other => panic!("unexpected import {other:?}"),
+    }
+
+    let exported = match ty.get_export("u") {
+        Some(ComponentItem::Resource(r)) => r,
+        other => panic!("unexpected export {other:?}"),
+    };
+    assert_ne!(exported, imported);
+    assert!(exported.instance().is_none());
+
+    // The same component always reports the same types, unlike another
+    // compilation of it.
+    match component.component_type().get_export("u") {
+        Some(ComponentItem::Resource(r)) => assert_eq!(r, exported),
+        other => panic!("unexpected export {other:?}"),
+    }
+    let other = Component::new(&engine, wat)?;
+    match other.component_type().get_export("u") {
+        Some(ComponentItem::Resource(r)) => assert_ne!(r, exported),
+        other => panic!("unexpected export {other:?}"),
+    }
+
+    // Each instantiation defines its own resource types, none of which are
+    // the uninstantiated type reported above.
+    struct T;
+    let mut store = Store::new(&engine, ());
+    let mut linker = Linker::new(&engine);
+    linker.root().resource::<T>("t", |_, _| Ok(()))?;
+    linker
+        .root()
+        .func_wrap("make", |_, (): ()| -> Result<(Resource<T>,)> {
+            Ok((Resource::new_own(1),))
+        })?;
+    let instance = linker.instantiate(&mut store, &component)?;
+    let u = instance.get_resource(&mut store, "u").unwrap();
+    assert_ne!(u, exported);
+    assert!(u.instance().is_some());
+    Ok(())
+}
+
+#[test]
+fn param_names_of_equal_signatures() -> Result<()> {
+    let engine = super::engine();
+    let component = Component::new(
+        &engine,
+        r#"
+            (component
+                (import "a" (func (param "x" u32)))
+                (import "b" (func (param "y" u32)))
+            )
+        "#,
+    )?;
+    let ty = component.component_type();
+
+    for (name, param) in [("a", "x"), ("b", "y")] {
+        match ty.get_import(name) {
+            Some(ComponentItem::ComponentFunc(f)) => {
+                let params = f.params().map(|(name, _)| name).collect::<Vec<_>>();
+                assert_eq!(params, [param]);
+            }
+            other => panic!("unexpected import {other:?}"),
+        }
+    }
+    Ok(())
+}
@@ -240,9 +240,13 @@ fn mismatch_resource_types() -> Result<()> {
 
     let (t,) = ctor.call(&mut store, (100,))?;
     ctor.post_return(&mut store)?;
+    assert_eq!(t.instance(), Some(i));
+    assert_eq!(t.ty(), i.get_resource(&mut store, "t").unwrap());
     assert_eq!(
         dtor.call(&mut store, (t,)).unwrap_err().to_string(),
-        "mismatched resource types"
+        "mismatched resource types: \
+         expected resource 1 defined by component instance 0 (exported as `u`), \
+         found resource 0 defined by component instance 0 (exported as `t`)"
     );
 
     Ok(())
@@ -1283,3 +1287,50 @@ fn guest_different_host_same() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn drop_host_resources() -> Result<()> {
+    let engine = super::engine();
+    let c = Component::new(
+        &engine,
+        r#"
+            (component
+                (import "t" (type $t (sub resource)))
+
+                (core module $m
+                    (func (export "keep") (param i32)))
+                (core instance $i (instantiate $m))
+
+                (func (export "keep") (param "x" (own $t))
+                    (canon lift (core func $i "keep")))
+            )
+        "#,
+    )?;
+
+    struct MyType;
+
+    let mut store = Store::new(&engine, Vec::new());
+    let mut linker = Linker::<Vec<u32>>::new(&engine);
+    linker.root().resource::<MyType>("t", |mut cx, rep| {
+        cx.data_mut().push(rep);
+        Ok(())
+    })?;
+    let i = linker.instantiate(&mut store, &c)?;
+    let keep = i.get_typed_func::<(Resource<MyType>,), ()>(&mut store, "keep")?;
+
+    for rep in [1, 2] {
+        keep.call(&mut store, (Resource::new_own(rep),))?;
+        keep.post_return(&mut store)?;
+    }
+    assert!(store.data().is_empty());
+
+    // The guest never dropped its resources, so their destructors run now.
+    i.drop_host_resources(&mut store)?;
+    assert_eq!(*store.data(), [1, 2]);
+
+    // And the instance doesn't own them anymore.
+    i.drop_host_resources(&mut store)?;
+    assert_eq!(*store.data(), [1, 2]);
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+#![cfg(not(miri))]
+
+use anyhow::Result;
+use wasmtime::*;
+
+const WAT: &str = r#"
+    (module
+        (func (export "select") (param i32) (result i32)
+            (if (result i32) (local.get 0)
+                (then (i32.const 1))
+                (else (i32.const 2))))
+        (func (export "loop") (param i32)
+            (loop $l
+                (br_if $l
+                    (local.tee 0 (i32.sub (local.get 0) (i32.const 1))))))
+        (func $unused
+            unreachable
+            (loop))
+    )
+"#;
+
+fn counts(coverage: &ModuleCoverage, index: u32) -> Vec<u64> {
+    coverage
+        .function(index)
+        .unwrap()
+        .blocks()
+        .iter()
+        .map(|b| b.count())
+        .collect()
+}
+
+#[test]
+fn counts_blocks() -> Result<()> {
+    let mut config = Config::new();
+    config.coverage(true);
+    let engine = Engine::new(&config)?;
+    let module = Module::new(&engine, WAT)?;
+
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let select = instance.get_typed_func::<i32, i32>(&mut store, "select")?;
+    let looping = instance.get_typed_func::<i32, ()>(&mut store, "loop")?;
+    assert_eq!(select.call(&mut store, 1)?, 1);
+    assert_eq!(select.call(&mut store, 1)?, 1);
+    assert_eq!(select.call(&mut store, 0)?, 2);
+    looping.call(&mut store, 3)?;
+
+    let coverage = module.coverage().unwrap();
+    assert_eq!(coverage.functions().len(), 3);
+
+    // Entry, `then`, `else`, after the `if` and the function's `end`.
+    assert_eq!(counts(&coverage, 0), [3, 2, 1, 3, 3]);
+    assert_eq!(coverage.function(0).unwrap().calls(), 3);
+
+    // Entry, loop header, `br_if` fallthrough, loop `end` and function `end`.
+    assert_eq!(counts(&coverage, 1), [1, 3, 1, 1, 1]);
+
+    // Blocks in unreachable code are never entered.
+    assert_eq!(counts(&coverage, 2), [0, 0, 0, 0]);
+
+    // Offsets point at the operators blocks start after.
+    let blocks = coverage.function(0).unwrap().blocks();
+    assert!(blocks.windows(2).all(|w| w[0].offset() < w[1].offset()));
+
+    // Counters are shared between instances and can be reset.
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let select = instance.get_typed_func::<i32, i32>(&mut store, "select")?;
+    select.call(&mut store, 0)?;
+    assert_eq!(counts(&module.coverage().unwrap(), 0), [4, 2, 2, 4, 4]);
+    module.reset_coverage();
+    assert_eq!(counts(&module.coverage().unwrap(), 0), [0, 0, 0, 0, 0]);
+
+    Ok(())
+}
+
+#[test]
+fn disabled_by_default() -> Result<()> {
+    let engine = Engine::default();
+    let module = Module::new(&engine, WAT)?;
+    assert!(module.coverage().is_none());
+    Ok(())
+}
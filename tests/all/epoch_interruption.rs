@@ -448,3 +448,77 @@ async fn drop_future_on_epoch_yield() {
 
     assert_eq!(true, alive_flag.load(Ordering::Acquire));
 }
+
+#[tokio::test]
+async fn epoch_deadline_scope_overrides_behavior() {
+    let wasm = "
+    (module
+      (import \"\" \"bump_epoch\" (func $bump))
+      (func (export \"run\")
+        (call $bump)
+        (call $subfunc))  ;; subfunc entry to do epoch check
+      (func $subfunc))
+    ";
+
+    let engine = build_engine();
+    let linker = make_env(&engine);
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    store.set_epoch_deadline(1);
+    store.epoch_deadline_async_yield_and_update(1);
+
+    let instance = linker.instantiate_async(&mut store, &module).await.unwrap();
+    let f = instance.get_func(&mut store, "run").unwrap();
+
+    // Within the scope the deadline traps instead of yielding.
+    {
+        let mut scope = store.epoch_deadline_scope();
+        let (result, yields) =
+            CountPending::new(Box::pin(f.call_async(&mut scope, &[], &mut []))).await;
+        assert_eq!(yields, 0);
+        let err = result.unwrap_err();
+        assert_eq!(err.downcast_ref::<Trap>(), Some(&Trap::Interrupt));
+    }
+
+    // Once the scope is dropped the store yields again.
+    store.set_epoch_deadline(1);
+    let (result, yields) =
+        CountPending::new(Box::pin(f.call_async(&mut store, &[], &mut []))).await;
+    result.unwrap();
+    assert_eq!(yields, 1);
+}
+
+#[tokio::test]
+async fn epoch_deadline_scope_restores_remaining_ticks() {
+    let wasm = "
+    (module
+      (import \"\" \"bump_epoch\" (func $bump))
+      (func (export \"run\")
+        (call $bump)
+        (call $subfunc))  ;; subfunc entry to do epoch check
+      (func $subfunc))
+    ";
+
+    let engine = build_engine();
+    let linker = make_env(&engine);
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    store.set_epoch_deadline(2);
+
+    let instance = linker.instantiate_async(&mut store, &module).await.unwrap();
+    let f = instance.get_func(&mut store, "run").unwrap();
+
+    // Time passes while the scope is active, well beyond the store's original
+    // deadline.
+    {
+        let mut scope = store.epoch_deadline_scope();
+        scope.set_epoch_deadline(100);
+        for _ in 0..10 {
+            engine.increment_epoch();
+        }
+    }
+
+    // The store still has two ticks left, so a call bumping the epoch once
+    // completes.
+    f.call_async(&mut store, &[], &mut []).await.unwrap();
+}
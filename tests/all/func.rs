@@ -750,6 +750,42 @@ fn call_wrapped_func() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn call_buffered() -> Result<()> {
+    let mut store = Store::<()>::default();
+    let f = Func::wrap(&mut store, |a: i32, b: i64, r: Option<ExternRef>| {
+        (a + 1, b * 2, r)
+    });
+
+    let mut buffer = f.call_buffer(&store);
+    assert_eq!(buffer.params().len(), 3);
+    assert_eq!(buffer.results().len(), 3);
+    assert_eq!(buffer.params()[0].unwrap_i32(), 0);
+    assert!(buffer.params()[2].unwrap_externref().is_none());
+
+    for i in 0..3 {
+        let r = ExternRef::new(i);
+        buffer.params_mut()[0] = Val::I32(i);
+        buffer.params_mut()[1] = Val::I64(i.into());
+        buffer.params_mut()[2] = Val::ExternRef(Some(r.clone()));
+        f.call_buffered(&mut store, &mut buffer)?;
+        assert_eq!(buffer.results()[0].unwrap_i32(), i + 1);
+        assert_eq!(buffer.results()[1].unwrap_i64(), i64::from(i) * 2);
+        assert!(buffer.results()[2].unwrap_externref().unwrap().ptr_eq(&r));
+    }
+
+    // Parameters are still type-checked against the cached signature.
+    buffer.params_mut()[0] = Val::I64(0);
+    let err = f.call_buffered(&mut store, &mut buffer).unwrap_err();
+    assert!(err.to_string().contains("argument type mismatch"), "{err}");
+
+    // A buffer can only be used with the function it was created for.
+    let g = Func::wrap(&mut store, |a: i32, b: i64, r: Option<ExternRef>| (a, b, r));
+    let err = g.call_buffered(&mut store, &mut buffer).unwrap_err();
+    assert!(err.to_string().contains("different function"), "{err}");
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn caller_memory() -> anyhow::Result<()> {
@@ -1183,6 +1219,129 @@ fn wrap_multiple_results() -> anyhow::Result<()> {
     }
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn wrap_many_results() -> anyhow::Result<()> {
+    let mut store = Store::<()>::default();
+    type Results = (
+        i32,
+        i64,
+        f32,
+        f64,
+        i32,
+        i64,
+        f32,
+        f64,
+        i32,
+        i64,
+        f32,
+        f64,
+        i32,
+        i64,
+        f32,
+        f64,
+        i32,
+        i64,
+        f32,
+        f64,
+    );
+    let expected: Results = (
+        1, 2, 3., 4., 5, 6, 7., 8., 9, 10, 11., 12., 13, 14, 15., 16., 17, 18, 19., 20.,
+    );
+    // Tuples this long don't implement `PartialEq`, so compare as floats.
+    let values = |r: Results| {
+        [
+            r.0 as f64, r.1 as f64, r.2 as f64, r.3, r.4 as f64, r.5 as f64, r.6 as f64, r.7,
+            r.8 as f64, r.9 as f64, r.10 as f64, r.11, r.12 as f64, r.13 as f64, r.14 as f64,
+            r.15, r.16 as f64, r.17 as f64, r.18 as f64, r.19,
+        ]
+    };
+    let f = Func::wrap(&mut store, move || -> Result<Results> { Ok(expected) });
+    assert_eq!(f.ty(&store).results().len(), 20);
+    assert_eq!(
+        values(f.typed::<(), Results>(&store)?.call(&mut store, ())?),
+        values(expected)
+    );
+
+    let module = Module::new(
+        store.engine(),
+        r#"
+            (module
+                (type $t (func (result i32 i64 f32 f64 i32 i64 f32 f64 i32 i64
+                                       f32 f64 i32 i64 f32 f64 i32 i64 f32 f64)))
+                (import "" "" (func $host (type $t)))
+                (func (export "foo") (type $t)
+                    call $host))
+        "#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[f.into()])?;
+    let foo = instance.get_typed_func::<(), Results>(&mut store, "foo")?;
+    assert_eq!(values(foo.call(&mut store, ())?), values(expected));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn derived_wasm_results() -> anyhow::Result<()> {
+    #[derive(WasmResults)]
+    struct Named {
+        a: i32,
+        b: f64,
+        c: Option<ExternRef>,
+    }
+
+    #[derive(WasmResults, Debug, PartialEq, Clone, Copy)]
+    struct Unnamed(i64, f32);
+
+    let mut store = Store::<()>::default();
+    let named = Func::wrap(&mut store, |a: i32| Named {
+        a: a + 1,
+        b: 2.5,
+        c: None,
+    });
+    let unnamed = Func::wrap(&mut store, |x: i64| -> Result<Unnamed> {
+        if x < 0 {
+            bail!("negative");
+        }
+        Ok(Unnamed(x * 2, 0.5))
+    });
+    assert_eq!(
+        named.ty(&store).results().collect::<Vec<_>>(),
+        [ValType::I32, ValType::F64, ValType::ExternRef]
+    );
+
+    let module = Module::new(
+        store.engine(),
+        r#"
+            (module
+                (import "" "named" (func $named (param i32) (result i32 f64 externref)))
+                (import "" "unnamed" (func $unnamed (param i64) (result i64 f32)))
+                (func (export "named") (param i32) (result i32 f64 externref)
+                    local.get 0
+                    call $named)
+                (func (export "unnamed") (param i64) (result i64 f32)
+                    local.get 0
+                    call $unnamed))
+        "#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[named.into(), unnamed.into()])?;
+
+    let named = instance.get_typed_func::<i32, Named>(&mut store, "named")?;
+    let result = named.call(&mut store, 41)?;
+    assert_eq!((result.a, result.b), (42, 2.5));
+    assert!(result.c.is_none());
+
+    let unnamed = instance.get_typed_func::<i64, Unnamed>(&mut store, "unnamed")?;
+    assert_eq!(unnamed.call(&mut store, 21)?, Unnamed(42, 0.5));
+    assert!(unnamed.call(&mut store, -1).is_err());
+
+    // Mismatched result types are rejected by the typecheck.
+    assert!(instance
+        .get_typed_func::<i64, Named>(&mut store, "unnamed")
+        .is_err());
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn trampoline_for_declared_elem() -> anyhow::Result<()> {
@@ -122,6 +122,117 @@ fn wasm_dropping_refs() -> anyhow::Result<()> {
     }
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn incremental_release_budget() -> anyhow::Result<()> {
+    let (mut store, module) = ref_types_module(
+        false,
+        r#"
+            (module
+                (func (export "drop_ref") (param externref)
+                    nop
+                )
+            )
+        "#,
+    )?;
+
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let drop_ref = instance.get_func(&mut store, "drop_ref").unwrap();
+
+    let num_refs_dropped = Arc::new(AtomicUsize::new(0));
+    store.gc_release_budget(Some(10));
+
+    for _ in 0..100 {
+        let r = ExternRef::new(CountDrops(num_refs_dropped.clone()));
+        let args = [Val::ExternRef(Some(r))];
+        drop_ref.call(&mut store, &args, &mut [])?;
+    }
+    assert_eq!(num_refs_dropped.load(SeqCst), 0);
+
+    // A GC only releases up to the budget...
+    store.gc();
+    assert_eq!(num_refs_dropped.load(SeqCst), 10);
+
+    // ...and the rest is released incrementally.
+    let mut steps = 0;
+    while store.gc_incremental_step() {
+        steps += 1;
+        assert_eq!(num_refs_dropped.load(SeqCst), 10 + steps * 10);
+    }
+    assert_eq!(num_refs_dropped.load(SeqCst), 100);
+
+    // Removing the budget makes GC release everything eagerly again.
+    store.gc_release_budget(None);
+    for _ in 0..100 {
+        let r = ExternRef::new(CountDrops(num_refs_dropped.clone()));
+        let args = [Val::ExternRef(Some(r))];
+        drop_ref.call(&mut store, &args, &mut [])?;
+    }
+    store.gc();
+    assert_eq!(num_refs_dropped.load(SeqCst), 200);
+    assert!(!store.gc_incremental_step());
+
+    return Ok(());
+
+    struct CountDrops(Arc<AtomicUsize>);
+
+    impl Drop for CountDrops {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, SeqCst);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn zero_release_budget_does_not_leak() -> anyhow::Result<()> {
+    let (mut store, module) = ref_types_module(
+        false,
+        r#"
+            (module
+                (func (export "drop_ref") (param externref)
+                    nop
+                )
+            )
+        "#,
+    )?;
+
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let drop_ref = instance.get_func(&mut store, "drop_ref").unwrap();
+
+    let num_refs_dropped = Arc::new(AtomicUsize::new(0));
+    store.gc_release_budget(Some(0));
+
+    // Even though no GC is allowed to release anything, the number of
+    // references left pending release stays bounded.
+    let total = 10_000;
+    for _ in 0..total {
+        let r = ExternRef::new(CountDrops(num_refs_dropped.clone()));
+        let args = [Val::ExternRef(Some(r))];
+        drop_ref.call(&mut store, &args, &mut [])?;
+    }
+    store.gc();
+    let dropped = num_refs_dropped.load(SeqCst);
+    assert_eq!(
+        total - dropped,
+        wasmtime_runtime::VMExternRefActivationsTable::MAX_PENDING_RELEASE
+    );
+
+    // And incremental steps still make progress with a zero budget.
+    while store.gc_incremental_step() {}
+    assert_eq!(num_refs_dropped.load(SeqCst), total);
+
+    return Ok(());
+
+    struct CountDrops(Arc<AtomicUsize>);
+
+    impl Drop for CountDrops {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, SeqCst);
+        }
+    }
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn many_live_refs() -> anyhow::Result<()> {
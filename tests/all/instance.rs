@@ -78,3 +78,99 @@ fn linear_memory_limits() -> Result<()> {
         Ok(())
     }
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn max_concurrent_instantiations() -> Result<()> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    fn wait(gate: &AtomicBool) {
+        while !gate.load(Ordering::SeqCst) {
+            std::thread::yield_now();
+        }
+    }
+
+    // Blocks allocation of the instance's memory until the gate opens.
+    struct Gated(Arc<AtomicBool>);
+
+    impl ResourceLimiter for Gated {
+        fn memory_growing(&mut self, _: usize, _: usize, _: Option<usize>) -> Result<bool> {
+            wait(&self.0);
+            Ok(true)
+        }
+
+        fn table_growing(&mut self, _: u32, _: u32, _: Option<u32>) -> Result<bool> {
+            Ok(true)
+        }
+    }
+
+    let mut config = Config::new();
+    config.max_concurrent_instantiations(Some(1));
+    let engine = Engine::new(&config)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (import "" "wait" (func $wait))
+                (memory 1)
+                (start $wait))
+        "#,
+    )?;
+
+    let allocate = Arc::new(AtomicBool::new(false));
+    let start = Arc::new(AtomicBool::new(false));
+    let mut linker = Linker::new(&engine);
+    linker.func_wrap("", "wait", {
+        let start = start.clone();
+        move || wait(&start)
+    })?;
+    let linker = Arc::new(linker);
+
+    let spawn = |tenant| {
+        let (engine, module, linker) = (engine.clone(), module.clone(), linker.clone());
+        let allocate = allocate.clone();
+        std::thread::spawn(move || -> Result<()> {
+            let mut store = Store::new(&engine, Gated(allocate));
+            store.limiter(|gated| gated);
+            store.set_admission_tenant(tenant);
+            linker.instantiate(&mut store, &module)?;
+            Ok(())
+        })
+    };
+
+    // The first instantiation holds the only slot while allocating, so the
+    // second one has to queue.
+    let first = spawn(1);
+    while engine.admission_stats().unwrap().active == 0 {
+        std::thread::yield_now();
+    }
+    let second = spawn(2);
+    while engine.admission_stats().unwrap().waiting == 0 {
+        std::thread::yield_now();
+    }
+    allocate.store(true, Ordering::SeqCst);
+
+    // Start functions don't hold a slot, so both instantiations are
+    // admitted and finish allocating while the first start function is
+    // still blocked.
+    while engine.admission_stats().unwrap().instantiations < 2
+        || engine.admission_stats().unwrap().active > 0
+    {
+        std::thread::yield_now();
+    }
+    start.store(true, Ordering::SeqCst);
+    first.join().unwrap()?;
+    second.join().unwrap()?;
+
+    let stats = engine.admission_stats().unwrap();
+    assert_eq!(stats.compilations, 1);
+    assert_eq!(stats.instantiations, 2);
+    assert_eq!(stats.queued, 1);
+    assert!(stats.max_queue_time > std::time::Duration::ZERO);
+    assert_eq!(stats.active, 0);
+    assert_eq!(stats.waiting, 0);
+
+    assert!(Engine::default().admission_stats().is_none());
+    Ok(())
+}
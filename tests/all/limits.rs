@@ -239,6 +239,69 @@ fn test_limits_memory_only() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_limits_memory_soft_limit() -> Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+    use std::sync::Arc;
+
+    let engine = Engine::default();
+    let module = Module::new(
+        &engine,
+        r#"(module
+            (memory (export "m") 0)
+            (func (export "grow") (param i32) (result i32)
+              (memory.grow (local.get 0)))
+           )"#,
+    )?;
+
+    // Approve the first growth past the soft limit, deny the second, and trap
+    // on any after that.
+    let calls = Arc::new(AtomicUsize::new(0));
+    let limits = StoreLimitsBuilder::new()
+        .memory_size(10 * WASM_PAGE_SIZE)
+        .memory_soft_limit(4 * WASM_PAGE_SIZE, {
+            let calls = calls.clone();
+            move |current, desired| {
+                assert!(desired > 4 * WASM_PAGE_SIZE);
+                assert!(current < desired);
+                match calls.fetch_add(1, SeqCst) {
+                    0 => Ok(true),
+                    1 => Ok(false),
+                    _ => anyhow::bail!("memory pressure"),
+                }
+            }
+        })
+        .build();
+    let mut store = Store::new(&engine, limits);
+    store.limiter(|s| s as &mut dyn ResourceLimiter);
+
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let memory = instance.get_memory(&mut store, "m").unwrap();
+    let grow = instance.get_typed_func::<i32, i32>(&mut store, "grow")?;
+
+    // Growth up to the soft limit doesn't consult the callback.
+    assert_eq!(grow.call(&mut store, 4)?, 0);
+    assert_eq!(calls.load(SeqCst), 0);
+
+    assert_eq!(grow.call(&mut store, 2)?, 4);
+    assert_eq!(calls.load(SeqCst), 1);
+
+    assert_eq!(grow.call(&mut store, 1)?, -1);
+    assert_eq!(calls.load(SeqCst), 2);
+    assert_eq!(memory.size(&store), 6);
+
+    // Growth beyond the hard limit is denied without asking the callback.
+    assert_eq!(grow.call(&mut store, 5)?, -1);
+    assert_eq!(calls.load(SeqCst), 2);
+
+    let err = grow.call(&mut store, 1).unwrap_err();
+    assert!(format!("{err:?}").contains("memory pressure"), "{err:?}");
+    assert_eq!(calls.load(SeqCst), 3);
+    assert_eq!(memory.size(&store), 6);
+
+    Ok(())
+}
+
 #[test]
 fn test_initial_memory_limits_exceeded() -> Result<()> {
     let engine = Engine::default();
@@ -122,6 +122,80 @@ fn function_interposition() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn function_intercept() -> Result<()> {
+    let mut store = Store::<()>::default();
+    let mut linker = Linker::new(store.engine());
+    linker.func_wrap("host", "add", |a: i32, b: i32| a + b)?;
+
+    // Modify the arguments and the result of the original function.
+    linker.func_intercept("host", "add", |mut caller, original, params, results| {
+        let params = [Val::I32(params[0].unwrap_i32() * 10), params[1].clone()];
+        original.call(&mut caller, &params, results)?;
+        results[0] = Val::I32(results[0].unwrap_i32() + 1);
+        Ok(())
+    })?;
+    // Layer a second interceptor which short-circuits some calls.
+    linker.func_intercept("host", "add", |mut caller, original, params, results| {
+        if params[1].unwrap_i32() == 0 {
+            results[0] = Val::I32(-1);
+            return Ok(());
+        }
+        original.call(&mut caller, params, results)
+    })?;
+
+    assert!(linker
+        .func_intercept("host", "missing", |_, _, _, _| Ok(()))
+        .is_err());
+
+    let module = Module::new(
+        store.engine(),
+        r#"(module
+            (import "host" "add" (func $add (param i32 i32) (result i32)))
+            (func (export "run") (param i32 i32) (result i32)
+                (call $add (local.get 0) (local.get 1)))
+        )"#,
+    )?;
+    let instance = linker.instantiate(&mut store, &module)?;
+    let run = instance.get_typed_func::<(i32, i32), i32>(&mut store, "run")?;
+    assert_eq!(run.call(&mut store, (2, 3))?, 24);
+    assert_eq!(run.call(&mut store, (2, 0))?, -1);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn function_intercept_runs_host_call_hooks() -> Result<()> {
+    let mut store = Store::new(&Engine::default(), 0);
+    store.host_call_hook("host", Some("add"), |calls, _| {
+        *calls += 1;
+        Ok(())
+    });
+    let mut linker = Linker::new(store.engine());
+    linker.func_wrap("host", "add", |a: i32, b: i32| a + b)?;
+    linker.func_intercept("host", "add", |mut caller, original, params, results| {
+        original.call(&mut caller, params, results)
+    })?;
+
+    let module = Module::new(
+        store.engine(),
+        r#"(module
+            (import "host" "add" (func $add (param i32 i32) (result i32)))
+            (func (export "run") (param i32 i32) (result i32)
+                (call $add (local.get 0) (local.get 1)))
+        )"#,
+    )?;
+    let instance = linker.instantiate(&mut store, &module)?;
+    let run = instance.get_typed_func::<(i32, i32), i32>(&mut store, "run")?;
+    for _ in 0..10 {
+        assert_eq!(run.call(&mut store, (2, 3))?, 5);
+    }
+    // The hooks run once per call, when the interceptor forwards it.
+    assert_eq!(*store.data(), 10);
+    Ok(())
+}
+
 // Same as `function_interposition`, but the linker's name for the function
 // differs from the module's name.
 #[test]
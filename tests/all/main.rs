@@ -1,11 +1,15 @@
 #![cfg_attr(miri, allow(dead_code, unused_imports))]
 
+mod allocation_profiler;
 mod async_functions;
+mod block_trace;
 mod call_hook;
+mod capabilities;
 mod cli_tests;
 mod code_too_large;
 mod component_model;
 mod coredump;
+mod coverage;
 mod custom_signal_handler;
 mod debug;
 mod epoch_interruption;
@@ -25,11 +29,15 @@ mod limits;
 mod linker;
 mod memory;
 mod memory_creator;
+mod memory_trace;
 mod module;
 mod module_serialize;
+mod module_verification;
 mod name;
 mod pooling_allocator;
 mod relocs;
+mod shutdown;
+mod snapshot;
 mod stack_creator;
 mod stack_overflow;
 mod store;
@@ -0,0 +1,134 @@
+#![cfg(not(miri))]
+
+use anyhow::{bail, Result};
+use std::sync::{Arc, Mutex};
+use wasmtime::*;
+
+const WAT: &str = r#"
+    (module
+        (memory 1)
+        (func (export "run") (param i32)
+            (i32.store (local.get 0) (i32.const 1))
+            (drop (i64.load offset=8 (local.get 0)))
+            (drop (i32.load8_u (i32.const 3))))
+    )
+"#;
+
+fn trace(config: &Config) -> Result<(Store<()>, Func, Arc<Mutex<Vec<MemoryAccess>>>)> {
+    let engine = Engine::new(config)?;
+    let module = Module::new(&engine, WAT)?;
+    let mut store = Store::new(&engine, ());
+    let accesses = Arc::new(Mutex::new(Vec::new()));
+    let recorded = accesses.clone();
+    store.memory_trace(move |_, access| {
+        recorded.lock().unwrap().push(access);
+        Ok(())
+    });
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let run = instance.get_func(&mut store, "run").unwrap();
+    Ok((store, run, accesses))
+}
+
+#[test]
+fn reports_loads_and_stores() -> Result<()> {
+    let mut config = Config::new();
+    config.memory_trace(true);
+    let (mut store, run, accesses) = trace(&config)?;
+    run.call(&mut store, &[Val::I32(16)], &mut [])?;
+
+    let accesses = accesses.lock().unwrap();
+    let summary = accesses
+        .iter()
+        .map(|a| (a.kind(), a.address(), a.size()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            (MemoryAccessKind::Store, 16, 4),
+            (MemoryAccessKind::Load, 24, 8),
+            (MemoryAccessKind::Load, 3, 1),
+        ]
+    );
+    assert!(accesses[0].wasm_offset() < accesses[1].wasm_offset());
+    assert!(accesses[1].wasm_offset() < accesses[2].wasm_offset());
+    Ok(())
+}
+
+#[test]
+fn hook_errors_trap() -> Result<()> {
+    let mut config = Config::new();
+    config.memory_trace(true);
+    let engine = Engine::new(&config)?;
+    let module = Module::new(&engine, WAT)?;
+    let mut store = Store::new(&engine, ());
+    store.memory_trace(|_, access| {
+        if access.kind() == MemoryAccessKind::Load {
+            bail!("no loads allowed");
+        }
+        Ok(())
+    });
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let run = instance.get_typed_func::<i32, ()>(&mut store, "run")?;
+    let err = run.call(&mut store, 0).unwrap_err();
+    assert!(format!("{err:?}").contains("no loads allowed"), "{err:?}");
+    Ok(())
+}
+
+#[test]
+fn disabled_by_default() -> Result<()> {
+    let (mut store, run, accesses) = trace(&Config::new())?;
+    run.call(&mut store, &[Val::I32(16)], &mut [])?;
+    assert!(accesses.lock().unwrap().is_empty());
+    Ok(())
+}
+
+#[test]
+fn reports_simd_atomic_and_memory_index() -> Result<()> {
+    let mut config = Config::new();
+    config.memory_trace(true);
+    config.wasm_multi_memory(true);
+    config.wasm_threads(true);
+    let engine = Engine::new(&config)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (memory 1)
+                (memory $other 1)
+                (func (export "run")
+                    (drop (v128.load (i32.const 16)))
+                    (drop (v128.load16x4_s $other (i32.const 32)))
+                    (drop (i32.atomic.rmw.add $other (i32.const 8) (i32.const 1)))
+                    (i64.atomic.store (i32.const 0) (i64.const 1)))
+            )
+        "#,
+    )?;
+    let mut store = Store::new(&engine, ());
+    let accesses = Arc::new(Mutex::new(Vec::new()));
+    let recorded = accesses.clone();
+    store.memory_trace(move |_, access| {
+        recorded.lock().unwrap().push(access);
+        Ok(())
+    });
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+    run.call(&mut store, ())?;
+
+    let summary = accesses
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|a| (a.memory(), a.kind(), a.address(), a.size()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            (0, MemoryAccessKind::Load, 16, 16),
+            (1, MemoryAccessKind::Load, 32, 8),
+            (1, MemoryAccessKind::Load, 8, 4),
+            (1, MemoryAccessKind::Store, 8, 4),
+            (0, MemoryAccessKind::Store, 0, 8),
+        ]
+    );
+    Ok(())
+}
@@ -217,6 +217,55 @@ fn missing_sse_and_floats_still_works() -> Result<()> {
     Ok(())
 }
 
+// Test that atomics compiled to check for LSE support at runtime, instead of
+// relying on it being enabled at compile time, resolve the feature check and
+// work whether or not the host has LSE.
+#[test]
+#[cfg_attr(any(not(target_arch = "aarch64"), miri), ignore)]
+fn outline_atomics_still_work() -> Result<()> {
+    let mut config = Config::new();
+    config.wasm_threads(true);
+    unsafe {
+        config.cranelift_flag_set("has_lse", "false");
+        config.cranelift_flag_enable("outline_atomics");
+    }
+    let engine = Engine::new(&config)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (memory 1 1 shared)
+                (func (export "add") (param i32) (result i32)
+                    i32.const 0
+                    local.get 0
+                    i32.atomic.rmw.add)
+                (func (export "cmpxchg") (param i64 i64) (result i64)
+                    i32.const 8
+                    local.get 0
+                    local.get 1
+                    i64.atomic.rmw.cmpxchg)
+            )
+        "#,
+    )?;
+    let bytes = module.serialize()?;
+    let module2 = unsafe { Module::deserialize(&engine, &bytes)? };
+
+    for module in [module, module2] {
+        let mut store = Store::new(&engine, ());
+        let instance = Instance::new(&mut store, &module, &[])?;
+        let add = instance.get_typed_func::<i32, i32>(&mut store, "add")?;
+        let cmpxchg = instance.get_typed_func::<(i64, i64), i64>(&mut store, "cmpxchg")?;
+
+        assert_eq!(add.call(&mut store, 3)?, 0);
+        assert_eq!(add.call(&mut store, 4)?, 3);
+        assert_eq!(cmpxchg.call(&mut store, (1, 5))?, 0);
+        assert_eq!(cmpxchg.call(&mut store, (0, 5))?, 0);
+        assert_eq!(cmpxchg.call(&mut store, (5, 6))?, 5);
+    }
+
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn large_add_chain_no_stack_overflow() -> Result<()> {
@@ -239,3 +288,274 @@ fn large_add_chain_no_stack_overflow() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn validate_reports_required_features() -> Result<()> {
+    let engine = Engine::default();
+
+    let report = engine.validate(b"(module (func (param i32) (result i32) local.get 0))")?;
+    assert!(!report.is_component());
+    assert!(report.required_features().is_empty());
+
+    let report = engine.validate(
+        br#"
+            (module
+                (func (param v128) (result externref)
+                    ref.null extern)
+                (func (result i32 i32)
+                    i32.const 0
+                    i32.const 1))
+        "#,
+    )?;
+    assert!(report.requires("simd"));
+    assert!(report.requires("reference-types"));
+    assert!(report.requires("multi-value"));
+    assert!(!report.requires("threads"));
+
+    let mut config = Config::new();
+    config.wasm_relaxed_simd(false);
+    config.wasm_simd(false);
+    let engine = Engine::new(&config)?;
+    assert!(engine.validate(b"(module (func (param v128)))").is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn artifact_cache_store() -> Result<()> {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Default)]
+    struct MemoryCacheStore {
+        entries: Mutex<HashMap<Vec<u8>, Vec<u8>>>,
+        hits: AtomicUsize,
+    }
+
+    impl CacheStore for MemoryCacheStore {
+        fn get(&self, key: &[u8]) -> Option<Cow<[u8]>> {
+            let value = self.entries.lock().unwrap().get(key).cloned()?;
+            self.hits.fetch_add(1, SeqCst);
+            Some(value.into())
+        }
+
+        fn insert(&self, key: &[u8], value: Vec<u8>) -> bool {
+            self.entries.lock().unwrap().insert(key.to_vec(), value);
+            true
+        }
+    }
+
+    let store = Arc::new(MemoryCacheStore::default());
+    let wat = "(module (func (export \"f\") (result i32) i32.const 42))";
+
+    // Two engines with the same settings share compiled artifacts.
+    for _ in 0..2 {
+        let mut config = Config::new();
+        config.artifact_cache_store(store.clone());
+        let engine = Engine::new(&config)?;
+        let module = Module::new(&engine, wat)?;
+        let mut wasm_store = Store::new(&engine, ());
+        let instance = Instance::new(&mut wasm_store, &module, &[])?;
+        let f = instance.get_typed_func::<(), i32>(&mut wasm_store, "f")?;
+        assert_eq!(f.call(&mut wasm_store, ())?, 42);
+    }
+    assert_eq!(store.entries.lock().unwrap().len(), 1);
+    assert_eq!(store.hits.load(SeqCst), 1);
+
+    // Different compilation settings use a different key.
+    let mut config = Config::new();
+    config.artifact_cache_store(store.clone());
+    config.cranelift_opt_level(OptLevel::None);
+    Module::new(&Engine::new(&config)?, wat)?;
+    assert_eq!(store.entries.lock().unwrap().len(), 2);
+    assert_eq!(store.hits.load(SeqCst), 1);
+
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn compilation_executor() -> Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+    use std::sync::Arc;
+
+    struct CountingExecutor {
+        tasks: AtomicUsize,
+        inner: Box<dyn CompilationExecutor>,
+    }
+
+    impl CompilationExecutor for CountingExecutor {
+        fn execute<'a>(&self, tasks: Vec<CompilationTask<'a>>) {
+            self.tasks.fetch_add(tasks.len(), SeqCst);
+            self.inner.execute(tasks);
+        }
+    }
+
+    let wat = r#"
+        (module
+            (func (export "a") (result i32) i32.const 1)
+            (func (export "b") (result i32) i32.const 2)
+            (func (export "c") (result i32) i32.const 3))
+    "#;
+
+    let mut serialized = Vec::new();
+    let mut threads = ScopedThreadsExecutor::new(2);
+    let started = Arc::new(AtomicUsize::new(0));
+    let started2 = started.clone();
+    threads.on_thread_start(move || {
+        started2.fetch_add(1, SeqCst);
+    });
+    let executors: Vec<Box<dyn CompilationExecutor>> =
+        vec![Box::new(SerialExecutor), Box::new(threads)];
+    for inner in executors {
+        let executor = Arc::new(CountingExecutor {
+            tasks: AtomicUsize::new(0),
+            inner,
+        });
+        let mut config = Config::new();
+        config.compilation_executor(executor.clone());
+        let engine = Engine::new(&config)?;
+        let module = Module::new(&engine, wat)?;
+        assert!(executor.tasks.load(SeqCst) > 0);
+
+        let mut store = Store::new(&engine, ());
+        let instance = Instance::new(&mut store, &module, &[])?;
+        let c = instance.get_typed_func::<(), i32>(&mut store, "c")?;
+        assert_eq!(c.call(&mut store, ())?, 3);
+        serialized.push(module.serialize()?);
+    }
+    assert!(started.load(SeqCst) > 0);
+    assert_eq!(serialized[0], serialized[1]);
+
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn compile_monitor_progress_and_cancellation() -> Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    let wat = r#"
+        (module
+            (func (export "a") (result i32) i32.const 1)
+            (func (export "b") (result i32) i32.const 2))
+    "#;
+    let engine = Engine::default();
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let reports2 = reports.clone();
+    let monitor = CompileMonitor::with_progress(move |p| reports2.lock().unwrap().push(p));
+    let background = engine.compile_module_in_background(wat, monitor.clone());
+    let module = background.wait()?;
+    assert_eq!(module.exports().len(), 2);
+
+    let progress = monitor.progress();
+    assert!(progress.total > 0);
+    assert_eq!(progress.completed, progress.total);
+    assert_eq!(reports.lock().unwrap().last(), Some(&progress));
+
+    // A cancelled monitor fails compilation.
+    let monitor = CompileMonitor::new();
+    monitor.cancel();
+    let err =
+        Module::from_binary_with_monitor(&engine, &wat::parse_str(wat)?, &monitor).unwrap_err();
+    assert!(err.is::<CompilationCancelled>(), "{err:?}");
+    let err = engine
+        .compile_module_in_background(wat, monitor)
+        .wait()
+        .unwrap_err();
+    assert!(err.is::<CompilationCancelled>(), "{err:?}");
+
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn compile_time_budget_falls_back_to_unoptimized() -> Result<()> {
+    // A zero budget makes every function fall back to an unoptimized
+    // compilation, which must still produce working code.
+    let mut config = Config::new();
+    config.cranelift_compile_time_budget(Some(std::time::Duration::ZERO));
+    let engine = Engine::new(&config)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (func (export "sum") (param i32) (result i32)
+                    (local i32)
+                    (block
+                        (loop
+                            (br_if 1 (i32.eqz (local.get 0)))
+                            (local.set 1 (i32.add (local.get 1) (local.get 0)))
+                            (local.set 0 (i32.sub (local.get 0) (i32.const 1)))
+                            (br 0)))
+                    local.get 1))
+        "#,
+    )?;
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let sum = instance.get_typed_func::<i32, i32>(&mut store, "sum")?;
+    assert_eq!(sum.call(&mut store, 10)?, 55);
+    assert_eq!(
+        module.functions_over_compile_budget().collect::<Vec<_>>(),
+        [0]
+    );
+
+    // With a generous budget nothing falls back.
+    let mut config = Config::new();
+    config.cranelift_compile_time_budget(Some(std::time::Duration::from_secs(3600)));
+    let engine = Engine::new(&config)?;
+    let module = Module::new(&engine, "(module (func))")?;
+    assert_eq!(module.functions_over_compile_budget().count(), 0);
+    Ok(())
+}
+
+// Test that code copied into `MAP_JIT` memory on macOS runs, including
+// relocated libcalls, traps and host calls. Elsewhere the option does nothing.
+#[test]
+#[cfg_attr(miri, ignore)]
+fn macos_use_map_jit() -> Result<()> {
+    let mut config = Config::new();
+    config.macos_use_map_jit(true);
+    if cfg!(target_arch = "x86_64") {
+        // Force `f32.ceil` to be a libcall, which needs a relocation.
+        config.wasm_simd(false).wasm_relaxed_simd(false);
+        unsafe {
+            config.cranelift_flag_set("has_sse41", "false");
+        }
+    }
+    let engine = Engine::new(&config)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (import "" "host" (func $host (param i32) (result i32)))
+                (func (export "ceil") (param f32) (result f32)
+                    (f32.ceil (local.get 0)))
+                (func (export "call-host") (param i32) (result i32)
+                    (call $host (local.get 0)))
+                (func (export "trap") unreachable)
+            )
+        "#,
+    )?;
+    let bytes = module.serialize()?;
+    let module2 = unsafe { Module::deserialize(&engine, &bytes)? };
+
+    for module in [module, module2] {
+        let mut store = Store::new(&engine, ());
+        let host = Func::wrap(&mut store, |x: i32| x + 1);
+        let instance = Instance::new(&mut store, &module, &[host.into()])?;
+        let ceil = instance.get_typed_func::<f32, f32>(&mut store, "ceil")?;
+        assert_eq!(ceil.call(&mut store, 2.3)?, 3.0);
+        let call_host = instance.get_typed_func::<i32, i32>(&mut store, "call-host")?;
+        assert_eq!(call_host.call(&mut store, 41)?, 42);
+        let trap = instance.get_typed_func::<(), ()>(&mut store, "trap")?;
+        let err = trap.call(&mut store, ()).unwrap_err();
+        assert_eq!(err.downcast::<Trap>()?, Trap::UnreachableCodeReached);
+    }
+    Ok(())
+}
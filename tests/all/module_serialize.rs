@@ -136,3 +136,50 @@ fn detect_precompiled() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn compressed_artifacts() -> Result<()> {
+    let mut wat = String::from("(module\n");
+    for i in 0..100 {
+        wat.push_str(&format!(
+            "(func $f{i} (export \"f{i}\") (param i32) (result i32)
+                local.get 0
+                i32.const {i}
+                i32.add
+                i32.const 3
+                i32.mul)\n"
+        ));
+    }
+    wat.push_str("(func $trap (export \"trap\") unreachable))");
+
+    let mut config = Config::new();
+    config.compress_artifacts(true);
+    let compressed_engine = Engine::new(&config)?;
+    let compressed = serialize(&compressed_engine, &wat)?;
+    let uncompressed = serialize(&Engine::default(), &wat)?;
+    assert!(compressed.len() < uncompressed.len());
+
+    // Compressed artifacts can be loaded into engines regardless of whether
+    // they themselves compress artifacts, and vice versa.
+    for (engine, buffer) in [
+        (Engine::default(), &compressed),
+        (compressed_engine.clone(), &compressed),
+        (compressed_engine, &uncompressed),
+    ] {
+        let mut store = Store::new(&engine, ());
+        let instance = unsafe { deserialize_and_instantiate(&mut store, buffer)? };
+        let f = instance.get_typed_func::<i32, i32>(&mut store, "f7")?;
+        assert_eq!(f.call(&mut store, 1)?, 24);
+
+        // The address map and function names are still available for
+        // backtraces.
+        let trap = instance.get_typed_func::<(), ()>(&mut store, "trap")?;
+        let err = trap.call(&mut store, ()).unwrap_err();
+        let trace = err.downcast_ref::<WasmBacktrace>().unwrap();
+        assert_eq!(trace.frames().len(), 1);
+        assert_eq!(trace.frames()[0].func_name(), Some("trap"));
+        assert!(trace.frames()[0].module_offset().is_some());
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+#![cfg(not(miri))]
+
+use anyhow::Result;
+use std::sync::{Arc, Mutex};
+use wasmtime::*;
+
+const SEED: [u8; 32] = [1; 32];
+
+fn verifying_engine(verifier: impl ModuleVerifier + 'static) -> Result<Engine> {
+    let mut config = Config::new();
+    config.module_verifier(Arc::new(verifier));
+    Engine::new(&config)
+}
+
+fn ed25519_engine(signer: &Ed25519Signer) -> Result<Engine> {
+    let mut verifier = Ed25519Verifier::new();
+    verifier.trust(signer.public_key());
+    verifying_engine(verifier)
+}
+
+#[test]
+fn signed_modules_are_accepted() -> Result<()> {
+    let signer = Ed25519Signer::from_seed(&SEED)?;
+    let engine = ed25519_engine(&signer)?;
+    let wasm = wat::parse_str(r#"(module (func (export "f") (result i32) i32.const 1))"#)?;
+    let module = Module::new(&engine, &signer.sign(&wasm)?)?;
+
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let f = instance.get_typed_func::<(), i32>(&mut store, "f")?;
+    assert_eq!(f.call(&mut store, ())?, 1);
+    Ok(())
+}
+
+#[test]
+fn unsigned_and_tampered_modules_are_rejected() -> Result<()> {
+    let signer = Ed25519Signer::from_seed(&SEED)?;
+    let engine = ed25519_engine(&signer)?;
+    let wasm = wat::parse_str("(module (func (export \"f\")))")?;
+
+    let err = Module::new(&engine, &wasm).unwrap_err();
+    assert!(err.to_string().contains("not signed"), "{err:?}");
+
+    // Swap the export name, keeping the signature of the original.
+    let mut tampered = signer.sign(&wasm)?;
+    let pos = tampered.windows(3).position(|w| w == b"\x01f\x00").unwrap();
+    tampered[pos + 1] = b'g';
+    let err = Module::new(&engine, &tampered).unwrap_err();
+    assert!(err.to_string().contains("does not match"), "{err:?}");
+
+    let other = Ed25519Signer::from_seed(&[2; 32])?;
+    assert!(Module::new(&engine, &other.sign(&wasm)?).is_err());
+    assert!(engine.precompile_module(&wasm).is_err());
+    assert!(engine.precompile_module(&signer.sign(&wasm)?).is_ok());
+    Ok(())
+}
+
+#[test]
+fn resigning_replaces_the_signature() -> Result<()> {
+    let old = Ed25519Signer::from_seed(&[2; 32])?;
+    let new = Ed25519Signer::from_seed(&SEED)?;
+    let engine = ed25519_engine(&new)?;
+    let wasm = wat::parse_str("(module)")?;
+    let signed = new.sign(&old.sign(&wasm)?)?;
+    assert_eq!(signed.len(), new.sign(&wasm)?.len());
+    Module::new(&engine, &signed)?;
+    Ok(())
+}
+
+#[test]
+fn precompiled_artifacts_follow_policy() -> Result<()> {
+    let signer = Ed25519Signer::from_seed(&SEED)?;
+    let wasm = signer.sign(&wat::parse_str("(module)")?)?;
+    let serialized = Engine::default().precompile_module(&wasm)?;
+
+    let engine = ed25519_engine(&signer)?;
+    assert!(unsafe { Module::deserialize(&engine, &serialized) }.is_err());
+
+    let mut verifier = Ed25519Verifier::new();
+    verifier.trust(signer.public_key()).allow_precompiled(true);
+    let engine = verifying_engine(verifier)?;
+    unsafe { Module::deserialize(&engine, &serialized)? };
+    Ok(())
+}
+
+#[test]
+fn custom_verifier_sees_hash_and_kind() -> Result<()> {
+    struct Record(Arc<Mutex<Vec<(VerificationKind, [u8; 32], bool)>>>);
+
+    impl ModuleVerifier for Record {
+        fn verify(&self, input: &VerificationInput<'_>) -> Result<()> {
+            self.0.lock().unwrap().push((
+                input.kind(),
+                *input.sha256(),
+                input.signature().is_some(),
+            ));
+            Ok(())
+        }
+    }
+
+    let record = Arc::new(Mutex::new(Vec::new()));
+    let engine = verifying_engine(Record(record.clone()))?;
+    let signer = Ed25519Signer::from_seed(&SEED)?;
+    let wasm = wat::parse_str("(module)")?;
+    Module::new(&engine, &wasm)?;
+    let module = Module::new(&engine, &signer.sign(&wasm)?)?;
+    let serialized = module.serialize()?;
+    unsafe { Module::deserialize(&engine, &serialized)? };
+    component::Component::new(&engine, "(component)")?;
+
+    let record = record.lock().unwrap();
+    let kinds = record.iter().map(|r| r.0).collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            VerificationKind::Module,
+            VerificationKind::Module,
+            VerificationKind::PrecompiledModule,
+            VerificationKind::Component,
+        ]
+    );
+    // The hash doesn't cover the signature section.
+    assert_eq!(record[0].1, record[1].1);
+    assert_eq!(
+        (record[0].2, record[1].2, record[2].2),
+        (false, true, false)
+    );
+    Ok(())
+}
@@ -0,0 +1,119 @@
+#![cfg(not(miri))]
+
+use anyhow::Result;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use wasmtime::*;
+
+const LOOP: &str = r#"
+    (module
+      (import "" "started" (func $started))
+      (func (export "run")
+        call $started
+        (loop $l br $l)))
+"#;
+
+#[test]
+fn shutdown_interrupts_running_stores() -> Result<()> {
+    let mut config = Config::new();
+    config.epoch_interruption(true);
+    let engine = Engine::new(&config)?;
+    let module = Module::new(&engine, LOOP)?;
+
+    let started = Arc::new(AtomicBool::new(false));
+    let thread = {
+        let engine = engine.clone();
+        let module = module.clone();
+        let started = started.clone();
+        std::thread::spawn(move || -> Result<()> {
+            let mut store = Store::new(&engine, ());
+            // A deadline which keeps being extended would never trap on its
+            // own.
+            store.set_epoch_deadline(1_000_000);
+            store.epoch_deadline_callback(|_| Ok(UpdateDeadline::Continue(1_000_000)));
+            let started = Func::wrap(&mut store, move || started.store(true, Ordering::SeqCst));
+            let instance = Instance::new(&mut store, &module, &[started.into()])?;
+            let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+            run.call(&mut store, ())
+        })
+    };
+    while !started.load(Ordering::SeqCst) {
+        std::thread::yield_now();
+    }
+
+    let shutdown = engine.initiate_shutdown();
+    assert!(engine.is_shutting_down());
+    shutdown.wait();
+    let err = thread.join().unwrap().unwrap_err();
+    assert_eq!(err.downcast::<Trap>()?, Trap::Interrupt);
+    assert!(engine.initiate_shutdown().is_complete());
+
+    // Nothing new can be instantiated or compiled.
+    let mut store = Store::new(&engine, ());
+    let started = Func::wrap(&mut store, || {});
+    let err = Instance::new(&mut store, &module, &[started.into()]).unwrap_err();
+    assert!(err.is::<EngineShutdown>(), "{err:?}");
+    let err = Module::new(&engine, "(module (func))").unwrap_err();
+    assert!(err.is::<CompilationCancelled>(), "{err:?}");
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn shutdown_interrupts_yielding_stores() -> Result<()> {
+    let mut config = Config::new();
+    config.async_support(true);
+    config.epoch_interruption(true);
+    let engine = Engine::new(&config)?;
+    let module = Module::new(&engine, LOOP)?;
+
+    let started = Arc::new(AtomicBool::new(false));
+    let task = {
+        let engine = engine.clone();
+        let started = started.clone();
+        tokio::task::spawn(async move {
+            let mut store = Store::new(&engine, ());
+            store.set_epoch_deadline(1);
+            store.epoch_deadline_async_yield_and_update(1);
+            let started = Func::wrap(&mut store, move || started.store(true, Ordering::SeqCst));
+            let instance = Instance::new_async(&mut store, &module, &[started.into()]).await?;
+            let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+            run.call_async(&mut store, ()).await
+        })
+    };
+    while !started.load(Ordering::SeqCst) {
+        tokio::task::yield_now().await;
+    }
+
+    engine.initiate_shutdown().await;
+    let err = task.await?.unwrap_err();
+    assert_eq!(err.downcast::<Trap>()?, Trap::Interrupt);
+    Ok(())
+}
+
+#[test]
+fn shutdown_only_waits_for_stores_in_wasm() -> Result<()> {
+    let engine = Engine::default();
+    let module = Module::new(&engine, r#"(module (func (export "run")))"#)?;
+
+    // Stores which have called into wasm and returned, or were dropped, don't
+    // hold up shutdown.
+    let mut idle = Store::new(&engine, ());
+    for _ in 0..100 {
+        let mut store = Store::new(&engine, ());
+        let instance = Instance::new(&mut store, &module, &[])?;
+        let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+        run.call(&mut store, ())?;
+    }
+    let instance = Instance::new(&mut idle, &module, &[])?;
+    let run = instance.get_typed_func::<(), ()>(&mut idle, "run")?;
+    run.call(&mut idle, ())?;
+
+    let shutdown = engine.initiate_shutdown();
+    assert!(shutdown.is_complete());
+    shutdown.wait();
+
+    // Calling into existing instances is still allowed.
+    run.call(&mut idle, ())?;
+    assert!(engine.initiate_shutdown().is_complete());
+    Ok(())
+}
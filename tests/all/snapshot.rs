@@ -0,0 +1,456 @@
+use anyhow::Result;
+use std::io::{Read, Write};
+use wasmtime::*;
+
+const WAT: &str = r#"
+    (module
+        (memory (export "memory") 1)
+        (global $counter (export "counter") (mut i32) (i32.const 0))
+        (func (export "step")
+            (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+            (i32.store (i32.mul (global.get $counter) (i32.const 4))
+                       (global.get $counter)))
+        (func (export "grow") (result i32)
+            (memory.grow (i32.const 1)))
+    )
+"#;
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn snapshot_and_restore() -> Result<()> {
+    let mut store = Store::<()>::default();
+    let module = Module::new(store.engine(), WAT)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let step = instance.get_typed_func::<(), ()>(&mut store, "step")?;
+    let grow = instance.get_typed_func::<(), i32>(&mut store, "grow")?;
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    let counter = instance.get_global(&mut store, "counter").unwrap();
+
+    step.call(&mut store, ())?;
+    let snapshot = instance.snapshot(&mut store)?;
+    assert_eq!(snapshot.memory_bytes(), 65536);
+
+    step.call(&mut store, ())?;
+    grow.call(&mut store, ())?;
+    memory.data_mut(&mut store)[65536] = 1;
+    assert_eq!(counter.get(&mut store).unwrap_i32(), 2);
+
+    instance.restore(&mut store, &snapshot)?;
+    assert_eq!(counter.get(&mut store).unwrap_i32(), 1);
+    assert_eq!(memory.data(&store)[4], 1);
+    assert_eq!(memory.data(&store)[8], 0);
+    // Memories can't shrink, but the grown region is cleared.
+    assert_eq!(memory.size(&store), 2);
+    assert_eq!(memory.data(&store)[65536], 0);
+
+    // Snapshots don't apply to instances of a different shape.
+    let other = Module::new(store.engine(), "(module)")?;
+    let other = Instance::new(&mut store, &other, &[])?;
+    assert!(other.restore(&mut store, &snapshot).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn timeline_rewind() -> Result<()> {
+    let mut store = Store::<()>::default();
+    let module = Module::new(store.engine(), WAT)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let step = instance.get_typed_func::<(), ()>(&mut store, "step")?;
+    let counter = instance.get_global(&mut store, "counter").unwrap();
+
+    let mut timeline = SnapshotTimeline::new(3, 2);
+    for position in 0..10 {
+        timeline.record(&mut store, &instance, position)?;
+        step.call(&mut store, ())?;
+    }
+    // Snapshots were taken at 0, 3, 6 and 9, but only the last two are
+    // retained.
+    assert_eq!(timeline.positions().collect::<Vec<_>>(), [6, 9]);
+    assert!(timeline.rewind(&mut store, &instance, 5).is_err());
+
+    // Rewinding to 8 restores the snapshot at 6; replaying two steps forward
+    // reaches the target.
+    assert_eq!(timeline.rewind(&mut store, &instance, 8)?, 6);
+    assert_eq!(counter.get(&mut store).unwrap_i32(), 6);
+    assert_eq!(timeline.positions().collect::<Vec<_>>(), [6]);
+    for _ in 6..8 {
+        step.call(&mut store, ())?;
+    }
+    assert_eq!(counter.get(&mut store).unwrap_i32(), 8);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn timeline_rewind_to_last_position() -> Result<()> {
+    let mut store = Store::<()>::default();
+    let module = Module::new(store.engine(), WAT)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let step = instance.get_typed_func::<(), ()>(&mut store, "step")?;
+    let counter = instance.get_global(&mut store, "counter").unwrap();
+
+    let mut timeline = SnapshotTimeline::new(1, 2);
+    timeline.record(&mut store, &instance, u64::MAX - 1)?;
+    step.call(&mut store, ())?;
+    timeline.record(&mut store, &instance, u64::MAX)?;
+    step.call(&mut store, ())?;
+
+    assert_eq!(timeline.rewind(&mut store, &instance, u64::MAX)?, u64::MAX);
+    assert_eq!(counter.get(&mut store).unwrap_i32(), 1);
+    assert_eq!(
+        timeline.positions().collect::<Vec<_>>(),
+        [u64::MAX - 1, u64::MAX]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn failed_restore_leaves_state_unchanged() -> Result<()> {
+    let mut store = Store::new(&Engine::default(), StoreLimits::default());
+    let module = Module::new(store.engine(), WAT)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let step = instance.get_typed_func::<(), ()>(&mut store, "step")?;
+    let grow = instance.get_typed_func::<(), i32>(&mut store, "grow")?;
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    let counter = instance.get_global(&mut store, "counter").unwrap();
+
+    // Take a snapshot of a two-page memory and then restore it into a fresh
+    // instance which isn't allowed to grow past one page.
+    grow.call(&mut store, ())?;
+    step.call(&mut store, ())?;
+    let snapshot = instance.snapshot(&mut store)?;
+
+    *store.data_mut() = StoreLimitsBuilder::new().memory_size(65536).build();
+    store.limiter(|limits| limits);
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let memory2 = instance.get_memory(&mut store, "memory").unwrap();
+    let counter2 = instance.get_global(&mut store, "counter").unwrap();
+    memory2.data_mut(&mut store)[0] = 42;
+    assert!(instance.restore(&mut store, &snapshot).is_err());
+
+    // Neither memory contents nor globals were touched.
+    assert_eq!(memory2.data(&store)[0], 42);
+    assert_eq!(counter2.get(&mut store).unwrap_i32(), 0);
+    assert_eq!(memory.size(&store), 2);
+    assert_eq!(counter.get(&mut store).unwrap_i32(), 1);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn rewind_and_replay_host_calls() -> Result<()> {
+    // Host calls return a different value every time, so re-running guest
+    // code after a rewind only reaches the same state if their results are
+    // replayed from a log.
+    #[derive(Default)]
+    struct Recorder {
+        next_random: i32,
+        log: Vec<i32>,
+        replay_from: Option<usize>,
+    }
+
+    let engine = Engine::default();
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (import "host" "random" (func $random (result i32)))
+                (global $sum (export "sum") (mut i32) (i32.const 0))
+                (func (export "step")
+                    (global.set $sum (i32.add (global.get $sum) (call $random))))
+            )
+        "#,
+    )?;
+    let mut linker = Linker::<Recorder>::new(&engine);
+    linker.func_wrap("host", "random", |mut caller: Caller<'_, Recorder>| {
+        caller.data_mut().next_random += 7;
+        caller.data().next_random
+    })?;
+    linker.func_intercept("host", "random", |mut caller, original, params, results| {
+        let recorder = caller.data_mut();
+        if let Some(index) = recorder.replay_from {
+            if let Some(value) = recorder.log.get(index).copied() {
+                recorder.replay_from = Some(index + 1);
+                results[0] = Val::I32(value);
+                return Ok(());
+            }
+        }
+        original.call(&mut caller, params, results)?;
+        caller.data_mut().log.push(results[0].unwrap_i32());
+        Ok(())
+    })?;
+
+    let mut store = Store::new(&engine, Recorder::default());
+    let instance = linker.instantiate(&mut store, &module)?;
+    let step = instance.get_typed_func::<(), ()>(&mut store, "step")?;
+    let sum = instance.get_global(&mut store, "sum").unwrap();
+
+    // Each call into the instance makes exactly one host call, so positions
+    // in the timeline are also indices into the log.
+    let mut timeline = SnapshotTimeline::new(3, 4);
+    let mut history = Vec::new();
+    for position in 0..10 {
+        timeline.record(&mut store, &instance, position)?;
+        step.call(&mut store, ())?;
+        history.push(sum.get(&mut store).unwrap_i32());
+    }
+
+    let position = timeline.rewind(&mut store, &instance, 7)?;
+    assert_eq!(position, 6);
+    store.data_mut().replay_from = Some(6);
+    for target in 6..10 {
+        step.call(&mut store, ())?;
+        assert_eq!(sum.get(&mut store).unwrap_i32(), history[target]);
+    }
+
+    // Past the end of the log execution continues live.
+    step.call(&mut store, ())?;
+    assert_eq!(store.data().log.len(), 11);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn diffing_checkpoints() -> Result<()> {
+    let mut store = Store::<()>::default();
+    let module = Module::new(store.engine(), WAT)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let step = instance.get_typed_func::<(), ()>(&mut store, "step")?;
+    let grow = instance.get_typed_func::<(), i32>(&mut store, "grow")?;
+    let memory = instance.get_memory(&mut store, "memory").unwrap();
+    let counter = instance.get_global(&mut store, "counter").unwrap();
+
+    let mut checkpoints = DiffingSnapshot::new(&mut store, &instance)?;
+    let initial = checkpoints.snapshot().clone();
+
+    step.call(&mut store, ())?;
+    let first = checkpoints.checkpoint(&mut store, &instance)?;
+    assert_eq!(first.changed_pages(), 1);
+    assert_eq!(first.memory_bytes(), 4096);
+
+    // Nothing changed since the last checkpoint.
+    assert_eq!(
+        checkpoints
+            .checkpoint(&mut store, &instance)?
+            .changed_pages(),
+        0
+    );
+
+    // Pages are compared rather than tracked, so rewriting a page with its
+    // current contents isn't a change.
+    let byte = memory.data(&store)[4];
+    memory.data_mut(&mut store)[4] = byte;
+    assert_eq!(
+        checkpoints
+            .checkpoint(&mut store, &instance)?
+            .changed_pages(),
+        0
+    );
+
+    // Grown memory is only recorded where it was written to.
+    step.call(&mut store, ())?;
+    grow.call(&mut store, ())?;
+    memory.data_mut(&mut store)[65536 + 4096] = 1;
+    let second = checkpoints.checkpoint(&mut store, &instance)?;
+    assert_eq!(second.changed_pages(), 2);
+    assert_eq!(checkpoints.snapshot().memory_bytes(), 2 * 65536);
+
+    // Deltas survive persistence and replay onto the initial snapshot.
+    let encoding = SnapshotEncoding::new().codec(Xor(0x5a));
+    let mut file = Vec::new();
+    encoding.write_delta(&first, &mut file)?;
+    assert!(encoding.read_snapshot(&file[..]).is_err());
+    let first = encoding.read_delta(&file[..])?;
+
+    let mut replayed = initial.clone();
+    replayed.apply(&first)?;
+    instance.restore(&mut store, &replayed)?;
+    assert_eq!(counter.get(&mut store).unwrap_i32(), 1);
+    assert_eq!(memory.data(&store)[4], 1);
+    assert_eq!(memory.data(&store)[65536 + 4096], 0);
+
+    replayed.apply(&second)?;
+    instance.restore(&mut store, &replayed)?;
+    assert_eq!(counter.get(&mut store).unwrap_i32(), 2);
+    assert_eq!(memory.data(&store)[8], 2);
+    assert_eq!(memory.data(&store)[65536 + 4096], 1);
+
+    // Deltas can also be computed between two full snapshots.
+    let delta = initial.delta_to(checkpoints.snapshot())?;
+    assert_eq!(delta.changed_pages(), 2);
+    Ok(())
+}
+
+/// A toy stream cipher standing in for a real encryption codec.
+struct Xor(u8);
+
+struct XorSink<'a>(Box<dyn SnapshotSink + 'a>, u8);
+
+impl Write for XorSink<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let buf = buf.iter().map(|b| b ^ self.1).collect::<Vec<_>>();
+        self.0.write_all(&buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl SnapshotSink for XorSink<'_> {
+    fn finish(self: Box<Self>) -> Result<()> {
+        self.0.finish()
+    }
+}
+
+struct XorReader<'a>(Box<dyn Read + 'a>, u8);
+
+impl Read for XorReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.0.read(buf)?;
+        for b in buf[..n].iter_mut() {
+            *b ^= self.1;
+        }
+        Ok(n)
+    }
+}
+
+impl SnapshotCodec for Xor {
+    fn encoder<'a>(
+        &self,
+        output: Box<dyn SnapshotSink + 'a>,
+    ) -> Result<Box<dyn SnapshotSink + 'a>> {
+        Ok(Box::new(XorSink(output, self.0)))
+    }
+
+    fn decoder<'a>(&self, input: Box<dyn Read + 'a>) -> Result<Box<dyn Read + 'a>> {
+        Ok(Box::new(XorReader(input, self.0)))
+    }
+}
+
+/// A codec which frames its input with a header, to check codec ordering.
+struct Header;
+
+impl SnapshotCodec for Header {
+    fn encoder<'a>(
+        &self,
+        mut output: Box<dyn SnapshotSink + 'a>,
+    ) -> Result<Box<dyn SnapshotSink + 'a>> {
+        output.write_all(b"HDR")?;
+        Ok(output)
+    }
+
+    fn decoder<'a>(&self, mut input: Box<dyn Read + 'a>) -> Result<Box<dyn Read + 'a>> {
+        let mut header = [0; 3];
+        input.read_exact(&mut header)?;
+        anyhow::ensure!(&header == b"HDR", "missing header");
+        Ok(input)
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn persist_with_codecs() -> Result<()> {
+    let mut store = Store::<()>::default();
+    let module = Module::new(store.engine(), WAT)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let step = instance.get_typed_func::<(), ()>(&mut store, "step")?;
+    let counter = instance.get_global(&mut store, "counter").unwrap();
+
+    step.call(&mut store, ())?;
+    let snapshot = instance.snapshot(&mut store)?;
+    step.call(&mut store, ())?;
+
+    // The first codec sees the raw data and the last one writes to the file.
+    let encoding = SnapshotEncoding::new().codec(Xor(0x5a)).codec(Header);
+    let mut file = Vec::new();
+    encoding.write_snapshot(&snapshot, &mut file)?;
+    assert_eq!(&file[..3], b"HDR");
+    assert!(!file.windows(6).any(|w| w == b"WTSNAP"));
+
+    // Decoding with the wrong codecs fails rather than restoring garbage.
+    assert!(SnapshotEncoding::new()
+        .codec(Header)
+        .read_snapshot(&file[..])
+        .is_err());
+
+    let restored = encoding.read_snapshot(&file[..])?;
+    assert_eq!(restored.memory_bytes(), snapshot.memory_bytes());
+    instance.restore(&mut store, &restored)?;
+    assert_eq!(counter.get(&mut store).unwrap_i32(), 1);
+    step.call(&mut store, ())?;
+    assert_eq!(counter.get(&mut store).unwrap_i32(), 2);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn persist_with_zstd() -> Result<()> {
+    let mut store = Store::<()>::default();
+    let module = Module::new(store.engine(), WAT)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let step = instance.get_typed_func::<(), ()>(&mut store, "step")?;
+    let counter = instance.get_global(&mut store, "counter").unwrap();
+
+    step.call(&mut store, ())?;
+    let snapshot = instance.snapshot(&mut store)?;
+
+    // Compression goes before encryption, as encrypted data doesn't compress.
+    let encoding = SnapshotEncoding::new()
+        .codec(ZstdCodec::new())
+        .codec(Xor(0x5a));
+    let mut file = Vec::new();
+    encoding.write_snapshot(&snapshot, &mut file)?;
+    // The memory is almost entirely zeroes.
+    assert!(file.len() < 1024);
+
+    // The level is only relevant when compressing.
+    let decoding = SnapshotEncoding::new()
+        .codec(ZstdCodec::new().level(19))
+        .codec(Xor(0x5a));
+    let restored = decoding.read_snapshot(&file[..])?;
+    step.call(&mut store, ())?;
+    instance.restore(&mut store, &restored)?;
+    assert_eq!(counter.get(&mut store).unwrap_i32(), 1);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn coredump_with_codecs() -> Result<()> {
+    let mut config = Config::default();
+    config.coredump_on_trap(true);
+    let engine = Engine::new(&config)?;
+    let mut store = Store::<()>::new(&engine, ());
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (memory 2)
+                (data (i32.const 70000) "secret")
+                (func (export "run") unreachable)
+            )
+        "#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+    let e = run.call(&mut store, ()).unwrap_err();
+    let coredump = e.downcast_ref::<WasmCoreDump>().unwrap();
+
+    let encoding = SnapshotEncoding::new()
+        .codec(ZstdCodec::new())
+        .codec(Xor(0x5a));
+    let mut file = Vec::new();
+    encoding.write_coredump(&mut store, coredump, "dump", &mut file)?;
+    assert!(!file.windows(6).any(|w| w == b"secret"));
+
+    let mut decoded = Vec::new();
+    encoding.decoder(&file[..])?.read_to_end(&mut decoded)?;
+    assert_eq!(decoded, coredump.serialize(&mut store, "dump"));
+    assert!(decoded.windows(6).any(|w| w == b"secret"));
+    wasmparser::validate(&decoded)?;
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
-use wasmtime::{Engine, Store};
+use wasmtime::{
+    Caller, Config, Engine, ExternRef, Func, Instance, Memory, MemoryType, Module, Store,
+};
 
 #[test]
 fn into_inner() {
@@ -20,3 +22,88 @@ fn into_inner() {
     Store::new(&engine, A).into_data();
     assert_eq!(HITS.load(SeqCst), 2);
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn resource_report() -> anyhow::Result<()> {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let report = store.resource_report();
+    assert_eq!(report.instances, 0);
+    assert_eq!(report.memories, 0);
+    assert!(report.resources.is_empty());
+
+    let module = Module::new(&engine, r#"(module $leaky (memory 1) (table 1 funcref))"#)?;
+    Instance::new(&mut store, &module, &[])?;
+    Instance::new(&mut store, &module, &[])?;
+    Memory::new(&mut store, MemoryType::new(1, None))?;
+
+    let mut report = store.resource_report();
+    assert_eq!(report.instances, 2);
+    assert_eq!(report.memories, 3);
+    assert_eq!(report.tables, 2);
+    assert_eq!(report.fiber_stacks_in_use, 0);
+    let instances = report
+        .resources
+        .iter()
+        .filter(|r| r.kind == "instance")
+        .map(|r| r.description.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(instances, ["leaky", "leaky"]);
+    assert!(report.resources.iter().all(|r| r.created_at.is_none()));
+
+    report.push("socket", "127.0.0.1:8080", None);
+    let rendered = report.to_string();
+    assert!(rendered.contains("instances: 2\n"));
+    assert!(rendered.contains("instance: leaky\n"));
+    assert!(rendered.contains("socket: 127.0.0.1:8080\n"));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn resource_report_origins_and_externrefs() -> anyhow::Result<()> {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(
+        &engine,
+        r#"(module $m (func (export "take") (param externref)))"#,
+    )?;
+
+    store.track_resource_origins(true);
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let report = store.resource_report();
+    assert_eq!(report.resources.len(), 1);
+    assert!(report.resources[0].created_at.is_some());
+    assert_eq!(report.externref_bytes, 0);
+
+    // Passing an externref to wasm roots it in the store until the next GC.
+    let take = instance.get_typed_func::<Option<ExternRef>, ()>(&mut store, "take")?;
+    let r = ExternRef::new([0u8; 4096]);
+    take.call(&mut store, Some(r.clone()))?;
+    take.call(&mut store, Some(r))?;
+    let report = store.resource_report();
+    assert!(report.externref_roots >= 1);
+    assert!(report.externref_bytes >= 4096);
+    assert!(report.externref_bytes < 2 * 4096);
+
+    store.gc();
+    assert_eq!(store.resource_report().externref_bytes, 0);
+    Ok(())
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn resource_report_counts_fiber_stacks() -> anyhow::Result<()> {
+    let engine = Engine::new(Config::new().async_support(true))?;
+    let mut store = Store::new(&engine, ());
+    let stacks = Func::wrap0_async(&mut store, |caller: Caller<'_, ()>| {
+        Box::new(async move { Ok(caller.resource_report().fiber_stacks_in_use as u32) })
+    });
+    let stacks = stacks.typed::<(), u32>(&store)?;
+
+    assert_eq!(store.resource_report().fiber_stacks_in_use, 0);
+    assert_eq!(stacks.call_async(&mut store, ()).await?, 1);
+    assert_eq!(store.resource_report().fiber_stacks_in_use, 0);
+    Ok(())
+}
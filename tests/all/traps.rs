@@ -513,6 +513,90 @@ fn rust_panic_start_function() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn rust_panic_import_caught() -> Result<()> {
+    let mut config = Config::new();
+    config.catch_host_panics(true);
+    let engine = Engine::new(&config)?;
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(
+        &engine,
+        r#"
+            (module $a
+                (import "" "" (func $foo))
+                (import "" "" (func $bar))
+                (func $call_foo (export "foo") call $foo)
+                (func (export "bar") call $bar)
+            )
+        "#,
+    )?;
+
+    let sig = FuncType::new(None, None);
+    let func = Func::new(&mut store, sig, |_, _, _| panic!("this is a panic"));
+    let func2 = Func::wrap(&mut store, || std::panic::panic_any(42_u32));
+    let instance = Instance::new(&mut store, &module, &[func.into(), func2.into()])?;
+
+    let foo = instance.get_typed_func::<(), ()>(&mut store, "foo")?;
+    let err = foo.call(&mut store, ()).unwrap_err();
+    let trace = err.downcast_ref::<WasmBacktrace>().unwrap();
+    assert_eq!(trace.frames()[0].func_name(), Some("call_foo"));
+    let panic = err.downcast::<HostPanic>()?;
+    assert_eq!(panic.message(), Some("this is a panic"));
+    assert_eq!(panic.to_string(), "host function panicked: this is a panic");
+
+    // Payloads which aren't strings are preserved as well.
+    let bar = instance.get_typed_func::<(), ()>(&mut store, "bar")?;
+    let panic = bar
+        .call(&mut store, ())
+        .unwrap_err()
+        .downcast::<HostPanic>()?;
+    assert_eq!(panic.message(), None);
+    assert_eq!(panic.into_payload().downcast_ref::<u32>(), Some(&42));
+    Ok(())
+}
+
+#[test]
+fn rust_panic_import_caught_at_innermost_call() -> Result<()> {
+    let mut config = Config::new();
+    config.catch_host_panics(true);
+    let engine = Engine::new(&config)?;
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(
+        &engine,
+        r#"
+            (module $a
+                (import "" "reenter" (func $reenter (result i32)))
+                (import "" "panic" (func $panic))
+                (func (export "outer") (result i32) call $reenter)
+                (func $call_panic (export "inner") call $panic)
+            )
+        "#,
+    )?;
+
+    let reenter = Func::wrap(&mut store, |mut caller: Caller<'_, ()>| -> Result<i32> {
+        let inner = caller
+            .get_export("inner")
+            .unwrap()
+            .into_func()
+            .unwrap()
+            .typed::<(), ()>(&caller)?;
+        let err = inner.call(&mut caller, ()).unwrap_err();
+        let trace = err.downcast_ref::<WasmBacktrace>().unwrap();
+        assert_eq!(trace.frames()[0].func_name(), Some("call_panic"));
+        let panic = err.downcast::<HostPanic>()?;
+        assert_eq!(panic.message(), Some("nested panic"));
+        Ok(42)
+    });
+    let panic = Func::wrap(&mut store, || panic!("nested panic"));
+    let instance = Instance::new(&mut store, &module, &[reenter.into(), panic.into()])?;
+
+    // The panic is caught by the nested call made from `reenter`, so the outer
+    // call completes normally with whatever that host function returned.
+    let outer = instance.get_typed_func::<(), i32>(&mut store, "outer")?;
+    assert_eq!(outer.call(&mut store, ())?, 42);
+    Ok(())
+}
+
 #[test]
 fn mismatched_arguments() -> Result<()> {
     let mut store = Store::<()>::default();
@@ -906,6 +990,37 @@ fn traps_without_address_map() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn traps_with_function_entry_address_map() -> Result<()> {
+    let mut config = Config::new();
+    config.address_map_granularity(AddressMapGranularity::FunctionEntry);
+    let engine = Engine::new(&config)?;
+    let mut store = Store::new(&engine, ());
+    let wat = r#"
+        (module $hello_mod
+            (func (export "run") nop (call $hello))
+            (func $hello nop nop (unreachable))
+        )
+    "#;
+
+    let module = Module::new(store.engine(), wat)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let run_func = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+
+    let e = run_func.call(&mut store, ()).unwrap_err();
+
+    let trace = e.downcast_ref::<WasmBacktrace>().unwrap().frames();
+    assert_eq!(trace.len(), 2);
+    assert_eq!(trace[0].func_name(), Some("hello"));
+    assert_eq!(trace[0].func_offset(), Some(0));
+    assert!(trace[0].module_offset().is_some());
+    assert_eq!(trace[1].func_name(), None);
+    assert_eq!(trace[1].func_offset(), Some(0));
+    assert!(trace[1].module_offset().is_some());
+    assert!(trace[1].module_offset() < trace[0].module_offset());
+    Ok(())
+}
+
 #[test]
 fn catch_trap_calling_across_stores() -> Result<()> {
     let _ = env_logger::try_init();
@@ -1648,3 +1763,54 @@ fn async_stack_size_ignored_if_disabled() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn trap_categories() -> Result<()> {
+    let mut config = Config::new();
+    config.consume_fuel(true).epoch_interruption(true);
+    let engine = Engine::new(&config)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+                (import "" "host" (func $host))
+                (func (export "unreachable") unreachable)
+                (func (export "host") call $host)
+                (func (export "loop") (loop br 0))
+            )
+        "#,
+    )?;
+    let mut store = Store::new(&engine, ());
+    store.set_fuel(10_000)?;
+    store.set_epoch_deadline(1);
+    let host = Func::wrap(&mut store, || -> Result<()> { bail!("cancelled") });
+    let instance = Instance::new(&mut store, &module, &[host.into()])?;
+
+    let category = |store: &mut Store<()>, name: &str| {
+        let func = instance
+            .get_typed_func::<(), ()>(&mut *store, name)
+            .unwrap();
+        let err = func.call(&mut *store, ()).unwrap_err();
+        TrapCategory::of_error(&err)
+    };
+
+    assert_eq!(category(&mut store, "unreachable"), TrapCategory::GuestBug);
+    assert_eq!(category(&mut store, "host"), TrapCategory::Unclassified);
+    assert_eq!(
+        category(&mut store, "loop"),
+        TrapCategory::ResourceExhaustion
+    );
+
+    store.set_fuel(u64::MAX)?;
+    engine.increment_epoch();
+    assert_eq!(category(&mut store, "loop"), TrapCategory::Deadline);
+
+    assert_eq!(TrapCategory::GuestBug.http_status(), 500);
+    assert_eq!(TrapCategory::Deadline.grpc_code(), 4);
+    assert_eq!(TrapCategory::Unclassified.grpc_code(), 2);
+    assert_eq!(
+        Trap::StackOverflow.category(),
+        TrapCategory::ResourceExhaustion
+    );
+    Ok(())
+}
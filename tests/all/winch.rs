@@ -175,7 +175,60 @@ fn mixed_roundtrip() -> Result<()> {
 
 #[test]
 #[cfg_attr(miri, ignore)]
-#[cfg_attr(windows, ignore)]
+fn calls_across_instances() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    let engine = Engine::new(&c)?;
+    let mut store = Store::new(&engine, ());
+
+    // Each function reads a global of its own instance, which it can only
+    // find through its own `VMContext`.
+    let callee = Module::new(
+        &engine,
+        r#"
+        (module
+          (global $base i32 (i32.const 1000))
+          (table (export "table") 1 funcref)
+          (elem (i32.const 0) $mul)
+          (func $mul (export "mul") (param i32 i32) (result i32)
+            (i32.add
+              (global.get $base)
+              (i32.mul (local.get 0) (local.get 1)))))
+    "#,
+    )?;
+    let caller = Module::new(
+        &engine,
+        r#"
+        (module
+          (import "" "mul" (func $mul (param i32 i32) (result i32)))
+          (import "" "table" (table 1 funcref))
+          (type $ty (func (param i32 i32) (result i32)))
+          (global $base i32 (i32.const 1))
+          (func (export "call_import") (param i32 i32) (result i32)
+            (i32.add
+              (call $mul (local.get 0) (local.get 1))
+              (global.get $base)))
+          (func (export "call_indirect") (param i32 i32) (result i32)
+            (i32.add
+              (call_indirect (type $ty) (local.get 0) (local.get 1) (i32.const 0))
+              (global.get $base))))
+    "#,
+    )?;
+
+    let callee = Instance::new(&mut store, &callee, &[])?;
+    let mul = callee.get_func(&mut store, "mul").unwrap();
+    let table = callee.get_table(&mut store, "table").unwrap();
+    let caller = Instance::new(&mut store, &caller, &[mul.into(), table.into()])?;
+
+    for name in ["call_import", "call_indirect"] {
+        let f = caller.get_typed_func::<(i32, i32), i32>(&mut store, name)?;
+        assert_eq!(f.call(&mut store, (6, 7))?, 1043, "{name}");
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
 // NB
 //
 // This and the following test(`native_to_wasm_trap` and `wasm_to_native_trap`),
@@ -186,9 +239,6 @@ fn mixed_roundtrip() -> Result<()> {
 // code generated by Winch and Cranelift is compliant. One way to achieve this
 // could be to share the implementation of trampolines between Cranelift and
 // Winch.
-//
-// FIXME The following two tests are also temporarily ignored on Windows, since
-// we are not emitting the require unwind information yet.
 fn native_to_wasm_trap() -> Result<()> {
     let mut c = Config::new();
     c.strategy(Strategy::Winch);
@@ -215,7 +265,6 @@ fn native_to_wasm_trap() -> Result<()> {
 
 #[test]
 #[cfg_attr(miri, ignore)]
-#[cfg_attr(windows, ignore)]
 fn wasm_to_native_trap() -> Result<()> {
     let mut c = Config::new();
     c.strategy(Strategy::Winch);
@@ -239,3 +288,842 @@ fn wasm_to_native_trap() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn frame_pointer_chain_through_leaf_functions() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    let engine = Engine::new(&c)?;
+    let wat = r#"
+        (module
+          (func $leaf unreachable)
+          (func $middle call $leaf)
+          (func $outer call $middle)
+
+          (export "outer" (func $outer)))
+    "#;
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, wat)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let f = instance.get_typed_func::<(), ()>(&mut store, "outer")?;
+    let result = f.call(&mut store, ()).unwrap_err();
+
+    // Walking the frame-pointer chain from the trapping leaf function must
+    // reach every caller.
+    let trace = result.downcast_ref::<WasmBacktrace>().unwrap();
+    let names = trace
+        .frames()
+        .iter()
+        .map(|frame| frame.func_name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, [Some("leaf"), Some("middle"), Some("outer")]);
+
+    Ok(())
+}
+
+/// A function with `ifs` conditional blocks inside of a loop, so that the
+/// loop's back edge spans the whole function. `run(x)` returns `3 * x` for
+/// `x < ifs`.
+fn large_function(ifs: u32) -> String {
+    let mut wat = String::from(
+        r#"
+        (module
+          (func (export "run") (param $x i32) (result i32)
+            (local $acc i32)
+            (local $i i32)
+            (loop $l
+        "#,
+    );
+    for k in 0..ifs {
+        wat.push_str(&format!(
+            "(if (i32.eq (local.get $x) (i32.const {k}))
+               (then (local.set $acc (i32.add (local.get $acc) (i32.const {k})))))\n"
+        ));
+    }
+    wat.push_str(
+        r#"
+              (local.set $i (i32.add (local.get $i) (i32.const 1)))
+              (br_if $l (i32.lt_u (local.get $i) (i32.const 3))))
+            (local.get $acc)))
+        "#,
+    );
+    wat
+}
+
+fn run_large_function(c: &Config, ifs: u32) -> Result<()> {
+    let engine = Engine::new(c)?;
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, large_function(ifs))?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let run = instance.get_typed_func::<i32, i32>(&mut store, "run")?;
+    for x in [0, 1, ifs / 2, ifs - 1] {
+        assert_eq!(run.call(&mut store, x as i32)?, 3 * x as i32);
+    }
+    assert_eq!(run.call(&mut store, ifs as i32)?, 0);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn very_large_function() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    run_large_function(&c, 100_000)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn large_function_with_padding() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    // Insert 64KiB of padding before every label to inflate branch distances
+    // and stress the handling of label fixups and islands.
+    unsafe {
+        c.cranelift_flag_set("bb_padding_log2_minus_one", "17");
+    }
+    run_large_function(&c, 64)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn bit_counting_without_cpu_features() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    // Force the software sequences used on CPUs without these extensions.
+    unsafe {
+        c.cranelift_flag_set("has_popcnt", "false");
+        c.cranelift_flag_set("has_lzcnt", "false");
+        c.cranelift_flag_set("has_bmi1", "false");
+    }
+    let engine = Engine::new(&c)?;
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+              (func (export "i32.popcnt") (param i32) (result i32) (i32.popcnt (local.get 0)))
+              (func (export "i64.popcnt") (param i64) (result i64) (i64.popcnt (local.get 0)))
+              (func (export "i32.clz") (param i32) (result i32) (i32.clz (local.get 0)))
+              (func (export "i64.ctz") (param i64) (result i64) (i64.ctz (local.get 0))))
+        "#,
+    )?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+
+    let popcnt32 = instance.get_typed_func::<u32, u32>(&mut store, "i32.popcnt")?;
+    let popcnt64 = instance.get_typed_func::<u64, u64>(&mut store, "i64.popcnt")?;
+    let clz32 = instance.get_typed_func::<u32, u32>(&mut store, "i32.clz")?;
+    let ctz64 = instance.get_typed_func::<u64, u64>(&mut store, "i64.ctz")?;
+    for x in [0u64, 1, 0x80, 0xff00_ff00, 0x8000_0000_0000_0001, u64::MAX] {
+        let x32 = x as u32;
+        assert_eq!(popcnt32.call(&mut store, x32)?, x32.count_ones());
+        assert_eq!(popcnt64.call(&mut store, x)?, u64::from(x.count_ones()));
+        assert_eq!(clz32.call(&mut store, x32)?, x32.leading_zeros());
+        assert_eq!(ctz64.call(&mut store, x)?, u64::from(x.trailing_zeros()));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn debug_info() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.debug_info(true);
+    let engine = Engine::new(&c)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+              (func (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1))))
+        "#,
+    )?;
+    let bytes = module.serialize()?;
+    assert!(memchr::memmem::find(&bytes, b".debug_line").is_some());
+
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let add = instance.get_typed_func::<(i32, i32), i32>(&mut store, "add")?;
+    assert_eq!(add.call(&mut store, (1, 2))?, 3);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(target_os = "linux")]
+fn perfmap_names_functions() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.profiler(ProfilingStrategy::PerfMap);
+    let engine = Engine::new(&c)?;
+    Module::new(
+        &engine,
+        r#"
+            (module
+              (func $winch_perfmap_function (result i32)
+                (i32.const 1)))
+        "#,
+    )?;
+
+    let map = std::fs::read_to_string(format!("/tmp/perf-{}.map", std::process::id()))?;
+    let line = map
+        .lines()
+        .find(|line| line.ends_with(" winch_perfmap_function"))
+        .expect("function missing from the perf map");
+    let size = line.split(' ').nth(1).unwrap();
+    assert_ne!(u64::from_str_radix(size, 16)?, 0);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(target_os = "linux")]
+fn jitdump_records_functions() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.profiler(ProfilingStrategy::JitDump);
+    let engine = Engine::new(&c)?;
+    Module::new(
+        &engine,
+        r#"
+            (module
+              (func $winch_jitdump_function (result i32)
+                (i32.const 1)))
+        "#,
+    )?;
+
+    // The dump is written to the working directory and shared by the whole
+    // process, so it's read back and removed here.
+    let path = format!("./jit-{}.dump", std::process::id());
+    let dump = std::fs::read(&path)?;
+    std::fs::remove_file(&path)?;
+
+    let u32_at = |pos: usize| u32::from_ne_bytes(dump[pos..][..4].try_into().unwrap());
+    let u64_at = |pos: usize| u64::from_ne_bytes(dump[pos..][..8].try_into().unwrap());
+    assert_eq!(u32_at(0), 0x4A695444);
+
+    // Walk the records after the file header looking for the code load
+    // record of our function, which is followed by its name and code.
+    let mut pos = u32_at(8) as usize;
+    while pos < dump.len() {
+        let (id, record_size) = (u32_at(pos), u32_at(pos + 4) as usize);
+        if id == 0 {
+            let code_size = u64_at(pos + 40) as usize;
+            let name = &dump[pos + 56..pos + record_size - code_size];
+            if name == b"winch_jitdump_function\0" {
+                assert_ne!(code_size, 0);
+                return Ok(());
+            }
+        }
+        pos += record_size;
+    }
+    panic!("function missing from the jitdump");
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn native_unwind_info() -> Result<()> {
+    let wat = r#"
+        (module
+          (import "" "" (func $host))
+          (func (export "run") (call $host)))
+    "#;
+    let section: &[u8] = if cfg!(windows) {
+        b".pdata"
+    } else {
+        b".eh_frame"
+    };
+
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    let engine = Engine::new(&c)?;
+    let module = Module::new(&engine, wat)?;
+    assert!(memchr::memmem::find(&module.serialize()?, section).is_some());
+
+    // The unwind information is registered with the system unwinder when the
+    // module is loaded, which must leave the code callable.
+    let mut store = Store::new(&engine, ());
+    let host = Func::wrap(&mut store, || {});
+    let instance = Instance::new(&mut store, &module, &[host.into()])?;
+    let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+    run.call(&mut store, ())?;
+
+    if !cfg!(windows) {
+        c.native_unwind_info(false);
+        let engine = Engine::new(&c)?;
+        let module = Module::new(&engine, wat)?;
+        assert!(memchr::memmem::find(&module.serialize()?, section).is_none());
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn array_call_host_funcs() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.allocation_strategy(InstanceAllocationStrategy::Pooling(
+        crate::small_pool_config(),
+    ));
+    let engine = Engine::new(&c)?;
+    let wat = r#"
+        (module
+          (import "" "swap" (func $swap (param i32 i64 f32 f64) (result f64 f32 i64 i32)))
+          (import "" "add" (func $add (param i32 i32) (result i32)))
+          (table 2 funcref)
+          (elem (i32.const 0) func $add $swap)
+
+          (func (export "swap") (param i32 i64 f32 f64) (result f64 f32 i64 i32)
+            (call $swap (local.get 0) (local.get 1) (local.get 2) (local.get 3)))
+          (func (export "get") (param i32) (result funcref)
+            (table.get (local.get 0))))
+    "#;
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, wat)?;
+    let swap = Func::new(
+        &mut store,
+        FuncType::new(
+            [ValType::I32, ValType::I64, ValType::F32, ValType::F64],
+            [ValType::F64, ValType::F32, ValType::I64, ValType::I32],
+        ),
+        |_, params, results| {
+            for (result, param) in results.iter_mut().zip(params.iter().rev()) {
+                *result = param.clone();
+            }
+            Ok(())
+        },
+    );
+    let add = Func::new(
+        &mut store,
+        FuncType::new([ValType::I32, ValType::I32], [ValType::I32]),
+        |_, params, results| {
+            results[0] = Val::I32(params[0].unwrap_i32() + params[1].unwrap_i32());
+            Ok(())
+        },
+    );
+    let instance = Instance::new(&mut store, &module, &[swap.into(), add.into()])?;
+
+    let f = instance
+        .get_typed_func::<(i32, i64, f32, f64), (f64, f32, i64, i32)>(&mut store, "swap")?;
+    assert_eq!(f.call(&mut store, (1, 2, 3.0, 4.0))?, (4.0, 3.0, 2, 1));
+
+    // The table's funcrefs are initialized lazily on first use. Host
+    // functions read out of the table are called through the native calling
+    // convention.
+    let get = instance.get_typed_func::<i32, Option<Func>>(&mut store, "get")?;
+    let add = get.call(&mut store, 0)?.unwrap();
+    let add = add.typed::<(i32, i32), i32>(&store)?;
+    assert_eq!(add.call(&mut store, (3, 4))?, 7);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn stack_overflow() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    let engine = Engine::new(&c)?;
+    let wat = r#"
+        (module
+          (func $recurse (export "recurse")
+            call $recurse))
+    "#;
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, wat)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let f = instance.get_typed_func::<(), ()>(&mut store, "recurse")?;
+    let trap = f.call(&mut store, ()).unwrap_err().downcast::<Trap>()?;
+    assert_eq!(trap, Trap::StackOverflow);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn v128_and_externref_signatures() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    let engine = Engine::new(&c)?;
+    let wat = r#"
+        (module
+          (import "" "wrap" (func $wrap (param v128 externref) (result externref v128)))
+          (import "" "new" (func $new (param v128 externref) (result externref v128)))
+
+          (func (export "call_wrap") (param v128 externref) (result externref v128)
+            (call $wrap (local.get 0) (local.get 1)))
+          (func (export "call_new") (param v128 externref) (result externref v128)
+            (call $new (local.get 0) (local.get 1)))
+          (func (export "first_and_last")
+            (param v128 v128 v128 v128 v128 v128 v128 v128 v128 v128)
+            (result v128 v128)
+            (local.get 0)
+            (local.get 9)))
+    "#;
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, wat)?;
+    let wrap = Func::wrap(&mut store, |v: V128, r: Option<ExternRef>| (r, v));
+    let new = Func::new(
+        &mut store,
+        FuncType::new(
+            [ValType::V128, ValType::ExternRef],
+            [ValType::ExternRef, ValType::V128],
+        ),
+        |_, params, results| {
+            results[0] = params[1].clone();
+            results[1] = params[0].clone();
+            Ok(())
+        },
+    );
+    let instance = Instance::new(&mut store, &module, &[wrap.into(), new.into()])?;
+
+    let v = V128::from(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+    let r = ExternRef::new(42_u32);
+    for name in ["call_wrap", "call_new"] {
+        let f = instance.get_typed_func::<(V128, Option<ExternRef>), (Option<ExternRef>, V128)>(
+            &mut store, name,
+        )?;
+        let (result_r, result_v) = f.call(&mut store, (v, Some(r.clone())))?;
+        assert!(result_r.unwrap().ptr_eq(&r));
+        assert_eq!(result_v, v);
+
+        let f = instance.get_func(&mut store, name).unwrap();
+        let mut results = [Val::null(), Val::null()];
+        f.call(
+            &mut store,
+            &[v.into(), Some(r.clone()).into()],
+            &mut results,
+        )?;
+        assert!(results[0].unwrap_externref().unwrap().ptr_eq(&r));
+        assert_eq!(results[1].unwrap_v128(), v);
+    }
+
+    // Vectors which don't fit in registers are passed on the stack.
+    let args = (0..10).map(|i| V128::from(i as u128)).collect::<Vec<_>>();
+    let f = instance.get_typed_func::<
+        (V128, V128, V128, V128, V128, V128, V128, V128, V128, V128),
+        (V128, V128),
+    >(&mut store, "first_and_last")?;
+    let results = f.call(
+        &mut store,
+        (
+            args[0], args[1], args[2], args[3], args[4], args[5], args[6], args[7], args[8],
+            args[9],
+        ),
+    )?;
+    assert_eq!(results, (args[0], args[9]));
+
+    let f = instance.get_func(&mut store, "first_and_last").unwrap();
+    let params = args.iter().map(|v| Val::V128(*v)).collect::<Vec<_>>();
+    let mut results = [Val::null(), Val::null()];
+    f.call(&mut store, &params, &mut results)?;
+    assert_eq!(results[0].unwrap_v128(), args[0]);
+    assert_eq!(results[1].unwrap_v128(), args[9]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn component_trampolines() -> Result<()> {
+    use wasmtime::component::{Component, Linker};
+
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.wasm_component_model(true);
+    let engine = Engine::new(&c)?;
+    let component = Component::new(
+        &engine,
+        r#"
+        (component
+          (import "double" (func $double (param "x" u32) (result u32)))
+          (type $r (resource (rep i32)))
+          (core func $double (canon lower (func $double)))
+          (core func $new (canon resource.new $r))
+          (core func $rep (canon resource.rep $r))
+          (core func $drop (canon resource.drop $r))
+
+          (core module $m
+            (import "" "double" (func $double (param i32) (result i32)))
+            (import "" "new" (func $new (param i32) (result i32)))
+            (import "" "rep" (func $rep (param i32) (result i32)))
+            (import "" "drop" (func $drop (param i32)))
+
+            (func (export "run") (param i32) (result i32)
+              (local $handle i32)
+              (local.set $handle (call $new (call $double (local.get 0))))
+              (call $rep (local.get $handle))
+              (call $drop (local.get $handle))))
+          (core instance $i (instantiate $m
+            (with "" (instance
+              (export "double" (func $double))
+              (export "new" (func $new))
+              (export "rep" (func $rep))
+              (export "drop" (func $drop))))))
+
+          (func (export "run") (param "x" u32) (result u32)
+            (canon lift (core func $i "run"))))
+        "#,
+    )?;
+    let mut linker = Linker::new(&engine);
+    linker
+        .root()
+        .func_wrap("double", |_, (x,): (u32,)| Ok((x * 2,)))?;
+    let mut store = Store::new(&engine, ());
+    let instance = linker.instantiate(&mut store, &component)?;
+    let run = instance.get_typed_func::<(u32,), (u32,)>(&mut store, "run")?;
+    assert_eq!(run.call(&mut store, (21,))?, (42,));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn component_resource_destructor() -> Result<()> {
+    use wasmtime::component::{Component, Linker};
+
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.wasm_component_model(true);
+    let engine = Engine::new(&c)?;
+    let component = Component::new(
+        &engine,
+        r#"
+        (component
+          (core module $dtor
+            (global $dropped (mut i32) (i32.const 0))
+            (func (export "dtor") (param i32)
+              (global.set $dropped (local.get 0)))
+            (func (export "dropped") (result i32)
+              (global.get $dropped)))
+          (core instance $dtor (instantiate $dtor))
+          (type $r (resource (rep i32) (dtor (func $dtor "dtor"))))
+          (core func $new (canon resource.new $r))
+          (core func $drop (canon resource.drop $r))
+
+          (core module $m
+            (import "" "new" (func $new (param i32) (result i32)))
+            (import "" "drop" (func $drop (param i32)))
+
+            (func (export "run") (param i32)
+              (call $drop (call $new (local.get 0)))))
+          (core instance $i (instantiate $m
+            (with "" (instance
+              (export "new" (func $new))
+              (export "drop" (func $drop))))))
+
+          (func (export "run") (param "x" u32)
+            (canon lift (core func $i "run")))
+          (func (export "dropped") (result u32)
+            (canon lift (core func $dtor "dropped"))))
+        "#,
+    )?;
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine).instantiate(&mut store, &component)?;
+    let run = instance.get_typed_func::<(u32,), ()>(&mut store, "run")?;
+    run.call(&mut store, (7,))?;
+    run.post_return(&mut store)?;
+    let dropped = instance.get_typed_func::<(), (u32,)>(&mut store, "dropped")?;
+    assert_eq!(dropped.call(&mut store, ())?, (7,));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn component_always_trap() -> Result<()> {
+    use wasmtime::component::{Component, Linker};
+
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.wasm_component_model(true);
+    let engine = Engine::new(&c)?;
+    let component = Component::new(
+        &engine,
+        r#"
+        (component
+          (core module $m
+            (func (export "f")))
+          (core instance $i (instantiate $m))
+          (func $f (canon lift (core func $i "f")))
+          (core func $f (canon lower (func $f)))
+
+          (core module $m2
+            (import "" "f" (func $f))
+            (func (export "run")
+              (call $f)))
+          (core instance $i2 (instantiate $m2
+            (with "" (instance (export "f" (func $f))))))
+
+          (func (export "run")
+            (canon lift (core func $i2 "run"))))
+        "#,
+    )?;
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine).instantiate(&mut store, &component)?;
+    let run = instance.get_typed_func::<(), ()>(&mut store, "run")?;
+    let err = run.call(&mut store, ()).unwrap_err();
+    assert_eq!(err.downcast::<Trap>()?, Trap::AlwaysTrapAdapter);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn component_string_transcoding() -> Result<()> {
+    use wasmtime::component::{Component, Linker};
+
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.wasm_component_model(true);
+    let engine = Engine::new(&c)?;
+    // Passing a UTF-16 string to a UTF-8 function goes through a fused
+    // adapter which calls the host's transcoder.
+    let component = Component::new(
+        &engine,
+        r#"
+        (component
+          (component $callee
+            (core module $m
+              (memory (export "memory") 1)
+              (global $next (mut i32) (i32.const 1024))
+              ;; A bump allocator which grows the latest allocation in place.
+              (func (export "realloc") (param i32 i32 i32 i32) (result i32)
+                (local $ret i32)
+                (if (local.get 0)
+                  (then (global.set $next (local.get 0))))
+                (local.set $ret (global.get $next))
+                (global.set $next (i32.add (global.get $next) (local.get 3)))
+                (local.get $ret))
+              ;; Returns the length of the UTF-8 string in the lower half and
+              ;; the sum of its bytes in the upper half.
+              (func (export "summarize") (param $ptr i32) (param $len i32) (result i32)
+                (local $i i32)
+                (local $sum i32)
+                (block $done
+                  (loop $loop
+                    (br_if $done (i32.ge_u (local.get $i) (local.get $len)))
+                    (local.set $sum
+                      (i32.add
+                        (local.get $sum)
+                        (i32.load8_u (i32.add (local.get $ptr) (local.get $i)))))
+                    (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                    (br $loop)))
+                (i32.or
+                  (local.get $len)
+                  (i32.shl (local.get $sum) (i32.const 16)))))
+            (core instance $i (instantiate $m))
+            (func (export "summarize") (param "s" string) (result u32)
+              (canon lift (core func $i "summarize")
+                (memory $i "memory")
+                (realloc (func $i "realloc")))))
+          (instance $callee (instantiate $callee))
+
+          (core module $libc
+            (memory (export "memory") 1))
+          (core instance $libc (instantiate $libc))
+          (core func $summarize (canon lower (func $callee "summarize")
+            string-encoding=utf16
+            (memory $libc "memory")))
+
+          (core module $caller
+            (import "" "memory" (memory 1))
+            (import "" "summarize" (func $summarize (param i32 i32) (result i32)))
+            ;; "hé" as UTF-16.
+            (data (i32.const 16) "h\00\e9\00")
+            (func (export "run") (result i32)
+              (call $summarize (i32.const 16) (i32.const 2))))
+          (core instance $caller (instantiate $caller
+            (with "" (instance
+              (export "memory" (memory $libc "memory"))
+              (export "summarize" (func $summarize))))))
+
+          (func (export "run") (result u32)
+            (canon lift (core func $caller "run"))))
+        "#,
+    )?;
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine).instantiate(&mut store, &component)?;
+    let run = instance.get_typed_func::<(), (u32,)>(&mut store, "run")?;
+    // "hé" is 3 bytes of UTF-8: 0x68, 0xc3 and 0xa9.
+    let (summary,) = run.call(&mut store, ())?;
+    assert_eq!(summary, 3 | ((0x68 + 0xc3 + 0xa9) << 16));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn nan_canonicalization() -> Result<()> {
+    let wat = r#"
+        (module
+          (func (export "f32") (param f32 f32) (result f32)
+            (f32.add (local.get 0) (local.get 1)))
+          (func (export "f64") (param f64) (result f64)
+            (f64.sqrt (local.get 0))))
+    "#;
+    let nan32 = f32::from_bits(0x7fe0_0000);
+    let nan64 = f64::from_bits(0x7ffc_0000_0000_0000);
+    for (canonicalize, bits32, bits64) in [
+        (true, 0x7fc0_0000, 0x7ff8_0000_0000_0000),
+        (false, 0x7fe0_0000, 0x7ffc_0000_0000_0000),
+    ] {
+        let mut c = Config::new();
+        c.strategy(Strategy::Winch);
+        c.cranelift_nan_canonicalization(canonicalize);
+        let engine = Engine::new(&c)?;
+        let mut store = Store::new(&engine, ());
+        let module = Module::new(&engine, wat)?;
+        let instance = Instance::new(&mut store, &module, &[])?;
+
+        let f32 = instance.get_typed_func::<(f32, f32), f32>(&mut store, "f32")?;
+        assert_eq!(f32.call(&mut store, (nan32, 1.0))?.to_bits(), bits32);
+        // Results which aren't NaN are left alone.
+        assert_eq!(f32.call(&mut store, (1.0, 2.0))?, 3.0);
+
+        let f64 = instance.get_typed_func::<f64, f64>(&mut store, "f64")?;
+        assert_eq!(f64.call(&mut store, nan64)?.to_bits(), bits64);
+        assert_eq!(f64.call(&mut store, 4.0)?, 2.0);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn out_of_bounds_guard_page() -> Result<()> {
+    let wat = r#"
+        (module
+          (memory 1)
+          (func (export "load") (param i32) (result i32)
+            (i32.load offset=4 (local.get 0))))
+    "#;
+    // Accesses to static memories fault in the guard region instead of being
+    // checked explicitly; both must be reported as out-of-bounds traps.
+    for static_memory_bound in [0, 0x1_0000] {
+        let mut c = Config::new();
+        c.strategy(Strategy::Winch);
+        c.static_memory_maximum_size(static_memory_bound << 16);
+        let engine = Engine::new(&c)?;
+        let mut store = Store::new(&engine, ());
+        let module = Module::new(&engine, wat)?;
+        let instance = Instance::new(&mut store, &module, &[])?;
+        let load = instance.get_typed_func::<i32, i32>(&mut store, "load")?;
+
+        assert_eq!(load.call(&mut store, 65532 - 4)?, 0);
+        for addr in [65532 - 3, 65536, -1] {
+            let trap = load
+                .call(&mut store, addr)
+                .unwrap_err()
+                .downcast::<Trap>()?;
+            assert_eq!(trap, Trap::MemoryOutOfBounds);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn codegen_stats() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.codegen_stats(true);
+    let engine = Engine::new(&c)?;
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+              (import "" "" (func))
+              (func (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1))
+                (i32.add (local.get 0))
+                return
+                (i32.add (local.get 0) (local.get 1))))
+        "#,
+    )?;
+    // Statistics survive serialization of the compiled artifact.
+    let module = unsafe { Module::deserialize(&engine, module.serialize()?)? };
+
+    let stats = module.codegen_stats().collect::<Vec<_>>();
+    assert_eq!(stats.len(), 1);
+    let (index, stats) = stats[0];
+    assert_eq!(index, 1);
+    // The `i32.add` after the `return` is unreachable and isn't compiled.
+    let add = &stats.opcodes["I32Add"];
+    assert_eq!(add.count, 2);
+    assert!(add.code_size > 0);
+    assert_eq!(stats.opcodes["LocalGet"].count, 3);
+    assert!(stats.code_size() >= add.code_size);
+    assert!(stats.machine_code_size > stats.code_size());
+    assert_eq!(stats.constant_pool_size, 0);
+    assert!(stats.compile_time > std::time::Duration::ZERO);
+
+    // Float constants are loaded from the constant pool and calls to
+    // imported functions go through the vmctx, without relocations. Calls
+    // to defined functions need a relocation each.
+    let module = Module::new(
+        &engine,
+        r#"
+            (module
+              (import "" "" (func))
+              (func (result f64)
+                call 0
+                call 2
+                (f64.add (f64.const 1) (f64.const 2)))
+              (func))
+        "#,
+    )?;
+    let (_, stats) = module.codegen_stats().next().unwrap();
+    assert_eq!(stats.constant_pool_size, 16);
+    assert_eq!(stats.relocations, 1);
+
+    // Nothing is recorded unless requested.
+    let engine = Engine::new(Config::new().strategy(Strategy::Winch))?;
+    let module = Module::new(&engine, "(module (func))")?;
+    assert_eq!(module.codegen_stats().count(), 0);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn repeated_call_indirect_signature_checks() -> Result<()> {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    let engine = Engine::new(&c)?;
+    let wat = r#"
+        (module
+          (type $unary (func (param i32) (result i32)))
+          (table funcref (elem $inc $nop))
+          (func $inc (param i32) (result i32)
+            (i32.add (local.get 0) (i32.const 1)))
+          (func $nop)
+          ;; Both calls share a type, so the expected signature id is loaded
+          ;; once in the prologue.
+          (func (export "twice") (param i32 i32) (result i32)
+            (call_indirect (type $unary)
+              (call_indirect (type $unary) (i32.const 1) (local.get 0))
+              (local.get 1))))
+    "#;
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, wat)?;
+    let instance = Instance::new(&mut store, &module, &[])?;
+    let twice = instance.get_typed_func::<(i32, i32), i32>(&mut store, "twice")?;
+
+    assert_eq!(twice.call(&mut store, (0, 0))?, 3);
+    for (first, second) in [(1, 0), (0, 1)] {
+        let trap = twice
+            .call(&mut store, (first, second))
+            .unwrap_err()
+            .downcast::<Trap>()?;
+        assert_eq!(trap, Trap::BadSignature);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn compile_time_budget_is_rejected() {
+    let mut c = Config::new();
+    c.strategy(Strategy::Winch);
+    c.cranelift_compile_time_budget(Some(std::time::Duration::from_secs(1)));
+    let Err(err) = Engine::new(&c) else {
+        panic!("budgets should be rejected with Winch");
+    };
+    assert!(
+        format!("{err:?}").contains("compile-time budgets are not supported"),
+        "unexpected error: {err:?}"
+    );
+}
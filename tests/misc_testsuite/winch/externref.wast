@@ -0,0 +1,68 @@
+(module
+  (global $g (mut externref) (ref.null extern))
+  (table $t 0 externref)
+
+  (func $id (param externref) (result externref)
+    (local.get 0)
+  )
+  (func $nop)
+
+  (func (export "null") (result externref)
+    (ref.null extern)
+  )
+  (func (export "is-null") (param externref) (result i32)
+    (ref.is_null (local.get 0))
+  )
+  (func (export "call") (param externref) (result externref)
+    (call $id (local.get 0))
+  )
+  ;; Keeps the reference in a local across a call.
+  (func (export "live-across-call") (param externref) (result externref)
+    (local $r externref)
+    (local.set $r (local.get 0))
+    (call $nop)
+    (local.get $r)
+  )
+  (func (export "global-set") (param externref)
+    (global.set $g (local.get 0))
+  )
+  (func (export "global-get") (result externref)
+    (global.get $g)
+  )
+  (func (export "grow") (param externref i32) (result i32)
+    (table.grow $t (local.get 0) (local.get 1))
+  )
+  (func (export "fill") (param i32 externref i32)
+    (table.fill $t (local.get 0) (local.get 1) (local.get 2))
+  )
+  (func (export "size") (result i32)
+    (table.size $t)
+  )
+  (func (export "get") (param i32) (result externref)
+    (table.get $t (local.get 0))
+  )
+  (func (export "set") (param i32 externref)
+    (table.set $t (local.get 0) (local.get 1))
+  )
+)
+
+(assert_return (invoke "null") (ref.null extern))
+(assert_return (invoke "is-null" (ref.null extern)) (i32.const 1))
+(assert_return (invoke "is-null" (ref.extern 1)) (i32.const 0))
+(assert_return (invoke "call" (ref.extern 1)) (ref.extern 1))
+(assert_return (invoke "call" (ref.null extern)) (ref.null extern))
+(assert_return (invoke "live-across-call" (ref.extern 2)) (ref.extern 2))
+(assert_return (invoke "global-get") (ref.null extern))
+(assert_return (invoke "global-set" (ref.extern 3)))
+(assert_return (invoke "global-get") (ref.extern 3))
+(assert_return (invoke "grow" (ref.extern 4) (i32.const 2)) (i32.const 0))
+(assert_return (invoke "size") (i32.const 2))
+(assert_return (invoke "fill" (i32.const 0) (ref.extern 5) (i32.const 2)))
+(assert_trap (invoke "fill" (i32.const 1) (ref.extern 5) (i32.const 2)) "out of bounds table access")
+(assert_return (invoke "get" (i32.const 1)) (ref.extern 5))
+(assert_return (invoke "set" (i32.const 1) (ref.extern 6)))
+(assert_return (invoke "get" (i32.const 1)) (ref.extern 6))
+(assert_return (invoke "set" (i32.const 0) (ref.null extern)))
+(assert_return (invoke "get" (i32.const 0)) (ref.null extern))
+(assert_trap (invoke "get" (i32.const 2)) "out of bounds table access")
+(assert_trap (invoke "set" (i32.const 2) (ref.extern 7)) "out of bounds table access")
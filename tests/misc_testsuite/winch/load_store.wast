@@ -0,0 +1,60 @@
+(module
+  (memory 1)
+  (data (i32.const 0) "\80\ff\7f\00\fe\ff\ff\ff\ff\ff\ff\ff")
+
+  (func (export "i32.load") (param i32) (result i32) (i32.load (local.get 0)))
+  (func (export "i64.load") (param i32) (result i64) (i64.load (local.get 0)))
+  (func (export "i32.load8_s") (param i32) (result i32) (i32.load8_s (local.get 0)))
+  (func (export "i32.load8_u") (param i32) (result i32) (i32.load8_u (local.get 0)))
+  (func (export "i32.load16_s") (param i32) (result i32) (i32.load16_s (local.get 0)))
+  (func (export "i32.load16_u") (param i32) (result i32) (i32.load16_u (local.get 0)))
+  (func (export "i64.load8_s") (param i32) (result i64) (i64.load8_s (local.get 0)))
+  (func (export "i64.load8_u") (param i32) (result i64) (i64.load8_u (local.get 0)))
+  (func (export "i64.load16_s") (param i32) (result i64) (i64.load16_s (local.get 0)))
+  (func (export "i64.load16_u") (param i32) (result i64) (i64.load16_u (local.get 0)))
+  (func (export "i64.load32_s") (param i32) (result i64) (i64.load32_s (local.get 0)))
+  (func (export "i64.load32_u") (param i32) (result i64) (i64.load32_u (local.get 0)))
+  (func (export "i64.load32_u_offset") (param i32) (result i64) (i64.load32_u offset=4 (local.get 0)))
+
+  (func (export "i32.store") (param i32 i32) (i32.store (local.get 0) (local.get 1)))
+  (func (export "i64.store") (param i32 i64) (i64.store (local.get 0) (local.get 1)))
+  (func (export "i32.store8") (param i32 i32) (i32.store8 (local.get 0) (local.get 1)))
+  (func (export "i32.store16") (param i32 i32) (i32.store16 (local.get 0) (local.get 1)))
+  (func (export "i64.store8") (param i32 i64) (i64.store8 (local.get 0) (local.get 1)))
+  (func (export "i64.store16") (param i32 i64) (i64.store16 (local.get 0) (local.get 1)))
+  (func (export "i64.store32") (param i32 i64) (i64.store32 (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "i32.load8_s" (i32.const 0)) (i32.const -128))
+(assert_return (invoke "i32.load8_u" (i32.const 0)) (i32.const 128))
+(assert_return (invoke "i32.load16_s" (i32.const 0)) (i32.const -128))
+(assert_return (invoke "i32.load16_u" (i32.const 0)) (i32.const 65408))
+(assert_return (invoke "i32.load16_s" (i32.const 1)) (i32.const 32767))
+(assert_return (invoke "i64.load8_s" (i32.const 1)) (i64.const -1))
+(assert_return (invoke "i64.load8_u" (i32.const 1)) (i64.const 255))
+(assert_return (invoke "i64.load16_s" (i32.const 4)) (i64.const -2))
+(assert_return (invoke "i64.load16_u" (i32.const 4)) (i64.const 65534))
+(assert_return (invoke "i64.load32_s" (i32.const 4)) (i64.const -2))
+(assert_return (invoke "i64.load32_u" (i32.const 4)) (i64.const 4294967294))
+(assert_return (invoke "i64.load32_u_offset" (i32.const 0)) (i64.const 4294967294))
+(assert_return (invoke "i32.load" (i32.const 0)) (i32.const 0x7fff80))
+(assert_return (invoke "i64.load" (i32.const 4)) (i64.const -2))
+
+(assert_return (invoke "i32.store8" (i32.const 16) (i32.const 0x1234)))
+(assert_return (invoke "i32.load" (i32.const 16)) (i32.const 0x34))
+(assert_return (invoke "i32.store16" (i32.const 16) (i32.const 0x123456)))
+(assert_return (invoke "i32.load" (i32.const 16)) (i32.const 0x3456))
+(assert_return (invoke "i32.store" (i32.const 16) (i32.const -1)))
+(assert_return (invoke "i64.store8" (i32.const 16) (i64.const 0)))
+(assert_return (invoke "i32.load" (i32.const 16)) (i32.const 0xffffff00))
+(assert_return (invoke "i64.store16" (i32.const 16) (i64.const 0)))
+(assert_return (invoke "i32.load" (i32.const 16)) (i32.const 0xffff0000))
+(assert_return (invoke "i64.store" (i32.const 16) (i64.const -1)))
+(assert_return (invoke "i64.store32" (i32.const 16) (i64.const 0x123456789)))
+(assert_return (invoke "i64.load" (i32.const 16)) (i64.const 0xffffffff23456789))
+
+(assert_trap (invoke "i32.load8_u" (i32.const 65536)) "out of bounds memory access")
+(assert_trap (invoke "i32.load16_s" (i32.const 65535)) "out of bounds memory access")
+(assert_trap (invoke "i64.load32_u_offset" (i32.const 65532)) "out of bounds memory access")
+(assert_trap (invoke "i64.store8" (i32.const -1) (i64.const 0)) "out of bounds memory access")
+(assert_trap (invoke "i32.store16" (i32.const 65535) (i32.const 0)) "out of bounds memory access")
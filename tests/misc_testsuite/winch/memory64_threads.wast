@@ -0,0 +1,65 @@
+(module
+  (memory i64 1)
+
+  (func (export "i32.load") (param i64) (result i32)
+    (i32.load (local.get 0)))
+  (func (export "i64.load8_u") (param i64) (result i64)
+    (i64.load8_u offset=0x10 (local.get 0)))
+  (func (export "i64.store") (param i64 i64)
+    (i64.store (local.get 0) (local.get 1)))
+  (func (export "load.max_offset") (param i64) (result i32)
+    (i32.load offset=0xfffffffffffffff0 (local.get 0)))
+
+  (func (export "size") (result i64)
+    (memory.size))
+  (func (export "grow") (param i64) (result i64)
+    (memory.grow (local.get 0)))
+  (func (export "fill") (param i64 i32 i64)
+    (memory.fill (local.get 0) (local.get 1) (local.get 2)))
+  (func (export "copy") (param i64 i64 i64)
+    (memory.copy (local.get 0) (local.get 1) (local.get 2)))
+
+  (func (export "i32.atomic.load") (param i64) (result i32)
+    (i32.atomic.load (local.get 0)))
+  (func (export "i64.atomic.rmw.add") (param i64 i64) (result i64)
+    (i64.atomic.rmw.add offset=8 (local.get 0) (local.get 1)))
+  (func (export "i32.atomic.rmw8.cmpxchg_u") (param i64 i32 i32) (result i32)
+    (i32.atomic.rmw8.cmpxchg_u (local.get 0) (local.get 1) (local.get 2)))
+  (func (export "notify") (param i64 i32) (result i32)
+    (memory.atomic.notify offset=4 (local.get 0) (local.get 1)))
+)
+
+(assert_return (invoke "size") (i64.const 1))
+(assert_return (invoke "i64.store" (i64.const 0) (i64.const 0x0102030405060708)))
+(assert_return (invoke "i32.load" (i64.const 4)) (i32.const 0x01020304))
+(assert_return (invoke "i64.load8_u" (i64.const 0)) (i64.const 0))
+(assert_trap (invoke "i64.load8_u" (i64.const -0x10)) "out of bounds memory access")
+(assert_return (invoke "i32.load" (i64.const 0xfffc)) (i32.const 0))
+(assert_trap (invoke "i32.load" (i64.const 0xfffd)) "out of bounds memory access")
+(assert_trap (invoke "i32.load" (i64.const 0x100000000)) "out of bounds memory access")
+(assert_trap (invoke "i32.load" (i64.const -1)) "out of bounds memory access")
+(assert_trap (invoke "load.max_offset" (i64.const 0x10)) "out of bounds memory access")
+
+(assert_return (invoke "fill" (i64.const 0x100) (i32.const 0xaa) (i64.const 4)))
+(assert_return (invoke "copy" (i64.const 0x200) (i64.const 0x100) (i64.const 4)))
+(assert_return (invoke "i32.load" (i64.const 0x200)) (i32.const 0xaaaaaaaa))
+(assert_trap (invoke "fill" (i64.const 0x10000) (i32.const 0) (i64.const 1)) "out of bounds memory access")
+
+(assert_return (invoke "grow" (i64.const 1)) (i64.const 1))
+(assert_return (invoke "size") (i64.const 2))
+(assert_return (invoke "i32.load" (i64.const 0x1fffc)) (i32.const 0))
+(assert_return (invoke "grow" (i64.const 0x1000000000000)) (i64.const -1))
+
+(assert_return (invoke "i32.atomic.load" (i64.const 0)) (i32.const 0x05060708))
+(assert_return (invoke "i64.atomic.rmw.add" (i64.const 0) (i64.const 1)) (i64.const 0))
+(assert_return (invoke "i64.atomic.rmw.add" (i64.const 0) (i64.const 1)) (i64.const 1))
+(assert_return (invoke "i32.atomic.rmw8.cmpxchg_u" (i64.const 0) (i32.const 0x108) (i32.const 0x09)) (i32.const 0x08))
+(assert_return (invoke "i32.atomic.load" (i64.const 0)) (i32.const 0x05060709))
+(assert_trap (invoke "i32.atomic.load" (i64.const 2)) "unaligned atomic")
+(assert_trap (invoke "i32.atomic.load" (i64.const 0x20000)) "out of bounds memory access")
+(assert_trap (invoke "i64.atomic.rmw.add" (i64.const -8) (i64.const 1)) "out of bounds memory access")
+
+;; Notifying an unshared memory wakes no threads.
+(assert_return (invoke "notify" (i64.const 0) (i32.const 1)) (i32.const 0))
+(assert_trap (invoke "notify" (i64.const -4) (i32.const 1)) "out of bounds memory access")
+(assert_trap (invoke "notify" (i64.const 0x1fffc) (i32.const 1)) "out of bounds memory access")
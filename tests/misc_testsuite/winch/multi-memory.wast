@@ -0,0 +1,49 @@
+(module $m
+  (memory (export "mem") 1)
+)
+(register "m" $m)
+
+(module
+  (import "m" "mem" (memory $imported 1))
+  (memory $a 1 2)
+  (memory $b 0)
+
+  (func (export "store-a") (param i32 i32)
+    (i32.store $a (local.get 0) (local.get 1)))
+  (func (export "load-a") (param i32) (result i32)
+    (i32.load $a (local.get 0)))
+  (func (export "store-imported") (param i32 i32)
+    (i32.store $imported (local.get 0) (local.get 1)))
+  (func (export "load-imported") (param i32) (result i32)
+    (i32.load $imported (local.get 0)))
+  (func (export "load-b") (param i32) (result i32)
+    (i32.load8_u $b (local.get 0)))
+  (func (export "size-a") (result i32)
+    (memory.size $a))
+  (func (export "size-b") (result i32)
+    (memory.size $b))
+  (func (export "grow-a") (param i32) (result i32)
+    (memory.grow $a (local.get 0)))
+  (func (export "grow-b") (param i32) (result i32)
+    (memory.grow $b (local.get 0)))
+)
+
+(assert_return (invoke "store-a" (i32.const 8) (i32.const 42)))
+(assert_return (invoke "store-imported" (i32.const 8) (i32.const 7)))
+(assert_return (invoke "load-a" (i32.const 8)) (i32.const 42))
+(assert_return (invoke "load-imported" (i32.const 8)) (i32.const 7))
+
+;; Each memory is bounds-checked against its own length.
+(assert_trap (invoke "load-b" (i32.const 0)) "out of bounds memory access")
+(assert_trap (invoke "load-a" (i32.const 65533)) "out of bounds memory access")
+(assert_return (invoke "load-a" (i32.const 65532)) (i32.const 0))
+
+(assert_return (invoke "size-a") (i32.const 1))
+(assert_return (invoke "size-b") (i32.const 0))
+(assert_return (invoke "grow-b" (i32.const 1)) (i32.const 0))
+(assert_return (invoke "size-b") (i32.const 1))
+(assert_return (invoke "load-b" (i32.const 65535)) (i32.const 0))
+(assert_return (invoke "grow-a" (i32.const 1)) (i32.const 1))
+(assert_return (invoke "grow-a" (i32.const 1)) (i32.const -1))
+(assert_return (invoke "size-a") (i32.const 2))
+(assert_return (invoke "load-a" (i32.const 8)) (i32.const 42))
@@ -0,0 +1,69 @@
+;; Test functions returning multiple values
+
+(module
+  (func $pair (result i32 i64) (i32.const 1) (i64.const 2))
+  (func $mixed (param i32) (result f32 i32 f64 i64)
+    (f32.const 1.5)
+    (local.get 0)
+    (f64.const 2.5)
+    (i64.const 4)
+  )
+  (func $two (result i64 i64) (i64.const 1) (i64.const 2))
+  ;; Enough parameters for the return area pointer to be passed on the stack.
+  (func $many-params (param i64 i64 i64 i64 i64 i64 i64) (result i64 i64 i64)
+    (local.get 0)
+    (local.get 5)
+    (local.get 6)
+  )
+
+  (func (export "pair") (result i32 i64) (call $pair))
+  (func (export "mixed") (param i32) (result f32 i32 f64 i64) (call $mixed (local.get 0)))
+  (func (export "many-params") (result i64 i64 i64)
+    (call $many-params
+      (i64.const 1) (i64.const 2) (i64.const 3) (i64.const 4)
+      (i64.const 5) (i64.const 6) (i64.const 7)
+    )
+  )
+
+  (func (export "sum") (result i64)
+    (i64.add (call $two))
+  )
+  (func (export "reverse-mixed") (param i32) (result i64 f64 i32 f32)
+    (local f32 i32 f64 i64)
+    (call $mixed (local.get 0))
+    (local.set 4)
+    (local.set 3)
+    (local.set 2)
+    (local.set 1)
+    (local.get 4)
+    (local.get 3)
+    (local.get 2)
+    (local.get 1)
+  )
+
+  (func (export "return") (param i32) (result i32 i64 i32)
+    (block
+      (br_if 0 (local.get 0))
+      (return (i32.const 1) (i64.const 2) (i32.const 3))
+    )
+    (i32.const 4) (i64.const 5) (i32.const 6)
+  )
+  (func (export "br_if") (param i32) (result i32 i64 i32)
+    (i32.const 1) (i64.const 2) (i32.const 3)
+    (br_if 0 (local.get 0))
+    (drop) (drop) (drop)
+    (i32.const 4) (i64.const 5) (i32.const 6)
+  )
+)
+
+(assert_return (invoke "pair") (i32.const 1) (i64.const 2))
+(assert_return (invoke "mixed" (i32.const 3))
+  (f32.const 1.5) (i32.const 3) (f64.const 2.5) (i64.const 4))
+(assert_return (invoke "many-params") (i64.const 1) (i64.const 6) (i64.const 7))
+(assert_return (invoke "sum") (i64.const 3))
+(assert_return (invoke "reverse-mixed" (i32.const 3))
+  (i64.const 4) (f64.const 2.5) (i32.const 3) (f32.const 1.5))
+(assert_return (invoke "return" (i32.const 0)) (i32.const 1) (i64.const 2) (i32.const 3))
+(assert_return (invoke "return" (i32.const 1)) (i32.const 4) (i64.const 5) (i32.const 6))
+(assert_return (invoke "br_if" (i32.const 1)) (i32.const 1) (i64.const 2) (i32.const 3))
+(assert_return (invoke "br_if" (i32.const 0)) (i32.const 4) (i64.const 5) (i32.const 6))
@@ -0,0 +1,39 @@
+(module
+  (table $t 2 funcref)
+  (elem declare func $f)
+  (func $f (result i32) (i32.const 42))
+
+  (func (export "null") (result funcref)
+    (ref.null func)
+  )
+  (func (export "is-null-null") (result i32)
+    (ref.is_null (ref.null func))
+  )
+  (func (export "is-null-func") (result i32)
+    (ref.is_null (ref.func $f))
+  )
+  (func (export "is-null-elem") (param $i i32) (result i32)
+    (ref.is_null (table.get $t (local.get $i)))
+  )
+  (func (export "set-func") (param $i i32)
+    (table.set $t (local.get $i) (ref.func $f))
+  )
+  (func (export "set-null") (param $i i32)
+    (table.set $t (local.get $i) (ref.null func))
+  )
+  (func (export "call") (param $i i32) (result i32)
+    (call_indirect $t (result i32) (local.get $i))
+  )
+)
+
+(assert_return (invoke "null") (ref.null func))
+(assert_return (invoke "is-null-null") (i32.const 1))
+(assert_return (invoke "is-null-func") (i32.const 0))
+(assert_return (invoke "is-null-elem" (i32.const 1)) (i32.const 1))
+(assert_return (invoke "set-func" (i32.const 1)))
+(assert_return (invoke "is-null-elem" (i32.const 1)) (i32.const 0))
+(assert_return (invoke "call" (i32.const 1)) (i32.const 42))
+(assert_return (invoke "set-null" (i32.const 1)))
+(assert_return (invoke "is-null-elem" (i32.const 1)) (i32.const 1))
+(assert_trap (invoke "call" (i32.const 1)) "uninitialized element")
+(assert_trap (invoke "call" (i32.const 2)) "undefined element")
@@ -0,0 +1,403 @@
+(module
+  (func (export "const") (result v128)
+    (v128.const i32x4 1 2 3 4)
+  )
+  (func (export "i8x16.add") (param v128 v128) (result v128)
+    (i8x16.add (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.sub") (param v128 v128) (result v128)
+    (i16x8.sub (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.mul") (param v128 v128) (result v128)
+    (i16x8.mul (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.add") (param v128 v128) (result v128)
+    (i64x2.add (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.add") (param v128 v128) (result v128)
+    (f32x4.add (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.div") (param v128 v128) (result v128)
+    (f64x2.div (local.get 0) (local.get 1))
+  )
+  (func (export "v128.and") (param v128 v128) (result v128)
+    (v128.and (local.get 0) (local.get 1))
+  )
+  (func (export "v128.andnot") (param v128 v128) (result v128)
+    (v128.andnot (local.get 0) (local.get 1))
+  )
+  (func (export "v128.or") (param v128 v128) (result v128)
+    (v128.or (local.get 0) (local.get 1))
+  )
+  (func (export "v128.xor") (param v128 v128) (result v128)
+    (v128.xor (local.get 0) (local.get 1))
+  )
+  (func (export "v128.not") (param v128) (result v128)
+    (v128.not (local.get 0))
+  )
+  (func (export "i32x4.eq") (param v128 v128) (result v128)
+    (i32x4.eq (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.lt_s") (param v128 v128) (result v128)
+    (i32x4.lt_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.ge_s") (param v128 v128) (result v128)
+    (i16x8.ge_s (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.gt") (param v128 v128) (result v128)
+    (f32x4.gt (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.ne") (param v128 v128) (result v128)
+    (f64x2.ne (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.convert_i32x4_s") (param v128) (result v128)
+    (f32x4.convert_i32x4_s (local.get 0))
+  )
+  (func (export "f64x2.promote_low_f32x4") (param v128) (result v128)
+    (f64x2.promote_low_f32x4 (local.get 0))
+  )
+  (func (export "i32x4.splat") (param i32) (result v128)
+    (i32x4.splat (local.get 0))
+  )
+  (func (export "i16x8.splat") (param i32) (result v128)
+    (i16x8.splat (local.get 0))
+  )
+  (func (export "f64x2.splat") (param f64) (result v128)
+    (f64x2.splat (local.get 0))
+  )
+  (func (export "spill") (param v128) (result v128)
+    (local v128)
+    (local.set 1 (i32x4.add (local.get 0) (local.get 0)))
+    (i32x4.add (local.get 1) (local.get 0))
+  )
+)
+
+(assert_return (invoke "const") (v128.const i32x4 1 2 3 4))
+(assert_return (invoke "i8x16.add" (v128.const i8x16 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 127) (v128.const i8x16 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1 1))
+               (v128.const i8x16 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 -128))
+(assert_return (invoke "i16x8.sub" (v128.const i16x8 10 20 30 40 50 60 70 0) (v128.const i16x8 1 2 3 4 5 6 7 1))
+               (v128.const i16x8 9 18 27 36 45 54 63 -1))
+(assert_return (invoke "i16x8.mul" (v128.const i16x8 1 2 3 4 5 6 7 -8) (v128.const i16x8 2 2 2 2 2 2 2 2))
+               (v128.const i16x8 2 4 6 8 10 12 14 -16))
+(assert_return (invoke "i64x2.add" (v128.const i64x2 -1 0x7fffffffffffffff) (v128.const i64x2 1 1))
+               (v128.const i64x2 0 0x8000000000000000))
+(assert_return (invoke "f32x4.add" (v128.const f32x4 1.5 2.5 -1.0 0.0) (v128.const f32x4 1.0 1.0 1.0 -0.0))
+               (v128.const f32x4 2.5 3.5 0.0 0.0))
+(assert_return (invoke "f64x2.div" (v128.const f64x2 1.0 9.0) (v128.const f64x2 2.0 3.0))
+               (v128.const f64x2 0.5 3.0))
+(assert_return (invoke "v128.and" (v128.const i32x4 0xff00ff00 0 -1 0x0f0f0f0f) (v128.const i32x4 0x0ff00ff0 -1 0 -1))
+               (v128.const i32x4 0x0f000f00 0 0 0x0f0f0f0f))
+(assert_return (invoke "v128.andnot" (v128.const i32x4 0xff00ff00 0 -1 0x0f0f0f0f) (v128.const i32x4 0x0ff00ff0 -1 0 -1))
+               (v128.const i32x4 0xf000f000 0 -1 0))
+(assert_return (invoke "v128.or" (v128.const i32x4 0xff000000 0 1 2) (v128.const i32x4 0x000000ff 0 2 2))
+               (v128.const i32x4 0xff0000ff 0 3 2))
+(assert_return (invoke "v128.xor" (v128.const i32x4 0xff000000 0 1 2) (v128.const i32x4 0x000000ff 0 2 2))
+               (v128.const i32x4 0xff0000ff 0 3 0))
+(assert_return (invoke "v128.not" (v128.const i32x4 0 -1 0x0f0f0f0f 1))
+               (v128.const i32x4 -1 0 0xf0f0f0f0 0xfffffffe))
+(assert_return (invoke "i32x4.eq" (v128.const i32x4 1 2 3 4) (v128.const i32x4 1 0 3 0))
+               (v128.const i32x4 -1 0 -1 0))
+(assert_return (invoke "i32x4.lt_s" (v128.const i32x4 -1 2 3 4) (v128.const i32x4 0 2 1 5))
+               (v128.const i32x4 -1 0 0 -1))
+(assert_return (invoke "i16x8.ge_s" (v128.const i16x8 -1 2 3 4 0 0 7 -8) (v128.const i16x8 0 2 1 5 0 1 6 -9))
+               (v128.const i16x8 0 -1 -1 0 -1 0 -1 -1))
+(assert_return (invoke "f32x4.gt" (v128.const f32x4 1.0 2.0 nan -1.0) (v128.const f32x4 0.0 2.0 1.0 -2.0))
+               (v128.const i32x4 -1 0 0 -1))
+(assert_return (invoke "f64x2.ne" (v128.const f64x2 1.0 nan) (v128.const f64x2 1.0 nan))
+               (v128.const i64x2 0 -1))
+(assert_return (invoke "f32x4.convert_i32x4_s" (v128.const i32x4 1 -1 0 16777216))
+               (v128.const f32x4 1.0 -1.0 0.0 16777216.0))
+(assert_return (invoke "f64x2.promote_low_f32x4" (v128.const f32x4 1.5 -2.0 3.0 4.0))
+               (v128.const f64x2 1.5 -2.0))
+(assert_return (invoke "i32x4.splat" (i32.const 42)) (v128.const i32x4 42 42 42 42))
+(assert_return (invoke "i16x8.splat" (i32.const 0x10007)) (v128.const i16x8 7 7 7 7 7 7 7 7))
+(assert_return (invoke "f64x2.splat" (f64.const -1.5)) (v128.const f64x2 -1.5 -1.5))
+(assert_return (invoke "spill" (v128.const i32x4 1 2 3 4)) (v128.const i32x4 3 6 9 12))
+
+(module
+  (memory 1)
+  (data (i32.const 0) "\01\02\03\04\05\06\07\08\09\0a\0b\0c\0d\0e\0f\10\80\ff")
+
+  (func (export "v128.load") (param i32) (result v128)
+    (v128.load (local.get 0))
+  )
+  (func (export "v128.load8x8_s") (param i32) (result v128)
+    (v128.load8x8_s (local.get 0))
+  )
+  (func (export "v128.load16x4_u") (param i32) (result v128)
+    (v128.load16x4_u (local.get 0))
+  )
+  (func (export "v128.load8_splat") (param i32) (result v128)
+    (v128.load8_splat (local.get 0))
+  )
+  (func (export "v128.load32_zero") (param i32) (result v128)
+    (v128.load32_zero (local.get 0))
+  )
+  (func (export "v128.load16_lane") (param i32 v128) (result v128)
+    (v128.load16_lane 7 (local.get 0) (local.get 1))
+  )
+  (func (export "v128.store8_lane") (param i32 v128) (result i32)
+    (v128.store8_lane 3 (local.get 0) (local.get 1))
+    (i32.load8_u (local.get 0))
+  )
+  (func (export "v128.store") (param i32 v128) (result v128)
+    (v128.store (local.get 0) (local.get 1))
+    (v128.load (local.get 0))
+  )
+  (func (export "i8x16.extract_lane_s") (param v128) (result i32)
+    (i8x16.extract_lane_s 15 (local.get 0))
+  )
+  (func (export "i16x8.extract_lane_u") (param v128) (result i32)
+    (i16x8.extract_lane_u 7 (local.get 0))
+  )
+  (func (export "i64x2.extract_lane") (param v128) (result i64)
+    (i64x2.extract_lane 1 (local.get 0))
+  )
+  (func (export "f32x4.extract_lane") (param v128) (result f32)
+    (f32x4.extract_lane 2 (local.get 0))
+  )
+  (func (export "f64x2.extract_lane") (param v128) (result f64)
+    (f64x2.extract_lane 1 (local.get 0))
+  )
+  (func (export "i32x4.replace_lane") (param v128 i32) (result v128)
+    (i32x4.replace_lane 2 (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.replace_lane") (param v128 f32) (result v128)
+    (f32x4.replace_lane 3 (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.replace_lane") (param v128 f64) (result v128)
+    (f64x2.replace_lane 0 (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.swizzle") (param v128 v128) (result v128)
+    (i8x16.swizzle (local.get 0) (local.get 1))
+  )
+  (func (export "v128.bitselect") (param v128 v128 v128) (result v128)
+    (v128.bitselect (local.get 0) (local.get 1) (local.get 2))
+  )
+  (func (export "v128.any_true") (param v128) (result i32)
+    (v128.any_true (local.get 0))
+  )
+  (func (export "i32x4.all_true") (param v128) (result i32)
+    (i32x4.all_true (local.get 0))
+  )
+  (func (export "i16x8.bitmask") (param v128) (result i32)
+    (i16x8.bitmask (local.get 0))
+  )
+  (func (export "i8x16.popcnt") (param v128) (result v128)
+    (i8x16.popcnt (local.get 0))
+  )
+  (func (export "i64x2.abs") (param v128) (result v128)
+    (i64x2.abs (local.get 0))
+  )
+  (func (export "f32x4.neg") (param v128) (result v128)
+    (f32x4.neg (local.get 0))
+  )
+  (func (export "i8x16.shl") (param v128 i32) (result v128)
+    (i8x16.shl (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.shr_s") (param v128 i32) (result v128)
+    (i8x16.shr_s (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.shr_s") (param v128 i32) (result v128)
+    (i64x2.shr_s (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.mul") (param v128 v128) (result v128)
+    (i64x2.mul (local.get 0) (local.get 1))
+  )
+  (func (export "i64x2.gt_s") (param v128 v128) (result v128)
+    (i64x2.gt_s (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.lt_u") (param v128 v128) (result v128)
+    (i8x16.lt_u (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.ge_u") (param v128 v128) (result v128)
+    (i32x4.ge_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.add_sat_u") (param v128 v128) (result v128)
+    (i8x16.add_sat_u (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.sub_sat_s") (param v128 v128) (result v128)
+    (i16x8.sub_sat_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.min_u") (param v128 v128) (result v128)
+    (i32x4.min_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.avgr_u") (param v128 v128) (result v128)
+    (i8x16.avgr_u (local.get 0) (local.get 1))
+  )
+  (func (export "i8x16.narrow_i16x8_s") (param v128 v128) (result v128)
+    (i8x16.narrow_i16x8_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.extend_high_i16x8_u") (param v128) (result v128)
+    (i32x4.extend_high_i16x8_u (local.get 0))
+  )
+  (func (export "i64x2.extmul_high_i32x4_s") (param v128 v128) (result v128)
+    (i64x2.extmul_high_i32x4_s (local.get 0) (local.get 1))
+  )
+  (func (export "i16x8.extmul_low_i8x16_u") (param v128 v128) (result v128)
+    (i16x8.extmul_low_i8x16_u (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.extadd_pairwise_i16x8_u") (param v128) (result v128)
+    (i32x4.extadd_pairwise_i16x8_u (local.get 0))
+  )
+  (func (export "i16x8.extadd_pairwise_i8x16_s") (param v128) (result v128)
+    (i16x8.extadd_pairwise_i8x16_s (local.get 0))
+  )
+  (func (export "i16x8.q15mulr_sat_s") (param v128 v128) (result v128)
+    (i16x8.q15mulr_sat_s (local.get 0) (local.get 1))
+  )
+  (func (export "i32x4.dot_i16x8_s") (param v128 v128) (result v128)
+    (i32x4.dot_i16x8_s (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.min") (param v128 v128) (result v128)
+    (f32x4.min (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.max") (param v128 v128) (result v128)
+    (f64x2.max (local.get 0) (local.get 1))
+  )
+  (func (export "f32x4.pmin") (param v128 v128) (result v128)
+    (f32x4.pmin (local.get 0) (local.get 1))
+  )
+  (func (export "f64x2.sqrt") (param v128) (result v128)
+    (f64x2.sqrt (local.get 0))
+  )
+  (func (export "f32x4.nearest") (param v128) (result v128)
+    (f32x4.nearest (local.get 0))
+  )
+  (func (export "f32x4.convert_i32x4_u") (param v128) (result v128)
+    (f32x4.convert_i32x4_u (local.get 0))
+  )
+  (func (export "f64x2.convert_low_i32x4_u") (param v128) (result v128)
+    (f64x2.convert_low_i32x4_u (local.get 0))
+  )
+  (func (export "i32x4.trunc_sat_f32x4_s") (param v128) (result v128)
+    (i32x4.trunc_sat_f32x4_s (local.get 0))
+  )
+  (func (export "i32x4.trunc_sat_f32x4_u") (param v128) (result v128)
+    (i32x4.trunc_sat_f32x4_u (local.get 0))
+  )
+  (func (export "i32x4.trunc_sat_f64x2_s_zero") (param v128) (result v128)
+    (i32x4.trunc_sat_f64x2_s_zero (local.get 0))
+  )
+  (func (export "i32x4.trunc_sat_f64x2_u_zero") (param v128) (result v128)
+    (i32x4.trunc_sat_f64x2_u_zero (local.get 0))
+  )
+)
+
+(assert_return (invoke "v128.load" (i32.const 0))
+               (v128.const i8x16 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16))
+(assert_return (invoke "v128.load8x8_s" (i32.const 10))
+               (v128.const i16x8 11 12 13 14 15 16 -128 -1))
+(assert_return (invoke "v128.load16x4_u" (i32.const 16))
+               (v128.const i32x4 0xff80 0 0 0))
+(assert_return (invoke "v128.load8_splat" (i32.const 17))
+               (v128.const i8x16 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1 -1))
+(assert_return (invoke "v128.load32_zero" (i32.const 0))
+               (v128.const i32x4 0x04030201 0 0 0))
+(assert_return (invoke "v128.load16_lane" (i32.const 0) (v128.const i16x8 0 0 0 0 0 0 0 0))
+               (v128.const i16x8 0 0 0 0 0 0 0 0x0201))
+(assert_return (invoke "v128.store8_lane" (i32.const 100) (v128.const i8x16 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15))
+               (i32.const 3))
+(assert_return (invoke "v128.store" (i32.const 200) (v128.const i32x4 1 2 3 4))
+               (v128.const i32x4 1 2 3 4))
+(assert_trap (invoke "v128.load" (i32.const 65521)) "out of bounds memory access")
+(assert_trap (invoke "v128.store8_lane" (i32.const 65536) (v128.const i64x2 0 0)) "out of bounds memory access")
+
+(assert_return (invoke "i8x16.extract_lane_s" (v128.const i8x16 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 -2))
+               (i32.const -2))
+(assert_return (invoke "i16x8.extract_lane_u" (v128.const i16x8 0 0 0 0 0 0 0 -2))
+               (i32.const 65534))
+(assert_return (invoke "i64x2.extract_lane" (v128.const i64x2 1 -5)) (i64.const -5))
+(assert_return (invoke "f32x4.extract_lane" (v128.const f32x4 1.0 2.0 3.5 4.0)) (f32.const 3.5))
+(assert_return (invoke "f64x2.extract_lane" (v128.const f64x2 1.0 -2.5)) (f64.const -2.5))
+(assert_return (invoke "i32x4.replace_lane" (v128.const i32x4 1 2 3 4) (i32.const -1))
+               (v128.const i32x4 1 2 -1 4))
+(assert_return (invoke "f32x4.replace_lane" (v128.const f32x4 1.0 2.0 3.0 4.0) (f32.const 0.5))
+               (v128.const f32x4 1.0 2.0 3.0 0.5))
+(assert_return (invoke "f64x2.replace_lane" (v128.const f64x2 1.0 2.0) (f64.const 0.5))
+               (v128.const f64x2 0.5 2.0))
+
+(assert_return (invoke "i8x16.swizzle"
+                 (v128.const i8x16 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15)
+                 (v128.const i8x16 15 0 16 255 1 1 2 3 4 5 6 7 8 9 128 14))
+               (v128.const i8x16 15 0 0 0 1 1 2 3 4 5 6 7 8 9 0 14))
+(assert_return (invoke "v128.bitselect" (v128.const i32x4 -1 -1 0 0) (v128.const i32x4 0 0 -1 -1) (v128.const i32x4 0xffff0000 0 -1 0x0000ffff))
+               (v128.const i32x4 0xffff0000 0 0 0xffff0000))
+(assert_return (invoke "v128.any_true" (v128.const i64x2 0 0)) (i32.const 0))
+(assert_return (invoke "v128.any_true" (v128.const i64x2 0 0x100)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 1 2 3 4)) (i32.const 1))
+(assert_return (invoke "i32x4.all_true" (v128.const i32x4 1 2 0 4)) (i32.const 0))
+(assert_return (invoke "i16x8.bitmask" (v128.const i16x8 -1 0 -1 0 0 0 0 -32768)) (i32.const 0x85))
+
+(assert_return (invoke "i8x16.popcnt" (v128.const i8x16 0 1 3 7 15 31 63 127 255 128 85 170 16 17 0 255))
+               (v128.const i8x16 0 1 2 3 4 5 6 7 8 1 4 4 1 2 0 8))
+(assert_return (invoke "i64x2.abs" (v128.const i64x2 -5 0x8000000000000000))
+               (v128.const i64x2 5 0x8000000000000000))
+(assert_return (invoke "f32x4.neg" (v128.const f32x4 1.0 -2.0 0.0 -0.0))
+               (v128.const f32x4 -1.0 2.0 -0.0 0.0))
+
+(assert_return (invoke "i8x16.shl" (v128.const i8x16 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 255) (i32.const 9))
+               (v128.const i8x16 2 4 6 8 10 12 14 16 18 20 22 24 26 28 30 254))
+(assert_return (invoke "i8x16.shr_s" (v128.const i8x16 -128 64 -1 2 0 0 0 0 0 0 0 0 0 0 0 -4) (i32.const 1))
+               (v128.const i8x16 -64 32 -1 1 0 0 0 0 0 0 0 0 0 0 0 -2))
+(assert_return (invoke "i64x2.shr_s" (v128.const i64x2 -256 256) (i32.const 68))
+               (v128.const i64x2 -16 16))
+
+(assert_return (invoke "i64x2.mul" (v128.const i64x2 0x100000003 -7) (v128.const i64x2 0x200000005 3))
+               (v128.const i64x2 0xb0000000f -21))
+(assert_return (invoke "i64x2.gt_s" (v128.const i64x2 -1 0x7fffffffffffffff) (v128.const i64x2 0 0x8000000000000000))
+               (v128.const i64x2 0 -1))
+(assert_return (invoke "i8x16.lt_u" (v128.const i8x16 0 255 1 128 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 1 1 1 127 0 0 0 0 0 0 0 0 0 0 0 1))
+               (v128.const i8x16 -1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 -1))
+(assert_return (invoke "i32x4.ge_u" (v128.const i32x4 -1 0 5 5) (v128.const i32x4 1 1 5 6))
+               (v128.const i32x4 -1 0 -1 0))
+
+(assert_return (invoke "i8x16.add_sat_u" (v128.const i8x16 250 1 0 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 10 1 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+               (v128.const i8x16 255 2 0 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i16x8.sub_sat_s" (v128.const i16x8 -32768 100 0 0 0 0 0 0) (v128.const i16x8 1 -50 0 0 0 0 0 0))
+               (v128.const i16x8 -32768 150 0 0 0 0 0 0))
+(assert_return (invoke "i32x4.min_u" (v128.const i32x4 -1 1 2 3) (v128.const i32x4 1 -1 2 0))
+               (v128.const i32x4 1 1 2 0))
+(assert_return (invoke "i8x16.avgr_u" (v128.const i8x16 0 1 255 0 0 0 0 0 0 0 0 0 0 0 0 0) (v128.const i8x16 1 2 255 0 0 0 0 0 0 0 0 0 0 0 0 0))
+               (v128.const i8x16 1 2 255 0 0 0 0 0 0 0 0 0 0 0 0 0))
+(assert_return (invoke "i8x16.narrow_i16x8_s" (v128.const i16x8 300 -300 5 -5 0 0 0 0) (v128.const i16x8 1 2 3 4 5 6 7 127))
+               (v128.const i8x16 127 -128 5 -5 0 0 0 0 1 2 3 4 5 6 7 127))
+
+(assert_return (invoke "i32x4.extend_high_i16x8_u" (v128.const i16x8 0 0 0 0 1 -1 2 -2))
+               (v128.const i32x4 1 65535 2 65534))
+(assert_return (invoke "i64x2.extmul_high_i32x4_s" (v128.const i32x4 0 0 -2 0x10000) (v128.const i32x4 0 0 3 0x10000))
+               (v128.const i64x2 -6 0x100000000))
+(assert_return (invoke "i16x8.extmul_low_i8x16_u" (v128.const i8x16 255 2 0 0 0 0 0 0 9 9 9 9 9 9 9 9) (v128.const i8x16 255 3 0 0 0 0 0 0 9 9 9 9 9 9 9 9))
+               (v128.const i16x8 65025 6 0 0 0 0 0 0))
+(assert_return (invoke "i32x4.extadd_pairwise_i16x8_u" (v128.const i16x8 -1 -1 1 2 0 0 3 -4))
+               (v128.const i32x4 131070 3 0 65535))
+(assert_return (invoke "i16x8.extadd_pairwise_i8x16_s" (v128.const i8x16 -1 -1 127 127 -128 -128 1 2 0 0 0 0 0 0 0 0))
+               (v128.const i16x8 -2 254 -256 3 0 0 0 0))
+(assert_return (invoke "i16x8.q15mulr_sat_s" (v128.const i16x8 -32768 16384 0 0 0 0 0 0) (v128.const i16x8 -32768 16384 0 0 0 0 0 0))
+               (v128.const i16x8 32767 8192 0 0 0 0 0 0))
+(assert_return (invoke "i32x4.dot_i16x8_s" (v128.const i16x8 1 2 3 4 -1 -1 0 0) (v128.const i16x8 5 6 7 8 2 3 0 0))
+               (v128.const i32x4 17 53 -5 0))
+
+(assert_return (invoke "f32x4.min" (v128.const f32x4 0.0 -0.0 nan 1.0) (v128.const f32x4 -0.0 0.0 1.0 2.0))
+               (v128.const f32x4 -0.0 -0.0 nan:canonical 1.0))
+(assert_return (invoke "f64x2.max" (v128.const f64x2 -0.0 nan) (v128.const f64x2 0.0 1.0))
+               (v128.const f64x2 0.0 nan:canonical))
+(assert_return (invoke "f32x4.pmin" (v128.const f32x4 0.0 -0.0 nan 1.0) (v128.const f32x4 -0.0 0.0 1.0 2.0))
+               (v128.const f32x4 0.0 -0.0 nan 1.0))
+(assert_return (invoke "f64x2.sqrt" (v128.const f64x2 4.0 -1.0))
+               (v128.const f64x2 2.0 nan:canonical))
+(assert_return (invoke "f32x4.nearest" (v128.const f32x4 0.5 1.5 -2.5 3.7))
+               (v128.const f32x4 0.0 2.0 -2.0 4.0))
+
+(assert_return (invoke "f32x4.convert_i32x4_u" (v128.const i32x4 0 1 -1 0x80000000))
+               (v128.const f32x4 0.0 1.0 4294967296.0 2147483648.0))
+(assert_return (invoke "f64x2.convert_low_i32x4_u" (v128.const i32x4 -1 7 0 0))
+               (v128.const f64x2 4294967295.0 7.0))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_s" (v128.const f32x4 -1.5 nan 3e9 -3e9))
+               (v128.const i32x4 -1 0 0x7fffffff 0x80000000))
+(assert_return (invoke "i32x4.trunc_sat_f32x4_u" (v128.const f32x4 -1.5 nan 3e9 5e9))
+               (v128.const i32x4 0 0 3000000000 0xffffffff))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_s_zero" (v128.const f64x2 nan -3e10))
+               (v128.const i32x4 0 0x80000000 0 0))
+(assert_return (invoke "i32x4.trunc_sat_f64x2_u_zero" (v128.const f64x2 3.9 5e10))
+               (v128.const i32x4 3 0xffffffff 0 0))
@@ -0,0 +1,173 @@
+(module
+  (memory 1 1 shared)
+
+  (func (export "i32.load") (param i32) (result i32)
+    (i32.atomic.load (local.get 0)))
+  (func (export "i64.load") (param i32) (result i64)
+    (i64.atomic.load (local.get 0)))
+  (func (export "i32.store") (param i32 i32)
+    (i32.atomic.store (local.get 0) (local.get 1)))
+  (func (export "i64.store") (param i32 i64)
+    (i64.atomic.store offset=8 (local.get 0) (local.get 1)))
+
+  (func (export "i32.rmw.add") (param i32 i32) (result i32)
+    (i32.atomic.rmw.add (local.get 0) (local.get 1)))
+  (func (export "i32.rmw.sub") (param i32 i32) (result i32)
+    (i32.atomic.rmw.sub (local.get 0) (local.get 1)))
+  (func (export "i32.rmw.and") (param i32 i32) (result i32)
+    (i32.atomic.rmw.and (local.get 0) (local.get 1)))
+  (func (export "i32.rmw.or") (param i32 i32) (result i32)
+    (i32.atomic.rmw.or (local.get 0) (local.get 1)))
+  (func (export "i32.rmw.xor") (param i32 i32) (result i32)
+    (i32.atomic.rmw.xor (local.get 0) (local.get 1)))
+  (func (export "i32.rmw.xchg") (param i32 i32) (result i32)
+    (i32.atomic.rmw.xchg (local.get 0) (local.get 1)))
+  (func (export "i32.rmw.cmpxchg") (param i32 i32 i32) (result i32)
+    (i32.atomic.rmw.cmpxchg (local.get 0) (local.get 1) (local.get 2)))
+
+  (func (export "i64.rmw.add") (param i32 i64) (result i64)
+    (i64.atomic.rmw.add (local.get 0) (local.get 1)))
+  (func (export "i64.rmw.xchg") (param i32 i64) (result i64)
+    (i64.atomic.rmw.xchg (local.get 0) (local.get 1)))
+  (func (export "i64.rmw.cmpxchg") (param i32 i64 i64) (result i64)
+    (i64.atomic.rmw.cmpxchg (local.get 0) (local.get 1) (local.get 2)))
+
+  (func (export "fence")
+    (atomic.fence))
+)
+
+(assert_return (invoke "i32.store" (i32.const 0) (i32.const 10)))
+(assert_return (invoke "i32.load" (i32.const 0)) (i32.const 10))
+(assert_return (invoke "i32.rmw.add" (i32.const 0) (i32.const 5)) (i32.const 10))
+(assert_return (invoke "i32.rmw.sub" (i32.const 0) (i32.const 3)) (i32.const 15))
+(assert_return (invoke "i32.rmw.and" (i32.const 0) (i32.const 0xe)) (i32.const 12))
+(assert_return (invoke "i32.rmw.or" (i32.const 0) (i32.const 1)) (i32.const 12))
+(assert_return (invoke "i32.rmw.xor" (i32.const 0) (i32.const 0xf)) (i32.const 13))
+(assert_return (invoke "i32.rmw.xchg" (i32.const 0) (i32.const 42)) (i32.const 2))
+(assert_return (invoke "i32.rmw.cmpxchg" (i32.const 0) (i32.const 0) (i32.const 7)) (i32.const 42))
+(assert_return (invoke "i32.load" (i32.const 0)) (i32.const 42))
+(assert_return (invoke "i32.rmw.cmpxchg" (i32.const 0) (i32.const 42) (i32.const 7)) (i32.const 42))
+(assert_return (invoke "i32.load" (i32.const 0)) (i32.const 7))
+
+(assert_return (invoke "i64.store" (i32.const 0) (i64.const 0x100000000)))
+(assert_return (invoke "i64.load" (i32.const 8)) (i64.const 0x100000000))
+(assert_return (invoke "i64.rmw.add" (i32.const 8) (i64.const 1)) (i64.const 0x100000000))
+(assert_return (invoke "i64.rmw.xchg" (i32.const 8) (i64.const -1)) (i64.const 0x100000001))
+(assert_return (invoke "i64.rmw.cmpxchg" (i32.const 8) (i64.const -1) (i64.const 3)) (i64.const -1))
+(assert_return (invoke "i64.load" (i32.const 8)) (i64.const 3))
+
+(assert_return (invoke "fence"))
+
+(assert_trap (invoke "i32.load" (i32.const 1)) "unaligned atomic")
+(assert_trap (invoke "i64.load" (i32.const 4)) "unaligned atomic")
+(assert_trap (invoke "i32.rmw.add" (i32.const 2) (i32.const 0)) "unaligned atomic")
+(assert_trap (invoke "i32.load" (i32.const 65536)) "out of bounds memory access")
+(assert_trap (invoke "i64.load" (i32.const 65536)) "out of bounds memory access")
+(assert_trap (invoke "i64.store" (i32.const 65528) (i64.const 0)) "out of bounds memory access")
+(assert_trap (invoke "i32.rmw.cmpxchg" (i32.const -4) (i32.const 0) (i32.const 0)) "out of bounds memory access")
+
+(module
+  (memory 1 1 shared)
+
+  (func (export "i32.load8_u") (param i32) (result i32)
+    (i32.atomic.load8_u (local.get 0)))
+  (func (export "i32.load16_u") (param i32) (result i32)
+    (i32.atomic.load16_u (local.get 0)))
+  (func (export "i64.load8_u") (param i32) (result i64)
+    (i64.atomic.load8_u (local.get 0)))
+  (func (export "i64.load16_u") (param i32) (result i64)
+    (i64.atomic.load16_u (local.get 0)))
+  (func (export "i64.load32_u") (param i32) (result i64)
+    (i64.atomic.load32_u (local.get 0)))
+  (func (export "i32.store8") (param i32 i32)
+    (i32.atomic.store8 (local.get 0) (local.get 1)))
+  (func (export "i32.store16") (param i32 i32)
+    (i32.atomic.store16 (local.get 0) (local.get 1)))
+  (func (export "i64.store8") (param i32 i64)
+    (i64.atomic.store8 (local.get 0) (local.get 1)))
+  (func (export "i64.store16") (param i32 i64)
+    (i64.atomic.store16 (local.get 0) (local.get 1)))
+  (func (export "i64.store32") (param i32 i64)
+    (i64.atomic.store32 (local.get 0) (local.get 1)))
+
+  (func (export "i32.rmw8.add_u") (param i32 i32) (result i32)
+    (i32.atomic.rmw8.add_u (local.get 0) (local.get 1)))
+  (func (export "i32.rmw16.sub_u") (param i32 i32) (result i32)
+    (i32.atomic.rmw16.sub_u (local.get 0) (local.get 1)))
+  (func (export "i32.rmw8.and_u") (param i32 i32) (result i32)
+    (i32.atomic.rmw8.and_u (local.get 0) (local.get 1)))
+  (func (export "i32.rmw16.or_u") (param i32 i32) (result i32)
+    (i32.atomic.rmw16.or_u (local.get 0) (local.get 1)))
+  (func (export "i32.rmw8.xor_u") (param i32 i32) (result i32)
+    (i32.atomic.rmw8.xor_u (local.get 0) (local.get 1)))
+  (func (export "i32.rmw16.xchg_u") (param i32 i32) (result i32)
+    (i32.atomic.rmw16.xchg_u (local.get 0) (local.get 1)))
+  (func (export "i32.rmw8.cmpxchg_u") (param i32 i32 i32) (result i32)
+    (i32.atomic.rmw8.cmpxchg_u (local.get 0) (local.get 1) (local.get 2)))
+  (func (export "i64.rmw8.sub_u") (param i32 i64) (result i64)
+    (i64.atomic.rmw8.sub_u (local.get 0) (local.get 1)))
+  (func (export "i64.rmw16.add_u") (param i32 i64) (result i64)
+    (i64.atomic.rmw16.add_u (local.get 0) (local.get 1)))
+  (func (export "i64.rmw32.xchg_u") (param i32 i64) (result i64)
+    (i64.atomic.rmw32.xchg_u (local.get 0) (local.get 1)))
+  (func (export "i64.rmw32.or_u") (param i32 i64) (result i64)
+    (i64.atomic.rmw32.or_u (local.get 0) (local.get 1)))
+  (func (export "i64.rmw32.cmpxchg_u") (param i32 i64 i64) (result i64)
+    (i64.atomic.rmw32.cmpxchg_u (local.get 0) (local.get 1) (local.get 2)))
+
+  (func (export "wait32") (param i32 i32 i64) (result i32)
+    (memory.atomic.wait32 (local.get 0) (local.get 1) (local.get 2)))
+  (func (export "wait64") (param i32 i64 i64) (result i32)
+    (memory.atomic.wait64 offset=8 (local.get 0) (local.get 1) (local.get 2)))
+  (func (export "notify") (param i32 i32) (result i32)
+    (memory.atomic.notify (local.get 0) (local.get 1)))
+)
+
+;; Narrow stores only write their low bytes, and narrow loads zero-extend.
+(assert_return (invoke "i64.store32" (i32.const 0) (i64.const -1)))
+(assert_return (invoke "i32.store8" (i32.const 0) (i32.const 0x1ff)))
+(assert_return (invoke "i32.load8_u" (i32.const 0)) (i32.const 0xff))
+(assert_return (invoke "i32.load16_u" (i32.const 0)) (i32.const 0xffff))
+(assert_return (invoke "i64.load32_u" (i32.const 0)) (i64.const 0xffffffff))
+(assert_return (invoke "i32.store16" (i32.const 2) (i32.const 0x1234)))
+(assert_return (invoke "i64.load16_u" (i32.const 2)) (i64.const 0x1234))
+(assert_return (invoke "i64.store8" (i32.const 1) (i64.const 0x180)))
+(assert_return (invoke "i64.load8_u" (i32.const 1)) (i64.const 0x80))
+(assert_return (invoke "i64.store16" (i32.const 0) (i64.const 0x10005)))
+(assert_return (invoke "i64.load32_u" (i32.const 0)) (i64.const 0x12340005))
+
+;; Narrow read-modify-writes wrap to their width and return the
+;; zero-extended previous value.
+(assert_return (invoke "i32.rmw8.add_u" (i32.const 0) (i32.const 0xff)) (i32.const 5))
+(assert_return (invoke "i32.load8_u" (i32.const 0)) (i32.const 4))
+(assert_return (invoke "i32.load8_u" (i32.const 1)) (i32.const 0))
+(assert_return (invoke "i32.rmw16.sub_u" (i32.const 0) (i32.const 5)) (i32.const 4))
+(assert_return (invoke "i32.load16_u" (i32.const 0)) (i32.const 0xffff))
+(assert_return (invoke "i32.rmw8.and_u" (i32.const 0) (i32.const 0x10f)) (i32.const 0xff))
+(assert_return (invoke "i32.rmw16.or_u" (i32.const 0) (i32.const 0x100)) (i32.const 0xff0f))
+(assert_return (invoke "i32.rmw8.xor_u" (i32.const 0) (i32.const 0xff)) (i32.const 0x0f))
+(assert_return (invoke "i32.rmw16.xchg_u" (i32.const 0) (i32.const 0x12345)) (i32.const 0xfff0))
+(assert_return (invoke "i32.load16_u" (i32.const 0)) (i32.const 0x2345))
+(assert_return (invoke "i32.rmw8.cmpxchg_u" (i32.const 0) (i32.const 0x145) (i32.const 0x11)) (i32.const 0x45))
+(assert_return (invoke "i32.load8_u" (i32.const 0)) (i32.const 0x11))
+(assert_return (invoke "i32.rmw8.cmpxchg_u" (i32.const 0) (i32.const 0x12) (i32.const 0x22)) (i32.const 0x11))
+(assert_return (invoke "i32.load8_u" (i32.const 0)) (i32.const 0x11))
+(assert_return (invoke "i64.rmw8.sub_u" (i32.const 0) (i64.const 0x12)) (i64.const 0x11))
+(assert_return (invoke "i64.rmw16.add_u" (i32.const 0) (i64.const 0x10001)) (i64.const 0x23ff))
+(assert_return (invoke "i64.rmw32.xchg_u" (i32.const 0) (i64.const 0x100000007)) (i64.const 0x12342400))
+(assert_return (invoke "i64.rmw32.or_u" (i32.const 0) (i64.const -1)) (i64.const 7))
+(assert_return (invoke "i64.rmw32.cmpxchg_u" (i32.const 0) (i64.const 0x1ffffffff) (i64.const 9)) (i64.const 0xffffffff))
+(assert_return (invoke "i64.load32_u" (i32.const 0)) (i64.const 9))
+
+(assert_trap (invoke "i32.rmw16.xchg_u" (i32.const 1) (i32.const 0)) "unaligned atomic")
+(assert_trap (invoke "i64.rmw32.or_u" (i32.const 65534) (i64.const 0)) "unaligned atomic")
+(assert_trap (invoke "i32.load8_u" (i32.const 65536)) "out of bounds memory access")
+(assert_trap (invoke "i64.store16" (i32.const 65536) (i64.const 0)) "out of bounds memory access")
+
+;; No thread waits on the memory, and the timeout expires immediately.
+(assert_return (invoke "notify" (i32.const 0) (i32.const 1)) (i32.const 0))
+(assert_return (invoke "wait32" (i32.const 0) (i32.const 0) (i64.const 0)) (i32.const 1))
+(assert_return (invoke "wait32" (i32.const 0) (i32.const 9) (i64.const 0)) (i32.const 2))
+(assert_return (invoke "wait64" (i32.const 0) (i64.const 0) (i64.const 0)) (i32.const 2))
+(assert_trap (invoke "wait32" (i32.const 1) (i32.const 0) (i64.const 0)) "unaligned atomic")
+(assert_trap (invoke "notify" (i32.const 65536) (i32.const 1)) "out of bounds memory access")
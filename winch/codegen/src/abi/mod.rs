@@ -14,8 +14,10 @@
 //! x86_64).
 //!
 //! The calling convention in the default ABI, uses registers to a
-//! certain fixed count for arguments and return values, and then the
-//! stack is used for all additional arguments.
+//! certain fixed count for arguments, and then the stack is used for
+//! all additional arguments. The first return value is held in a
+//! register and any others are written to a return area allocated by
+//! the caller, see [`ABIResult`].
 //!
 //! Generally the stack layout looks like:
 //! +-------------------------------+
@@ -48,7 +50,7 @@ use crate::masm::OperandSize;
 use smallvec::SmallVec;
 use std::collections::HashSet;
 use std::ops::{Add, BitAnd, Not, Sub};
-use wasmtime_environ::{WasmFuncType, WasmHeapType, WasmType};
+use wasmtime_environ::{WasmFuncType, WasmHeapType, WasmRefType, WasmType};
 
 pub(crate) mod local;
 pub(crate) use local::*;
@@ -77,6 +79,17 @@ pub(crate) trait ABI {
     fn sig_from(params: &[WasmType], returns: &[WasmType], call_conv: &CallingConvention)
         -> ABISig;
 
+    /// Construct the signature of a WebAssembly function in the default
+    /// calling convention, which takes the callee and caller `VMContext`
+    /// pointers ahead of its parameters, like the functions compiled by
+    /// Cranelift and the trampolines.
+    fn wasm_sig(wasm_sig: &WasmFuncType) -> ABISig {
+        let mut params: SmallVec<[WasmType; 6]> = SmallVec::new();
+        params.extend_from_slice(&[WasmType::I64, WasmType::I64]);
+        params.extend_from_slice(wasm_sig.params());
+        Self::sig_from(&params, wasm_sig.returns(), &CallingConvention::Default)
+    }
+
     /// Construct the ABI-specific result from a slice of
     /// [`wasmtime_environ::WasmtType`].
     fn result(returns: &[WasmType], call_conv: &CallingConvention) -> ABIResult;
@@ -92,6 +105,9 @@ pub(crate) trait ABI {
     /// Returns the designated scratch register.
     fn scratch_reg() -> Reg;
 
+    /// Returns the designated floating point scratch register.
+    fn float_scratch_reg() -> Reg;
+
     /// Returns the frame pointer register.
     fn fp_reg() -> Reg;
 
@@ -127,6 +143,18 @@ pub enum ABIArg {
         /// Offset of the argument relative to the frame pointer.
         offset: u32,
     },
+    /// An argument passed by reference, as the fastcall calling convention
+    /// does for vectors. The caller copies the value to its stack arguments
+    /// and passes the address of the copy in `pointer`.
+    Indirect {
+        /// The type of the argument.
+        ty: WasmType,
+        /// The location of the pointer to the value, which is either a
+        /// register or a stack argument.
+        pointer: Box<ABIArg>,
+        /// Offset of the copy of the value in the stack arguments.
+        offset: u32,
+    },
 }
 
 impl ABIArg {
@@ -140,6 +168,16 @@ impl ABIArg {
         Self::Stack { ty, offset }
     }
 
+    /// Allocate a new indirect abi arg, whose value is copied to `offset` in
+    /// the stack arguments.
+    pub fn indirect(pointer: ABIArg, offset: u32, ty: WasmType) -> Self {
+        Self::Indirect {
+            ty,
+            pointer: Box::new(pointer),
+            offset,
+        }
+    }
+
     /// Is this abi arg in a register.
     pub fn is_reg(&self) -> bool {
         match *self {
@@ -148,10 +186,12 @@ impl ABIArg {
         }
     }
 
-    /// Get the register associated to this arg.
+    /// Get the register associated to this arg. For indirect args, this is
+    /// the register holding the pointer to the value, if any.
     pub fn get_reg(&self) -> Option<Reg> {
-        match *self {
-            ABIArg::Reg { reg, .. } => Some(reg),
+        match self {
+            ABIArg::Reg { reg, .. } => Some(*reg),
+            ABIArg::Indirect { pointer, .. } => pointer.get_reg(),
             _ => None,
         }
     }
@@ -159,54 +199,94 @@ impl ABIArg {
     /// Get the type associated to this arg.
     pub fn ty(&self) -> WasmType {
         match *self {
-            ABIArg::Reg { ty, .. } | ABIArg::Stack { ty, .. } => ty,
+            ABIArg::Reg { ty, .. } | ABIArg::Stack { ty, .. } | ABIArg::Indirect { ty, .. } => ty,
         }
     }
-}
 
-/// ABI-specific representation of the function result.
-#[derive(Copy, Clone, Debug)]
-pub(crate) enum ABIResult {
-    Void,
-    Reg {
-        /// Type of the result.
-        ty: WasmType,
-        /// Register to hold the result.
-        reg: Reg,
-    },
+    /// Get the type of the value held in the register or stack slot of this
+    /// arg, which is the pointer's type for indirect args.
+    pub fn slot_ty(&self) -> WasmType {
+        match self {
+            ABIArg::Indirect { pointer, .. } => pointer.ty(),
+            _ => self.ty(),
+        }
+    }
 }
 
-impl Default for ABIResult {
-    fn default() -> Self {
-        Self::Void
-    }
+/// ABI-specific representation of the function results.
+///
+/// Matching Cranelift's convention for Wasmtime, the first result is held in
+/// a register and any other results are written to a return area provided by
+/// the caller, whose address is passed as a hidden argument after all the
+/// other arguments (see [`ABISig::ret_area_ptr`]). The results in the return
+/// area are laid out like the fields of a `#[repr(C)]` structure.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ABIResult {
+    /// The type and register of the first result, if any.
+    reg: Option<(WasmType, Reg)>,
+    /// The type and offset of each result in the return area.
+    ret_area: SmallVec<[(WasmType, u32); 4]>,
+    /// The size of the return area, in bytes.
+    ret_area_size: u32,
 }
 
 impl ABIResult {
-    /// Create a register ABI result.
-    pub fn reg(ty: WasmType, reg: Reg) -> Self {
-        Self::Reg { ty, reg }
+    /// Create the ABI results for the given types, where `reg` gives the
+    /// register that holds the first result.
+    pub fn new(returns: &[WasmType], reg: impl FnOnce(WasmType) -> Reg) -> Self {
+        let (first, rest) = match returns.split_first() {
+            Some((first, rest)) => (first, rest),
+            None => return Self::void(),
+        };
+
+        let mut ret_area_size = 0;
+        let ret_area = rest
+            .iter()
+            .map(|ty| {
+                let size = ty_size(ty);
+                let offset = align_to(ret_area_size, size);
+                ret_area_size = offset + size;
+                (*ty, offset)
+            })
+            .collect();
+
+        Self {
+            reg: Some((*first, reg(*first))),
+            ret_area,
+            ret_area_size,
+        }
     }
 
     /// Createa void ABI result.
     pub fn void() -> Self {
-        Self::Void
+        Self::default()
     }
 
     /// Get the result reg.
     pub fn result_reg(&self) -> Option<Reg> {
-        match self {
-            Self::Reg { reg, .. } => Some(*reg),
-            _ => None,
-        }
+        self.reg.map(|(_, reg)| reg)
+    }
+
+    /// Get the type and register of the first result, if any.
+    pub fn reg_result(&self) -> Option<(WasmType, Reg)> {
+        self.reg
+    }
+
+    /// Returns the type and offset of each result passed through the
+    /// return area.
+    pub fn ret_area(&self) -> &[(WasmType, u32)] {
+        &self.ret_area
+    }
+
+    /// Returns the size of the return area, in bytes. This is zero if all
+    /// the results fit in registers.
+    pub fn ret_area_size(&self) -> u32 {
+        self.ret_area_size
     }
 
     /// Checks if the result is void.
     pub fn is_void(&self) -> bool {
-        match self {
-            Self::Void => true,
-            _ => false,
-        }
+        self.reg.is_none()
     }
 
     /// Returns the length of the result.
@@ -214,16 +294,13 @@ impl ABIResult {
         if self.is_void() {
             0
         } else {
-            1
+            1 + self.ret_area.len()
         }
     }
 
     /// Returns an iterator over the result registers.
-    ///
-    /// NOTE: Currently only one or zero registers
-    /// will be returned until suport for multi-value is introduced.
     pub fn regs(&self) -> impl Iterator<Item = Reg> + '_ {
-        std::iter::once(self.result_reg()).filter_map(|v| v)
+        self.result_reg().into_iter()
     }
 }
 
@@ -236,6 +313,9 @@ pub(crate) struct ABISig {
     pub params: ABIParams,
     /// Function result.
     pub result: ABIResult,
+    /// The location of the hidden argument holding the address of the
+    /// return area, if the results need one.
+    pub ret_area_ptr: Option<ABIArg>,
     /// Stack space needed for stack arguments.
     pub stack_bytes: u32,
     /// All the registers used in the [`ABISig`].
@@ -243,25 +323,52 @@ pub(crate) struct ABISig {
     /// be unique: in some cases some registers might
     /// be used as params as a well as returns (e.g. xmm0 in x64).
     pub regs: HashSet<Reg>,
+    /// The calling convention used to derive this signature.
+    pub call_conv: CallingConvention,
 }
 
 impl ABISig {
     /// Create a new ABI signature.
-    pub fn new(params: ABIParams, result: ABIResult, stack_bytes: u32) -> Self {
+    pub fn new(
+        params: ABIParams,
+        result: ABIResult,
+        ret_area_ptr: Option<ABIArg>,
+        stack_bytes: u32,
+        call_conv: CallingConvention,
+    ) -> Self {
         let regs = params
             .iter()
+            .chain(&ret_area_ptr)
             .filter_map(|r| r.get_reg())
-            .collect::<SmallVec<[Reg; 6]>>();
-        let result_regs = result.regs();
-        let chained = regs.into_iter().chain(result_regs);
+            .chain(result.regs())
+            .collect::<HashSet<Reg>>();
 
         Self {
             params,
             result,
+            ret_area_ptr,
             stack_bytes,
-            regs: HashSet::from_iter(chained),
+            regs,
+            call_conv,
         }
     }
+
+    /// Returns the parameters of a signature constructed by
+    /// [`ABI::wasm_sig`] which correspond to the function's WebAssembly
+    /// parameters, skipping the `VMContext` pointers.
+    pub fn wasm_params(&self) -> &[ABIArg] {
+        &self.params[2..]
+    }
+
+    /// Returns the parameters followed by the hidden return area pointer, if
+    /// any.
+    pub fn params_with_ret_area_ptr(&self) -> ABIParams {
+        self.params
+            .iter()
+            .chain(&self.ret_area_ptr)
+            .cloned()
+            .collect()
+    }
 }
 
 /// Returns the size in bytes of a given WebAssembly type.
@@ -269,18 +376,31 @@ pub(crate) fn ty_size(ty: &WasmType) -> u32 {
     match *ty {
         WasmType::I32 | WasmType::F32 => 4,
         WasmType::I64 | WasmType::F64 => 8,
+        WasmType::V128 => 16,
         WasmType::Ref(rt) => match rt.heap_type {
             // TODO: Similar to the comment in visitor.rs at impl From<WasmType> for
             // OperandSize, Once Wasmtime supports 32-bit architectures, this will
             // need to be updated to derive operand size from the target's pointer
             // size.
-            WasmHeapType::Func => 8,
+            WasmHeapType::Func | WasmHeapType::Extern => 8,
             ht => unimplemented!("Support for WasmHeapType: {ht}"),
         },
-        t => unimplemented!("Support for WasmType: {t}"),
     }
 }
 
+/// Returns whether the given type is an `externref`, which is the only kind of
+/// reference managed by the garbage collector, and therefore the only kind of
+/// reference which needs to be described in stack maps.
+pub(crate) fn is_externref(ty: WasmType) -> bool {
+    matches!(
+        ty,
+        WasmType::Ref(WasmRefType {
+            heap_type: WasmHeapType::Extern,
+            ..
+        })
+    )
+}
+
 /// Align a value up to the given power-of-two-alignment.
 // See https://sites.google.com/site/theoryofoperatingsystems/labs/malloc/align8
 pub(crate) fn align_to<N>(value: N, alignment: N) -> N
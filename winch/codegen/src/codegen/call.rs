@@ -56,16 +56,24 @@
 //! │                                                  │
 //! │                                                  │
 //! └──────────────────────────────────────────────────┘ ------> Stack pointer when emitting the call
+//!
+//! When the callee returns more values than fit in registers, the stack
+//! space allocated for the callee arguments is extended with the return
+//! area, right above the stack arguments, and with enough space to hold
+//! all the results as if they had been pushed to the machine stack. Once
+//! the call returns, the results are copied to that space, so that they
+//! can be tracked as memory entries in the value stack after reclaiming
+//! the rest of the space consumed by the call.
 
 use crate::{
-    abi::{ABIArg, ABISig, ABI},
+    abi::{align_to, is_externref, ABIArg, ABISig, ABI},
     codegen::{
         ptr_type_from_ptr_size, BuiltinFunction, BuiltinType, Callee, CalleeInfo, CodeGenContext,
         TypedReg,
     },
-    masm::{CalleeKind, MacroAssembler, OperandSize},
+    masm::{CalleeKind, MacroAssembler, OperandSize, RegImm, StackSlot},
     reg::Reg,
-    CallingConvention,
+    stack::Val,
 };
 use smallvec::SmallVec;
 use std::borrow::Cow;
@@ -90,26 +98,38 @@ impl FnCall {
         R: FnMut(&mut CodeGenContext) -> Callee,
     {
         let callee = resolve(context);
-        let ptr_type = ptr_type_from_ptr_size(context.vmoffsets.ptr.size());
-        let sig = Self::get_sig::<M>(&callee, ptr_type);
+        let sig = Self::get_sig::<M>(&callee);
         let sig = sig.as_ref();
 
-        let arg_stack_space = sig.stack_bytes;
+        let ret_area = RetArea::new::<M>(sig);
+        let arg_stack_space = ret_area
+            .as_ref()
+            .map_or(sig.stack_bytes, |area| area.stack_space());
         let kind = Self::map(&context.vmoffsets, &callee, sig, context, masm);
         let call_stack_space = Self::save(context, masm, &sig);
 
+        let gc_ref_slots = Self::live_gc_refs(sig, context);
+
         let reserved_stack = masm.call(arg_stack_space, |masm| {
             let scratch = <M::ABI as ABI>::scratch_reg();
-            Self::assign(sig, context, masm, scratch);
-            kind
+            Self::assign(sig, ret_area.as_ref(), context, masm, scratch);
+            Self::stack_map(sig, &gc_ref_slots, masm);
+            (kind, sig.call_conv)
         });
 
         match kind {
             CalleeKind::Indirect(r) => context.free_reg(r),
             _ => {}
         }
+        // Functions of other instances switch the pinned register to their
+        // own `VMContext`, so it's restored from its slot.
+        if matches!(callee, Callee::Import(_) | Callee::FuncRef(_)) {
+            let vmctx_addr = masm.local_address(&context.frame.vmctx_slot);
+            masm.load_ptr(vmctx_addr, <M::ABI as ABI>::vmctx_reg());
+        }
         Self::cleanup(
             sig,
+            ret_area.as_ref(),
             call_stack_space.checked_add(reserved_stack).unwrap(),
             masm,
             context,
@@ -117,26 +137,13 @@ impl FnCall {
     }
 
     /// Derive the [`ABISig`] for a particulare [`Callee].
-    fn get_sig<M: MacroAssembler>(callee: &Callee, ptr_type: WasmType) -> Cow<'_, ABISig> {
+    fn get_sig<M: MacroAssembler>(callee: &Callee) -> Cow<'_, ABISig> {
         match callee {
             Callee::Builtin(info) => Cow::Borrowed(info.sig()),
-            Callee::Import(info) => {
-                let mut params: SmallVec<[WasmType; 6]> =
-                    SmallVec::with_capacity(info.ty.params().len() + 2);
-                params.extend_from_slice(&[ptr_type, ptr_type]);
-                params.extend_from_slice(info.ty.params());
-                Cow::Owned(<M::ABI as ABI>::sig_from(
-                    &params,
-                    info.ty.returns(),
-                    &CallingConvention::Default,
-                ))
-            }
-            Callee::Local(info) => {
-                Cow::Owned(<M::ABI as ABI>::sig(&info.ty, &CallingConvention::Default))
-            }
-            Callee::FuncRef(ty) => {
-                Cow::Owned(<M::ABI as ABI>::sig(&ty, &CallingConvention::Default))
+            Callee::Import(info) | Callee::Local(info) => {
+                Cow::Owned(<M::ABI as ABI>::wasm_sig(&info.ty))
             }
+            Callee::FuncRef(ty) => Cow::Owned(<M::ABI as ABI>::wasm_sig(&ty)),
         }
     }
 
@@ -151,7 +158,7 @@ impl FnCall {
         match callee {
             Callee::Builtin(b) => Self::load_builtin(b, context, masm),
             Callee::FuncRef(_) => Self::load_funcref(sig, vmoffsets.ptr.size(), context, masm),
-            Callee::Local(i) => Self::map_local(i),
+            Callee::Local(i) => Self::map_local::<M, P>(i, sig, context, vmoffsets),
             Callee::Import(i) => Self::load_import(i, sig, context, masm, vmoffsets),
         }
     }
@@ -181,7 +188,14 @@ impl FnCall {
     }
 
     /// Map a local function to a [`CalleeKind`].
-    fn map_local(info: &CalleeInfo) -> CalleeKind {
+    fn map_local<M: MacroAssembler, P: PtrSize>(
+        info: &CalleeInfo,
+        sig: &ABISig,
+        context: &mut CodeGenContext,
+        vmoffsets: &VMOffsets<P>,
+    ) -> CalleeKind {
+        let vmctx = <M::ABI as ABI>::vmctx_reg();
+        Self::insert_vmctx_args(sig, vmctx, vmctx, context, vmoffsets.ptr.size());
         CalleeKind::direct(info.index.as_u32())
     }
 
@@ -193,7 +207,6 @@ impl FnCall {
         masm: &mut M,
         vmoffsets: &VMOffsets<P>,
     ) -> CalleeKind {
-        let ptr_type = ptr_type_from_ptr_size(vmoffsets.ptr.size());
         let caller_vmctx = <M::ABI as ABI>::vmctx_reg();
         let (callee, callee_vmctx) =
             context.without::<(Reg, Reg), M, _>(&sig.regs, masm, |context, masm| {
@@ -207,19 +220,34 @@ impl FnCall {
         let callee_addr = masm.address_at_vmctx(callee_body_offset);
         masm.load_ptr(callee_addr, callee);
 
-        // Put the callee / caller vmctx at the start of the
-        // range of the stack so that they are used as first
-        // and second arguments.
-        let stack = &mut context.stack;
-        let location = stack.len() - (sig.params.len() - 2);
+        Self::insert_vmctx_args(
+            sig,
+            callee_vmctx,
+            caller_vmctx,
+            context,
+            vmoffsets.ptr.size(),
+        );
+        CalleeKind::indirect(callee)
+    }
+
+    /// Puts the callee and caller `VMContext` pointers in the given
+    /// registers right below the arguments in the value stack, so that they
+    /// are passed as the first and second arguments.
+    fn insert_vmctx_args(
+        sig: &ABISig,
+        callee_vmctx: Reg,
+        caller_vmctx: Reg,
+        context: &mut CodeGenContext,
+        ptr_size: u8,
+    ) {
+        let ptr_type = ptr_type_from_ptr_size(ptr_size);
+        let location = context.stack.len() - sig.wasm_params().len();
         let values = [
             TypedReg::new(ptr_type, callee_vmctx).into(),
             TypedReg::new(ptr_type, caller_vmctx).into(),
         ]
         .into_iter();
         context.stack.insert_many(location, values);
-
-        CalleeKind::indirect(callee)
     }
 
     /// Loads a function reference to the next available register.
@@ -242,13 +270,27 @@ impl FnCall {
             masm.address_at_reg(funcref_ptr, ptr.vm_func_ref_wasm_call().into()),
             funcref,
         );
-        context.free_reg(funcref_ptr);
+        // The register holding the funcref pointer is reused to pass the
+        // callee's `VMContext`, which may belong to another instance.
+        let callee_vmctx = funcref_ptr;
+        masm.load_ptr(
+            masm.address_at_reg(funcref_ptr, ptr.vm_func_ref_vmctx().into()),
+            callee_vmctx,
+        );
+        Self::insert_vmctx_args(
+            sig,
+            callee_vmctx,
+            <M::ABI as ABI>::vmctx_reg(),
+            context,
+            ptr.size(),
+        );
         CalleeKind::indirect(funcref)
     }
 
     /// Assign arguments for the function call.
     fn assign<M: MacroAssembler>(
         sig: &ABISig,
+        ret_area: Option<&RetArea>,
         context: &mut CodeGenContext,
         masm: &mut M,
         scratch: Reg,
@@ -270,6 +312,27 @@ impl FnCall {
                     context.move_val_to_reg(val, scratch, masm);
                     masm.store(scratch.into(), addr, size);
                 }
+                // Neither Wasm functions nor builtins take arguments by
+                // reference.
+                &ABIArg::Indirect { .. } => unreachable!("indirect argument in a call"),
+            }
+        }
+
+        if let (Some(arg), Some(ret_area)) = (&sig.ret_area_ptr, ret_area) {
+            let dst = arg.get_reg().unwrap_or(scratch);
+            masm.mov(
+                RegImm::reg(<M::ABI as ABI>::sp_reg()),
+                dst,
+                OperandSize::S64,
+            );
+            masm.add(
+                dst,
+                dst,
+                RegImm::i32(ret_area.offset as i32),
+                OperandSize::S64,
+            );
+            if let &ABIArg::Stack { offset, .. } = arg {
+                masm.store_ptr(scratch, masm.address_at_sp(offset));
             }
         }
     }
@@ -318,13 +381,63 @@ impl FnCall {
         }
     }
 
+    /// Collects the slots holding GC references which stay live across the
+    /// call: the function's locals and the values in the value stack which
+    /// aren't consumed as arguments.
+    ///
+    /// This must be called after saving the live registers, so that no GC
+    /// reference stays in a register across the call.
+    fn live_gc_refs(sig: &ABISig, context: &CodeGenContext) -> SmallVec<[u32; 8]> {
+        let locals = context
+            .frame
+            .locals
+            .iter()
+            .filter(|slot| slot.addressed_from_sp() && is_externref(slot.ty))
+            .map(|slot| slot.offset);
+        let partition = context.stack.len() - sig.params.len();
+        let values = context
+            .stack
+            .inner()
+            .range(..partition)
+            .filter_map(|v| match v {
+                Val::Memory(mem) if is_externref(mem.ty) => Some(mem.slot.offset),
+                Val::Reg(r) if is_externref(r.ty) => {
+                    unreachable!("GC reference in a register across a call")
+                }
+                _ => None,
+            });
+        locals.chain(values).collect()
+    }
+
+    /// Attaches the stack map for the call, made of the given slots and of
+    /// any GC references passed as stack arguments.
+    fn stack_map<M: MacroAssembler>(sig: &ABISig, gc_ref_slots: &[u32], masm: &mut M) {
+        let stack_args = sig.params.iter().filter_map(|arg| match arg {
+            // Stack arguments are addressed from the stack pointer at the
+            // call.
+            &ABIArg::Stack { ty, offset } if is_externref(ty) => Some(masm.sp_offset() - offset),
+            _ => None,
+        });
+        let slots: SmallVec<[u32; 8]> = gc_ref_slots.iter().copied().chain(stack_args).collect();
+        masm.stack_map(&slots);
+    }
+
     /// Cleanup stack space and free registers after emitting the call.
     fn cleanup<M: MacroAssembler>(
         sig: &ABISig,
+        ret_area: Option<&RetArea>,
         total_space: u32,
         masm: &mut M,
         context: &mut CodeGenContext,
     ) {
+        // Any results in the return area are moved, together with the result
+        // in a register, to the bottom of the space consumed by the call,
+        // right where they would be if they had been pushed.
+        let results = ret_area.map(|area| {
+            let base = masm.sp_offset() - total_space;
+            area.move_results::<M>(&sig, base, masm)
+        });
+        let total_space = total_space - ret_area.map_or(0, |area| area.results_size);
         masm.free_stack(total_space);
         // Only account for registers given that any memory entries
         // consumed by the call (assigned to a register or to a stack
@@ -348,6 +461,91 @@ impl FnCall {
                 regalloc.free(v.get_reg().into());
             }
         });
-        context.push_abi_results(&sig.result, masm);
+        match results {
+            Some(results) => context.stack.extend(results),
+            None => context.push_abi_results(&sig.result, masm),
+        }
+    }
+}
+
+/// The stack space needed at a callsite for a callee which returns some of
+/// its results through a return area.
+struct RetArea {
+    /// The offset of the return area from the stack pointer at the callsite.
+    offset: u32,
+    /// The size of the return area.
+    size: u32,
+    /// The size of all the results, once they're moved to the machine
+    /// stack.
+    results_size: u32,
+}
+
+impl RetArea {
+    /// Returns the [`RetArea`] needed to call a function with the given
+    /// signature, if any.
+    fn new<M: MacroAssembler>(sig: &ABISig) -> Option<Self> {
+        let size = sig.result.ret_area_size();
+        if size == 0 {
+            return None;
+        }
+
+        let (ty, _) = sig.result.reg_result().unwrap();
+        let results_size = std::iter::once(ty)
+            .chain(sig.result.ret_area().iter().map(|(ty, _)| *ty))
+            .map(<M::ABI as ABI>::stack_arg_slot_size_for_type)
+            .sum();
+
+        Some(Self {
+            offset: align_to(sig.stack_bytes, <M::ABI as ABI>::word_bytes()),
+            size,
+            results_size,
+        })
+    }
+
+    /// The stack space to reserve for the callee arguments, the return area
+    /// and the results.
+    fn stack_space(&self) -> u32 {
+        self.offset + self.size + self.results_size
+    }
+
+    /// Moves the results of the call to the machine stack, right above the
+    /// given stack pointer offset, returning their value stack entries.
+    fn move_results<M: MacroAssembler>(
+        &self,
+        sig: &ABISig,
+        base: u32,
+        masm: &mut M,
+    ) -> impl Iterator<Item = Val> {
+        let scratch = <M::ABI as ABI>::scratch_reg();
+        let mut results: SmallVec<[Val; 4]> = SmallVec::new();
+        let mut next = base;
+        let mut slot_for = |ty: WasmType, masm: &mut M| {
+            let size = <M::ABI as ABI>::stack_arg_slot_size_for_type(ty);
+            next += size;
+            let slot = StackSlot { offset: next, size };
+            results.push(Val::mem(ty, slot));
+            (
+                masm.address_from_sp(next),
+                OperandSize::from_bytes(size as u8),
+            )
+        };
+
+        let (ty, reg) = sig.result.reg_result().unwrap();
+        let (addr, size) = slot_for(ty, masm);
+        masm.store(reg.into(), addr, size);
+
+        for (ty, offset) in sig.result.ret_area() {
+            let (addr, size) = slot_for(*ty, masm);
+            let src = masm.address_at_sp(self.offset + offset);
+            // Vectors don't fit in the scratch register.
+            let scratch = match ty {
+                WasmType::V128 => <M::ABI as ABI>::float_scratch_reg(),
+                _ => scratch,
+            };
+            masm.load(src, scratch, (*ty).into());
+            masm.store(scratch.into(), addr, size);
+        }
+
+        results.into_iter()
     }
 }
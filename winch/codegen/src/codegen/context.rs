@@ -6,7 +6,7 @@ use crate::{
     codegen::BuiltinFunctions,
     frame::Frame,
     isa::reg::RegClass,
-    masm::{MacroAssembler, OperandSize, RegImm},
+    masm::{AluKind, MacroAssembler, OperandSize, RegImm},
     reg::Reg,
     regalloc::RegAlloc,
     stack::{Stack, TypedReg, Val},
@@ -41,6 +41,10 @@ pub(crate) struct CodeGenContext<'a, 'builtins: 'a> {
     pub builtins: &'builtins mut BuiltinFunctions,
     /// A reference to the VMOffsets.
     pub vmoffsets: &'a VMOffsets<u8>,
+    /// The number of values spilled to the machine stack so far.
+    pub spills: u32,
+    /// The number of spilled values popped back into a register so far.
+    pub reloads: u32,
 }
 
 impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
@@ -59,6 +63,8 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
             reachable: true,
             builtins,
             vmoffsets,
+            spills: 0,
+            reloads: 0,
         }
     }
 
@@ -66,7 +72,8 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
     /// spilling if not available.
     pub fn reg<M: MacroAssembler>(&mut self, named: Reg, masm: &mut M) -> Reg {
         self.regalloc.reg(named, |regalloc| {
-            Self::spill_impl(&mut self.stack, regalloc, &self.frame, masm)
+            let len = Self::spill_len(&self.stack, |reg| reg == named);
+            self.spills += Self::spill_prefix(&mut self.stack, regalloc, &self.frame, masm, len);
         })
     }
 
@@ -75,12 +82,13 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
         use WasmType::*;
         match ty {
             I32 | I64 => self.reg_for_class(RegClass::Int, masm),
-            F32 | F64 => self.reg_for_class(RegClass::Float, masm),
+            F32 | F64 | V128 => self.reg_for_class(RegClass::Float, masm),
             Ref(rt) => match rt.heap_type {
-                WasmHeapType::Func => self.reg_for_class(RegClass::Int, masm),
+                WasmHeapType::Func | WasmHeapType::Extern => {
+                    self.reg_for_class(RegClass::Int, masm)
+                }
                 ht => unimplemented!("Support for WasmHeapType: {ht}"),
             },
-            t => unimplemented!("Support for WasmType: {t}"),
         }
     }
 
@@ -88,7 +96,8 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
     /// register of the specified class.
     pub fn reg_for_class<M: MacroAssembler>(&mut self, class: RegClass, masm: &mut M) -> Reg {
         self.regalloc.reg_for_class(class, &mut |regalloc| {
-            Self::spill_impl(&mut self.stack, regalloc, &self.frame, masm)
+            let len = Self::spill_len(&self.stack, |reg| reg.class() == class);
+            self.spills += Self::spill_prefix(&mut self.stack, regalloc, &self.frame, masm, len);
         })
     }
 
@@ -172,6 +181,7 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
 
         if val.is_mem() {
             masm.pop(reg, val.ty().into());
+            self.reloads += 1;
         } else {
             self.move_val_to_reg(&val, reg, masm);
             // Free the source value if it is a register.
@@ -192,6 +202,7 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
             Val::I64(imm) => masm.mov(RegImm::i64(*imm), dst, size),
             Val::F32(imm) => masm.mov(RegImm::f32(imm.bits()), dst, size),
             Val::F64(imm) => masm.mov(RegImm::f64(imm.bits()), dst, size),
+            Val::V128(imm) => masm.mov(RegImm::v128(*imm), dst, size),
             Val::Local(local) => {
                 let slot = self
                     .frame
@@ -231,6 +242,39 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
         self.stack.push(dst.into());
     }
 
+    /// Prepares arguments for emitting a conversion operation, which produces
+    /// a value of type `dst_ty` from the value at the top of the stack.
+    pub fn convert_op<F, M>(&mut self, masm: &mut M, dst_ty: WasmType, mut emit: F)
+    where
+        F: FnMut(&mut M, Reg, Reg, OperandSize),
+        M: MacroAssembler,
+    {
+        let src = self.pop_to_reg(masm, None);
+        let dst = self.reg_for_type(dst_ty, masm);
+        emit(masm, dst, src.reg, dst_ty.into());
+        self.free_reg(src);
+        self.stack.push(TypedReg::new(dst_ty, dst).into());
+    }
+
+    /// Prepares arguments for emitting a conversion operation which requires
+    /// a temporary register of the given class.
+    pub fn convert_op_with_tmp_reg<F, M>(
+        &mut self,
+        masm: &mut M,
+        dst_ty: WasmType,
+        tmp_reg_class: RegClass,
+        mut emit: F,
+    ) where
+        F: FnMut(&mut M, Reg, Reg, Reg, OperandSize),
+        M: MacroAssembler,
+    {
+        let tmp = self.reg_for_class(tmp_reg_class, masm);
+        self.convert_op(masm, dst_ty, |masm, dst, src, dst_size| {
+            emit(masm, dst, src, tmp, dst_size)
+        });
+        self.free_reg(tmp);
+    }
+
     /// Prepares arguments for emitting an f32 or f64 comparison operation.
     pub fn float_cmp_op<F, M>(&mut self, masm: &mut M, size: OperandSize, mut emit: F)
     where
@@ -247,7 +291,7 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
         let dst = match size {
             OperandSize::S32 => TypedReg::i32(dst),
             OperandSize::S64 => TypedReg::i64(dst),
-            OperandSize::S128 => unreachable!(),
+            _ => unreachable!(),
         };
         self.stack.push(dst.into());
     }
@@ -297,6 +341,34 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
         }
     }
 
+    /// Prepares arguments for emitting an integer ALU operation.
+    ///
+    /// This behaves like [`Self::i32_binop`] and [`Self::i64_binop`], except
+    /// that when the second operand is a local it's read directly from the
+    /// local's slot through [`MacroAssembler::alu_mem`] rather than being
+    /// loaded into a register first.
+    pub fn alu_op<F, M>(&mut self, masm: &mut M, kind: AluKind, size: OperandSize, emit: F)
+    where
+        F: FnMut(&mut M, Reg, RegImm, OperandSize),
+        M: MacroAssembler,
+    {
+        let top = self.stack.peek().expect("value at stack top");
+        if let Val::Local(local) = *top {
+            self.stack.pop();
+            let typed_reg = self.pop_to_reg(masm, None);
+            let (_, addr) = self.frame.get_local_address(local.index, masm);
+            masm.alu_mem(typed_reg.reg, typed_reg.reg, addr, kind, size);
+            self.stack.push(typed_reg.into());
+            return;
+        }
+
+        match size {
+            OperandSize::S32 => self.i32_binop(masm, emit),
+            OperandSize::S64 => self.i64_binop(masm, emit),
+            _ => unreachable!(),
+        }
+    }
+
     /// Saves any live registers in the value stack in a particular
     /// range defined by the caller.  This is a specialization of the
     /// spill function; made available for cases in which spilling
@@ -316,7 +388,8 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
                     let slot = masm.push(*reg, (*ty).into());
                     self.regalloc.free(*reg);
                     *v = Val::mem(*ty, slot);
-                    size += slot.size
+                    size += slot.size;
+                    self.spills += 1;
                 }
                 Val::Memory(mem) => size += mem.slot.size,
                 _ => {}
@@ -343,12 +416,32 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
         stack_mut.truncate(truncate);
     }
 
+    /// Spills the value stack up to the topmost latent read of the local at
+    /// the given index, if any, so that values read from the local before it
+    /// is set keep their value.
+    pub fn spill_latent_local<M: MacroAssembler>(&mut self, index: u32, masm: &mut M) {
+        let len = self
+            .stack
+            .inner()
+            .iter()
+            .rposition(|v| matches!(v, Val::Local(local) if local.index == index));
+        if let Some(len) = len {
+            self.spills += Self::spill_prefix(
+                &mut self.stack,
+                &mut self.regalloc,
+                &self.frame,
+                masm,
+                len + 1,
+            );
+        }
+    }
+
     /// Convenience wrapper around [`Self::spill_callback`].
     ///
     /// This function exists for cases in which triggering an unconditional
     /// spill is needed, like before entering control flow.
     pub fn spill<M: MacroAssembler>(&mut self, masm: &mut M) {
-        Self::spill_impl(&mut self.stack, &mut self.regalloc, &mut self.frame, masm);
+        self.spills += Self::spill_impl(&mut self.stack, &mut self.regalloc, &mut self.frame, masm);
     }
 
     /// Prepares the compiler to emit an uncoditional jump to the
@@ -408,12 +501,21 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
     /// of a block or function to pop the results from the value stack into the
     /// corresponding ABI result representation.
     pub fn pop_abi_results<M: MacroAssembler>(&mut self, result: &ABIResult, masm: &mut M) {
-        match result {
-            ABIResult::Void => {}
-            ABIResult::Reg { reg, .. } => {
-                let TypedReg { reg, ty: _ } = self.pop_to_reg(masm, Some(*reg));
+        if !result.ret_area().is_empty() {
+            // The results passed through the return area are the ones at the
+            // top of the value stack, so they're popped in reverse order.
+            let ptr = self.ret_area_ptr(masm);
+            for (ty, offset) in result.ret_area().iter().rev() {
+                let TypedReg { reg, ty: _ } = self.pop_to_reg(masm, None);
+                masm.store(reg.into(), masm.address_at_reg(ptr, *offset), (*ty).into());
                 self.free_reg(reg);
             }
+            self.free_reg(ptr);
+        }
+
+        if let Some(reg) = result.result_reg() {
+            let TypedReg { reg, ty: _ } = self.pop_to_reg(masm, Some(reg));
+            self.free_reg(reg);
         }
     }
 
@@ -421,45 +523,103 @@ impl<'a, 'builtins> CodeGenContext<'a, 'builtins> {
     /// of a block or after a function call to push the corresponding ABI
     /// results into the value stack.
     pub fn push_abi_results<M: MacroAssembler>(&mut self, result: &ABIResult, masm: &mut M) {
-        match result {
-            ABIResult::Void => {}
-            ABIResult::Reg { ty, reg } => {
-                assert!(self.regalloc.reg_available(*reg));
-                let typed_reg = TypedReg::new(*ty, self.reg(*reg, masm));
-                self.stack.push(typed_reg.into());
+        if let Some((ty, reg)) = result.reg_result() {
+            assert!(self.regalloc.reg_available(reg));
+            let typed_reg = TypedReg::new(ty, self.reg(reg, masm));
+            self.stack.push(typed_reg.into());
+        }
+
+        if !result.ret_area().is_empty() {
+            let ptr = self.ret_area_ptr(masm);
+            for (ty, offset) in result.ret_area() {
+                let reg = self.reg_for_type(*ty, masm);
+                masm.load(masm.address_at_reg(ptr, *offset), reg, (*ty).into());
+                self.stack.push(TypedReg::new(*ty, reg).into());
             }
+            self.free_reg(ptr);
         }
     }
 
-    /// Spill locals and registers to memory.
-    // TODO optimize the spill range;
-    //
-    // At any point in the program, the stack
-    // might already contain Memory entries;
-    // we could effectively ignore that range;
-    // only focusing on the range that contains
-    // spillable values.
+    /// Loads the address of the current function's return area into a
+    /// general purpose register.
+    ///
+    /// # Panics
+    /// This function panics if the current function doesn't have a return
+    /// area.
+    fn ret_area_ptr<M: MacroAssembler>(&mut self, masm: &mut M) -> Reg {
+        let slot = self
+            .frame
+            .ret_area_ptr_slot
+            .clone()
+            .expect("return area pointer slot");
+        let ptr = self.any_gpr(masm);
+        let addr = masm.local_address(&slot);
+        masm.load_ptr(addr, ptr);
+        ptr
+    }
+
+    /// Spill locals and registers to memory, returning the number of
+    /// spilled values.
     fn spill_impl<M: MacroAssembler>(
         stack: &mut Stack,
         regalloc: &mut RegAlloc,
         frame: &Frame,
         masm: &mut M,
-    ) {
-        stack.inner_mut().iter_mut().for_each(|v| match v {
+    ) -> u32 {
+        let len = stack.len();
+        Self::spill_prefix(stack, regalloc, frame, masm, len)
+    }
+
+    /// Returns the length of the shortest prefix of the value stack that
+    /// needs to be spilled to free a register matching `pred`.
+    ///
+    /// Memory entries in the value stack mirror the machine stack, so values
+    /// can only be spilled from the bottom of the value stack upwards. If no
+    /// register in the value stack matches, the whole value stack is spilled.
+    fn spill_len(stack: &Stack, pred: impl Fn(Reg) -> bool) -> usize {
+        stack
+            .inner()
+            .iter()
+            .position(|v| matches!(v, Val::Reg(r) if pred(r.reg)))
+            .map_or(stack.len(), |index| index + 1)
+    }
+
+    /// Spill locals and registers in the first `len` entries of the value
+    /// stack to memory, returning the number of spilled values.
+    ///
+    /// Spilled values are pushed to the machine stack in value stack order,
+    /// so the stack slot assigned to each value only depends on the state of
+    /// the value stack.
+    fn spill_prefix<M: MacroAssembler>(
+        stack: &mut Stack,
+        regalloc: &mut RegAlloc,
+        frame: &Frame,
+        masm: &mut M,
+        len: usize,
+    ) -> u32 {
+        let mut spills = 0;
+        stack.inner_mut().range_mut(..len).for_each(|v| match v {
             Val::Reg(r) => {
                 let slot = masm.push(r.reg, r.ty.into());
                 regalloc.free(r.reg);
                 *v = Val::mem(r.ty, slot);
+                spills += 1;
             }
             Val::Local(local) => {
                 let slot = frame.get_local(local.index).expect("valid local at slot");
                 let addr = masm.local_address(&slot);
-                let scratch = <M::ABI as ABI>::scratch_reg();
+                // 128-bit values don't fit in a general purpose register.
+                let scratch = match slot.ty {
+                    WasmType::V128 => <M::ABI as ABI>::float_scratch_reg(),
+                    _ => <M::ABI as ABI>::scratch_reg(),
+                };
                 masm.load(addr, scratch, slot.ty.into());
                 let stack_slot = masm.push(scratch, slot.ty.into());
                 *v = Val::mem(slot.ty, stack_slot);
+                spills += 1;
             }
             _ => {}
         });
+        spills
     }
 }
@@ -1,8 +1,10 @@
 //! Data structures for control flow emission.
 //!
-//! As of the current implementation, Winch doesn't offer support for the
-//! multi-value proposal, which in the context of control flow constructs it
-//! means that blocks don't take any params and produce 0 or 1 return. The
+//! As of the current implementation, Winch only offers support for the
+//! multi-value proposal in function results, which in the context of control
+//! flow constructs it means that blocks don't take any params and produce 0 or
+//! 1 return, while the block for the function body produces as many results
+//! as the function returns. The
 //! intention is to implement support for multi-value across the compiler, when
 //! that time comes, here are some general changes that will be needed for
 //! control flow:
@@ -102,7 +104,7 @@ impl ControlStackFrame {
         masm: &mut M,
         context: &mut CodeGenContext,
     ) -> Self {
-        let result = <M::ABI as ABI>::result(&returns, &CallingConvention::Default);
+        let result = Self::block_result::<M>(returns);
         let mut control = Self::If {
             cont: masm.get_label(),
             exit: masm.get_label(),
@@ -116,6 +118,14 @@ impl ControlStackFrame {
         control
     }
 
+    /// Returns the [`ABIResult`] of a block, if or loop.
+    fn block_result<M: MacroAssembler>(returns: &[WasmType]) -> ABIResult {
+        // Only the function body block may have more than one result, given
+        // that the return area is provided by the function's caller.
+        assert!(returns.len() <= 1, "multi-value blocks not supported");
+        <M::ABI as ABI>::result(returns, &CallingConvention::Default)
+    }
+
     /// Creates a block that represents the base
     /// block for the function body.
     pub fn function_body_block<M: MacroAssembler>(
@@ -138,7 +148,7 @@ impl ControlStackFrame {
         masm: &mut M,
         context: &mut CodeGenContext,
     ) -> Self {
-        let result = <M::ABI as ABI>::result(&returns, &CallingConvention::Default);
+        let result = Self::block_result::<M>(returns);
         let mut control = Self::Block {
             original_stack_len: 0,
             result,
@@ -157,7 +167,7 @@ impl ControlStackFrame {
         masm: &mut M,
         context: &mut CodeGenContext,
     ) -> Self {
-        let result = <M::ABI as ABI>::result(&returns, &CallingConvention::Default);
+        let result = Self::block_result::<M>(returns);
         let mut control = Self::Loop {
             original_stack_len: 0,
             result,
@@ -274,7 +284,7 @@ impl ControlStackFrame {
                 *self = ControlStackFrame::Else {
                     exit: *exit,
                     original_stack_len: *original_stack_len,
-                    result: *result,
+                    result: result.clone(),
                     reachable,
                     original_sp_offset: *original_sp_offset,
                 };
@@ -450,7 +460,7 @@ impl ControlStackFrame {
         use ControlStackFrame::*;
         match self {
             Loop { .. } => ABIResult::void(),
-            f => *f.result(),
+            f => f.result().clone(),
         }
     }
 }
@@ -6,8 +6,9 @@ use std::collections::{
 };
 use wasmparser::BlockType;
 use wasmtime_environ::{
-    FuncIndex, GlobalIndex, ModuleTranslation, ModuleTypes, PtrSize, TableIndex, TablePlan,
-    TypeConvert, TypeIndex, VMOffsets, WasmFuncType, WasmType,
+    FuncIndex, GlobalIndex, MemoryIndex, MemoryStyle, ModuleTranslation, ModuleTypes, PtrSize,
+    TableIndex, TablePlan, TypeConvert, TypeIndex, VMOffsets, WasmFuncType, WasmType,
+    WASM_PAGE_SIZE,
 };
 
 /// Table metadata.
@@ -26,6 +27,41 @@ pub struct TableData {
     pub(crate) current_elements_size: OperandSize,
 }
 
+/// Heap metadata.
+#[derive(Debug, Copy, Clone)]
+pub struct HeapData {
+    /// The offset to the base of the heap.
+    pub offset: u32,
+    /// The offset to the current length field.
+    pub current_length_offset: u32,
+    /// If the heap's `VMMemoryDefinition` is not stored inline in the
+    /// `VMContext` (i.e. the memory is imported or shared), the offset of the
+    /// pointer to the definition.
+    pub import_from: Option<u32>,
+    /// How the heap is laid out in memory.
+    pub style: HeapStyle,
+    /// The size, in bytes, of the guard pages following the heap.
+    pub offset_guard_size: u64,
+    /// The type of the heap's indices: `I32` for 32-bit memories, and `I64`
+    /// for 64-bit memories.
+    pub ty: WasmType,
+}
+
+/// The memory layout of a heap, which determines how its accesses are
+/// bounds-checked.
+#[derive(Debug, Copy, Clone)]
+pub enum HeapStyle {
+    /// The heap may be moved when it grows, so accesses are checked against
+    /// its current length.
+    Dynamic,
+    /// The address space for the heap is reserved up front. Accesses past
+    /// its current length but within `bound` bytes fault.
+    Static {
+        /// The size of the reserved address space, in bytes.
+        bound: u64,
+    },
+}
+
 /// A function callee.
 /// It categorizes how the callee should be treated
 /// when performing the call.
@@ -64,6 +100,8 @@ pub struct FuncEnv<'a, 'translation: 'a, 'data: 'translation, P: PtrSize> {
     pub types: &'translation ModuleTypes,
     /// Track resolved table information.
     resolved_tables: HashMap<TableIndex, TableData>,
+    /// Track resolved heap information.
+    resolved_heaps: HashMap<MemoryIndex, HeapData>,
 }
 
 pub fn ptr_type_from_ptr_size(size: u8) -> WasmType {
@@ -84,6 +122,7 @@ impl<'a, 'translation, 'data, P: PtrSize> FuncEnv<'a, 'translation, 'data, P> {
             translation,
             types,
             resolved_tables: HashMap::new(),
+            resolved_heaps: HashMap::new(),
         }
     }
 
@@ -130,15 +169,19 @@ impl<'a, 'translation, 'data, P: PtrSize> FuncEnv<'a, 'translation, 'data, P> {
         }
     }
 
-    /// Resolves the type and offset of a global at the given index.
-    pub fn resolve_global_type_and_offset(&self, index: GlobalIndex) -> (WasmType, u32) {
+    /// Resolves the type and offset of a global at the given index. For
+    /// imported globals, the offset is that of the pointer to the global's
+    /// definition, which is indicated by the returned flag.
+    pub fn resolve_global_type_and_offset(&self, index: GlobalIndex) -> (WasmType, u32, bool) {
         let ty = self.translation.module.globals[index].wasm_ty;
-        let offset = match self.translation.module.defined_global_index(index) {
-            Some(defined_index) => self.vmoffsets.vmctx_vmglobal_definition(defined_index),
-            None => self.vmoffsets.vmctx_vmglobal_import_from(index),
-        };
-
-        (ty, offset)
+        match self.translation.module.defined_global_index(index) {
+            Some(defined_index) => (
+                ty,
+                self.vmoffsets.vmctx_vmglobal_definition(defined_index),
+                false,
+            ),
+            None => (ty, self.vmoffsets.vmctx_vmglobal_import_from(index), true),
+        }
     }
 
     /// Returns the table information for the given table index.
@@ -174,6 +217,68 @@ impl<'a, 'translation, 'data, P: PtrSize> FuncEnv<'a, 'translation, 'data, P> {
         }
     }
 
+    /// Returns the heap information for the given memory index.
+    pub fn resolve_heap_data(&mut self, index: MemoryIndex) -> HeapData {
+        match self.resolved_heaps.entry(index) {
+            Occupied(entry) => *entry.get(),
+            Vacant(entry) => {
+                let module = &self.translation.module;
+                let plan = &module.memory_plans[index];
+
+                let (import_from, base_offset, current_length_offset) =
+                    match module.defined_memory_index(index) {
+                        // Shared memories store a pointer to their
+                        // definition, as imported memories do.
+                        Some(defined) if plan.memory.shared => (
+                            Some(self.vmoffsets.vmctx_vmmemory_pointer(defined)),
+                            self.vmoffsets.ptr.vmmemory_definition_base().into(),
+                            self.vmoffsets
+                                .ptr
+                                .vmmemory_definition_current_length()
+                                .into(),
+                        ),
+                        Some(defined) => {
+                            let owned = module.owned_memory_index(defined);
+                            (
+                                None,
+                                self.vmoffsets.vmctx_vmmemory_definition_base(owned),
+                                self.vmoffsets
+                                    .vmctx_vmmemory_definition_current_length(owned),
+                            )
+                        }
+                        None => (
+                            Some(self.vmoffsets.vmctx_vmmemory_import_from(index)),
+                            self.vmoffsets.ptr.vmmemory_definition_base().into(),
+                            self.vmoffsets
+                                .ptr
+                                .vmmemory_definition_current_length()
+                                .into(),
+                        ),
+                    };
+
+                let style = match plan.style {
+                    MemoryStyle::Dynamic { .. } => HeapStyle::Dynamic,
+                    MemoryStyle::Static { bound } => HeapStyle::Static {
+                        bound: bound * u64::from(WASM_PAGE_SIZE),
+                    },
+                };
+
+                *entry.insert(HeapData {
+                    import_from,
+                    offset: base_offset,
+                    current_length_offset,
+                    style,
+                    offset_guard_size: plan.offset_guard_size,
+                    ty: if plan.memory.memory64 {
+                        WasmType::I64
+                    } else {
+                        WasmType::I32
+                    },
+                })
+            }
+        }
+    }
+
     /// Get a [`TablePlan`] from a [`TableIndex`].
     pub fn table_plan(&mut self, index: TableIndex) -> &TablePlan {
         &self.translation.module.table_plans[index]
@@ -1,13 +1,17 @@
 use crate::{
-    abi::{ABISig, ABI},
+    abi::{ABIArg, ABISig, ABI},
     isa::reg::Reg,
     masm::{IntCmpKind, MacroAssembler, OperandSize, RegImm, TrapCode},
     stack::{TypedReg, Val},
 };
 use anyhow::Result;
+use cranelift_codegen::ir::{RelSourceLoc, SourceLoc};
+use cranelift_codegen::settings;
 use smallvec::SmallVec;
 use wasmparser::{BinaryReader, FuncValidator, Operator, ValidatorResources, VisitOperator};
-use wasmtime_environ::{PtrSize, TableIndex, TypeIndex, WasmHeapType, WasmType, FUNCREF_MASK};
+use wasmtime_environ::{
+    FunctionCodegenStats, PtrSize, TableIndex, TypeIndex, WasmHeapType, WasmType, FUNCREF_MASK,
+};
 
 mod context;
 pub(crate) use context::*;
@@ -41,6 +45,16 @@ where
     // NB The 64 is set arbitrarily, we can adjust it as
     // we see fit.
     pub control_frames: SmallVec<[ControlStackFrame; 64]>,
+
+    /// The location of the function's first operator in the module, which
+    /// the source locations of the generated code are relative to.
+    pub base_srcloc: Option<SourceLoc>,
+
+    /// Shared compilation flags.
+    pub flags: &'a settings::Flags,
+
+    /// Where to record code generation statistics, if requested.
+    stats: Option<&'a mut FunctionCodegenStats>,
 }
 
 impl<'a, 'translation, 'data, M> CodeGen<'a, 'translation, 'data, M>
@@ -52,6 +66,8 @@ where
         context: CodeGenContext<'a, 'translation>,
         env: FuncEnv<'a, 'translation, 'data, M::Ptr>,
         sig: ABISig,
+        flags: &'a settings::Flags,
+        stats: Option<&'a mut FunctionCodegenStats>,
     ) -> Self {
         Self {
             sig,
@@ -59,9 +75,20 @@ where
             masm,
             env,
             control_frames: Default::default(),
+            base_srcloc: None,
+            flags,
+            stats,
         }
     }
 
+    /// Returns the source location of the operator at `offset` in the module,
+    /// relative to the function's first operator.
+    fn source_loc_from(&mut self, offset: usize) -> RelSourceLoc {
+        let loc = SourceLoc::new(u32::try_from(offset).unwrap());
+        let base = *self.base_srcloc.get_or_insert(loc);
+        RelSourceLoc::from_base_offset(base, loc)
+    }
+
     /// Emit the function body to machine code.
     pub fn emit(
         &mut self,
@@ -72,25 +99,59 @@ where
             .and_then(|_| self.emit_body(body, validator))
             .and_then(|_| self.emit_end())?;
 
+        if let Some(stats) = self.stats.as_deref_mut() {
+            stats.spills = self.context.spills;
+            stats.reloads = self.context.reloads;
+            stats.constant_pool_size = self.masm.constant_pool_size();
+        }
         Ok(())
     }
 
-    // TODO stack checks
     fn emit_start(&mut self) -> Result<()> {
-        self.masm.prologue();
+        self.masm.prologue(&[]);
+        // The callee's `VMContext` is passed as the first argument and kept
+        // in the pinned register.
+        let vmctx = self.sig.params[0]
+            .get_reg()
+            .expect("VM context pointer to be passed in a register");
+        self.masm
+            .mov(vmctx.into(), <M::ABI as ABI>::vmctx_reg(), OperandSize::S64);
+        self.emit_stack_check();
         self.masm.reserve_stack(self.context.frame.locals_size);
 
         // Once we have emitted the epilogue and reserved stack space for the locals, we push the
         // base control flow block.
         self.control_frames
             .push(ControlStackFrame::function_body_block(
-                self.sig.result,
+                self.sig.result.clone(),
                 self.masm,
                 &mut self.context,
             ));
         Ok(())
     }
 
+    /// Emits a check that the function's frame fits on the stack, trapping
+    /// with a stack overflow otherwise.
+    ///
+    /// The frame covers everything the function pushes below the stack
+    /// pointer: its locals as well as the spilled values and outgoing call
+    /// arguments of its peak stack usage. The limit is the `stack_limit` of
+    /// the store's `VMRuntimeLimits`, so deep recursion traps before it
+    /// reaches the guard pages at the end of the stack.
+    fn emit_stack_check(&mut self) {
+        let ptr = &self.env.vmoffsets.ptr;
+        let limit = <M::ABI as ABI>::scratch_reg();
+        let addr = self
+            .masm
+            .address_at_vmctx(ptr.vmcontext_runtime_limits().into());
+        self.masm.load_ptr(addr, limit);
+        let addr = self
+            .masm
+            .address_at_reg(limit, ptr.vmruntime_limits_stack_limit().into());
+        self.masm.load_ptr(addr, limit);
+        self.masm.check_stack(limit);
+    }
+
     /// The following two helpers, handle else or end instructions when the
     /// compiler has entered into an unreachable code state. These instructions
     /// must be observed to determine if the reachability state should be
@@ -200,10 +261,42 @@ where
             vmctx_addr,
             OperandSize::S64,
         );
+        self.emit_sig_id_cache();
+
+        // Targets lacking the features needed to lower SIMD operators reject
+        // the function when the first one is found, rather than panicking
+        // halfway through its lowering.
+        let mut simd_unsupported = self.masm.check_simd_support().err();
 
         while !body.eof() {
             let offset = body.original_position();
+            if simd_unsupported.is_some() && is_simd_operator(body)? {
+                return Err(simd_unsupported.take().unwrap());
+            }
+            self.masm
+                .check_operator_support(&body.clone().read_operator()?)?;
+            let loc = self.source_loc_from(offset);
+            let start = self.masm.current_code_offset();
+            // Operators in unreachable code aren't compiled, so they aren't
+            // recorded either.
+            let opcode = match self.stats {
+                Some(_) if self.context.reachable => Some(opcode_name(body)?),
+                _ => None,
+            };
+            self.masm.start_source_loc(loc);
             body.visit_operator(&mut ValidateThenVisit(validator.visitor(offset), self))??;
+            let end = self.masm.current_code_offset();
+            // Binding a label may remove a branch right before it, which can
+            // leave the buffer shorter than it was when this operator
+            // started; such operators don't get a source location, but the
+            // one they started is closed with an empty range all the same.
+            if end < start {
+                self.masm.start_source_loc(loc);
+            }
+            self.masm.end_source_loc();
+            if let (Some(stats), Some(opcode)) = (self.stats.as_deref_mut(), opcode) {
+                stats.record_opcode(opcode, end.saturating_sub(start));
+            }
         }
         validator.finish(body.original_position())?;
         return Ok(());
@@ -265,32 +358,28 @@ where
 
     /// Emits a a series of instructions that will type check a function reference call.
     pub fn emit_typecheck_funcref(&mut self, funcref_ptr: Reg, type_index: TypeIndex) {
-        let ptr_size: OperandSize = self.env.ptr_type().into();
-        let sig_index_bytes = self.env.vmoffsets.size_of_vmshared_signature_index();
-        let sig_size = OperandSize::from_bytes(sig_index_bytes);
-        let sig_index = self.env.translation.module.types[type_index].unwrap_function();
-        let sig_offset = sig_index
-            .as_u32()
-            .checked_mul(sig_index_bytes.into())
-            .unwrap();
-        let signatures_base_offset = self.env.vmoffsets.vmctx_signature_ids_array();
-        let scratch = <M::ABI as ABI>::scratch_reg();
+        let sig_size = self.sig_id_size();
         let funcref_sig_offset = self.env.vmoffsets.ptr.vm_func_ref_type_index();
 
-        // Load the signatures address into the scratch register.
-        self.masm.load(
-            self.masm.address_at_vmctx(signatures_base_offset),
-            scratch,
-            ptr_size,
-        );
-
-        // Get the caller id.
+        // Get the caller id, from its cache slot if this type is used by
+        // more than one `call_indirect`.
         let caller_id = self.context.any_gpr(self.masm);
-        self.masm.load(
-            self.masm.address_at_reg(scratch, sig_offset),
-            caller_id,
-            sig_size,
-        );
+        match self.context.frame.sig_id_slot(type_index) {
+            Some(slot) => {
+                let addr = self.masm.local_address(slot);
+                self.masm.load(addr, caller_id, sig_size);
+            }
+            None => {
+                let scratch = <M::ABI as ABI>::scratch_reg();
+                self.load_signature_ids_array(scratch);
+                self.masm.load(
+                    self.masm
+                        .address_at_reg(scratch, self.sig_id_offset(type_index)),
+                    caller_id,
+                    sig_size,
+                );
+            }
+        }
 
         let callee_id = self.context.any_gpr(self.masm);
         self.masm.load(
@@ -307,17 +396,86 @@ where
         self.context.free_reg(caller_id);
     }
 
+    /// Returns the address of the value of the global at the given vmctx
+    /// `offset`, as resolved by
+    /// [`FuncEnv::resolve_global_type_and_offset`](crate::codegen::FuncEnv::resolve_global_type_and_offset).
+    /// The address of an imported global is based on the scratch register.
+    pub fn emit_global_addr(&mut self, offset: u32, imported: bool) -> M::Address {
+        if imported {
+            let scratch = <M::ABI as ABI>::scratch_reg();
+            self.masm
+                .load_ptr(self.masm.address_at_vmctx(offset), scratch);
+            self.masm.address_at_reg(scratch, 0)
+        } else {
+            self.masm.address_at_vmctx(offset)
+        }
+    }
+
+    /// Loads the shared signature ids of the types used by more than one
+    /// `call_indirect` into their cache slots, so each call only needs a
+    /// single load to get the id it checks against.
+    fn emit_sig_id_cache(&mut self) {
+        if self.context.frame.sig_id_slots.is_empty() {
+            return;
+        }
+        let sig_size = self.sig_id_size();
+        let scratch = <M::ABI as ABI>::scratch_reg();
+        self.load_signature_ids_array(scratch);
+
+        let id = self.context.any_gpr(self.masm);
+        for i in 0..self.context.frame.sig_id_slots.len() {
+            let (type_index, slot) = self.context.frame.sig_id_slots[i].clone();
+            self.masm.load(
+                self.masm
+                    .address_at_reg(scratch, self.sig_id_offset(type_index)),
+                id,
+                sig_size,
+            );
+            let addr = self.masm.local_address(&slot);
+            self.masm.store(id.into(), addr, sig_size);
+        }
+        self.context.free_reg(id);
+    }
+
+    /// Loads the address of the instance's shared signature ids array into
+    /// the given register.
+    fn load_signature_ids_array(&mut self, dst: Reg) {
+        let ptr_size: OperandSize = self.env.ptr_type().into();
+        let signatures_base_offset = self.env.vmoffsets.vmctx_signature_ids_array();
+        self.masm.load(
+            self.masm.address_at_vmctx(signatures_base_offset),
+            dst,
+            ptr_size,
+        );
+    }
+
+    /// The offset of the shared signature id of the given type index in the
+    /// instance's signature ids array.
+    fn sig_id_offset(&self, type_index: TypeIndex) -> u32 {
+        let sig_index_bytes = self.env.vmoffsets.size_of_vmshared_signature_index();
+        let sig_index = self.env.translation.module.types[type_index].unwrap_function();
+        sig_index
+            .as_u32()
+            .checked_mul(sig_index_bytes.into())
+            .unwrap()
+    }
+
+    /// The size of a shared signature id.
+    fn sig_id_size(&self) -> OperandSize {
+        OperandSize::from_bytes(self.env.vmoffsets.size_of_vmshared_signature_index())
+    }
+
     /// Emit the usual function end instruction sequence.
     fn emit_end(&mut self) -> Result<()> {
         assert!(self.context.stack.len() == 0);
-        self.masm.epilogue(self.context.frame.locals_size);
+        self.masm.epilogue(self.context.frame.locals_size, &[]);
         Ok(())
     }
 
     fn spill_register_arguments(&mut self) {
         use WasmType::*;
         self.sig
-            .params
+            .wasm_params()
             .iter()
             .enumerate()
             .filter(|(_, a)| a.is_reg())
@@ -334,23 +492,39 @@ where
                     .expect("arg should be associated to a register");
 
                 match &ty {
-                    I32 | I64 | F32 | F64 => self.masm.store(src.into(), addr, ty.into()),
+                    I32 | I64 | F32 | F64 | V128 => self.masm.store(src.into(), addr, ty.into()),
                     Ref(rt) => match rt.heap_type {
-                        WasmHeapType::Func => self.masm.store_ptr(src.into(), addr),
+                        WasmHeapType::Func | WasmHeapType::Extern => {
+                            self.masm.store_ptr(src.into(), addr)
+                        }
                         ht => unimplemented!("Support for WasmHeapType: {ht}"),
                     },
-                    _ => unimplemented!("Support for WasmType {ty}"),
                 }
             });
+
+        // The address of the return area is needed when returning, so it's
+        // spilled like any other register argument.
+        if let Some(ABIArg::Reg { reg, .. }) = &self.sig.ret_area_ptr {
+            let slot = self
+                .context
+                .frame
+                .ret_area_ptr_slot
+                .as_ref()
+                .expect("return area pointer slot");
+            let addr = self.masm.local_address(slot);
+            self.masm.store_ptr(*reg, addr);
+        }
     }
 
-    /// Pops the value at the stack top and assigns it to the local at
-    ///
-    /// the given index, returning the typed register holding the
-    /// source value.
-    pub fn emit_set_local(&mut self, addr: M::Address, size: OperandSize) -> TypedReg {
+    /// Pops the value at the stack top and assigns it to the local at the
+    /// given index, returning the typed register holding the source value.
+    pub fn emit_set_local(&mut self, index: u32) -> TypedReg {
+        self.context.spill_latent_local(index, self.masm);
         let src = self.context.pop_to_reg(self.masm, None);
-        self.masm.store(RegImm::reg(src.reg), addr, size);
+        // Locals are addressed from the stack pointer, which popping the
+        // value may have moved, so resolve the address afterwards.
+        let (ty, addr) = self.context.frame.get_local_address(index, self.masm);
+        self.masm.store(RegImm::reg(src.reg), addr, ty.into());
 
         src
     }
@@ -374,6 +548,12 @@ where
             .into();
 
         let index = self.context.pop_to_reg(self.masm, None);
+        // The built-in call below only happens if the element isn't
+        // initialized and saves any live registers in the value stack; save
+        // them beforehand so that both paths agree on the location of every
+        // value at the merge.
+        self.context
+            .save_live_registers_and_calculate_sizeof(self.masm, ..);
         let base = self.context.any_gpr(self.masm);
 
         let elem_addr =
@@ -434,3 +614,67 @@ pub fn control_index(depth: u32, control_length: usize) -> usize {
         .checked_sub(depth as usize)
         .unwrap_or_else(|| panic!("expected valid control stack frame at index: {}", depth))
 }
+
+/// Returns the name of the operator at the current position of `body`,
+/// without consuming it.
+fn opcode_name(body: &BinaryReader<'_>) -> Result<&'static str> {
+    struct OpcodeName;
+
+    macro_rules! opcode_name {
+        ($( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident)*) => {
+            $(
+                #[allow(unused_variables)]
+                fn $visit(&mut self $($(,$arg: $argty)*)?) -> Self::Output {
+                    stringify!($op)
+                }
+            )*
+        };
+    }
+
+    impl<'a> VisitOperator<'a> for OpcodeName {
+        type Output = &'static str;
+
+        wasmparser::for_each_operator!(opcode_name);
+    }
+
+    Ok(body.clone().visit_operator(&mut OpcodeName)?)
+}
+
+/// Returns the name of the proposal `op` belongs to, as spelled by
+/// `wasmparser::for_each_operator`.
+pub(crate) fn operator_proposal(op: &Operator<'_>) -> &'static str {
+    macro_rules! operator_proposal {
+        ($( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident)*) => {
+            match op {
+                $(Operator::$op { .. } => stringify!($proposal),)*
+            }
+        };
+    }
+
+    wasmparser::for_each_operator!(operator_proposal)
+}
+
+/// Returns whether the next operator in `body` belongs to the SIMD proposal,
+/// without consuming it.
+fn is_simd_operator(body: &BinaryReader<'_>) -> Result<bool> {
+    struct IsSimd;
+
+    macro_rules! is_simd {
+        ($( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident)*) => {
+            $(
+                #[allow(unused_variables)]
+                fn $visit(&mut self $($(,$arg: $argty)*)?) -> Self::Output {
+                    stringify!($proposal) == "simd"
+                }
+            )*
+        };
+    }
+
+    impl<'a> VisitOperator<'a> for IsSimd {
+        type Output = bool;
+
+        wasmparser::for_each_operator!(is_simd);
+    }
+
+    Ok(body.clone().visit_operator(&mut IsSimd)?)
+}
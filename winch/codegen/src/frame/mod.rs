@@ -4,9 +4,10 @@ use crate::{
 };
 use anyhow::Result;
 use smallvec::SmallVec;
+use std::collections::BTreeMap;
 use std::ops::Range;
-use wasmparser::{BinaryReader, FuncValidator, ValidatorResources};
-use wasmtime_environ::{ModuleTranslation, TypeConvert, WasmType};
+use wasmparser::{BinaryReader, FuncValidator, Operator, ValidatorResources};
+use wasmtime_environ::{ModuleTranslation, TypeConvert, TypeIndex, WasmType};
 
 // TODO:
 // SpiderMonkey's implementation uses 16;
@@ -31,6 +32,9 @@ pub(crate) struct DefinedLocals {
     pub defined_locals: Locals,
     /// The size of the defined locals.
     pub stack_size: u32,
+    /// The type indices of the `call_indirect`s that appear more than once
+    /// in the function body.
+    pub repeated_indirect_call_types: SmallVec<[TypeIndex; 4]>,
 }
 
 impl DefinedLocals {
@@ -62,8 +66,29 @@ impl DefinedLocals {
         Ok(Self {
             defined_locals: slots,
             stack_size: next_stack,
+            repeated_indirect_call_types: Self::repeated_indirect_call_types(reader.clone()),
         })
     }
+
+    /// Scan the function body for type indices used by more than one
+    /// `call_indirect`.
+    ///
+    /// The body hasn't been validated yet, so scanning stops at the first
+    /// operator which can't be read and the error is left for validation to
+    /// report.
+    fn repeated_indirect_call_types(mut reader: BinaryReader<'_>) -> SmallVec<[TypeIndex; 4]> {
+        let mut counts = BTreeMap::<u32, u32>::new();
+        while let Ok(op) = reader.read_operator() {
+            if let Operator::CallIndirect { type_index, .. } = op {
+                *counts.entry(type_index).or_default() += 1;
+            }
+        }
+        counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(ty, _)| TypeIndex::from_u32(ty))
+            .collect()
+    }
 }
 
 /// Frame handler abstraction.
@@ -82,12 +107,21 @@ pub(crate) struct Frame {
 
     /// The offset to the slot containing the `VMContext`.
     pub vmctx_slot: LocalSlot,
+
+    /// The slot containing the address of the return area, if the function
+    /// returns more values than it can in registers.
+    pub ret_area_ptr_slot: Option<LocalSlot>,
+
+    /// The slots caching the shared signature id of each type index used by
+    /// more than one `call_indirect`, loaded once in the prologue.
+    pub sig_id_slots: SmallVec<[(TypeIndex, LocalSlot); 4]>,
 }
 
 impl Frame {
     /// Allocate a new Frame.
     pub fn new<A: ABI>(sig: &ABISig, defined_locals: &DefinedLocals) -> Result<Self> {
-        let (mut locals, defined_locals_start) = Self::compute_arg_slots::<A>(sig)?;
+        let (mut locals, ret_area_ptr_slot, defined_locals_start) =
+            Self::compute_arg_slots::<A>(sig)?;
 
         // The defined locals have a zero-based offset by default
         // so we need to add the defined locals start to the offset.
@@ -98,8 +132,18 @@ impl Frame {
                 .map(|l| LocalSlot::new(l.ty, l.offset + defined_locals_start)),
         );
 
+        let mut next_stack = defined_locals_start + defined_locals.stack_size;
+        let sig_id_slots = defined_locals
+            .repeated_indirect_call_types
+            .iter()
+            .map(|ty| {
+                next_stack = align_to(next_stack, 4) + 4;
+                (*ty, LocalSlot::i32(next_stack))
+            })
+            .collect();
+
         let vmctx_slots_size = <A as ABI>::word_bytes();
-        let vmctx_offset = defined_locals_start + defined_locals.stack_size + vmctx_slots_size;
+        let vmctx_offset = next_stack + vmctx_slots_size;
 
         let locals_size = align_to(vmctx_offset, <A as ABI>::stack_align().into());
 
@@ -107,12 +151,23 @@ impl Frame {
             locals,
             locals_size,
             vmctx_slot: LocalSlot::i64(vmctx_offset),
+            ret_area_ptr_slot,
+            sig_id_slots,
             defined_locals_range: DefinedLocalsRange(
                 defined_locals_start..(defined_locals_start + defined_locals.stack_size),
             ),
         })
     }
 
+    /// Get the slot caching the shared signature id of the given type index,
+    /// if any.
+    pub fn sig_id_slot(&self, ty: TypeIndex) -> Option<&LocalSlot> {
+        self.sig_id_slots
+            .iter()
+            .find(|(t, _)| *t == ty)
+            .map(|(_, slot)| slot)
+    }
+
     /// Get a local slot.
     pub fn get_local(&self, index: u32) -> Option<&LocalSlot> {
         self.locals.get(index as usize)
@@ -132,7 +187,7 @@ impl Frame {
             .unwrap_or_else(|| panic!("Invalid local slot: {}", index))
     }
 
-    fn compute_arg_slots<A: ABI>(sig: &ABISig) -> Result<(Locals, u32)> {
+    fn compute_arg_slots<A: ABI>(sig: &ABISig) -> Result<(Locals, Option<LocalSlot>, u32)> {
         // Go over the function ABI-signature and
         // calculate the stack slots.
         //
@@ -161,15 +216,24 @@ impl Frame {
         //      we want positive addressing from the stack pointer
         //      for both locals and stack arguments.
 
+        // The `VMContext` pointers aren't Wasm locals: the callee's is kept in
+        // the pinned register and the caller's isn't needed.
         let arg_base_offset = <A as ABI>::arg_base_offset().into();
         let mut next_stack = 0u32;
         let slots: Locals = sig
-            .params
+            .wasm_params()
             .iter()
             .map(|arg| Self::abi_arg_slot(&arg, &mut next_stack, arg_base_offset))
             .collect();
 
-        Ok((slots, next_stack))
+        // The return area pointer isn't a Wasm local, but it's given a slot
+        // in the same way so that it can be retrieved when returning.
+        let ret_area_ptr_slot = sig
+            .ret_area_ptr
+            .as_ref()
+            .map(|arg| Self::abi_arg_slot(arg, &mut next_stack, arg_base_offset));
+
+        Ok((slots, ret_area_ptr_slot, next_stack))
     }
 
     fn abi_arg_slot(arg: &ABIArg, next_stack: &mut u32, arg_base_offset: u32) -> LocalSlot {
@@ -184,6 +248,9 @@ impl Frame {
             // Create a local slot, with an offset from the arguments base in
             // the stack; which is the frame pointer + return address.
             ABIArg::Stack { ty, offset } => LocalSlot::stack_arg(*ty, offset + arg_base_offset),
+            // Functions are compiled with Winch's default calling convention,
+            // which passes every argument by value.
+            ABIArg::Indirect { .. } => unreachable!("indirect argument in a Wasm function"),
         }
     }
 }
@@ -75,10 +75,6 @@ impl ABI for Aarch64ABI {
     ) -> ABISig {
         assert!(call_conv.is_apple_aarch64() || call_conv.is_default());
 
-        if returns.len() > 1 {
-            panic!("multi-value not supported");
-        }
-
         let mut stack_offset = 0;
         let mut index_env = RegIndexEnv::default();
 
@@ -88,29 +84,29 @@ impl ABI for Aarch64ABI {
             .collect();
 
         let result = Self::result(returns, call_conv);
-        ABISig::new(params, result, stack_offset)
+        // Results which don't fit in registers are written to a return area
+        // whose address is passed after all the other arguments.
+        let ret_area_ptr = (result.ret_area_size() > 0)
+            .then(|| Self::to_abi_arg(&WasmType::I64, &mut stack_offset, &mut index_env));
+        ABISig::new(params, result, ret_area_ptr, stack_offset, *call_conv)
     }
 
     fn result(returns: &[WasmType], _call_conv: &CallingConvention) -> ABIResult {
-        // This invariant will be lifted once support for multi-value is added.
-        assert!(returns.len() <= 1, "multi-value not supported");
-
-        let ty = returns.get(0).copied();
-        ty.map(|ty| {
-            let reg = match ty {
-                WasmType::I32 | WasmType::I64 => regs::xreg(0),
-                WasmType::F32 | WasmType::F64 => regs::vreg(0),
-                t => panic!("Unsupported return type {:?}", t),
-            };
-            ABIResult::reg(ty, reg)
+        ABIResult::new(returns, |ty| match ty {
+            WasmType::I32 | WasmType::I64 => regs::xreg(0),
+            WasmType::F32 | WasmType::F64 => regs::vreg(0),
+            t => panic!("Unsupported return type {:?}", t),
         })
-        .unwrap_or_else(|| ABIResult::void())
     }
 
     fn scratch_reg() -> Reg {
         regs::scratch()
     }
 
+    fn float_scratch_reg() -> Reg {
+        regs::float_scratch()
+    }
+
     fn sp_reg() -> Reg {
         todo!()
     }
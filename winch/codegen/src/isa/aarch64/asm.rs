@@ -1,16 +1,22 @@
 //! Assembler library implementation for Aarch64.
 
 use super::{address::Address, regs};
-use crate::{masm::OperandSize, reg::Reg};
+use crate::{
+    masm::{OperandSize, RoundingMode, ShiftKind},
+    reg::Reg,
+};
 use cranelift_codegen::{
-    ir::MemFlags,
+    binemit::{CodeOffset, StackMap},
+    ir::{MemFlags, SourceLoc, TrapCode},
     isa::aarch64::inst::{
         self,
         emit::{EmitInfo, EmitState},
-        ALUOp, ALUOp3, AMode, ExtendOp, Imm12, Inst, PairAMode,
+        ALUOp, ALUOp3, AMode, APIKey, BranchTarget, BranchTargetType, Cond, CondBrKind, ExtendOp,
+        FPULeftShiftImm, FPUOp1, FPUOp2, FPUOpRI, FPUOpRIMod, FPURightShiftImm, FpuRoundMode,
+        FpuToIntOp, Imm12, ImmShift, Inst, MoveWideConst, MoveWideOp, PairAMode, ScalarSize,
     },
-    settings, Final, MachBuffer, MachBufferFinalized, MachInstEmit, MachInstEmitState, MachLabel,
-    Writable,
+    settings, Final, MachBuffer, MachBufferFinalized, MachInst, MachInstEmit, MachInstEmitState,
+    MachLabel, PatchRegion, Writable,
 };
 
 impl From<OperandSize> for inst::OperandSize {
@@ -23,6 +29,18 @@ impl From<OperandSize> for inst::OperandSize {
     }
 }
 
+impl From<OperandSize> for ScalarSize {
+    fn from(size: OperandSize) -> ScalarSize {
+        match size {
+            OperandSize::S8 => ScalarSize::Size8,
+            OperandSize::S16 => ScalarSize::Size16,
+            OperandSize::S32 => ScalarSize::Size32,
+            OperandSize::S64 => ScalarSize::Size64,
+            OperandSize::S128 => ScalarSize::Size128,
+        }
+    }
+}
+
 /// Low level assembler implementation for Aarch64.
 pub(crate) struct Assembler {
     /// The machine instruction buffer.
@@ -31,33 +49,75 @@ pub(crate) struct Assembler {
     emit_info: EmitInfo,
     /// Emission state.
     emit_state: EmitState,
+    /// Dummy padding inserted before each bound label, see
+    /// [`Assembler::bind_label`].
+    bb_padding: Vec<u8>,
+    /// The total amount of padding inserted so far.
+    total_bb_padding: usize,
 }
 
 impl Assembler {
     /// Create a new Aarch64 assembler.
     pub fn new(shared_flags: settings::Flags) -> Self {
+        let bb_padding = match shared_flags.bb_padding_log2_minus_one() {
+            0 => Vec::new(),
+            n => vec![0; 1 << (n - 1)],
+        };
         Self {
             buffer: MachBuffer::<Inst>::new(),
             emit_state: Default::default(),
             emit_info: EmitInfo::new(shared_flags),
+            bb_padding,
+            total_bb_padding: 0,
         }
     }
 }
 
 impl Assembler {
+    /// Get a reference to the underlying machine buffer.
+    pub fn buffer(&self) -> &MachBuffer<Inst> {
+        &self.buffer
+    }
+
+    /// Get a mutable reference to underlying
+    /// machine buffer.
+    pub fn buffer_mut(&mut self) -> &mut MachBuffer<Inst> {
+        &mut self.buffer
+    }
+
     /// Return the emitted code.
-    pub fn finalize(mut self) -> MachBufferFinalized<Final> {
+    pub fn finalize(mut self, loc: Option<SourceLoc>) -> MachBufferFinalized<Final> {
         let constants = Default::default();
         let stencil = self
             .buffer
             .finish(&constants, self.emit_state.ctrl_plane_mut());
-        stencil.apply_base_srcloc(Default::default())
+        stencil.apply_base_srcloc(loc.unwrap_or_default())
     }
 
     fn emit(&mut self, inst: Inst) {
+        self.emit_island_if_needed(Inst::worst_case_size());
         inst.emit(&[], &mut self.buffer, &self.emit_info, &mut self.emit_state);
     }
 
+    /// Emit an island with the pending constants, traps and veneers if any
+    /// of the pending label references would go out of range after emitting
+    /// `distance` more bytes. The island is preceded by a jump over it.
+    fn emit_island_if_needed(&mut self, distance: u32) {
+        // Account for the jump over the island.
+        let distance = distance + Inst::worst_case_size();
+        if self.buffer.island_needed(distance) {
+            let continuation = self.buffer.get_label();
+            Inst::Jump {
+                dest: BranchTarget::Label(continuation),
+            }
+            .emit(&[], &mut self.buffer, &self.emit_info, &mut self.emit_state);
+            self.buffer
+                .emit_island(distance, self.emit_state.ctrl_plane_mut());
+            self.buffer
+                .bind_label(continuation, self.emit_state.ctrl_plane_mut());
+        }
+    }
+
     /// Load a constant into a register.
     pub fn load_constant(&mut self, imm: u64, rd: Reg) {
         let writable = Writable::from_reg(rd.into());
@@ -66,6 +126,43 @@ impl Assembler {
             .for_each(|i| self.emit(i));
     }
 
+    /// Load a 32-bit constant, to be filled in later with
+    /// [`Assembler::patch_constant`], into a register.
+    ///
+    /// The constant is always loaded with a `movz`/`movk` pair, and the
+    /// region of the buffer holding them is returned.
+    pub fn load_constant_patchable(&mut self, rd: Reg) -> PatchRegion {
+        let writable = Writable::from_reg(rd.into());
+        let open = self.buffer.start_patchable();
+        self.emit(Inst::MovWide {
+            op: MoveWideOp::MovZ,
+            rd: writable,
+            imm: MoveWideConst { bits: 0, shift: 0 },
+            size: inst::OperandSize::Size64,
+        });
+        self.emit(Inst::MovK {
+            rd: writable,
+            rn: rd.into(),
+            imm: MoveWideConst { bits: 0, shift: 1 },
+            size: inst::OperandSize::Size64,
+        });
+        self.buffer.end_patchable(open)
+    }
+
+    /// Overwrite the constant loaded by the instructions emitted by
+    /// [`Assembler::load_constant_patchable`].
+    pub fn patch_constant(&mut self, region: PatchRegion, imm: u32) {
+        let halves = [imm as u16, (imm >> 16) as u16];
+        let data = region.patch(&mut self.buffer);
+        debug_assert_eq!(data.len(), 8);
+        for (bytes, half) in data.chunks_exact_mut(4).zip(halves) {
+            let word = u32::from_le_bytes(bytes.try_into().unwrap());
+            // The immediate of `movz` and `movk` is held in bits 5 to 20.
+            let word = (word & !(0xffff << 5)) | (u32::from(half) << 5);
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+
     /// Store a pair of registers.
     pub fn stp(&mut self, xt1: Reg, xt2: Reg, addr: Address) {
         let mem: PairAMode = addr.try_into().unwrap();
@@ -83,18 +180,27 @@ impl Assembler {
         let flags = MemFlags::trusted();
 
         use OperandSize::*;
-        let inst = match size {
-            S64 => Inst::Store64 {
+        let inst = match (reg.is_int(), size) {
+            (true, S64) => Inst::Store64 {
                 rd: reg.into(),
                 mem,
                 flags,
             },
-            S32 => Inst::Store32 {
+            (true, S32) => Inst::Store32 {
+                rd: reg.into(),
+                mem,
+                flags,
+            },
+            (false, S64) => Inst::FpuStore64 {
+                rd: reg.into(),
+                mem,
+                flags,
+            },
+            (false, S32) => Inst::FpuStore32 {
                 rd: reg.into(),
                 mem,
                 flags,
             },
-
             _ => unreachable!(),
         };
 
@@ -108,13 +214,23 @@ impl Assembler {
         let mem: AMode = addr.try_into().unwrap();
         let flags = MemFlags::trusted();
 
-        let inst = match size {
-            S64 => Inst::ULoad64 {
+        let inst = match (rd.is_int(), size) {
+            (true, S64) => Inst::ULoad64 {
+                rd: writable_reg,
+                mem,
+                flags,
+            },
+            (true, S32) => Inst::ULoad32 {
+                rd: writable_reg,
+                mem,
+                flags,
+            },
+            (false, S64) => Inst::FpuLoad64 {
                 rd: writable_reg,
                 mem,
                 flags,
             },
-            S32 => Inst::ULoad32 {
+            (false, S32) => Inst::FpuLoad32 {
                 rd: writable_reg,
                 mem,
                 flags,
@@ -149,11 +265,34 @@ impl Assembler {
         });
     }
 
+    /// Floating point register to register move.
+    pub fn fmov_rr(&mut self, rn: Reg, rd: Reg) {
+        self.emit(Inst::FpuMove64 {
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+        });
+    }
+
+    /// Move the bits of a general purpose register into the lowest lane of a
+    /// floating point register.
+    pub fn mov_to_fpu(&mut self, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit(Inst::MovToFpu {
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            size: size.into(),
+        });
+    }
+
     /// Add with three registers.
     pub fn add_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
         self.emit_alu_rrr_extend(ALUOp::Add, rm, rn, rd, size);
     }
 
+    /// Add with three registers, setting flags.
+    pub fn adds_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_alu_rrr_extend(ALUOp::AddS, rm, rn, rd, size);
+    }
+
     /// Add immediate and register.
     pub fn add_ir(&mut self, imm: u64, rn: Reg, rd: Reg, size: OperandSize) {
         let alu_op = ALUOp::Add;
@@ -195,11 +334,251 @@ impl Assembler {
         self.emit_alu_rrrr(ALUOp3::MAdd, scratch, rn, rd, regs::zero(), size);
     }
 
+    /// Shift a register by an immediate amount.
+    pub fn shift_ir(&mut self, imm: u64, rn: Reg, rd: Reg, kind: ShiftKind, size: OperandSize) {
+        let bits = size.num_bits() as u64;
+        // Wasm shift amounts are taken modulo the operand width; a left
+        // rotation is a right rotation by the complementary amount.
+        let imm = imm % bits;
+        let (alu_op, imm) = match kind {
+            ShiftKind::Shl => (ALUOp::Lsl, imm),
+            ShiftKind::ShrS => (ALUOp::Asr, imm),
+            ShiftKind::ShrU => (ALUOp::Lsr, imm),
+            ShiftKind::Rotr => (ALUOp::RotR, imm),
+            ShiftKind::Rotl => (ALUOp::RotR, (bits - imm) % bits),
+        };
+        self.emit(Inst::AluRRImmShift {
+            alu_op,
+            size: size.into(),
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            immshift: ImmShift::maybe_from_u64(imm).unwrap(),
+        });
+    }
+
+    /// Float add with three registers.
+    pub fn fadd_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Add, rm, rn, rd, size);
+    }
+
+    /// Float subtract with three registers.
+    pub fn fsub_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Sub, rm, rn, rd, size);
+    }
+
+    /// Float multiply with three registers.
+    pub fn fmul_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Mul, rm, rn, rd, size);
+    }
+
+    /// Float divide with three registers.
+    pub fn fdiv_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Div, rm, rn, rd, size);
+    }
+
+    /// Float minimum with three registers. Propagates NaNs and orders -0.0
+    /// below 0.0, as WebAssembly requires.
+    pub fn fmin_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Min, rm, rn, rd, size);
+    }
+
+    /// Float maximum with three registers. Propagates NaNs and orders -0.0
+    /// below 0.0, as WebAssembly requires.
+    pub fn fmax_rrr(&mut self, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rrr(FPUOp2::Max, rm, rn, rd, size);
+    }
+
+    /// Float absolute value.
+    pub fn fabs_rr(&mut self, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rr(FPUOp1::Abs, rn, rd, size);
+    }
+
+    /// Float negation.
+    pub fn fneg_rr(&mut self, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rr(FPUOp1::Neg, rn, rd, size);
+    }
+
+    /// Float square root.
+    pub fn fsqrt_rr(&mut self, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit_fpu_rr(FPUOp1::Sqrt, rn, rd, size);
+    }
+
+    /// Round a float to an integral value using the given rounding mode.
+    pub fn fround_rr(&mut self, rn: Reg, rd: Reg, mode: RoundingMode, size: OperandSize) {
+        use OperandSize::*;
+        use RoundingMode::*;
+        let op = match (mode, size) {
+            (Down, S32) => FpuRoundMode::Minus32,
+            (Down, S64) => FpuRoundMode::Minus64,
+            (Up, S32) => FpuRoundMode::Plus32,
+            (Up, S64) => FpuRoundMode::Plus64,
+            (Zero, S32) => FpuRoundMode::Zero32,
+            (Zero, S64) => FpuRoundMode::Zero64,
+            (Nearest, S32) => FpuRoundMode::Nearest32,
+            (Nearest, S64) => FpuRoundMode::Nearest64,
+            _ => unreachable!(),
+        };
+        self.emit(Inst::FpuRound {
+            op,
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+        });
+    }
+
+    /// Unsigned right shift of the scalar in a floating point register.
+    pub fn fushr_rri(&mut self, rn: Reg, rd: Reg, amount: u8, size: OperandSize) {
+        let imm = FPURightShiftImm::maybe_from_u8(amount, size.num_bits() as u8).unwrap();
+        let fpu_op = match size {
+            OperandSize::S32 => FPUOpRI::UShr32(imm),
+            OperandSize::S64 => FPUOpRI::UShr64(imm),
+            _ => unreachable!(),
+        };
+        self.emit(Inst::FpuRRI {
+            fpu_op,
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+        });
+    }
+
+    /// Shift the scalar in `rn` left by `amount` and insert it into `rd`,
+    /// keeping the low `amount` bits of `rd`.
+    pub fn fsli_rri_mod(&mut self, rn: Reg, rd: Reg, amount: u8, size: OperandSize) {
+        let imm = FPULeftShiftImm::maybe_from_u8(amount, size.num_bits() as u8).unwrap();
+        let fpu_op = match size {
+            OperandSize::S32 => FPUOpRIMod::Sli32(imm),
+            OperandSize::S64 => FPUOpRIMod::Sli64(imm),
+            _ => unreachable!(),
+        };
+        self.emit(Inst::FpuRRIMod {
+            fpu_op,
+            rd: Writable::from_reg(rd.into()),
+            ri: rd.into(),
+            rn: rn.into(),
+        });
+    }
+
+    /// Float comparison, setting the condition flags.
+    pub fn fcmp(&mut self, rn: Reg, rm: Reg, size: OperandSize) {
+        self.emit(Inst::FpuCmp {
+            size: size.into(),
+            rn: rn.into(),
+            rm: rm.into(),
+        });
+    }
+
+    /// Float conditional select: `rd` is set to `rn` if the condition holds
+    /// and to `rm` otherwise.
+    pub fn fcsel(&mut self, rn: Reg, rm: Reg, rd: Reg, cond: Cond, size: OperandSize) {
+        let rd = Writable::from_reg(rd.into());
+        let (rn, rm) = (rn.into(), rm.into());
+        self.emit(match size {
+            OperandSize::S32 => Inst::FpuCSel32 { rd, rn, rm, cond },
+            OperandSize::S64 => Inst::FpuCSel64 { rd, rn, rm, cond },
+            _ => unreachable!(),
+        });
+    }
+
+    /// Convert a float to an integer, rounding towards zero. Out of range
+    /// values saturate and NaN converts to zero.
+    pub fn fcvtz(
+        &mut self,
+        rn: Reg,
+        rd: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        signed: bool,
+    ) {
+        use OperandSize::*;
+        let op = match (src_size, dst_size, signed) {
+            (S32, S32, true) => FpuToIntOp::F32ToI32,
+            (S32, S32, false) => FpuToIntOp::F32ToU32,
+            (S32, S64, true) => FpuToIntOp::F32ToI64,
+            (S32, S64, false) => FpuToIntOp::F32ToU64,
+            (S64, S32, true) => FpuToIntOp::F64ToI32,
+            (S64, S32, false) => FpuToIntOp::F64ToU32,
+            (S64, S64, true) => FpuToIntOp::F64ToI64,
+            (S64, S64, false) => FpuToIntOp::F64ToU64,
+            _ => unreachable!(),
+        };
+        self.emit(Inst::FpuToInt {
+            op,
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+        });
+    }
+
+    /// Compare `rn` against `rm`, setting flags as for `rn - rm`.
+    pub fn cmp_rr(&mut self, rm: Reg, rn: Reg, size: OperandSize) {
+        self.emit_alu_rrr_extend(ALUOp::SubS, rm, rn, regs::zero(), size);
+    }
+
+    /// Sign or zero-extend the `src_size` integer in `rn` to `dst_size`.
+    pub fn extend(
+        &mut self,
+        rn: Reg,
+        rd: Reg,
+        signed: bool,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+    ) {
+        self.emit(Inst::Extend {
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            signed,
+            from_bits: src_size.num_bits() as u8,
+            to_bits: dst_size.num_bits() as u8,
+        });
+    }
+
+    /// Set `rd` to 1 if the condition holds and to 0 otherwise.
+    pub fn cset(&mut self, rd: Reg, cond: Cond) {
+        self.emit(Inst::CSet {
+            rd: Writable::from_reg(rd.into()),
+            cond,
+        });
+    }
+
+    /// Trap unconditionally.
+    pub fn udf(&mut self, trap_code: TrapCode) {
+        self.emit(Inst::Udf { trap_code });
+    }
+
+    /// Trap if the condition holds.
+    pub fn trapif(&mut self, cond: Cond, trap_code: TrapCode) {
+        self.emit(Inst::TrapIf {
+            kind: CondBrKind::Cond(cond),
+            trap_code,
+        });
+    }
+
+    /// Attach a stack map to the next call instruction.
+    pub fn stack_map(&mut self, stack_map: StackMap) {
+        self.emit_state.pre_safepoint(stack_map);
+    }
+
     /// Return instruction.
     pub fn ret(&mut self) {
         self.emit(Inst::Ret {});
     }
 
+    /// Sign the return address in the link register with `key`.
+    pub fn paci(&mut self, key: APIKey) {
+        self.emit(Inst::Paci { key });
+    }
+
+    /// Authenticate the return address in the link register with `key` and
+    /// return. When `is_hint` is set the authentication is done with an
+    /// instruction from the hint space, which executes as a no-op on cores
+    /// without pointer authentication, followed by a plain return.
+    pub fn authenticated_ret(&mut self, key: APIKey, is_hint: bool) {
+        self.emit(Inst::AuthenticatedRet { key, is_hint });
+    }
+
+    /// Branch target identification landing pad.
+    pub fn bti(&mut self, targets: BranchTargetType) {
+        self.emit(Inst::Bti { targets });
+    }
+
     // Helpers for ALU operations.
 
     fn emit_alu_rri(&mut self, op: ALUOp, imm: Imm12, rn: Reg, rd: Reg, size: OperandSize) {
@@ -223,6 +602,25 @@ impl Assembler {
         });
     }
 
+    fn emit_fpu_rrr(&mut self, op: FPUOp2, rm: Reg, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit(Inst::FpuRRR {
+            fpu_op: op,
+            size: size.into(),
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+            rm: rm.into(),
+        });
+    }
+
+    fn emit_fpu_rr(&mut self, op: FPUOp1, rn: Reg, rd: Reg, size: OperandSize) {
+        self.emit(Inst::FpuRR {
+            fpu_op: op,
+            size: size.into(),
+            rd: Writable::from_reg(rd.into()),
+            rn: rn.into(),
+        });
+    }
+
     fn emit_alu_rrrr(&mut self, op: ALUOp3, rm: Reg, rn: Reg, rd: Reg, ra: Reg, size: OperandSize) {
         self.emit(Inst::AluRRRR {
             alu_op: op,
@@ -239,9 +637,26 @@ impl Assembler {
         self.buffer.get_label()
     }
 
-    /// Get a mutable reference to underlying
-    /// machine buffer.
-    pub fn buffer_mut(&mut self) -> &mut MachBuffer<Inst> {
-        &mut self.buffer
+    /// Bind a label to the current offset, inserting dummy padding first if
+    /// the `bb_padding_log2_minus_one` setting is enabled.
+    pub fn bind_label(&mut self, label: MachLabel) {
+        if !self.bb_padding.is_empty() {
+            let continuation = self.buffer.get_label();
+            self.emit(Inst::Jump {
+                dest: BranchTarget::Label(continuation),
+            });
+            self.emit_island_if_needed(self.bb_padding.len() as u32);
+            self.buffer.put_data(&self.bb_padding);
+            // Instructions must remain 4-byte aligned.
+            self.buffer.align_to(4);
+            self.buffer
+                .bind_label(continuation, self.emit_state.ctrl_plane_mut());
+            self.total_bb_padding += self.bb_padding.len();
+            if self.total_bb_padding > (150 << 20) {
+                self.bb_padding = Vec::new();
+            }
+        }
+        self.buffer
+            .bind_label(label, self.emit_state.ctrl_plane_mut());
     }
 }
@@ -1,14 +1,27 @@
 use super::{abi::Aarch64ABI, address::Address, asm::Assembler, regs};
 use crate::{
     abi::{self, local::LocalSlot},
-    codegen::{CodeGenContext, TableData},
-    isa::reg::Reg,
+    codegen::{operator_proposal, CodeGenContext, HeapData, TableData},
+    isa::{
+        reg::{Reg, RegClass},
+        CallingConvention,
+    },
     masm::{
-        CalleeKind, DivKind, FloatCmpKind, Imm as I, IntCmpKind, MacroAssembler as Masm,
-        OperandSize, RegImm, RemKind, RoundingMode, ShiftKind, StackSlot, TrapCode,
+        AluKind, AtomicRmwKind, CalleeKind, DivKind, ExtendKind, FloatCmpKind, Imm as I,
+        IntCmpKind, MacroAssembler as Masm, OperandSize, RegImm, RemKind, RoundingMode, ShiftKind,
+        Signedness, StackSlot, TrapCode, TruncKind, VectorConvertKind, VectorHalf, VectorLoadKind,
+        VectorShape,
     },
 };
-use cranelift_codegen::{settings, Final, MachBufferFinalized, MachLabel};
+use anyhow::{anyhow, Result};
+use cranelift_codegen::{
+    binemit::{CodeOffset, StackMap},
+    ir::{RelSourceLoc, SourceLoc},
+    isa::aarch64::inst::{APIKey, BranchTargetType, Cond},
+    isa::aarch64::settings as aarch64_settings,
+    settings, Final, MachBufferFinalized, MachLabel, PatchRegion,
+};
+use wasmparser::Operator;
 
 /// Aarch64 MacroAssembler.
 pub(crate) struct MacroAssembler {
@@ -16,15 +29,39 @@ pub(crate) struct MacroAssembler {
     asm: Assembler,
     /// Stack pointer offset.
     sp_offset: u32,
+    /// The largest stack pointer offset so far.
+    max_sp_offset: u32,
+    /// The instructions loading the stack check's frame size and the stack
+    /// pointer offset at the check, if one was emitted.
+    stack_check: Option<(PatchRegion, u32)>,
+    /// ISA specific flags.
+    isa_flags: aarch64_settings::Flags,
 }
 
 impl MacroAssembler {
     /// Create an Aarch64 MacroAssembler.
-    pub fn new(shared_flags: settings::Flags) -> Self {
+    pub fn new(shared_flags: settings::Flags, isa_flags: aarch64_settings::Flags) -> Self {
         Self {
             asm: Assembler::new(shared_flags),
             sp_offset: 0u32,
+            max_sp_offset: 0,
+            stack_check: None,
+            isa_flags,
+        }
+    }
+
+    /// The key used to sign the return address, if return address signing is
+    /// enabled. Winch always sets up a frame, so unlike Cranelift there's no
+    /// need to consult `sign_return_address_all`.
+    fn return_address_key(&self) -> Option<APIKey> {
+        if !self.isa_flags.sign_return_address() {
+            return None;
         }
+        Some(if self.isa_flags.sign_return_address_with_bkey() {
+            APIKey::BSP
+        } else {
+            APIKey::ASP
+        })
     }
 }
 
@@ -33,18 +70,30 @@ impl Masm for MacroAssembler {
     type Ptr = u8;
     type ABI = Aarch64ABI;
 
-    fn prologue(&mut self) {
+    fn prologue(&mut self, clobbers: &[(Reg, OperandSize)]) {
+        // Only trampolines save callee-saved registers, and they aren't
+        // implemented for aarch64 yet.
+        assert!(clobbers.is_empty());
         let lr = regs::lr();
         let fp = regs::fp();
         let sp = regs::sp();
         let addr = Address::pre_indexed_from_sp(-16);
 
+        // `paciasp` and `pacibsp` are also valid landing pads for indirect
+        // calls, so a `bti` is only needed when return addresses aren't
+        // signed.
+        match self.return_address_key() {
+            Some(key) => self.asm.paci(key),
+            None if self.isa_flags.use_bti() => self.asm.bti(BranchTargetType::C),
+            None => {}
+        }
         self.asm.stp(fp, lr, addr);
         self.asm.mov_rr(sp, fp, OperandSize::S64);
         self.move_sp_to_shadow_sp();
     }
 
-    fn epilogue(&mut self, locals_size: u32) {
+    fn epilogue(&mut self, locals_size: u32, clobbers: &[(Reg, OperandSize)]) {
+        assert!(clobbers.is_empty());
         assert!(self.sp_offset == locals_size);
 
         let sp = regs::sp();
@@ -59,7 +108,10 @@ impl Masm for MacroAssembler {
         let addr = Address::post_indexed_from_sp(16);
 
         self.asm.ldp(fp, lr, addr);
-        self.asm.ret();
+        match self.return_address_key() {
+            Some(key) => self.asm.authenticated_ret(key, !self.isa_flags.has_pauth()),
+            None => self.asm.ret(),
+        }
     }
 
     fn reserve_stack(&mut self, bytes: u32) {
@@ -111,6 +163,67 @@ impl Masm for MacroAssembler {
         todo!()
     }
 
+    fn memory_size(&mut self, _heap_data: &HeapData, _context: &mut CodeGenContext) {
+        todo!()
+    }
+
+    fn heap_address(
+        &mut self,
+        _index: Reg,
+        _base: Reg,
+        _heap_data: &HeapData,
+        _offset: u64,
+        _size: OperandSize,
+        _context: &mut CodeGenContext,
+    ) -> Self::Address {
+        todo!()
+    }
+
+    fn atomic_heap_address(
+        &mut self,
+        _index: Reg,
+        _base: Reg,
+        _heap_data: &HeapData,
+        _offset: u64,
+        _size: OperandSize,
+        _context: &mut CodeGenContext,
+    ) -> Self::Address {
+        todo!()
+    }
+
+    fn atomic_load(&mut self, _src: Self::Address, _dst: Reg, _size: OperandSize) {
+        todo!()
+    }
+
+    fn atomic_store(&mut self, _src: Reg, _dst: Self::Address, _size: OperandSize) {
+        todo!()
+    }
+
+    fn atomic_rmw(
+        &mut self,
+        _heap_data: &HeapData,
+        _offset: u64,
+        _kind: AtomicRmwKind,
+        _size: OperandSize,
+        _context: &mut CodeGenContext,
+    ) {
+        todo!()
+    }
+
+    fn atomic_cmpxchg(
+        &mut self,
+        _heap_data: &HeapData,
+        _offset: u64,
+        _size: OperandSize,
+        _context: &mut CodeGenContext,
+    ) {
+        todo!()
+    }
+
+    fn fence(&mut self) {
+        todo!()
+    }
+
     fn address_from_sp(&self, _offset: u32) -> Self::Address {
         todo!()
     }
@@ -119,8 +232,8 @@ impl Masm for MacroAssembler {
         todo!()
     }
 
-    fn address_at_vmctx(&self, _offset: u32) -> Self::Address {
-        todo!()
+    fn address_at_vmctx(&self, offset: u32) -> Self::Address {
+        Address::offset(regs::vmctx(), offset as i64)
     }
 
     fn store_ptr(&mut self, _src: Reg, _dst: Self::Address) {
@@ -148,19 +261,58 @@ impl Masm for MacroAssembler {
     fn call(
         &mut self,
         _stack_args_size: u32,
-        _load_callee: impl FnMut(&mut Self) -> CalleeKind,
+        _load_callee: impl FnMut(&mut Self) -> (CalleeKind, CallingConvention),
     ) -> u32 {
         todo!()
     }
 
+    fn stack_map(&mut self, gc_ref_slots: &[u32]) {
+        if gc_ref_slots.is_empty() {
+            return;
+        }
+        let word_bytes = <Self::ABI as abi::ABI>::word_bytes();
+        // The stack pointer is aligned at call sites, so the frame is made of
+        // whole words.
+        assert!(self.sp_offset % word_bytes == 0);
+        let mut words = vec![false; (self.sp_offset / word_bytes) as usize];
+        for &offset in gc_ref_slots {
+            let index = (self.sp_offset - offset) / word_bytes;
+            words[index as usize] = true;
+        }
+        self.asm.stack_map(StackMap::from_slice(&words));
+    }
+
     fn load(&mut self, src: Address, dst: Reg, size: OperandSize) {
         self.asm.ldr(src, dst, size);
     }
 
-    fn load_ptr(&mut self, _src: Self::Address, _dst: Reg) {
+    fn load_extend(
+        &mut self,
+        _src: Address,
+        _dst: Reg,
+        _src_size: OperandSize,
+        _dst_size: OperandSize,
+        _kind: ExtendKind,
+    ) {
         todo!()
     }
 
+    fn extend(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: ExtendKind,
+    ) {
+        let signed = matches!(kind, ExtendKind::Signed);
+        self.asm.extend(src, dst, signed, src_size, dst_size);
+    }
+
+    fn load_ptr(&mut self, src: Self::Address, dst: Reg) {
+        self.load(src, dst, OperandSize::S64);
+    }
+
     fn pop(&mut self, _dst: Reg, _size: OperandSize) {
         todo!()
     }
@@ -169,8 +321,29 @@ impl Masm for MacroAssembler {
         self.sp_offset
     }
 
-    fn finalize(self) -> MachBufferFinalized<Final> {
-        self.asm.finalize()
+    fn current_code_offset(&self) -> CodeOffset {
+        self.asm.buffer().cur_offset()
+    }
+
+    fn constant_pool_size(&self) -> u32 {
+        // Constants aren't supported yet.
+        0
+    }
+
+    fn start_source_loc(&mut self, loc: RelSourceLoc) {
+        self.asm.buffer_mut().start_srcloc(loc);
+    }
+
+    fn end_source_loc(&mut self) {
+        self.asm.buffer_mut().end_srcloc();
+    }
+
+    fn finalize(mut self, base: Option<SourceLoc>) -> MachBufferFinalized<Final> {
+        if let Some((region, sp_offset)) = self.stack_check.take() {
+            self.asm
+                .patch_constant(region, self.max_sp_offset - sp_offset);
+        }
+        self.asm.finalize(base)
     }
 
     fn mov(&mut self, src: RegImm, dst: Reg, size: OperandSize) {
@@ -179,16 +352,24 @@ impl Masm for MacroAssembler {
                 let imm = match v {
                     I::I32(v) => v as u64,
                     I::I64(v) => v,
+                    I::F32(v) => v as u64,
+                    I::F64(v) => v,
                     _ => panic!(),
                 };
 
                 let scratch = regs::scratch();
                 self.asm.load_constant(imm as u64, scratch);
-                self.asm.mov_rr(scratch, rd, size);
-            }
-            (RegImm::Reg(rs), rd) => {
-                self.asm.mov_rr(rs, rd, size);
+                if rd.is_int() {
+                    self.asm.mov_rr(scratch, rd, size);
+                } else {
+                    self.asm.mov_to_fpu(scratch, rd, size);
+                }
             }
+            (RegImm::Reg(rs), rd) => match (rs.class(), rd.class()) {
+                (RegClass::Int, RegClass::Int) => self.asm.mov_rr(rs, rd, size),
+                (RegClass::Float, RegClass::Float) => self.asm.fmov_rr(rs, rd),
+                _ => panic!("Invalid operand combination; src={rs:?}, dst={rd:?}"),
+            },
         }
     }
 
@@ -214,6 +395,17 @@ impl Masm for MacroAssembler {
         }
     }
 
+    fn checked_uadd(
+        &mut self,
+        _dst: Reg,
+        _lhs: Reg,
+        _rhs: RegImm,
+        _size: OperandSize,
+        _trap: TrapCode,
+    ) {
+        todo!()
+    }
+
     fn sub(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
         match (rhs, lhs, dst) {
             (RegImm::Imm(v), rn, rd) => {
@@ -250,53 +442,421 @@ impl Masm for MacroAssembler {
         }
     }
 
-    fn float_add(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_add(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fadd_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_sub(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_sub(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fsub_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_mul(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_mul(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fmul_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_div(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_div(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fdiv_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_min(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_min(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fmin_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_max(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_max(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        self.asm.fmax_rrr(rhs, lhs, dst, size);
     }
 
-    fn float_copysign(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _size: OperandSize) {
-        todo!()
+    fn float_copysign(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
+        // Move the sign bit of rhs to the lowest bit of the scratch register
+        // and then shift it back into the sign position of dst, keeping the
+        // rest of lhs intact.
+        let max_shift = (size.num_bits() - 1) as u8;
+        let scratch = regs::float_scratch();
+        self.asm.fushr_rri(rhs, scratch, max_shift, size);
+        if dst != lhs {
+            self.asm.fmov_rr(lhs, dst);
+        }
+        self.asm.fsli_rri_mod(scratch, dst, max_shift, size);
     }
 
-    fn float_neg(&mut self, _dst: Reg, _size: OperandSize) {
-        todo!()
+    fn float_neg(&mut self, dst: Reg, size: OperandSize) {
+        self.asm.fneg_rr(dst, dst, size);
     }
 
-    fn float_abs(&mut self, _dst: Reg, _size: OperandSize) {
-        todo!()
+    fn float_abs(&mut self, dst: Reg, size: OperandSize) {
+        self.asm.fabs_rr(dst, dst, size);
+    }
+
+    fn float_round(&mut self, mode: RoundingMode, context: &mut CodeGenContext, size: OperandSize) {
+        let src = context.pop_to_reg(self, None);
+        self.asm.fround_rr(src.into(), src.into(), mode, size);
+        context.stack.push(src.into());
+    }
+
+    fn float_sqrt(&mut self, dst: Reg, src: Reg, size: OperandSize) {
+        self.asm.fsqrt_rr(src, dst, size);
+    }
+
+    fn float_canonicalize_nan(&mut self, reg: Reg, size: OperandSize) {
+        let scratch = regs::scratch();
+        let nan = regs::float_scratch();
+        self.asm.load_constant(size.canonical_nan(), scratch);
+        self.asm.mov_to_fpu(scratch, nan, size);
+        // Only a NaN compares unordered with itself, setting the V flag.
+        self.asm.fcmp(reg, reg, size);
+        self.asm.fcsel(nan, reg, reg, Cond::Vs, size);
+    }
+
+    fn v128_add(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_sub(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_mul(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_div(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_and(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_and_not(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_or(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
     }
 
-    fn float_round(
+    fn v128_xor(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_not(&mut self, _dst: Reg) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_cmp(
         &mut self,
-        _mode: RoundingMode,
-        _context: &mut CodeGenContext,
-        _size: OperandSize,
+        _dst: Reg,
+        _lhs: Reg,
+        _rhs: Reg,
+        _kind: IntCmpKind,
+        _shape: VectorShape,
     ) {
-        todo!();
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
     }
 
-    fn float_sqrt(&mut self, _dst: Reg, _src: Reg, _size: OperandSize) {
-        todo!()
+    fn v128_float_cmp(
+        &mut self,
+        _dst: Reg,
+        _lhs: Reg,
+        _rhs: Reg,
+        _kind: FloatCmpKind,
+        _shape: VectorShape,
+    ) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_convert(&mut self, _dst: Reg, _src: Reg, _tmp: Reg, _kind: VectorConvertKind) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_canonicalize_nan(&mut self, _reg: Reg, _tmp: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn signed_truncate(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: TruncKind,
+    ) {
+        self.truncate(dst, src, src_size, dst_size, kind, true);
+    }
+
+    fn unsigned_truncate(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        _tmp_fpr: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: TruncKind,
+    ) {
+        self.truncate(dst, src, src_size, dst_size, kind, false);
+    }
+
+    fn v128_splat(&mut self, _dst: Reg, _src: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_shuffle(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _lanes: [u8; 16]) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn check_simd_support(&self) -> Result<()> {
+        Err(anyhow!(
+            "the SIMD proposal isn't supported by Winch on aarch64"
+        ))
+    }
+
+    fn check_operator_support(&self, op: &Operator<'_>) -> Result<()> {
+        use Operator::*;
+
+        let supported = match op {
+            // Accessing linear memory needs heap bounds checks, and the
+            // memory and data segment instructions call builtins, none of
+            // which are lowered yet.
+            I32Load { .. }
+            | I64Load { .. }
+            | F32Load { .. }
+            | F64Load { .. }
+            | I32Load8S { .. }
+            | I32Load8U { .. }
+            | I32Load16S { .. }
+            | I32Load16U { .. }
+            | I64Load8S { .. }
+            | I64Load8U { .. }
+            | I64Load16S { .. }
+            | I64Load16U { .. }
+            | I64Load32S { .. }
+            | I64Load32U { .. }
+            | I32Store { .. }
+            | I64Store { .. }
+            | F32Store { .. }
+            | F64Store { .. }
+            | I32Store8 { .. }
+            | I32Store16 { .. }
+            | I64Store8 { .. }
+            | I64Store16 { .. }
+            | I64Store32 { .. }
+            | MemorySize { .. }
+            | MemoryGrow { .. }
+            | MemoryCopy { .. }
+            | MemoryFill { .. }
+            | MemoryInit { .. }
+            | DataDrop { .. } => false,
+            // Neither are atomics.
+            _ => operator_proposal(op) != "threads",
+        };
+
+        if supported {
+            Ok(())
+        } else {
+            Err(anyhow!("{op:?} isn't supported by Winch on aarch64"))
+        }
+    }
+
+    fn v128_load(&mut self, _src: Address, _dst: Reg, _kind: VectorLoadKind) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_load_lane(&mut self, _src: Address, _dst: Reg, _lane: u8, _size: OperandSize) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_store_lane(&mut self, _src: Reg, _dst: Address, _lane: u8, _size: OperandSize) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_extract_lane(
+        &mut self,
+        _dst: Reg,
+        _src: Reg,
+        _lane: u8,
+        _shape: VectorShape,
+        _extend: Option<ExtendKind>,
+    ) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_replace_lane(&mut self, _dst: Reg, _src: Reg, _lane: u8, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_bitselect(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _mask: Reg) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_any_true(&mut self, _dst: Reg, _src: Reg) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_all_true(&mut self, _dst: Reg, _src: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_bitmask(&mut self, _dst: Reg, _src: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_abs(&mut self, _dst: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_neg(&mut self, _dst: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_popcnt(&mut self, _dst: Reg, _tmp: Reg) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_sqrt(&mut self, _dst: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_round(&mut self, _dst: Reg, _mode: RoundingMode, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_add_sat(
+        &mut self,
+        _dst: Reg,
+        _lhs: Reg,
+        _rhs: Reg,
+        _shape: VectorShape,
+        _sign: Signedness,
+    ) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_sub_sat(
+        &mut self,
+        _dst: Reg,
+        _lhs: Reg,
+        _rhs: Reg,
+        _shape: VectorShape,
+        _sign: Signedness,
+    ) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_min(
+        &mut self,
+        _dst: Reg,
+        _lhs: Reg,
+        _rhs: Reg,
+        _shape: VectorShape,
+        _sign: Signedness,
+    ) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_max(
+        &mut self,
+        _dst: Reg,
+        _lhs: Reg,
+        _rhs: Reg,
+        _shape: VectorShape,
+        _sign: Signedness,
+    ) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_float_min(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_float_max(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_pmin(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_pmax(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_avgr(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_q15mulr_sat(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_dot(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_extmul(
+        &mut self,
+        _dst: Reg,
+        _lhs: Reg,
+        _rhs: Reg,
+        _shape: VectorShape,
+        _half: VectorHalf,
+        _kind: ExtendKind,
+    ) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_extend(
+        &mut self,
+        _dst: Reg,
+        _shape: VectorShape,
+        _half: VectorHalf,
+        _kind: ExtendKind,
+    ) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_extadd_pairwise(&mut self, _dst: Reg, _shape: VectorShape, _kind: ExtendKind) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_narrow(
+        &mut self,
+        _dst: Reg,
+        _lhs: Reg,
+        _rhs: Reg,
+        _shape: VectorShape,
+        _sign: Signedness,
+    ) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_shift(&mut self, _context: &mut CodeGenContext, _kind: ShiftKind, _shape: VectorShape) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn v128_swizzle(&mut self, _dst: Reg, _lhs: Reg, _rhs: Reg) {
+        unreachable!("SIMD operators are rejected by `check_simd_support`")
+    }
+
+    fn alu_mem(
+        &mut self,
+        dst: Reg,
+        lhs: Reg,
+        rhs: Self::Address,
+        kind: AluKind,
+        size: OperandSize,
+    ) {
+        // Aarch64 has no ALU instructions with memory operands, so load the
+        // operand into the scratch register first.
+        let scratch = regs::scratch();
+        self.load(rhs, scratch, size);
+        let rhs = RegImm::reg(scratch);
+        match kind {
+            AluKind::Add => self.add(dst, lhs, rhs, size),
+            AluKind::Sub => self.sub(dst, lhs, rhs, size),
+            AluKind::And => self.and(dst, lhs, rhs, size),
+            AluKind::Or => self.or(dst, lhs, rhs, size),
+            AluKind::Xor => self.xor(dst, lhs, rhs, size),
+        }
     }
 
     fn and(&mut self, _dst: Reg, _lhs: Reg, _rhs: RegImm, _size: OperandSize) {
@@ -315,6 +875,10 @@ impl Masm for MacroAssembler {
         todo!()
     }
 
+    fn shift_ir(&mut self, dst: Reg, imm: u64, lhs: Reg, kind: ShiftKind, size: OperandSize) {
+        self.asm.shift_ir(imm, lhs, dst, kind, size);
+    }
+
     fn div(&mut self, _context: &mut CodeGenContext, _kind: DivKind, _size: OperandSize) {
         todo!()
     }
@@ -357,13 +921,25 @@ impl Masm for MacroAssembler {
 
     fn float_cmp_with_set(
         &mut self,
-        _src1: Reg,
-        _src2: Reg,
-        _dst: Reg,
-        _kind: FloatCmpKind,
-        _size: OperandSize,
+        src1: Reg,
+        src2: Reg,
+        dst: Reg,
+        kind: FloatCmpKind,
+        size: OperandSize,
     ) {
-        todo!()
+        // An unordered comparison sets the C and V flags, so all the
+        // conditions below evaluate to false when either operand is NaN,
+        // except for not equal which evaluates to true.
+        let cond = match kind {
+            FloatCmpKind::Eq => Cond::Eq,
+            FloatCmpKind::Ne => Cond::Ne,
+            FloatCmpKind::Lt => Cond::Mi,
+            FloatCmpKind::Le => Cond::Ls,
+            FloatCmpKind::Gt => Cond::Gt,
+            FloatCmpKind::Ge => Cond::Ge,
+        };
+        self.asm.fcmp(src2, src1, size);
+        self.asm.cset(dst, cond);
     }
 
     fn clz(&mut self, _src: Reg, _dst: Reg, _size: OperandSize) {
@@ -379,8 +955,7 @@ impl Masm for MacroAssembler {
     }
 
     fn bind(&mut self, label: MachLabel) {
-        let buffer = self.asm.buffer_mut();
-        buffer.bind_label(label, &mut Default::default());
+        self.asm.bind_label(label);
     }
 
     fn branch(
@@ -402,6 +977,10 @@ impl Masm for MacroAssembler {
         todo!()
     }
 
+    fn trap(&mut self, code: TrapCode) {
+        self.asm.udf(code);
+    }
+
     fn jmp_table(&mut self, _targets: &[MachLabel], _index: Reg, _tmp: Reg) {
         todo!()
     }
@@ -413,11 +992,105 @@ impl Masm for MacroAssembler {
     fn trapif(&mut self, _cc: IntCmpKind, _code: TrapCode) {
         todo!()
     }
+
+    fn check_stack(&mut self, limit: Reg) {
+        // The limit is held in the scratch register, so the frame size is
+        // materialized in the other intra-procedure-call register.
+        let size = regs::ip1();
+        let region = self.asm.load_constant_patchable(size);
+        self.stack_check = Some((region, self.sp_offset));
+        self.asm.adds_rrr(size, limit, limit, OperandSize::S64);
+        self.asm.trapif(Cond::Hs, TrapCode::StackOverflow);
+        self.asm.cmp_rr(limit, regs::sp(), OperandSize::S64);
+        self.asm.trapif(Cond::Lo, TrapCode::StackOverflow);
+    }
 }
 
 impl MacroAssembler {
     fn increment_sp(&mut self, bytes: u32) {
         self.sp_offset += bytes;
+        self.max_sp_offset = self.max_sp_offset.max(self.sp_offset);
+    }
+
+    /// Convert the float in `src` to an integer, rounding towards zero.
+    ///
+    /// The conversion instructions already saturate out of range values and
+    /// convert NaN to zero, which is what the saturating variants require.
+    /// The checked variants compare `src` against the bounds of the
+    /// destination type first and trap if it's NaN or out of range.
+    fn truncate(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: TruncKind,
+        signed: bool,
+    ) {
+        if kind == TruncKind::Checked {
+            self.asm.fcmp(src, src, src_size);
+            self.asm.trapif(Cond::Vs, TrapCode::BadConversionToInteger);
+
+            let (min, min_cond) = Self::truncate_lower_bound(src_size, dst_size, signed);
+            self.load_float_const(min, regs::float_scratch(), src_size);
+            self.asm.fcmp(src, regs::float_scratch(), src_size);
+            self.asm.trapif(min_cond, TrapCode::IntegerOverflow);
+
+            let max = Self::truncate_upper_bound(src_size, dst_size, signed);
+            self.load_float_const(max, regs::float_scratch(), src_size);
+            self.asm.fcmp(src, regs::float_scratch(), src_size);
+            self.asm.trapif(Cond::Ge, TrapCode::IntegerOverflow);
+        }
+        self.asm.fcvtz(src, dst, src_size, dst_size, signed);
+    }
+
+    /// Returns the bits of the lower bound for converting a float to an
+    /// integer of size `dst_size`, together with the condition under which a
+    /// value compared against it is out of range.
+    fn truncate_lower_bound(
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        signed: bool,
+    ) -> (u64, Cond) {
+        use OperandSize::*;
+        match (src_size, dst_size, signed) {
+            (_, _, false) => match src_size {
+                S32 => ((-1.0f32).to_bits() as u64, Cond::Le),
+                _ => ((-1.0f64).to_bits(), Cond::Le),
+            },
+            // i32::MIN - 1 isn't representable as an f32, so compare against
+            // i32::MIN itself and only trap below it.
+            (S32, S32, true) => ((i32::MIN as f32).to_bits() as u64, Cond::Lt),
+            (S32, S64, true) => ((i64::MIN as f32).to_bits() as u64, Cond::Lt),
+            (S64, S32, true) => ((i32::MIN as f64 - 1.0).to_bits(), Cond::Le),
+            (S64, S64, true) => ((i64::MIN as f64).to_bits(), Cond::Lt),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the bits of the smallest float which is out of range when
+    /// converted to an integer of size `dst_size`.
+    fn truncate_upper_bound(src_size: OperandSize, dst_size: OperandSize, signed: bool) -> u64 {
+        use OperandSize::*;
+        match (src_size, dst_size, signed) {
+            (S32, S32, true) => ((i32::MAX as u64 + 1) as f32).to_bits() as u64,
+            (S32, S64, true) => ((i64::MAX as u64 + 1) as f32).to_bits() as u64,
+            (S32, S32, false) => ((u32::MAX as u64 + 1) as f32).to_bits() as u64,
+            (S32, S64, false) => ((u64::MAX as u128 + 1) as f32).to_bits() as u64,
+            (S64, S32, true) => (i32::MAX as f64 + 1.0).to_bits(),
+            (S64, S64, true) => ((i64::MAX as u64 + 1) as f64).to_bits(),
+            (S64, S32, false) => (u32::MAX as f64 + 1.0).to_bits(),
+            (S64, S64, false) => ((u64::MAX as u128 + 1) as f64).to_bits(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Load the float with the given bits into `dst`, going through the
+    /// general purpose scratch register.
+    fn load_float_const(&mut self, bits: u64, dst: Reg, size: OperandSize) {
+        let scratch = regs::scratch();
+        self.asm.load_constant(bits, scratch);
+        self.asm.mov_to_fpu(scratch, dst, size);
     }
 
     // Copies the value of the stack pointer to the shadow stack
@@ -434,3 +1107,42 @@ impl MacroAssembler {
         self.asm.mov_rr(sp, shadow_sp, OperandSize::S64);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MacroAssembler;
+    use crate::masm::MacroAssembler as _;
+    use cranelift_codegen::{isa::aarch64::settings as aarch64_settings, settings};
+    use wasmparser::{MemArg, Operator};
+
+    #[test]
+    fn test_check_operator_support() {
+        let shared_flags = settings::Flags::new(settings::builder());
+        let isa_flags = aarch64_settings::Flags::new(&shared_flags, &aarch64_settings::builder());
+        let masm = MacroAssembler::new(shared_flags, isa_flags);
+        let memarg = MemArg {
+            align: 2,
+            max_align: 2,
+            offset: 0,
+            memory: 0,
+        };
+
+        assert!(masm.check_operator_support(&Operator::I32Add).is_ok());
+        assert!(masm
+            .check_operator_support(&Operator::I64ExtendI32S)
+            .is_ok());
+        assert!(masm
+            .check_operator_support(&Operator::I32Load { memarg })
+            .is_err());
+        assert!(masm
+            .check_operator_support(&Operator::MemorySize {
+                mem: 0,
+                mem_byte: 0
+            })
+            .is_err());
+        assert!(masm
+            .check_operator_support(&Operator::I32AtomicLoad { memarg })
+            .is_err());
+        assert!(masm.check_operator_support(&Operator::AtomicFence).is_err());
+    }
+}
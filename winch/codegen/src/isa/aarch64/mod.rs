@@ -1,23 +1,30 @@
-use self::regs::{ALL_GPR, MAX_FPR, MAX_GPR, NON_ALLOCATABLE_GPR};
+use self::regs::{ALL_FPR, ALL_GPR, MAX_FPR, MAX_GPR, NON_ALLOCATABLE_FPR, NON_ALLOCATABLE_GPR};
 use crate::{
     abi::ABI,
     codegen::{CodeGen, CodeGenContext, FuncEnv},
     frame::{DefinedLocals, Frame},
-    isa::{Builder, CallingConvention, TargetIsa},
+    isa::{Builder, TargetIsa},
     masm::MacroAssembler,
     regalloc::RegAlloc,
     regset::RegBitSet,
     stack::Stack,
     BuiltinFunctions, TrampolineKind,
 };
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use cranelift_codegen::settings::{self, Flags};
 use cranelift_codegen::{isa::aarch64::settings as aarch64_settings, Final, MachBufferFinalized};
 use cranelift_codegen::{MachTextSectionBuilder, TextSectionBuilder};
 use masm::MacroAssembler as Aarch64Masm;
 use target_lexicon::Triple;
 use wasmparser::{FuncValidator, FunctionBody, ValidatorResources};
-use wasmtime_environ::{ModuleTranslation, ModuleTypes, VMOffsets, WasmFuncType};
+use wasmtime_environ::{
+    FunctionCodegenStats, ModuleTranslation, ModuleTypes, VMOffsets, WasmFuncType,
+};
+#[cfg(feature = "component-model")]
+use {
+    crate::ComponentAbi,
+    wasmtime_environ::component::{ComponentTranslation, ComponentTypes, TrampolineIndex},
+};
 
 mod abi;
 mod address;
@@ -90,12 +97,13 @@ impl TargetIsa for Aarch64 {
         types: &ModuleTypes,
         builtins: &mut BuiltinFunctions,
         validator: &mut FuncValidator<ValidatorResources>,
+        stats: Option<&mut FunctionCodegenStats>,
     ) -> Result<MachBufferFinalized<Final>> {
         let vmoffsets = VMOffsets::new(self.pointer_bytes(), &translation.module);
         let mut body = body.get_binary_reader();
-        let mut masm = Aarch64Masm::new(self.shared_flags.clone());
+        let mut masm = Aarch64Masm::new(self.shared_flags.clone(), self.isa_flags.clone());
         let stack = Stack::new();
-        let abi_sig = abi::Aarch64ABI::sig(sig, &CallingConvention::Default);
+        let abi_sig = abi::Aarch64ABI::wasm_sig(sig);
 
         let defined_locals = DefinedLocals::new(translation, &mut body, validator)?;
         let frame = Frame::new::<abi::Aarch64ABI>(&abi_sig, &defined_locals)?;
@@ -104,15 +112,27 @@ impl TargetIsa for Aarch64 {
             NON_ALLOCATABLE_GPR.into(),
             usize::try_from(MAX_GPR).unwrap(),
         );
-        // TODO: Add floating point bitmask
-        let fpr = RegBitSet::float(0, 0, usize::try_from(MAX_FPR).unwrap());
+        let fpr = RegBitSet::float(
+            ALL_FPR.into(),
+            NON_ALLOCATABLE_FPR.into(),
+            usize::try_from(MAX_FPR).unwrap(),
+        );
         let regalloc = RegAlloc::from(gpr, fpr);
         let codegen_context = CodeGenContext::new(regalloc, stack, frame, builtins, &vmoffsets);
         let env = FuncEnv::new(&vmoffsets, translation, types);
-        let mut codegen = CodeGen::new(&mut masm, codegen_context, env, abi_sig);
+        let mut codegen = CodeGen::new(
+            &mut masm,
+            codegen_context,
+            env,
+            abi_sig,
+            &self.shared_flags,
+            stats,
+        );
 
         codegen.emit(&mut body, validator)?;
-        Ok(masm.finalize())
+        let base = codegen.base_srcloc;
+
+        Ok(masm.finalize(base))
     }
 
     fn text_section_builder(&self, num_funcs: usize) -> Box<dyn TextSectionBuilder> {
@@ -131,6 +151,19 @@ impl TargetIsa for Aarch64 {
         _ty: &WasmFuncType,
         _kind: TrampolineKind,
     ) -> Result<MachBufferFinalized<Final>> {
-        todo!()
+        Err(anyhow!("trampolines aren't supported by Winch on aarch64"))
+    }
+
+    #[cfg(feature = "component-model")]
+    fn compile_component_trampoline(
+        &self,
+        _translation: &ComponentTranslation,
+        _types: &ComponentTypes,
+        _index: TrampolineIndex,
+        _abi: ComponentAbi,
+    ) -> Result<MachBufferFinalized<Final>> {
+        Err(anyhow!(
+            "component trampolines aren't supported by Winch on aarch64"
+        ))
     }
 }
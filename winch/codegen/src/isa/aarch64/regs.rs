@@ -32,6 +32,11 @@ pub(crate) const fn scratch() -> Reg {
     ip0()
 }
 
+/// Scratch register for floating point and vector operations.
+pub(crate) const fn float_scratch() -> Reg {
+    vreg(31)
+}
+
 /// Scratch register.
 /// Intra-procedure-call corruptible register.
 pub(crate) const fn ip1() -> Reg {
@@ -149,6 +154,12 @@ pub(crate) const NON_ALLOCATABLE_GPR: u32 = (1 << ip0().hw_enc())
 /// Bitmask to represent the available general purpose registers.
 pub(crate) const ALL_GPR: u32 = u32::MAX & !NON_ALLOCATABLE_GPR;
 
+/// Bitmask for non-allocatable FPR.
+pub(crate) const NON_ALLOCATABLE_FPR: u32 = 1 << float_scratch().hw_enc();
+
+/// Bitmask to represent the available floating point registers.
+pub(crate) const ALL_FPR: u32 = u32::MAX & !NON_ALLOCATABLE_FPR;
+
 /// Returns the callee-saved registers.
 ///
 /// This function will return the set of registers that need to be saved
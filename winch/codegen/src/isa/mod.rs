@@ -1,6 +1,7 @@
 use crate::{BuiltinFunctions, TrampolineKind};
 use anyhow::{anyhow, Result};
 use core::fmt::Formatter;
+use cranelift_codegen::isa::unwind::{UnwindInfo, UnwindInfoKind};
 use cranelift_codegen::isa::{CallConv, IsaBuilder};
 use cranelift_codegen::settings;
 use cranelift_codegen::{Final, MachBufferFinalized, TextSectionBuilder};
@@ -10,7 +11,12 @@ use std::{
 };
 use target_lexicon::{Architecture, Triple};
 use wasmparser::{FuncValidator, FunctionBody, ValidatorResources};
-use wasmtime_environ::{ModuleTranslation, ModuleTypes, WasmFuncType};
+use wasmtime_environ::{FunctionCodegenStats, ModuleTranslation, ModuleTypes, WasmFuncType};
+#[cfg(feature = "component-model")]
+use {
+    crate::ComponentAbi,
+    wasmtime_environ::component::{ComponentTranslation, ComponentTypes, TrampolineIndex},
+};
 
 #[cfg(feature = "x64")]
 pub(crate) mod x64;
@@ -77,7 +83,7 @@ pub(crate) enum LookupError {
 /// This enum is a reduced subset of the calling conventions defined in
 /// [cranelift_codegen::isa::CallConv]. Introducing this enum makes it easier
 /// to enforce the invariant of all the calling conventions supported by Winch.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CallingConvention {
     /// See [cranelift_codegen::isa::CallConv::WasmtimeSystemV]
     WasmtimeSystemV,
@@ -125,6 +131,19 @@ impl CallingConvention {
     }
 }
 
+impl From<CallingConvention> for CallConv {
+    fn from(value: CallingConvention) -> Self {
+        match value {
+            CallingConvention::WasmtimeSystemV => Self::SystemV,
+            CallingConvention::WindowsFastcall => Self::WindowsFastcall,
+            CallingConvention::AppleAarch64 => Self::AppleAarch64,
+            // Winch's default calling convention is an internal one which
+            // largely follows SystemV.
+            CallingConvention::Default => Self::SystemV,
+        }
+    }
+}
+
 /// A trait representing commonalities between the supported
 /// instruction set architectures.
 pub trait TargetIsa: Send + Sync {
@@ -146,6 +165,9 @@ pub trait TargetIsa: Send + Sync {
     }
 
     /// Compile a function.
+    ///
+    /// When `stats` is provided, code generation statistics for the function
+    /// are recorded into it.
     fn compile_function(
         &self,
         sig: &WasmFuncType,
@@ -154,6 +176,7 @@ pub trait TargetIsa: Send + Sync {
         types: &ModuleTypes,
         builtins: &mut BuiltinFunctions,
         validator: &mut FuncValidator<ValidatorResources>,
+        stats: Option<&mut FunctionCodegenStats>,
     ) -> Result<MachBufferFinalized<Final>>;
 
     /// Get the default calling convention of the underlying target triple.
@@ -183,6 +206,16 @@ pub trait TargetIsa: Send + Sync {
         None
     }
 
+    /// See `cranelift_codegen::isa::TargetIsa::emit_unwind_info`.
+    fn emit_unwind_info(
+        &self,
+        _buffer: &MachBufferFinalized<Final>,
+        _kind: UnwindInfoKind,
+    ) -> Result<Option<UnwindInfo>> {
+        // By default, an ISA doesn't describe its frames to unwinders.
+        Ok(None)
+    }
+
     /// See `cranelift_codegen::isa::TargetIsa::text_section_builder`.
     fn text_section_builder(&self, num_labeled_funcs: usize) -> Box<dyn TextSectionBuilder>;
 
@@ -199,6 +232,17 @@ pub trait TargetIsa: Send + Sync {
         kind: TrampolineKind,
     ) -> Result<MachBufferFinalized<Final>>;
 
+    /// Compile the trampoline at `index` of `translation`, for callers using
+    /// the given calling convention.
+    #[cfg(feature = "component-model")]
+    fn compile_component_trampoline(
+        &self,
+        translation: &ComponentTranslation,
+        types: &ComponentTypes,
+        index: TrampolineIndex,
+        abi: ComponentAbi,
+    ) -> Result<MachBufferFinalized<Final>>;
+
     /// Returns the pointer width of the ISA in bytes.
     fn pointer_bytes(&self) -> u8 {
         let width = self.triple().pointer_width().unwrap();
@@ -1,6 +1,6 @@
 use super::regs;
 use crate::{
-    abi::{ABIArg, ABIResult, ABISig, ABI},
+    abi::{align_to, ty_size, ABIArg, ABIResult, ABISig, ABI},
     isa::{reg::Reg, CallingConvention},
     masm::OperandSize,
 };
@@ -104,10 +104,6 @@ impl ABI for X64ABI {
     ) -> ABISig {
         assert!(call_conv.is_fastcall() || call_conv.is_systemv() || call_conv.is_default());
 
-        if returns.len() > 1 {
-            panic!("multi-value not supported");
-        }
-
         let is_fastcall = call_conv.is_fastcall();
         // In the fastcall calling convention, the callee gets a contiguous
         // stack area of 32 bytes (4 register arguments) just before its frame.
@@ -119,13 +115,33 @@ impl ABI for X64ABI {
             (0, RegIndexEnv::default())
         };
 
-        let params: SmallVec<[ABIArg; 6]> = params
+        let mut params: SmallVec<[ABIArg; 6]> = params
             .iter()
             .map(|arg| Self::to_abi_arg(arg, &mut stack_offset, &mut index_env, is_fastcall))
             .collect();
 
+        // Like in Cranelift, the copies of the values passed by reference
+        // follow all the other stack arguments.
+        for param in params.iter_mut() {
+            if let ABIArg::Indirect { ty, offset, .. } = param {
+                let size = ty_size(ty);
+                *offset = align_to(stack_offset, size);
+                stack_offset = *offset + size;
+            }
+        }
+
         let result = Self::result(returns, call_conv);
-        ABISig::new(params, result, stack_offset)
+        // Results which don't fit in registers are written to a return area
+        // whose address is passed after all the other arguments.
+        let ret_area_ptr = (result.ret_area_size() > 0).then(|| {
+            Self::to_abi_arg(
+                &WasmType::I64,
+                &mut stack_offset,
+                &mut index_env,
+                is_fastcall,
+            )
+        });
+        ABISig::new(params, result, ret_area_ptr, stack_offset, *call_conv)
     }
 
     fn sig(wasm_sig: &WasmFuncType, call_conv: &CallingConvention) -> ABISig {
@@ -133,30 +149,30 @@ impl ABI for X64ABI {
     }
 
     fn result(returns: &[WasmType], _call_conv: &CallingConvention) -> ABIResult {
-        // This invariant will be lifted once support for multi-value is added.
-        assert!(returns.len() <= 1, "multi-value not supported");
-        let ty = returns.get(0).copied();
-        ty.map(|ty| {
-            let reg = match ty {
-                // The `Default`, `WasmtimeFastcall` and `WasmtimeSystemV use `rax` and `xmm0`.
-                // NOTE This should be updated when supporting multi-value.
-                WasmType::I32 | WasmType::I64 => regs::rax(),
-                WasmType::F32 | WasmType::F64 => regs::xmm0(),
-                WasmType::Ref(rt) => {
-                    assert!(rt.heap_type == WasmHeapType::Func);
-                    regs::rax()
-                }
-                t => panic!("Unsupported return type {:?}", t),
-            };
-            ABIResult::reg(ty, reg)
+        ABIResult::new(returns, |ty| match ty {
+            // The `Default`, `WasmtimeFastcall` and `WasmtimeSystemV use `rax`
+            // and `xmm0` for the first result; any other results are passed
+            // through the return area.
+            WasmType::I32 | WasmType::I64 => regs::rax(),
+            WasmType::F32 | WasmType::F64 | WasmType::V128 => regs::xmm0(),
+            WasmType::Ref(rt) => {
+                assert!(matches!(
+                    rt.heap_type,
+                    WasmHeapType::Func | WasmHeapType::Extern
+                ));
+                regs::rax()
+            }
         })
-        .unwrap_or_else(|| ABIResult::void())
     }
 
     fn scratch_reg() -> Reg {
         regs::scratch()
     }
 
+    fn float_scratch_reg() -> Reg {
+        regs::scratch_xmm()
+    }
+
     fn fp_reg() -> Reg {
         regs::rbp()
     }
@@ -176,12 +192,12 @@ impl ABI for X64ABI {
     fn stack_arg_slot_size_for_type(ty: WasmType) -> u32 {
         match ty {
             WasmType::Ref(rt) => match rt.heap_type {
-                WasmHeapType::Func => Self::word_bytes(),
+                WasmHeapType::Func | WasmHeapType::Extern => Self::word_bytes(),
                 ht => unimplemented!("Support for WasmHeapType: {ht}"),
             },
             WasmType::F64 | WasmType::I32 | WasmType::I64 => Self::word_bytes(),
             WasmType::F32 => Self::word_bytes() / 2,
-            ty => unimplemented!("Support for WasmType: {ty}"),
+            WasmType::V128 => Self::word_bytes() * 2,
         }
     }
 }
@@ -195,7 +211,9 @@ impl X64ABI {
     ) -> ABIArg {
         let (reg, ty) = match wasm_arg {
             ty @ WasmType::Ref(rt) => match rt.heap_type {
-                WasmHeapType::Func => (Self::int_reg_for(index_env.next_gpr(), fastcall), ty),
+                WasmHeapType::Func | WasmHeapType::Extern => {
+                    (Self::int_reg_for(index_env.next_gpr(), fastcall), ty)
+                }
                 ht => unimplemented!("Support for WasmHeapType: {ht}"),
             },
 
@@ -203,16 +221,26 @@ impl X64ABI {
                 (Self::int_reg_for(index_env.next_gpr(), fastcall), ty)
             }
 
-            ty @ (WasmType::F32 | WasmType::F64) => {
-                (Self::float_reg_for(index_env.next_fpr(), fastcall), ty)
+            // Fastcall passes 128-bit vectors indirectly, through a pointer to
+            // a copy made by the caller. The copy's offset is assigned once
+            // all the arguments are known.
+            WasmType::V128 if fastcall => {
+                let pointer = Self::to_abi_arg(&WasmType::I64, stack_offset, index_env, fastcall);
+                return ABIArg::indirect(pointer, 0, WasmType::V128);
             }
 
-            ty => unimplemented!("Support for argument of WasmType: {ty}"),
+            ty @ (WasmType::F32 | WasmType::F64 | WasmType::V128) => {
+                (Self::float_reg_for(index_env.next_fpr(), fastcall), ty)
+            }
         };
 
         let default = || {
-            let arg = ABIArg::stack_offset(*stack_offset, *ty);
             let size = Self::stack_arg_slot_size_for_type(*ty);
+            // Vectors passed on the stack are naturally aligned.
+            if *ty == WasmType::V128 {
+                *stack_offset = align_to(*stack_offset, size);
+            }
+            let arg = ABIArg::stack_offset(*stack_offset, *ty);
             *stack_offset += size;
             arg
         };
@@ -388,6 +416,80 @@ mod tests {
         match_stack_arg(params.get(5).unwrap(), F32, 40);
     }
 
+    #[test]
+    fn fastcall_shadow_space() {
+        let wasm_sig = WasmFuncType::new([I64, I64].into(), [I64].into());
+
+        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::WindowsFastcall);
+        match_reg_arg(sig.params.get(0).unwrap(), I64, regs::rcx());
+        match_reg_arg(sig.params.get(1).unwrap(), I64, regs::rdx());
+        // The 32 bytes of shadow space are reserved even if all arguments
+        // are passed in registers.
+        assert_eq!(sig.stack_bytes, 32);
+        assert_eq!(sig.call_conv, CallingConvention::WindowsFastcall);
+
+        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::WasmtimeSystemV);
+        assert_eq!(sig.stack_bytes, 0);
+    }
+
+    #[test]
+    fn fastcall_indirect_vectors() {
+        let wasm_sig = WasmFuncType::new(
+            [V128, I64, V128, F64, V128, V128].into(),
+            [V128, V128].into(),
+        );
+
+        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::WindowsFastcall);
+        let params = sig.params;
+
+        // The pointers take the place of the vectors in the argument
+        // registers and stack arguments, and the copies follow all the stack
+        // arguments.
+        match_indirect_arg(params.get(0).unwrap(), Some(regs::rcx()), 48);
+        match_reg_arg(params.get(1).unwrap(), I64, regs::rdx());
+        match_indirect_arg(params.get(2).unwrap(), Some(regs::r8()), 64);
+        match_reg_arg(params.get(3).unwrap(), F64, regs::xmm3());
+        match_indirect_arg(params.get(4).unwrap(), None, 80);
+        match_indirect_arg(params.get(5).unwrap(), None, 96);
+        match_stack_arg(sig.ret_area_ptr.as_ref().unwrap(), I64, 112);
+        assert_eq!(sig.stack_bytes, 120);
+        // Vectors are still returned in registers.
+        assert_eq!(sig.result.result_reg(), Some(regs::xmm0()));
+
+        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::WasmtimeSystemV);
+        match_reg_arg(sig.params.get(0).unwrap(), V128, regs::xmm0());
+    }
+
+    #[test]
+    fn multi_value_sig() {
+        let wasm_sig = WasmFuncType::new(
+            [I32, I64, I32, I64, I32, I32].into(),
+            [I32, F64, I32, F32, I64].into(),
+        );
+
+        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::Default);
+
+        assert_eq!(sig.result.result_reg(), Some(regs::rax()));
+        assert_eq!(
+            sig.result.ret_area(),
+            &[(F64, 0), (I32, 8), (F32, 12), (I64, 16)]
+        );
+        assert_eq!(sig.result.ret_area_size(), 24);
+        assert_eq!(sig.result.len(), 5);
+        // All the integer registers are taken by the parameters, so the
+        // return area pointer is passed on the stack.
+        match_stack_arg(sig.ret_area_ptr.as_ref().unwrap(), I64, 0);
+        assert_eq!(sig.stack_bytes, 8);
+
+        let wasm_sig = WasmFuncType::new([F32].into(), [F32, F32].into());
+        let sig = X64ABI::sig(&wasm_sig, &CallingConvention::Default);
+
+        assert_eq!(sig.result.result_reg(), Some(regs::xmm0()));
+        assert_eq!(sig.result.ret_area(), &[(F32, 0)]);
+        match_reg_arg(sig.ret_area_ptr.as_ref().unwrap(), I64, regs::rdi());
+        assert_eq!(sig.stack_bytes, 0);
+    }
+
     fn match_reg_arg(abi_arg: &ABIArg, expected_ty: WasmType, expected_reg: Reg) {
         match abi_arg {
             &ABIArg::Reg { reg, ty } => {
@@ -398,6 +500,22 @@ mod tests {
         }
     }
 
+    fn match_indirect_arg(abi_arg: &ABIArg, expected_reg: Option<Reg>, expected_offset: u32) {
+        match abi_arg {
+            ABIArg::Indirect {
+                ty,
+                pointer,
+                offset,
+            } => {
+                assert_eq!(*ty, V128);
+                assert_eq!(pointer.ty(), I64);
+                assert_eq!(pointer.get_reg(), expected_reg);
+                assert_eq!(*offset, expected_offset);
+            }
+            other => panic!("Expected indirect argument, got {:?}", other),
+        }
+    }
+
     fn match_stack_arg(abi_arg: &ABIArg, expected_ty: WasmType, expected_offset: u32) {
         match abi_arg {
             &ABIArg::Stack { offset, ty } => {
@@ -8,6 +8,17 @@ use cranelift_codegen::ir::Constant;
 pub(crate) enum Address {
     /// Base register with an immediate offset.
     Offset { base: Reg, offset: u32 },
+    /// Base register plus an index register scaled by `1 << shift`, with an
+    /// immediate offset.
+    IndexedOffset {
+        base: Reg,
+        index: Reg,
+        shift: u8,
+        offset: u32,
+        /// Whether accesses through the address may fault, in which case a
+        /// heap out-of-bounds trap is recorded for them.
+        can_trap: bool,
+    },
     /// Address to identify a constant.
     Const(Constant),
 }
@@ -18,15 +29,40 @@ impl Address {
         Self::Offset { base, offset }
     }
 
+    /// Create an indexed offset, in which the index is scaled by `1 << shift`.
+    pub fn indexed(base: Reg, index: Reg, shift: u8, offset: u32) -> Self {
+        assert!(shift <= 3, "invalid index scale shift {shift}");
+        Self::IndexedOffset {
+            base,
+            index,
+            shift,
+            offset,
+            can_trap: false,
+        }
+    }
+
+    /// Create an address into a heap, accesses through which may fault on
+    /// the heap's guard pages instead of being explicitly bounds-checked.
+    pub fn heap(base: Reg, index: Reg) -> Self {
+        Self::IndexedOffset {
+            base,
+            index,
+            shift: 0,
+            offset: 0,
+            can_trap: true,
+        }
+    }
+
     /// Create an address for a constant.
     pub fn constant(data: Constant) -> Self {
         Self::Const(data)
     }
 
-    /// Check if the address is a made made of a base and offset.
+    /// Check if the address is a made made of a base and offset, with or
+    /// without an index.
     pub fn is_offset(&self) -> bool {
         match self {
-            Self::Offset { .. } => true,
+            Self::Offset { .. } | Self::IndexedOffset { .. } => true,
             _ => false,
         }
     }
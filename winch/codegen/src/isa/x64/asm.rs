@@ -1,26 +1,33 @@
 //! Assembler library implementation for x64.
 
 use crate::{
-    isa::reg::Reg,
-    masm::{DivKind, IntCmpKind, OperandSize, RemKind, RoundingMode, ShiftKind},
+    isa::{reg::Reg, CallingConvention},
+    masm::{
+        AtomicRmwKind, DivKind, ExtendKind, FloatCmpKind, IntCmpKind, OperandSize, RemKind,
+        RoundingMode, ShiftKind, Signedness, VectorConvertKind, VectorShape,
+    },
 };
 use cranelift_codegen::{
+    binemit::StackMap,
     entity::EntityRef,
-    ir::{types, ConstantPool, ExternalName, LibCall, Opcode, TrapCode, UserExternalNameRef},
+    ir::{
+        types, ConstantPool, ExternalName, LibCall, MemFlags, Opcode, SourceLoc, TrapCode,
+        UserExternalNameRef,
+    },
     isa::{
+        unwind::UnwindInst,
         x64::{
             args::{
-                self, AluRmiROpcode, Amode, CmpOpcode, DivSignedness, ExtMode, FromWritableReg,
-                Gpr, GprMem, GprMemImm, Imm8Gpr, Imm8Reg, RegMem, RegMemImm,
-                ShiftKind as CraneliftShiftKind, SseOpcode, SyntheticAmode, WritableGpr,
-                WritableXmm, Xmm, XmmMem, XmmMemAligned, CC,
+                self, AluRmiROpcode, Amode, AvxOpcode, CmpOpcode, DivSignedness, ExtMode,
+                FenceKind, FromWritableReg, Gpr, GprMem, GprMemImm, Imm8Gpr, Imm8Reg, RegMem,
+                RegMemImm, ShiftKind as CraneliftShiftKind, SseOpcode, SyntheticAmode, WritableGpr,
+                WritableXmm, Xmm, XmmMem, XmmMemAligned, XmmMemAlignedImm, XmmMemImm, CC,
             },
             settings as x64_settings, CallInfo, EmitInfo, EmitState, Inst,
         },
-        CallConv,
     },
-    settings, Final, MachBuffer, MachBufferFinalized, MachInstEmit, MachInstEmitState, MachLabel,
-    VCodeConstantData, VCodeConstants, Writable,
+    settings, Final, MachAtomicRmwOp, MachBuffer, MachBufferFinalized, MachInst, MachInstEmit,
+    MachInstEmitState, MachLabel, PatchRegion, VCodeConstantData, VCodeConstants, Writable,
 };
 
 use super::address::Address;
@@ -87,6 +94,8 @@ impl From<Reg> for Xmm {
 impl From<OperandSize> for args::OperandSize {
     fn from(size: OperandSize) -> Self {
         match size {
+            OperandSize::S8 => Self::Size8,
+            OperandSize::S16 => Self::Size16,
             OperandSize::S32 => Self::Size32,
             OperandSize::S64 => Self::Size64,
             s => panic!("Invalid operand size {:?}", s),
@@ -132,6 +141,19 @@ impl From<ShiftKind> for CraneliftShiftKind {
     }
 }
 
+impl From<AtomicRmwKind> for MachAtomicRmwOp {
+    fn from(value: AtomicRmwKind) -> Self {
+        match value {
+            AtomicRmwKind::Add => MachAtomicRmwOp::Add,
+            AtomicRmwKind::Sub => MachAtomicRmwOp::Sub,
+            AtomicRmwKind::And => MachAtomicRmwOp::And,
+            AtomicRmwKind::Or => MachAtomicRmwOp::Or,
+            AtomicRmwKind::Xor => MachAtomicRmwOp::Xor,
+            AtomicRmwKind::Xchg => MachAtomicRmwOp::Xchg,
+        }
+    }
+}
+
 /// Low level assembler implementation for x64.
 pub(crate) struct Assembler {
     /// The machine instruction buffer.
@@ -146,11 +168,20 @@ pub(crate) struct Assembler {
     pool: ConstantPool,
     /// Constants that will be emitted separately by the MachBuffer.
     constants: VCodeConstants,
+    /// Dummy padding inserted before each bound label, see
+    /// [`Assembler::bind_label`].
+    bb_padding: Vec<u8>,
+    /// The total amount of padding inserted so far.
+    total_bb_padding: usize,
 }
 
 impl Assembler {
     /// Create a new x64 assembler.
     pub fn new(shared_flags: settings::Flags, isa_flags: x64_settings::Flags) -> Self {
+        let bb_padding = match shared_flags.bb_padding_log2_minus_one() {
+            0 => Vec::new(),
+            n => vec![0; 1 << (n - 1)],
+        };
         Self {
             buffer: MachBuffer::<Inst>::new(),
             emit_state: Default::default(),
@@ -158,9 +189,16 @@ impl Assembler {
             constants: Default::default(),
             pool: ConstantPool::new(),
             isa_flags,
+            bb_padding,
+            total_bb_padding: 0,
         }
     }
 
+    /// Get a reference to the underlying machine buffer.
+    pub fn buffer(&self) -> &MachBuffer<Inst> {
+        &self.buffer
+    }
+
     /// Get a mutable reference to underlying
     /// machine buffer.
     pub fn buffer_mut(&mut self) -> &mut MachBuffer<Inst> {
@@ -168,23 +206,85 @@ impl Assembler {
     }
 
     /// Adds a constant to the constant pool and returns its address.
+    ///
+    /// Identical constants share a single pool entry: [`ConstantPool::insert`]
+    /// returns the existing handle for data it has already seen, so repeated
+    /// literals are emitted once and every use refers to the same address.
     pub fn add_constant(&mut self, constant: &[u8]) -> Address {
         let handle = self.pool.insert(constant.into());
         Address::constant(handle)
     }
 
+    /// The total size, in bytes, of the constants referenced by the emitted
+    /// code.
+    pub fn constant_pool_size(&self) -> u32 {
+        self.constants
+            .iter()
+            .map(|(_, data)| data.as_slice().len() as u32)
+            .sum()
+    }
+
     /// Return the emitted code.
-    pub fn finalize(mut self) -> MachBufferFinalized<Final> {
+    pub fn finalize(mut self, loc: Option<SourceLoc>) -> MachBufferFinalized<Final> {
         let stencil = self
             .buffer
             .finish(&self.constants, self.emit_state.ctrl_plane_mut());
-        stencil.apply_base_srcloc(Default::default())
+        stencil.apply_base_srcloc(loc.unwrap_or_default())
+    }
+
+    /// Bind a label to the current offset.
+    ///
+    /// If the `bb_padding_log2_minus_one` setting is enabled, dummy padding
+    /// (jumped over) is inserted first; this stresses the `MachBuffer`'s
+    /// island and veneer handling without requiring large functions.
+    pub fn bind_label(&mut self, label: MachLabel) {
+        if !self.bb_padding.is_empty() {
+            let continuation = self.buffer.get_label();
+            self.emit(Inst::JmpKnown { dst: continuation });
+            self.emit_island_if_needed(self.bb_padding.len() as u32);
+            self.buffer.put_data(&self.bb_padding);
+            self.buffer
+                .bind_label(continuation, self.emit_state.ctrl_plane_mut());
+            // Cap the total padding, as Cranelift does, to keep the memory
+            // footprint of fuzzed functions reasonable.
+            self.total_bb_padding += self.bb_padding.len();
+            if self.total_bb_padding > (150 << 20) {
+                self.bb_padding = Vec::new();
+            }
+        }
+        self.buffer
+            .bind_label(label, self.emit_state.ctrl_plane_mut());
     }
 
     fn emit(&mut self, inst: Inst) {
+        self.emit_island_if_needed(Inst::worst_case_size());
         inst.emit(&[], &mut self.buffer, &self.emit_info, &mut self.emit_state);
     }
 
+    /// Emit an island with the pending constants, traps and veneers if any
+    /// of the pending label references would go out of range after emitting
+    /// `distance` more bytes.
+    ///
+    /// Unlike Cranelift, which only emits islands between blocks, Winch emits
+    /// straight-line code, so the island is preceded by a jump over it.
+    fn emit_island_if_needed(&mut self, distance: u32) {
+        // Account for the jump over the island.
+        let distance = distance + Inst::worst_case_size();
+        if self.buffer.island_needed(distance) {
+            let continuation = self.buffer.get_label();
+            Inst::JmpKnown { dst: continuation }.emit(
+                &[],
+                &mut self.buffer,
+                &self.emit_info,
+                &mut self.emit_state,
+            );
+            self.buffer
+                .emit_island(distance, self.emit_state.ctrl_plane_mut());
+            self.buffer
+                .bind_label(continuation, self.emit_state.ctrl_plane_mut());
+        }
+    }
+
     fn to_synthetic_amode(
         addr: &Address,
         pool: &mut ConstantPool,
@@ -195,6 +295,23 @@ impl Assembler {
             Address::Offset { base, offset } => {
                 SyntheticAmode::real(Amode::imm_reg(*offset as i32, (*base).into()))
             }
+            Address::IndexedOffset {
+                base,
+                index,
+                shift,
+                offset,
+                can_trap,
+            } => SyntheticAmode::real(Amode::ImmRegRegShift {
+                simm32: *offset as i32,
+                base: (*base).into(),
+                index: (*index).into(),
+                shift: *shift,
+                flags: if *can_trap {
+                    MemFlags::new()
+                } else {
+                    MemFlags::trusted()
+                },
+            }),
             Address::Const(c) => {
                 // Defer the creation of the
                 // `SyntheticAmode::ConstantOffset` addressing mode
@@ -227,6 +344,11 @@ impl Assembler {
         self.emit(Inst::Pop64 { dst });
     }
 
+    /// Record an unwind pseudo-instruction at the current offset.
+    pub fn unwind_inst(&mut self, inst: UnwindInst) {
+        self.emit(Inst::Unwind { inst });
+    }
+
     /// Return instruction.
     pub fn ret(&mut self) {
         self.emit(Inst::Ret {
@@ -281,24 +403,68 @@ impl Assembler {
 
     /// Memory-to-register load.
     pub fn mov_mr(&mut self, addr: &Address, dst: Reg, size: OperandSize) {
-        use OperandSize::S64;
+        use OperandSize::*;
+
+        match size {
+            S64 => {
+                let src = Self::to_synthetic_amode(
+                    addr,
+                    &mut self.pool,
+                    &mut self.constants,
+                    &mut self.buffer,
+                );
+                self.emit(Inst::Mov64MR {
+                    src,
+                    dst: dst.into(),
+                });
+            }
+            S32 => self.movzx_mr(addr, dst, ExtMode::LQ),
+            _ => unreachable!(),
+        }
+    }
 
+    /// Memory-to-register zero-extending load, `ext` describes the width of
+    /// the source and destination.
+    pub fn movzx_mr(&mut self, addr: &Address, dst: Reg, ext: ExtMode) {
         let src =
             Self::to_synthetic_amode(addr, &mut self.pool, &mut self.constants, &mut self.buffer);
+        self.emit(Inst::MovzxRmR {
+            ext_mode: ext,
+            src: GprMem::new(RegMem::mem(src)).expect("valid memory address"),
+            dst: dst.into(),
+        });
+    }
 
-        if size == S64 {
-            self.emit(Inst::Mov64MR {
-                src,
-                dst: dst.into(),
-            });
-        } else {
-            let reg_mem = RegMem::mem(src);
-            self.emit(Inst::MovzxRmR {
-                ext_mode: ExtMode::LQ,
-                src: GprMem::new(reg_mem).expect("valid memory address"),
-                dst: dst.into(),
-            });
-        }
+    /// Memory-to-register sign-extending load, `ext` describes the width of
+    /// the source and destination.
+    pub fn movsx_mr(&mut self, addr: &Address, dst: Reg, ext: ExtMode) {
+        let src =
+            Self::to_synthetic_amode(addr, &mut self.pool, &mut self.constants, &mut self.buffer);
+        self.emit(Inst::MovsxRmR {
+            ext_mode: ext,
+            src: GprMem::new(RegMem::mem(src)).expect("valid memory address"),
+            dst: dst.into(),
+        });
+    }
+
+    /// Register-to-register sign-extending move, `ext` describes the width
+    /// of the source and destination.
+    pub fn movsx_rr(&mut self, src: Reg, dst: Reg, ext: ExtMode) {
+        self.emit(Inst::MovsxRmR {
+            ext_mode: ext,
+            src: src.into(),
+            dst: dst.into(),
+        });
+    }
+
+    /// Register-to-register zero-extending move, `ext` describes the width of
+    /// the source and destination.
+    pub fn movzx_rr(&mut self, src: Reg, dst: Reg, ext: ExtMode) {
+        self.emit(Inst::MovzxRmR {
+            ext_mode: ext,
+            src: src.into(),
+            dst: dst.into(),
+        });
     }
 
     /// Integer register conditional move.
@@ -321,6 +487,7 @@ impl Assembler {
             S32 => SseOpcode::Movaps,
             S64 => SseOpcode::Movapd,
             S128 => SseOpcode::Movdqa,
+            _ => unreachable!(),
         };
 
         self.emit(Inst::XmmUnaryRmRUnaligned {
@@ -335,19 +502,29 @@ impl Assembler {
         use OperandSize::*;
 
         assert!(dst.is_float());
-        let op = match size {
-            S32 => SseOpcode::Movss,
-            S64 => SseOpcode::Movsd,
-            S128 => SseOpcode::Movdqu,
+        let (op, avx_op) = match size {
+            S32 => (SseOpcode::Movss, AvxOpcode::Vmovss),
+            S64 => (SseOpcode::Movsd, AvxOpcode::Vmovsd),
+            S128 => (SseOpcode::Movdqu, AvxOpcode::Vmovdqu),
+            _ => unreachable!(),
         };
 
         let src =
             Self::to_synthetic_amode(src, &mut self.pool, &mut self.constants, &mut self.buffer);
-        self.emit(Inst::XmmUnaryRmRUnaligned {
-            op,
-            src: XmmMem::new(RegMem::mem(src)).expect("valid xmm unaligned"),
-            dst: dst.into(),
-        });
+        let src = XmmMem::new(RegMem::mem(src)).expect("valid xmm unaligned");
+        if self.isa_flags.has_avx() {
+            self.emit(Inst::XmmUnaryRmRVex {
+                op: avx_op,
+                src,
+                dst: dst.into(),
+            });
+        } else {
+            self.emit(Inst::XmmUnaryRmRUnaligned {
+                op,
+                src,
+                dst: dst.into(),
+            });
+        }
     }
 
     /// Single and double precision floating point store.
@@ -356,19 +533,28 @@ impl Assembler {
 
         assert!(src.is_float());
 
-        let op = match size {
-            S32 => SseOpcode::Movss,
-            S64 => SseOpcode::Movsd,
-            S128 => SseOpcode::Movdqu,
+        let (op, avx_op) = match size {
+            S32 => (SseOpcode::Movss, AvxOpcode::Vmovss),
+            S64 => (SseOpcode::Movsd, AvxOpcode::Vmovsd),
+            S128 => (SseOpcode::Movdqu, AvxOpcode::Vmovdqu),
+            _ => unreachable!(),
         };
 
         let dst =
             Self::to_synthetic_amode(dst, &mut self.pool, &mut self.constants, &mut self.buffer);
-        self.emit(Inst::XmmMovRM {
-            op,
-            src: src.into(),
-            dst,
-        });
+        if self.isa_flags.has_avx() {
+            self.emit(Inst::XmmMovRMVex {
+                op: avx_op,
+                src: src.into(),
+                dst,
+            });
+        } else {
+            self.emit(Inst::XmmMovRM {
+                op,
+                src: src.into(),
+                dst,
+            });
+        }
     }
 
     /// Floating point register conditional move.
@@ -378,6 +564,7 @@ impl Assembler {
             OperandSize::S64 => types::F64,
             // Move the entire 128 bits via movdqa.
             OperandSize::S128 => types::I128,
+            _ => unreachable!(),
         };
 
         self.emit(Inst::XmmCmove {
@@ -437,41 +624,36 @@ impl Assembler {
 
     /// "and" two float registers.
     pub fn xmm_and_rr(&mut self, src: Reg, dst: Reg, size: OperandSize) {
-        let op = match size {
-            OperandSize::S32 => SseOpcode::Andps,
-            OperandSize::S64 => SseOpcode::Andpd,
-            OperandSize::S128 => unreachable!(),
+        let (op, avx_op) = match size {
+            OperandSize::S32 => (SseOpcode::Andps, AvxOpcode::Vandps),
+            OperandSize::S64 => (SseOpcode::Andpd, AvxOpcode::Vandpd),
+            _ => unreachable!(),
         };
-
-        self.emit(Inst::XmmRmR {
-            op,
-            src1: dst.into(),
-            src2: XmmMemAligned::from(Xmm::from(src)),
-            dst: dst.into(),
-        });
+        self.xmm_rmr(op, avx_op, dst, src, dst);
     }
 
-    /// "and not" two float registers.
+    /// "and not" two float registers, complementing dst.
     pub fn xmm_andn_rr(&mut self, src: Reg, dst: Reg, size: OperandSize) {
-        let op = match size {
-            OperandSize::S32 => SseOpcode::Andnps,
-            OperandSize::S64 => SseOpcode::Andnpd,
-            OperandSize::S128 => unreachable!(),
-        };
+        self.xmm_andn_rrr(dst, src, dst, size);
+    }
 
-        self.emit(Inst::XmmRmR {
-            op,
-            src1: dst.into(),
-            src2: Xmm::from(src).into(),
-            dst: dst.into(),
-        });
+    /// "and" of src2 with the complement of src1, placing the result in dst.
+    ///
+    /// Without AVX, dst must be src1.
+    pub fn xmm_andn_rrr(&mut self, src1: Reg, src2: Reg, dst: Reg, size: OperandSize) {
+        let (op, avx_op) = match size {
+            OperandSize::S32 => (SseOpcode::Andnps, AvxOpcode::Vandnps),
+            OperandSize::S64 => (SseOpcode::Andnpd, AvxOpcode::Vandnpd),
+            _ => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, src1, src2, dst);
     }
 
     pub fn gpr_to_xmm(&mut self, src: Reg, dst: Reg, size: OperandSize) {
         let op = match size {
             OperandSize::S32 => SseOpcode::Movd,
             OperandSize::S64 => SseOpcode::Movq,
-            OperandSize::S128 => unreachable!(),
+            _ => unreachable!(),
         };
 
         self.emit(Inst::GprToXmm {
@@ -505,18 +687,12 @@ impl Assembler {
     }
 
     pub fn xmm_or_rr(&mut self, src: Reg, dst: Reg, size: OperandSize) {
-        let op = match size {
-            OperandSize::S32 => SseOpcode::Orps,
-            OperandSize::S64 => SseOpcode::Orpd,
-            OperandSize::S128 => unreachable!(),
+        let (op, avx_op) = match size {
+            OperandSize::S32 => (SseOpcode::Orps, AvxOpcode::Vorps),
+            OperandSize::S64 => (SseOpcode::Orpd, AvxOpcode::Vorpd),
+            _ => unreachable!(),
         };
-
-        self.emit(Inst::XmmRmR {
-            op,
-            src1: dst.into(),
-            src2: XmmMemAligned::from(Xmm::from(src)),
-            dst: dst.into(),
-        });
+        self.xmm_rmr(op, avx_op, dst, src, dst);
     }
 
     /// Logical exclusive or with registers.
@@ -544,18 +720,69 @@ impl Assembler {
 
     /// Logical exclusive or with float registers.
     pub fn xmm_xor_rr(&mut self, src: Reg, dst: Reg, size: OperandSize) {
-        let op = match size {
-            OperandSize::S32 => SseOpcode::Xorps,
-            OperandSize::S64 => SseOpcode::Xorpd,
-            OperandSize::S128 => unreachable!(),
+        let (op, avx_op) = match size {
+            OperandSize::S32 => (SseOpcode::Xorps, AvxOpcode::Vxorps),
+            OperandSize::S64 => (SseOpcode::Xorpd, AvxOpcode::Vxorpd),
+            _ => unreachable!(),
         };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
 
-        self.emit(Inst::XmmRmR {
-            op,
-            src1: dst.into(),
-            src2: XmmMemAligned::from(Xmm::from(src)),
-            dst: dst.into(),
-        });
+    /// Clears the sign bit of a float register.
+    ///
+    /// The mask is loaded from the constant pool into `scratch`.
+    pub fn xmm_abs(&mut self, dst: Reg, scratch: Reg, size: OperandSize) {
+        self.load_float_mask(!Self::sign_bit(size), scratch, size);
+        self.xmm_and_rr(scratch, dst, size);
+    }
+
+    /// Flips the sign bit of a float register.
+    ///
+    /// The mask is loaded from the constant pool into `scratch`.
+    pub fn xmm_neg(&mut self, dst: Reg, scratch: Reg, size: OperandSize) {
+        self.load_float_mask(Self::sign_bit(size), scratch, size);
+        self.xmm_xor_rr(scratch, dst, size);
+    }
+
+    /// Copies the sign bit of `src` into the float register `dst`.
+    ///
+    /// The mask is loaded from the constant pool into `scratch`, and `src` is
+    /// clobbered.
+    pub fn xmm_copysign(&mut self, src: Reg, dst: Reg, scratch: Reg, size: OperandSize) {
+        self.load_float_mask(Self::sign_bit(size), scratch, size);
+
+        // Clear everything except the sign bit in src.
+        self.xmm_and_rr(scratch, src, size);
+
+        // Clear the sign bit in dst. Without AVX `andn` complements its
+        // destination, so the result is computed in scratch and copied back
+        // to dst.
+        if self.isa_flags.has_avx() {
+            self.xmm_andn_rrr(scratch, dst, dst, size);
+        } else {
+            self.xmm_andn_rr(dst, scratch, size);
+            self.xmm_mov_rr(scratch, dst, size);
+        }
+
+        // Copy the sign bit from src to dst.
+        self.xmm_or_rr(src, dst, size);
+    }
+
+    /// Returns the sign bit of a float of the given size.
+    fn sign_bit(size: OperandSize) -> u64 {
+        match size {
+            OperandSize::S32 => 0x8000_0000,
+            OperandSize::S64 => 0x8000_0000_0000_0000,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Loads the low `size` bytes of `mask` from the constant pool into a
+    /// float register.
+    fn load_float_mask(&mut self, mask: u64, dst: Reg, size: OperandSize) {
+        let mask = mask.to_le_bytes();
+        let addr = self.add_constant(&mask[..size.bytes() as usize]);
+        self.xmm_mov_mr(&addr, dst, size);
     }
 
     /// Shift with register and register.
@@ -731,6 +958,26 @@ impl Assembler {
         });
     }
 
+    /// Add a 32-bit immediate, to be filled in later with
+    /// [`Assembler::patch_imm32`], to a register.
+    ///
+    /// Returns the region of the buffer holding the instruction.
+    pub fn add_ir_patchable(&mut self, dst: Reg, size: OperandSize) -> PatchRegion {
+        let open = self.buffer.start_patchable();
+        // A placeholder which doesn't fit in 8 bits forces the encoding with
+        // a 32-bit immediate, which is the last part of the instruction.
+        self.add_ir(i32::MAX, dst, size);
+        self.buffer.end_patchable(open)
+    }
+
+    /// Overwrite the immediate of the instruction emitted by
+    /// [`Assembler::add_ir_patchable`].
+    pub fn patch_imm32(&mut self, region: PatchRegion, imm: i32) {
+        let bytes = region.patch(&mut self.buffer);
+        let len = bytes.len();
+        bytes[len - 4..].copy_from_slice(&imm.to_le_bytes());
+    }
+
     /// Add register and register.
     pub fn add_rr(&mut self, src: Reg, dst: Reg, size: OperandSize) {
         self.emit(Inst::AluRmiR {
@@ -742,6 +989,46 @@ impl Assembler {
         });
     }
 
+    /// Add memory and register.
+    pub fn add_mr(&mut self, addr: &Address, dst: Reg, size: OperandSize) {
+        self.alu_mr(AluRmiROpcode::Add, addr, dst, size);
+    }
+
+    /// Subtract memory from register.
+    pub fn sub_mr(&mut self, addr: &Address, dst: Reg, size: OperandSize) {
+        self.alu_mr(AluRmiROpcode::Sub, addr, dst, size);
+    }
+
+    /// And memory and register.
+    pub fn and_mr(&mut self, addr: &Address, dst: Reg, size: OperandSize) {
+        self.alu_mr(AluRmiROpcode::And, addr, dst, size);
+    }
+
+    /// Or memory and register.
+    pub fn or_mr(&mut self, addr: &Address, dst: Reg, size: OperandSize) {
+        self.alu_mr(AluRmiROpcode::Or, addr, dst, size);
+    }
+
+    /// Xor memory and register.
+    pub fn xor_mr(&mut self, addr: &Address, dst: Reg, size: OperandSize) {
+        self.alu_mr(AluRmiROpcode::Xor, addr, dst, size);
+    }
+
+    /// Emit an ALU operation with a memory source operand, `dst = dst op
+    /// [addr]`.
+    fn alu_mr(&mut self, op: AluRmiROpcode, addr: &Address, dst: Reg, size: OperandSize) {
+        assert!(addr.is_offset());
+        let src =
+            Self::to_synthetic_amode(addr, &mut self.pool, &mut self.constants, &mut self.buffer);
+        self.emit(Inst::AluRmiR {
+            size: size.into(),
+            op,
+            src1: dst.into(),
+            src2: GprMemImm::new(RegMemImm::mem(src)).expect("valid memory address"),
+            dst: dst.into(),
+        });
+    }
+
     pub fn cmp_ir(&mut self, imm: i32, dst: Reg, size: OperandSize) {
         let imm = RegMemImm::imm(imm as u32);
 
@@ -768,7 +1055,7 @@ impl Assembler {
         let op = match size {
             OperandSize::S32 => SseOpcode::Ucomiss,
             OperandSize::S64 => SseOpcode::Ucomisd,
-            OperandSize::S128 => unreachable!(),
+            _ => unreachable!(),
         };
 
         self.emit(Inst::XmmCmpRmR {
@@ -891,66 +1178,42 @@ impl Assembler {
 
     /// Performs float addition on src and dst and places result in dst.
     pub fn xmm_add_rr(&mut self, src: Reg, dst: Reg, size: OperandSize) {
-        let op = match size {
-            OperandSize::S32 => SseOpcode::Addss,
-            OperandSize::S64 => SseOpcode::Addsd,
-            OperandSize::S128 => unreachable!(),
+        let (op, avx_op) = match size {
+            OperandSize::S32 => (SseOpcode::Addss, AvxOpcode::Vaddss),
+            OperandSize::S64 => (SseOpcode::Addsd, AvxOpcode::Vaddsd),
+            _ => unreachable!(),
         };
-
-        self.emit(Inst::XmmRmRUnaligned {
-            op,
-            src1: Xmm::from(dst).into(),
-            src2: Xmm::from(src).into(),
-            dst: dst.into(),
-        });
+        self.xmm_rmr(op, avx_op, dst, src, dst);
     }
 
     /// Performs float subtraction on src and dst and places result in dst.
     pub fn xmm_sub_rr(&mut self, src: Reg, dst: Reg, size: OperandSize) {
-        let op = match size {
-            OperandSize::S32 => SseOpcode::Subss,
-            OperandSize::S64 => SseOpcode::Subsd,
-            OperandSize::S128 => unreachable!(),
+        let (op, avx_op) = match size {
+            OperandSize::S32 => (SseOpcode::Subss, AvxOpcode::Vsubss),
+            OperandSize::S64 => (SseOpcode::Subsd, AvxOpcode::Vsubsd),
+            _ => unreachable!(),
         };
-
-        self.emit(Inst::XmmRmRUnaligned {
-            op,
-            src1: Xmm::from(dst).into(),
-            src2: Xmm::from(src).into(),
-            dst: dst.into(),
-        });
+        self.xmm_rmr(op, avx_op, dst, src, dst);
     }
 
     /// Performs float multiplication on src and dst and places result in dst.
     pub fn xmm_mul_rr(&mut self, src: Reg, dst: Reg, size: OperandSize) {
-        let op = match size {
-            OperandSize::S32 => SseOpcode::Mulss,
-            OperandSize::S64 => SseOpcode::Mulsd,
-            OperandSize::S128 => unreachable!(),
+        let (op, avx_op) = match size {
+            OperandSize::S32 => (SseOpcode::Mulss, AvxOpcode::Vmulss),
+            OperandSize::S64 => (SseOpcode::Mulsd, AvxOpcode::Vmulsd),
+            _ => unreachable!(),
         };
-
-        self.emit(Inst::XmmRmRUnaligned {
-            op,
-            src1: Xmm::from(dst).into(),
-            src2: Xmm::from(src).into(),
-            dst: dst.into(),
-        });
+        self.xmm_rmr(op, avx_op, dst, src, dst);
     }
 
     /// Performs float division on src and dst and places result in dst.
     pub fn xmm_div_rr(&mut self, src: Reg, dst: Reg, size: OperandSize) {
-        let op = match size {
-            OperandSize::S32 => SseOpcode::Divss,
-            OperandSize::S64 => SseOpcode::Divsd,
-            OperandSize::S128 => unreachable!(),
+        let (op, avx_op) = match size {
+            OperandSize::S32 => (SseOpcode::Divss, AvxOpcode::Vdivss),
+            OperandSize::S64 => (SseOpcode::Divsd, AvxOpcode::Vdivsd),
+            _ => unreachable!(),
         };
-
-        self.emit(Inst::XmmRmRUnaligned {
-            op,
-            src1: Xmm::from(dst).into(),
-            src2: Xmm::from(src).into(),
-            dst: dst.into(),
-        });
+        self.xmm_rmr(op, avx_op, dst, src, dst);
     }
 
     /// Mininum for src and dst XMM registers with results put in dst.
@@ -978,10 +1241,10 @@ impl Assembler {
     /// Perform rounding operation on float register src and place results in
     /// float register dst.
     pub fn xmm_rounds_rr(&mut self, src: Reg, dst: Reg, mode: RoundingMode, size: OperandSize) {
-        let op = match size {
-            OperandSize::S32 => SseOpcode::Roundss,
-            OperandSize::S64 => SseOpcode::Roundsd,
-            OperandSize::S128 => unreachable!(),
+        let (op, avx_op) = match size {
+            OperandSize::S32 => (SseOpcode::Roundss, AvxOpcode::Vroundss),
+            OperandSize::S64 => (SseOpcode::Roundsd, AvxOpcode::Vroundsd),
+            _ => unreachable!(),
         };
 
         let imm: u8 = match mode {
@@ -991,30 +1254,795 @@ impl Assembler {
             RoundingMode::Zero => 0x03,
         };
 
-        self.emit(Inst::XmmUnaryRmRImm {
-            op,
-            src: XmmMemAligned::from(Xmm::from(src)),
-            imm,
-            dst: dst.into(),
-        })
+        self.xmm_unary_rr_imm(op, avx_op, src, dst, imm);
     }
 
     pub fn sqrt(&mut self, src: Reg, dst: Reg, size: OperandSize) {
-        let op = match size {
-            OperandSize::S32 => SseOpcode::Sqrtss,
-            OperandSize::S64 => SseOpcode::Sqrtsd,
+        let (op, avx_op) = match size {
+            OperandSize::S32 => (SseOpcode::Sqrtss, AvxOpcode::Vsqrtss),
+            OperandSize::S64 => (SseOpcode::Sqrtsd, AvxOpcode::Vsqrtsd),
+            _ => unreachable!(),
+        };
+        self.xmm_unary_rr(op, avx_op, src, dst);
+    }
+
+    /// Register-to-register operation computing `src1 op src2` and placing
+    /// the result in dst.
+    ///
+    /// When AVX is available the non-destructive VEX-encoded form is used,
+    /// so dst may be any register; otherwise dst must be src1.
+    fn xmm_rmr(&mut self, op: SseOpcode, avx_op: AvxOpcode, src1: Reg, src2: Reg, dst: Reg) {
+        if self.isa_flags.has_avx() {
+            self.emit(Inst::XmmRmiRVex {
+                op: avx_op,
+                src1: src1.into(),
+                src2: XmmMemImm::new(src2.into()).expect("valid xmm register"),
+                dst: dst.into(),
+            });
+        } else {
+            Self::ensure_sse_operands(src1, dst);
+            self.emit(Inst::XmmRmR {
+                op,
+                src1: dst.into(),
+                src2: XmmMemAligned::from(Xmm::from(src2)),
+                dst: dst.into(),
+            });
+        }
+    }
+
+    /// Unary register-to-register operation on src, placing the result in
+    /// dst, using the VEX encoding when AVX is available.
+    fn xmm_unary_rr(&mut self, op: SseOpcode, avx_op: AvxOpcode, src: Reg, dst: Reg) {
+        if self.isa_flags.has_avx() {
+            self.emit(Inst::XmmUnaryRmRVex {
+                op: avx_op,
+                src: Xmm::from(src).into(),
+                dst: dst.into(),
+            });
+        } else {
+            self.emit(Inst::XmmUnaryRmR {
+                op,
+                src: Xmm::from(src).into(),
+                dst: dst.into(),
+            });
+        }
+    }
+
+    /// Like [`Self::xmm_unary_rr`], for operations taking an immediate.
+    fn xmm_unary_rr_imm(&mut self, op: SseOpcode, avx_op: AvxOpcode, src: Reg, dst: Reg, imm: u8) {
+        if self.isa_flags.has_avx() {
+            self.emit(Inst::XmmUnaryRmRImmVex {
+                op: avx_op,
+                src: Xmm::from(src).into(),
+                dst: dst.into(),
+                imm,
+            });
+        } else {
+            self.emit(Inst::XmmUnaryRmRImm {
+                op,
+                src: XmmMemAligned::from(Xmm::from(src)),
+                imm,
+                dst: dst.into(),
+            });
+        }
+    }
+
+    fn ensure_sse_operands(src1: Reg, dst: Reg) {
+        assert!(
+            src1 == dst,
+            "SSE operations require the destination and first source to be the same, dst={:?}, src1={:?}",
+            dst,
+            src1
+        );
+    }
+
+    /// Performs lane-wise addition on the vectors in src and dst and places
+    /// the result in dst.
+    pub fn xmm_vadd_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::I8x16 => (SseOpcode::Paddb, AvxOpcode::Vpaddb),
+            VectorShape::I16x8 => (SseOpcode::Paddw, AvxOpcode::Vpaddw),
+            VectorShape::I32x4 => (SseOpcode::Paddd, AvxOpcode::Vpaddd),
+            VectorShape::I64x2 => (SseOpcode::Paddq, AvxOpcode::Vpaddq),
+            VectorShape::F32x4 => (SseOpcode::Addps, AvxOpcode::Vaddps),
+            VectorShape::F64x2 => (SseOpcode::Addpd, AvxOpcode::Vaddpd),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Performs lane-wise subtraction on the vectors in src and dst and
+    /// places the result in dst.
+    pub fn xmm_vsub_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::I8x16 => (SseOpcode::Psubb, AvxOpcode::Vpsubb),
+            VectorShape::I16x8 => (SseOpcode::Psubw, AvxOpcode::Vpsubw),
+            VectorShape::I32x4 => (SseOpcode::Psubd, AvxOpcode::Vpsubd),
+            VectorShape::I64x2 => (SseOpcode::Psubq, AvxOpcode::Vpsubq),
+            VectorShape::F32x4 => (SseOpcode::Subps, AvxOpcode::Vsubps),
+            VectorShape::F64x2 => (SseOpcode::Subpd, AvxOpcode::Vsubpd),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Performs lane-wise multiplication on the vectors in src and dst and
+    /// places the result in dst.
+    pub fn xmm_vmul_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::I16x8 => (SseOpcode::Pmullw, AvxOpcode::Vpmullw),
+            VectorShape::I32x4 => (SseOpcode::Pmulld, AvxOpcode::Vpmulld),
+            VectorShape::F32x4 => (SseOpcode::Mulps, AvxOpcode::Vmulps),
+            VectorShape::F64x2 => (SseOpcode::Mulpd, AvxOpcode::Vmulpd),
+            VectorShape::I8x16 | VectorShape::I64x2 => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Performs lane-wise float division on the vectors in src and dst and
+    /// places the result in dst.
+    pub fn xmm_vdiv_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::F32x4 => (SseOpcode::Divps, AvxOpcode::Vdivps),
+            VectorShape::F64x2 => (SseOpcode::Divpd, AvxOpcode::Vdivpd),
+            _ => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Bitwise "and" of the vectors in src and dst.
+    pub fn xmm_vand_rr(&mut self, src: Reg, dst: Reg) {
+        self.xmm_rmr(SseOpcode::Pand, AvxOpcode::Vpand, dst, src, dst);
+    }
+
+    /// Bitwise "and" of src with the complement of dst.
+    pub fn xmm_vandn_rr(&mut self, src: Reg, dst: Reg) {
+        self.xmm_vandn_rrr(dst, src, dst);
+    }
+
+    /// Bitwise "and" of src2 with the complement of src1, placing the result
+    /// in dst.
+    ///
+    /// Without AVX, dst must be src1.
+    pub fn xmm_vandn_rrr(&mut self, src1: Reg, src2: Reg, dst: Reg) {
+        self.xmm_rmr(SseOpcode::Pandn, AvxOpcode::Vpandn, src1, src2, dst);
+    }
+
+    /// Bitwise "or" of the vectors in src and dst.
+    pub fn xmm_vor_rr(&mut self, src: Reg, dst: Reg) {
+        self.xmm_rmr(SseOpcode::Por, AvxOpcode::Vpor, dst, src, dst);
+    }
+
+    /// Bitwise exclusive or of the vectors in src and dst.
+    pub fn xmm_vxor_rr(&mut self, src: Reg, dst: Reg) {
+        self.xmm_rmr(SseOpcode::Pxor, AvxOpcode::Vpxor, dst, src, dst);
+    }
+
+    /// Lane-wise integer equality of the vectors in src and dst, setting all
+    /// the bits of the equal lanes in dst.
+    pub fn xmm_vcmpeq_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::I8x16 => (SseOpcode::Pcmpeqb, AvxOpcode::Vpcmpeqb),
+            VectorShape::I16x8 => (SseOpcode::Pcmpeqw, AvxOpcode::Vpcmpeqw),
+            VectorShape::I32x4 => (SseOpcode::Pcmpeqd, AvxOpcode::Vpcmpeqd),
+            VectorShape::I64x2 => (SseOpcode::Pcmpeqq, AvxOpcode::Vpcmpeqq),
+            VectorShape::F32x4 | VectorShape::F64x2 => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Lane-wise signed integer greater than comparison of dst and src,
+    /// setting all the bits of the lanes of dst that are greater than the
+    /// corresponding lane of src.
+    pub fn xmm_vcmpgt_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        self.xmm_vcmpgt_rrr(dst, src, dst, shape);
+    }
+
+    /// Lane-wise signed integer greater than comparison of src1 and src2,
+    /// setting all the bits of the lanes of dst for which src1 is greater.
+    ///
+    /// Without AVX, dst must be src1.
+    pub fn xmm_vcmpgt_rrr(&mut self, src1: Reg, src2: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::I8x16 => (SseOpcode::Pcmpgtb, AvxOpcode::Vpcmpgtb),
+            VectorShape::I16x8 => (SseOpcode::Pcmpgtw, AvxOpcode::Vpcmpgtw),
+            VectorShape::I32x4 => (SseOpcode::Pcmpgtd, AvxOpcode::Vpcmpgtd),
+            VectorShape::I64x2 => (SseOpcode::Pcmpgtq, AvxOpcode::Vpcmpgtq),
+            VectorShape::F32x4 | VectorShape::F64x2 => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, src1, src2, dst);
+    }
+
+    /// Lane-wise float comparison of dst and src, setting all the bits of
+    /// the lanes of dst for which the comparison holds.
+    ///
+    /// Only the predicates with a direct encoding are supported; greater
+    /// than comparisons are expected to be lowered by swapping the
+    /// operands.
+    pub fn xmm_vcmpp_rr(&mut self, src: Reg, dst: Reg, kind: FloatCmpKind, shape: VectorShape) {
+        self.xmm_vcmpp_rrr(dst, src, dst, kind, shape);
+    }
+
+    /// Lane-wise float comparison of src1 and src2, setting all the bits of
+    /// the lanes of dst for which the comparison holds.
+    ///
+    /// Without AVX, dst must be src1.
+    pub fn xmm_vcmpp_rrr(
+        &mut self,
+        src1: Reg,
+        src2: Reg,
+        dst: Reg,
+        kind: FloatCmpKind,
+        shape: VectorShape,
+    ) {
+        let imm = match kind {
+            FloatCmpKind::Eq => 0x00,
+            FloatCmpKind::Lt => 0x01,
+            FloatCmpKind::Le => 0x02,
+            // Unordered or not equal.
+            FloatCmpKind::Ne => 0x04,
+            FloatCmpKind::Gt | FloatCmpKind::Ge => unreachable!(),
+        };
+        self.xmm_cmpp(src1, src2, dst, imm, shape);
+    }
+
+    fn xmm_cmpp(&mut self, src1: Reg, src2: Reg, dst: Reg, imm: u8, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::F32x4 => (SseOpcode::Cmpps, AvxOpcode::Vcmpps),
+            VectorShape::F64x2 => (SseOpcode::Cmppd, AvxOpcode::Vcmppd),
+            _ => unreachable!(),
+        };
+
+        if self.isa_flags.has_avx() {
+            self.emit(Inst::XmmRmRImmVex {
+                op: avx_op,
+                src1: src1.into(),
+                src2: Xmm::from(src2).into(),
+                dst: dst.into(),
+                imm,
+            });
+        } else {
+            Self::ensure_sse_operands(src1, dst);
+            self.emit(Inst::XmmRmRImm {
+                op,
+                src1: dst.into(),
+                src2: src2.into(),
+                dst: Writable::from_reg(dst.into()),
+                imm,
+                size: args::OperandSize::Size32,
+            });
+        }
+    }
+
+    /// Converts the lanes of the vector in src and places the result in dst.
+    pub fn xmm_vcvt_rr(&mut self, src: Reg, dst: Reg, kind: VectorConvertKind) {
+        let (op, avx_op) = match kind {
+            VectorConvertKind::F32x4ConvertI32x4S => (SseOpcode::Cvtdq2ps, AvxOpcode::Vcvtdq2ps),
+            VectorConvertKind::F64x2ConvertLowI32x4S => (SseOpcode::Cvtdq2pd, AvxOpcode::Vcvtdq2pd),
+            VectorConvertKind::F32x4DemoteF64x2Zero => (SseOpcode::Cvtpd2ps, AvxOpcode::Vcvtpd2ps),
+            VectorConvertKind::F64x2PromoteLowF32x4 => (SseOpcode::Cvtps2pd, AvxOpcode::Vcvtps2pd),
+            // These have no single instruction equivalent and are lowered as
+            // sequences by the macro assembler.
+            VectorConvertKind::F32x4ConvertI32x4U
+            | VectorConvertKind::F64x2ConvertLowI32x4U
+            | VectorConvertKind::I32x4TruncSatF32x4S
+            | VectorConvertKind::I32x4TruncSatF32x4U
+            | VectorConvertKind::I32x4TruncSatF64x2SZero
+            | VectorConvertKind::I32x4TruncSatF64x2UZero => unreachable!(),
+        };
+        self.xmm_unary_rr(op, avx_op, src, dst);
+    }
+
+    /// Converts a scalar float to a signed integer with `cvttss2si` or
+    /// `cvttsd2si`, as part of a sequence that either traps on NaN and
+    /// out-of-range values or saturates them.
+    pub fn cvt_float_to_sint_seq(
+        &mut self,
+        src: Reg,
+        dst: Reg,
+        tmp_gpr: Reg,
+        tmp_xmm: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        saturating: bool,
+    ) {
+        self.emit(Inst::CvtFloatToSintSeq {
+            dst_size: dst_size.into(),
+            src_size: src_size.into(),
+            is_saturating: saturating,
+            src: src.into(),
+            dst: dst.into(),
+            tmp_gpr: tmp_gpr.into(),
+            tmp_xmm: tmp_xmm.into(),
+        });
+    }
+
+    /// Converts a scalar float to an unsigned integer, as part of a sequence
+    /// that either traps on NaN and out-of-range values or saturates them.
+    pub fn cvt_float_to_uint_seq(
+        &mut self,
+        src: Reg,
+        dst: Reg,
+        tmp_gpr: Reg,
+        tmp_xmm: Reg,
+        tmp_xmm2: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        saturating: bool,
+    ) {
+        self.emit(Inst::CvtFloatToUintSeq {
+            dst_size: dst_size.into(),
+            src_size: src_size.into(),
+            is_saturating: saturating,
+            src: src.into(),
+            dst: dst.into(),
+            tmp_gpr: tmp_gpr.into(),
+            tmp_xmm: tmp_xmm.into(),
+            tmp_xmm2: tmp_xmm2.into(),
+        });
+    }
+
+    /// Shuffles the 32-bit lanes of src according to the given immediate and
+    /// places the result in dst.
+    pub fn xmm_pshufd_rr(&mut self, src: Reg, dst: Reg, imm: u8) {
+        self.xmm_unary_rr_imm(SseOpcode::Pshufd, AvxOpcode::Vpshufd, src, dst, imm);
+    }
+
+    /// Shuffles the low four 16-bit lanes of src according to the given
+    /// immediate and places the result in dst.
+    pub fn xmm_pshuflw_rr(&mut self, src: Reg, dst: Reg, imm: u8) {
+        self.xmm_unary_rr_imm(SseOpcode::Pshuflw, AvxOpcode::Vpshuflw, src, dst, imm);
+    }
+
+    /// Shuffles the bytes of dst according to the mask in src.
+    pub fn xmm_pshufb_rr(&mut self, src: Reg, dst: Reg) {
+        self.xmm_rmr(SseOpcode::Pshufb, AvxOpcode::Vpshufb, dst, src, dst);
+    }
+
+    /// Shuffles the bytes of dst according to the mask at the given address.
+    pub fn xmm_pshufb_mr(&mut self, src: &Address, dst: Reg) {
+        let src =
+            Self::to_synthetic_amode(src, &mut self.pool, &mut self.constants, &mut self.buffer);
+        if self.isa_flags.has_avx() {
+            self.emit(Inst::XmmRmiRVex {
+                op: AvxOpcode::Vpshufb,
+                src1: dst.into(),
+                src2: XmmMemImm::new(RegMemImm::mem(src)).expect("valid xmm memory"),
+                dst: dst.into(),
+            });
+        } else {
+            self.emit(Inst::XmmRmR {
+                op: SseOpcode::Pshufb,
+                src1: dst.into(),
+                src2: XmmMemAligned::new(RegMem::mem(src)).expect("valid xmm aligned"),
+                dst: dst.into(),
+            });
+        }
+    }
+
+    /// Lane-wise integer addition of src and dst, saturating on overflow.
+    pub fn xmm_vadd_sat_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape, sign: Signedness) {
+        let (op, avx_op) = match (shape, sign) {
+            (VectorShape::I8x16, Signedness::Signed) => (SseOpcode::Paddsb, AvxOpcode::Vpaddsb),
+            (VectorShape::I8x16, Signedness::Unsigned) => (SseOpcode::Paddusb, AvxOpcode::Vpaddusb),
+            (VectorShape::I16x8, Signedness::Signed) => (SseOpcode::Paddsw, AvxOpcode::Vpaddsw),
+            (VectorShape::I16x8, Signedness::Unsigned) => (SseOpcode::Paddusw, AvxOpcode::Vpaddusw),
+            _ => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Lane-wise integer subtraction of src from dst, saturating on overflow.
+    pub fn xmm_vsub_sat_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape, sign: Signedness) {
+        let (op, avx_op) = match (shape, sign) {
+            (VectorShape::I8x16, Signedness::Signed) => (SseOpcode::Psubsb, AvxOpcode::Vpsubsb),
+            (VectorShape::I8x16, Signedness::Unsigned) => (SseOpcode::Psubusb, AvxOpcode::Vpsubusb),
+            (VectorShape::I16x8, Signedness::Signed) => (SseOpcode::Psubsw, AvxOpcode::Vpsubsw),
+            (VectorShape::I16x8, Signedness::Unsigned) => (SseOpcode::Psubusw, AvxOpcode::Vpsubusw),
+            _ => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Lane-wise minimum of the integer lanes of src and dst.
+    pub fn xmm_vmin_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape, sign: Signedness) {
+        use Signedness::*;
+        use VectorShape::*;
+
+        let (op, avx_op) = match (shape, sign) {
+            (I8x16, Signed) => (SseOpcode::Pminsb, AvxOpcode::Vpminsb),
+            (I8x16, Unsigned) => (SseOpcode::Pminub, AvxOpcode::Vpminub),
+            (I16x8, Signed) => (SseOpcode::Pminsw, AvxOpcode::Vpminsw),
+            (I16x8, Unsigned) => (SseOpcode::Pminuw, AvxOpcode::Vpminuw),
+            (I32x4, Signed) => (SseOpcode::Pminsd, AvxOpcode::Vpminsd),
+            (I32x4, Unsigned) => (SseOpcode::Pminud, AvxOpcode::Vpminud),
+            _ => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Lane-wise minimum of the float lanes of src and dst, with the x86
+    /// `minps`/`minpd` semantics, which return src when either lane is a NaN
+    /// or both are zero.
+    pub fn xmm_vminp_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::F32x4 => (SseOpcode::Minps, AvxOpcode::Vminps),
+            VectorShape::F64x2 => (SseOpcode::Minpd, AvxOpcode::Vminpd),
+            _ => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Lane-wise maximum of the integer lanes of src and dst.
+    pub fn xmm_vmax_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape, sign: Signedness) {
+        use Signedness::*;
+        use VectorShape::*;
+
+        let (op, avx_op) = match (shape, sign) {
+            (I8x16, Signed) => (SseOpcode::Pmaxsb, AvxOpcode::Vpmaxsb),
+            (I8x16, Unsigned) => (SseOpcode::Pmaxub, AvxOpcode::Vpmaxub),
+            (I16x8, Signed) => (SseOpcode::Pmaxsw, AvxOpcode::Vpmaxsw),
+            (I16x8, Unsigned) => (SseOpcode::Pmaxuw, AvxOpcode::Vpmaxuw),
+            (I32x4, Signed) => (SseOpcode::Pmaxsd, AvxOpcode::Vpmaxsd),
+            (I32x4, Unsigned) => (SseOpcode::Pmaxud, AvxOpcode::Vpmaxud),
+            _ => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Lane-wise maximum of the float lanes of src and dst, with the x86
+    /// `maxps`/`maxpd` semantics, which return src when either lane is a NaN
+    /// or both are zero.
+    pub fn xmm_vmaxp_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::F32x4 => (SseOpcode::Maxps, AvxOpcode::Vmaxps),
+            VectorShape::F64x2 => (SseOpcode::Maxpd, AvxOpcode::Vmaxpd),
+            _ => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Lane-wise unsigned rounding average of src and dst.
+    pub fn xmm_vavg_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::I8x16 => (SseOpcode::Pavgb, AvxOpcode::Vpavgb),
+            VectorShape::I16x8 => (SseOpcode::Pavgw, AvxOpcode::Vpavgw),
+            _ => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Narrows the lanes of dst and src, with saturation, to the lanes of
+    /// `shape`; the lanes of dst make up the low half of the result.
+    pub fn xmm_vpack_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape, sign: Signedness) {
+        let (op, avx_op) = match (shape, sign) {
+            (VectorShape::I8x16, Signedness::Signed) => (SseOpcode::Packsswb, AvxOpcode::Vpacksswb),
+            (VectorShape::I8x16, Signedness::Unsigned) => {
+                (SseOpcode::Packuswb, AvxOpcode::Vpackuswb)
+            }
+            (VectorShape::I16x8, Signedness::Signed) => (SseOpcode::Packssdw, AvxOpcode::Vpackssdw),
+            (VectorShape::I16x8, Signedness::Unsigned) => {
+                (SseOpcode::Packusdw, AvxOpcode::Vpackusdw)
+            }
+            _ => unreachable!(),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Interleaves the low (`high` unset) or high 8-bit lanes of dst and src.
+    pub fn xmm_punpckbw_rr(&mut self, src: Reg, dst: Reg, high: bool) {
+        let (op, avx_op) = if high {
+            (SseOpcode::Punpckhbw, AvxOpcode::Vpunpckhbw)
+        } else {
+            (SseOpcode::Punpcklbw, AvxOpcode::Vpunpcklbw)
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Interleaves the low 32-bit float lanes of dst and src.
+    pub fn xmm_unpcklps_rr(&mut self, src: Reg, dst: Reg) {
+        self.xmm_rmr(SseOpcode::Unpcklps, AvxOpcode::Vunpcklps, dst, src, dst);
+    }
+
+    /// Moves the low 64 bits of src to the high 64 bits of dst.
+    pub fn xmm_movlhps_rr(&mut self, src: Reg, dst: Reg) {
+        self.xmm_rmr(SseOpcode::Movlhps, AvxOpcode::Vmovlhps, dst, src, dst);
+    }
+
+    /// Moves the low 64 bits of src to the low 64 bits of dst, leaving the
+    /// high 64 bits of dst untouched.
+    pub fn xmm_movsd_rr(&mut self, src: Reg, dst: Reg) {
+        self.xmm_rmr(SseOpcode::Movsd, AvxOpcode::Vmovsd, dst, src, dst);
+    }
+
+    /// Multiplies the signed 16-bit lanes of dst and src, rounding and
+    /// scaling the products to their 16 high bits.
+    pub fn xmm_pmulhrsw_rr(&mut self, src: Reg, dst: Reg) {
+        self.xmm_rmr(SseOpcode::Pmulhrsw, AvxOpcode::Vpmulhrsw, dst, src, dst);
+    }
+
+    /// Multiplies the signed 16-bit lanes of dst and src and adds the
+    /// adjacent pairs of 32-bit products.
+    pub fn xmm_pmaddwd_rr(&mut self, src: Reg, dst: Reg) {
+        self.xmm_rmr(SseOpcode::Pmaddwd, AvxOpcode::Vpmaddwd, dst, src, dst);
+    }
+
+    /// Multiplies the unsigned 8-bit lanes of dst and the signed 8-bit lanes
+    /// of src and adds the adjacent pairs of 16-bit products, with signed
+    /// saturation.
+    pub fn xmm_pmaddubsw_rr(&mut self, src: Reg, dst: Reg) {
+        self.xmm_rmr(SseOpcode::Pmaddubsw, AvxOpcode::Vpmaddubsw, dst, src, dst);
+    }
+
+    /// Multiplies the low 32 bits of each 64-bit lane of dst and src into
+    /// the full 64-bit lane.
+    pub fn xmm_pmuldq_rr(&mut self, src: Reg, dst: Reg, sign: Signedness) {
+        let (op, avx_op) = match sign {
+            Signedness::Signed => (SseOpcode::Pmuldq, AvxOpcode::Vpmuldq),
+            Signedness::Unsigned => (SseOpcode::Pmuludq, AvxOpcode::Vpmuludq),
+        };
+        self.xmm_rmr(op, avx_op, dst, src, dst);
+    }
+
+    /// Shifts the lanes of dst by the amount in the low 64 bits of src.
+    /// Amounts greater than the lane width shift all the bits out.
+    pub fn xmm_vshift_rr(&mut self, src: Reg, dst: Reg, kind: ShiftKind, shape: VectorShape) {
+        self.xmm_vshift(RegMemImm::reg(src.into()), dst, kind, shape);
+    }
+
+    /// Shifts the lanes of dst by an immediate amount.
+    pub fn xmm_vshift_ir(&mut self, imm: u8, dst: Reg, kind: ShiftKind, shape: VectorShape) {
+        self.xmm_vshift(RegMemImm::imm(imm.into()), dst, kind, shape);
+    }
+
+    fn xmm_vshift(&mut self, amount: RegMemImm, dst: Reg, kind: ShiftKind, shape: VectorShape) {
+        let (op, avx_op) = Self::vshift_opcodes(kind, shape);
+        if self.isa_flags.has_avx() {
+            self.emit(Inst::XmmRmiRVex {
+                op: avx_op,
+                src1: dst.into(),
+                src2: XmmMemImm::new(amount).expect("valid xmm shift amount"),
+                dst: dst.into(),
+            });
+        } else {
+            self.emit(Inst::XmmRmiReg {
+                opcode: op,
+                src1: dst.into(),
+                src2: XmmMemAlignedImm::new(amount).expect("valid xmm shift amount"),
+                dst: dst.into(),
+            });
+        }
+    }
+
+    fn vshift_opcodes(kind: ShiftKind, shape: VectorShape) -> (SseOpcode, AvxOpcode) {
+        use ShiftKind::*;
+        use VectorShape::*;
+
+        match (kind, shape) {
+            (Shl, I16x8) => (SseOpcode::Psllw, AvxOpcode::Vpsllw),
+            (Shl, I32x4) => (SseOpcode::Pslld, AvxOpcode::Vpslld),
+            (Shl, I64x2) => (SseOpcode::Psllq, AvxOpcode::Vpsllq),
+            (ShrU, I16x8) => (SseOpcode::Psrlw, AvxOpcode::Vpsrlw),
+            (ShrU, I32x4) => (SseOpcode::Psrld, AvxOpcode::Vpsrld),
+            (ShrU, I64x2) => (SseOpcode::Psrlq, AvxOpcode::Vpsrlq),
+            (ShrS, I16x8) => (SseOpcode::Psraw, AvxOpcode::Vpsraw),
+            (ShrS, I32x4) => (SseOpcode::Psrad, AvxOpcode::Vpsrad),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Computes the absolute value of the signed integer lanes of src and
+    /// places the result in dst.
+    pub fn xmm_vabs_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::I8x16 => (SseOpcode::Pabsb, AvxOpcode::Vpabsb),
+            VectorShape::I16x8 => (SseOpcode::Pabsw, AvxOpcode::Vpabsw),
+            VectorShape::I32x4 => (SseOpcode::Pabsd, AvxOpcode::Vpabsd),
+            _ => unreachable!(),
+        };
+        self.xmm_unary_rr(op, avx_op, src, dst);
+    }
+
+    /// Computes the square root of the float lanes of src and places the
+    /// result in dst.
+    pub fn xmm_vsqrt_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::F32x4 => (SseOpcode::Sqrtps, AvxOpcode::Vsqrtps),
+            VectorShape::F64x2 => (SseOpcode::Sqrtpd, AvxOpcode::Vsqrtpd),
+            _ => unreachable!(),
+        };
+        self.xmm_unary_rr(op, avx_op, src, dst);
+    }
+
+    /// Rounds the float lanes of src in the given mode and places the result
+    /// in dst.
+    pub fn xmm_vround_rr(&mut self, src: Reg, dst: Reg, mode: RoundingMode, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::F32x4 => (SseOpcode::Roundps, AvxOpcode::Vroundps),
+            VectorShape::F64x2 => (SseOpcode::Roundpd, AvxOpcode::Vroundpd),
+            _ => unreachable!(),
+        };
+        let imm: u8 = match mode {
+            RoundingMode::Nearest => 0x00,
+            RoundingMode::Down => 0x01,
+            RoundingMode::Up => 0x02,
+            RoundingMode::Zero => 0x03,
+        };
+        self.xmm_unary_rr_imm(op, avx_op, src, dst, imm);
+    }
+
+    /// Extends the low lanes of src to the lanes of `shape` and places the
+    /// result in dst.
+    pub fn xmm_vextend_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape, kind: ExtendKind) {
+        use ExtendKind::*;
+        use VectorShape::*;
+
+        let (op, avx_op) = match (shape, kind) {
+            (I16x8, Signed) => (SseOpcode::Pmovsxbw, AvxOpcode::Vpmovsxbw),
+            (I16x8, Unsigned) => (SseOpcode::Pmovzxbw, AvxOpcode::Vpmovzxbw),
+            (I32x4, Signed) => (SseOpcode::Pmovsxwd, AvxOpcode::Vpmovsxwd),
+            (I32x4, Unsigned) => (SseOpcode::Pmovzxwd, AvxOpcode::Vpmovzxwd),
+            (I64x2, Signed) => (SseOpcode::Pmovsxdq, AvxOpcode::Vpmovsxdq),
+            (I64x2, Unsigned) => (SseOpcode::Pmovzxdq, AvxOpcode::Vpmovzxdq),
+            _ => unreachable!(),
+        };
+        self.xmm_unary_rr(op, avx_op, src, dst);
+    }
+
+    /// Converts the float lanes of src to signed 32-bit integers, truncating
+    /// towards zero, and places the result in dst. Out-of-range and NaN
+    /// lanes produce `0x80000000`.
+    pub fn xmm_vcvtt_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::F32x4 => (SseOpcode::Cvttps2dq, AvxOpcode::Vcvttps2dq),
+            VectorShape::F64x2 => (SseOpcode::Cvttpd2dq, AvxOpcode::Vcvttpd2dq),
+            _ => unreachable!(),
+        };
+        self.xmm_unary_rr(op, avx_op, src, dst);
+    }
+
+    /// Lane-wise unordered float comparison of dst and src, setting all the
+    /// bits of the lanes of dst where either operand is a NaN.
+    pub fn xmm_vcmpunord_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        self.xmm_cmpp(dst, src, dst, 0x03, shape);
+    }
+
+    /// Inserts a 32-bit float lane of src into dst, as selected by the
+    /// `insertps` immediate.
+    pub fn xmm_insertps_rr(&mut self, src: Reg, dst: Reg, imm: u8) {
+        self.xmm_rmr_imm(SseOpcode::Insertps, AvxOpcode::Vinsertps, src, dst, imm);
+    }
+
+    /// Selects the two low 32-bit lanes of the result from dst and the two
+    /// high ones from src, as given by the `shufps` immediate.
+    pub fn xmm_shufps_rr(&mut self, src: Reg, dst: Reg, imm: u8) {
+        self.xmm_rmr_imm(SseOpcode::Shufps, AvxOpcode::Vshufps, src, dst, imm);
+    }
+
+    fn xmm_rmr_imm(&mut self, op: SseOpcode, avx_op: AvxOpcode, src: Reg, dst: Reg, imm: u8) {
+        if self.isa_flags.has_avx() {
+            self.emit(Inst::XmmRmRImmVex {
+                op: avx_op,
+                src1: dst.into(),
+                src2: Xmm::from(src).into(),
+                dst: dst.into(),
+                imm,
+            });
+        } else {
+            self.emit(Inst::XmmRmRImm {
+                op,
+                src1: dst.into(),
+                src2: src.into(),
+                dst: Writable::from_reg(dst.into()),
+                imm,
+                size: args::OperandSize::Size32,
+            });
+        }
+    }
+
+    /// Extracts the given integer lane of src into the general purpose
+    /// register dst, zero-extending lanes narrower than 32 bits.
+    pub fn xmm_pextr_rr(&mut self, src: Reg, dst: Reg, lane: u8, size: OperandSize) {
+        let (op, avx_op) = match size {
+            OperandSize::S8 => (SseOpcode::Pextrb, AvxOpcode::Vpextrb),
+            OperandSize::S16 => (SseOpcode::Pextrw, AvxOpcode::Vpextrw),
+            OperandSize::S32 => (SseOpcode::Pextrd, AvxOpcode::Vpextrd),
+            OperandSize::S64 => (SseOpcode::Pextrq, AvxOpcode::Vpextrq),
             OperandSize::S128 => unreachable!(),
         };
+        if self.isa_flags.has_avx() {
+            self.emit(Inst::XmmToGprImmVex {
+                op: avx_op,
+                src: src.into(),
+                dst: dst.into(),
+                imm: lane,
+            });
+        } else {
+            self.emit(Inst::XmmToGprImm {
+                op,
+                src: src.into(),
+                dst: dst.into(),
+                imm: lane,
+            });
+        }
+    }
 
-        self.emit(Inst::XmmUnaryRmR {
-            op,
+    /// Inserts the low bits of the general purpose register src into the
+    /// given integer lane of dst.
+    pub fn xmm_pinsr_rr(&mut self, src: Reg, dst: Reg, lane: u8, size: OperandSize) {
+        let (op, avx_op) = match size {
+            OperandSize::S8 => (SseOpcode::Pinsrb, AvxOpcode::Vpinsrb),
+            OperandSize::S16 => (SseOpcode::Pinsrw, AvxOpcode::Vpinsrw),
+            // `pinsrq` is `pinsrd` with REX.W set.
+            OperandSize::S32 | OperandSize::S64 => (SseOpcode::Pinsrd, AvxOpcode::Vpinsrd),
+            OperandSize::S128 => unreachable!(),
+        };
+        if self.isa_flags.has_avx() {
+            let avx_op = match size {
+                OperandSize::S64 => AvxOpcode::Vpinsrq,
+                _ => avx_op,
+            };
+            self.emit(Inst::XmmVexPinsr {
+                op: avx_op,
+                src1: dst.into(),
+                src2: src.into(),
+                dst: dst.into(),
+                imm: lane,
+            });
+        } else {
+            let size = match size {
+                OperandSize::S64 => args::OperandSize::Size64,
+                _ => args::OperandSize::Size32,
+            };
+            self.emit(Inst::XmmRmRImm {
+                op,
+                src1: dst.into(),
+                src2: src.into(),
+                dst: Writable::from_reg(dst.into()),
+                imm: lane,
+                size,
+            });
+        }
+    }
+
+    /// Gathers the most significant bit of each lane of src into the low
+    /// bits of the general purpose register dst. Only 8-bit, 32-bit and
+    /// 64-bit lanes can be gathered directly.
+    pub fn xmm_movmsk_rr(&mut self, src: Reg, dst: Reg, shape: VectorShape) {
+        let (op, avx_op) = match shape {
+            VectorShape::I8x16 => (SseOpcode::Pmovmskb, AvxOpcode::Vpmovmskb),
+            VectorShape::I32x4 | VectorShape::F32x4 => (SseOpcode::Movmskps, AvxOpcode::Vmovmskps),
+            VectorShape::I64x2 | VectorShape::F64x2 => (SseOpcode::Movmskpd, AvxOpcode::Vmovmskpd),
+            VectorShape::I16x8 => unreachable!(),
+        };
+        if self.isa_flags.has_avx() {
+            self.emit(Inst::XmmToGprVex {
+                op: avx_op,
+                src: src.into(),
+                dst: dst.into(),
+                dst_size: args::OperandSize::Size32,
+            });
+        } else {
+            self.emit(Inst::XmmToGpr {
+                op,
+                src: src.into(),
+                dst: dst.into(),
+                dst_size: args::OperandSize::Size32,
+            });
+        }
+    }
+
+    /// Sets the zero flag if the bitwise and of src and dst is zero.
+    pub fn xmm_ptest_rr(&mut self, src: Reg, dst: Reg) {
+        // There's no VEX-encoded `ptest` in Cranelift's x64 backend, the
+        // legacy encoding works on every SSE4.1 target.
+        self.emit(Inst::XmmCmpRmR {
+            op: SseOpcode::Ptest,
             src: Xmm::from(src).into(),
             dst: dst.into(),
-        })
+        });
     }
 
     /// Emit a call to an unknown location through a register.
-    pub fn call_with_reg(&mut self, callee: Reg) {
+    pub fn call_with_reg(&mut self, cc: CallingConvention, callee: Reg) {
         self.emit(Inst::CallUnknown {
             dest: RegMem::reg(callee.into()),
             info: Box::new(CallInfo {
@@ -1023,13 +2051,18 @@ impl Assembler {
                 clobbers: Default::default(),
                 opcode: Opcode::Call,
                 callee_pop_size: 0,
-                callee_conv: CallConv::SystemV,
+                callee_conv: cc.into(),
             }),
         });
     }
 
+    /// Attach a stack map to the next call instruction.
+    pub fn stack_map(&mut self, stack_map: StackMap) {
+        self.emit_state.pre_safepoint(stack_map);
+    }
+
     /// Emit a call to a locally defined function through an index.
-    pub fn call_with_index(&mut self, index: u32) {
+    pub fn call_with_index(&mut self, cc: CallingConvention, index: u32) {
         let dest = ExternalName::user(UserExternalNameRef::new(index as usize));
         self.emit(Inst::CallKnown {
             dest,
@@ -1039,13 +2072,13 @@ impl Assembler {
                 clobbers: Default::default(),
                 opcode: Opcode::Call,
                 callee_pop_size: 0,
-                callee_conv: CallConv::SystemV,
+                callee_conv: cc.into(),
             }),
         });
     }
 
     /// Emit a call to a well-known libcall.
-    pub fn call_with_lib(&mut self, lib: LibCall) {
+    pub fn call_with_lib(&mut self, cc: CallingConvention, lib: LibCall) {
         let dest = ExternalName::LibCall(lib);
         self.emit(Inst::CallKnown {
             dest,
@@ -1055,7 +2088,7 @@ impl Assembler {
                 clobbers: Default::default(),
                 opcode: Opcode::Call,
                 callee_pop_size: 0,
-                callee_conv: CallConv::SystemV,
+                callee_conv: cc.into(),
             }),
         });
     }
@@ -1103,4 +2136,98 @@ impl Assembler {
             trap_code,
         });
     }
+
+    /// Atomic read-modify-write of the value at `addr`, implemented as a
+    /// `lock cmpxchg` loop. The previous value is written to `dst`, which
+    /// must be `rax`; `temp` is clobbered.
+    pub fn atomic_rmw_seq(
+        &mut self,
+        kind: AtomicRmwKind,
+        addr: &Address,
+        operand: Reg,
+        temp: Reg,
+        dst: Reg,
+        size: OperandSize,
+    ) {
+        assert!(addr.is_offset());
+        let mem =
+            Self::to_synthetic_amode(addr, &mut self.pool, &mut self.constants, &mut self.buffer);
+        self.emit(Inst::AtomicRmwSeq {
+            ty: Self::int_type(size),
+            op: kind.into(),
+            mem,
+            operand: operand.into(),
+            temp: Writable::from_reg(temp.into()),
+            dst_old: Writable::from_reg(dst.into()),
+        });
+    }
+
+    /// Compare the value at `addr` with `expected`, which must be `rax`, and
+    /// store `replacement` if they are equal, through `lock cmpxchg`. The
+    /// previous value is written back to `expected`.
+    pub fn lock_cmpxchg(
+        &mut self,
+        addr: &Address,
+        replacement: Reg,
+        expected: Reg,
+        size: OperandSize,
+    ) {
+        assert!(addr.is_offset());
+        let mem =
+            Self::to_synthetic_amode(addr, &mut self.pool, &mut self.constants, &mut self.buffer);
+        self.emit(Inst::LockCmpxchg {
+            ty: Self::int_type(size),
+            replacement: replacement.into(),
+            expected: expected.into(),
+            mem,
+            dst_old: Writable::from_reg(expected.into()),
+        });
+    }
+
+    /// Atomically add `operand` to the value at `addr` through `lock xadd`.
+    /// The previous value is written back to `operand`.
+    pub fn lock_xadd(&mut self, addr: &Address, operand: Reg, size: OperandSize) {
+        assert!(addr.is_offset());
+        let mem =
+            Self::to_synthetic_amode(addr, &mut self.pool, &mut self.constants, &mut self.buffer);
+        self.emit(Inst::LockXadd {
+            ty: Self::int_type(size),
+            operand: operand.into(),
+            mem,
+            dst_old: Writable::from_reg(operand.into()),
+        });
+    }
+
+    /// Atomically exchange `operand` with the value at `addr` through
+    /// `xchg`, which is implicitly locked. The previous value is written back
+    /// to `operand`.
+    pub fn xchg(&mut self, addr: &Address, operand: Reg, size: OperandSize) {
+        assert!(addr.is_offset());
+        let mem =
+            Self::to_synthetic_amode(addr, &mut self.pool, &mut self.constants, &mut self.buffer);
+        self.emit(Inst::Xchg {
+            ty: Self::int_type(size),
+            operand: operand.into(),
+            mem,
+            dst_old: Writable::from_reg(operand.into()),
+        });
+    }
+
+    /// Emit a memory fence.
+    pub fn mfence(&mut self) {
+        self.emit(Inst::Fence {
+            kind: FenceKind::MFence,
+        });
+    }
+
+    /// The integer type corresponding to an operand size.
+    fn int_type(size: OperandSize) -> types::Type {
+        match size {
+            OperandSize::S8 => types::I8,
+            OperandSize::S16 => types::I16,
+            OperandSize::S32 => types::I32,
+            OperandSize::S64 => types::I64,
+            s => panic!("Invalid integer operand size {:?}", s),
+        }
+    }
 }
@@ -6,29 +6,93 @@ use super::{
 };
 
 use crate::masm::{
-    DivKind, FloatCmpKind, Imm as I, IntCmpKind, MacroAssembler as Masm, OperandSize, RegImm,
-    RemKind, RoundingMode, ShiftKind, TrapCode,
+    AluKind, AtomicRmwKind, DivKind, ExtendKind, FloatCmpKind, Imm as I, IntCmpKind,
+    MacroAssembler as Masm, OperandSize, RegImm, RemKind, RoundingMode, ShiftKind, Signedness,
+    TrapCode, TruncKind, VectorConvertKind, VectorHalf, VectorLoadKind, VectorShape,
 };
 use crate::{abi::ABI, masm::StackSlot, stack::TypedReg};
 use crate::{
     abi::{self, align_to, calculate_frame_adjustment, LocalSlot},
-    codegen::{ptr_type_from_ptr_size, Callee, CodeGenContext, FnCall, TableData},
+    codegen::{
+        ptr_type_from_ptr_size, Callee, CodeGenContext, FnCall, HeapData, HeapStyle, TableData,
+    },
     stack::Val,
 };
 use crate::{
-    isa::reg::{Reg, RegClass},
+    isa::{
+        reg::{Reg, RegClass},
+        CallingConvention,
+    },
     masm::CalleeKind,
 };
+use anyhow::{anyhow, Result};
 use cranelift_codegen::{
-    isa::x64::settings as x64_settings, settings, Final, MachBufferFinalized, MachLabel,
+    binemit::{CodeOffset, StackMap},
+    ir::{RelSourceLoc, SourceLoc},
+    isa::unwind::UnwindInst,
+    isa::x64::{
+        args::{ExtMode, CC},
+        settings as x64_settings,
+    },
+    settings, Final, MachBufferFinalized, MachLabel, PatchRegion,
 };
+use smallvec::SmallVec;
+use wasmparser::Operator;
+
+use wasmtime_environ::{PtrSize, WasmType, WASM_PAGE_SIZE};
+
+/// The size of the frame setup area, made of the return address and the
+/// caller's frame pointer.
+const SETUP_AREA_SIZE: u32 = 16;
+
+/// Lays out the area in which `clobbers` are saved, right below the frame
+/// pointer, returning each register's offset from the bottom of the area and
+/// the area's size.
+///
+/// This matches the clobber area of Cranelift's x64 backend, so that the
+/// unwind information of both describes frames in the same way: integer
+/// registers take 8 bytes, float registers take 16 bytes at 16-byte aligned
+/// offsets, and the area's size is a multiple of 16 bytes.
+fn clobber_layout(clobbers: &[(Reg, OperandSize)]) -> (SmallVec<[u32; 18]>, u32) {
+    let mut offsets = SmallVec::new();
+    let mut size = 0;
+    for (reg, _) in clobbers {
+        if reg.is_float() {
+            size = align_to(size, 16);
+            offsets.push(size);
+            size += 16;
+        } else {
+            offsets.push(size);
+            size += 8;
+        }
+    }
+    (offsets, align_to(size, 16))
+}
 
-use wasmtime_environ::PtrSize;
+/// The extension mode of a `movsx` or `movzx` from a `src_size` integer to a
+/// `dst_size` one.
+fn ext_mode(src_size: OperandSize, dst_size: OperandSize) -> ExtMode {
+    use OperandSize::*;
+
+    match (src_size, dst_size) {
+        (S8, S32) => ExtMode::BL,
+        (S8, S64) => ExtMode::BQ,
+        (S16, S32) => ExtMode::WL,
+        (S16, S64) => ExtMode::WQ,
+        (S32, S64) => ExtMode::LQ,
+        _ => unreachable!(),
+    }
+}
 
 /// x64 MacroAssembler.
 pub(crate) struct MacroAssembler {
     /// Stack pointer offset.
     sp_offset: u32,
+    /// The largest stack pointer offset so far.
+    max_sp_offset: u32,
+    /// The instruction adding the stack check's frame size and the stack
+    /// pointer offset at the check, if one was emitted.
+    stack_check: Option<(PatchRegion, u32)>,
     /// Low level assembler.
     asm: Assembler,
     /// ISA flags.
@@ -44,13 +108,36 @@ impl Masm for MacroAssembler {
     type Ptr = u8;
     type ABI = X64ABI;
 
-    fn prologue(&mut self) {
+    fn prologue(&mut self, clobbers: &[(Reg, OperandSize)]) {
         let frame_pointer = rbp();
         let stack_pointer = rsp();
 
         self.asm.push_r(frame_pointer);
+        if self.shared_flags.unwind_info() {
+            self.asm.unwind_inst(UnwindInst::PushFrameRegs {
+                offset_upward_to_caller_sp: SETUP_AREA_SIZE,
+            });
+        }
         self.asm
             .mov_rr(stack_pointer, frame_pointer, OperandSize::S64);
+
+        let (offsets, clobber_size) = clobber_layout(clobbers);
+        if self.shared_flags.unwind_info() {
+            self.asm.unwind_inst(UnwindInst::DefineNewFrame {
+                offset_upward_to_caller_sp: SETUP_AREA_SIZE,
+                offset_downward_to_clobbers: clobber_size,
+            });
+        }
+        self.reserve_stack(clobber_size);
+        for (&(reg, size), offset) in clobbers.iter().zip(offsets) {
+            self.store(reg.into(), self.address_at_sp(offset), size);
+            if self.shared_flags.unwind_info() {
+                self.asm.unwind_inst(UnwindInst::SaveReg {
+                    clobber_offset: offset,
+                    reg: cranelift_codegen::Reg::from(reg).to_real_reg().unwrap(),
+                });
+            }
+        }
     }
 
     fn push(&mut self, reg: Reg, size: OperandSize) -> StackSlot {
@@ -140,35 +227,39 @@ impl Masm for MacroAssembler {
         self.asm.cmp_rr(bound, index, bound_size);
         self.asm.trapif(IntCmpKind::GeU, TrapCode::TableOutOfBounds);
 
-        // Move the index into the scratch register to calcualte the table
-        // element address.
-        // Moving the value of the index register to the scratch register
-        // also avoids overwriting the context of the index register.
-        self.asm.mov_rr(index, scratch, bound_size);
-        self.asm.mul_ir(
-            table_data.element_size.bytes() as i32,
-            scratch,
-            table_data.element_size,
-        );
+        // Scale the index as part of the element address instead of
+        // calculating the element offset separately; table elements are
+        // always pointer-sized, which fits one of the scales supported by
+        // x64 addressing modes.
+        let element_size = table_data.element_size.bytes();
+        assert!(element_size.is_power_of_two() && element_size <= 8);
+        let shift = element_size.trailing_zeros() as u8;
+
+        if self.shared_flags.enable_table_access_spectre_mitigation() {
+            // Zero the temporary register ahead of the comparison below, since
+            // `xor` clobbers the flags.
+            self.asm.xor_rr(tmp, tmp, OperandSize::S32);
+        }
         self.asm.mov_mr(
             &self.address_at_reg(ptr_base, table_data.offset),
             ptr_base,
             self.ptr_size,
         );
-        // Copy the value of the table base into a temporary register
-        // so that we can use it later in case of a misspeculation.
-        self.asm.mov_rr(ptr_base, tmp, self.ptr_size);
-        // Calculate the address of the table element.
-        self.asm.add_rr(scratch, ptr_base, self.ptr_size);
+        // Move the index into the scratch register to use it as the index of
+        // the table element address.
+        // Moving the value of the index register to the scratch register
+        // also avoids overwriting the context of the index register.
+        self.asm.mov_rr(index, scratch, bound_size);
         if self.shared_flags.enable_table_access_spectre_mitigation() {
-            // Perform a bounds check and override the value of the
-            // table element address in case the index is out of bounds.
+            // Perform a bounds check and override the index with zero,
+            // pointing at the start of the table, in case the index is out of
+            // bounds.
             self.asm.cmp_rr(bound, index, OperandSize::S32);
-            self.asm.cmov(tmp, ptr_base, IntCmpKind::GeU, self.ptr_size);
+            self.asm.cmov(tmp, scratch, IntCmpKind::GeU, self.ptr_size);
         }
         context.free_reg(bound);
         context.free_reg(tmp);
-        self.address_at_reg(ptr_base, 0)
+        Address::indexed(ptr_base, scratch, shift, 0)
     }
 
     fn table_size(&mut self, table_data: &TableData, context: &mut CodeGenContext) {
@@ -190,6 +281,166 @@ impl Masm for MacroAssembler {
         context.stack.push(TypedReg::i32(size).into());
     }
 
+    fn memory_size(&mut self, heap_data: &HeapData, context: &mut CodeGenContext) {
+        let vmctx = <Self::ABI as ABI>::vmctx_reg();
+        let scratch = regs::scratch();
+        let size = context.any_gpr(self);
+
+        if let Some(offset) = heap_data.import_from {
+            self.asm
+                .mov_mr(&self.address_at_vmctx(offset), scratch, self.ptr_size);
+        } else {
+            self.asm.mov_rr(vmctx, scratch, self.ptr_size);
+        };
+
+        let size_addr = Address::offset(scratch, heap_data.current_length_offset);
+        self.asm.mov_mr(&size_addr, size, self.ptr_size);
+        // Convert the length in bytes to wasm pages.
+        self.asm.shift_ir(
+            WASM_PAGE_SIZE.trailing_zeros() as u8,
+            size,
+            ShiftKind::ShrU,
+            self.ptr_size,
+        );
+
+        context.stack.push(TypedReg::new(heap_data.ty, size).into());
+    }
+
+    fn heap_address(
+        &mut self,
+        index: Reg,
+        base: Reg,
+        heap_data: &HeapData,
+        offset: u64,
+        size: OperandSize,
+        context: &mut CodeGenContext,
+    ) -> Self::Address {
+        self.checked_heap_address(index, base, heap_data, offset, size, false, context)
+    }
+
+    fn atomic_heap_address(
+        &mut self,
+        index: Reg,
+        base: Reg,
+        heap_data: &HeapData,
+        offset: u64,
+        size: OperandSize,
+        context: &mut CodeGenContext,
+    ) -> Self::Address {
+        self.checked_heap_address(index, base, heap_data, offset, size, true, context)
+    }
+
+    fn atomic_load(&mut self, src: Self::Address, dst: Reg, size: OperandSize) {
+        // Aligned loads are sequentially consistent under x86-TSO.
+        match size {
+            OperandSize::S8 => self.asm.movzx_mr(&src, dst, ExtMode::BQ),
+            OperandSize::S16 => self.asm.movzx_mr(&src, dst, ExtMode::WQ),
+            _ => self.asm.mov_mr(&src, dst, size),
+        }
+    }
+
+    fn atomic_store(&mut self, src: Reg, dst: Self::Address, size: OperandSize) {
+        // Aligned stores are atomic but may be reordered with later loads,
+        // which the fence prevents.
+        self.asm.mov_rm(src, &dst, size);
+        self.asm.mfence();
+    }
+
+    fn atomic_rmw(
+        &mut self,
+        heap_data: &HeapData,
+        offset: u64,
+        kind: AtomicRmwKind,
+        size: OperandSize,
+        context: &mut CodeGenContext,
+    ) {
+        match kind {
+            // These have native instructions, which write the previous value
+            // back to the operand register.
+            AtomicRmwKind::Add | AtomicRmwKind::Sub | AtomicRmwKind::Xchg => {
+                let operand = context.pop_to_reg(self, None);
+                let index = context.pop_to_reg(self, None);
+                let base = context.any_gpr(self);
+
+                let addr =
+                    self.atomic_heap_address(index.into(), base, heap_data, offset, size, context);
+                match kind {
+                    AtomicRmwKind::Add => self.asm.lock_xadd(&addr, operand.into(), size),
+                    AtomicRmwKind::Sub => {
+                        // Subtraction is addition of the negated operand;
+                        // negating the whole register also negates its low
+                        // `size` bytes.
+                        self.asm
+                            .neg(operand.into(), operand.into(), operand.ty.into());
+                        self.asm.lock_xadd(&addr, operand.into(), size);
+                    }
+                    _ => self.asm.xchg(&addr, operand.into(), size),
+                }
+                self.zero_extend_narrow(operand.into(), size, operand.ty.into());
+
+                context.free_reg(base);
+                context.free_reg(index);
+                context.stack.push(operand.into());
+            }
+            // The bitwise operations don't produce the previous value
+            // natively, so they use a `lock cmpxchg` loop.
+            AtomicRmwKind::And | AtomicRmwKind::Or | AtomicRmwKind::Xor => {
+                // The previous value is always returned in rax.
+                let rax = context.reg(regs::rax(), self);
+                let operand = context.pop_to_reg(self, None);
+                let index = context.pop_to_reg(self, None);
+                let base = context.any_gpr(self);
+
+                let addr =
+                    self.atomic_heap_address(index.into(), base, heap_data, offset, size, context);
+                // The scratch register isn't used by the address, so it can
+                // serve as the temporary register of the sequence. The
+                // sequence loads the previous value zero-extended.
+                self.asm
+                    .atomic_rmw_seq(kind, &addr, operand.into(), regs::scratch(), rax, size);
+
+                context.free_reg(base);
+                context.free_reg(index);
+                context.free_reg(operand);
+                context.stack.push(TypedReg::new(operand.ty, rax).into());
+            }
+        }
+    }
+
+    fn atomic_cmpxchg(
+        &mut self,
+        heap_data: &HeapData,
+        offset: u64,
+        size: OperandSize,
+        context: &mut CodeGenContext,
+    ) {
+        // `lock cmpxchg` expects the expected value in rax, and writes the
+        // previous value back to it.
+        let rax = context.reg(regs::rax(), self);
+        let replacement = context.pop_to_reg(self, None);
+        // Mark rax as allocatable.
+        context.free_reg(rax);
+        let expected = context.pop_to_reg(self, Some(rax));
+        let index = context.pop_to_reg(self, None);
+        let base = context.any_gpr(self);
+
+        let addr = self.atomic_heap_address(index.into(), base, heap_data, offset, size, context);
+        self.asm
+            .lock_cmpxchg(&addr, replacement.into(), expected.into(), size);
+        // Only the low `size` bytes of rax are compared and, on failure,
+        // written, so the upper bytes of the expected value may remain.
+        self.zero_extend_narrow(expected.into(), size, expected.ty.into());
+
+        context.free_reg(base);
+        context.free_reg(index);
+        context.free_reg(replacement);
+        context.stack.push(expected.into());
+    }
+
+    fn fence(&mut self) {
+        self.asm.mfence();
+    }
+
     fn address_from_sp(&self, offset: u32) -> Self::Address {
         Address::offset(regs::rsp(), self.sp_offset - offset)
     }
@@ -245,7 +496,7 @@ impl Masm for MacroAssembler {
     fn call(
         &mut self,
         stack_args_size: u32,
-        mut load_callee: impl FnMut(&mut Self) -> CalleeKind,
+        mut load_callee: impl FnMut(&mut Self) -> (CalleeKind, CallingConvention),
     ) -> u32 {
         let alignment: u32 = <Self::ABI as abi::ABI>::call_stack_align().into();
         let addend: u32 = <Self::ABI as abi::ABI>::arg_base_offset().into();
@@ -253,15 +504,31 @@ impl Masm for MacroAssembler {
         let aligned_args_size = align_to(stack_args_size, alignment);
         let total_stack = delta + aligned_args_size;
         self.reserve_stack(total_stack);
-        let callee = load_callee(self);
+        let (callee, cc) = load_callee(self);
         match callee {
-            CalleeKind::Indirect(reg) => self.asm.call_with_reg(reg),
-            CalleeKind::Direct(idx) => self.asm.call_with_index(idx),
-            CalleeKind::Known(lib) => self.asm.call_with_lib(lib),
+            CalleeKind::Indirect(reg) => self.asm.call_with_reg(cc, reg),
+            CalleeKind::Direct(idx) => self.asm.call_with_index(cc, idx),
+            CalleeKind::Known(lib) => self.asm.call_with_lib(cc, lib),
         };
         total_stack
     }
 
+    fn stack_map(&mut self, gc_ref_slots: &[u32]) {
+        if gc_ref_slots.is_empty() {
+            return;
+        }
+        let word_bytes = <Self::ABI as ABI>::word_bytes();
+        // The stack pointer is aligned at call sites, so the frame is made of
+        // whole words.
+        assert!(self.sp_offset % word_bytes == 0);
+        let mut words = vec![false; (self.sp_offset / word_bytes) as usize];
+        for &offset in gc_ref_slots {
+            let index = (self.sp_offset - offset) / word_bytes;
+            words[index as usize] = true;
+        }
+        self.asm.stack_map(StackMap::from_slice(&words));
+    }
+
     fn load_ptr(&mut self, src: Self::Address, dst: Reg) {
         self.load(src, dst, self.ptr_size);
     }
@@ -274,6 +541,36 @@ impl Masm for MacroAssembler {
         }
     }
 
+    fn load_extend(
+        &mut self,
+        src: Address,
+        dst: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: ExtendKind,
+    ) {
+        let ext = ext_mode(src_size, dst_size);
+        match kind {
+            ExtendKind::Signed => self.asm.movsx_mr(&src, dst, ext),
+            ExtendKind::Unsigned => self.asm.movzx_mr(&src, dst, ext),
+        }
+    }
+
+    fn extend(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: ExtendKind,
+    ) {
+        let ext = ext_mode(src_size, dst_size);
+        match kind {
+            ExtendKind::Signed => self.asm.movsx_rr(src, dst, ext),
+            ExtendKind::Unsigned => self.asm.movzx_rr(src, dst, ext),
+        }
+    }
+
     fn sp_offset(&self) -> u32 {
         self.sp_offset
     }
@@ -300,6 +597,10 @@ impl Masm for MacroAssembler {
                     let addr = self.asm.add_constant(v.to_le_bytes().as_slice());
                     self.asm.xmm_mov_mr(&addr, dst, size);
                 }
+                I::V128(v) => {
+                    let addr = self.asm.add_constant(v.to_le_bytes().as_slice());
+                    self.asm.xmm_mov_mr(&addr, dst, size);
+                }
             },
         }
     }
@@ -314,6 +615,10 @@ impl Masm for MacroAssembler {
 
     fn add(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
         Self::ensure_two_argument_form(&dst, &lhs);
+        // Adding zero leaves the value unchanged.
+        if rhs.is_int_imm(0) {
+            return;
+        }
         match (rhs, dst) {
             (RegImm::Imm(imm), reg) => {
                 if let Some(v) = imm.to_i32() {
@@ -331,8 +636,24 @@ impl Masm for MacroAssembler {
         }
     }
 
+    fn checked_uadd(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize, trap: TrapCode) {
+        // Adding zero can't overflow, and emits no instruction that would
+        // set the flags.
+        if rhs.is_int_imm(0) {
+            return;
+        }
+        self.add(dst, lhs, rhs, size);
+        // `add` sets the carry flag on unsigned overflow, which is the
+        // unsigned less-than condition.
+        self.asm.trapif(IntCmpKind::LtU, trap);
+    }
+
     fn sub(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
         Self::ensure_two_argument_form(&dst, &lhs);
+        // Subtracting zero leaves the value unchanged.
+        if rhs.is_int_imm(0) {
+            return;
+        }
         match (rhs, dst) {
             (RegImm::Imm(imm), reg) => {
                 if let Some(v) = imm.to_i32() {
@@ -352,6 +673,10 @@ impl Masm for MacroAssembler {
 
     fn mul(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
         Self::ensure_two_argument_form(&dst, &lhs);
+        // Multiplying by one leaves the value unchanged.
+        if rhs.is_int_imm(1) {
+            return;
+        }
         match (rhs, dst) {
             (RegImm::Imm(imm), reg) => {
                 if let Some(v) = imm.to_i32() {
@@ -401,54 +726,17 @@ impl Masm for MacroAssembler {
 
     fn float_copysign(&mut self, dst: Reg, lhs: Reg, rhs: Reg, size: OperandSize) {
         Self::ensure_two_argument_form(&dst, &lhs);
-        let scratch_gpr = regs::scratch();
-        let scratch_xmm = regs::scratch_xmm();
-        let sign_mask = match size {
-            OperandSize::S32 => I::I32(0x80000000),
-            OperandSize::S64 => I::I64(0x8000000000000000),
-            OperandSize::S128 => unreachable!(),
-        };
-        self.load_constant(&sign_mask, scratch_gpr, size);
-        self.asm.gpr_to_xmm(scratch_gpr, scratch_xmm, size);
-
-        // Clear everything except sign bit in src.
-        self.asm.xmm_and_rr(scratch_xmm, rhs, size);
-
-        // Clear sign bit in dst using scratch to store result. Then copy the
-        // result back to dst.
-        self.asm.xmm_andn_rr(dst, scratch_xmm, size);
-        self.asm.xmm_mov_rr(scratch_xmm, dst, size);
-
-        // Copy sign bit from src to dst.
-        self.asm.xmm_or_rr(rhs, dst, size);
+        self.asm.xmm_copysign(rhs, dst, regs::scratch_xmm(), size);
     }
 
     fn float_neg(&mut self, dst: Reg, size: OperandSize) {
         assert_eq!(dst.class(), RegClass::Float);
-        let mask = match size {
-            OperandSize::S32 => I::I32(0x80000000),
-            OperandSize::S64 => I::I64(0x8000000000000000),
-            OperandSize::S128 => unreachable!(),
-        };
-        let scratch_gpr = regs::scratch();
-        self.load_constant(&mask, scratch_gpr, size);
-        let scratch_xmm = regs::scratch_xmm();
-        self.asm.gpr_to_xmm(scratch_gpr, scratch_xmm, size);
-        self.asm.xmm_xor_rr(scratch_xmm, dst, size);
+        self.asm.xmm_neg(dst, regs::scratch_xmm(), size);
     }
 
     fn float_abs(&mut self, dst: Reg, size: OperandSize) {
         assert_eq!(dst.class(), RegClass::Float);
-        let mask = match size {
-            OperandSize::S32 => I::I32(0x7fffffff),
-            OperandSize::S64 => I::I64(0x7fffffffffffffff),
-            OperandSize::S128 => unreachable!(),
-        };
-        let scratch_gpr = regs::scratch();
-        self.load_constant(&mask, scratch_gpr, size);
-        let scratch_xmm = regs::scratch_xmm();
-        self.asm.gpr_to_xmm(scratch_gpr, scratch_xmm, size);
-        self.asm.xmm_and_rr(scratch_xmm, dst, size);
+        self.asm.xmm_abs(dst, regs::scratch_xmm(), size);
     }
 
     fn float_round(&mut self, mode: RoundingMode, context: &mut CodeGenContext, size: OperandSize) {
@@ -494,8 +782,930 @@ impl Masm for MacroAssembler {
         self.asm.sqrt(src, dst, size);
     }
 
+    fn float_canonicalize_nan(&mut self, reg: Reg, size: OperandSize) {
+        // Only a NaN compares unordered with itself, setting the parity flag.
+        let done = self.get_label();
+        self.asm.ucomis(reg, reg, size);
+        self.asm.jmp_if(CC::NP, done);
+        let nan = size.canonical_nan().to_le_bytes();
+        let addr = self.asm.add_constant(&nan[..size.bytes() as usize]);
+        self.asm.xmm_mov_mr(&addr, reg, size);
+        self.asm.bind_label(done);
+    }
+
+    fn v128_add(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vadd_rr(rhs, dst, shape);
+    }
+
+    fn v128_sub(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vsub_rr(rhs, dst, shape);
+    }
+
+    fn v128_mul(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let scratch = regs::scratch_xmm();
+        match shape {
+            VectorShape::I8x16 => {
+                // There's no 8-bit multiplication, so the even and odd
+                // bytes are multiplied as 16-bit lanes and the low byte of
+                // each product kept.
+                self.asm.xmm_mov_rr(dst, scratch, OperandSize::S128);
+                self.asm.xmm_vmul_rr(rhs, scratch, VectorShape::I16x8);
+                self.asm
+                    .xmm_vshift_ir(8, scratch, ShiftKind::Shl, VectorShape::I16x8);
+                self.asm
+                    .xmm_vshift_ir(8, scratch, ShiftKind::ShrU, VectorShape::I16x8);
+                self.asm
+                    .xmm_vshift_ir(8, dst, ShiftKind::ShrU, VectorShape::I16x8);
+                self.asm
+                    .xmm_vshift_ir(8, rhs, ShiftKind::ShrU, VectorShape::I16x8);
+                self.asm.xmm_vmul_rr(rhs, dst, VectorShape::I16x8);
+                self.asm
+                    .xmm_vshift_ir(8, dst, ShiftKind::Shl, VectorShape::I16x8);
+                self.asm.xmm_vor_rr(scratch, dst);
+                return;
+            }
+            VectorShape::I64x2 => {}
+            _ => {
+                self.asm.xmm_vmul_rr(rhs, dst, shape);
+                return;
+            }
+        }
+
+        // There's no packed 64-bit multiplication before AVX-512, so each
+        // product is assembled from 32-bit halves as
+        // lo(a) * lo(b) + ((hi(a) * lo(b) + lo(a) * hi(b)) << 32).
+        self.asm.xmm_pshufd_rr(rhs, scratch, 0b10_11_00_01);
+        self.asm.xmm_vmul_rr(dst, scratch, VectorShape::I32x4);
+        self.asm.xmm_pmuldq_rr(rhs, dst, Signedness::Unsigned);
+        self.asm.xmm_pshufd_rr(scratch, rhs, 0b10_11_00_01);
+        self.asm.xmm_vadd_rr(rhs, scratch, VectorShape::I32x4);
+        self.asm
+            .xmm_vshift_ir(32, scratch, ShiftKind::Shl, VectorShape::I64x2);
+        self.asm.xmm_vadd_rr(scratch, dst, VectorShape::I64x2);
+    }
+
+    fn v128_div(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vdiv_rr(rhs, dst, shape);
+    }
+
+    fn v128_and(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vand_rr(rhs, dst);
+    }
+
+    fn v128_and_not(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        if self.flags.has_avx() {
+            self.asm.xmm_vandn_rrr(rhs, lhs, dst);
+            return;
+        }
+        // `pandn` complements its destination operand, so the result is
+        // computed in the scratch register.
+        let scratch = regs::scratch_xmm();
+        self.asm.xmm_mov_rr(rhs, scratch, OperandSize::S128);
+        self.asm.xmm_vandn_rr(lhs, scratch);
+        self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+    }
+
+    fn v128_or(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vor_rr(rhs, dst);
+    }
+
+    fn v128_xor(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vxor_rr(rhs, dst);
+    }
+
+    fn v128_not(&mut self, dst: Reg) {
+        // Comparing a register with itself produces all ones.
+        let scratch = regs::scratch_xmm();
+        self.asm.xmm_vcmpeq_rr(scratch, scratch, VectorShape::I32x4);
+        self.asm.xmm_vxor_rr(scratch, dst);
+    }
+
+    fn v128_cmp(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: IntCmpKind, shape: VectorShape) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        let has_pcmpgt = shape != VectorShape::I64x2 || self.flags.has_sse42();
+
+        match kind {
+            IntCmpKind::Eq => self.asm.xmm_vcmpeq_rr(rhs, dst, shape),
+            IntCmpKind::Ne => {
+                self.asm.xmm_vcmpeq_rr(rhs, dst, shape);
+                self.v128_not(dst);
+            }
+            IntCmpKind::GtS => self.v128_gt_s(dst, rhs, shape),
+            IntCmpKind::LeS => {
+                self.v128_gt_s(dst, rhs, shape);
+                self.v128_not(dst);
+            }
+            IntCmpKind::LtS | IntCmpKind::GeS => {
+                // There's no packed less than comparison, so the operands
+                // are swapped and, without AVX, the comparison is performed
+                // in the scratch register.
+                if has_pcmpgt && self.flags.has_avx() {
+                    self.asm.xmm_vcmpgt_rrr(rhs, lhs, dst, shape);
+                } else if has_pcmpgt {
+                    let scratch = regs::scratch_xmm();
+                    self.asm.xmm_mov_rr(rhs, scratch, OperandSize::S128);
+                    self.asm.xmm_vcmpgt_rr(lhs, scratch, shape);
+                    self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+                } else {
+                    // The fallback sequence needs the scratch register, so
+                    // the operands are swapped in place instead.
+                    self.asm.xmm_vxor_rr(rhs, dst);
+                    self.asm.xmm_vxor_rr(dst, rhs);
+                    self.asm.xmm_vxor_rr(rhs, dst);
+                    self.v128_gt_s(dst, rhs, shape);
+                }
+                if kind == IntCmpKind::GeS {
+                    self.v128_not(dst);
+                }
+            }
+            IntCmpKind::GeU | IntCmpKind::LtU => {
+                // There are no packed unsigned comparisons either, but
+                // `lhs >= rhs` exactly when `max(lhs, rhs) == lhs`.
+                let scratch = regs::scratch_xmm();
+                self.asm.xmm_mov_rr(dst, scratch, OperandSize::S128);
+                self.asm
+                    .xmm_vmax_rr(rhs, scratch, shape, Signedness::Unsigned);
+                self.asm.xmm_vcmpeq_rr(scratch, dst, shape);
+                if kind == IntCmpKind::LtU {
+                    self.v128_not(dst);
+                }
+            }
+            IntCmpKind::LeU | IntCmpKind::GtU => {
+                let scratch = regs::scratch_xmm();
+                self.asm.xmm_mov_rr(dst, scratch, OperandSize::S128);
+                self.asm
+                    .xmm_vmin_rr(rhs, scratch, shape, Signedness::Unsigned);
+                self.asm.xmm_vcmpeq_rr(scratch, dst, shape);
+                if kind == IntCmpKind::GtU {
+                    self.v128_not(dst);
+                }
+            }
+        }
+    }
+
+    fn v128_float_cmp(
+        &mut self,
+        dst: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        kind: FloatCmpKind,
+        shape: VectorShape,
+    ) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        match kind {
+            FloatCmpKind::Eq | FloatCmpKind::Ne | FloatCmpKind::Lt | FloatCmpKind::Le => {
+                self.asm.xmm_vcmpp_rr(rhs, dst, kind, shape)
+            }
+            FloatCmpKind::Gt | FloatCmpKind::Ge => {
+                // Greater than comparisons are lowered as less than
+                // comparisons with the operands swapped.
+                let kind = match kind {
+                    FloatCmpKind::Gt => FloatCmpKind::Lt,
+                    _ => FloatCmpKind::Le,
+                };
+                if self.flags.has_avx() {
+                    self.asm.xmm_vcmpp_rrr(rhs, lhs, dst, kind, shape);
+                } else {
+                    let scratch = regs::scratch_xmm();
+                    self.asm.xmm_mov_rr(rhs, scratch, OperandSize::S128);
+                    self.asm.xmm_vcmpp_rr(lhs, scratch, kind, shape);
+                    self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+                }
+            }
+        }
+    }
+
+    fn signed_truncate(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: TruncKind,
+    ) {
+        self.asm.cvt_float_to_sint_seq(
+            src,
+            dst,
+            regs::scratch(),
+            regs::scratch_xmm(),
+            src_size,
+            dst_size,
+            kind == TruncKind::Saturating,
+        );
+    }
+
+    fn unsigned_truncate(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        tmp_fpr: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: TruncKind,
+    ) {
+        self.asm.cvt_float_to_uint_seq(
+            src,
+            dst,
+            regs::scratch(),
+            regs::scratch_xmm(),
+            tmp_fpr,
+            src_size,
+            dst_size,
+            kind == TruncKind::Saturating,
+        );
+    }
+
+    fn v128_convert(&mut self, dst: Reg, src: Reg, tmp: Reg, kind: VectorConvertKind) {
+        use VectorConvertKind::*;
+
+        let scratch = regs::scratch_xmm();
+        match kind {
+            F32x4ConvertI32x4S
+            | F64x2ConvertLowI32x4S
+            | F32x4DemoteF64x2Zero
+            | F64x2PromoteLowF32x4 => self.asm.xmm_vcvt_rr(src, dst, kind),
+            F32x4ConvertI32x4U => {
+                // The low 16 bits of each lane convert exactly. The rest is
+                // halved, so that it converts as a positive signed integer,
+                // and doubled back.
+                self.move_v128(src, dst);
+                self.asm.xmm_mov_rr(dst, scratch, OperandSize::S128);
+                self.asm
+                    .xmm_vshift_ir(16, scratch, ShiftKind::Shl, VectorShape::I32x4);
+                self.asm
+                    .xmm_vshift_ir(16, scratch, ShiftKind::ShrU, VectorShape::I32x4);
+                self.asm.xmm_vsub_rr(scratch, dst, VectorShape::I32x4);
+                self.asm.xmm_vcvt_rr(scratch, scratch, F32x4ConvertI32x4S);
+                self.asm
+                    .xmm_vshift_ir(1, dst, ShiftKind::ShrU, VectorShape::I32x4);
+                self.asm.xmm_vcvt_rr(dst, dst, F32x4ConvertI32x4S);
+                self.asm.xmm_vadd_rr(dst, dst, VectorShape::F32x4);
+                self.asm.xmm_vadd_rr(scratch, dst, VectorShape::F32x4);
+            }
+            F64x2ConvertLowI32x4U => {
+                // Interleaving each integer with the high half of 2^52 builds
+                // the double 2^52 + x, from which 2^52 is then subtracted.
+                self.move_v128(src, dst);
+                self.load_v128_constant(&0x4330_0000u32.to_le_bytes().repeat(4), scratch);
+                self.asm.xmm_unpcklps_rr(scratch, dst);
+                self.load_v128_constant(&0x4330_0000_0000_0000u64.to_le_bytes().repeat(2), scratch);
+                self.asm.xmm_vsub_rr(scratch, dst, VectorShape::F64x2);
+            }
+            I32x4TruncSatF32x4S => {
+                // `cvttps2dq` turns NaN and out-of-range lanes into
+                // 0x80000000. NaNs are zeroed beforehand and the lanes that
+                // were positive before the conversion are flipped to
+                // 0x7fffffff afterwards.
+                self.move_v128(src, dst);
+                self.asm.xmm_mov_rr(dst, scratch, OperandSize::S128);
+                self.asm
+                    .xmm_vcmpp_rr(dst, scratch, FloatCmpKind::Eq, VectorShape::F32x4);
+                self.asm.xmm_vand_rr(scratch, dst);
+                self.asm.xmm_vxor_rr(dst, scratch);
+                self.asm.xmm_vcvtt_rr(dst, dst, VectorShape::F32x4);
+                self.asm.xmm_vand_rr(dst, scratch);
+                self.asm
+                    .xmm_vshift_ir(31, scratch, ShiftKind::ShrS, VectorShape::I32x4);
+                self.asm.xmm_vxor_rr(scratch, dst);
+            }
+            I32x4TruncSatF32x4U => {
+                // Negative and NaN lanes are clamped to zero first. Lanes
+                // below 2^31 are converted directly, the rest are converted
+                // after subtracting 2^31 and added back, saturating the
+                // lanes that still overflow.
+                self.move_v128(src, dst);
+                self.asm.xmm_vxor_rr(scratch, scratch);
+                self.asm.xmm_vmaxp_rr(scratch, dst, VectorShape::F32x4);
+                self.asm.xmm_vcmpeq_rr(scratch, scratch, VectorShape::I32x4);
+                self.asm
+                    .xmm_vshift_ir(1, scratch, ShiftKind::ShrU, VectorShape::I32x4);
+                self.asm.xmm_vcvt_rr(scratch, scratch, F32x4ConvertI32x4S);
+                self.asm.xmm_vcvtt_rr(dst, tmp, VectorShape::F32x4);
+                self.asm.xmm_vsub_rr(scratch, dst, VectorShape::F32x4);
+                self.asm
+                    .xmm_vcmpp_rr(dst, scratch, FloatCmpKind::Le, VectorShape::F32x4);
+                self.asm.xmm_vcvtt_rr(dst, dst, VectorShape::F32x4);
+                self.asm.xmm_vxor_rr(dst, scratch);
+                self.asm.xmm_vxor_rr(dst, dst);
+                self.asm
+                    .xmm_vmax_rr(dst, scratch, VectorShape::I32x4, Signedness::Signed);
+                self.asm.xmm_vadd_rr(scratch, tmp, VectorShape::I32x4);
+                self.asm.xmm_mov_rr(tmp, dst, OperandSize::S128);
+            }
+            I32x4TruncSatF64x2SZero => {
+                // NaN lanes are zeroed and the rest clamped to i32::MAX;
+                // `cvttpd2dq` already produces i32::MIN for the lanes
+                // below it, and zeroes the upper lanes.
+                self.move_v128(src, dst);
+                self.asm.xmm_mov_rr(dst, scratch, OperandSize::S128);
+                self.asm
+                    .xmm_vcmpp_rr(dst, scratch, FloatCmpKind::Eq, VectorShape::F64x2);
+                self.load_v128_constant(&2147483647f64.to_le_bytes().repeat(2), tmp);
+                self.asm.xmm_vand_rr(tmp, scratch);
+                self.asm.xmm_vminp_rr(scratch, dst, VectorShape::F64x2);
+                self.asm.xmm_vcvtt_rr(dst, dst, VectorShape::F64x2);
+            }
+            I32x4TruncSatF64x2UZero => {
+                // The lanes are clamped to [0, u32::MAX], with NaNs going to
+                // zero, and truncated; adding 2^52 then leaves each integer
+                // in the low 32 bits of its lane, which are gathered next to
+                // zeroes.
+                self.move_v128(src, dst);
+                self.asm.xmm_vxor_rr(scratch, scratch);
+                self.asm.xmm_vmaxp_rr(scratch, dst, VectorShape::F64x2);
+                self.load_v128_constant(&4294967295f64.to_le_bytes().repeat(2), tmp);
+                self.asm.xmm_vminp_rr(tmp, dst, VectorShape::F64x2);
+                self.asm
+                    .xmm_vround_rr(dst, dst, RoundingMode::Zero, VectorShape::F64x2);
+                self.load_v128_constant(&0x4330_0000_0000_0000u64.to_le_bytes().repeat(2), tmp);
+                self.asm.xmm_vadd_rr(tmp, dst, VectorShape::F64x2);
+                self.asm.xmm_shufps_rr(scratch, dst, 0b10_00_10_00);
+            }
+        }
+    }
+
+    fn v128_canonicalize_nan(&mut self, reg: Reg, tmp: Reg, shape: VectorShape) {
+        let lane = match shape {
+            VectorShape::F32x4 => OperandSize::S32,
+            VectorShape::F64x2 => OperandSize::S64,
+            _ => unreachable!(),
+        };
+        let nan = lane.canonical_nan().to_le_bytes();
+        let nans = nan[..lane.bytes() as usize].repeat(16 / lane.bytes() as usize);
+        let scratch = regs::scratch_xmm();
+
+        // Build a mask of the NaN lanes, which are the only ones unordered
+        // with themselves, and use it to blend in the canonical NaN.
+        if self.flags.has_avx() {
+            self.asm
+                .xmm_vcmpp_rrr(reg, reg, tmp, FloatCmpKind::Ne, shape);
+        } else {
+            self.asm.xmm_mov_rr(reg, tmp, OperandSize::S128);
+            self.asm.xmm_vcmpp_rr(reg, tmp, FloatCmpKind::Ne, shape);
+        }
+        let addr = self.asm.add_constant(&nans);
+        self.asm.xmm_mov_mr(&addr, scratch, OperandSize::S128);
+        self.asm.xmm_vand_rr(tmp, scratch);
+        self.asm.xmm_vandn_rr(reg, tmp);
+        self.asm.xmm_vor_rr(scratch, tmp);
+        self.asm.xmm_mov_rr(tmp, reg, OperandSize::S128);
+    }
+
+    fn v128_splat(&mut self, dst: Reg, src: Reg, shape: VectorShape) {
+        assert_eq!(dst.class(), RegClass::Float);
+        match shape {
+            VectorShape::I8x16 => {
+                self.asm.gpr_to_xmm(src, dst, OperandSize::S32);
+                // Shuffling with an all-zeros mask replicates the lowest
+                // byte.
+                let scratch = regs::scratch_xmm();
+                self.asm.xmm_vxor_rr(scratch, scratch);
+                self.asm.xmm_pshufb_rr(scratch, dst);
+            }
+            VectorShape::I16x8 => {
+                self.asm.gpr_to_xmm(src, dst, OperandSize::S32);
+                self.asm.xmm_pshuflw_rr(dst, dst, 0);
+                self.asm.xmm_pshufd_rr(dst, dst, 0);
+            }
+            VectorShape::I32x4 => {
+                self.asm.gpr_to_xmm(src, dst, OperandSize::S32);
+                self.asm.xmm_pshufd_rr(dst, dst, 0);
+            }
+            VectorShape::I64x2 => {
+                self.asm.gpr_to_xmm(src, dst, OperandSize::S64);
+                self.asm.xmm_pshufd_rr(dst, dst, 0b01_00_01_00);
+            }
+            VectorShape::F32x4 => self.asm.xmm_pshufd_rr(src, dst, 0),
+            VectorShape::F64x2 => self.asm.xmm_pshufd_rr(src, dst, 0b01_00_01_00),
+        }
+    }
+
+    fn v128_shuffle(&mut self, dst: Reg, lhs: Reg, rhs: Reg, lanes: [u8; 16]) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        // Each operand is shuffled with a mask that selects its own bytes and
+        // zeroes the rest, setting the high bit of the mask byte, and the two
+        // results are combined.
+        let mask = |select: &dyn Fn(u8) -> Option<u8>| -> Vec<u8> {
+            lanes.iter().map(|l| select(*l).unwrap_or(0x80)).collect()
+        };
+        let lhs_mask = mask(&|l| (l < 16).then(|| l));
+        let rhs_mask = mask(&|l| (l >= 16).then(|| l - 16));
+
+        let addr = self.asm.add_constant(&lhs_mask);
+        self.asm.xmm_pshufb_mr(&addr, dst);
+        let addr = self.asm.add_constant(&rhs_mask);
+        self.asm.xmm_pshufb_mr(&addr, rhs);
+        self.asm.xmm_vor_rr(rhs, dst);
+    }
+
+    fn check_simd_support(&self) -> Result<()> {
+        let missing: Vec<&str> = [
+            ("SSSE3", self.flags.has_ssse3()),
+            ("SSE4.1", self.flags.has_sse41()),
+        ]
+        .into_iter()
+        .filter(|(_, enabled)| !enabled)
+        .map(|(name, _)| name)
+        .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "the SIMD proposal requires {} support in Winch",
+                missing.join(" and ")
+            ))
+        }
+    }
+
+    fn check_operator_support(&self, _op: &Operator<'_>) -> Result<()> {
+        Ok(())
+    }
+
+    fn v128_load(&mut self, src: Address, dst: Reg, kind: VectorLoadKind) {
+        match kind {
+            VectorLoadKind::V128 => self.asm.xmm_mov_mr(&src, dst, OperandSize::S128),
+            VectorLoadKind::Extend8x8(ext) => {
+                self.asm.xmm_mov_mr(&src, dst, OperandSize::S64);
+                self.asm.xmm_vextend_rr(dst, dst, VectorShape::I16x8, ext);
+            }
+            VectorLoadKind::Extend16x4(ext) => {
+                self.asm.xmm_mov_mr(&src, dst, OperandSize::S64);
+                self.asm.xmm_vextend_rr(dst, dst, VectorShape::I32x4, ext);
+            }
+            VectorLoadKind::Extend32x2(ext) => {
+                self.asm.xmm_mov_mr(&src, dst, OperandSize::S64);
+                self.asm.xmm_vextend_rr(dst, dst, VectorShape::I64x2, ext);
+            }
+            VectorLoadKind::Splat(size) => match size {
+                OperandSize::S8 | OperandSize::S16 => {
+                    let scratch = regs::scratch();
+                    let (ext, shape) = if size == OperandSize::S8 {
+                        (ExtMode::BL, VectorShape::I8x16)
+                    } else {
+                        (ExtMode::WL, VectorShape::I16x8)
+                    };
+                    self.asm.movzx_mr(&src, scratch, ext);
+                    self.v128_splat(dst, scratch, shape);
+                }
+                OperandSize::S32 => {
+                    self.asm.xmm_mov_mr(&src, dst, size);
+                    self.asm.xmm_pshufd_rr(dst, dst, 0);
+                }
+                OperandSize::S64 => {
+                    self.asm.xmm_mov_mr(&src, dst, size);
+                    self.asm.xmm_pshufd_rr(dst, dst, 0b01_00_01_00);
+                }
+                OperandSize::S128 => unreachable!(),
+            },
+            VectorLoadKind::Zero(size) => self.asm.xmm_mov_mr(&src, dst, size),
+        }
+    }
+
+    fn v128_load_lane(&mut self, src: Address, dst: Reg, lane: u8, size: OperandSize) {
+        let scratch = regs::scratch();
+        match size {
+            OperandSize::S8 => self.asm.movzx_mr(&src, scratch, ExtMode::BL),
+            OperandSize::S16 => self.asm.movzx_mr(&src, scratch, ExtMode::WL),
+            OperandSize::S32 | OperandSize::S64 => self.asm.mov_mr(&src, scratch, size),
+            OperandSize::S128 => unreachable!(),
+        }
+        self.asm.xmm_pinsr_rr(scratch, dst, lane, size);
+    }
+
+    fn v128_store_lane(&mut self, src: Reg, dst: Address, lane: u8, size: OperandSize) {
+        let scratch = regs::scratch();
+        self.asm.xmm_pextr_rr(src, scratch, lane, size);
+        self.asm.mov_rm(scratch, &dst, size);
+    }
+
+    fn v128_extract_lane(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        lane: u8,
+        shape: VectorShape,
+        extend: Option<ExtendKind>,
+    ) {
+        match shape {
+            VectorShape::I8x16 | VectorShape::I16x8 => {
+                let (size, ext) = if shape == VectorShape::I8x16 {
+                    (OperandSize::S8, ExtMode::BL)
+                } else {
+                    (OperandSize::S16, ExtMode::WL)
+                };
+                // `pextrb` and `pextrw` zero-extend the lane.
+                self.asm.xmm_pextr_rr(src, dst, lane, size);
+                if extend == Some(ExtendKind::Signed) {
+                    self.asm.movsx_rr(dst, dst, ext);
+                }
+            }
+            VectorShape::I32x4 => self.asm.xmm_pextr_rr(src, dst, lane, OperandSize::S32),
+            VectorShape::I64x2 => self.asm.xmm_pextr_rr(src, dst, lane, OperandSize::S64),
+            // Float scalars live in the low lane of a vector register, so the
+            // requested lane is shuffled into it; the upper lanes are
+            // irrelevant.
+            VectorShape::F32x4 if lane == 0 => self.move_v128(src, dst),
+            VectorShape::F32x4 => self.asm.xmm_pshufd_rr(src, dst, lane),
+            VectorShape::F64x2 if lane == 0 => self.move_v128(src, dst),
+            VectorShape::F64x2 => self.asm.xmm_pshufd_rr(src, dst, 0b11_10_11_10),
+        }
+    }
+
+    fn v128_replace_lane(&mut self, dst: Reg, src: Reg, lane: u8, shape: VectorShape) {
+        match shape {
+            VectorShape::I8x16 => self.asm.xmm_pinsr_rr(src, dst, lane, OperandSize::S8),
+            VectorShape::I16x8 => self.asm.xmm_pinsr_rr(src, dst, lane, OperandSize::S16),
+            VectorShape::I32x4 => self.asm.xmm_pinsr_rr(src, dst, lane, OperandSize::S32),
+            VectorShape::I64x2 => self.asm.xmm_pinsr_rr(src, dst, lane, OperandSize::S64),
+            VectorShape::F32x4 => self.asm.xmm_insertps_rr(src, dst, lane << 4),
+            VectorShape::F64x2 if lane == 0 => self.asm.xmm_movsd_rr(src, dst),
+            VectorShape::F64x2 => self.asm.xmm_movlhps_rr(src, dst),
+        }
+    }
+
+    fn v128_bitselect(&mut self, dst: Reg, lhs: Reg, rhs: Reg, mask: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vand_rr(mask, dst);
+        self.asm.xmm_vandn_rr(rhs, mask);
+        self.asm.xmm_vor_rr(mask, dst);
+    }
+
+    fn v128_any_true(&mut self, dst: Reg, src: Reg) {
+        self.asm.xmm_ptest_rr(src, src);
+        self.asm.setcc(IntCmpKind::Ne, dst);
+    }
+
+    fn v128_all_true(&mut self, dst: Reg, src: Reg, shape: VectorShape) {
+        // Set the lanes that are zero and check that none is.
+        let scratch = regs::scratch_xmm();
+        self.asm.xmm_vxor_rr(scratch, scratch);
+        self.asm.xmm_vcmpeq_rr(src, scratch, shape);
+        self.asm.xmm_ptest_rr(scratch, scratch);
+        self.asm.setcc(IntCmpKind::Eq, dst);
+    }
+
+    fn v128_bitmask(&mut self, dst: Reg, src: Reg, shape: VectorShape) {
+        if shape == VectorShape::I16x8 {
+            // There's no `movmsk` for 16-bit lanes, so the lanes are first
+            // narrowed to bytes, which preserves their sign. Both halves of
+            // the narrowed vector hold the same bytes, so the upper half of
+            // the mask is dropped.
+            let scratch = regs::scratch_xmm();
+            self.asm.xmm_mov_rr(src, scratch, OperandSize::S128);
+            self.asm
+                .xmm_vpack_rr(scratch, scratch, VectorShape::I8x16, Signedness::Signed);
+            self.asm.xmm_movmsk_rr(scratch, dst, VectorShape::I8x16);
+            self.asm.shift_ir(8, dst, ShiftKind::ShrU, OperandSize::S32);
+        } else {
+            self.asm.xmm_movmsk_rr(src, dst, shape);
+        }
+    }
+
+    fn v128_abs(&mut self, dst: Reg, shape: VectorShape) {
+        match shape {
+            VectorShape::I8x16 | VectorShape::I16x8 | VectorShape::I32x4 => {
+                self.asm.xmm_vabs_rr(dst, dst, shape)
+            }
+            VectorShape::I64x2 => {
+                // Build a mask of the negative lanes from their sign and
+                // compute `(x ^ mask) - mask`.
+                let scratch = regs::scratch_xmm();
+                self.asm.xmm_pshufd_rr(dst, scratch, 0b11_11_01_01);
+                self.asm
+                    .xmm_vshift_ir(31, scratch, ShiftKind::ShrS, VectorShape::I32x4);
+                self.asm.xmm_vxor_rr(scratch, dst);
+                self.asm.xmm_vsub_rr(scratch, dst, VectorShape::I64x2);
+            }
+            VectorShape::F32x4 => {
+                self.v128_and_constant(&0x7fff_ffffu32.to_le_bytes().repeat(4), dst)
+            }
+            VectorShape::F64x2 => {
+                self.v128_and_constant(&0x7fff_ffff_ffff_ffffu64.to_le_bytes().repeat(2), dst)
+            }
+        }
+    }
+
+    fn v128_neg(&mut self, dst: Reg, shape: VectorShape) {
+        let scratch = regs::scratch_xmm();
+        match shape {
+            VectorShape::F32x4 => {
+                self.load_v128_constant(&0x8000_0000u32.to_le_bytes().repeat(4), scratch);
+                self.asm.xmm_vxor_rr(scratch, dst);
+            }
+            VectorShape::F64x2 => {
+                self.load_v128_constant(&0x8000_0000_0000_0000u64.to_le_bytes().repeat(2), scratch);
+                self.asm.xmm_vxor_rr(scratch, dst);
+            }
+            _ => {
+                self.asm.xmm_vxor_rr(scratch, scratch);
+                self.asm.xmm_vsub_rr(dst, scratch, shape);
+                self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+            }
+        }
+    }
+
+    fn v128_popcnt(&mut self, dst: Reg, tmp: Reg) {
+        // Each nibble indexes a lookup table of bit counts through `pshufb`,
+        // and the counts of both nibbles of each byte are added.
+        const COUNTS: [u8; 16] = [0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4];
+        let scratch = regs::scratch_xmm();
+        self.load_v128_constant(&[0x0f; 16], tmp);
+        self.asm.xmm_mov_rr(dst, scratch, OperandSize::S128);
+        self.asm
+            .xmm_vshift_ir(4, scratch, ShiftKind::ShrU, VectorShape::I16x8);
+        self.asm.xmm_vand_rr(tmp, scratch);
+        self.asm.xmm_vand_rr(tmp, dst);
+        self.load_v128_constant(&COUNTS, tmp);
+        self.asm.xmm_pshufb_rr(dst, tmp);
+        self.load_v128_constant(&COUNTS, dst);
+        self.asm.xmm_pshufb_rr(scratch, dst);
+        self.asm.xmm_vadd_rr(tmp, dst, VectorShape::I8x16);
+    }
+
+    fn v128_sqrt(&mut self, dst: Reg, shape: VectorShape) {
+        self.asm.xmm_vsqrt_rr(dst, dst, shape);
+    }
+
+    fn v128_round(&mut self, dst: Reg, mode: RoundingMode, shape: VectorShape) {
+        self.asm.xmm_vround_rr(dst, dst, mode, shape);
+    }
+
+    fn v128_add_sat(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape, sign: Signedness) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vadd_sat_rr(rhs, dst, shape, sign);
+    }
+
+    fn v128_sub_sat(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape, sign: Signedness) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vsub_sat_rr(rhs, dst, shape, sign);
+    }
+
+    fn v128_min(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape, sign: Signedness) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vmin_rr(rhs, dst, shape, sign);
+    }
+
+    fn v128_max(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape, sign: Signedness) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vmax_rr(rhs, dst, shape, sign);
+    }
+
+    fn v128_float_min(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        // `minps` returns its second operand when either lane is a NaN or
+        // both are zero. Computing it in both directions and or-ing the
+        // results propagates NaNs and -0.0; the NaN lanes are then turned
+        // into quiet NaNs by clearing their payload bits below the quiet
+        // bit.
+        let scratch = regs::scratch_xmm();
+        self.asm.xmm_mov_rr(rhs, scratch, OperandSize::S128);
+        self.asm.xmm_vminp_rr(dst, scratch, shape);
+        self.asm.xmm_vminp_rr(rhs, dst, shape);
+        self.asm.xmm_vor_rr(scratch, dst);
+        self.asm.xmm_mov_rr(dst, rhs, OperandSize::S128);
+        self.asm.xmm_vcmpunord_rr(scratch, rhs, shape);
+        self.asm.xmm_vor_rr(rhs, dst);
+        self.shift_nan_mask(rhs, shape);
+        self.asm.xmm_vandn_rr(dst, rhs);
+        self.asm.xmm_mov_rr(rhs, dst, OperandSize::S128);
+    }
+
+    fn v128_float_max(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        // As with the minimum, `maxps` is computed in both directions. The
+        // difference between the results marks the NaN lanes and the lanes
+        // of zeroes with different signs, which is folded in so that NaNs
+        // propagate and +0.0 wins.
+        let scratch = regs::scratch_xmm();
+        self.asm.xmm_mov_rr(rhs, scratch, OperandSize::S128);
+        self.asm.xmm_vmaxp_rr(dst, scratch, shape);
+        self.asm.xmm_vmaxp_rr(rhs, dst, shape);
+        self.asm.xmm_vxor_rr(dst, scratch);
+        self.asm.xmm_vor_rr(scratch, dst);
+        self.asm.xmm_mov_rr(dst, rhs, OperandSize::S128);
+        self.asm.xmm_vsub_rr(scratch, rhs, shape);
+        self.asm.xmm_mov_rr(dst, scratch, OperandSize::S128);
+        self.asm.xmm_vcmpunord_rr(dst, scratch, shape);
+        self.shift_nan_mask(scratch, shape);
+        self.asm.xmm_vandn_rr(rhs, scratch);
+        self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+    }
+
+    fn v128_pmin(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        // `minps` already returns its second operand unless the first is
+        // strictly less than it.
+        self.asm.xmm_vminp_rr(dst, rhs, shape);
+        self.asm.xmm_mov_rr(rhs, dst, OperandSize::S128);
+    }
+
+    fn v128_pmax(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vmaxp_rr(dst, rhs, shape);
+        self.asm.xmm_mov_rr(rhs, dst, OperandSize::S128);
+    }
+
+    fn v128_avgr(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vavg_rr(rhs, dst, shape);
+    }
+
+    fn v128_q15mulr_sat(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        // `pmulhrsw` only overflows for `-1.0 * -1.0`, producing 0x8000
+        // instead of 0x7fff, which is fixed up by flipping those lanes.
+        let scratch = regs::scratch_xmm();
+        self.asm.xmm_pmulhrsw_rr(rhs, dst);
+        self.load_v128_constant(&0x8000u16.to_le_bytes().repeat(8), scratch);
+        self.asm.xmm_vcmpeq_rr(dst, scratch, VectorShape::I16x8);
+        self.asm.xmm_vxor_rr(scratch, dst);
+    }
+
+    fn v128_dot(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_pmaddwd_rr(rhs, dst);
+    }
+
+    fn v128_extmul(
+        &mut self,
+        dst: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        shape: VectorShape,
+        half: VectorHalf,
+        kind: ExtendKind,
+    ) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        if shape == VectorShape::I64x2 {
+            // `pmuldq` multiplies the even 32-bit lanes, so the lanes of the
+            // requested half are spread to them.
+            let imm = match half {
+                VectorHalf::Low => 0b01_01_00_00,
+                VectorHalf::High => 0b11_11_10_10,
+            };
+            let sign = match kind {
+                ExtendKind::Signed => Signedness::Signed,
+                ExtendKind::Unsigned => Signedness::Unsigned,
+            };
+            self.asm.xmm_pshufd_rr(dst, dst, imm);
+            self.asm.xmm_pshufd_rr(rhs, rhs, imm);
+            self.asm.xmm_pmuldq_rr(rhs, dst, sign);
+        } else {
+            self.v128_extend(dst, shape, half, kind);
+            self.v128_extend(rhs, shape, half, kind);
+            self.asm.xmm_vmul_rr(rhs, dst, shape);
+        }
+    }
+
+    fn v128_extend(&mut self, dst: Reg, shape: VectorShape, half: VectorHalf, kind: ExtendKind) {
+        if half == VectorHalf::High {
+            self.asm.xmm_pshufd_rr(dst, dst, 0b11_10_11_10);
+        }
+        self.asm.xmm_vextend_rr(dst, dst, shape, kind);
+    }
+
+    fn v128_extadd_pairwise(&mut self, dst: Reg, shape: VectorShape, kind: ExtendKind) {
+        // The pairs are added by multiplying them by one with the
+        // `pmadd` instructions.
+        let scratch = regs::scratch_xmm();
+        match (shape, kind) {
+            (VectorShape::I16x8, ExtendKind::Signed) => {
+                // `pmaddubsw` treats its first operand as unsigned.
+                self.load_v128_constant(&[1; 16], scratch);
+                self.asm.xmm_pmaddubsw_rr(dst, scratch);
+                self.asm.xmm_mov_rr(scratch, dst, OperandSize::S128);
+            }
+            (VectorShape::I16x8, ExtendKind::Unsigned) => {
+                self.load_v128_constant(&[1; 16], scratch);
+                self.asm.xmm_pmaddubsw_rr(scratch, dst);
+            }
+            (VectorShape::I32x4, ExtendKind::Signed) => {
+                self.load_v128_constant(&1u16.to_le_bytes().repeat(8), scratch);
+                self.asm.xmm_pmaddwd_rr(scratch, dst);
+            }
+            (VectorShape::I32x4, ExtendKind::Unsigned) => {
+                // `pmaddwd` only multiplies signed lanes, so the lanes are
+                // biased by -0x8000 first and the bias of each pair added
+                // back afterwards.
+                self.load_v128_constant(&0x8000u16.to_le_bytes().repeat(8), scratch);
+                self.asm.xmm_vxor_rr(scratch, dst);
+                self.load_v128_constant(&1u16.to_le_bytes().repeat(8), scratch);
+                self.asm.xmm_pmaddwd_rr(scratch, dst);
+                self.load_v128_constant(&0x1_0000u32.to_le_bytes().repeat(4), scratch);
+                self.asm.xmm_vadd_rr(scratch, dst, VectorShape::I32x4);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn v128_narrow(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape, sign: Signedness) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.xmm_vpack_rr(rhs, dst, shape, sign);
+    }
+
+    fn v128_shift(&mut self, context: &mut CodeGenContext, kind: ShiftKind, shape: VectorShape) {
+        let lane_bits = match shape {
+            VectorShape::I8x16 => 8,
+            VectorShape::I16x8 => 16,
+            VectorShape::I32x4 => 32,
+            VectorShape::I64x2 => 64,
+            _ => unreachable!(),
+        };
+        let amount = context.pop_to_reg(self, None);
+        let operand = context.pop_to_reg(self, None);
+        let dst = operand.reg;
+        let count = regs::scratch_xmm();
+        self.asm.and_ir(lane_bits - 1, amount.reg, OperandSize::S32);
+
+        match (kind, shape) {
+            (ShiftKind::Shl | ShiftKind::ShrU, VectorShape::I8x16) => {
+                // There are no 8-bit shifts, so the bytes are shifted as
+                // 16-bit lanes and the bits that crossed into the
+                // neighbouring byte masked out.
+                self.asm.gpr_to_xmm(amount.reg, count, OperandSize::S32);
+                self.asm.xmm_vshift_rr(count, dst, kind, VectorShape::I16x8);
+                let mask = context.reg_for_class(RegClass::Float, self);
+                self.asm.xmm_vcmpeq_rr(mask, mask, VectorShape::I16x8);
+                self.asm
+                    .xmm_vshift_rr(count, mask, kind, VectorShape::I16x8);
+                if kind == ShiftKind::Shl {
+                    self.asm
+                        .xmm_vshift_ir(8, mask, ShiftKind::Shl, VectorShape::I16x8);
+                }
+                self.asm
+                    .xmm_vshift_ir(8, mask, ShiftKind::ShrU, VectorShape::I16x8);
+                self.asm
+                    .xmm_vpack_rr(mask, mask, VectorShape::I8x16, Signedness::Unsigned);
+                self.asm.xmm_vand_rr(mask, dst);
+                context.free_reg(mask);
+            }
+            (ShiftKind::ShrS, VectorShape::I8x16) => {
+                // Each byte is duplicated into a 16-bit lane, which is then
+                // shifted by 8 more bits, sign-extending the byte, and
+                // narrowed back.
+                self.asm.add_ir(8, amount.reg, OperandSize::S32);
+                self.asm.gpr_to_xmm(amount.reg, count, OperandSize::S32);
+                let tmp = context.reg_for_class(RegClass::Float, self);
+                self.asm.xmm_mov_rr(dst, tmp, OperandSize::S128);
+                self.asm.xmm_punpckbw_rr(tmp, tmp, false);
+                self.asm.xmm_punpckbw_rr(dst, dst, true);
+                self.asm.xmm_vshift_rr(count, tmp, kind, VectorShape::I16x8);
+                self.asm.xmm_vshift_rr(count, dst, kind, VectorShape::I16x8);
+                self.asm
+                    .xmm_vpack_rr(dst, tmp, VectorShape::I8x16, Signedness::Signed);
+                self.asm.xmm_mov_rr(tmp, dst, OperandSize::S128);
+                context.free_reg(tmp);
+            }
+            (ShiftKind::ShrS, VectorShape::I64x2) => {
+                // There's no 64-bit arithmetic shift before AVX-512; the
+                // shift is logical and the sign is extended with
+                // `(x ^ m) - m`, `m` being the shifted sign bit.
+                self.asm.gpr_to_xmm(amount.reg, count, OperandSize::S32);
+                let tmp = context.reg_for_class(RegClass::Float, self);
+                self.load_v128_constant(&0x8000_0000_0000_0000u64.to_le_bytes().repeat(2), tmp);
+                self.asm.xmm_vshift_rr(count, tmp, ShiftKind::ShrU, shape);
+                self.asm.xmm_vshift_rr(count, dst, ShiftKind::ShrU, shape);
+                self.asm.xmm_vxor_rr(tmp, dst);
+                self.asm.xmm_vsub_rr(tmp, dst, shape);
+                context.free_reg(tmp);
+            }
+            _ => {
+                self.asm.gpr_to_xmm(amount.reg, count, OperandSize::S32);
+                self.asm.xmm_vshift_rr(count, dst, kind, shape);
+            }
+        }
+
+        context.free_reg(amount);
+        context.stack.push(operand.into());
+    }
+
+    fn v128_swizzle(&mut self, dst: Reg, lhs: Reg, rhs: Reg) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        // `pshufb` zeroes the lanes whose index has the high bit set, so the
+        // out-of-range indices are pushed up there with a saturating add,
+        // which leaves the low bits of the in-range ones untouched.
+        let scratch = regs::scratch_xmm();
+        self.load_v128_constant(&[0x70; 16], scratch);
+        self.asm
+            .xmm_vadd_sat_rr(scratch, rhs, VectorShape::I8x16, Signedness::Unsigned);
+        self.asm.xmm_pshufb_rr(rhs, dst);
+    }
+
     fn and(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
         Self::ensure_two_argument_form(&dst, &lhs);
+        // Masking with all ones leaves the value unchanged.
+        if rhs.is_int_imm(-1) {
+            return;
+        }
         match (rhs, dst) {
             (RegImm::Imm(imm), reg) => {
                 if let Some(v) = imm.to_i32() {
@@ -515,6 +1725,10 @@ impl Masm for MacroAssembler {
 
     fn or(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
         Self::ensure_two_argument_form(&dst, &lhs);
+        // Or-ing zero leaves the value unchanged.
+        if rhs.is_int_imm(0) {
+            return;
+        }
         match (rhs, dst) {
             (RegImm::Imm(imm), reg) => {
                 if let Some(v) = imm.to_i32() {
@@ -532,8 +1746,30 @@ impl Masm for MacroAssembler {
         }
     }
 
+    fn alu_mem(
+        &mut self,
+        dst: Reg,
+        lhs: Reg,
+        rhs: Self::Address,
+        kind: AluKind,
+        size: OperandSize,
+    ) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        match kind {
+            AluKind::Add => self.asm.add_mr(&rhs, dst, size),
+            AluKind::Sub => self.asm.sub_mr(&rhs, dst, size),
+            AluKind::And => self.asm.and_mr(&rhs, dst, size),
+            AluKind::Or => self.asm.or_mr(&rhs, dst, size),
+            AluKind::Xor => self.asm.xor_mr(&rhs, dst, size),
+        }
+    }
+
     fn xor(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize) {
         Self::ensure_two_argument_form(&dst, &lhs);
+        // Xor-ing zero leaves the value unchanged.
+        if rhs.is_int_imm(0) {
+            return;
+        }
         match (rhs, dst) {
             (RegImm::Imm(imm), reg) => {
                 if let Some(v) = imm.to_i32() {
@@ -559,21 +1795,13 @@ impl Masm for MacroAssembler {
                 .stack
                 .pop_i32_const()
                 .expect("i32 const value at stack top");
-            let typed_reg = context.pop_to_reg(self, None);
-
-            self.asm.shift_ir(val as u8, typed_reg.into(), kind, size);
-
-            context.stack.push(typed_reg.into());
+            self.shift_by_const(context, val as u8, kind, size);
         } else if size == OperandSize::S64 && top.is_i64_const() {
             let val = context
                 .stack
                 .pop_i64_const()
                 .expect("i64 const value at stack top");
-            let typed_reg = context.pop_to_reg(self, None);
-
-            self.asm.shift_ir(val as u8, typed_reg.into(), kind, size);
-
-            context.stack.push(typed_reg.into());
+            self.shift_by_const(context, val as u8, kind, size);
         } else {
             // Number of bits to shift must be in the CL register.
             let src = context.pop_to_reg(self, Some(regs::rcx()));
@@ -586,6 +1814,11 @@ impl Masm for MacroAssembler {
         }
     }
 
+    fn shift_ir(&mut self, dst: Reg, imm: u64, lhs: Reg, kind: ShiftKind, size: OperandSize) {
+        Self::ensure_two_argument_form(&dst, &lhs);
+        self.asm.shift_ir(imm as u8, dst, kind, size)
+    }
+
     fn div(&mut self, context: &mut CodeGenContext, kind: DivKind, size: OperandSize) {
         // Allocate rdx:rax.
         let rdx = context.reg(regs::rdx(), self);
@@ -630,19 +1863,45 @@ impl Masm for MacroAssembler {
         context.stack.push(Val::reg(rdx, divisor.ty));
     }
 
-    fn epilogue(&mut self, locals_size: u32) {
-        assert!(self.sp_offset == locals_size);
+    fn epilogue(&mut self, locals_size: u32, clobbers: &[(Reg, OperandSize)]) {
+        let (offsets, clobber_size) = clobber_layout(clobbers);
+        assert!(self.sp_offset == locals_size + clobber_size);
 
+        for (&(reg, size), offset) in clobbers.iter().zip(offsets) {
+            self.load(self.address_at_sp(locals_size + offset), reg, size);
+        }
         let rsp = rsp();
-        if locals_size > 0 {
-            self.asm.add_ir(locals_size as i32, rsp, OperandSize::S64);
+        if self.sp_offset > 0 {
+            self.asm
+                .add_ir(self.sp_offset as i32, rsp, OperandSize::S64);
         }
         self.asm.pop_r(rbp());
         self.asm.ret();
     }
 
-    fn finalize(self) -> MachBufferFinalized<Final> {
-        self.asm.finalize()
+    fn current_code_offset(&self) -> CodeOffset {
+        self.asm.buffer().cur_offset()
+    }
+
+    fn constant_pool_size(&self) -> u32 {
+        self.asm.constant_pool_size()
+    }
+
+    fn start_source_loc(&mut self, loc: RelSourceLoc) {
+        self.asm.buffer_mut().start_srcloc(loc);
+    }
+
+    fn end_source_loc(&mut self) {
+        self.asm.buffer_mut().end_srcloc();
+    }
+
+    fn finalize(mut self, base: Option<SourceLoc>) -> MachBufferFinalized<Final> {
+        if let Some((imm, sp_offset)) = self.stack_check.take() {
+            let bytes = self.max_sp_offset - sp_offset;
+            let bytes = i32::try_from(bytes).expect("stack usage should fit in an i32");
+            self.asm.patch_imm32(imm, bytes);
+        }
+        self.asm.finalize(base)
     }
 
     fn address_at_reg(&self, reg: Reg, offset: u32) -> Self::Address {
@@ -651,6 +1910,11 @@ impl Masm for MacroAssembler {
 
     fn cmp(&mut self, src: RegImm, dst: Reg, size: OperandSize) {
         match src {
+            // Comparing against zero sets the same flags as testing the
+            // value against itself, which has a shorter encoding.
+            RegImm::Imm(_) if src.is_int_imm(0) => {
+                self.asm.test_rr(dst, dst, size);
+            }
             RegImm::Imm(imm) => {
                 if let Some(v) = imm.to_i32() {
                     self.asm.cmp_ir(v, dst, size);
@@ -727,13 +1991,14 @@ impl Masm for MacroAssembler {
             let scratch = regs::scratch();
 
             // Use the following approach:
-            // dst = size.num_bits() - bsr(src) - is_not_zero
-            //     = size.num.bits() + -bsr(src) - is_not_zero.
+            // dst = (size.num_bits() - 1) - bsr(src), where bsr(0) = -1.
+            // BSR leaves its destination undefined and sets the zero flag
+            // when the source is 0, so select -1 with a cmov in that case.
             self.asm.bsr(src.into(), dst.into(), size);
-            self.asm.setcc(IntCmpKind::Ne, scratch.into());
+            self.asm.mov_ir(u64::MAX, scratch, size);
+            self.asm.cmov(scratch, dst, IntCmpKind::Eq, size);
             self.asm.neg(dst, dst, size);
-            self.asm.add_ir(size.num_bits(), dst, size);
-            self.asm.sub_rr(scratch, dst, size);
+            self.asm.add_ir(size.num_bits() - 1, dst, size);
         }
     }
 
@@ -743,17 +2008,13 @@ impl Masm for MacroAssembler {
         } else {
             let scratch = regs::scratch();
 
-            // Use the following approach:
-            // dst = bsf(src) + (is_zero * size.num_bits())
-            //     = bsf(src) + (is_zero << size.log2()).
-            // BSF outputs the correct value for every value except 0.
-            // When the value is 0, BSF outputs 0, correct output for ctz is
-            // the number of bits.
+            // BSF outputs the correct value for every value except 0, for
+            // which it leaves its destination undefined and sets the zero
+            // flag; the correct output for ctz is then the number of bits,
+            // selected with a cmov.
             self.asm.bsf(src.into(), dst.into(), size);
-            self.asm.setcc(IntCmpKind::Eq, scratch.into());
-            self.asm
-                .shift_ir(size.log2(), scratch, ShiftKind::Shl, size);
-            self.asm.add_rr(scratch, dst, size);
+            self.asm.mov_ir(size.num_bits() as u64, scratch, size);
+            self.asm.cmov(scratch, dst, IntCmpKind::Eq, size);
         }
     }
 
@@ -763,8 +2024,7 @@ impl Masm for MacroAssembler {
     }
 
     fn bind(&mut self, label: MachLabel) {
-        let buffer = self.asm.buffer_mut();
-        buffer.bind_label(label, &mut Default::default());
+        self.asm.bind_label(label);
     }
 
     fn branch(
@@ -867,10 +2127,23 @@ impl Masm for MacroAssembler {
         self.asm.trap(TrapCode::UnreachableCodeReached)
     }
 
+    fn trap(&mut self, code: TrapCode) {
+        self.asm.trap(code)
+    }
+
     fn trapif(&mut self, cc: IntCmpKind, code: TrapCode) {
         self.asm.trapif(cc, code);
     }
 
+    fn check_stack(&mut self, limit: Reg) {
+        let imm = self.asm.add_ir_patchable(limit, OperandSize::S64);
+        self.stack_check = Some((imm, self.sp_offset));
+        // The carry flag is set if the addition overflowed.
+        self.asm.trapif(IntCmpKind::LtU, TrapCode::StackOverflow);
+        self.asm.cmp_rr(limit, rsp(), OperandSize::S64);
+        self.asm.trapif(IntCmpKind::LtU, TrapCode::StackOverflow);
+    }
+
     fn trapz(&mut self, src: Reg, code: TrapCode) {
         self.asm.test_rr(src, src, self.ptr_size);
         self.asm.trapif(IntCmpKind::Eq, code);
@@ -905,6 +2178,8 @@ impl MacroAssembler {
     ) -> Self {
         Self {
             sp_offset: 0,
+            max_sp_offset: 0,
+            stack_check: None,
             asm: Assembler::new(shared_flags.clone(), isa_flags.clone()),
             flags: isa_flags,
             shared_flags,
@@ -914,6 +2189,7 @@ impl MacroAssembler {
 
     fn increment_sp(&mut self, bytes: u32) {
         self.sp_offset += bytes;
+        self.max_sp_offset = self.max_sp_offset.max(self.sp_offset);
     }
 
     fn decrement_sp(&mut self, bytes: u32) {
@@ -934,6 +2210,26 @@ impl MacroAssembler {
         }
     }
 
+    /// Shifts the value at the top of the value stack by a constant amount.
+    fn shift_by_const(
+        &mut self,
+        context: &mut CodeGenContext,
+        amount: u8,
+        kind: ShiftKind,
+        size: OperandSize,
+    ) {
+        // The amount is taken modulo the operand width, so shifting or
+        // rotating by a multiple of it leaves the value unchanged, in which
+        // case the value is left as is in the value stack.
+        if amount as i32 % size.num_bits() == 0 {
+            return;
+        }
+
+        let typed_reg = context.pop_to_reg(self, None);
+        self.asm.shift_ir(amount, typed_reg.into(), kind, size);
+        context.stack.push(typed_reg.into());
+    }
+
     fn handle_invalid_operand_combination<T>(src: impl Into<RegImm>, dst: impl Into<RegImm>) -> T {
         panic!(
             "Invalid operand combination; src={:?}, dst={:?}",
@@ -950,4 +2246,193 @@ impl MacroAssembler {
             lhs
         );
     }
+
+    /// Moves the vector in src to dst, unless they're the same register.
+    fn move_v128(&mut self, src: Reg, dst: Reg) {
+        if src != dst {
+            self.asm.xmm_mov_rr(src, dst, OperandSize::S128);
+        }
+    }
+
+    /// Loads a 128-bit constant into dst.
+    fn load_v128_constant(&mut self, bytes: &[u8], dst: Reg) {
+        let addr = self.asm.add_constant(bytes);
+        self.asm.xmm_mov_mr(&addr, dst, OperandSize::S128);
+    }
+
+    /// Ands dst with a 128-bit constant.
+    fn v128_and_constant(&mut self, bytes: &[u8], dst: Reg) {
+        let scratch = regs::scratch_xmm();
+        self.load_v128_constant(bytes, scratch);
+        self.asm.xmm_vand_rr(scratch, dst);
+    }
+
+    /// Shifts a mask of NaN lanes right so that it covers the payload bits
+    /// below the quiet bit.
+    fn shift_nan_mask(&mut self, mask: Reg, shape: VectorShape) {
+        match shape {
+            VectorShape::F32x4 => {
+                self.asm
+                    .xmm_vshift_ir(10, mask, ShiftKind::ShrU, VectorShape::I32x4)
+            }
+            VectorShape::F64x2 => {
+                self.asm
+                    .xmm_vshift_ir(13, mask, ShiftKind::ShrU, VectorShape::I64x2)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Lane-wise signed greater than comparison of dst and rhs. The contents
+    /// of `rhs` may be clobbered.
+    fn v128_gt_s(&mut self, dst: Reg, rhs: Reg, shape: VectorShape) {
+        if shape != VectorShape::I64x2 || self.flags.has_sse42() {
+            self.asm.xmm_vcmpgt_rr(rhs, dst, shape);
+            return;
+        }
+
+        // Without `pcmpgtq`, the sign of `rhs - dst` gives the result, unless
+        // the subtraction overflows, which can only happen when the operands'
+        // signs differ; in that case the sign of `rhs` gives it.
+        let scratch = regs::scratch_xmm();
+        self.asm.xmm_mov_rr(rhs, scratch, OperandSize::S128);
+        self.asm.xmm_vsub_rr(dst, scratch, shape);
+        self.asm.xmm_vxor_rr(rhs, dst);
+        self.asm.xmm_vxor_rr(scratch, rhs);
+        self.asm.xmm_vand_rr(rhs, dst);
+        self.asm.xmm_vxor_rr(scratch, dst);
+        self.asm
+            .xmm_vshift_ir(31, dst, ShiftKind::ShrS, VectorShape::I32x4);
+        self.asm.xmm_pshufd_rr(dst, dst, 0b11_11_01_01);
+    }
+
+    /// Zero-extends the low `size` bytes of `reg` to `dst_size`, if `size` is
+    /// narrower.
+    fn zero_extend_narrow(&mut self, reg: Reg, size: OperandSize, dst_size: OperandSize) {
+        match (size, dst_size) {
+            (OperandSize::S8, _) => self.asm.movzx_rr(reg, reg, ExtMode::BQ),
+            (OperandSize::S16, _) => self.asm.movzx_rr(reg, reg, ExtMode::WQ),
+            (OperandSize::S32, OperandSize::S64) => self.asm.mov_rr(reg, reg, OperandSize::S32),
+            _ => {}
+        }
+    }
+
+    /// Computes the address of a heap access of `size` bytes at
+    /// `index + offset`, trapping if it's out of bounds and, when
+    /// `check_alignment` is set, if it isn't naturally aligned.
+    fn checked_heap_address(
+        &mut self,
+        index: Reg,
+        base: Reg,
+        heap_data: &HeapData,
+        offset: u64,
+        size: OperandSize,
+        check_alignment: bool,
+        context: &mut CodeGenContext,
+    ) -> Address {
+        let vmctx = <X64ABI as ABI>::vmctx_reg();
+        let scratch = regs::scratch();
+        let bytes = size.bytes();
+
+        let memory64 = heap_data.ty == WasmType::I64;
+
+        // The explicit bounds check of a static heap is skipped, as in
+        // Cranelift, if any 32-bit index plus the static offset and the
+        // access size lands either in the heap or in its guard pages:
+        // out-of-bounds accesses then fault and are reported as heap
+        // out-of-bounds traps. 64-bit indices are always checked.
+        let offset_and_size = offset.saturating_add(u64::from(bytes));
+        let bounds_check = match heap_data.style {
+            HeapStyle::Static { bound }
+                if !memory64
+                    && u64::from(u32::MAX)
+                        <= bound
+                            .saturating_add(heap_data.offset_guard_size)
+                            .saturating_sub(offset_and_size) =>
+            {
+                None
+            }
+            style => Some((style, context.any_gpr(self))),
+        };
+        let spectre_mitigation = self.shared_flags.enable_heap_access_spectre_mitigation();
+        let zero = (bounds_check.is_some() && spectre_mitigation).then(|| context.any_gpr(self));
+
+        if let Some(offset) = heap_data.import_from {
+            self.asm
+                .mov_mr(&self.address_at_vmctx(offset), base, self.ptr_size);
+        } else {
+            self.asm.mov_rr(vmctx, base, self.ptr_size);
+        };
+
+        // Add the static offset to the index to obtain the effective
+        // address. A 32-bit index is zero-extended first, so the sum can't
+        // overflow 64 bits; a 64-bit one traps if it does, since `add` sets
+        // the carry flag, the unsigned less-than condition, on overflow.
+        if !memory64 {
+            self.asm.mov_rr(index, index, OperandSize::S32);
+        }
+        if offset != 0 {
+            self.asm.mov_ir(offset, scratch, OperandSize::S64);
+            self.asm.add_rr(scratch, index, OperandSize::S64);
+            if memory64 {
+                self.asm.trapif(IntCmpKind::LtU, TrapCode::HeapOutOfBounds);
+            }
+        }
+
+        // Atomic accesses must be naturally aligned; the alignment check
+        // takes precedence over the bounds check.
+        if check_alignment {
+            self.asm
+                .mov_ir(u64::from(bytes - 1), scratch, OperandSize::S64);
+            self.asm.test_rr(scratch, index, OperandSize::S64);
+            self.asm.trapif(IntCmpKind::Ne, TrapCode::HeapMisaligned);
+        }
+
+        if let Some(zero) = zero {
+            // Zero the register ahead of the comparison below, since `xor`
+            // clobbers the flags.
+            self.asm.xor_rr(zero, zero, OperandSize::S32);
+        }
+
+        // OOB check.
+        if let Some((style, bound)) = bounds_check {
+            self.asm.mov_rr(index, scratch, OperandSize::S64);
+            self.asm.add_ir(bytes as i32, scratch, OperandSize::S64);
+            if memory64 {
+                self.asm.trapif(IntCmpKind::LtU, TrapCode::HeapOutOfBounds);
+            }
+            match style {
+                HeapStyle::Dynamic => self.asm.mov_mr(
+                    &self.address_at_reg(base, heap_data.current_length_offset),
+                    bound,
+                    self.ptr_size,
+                ),
+                // Accesses past the current length of a static heap but
+                // within its bound fault, so the bound can be used instead
+                // of loading the length.
+                HeapStyle::Static { bound: bytes } => {
+                    self.asm.mov_ir(bytes, bound, OperandSize::S64)
+                }
+            }
+            self.asm.cmp_rr(bound, scratch, self.ptr_size);
+            self.asm.trapif(IntCmpKind::GtU, TrapCode::HeapOutOfBounds);
+            context.free_reg(bound);
+        }
+
+        self.asm.mov_mr(
+            &self.address_at_reg(base, heap_data.offset),
+            base,
+            self.ptr_size,
+        );
+        if let Some(zero) = zero {
+            // Like Cranelift, clamp the address to null in case the access is
+            // out of bounds, so that a misspeculation of the bounds check
+            // can't access memory outside of the heap.
+            self.asm.cmov(zero, base, IntCmpKind::GtU, self.ptr_size);
+            self.asm
+                .cmov(zero, index, IntCmpKind::GtU, OperandSize::S64);
+            context.free_reg(zero);
+        }
+        Address::heap(base, index)
+    }
 }
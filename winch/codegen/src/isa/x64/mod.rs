@@ -4,7 +4,7 @@ use crate::{
 };
 
 use crate::frame::{DefinedLocals, Frame};
-use crate::isa::{x64::masm::MacroAssembler as X64Masm, CallingConvention};
+use crate::isa::x64::masm::MacroAssembler as X64Masm;
 use crate::masm::MacroAssembler;
 use crate::regalloc::RegAlloc;
 use crate::stack::Stack;
@@ -14,12 +14,20 @@ use crate::{
     regset::RegBitSet,
 };
 use anyhow::Result;
+use cranelift_codegen::isa::unwind::{UnwindInfo, UnwindInfoKind};
 use cranelift_codegen::settings::{self, Flags};
 use cranelift_codegen::{isa::x64::settings as x64_settings, Final, MachBufferFinalized};
 use cranelift_codegen::{MachTextSectionBuilder, TextSectionBuilder};
 use target_lexicon::Triple;
 use wasmparser::{FuncValidator, FunctionBody, ValidatorResources};
-use wasmtime_environ::{ModuleTranslation, ModuleTypes, VMOffsets, WasmFuncType};
+use wasmtime_environ::{
+    FunctionCodegenStats, ModuleTranslation, ModuleTypes, VMOffsets, WasmFuncType,
+};
+#[cfg(feature = "component-model")]
+use {
+    crate::ComponentAbi,
+    wasmtime_environ::component::{ComponentTranslation, ComponentTypes, TrampolineIndex},
+};
 
 use self::regs::{ALL_FPR, ALL_GPR, MAX_FPR, MAX_GPR, NON_ALLOCATABLE_FPR, NON_ALLOCATABLE_GPR};
 
@@ -94,6 +102,7 @@ impl TargetIsa for X64 {
         types: &ModuleTypes,
         builtins: &mut BuiltinFunctions,
         validator: &mut FuncValidator<ValidatorResources>,
+        stats: Option<&mut FunctionCodegenStats>,
     ) -> Result<MachBufferFinalized<Final>> {
         let pointer_bytes = self.pointer_bytes();
         let vmoffsets = VMOffsets::new(pointer_bytes, &translation.module);
@@ -105,7 +114,7 @@ impl TargetIsa for X64 {
             self.isa_flags.clone(),
         );
         let stack = Stack::new();
-        let abi_sig = abi::X64ABI::sig(sig, &CallingConvention::Default);
+        let abi_sig = abi::X64ABI::wasm_sig(sig);
 
         let defined_locals = DefinedLocals::new(translation, &mut body, validator)?;
         let frame = Frame::new::<abi::X64ABI>(&abi_sig, &defined_locals)?;
@@ -123,11 +132,31 @@ impl TargetIsa for X64 {
         let regalloc = RegAlloc::from(gpr, fpr);
         let env = FuncEnv::new(&vmoffsets, translation, types);
         let codegen_context = CodeGenContext::new(regalloc, stack, frame, builtins, &vmoffsets);
-        let mut codegen = CodeGen::new(&mut masm, codegen_context, env, abi_sig);
+        let mut codegen = CodeGen::new(
+            &mut masm,
+            codegen_context,
+            env,
+            abi_sig,
+            &self.shared_flags,
+            stats,
+        );
 
         codegen.emit(&mut body, validator)?;
+        let base = codegen.base_srcloc;
+
+        Ok(masm.finalize(base))
+    }
+
+    fn create_systemv_cie(&self) -> Option<gimli::write::CommonInformationEntry> {
+        Some(cranelift_codegen::isa::x64::create_cie())
+    }
 
-        Ok(masm.finalize())
+    fn emit_unwind_info(
+        &self,
+        buffer: &MachBufferFinalized<Final>,
+        kind: UnwindInfoKind,
+    ) -> Result<Option<UnwindInfo>> {
+        Ok(cranelift_codegen::isa::x64::emit_unwind_info(buffer, kind)?)
     }
 
     fn text_section_builder(&self, num_funcs: usize) -> Box<dyn TextSectionBuilder> {
@@ -165,8 +194,37 @@ impl TargetIsa for X64 {
             ArrayToWasm(idx) => trampoline.emit_array_to_wasm(ty, idx)?,
             NativeToWasm(idx) => trampoline.emit_native_to_wasm(ty, idx)?,
             WasmToNative => trampoline.emit_wasm_to_native(ty)?,
+            WasmToArray(host_fn) => trampoline.emit_to_array(ty, host_fn, true)?,
+            NativeToArray(host_fn) => trampoline.emit_to_array(ty, host_fn, false)?,
         }
 
-        Ok(masm.finalize())
+        Ok(masm.finalize(None))
+    }
+
+    #[cfg(feature = "component-model")]
+    fn compile_component_trampoline(
+        &self,
+        translation: &ComponentTranslation,
+        types: &ComponentTypes,
+        index: TrampolineIndex,
+        abi: ComponentAbi,
+    ) -> Result<MachBufferFinalized<Final>> {
+        let mut masm = X64Masm::new(
+            self.pointer_bytes(),
+            self.shared_flags.clone(),
+            self.isa_flags.clone(),
+        );
+        let call_conv = self.wasmtime_call_conv();
+
+        let mut trampoline = Trampoline::new(
+            &mut masm,
+            regs::scratch(),
+            regs::argv(),
+            &call_conv,
+            self.pointer_bytes(),
+        );
+        trampoline.emit_component(translation, types, index, abi)?;
+
+        Ok(masm.finalize(None))
     }
 }
@@ -17,5 +17,7 @@ mod regalloc;
 mod regset;
 mod stack;
 mod trampoline;
+#[cfg(feature = "component-model")]
+pub use trampoline::ComponentAbi;
 pub use trampoline::TrampolineKind;
 mod visitor;
@@ -1,8 +1,14 @@
 use crate::abi::{self, align_to, LocalSlot};
-use crate::codegen::{CodeGenContext, TableData};
-use crate::isa::reg::Reg;
-use cranelift_codegen::{ir::LibCall, Final, MachBufferFinalized, MachLabel};
+use crate::codegen::{CodeGenContext, HeapData, TableData};
+use crate::isa::{reg::Reg, CallingConvention};
+use anyhow::Result;
+use cranelift_codegen::{
+    binemit::CodeOffset,
+    ir::{LibCall, RelSourceLoc, SourceLoc},
+    Final, MachBufferFinalized, MachLabel,
+};
 use std::{fmt::Debug, ops::Range};
+use wasmparser::Operator;
 use wasmtime_environ::PtrSize;
 
 pub(crate) use cranelift_codegen::ir::TrapCode;
@@ -62,7 +68,7 @@ pub(crate) enum IntCmpKind {
 /// Kinds of float binary comparison in WebAssembly. The [`MacroAssembler`]
 /// implementation for each ISA is responsible for emitting the correct
 /// sequence of instructions when lowering code.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum FloatCmpKind {
     /// Equal.
     Eq,
@@ -78,9 +84,145 @@ pub(crate) enum FloatCmpKind {
     Ge,
 }
 
+/// Lane shapes of the WebAssembly `v128` type. The [`MacroAssembler`]
+/// implementation for each ISA uses the shape to select the packed
+/// instructions operating on the right lanes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum VectorShape {
+    /// Sixteen 8-bit integer lanes.
+    I8x16,
+    /// Eight 16-bit integer lanes.
+    I16x8,
+    /// Four 32-bit integer lanes.
+    I32x4,
+    /// Two 64-bit integer lanes.
+    I64x2,
+    /// Four 32-bit float lanes.
+    F32x4,
+    /// Two 64-bit float lanes.
+    F64x2,
+}
+
+/// Kinds of conversions between vector shapes in WebAssembly.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum VectorConvertKind {
+    /// `f32x4.convert_i32x4_s`.
+    F32x4ConvertI32x4S,
+    /// `f64x2.convert_low_i32x4_s`.
+    F64x2ConvertLowI32x4S,
+    /// `f32x4.demote_f64x2_zero`.
+    F32x4DemoteF64x2Zero,
+    /// `f64x2.promote_low_f32x4`.
+    F64x2PromoteLowF32x4,
+    /// `f32x4.convert_i32x4_u`.
+    F32x4ConvertI32x4U,
+    /// `f64x2.convert_low_i32x4_u`.
+    F64x2ConvertLowI32x4U,
+    /// `i32x4.trunc_sat_f32x4_s`.
+    I32x4TruncSatF32x4S,
+    /// `i32x4.trunc_sat_f32x4_u`.
+    I32x4TruncSatF32x4U,
+    /// `i32x4.trunc_sat_f64x2_s_zero`.
+    I32x4TruncSatF64x2SZero,
+    /// `i32x4.trunc_sat_f64x2_u_zero`.
+    I32x4TruncSatF64x2UZero,
+}
+
+/// Kinds of vector loads in WebAssembly, see [`MacroAssembler::v128_load`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum VectorLoadKind {
+    /// `v128.load`.
+    V128,
+    /// Load eight 8-bit integers and extend each of them to 16 bits.
+    Extend8x8(ExtendKind),
+    /// Load four 16-bit integers and extend each of them to 32 bits.
+    Extend16x4(ExtendKind),
+    /// Load two 32-bit integers and extend each of them to 64 bits.
+    Extend32x2(ExtendKind),
+    /// Load a scalar of the given size and replicate it to all the lanes.
+    Splat(OperandSize),
+    /// Load a 32 or 64-bit scalar into the lowest lane and zero the rest.
+    Zero(OperandSize),
+}
+
+/// The half of the lanes of a vector read by operations that widen them.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum VectorHalf {
+    /// The lowest numbered lanes.
+    Low,
+    /// The highest numbered lanes.
+    High,
+}
+
+/// Whether integer vector lanes are interpreted as signed or unsigned, for
+/// the operations where it matters.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Signedness {
+    /// Signed lanes.
+    Signed,
+    /// Unsigned lanes.
+    Unsigned,
+}
+
+/// Kinds of extension applied to values loaded from memory that are narrower
+/// than their destination register.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ExtendKind {
+    /// Sign-extend the value.
+    Signed,
+    /// Zero-extend the value.
+    Unsigned,
+}
+
+/// Integer ALU operations whose second operand may be read directly from
+/// memory, see [`MacroAssembler::alu_mem`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum AluKind {
+    /// Addition.
+    Add,
+    /// Subtraction.
+    Sub,
+    /// Bitwise and.
+    And,
+    /// Bitwise or.
+    Or,
+    /// Bitwise xor.
+    Xor,
+}
+
+/// Atomic read-modify-write operations, see
+/// [`MacroAssembler::atomic_rmw`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum AtomicRmwKind {
+    /// Addition.
+    Add,
+    /// Subtraction.
+    Sub,
+    /// Bitwise and.
+    And,
+    /// Bitwise or.
+    Or,
+    /// Bitwise xor.
+    Xor,
+    /// Exchange.
+    Xchg,
+}
+
+/// How float-to-integer truncations handle values that can't be represented
+/// in the destination type.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum TruncKind {
+    /// Trap on NaN and out-of-range values.
+    Checked,
+    /// Saturate out-of-range values to the bounds of the destination type,
+    /// and convert NaN to zero.
+    Saturating,
+}
+
 /// Kinds of shifts in WebAssembly.The [`masm`] implementation for each ISA is
 /// responsible for emitting the correct sequence of instructions when
 /// lowering to machine code.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub(crate) enum ShiftKind {
     /// Left shift.
     Shl,
@@ -97,6 +239,10 @@ pub(crate) enum ShiftKind {
 /// Operand size, in bits.
 #[derive(Copy, Debug, Clone, Eq, PartialEq)]
 pub(crate) enum OperandSize {
+    /// 8 bits.
+    S8,
+    /// 16 bits.
+    S16,
     /// 32 bits.
     S32,
     /// 64 bits.
@@ -109,6 +255,8 @@ impl OperandSize {
     /// The number of bits in the operand.
     pub fn num_bits(&self) -> i32 {
         match self {
+            OperandSize::S8 => 8,
+            OperandSize::S16 => 16,
             OperandSize::S32 => 32,
             OperandSize::S64 => 64,
             OperandSize::S128 => 128,
@@ -118,15 +266,28 @@ impl OperandSize {
     /// The number of bytes in the operand.
     pub fn bytes(&self) -> u32 {
         match self {
+            Self::S8 => 1,
+            Self::S16 => 2,
             Self::S32 => 4,
             Self::S64 => 8,
             Self::S128 => 16,
         }
     }
 
+    /// The bit pattern of the canonical NaN of the float type of this size.
+    pub fn canonical_nan(&self) -> u64 {
+        match self {
+            OperandSize::S32 => 0x7fc0_0000,
+            OperandSize::S64 => 0x7ff8_0000_0000_0000,
+            _ => unreachable!(),
+        }
+    }
+
     /// The binary logarithm of the number of bits in the operand.
     pub fn log2(&self) -> u8 {
         match self {
+            OperandSize::S8 => 3,
+            OperandSize::S16 => 4,
             OperandSize::S32 => 5,
             OperandSize::S64 => 6,
             OperandSize::S128 => 7,
@@ -137,6 +298,8 @@ impl OperandSize {
     pub fn from_bytes(bytes: u8) -> Self {
         use OperandSize::*;
         match bytes {
+            1 => S8,
+            2 => S16,
             4 => S32,
             8 => S64,
             16 => S128,
@@ -165,6 +328,8 @@ pub(crate) enum Imm {
     F32(u32),
     /// F64 immediate.
     F64(u64),
+    /// V128 immediate.
+    V128(u128),
 }
 
 impl Imm {
@@ -192,6 +357,11 @@ impl Imm {
         Self::F64(bits)
     }
 
+    /// Create a new V128 immediate.
+    pub fn v128(bits: u128) -> Self {
+        Self::V128(bits)
+    }
+
     /// Convert the immediate to i32, if possible.
     pub fn to_i32(&self) -> Option<i32> {
         match self {
@@ -245,6 +415,15 @@ impl RegImm {
         RegImm::Imm(Imm::i32(val))
     }
 
+    /// Checks if this is an integer immediate with the given value, which
+    /// allows identities such as adding zero to be elided.
+    pub fn is_int_imm(&self, val: i32) -> bool {
+        match self {
+            RegImm::Imm(imm) => imm.to_i32() == Some(val),
+            RegImm::Reg(_) => false,
+        }
+    }
+
     /// F32 immediate, stored using its bits representation.
     // Temporary until support for f32.const is added.
     #[allow(dead_code)]
@@ -258,6 +437,11 @@ impl RegImm {
     pub fn f64(bits: u64) -> Self {
         RegImm::Imm(Imm::f64(bits))
     }
+
+    /// V128 immediate, stored using its bits representation.
+    pub fn v128(bits: u128) -> Self {
+        RegImm::Imm(Imm::v128(bits))
+    }
 }
 
 impl From<Reg> for RegImm {
@@ -302,10 +486,23 @@ pub(crate) trait MacroAssembler {
     type ABI: abi::ABI;
 
     /// Emit the function prologue.
-    fn prologue(&mut self);
+    ///
+    /// The prologue must save the caller's frame pointer and establish a new
+    /// frame, for every function including leaf functions. Wasmtime's stack
+    /// walking relies on this frame-pointer chain being intact, and so do
+    /// external profilers using frame-pointer based unwinding, such as
+    /// `perf record --call-graph fp` and eBPF stack walkers.
+    ///
+    /// `clobbers` are the callee-saved registers modified by the function,
+    /// which are saved right below the frame pointer. When the `unwind_info`
+    /// setting is enabled, the frame setup and the location of each saved
+    /// register are also recorded for the unwind information.
+    fn prologue(&mut self, clobbers: &[(Reg, OperandSize)]);
 
-    /// Emit the function epilogue.
-    fn epilogue(&mut self, locals_size: u32);
+    /// Emit the function epilogue, freeing `locals_size` bytes of stack and
+    /// restoring `clobbers`, as saved by [`MacroAssembler::prologue`], and the
+    /// caller's frame pointer.
+    fn epilogue(&mut self, locals_size: u32, clobbers: &[(Reg, OperandSize)]);
 
     /// Reserve stack space.
     fn reserve_stack(&mut self, bytes: u32);
@@ -324,6 +521,10 @@ pub(crate) trait MacroAssembler {
 
     /// Loads the address of the table element at a given index. Returns the
     /// address of the table element using the provided register as base.
+    ///
+    /// The returned address may also make use of the scratch register to hold
+    /// the scaled index, so it must be used before the scratch register is
+    /// clobbered.
     fn table_elem_address(
         &mut self,
         index: Reg,
@@ -335,6 +536,74 @@ pub(crate) trait MacroAssembler {
     /// Retrieves the size of the table, pushing the result to the value stack.
     fn table_size(&mut self, table_data: &TableData, context: &mut CodeGenContext);
 
+    /// Retrieves the size of the heap in wasm pages, pushing the result to
+    /// the value stack.
+    fn memory_size(&mut self, heap_data: &HeapData, context: &mut CodeGenContext);
+
+    /// Computes the address of a heap access of `size` bytes at
+    /// `index + offset`, trapping if the access is out of bounds. The
+    /// effective address is computed in place in the index register, and the
+    /// returned address uses the provided register as base.
+    fn heap_address(
+        &mut self,
+        index: Reg,
+        base: Reg,
+        heap_data: &HeapData,
+        offset: u64,
+        size: OperandSize,
+        context: &mut CodeGenContext,
+    ) -> Self::Address;
+
+    /// Computes the address of an atomic heap access of `size` bytes at
+    /// `index + offset`, trapping if the access is misaligned or out of
+    /// bounds. The effective address is computed in place in the index
+    /// register, and the returned address uses the provided register as
+    /// base.
+    fn atomic_heap_address(
+        &mut self,
+        index: Reg,
+        base: Reg,
+        heap_data: &HeapData,
+        offset: u64,
+        size: OperandSize,
+        context: &mut CodeGenContext,
+    ) -> Self::Address;
+
+    /// Sequentially consistent atomic load from the heap. Values narrower
+    /// than 64 bits are zero-extended.
+    fn atomic_load(&mut self, src: Self::Address, dst: Reg, size: OperandSize);
+
+    /// Sequentially consistent atomic store to the heap.
+    fn atomic_store(&mut self, src: Reg, dst: Self::Address, size: OperandSize);
+
+    /// Atomically applies `kind` to the `size`-byte heap value addressed by
+    /// the heap index and operand at the top of the value stack, pushing the
+    /// previous value, zero-extended to the type of the operand, to the
+    /// value stack.
+    fn atomic_rmw(
+        &mut self,
+        heap_data: &HeapData,
+        offset: u64,
+        kind: AtomicRmwKind,
+        size: OperandSize,
+        context: &mut CodeGenContext,
+    );
+
+    /// Atomically compares the `size`-byte heap value addressed by the heap
+    /// index at the top of the value stack with the expected value, wrapped
+    /// to `size`, replacing it if they are equal. Pushes the previous value,
+    /// zero-extended to the type of the expected value, to the value stack.
+    fn atomic_cmpxchg(
+        &mut self,
+        heap_data: &HeapData,
+        offset: u64,
+        size: OperandSize,
+        context: &mut CodeGenContext,
+    );
+
+    /// Emit a sequentially consistent memory fence.
+    fn fence(&mut self);
+
     /// Constructs an address with an offset that is relative to the
     /// current position of the stack pointer (e.g. [sp + (sp_offset -
     /// offset)].
@@ -354,7 +623,24 @@ pub(crate) trait MacroAssembler {
     fn address_at_reg(&self, reg: Reg, offset: u32) -> Self::Address;
 
     /// Emit a function call to either a local or external function.
-    fn call(&mut self, stack_args_size: u32, f: impl FnMut(&mut Self) -> CalleeKind) -> u32;
+    ///
+    /// The closure loads the callee and returns it along with the calling
+    /// convention it expects to be called with.
+    fn call(
+        &mut self,
+        stack_args_size: u32,
+        f: impl FnMut(&mut Self) -> (CalleeKind, CallingConvention),
+    ) -> u32;
+
+    /// Attach a stack map to the next call, marking the given slots as
+    /// holding live GC references.
+    ///
+    /// Slots are identified by their offset, in the same terms as
+    /// [`StackSlot::offset`]: the slot starts `offset` bytes below the frame
+    /// pointer. The stack map covers the frame from the stack pointer at the
+    /// call up to the frame pointer, one bit per word, which is the layout
+    /// the runtime expects when tracing the stack.
+    fn stack_map(&mut self, gc_ref_slots: &[u32]);
 
     /// Get stack pointer offset.
     fn sp_offset(&self) -> u32;
@@ -365,6 +651,28 @@ pub(crate) trait MacroAssembler {
     /// Perform a stack load.
     fn load(&mut self, src: Self::Address, dst: Reg, size: OperandSize);
 
+    /// Load a `src_size` integer from the given address into `dst`, extending
+    /// it to `dst_size` as indicated by `kind`.
+    fn load_extend(
+        &mut self,
+        src: Self::Address,
+        dst: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: ExtendKind,
+    );
+
+    /// Extend the `src_size` integer in `src` to `dst_size` as indicated by
+    /// `kind`, placing the result in `dst`.
+    fn extend(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: ExtendKind,
+    );
+
     /// Alias for `MacroAssembler::load` with the operand size corresponding
     /// to the pointer size of the target.
     fn load_ptr(&mut self, src: Self::Address, dst: Reg);
@@ -385,6 +693,10 @@ pub(crate) trait MacroAssembler {
     /// Perform add operation.
     fn add(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize);
 
+    /// Perform an unsigned add operation, trapping with `trap` if it
+    /// overflows.
+    fn checked_uadd(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize, trap: TrapCode);
+
     /// Perform subtraction operation.
     fn sub(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize);
 
@@ -427,6 +739,237 @@ pub(crate) trait MacroAssembler {
     /// Perform a floating point square root operation.
     fn float_sqrt(&mut self, dst: Reg, src: Reg, size: OperandSize);
 
+    /// Replace the float in `reg` with the canonical NaN if it is a NaN.
+    fn float_canonicalize_nan(&mut self, reg: Reg, size: OperandSize);
+
+    /// Perform a lane-wise vector add operation.
+    fn v128_add(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape);
+
+    /// Perform a lane-wise vector subtraction operation.
+    fn v128_sub(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape);
+
+    /// Perform a lane-wise vector multiplication operation. The contents of
+    /// `rhs` may be clobbered.
+    fn v128_mul(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape);
+
+    /// Perform a lane-wise floating point vector division operation.
+    fn v128_div(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape);
+
+    /// Perform a bitwise and of two vectors.
+    fn v128_and(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Perform a bitwise and of `lhs` with the complement of `rhs`.
+    fn v128_and_not(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Perform a bitwise or of two vectors.
+    fn v128_or(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Perform a bitwise exclusive or of two vectors.
+    fn v128_xor(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Perform a bitwise complement of a vector.
+    fn v128_not(&mut self, dst: Reg);
+
+    /// Compare the integer lanes of two vectors, setting all the bits of each
+    /// lane in dst for which the comparison holds and clearing them
+    /// otherwise. The contents of `rhs` may be clobbered.
+    fn v128_cmp(&mut self, dst: Reg, lhs: Reg, rhs: Reg, kind: IntCmpKind, shape: VectorShape);
+
+    /// Compare the floating point lanes of two vectors, setting all the bits
+    /// of each lane in dst for which the comparison holds and clearing them
+    /// otherwise.
+    fn v128_float_cmp(
+        &mut self,
+        dst: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        kind: FloatCmpKind,
+        shape: VectorShape,
+    );
+
+    /// Convert the lanes of the vector in src and put the result in dst,
+    /// using `tmp` as a temporary register.
+    fn v128_convert(&mut self, dst: Reg, src: Reg, tmp: Reg, kind: VectorConvertKind);
+
+    /// Replace each NaN lane of the vector in `reg` with the canonical NaN,
+    /// using `tmp` as a temporary register.
+    fn v128_canonicalize_nan(&mut self, reg: Reg, tmp: Reg, shape: VectorShape);
+
+    /// Convert the float in src to a signed integer, truncating towards zero,
+    /// and put the result in dst.
+    fn signed_truncate(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: TruncKind,
+    );
+
+    /// Convert the float in src to an unsigned integer, truncating towards
+    /// zero, and put the result in dst. `tmp_fpr` is clobbered.
+    fn unsigned_truncate(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        tmp_fpr: Reg,
+        src_size: OperandSize,
+        dst_size: OperandSize,
+        kind: TruncKind,
+    );
+
+    /// Replicate the scalar in src to all the lanes of dst. Integer scalars
+    /// are expected in general purpose registers and float scalars in float
+    /// registers.
+    fn v128_splat(&mut self, dst: Reg, src: Reg, shape: VectorShape);
+
+    /// Select bytes from the concatenation of `lhs` and `rhs`, according to
+    /// the given lane indices. Indices less than 16 select bytes from `lhs`
+    /// and the rest from `rhs`. The contents of `rhs` may be clobbered.
+    fn v128_shuffle(&mut self, dst: Reg, lhs: Reg, rhs: Reg, lanes: [u8; 16]);
+
+    /// Check that the target supports the instructions needed to lower the
+    /// WebAssembly SIMD proposal, returning an error naming what's missing
+    /// otherwise. None of the `v128_*` methods are called when this fails.
+    fn check_simd_support(&self) -> Result<()>;
+
+    /// Check that the target can lower the given operator, returning an
+    /// error naming it otherwise. Functions containing operators that fail
+    /// this check are rejected before any of them is lowered.
+    fn check_operator_support(&self, op: &Operator<'_>) -> Result<()>;
+
+    /// Load a vector, or part of one, from memory into dst.
+    fn v128_load(&mut self, src: Self::Address, dst: Reg, kind: VectorLoadKind);
+
+    /// Load a scalar of the given size from memory into the given lane of
+    /// the vector in dst, leaving the other lanes untouched.
+    fn v128_load_lane(&mut self, src: Self::Address, dst: Reg, lane: u8, size: OperandSize);
+
+    /// Store the given lane of the vector in src, of the given size, to
+    /// memory.
+    fn v128_store_lane(&mut self, src: Reg, dst: Self::Address, lane: u8, size: OperandSize);
+
+    /// Extract the given lane of the vector in src into dst. Integer lanes
+    /// are extracted to general purpose registers, extended according to
+    /// `extend` if they are narrower than 32 bits, and float lanes to float
+    /// registers.
+    fn v128_extract_lane(
+        &mut self,
+        dst: Reg,
+        src: Reg,
+        lane: u8,
+        shape: VectorShape,
+        extend: Option<ExtendKind>,
+    );
+
+    /// Replace the given lane of the vector in dst with the scalar in src.
+    fn v128_replace_lane(&mut self, dst: Reg, src: Reg, lane: u8, shape: VectorShape);
+
+    /// Select the bits of `lhs` for which the corresponding bit of `mask` is
+    /// set and the bits of `rhs` otherwise. The contents of `mask` may be
+    /// clobbered.
+    fn v128_bitselect(&mut self, dst: Reg, lhs: Reg, rhs: Reg, mask: Reg);
+
+    /// Set dst to 1 if any bit of the vector in src is set, and to 0
+    /// otherwise.
+    fn v128_any_true(&mut self, dst: Reg, src: Reg);
+
+    /// Set dst to 1 if all the lanes of the vector in src are non-zero, and
+    /// to 0 otherwise.
+    fn v128_all_true(&mut self, dst: Reg, src: Reg, shape: VectorShape);
+
+    /// Gather the most significant bit of each lane of the vector in src
+    /// into the low bits of dst.
+    fn v128_bitmask(&mut self, dst: Reg, src: Reg, shape: VectorShape);
+
+    /// Perform a lane-wise absolute value operation.
+    fn v128_abs(&mut self, dst: Reg, shape: VectorShape);
+
+    /// Perform a lane-wise negation.
+    fn v128_neg(&mut self, dst: Reg, shape: VectorShape);
+
+    /// Count the set bits in each 8-bit lane of dst, using `tmp` as a
+    /// temporary register.
+    fn v128_popcnt(&mut self, dst: Reg, tmp: Reg);
+
+    /// Perform a lane-wise floating point square root operation.
+    fn v128_sqrt(&mut self, dst: Reg, shape: VectorShape);
+
+    /// Round the floating point lanes of dst to an integer in the given
+    /// mode.
+    fn v128_round(&mut self, dst: Reg, mode: RoundingMode, shape: VectorShape);
+
+    /// Perform a lane-wise integer addition, saturating on overflow.
+    fn v128_add_sat(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape, sign: Signedness);
+
+    /// Perform a lane-wise integer subtraction, saturating on overflow.
+    fn v128_sub_sat(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape, sign: Signedness);
+
+    /// Perform a lane-wise integer minimum operation.
+    fn v128_min(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape, sign: Signedness);
+
+    /// Perform a lane-wise integer maximum operation.
+    fn v128_max(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape, sign: Signedness);
+
+    /// Perform a lane-wise floating point minimum operation, with the NaN
+    /// and signed zero semantics of WebAssembly's `min`. The contents of
+    /// `rhs` may be clobbered.
+    fn v128_float_min(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape);
+
+    /// Perform a lane-wise floating point maximum operation, with the NaN
+    /// and signed zero semantics of WebAssembly's `max`. The contents of
+    /// `rhs` may be clobbered.
+    fn v128_float_max(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape);
+
+    /// Perform a lane-wise pseudo-minimum, `rhs < lhs ? rhs : lhs`. The
+    /// contents of `rhs` may be clobbered.
+    fn v128_pmin(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape);
+
+    /// Perform a lane-wise pseudo-maximum, `lhs < rhs ? rhs : lhs`. The
+    /// contents of `rhs` may be clobbered.
+    fn v128_pmax(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape);
+
+    /// Perform a lane-wise unsigned rounding average.
+    fn v128_avgr(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape);
+
+    /// Perform `i16x8.q15mulr_sat_s`.
+    fn v128_q15mulr_sat(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Perform `i32x4.dot_i16x8_s`.
+    fn v128_dot(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
+    /// Multiply the given half of the lanes of `lhs` and `rhs`, extended to
+    /// the lanes of `shape`. The contents of `rhs` may be clobbered.
+    fn v128_extmul(
+        &mut self,
+        dst: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        shape: VectorShape,
+        half: VectorHalf,
+        kind: ExtendKind,
+    );
+
+    /// Extend the given half of the lanes of dst to the lanes of `shape`.
+    fn v128_extend(&mut self, dst: Reg, shape: VectorShape, half: VectorHalf, kind: ExtendKind);
+
+    /// Add the adjacent pairs of lanes of dst, extended to the lanes of
+    /// `shape`.
+    fn v128_extadd_pairwise(&mut self, dst: Reg, shape: VectorShape, kind: ExtendKind);
+
+    /// Narrow the lanes of `lhs` and `rhs`, with saturation, to the lanes of
+    /// `shape`; the lanes of `lhs` make up the low half of the result.
+    fn v128_narrow(&mut self, dst: Reg, lhs: Reg, rhs: Reg, shape: VectorShape, sign: Signedness);
+
+    /// Perform a lane-wise shift of the vector below the top of the value
+    /// stack by the 32-bit amount on top of it, taken modulo the lane width.
+    fn v128_shift(&mut self, context: &mut CodeGenContext, kind: ShiftKind, shape: VectorShape);
+
+    /// Select the bytes of `lhs` indexed by the bytes of `rhs`, producing
+    /// zero for out-of-range indices. The contents of `rhs` may be
+    /// clobbered.
+    fn v128_swizzle(&mut self, dst: Reg, lhs: Reg, rhs: Reg);
+
     /// Perform logical and operation.
     fn and(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize);
 
@@ -436,6 +979,10 @@ pub(crate) trait MacroAssembler {
     /// Perform logical exclusive or operation.
     fn xor(&mut self, dst: Reg, lhs: Reg, rhs: RegImm, size: OperandSize);
 
+    /// Perform an integer ALU operation in which the second operand is read
+    /// from memory, `dst = lhs op [rhs]`.
+    fn alu_mem(&mut self, dst: Reg, lhs: Reg, rhs: Self::Address, kind: AluKind, size: OperandSize);
+
     /// Perform a shift operation.
     /// Shift is special in that some architectures have specific expectations
     /// regarding the location of the instruction arguments. To free the
@@ -444,6 +991,9 @@ pub(crate) trait MacroAssembler {
     /// each implementation to decide the lowering path.
     fn shift(&mut self, context: &mut CodeGenContext, kind: ShiftKind, size: OperandSize);
 
+    /// Perform a shift operation by an immediate, `dst = lhs kind imm`.
+    fn shift_ir(&mut self, dst: Reg, imm: u64, lhs: Reg, kind: ShiftKind, size: OperandSize);
+
     /// Perform division operation.
     /// Division is special in that some architectures have specific
     /// expectations regarding the location of the instruction
@@ -496,8 +1046,23 @@ pub(crate) trait MacroAssembler {
     // epilogues.
     fn push(&mut self, src: Reg, size: OperandSize) -> StackSlot;
 
-    /// Finalize the assembly and return the result.
-    fn finalize(self) -> MachBufferFinalized<Final>;
+    /// Get the offset of the next instruction to be emitted.
+    fn current_code_offset(&self) -> CodeOffset;
+
+    /// Get the total size, in bytes, of the constants referenced so far,
+    /// which are emitted after the function's code.
+    fn constant_pool_size(&self) -> u32;
+
+    /// Mark the start of the code generated for the wasm operator at `loc`.
+    fn start_source_loc(&mut self, loc: RelSourceLoc);
+
+    /// Mark the end of the code started by the last call to
+    /// `start_source_loc`.
+    fn end_source_loc(&mut self);
+
+    /// Finalize the assembly and return the result. The source locations
+    /// marked while emitting are relative to `base`, if any.
+    fn finalize(self, base: Option<SourceLoc>) -> MachBufferFinalized<Final>;
 
     /// Zero a particular register.
     fn zero(&mut self, reg: Reg);
@@ -584,9 +1149,23 @@ pub(crate) trait MacroAssembler {
     /// Emit an unreachable code trap.
     fn unreachable(&mut self);
 
+    /// Emit an unconditional trap with the given code.
+    fn trap(&mut self, code: TrapCode);
+
     /// Traps if the condition code is met.
     fn trapif(&mut self, cc: IntCmpKind, code: TrapCode);
 
+    /// Traps with [`TrapCode::StackOverflow`] if the stack space the function
+    /// uses from this point on would move the stack pointer below `limit`.
+    ///
+    /// That space is the peak stack pointer offset past the current one,
+    /// covering locals, spilled values and outgoing call arguments alike. It's
+    /// only known once the whole function has been emitted, so it's patched
+    /// into the check by [`MacroAssembler::finalize`]. The `limit` register is
+    /// clobbered, and the check also traps if adding the space to `limit`
+    /// overflows.
+    fn check_stack(&mut self, limit: Reg);
+
     /// Trap if the source register is zero.
     fn trapz(&mut self, src: Reg, code: TrapCode);
 }
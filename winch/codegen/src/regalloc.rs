@@ -11,9 +11,18 @@ use crate::{
 ///
 /// If a particular register is not available upon request
 /// the register allocation will perform a "spill", essentially
-/// moving Local and Register values in the stack to memory.
+/// moving Local and Register values in the stack to memory,
+/// starting from the bottom of the value stack until the requested
+/// register is freed.
 /// This processs ensures that whenever a register is requested,
-/// it is going to be available.
+/// it is going to be available, as long as it isn't held by the
+/// instruction being compiled.
+///
+/// Spilled values are pushed to the machine stack in value stack
+/// order, so the slot assigned to each spilled value only depends on
+/// the state of the value stack and not on which registers happened
+/// to be free. A spilled value is reloaded into a register when it's
+/// popped from the value stack, see `CodeGenContext::pop_to_reg`.
 pub(crate) struct RegAlloc {
     /// The register set.
     regset: RegSet,
@@ -35,7 +44,11 @@ impl RegAlloc {
         self.regset.reg_for_class(class).unwrap_or_else(|| {
             spill(self);
             self.regset.reg_for_class(class).unwrap_or_else(|| {
-                panic!("expected register for class {:?}, to be avilable", class)
+                panic!(
+                    "expected register for class {:?} to be available after spilling; \
+                     all of them are held by the current instruction",
+                    class
+                )
             })
         })
     }
@@ -52,9 +65,13 @@ impl RegAlloc {
     {
         self.regset.reg(named).unwrap_or_else(|| {
             spill(self);
-            self.regset
-                .reg(named)
-                .expect(&format!("register {:?} to be available", named))
+            self.regset.reg(named).unwrap_or_else(|| {
+                panic!(
+                    "expected register {:?} to be available after spilling; \
+                     it is held by the current instruction",
+                    named
+                )
+            })
         })
     }
 
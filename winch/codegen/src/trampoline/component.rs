@@ -0,0 +1,785 @@
+//! Trampolines of components.
+//!
+//! These are the core functions through which a component's instances call
+//! into the host: lowered imports, resource intrinsics, string transcoders
+//! and functions which always trap. Each of them is compiled once for every
+//! calling convention of a `VMFuncRef`, see [`ComponentAbi`].
+use super::Trampoline;
+use crate::{
+    abi::{ABIArg, ABIParams, ABI},
+    masm::{CalleeKind, IntCmpKind, MacroAssembler, OperandSize, RegImm, ShiftKind, TrapCode},
+    reg::Reg,
+};
+use anyhow::Result;
+use smallvec::SmallVec;
+use std::mem;
+use wasmtime_environ::component::{
+    CanonicalOptions, Component, ComponentTranslation, ComponentTypes, FixedEncoding,
+    GlobalInitializer, LoweredIndex, RuntimeMemoryIndex, Trampoline as ComponentTrampoline,
+    TrampolineIndex, Transcode, TypeFuncIndex, TypeResourceTableIndex, VMComponentOffsets,
+    FLAG_MAY_ENTER,
+};
+use wasmtime_environ::{PtrSize, WasmFuncType, WasmType, FIRST_RESERVED_TRAP_CODE};
+
+/// Trap code of functions which always trap, which the runtime reports as
+/// `Trap::AlwaysTrapAdapter`.
+const ALWAYS_TRAP_CODE: u16 = FIRST_RESERVED_TRAP_CODE;
+
+/// Trap code for entering a component instance which can't be entered, which
+/// the runtime reports as `Trap::CannotEnterComponent`.
+const CANNOT_ENTER_CODE: u16 = FIRST_RESERVED_TRAP_CODE + 1;
+
+/// Trap code of debug assertions, which the runtime lets crash the process.
+const DEBUG_ASSERT_TRAP_CODE: u16 = u16::MAX;
+
+/// The calling conventions of component trampolines, one for each of the
+/// entry points of a `VMFuncRef`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ComponentAbi {
+    /// Winch's default calling convention, used by calls from Wasm.
+    Wasm,
+    /// The system's calling convention.
+    Native,
+    /// The array calling convention, which passes the arguments and the
+    /// results through an array of values.
+    Array,
+}
+
+/// An argument of a host function called by a component trampoline.
+#[derive(Copy, Clone)]
+enum HostArg {
+    /// The component's VM context.
+    Vmctx,
+    /// The pointer at the given offset of the component's VM context.
+    VmctxLoad(u32),
+    /// The address at the given offset of the component's VM context.
+    VmctxAddr(u32),
+    /// An immediate.
+    Imm(i64),
+    /// The trampoline's parameter at the given index, counting the VM
+    /// context pointers.
+    Param(usize),
+    /// The Wasm argument at the given index.
+    Value(usize),
+    /// The address of the value at the given index of the values array.
+    ValueAddr(usize),
+    /// The Wasm argument at the given index, which is an address in a linear
+    /// memory, translated to a host address.
+    MemoryAddr {
+        /// The index of the argument.
+        value: usize,
+        /// The offset of the memory's `VMMemoryDefinition` pointer in the
+        /// component's VM context.
+        definition: u32,
+        /// The offset of the base address in the `VMMemoryDefinition`.
+        base: u32,
+    },
+}
+
+/// The frame of a component trampoline.
+struct Frame<'a> {
+    /// The calling convention of the trampoline.
+    abi: ComponentAbi,
+    /// The type of the trampoline.
+    ty: &'a WasmFuncType,
+    /// The trampoline's parameters, including the return area pointer.
+    params: ABIParams,
+    /// The spill slots of the parameters passed in registers.
+    spill_offsets: SmallVec<[u32; 6]>,
+    /// The values array holding the Wasm arguments and results. With the
+    /// array calling convention this is the caller's array, otherwise it's
+    /// allocated by the trampoline at this stack pointer offset.
+    values_offset: u32,
+    /// The length of the values array.
+    values_len: u32,
+}
+
+impl<'a, M> Trampoline<'a, M>
+where
+    M: MacroAssembler,
+{
+    /// Emit the trampoline at `index` of `translation`, called with `abi`.
+    pub fn emit_component(
+        &mut self,
+        translation: &ComponentTranslation,
+        types: &ComponentTypes,
+        index: TrampolineIndex,
+        abi: ComponentAbi,
+    ) -> Result<()> {
+        let component = &translation.component;
+        let ty = &types[component.trampolines[index]];
+        let offsets = VMComponentOffsets::new(self.pointer_size.size(), component);
+        let sig = match abi {
+            ComponentAbi::Wasm => <M::ABI as ABI>::wasm_sig(ty),
+            ComponentAbi::Native => self.native_sig(ty),
+            // The values pointer and length follow the VM context pointers.
+            ComponentAbi::Array => self.native_sig(&WasmFuncType::new(
+                [WasmType::I64, WasmType::I64].into(),
+                [].into(),
+            )),
+        };
+        let params = sig.params_with_ret_area_ptr();
+        let (vmctx, _) = Self::callee_and_caller_vmctx(&params)?;
+
+        // The second scratch register is callee-saved in the system's calling
+        // convention, so the callee-saved registers are saved as well.
+        self.prologue_with_callee_saved();
+
+        if abi == ComponentAbi::Wasm {
+            let limits_addr = self.masm.address_at_reg(vmctx, offsets.limits());
+            Self::save_last_wasm_exit_fp_and_pc(
+                self.masm,
+                limits_addr,
+                self.scratch_reg,
+                self.alloc_scratch_reg,
+                &self.pointer_size,
+            );
+        }
+
+        let (spill_offsets, spill_size) = self.spill(&params);
+
+        let (values_size, values_len) = match abi {
+            ComponentAbi::Array => (0, 0),
+            ComponentAbi::Wasm | ComponentAbi::Native => {
+                let len = ty.params().len().max(ty.returns().len()) as u32;
+                (self.reserve_values_array(len), len)
+            }
+        };
+        let frame = Frame {
+            abi,
+            ty,
+            params,
+            spill_offsets,
+            values_offset: self.masm.sp_offset(),
+            values_len,
+        };
+
+        // Move the Wasm arguments to the values array, which is where the
+        // trampoline reads them from.
+        if abi != ComponentAbi::Array {
+            for (i, param_ty) in ty.params().iter().enumerate() {
+                let addr = Self::param_address(
+                    self.masm,
+                    &frame.params,
+                    &frame.spill_offsets,
+                    i + 2,
+                    self.scratch_reg,
+                );
+                let scratch = Self::scratch_for(param_ty, self.scratch_reg);
+                self.masm.load(addr, scratch, (*param_ty).into());
+                let value_addr = Self::value_address(self.masm, &frame, i, self.scratch_reg);
+                self.masm
+                    .store(RegImm::reg(scratch), value_addr, (*param_ty).into());
+            }
+        }
+
+        match &translation.trampolines[index] {
+            ComponentTrampoline::Transcoder { op, from, to, .. } => match abi {
+                ComponentAbi::Wasm => self.emit_transcode(&frame, &offsets, *op, *from, *to),
+                // Transcoders can only be called by Wasm.
+                ComponentAbi::Native | ComponentAbi::Array => {
+                    self.masm.trap(TrapCode::User(DEBUG_ASSERT_TRAP_CODE))
+                }
+            },
+            ComponentTrampoline::LowerImport {
+                index,
+                options,
+                lower_ty,
+            } => self.emit_lower_import(&frame, &offsets, *index, options, *lower_ty),
+            ComponentTrampoline::AlwaysTrap => {
+                self.masm.trap(TrapCode::User(ALWAYS_TRAP_CODE));
+            }
+            ComponentTrampoline::ResourceNew(resource) => {
+                self.emit_resource_libcall(&frame, &offsets, host::resource_new32, *resource)
+            }
+            ComponentTrampoline::ResourceRep(resource) => {
+                self.emit_resource_libcall(&frame, &offsets, host::resource_rep32, *resource)
+            }
+            ComponentTrampoline::ResourceDrop(resource) => {
+                self.emit_resource_drop(&frame, &offsets, component, types, *resource)
+            }
+            ComponentTrampoline::ResourceTransferOwn => {
+                self.emit_libcall(&frame, &offsets, host::resource_transfer_own)
+            }
+            ComponentTrampoline::ResourceTransferBorrow => {
+                self.emit_libcall(&frame, &offsets, host::resource_transfer_borrow)
+            }
+            ComponentTrampoline::ResourceEnterCall => {
+                self.emit_libcall(&frame, &offsets, host::resource_enter_call)
+            }
+            ComponentTrampoline::ResourceExitCall => {
+                self.emit_libcall(&frame, &offsets, host::resource_exit_call)
+            }
+        }
+
+        // The results are in the values array, from which they're loaded
+        // unless it's the caller's.
+        if abi != ComponentAbi::Array {
+            if sig.ret_area_ptr.is_some() {
+                let ptr = self.alloc_scratch_reg;
+                let addr = Self::param_address(
+                    self.masm,
+                    &frame.params,
+                    &frame.spill_offsets,
+                    frame.params.len() - 1,
+                    self.scratch_reg,
+                );
+                self.masm.load(addr, ptr, OperandSize::S64);
+                for (i, (ty, offset)) in sig.result.ret_area().iter().enumerate() {
+                    let value_addr = Self::value_address(self.masm, &frame, i + 1, ptr);
+                    let scratch = Self::scratch_for(ty, self.scratch_reg);
+                    self.masm.load(value_addr, scratch, (*ty).into());
+                    self.masm.store(
+                        RegImm::reg(scratch),
+                        self.masm.address_at_reg(ptr, *offset),
+                        (*ty).into(),
+                    );
+                }
+            }
+            if let Some((ty, reg)) = sig.result.reg_result() {
+                let value_addr = Self::value_address(self.masm, &frame, 0, self.scratch_reg);
+                self.masm.load(value_addr, reg, ty.into());
+            }
+        }
+
+        self.masm.free_stack(values_size);
+        self.epilogue_with_callee_saved_restore(spill_size);
+
+        Ok(())
+    }
+
+    /// Calls the host function of a lowered import, which takes the
+    /// arguments and returns the results through the values array.
+    fn emit_lower_import(
+        &mut self,
+        frame: &Frame,
+        offsets: &VMComponentOffsets<u8>,
+        index: LoweredIndex,
+        options: &CanonicalOptions,
+        lower_ty: TypeFuncIndex,
+    ) {
+        let CanonicalOptions {
+            instance,
+            memory,
+            realloc,
+            post_return,
+            string_encoding,
+        } = *options;
+
+        // A post-return option is only valid on `canon.lift`'d functions.
+        assert!(post_return.is_none());
+
+        let (storage, storage_len) = match frame.abi {
+            ComponentAbi::Array => (HostArg::Param(2), HostArg::Param(3)),
+            ComponentAbi::Wasm | ComponentAbi::Native => {
+                (HostArg::ValueAddr(0), HostArg::Imm(frame.values_len.into()))
+            }
+        };
+        let ptr = WasmType::I64;
+        let ty = WasmFuncType::new(
+            [
+                ptr,
+                ptr,
+                WasmType::I32,
+                ptr,
+                ptr,
+                ptr,
+                WasmType::I32,
+                ptr,
+                ptr,
+            ]
+            .into(),
+            [].into(),
+        );
+        let args = [
+            // vmctx: *mut VMComponentContext
+            HostArg::Vmctx,
+            // data: *mut u8
+            HostArg::VmctxLoad(offsets.lowering_data(index)),
+            // ty: TypeFuncIndex
+            HostArg::Imm(lower_ty.as_u32().into()),
+            // flags: *mut VMGlobalDefinition
+            HostArg::VmctxAddr(offsets.instance_flags(instance)),
+            // memory: *mut VMMemoryDefinition
+            memory.map_or(HostArg::Imm(0), |memory| {
+                HostArg::VmctxLoad(offsets.runtime_memory(memory))
+            }),
+            // realloc: *mut VMFuncRef
+            realloc.map_or(HostArg::Imm(0), |realloc| {
+                HostArg::VmctxLoad(offsets.runtime_realloc(realloc))
+            }),
+            // string_encoding: StringEncoding
+            HostArg::Imm(string_encoding as i64),
+            // storage: *mut ValRaw
+            storage,
+            // storage_len: usize
+            storage_len,
+        ];
+        self.call_host(frame, &ty, &args, &[offsets.lowering_callee(index)]);
+    }
+
+    /// Calls the libcall of a resource intrinsic, which takes the resource's
+    /// type along with the Wasm argument.
+    fn emit_resource_libcall(
+        &mut self,
+        frame: &Frame,
+        offsets: &VMComponentOffsets<u8>,
+        libcall: fn() -> (WasmFuncType, u32),
+        resource: TypeResourceTableIndex,
+    ) {
+        // Only resources represented by `i32` are supported.
+        assert_eq!(frame.ty.params(), [WasmType::I32]);
+        assert_eq!(frame.ty.returns(), [WasmType::I32]);
+
+        let (ty, libcall) = libcall();
+        let args = [
+            HostArg::Vmctx,
+            HostArg::Imm(resource.as_u32().into()),
+            HostArg::Value(0),
+        ];
+        let result = self.call_host(frame, &ty, &args, &Self::libcall(offsets, libcall));
+        self.store_value(frame, 0, result.unwrap());
+    }
+
+    /// Calls the libcall dropping a resource, followed by the resource's
+    /// destructor if the libcall says so.
+    fn emit_resource_drop(
+        &mut self,
+        frame: &Frame,
+        offsets: &VMComponentOffsets<u8>,
+        component: &Component,
+        types: &ComponentTypes,
+        resource: TypeResourceTableIndex,
+    ) {
+        let (ty, libcall) = host::resource_drop();
+        let args = [
+            HostArg::Vmctx,
+            HostArg::Imm(resource.as_u32().into()),
+            HostArg::Value(0),
+        ];
+        // The result is `Some(rep)` encoded in 64 bits, with the low bit
+        // set if the destructor needs to run on the `rep` in the bits above.
+        let result = self
+            .call_host(frame, &ty, &args, &Self::libcall(offsets, libcall))
+            .unwrap();
+
+        let resource_ty = types[resource].ty;
+        let resource_def = component.defined_resource_index(resource_ty).map(|idx| {
+            component
+                .initializers
+                .iter()
+                .filter_map(|i| match i {
+                    GlobalInitializer::Resource(r) if r.index == idx => Some(r),
+                    _ => None,
+                })
+                .next()
+                .unwrap()
+        });
+        let has_destructor = match resource_def {
+            Some(def) => def.dtor.is_some(),
+            None => true,
+        };
+
+        let done = self.masm.get_label();
+        self.masm.branch(
+            IntCmpKind::Eq,
+            RegImm::reg(result),
+            result,
+            done,
+            OperandSize::S64,
+        );
+
+        // A resource defined within the component can only be destroyed if
+        // its instance may be entered, unless the instance is the one
+        // calling itself.
+        if let Some(def) = resource_def {
+            if types[resource].instance != def.instance {
+                let flags = self.scratch_reg;
+                Self::load_vmctx(self.masm, frame, flags);
+                let flags_addr = self
+                    .masm
+                    .address_at_reg(flags, offsets.instance_flags(def.instance));
+                self.masm.load(flags_addr, flags, OperandSize::S32);
+                self.masm
+                    .and(flags, flags, RegImm::i32(FLAG_MAY_ENTER), OperandSize::S32);
+                self.masm.trapz(flags, TrapCode::User(CANNOT_ENTER_CODE));
+            }
+        }
+
+        if has_destructor {
+            let dtor_sig =
+                <M::ABI as ABI>::wasm_sig(&WasmFuncType::new([WasmType::I32].into(), [].into()));
+            let dtor_offset = offsets.resource_destructor(resource_ty);
+            let scratch = self.scratch_reg;
+            let reserved_stack = self.masm.call(dtor_sig.stack_bytes, |masm| {
+                let regs = dtor_sig.params.iter().map(|p| p.get_reg().unwrap());
+                let [callee_vmctx, caller_vmctx, rep]: [Reg; 3] =
+                    regs.collect::<Vec<_>>().try_into().unwrap();
+                masm.mov(RegImm::reg(result), rep, OperandSize::S64);
+                masm.shift_ir(rep, 1, rep, ShiftKind::ShrU, OperandSize::S64);
+                Self::load_vmctx(masm, frame, caller_vmctx);
+
+                // The destructor's funcref is known to be non-null, given
+                // that the libcall asked for it to run.
+                Self::load_vmctx(masm, frame, scratch);
+                masm.load(
+                    masm.address_at_reg(scratch, dtor_offset),
+                    scratch,
+                    OperandSize::S64,
+                );
+                if cfg!(debug_assertions) {
+                    masm.trapz(scratch, TrapCode::User(DEBUG_ASSERT_TRAP_CODE));
+                }
+                masm.load(
+                    masm.address_at_reg(scratch, offsets.ptr.vm_func_ref_vmctx().into()),
+                    callee_vmctx,
+                    OperandSize::S64,
+                );
+                masm.load(
+                    masm.address_at_reg(scratch, offsets.ptr.vm_func_ref_wasm_call().into()),
+                    scratch,
+                    OperandSize::S64,
+                );
+                (CalleeKind::Indirect(scratch), dtor_sig.call_conv)
+            });
+            self.masm.free_stack(reserved_stack);
+        }
+
+        self.masm.bind(done);
+    }
+
+    /// Calls a libcall taking the Wasm arguments as they are, returning its
+    /// result, if any, as they are too.
+    fn emit_libcall(
+        &mut self,
+        frame: &Frame,
+        offsets: &VMComponentOffsets<u8>,
+        libcall: fn() -> (WasmFuncType, u32),
+    ) {
+        // These trampolines can only be called by Wasm.
+        if frame.abi != ComponentAbi::Wasm {
+            self.masm.trap(TrapCode::User(DEBUG_ASSERT_TRAP_CODE));
+            return;
+        }
+
+        let (ty, libcall) = libcall();
+        let args = [HostArg::Vmctx]
+            .into_iter()
+            .chain((0..frame.ty.params().len()).map(HostArg::Value))
+            .collect::<Vec<_>>();
+        let result = self.call_host(frame, &ty, &args, &Self::libcall(offsets, libcall));
+        if let Some(result) = result {
+            self.store_value(frame, 0, result);
+        }
+    }
+
+    /// Calls the libcall of a string transcoder, passing the Wasm addresses
+    /// of the strings translated to host addresses.
+    fn emit_transcode(
+        &mut self,
+        frame: &Frame,
+        offsets: &VMComponentOffsets<u8>,
+        op: Transcode,
+        from: RuntimeMemoryIndex,
+        to: RuntimeMemoryIndex,
+    ) {
+        let (ty, libcall) = match op {
+            Transcode::Copy(FixedEncoding::Utf8) => host::utf8_to_utf8(),
+            Transcode::Copy(FixedEncoding::Utf16) => host::utf16_to_utf16(),
+            Transcode::Copy(FixedEncoding::Latin1) => host::latin1_to_latin1(),
+            Transcode::Latin1ToUtf16 => host::latin1_to_utf16(),
+            Transcode::Latin1ToUtf8 => host::latin1_to_utf8(),
+            Transcode::Utf16ToCompactProbablyUtf16 => host::utf16_to_compact_probably_utf16(),
+            Transcode::Utf16ToCompactUtf16 => host::utf16_to_compact_utf16(),
+            Transcode::Utf16ToLatin1 => host::utf16_to_latin1(),
+            Transcode::Utf16ToUtf8 => host::utf16_to_utf8(),
+            Transcode::Utf8ToCompactUtf16 => host::utf8_to_compact_utf16(),
+            Transcode::Utf8ToLatin1 => host::utf8_to_latin1(),
+            Transcode::Utf8ToUtf16 => host::utf8_to_utf16(),
+        };
+
+        // Bounds checks happen in the adapter modules, so the addresses are
+        // simply added to the memories' base addresses. Addresses and
+        // lengths of 32-bit memories are zero-extended when loaded.
+        let base = self.pointer_size.vmmemory_definition_base().into();
+        let from_addr = |value| HostArg::MemoryAddr {
+            value,
+            definition: offsets.runtime_memory(from),
+            base,
+        };
+        let to_addr = |value| HostArg::MemoryAddr {
+            value,
+            definition: offsets.runtime_memory(to),
+            base,
+        };
+        let mut args = match op {
+            Transcode::Copy(_)
+            | Transcode::Latin1ToUtf16
+            | Transcode::Utf16ToCompactProbablyUtf16
+            | Transcode::Utf8ToLatin1
+            | Transcode::Utf16ToLatin1
+            | Transcode::Utf8ToUtf16 => vec![from_addr(0), HostArg::Value(1), to_addr(2)],
+
+            Transcode::Utf16ToUtf8 | Transcode::Latin1ToUtf8 => vec![
+                from_addr(0),
+                HostArg::Value(1),
+                to_addr(2),
+                HostArg::Value(3),
+            ],
+
+            Transcode::Utf8ToCompactUtf16 | Transcode::Utf16ToCompactUtf16 => vec![
+                from_addr(0),
+                HostArg::Value(1),
+                to_addr(2),
+                HostArg::Value(3),
+                HostArg::Value(4),
+            ],
+        };
+
+        // The second result of these is returned through a pointer, which
+        // points to where it's returned from in the values array. The
+        // arguments there have been loaded by then.
+        if matches!(
+            op,
+            Transcode::Utf16ToUtf8
+                | Transcode::Latin1ToUtf8
+                | Transcode::Utf8ToLatin1
+                | Transcode::Utf16ToLatin1
+        ) {
+            args.push(HostArg::ValueAddr(1));
+        }
+
+        // The results are sizes, which are truncated when stored for 32-bit
+        // memories.
+        let result = self.call_host(frame, &ty, &args, &Self::libcall(offsets, libcall));
+        if let Some(result) = result {
+            self.store_value(frame, 0, result);
+        }
+    }
+
+    /// Calls the host function at the address loaded by following the
+    /// pointers at the given `callee` offsets, starting from the component's
+    /// VM context. The host function has type `ty` in the system's calling
+    /// convention and is passed `args`.
+    ///
+    /// Returns the register holding the result, if any.
+    fn call_host(
+        &mut self,
+        frame: &Frame,
+        ty: &WasmFuncType,
+        args: &[HostArg],
+        callee: &[u32],
+    ) -> Option<Reg> {
+        let sig = <M::ABI as ABI>::sig(ty, self.call_conv);
+        assert_eq!(sig.params.len(), args.len());
+        let scratch = self.scratch_reg;
+        let tmp = self.alloc_scratch_reg;
+
+        let reserved_stack = self.masm.call(sig.stack_bytes, |masm| {
+            // The arguments are loaded from the stack, so assigning one
+            // doesn't clobber the others.
+            for (param, arg) in sig.params.iter().zip(args) {
+                match param {
+                    ABIArg::Reg { reg, .. } => Self::load_host_arg(masm, frame, *arg, *reg, tmp),
+                    ABIArg::Stack { ty, offset } => {
+                        Self::load_host_arg(masm, frame, *arg, scratch, tmp);
+                        masm.store(
+                            RegImm::reg(scratch),
+                            masm.address_at_sp(*offset),
+                            (*ty).into(),
+                        );
+                    }
+                    ABIArg::Indirect { .. } => {
+                        unreachable!("indirect argument in a host function call")
+                    }
+                }
+            }
+
+            Self::load_vmctx(masm, frame, scratch);
+            for offset in callee {
+                masm.load(
+                    masm.address_at_reg(scratch, *offset),
+                    scratch,
+                    OperandSize::S64,
+                );
+            }
+            (CalleeKind::Indirect(scratch), sig.call_conv)
+        });
+        self.masm.free_stack(reserved_stack);
+
+        sig.result.reg_result().map(|(_, reg)| reg)
+    }
+
+    /// Returns the offsets of the pointers leading to the libcall at the
+    /// given offset of the libcalls array.
+    fn libcall(offsets: &VMComponentOffsets<u8>, libcall: u32) -> [u32; 2] {
+        [offsets.libcalls(), libcall * u32::from(offsets.ptr.size())]
+    }
+
+    /// Loads `arg` into `dst`, using `tmp` if a second register is needed.
+    fn load_host_arg(masm: &mut M, frame: &Frame, arg: HostArg, dst: Reg, tmp: Reg) {
+        match arg {
+            HostArg::Vmctx => Self::load_vmctx(masm, frame, dst),
+            HostArg::VmctxLoad(offset) => {
+                Self::load_vmctx(masm, frame, dst);
+                masm.load(masm.address_at_reg(dst, offset), dst, OperandSize::S64);
+            }
+            HostArg::VmctxAddr(offset) => {
+                Self::load_vmctx(masm, frame, dst);
+                masm.add(dst, dst, RegImm::i32(offset as i32), OperandSize::S64);
+            }
+            HostArg::Imm(imm) => masm.mov(RegImm::i64(imm), dst, OperandSize::S64),
+            HostArg::Param(index) => {
+                let addr =
+                    Self::param_address(masm, &frame.params, &frame.spill_offsets, index, dst);
+                masm.load(addr, dst, OperandSize::S64);
+            }
+            HostArg::Value(index) => {
+                let addr = Self::value_address(masm, frame, index, dst);
+                masm.load(addr, dst, frame.ty.params()[index].into());
+            }
+            HostArg::ValueAddr(index) => {
+                let offset = (index * mem::size_of::<u128>()) as u32;
+                match frame.abi {
+                    ComponentAbi::Array => {
+                        let addr =
+                            Self::param_address(masm, &frame.params, &frame.spill_offsets, 2, dst);
+                        masm.load(addr, dst, OperandSize::S64);
+                    }
+                    ComponentAbi::Wasm | ComponentAbi::Native => {
+                        let sp = <M::ABI as ABI>::sp_reg();
+                        masm.mov(RegImm::reg(sp), dst, OperandSize::S64);
+                        let delta = masm.sp_offset() - frame.values_offset;
+                        masm.add(dst, dst, RegImm::i32(delta as i32), OperandSize::S64);
+                    }
+                }
+                if offset > 0 {
+                    masm.add(dst, dst, RegImm::i32(offset as i32), OperandSize::S64);
+                }
+            }
+            HostArg::MemoryAddr {
+                value,
+                definition,
+                base,
+            } => {
+                Self::load_vmctx(masm, frame, dst);
+                masm.load(masm.address_at_reg(dst, definition), dst, OperandSize::S64);
+                masm.load(masm.address_at_reg(dst, base), dst, OperandSize::S64);
+                let addr = Self::value_address(masm, frame, value, tmp);
+                masm.load(addr, tmp, frame.ty.params()[value].into());
+                masm.add(dst, dst, RegImm::reg(tmp), OperandSize::S64);
+            }
+        }
+    }
+
+    /// Loads the component's VM context into `dst`.
+    fn load_vmctx(masm: &mut M, frame: &Frame, dst: Reg) {
+        let addr = Self::param_address(masm, &frame.params, &frame.spill_offsets, 0, dst);
+        masm.load(addr, dst, OperandSize::S64);
+    }
+
+    /// Returns the address of the value at `index` of the values array. The
+    /// caller's array is addressed through `scratch`, which is loaded with
+    /// its pointer.
+    fn value_address(masm: &mut M, frame: &Frame, index: usize, scratch: Reg) -> M::Address {
+        let offset = (index * mem::size_of::<u128>()) as u32;
+        match frame.abi {
+            ComponentAbi::Array => {
+                let addr =
+                    Self::param_address(masm, &frame.params, &frame.spill_offsets, 2, scratch);
+                masm.load(addr, scratch, OperandSize::S64);
+                masm.address_at_reg(scratch, offset)
+            }
+            ComponentAbi::Wasm | ComponentAbi::Native => {
+                masm.address_from_sp(frame.values_offset - offset)
+            }
+        }
+    }
+
+    /// Stores the result in `reg` to the value at `index` of the values
+    /// array.
+    fn store_value(&mut self, frame: &Frame, index: usize, reg: Reg) {
+        let ty = frame.ty.returns()[index];
+        let addr = Self::value_address(self.masm, frame, index, self.scratch_reg);
+        self.masm.store(RegImm::reg(reg), addr, ty.into());
+    }
+}
+
+/// Module with macro-generated contents that will return the signature and
+/// offset for each of the host transcoder functions.
+///
+/// Note that a macro is used here to keep this in sync with the actual
+/// transcoder functions themselves which are also defined via a macro.
+mod host {
+    use wasmtime_environ::{WasmFuncType, WasmType};
+
+    macro_rules! define {
+        (
+            $(
+                $( #[$attr:meta] )*
+                $name:ident( $( $pname:ident: $param:ident ),* ) $( -> $result:ident )?;
+            )*
+        ) => {
+            $(
+                pub(super) fn $name() -> (WasmFuncType, u32) {
+                    let ty = WasmFuncType::new(
+                        [ $( define!(@ty $param) ),* ].into(),
+                        [ $( define!(@ty $result) )? ].into(),
+                    );
+                    (ty, offsets::$name)
+                }
+            )*
+        };
+
+        (@ty size) => (WasmType::I64);
+        (@ty ptr_u8) => (WasmType::I64);
+        (@ty ptr_u16) => (WasmType::I64);
+        (@ty ptr_size) => (WasmType::I64);
+        (@ty u32) => (WasmType::I32);
+        (@ty u64) => (WasmType::I64);
+        (@ty vmctx) => (WasmType::I64);
+    }
+
+    wasmtime_environ::foreach_transcoder!(define);
+    wasmtime_environ::foreach_builtin_component_function!(define);
+
+    mod offsets {
+        macro_rules! offsets {
+            (
+                $(
+                    $( #[$attr:meta] )*
+                    $name:ident($($t:tt)*) $( -> $result:ident )?;
+                )*
+            ) => {
+                offsets!(@declare (0) $($name)*);
+            };
+
+            (@declare ($n:expr)) => (const LAST_BUILTIN: u32 = $n;);
+            (@declare ($n:expr) $name:ident $($rest:tt)*) => (
+                pub const $name: u32 = $n;
+                offsets!(@declare ($n + 1) $($rest)*);
+            );
+        }
+
+        wasmtime_environ::foreach_builtin_component_function!(offsets);
+
+        macro_rules! transcode_offsets {
+            (
+                $(
+                    $( #[$attr:meta] )*
+                    $name:ident($($t:tt)*) $( -> $result:ident )?;
+                )*
+            ) => {
+                transcode_offsets!(@declare (0) $($name)*);
+            };
+
+            (@declare ($n:expr)) => ();
+            (@declare ($n:expr) $name:ident $($rest:tt)*) => (
+                pub const $name: u32 = LAST_BUILTIN + $n;
+                transcode_offsets!(@declare ($n + 1) $($rest)*);
+            );
+        }
+
+        wasmtime_environ::foreach_transcoder!(transcode_offsets);
+    }
+}
@@ -0,0 +1,762 @@
+//! Trampoline implementation for Winch.
+//!
+//! This module contains all the necessary pieces to emit the various
+//! trampolines required by Wasmtime to call JIT code.
+//
+// TODO
+//
+// * Remove the usage of hardcoded operand sizes (`OperandSize::S64`) when
+// loading/storing the VM context pointer. The real value of the operand size
+// and VM context type should be derived from the ABI's pointer size. This is
+// going to be relevant once 32-bit architectures are supported.
+use crate::{
+    abi::{align_to, ABIArg, ABIParams, ABISig, ABI},
+    isa::CallingConvention,
+    masm::{CalleeKind, MacroAssembler, OperandSize, RegImm},
+    reg::Reg,
+};
+use anyhow::{anyhow, Result};
+use smallvec::SmallVec;
+use std::mem;
+use wasmtime_environ::{FuncIndex, PtrSize, WasmFuncType, WasmType};
+
+#[cfg(feature = "component-model")]
+mod component;
+#[cfg(feature = "component-model")]
+pub use component::ComponentAbi;
+
+/// The supported trampoline kinds.
+/// See <https://github.com/bytecodealliance/rfcs/blob/main/accepted/tail-calls.md#new-trampolines-and-vmcallercheckedanyfunc-changes>
+/// for more details.
+pub enum TrampolineKind {
+    /// Calling from native to Wasm, using the array calling convention.
+    ArrayToWasm(FuncIndex),
+    /// Calling from native to Wasm.
+    NativeToWasm(FuncIndex),
+    /// Calling from Wasm to native.
+    WasmToNative,
+    /// Calling from Wasm to the given host function, which uses the array
+    /// calling convention.
+    WasmToArray(usize),
+    /// Calling from native to the given host function, which uses the array
+    /// calling convention.
+    NativeToArray(usize),
+}
+
+/// The main trampoline abstraction.
+pub(crate) struct Trampoline<'a, M>
+where
+    M: MacroAssembler,
+{
+    /// The macro assembler.
+    masm: &'a mut M,
+    /// The main scratch register for the current architecture. It is
+    /// not allocatable for the callee.
+    scratch_reg: Reg,
+    /// A second scratch register. This will be allocatable for the
+    /// callee, so it can only be used after the callee-saved
+    /// registers are on the stack.
+    alloc_scratch_reg: Reg,
+    /// Registers to be saved as part of the trampoline's prologue
+    /// and to be restored as part of the trampoline's epilogue.
+    callee_saved_regs: SmallVec<[(Reg, OperandSize); 18]>,
+    /// The calling convention used by the trampoline,
+    /// which is the Wasmtime variant of the system ABI's
+    /// calling convention.
+    call_conv: &'a CallingConvention,
+    /// The pointer size of the current ISA.
+    pointer_size: M::Ptr,
+}
+
+impl<'a, M> Trampoline<'a, M>
+where
+    M: MacroAssembler,
+{
+    /// Create a new trampoline.
+    pub fn new(
+        masm: &'a mut M,
+        scratch_reg: Reg,
+        alloc_scratch_reg: Reg,
+        call_conv: &'a CallingConvention,
+        pointer_size: M::Ptr,
+    ) -> Self {
+        Self {
+            masm,
+            scratch_reg,
+            alloc_scratch_reg,
+            callee_saved_regs: <M::ABI as ABI>::callee_saved_regs(call_conv),
+            call_conv,
+            pointer_size,
+        }
+    }
+
+    /// Emit an array-to-wasm trampoline.
+    pub fn emit_array_to_wasm(&mut self, ty: &WasmFuncType, callee_index: FuncIndex) -> Result<()> {
+        let native_ty = WasmFuncType::new(
+            [WasmType::I64, WasmType::I64, WasmType::I64, WasmType::I64].into(),
+            [].into(),
+        );
+
+        let native_sig = self.native_sig(&native_ty);
+        let wasm_sig = <M::ABI as ABI>::wasm_sig(ty);
+
+        let val_ptr = &native_sig.params[2]
+            .get_reg()
+            .map(RegImm::reg)
+            .ok_or_else(|| anyhow!("Expected value pointer to be in a register"))?;
+
+        self.prologue_with_callee_saved();
+
+        // Get the VM context pointer and move it to the designated pinned
+        // register.
+        let (vmctx, caller_vmctx) = Self::callee_and_caller_vmctx(&native_sig.params)?;
+
+        self.masm.mov(
+            vmctx.into(),
+            <M::ABI as ABI>::vmctx_reg().into(),
+            OperandSize::S64,
+        );
+
+        let vmctx_runtime_limits_addr = self.vmctx_runtime_limits_addr(caller_vmctx);
+        let (offsets, spill_size) = self.spill(&native_sig.params);
+
+        let val_ptr_offset = offsets[2];
+
+        // Any results which don't fit in registers are returned through a
+        // return area right above the stack arguments.
+        let ret_area_offset = align_to(wasm_sig.stack_bytes, <M::ABI as ABI>::word_bytes());
+        let ret_area_size = wasm_sig.result.ret_area_size();
+        let stack_args_size = if ret_area_size > 0 {
+            ret_area_offset + ret_area_size
+        } else {
+            wasm_sig.stack_bytes
+        };
+
+        // Call the function that was passed into the trampoline.
+        let allocated_stack = self.masm.call(stack_args_size, |masm| {
+            // Save the SP when entering Wasm.
+            // TODO: Once Winch supports comparison operators,
+            // check that the caller VM context is what we expect.
+            // See [`wasmtime_environ::MAGIC`].
+            Self::save_last_wasm_entry_sp(
+                masm,
+                vmctx_runtime_limits_addr,
+                self.scratch_reg,
+                &self.pointer_size,
+            );
+
+            // Move the values register to the scratch
+            // register for argument assignment.
+            masm.mov(*val_ptr, self.scratch_reg.into(), OperandSize::S64);
+            // The VM context pointers are forwarded ahead of the arguments.
+            Self::assign_args(
+                masm,
+                &wasm_sig.params[..2],
+                &native_sig.params[..2],
+                &offsets[..2],
+                self.alloc_scratch_reg,
+                <M::ABI as ABI>::arg_base_offset().into(),
+            );
+            Self::assign_args_from_array(
+                masm,
+                wasm_sig.wasm_params(),
+                self.scratch_reg,
+                self.alloc_scratch_reg,
+            );
+            Self::assign_ret_area_ptr(masm, &wasm_sig, ret_area_offset, self.scratch_reg);
+            (
+                CalleeKind::Direct(callee_index.as_u32()),
+                wasm_sig.call_conv,
+            )
+        });
+
+        // Move the val ptr back into the scratch register so we can
+        // load the return values.
+        self.masm.load(
+            self.masm.address_from_sp(val_ptr_offset),
+            self.scratch_reg,
+            OperandSize::S64,
+        );
+
+        // Move the return values into the value ptr, starting with the one
+        // in a register and followed by the ones in the return area.
+        let value_size = mem::size_of::<u128>() as u32;
+        if let Some((ty, reg)) = wasm_sig.result.reg_result() {
+            self.masm.store(
+                RegImm::reg(reg),
+                self.masm.address_at_reg(self.scratch_reg, 0),
+                ty.into(),
+            );
+        }
+        for (i, (ty, offset)) in wasm_sig.result.ret_area().iter().enumerate() {
+            let scratch = Self::scratch_for(ty, self.alloc_scratch_reg);
+            self.masm.load(
+                self.masm.address_at_sp(ret_area_offset + offset),
+                scratch,
+                (*ty).into(),
+            );
+            self.masm.store(
+                RegImm::reg(scratch),
+                self.masm
+                    .address_at_reg(self.scratch_reg, (i as u32 + 1) * value_size),
+                (*ty).into(),
+            );
+        }
+
+        self.masm.free_stack(allocated_stack);
+
+        self.epilogue_with_callee_saved_restore(spill_size);
+        Ok(())
+    }
+
+    /// Emit a native-to-wasm trampoline.
+    pub fn emit_native_to_wasm(
+        &mut self,
+        ty: &WasmFuncType,
+        callee_index: FuncIndex,
+    ) -> Result<()> {
+        let native_sig = self.native_sig(&ty);
+        let wasm_sig = <M::ABI as ABI>::wasm_sig(&ty);
+        // The return area pointer, if any, is forwarded to the callee given
+        // that both calling conventions lay out the return area in the same
+        // way.
+        let native_params = native_sig.params_with_ret_area_ptr();
+        let wasm_params = wasm_sig.params_with_ret_area_ptr();
+        let (vmctx, caller_vmctx) = Self::callee_and_caller_vmctx(&native_params)?;
+
+        self.prologue_with_callee_saved();
+        // Move the VM context pointer to the designated pinned register.
+        self.masm.mov(
+            vmctx.into(),
+            <M::ABI as ABI>::vmctx_reg().into(),
+            OperandSize::S64,
+        );
+
+        let vmctx_runtime_limits_addr = self.vmctx_runtime_limits_addr(caller_vmctx);
+        let (offsets, spill_size) = self.spill(&native_params);
+
+        let reserved_stack = self.masm.call(wasm_sig.stack_bytes, |masm| {
+            // Save the SP when entering Wasm.
+            // TODO: Once Winch supports comparison operators,
+            // check that the caller VM context is what we expect.
+            // See [`wasmtime_environ::MAGIC`].
+            Self::save_last_wasm_entry_sp(
+                masm,
+                vmctx_runtime_limits_addr,
+                self.scratch_reg,
+                &self.pointer_size,
+            );
+            Self::assign_args(
+                masm,
+                &wasm_params,
+                &native_params,
+                &offsets,
+                self.scratch_reg,
+                <M::ABI as ABI>::arg_base_offset().into(),
+            );
+            (
+                CalleeKind::Direct(callee_index.as_u32()),
+                wasm_sig.call_conv,
+            )
+        });
+
+        self.masm.free_stack(reserved_stack);
+        self.epilogue_with_callee_saved_restore(spill_size);
+
+        Ok(())
+    }
+
+    /// Emit a wasm-to-native trampoline.
+    pub fn emit_wasm_to_native(&mut self, ty: &WasmFuncType) -> Result<()> {
+        let wasm_sig = <M::ABI as ABI>::wasm_sig(ty);
+        let native_sig = self.native_sig(ty);
+        // The return area pointer, if any, is forwarded to the callee given
+        // that both calling conventions lay out the return area in the same
+        // way.
+        let wasm_params = wasm_sig.params_with_ret_area_ptr();
+        let native_params = native_sig.params_with_ret_area_ptr();
+
+        let (vmctx, caller_vmctx) = Self::callee_and_caller_vmctx(&wasm_params).unwrap();
+        let vmctx_runtime_limits_addr = self.vmctx_runtime_limits_addr(caller_vmctx);
+
+        self.prologue();
+
+        // Save the FP and return address when exiting Wasm.
+        // TODO: Once Winch supports comparison operators,
+        // check that the caller VM context is what we expect.
+        // See [`wasmtime_environ::MAGIC`].
+        Self::save_last_wasm_exit_fp_and_pc(
+            self.masm,
+            vmctx_runtime_limits_addr,
+            self.scratch_reg,
+            self.alloc_scratch_reg,
+            &self.pointer_size,
+        );
+
+        let (offsets, spill_size) = self.spill(&wasm_params);
+
+        let reserved_stack = self.masm.call(native_sig.stack_bytes, |masm| {
+            // Move the VM context into one of the scratch registers.
+            masm.mov(
+                vmctx.into(),
+                self.alloc_scratch_reg.into(),
+                OperandSize::S64,
+            );
+
+            Self::assign_args(
+                masm,
+                &native_params,
+                &wasm_params,
+                &offsets,
+                self.scratch_reg,
+                <M::ABI as ABI>::arg_base_offset().into(),
+            );
+
+            let body_offset = self.pointer_size.vmnative_call_host_func_context_func_ref()
+                + self.pointer_size.vm_func_ref_native_call();
+            let callee_addr = masm.address_at_reg(self.alloc_scratch_reg, body_offset.into());
+            masm.load(callee_addr, self.scratch_reg, OperandSize::S64);
+
+            (CalleeKind::Indirect(self.scratch_reg), native_sig.call_conv)
+        });
+
+        self.masm.free_stack(reserved_stack);
+        self.epilogue(spill_size);
+
+        Ok(())
+    }
+
+    /// Emit a trampoline which calls `host_fn`, a host function using the
+    /// array calling convention:
+    ///
+    /// ```ignore
+    /// unsafe extern "C" fn(*mut VMContext, *mut VMContext, *mut ValRaw, usize)
+    /// ```
+    ///
+    /// The trampoline is called with Winch's default calling convention when
+    /// `from_wasm` is set and with the system's calling convention otherwise.
+    /// Its arguments are spilled to an array on the stack, which also holds
+    /// the results once `host_fn` returns.
+    ///
+    /// `host_fn` is an address in this process, so the trampoline can't be
+    /// serialized.
+    pub fn emit_to_array(
+        &mut self,
+        ty: &WasmFuncType,
+        host_fn: usize,
+        from_wasm: bool,
+    ) -> Result<()> {
+        let sig = if from_wasm {
+            <M::ABI as ABI>::wasm_sig(ty)
+        } else {
+            self.native_sig(ty)
+        };
+        // The VM context pointers are followed by the values pointer and
+        // length.
+        let array_ty = WasmFuncType::new([WasmType::I64, WasmType::I64].into(), [].into());
+        let array_sig = self.native_sig(&array_ty);
+        let params = sig.params_with_ret_area_ptr();
+        let (_, caller_vmctx) = Self::callee_and_caller_vmctx(&params)?;
+
+        self.prologue();
+
+        if from_wasm {
+            let vmctx_runtime_limits_addr = self.vmctx_runtime_limits_addr(caller_vmctx);
+            Self::save_last_wasm_exit_fp_and_pc(
+                self.masm,
+                vmctx_runtime_limits_addr,
+                self.scratch_reg,
+                self.alloc_scratch_reg,
+                &self.pointer_size,
+            );
+        }
+
+        let (offsets, spill_size) = self.spill(&params);
+
+        // The array holds both the arguments and the results.
+        let value_size = mem::size_of::<u128>() as u32;
+        let values_len = ty.params().len().max(ty.returns().len()) as u32;
+        let values_size = self.reserve_values_array(values_len);
+        let values_offset = self.masm.sp_offset();
+
+        let values_params = params.len() - 2 - sig.ret_area_ptr.iter().count();
+        for i in 0..values_params {
+            let param = &params[i + 2];
+            let addr = Self::param_address(self.masm, &params, &offsets, i + 2, self.scratch_reg);
+            let scratch = Self::scratch_for(&param.ty(), self.scratch_reg);
+            self.masm.load(addr, scratch, param.ty().into());
+            let value_addr = self
+                .masm
+                .address_from_sp(values_offset - i as u32 * value_size);
+            self.masm
+                .store(RegImm::reg(scratch), value_addr, param.ty().into());
+        }
+
+        let reserved_stack = self.masm.call(array_sig.stack_bytes, |masm| {
+            let args = &array_sig.params;
+            let (vmctx, caller_vmctx) = (args[0].get_reg(), args[1].get_reg());
+            let (values, len) = (args[2].get_reg(), args[3].get_reg());
+            let addr = Self::param_address(masm, &params, &offsets, 0, self.scratch_reg);
+            masm.load(addr, vmctx.unwrap(), OperandSize::S64);
+            let addr = Self::param_address(masm, &params, &offsets, 1, self.scratch_reg);
+            masm.load(addr, caller_vmctx.unwrap(), OperandSize::S64);
+
+            let values = values.unwrap();
+            let sp = <M::ABI as ABI>::sp_reg();
+            masm.mov(RegImm::reg(sp), values, OperandSize::S64);
+            let delta = masm.sp_offset() - values_offset;
+            masm.add(values, values, RegImm::i32(delta as i32), OperandSize::S64);
+            masm.mov(
+                RegImm::i64(values_len.into()),
+                len.unwrap(),
+                OperandSize::S64,
+            );
+
+            masm.mov(
+                RegImm::i64(host_fn as i64),
+                self.scratch_reg,
+                OperandSize::S64,
+            );
+            (CalleeKind::Indirect(self.scratch_reg), array_sig.call_conv)
+        });
+        self.masm.free_stack(reserved_stack);
+
+        // Load the results from the array, starting with those in the return
+        // area, which are followed by the one in a register. The host
+        // function's argument registers are free once it returns, unlike the
+        // second scratch register which may be callee-saved in the system's
+        // calling convention.
+        if sig.ret_area_ptr.is_some() {
+            let ret_area_ptr = array_sig.params[0].get_reg().unwrap();
+            let addr = Self::param_address(
+                self.masm,
+                &params,
+                &offsets,
+                params.len() - 1,
+                self.scratch_reg,
+            );
+            self.masm.load(addr, ret_area_ptr, OperandSize::S64);
+            for (i, (ty, offset)) in sig.result.ret_area().iter().enumerate() {
+                let value_addr = self
+                    .masm
+                    .address_from_sp(values_offset - (i as u32 + 1) * value_size);
+                let scratch = Self::scratch_for(ty, self.scratch_reg);
+                self.masm.load(value_addr, scratch, (*ty).into());
+                self.masm.store(
+                    RegImm::reg(scratch),
+                    self.masm.address_at_reg(ret_area_ptr, *offset),
+                    (*ty).into(),
+                );
+            }
+        }
+        if let Some((ty, reg)) = sig.result.reg_result() {
+            let value_addr = self.masm.address_from_sp(values_offset);
+            self.masm.load(value_addr, reg, ty.into());
+        }
+
+        self.masm.free_stack(values_size);
+        self.epilogue(spill_size);
+
+        Ok(())
+    }
+
+    /// Reserves stack space for an array of `len` values, as passed to host
+    /// functions using the array calling convention, returning the size of
+    /// the reservation. The array starts at the stack pointer, which is
+    /// aligned to the alignment of a value.
+    fn reserve_values_array(&mut self, len: u32) -> u32 {
+        let value_size = mem::size_of::<u128>() as u32;
+        // The stack pointer is aligned to the value size when the frame
+        // pointer is set up.
+        let sp_offset = self.masm.sp_offset();
+        let size = align_to(sp_offset + len * value_size, value_size) - sp_offset;
+        self.masm.reserve_stack(size);
+        size
+    }
+
+    /// Returns the address of the parameter at `index`, which is either in
+    /// its spill slot or in the caller's stack arguments. The value of a
+    /// parameter passed by reference is addressed through `scratch`, which
+    /// is loaded with its pointer.
+    fn param_address(
+        masm: &mut M,
+        params: &ABIParams,
+        spill_offsets: &[u32],
+        index: usize,
+        scratch: Reg,
+    ) -> M::Address {
+        let slot_address = |masm: &mut M, arg: &ABIArg| match arg {
+            ABIArg::Reg { .. } => {
+                let spilled = params[..index]
+                    .iter()
+                    .filter(|param| param.get_reg().is_some())
+                    .count();
+                masm.address_from_sp(spill_offsets[spilled])
+            }
+            ABIArg::Stack { offset, .. } => {
+                let arg_base_offset: u32 = <M::ABI as ABI>::arg_base_offset().into();
+                masm.address_at_reg(<M::ABI as ABI>::fp_reg(), arg_base_offset + offset)
+            }
+            ABIArg::Indirect { .. } => unreachable!("nested indirect argument"),
+        };
+        match &params[index] {
+            ABIArg::Indirect { pointer, .. } => {
+                let addr = slot_address(masm, pointer.as_ref());
+                masm.load(addr, scratch, OperandSize::S64);
+                masm.address_at_reg(scratch, 0)
+            }
+            param => slot_address(masm, param),
+        }
+    }
+
+    /// Perfom argument assignment, translating between
+    /// caller and callee calling conventions.
+    fn assign_args(
+        masm: &mut M,
+        callee_params: &[ABIArg],
+        caller_params: &[ABIArg],
+        caller_stack_offsets: &[u32],
+        scratch: Reg,
+        arg_base_offset: u32,
+    ) {
+        assert!(callee_params.len() == caller_params.len());
+        let fp = <M::ABI as ABI>::fp_reg();
+        let mut offset_index = 0;
+
+        for (callee_param, caller_param) in callee_params.iter().zip(caller_params) {
+            // The address of the argument's value, which is either in its
+            // spill slot, in the caller's stack arguments or, for arguments
+            // passed by reference, behind a pointer held in either.
+            let mut slot_address = |masm: &mut M, arg: &ABIArg| match arg {
+                ABIArg::Reg { .. } => {
+                    let offset = caller_stack_offsets[offset_index];
+                    offset_index += 1;
+                    masm.address_from_sp(offset)
+                }
+                ABIArg::Stack { offset, .. } => masm.address_at_reg(fp, arg_base_offset + offset),
+                ABIArg::Indirect { .. } => unreachable!("nested indirect argument"),
+            };
+            let src = match caller_param {
+                ABIArg::Indirect { pointer, .. } => {
+                    let addr = slot_address(masm, pointer.as_ref());
+                    masm.load(addr, scratch, OperandSize::S64);
+                    masm.address_at_reg(scratch, 0)
+                }
+                param => slot_address(masm, param),
+            };
+
+            let ty = callee_param.ty();
+            match callee_param {
+                ABIArg::Reg { reg: dst, .. } => masm.load(src, *dst, ty.into()),
+                ABIArg::Stack { offset, .. } => {
+                    let scratch = Self::scratch_for(&ty, scratch);
+                    masm.load(src, scratch, ty.into());
+                    masm.store(scratch.into(), masm.address_at_sp(*offset), ty.into());
+                }
+                ABIArg::Indirect {
+                    pointer, offset, ..
+                } => {
+                    // Copy the value to the stack arguments and pass its
+                    // address.
+                    let value = Self::scratch_for(&ty, scratch);
+                    masm.load(src, value, ty.into());
+                    masm.store(value.into(), masm.address_at_sp(*offset), ty.into());
+
+                    let dst = pointer.get_reg().unwrap_or(scratch);
+                    let sp = <M::ABI as ABI>::sp_reg();
+                    masm.mov(RegImm::reg(sp), dst, OperandSize::S64);
+                    masm.add(dst, dst, RegImm::i32(*offset as i32), OperandSize::S64);
+                    if let ABIArg::Stack { offset, .. } = pointer.as_ref() {
+                        masm.store(
+                            RegImm::reg(scratch),
+                            masm.address_at_sp(*offset),
+                            OperandSize::S64,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the register to move a value of type `ty` through: the float
+    /// scratch register for floats and vectors, which can't be held in
+    /// `scratch`, and `scratch` otherwise.
+    fn scratch_for(ty: &WasmType, scratch: Reg) -> Reg {
+        match ty {
+            WasmType::F32 | WasmType::F64 | WasmType::V128 => <M::ABI as ABI>::float_scratch_reg(),
+            _ => scratch,
+        }
+    }
+
+    /// Get the type of the caller and callee VM contexts.
+    fn callee_and_caller_vmctx_types() -> Vec<WasmType> {
+        vec![WasmType::I64, WasmType::I64]
+    }
+
+    /// Returns a signature using the system's calling convention.
+    fn native_sig(&self, ty: &WasmFuncType) -> ABISig {
+        let mut params = Self::callee_and_caller_vmctx_types();
+        params.extend_from_slice(ty.params());
+        let native_type = WasmFuncType::new(params.into(), ty.returns().into());
+
+        <M::ABI as ABI>::sig(&native_type, self.call_conv)
+    }
+
+    /// Returns the register pair containing the callee and caller VM context pointers.
+    fn callee_and_caller_vmctx(params: &ABIParams) -> Result<(Reg, Reg)> {
+        let vmctx = params[0]
+            .get_reg()
+            .ok_or_else(|| anyhow!("Expected vm context pointer to be in a register"))?;
+
+        let caller_vmctx = params[1]
+            .get_reg()
+            .ok_or_else(|| anyhow!("Expected caller vm context pointer to be in a register"))?;
+
+        Ok((vmctx, caller_vmctx))
+    }
+
+    /// Returns the address of the VM context runtime limits
+    /// field.
+    fn vmctx_runtime_limits_addr(&mut self, caller_vmctx: Reg) -> M::Address {
+        self.masm.address_at_reg(
+            caller_vmctx,
+            self.pointer_size.vmcontext_runtime_limits().into(),
+        )
+    }
+
+    /// Performs a spill of the register params.
+    fn spill(&mut self, params: &ABIParams) -> (SmallVec<[u32; 6]>, u32) {
+        let mut offsets = SmallVec::new();
+        let mut spill_size = 0;
+        params.iter().for_each(|param| {
+            if let Some(reg) = param.get_reg() {
+                let slot = self.masm.push(reg, param.slot_ty().into());
+                offsets.push(slot.offset);
+                spill_size += slot.size;
+            }
+        });
+
+        (offsets, spill_size)
+    }
+
+    /// Assigns the callee's Wasm parameters, loading them from the array in
+    /// a register.
+    fn assign_args_from_array(masm: &mut M, params: &[ABIArg], values_reg: Reg, scratch: Reg) {
+        // The max size a value can be when reading from the params
+        // memory location.
+        let value_size = mem::size_of::<u128>();
+        params.iter().enumerate().for_each(|(i, param)| {
+            let value_offset = (i * value_size) as u32;
+
+            match param {
+                ABIArg::Reg { reg, ty } => masm.load(
+                    masm.address_at_reg(values_reg, value_offset),
+                    *reg,
+                    (*ty).into(),
+                ),
+                ABIArg::Stack { offset, ty } => {
+                    let scratch = Self::scratch_for(ty, scratch);
+                    masm.load(
+                        masm.address_at_reg(values_reg, value_offset),
+                        scratch,
+                        (*ty).into(),
+                    );
+                    masm.store(
+                        RegImm::reg(scratch),
+                        masm.address_at_sp(*offset),
+                        (*ty).into(),
+                    );
+                }
+                ABIArg::Indirect { .. } => {
+                    unreachable!("indirect argument in Winch's default calling convention")
+                }
+            }
+        });
+    }
+
+    /// Assigns the address of the return area, at the given offset from the
+    /// stack pointer, to the callee's return area pointer argument, if any.
+    fn assign_ret_area_ptr(masm: &mut M, callee_sig: &ABISig, offset: u32, scratch: Reg) {
+        if let Some(arg) = &callee_sig.ret_area_ptr {
+            let dst = arg.get_reg().unwrap_or(scratch);
+            let sp = <M::ABI as ABI>::sp_reg();
+            masm.mov(RegImm::reg(sp), dst, OperandSize::S64);
+            masm.add(dst, dst, RegImm::i32(offset as i32), OperandSize::S64);
+            if let ABIArg::Stack { offset, .. } = arg {
+                masm.store(
+                    RegImm::reg(scratch),
+                    masm.address_at_sp(*offset),
+                    OperandSize::S64,
+                );
+            }
+        }
+    }
+
+    fn save_last_wasm_entry_sp(
+        masm: &mut M,
+        vm_runtime_limits_addr: M::Address,
+        scratch: Reg,
+        ptr: &impl PtrSize,
+    ) {
+        let sp = <M::ABI as ABI>::sp_reg();
+        masm.load(vm_runtime_limits_addr, scratch, OperandSize::S64);
+        let addr = masm.address_at_reg(scratch, ptr.vmruntime_limits_last_wasm_entry_sp().into());
+        masm.store(sp.into(), addr, OperandSize::S64);
+    }
+
+    fn save_last_wasm_exit_fp_and_pc(
+        masm: &mut M,
+        vm_runtime_limits_addr: M::Address,
+        scratch: Reg,
+        alloc_scratch: Reg,
+        ptr: &impl PtrSize,
+    ) {
+        masm.load(vm_runtime_limits_addr, alloc_scratch, OperandSize::S64);
+        let last_wasm_exit_fp_addr = masm.address_at_reg(
+            alloc_scratch,
+            ptr.vmruntime_limits_last_wasm_exit_fp().into(),
+        );
+        let last_wasm_exit_pc_addr = masm.address_at_reg(
+            alloc_scratch,
+            ptr.vmruntime_limits_last_wasm_exit_pc().into(),
+        );
+
+        // Handle the frame pointer.
+        let fp = <M::ABI as ABI>::fp_reg();
+        let fp_addr = masm.address_at_reg(fp, 0);
+        masm.load(fp_addr, scratch, OperandSize::S64);
+        masm.store(scratch.into(), last_wasm_exit_fp_addr, OperandSize::S64);
+
+        // Handle the return address.
+        let ret_addr_offset = <M::ABI as ABI>::ret_addr_offset();
+        let ret_addr = masm.address_at_reg(fp, ret_addr_offset.into());
+        masm.load(ret_addr, scratch, OperandSize::S64);
+        masm.store(scratch.into(), last_wasm_exit_pc_addr, OperandSize::S64);
+    }
+
+    /// The trampoline's prologue.
+    fn prologue(&mut self) {
+        self.masm.prologue(&[]);
+    }
+
+    /// Similar to [Trampoline::prologue], but saves
+    /// callee-saved registers.
+    fn prologue_with_callee_saved(&mut self) {
+        self.masm.prologue(&self.callee_saved_regs);
+    }
+
+    /// Similar to [Trampoline::epilogue], but restores
+    /// callee-saved registers.
+    fn epilogue_with_callee_saved_restore(&mut self, arg_size: u32) {
+        // Free the stack space allocated by pushing the trampoline arguments.
+        self.masm.free_stack(arg_size);
+        self.masm.epilogue(0, &self.callee_saved_regs);
+    }
+
+    /// The trampoline's epilogue.
+    fn epilogue(&mut self, arg_size: u32) {
+        // Free the stack space allocated by pushing the trampoline arguments.
+        self.masm.free_stack(arg_size);
+        self.masm.epilogue(0, &[]);
+    }
+}
@@ -4,20 +4,24 @@
 //! which validates and dispatches to the corresponding
 //! machine code emitter.
 
-use crate::abi::ABI;
-use crate::codegen::{control_index, Callee, CodeGen, ControlStackFrame, FnCall};
+use crate::abi::{is_externref, ABI};
+use crate::codegen::{
+    control_index, BuiltinFunction, Callee, CodeGen, ControlStackFrame, FnCall, HeapData,
+};
+use crate::isa::reg::{Reg, RegClass};
 use crate::masm::{
-    DivKind, FloatCmpKind, IntCmpKind, MacroAssembler, OperandSize, RegImm, RemKind, RoundingMode,
-    ShiftKind,
+    AluKind, AtomicRmwKind, DivKind, ExtendKind, FloatCmpKind, IntCmpKind, MacroAssembler,
+    OperandSize, RegImm, RemKind, RoundingMode, ShiftKind, Signedness, TruncKind,
+    VectorConvertKind, VectorHalf, VectorLoadKind, VectorShape,
 };
 use crate::stack::{TypedReg, Val};
 use cranelift_codegen::ir::TrapCode;
 use smallvec::SmallVec;
 use wasmparser::BrTable;
-use wasmparser::{BlockType, Ieee32, Ieee64, VisitOperator};
+use wasmparser::{BlockType, HeapType, Ieee32, Ieee64, MemArg, VisitOperator, V128};
 use wasmtime_environ::{
-    FuncIndex, GlobalIndex, TableIndex, TableStyle, TypeIndex, WasmHeapType, WasmType,
-    FUNCREF_INIT_BIT,
+    FuncIndex, GlobalIndex, MemoryIndex, TableIndex, TableStyle, TypeConvert, TypeIndex,
+    WasmHeapType, WasmRefType, WasmType, FUNCREF_INIT_BIT,
 };
 
 /// A macro to define unsupported WebAssembly operators.
@@ -85,6 +89,22 @@ macro_rules! def_unsupported {
     (emit F64Le $($rest:tt)*) => {};
     (emit F32Ge $($rest:tt)*) => {};
     (emit F64Ge $($rest:tt)*) => {};
+    (emit I32TruncF32S $($rest:tt)*) => {};
+    (emit I32TruncF32U $($rest:tt)*) => {};
+    (emit I32TruncF64S $($rest:tt)*) => {};
+    (emit I32TruncF64U $($rest:tt)*) => {};
+    (emit I64TruncF32S $($rest:tt)*) => {};
+    (emit I64TruncF32U $($rest:tt)*) => {};
+    (emit I64TruncF64S $($rest:tt)*) => {};
+    (emit I64TruncF64U $($rest:tt)*) => {};
+    (emit I32TruncSatF32S $($rest:tt)*) => {};
+    (emit I32TruncSatF32U $($rest:tt)*) => {};
+    (emit I32TruncSatF64S $($rest:tt)*) => {};
+    (emit I32TruncSatF64U $($rest:tt)*) => {};
+    (emit I64TruncSatF32S $($rest:tt)*) => {};
+    (emit I64TruncSatF32U $($rest:tt)*) => {};
+    (emit I64TruncSatF64S $($rest:tt)*) => {};
+    (emit I64TruncSatF64U $($rest:tt)*) => {};
     (emit I32Add $($rest:tt)*) => {};
     (emit I64Add $($rest:tt)*) => {};
     (emit I32Sub $($rest:tt)*) => {};
@@ -138,6 +158,14 @@ macro_rules! def_unsupported {
     (emit I32Rotr $($rest:tt)*) => {};
     (emit I64Rotr $($rest:tt)*) => {};
     (emit I32Clz $($rest:tt)*) => {};
+    (emit I32WrapI64 $($rest:tt)*) => {};
+    (emit I64ExtendI32S $($rest:tt)*) => {};
+    (emit I64ExtendI32U $($rest:tt)*) => {};
+    (emit I32Extend8S $($rest:tt)*) => {};
+    (emit I32Extend16S $($rest:tt)*) => {};
+    (emit I64Extend8S $($rest:tt)*) => {};
+    (emit I64Extend16S $($rest:tt)*) => {};
+    (emit I64Extend32S $($rest:tt)*) => {};
     (emit I64Clz $($rest:tt)*) => {};
     (emit I32Ctz $($rest:tt)*) => {};
     (emit I64Ctz $($rest:tt)*) => {};
@@ -170,7 +198,338 @@ macro_rules! def_unsupported {
     (emit TableGrow $($rest:tt)*) => {};
     (emit TableSize $($rest:tt)*) => {};
     (emit TableFill $($rest:tt)*) => {};
+    (emit MemorySize $($rest:tt)*) => {};
+    (emit MemoryGrow $($rest:tt)*) => {};
+    (emit MemoryCopy $($rest:tt)*) => {};
+    (emit MemoryFill $($rest:tt)*) => {};
+    (emit MemoryInit $($rest:tt)*) => {};
+    (emit DataDrop $($rest:tt)*) => {};
     (emit ElemDrop $($rest:tt)*) => {};
+    (emit RefNull $($rest:tt)*) => {};
+    (emit RefIsNull $($rest:tt)*) => {};
+    (emit RefFunc $($rest:tt)*) => {};
+    (emit V128Const $($rest:tt)*) => {};
+    (emit I8x16Add $($rest:tt)*) => {};
+    (emit I16x8Add $($rest:tt)*) => {};
+    (emit I32x4Add $($rest:tt)*) => {};
+    (emit I64x2Add $($rest:tt)*) => {};
+    (emit F32x4Add $($rest:tt)*) => {};
+    (emit F64x2Add $($rest:tt)*) => {};
+    (emit I8x16Sub $($rest:tt)*) => {};
+    (emit I16x8Sub $($rest:tt)*) => {};
+    (emit I32x4Sub $($rest:tt)*) => {};
+    (emit I64x2Sub $($rest:tt)*) => {};
+    (emit F32x4Sub $($rest:tt)*) => {};
+    (emit F64x2Sub $($rest:tt)*) => {};
+    (emit I16x8Mul $($rest:tt)*) => {};
+    (emit I32x4Mul $($rest:tt)*) => {};
+    (emit F32x4Mul $($rest:tt)*) => {};
+    (emit F64x2Mul $($rest:tt)*) => {};
+    (emit F32x4Div $($rest:tt)*) => {};
+    (emit F64x2Div $($rest:tt)*) => {};
+    (emit V128And $($rest:tt)*) => {};
+    (emit V128AndNot $($rest:tt)*) => {};
+    (emit V128Or $($rest:tt)*) => {};
+    (emit V128Xor $($rest:tt)*) => {};
+    (emit V128Not $($rest:tt)*) => {};
+    (emit I8x16Eq $($rest:tt)*) => {};
+    (emit I8x16Ne $($rest:tt)*) => {};
+    (emit I8x16LtS $($rest:tt)*) => {};
+    (emit I8x16GtS $($rest:tt)*) => {};
+    (emit I8x16LeS $($rest:tt)*) => {};
+    (emit I8x16GeS $($rest:tt)*) => {};
+    (emit I16x8Eq $($rest:tt)*) => {};
+    (emit I16x8Ne $($rest:tt)*) => {};
+    (emit I16x8LtS $($rest:tt)*) => {};
+    (emit I16x8GtS $($rest:tt)*) => {};
+    (emit I16x8LeS $($rest:tt)*) => {};
+    (emit I16x8GeS $($rest:tt)*) => {};
+    (emit I32x4Eq $($rest:tt)*) => {};
+    (emit I32x4Ne $($rest:tt)*) => {};
+    (emit I32x4LtS $($rest:tt)*) => {};
+    (emit I32x4GtS $($rest:tt)*) => {};
+    (emit I32x4LeS $($rest:tt)*) => {};
+    (emit I32x4GeS $($rest:tt)*) => {};
+    (emit I64x2Eq $($rest:tt)*) => {};
+    (emit I64x2Ne $($rest:tt)*) => {};
+    (emit I64x2LtS $($rest:tt)*) => {};
+    (emit I64x2GtS $($rest:tt)*) => {};
+    (emit I64x2LeS $($rest:tt)*) => {};
+    (emit I64x2GeS $($rest:tt)*) => {};
+    (emit F32x4Eq $($rest:tt)*) => {};
+    (emit F32x4Ne $($rest:tt)*) => {};
+    (emit F32x4Lt $($rest:tt)*) => {};
+    (emit F32x4Gt $($rest:tt)*) => {};
+    (emit F32x4Le $($rest:tt)*) => {};
+    (emit F32x4Ge $($rest:tt)*) => {};
+    (emit F64x2Eq $($rest:tt)*) => {};
+    (emit F64x2Ne $($rest:tt)*) => {};
+    (emit F64x2Lt $($rest:tt)*) => {};
+    (emit F64x2Gt $($rest:tt)*) => {};
+    (emit F64x2Le $($rest:tt)*) => {};
+    (emit F64x2Ge $($rest:tt)*) => {};
+    (emit F32x4ConvertI32x4S $($rest:tt)*) => {};
+    (emit F64x2ConvertLowI32x4S $($rest:tt)*) => {};
+    (emit F32x4DemoteF64x2Zero $($rest:tt)*) => {};
+    (emit F64x2PromoteLowF32x4 $($rest:tt)*) => {};
+    (emit I8x16Splat $($rest:tt)*) => {};
+    (emit I16x8Splat $($rest:tt)*) => {};
+    (emit I32x4Splat $($rest:tt)*) => {};
+    (emit I64x2Splat $($rest:tt)*) => {};
+    (emit F32x4Splat $($rest:tt)*) => {};
+    (emit F64x2Splat $($rest:tt)*) => {};
+    (emit I8x16Shuffle $($rest:tt)*) => {};
+    (emit V128Load $($rest:tt)*) => {};
+    (emit V128Load8x8S $($rest:tt)*) => {};
+    (emit V128Load8x8U $($rest:tt)*) => {};
+    (emit V128Load16x4S $($rest:tt)*) => {};
+    (emit V128Load16x4U $($rest:tt)*) => {};
+    (emit V128Load32x2S $($rest:tt)*) => {};
+    (emit V128Load32x2U $($rest:tt)*) => {};
+    (emit V128Load8Splat $($rest:tt)*) => {};
+    (emit V128Load16Splat $($rest:tt)*) => {};
+    (emit V128Load32Splat $($rest:tt)*) => {};
+    (emit V128Load64Splat $($rest:tt)*) => {};
+    (emit V128Load32Zero $($rest:tt)*) => {};
+    (emit V128Load64Zero $($rest:tt)*) => {};
+    (emit V128Store $($rest:tt)*) => {};
+    (emit V128Load8Lane $($rest:tt)*) => {};
+    (emit V128Load16Lane $($rest:tt)*) => {};
+    (emit V128Load32Lane $($rest:tt)*) => {};
+    (emit V128Load64Lane $($rest:tt)*) => {};
+    (emit V128Store8Lane $($rest:tt)*) => {};
+    (emit V128Store16Lane $($rest:tt)*) => {};
+    (emit V128Store32Lane $($rest:tt)*) => {};
+    (emit V128Store64Lane $($rest:tt)*) => {};
+    (emit I8x16ExtractLaneS $($rest:tt)*) => {};
+    (emit I8x16ExtractLaneU $($rest:tt)*) => {};
+    (emit I16x8ExtractLaneS $($rest:tt)*) => {};
+    (emit I16x8ExtractLaneU $($rest:tt)*) => {};
+    (emit I32x4ExtractLane $($rest:tt)*) => {};
+    (emit I64x2ExtractLane $($rest:tt)*) => {};
+    (emit F32x4ExtractLane $($rest:tt)*) => {};
+    (emit F64x2ExtractLane $($rest:tt)*) => {};
+    (emit I8x16ReplaceLane $($rest:tt)*) => {};
+    (emit I16x8ReplaceLane $($rest:tt)*) => {};
+    (emit I32x4ReplaceLane $($rest:tt)*) => {};
+    (emit I64x2ReplaceLane $($rest:tt)*) => {};
+    (emit F32x4ReplaceLane $($rest:tt)*) => {};
+    (emit F64x2ReplaceLane $($rest:tt)*) => {};
+    (emit I8x16Swizzle $($rest:tt)*) => {};
+    (emit V128Bitselect $($rest:tt)*) => {};
+    (emit V128AnyTrue $($rest:tt)*) => {};
+    (emit I8x16AllTrue $($rest:tt)*) => {};
+    (emit I16x8AllTrue $($rest:tt)*) => {};
+    (emit I32x4AllTrue $($rest:tt)*) => {};
+    (emit I64x2AllTrue $($rest:tt)*) => {};
+    (emit I8x16Bitmask $($rest:tt)*) => {};
+    (emit I16x8Bitmask $($rest:tt)*) => {};
+    (emit I32x4Bitmask $($rest:tt)*) => {};
+    (emit I64x2Bitmask $($rest:tt)*) => {};
+    (emit I8x16Abs $($rest:tt)*) => {};
+    (emit I16x8Abs $($rest:tt)*) => {};
+    (emit I32x4Abs $($rest:tt)*) => {};
+    (emit I64x2Abs $($rest:tt)*) => {};
+    (emit F32x4Abs $($rest:tt)*) => {};
+    (emit F64x2Abs $($rest:tt)*) => {};
+    (emit I8x16Neg $($rest:tt)*) => {};
+    (emit I16x8Neg $($rest:tt)*) => {};
+    (emit I32x4Neg $($rest:tt)*) => {};
+    (emit I64x2Neg $($rest:tt)*) => {};
+    (emit F32x4Neg $($rest:tt)*) => {};
+    (emit F64x2Neg $($rest:tt)*) => {};
+    (emit I8x16Popcnt $($rest:tt)*) => {};
+    (emit F32x4Sqrt $($rest:tt)*) => {};
+    (emit F32x4Ceil $($rest:tt)*) => {};
+    (emit F32x4Floor $($rest:tt)*) => {};
+    (emit F32x4Trunc $($rest:tt)*) => {};
+    (emit F32x4Nearest $($rest:tt)*) => {};
+    (emit F64x2Sqrt $($rest:tt)*) => {};
+    (emit F64x2Ceil $($rest:tt)*) => {};
+    (emit F64x2Floor $($rest:tt)*) => {};
+    (emit F64x2Trunc $($rest:tt)*) => {};
+    (emit F64x2Nearest $($rest:tt)*) => {};
+    (emit I8x16Shl $($rest:tt)*) => {};
+    (emit I8x16ShrS $($rest:tt)*) => {};
+    (emit I8x16ShrU $($rest:tt)*) => {};
+    (emit I16x8Shl $($rest:tt)*) => {};
+    (emit I16x8ShrS $($rest:tt)*) => {};
+    (emit I16x8ShrU $($rest:tt)*) => {};
+    (emit I32x4Shl $($rest:tt)*) => {};
+    (emit I32x4ShrS $($rest:tt)*) => {};
+    (emit I32x4ShrU $($rest:tt)*) => {};
+    (emit I64x2Shl $($rest:tt)*) => {};
+    (emit I64x2ShrS $($rest:tt)*) => {};
+    (emit I64x2ShrU $($rest:tt)*) => {};
+    (emit I8x16AddSatS $($rest:tt)*) => {};
+    (emit I8x16AddSatU $($rest:tt)*) => {};
+    (emit I8x16SubSatS $($rest:tt)*) => {};
+    (emit I8x16SubSatU $($rest:tt)*) => {};
+    (emit I16x8AddSatS $($rest:tt)*) => {};
+    (emit I16x8AddSatU $($rest:tt)*) => {};
+    (emit I16x8SubSatS $($rest:tt)*) => {};
+    (emit I16x8SubSatU $($rest:tt)*) => {};
+    (emit I8x16MinS $($rest:tt)*) => {};
+    (emit I8x16MinU $($rest:tt)*) => {};
+    (emit I8x16MaxS $($rest:tt)*) => {};
+    (emit I8x16MaxU $($rest:tt)*) => {};
+    (emit I16x8MinS $($rest:tt)*) => {};
+    (emit I16x8MinU $($rest:tt)*) => {};
+    (emit I16x8MaxS $($rest:tt)*) => {};
+    (emit I16x8MaxU $($rest:tt)*) => {};
+    (emit I32x4MinS $($rest:tt)*) => {};
+    (emit I32x4MinU $($rest:tt)*) => {};
+    (emit I32x4MaxS $($rest:tt)*) => {};
+    (emit I32x4MaxU $($rest:tt)*) => {};
+    (emit I8x16AvgrU $($rest:tt)*) => {};
+    (emit I16x8AvgrU $($rest:tt)*) => {};
+    (emit F32x4Min $($rest:tt)*) => {};
+    (emit F32x4Max $($rest:tt)*) => {};
+    (emit F32x4PMin $($rest:tt)*) => {};
+    (emit F32x4PMax $($rest:tt)*) => {};
+    (emit F64x2Min $($rest:tt)*) => {};
+    (emit F64x2Max $($rest:tt)*) => {};
+    (emit F64x2PMin $($rest:tt)*) => {};
+    (emit F64x2PMax $($rest:tt)*) => {};
+    (emit I8x16LtU $($rest:tt)*) => {};
+    (emit I8x16GtU $($rest:tt)*) => {};
+    (emit I8x16LeU $($rest:tt)*) => {};
+    (emit I8x16GeU $($rest:tt)*) => {};
+    (emit I16x8LtU $($rest:tt)*) => {};
+    (emit I16x8GtU $($rest:tt)*) => {};
+    (emit I16x8LeU $($rest:tt)*) => {};
+    (emit I16x8GeU $($rest:tt)*) => {};
+    (emit I32x4LtU $($rest:tt)*) => {};
+    (emit I32x4GtU $($rest:tt)*) => {};
+    (emit I32x4LeU $($rest:tt)*) => {};
+    (emit I32x4GeU $($rest:tt)*) => {};
+    (emit I64x2Mul $($rest:tt)*) => {};
+    (emit I8x16NarrowI16x8S $($rest:tt)*) => {};
+    (emit I8x16NarrowI16x8U $($rest:tt)*) => {};
+    (emit I16x8NarrowI32x4S $($rest:tt)*) => {};
+    (emit I16x8NarrowI32x4U $($rest:tt)*) => {};
+    (emit I16x8ExtendLowI8x16S $($rest:tt)*) => {};
+    (emit I16x8ExtendLowI8x16U $($rest:tt)*) => {};
+    (emit I16x8ExtendHighI8x16S $($rest:tt)*) => {};
+    (emit I16x8ExtendHighI8x16U $($rest:tt)*) => {};
+    (emit I32x4ExtendLowI16x8S $($rest:tt)*) => {};
+    (emit I32x4ExtendLowI16x8U $($rest:tt)*) => {};
+    (emit I32x4ExtendHighI16x8S $($rest:tt)*) => {};
+    (emit I32x4ExtendHighI16x8U $($rest:tt)*) => {};
+    (emit I64x2ExtendLowI32x4S $($rest:tt)*) => {};
+    (emit I64x2ExtendLowI32x4U $($rest:tt)*) => {};
+    (emit I64x2ExtendHighI32x4S $($rest:tt)*) => {};
+    (emit I64x2ExtendHighI32x4U $($rest:tt)*) => {};
+    (emit I16x8ExtMulLowI8x16S $($rest:tt)*) => {};
+    (emit I16x8ExtMulLowI8x16U $($rest:tt)*) => {};
+    (emit I16x8ExtMulHighI8x16S $($rest:tt)*) => {};
+    (emit I16x8ExtMulHighI8x16U $($rest:tt)*) => {};
+    (emit I32x4ExtMulLowI16x8S $($rest:tt)*) => {};
+    (emit I32x4ExtMulLowI16x8U $($rest:tt)*) => {};
+    (emit I32x4ExtMulHighI16x8S $($rest:tt)*) => {};
+    (emit I32x4ExtMulHighI16x8U $($rest:tt)*) => {};
+    (emit I64x2ExtMulLowI32x4S $($rest:tt)*) => {};
+    (emit I64x2ExtMulLowI32x4U $($rest:tt)*) => {};
+    (emit I64x2ExtMulHighI32x4S $($rest:tt)*) => {};
+    (emit I64x2ExtMulHighI32x4U $($rest:tt)*) => {};
+    (emit I16x8ExtAddPairwiseI8x16S $($rest:tt)*) => {};
+    (emit I16x8ExtAddPairwiseI8x16U $($rest:tt)*) => {};
+    (emit I32x4ExtAddPairwiseI16x8S $($rest:tt)*) => {};
+    (emit I32x4ExtAddPairwiseI16x8U $($rest:tt)*) => {};
+    (emit I16x8Q15MulrSatS $($rest:tt)*) => {};
+    (emit I32x4DotI16x8S $($rest:tt)*) => {};
+    (emit F32x4ConvertI32x4U $($rest:tt)*) => {};
+    (emit F64x2ConvertLowI32x4U $($rest:tt)*) => {};
+    (emit I32x4TruncSatF32x4S $($rest:tt)*) => {};
+    (emit I32x4TruncSatF32x4U $($rest:tt)*) => {};
+    (emit I32x4TruncSatF64x2SZero $($rest:tt)*) => {};
+    (emit I32x4TruncSatF64x2UZero $($rest:tt)*) => {};
+    (emit I32Load $($rest:tt)*) => {};
+    (emit I64Load $($rest:tt)*) => {};
+    (emit I32Load8S $($rest:tt)*) => {};
+    (emit I32Load8U $($rest:tt)*) => {};
+    (emit I32Load16S $($rest:tt)*) => {};
+    (emit I32Load16U $($rest:tt)*) => {};
+    (emit I64Load8S $($rest:tt)*) => {};
+    (emit I64Load8U $($rest:tt)*) => {};
+    (emit I64Load16S $($rest:tt)*) => {};
+    (emit I64Load16U $($rest:tt)*) => {};
+    (emit I64Load32S $($rest:tt)*) => {};
+    (emit I64Load32U $($rest:tt)*) => {};
+    (emit I32Store $($rest:tt)*) => {};
+    (emit I64Store $($rest:tt)*) => {};
+    (emit I32Store8 $($rest:tt)*) => {};
+    (emit I32Store16 $($rest:tt)*) => {};
+    (emit I64Store8 $($rest:tt)*) => {};
+    (emit I64Store16 $($rest:tt)*) => {};
+    (emit I64Store32 $($rest:tt)*) => {};
+    (emit AtomicFence $($rest:tt)*) => {};
+    (emit I32AtomicLoad $($rest:tt)*) => {};
+    (emit I32AtomicLoad8U $($rest:tt)*) => {};
+    (emit I32AtomicLoad16U $($rest:tt)*) => {};
+    (emit I32AtomicStore $($rest:tt)*) => {};
+    (emit I32AtomicStore8 $($rest:tt)*) => {};
+    (emit I32AtomicStore16 $($rest:tt)*) => {};
+    (emit I32AtomicRmwAdd $($rest:tt)*) => {};
+    (emit I32AtomicRmwSub $($rest:tt)*) => {};
+    (emit I32AtomicRmwAnd $($rest:tt)*) => {};
+    (emit I32AtomicRmwOr $($rest:tt)*) => {};
+    (emit I32AtomicRmwXor $($rest:tt)*) => {};
+    (emit I32AtomicRmwXchg $($rest:tt)*) => {};
+    (emit I32AtomicRmwCmpxchg $($rest:tt)*) => {};
+    (emit I32AtomicRmw8AddU $($rest:tt)*) => {};
+    (emit I32AtomicRmw8SubU $($rest:tt)*) => {};
+    (emit I32AtomicRmw8AndU $($rest:tt)*) => {};
+    (emit I32AtomicRmw8OrU $($rest:tt)*) => {};
+    (emit I32AtomicRmw8XorU $($rest:tt)*) => {};
+    (emit I32AtomicRmw8XchgU $($rest:tt)*) => {};
+    (emit I32AtomicRmw8CmpxchgU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16AddU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16SubU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16AndU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16OrU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16XorU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16XchgU $($rest:tt)*) => {};
+    (emit I32AtomicRmw16CmpxchgU $($rest:tt)*) => {};
+    (emit I64AtomicLoad $($rest:tt)*) => {};
+    (emit I64AtomicLoad8U $($rest:tt)*) => {};
+    (emit I64AtomicLoad16U $($rest:tt)*) => {};
+    (emit I64AtomicLoad32U $($rest:tt)*) => {};
+    (emit I64AtomicStore $($rest:tt)*) => {};
+    (emit I64AtomicStore8 $($rest:tt)*) => {};
+    (emit I64AtomicStore16 $($rest:tt)*) => {};
+    (emit I64AtomicStore32 $($rest:tt)*) => {};
+    (emit I64AtomicRmwAdd $($rest:tt)*) => {};
+    (emit I64AtomicRmwSub $($rest:tt)*) => {};
+    (emit I64AtomicRmwAnd $($rest:tt)*) => {};
+    (emit I64AtomicRmwOr $($rest:tt)*) => {};
+    (emit I64AtomicRmwXor $($rest:tt)*) => {};
+    (emit I64AtomicRmwXchg $($rest:tt)*) => {};
+    (emit I64AtomicRmwCmpxchg $($rest:tt)*) => {};
+    (emit I64AtomicRmw8AddU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8SubU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8AndU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8OrU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8XorU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8XchgU $($rest:tt)*) => {};
+    (emit I64AtomicRmw8CmpxchgU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16AddU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16SubU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16AndU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16OrU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16XorU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16XchgU $($rest:tt)*) => {};
+    (emit I64AtomicRmw16CmpxchgU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32AddU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32SubU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32AndU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32OrU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32XorU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32XchgU $($rest:tt)*) => {};
+    (emit I64AtomicRmw32CmpxchgU $($rest:tt)*) => {};
+    (emit MemoryAtomicNotify $($rest:tt)*) => {};
+    (emit MemoryAtomicWait32 $($rest:tt)*) => {};
+    (emit MemoryAtomicWait64 $($rest:tt)*) => {};
 
     (emit $unsupported:tt $($rest:tt)*) => {$($rest)*};
 }
@@ -205,6 +564,7 @@ where
                 masm.float_add(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S32);
     }
 
     fn visit_f64_add(&mut self) {
@@ -215,6 +575,7 @@ where
                 masm.float_add(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S64);
     }
 
     fn visit_f32_sub(&mut self) {
@@ -225,6 +586,7 @@ where
                 masm.float_sub(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S32);
     }
 
     fn visit_f64_sub(&mut self) {
@@ -235,6 +597,7 @@ where
                 masm.float_sub(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S64);
     }
 
     fn visit_f32_mul(&mut self) {
@@ -245,6 +608,7 @@ where
                 masm.float_mul(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S32);
     }
 
     fn visit_f64_mul(&mut self) {
@@ -255,6 +619,7 @@ where
                 masm.float_mul(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S64);
     }
 
     fn visit_f32_div(&mut self) {
@@ -265,6 +630,7 @@ where
                 masm.float_div(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S32);
     }
 
     fn visit_f64_div(&mut self) {
@@ -275,6 +641,7 @@ where
                 masm.float_div(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S64);
     }
 
     fn visit_f32_min(&mut self) {
@@ -285,6 +652,7 @@ where
                 masm.float_min(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S32);
     }
 
     fn visit_f64_min(&mut self) {
@@ -295,6 +663,7 @@ where
                 masm.float_min(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S64);
     }
 
     fn visit_f32_max(&mut self) {
@@ -305,6 +674,7 @@ where
                 masm.float_max(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S32);
     }
 
     fn visit_f64_max(&mut self) {
@@ -315,6 +685,7 @@ where
                 masm.float_max(dst, dst, src, size);
             },
         );
+        self.canonicalize_nan(OperandSize::S64);
     }
 
     fn visit_f32_copysign(&mut self) {
@@ -368,41 +739,49 @@ where
     fn visit_f32_floor(&mut self) {
         self.masm
             .float_round(RoundingMode::Down, &mut self.context, OperandSize::S32);
+        self.canonicalize_nan(OperandSize::S32);
     }
 
     fn visit_f64_floor(&mut self) {
         self.masm
             .float_round(RoundingMode::Down, &mut self.context, OperandSize::S64);
+        self.canonicalize_nan(OperandSize::S64);
     }
 
     fn visit_f32_ceil(&mut self) {
         self.masm
             .float_round(RoundingMode::Up, &mut self.context, OperandSize::S32);
+        self.canonicalize_nan(OperandSize::S32);
     }
 
     fn visit_f64_ceil(&mut self) {
         self.masm
             .float_round(RoundingMode::Up, &mut self.context, OperandSize::S64);
+        self.canonicalize_nan(OperandSize::S64);
     }
 
     fn visit_f32_nearest(&mut self) {
         self.masm
             .float_round(RoundingMode::Nearest, &mut self.context, OperandSize::S32);
+        self.canonicalize_nan(OperandSize::S32);
     }
 
     fn visit_f64_nearest(&mut self) {
         self.masm
             .float_round(RoundingMode::Nearest, &mut self.context, OperandSize::S64);
+        self.canonicalize_nan(OperandSize::S64);
     }
 
     fn visit_f32_trunc(&mut self) {
         self.masm
             .float_round(RoundingMode::Zero, &mut self.context, OperandSize::S32);
+        self.canonicalize_nan(OperandSize::S32);
     }
 
     fn visit_f64_trunc(&mut self) {
         self.masm
             .float_round(RoundingMode::Zero, &mut self.context, OperandSize::S64);
+        self.canonicalize_nan(OperandSize::S64);
     }
 
     fn visit_f32_sqrt(&mut self) {
@@ -410,6 +789,7 @@ where
             .unop(self.masm, OperandSize::S32, &mut |masm, reg, size| {
                 masm.float_sqrt(reg, reg, size);
             });
+        self.canonicalize_nan(OperandSize::S32);
     }
 
     fn visit_f64_sqrt(&mut self) {
@@ -417,6 +797,7 @@ where
             .unop(self.masm, OperandSize::S64, &mut |masm, reg, size| {
                 masm.float_sqrt(reg, reg, size);
             });
+        self.canonicalize_nan(OperandSize::S64);
     }
 
     fn visit_f32_eq(&mut self) {
@@ -539,28 +920,248 @@ where
         );
     }
 
+    fn visit_i32_trunc_f32_s(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, dst_size| {
+                masm.signed_truncate(dst, src, OperandSize::S32, dst_size, TruncKind::Checked);
+            });
+    }
+
+    fn visit_i32_trunc_f32_u(&mut self) {
+        self.context.convert_op_with_tmp_reg(
+            self.masm,
+            WasmType::I32,
+            RegClass::Float,
+            |masm, dst, src, tmp_fpr, dst_size| {
+                masm.unsigned_truncate(
+                    dst,
+                    src,
+                    tmp_fpr,
+                    OperandSize::S32,
+                    dst_size,
+                    TruncKind::Checked,
+                );
+            },
+        );
+    }
+
+    fn visit_i32_trunc_f64_s(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, dst_size| {
+                masm.signed_truncate(dst, src, OperandSize::S64, dst_size, TruncKind::Checked);
+            });
+    }
+
+    fn visit_i32_trunc_f64_u(&mut self) {
+        self.context.convert_op_with_tmp_reg(
+            self.masm,
+            WasmType::I32,
+            RegClass::Float,
+            |masm, dst, src, tmp_fpr, dst_size| {
+                masm.unsigned_truncate(
+                    dst,
+                    src,
+                    tmp_fpr,
+                    OperandSize::S64,
+                    dst_size,
+                    TruncKind::Checked,
+                );
+            },
+        );
+    }
+
+    fn visit_i64_trunc_f32_s(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I64, |masm, dst, src, dst_size| {
+                masm.signed_truncate(dst, src, OperandSize::S32, dst_size, TruncKind::Checked);
+            });
+    }
+
+    fn visit_i64_trunc_f32_u(&mut self) {
+        self.context.convert_op_with_tmp_reg(
+            self.masm,
+            WasmType::I64,
+            RegClass::Float,
+            |masm, dst, src, tmp_fpr, dst_size| {
+                masm.unsigned_truncate(
+                    dst,
+                    src,
+                    tmp_fpr,
+                    OperandSize::S32,
+                    dst_size,
+                    TruncKind::Checked,
+                );
+            },
+        );
+    }
+
+    fn visit_i64_trunc_f64_s(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I64, |masm, dst, src, dst_size| {
+                masm.signed_truncate(dst, src, OperandSize::S64, dst_size, TruncKind::Checked);
+            });
+    }
+
+    fn visit_i64_trunc_f64_u(&mut self) {
+        self.context.convert_op_with_tmp_reg(
+            self.masm,
+            WasmType::I64,
+            RegClass::Float,
+            |masm, dst, src, tmp_fpr, dst_size| {
+                masm.unsigned_truncate(
+                    dst,
+                    src,
+                    tmp_fpr,
+                    OperandSize::S64,
+                    dst_size,
+                    TruncKind::Checked,
+                );
+            },
+        );
+    }
+
+    fn visit_i32_trunc_sat_f32_s(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, dst_size| {
+                masm.signed_truncate(dst, src, OperandSize::S32, dst_size, TruncKind::Saturating);
+            });
+    }
+
+    fn visit_i32_trunc_sat_f32_u(&mut self) {
+        self.context.convert_op_with_tmp_reg(
+            self.masm,
+            WasmType::I32,
+            RegClass::Float,
+            |masm, dst, src, tmp_fpr, dst_size| {
+                masm.unsigned_truncate(
+                    dst,
+                    src,
+                    tmp_fpr,
+                    OperandSize::S32,
+                    dst_size,
+                    TruncKind::Saturating,
+                );
+            },
+        );
+    }
+
+    fn visit_i32_trunc_sat_f64_s(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, dst_size| {
+                masm.signed_truncate(dst, src, OperandSize::S64, dst_size, TruncKind::Saturating);
+            });
+    }
+
+    fn visit_i32_trunc_sat_f64_u(&mut self) {
+        self.context.convert_op_with_tmp_reg(
+            self.masm,
+            WasmType::I32,
+            RegClass::Float,
+            |masm, dst, src, tmp_fpr, dst_size| {
+                masm.unsigned_truncate(
+                    dst,
+                    src,
+                    tmp_fpr,
+                    OperandSize::S64,
+                    dst_size,
+                    TruncKind::Saturating,
+                );
+            },
+        );
+    }
+
+    fn visit_i64_trunc_sat_f32_s(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I64, |masm, dst, src, dst_size| {
+                masm.signed_truncate(dst, src, OperandSize::S32, dst_size, TruncKind::Saturating);
+            });
+    }
+
+    fn visit_i64_trunc_sat_f32_u(&mut self) {
+        self.context.convert_op_with_tmp_reg(
+            self.masm,
+            WasmType::I64,
+            RegClass::Float,
+            |masm, dst, src, tmp_fpr, dst_size| {
+                masm.unsigned_truncate(
+                    dst,
+                    src,
+                    tmp_fpr,
+                    OperandSize::S32,
+                    dst_size,
+                    TruncKind::Saturating,
+                );
+            },
+        );
+    }
+
+    fn visit_i64_trunc_sat_f64_s(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I64, |masm, dst, src, dst_size| {
+                masm.signed_truncate(dst, src, OperandSize::S64, dst_size, TruncKind::Saturating);
+            });
+    }
+
+    fn visit_i64_trunc_sat_f64_u(&mut self) {
+        self.context.convert_op_with_tmp_reg(
+            self.masm,
+            WasmType::I64,
+            RegClass::Float,
+            |masm, dst, src, tmp_fpr, dst_size| {
+                masm.unsigned_truncate(
+                    dst,
+                    src,
+                    tmp_fpr,
+                    OperandSize::S64,
+                    dst_size,
+                    TruncKind::Saturating,
+                );
+            },
+        );
+    }
+
     fn visit_i32_add(&mut self) {
-        self.context.i32_binop(self.masm, |masm, dst, src, size| {
-            masm.add(dst, dst, src, size);
-        });
+        self.context.alu_op(
+            self.masm,
+            AluKind::Add,
+            OperandSize::S32,
+            |masm, dst, src, size| {
+                masm.add(dst, dst, src, size);
+            },
+        );
     }
 
     fn visit_i64_add(&mut self) {
-        self.context.i64_binop(self.masm, |masm, dst, src, size| {
-            masm.add(dst, dst, src, size);
-        });
+        self.context.alu_op(
+            self.masm,
+            AluKind::Add,
+            OperandSize::S64,
+            |masm, dst, src, size| {
+                masm.add(dst, dst, src, size);
+            },
+        );
     }
 
     fn visit_i32_sub(&mut self) {
-        self.context.i32_binop(self.masm, |masm, dst, src, size| {
-            masm.sub(dst, dst, src, size);
-        });
+        self.context.alu_op(
+            self.masm,
+            AluKind::Sub,
+            OperandSize::S32,
+            |masm, dst, src, size| {
+                masm.sub(dst, dst, src, size);
+            },
+        );
     }
 
     fn visit_i64_sub(&mut self) {
-        self.context.i64_binop(self.masm, |masm, dst, src, size| {
-            masm.sub(dst, dst, src, size);
-        });
+        self.context.alu_op(
+            self.masm,
+            AluKind::Sub,
+            OperandSize::S64,
+            |masm, dst, src, size| {
+                masm.sub(dst, dst, src, size);
+            },
+        );
     }
 
     fn visit_i32_mul(&mut self) {
@@ -727,6 +1328,71 @@ where
         });
     }
 
+    fn visit_i32_wrap_i64(&mut self) {
+        self.context
+            .convert_op(self.masm, WasmType::I32, |masm, dst, src, size| {
+                masm.mov(src.into(), dst, size);
+            });
+    }
+
+    fn visit_i64_extend_i32_s(&mut self) {
+        use OperandSize::*;
+
+        self.context
+            .convert_op(self.masm, WasmType::I64, |masm, dst, src, size| {
+                masm.extend(dst, src, S32, size, ExtendKind::Signed);
+            });
+    }
+
+    fn visit_i64_extend_i32_u(&mut self) {
+        use OperandSize::*;
+
+        self.context
+            .convert_op(self.masm, WasmType::I64, |masm, dst, src, size| {
+                masm.extend(dst, src, S32, size, ExtendKind::Unsigned);
+            });
+    }
+
+    fn visit_i32_extend8_s(&mut self) {
+        use OperandSize::*;
+
+        self.context.unop(self.masm, S32, &mut |masm, reg, size| {
+            masm.extend(reg, reg, S8, size, ExtendKind::Signed);
+        });
+    }
+
+    fn visit_i32_extend16_s(&mut self) {
+        use OperandSize::*;
+
+        self.context.unop(self.masm, S32, &mut |masm, reg, size| {
+            masm.extend(reg, reg, S16, size, ExtendKind::Signed);
+        });
+    }
+
+    fn visit_i64_extend8_s(&mut self) {
+        use OperandSize::*;
+
+        self.context.unop(self.masm, S64, &mut |masm, reg, size| {
+            masm.extend(reg, reg, S8, size, ExtendKind::Signed);
+        });
+    }
+
+    fn visit_i64_extend16_s(&mut self) {
+        use OperandSize::*;
+
+        self.context.unop(self.masm, S64, &mut |masm, reg, size| {
+            masm.extend(reg, reg, S16, size, ExtendKind::Signed);
+        });
+    }
+
+    fn visit_i64_extend32_s(&mut self) {
+        use OperandSize::*;
+
+        self.context.unop(self.masm, S64, &mut |masm, reg, size| {
+            masm.extend(reg, reg, S32, size, ExtendKind::Signed);
+        });
+    }
+
     fn visit_i32_clz(&mut self) {
         use OperandSize::*;
 
@@ -760,39 +1426,69 @@ where
     }
 
     fn visit_i32_and(&mut self) {
-        self.context.i32_binop(self.masm, |masm, dst, src, size| {
-            masm.and(dst, dst, src, size);
-        });
+        self.context.alu_op(
+            self.masm,
+            AluKind::And,
+            OperandSize::S32,
+            |masm, dst, src, size| {
+                masm.and(dst, dst, src, size);
+            },
+        );
     }
 
     fn visit_i64_and(&mut self) {
-        self.context.i64_binop(self.masm, |masm, dst, src, size| {
-            masm.and(dst, dst, src, size);
-        });
+        self.context.alu_op(
+            self.masm,
+            AluKind::And,
+            OperandSize::S64,
+            |masm, dst, src, size| {
+                masm.and(dst, dst, src, size);
+            },
+        );
     }
 
     fn visit_i32_or(&mut self) {
-        self.context.i32_binop(self.masm, |masm, dst, src, size| {
-            masm.or(dst, dst, src, size);
-        });
+        self.context.alu_op(
+            self.masm,
+            AluKind::Or,
+            OperandSize::S32,
+            |masm, dst, src, size| {
+                masm.or(dst, dst, src, size);
+            },
+        );
     }
 
     fn visit_i64_or(&mut self) {
-        self.context.i64_binop(self.masm, |masm, dst, src, size| {
-            masm.or(dst, dst, src, size);
-        });
+        self.context.alu_op(
+            self.masm,
+            AluKind::Or,
+            OperandSize::S64,
+            |masm, dst, src, size| {
+                masm.or(dst, dst, src, size);
+            },
+        );
     }
 
     fn visit_i32_xor(&mut self) {
-        self.context.i32_binop(self.masm, |masm, dst, src, size| {
-            masm.xor(dst, dst, src, size);
-        });
+        self.context.alu_op(
+            self.masm,
+            AluKind::Xor,
+            OperandSize::S32,
+            |masm, dst, src, size| {
+                masm.xor(dst, dst, src, size);
+            },
+        );
     }
 
     fn visit_i64_xor(&mut self) {
-        self.context.i64_binop(self.masm, |masm, dst, src, size| {
-            masm.xor(dst, dst, src, size);
-        });
+        self.context.alu_op(
+            self.masm,
+            AluKind::Xor,
+            OperandSize::S64,
+            |masm, dst, src, size| {
+                masm.xor(dst, dst, src, size);
+            },
+        );
     }
 
     fn visit_i32_shl(&mut self) {
@@ -903,19 +1599,19 @@ where
             .get_local(index)
             .unwrap_or_else(|| panic!("valid local at slot = {}", index));
         match slot.ty {
-            I32 | I64 | F32 | F64 => context.stack.push(Val::local(index, slot.ty)),
+            I32 | I64 | F32 | F64 | V128 => context.stack.push(Val::local(index, slot.ty)),
             Ref(rt) => match rt.heap_type {
-                WasmHeapType::Func => context.stack.push(Val::local(index, slot.ty)),
+                WasmHeapType::Func | WasmHeapType::Extern => {
+                    context.stack.push(Val::local(index, slot.ty))
+                }
                 ht => unimplemented!("Support for WasmHeapType: {ht}"),
             },
-            t => unimplemented!("Support local type: {t}"),
         }
     }
 
     // TODO: verify the case where the target local is on the stack.
     fn visit_local_set(&mut self, index: u32) {
-        let (ty, slot) = self.context.frame.get_local_address(index, self.masm);
-        let src = self.emit_set_local(slot, ty.into());
+        let src = self.emit_set_local(index);
         self.context.free_reg(src);
     }
 
@@ -996,6 +1692,22 @@ where
             WasmHeapType::Func => match style {
                 TableStyle::CallerChecksSignature => self.emit_lazy_init_funcref(table_index),
             },
+            WasmHeapType::Extern => {
+                // Reading an `externref` table element goes through the
+                // runtime, which takes care of the bounds check, the reference
+                // count and of rooting the reference in the activations table.
+                let ty = WasmType::Ref(plan.table.wasm_ty);
+                let vmctx = TypedReg::new(self.env.ptr_type(), <M::ABI as ABI>::vmctx_reg());
+                let at = self.context.stack.len() - 1;
+                self.context
+                    .stack
+                    .insert_many(at, [vmctx.into(), table.try_into().unwrap()]);
+                FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+                    Callee::Builtin(cx.builtins.table_get_externref::<M::ABI, M::Ptr>())
+                });
+                let value = self.context.pop_to_reg(self.masm, None);
+                self.context.stack.push(TypedReg::new(ty, value.reg).into());
+            }
             t => unimplemented!("Support for WasmHeapType: {t}"),
         }
     }
@@ -1010,6 +1722,10 @@ where
                 .context
                 .builtins
                 .table_grow_func_ref::<M::ABI, M::Ptr>(),
+            WasmHeapType::Extern => self
+                .context
+                .builtins
+                .table_grow_externref::<M::ABI, M::Ptr>(),
             ty => unimplemented!("Support for HeapType: {ty}"),
         };
 
@@ -1040,6 +1756,128 @@ where
         self.masm.table_size(&table_data, &mut self.context);
     }
 
+    fn visit_memory_size(&mut self, mem: u32, _: u8) {
+        let heap_data = self.env.resolve_heap_data(MemoryIndex::from_u32(mem));
+        self.masm.memory_size(&heap_data, &mut self.context);
+    }
+
+    fn visit_memory_grow(&mut self, mem: u32, _: u8) {
+        let ptr_type = self.env.ptr_type();
+        let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+        let builtin = self.context.builtins.memory32_grow::<M::ABI, M::Ptr>();
+
+        // The builtin takes the number of pages as a 64-bit value.
+        let index_type = self.memory_index_type(mem);
+        let delta = self.pop_index(index_type);
+        self.context
+            .stack
+            .extend([vmctx.into(), delta.into(), mem.try_into().unwrap()]);
+
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |_| {
+            Callee::Builtin(builtin.clone())
+        });
+
+        // The builtin returns the previous size in pages, or -1 as a
+        // pointer-sized value on failure, whose low bits are the wasm result.
+        let result = self.context.pop_to_reg(self.masm, None);
+        self.context
+            .stack
+            .push(TypedReg::new(index_type, result.reg).into());
+    }
+
+    fn visit_memory_copy(&mut self, dst_mem: u32, src_mem: u32) {
+        let ptr_type = self.env.ptr_type();
+        let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+        let builtin = self.context.builtins.memory_copy::<M::ABI, M::Ptr>();
+
+        // The value stack contains [ dst | src | len ] (stack top), which the
+        // builtin expects as 64-bit values interleaved with the memory
+        // indices. The length is 64-bit only if both memories are.
+        let dst_type = self.memory_index_type(dst_mem);
+        let src_type = self.memory_index_type(src_mem);
+        let len_type = if dst_type == WasmType::I64 && src_type == WasmType::I64 {
+            WasmType::I64
+        } else {
+            WasmType::I32
+        };
+        let len = self.pop_index(len_type);
+        let src = self.pop_index(src_type);
+        let dst = self.pop_index(dst_type);
+        self.context.stack.extend([
+            vmctx.into(),
+            dst_mem.try_into().unwrap(),
+            dst.into(),
+            src_mem.try_into().unwrap(),
+            src.into(),
+            len.into(),
+        ]);
+
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |_| {
+            Callee::Builtin(builtin.clone())
+        });
+    }
+
+    fn visit_memory_fill(&mut self, mem: u32) {
+        let ptr_type = self.env.ptr_type();
+        let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+        let builtin = self.context.builtins.memory_fill::<M::ABI, M::Ptr>();
+
+        // The value stack contains [ dst | val | len ] (stack top); the
+        // destination and length are passed as 64-bit values.
+        let index_type = self.memory_index_type(mem);
+        let len = self.pop_index(index_type);
+        let val = self.context.pop_to_reg(self.masm, None);
+        let dst = self.pop_index(index_type);
+        self.context.stack.extend([
+            vmctx.into(),
+            mem.try_into().unwrap(),
+            dst.into(),
+            val.into(),
+            len.into(),
+        ]);
+
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |_| {
+            Callee::Builtin(builtin.clone())
+        });
+    }
+
+    fn visit_memory_init(&mut self, data: u32, mem: u32) {
+        let ptr_type = self.env.ptr_type();
+        let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+        let builtin = self.context.builtins.memory_init::<M::ABI, M::Ptr>();
+
+        // The value stack contains [ dst | src | len ] (stack top); only the
+        // destination, an address in the memory, is passed as a 64-bit value.
+        let index_type = self.memory_index_type(mem);
+        let len = self.context.pop_to_reg(self.masm, None);
+        let src = self.context.pop_to_reg(self.masm, None);
+        let dst = self.pop_index(index_type);
+        self.context.stack.extend([
+            vmctx.into(),
+            mem.try_into().unwrap(),
+            data.try_into().unwrap(),
+            dst.into(),
+            src.into(),
+            len.into(),
+        ]);
+
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |_| {
+            Callee::Builtin(builtin.clone())
+        });
+    }
+
+    fn visit_data_drop(&mut self, data: u32) {
+        let ptr_type = self.env.ptr_type();
+        let data_drop = self.context.builtins.data_drop::<M::ABI, M::Ptr>();
+        let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+        self.context
+            .stack
+            .extend([vmctx.into(), data.try_into().unwrap()]);
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |_| {
+            Callee::Builtin(data_drop.clone())
+        });
+    }
+
     fn visit_table_fill(&mut self, table: u32) {
         let ptr_type = self.env.ptr_type();
         let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
@@ -1050,6 +1888,10 @@ where
                 .context
                 .builtins
                 .table_fill_func_ref::<M::ABI, M::Ptr>(),
+            WasmHeapType::Extern => self
+                .context
+                .builtins
+                .table_fill_externref::<M::ABI, M::Ptr>(),
             ty => unimplemented!("Support for heap type: {ty}"),
         };
 
@@ -1097,6 +1939,18 @@ where
                     self.context.free_reg(base);
                 }
             },
+            WasmHeapType::Extern => {
+                // The runtime takes care of the bounds check and of the
+                // reference counts of both the old and the new element.
+                let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+                let at = self.context.stack.len() - 2;
+                self.context
+                    .stack
+                    .insert_many(at, [vmctx.into(), table.try_into().unwrap()]);
+                FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+                    Callee::Builtin(cx.builtins.table_set_externref::<M::ABI, M::Ptr>())
+                });
+            }
             ty => unimplemented!("Support for WasmHeapType: {ty}"),
         };
     }
@@ -1113,6 +1967,50 @@ where
         });
     }
 
+    fn visit_ref_null(&mut self, hty: HeapType) {
+        let heap_type = self.env.translation.module.convert_heap_type(hty);
+        match heap_type {
+            WasmHeapType::Func | WasmHeapType::Extern => {
+                let ty = WasmType::Ref(WasmRefType {
+                    nullable: true,
+                    heap_type,
+                });
+                let dst = self.context.any_gpr(self.masm);
+                self.masm.mov(RegImm::i64(0), dst, ty.into());
+                self.context.stack.push(TypedReg::new(ty, dst).into());
+            }
+            ht => unimplemented!("Support for WasmHeapType: {ht}"),
+        }
+    }
+
+    fn visit_ref_is_null(&mut self) {
+        let typed_reg = self.context.pop_to_reg(self.masm, None);
+        self.masm.cmp_with_set(
+            RegImm::i64(0),
+            typed_reg.reg,
+            IntCmpKind::Eq,
+            typed_reg.ty.into(),
+        );
+        self.context.stack.push(TypedReg::i32(typed_reg.reg).into());
+    }
+
+    fn visit_ref_func(&mut self, function_index: u32) {
+        let ptr_type = self.env.ptr_type();
+        let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+        self.context
+            .stack
+            .extend([vmctx.into(), function_index.try_into().unwrap()]);
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+            Callee::Builtin(cx.builtins.ref_func::<M::ABI, M::Ptr>())
+        });
+
+        // The builtin returns a plain pointer; retype it as a funcref.
+        let funcref = self.context.pop_to_reg(self.masm, None);
+        self.context
+            .stack
+            .push(TypedReg::new(WasmType::Ref(WasmRefType::FUNCREF), funcref.reg).into());
+    }
+
     fn visit_nop(&mut self) {}
 
     fn visit_if(&mut self, blockty: BlockType) {
@@ -1247,30 +2145,53 @@ where
     }
 
     fn visit_local_tee(&mut self, index: u32) {
-        let (ty, slot) = self.context.frame.get_local_address(index, self.masm);
-        let typed_reg = self.emit_set_local(slot, ty.into());
+        let typed_reg = self.emit_set_local(index);
         self.context.stack.push(typed_reg.into());
     }
 
     fn visit_global_get(&mut self, global_index: u32) {
         let index = GlobalIndex::from_u32(global_index);
-        let (ty, offset) = self.env.resolve_global_type_and_offset(index);
-        let addr = self
-            .masm
-            .address_at_reg(<M::ABI as ABI>::vmctx_reg(), offset);
+        let (ty, offset, imported) = self.env.resolve_global_type_and_offset(index);
+        if is_externref(ty) {
+            // Reading an `externref` global goes through the runtime, which
+            // takes care of the reference count and of rooting the
+            // reference in the activations table.
+            let vmctx = TypedReg::new(self.env.ptr_type(), <M::ABI as ABI>::vmctx_reg());
+            self.context
+                .stack
+                .extend([vmctx.into(), global_index.try_into().unwrap()]);
+            FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+                Callee::Builtin(cx.builtins.externref_global_get::<M::ABI, M::Ptr>())
+            });
+            let value = self.context.pop_to_reg(self.masm, None);
+            self.context.stack.push(TypedReg::new(ty, value.reg).into());
+            return;
+        }
         let dst = self.context.reg_for_type(ty, self.masm);
+        let addr = self.emit_global_addr(offset, imported);
         self.masm.load(addr, dst, ty.into());
         self.context.stack.push(Val::reg(dst, ty));
     }
 
     fn visit_global_set(&mut self, global_index: u32) {
         let index = GlobalIndex::from_u32(global_index);
-        let (ty, offset) = self.env.resolve_global_type_and_offset(index);
-        let addr = self
-            .masm
-            .address_at_reg(<M::ABI as ABI>::vmctx_reg(), offset);
+        let (ty, offset, imported) = self.env.resolve_global_type_and_offset(index);
+        if is_externref(ty) {
+            // The runtime takes care of the reference counts of both the old
+            // and the new value.
+            let vmctx = TypedReg::new(self.env.ptr_type(), <M::ABI as ABI>::vmctx_reg());
+            let at = self.context.stack.len() - 1;
+            self.context
+                .stack
+                .insert_many(at, [vmctx.into(), global_index.try_into().unwrap()]);
+            FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |cx| {
+                Callee::Builtin(cx.builtins.externref_global_set::<M::ABI, M::Ptr>())
+            });
+            return;
+        }
         let typed_reg = self.context.pop_to_reg(self.masm, None);
         self.context.free_reg(typed_reg.reg);
+        let addr = self.emit_global_addr(offset, imported);
         self.masm.store(typed_reg.reg.into(), addr, ty.into());
     }
 
@@ -1297,13 +2218,1996 @@ where
         self.context.free_reg(cond);
     }
 
-    wasmparser::for_each_operator!(def_unsupported);
-}
+    fn visit_v128_const(&mut self, val: V128) {
+        self.context
+            .stack
+            .push(Val::v128(u128::from_le_bytes(*val.bytes())));
+    }
+
+    fn visit_i8x16_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, VectorShape::I8x16));
+    }
+
+    fn visit_i16x8_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, VectorShape::I16x8));
+    }
+
+    fn visit_i32x4_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, VectorShape::I32x4));
+    }
+
+    fn visit_i64x2_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, VectorShape::I64x2));
+    }
+
+    fn visit_f32x4_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, VectorShape::F32x4));
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f64x2_add(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_add(dst, dst, src, VectorShape::F64x2));
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_i8x16_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, VectorShape::I8x16));
+    }
+
+    fn visit_i16x8_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, VectorShape::I16x8));
+    }
+
+    fn visit_i32x4_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, VectorShape::I32x4));
+    }
+
+    fn visit_i64x2_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, VectorShape::I64x2));
+    }
+
+    fn visit_f32x4_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, VectorShape::F32x4));
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f64x2_sub(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_sub(dst, dst, src, VectorShape::F64x2));
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_i16x8_mul(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_mul(dst, dst, src, VectorShape::I16x8));
+    }
+
+    fn visit_i32x4_mul(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_mul(dst, dst, src, VectorShape::I32x4));
+    }
+
+    fn visit_f32x4_mul(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_mul(dst, dst, src, VectorShape::F32x4));
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f64x2_mul(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_mul(dst, dst, src, VectorShape::F64x2));
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_f32x4_div(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_div(dst, dst, src, VectorShape::F32x4));
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f64x2_div(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_div(dst, dst, src, VectorShape::F64x2));
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_v128_and(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_and(dst, dst, src));
+    }
+
+    fn visit_v128_andnot(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_and_not(dst, dst, src));
+    }
+
+    fn visit_v128_or(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_or(dst, dst, src));
+    }
+
+    fn visit_v128_xor(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_xor(dst, dst, src));
+    }
+
+    fn visit_v128_not(&mut self) {
+        self.context
+            .unop(self.masm, OperandSize::S128, &mut |masm, reg, _size| {
+                masm.v128_not(reg);
+            });
+    }
+
+    fn visit_i8x16_eq(&mut self) {
+        self.v128_cmp(IntCmpKind::Eq, VectorShape::I8x16);
+    }
+
+    fn visit_i8x16_ne(&mut self) {
+        self.v128_cmp(IntCmpKind::Ne, VectorShape::I8x16);
+    }
+
+    fn visit_i8x16_lt_s(&mut self) {
+        self.v128_cmp(IntCmpKind::LtS, VectorShape::I8x16);
+    }
+
+    fn visit_i8x16_gt_s(&mut self) {
+        self.v128_cmp(IntCmpKind::GtS, VectorShape::I8x16);
+    }
+
+    fn visit_i8x16_le_s(&mut self) {
+        self.v128_cmp(IntCmpKind::LeS, VectorShape::I8x16);
+    }
+
+    fn visit_i8x16_ge_s(&mut self) {
+        self.v128_cmp(IntCmpKind::GeS, VectorShape::I8x16);
+    }
+
+    fn visit_i16x8_eq(&mut self) {
+        self.v128_cmp(IntCmpKind::Eq, VectorShape::I16x8);
+    }
+
+    fn visit_i16x8_ne(&mut self) {
+        self.v128_cmp(IntCmpKind::Ne, VectorShape::I16x8);
+    }
+
+    fn visit_i16x8_lt_s(&mut self) {
+        self.v128_cmp(IntCmpKind::LtS, VectorShape::I16x8);
+    }
+
+    fn visit_i16x8_gt_s(&mut self) {
+        self.v128_cmp(IntCmpKind::GtS, VectorShape::I16x8);
+    }
+
+    fn visit_i16x8_le_s(&mut self) {
+        self.v128_cmp(IntCmpKind::LeS, VectorShape::I16x8);
+    }
+
+    fn visit_i16x8_ge_s(&mut self) {
+        self.v128_cmp(IntCmpKind::GeS, VectorShape::I16x8);
+    }
+
+    fn visit_i32x4_eq(&mut self) {
+        self.v128_cmp(IntCmpKind::Eq, VectorShape::I32x4);
+    }
+
+    fn visit_i32x4_ne(&mut self) {
+        self.v128_cmp(IntCmpKind::Ne, VectorShape::I32x4);
+    }
+
+    fn visit_i32x4_lt_s(&mut self) {
+        self.v128_cmp(IntCmpKind::LtS, VectorShape::I32x4);
+    }
+
+    fn visit_i32x4_gt_s(&mut self) {
+        self.v128_cmp(IntCmpKind::GtS, VectorShape::I32x4);
+    }
+
+    fn visit_i32x4_le_s(&mut self) {
+        self.v128_cmp(IntCmpKind::LeS, VectorShape::I32x4);
+    }
+
+    fn visit_i32x4_ge_s(&mut self) {
+        self.v128_cmp(IntCmpKind::GeS, VectorShape::I32x4);
+    }
+
+    fn visit_i64x2_eq(&mut self) {
+        self.v128_cmp(IntCmpKind::Eq, VectorShape::I64x2);
+    }
+
+    fn visit_i64x2_ne(&mut self) {
+        self.v128_cmp(IntCmpKind::Ne, VectorShape::I64x2);
+    }
+
+    fn visit_i64x2_lt_s(&mut self) {
+        self.v128_cmp(IntCmpKind::LtS, VectorShape::I64x2);
+    }
+
+    fn visit_i64x2_gt_s(&mut self) {
+        self.v128_cmp(IntCmpKind::GtS, VectorShape::I64x2);
+    }
+
+    fn visit_i64x2_le_s(&mut self) {
+        self.v128_cmp(IntCmpKind::LeS, VectorShape::I64x2);
+    }
+
+    fn visit_i64x2_ge_s(&mut self) {
+        self.v128_cmp(IntCmpKind::GeS, VectorShape::I64x2);
+    }
+
+    fn visit_f32x4_eq(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Eq, VectorShape::F32x4);
+    }
+
+    fn visit_f32x4_ne(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Ne, VectorShape::F32x4);
+    }
+
+    fn visit_f32x4_lt(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Lt, VectorShape::F32x4);
+    }
+
+    fn visit_f32x4_gt(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Gt, VectorShape::F32x4);
+    }
+
+    fn visit_f32x4_le(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Le, VectorShape::F32x4);
+    }
+
+    fn visit_f32x4_ge(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Ge, VectorShape::F32x4);
+    }
+
+    fn visit_f64x2_eq(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Eq, VectorShape::F64x2);
+    }
+
+    fn visit_f64x2_ne(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Ne, VectorShape::F64x2);
+    }
+
+    fn visit_f64x2_lt(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Lt, VectorShape::F64x2);
+    }
+
+    fn visit_f64x2_gt(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Gt, VectorShape::F64x2);
+    }
+
+    fn visit_f64x2_le(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Le, VectorShape::F64x2);
+    }
+
+    fn visit_f64x2_ge(&mut self) {
+        self.v128_float_cmp(FloatCmpKind::Ge, VectorShape::F64x2);
+    }
+
+    fn visit_f32x4_convert_i32x4_s(&mut self) {
+        self.v128_convert(VectorConvertKind::F32x4ConvertI32x4S);
+    }
+
+    fn visit_f64x2_convert_low_i32x4_s(&mut self) {
+        self.v128_convert(VectorConvertKind::F64x2ConvertLowI32x4S);
+    }
+
+    fn visit_f32x4_demote_f64x2_zero(&mut self) {
+        self.v128_convert(VectorConvertKind::F32x4DemoteF64x2Zero);
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f64x2_promote_low_f32x4(&mut self) {
+        self.v128_convert(VectorConvertKind::F64x2PromoteLowF32x4);
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_i8x16_splat(&mut self) {
+        self.v128_splat(VectorShape::I8x16);
+    }
+
+    fn visit_i16x8_splat(&mut self) {
+        self.v128_splat(VectorShape::I16x8);
+    }
+
+    fn visit_i32x4_splat(&mut self) {
+        self.v128_splat(VectorShape::I32x4);
+    }
+
+    fn visit_i64x2_splat(&mut self) {
+        self.v128_splat(VectorShape::I64x2);
+    }
+
+    fn visit_f32x4_splat(&mut self) {
+        self.v128_splat(VectorShape::F32x4);
+    }
+
+    fn visit_f64x2_splat(&mut self) {
+        self.v128_splat(VectorShape::F64x2);
+    }
+
+    fn visit_i8x16_shuffle(&mut self, lanes: [u8; 16]) {
+        self.v128_binop(|masm, dst, src| masm.v128_shuffle(dst, dst, src, lanes));
+    }
+
+    fn visit_v128_load(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::V128);
+    }
+
+    fn visit_v128_load8x8_s(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Extend8x8(ExtendKind::Signed));
+    }
+
+    fn visit_v128_load8x8_u(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Extend8x8(ExtendKind::Unsigned));
+    }
+
+    fn visit_v128_load16x4_s(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Extend16x4(ExtendKind::Signed));
+    }
+
+    fn visit_v128_load16x4_u(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Extend16x4(ExtendKind::Unsigned));
+    }
+
+    fn visit_v128_load32x2_s(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Extend32x2(ExtendKind::Signed));
+    }
+
+    fn visit_v128_load32x2_u(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Extend32x2(ExtendKind::Unsigned));
+    }
+
+    fn visit_v128_load8_splat(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Splat(OperandSize::S8));
+    }
+
+    fn visit_v128_load16_splat(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Splat(OperandSize::S16));
+    }
+
+    fn visit_v128_load32_splat(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Splat(OperandSize::S32));
+    }
+
+    fn visit_v128_load64_splat(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Splat(OperandSize::S64));
+    }
+
+    fn visit_v128_load32_zero(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Zero(OperandSize::S32));
+    }
+
+    fn visit_v128_load64_zero(&mut self, memarg: MemArg) {
+        self.emit_v128_load(&memarg, VectorLoadKind::Zero(OperandSize::S64));
+    }
+
+    fn visit_v128_store(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S128);
+    }
+
+    fn visit_v128_load8_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_v128_load_lane(&memarg, lane, OperandSize::S8);
+    }
+
+    fn visit_v128_load16_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_v128_load_lane(&memarg, lane, OperandSize::S16);
+    }
+
+    fn visit_v128_load32_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_v128_load_lane(&memarg, lane, OperandSize::S32);
+    }
+
+    fn visit_v128_load64_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_v128_load_lane(&memarg, lane, OperandSize::S64);
+    }
+
+    fn visit_v128_store8_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_v128_store_lane(&memarg, lane, OperandSize::S8);
+    }
+
+    fn visit_v128_store16_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_v128_store_lane(&memarg, lane, OperandSize::S16);
+    }
+
+    fn visit_v128_store32_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_v128_store_lane(&memarg, lane, OperandSize::S32);
+    }
+
+    fn visit_v128_store64_lane(&mut self, memarg: MemArg, lane: u8) {
+        self.emit_v128_store_lane(&memarg, lane, OperandSize::S64);
+    }
+
+    fn visit_i8x16_extract_lane_s(&mut self, lane: u8) {
+        self.v128_extract_lane(lane, VectorShape::I8x16, Some(ExtendKind::Signed));
+    }
+
+    fn visit_i8x16_extract_lane_u(&mut self, lane: u8) {
+        self.v128_extract_lane(lane, VectorShape::I8x16, Some(ExtendKind::Unsigned));
+    }
+
+    fn visit_i16x8_extract_lane_s(&mut self, lane: u8) {
+        self.v128_extract_lane(lane, VectorShape::I16x8, Some(ExtendKind::Signed));
+    }
+
+    fn visit_i16x8_extract_lane_u(&mut self, lane: u8) {
+        self.v128_extract_lane(lane, VectorShape::I16x8, Some(ExtendKind::Unsigned));
+    }
+
+    fn visit_i32x4_extract_lane(&mut self, lane: u8) {
+        self.v128_extract_lane(lane, VectorShape::I32x4, None);
+    }
+
+    fn visit_i64x2_extract_lane(&mut self, lane: u8) {
+        self.v128_extract_lane(lane, VectorShape::I64x2, None);
+    }
+
+    fn visit_f32x4_extract_lane(&mut self, lane: u8) {
+        self.v128_extract_lane(lane, VectorShape::F32x4, None);
+    }
+
+    fn visit_f64x2_extract_lane(&mut self, lane: u8) {
+        self.v128_extract_lane(lane, VectorShape::F64x2, None);
+    }
+
+    fn visit_i8x16_replace_lane(&mut self, lane: u8) {
+        self.v128_replace_lane(lane, VectorShape::I8x16);
+    }
+
+    fn visit_i16x8_replace_lane(&mut self, lane: u8) {
+        self.v128_replace_lane(lane, VectorShape::I16x8);
+    }
+
+    fn visit_i32x4_replace_lane(&mut self, lane: u8) {
+        self.v128_replace_lane(lane, VectorShape::I32x4);
+    }
+
+    fn visit_i64x2_replace_lane(&mut self, lane: u8) {
+        self.v128_replace_lane(lane, VectorShape::I64x2);
+    }
+
+    fn visit_f32x4_replace_lane(&mut self, lane: u8) {
+        self.v128_replace_lane(lane, VectorShape::F32x4);
+    }
+
+    fn visit_f64x2_replace_lane(&mut self, lane: u8) {
+        self.v128_replace_lane(lane, VectorShape::F64x2);
+    }
+
+    fn visit_i8x16_swizzle(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_swizzle(dst, dst, src));
+    }
+
+    fn visit_v128_bitselect(&mut self) {
+        let mask = self.context.pop_to_reg(self.masm, None);
+        let rhs = self.context.pop_to_reg(self.masm, None);
+        let lhs = self.context.pop_to_reg(self.masm, None);
+        self.masm
+            .v128_bitselect(lhs.into(), lhs.into(), rhs.into(), mask.into());
+        self.context.free_reg(mask);
+        self.context.free_reg(rhs);
+        self.context.stack.push(lhs.into());
+    }
+
+    fn visit_v128_any_true(&mut self) {
+        self.v128_to_i32(|masm, dst, src| masm.v128_any_true(dst, src));
+    }
+
+    fn visit_i8x16_all_true(&mut self) {
+        self.v128_to_i32(|masm, dst, src| masm.v128_all_true(dst, src, VectorShape::I8x16));
+    }
+
+    fn visit_i16x8_all_true(&mut self) {
+        self.v128_to_i32(|masm, dst, src| masm.v128_all_true(dst, src, VectorShape::I16x8));
+    }
+
+    fn visit_i32x4_all_true(&mut self) {
+        self.v128_to_i32(|masm, dst, src| masm.v128_all_true(dst, src, VectorShape::I32x4));
+    }
+
+    fn visit_i64x2_all_true(&mut self) {
+        self.v128_to_i32(|masm, dst, src| masm.v128_all_true(dst, src, VectorShape::I64x2));
+    }
+
+    fn visit_i8x16_bitmask(&mut self) {
+        self.v128_to_i32(|masm, dst, src| masm.v128_bitmask(dst, src, VectorShape::I8x16));
+    }
+
+    fn visit_i16x8_bitmask(&mut self) {
+        self.v128_to_i32(|masm, dst, src| masm.v128_bitmask(dst, src, VectorShape::I16x8));
+    }
+
+    fn visit_i32x4_bitmask(&mut self) {
+        self.v128_to_i32(|masm, dst, src| masm.v128_bitmask(dst, src, VectorShape::I32x4));
+    }
+
+    fn visit_i64x2_bitmask(&mut self) {
+        self.v128_to_i32(|masm, dst, src| masm.v128_bitmask(dst, src, VectorShape::I64x2));
+    }
+
+    fn visit_i8x16_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::I8x16));
+    }
+
+    fn visit_i16x8_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::I16x8));
+    }
+
+    fn visit_i32x4_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::I32x4));
+    }
+
+    fn visit_i64x2_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::I64x2));
+    }
+
+    fn visit_f32x4_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::F32x4));
+    }
+
+    fn visit_f64x2_abs(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_abs(reg, VectorShape::F64x2));
+    }
+
+    fn visit_i8x16_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::I8x16));
+    }
+
+    fn visit_i16x8_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::I16x8));
+    }
+
+    fn visit_i32x4_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::I32x4));
+    }
+
+    fn visit_i64x2_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::I64x2));
+    }
+
+    fn visit_f32x4_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::F32x4));
+    }
+
+    fn visit_f64x2_neg(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_neg(reg, VectorShape::F64x2));
+    }
+
+    fn visit_i8x16_popcnt(&mut self) {
+        self.v128_unop_with_tmp(|masm, reg, tmp| masm.v128_popcnt(reg, tmp));
+    }
+
+    fn visit_f32x4_sqrt(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_sqrt(reg, VectorShape::F32x4));
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f32x4_ceil(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, RoundingMode::Up, VectorShape::F32x4));
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f32x4_floor(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, RoundingMode::Down, VectorShape::F32x4));
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f32x4_trunc(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, RoundingMode::Zero, VectorShape::F32x4));
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f32x4_nearest(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, RoundingMode::Nearest, VectorShape::F32x4));
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f64x2_sqrt(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_sqrt(reg, VectorShape::F64x2));
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_f64x2_ceil(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, RoundingMode::Up, VectorShape::F64x2));
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_f64x2_floor(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, RoundingMode::Down, VectorShape::F64x2));
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_f64x2_trunc(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, RoundingMode::Zero, VectorShape::F64x2));
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_f64x2_nearest(&mut self) {
+        self.v128_unop(|masm, reg| masm.v128_round(reg, RoundingMode::Nearest, VectorShape::F64x2));
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_i8x16_shl(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::Shl, VectorShape::I8x16);
+    }
+
+    fn visit_i8x16_shr_s(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::ShrS, VectorShape::I8x16);
+    }
+
+    fn visit_i8x16_shr_u(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::ShrU, VectorShape::I8x16);
+    }
+
+    fn visit_i16x8_shl(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::Shl, VectorShape::I16x8);
+    }
+
+    fn visit_i16x8_shr_s(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::ShrS, VectorShape::I16x8);
+    }
+
+    fn visit_i16x8_shr_u(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::ShrU, VectorShape::I16x8);
+    }
+
+    fn visit_i32x4_shl(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::Shl, VectorShape::I32x4);
+    }
+
+    fn visit_i32x4_shr_s(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::ShrS, VectorShape::I32x4);
+    }
+
+    fn visit_i32x4_shr_u(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::ShrU, VectorShape::I32x4);
+    }
+
+    fn visit_i64x2_shl(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::Shl, VectorShape::I64x2);
+    }
+
+    fn visit_i64x2_shr_s(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::ShrS, VectorShape::I64x2);
+    }
+
+    fn visit_i64x2_shr_u(&mut self) {
+        self.masm
+            .v128_shift(&mut self.context, ShiftKind::ShrU, VectorShape::I64x2);
+    }
+
+    fn visit_i8x16_add_sat_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_add_sat(dst, dst, src, VectorShape::I8x16, Signedness::Signed)
+        });
+    }
+
+    fn visit_i8x16_add_sat_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_add_sat(dst, dst, src, VectorShape::I8x16, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i8x16_sub_sat_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_sub_sat(dst, dst, src, VectorShape::I8x16, Signedness::Signed)
+        });
+    }
+
+    fn visit_i8x16_sub_sat_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_sub_sat(dst, dst, src, VectorShape::I8x16, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i16x8_add_sat_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_add_sat(dst, dst, src, VectorShape::I16x8, Signedness::Signed)
+        });
+    }
+
+    fn visit_i16x8_add_sat_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_add_sat(dst, dst, src, VectorShape::I16x8, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i16x8_sub_sat_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_sub_sat(dst, dst, src, VectorShape::I16x8, Signedness::Signed)
+        });
+    }
+
+    fn visit_i16x8_sub_sat_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_sub_sat(dst, dst, src, VectorShape::I16x8, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i8x16_min_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_min(dst, dst, src, VectorShape::I8x16, Signedness::Signed)
+        });
+    }
+
+    fn visit_i8x16_min_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_min(dst, dst, src, VectorShape::I8x16, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i8x16_max_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_max(dst, dst, src, VectorShape::I8x16, Signedness::Signed)
+        });
+    }
+
+    fn visit_i8x16_max_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_max(dst, dst, src, VectorShape::I8x16, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i16x8_min_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_min(dst, dst, src, VectorShape::I16x8, Signedness::Signed)
+        });
+    }
+
+    fn visit_i16x8_min_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_min(dst, dst, src, VectorShape::I16x8, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i16x8_max_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_max(dst, dst, src, VectorShape::I16x8, Signedness::Signed)
+        });
+    }
+
+    fn visit_i16x8_max_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_max(dst, dst, src, VectorShape::I16x8, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i32x4_min_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_min(dst, dst, src, VectorShape::I32x4, Signedness::Signed)
+        });
+    }
+
+    fn visit_i32x4_min_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_min(dst, dst, src, VectorShape::I32x4, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i32x4_max_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_max(dst, dst, src, VectorShape::I32x4, Signedness::Signed)
+        });
+    }
+
+    fn visit_i32x4_max_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_max(dst, dst, src, VectorShape::I32x4, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i8x16_avgr_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_avgr(dst, dst, src, VectorShape::I8x16));
+    }
+
+    fn visit_i16x8_avgr_u(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_avgr(dst, dst, src, VectorShape::I16x8));
+    }
+
+    fn visit_f32x4_min(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_float_min(dst, dst, src, VectorShape::F32x4));
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f32x4_max(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_float_max(dst, dst, src, VectorShape::F32x4));
+        self.v128_canonicalize_nan(VectorShape::F32x4);
+    }
+
+    fn visit_f32x4_pmin(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_pmin(dst, dst, src, VectorShape::F32x4));
+    }
+
+    fn visit_f32x4_pmax(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_pmax(dst, dst, src, VectorShape::F32x4));
+    }
+
+    fn visit_f64x2_min(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_float_min(dst, dst, src, VectorShape::F64x2));
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_f64x2_max(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_float_max(dst, dst, src, VectorShape::F64x2));
+        self.v128_canonicalize_nan(VectorShape::F64x2);
+    }
+
+    fn visit_f64x2_pmin(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_pmin(dst, dst, src, VectorShape::F64x2));
+    }
+
+    fn visit_f64x2_pmax(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_pmax(dst, dst, src, VectorShape::F64x2));
+    }
+
+    fn visit_i8x16_lt_u(&mut self) {
+        self.v128_cmp(IntCmpKind::LtU, VectorShape::I8x16);
+    }
+
+    fn visit_i8x16_gt_u(&mut self) {
+        self.v128_cmp(IntCmpKind::GtU, VectorShape::I8x16);
+    }
+
+    fn visit_i8x16_le_u(&mut self) {
+        self.v128_cmp(IntCmpKind::LeU, VectorShape::I8x16);
+    }
+
+    fn visit_i8x16_ge_u(&mut self) {
+        self.v128_cmp(IntCmpKind::GeU, VectorShape::I8x16);
+    }
+
+    fn visit_i16x8_lt_u(&mut self) {
+        self.v128_cmp(IntCmpKind::LtU, VectorShape::I16x8);
+    }
+
+    fn visit_i16x8_gt_u(&mut self) {
+        self.v128_cmp(IntCmpKind::GtU, VectorShape::I16x8);
+    }
+
+    fn visit_i16x8_le_u(&mut self) {
+        self.v128_cmp(IntCmpKind::LeU, VectorShape::I16x8);
+    }
+
+    fn visit_i16x8_ge_u(&mut self) {
+        self.v128_cmp(IntCmpKind::GeU, VectorShape::I16x8);
+    }
+
+    fn visit_i32x4_lt_u(&mut self) {
+        self.v128_cmp(IntCmpKind::LtU, VectorShape::I32x4);
+    }
+
+    fn visit_i32x4_gt_u(&mut self) {
+        self.v128_cmp(IntCmpKind::GtU, VectorShape::I32x4);
+    }
+
+    fn visit_i32x4_le_u(&mut self) {
+        self.v128_cmp(IntCmpKind::LeU, VectorShape::I32x4);
+    }
+
+    fn visit_i32x4_ge_u(&mut self) {
+        self.v128_cmp(IntCmpKind::GeU, VectorShape::I32x4);
+    }
+
+    fn visit_i64x2_mul(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_mul(dst, dst, src, VectorShape::I64x2));
+    }
+
+    fn visit_i8x16_narrow_i16x8_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_narrow(dst, dst, src, VectorShape::I8x16, Signedness::Signed)
+        });
+    }
+
+    fn visit_i8x16_narrow_i16x8_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_narrow(dst, dst, src, VectorShape::I8x16, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i16x8_narrow_i32x4_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_narrow(dst, dst, src, VectorShape::I16x8, Signedness::Signed)
+        });
+    }
+
+    fn visit_i16x8_narrow_i32x4_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_narrow(dst, dst, src, VectorShape::I16x8, Signedness::Unsigned)
+        });
+    }
+
+    fn visit_i16x8_extend_low_i8x16_s(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(reg, VectorShape::I16x8, VectorHalf::Low, ExtendKind::Signed)
+        });
+    }
+
+    fn visit_i16x8_extend_low_i8x16_u(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(
+                reg,
+                VectorShape::I16x8,
+                VectorHalf::Low,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i16x8_extend_high_i8x16_s(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(
+                reg,
+                VectorShape::I16x8,
+                VectorHalf::High,
+                ExtendKind::Signed,
+            )
+        });
+    }
+
+    fn visit_i16x8_extend_high_i8x16_u(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(
+                reg,
+                VectorShape::I16x8,
+                VectorHalf::High,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i32x4_extend_low_i16x8_s(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(reg, VectorShape::I32x4, VectorHalf::Low, ExtendKind::Signed)
+        });
+    }
+
+    fn visit_i32x4_extend_low_i16x8_u(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(
+                reg,
+                VectorShape::I32x4,
+                VectorHalf::Low,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i32x4_extend_high_i16x8_s(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(
+                reg,
+                VectorShape::I32x4,
+                VectorHalf::High,
+                ExtendKind::Signed,
+            )
+        });
+    }
+
+    fn visit_i32x4_extend_high_i16x8_u(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(
+                reg,
+                VectorShape::I32x4,
+                VectorHalf::High,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i64x2_extend_low_i32x4_s(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(reg, VectorShape::I64x2, VectorHalf::Low, ExtendKind::Signed)
+        });
+    }
+
+    fn visit_i64x2_extend_low_i32x4_u(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(
+                reg,
+                VectorShape::I64x2,
+                VectorHalf::Low,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i64x2_extend_high_i32x4_s(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(
+                reg,
+                VectorShape::I64x2,
+                VectorHalf::High,
+                ExtendKind::Signed,
+            )
+        });
+    }
+
+    fn visit_i64x2_extend_high_i32x4_u(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extend(
+                reg,
+                VectorShape::I64x2,
+                VectorHalf::High,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i16x8_extmul_low_i8x16_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I16x8,
+                VectorHalf::Low,
+                ExtendKind::Signed,
+            )
+        });
+    }
+
+    fn visit_i16x8_extmul_low_i8x16_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I16x8,
+                VectorHalf::Low,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i16x8_extmul_high_i8x16_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I16x8,
+                VectorHalf::High,
+                ExtendKind::Signed,
+            )
+        });
+    }
+
+    fn visit_i16x8_extmul_high_i8x16_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I16x8,
+                VectorHalf::High,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i32x4_extmul_low_i16x8_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I32x4,
+                VectorHalf::Low,
+                ExtendKind::Signed,
+            )
+        });
+    }
+
+    fn visit_i32x4_extmul_low_i16x8_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I32x4,
+                VectorHalf::Low,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i32x4_extmul_high_i16x8_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I32x4,
+                VectorHalf::High,
+                ExtendKind::Signed,
+            )
+        });
+    }
+
+    fn visit_i32x4_extmul_high_i16x8_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I32x4,
+                VectorHalf::High,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i64x2_extmul_low_i32x4_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I64x2,
+                VectorHalf::Low,
+                ExtendKind::Signed,
+            )
+        });
+    }
+
+    fn visit_i64x2_extmul_low_i32x4_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I64x2,
+                VectorHalf::Low,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i64x2_extmul_high_i32x4_s(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I64x2,
+                VectorHalf::High,
+                ExtendKind::Signed,
+            )
+        });
+    }
+
+    fn visit_i64x2_extmul_high_i32x4_u(&mut self) {
+        self.v128_binop(|masm, dst, src| {
+            masm.v128_extmul(
+                dst,
+                dst,
+                src,
+                VectorShape::I64x2,
+                VectorHalf::High,
+                ExtendKind::Unsigned,
+            )
+        });
+    }
+
+    fn visit_i16x8_extadd_pairwise_i8x16_s(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extadd_pairwise(reg, VectorShape::I16x8, ExtendKind::Signed)
+        });
+    }
+
+    fn visit_i16x8_extadd_pairwise_i8x16_u(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extadd_pairwise(reg, VectorShape::I16x8, ExtendKind::Unsigned)
+        });
+    }
+
+    fn visit_i32x4_extadd_pairwise_i16x8_s(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extadd_pairwise(reg, VectorShape::I32x4, ExtendKind::Signed)
+        });
+    }
+
+    fn visit_i32x4_extadd_pairwise_i16x8_u(&mut self) {
+        self.v128_unop(|masm, reg| {
+            masm.v128_extadd_pairwise(reg, VectorShape::I32x4, ExtendKind::Unsigned)
+        });
+    }
+
+    fn visit_i16x8_q15mulr_sat_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_q15mulr_sat(dst, dst, src));
+    }
+
+    fn visit_i32x4_dot_i16x8_s(&mut self) {
+        self.v128_binop(|masm, dst, src| masm.v128_dot(dst, dst, src));
+    }
+
+    fn visit_f32x4_convert_i32x4_u(&mut self) {
+        self.v128_convert(VectorConvertKind::F32x4ConvertI32x4U);
+    }
+
+    fn visit_f64x2_convert_low_i32x4_u(&mut self) {
+        self.v128_convert(VectorConvertKind::F64x2ConvertLowI32x4U);
+    }
+
+    fn visit_i32x4_trunc_sat_f32x4_s(&mut self) {
+        self.v128_convert(VectorConvertKind::I32x4TruncSatF32x4S);
+    }
+
+    fn visit_i32x4_trunc_sat_f32x4_u(&mut self) {
+        self.v128_convert(VectorConvertKind::I32x4TruncSatF32x4U);
+    }
+
+    fn visit_i32x4_trunc_sat_f64x2_s_zero(&mut self) {
+        self.v128_convert(VectorConvertKind::I32x4TruncSatF64x2SZero);
+    }
+
+    fn visit_i32x4_trunc_sat_f64x2_u_zero(&mut self) {
+        self.v128_convert(VectorConvertKind::I32x4TruncSatF64x2UZero);
+    }
+
+    fn visit_i32_load(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::I32, OperandSize::S32, None);
+    }
+
+    fn visit_i64_load(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(&memarg, WasmType::I64, OperandSize::S64, None);
+    }
+
+    fn visit_i32_load8_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I32,
+            OperandSize::S8,
+            Some(ExtendKind::Signed),
+        );
+    }
+
+    fn visit_i32_load8_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I32,
+            OperandSize::S8,
+            Some(ExtendKind::Unsigned),
+        );
+    }
+
+    fn visit_i32_load16_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I32,
+            OperandSize::S16,
+            Some(ExtendKind::Signed),
+        );
+    }
+
+    fn visit_i32_load16_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I32,
+            OperandSize::S16,
+            Some(ExtendKind::Unsigned),
+        );
+    }
+
+    fn visit_i64_load8_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I64,
+            OperandSize::S8,
+            Some(ExtendKind::Signed),
+        );
+    }
+
+    fn visit_i64_load8_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I64,
+            OperandSize::S8,
+            Some(ExtendKind::Unsigned),
+        );
+    }
+
+    fn visit_i64_load16_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I64,
+            OperandSize::S16,
+            Some(ExtendKind::Signed),
+        );
+    }
+
+    fn visit_i64_load16_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I64,
+            OperandSize::S16,
+            Some(ExtendKind::Unsigned),
+        );
+    }
+
+    fn visit_i64_load32_s(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I64,
+            OperandSize::S32,
+            Some(ExtendKind::Signed),
+        );
+    }
+
+    fn visit_i64_load32_u(&mut self, memarg: MemArg) {
+        self.emit_wasm_load(
+            &memarg,
+            WasmType::I64,
+            OperandSize::S32,
+            Some(ExtendKind::Unsigned),
+        );
+    }
+
+    fn visit_i32_store(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S32);
+    }
+
+    fn visit_i64_store(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S64);
+    }
+
+    fn visit_i32_store8(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i32_store16(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i64_store8(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i64_store16(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i64_store32(&mut self, memarg: MemArg) {
+        self.emit_wasm_store(&memarg, OperandSize::S32);
+    }
+
+    fn visit_atomic_fence(&mut self) {
+        self.masm.fence();
+    }
+
+    fn visit_i32_atomic_load(&mut self, memarg: MemArg) {
+        self.emit_atomic_load(&memarg, WasmType::I32, OperandSize::S32);
+    }
+
+    fn visit_i32_atomic_load8_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_load(&memarg, WasmType::I32, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_load16_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_load(&memarg, WasmType::I32, OperandSize::S16);
+    }
+
+    fn visit_i32_atomic_store(&mut self, memarg: MemArg) {
+        self.emit_atomic_store(&memarg, OperandSize::S32);
+    }
+
+    fn visit_i32_atomic_store8(&mut self, memarg: MemArg) {
+        self.emit_atomic_store(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_store16(&mut self, memarg: MemArg) {
+        self.emit_atomic_store(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i32_atomic_rmw_add(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Add, OperandSize::S32);
+    }
+
+    fn visit_i32_atomic_rmw_sub(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Sub, OperandSize::S32);
+    }
+
+    fn visit_i32_atomic_rmw_and(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::And, OperandSize::S32);
+    }
+
+    fn visit_i32_atomic_rmw_or(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Or, OperandSize::S32);
+    }
+
+    fn visit_i32_atomic_rmw_xor(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xor, OperandSize::S32);
+    }
+
+    fn visit_i32_atomic_rmw_xchg(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xchg, OperandSize::S32);
+    }
+
+    fn visit_i32_atomic_rmw_cmpxchg(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S32);
+    }
+
+    fn visit_i32_atomic_rmw8_add_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Add, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_rmw8_sub_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Sub, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_rmw8_and_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::And, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_rmw8_or_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Or, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_rmw8_xor_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xor, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_rmw8_xchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xchg, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_rmw8_cmpxchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i32_atomic_rmw16_add_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Add, OperandSize::S16);
+    }
+
+    fn visit_i32_atomic_rmw16_sub_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Sub, OperandSize::S16);
+    }
+
+    fn visit_i32_atomic_rmw16_and_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::And, OperandSize::S16);
+    }
+
+    fn visit_i32_atomic_rmw16_or_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Or, OperandSize::S16);
+    }
+
+    fn visit_i32_atomic_rmw16_xor_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xor, OperandSize::S16);
+    }
+
+    fn visit_i32_atomic_rmw16_xchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xchg, OperandSize::S16);
+    }
+
+    fn visit_i32_atomic_rmw16_cmpxchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_load(&mut self, memarg: MemArg) {
+        self.emit_atomic_load(&memarg, WasmType::I64, OperandSize::S64);
+    }
+
+    fn visit_i64_atomic_load8_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_load(&memarg, WasmType::I64, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_load16_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_load(&memarg, WasmType::I64, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_load32_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_load(&memarg, WasmType::I64, OperandSize::S32);
+    }
+
+    fn visit_i64_atomic_store(&mut self, memarg: MemArg) {
+        self.emit_atomic_store(&memarg, OperandSize::S64);
+    }
+
+    fn visit_i64_atomic_store8(&mut self, memarg: MemArg) {
+        self.emit_atomic_store(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_store16(&mut self, memarg: MemArg) {
+        self.emit_atomic_store(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_store32(&mut self, memarg: MemArg) {
+        self.emit_atomic_store(&memarg, OperandSize::S32);
+    }
+
+    fn visit_i64_atomic_rmw_add(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Add, OperandSize::S64);
+    }
+
+    fn visit_i64_atomic_rmw_sub(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Sub, OperandSize::S64);
+    }
+
+    fn visit_i64_atomic_rmw_and(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::And, OperandSize::S64);
+    }
+
+    fn visit_i64_atomic_rmw_or(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Or, OperandSize::S64);
+    }
+
+    fn visit_i64_atomic_rmw_xor(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xor, OperandSize::S64);
+    }
+
+    fn visit_i64_atomic_rmw_xchg(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xchg, OperandSize::S64);
+    }
+
+    fn visit_i64_atomic_rmw_cmpxchg(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S64);
+    }
+
+    fn visit_i64_atomic_rmw8_add_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Add, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_rmw8_sub_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Sub, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_rmw8_and_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::And, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_rmw8_or_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Or, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_rmw8_xor_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xor, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_rmw8_xchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xchg, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_rmw8_cmpxchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S8);
+    }
+
+    fn visit_i64_atomic_rmw16_add_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Add, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_rmw16_sub_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Sub, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_rmw16_and_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::And, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_rmw16_or_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Or, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_rmw16_xor_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xor, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_rmw16_xchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xchg, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_rmw16_cmpxchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S16);
+    }
+
+    fn visit_i64_atomic_rmw32_add_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Add, OperandSize::S32);
+    }
+
+    fn visit_i64_atomic_rmw32_sub_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Sub, OperandSize::S32);
+    }
+
+    fn visit_i64_atomic_rmw32_and_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::And, OperandSize::S32);
+    }
+
+    fn visit_i64_atomic_rmw32_or_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Or, OperandSize::S32);
+    }
+
+    fn visit_i64_atomic_rmw32_xor_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xor, OperandSize::S32);
+    }
+
+    fn visit_i64_atomic_rmw32_xchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_rmw(&memarg, AtomicRmwKind::Xchg, OperandSize::S32);
+    }
+
+    fn visit_i64_atomic_rmw32_cmpxchg_u(&mut self, memarg: MemArg) {
+        self.emit_atomic_cmpxchg(&memarg, OperandSize::S32);
+    }
+
+    fn visit_memory_atomic_notify(&mut self, memarg: MemArg) {
+        let ptr_type = self.env.ptr_type();
+        let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+        let builtin = self
+            .context
+            .builtins
+            .memory_atomic_notify::<M::ABI, M::Ptr>();
+
+        // The value stack contains [ addr | count ] (stack top).
+        let count = self.context.pop_to_reg(self.masm, None);
+        let addr = self.emit_atomic_addr(&memarg);
+        self.context.stack.extend([
+            vmctx.into(),
+            memarg.memory.try_into().unwrap(),
+            addr.into(),
+            count.into(),
+        ]);
+
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |_| {
+            Callee::Builtin(builtin.clone())
+        });
+    }
+
+    fn visit_memory_atomic_wait32(&mut self, memarg: MemArg) {
+        let builtin = self
+            .context
+            .builtins
+            .memory_atomic_wait32::<M::ABI, M::Ptr>();
+        self.emit_atomic_wait(&memarg, builtin);
+    }
+
+    fn visit_memory_atomic_wait64(&mut self, memarg: MemArg) {
+        let builtin = self
+            .context
+            .builtins
+            .memory_atomic_wait64::<M::ABI, M::Ptr>();
+        self.emit_atomic_wait(&memarg, builtin);
+    }
+
+    wasmparser::for_each_operator!(def_unsupported);
+}
+
+impl<'a, 'b, 'c, M> CodeGen<'a, 'b, 'c, M>
+where
+    M: MacroAssembler,
+{
+    /// Replaces a NaN on top of the value stack with the canonical NaN, if
+    /// NaN canonicalization is enabled.
+    fn canonicalize_nan(&mut self, size: OperandSize) {
+        if self.flags.enable_nan_canonicalization() {
+            self.context.unop(self.masm, size, &mut |masm, reg, size| {
+                masm.float_canonicalize_nan(reg, size);
+            });
+        }
+    }
+
+    /// Replaces the NaN lanes of the vector on top of the value stack with
+    /// the canonical NaN, if NaN canonicalization is enabled.
+    fn v128_canonicalize_nan(&mut self, shape: VectorShape) {
+        if self.flags.enable_nan_canonicalization() {
+            let reg = self.context.pop_to_reg(self.masm, None);
+            let tmp = self.context.reg_for_class(RegClass::Float, self.masm);
+            self.masm.v128_canonicalize_nan(reg.into(), tmp, shape);
+            self.context.free_reg(tmp);
+            self.context.stack.push(reg.into());
+        }
+    }
+
+    fn v128_binop<F>(&mut self, mut emit: F)
+    where
+        F: FnMut(&mut M, Reg, Reg),
+    {
+        self.context
+            .binop(self.masm, OperandSize::S128, |masm, dst, src, _size| {
+                emit(masm, dst, src)
+            });
+    }
+
+    fn v128_cmp(&mut self, kind: IntCmpKind, shape: VectorShape) {
+        self.v128_binop(|masm, dst, src| masm.v128_cmp(dst, dst, src, kind, shape));
+    }
+
+    fn v128_float_cmp(&mut self, kind: FloatCmpKind, shape: VectorShape) {
+        self.v128_binop(|masm, dst, src| masm.v128_float_cmp(dst, dst, src, kind, shape));
+    }
+
+    fn v128_splat(&mut self, shape: VectorShape) {
+        let src = self.context.pop_to_reg(self.masm, None);
+        let dst = self.context.reg_for_type(WasmType::V128, self.masm);
+        self.masm.v128_splat(dst, src.into(), shape);
+        self.context.free_reg(src);
+        self.context.stack.push(Val::reg(dst, WasmType::V128));
+    }
+
+    fn v128_unop<F>(&mut self, mut emit: F)
+    where
+        F: FnMut(&mut M, Reg),
+    {
+        self.context
+            .unop(self.masm, OperandSize::S128, &mut |masm, reg, _size| {
+                emit(masm, reg)
+            });
+    }
+
+    /// Emits a unary vector operation that needs a temporary float register.
+    fn v128_unop_with_tmp<F>(&mut self, mut emit: F)
+    where
+        F: FnMut(&mut M, Reg, Reg),
+    {
+        let reg = self.context.pop_to_reg(self.masm, None);
+        let tmp = self.context.reg_for_class(RegClass::Float, self.masm);
+        emit(self.masm, reg.into(), tmp);
+        self.context.free_reg(tmp);
+        self.context.stack.push(reg.into());
+    }
+
+    fn v128_convert(&mut self, kind: VectorConvertKind) {
+        self.v128_unop_with_tmp(|masm, reg, tmp| masm.v128_convert(reg, reg, tmp, kind));
+    }
+
+    /// Reduces the vector on top of the value stack to an `i32`.
+    fn v128_to_i32<F>(&mut self, mut emit: F)
+    where
+        F: FnMut(&mut M, Reg, Reg),
+    {
+        let src = self.context.pop_to_reg(self.masm, None);
+        let dst = self.context.reg_for_type(WasmType::I32, self.masm);
+        emit(self.masm, dst, src.into());
+        self.context.free_reg(src);
+        self.context.stack.push(TypedReg::i32(dst).into());
+    }
+
+    fn v128_extract_lane(&mut self, lane: u8, shape: VectorShape, extend: Option<ExtendKind>) {
+        let ty = match shape {
+            VectorShape::I8x16 | VectorShape::I16x8 | VectorShape::I32x4 => WasmType::I32,
+            VectorShape::I64x2 => WasmType::I64,
+            VectorShape::F32x4 => WasmType::F32,
+            VectorShape::F64x2 => WasmType::F64,
+        };
+        let src = self.context.pop_to_reg(self.masm, None);
+        let dst = self.context.reg_for_type(ty, self.masm);
+        self.masm
+            .v128_extract_lane(dst, src.into(), lane, shape, extend);
+        self.context.free_reg(src);
+        self.context.stack.push(TypedReg::new(ty, dst).into());
+    }
+
+    fn v128_replace_lane(&mut self, lane: u8, shape: VectorShape) {
+        let src = self.context.pop_to_reg(self.masm, None);
+        let dst = self.context.pop_to_reg(self.masm, None);
+        self.masm
+            .v128_replace_lane(dst.into(), src.into(), lane, shape);
+        self.context.free_reg(src);
+        self.context.stack.push(dst.into());
+    }
+
+    /// Pops the 32-bit value at the top of the value stack into a register,
+    /// zero-extending it to 64 bits.
+    fn pop_zero_extended(&mut self) -> TypedReg {
+        let reg = self.context.pop_to_reg(self.masm, None).reg;
+        self.masm.mov(RegImm::reg(reg), reg, OperandSize::S32);
+        TypedReg::i64(reg)
+    }
+
+    /// Pops an index into a memory whose indices have type `ty`, as a
+    /// 64-bit value.
+    fn pop_index(&mut self, ty: WasmType) -> TypedReg {
+        match ty {
+            WasmType::I32 => self.pop_zero_extended(),
+            _ => self.context.pop_to_reg(self.masm, None),
+        }
+    }
+
+    /// The type of the indices of the memory at `index`.
+    fn memory_index_type(&mut self, index: u32) -> WasmType {
+        self.env.resolve_heap_data(MemoryIndex::from_u32(index)).ty
+    }
+
+    fn heap_data(&mut self, memarg: &MemArg) -> HeapData {
+        self.env
+            .resolve_heap_data(MemoryIndex::from_u32(memarg.memory))
+    }
+
+    fn emit_wasm_load(
+        &mut self,
+        memarg: &MemArg,
+        ty: WasmType,
+        size: OperandSize,
+        extend: Option<ExtendKind>,
+    ) {
+        let heap_data = self.heap_data(memarg);
+        let index = self.context.pop_to_reg(self.masm, None);
+        let base = self.context.any_gpr(self.masm);
+        let addr = self.masm.heap_address(
+            index.into(),
+            base,
+            &heap_data,
+            memarg.offset,
+            size,
+            &mut self.context,
+        );
+        // The index register is no longer needed once the address is
+        // computed, so reuse it for the loaded value.
+        match extend {
+            Some(kind) => self
+                .masm
+                .load_extend(addr, index.into(), size, ty.into(), kind),
+            None => self.masm.load(addr, index.into(), size),
+        }
+        self.context.free_reg(base);
+        self.context.stack.push(TypedReg::new(ty, index.reg).into());
+    }
+
+    fn emit_wasm_store(&mut self, memarg: &MemArg, size: OperandSize) {
+        let heap_data = self.heap_data(memarg);
+        let src = self.context.pop_to_reg(self.masm, None);
+        let index = self.context.pop_to_reg(self.masm, None);
+        let base = self.context.any_gpr(self.masm);
+        let addr = self.masm.heap_address(
+            index.into(),
+            base,
+            &heap_data,
+            memarg.offset,
+            size,
+            &mut self.context,
+        );
+        self.masm.store(RegImm::reg(src.into()), addr, size);
+        self.context.free_reg(base);
+        self.context.free_reg(index);
+        self.context.free_reg(src);
+    }
+
+    fn emit_v128_load(&mut self, memarg: &MemArg, kind: VectorLoadKind) {
+        let size = match kind {
+            VectorLoadKind::V128 => OperandSize::S128,
+            VectorLoadKind::Extend8x8(_)
+            | VectorLoadKind::Extend16x4(_)
+            | VectorLoadKind::Extend32x2(_) => OperandSize::S64,
+            VectorLoadKind::Splat(size) | VectorLoadKind::Zero(size) => size,
+        };
+        let heap_data = self.heap_data(memarg);
+        let index = self.context.pop_to_reg(self.masm, None);
+        let base = self.context.any_gpr(self.masm);
+        let dst = self.context.reg_for_type(WasmType::V128, self.masm);
+        let addr = self.masm.heap_address(
+            index.into(),
+            base,
+            &heap_data,
+            memarg.offset,
+            size,
+            &mut self.context,
+        );
+        self.masm.v128_load(addr, dst, kind);
+        self.context.free_reg(base);
+        self.context.free_reg(index);
+        self.context.stack.push(Val::reg(dst, WasmType::V128));
+    }
+
+    fn emit_v128_load_lane(&mut self, memarg: &MemArg, lane: u8, size: OperandSize) {
+        let heap_data = self.heap_data(memarg);
+        let vector = self.context.pop_to_reg(self.masm, None);
+        let index = self.context.pop_to_reg(self.masm, None);
+        let base = self.context.any_gpr(self.masm);
+        let addr = self.masm.heap_address(
+            index.into(),
+            base,
+            &heap_data,
+            memarg.offset,
+            size,
+            &mut self.context,
+        );
+        self.masm.v128_load_lane(addr, vector.into(), lane, size);
+        self.context.free_reg(base);
+        self.context.free_reg(index);
+        self.context.stack.push(vector.into());
+    }
+
+    fn emit_v128_store_lane(&mut self, memarg: &MemArg, lane: u8, size: OperandSize) {
+        let heap_data = self.heap_data(memarg);
+        let vector = self.context.pop_to_reg(self.masm, None);
+        let index = self.context.pop_to_reg(self.masm, None);
+        let base = self.context.any_gpr(self.masm);
+        let addr = self.masm.heap_address(
+            index.into(),
+            base,
+            &heap_data,
+            memarg.offset,
+            size,
+            &mut self.context,
+        );
+        self.masm.v128_store_lane(vector.into(), addr, lane, size);
+        self.context.free_reg(base);
+        self.context.free_reg(index);
+        self.context.free_reg(vector);
+    }
+
+    fn emit_atomic_load(&mut self, memarg: &MemArg, ty: WasmType, size: OperandSize) {
+        let heap_data = self.heap_data(memarg);
+        let index = self.context.pop_to_reg(self.masm, None);
+        let base = self.context.any_gpr(self.masm);
+        let addr = self.masm.atomic_heap_address(
+            index.into(),
+            base,
+            &heap_data,
+            memarg.offset,
+            size,
+            &mut self.context,
+        );
+        // The index register is no longer needed once the address is
+        // computed, so reuse it for the loaded value.
+        self.masm.atomic_load(addr, index.into(), size);
+        self.context.free_reg(base);
+        self.context.stack.push(TypedReg::new(ty, index.reg).into());
+    }
+
+    fn emit_atomic_store(&mut self, memarg: &MemArg, size: OperandSize) {
+        let heap_data = self.heap_data(memarg);
+        let src = self.context.pop_to_reg(self.masm, None);
+        let index = self.context.pop_to_reg(self.masm, None);
+        let base = self.context.any_gpr(self.masm);
+        let addr = self.masm.atomic_heap_address(
+            index.into(),
+            base,
+            &heap_data,
+            memarg.offset,
+            size,
+            &mut self.context,
+        );
+        self.masm.atomic_store(src.into(), addr, size);
+        self.context.free_reg(base);
+        self.context.free_reg(index);
+        self.context.free_reg(src);
+    }
+
+    fn emit_atomic_rmw(&mut self, memarg: &MemArg, kind: AtomicRmwKind, size: OperandSize) {
+        let heap_data = self.heap_data(memarg);
+        self.masm
+            .atomic_rmw(&heap_data, memarg.offset, kind, size, &mut self.context);
+    }
+
+    fn emit_atomic_cmpxchg(&mut self, memarg: &MemArg, size: OperandSize) {
+        let heap_data = self.heap_data(memarg);
+        self.masm
+            .atomic_cmpxchg(&heap_data, memarg.offset, size, &mut self.context);
+    }
+
+    /// Pops the heap index of an atomic access and adds the static offset to
+    /// it, trapping if the sum overflows. The builtins that take the
+    /// resulting address check its bounds and alignment.
+    fn emit_atomic_addr(&mut self, memarg: &MemArg) -> TypedReg {
+        let heap_data = self.heap_data(memarg);
+        let addr = self.pop_index(heap_data.ty);
+        self.masm.checked_uadd(
+            addr.reg,
+            addr.reg,
+            RegImm::i64(memarg.offset as i64),
+            OperandSize::S64,
+            TrapCode::HeapOutOfBounds,
+        );
+        addr
+    }
+
+    fn emit_atomic_wait(&mut self, memarg: &MemArg, builtin: BuiltinFunction) {
+        let ptr_type = self.env.ptr_type();
+        let vmctx = TypedReg::new(ptr_type, <M::ABI as ABI>::vmctx_reg());
+
+        // The value stack contains [ addr | expected | timeout ] (stack top).
+        let timeout = self.context.pop_to_reg(self.masm, None);
+        let expected = self.context.pop_to_reg(self.masm, None);
+        let addr = self.emit_atomic_addr(memarg);
+        self.context.stack.extend([
+            vmctx.into(),
+            memarg.memory.try_into().unwrap(),
+            addr.into(),
+            expected.into(),
+            timeout.into(),
+        ]);
+
+        FnCall::emit::<M, M::Ptr, _>(self.masm, &mut self.context, |_| {
+            Callee::Builtin(builtin.clone())
+        });
+    }
 
-impl<'a, 'b, 'c, M> CodeGen<'a, 'b, 'c, M>
-where
-    M: MacroAssembler,
-{
     fn cmp_i32s(&mut self, kind: IntCmpKind) {
         self.context.i32_binop(self.masm, |masm, dst, src, size| {
             masm.cmp_with_set(src, dst, kind, size);
@@ -1323,17 +4227,17 @@ impl From<WasmType> for OperandSize {
         match ty {
             WasmType::I32 | WasmType::F32 => OperandSize::S32,
             WasmType::I64 | WasmType::F64 => OperandSize::S64,
+            WasmType::V128 => OperandSize::S128,
             WasmType::Ref(rt) => {
                 match rt.heap_type {
                     // TODO: Harcoded size, assuming 64-bit support only. Once
                     // Wasmtime supports 32-bit architectures, this will need
                     // to be updated in such a way that the calculation of the
                     // OperandSize will depend on the target's  pointer size.
-                    WasmHeapType::Func => OperandSize::S64,
+                    WasmHeapType::Func | WasmHeapType::Extern => OperandSize::S64,
                     t => unimplemented!("Support for WasmHeapType: {t}"),
                 }
             }
-            ty => unimplemented!("Support for WasmType {ty}"),
         }
     }
 }
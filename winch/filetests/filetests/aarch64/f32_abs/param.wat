@@ -0,0 +1,32 @@
+;;! target = "aarch64"
+
+(module
+    (func (param f32) (result f32)
+        (local.get 0)
+        (f32.abs)
+    )
+)
+;;    0:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;    4:	 fd030091             	mov	x29, sp
+;;    8:	 fc030091             	mov	x28, sp
+;;    c:	 e90300aa             	mov	x9, x0
+;;   10:	 308140f8             	ldur	x16, [x9, #8]
+;;   14:	 100240f8             	ldur	x16, [x16]
+;;   18:	 110280d2             	mov	x17, #0x10
+;;   1c:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   20:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   24:	 a2010054             	b.hs	#0x58
+;;   28:	 ff6330eb             	cmp	sp, x16
+;;   2c:	 83010054             	b.lo	#0x5c
+;;   30:	 ff4300d1             	sub	sp, sp, #0x10
+;;   34:	 fc030091             	mov	x28, sp
+;;   38:	 80c300bc             	stur	s0, [x28, #0xc]
+;;   3c:	 894300f8             	stur	x9, [x28, #4]
+;;   40:	 80c340bc             	ldur	s0, [x28, #0xc]
+;;   44:	 00c0201e             	fabs	s0, s0
+;;   48:	 ff430091             	add	sp, sp, #0x10
+;;   4c:	 fc030091             	mov	x28, sp
+;;   50:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   54:	 c0035fd6             	ret	
+;;   58:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   5c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,36 @@
+;;! target = "aarch64"
+
+(module
+    (func (param f32) (param f32) (result i32)
+        (local.get 0)
+        (local.get 1)
+        (f32.ge)
+    )
+)
+;;    0:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;    4:	 fd030091             	mov	x29, sp
+;;    8:	 fc030091             	mov	x28, sp
+;;    c:	 e90300aa             	mov	x9, x0
+;;   10:	 308140f8             	ldur	x16, [x9, #8]
+;;   14:	 100240f8             	ldur	x16, [x16]
+;;   18:	 110280d2             	mov	x17, #0x10
+;;   1c:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   20:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   24:	 02020054             	b.hs	#0x64
+;;   28:	 ff6330eb             	cmp	sp, x16
+;;   2c:	 e3010054             	b.lo	#0x68
+;;   30:	 ff4300d1             	sub	sp, sp, #0x10
+;;   34:	 fc030091             	mov	x28, sp
+;;   38:	 80c300bc             	stur	s0, [x28, #0xc]
+;;   3c:	 818300bc             	stur	s1, [x28, #8]
+;;   40:	 890300f8             	stur	x9, [x28]
+;;   44:	 808340bc             	ldur	s0, [x28, #8]
+;;   48:	 81c340bc             	ldur	s1, [x28, #0xc]
+;;   4c:	 2020201e             	fcmp	s1, s0
+;;   50:	 e0b79f9a             	cset	x0, ge
+;;   54:	 ff430091             	add	sp, sp, #0x10
+;;   58:	 fc030091             	mov	x28, sp
+;;   5c:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   60:	 c0035fd6             	ret	
+;;   64:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   68:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
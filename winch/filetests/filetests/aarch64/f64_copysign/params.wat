@@ -0,0 +1,37 @@
+;;! target = "aarch64"
+
+(module
+    (func (param f64) (param f64) (result f64)
+        (local.get 0)
+        (local.get 1)
+        (f64.copysign)
+    )
+)
+;;    0:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;    4:	 fd030091             	mov	x29, sp
+;;    8:	 fc030091             	mov	x28, sp
+;;    c:	 e90300aa             	mov	x9, x0
+;;   10:	 308140f8             	ldur	x16, [x9, #8]
+;;   14:	 100240f8             	ldur	x16, [x16]
+;;   18:	 110380d2             	mov	x17, #0x18
+;;   1c:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   20:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   24:	 22020054             	b.hs	#0x68
+;;   28:	 ff6330eb             	cmp	sp, x16
+;;   2c:	 03020054             	b.lo	#0x6c
+;;   30:	 ff6300d1             	sub	sp, sp, #0x18
+;;   34:	 fc030091             	mov	x28, sp
+;;   38:	 800301fc             	stur	d0, [x28, #0x10]
+;;   3c:	 818300fc             	stur	d1, [x28, #8]
+;;   40:	 890300f8             	stur	x9, [x28]
+;;   44:	 808340fc             	ldur	d0, [x28, #8]
+;;   48:	 810341fc             	ldur	d1, [x28, #0x10]
+;;   4c:	 1f04417f             	ushr	d31, d0, #0x3f
+;;   50:	 e1577f7f             	sli	d1, d31, #0x3f
+;;   54:	 2040601e             	fmov	d0, d1
+;;   58:	 ff630091             	add	sp, sp, #0x18
+;;   5c:	 fc030091             	mov	x28, sp
+;;   60:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   64:	 c0035fd6             	ret	
+;;   68:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   6c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -19,22 +19,32 @@
 ;;    0:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
 ;;    4:	 fd030091             	mov	x29, sp
 ;;    8:	 fc030091             	mov	x28, sp
-;;    c:	 ff4300d1             	sub	sp, sp, #0x10
-;;   10:	 fc030091             	mov	x28, sp
-;;   14:	 100080d2             	mov	x16, #0
-;;   18:	 908300f8             	stur	x16, [x28, #8]
-;;   1c:	 890300f8             	stur	x9, [x28]
-;;   20:	 500180d2             	mov	x16, #0xa
-;;   24:	 e003102a             	mov	w0, w16
-;;   28:	 80c300b8             	stur	w0, [x28, #0xc]
-;;   2c:	 900280d2             	mov	x16, #0x14
-;;   30:	 e003102a             	mov	w0, w16
-;;   34:	 808300b8             	stur	w0, [x28, #8]
-;;   38:	 808340b8             	ldur	w0, [x28, #8]
-;;   3c:	 81c340b8             	ldur	w1, [x28, #0xc]
-;;   40:	 2160200b             	add	w1, w1, w0, uxtx
-;;   44:	 e003012a             	mov	w0, w1
-;;   48:	 ff430091             	add	sp, sp, #0x10
-;;   4c:	 fc030091             	mov	x28, sp
-;;   50:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
-;;   54:	 c0035fd6             	ret	
+;;    c:	 e90300aa             	mov	x9, x0
+;;   10:	 308140f8             	ldur	x16, [x9, #8]
+;;   14:	 100240f8             	ldur	x16, [x16]
+;;   18:	 110280d2             	mov	x17, #0x10
+;;   1c:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   20:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   24:	 a2020054             	b.hs	#0x78
+;;   28:	 ff6330eb             	cmp	sp, x16
+;;   2c:	 83020054             	b.lo	#0x7c
+;;   30:	 ff4300d1             	sub	sp, sp, #0x10
+;;   34:	 fc030091             	mov	x28, sp
+;;   38:	 100080d2             	mov	x16, #0
+;;   3c:	 908300f8             	stur	x16, [x28, #8]
+;;   40:	 890300f8             	stur	x9, [x28]
+;;   44:	 500180d2             	mov	x16, #0xa
+;;   48:	 e003102a             	mov	w0, w16
+;;   4c:	 80c300b8             	stur	w0, [x28, #0xc]
+;;   50:	 900280d2             	mov	x16, #0x14
+;;   54:	 e003102a             	mov	w0, w16
+;;   58:	 808300b8             	stur	w0, [x28, #8]
+;;   5c:	 80c340b8             	ldur	w0, [x28, #0xc]
+;;   60:	 908340b8             	ldur	w16, [x28, #8]
+;;   64:	 0060300b             	add	w0, w0, w16, uxtx
+;;   68:	 ff430091             	add	sp, sp, #0x10
+;;   6c:	 fc030091             	mov	x28, sp
+;;   70:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   74:	 c0035fd6             	ret	
+;;   78:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   7c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
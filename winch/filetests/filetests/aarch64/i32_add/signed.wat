@@ -10,14 +10,25 @@
 ;;    0:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
 ;;    4:	 fd030091             	mov	x29, sp
 ;;    8:	 fc030091             	mov	x28, sp
-;;    c:	 ff2300d1             	sub	sp, sp, #8
-;;   10:	 fc030091             	mov	x28, sp
-;;   14:	 890300f8             	stur	x9, [x28]
-;;   18:	 f07f40b2             	orr	x16, xzr, #0xffffffff
-;;   1c:	 e003102a             	mov	w0, w16
-;;   20:	 f07f40b2             	orr	x16, xzr, #0xffffffff
-;;   24:	 0060300b             	add	w0, w0, w16, uxtx
-;;   28:	 ff230091             	add	sp, sp, #8
-;;   2c:	 fc030091             	mov	x28, sp
-;;   30:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
-;;   34:	 c0035fd6             	ret	
+;;    c:	 e90300aa             	mov	x9, x0
+;;   10:	 308140f8             	ldur	x16, [x9, #8]
+;;   14:	 100240f8             	ldur	x16, [x16]
+;;   18:	 110180d2             	mov	x17, #8
+;;   1c:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   20:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   24:	 c2010054             	b.hs	#0x5c
+;;   28:	 ff6330eb             	cmp	sp, x16
+;;   2c:	 a3010054             	b.lo	#0x60
+;;   30:	 ff2300d1             	sub	sp, sp, #8
+;;   34:	 fc030091             	mov	x28, sp
+;;   38:	 890300f8             	stur	x9, [x28]
+;;   3c:	 f07f40b2             	orr	x16, xzr, #0xffffffff
+;;   40:	 e003102a             	mov	w0, w16
+;;   44:	 f07f40b2             	orr	x16, xzr, #0xffffffff
+;;   48:	 0060300b             	add	w0, w0, w16, uxtx
+;;   4c:	 ff230091             	add	sp, sp, #8
+;;   50:	 fc030091             	mov	x28, sp
+;;   54:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   58:	 c0035fd6             	ret	
+;;   5c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   60:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -10,16 +10,27 @@
 ;;    0:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
 ;;    4:	 fd030091             	mov	x29, sp
 ;;    8:	 fc030091             	mov	x28, sp
-;;    c:	 ff4300d1             	sub	sp, sp, #0x10
-;;   10:	 fc030091             	mov	x28, sp
-;;   14:	 80c300b8             	stur	w0, [x28, #0xc]
-;;   18:	 818300b8             	stur	w1, [x28, #8]
-;;   1c:	 890300f8             	stur	x9, [x28]
-;;   20:	 808340b8             	ldur	w0, [x28, #8]
-;;   24:	 81c340b8             	ldur	w1, [x28, #0xc]
-;;   28:	 217c001b             	mul	w1, w1, w0
-;;   2c:	 e003012a             	mov	w0, w1
-;;   30:	 ff430091             	add	sp, sp, #0x10
+;;    c:	 e90300aa             	mov	x9, x0
+;;   10:	 308140f8             	ldur	x16, [x9, #8]
+;;   14:	 100240f8             	ldur	x16, [x16]
+;;   18:	 110280d2             	mov	x17, #0x10
+;;   1c:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   20:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   24:	 02020054             	b.hs	#0x64
+;;   28:	 ff6330eb             	cmp	sp, x16
+;;   2c:	 e3010054             	b.lo	#0x68
+;;   30:	 ff4300d1             	sub	sp, sp, #0x10
 ;;   34:	 fc030091             	mov	x28, sp
-;;   38:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
-;;   3c:	 c0035fd6             	ret	
+;;   38:	 82c300b8             	stur	w2, [x28, #0xc]
+;;   3c:	 838300b8             	stur	w3, [x28, #8]
+;;   40:	 890300f8             	stur	x9, [x28]
+;;   44:	 808340b8             	ldur	w0, [x28, #8]
+;;   48:	 81c340b8             	ldur	w1, [x28, #0xc]
+;;   4c:	 217c001b             	mul	w1, w1, w0
+;;   50:	 e003012a             	mov	w0, w1
+;;   54:	 ff430091             	add	sp, sp, #0x10
+;;   58:	 fc030091             	mov	x28, sp
+;;   5c:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   60:	 c0035fd6             	ret	
+;;   64:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   68:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,45 @@
+;;! target = "aarch64"
+
+(module
+    (func (param f32) (result i32)
+        (local.get 0)
+        (i32.trunc_f32_s)
+    )
+)
+;;    0:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;    4:	 fd030091             	mov	x29, sp
+;;    8:	 fc030091             	mov	x28, sp
+;;    c:	 e90300aa             	mov	x9, x0
+;;   10:	 308140f8             	ldur	x16, [x9, #8]
+;;   14:	 100240f8             	ldur	x16, [x16]
+;;   18:	 110280d2             	mov	x17, #0x10
+;;   1c:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   20:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   24:	 e2020054             	b.hs	#0x80
+;;   28:	 ff6330eb             	cmp	sp, x16
+;;   2c:	 c3020054             	b.lo	#0x84
+;;   30:	 ff4300d1             	sub	sp, sp, #0x10
+;;   34:	 fc030091             	mov	x28, sp
+;;   38:	 80c300bc             	stur	s0, [x28, #0xc]
+;;   3c:	 894300f8             	stur	x9, [x28, #4]
+;;   40:	 80c340bc             	ldur	s0, [x28, #0xc]
+;;   44:	 0020201e             	fcmp	s0, s0
+;;   48:	 06020054             	b.vs	#0x88
+;;   4c:	 10e0b9d2             	mov	x16, #0xcf000000
+;;   50:	 1f02271e             	fmov	s31, w16
+;;   54:	 00203f1e             	fcmp	s0, s31
+;;   58:	 ab010054             	b.lt	#0x8c
+;;   5c:	 10e0a9d2             	mov	x16, #0x4f000000
+;;   60:	 1f02271e             	fmov	s31, w16
+;;   64:	 00203f1e             	fcmp	s0, s31
+;;   68:	 4a010054             	b.ge	#0x90
+;;   6c:	 0000381e             	fcvtzs	w0, s0
+;;   70:	 ff430091             	add	sp, sp, #0x10
+;;   74:	 fc030091             	mov	x28, sp
+;;   78:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   7c:	 c0035fd6             	ret	
+;;   80:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   88:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   8c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   90:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,46 @@
+;;! target = "aarch64"
+
+(module
+    (func (param f64) (result i32)
+        (local.get 0)
+        (i32.trunc_f64_s)
+    )
+)
+;;    0:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;    4:	 fd030091             	mov	x29, sp
+;;    8:	 fc030091             	mov	x28, sp
+;;    c:	 e90300aa             	mov	x9, x0
+;;   10:	 308140f8             	ldur	x16, [x9, #8]
+;;   14:	 100240f8             	ldur	x16, [x16]
+;;   18:	 110280d2             	mov	x17, #0x10
+;;   1c:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   20:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   24:	 02030054             	b.hs	#0x84
+;;   28:	 ff6330eb             	cmp	sp, x16
+;;   2c:	 e3020054             	b.lo	#0x88
+;;   30:	 ff4300d1             	sub	sp, sp, #0x10
+;;   34:	 fc030091             	mov	x28, sp
+;;   38:	 808300fc             	stur	d0, [x28, #8]
+;;   3c:	 890300f8             	stur	x9, [x28]
+;;   40:	 808340fc             	ldur	d0, [x28, #8]
+;;   44:	 0020601e             	fcmp	d0, d0
+;;   48:	 26020054             	b.vs	#0x8c
+;;   4c:	 1004a0d2             	mov	x16, #0x200000
+;;   50:	 103cf8f2             	movk	x16, #0xc1e0, lsl #48
+;;   54:	 1f02679e             	fmov	d31, x16
+;;   58:	 00207f1e             	fcmp	d0, d31
+;;   5c:	 ad010054             	b.le	#0x90
+;;   60:	 103ce8d2             	mov	x16, #0x41e0000000000000
+;;   64:	 1f02679e             	fmov	d31, x16
+;;   68:	 00207f1e             	fcmp	d0, d31
+;;   6c:	 4a010054             	b.ge	#0x94
+;;   70:	 0000781e             	fcvtzs	w0, d0
+;;   74:	 ff430091             	add	sp, sp, #0x10
+;;   78:	 fc030091             	mov	x28, sp
+;;   7c:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   80:	 c0035fd6             	ret	
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   88:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   8c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   90:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   94:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -10,16 +10,26 @@
 ;;    0:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
 ;;    4:	 fd030091             	mov	x29, sp
 ;;    8:	 fc030091             	mov	x28, sp
-;;    c:	 ff6300d1             	sub	sp, sp, #0x18
-;;   10:	 fc030091             	mov	x28, sp
-;;   14:	 800301f8             	stur	x0, [x28, #0x10]
-;;   18:	 818300f8             	stur	x1, [x28, #8]
-;;   1c:	 890300f8             	stur	x9, [x28]
-;;   20:	 808340f8             	ldur	x0, [x28, #8]
-;;   24:	 810341f8             	ldur	x1, [x28, #0x10]
-;;   28:	 2160208b             	add	x1, x1, x0, uxtx
-;;   2c:	 e00301aa             	mov	x0, x1
-;;   30:	 ff630091             	add	sp, sp, #0x18
+;;    c:	 e90300aa             	mov	x9, x0
+;;   10:	 308140f8             	ldur	x16, [x9, #8]
+;;   14:	 100240f8             	ldur	x16, [x16]
+;;   18:	 110380d2             	mov	x17, #0x18
+;;   1c:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   20:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   24:	 e2010054             	b.hs	#0x60
+;;   28:	 ff6330eb             	cmp	sp, x16
+;;   2c:	 c3010054             	b.lo	#0x64
+;;   30:	 ff6300d1             	sub	sp, sp, #0x18
 ;;   34:	 fc030091             	mov	x28, sp
-;;   38:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
-;;   3c:	 c0035fd6             	ret	
+;;   38:	 820301f8             	stur	x2, [x28, #0x10]
+;;   3c:	 838300f8             	stur	x3, [x28, #8]
+;;   40:	 890300f8             	stur	x9, [x28]
+;;   44:	 800341f8             	ldur	x0, [x28, #0x10]
+;;   48:	 908340f8             	ldur	x16, [x28, #8]
+;;   4c:	 0060308b             	add	x0, x0, x16, uxtx
+;;   50:	 ff630091             	add	sp, sp, #0x18
+;;   54:	 fc030091             	mov	x28, sp
+;;   58:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   5c:	 c0035fd6             	ret	
+;;   60:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   64:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -19,23 +19,34 @@
 ;;    0:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
 ;;    4:	 fd030091             	mov	x29, sp
 ;;    8:	 fc030091             	mov	x28, sp
-;;    c:	 ff6300d1             	sub	sp, sp, #0x18
-;;   10:	 fc030091             	mov	x28, sp
-;;   14:	 100080d2             	mov	x16, #0
-;;   18:	 900301f8             	stur	x16, [x28, #0x10]
-;;   1c:	 908300f8             	stur	x16, [x28, #8]
-;;   20:	 890300f8             	stur	x9, [x28]
-;;   24:	 500180d2             	mov	x16, #0xa
-;;   28:	 e00310aa             	mov	x0, x16
-;;   2c:	 800301f8             	stur	x0, [x28, #0x10]
-;;   30:	 900280d2             	mov	x16, #0x14
-;;   34:	 e00310aa             	mov	x0, x16
-;;   38:	 808300f8             	stur	x0, [x28, #8]
-;;   3c:	 808340f8             	ldur	x0, [x28, #8]
-;;   40:	 810341f8             	ldur	x1, [x28, #0x10]
-;;   44:	 217c009b             	mul	x1, x1, x0
-;;   48:	 e00301aa             	mov	x0, x1
-;;   4c:	 ff630091             	add	sp, sp, #0x18
-;;   50:	 fc030091             	mov	x28, sp
-;;   54:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
-;;   58:	 c0035fd6             	ret	
+;;    c:	 e90300aa             	mov	x9, x0
+;;   10:	 308140f8             	ldur	x16, [x9, #8]
+;;   14:	 100240f8             	ldur	x16, [x16]
+;;   18:	 110380d2             	mov	x17, #0x18
+;;   1c:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   20:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   24:	 e2020054             	b.hs	#0x80
+;;   28:	 ff6330eb             	cmp	sp, x16
+;;   2c:	 c3020054             	b.lo	#0x84
+;;   30:	 ff6300d1             	sub	sp, sp, #0x18
+;;   34:	 fc030091             	mov	x28, sp
+;;   38:	 100080d2             	mov	x16, #0
+;;   3c:	 900301f8             	stur	x16, [x28, #0x10]
+;;   40:	 908300f8             	stur	x16, [x28, #8]
+;;   44:	 890300f8             	stur	x9, [x28]
+;;   48:	 500180d2             	mov	x16, #0xa
+;;   4c:	 e00310aa             	mov	x0, x16
+;;   50:	 800301f8             	stur	x0, [x28, #0x10]
+;;   54:	 900280d2             	mov	x16, #0x14
+;;   58:	 e00310aa             	mov	x0, x16
+;;   5c:	 808300f8             	stur	x0, [x28, #8]
+;;   60:	 808340f8             	ldur	x0, [x28, #8]
+;;   64:	 810341f8             	ldur	x1, [x28, #0x10]
+;;   68:	 217c009b             	mul	x1, x1, x0
+;;   6c:	 e00301aa             	mov	x0, x1
+;;   70:	 ff630091             	add	sp, sp, #0x18
+;;   74:	 fc030091             	mov	x28, sp
+;;   78:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   7c:	 c0035fd6             	ret	
+;;   80:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -0,0 +1,45 @@
+;;! target = "aarch64"
+
+(module
+    (func (param f64) (result i64)
+        (local.get 0)
+        (i64.trunc_f64_u)
+    )
+)
+;;    0:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;    4:	 fd030091             	mov	x29, sp
+;;    8:	 fc030091             	mov	x28, sp
+;;    c:	 e90300aa             	mov	x9, x0
+;;   10:	 308140f8             	ldur	x16, [x9, #8]
+;;   14:	 100240f8             	ldur	x16, [x16]
+;;   18:	 110280d2             	mov	x17, #0x10
+;;   1c:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   20:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   24:	 e2020054             	b.hs	#0x80
+;;   28:	 ff6330eb             	cmp	sp, x16
+;;   2c:	 c3020054             	b.lo	#0x84
+;;   30:	 ff4300d1             	sub	sp, sp, #0x10
+;;   34:	 fc030091             	mov	x28, sp
+;;   38:	 808300fc             	stur	d0, [x28, #8]
+;;   3c:	 890300f8             	stur	x9, [x28]
+;;   40:	 818340fc             	ldur	d1, [x28, #8]
+;;   44:	 2020611e             	fcmp	d1, d1
+;;   48:	 06020054             	b.vs	#0x88
+;;   4c:	 10fef7d2             	mov	x16, #-0x4010000000000000
+;;   50:	 1f02679e             	fmov	d31, x16
+;;   54:	 20207f1e             	fcmp	d1, d31
+;;   58:	 ad010054             	b.le	#0x8c
+;;   5c:	 107ee8d2             	mov	x16, #0x43f0000000000000
+;;   60:	 1f02679e             	fmov	d31, x16
+;;   64:	 20207f1e             	fcmp	d1, d31
+;;   68:	 4a010054             	b.ge	#0x90
+;;   6c:	 2000799e             	fcvtzu	x0, d1
+;;   70:	 ff430091             	add	sp, sp, #0x10
+;;   74:	 fc030091             	mov	x28, sp
+;;   78:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   7c:	 c0035fd6             	ret	
+;;   80:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   84:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   88:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   8c:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   90:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
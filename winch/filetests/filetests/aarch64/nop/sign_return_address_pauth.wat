@@ -0,0 +1,30 @@
+;;! target = "aarch64"
+;;! flags = ["sign_return_address", "sign_return_address_with_bkey", "has_pauth"]
+
+(module
+    (func
+        nop
+    )
+)
+;;    0:	 7f2303d5             	pacibsp	
+;;    4:	 fd7bbfa9             	stp	x29, x30, [sp, #-0x10]!
+;;    8:	 fd030091             	mov	x29, sp
+;;    c:	 fc030091             	mov	x28, sp
+;;   10:	 e90300aa             	mov	x9, x0
+;;   14:	 308140f8             	ldur	x16, [x9, #8]
+;;   18:	 100240f8             	ldur	x16, [x16]
+;;   1c:	 110180d2             	mov	x17, #8
+;;   20:	 1100a0f2             	movk	x17, #0, lsl #16
+;;   24:	 106231ab             	adds	x16, x16, x17, uxtx
+;;   28:	 42010054             	b.hs	#0x50
+;;   2c:	 ff6330eb             	cmp	sp, x16
+;;   30:	 23010054             	b.lo	#0x54
+;;   34:	 ff2300d1             	sub	sp, sp, #8
+;;   38:	 fc030091             	mov	x28, sp
+;;   3c:	 890300f8             	stur	x9, [x28]
+;;   40:	 ff230091             	add	sp, sp, #8
+;;   44:	 fc030091             	mov	x28, sp
+;;   48:	 fd7bc1a8             	ldp	x29, x30, [sp], #0x10
+;;   4c:	 ff0f5fd6             	retab	
+;;   50:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
+;;   54:	 1fc10000             	.byte	0x1f, 0xc1, 0x00, 0x00
@@ -9,22 +9,44 @@
   
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b801000000           	mov	eax, 1
-;;   11:	 85c0                 	test	eax, eax
-;;   13:	 0f840d000000         	je	0x26
-;;   19:	 4883ec08             	sub	rsp, 8
-;;   1d:	 e800000000           	call	0x22
-;;   22:	 4883c408             	add	rsp, 8
-;;   26:	 4883c408             	add	rsp, 8
-;;   2a:	 5d                   	pop	rbp
-;;   2b:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f823f000000         	jb	0x5a
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8238000000         	jb	0x5c
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b801000000           	mov	eax, 1
+;;   31:	 85c0                 	test	eax, eax
+;;   33:	 0f841b000000         	je	0x54
+;;   39:	 4156                 	push	r14
+;;   3b:	 4156                 	push	r14
+;;   3d:	 4883ec08             	sub	rsp, 8
+;;   41:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   46:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   4b:	 e800000000           	call	0x50
+;;   50:	 4883c418             	add	rsp, 0x18
+;;   54:	 4883c408             	add	rsp, 8
+;;   58:	 5d                   	pop	rbp
+;;   59:	 c3                   	ret	
+;;   5a:	 0f0b                 	ud2	
+;;   5c:	 0f0b                 	ud2	
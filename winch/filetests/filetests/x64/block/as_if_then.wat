@@ -6,14 +6,23 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b801000000           	mov	eax, 1
-;;   11:	 85c0                 	test	eax, eax
-;;   13:	 0f840a000000         	je	0x23
-;;   19:	 b801000000           	mov	eax, 1
-;;   1e:	 e905000000           	jmp	0x28
-;;   23:	 b802000000           	mov	eax, 2
-;;   28:	 4883c408             	add	rsp, 8
-;;   2c:	 5d                   	pop	rbp
-;;   2d:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8233000000         	jb	0x4e
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822c000000         	jb	0x50
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b801000000           	mov	eax, 1
+;;   31:	 85c0                 	test	eax, eax
+;;   33:	 0f840a000000         	je	0x43
+;;   39:	 b801000000           	mov	eax, 1
+;;   3e:	 e905000000           	jmp	0x48
+;;   43:	 b802000000           	mov	eax, 2
+;;   48:	 4883c408             	add	rsp, 8
+;;   4c:	 5d                   	pop	rbp
+;;   4d:	 c3                   	ret	
+;;   4e:	 0f0b                 	ud2	
+;;   50:	 0f0b                 	ud2	
@@ -10,16 +10,34 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
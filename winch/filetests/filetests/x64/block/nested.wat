@@ -11,23 +11,49 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883ec08             	sub	rsp, 8
-;;   10:	 e800000000           	call	0x15
-;;   15:	 4883c408             	add	rsp, 8
-;;   19:	 4883ec08             	sub	rsp, 8
-;;   1d:	 e800000000           	call	0x22
-;;   22:	 4883c408             	add	rsp, 8
-;;   26:	 b809000000           	mov	eax, 9
-;;   2b:	 4883c408             	add	rsp, 8
-;;   2f:	 5d                   	pop	rbp
-;;   30:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f8252000000         	jb	0x6d
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f824b000000         	jb	0x6f
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4156                 	push	r14
+;;   2e:	 4156                 	push	r14
+;;   30:	 4883ec08             	sub	rsp, 8
+;;   34:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   39:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   3e:	 e800000000           	call	0x43
+;;   43:	 4883c418             	add	rsp, 0x18
+;;   47:	 4156                 	push	r14
+;;   49:	 4156                 	push	r14
+;;   4b:	 4883ec08             	sub	rsp, 8
+;;   4f:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   54:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   59:	 e800000000           	call	0x5e
+;;   5e:	 4883c418             	add	rsp, 0x18
+;;   62:	 b809000000           	mov	eax, 9
+;;   67:	 4883c408             	add	rsp, 8
+;;   6b:	 5d                   	pop	rbp
+;;   6c:	 c3                   	ret	
+;;   6d:	 0f0b                 	ud2	
+;;   6f:	 0f0b                 	ud2	
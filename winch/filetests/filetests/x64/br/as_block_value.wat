@@ -8,20 +8,42 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883ec08             	sub	rsp, 8
-;;   10:	 e800000000           	call	0x15
-;;   15:	 4883c408             	add	rsp, 8
-;;   19:	 b802000000           	mov	eax, 2
-;;   1e:	 4883c408             	add	rsp, 8
-;;   22:	 5d                   	pop	rbp
-;;   23:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f8237000000         	jb	0x52
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8230000000         	jb	0x54
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4156                 	push	r14
+;;   2e:	 4156                 	push	r14
+;;   30:	 4883ec08             	sub	rsp, 8
+;;   34:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   39:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   3e:	 e800000000           	call	0x43
+;;   43:	 4883c418             	add	rsp, 0x18
+;;   47:	 b802000000           	mov	eax, 2
+;;   4c:	 4883c408             	add	rsp, 8
+;;   50:	 5d                   	pop	rbp
+;;   51:	 c3                   	ret	
+;;   52:	 0f0b                 	ud2	
+;;   54:	 0f0b                 	ud2	
@@ -6,9 +6,18 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b809000000           	mov	eax, 9
-;;   11:	 4883c408             	add	rsp, 8
-;;   15:	 5d                   	pop	rbp
-;;   16:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f821c000000         	jb	0x37
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8215000000         	jb	0x39
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b809000000           	mov	eax, 9
+;;   31:	 4883c408             	add	rsp, 8
+;;   35:	 5d                   	pop	rbp
+;;   36:	 c3                   	ret	
+;;   37:	 0f0b                 	ud2	
+;;   39:	 0f0b                 	ud2	
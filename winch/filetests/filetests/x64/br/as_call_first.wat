@@ -10,21 +10,39 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 897c2414             	mov	dword ptr [rsp + 0x14], edi
-;;    c:	 89742410             	mov	dword ptr [rsp + 0x10], esi
-;;   10:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
-;;   14:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   19:	 b8ffffffff           	mov	eax, 0xffffffff
-;;   1e:	 4883c418             	add	rsp, 0x18
-;;   22:	 5d                   	pop	rbp
-;;   23:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f822a000000         	jb	0x45
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8223000000         	jb	0x47
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;   2c:	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
+;;   30:	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
+;;   35:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   3a:	 b8ffffffff           	mov	eax, 0xffffffff
+;;   3f:	 4883c418             	add	rsp, 0x18
+;;   43:	 5d                   	pop	rbp
+;;   44:	 c3                   	ret	
+;;   45:	 0f0b                 	ud2	
+;;   47:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b80c000000           	mov	eax, 0xc
-;;   11:	 4883c408             	add	rsp, 8
-;;   15:	 5d                   	pop	rbp
-;;   16:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f821c000000         	jb	0x37
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8215000000         	jb	0x39
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b80c000000           	mov	eax, 0xc
+;;   31:	 4883c408             	add	rsp, 8
+;;   35:	 5d                   	pop	rbp
+;;   36:	 c3                   	ret	
+;;   37:	 0f0b                 	ud2	
+;;   39:	 0f0b                 	ud2	
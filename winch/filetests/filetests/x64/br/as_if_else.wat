@@ -11,16 +11,25 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   18:	 85c0                 	test	eax, eax
-;;   1a:	 0f8409000000         	je	0x29
-;;   20:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   24:	 e905000000           	jmp	0x2e
-;;   29:	 b804000000           	mov	eax, 4
-;;   2e:	 4883c410             	add	rsp, 0x10
-;;   32:	 5d                   	pop	rbp
-;;   33:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8239000000         	jb	0x54
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8232000000         	jb	0x56
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   38:	 85c0                 	test	eax, eax
+;;   3a:	 0f8409000000         	je	0x49
+;;   40:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   44:	 e905000000           	jmp	0x4e
+;;   49:	 b804000000           	mov	eax, 4
+;;   4e:	 4883c410             	add	rsp, 0x10
+;;   52:	 5d                   	pop	rbp
+;;   53:	 c3                   	ret	
+;;   54:	 0f0b                 	ud2	
+;;   56:	 0f0b                 	ud2	
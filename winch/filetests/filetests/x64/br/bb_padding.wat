@@ -0,0 +1,34 @@
+;;! target = "x86_64"
+;;! flags = ["bb_padding_log2_minus_one=3"]
+
+(module
+  (func (result i32)
+    (block (result i32)
+      (br 0 (i32.const 1))
+    )
+  )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8233000000         	jb	0x4e
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822c000000         	jb	0x50
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b801000000           	mov	eax, 1
+;;   31:	 e909000000           	jmp	0x3f
+;;   36:	 e904000000           	jmp	0x3f
+;;   3b:	 0000                 	add	byte ptr [rax], al
+;;   3d:	 0000                 	add	byte ptr [rax], al
+;;   3f:	 e904000000           	jmp	0x48
+;;   44:	 0000                 	add	byte ptr [rax], al
+;;   46:	 0000                 	add	byte ptr [rax], al
+;;   48:	 4883c408             	add	rsp, 8
+;;   4c:	 5d                   	pop	rbp
+;;   4d:	 c3                   	ret	
+;;   4e:	 0f0b                 	ud2	
+;;   50:	 0f0b                 	ud2	
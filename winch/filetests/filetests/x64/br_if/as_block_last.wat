@@ -7,22 +7,50 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 e800000000           	call	0x16
-;;   16:	 e800000000           	call	0x1b
-;;   1b:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   1f:	 85c0                 	test	eax, eax
-;;   21:	 0f8500000000         	jne	0x27
-;;   27:	 4883c410             	add	rsp, 0x10
-;;   2b:	 5d                   	pop	rbp
-;;   2c:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f8254000000         	jb	0x6f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f824d000000         	jb	0x71
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 4156                 	push	r14
+;;   33:	 4156                 	push	r14
+;;   35:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   3a:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   3e:	 e800000000           	call	0x43
+;;   43:	 4883c410             	add	rsp, 0x10
+;;   47:	 4156                 	push	r14
+;;   49:	 4156                 	push	r14
+;;   4b:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   50:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   54:	 e800000000           	call	0x59
+;;   59:	 4883c410             	add	rsp, 0x10
+;;   5d:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   61:	 85c0                 	test	eax, eax
+;;   63:	 0f8500000000         	jne	0x69
+;;   69:	 4883c410             	add	rsp, 0x10
+;;   6d:	 5d                   	pop	rbp
+;;   6e:	 c3                   	ret	
+;;   6f:	 0f0b                 	ud2	
+;;   71:	 0f0b                 	ud2	
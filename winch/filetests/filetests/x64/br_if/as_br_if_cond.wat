@@ -6,14 +6,23 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b801000000           	mov	eax, 1
-;;   11:	 85c0                 	test	eax, eax
-;;   13:	 0f850d000000         	jne	0x26
-;;   19:	 b801000000           	mov	eax, 1
-;;   1e:	 85c0                 	test	eax, eax
-;;   20:	 0f8500000000         	jne	0x26
-;;   26:	 4883c408             	add	rsp, 8
-;;   2a:	 5d                   	pop	rbp
-;;   2b:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8231000000         	jb	0x4c
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822a000000         	jb	0x4e
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b801000000           	mov	eax, 1
+;;   31:	 85c0                 	test	eax, eax
+;;   33:	 0f850d000000         	jne	0x46
+;;   39:	 b801000000           	mov	eax, 1
+;;   3e:	 85c0                 	test	eax, eax
+;;   40:	 0f8500000000         	jne	0x46
+;;   46:	 4883c408             	add	rsp, 8
+;;   4a:	 5d                   	pop	rbp
+;;   4b:	 c3                   	ret	
+;;   4c:	 0f0b                 	ud2	
+;;   4e:	 0f0b                 	ud2	
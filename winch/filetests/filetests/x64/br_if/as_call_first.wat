@@ -11,30 +11,52 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 897c2414             	mov	dword ptr [rsp + 0x14], edi
-;;    c:	 89742410             	mov	dword ptr [rsp + 0x10], esi
-;;   10:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
-;;   14:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   19:	 b8ffffffff           	mov	eax, 0xffffffff
-;;   1e:	 4883c418             	add	rsp, 0x18
-;;   22:	 5d                   	pop	rbp
-;;   23:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f822a000000         	jb	0x45
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8223000000         	jb	0x47
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;   2c:	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
+;;   30:	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
+;;   35:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   3a:	 b8ffffffff           	mov	eax, 0xffffffff
+;;   3f:	 4883c418             	add	rsp, 0x18
+;;   43:	 5d                   	pop	rbp
+;;   44:	 c3                   	ret	
+;;   45:	 0f0b                 	ud2	
+;;   47:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b901000000           	mov	ecx, 1
-;;   11:	 b80c000000           	mov	eax, 0xc
-;;   16:	 85c9                 	test	ecx, ecx
-;;   18:	 0f8517000000         	jne	0x35
-;;   1e:	 50                   	push	rax
-;;   1f:	 8b3c24               	mov	edi, dword ptr [rsp]
-;;   22:	 be02000000           	mov	esi, 2
-;;   27:	 ba03000000           	mov	edx, 3
-;;   2c:	 e800000000           	call	0x31
-;;   31:	 4883c408             	add	rsp, 8
-;;   35:	 4883c408             	add	rsp, 8
-;;   39:	 5d                   	pop	rbp
-;;   3a:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f824f000000         	jb	0x6a
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8248000000         	jb	0x6c
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b901000000           	mov	ecx, 1
+;;   31:	 b80c000000           	mov	eax, 0xc
+;;   36:	 85c9                 	test	ecx, ecx
+;;   38:	 0f8526000000         	jne	0x64
+;;   3e:	 4156                 	push	r14
+;;   40:	 4156                 	push	r14
+;;   42:	 50                   	push	rax
+;;   43:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   48:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   4d:	 8b1424               	mov	edx, dword ptr [rsp]
+;;   50:	 b902000000           	mov	ecx, 2
+;;   55:	 41b803000000         	mov	r8d, 3
+;;   5b:	 e800000000           	call	0x60
+;;   60:	 4883c418             	add	rsp, 0x18
+;;   64:	 4883c408             	add	rsp, 8
+;;   68:	 5d                   	pop	rbp
+;;   69:	 c3                   	ret	
+;;   6a:	 0f0b                 	ud2	
+;;   6c:	 0f0b                 	ud2	
@@ -12,18 +12,27 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   15:	 b801000000           	mov	eax, 1
-;;   1a:	 85c9                 	test	ecx, ecx
-;;   1c:	 0f8517000000         	jne	0x39
-;;   22:	 85c0                 	test	eax, eax
-;;   24:	 0f840a000000         	je	0x34
-;;   2a:	 b802000000           	mov	eax, 2
-;;   2f:	 e905000000           	jmp	0x39
-;;   34:	 b803000000           	mov	eax, 3
-;;   39:	 4883c410             	add	rsp, 0x10
-;;   3d:	 5d                   	pop	rbp
-;;   3e:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8244000000         	jb	0x5f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f823d000000         	jb	0x61
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   35:	 b801000000           	mov	eax, 1
+;;   3a:	 85c9                 	test	ecx, ecx
+;;   3c:	 0f8517000000         	jne	0x59
+;;   42:	 85c0                 	test	eax, eax
+;;   44:	 0f840a000000         	je	0x54
+;;   4a:	 b802000000           	mov	eax, 2
+;;   4f:	 e905000000           	jmp	0x59
+;;   54:	 b803000000           	mov	eax, 3
+;;   59:	 4883c410             	add	rsp, 0x10
+;;   5d:	 5d                   	pop	rbp
+;;   5e:	 c3                   	ret	
+;;   5f:	 0f0b                 	ud2	
+;;   61:	 0f0b                 	ud2	
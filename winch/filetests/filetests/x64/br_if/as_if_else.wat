@@ -10,26 +10,49 @@
 
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   18:	 85c0                 	test	eax, eax
-;;   1a:	 0f840a000000         	je	0x2a
-;;   20:	 e800000000           	call	0x25
-;;   25:	 e90c000000           	jmp	0x36
-;;   2a:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   2e:	 85c0                 	test	eax, eax
-;;   30:	 0f8500000000         	jne	0x36
-;;   36:	 4883c410             	add	rsp, 0x10
-;;   3a:	 5d                   	pop	rbp
-;;   3b:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f8252000000         	jb	0x6d
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f824b000000         	jb	0x6f
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   38:	 85c0                 	test	eax, eax
+;;   3a:	 0f841b000000         	je	0x5b
+;;   40:	 4156                 	push	r14
+;;   42:	 4156                 	push	r14
+;;   44:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   49:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   4d:	 e800000000           	call	0x52
+;;   52:	 4883c410             	add	rsp, 0x10
+;;   56:	 e90c000000           	jmp	0x67
+;;   5b:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   5f:	 85c0                 	test	eax, eax
+;;   61:	 0f8500000000         	jne	0x67
+;;   67:	 4883c410             	add	rsp, 0x10
+;;   6b:	 5d                   	pop	rbp
+;;   6c:	 c3                   	ret	
+;;   6d:	 0f0b                 	ud2	
+;;   6f:	 0f0b                 	ud2	
@@ -10,17 +10,26 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 c744240800000000     	mov	dword ptr [rsp + 8], 0
-;;   14:	 4531db               	xor	r11d, r11d
-;;   17:	 4c893424             	mov	qword ptr [rsp], r14
-;;   1b:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   1f:	 b811000000           	mov	eax, 0x11
-;;   24:	 85c9                 	test	ecx, ecx
-;;   26:	 0f8509000000         	jne	0x35
-;;   2c:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   30:	 b8ffffffff           	mov	eax, 0xffffffff
-;;   35:	 4883c410             	add	rsp, 0x10
-;;   39:	 5d                   	pop	rbp
-;;   3a:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8240000000         	jb	0x5b
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8239000000         	jb	0x5d
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 c744240800000000     	mov	dword ptr [rsp + 8], 0
+;;   34:	 4531db               	xor	r11d, r11d
+;;   37:	 4c893424             	mov	qword ptr [rsp], r14
+;;   3b:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   3f:	 b811000000           	mov	eax, 0x11
+;;   44:	 85c9                 	test	ecx, ecx
+;;   46:	 0f8509000000         	jne	0x55
+;;   4c:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   50:	 b8ffffffff           	mov	eax, 0xffffffff
+;;   55:	 4883c410             	add	rsp, 0x10
+;;   59:	 5d                   	pop	rbp
+;;   5a:	 c3                   	ret	
+;;   5b:	 0f0b                 	ud2	
+;;   5d:	 0f0b                 	ud2	
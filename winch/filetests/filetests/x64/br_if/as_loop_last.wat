@@ -7,21 +7,44 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 e800000000           	call	0x16
-;;   16:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   1a:	 85c0                 	test	eax, eax
-;;   1c:	 0f8500000000         	jne	0x22
-;;   22:	 4883c410             	add	rsp, 0x10
-;;   26:	 5d                   	pop	rbp
-;;   27:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f823e000000         	jb	0x59
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8237000000         	jb	0x5b
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 4156                 	push	r14
+;;   33:	 4156                 	push	r14
+;;   35:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   3a:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   3e:	 e800000000           	call	0x43
+;;   43:	 4883c410             	add	rsp, 0x10
+;;   47:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   4b:	 85c0                 	test	eax, eax
+;;   4d:	 0f8500000000         	jne	0x53
+;;   53:	 4883c410             	add	rsp, 0x10
+;;   57:	 5d                   	pop	rbp
+;;   58:	 c3                   	ret	
+;;   59:	 0f0b                 	ud2	
+;;   5b:	 0f0b                 	ud2	
@@ -739,27 +739,24 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   15:	 b927600000           	mov	ecx, 0x6027
-;;   1a:	 39c1                 	cmp	ecx, eax
-;;   1c:	 0f42c1               	cmovb	eax, ecx
-;;   1f:	 4c8d1d0a000000       	lea	r11, [rip + 0xa]
-;;   26:	 49630c83             	movsxd	rcx, dword ptr [r11 + rax*4]
-;;   2a:	 4901cb               	add	r11, rcx
-;;   2d:	 41ffe3               	jmp	r11
-;;   30:	 a0800100aa800100a0   	
-;; 				movabs	al, byte ptr [0xa0000180aa000180]
-;;   39:	 800100               	add	byte ptr [rcx], 0
-;;   3c:	 aa                   	stosb	byte ptr [rdi], al
-;;   3d:	 800100               	add	byte ptr [rcx], 0
-;;   40:	 a0800100aa800100a0   	
-;; 				movabs	al, byte ptr [0xa0000180aa000180]
-;;   49:	 800100               	add	byte ptr [rcx], 0
-;;   4c:	 aa                   	stosb	byte ptr [rdi], al
-;;   4d:	 800100               	add	byte ptr [rcx], 0
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f82ea800100         	jb	0x18105
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f82e3800100         	jb	0x18107
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 b927600000           	mov	ecx, 0x6027
+;;   3a:	 39c1                 	cmp	ecx, eax
+;;   3c:	 0f42c1               	cmovb	eax, ecx
+;;   3f:	 4c8d1d0a000000       	lea	r11, [rip + 0xa]
+;;   46:	 49630c83             	movsxd	rcx, dword ptr [r11 + rax*4]
+;;   4a:	 4901cb               	add	r11, rcx
+;;   4d:	 41ffe3               	jmp	r11
 ;;   50:	 a0800100aa800100a0   	
 ;; 				movabs	al, byte ptr [0xa0000180aa000180]
 ;;   59:	 800100               	add	byte ptr [rcx], 0
@@ -31520,9 +31517,21 @@
 ;; 180c9:	 800100               	add	byte ptr [rcx], 0
 ;; 180cc:	 aa                   	stosb	byte ptr [rdi], al
 ;; 180cd:	 800100               	add	byte ptr [rcx], 0
-;; 180d0:	 b800000000           	mov	eax, 0
-;; 180d5:	 e905000000           	jmp	0x180df
-;; 180da:	 b801000000           	mov	eax, 1
-;; 180df:	 4883c410             	add	rsp, 0x10
-;; 180e3:	 5d                   	pop	rbp
-;; 180e4:	 c3                   	ret	
+;; 180d0:	 a0800100aa800100a0   	
+;; 				movabs	al, byte ptr [0xa0000180aa000180]
+;; 180d9:	 800100               	add	byte ptr [rcx], 0
+;; 180dc:	 aa                   	stosb	byte ptr [rdi], al
+;; 180dd:	 800100               	add	byte ptr [rcx], 0
+;; 180e0:	 a0800100aa800100a0   	
+;; 				movabs	al, byte ptr [0xa0000180aa000180]
+;; 180e9:	 800100               	add	byte ptr [rcx], 0
+;; 180ec:	 aa                   	stosb	byte ptr [rdi], al
+;; 180ed:	 800100               	add	byte ptr [rcx], 0
+;; 180f0:	 b800000000           	mov	eax, 0
+;; 180f5:	 e905000000           	jmp	0x180ff
+;; 180fa:	 b801000000           	mov	eax, 1
+;; 180ff:	 4883c410             	add	rsp, 0x10
+;; 18103:	 5d                   	pop	rbp
+;; 18104:	 c3                   	ret	
+;; 18105:	 0f0b                 	ud2	
+;; 18107:	 0f0b                 	ud2	
@@ -19,15 +19,22 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   15:	 b902000000           	mov	ecx, 2
-;;   1a:	 39c1                 	cmp	ecx, eax
-;;   1c:	 0f42c1               	cmovb	eax, ecx
-;;   1f:	 4c8d1d0a000000       	lea	r11, [rip + 0xa]
-;;   26:	 49630c83             	movsxd	rcx, dword ptr [r11 + rax*4]
-;;   2a:	 4901cb               	add	r11, rcx
-;;   2d:	 41ffe3               	jmp	r11
-;;   30:	 e1ff                 	loope	0x31
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8285000000         	jb	0xa0
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f827e000000         	jb	0xa2
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 b902000000           	mov	ecx, 2
+;;   3a:	 39c1                 	cmp	ecx, eax
+;;   3c:	 0f42c1               	cmovb	eax, ecx
+;;   3f:	 4c8d1d0a000000       	lea	r11, [rip + 0xa]
+;;   46:	 49630c83             	movsxd	rcx, dword ptr [r11 + rax*4]
+;;   4a:	 4901cb               	add	r11, rcx
+;;   4d:	 41ffe3               	jmp	r11
+;;   50:	 e1ff                 	loope	0x51
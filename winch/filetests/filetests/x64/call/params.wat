@@ -37,87 +37,102 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   18:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   1c:	 31d2                 	xor	edx, edx
-;;   1e:	 f7f1                 	div	ecx
-;;   20:	 50                   	push	rax
-;;   21:	 4883ec28             	sub	rsp, 0x28
-;;   25:	 8b7c2428             	mov	edi, dword ptr [rsp + 0x28]
-;;   29:	 be01000000           	mov	esi, 1
-;;   2e:	 ba02000000           	mov	edx, 2
-;;   33:	 b903000000           	mov	ecx, 3
-;;   38:	 41b804000000         	mov	r8d, 4
-;;   3e:	 41b905000000         	mov	r9d, 5
-;;   44:	 41bb06000000         	mov	r11d, 6
-;;   4a:	 44891c24             	mov	dword ptr [rsp], r11d
-;;   4e:	 41bb07000000         	mov	r11d, 7
-;;   54:	 44895c2408           	mov	dword ptr [rsp + 8], r11d
-;;   59:	 41bb08000000         	mov	r11d, 8
-;;   5f:	 44895c2410           	mov	dword ptr [rsp + 0x10], r11d
-;;   64:	 e800000000           	call	0x69
-;;   69:	 4883c430             	add	rsp, 0x30
-;;   6d:	 50                   	push	rax
-;;   6e:	 448b5c2410           	mov	r11d, dword ptr [rsp + 0x10]
-;;   73:	 4153                 	push	r11
-;;   75:	 448b5c241c           	mov	r11d, dword ptr [rsp + 0x1c]
-;;   7a:	 4153                 	push	r11
-;;   7c:	 59                   	pop	rcx
-;;   7d:	 58                   	pop	rax
-;;   7e:	 31d2                 	xor	edx, edx
-;;   80:	 f7f1                 	div	ecx
-;;   82:	 50                   	push	rax
-;;   83:	 4883ec20             	sub	rsp, 0x20
-;;   87:	 8b7c2428             	mov	edi, dword ptr [rsp + 0x28]
-;;   8b:	 8b742420             	mov	esi, dword ptr [rsp + 0x20]
-;;   8f:	 ba02000000           	mov	edx, 2
-;;   94:	 b903000000           	mov	ecx, 3
-;;   99:	 41b804000000         	mov	r8d, 4
-;;   9f:	 41b905000000         	mov	r9d, 5
-;;   a5:	 41bb06000000         	mov	r11d, 6
-;;   ab:	 44891c24             	mov	dword ptr [rsp], r11d
-;;   af:	 41bb07000000         	mov	r11d, 7
-;;   b5:	 44895c2408           	mov	dword ptr [rsp + 8], r11d
-;;   ba:	 41bb08000000         	mov	r11d, 8
-;;   c0:	 44895c2410           	mov	dword ptr [rsp + 0x10], r11d
-;;   c5:	 e800000000           	call	0xca
-;;   ca:	 4883c430             	add	rsp, 0x30
-;;   ce:	 4883c410             	add	rsp, 0x10
-;;   d2:	 5d                   	pop	rbp
-;;   d3:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c360000000       	add	r11, 0x60
+;;   15:	 0f8205010000         	jb	0x120
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f82fe000000         	jb	0x122
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   38:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   3c:	 31d2                 	xor	edx, edx
+;;   3e:	 f7f1                 	div	ecx
+;;   40:	 4156                 	push	r14
+;;   42:	 4156                 	push	r14
+;;   44:	 50                   	push	rax
+;;   45:	 4883ec38             	sub	rsp, 0x38
+;;   49:	 488b7c2448           	mov	rdi, qword ptr [rsp + 0x48]
+;;   4e:	 488b742440           	mov	rsi, qword ptr [rsp + 0x40]
+;;   53:	 8b542438             	mov	edx, dword ptr [rsp + 0x38]
+;;   57:	 b901000000           	mov	ecx, 1
+;;   5c:	 41b802000000         	mov	r8d, 2
+;;   62:	 41b903000000         	mov	r9d, 3
+;;   68:	 41bb04000000         	mov	r11d, 4
+;;   6e:	 44891c24             	mov	dword ptr [rsp], r11d
+;;   72:	 41bb05000000         	mov	r11d, 5
+;;   78:	 44895c2408           	mov	dword ptr [rsp + 8], r11d
+;;   7d:	 41bb06000000         	mov	r11d, 6
+;;   83:	 44895c2410           	mov	dword ptr [rsp + 0x10], r11d
+;;   88:	 41bb07000000         	mov	r11d, 7
+;;   8e:	 44895c2418           	mov	dword ptr [rsp + 0x18], r11d
+;;   93:	 41bb08000000         	mov	r11d, 8
+;;   99:	 44895c2420           	mov	dword ptr [rsp + 0x20], r11d
+;;   9e:	 e800000000           	call	0xa3
+;;   a3:	 4883c450             	add	rsp, 0x50
+;;   a7:	 50                   	push	rax
+;;   a8:	 8b4c2414             	mov	ecx, dword ptr [rsp + 0x14]
+;;   ac:	 8b442410             	mov	eax, dword ptr [rsp + 0x10]
+;;   b0:	 31d2                 	xor	edx, edx
+;;   b2:	 f7f1                 	div	ecx
+;;   b4:	 4156                 	push	r14
+;;   b6:	 4156                 	push	r14
+;;   b8:	 50                   	push	rax
+;;   b9:	 4883ec30             	sub	rsp, 0x30
+;;   bd:	 488b7c2440           	mov	rdi, qword ptr [rsp + 0x40]
+;;   c2:	 488b742438           	mov	rsi, qword ptr [rsp + 0x38]
+;;   c7:	 8b542448             	mov	edx, dword ptr [rsp + 0x48]
+;;   cb:	 8b4c2430             	mov	ecx, dword ptr [rsp + 0x30]
+;;   cf:	 41b802000000         	mov	r8d, 2
+;;   d5:	 41b903000000         	mov	r9d, 3
+;;   db:	 41bb04000000         	mov	r11d, 4
+;;   e1:	 44891c24             	mov	dword ptr [rsp], r11d
+;;   e5:	 41bb05000000         	mov	r11d, 5
+;;   eb:	 44895c2408           	mov	dword ptr [rsp + 8], r11d
+;;   f0:	 41bb06000000         	mov	r11d, 6
+;;   f6:	 44895c2410           	mov	dword ptr [rsp + 0x10], r11d
+;;   fb:	 41bb07000000         	mov	r11d, 7
+;;  101:	 44895c2418           	mov	dword ptr [rsp + 0x18], r11d
+;;  106:	 41bb08000000         	mov	r11d, 8
+;;  10c:	 44895c2420           	mov	dword ptr [rsp + 0x20], r11d
+;;  111:	 e800000000           	call	0x116
+;;  116:	 4883c450             	add	rsp, 0x50
+;;  11a:	 4883c410             	add	rsp, 0x10
+;;  11e:	 5d                   	pop	rbp
+;;  11f:	 c3                   	ret	
+;;  120:	 0f0b                 	ud2	
+;;  122:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec20             	sub	rsp, 0x20
-;;    8:	 897c241c             	mov	dword ptr [rsp + 0x1c], edi
-;;    c:	 89742418             	mov	dword ptr [rsp + 0x18], esi
-;;   10:	 89542414             	mov	dword ptr [rsp + 0x14], edx
-;;   14:	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
-;;   18:	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
-;;   1d:	 44894c2408           	mov	dword ptr [rsp + 8], r9d
-;;   22:	 4c893424             	mov	qword ptr [rsp], r14
-;;   26:	 8b442418             	mov	eax, dword ptr [rsp + 0x18]
-;;   2a:	 8b4c241c             	mov	ecx, dword ptr [rsp + 0x1c]
-;;   2e:	 01c1                 	add	ecx, eax
-;;   30:	 8b442414             	mov	eax, dword ptr [rsp + 0x14]
-;;   34:	 01c1                 	add	ecx, eax
-;;   36:	 8b442410             	mov	eax, dword ptr [rsp + 0x10]
-;;   3a:	 01c1                 	add	ecx, eax
-;;   3c:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   40:	 01c1                 	add	ecx, eax
-;;   42:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   46:	 01c1                 	add	ecx, eax
-;;   48:	 8b4510               	mov	eax, dword ptr [rbp + 0x10]
-;;   4b:	 01c1                 	add	ecx, eax
-;;   4d:	 8b4518               	mov	eax, dword ptr [rbp + 0x18]
-;;   50:	 01c1                 	add	ecx, eax
-;;   52:	 8b4520               	mov	eax, dword ptr [rbp + 0x20]
-;;   55:	 01c1                 	add	ecx, eax
-;;   57:	 89c8                 	mov	eax, ecx
-;;   59:	 4883c420             	add	rsp, 0x20
-;;   5d:	 5d                   	pop	rbp
-;;   5e:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8248000000         	jb	0x63
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8241000000         	jb	0x65
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;   2c:	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
+;;   30:	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
+;;   35:	 44894c2408           	mov	dword ptr [rsp + 8], r9d
+;;   3a:	 4c893424             	mov	qword ptr [rsp], r14
+;;   3e:	 8b442414             	mov	eax, dword ptr [rsp + 0x14]
+;;   42:	 03442410             	add	eax, dword ptr [rsp + 0x10]
+;;   46:	 0344240c             	add	eax, dword ptr [rsp + 0xc]
+;;   4a:	 03442408             	add	eax, dword ptr [rsp + 8]
+;;   4e:	 034510               	add	eax, dword ptr [rbp + 0x10]
+;;   51:	 034518               	add	eax, dword ptr [rbp + 0x18]
+;;   54:	 034520               	add	eax, dword ptr [rbp + 0x20]
+;;   57:	 034528               	add	eax, dword ptr [rbp + 0x28]
+;;   5a:	 034530               	add	eax, dword ptr [rbp + 0x30]
+;;   5d:	 4883c418             	add	rsp, 0x18
+;;   61:	 5d                   	pop	rbp
+;;   62:	 c3                   	ret	
+;;   63:	 0f0b                 	ud2	
+;;   65:	 0f0b                 	ud2	
@@ -24,34 +24,51 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   15:	 83f801               	cmp	eax, 1
-;;   18:	 b800000000           	mov	eax, 0
-;;   1d:	 400f9ec0             	setle	al
-;;   21:	 85c0                 	test	eax, eax
-;;   23:	 0f8409000000         	je	0x32
-;;   29:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   2d:	 e933000000           	jmp	0x65
-;;   32:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   36:	 83e801               	sub	eax, 1
-;;   39:	 50                   	push	rax
-;;   3a:	 4883ec08             	sub	rsp, 8
-;;   3e:	 8b7c2408             	mov	edi, dword ptr [rsp + 8]
-;;   42:	 e800000000           	call	0x47
-;;   47:	 4883c410             	add	rsp, 0x10
-;;   4b:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   4f:	 83e902               	sub	ecx, 2
-;;   52:	 50                   	push	rax
-;;   53:	 51                   	push	rcx
-;;   54:	 8b3c24               	mov	edi, dword ptr [rsp]
-;;   57:	 e800000000           	call	0x5c
-;;   5c:	 4883c408             	add	rsp, 8
-;;   60:	 59                   	pop	rcx
-;;   61:	 01c1                 	add	ecx, eax
-;;   63:	 89c8                 	mov	eax, ecx
-;;   65:	 4883c410             	add	rsp, 0x10
-;;   69:	 5d                   	pop	rbp
-;;   6a:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c330000000       	add	r11, 0x30
+;;   15:	 0f828c000000         	jb	0xa7
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8285000000         	jb	0xa9
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 83f801               	cmp	eax, 1
+;;   38:	 b800000000           	mov	eax, 0
+;;   3d:	 400f9ec0             	setle	al
+;;   41:	 85c0                 	test	eax, eax
+;;   43:	 0f8409000000         	je	0x52
+;;   49:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   4d:	 e94f000000           	jmp	0xa1
+;;   52:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   56:	 83e801               	sub	eax, 1
+;;   59:	 4156                 	push	r14
+;;   5b:	 4156                 	push	r14
+;;   5d:	 50                   	push	rax
+;;   5e:	 4883ec08             	sub	rsp, 8
+;;   62:	 488b7c2418           	mov	rdi, qword ptr [rsp + 0x18]
+;;   67:	 488b742410           	mov	rsi, qword ptr [rsp + 0x10]
+;;   6c:	 8b542408             	mov	edx, dword ptr [rsp + 8]
+;;   70:	 e800000000           	call	0x75
+;;   75:	 4883c420             	add	rsp, 0x20
+;;   79:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   7d:	 83e902               	sub	ecx, 2
+;;   80:	 50                   	push	rax
+;;   81:	 4156                 	push	r14
+;;   83:	 4156                 	push	r14
+;;   85:	 51                   	push	rcx
+;;   86:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   8b:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   90:	 8b1424               	mov	edx, dword ptr [rsp]
+;;   93:	 e800000000           	call	0x98
+;;   98:	 4883c418             	add	rsp, 0x18
+;;   9c:	 59                   	pop	rcx
+;;   9d:	 01c1                 	add	ecx, eax
+;;   9f:	 89c8                 	mov	eax, ecx
+;;   a1:	 4883c410             	add	rsp, 0x10
+;;   a5:	 5d                   	pop	rbp
+;;   a6:	 c3                   	ret	
+;;   a7:	 0f0b                 	ud2	
+;;   a9:	 0f0b                 	ud2	
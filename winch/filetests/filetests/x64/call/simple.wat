@@ -15,36 +15,57 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f8254000000         	jb	0x6f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f824d000000         	jb	0x71
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   16:	 bf14000000           	mov	edi, 0x14
-;;   1b:	 be50000000           	mov	esi, 0x50
-;;   20:	 e800000000           	call	0x25
-;;   25:	 b902000000           	mov	ecx, 2
-;;   2a:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
-;;   2e:	 50                   	push	rax
-;;   2f:	 448b5c2414           	mov	r11d, dword ptr [rsp + 0x14]
-;;   34:	 4153                 	push	r11
-;;   36:	 59                   	pop	rcx
-;;   37:	 58                   	pop	rax
-;;   38:	 31d2                 	xor	edx, edx
-;;   3a:	 f7f1                 	div	ecx
-;;   3c:	 4883c410             	add	rsp, 0x10
-;;   40:	 5d                   	pop	rbp
-;;   41:	 c3                   	ret	
+;;   31:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   36:	 4156                 	push	r14
+;;   38:	 4156                 	push	r14
+;;   3a:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   3f:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   43:	 ba14000000           	mov	edx, 0x14
+;;   48:	 b950000000           	mov	ecx, 0x50
+;;   4d:	 e800000000           	call	0x52
+;;   52:	 4883c410             	add	rsp, 0x10
+;;   56:	 b902000000           	mov	ecx, 2
+;;   5b:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
+;;   5f:	 50                   	push	rax
+;;   60:	 8b4c2414             	mov	ecx, dword ptr [rsp + 0x14]
+;;   64:	 58                   	pop	rax
+;;   65:	 31d2                 	xor	edx, edx
+;;   67:	 f7f1                 	div	ecx
+;;   69:	 4883c410             	add	rsp, 0x10
+;;   6d:	 5d                   	pop	rbp
+;;   6e:	 c3                   	ret	
+;;   6f:	 0f0b                 	ud2	
+;;   71:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   18:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   1c:	 0fafc8               	imul	ecx, eax
-;;   1f:	 89c8                 	mov	eax, ecx
-;;   21:	 4883c410             	add	rsp, 0x10
-;;   25:	 5d                   	pop	rbp
-;;   26:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f822c000000         	jb	0x47
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8225000000         	jb	0x49
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   38:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   3c:	 0fafc8               	imul	ecx, eax
+;;   3f:	 89c8                 	mov	eax, ecx
+;;   41:	 4883c410             	add	rsp, 0x10
+;;   45:	 5d                   	pop	rbp
+;;   46:	 c3                   	ret	
+;;   47:	 0f0b                 	ud2	
+;;   49:	 0f0b                 	ud2	
@@ -31,113 +31,131 @@
 
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   15:	 83f801               	cmp	eax, 1
-;;   18:	 b800000000           	mov	eax, 0
-;;   1d:	 400f96c0             	setbe	al
-;;   21:	 85c0                 	test	eax, eax
-;;   23:	 0f840a000000         	je	0x33
-;;   29:	 b801000000           	mov	eax, 1
-;;   2e:	 e92e010000           	jmp	0x161
-;;   33:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   37:	 83e802               	sub	eax, 2
-;;   3a:	 50                   	push	rax
-;;   3b:	 b900000000           	mov	ecx, 0
-;;   40:	 4c89f2               	mov	rdx, r14
-;;   43:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
-;;   46:	 39d9                 	cmp	ecx, ebx
-;;   48:	 0f8319010000         	jae	0x167
-;;   4e:	 4189cb               	mov	r11d, ecx
-;;   51:	 4d6bdb08             	imul	r11, r11, 8
-;;   55:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
-;;   59:	 4889d6               	mov	rsi, rdx
-;;   5c:	 4c01da               	add	rdx, r11
-;;   5f:	 39d9                 	cmp	ecx, ebx
-;;   61:	 480f43d6             	cmovae	rdx, rsi
-;;   65:	 488b02               	mov	rax, qword ptr [rdx]
-;;   68:	 4885c0               	test	rax, rax
-;;   6b:	 0f8528000000         	jne	0x99
-;;   71:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   75:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
-;;   79:	 4156                 	push	r14
-;;   7b:	 51                   	push	rcx
-;;   7c:	 4883ec08             	sub	rsp, 8
-;;   80:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
-;;   85:	 be00000000           	mov	esi, 0
-;;   8a:	 8b542408             	mov	edx, dword ptr [rsp + 8]
-;;   8e:	 ffd3                 	call	rbx
-;;   90:	 4883c418             	add	rsp, 0x18
-;;   94:	 e904000000           	jmp	0x9d
-;;   99:	 4883e0fe             	and	rax, 0xfffffffffffffffe
-;;   9d:	 4885c0               	test	rax, rax
-;;   a0:	 0f84c3000000         	je	0x169
-;;   a6:	 4d8b5e40             	mov	r11, qword ptr [r14 + 0x40]
-;;   aa:	 418b0b               	mov	ecx, dword ptr [r11]
-;;   ad:	 8b5018               	mov	edx, dword ptr [rax + 0x18]
-;;   b0:	 39d1                 	cmp	ecx, edx
-;;   b2:	 0f85b3000000         	jne	0x16b
-;;   b8:	 50                   	push	rax
-;;   b9:	 59                   	pop	rcx
-;;   ba:	 488b5110             	mov	rdx, qword ptr [rcx + 0x10]
-;;   be:	 4883ec08             	sub	rsp, 8
-;;   c2:	 8b7c2408             	mov	edi, dword ptr [rsp + 8]
-;;   c6:	 ffd2                 	call	rdx
-;;   c8:	 4883c410             	add	rsp, 0x10
-;;   cc:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   d0:	 83e901               	sub	ecx, 1
-;;   d3:	 50                   	push	rax
-;;   d4:	 51                   	push	rcx
-;;   d5:	 b900000000           	mov	ecx, 0
-;;   da:	 4c89f2               	mov	rdx, r14
-;;   dd:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
-;;   e0:	 39d9                 	cmp	ecx, ebx
-;;   e2:	 0f8385000000         	jae	0x16d
-;;   e8:	 4189cb               	mov	r11d, ecx
-;;   eb:	 4d6bdb08             	imul	r11, r11, 8
-;;   ef:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
-;;   f3:	 4889d6               	mov	rsi, rdx
-;;   f6:	 4c01da               	add	rdx, r11
-;;   f9:	 39d9                 	cmp	ecx, ebx
-;;   fb:	 480f43d6             	cmovae	rdx, rsi
-;;   ff:	 488b02               	mov	rax, qword ptr [rdx]
-;;  102:	 4885c0               	test	rax, rax
-;;  105:	 0f8523000000         	jne	0x12e
-;;  10b:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;  10f:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
-;;  113:	 4156                 	push	r14
-;;  115:	 51                   	push	rcx
-;;  116:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
-;;  11b:	 be00000000           	mov	esi, 0
-;;  120:	 8b1424               	mov	edx, dword ptr [rsp]
-;;  123:	 ffd3                 	call	rbx
-;;  125:	 4883c410             	add	rsp, 0x10
-;;  129:	 e904000000           	jmp	0x132
-;;  12e:	 4883e0fe             	and	rax, 0xfffffffffffffffe
-;;  132:	 4885c0               	test	rax, rax
-;;  135:	 0f8434000000         	je	0x16f
-;;  13b:	 4d8b5e40             	mov	r11, qword ptr [r14 + 0x40]
-;;  13f:	 418b0b               	mov	ecx, dword ptr [r11]
-;;  142:	 8b5018               	mov	edx, dword ptr [rax + 0x18]
-;;  145:	 39d1                 	cmp	ecx, edx
-;;  147:	 0f8524000000         	jne	0x171
-;;  14d:	 50                   	push	rax
-;;  14e:	 59                   	pop	rcx
-;;  14f:	 488b5110             	mov	rdx, qword ptr [rcx + 0x10]
-;;  153:	 8b3c24               	mov	edi, dword ptr [rsp]
-;;  156:	 ffd2                 	call	rdx
-;;  158:	 4883c408             	add	rsp, 8
-;;  15c:	 59                   	pop	rcx
-;;  15d:	 01c1                 	add	ecx, eax
-;;  15f:	 89c8                 	mov	eax, ecx
-;;  161:	 4883c410             	add	rsp, 0x10
-;;  165:	 5d                   	pop	rbp
-;;  166:	 c3                   	ret	
-;;  167:	 0f0b                 	ud2	
-;;  169:	 0f0b                 	ud2	
-;;  16b:	 0f0b                 	ud2	
-;;  16d:	 0f0b                 	ud2	
-;;  16f:	 0f0b                 	ud2	
-;;  171:	 0f0b                 	ud2	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c330000000       	add	r11, 0x30
+;;   15:	 0f828e010000         	jb	0x1a9
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8287010000         	jb	0x1ab
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c893424             	mov	qword ptr [rsp], r14
+;;   30:	 4d8b5e40             	mov	r11, qword ptr [r14 + 0x40]
+;;   34:	 418b03               	mov	eax, dword ptr [r11]
+;;   37:	 89442408             	mov	dword ptr [rsp + 8], eax
+;;   3b:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   3f:	 83f801               	cmp	eax, 1
+;;   42:	 b800000000           	mov	eax, 0
+;;   47:	 400f96c0             	setbe	al
+;;   4b:	 85c0                 	test	eax, eax
+;;   4d:	 0f840a000000         	je	0x5d
+;;   53:	 b801000000           	mov	eax, 1
+;;   58:	 e946010000           	jmp	0x1a3
+;;   5d:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   61:	 83e802               	sub	eax, 2
+;;   64:	 50                   	push	rax
+;;   65:	 b900000000           	mov	ecx, 0
+;;   6a:	 4c89f2               	mov	rdx, r14
+;;   6d:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
+;;   70:	 39d9                 	cmp	ecx, ebx
+;;   72:	 0f8335010000         	jae	0x1ad
+;;   78:	 31f6                 	xor	esi, esi
+;;   7a:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
+;;   7e:	 4189cb               	mov	r11d, ecx
+;;   81:	 39d9                 	cmp	ecx, ebx
+;;   83:	 4c0f43de             	cmovae	r11, rsi
+;;   87:	 4a8b04da             	mov	rax, qword ptr [rdx + r11*8]
+;;   8b:	 4885c0               	test	rax, rax
+;;   8e:	 0f8528000000         	jne	0xbc
+;;   94:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   98:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
+;;   9c:	 4156                 	push	r14
+;;   9e:	 51                   	push	rcx
+;;   9f:	 4883ec08             	sub	rsp, 8
+;;   a3:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   a8:	 be00000000           	mov	esi, 0
+;;   ad:	 8b542408             	mov	edx, dword ptr [rsp + 8]
+;;   b1:	 ffd3                 	call	rbx
+;;   b3:	 4883c418             	add	rsp, 0x18
+;;   b7:	 e904000000           	jmp	0xc0
+;;   bc:	 4883e0fe             	and	rax, 0xfffffffffffffffe
+;;   c0:	 4885c0               	test	rax, rax
+;;   c3:	 0f84e6000000         	je	0x1af
+;;   c9:	 8b4c2410             	mov	ecx, dword ptr [rsp + 0x10]
+;;   cd:	 8b5018               	mov	edx, dword ptr [rax + 0x18]
+;;   d0:	 39d1                 	cmp	ecx, edx
+;;   d2:	 0f85d9000000         	jne	0x1b1
+;;   d8:	 50                   	push	rax
+;;   d9:	 59                   	pop	rcx
+;;   da:	 488b5910             	mov	rbx, qword ptr [rcx + 0x10]
+;;   de:	 488b4920             	mov	rcx, qword ptr [rcx + 0x20]
+;;   e2:	 51                   	push	rcx
+;;   e3:	 4156                 	push	r14
+;;   e5:	 4883ec08             	sub	rsp, 8
+;;   e9:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   ee:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   f3:	 8b542418             	mov	edx, dword ptr [rsp + 0x18]
+;;   f7:	 ffd3                 	call	rbx
+;;   f9:	 4c8b742420           	mov	r14, qword ptr [rsp + 0x20]
+;;   fe:	 4883c420             	add	rsp, 0x20
+;;  102:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;  106:	 83e901               	sub	ecx, 1
+;;  109:	 50                   	push	rax
+;;  10a:	 ba00000000           	mov	edx, 0
+;;  10f:	 51                   	push	rcx
+;;  110:	 4c89f1               	mov	rcx, r14
+;;  113:	 8b5950               	mov	ebx, dword ptr [rcx + 0x50]
+;;  116:	 39da                 	cmp	edx, ebx
+;;  118:	 0f8395000000         	jae	0x1b3
+;;  11e:	 31f6                 	xor	esi, esi
+;;  120:	 488b4948             	mov	rcx, qword ptr [rcx + 0x48]
+;;  124:	 4189d3               	mov	r11d, edx
+;;  127:	 39da                 	cmp	edx, ebx
+;;  129:	 4c0f43de             	cmovae	r11, rsi
+;;  12d:	 4a8b04d9             	mov	rax, qword ptr [rcx + r11*8]
+;;  131:	 4885c0               	test	rax, rax
+;;  134:	 0f8523000000         	jne	0x15d
+;;  13a:	 4156                 	push	r14
+;;  13c:	 52                   	push	rdx
+;;  13d:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;  141:	 498b4b48             	mov	rcx, qword ptr [r11 + 0x48]
+;;  145:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;  14a:	 be00000000           	mov	esi, 0
+;;  14f:	 8b1424               	mov	edx, dword ptr [rsp]
+;;  152:	 ffd1                 	call	rcx
+;;  154:	 4883c410             	add	rsp, 0x10
+;;  158:	 e904000000           	jmp	0x161
+;;  15d:	 4883e0fe             	and	rax, 0xfffffffffffffffe
+;;  161:	 4885c0               	test	rax, rax
+;;  164:	 0f844b000000         	je	0x1b5
+;;  16a:	 8b4c2418             	mov	ecx, dword ptr [rsp + 0x18]
+;;  16e:	 8b5018               	mov	edx, dword ptr [rax + 0x18]
+;;  171:	 39d1                 	cmp	ecx, edx
+;;  173:	 0f853e000000         	jne	0x1b7
+;;  179:	 50                   	push	rax
+;;  17a:	 59                   	pop	rcx
+;;  17b:	 488b5910             	mov	rbx, qword ptr [rcx + 0x10]
+;;  17f:	 488b4920             	mov	rcx, qword ptr [rcx + 0x20]
+;;  183:	 51                   	push	rcx
+;;  184:	 4156                 	push	r14
+;;  186:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;  18b:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;  18f:	 8b542410             	mov	edx, dword ptr [rsp + 0x10]
+;;  193:	 ffd3                 	call	rbx
+;;  195:	 4c8b742420           	mov	r14, qword ptr [rsp + 0x20]
+;;  19a:	 4883c418             	add	rsp, 0x18
+;;  19e:	 59                   	pop	rcx
+;;  19f:	 01c1                 	add	ecx, eax
+;;  1a1:	 89c8                 	mov	eax, ecx
+;;  1a3:	 4883c410             	add	rsp, 0x10
+;;  1a7:	 5d                   	pop	rbp
+;;  1a8:	 c3                   	ret	
+;;  1a9:	 0f0b                 	ud2	
+;;  1ab:	 0f0b                 	ud2	
+;;  1ad:	 0f0b                 	ud2	
+;;  1af:	 0f0b                 	ud2	
+;;  1b1:	 0f0b                 	ud2	
+;;  1b3:	 0f0b                 	ud2	
+;;  1b5:	 0f0b                 	ud2	
+;;  1b7:	 0f0b                 	ud2	
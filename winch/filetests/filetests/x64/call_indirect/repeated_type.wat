@@ -0,0 +1,181 @@
+;;! target="x86_64"
+
+(module
+  (type $i32-i32 (func (param i32) (result i32)))
+  (type $i64-i64 (func (param i64) (result i64)))
+
+  (table 2 funcref)
+
+  (func (export "main") (param i32) (result i32)
+    (call_indirect (type $i32-i32)
+      (call_indirect (type $i32-i32) (local.get 0) (i32.const 0))
+      (i32.const 0))
+    (drop (call_indirect (type $i64-i64) (i64.const 1) (i32.const 1)))
+  )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c330000000       	add	r11, 0x30
+;;   15:	 0f8209020000         	jb	0x224
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8202020000         	jb	0x226
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c893424             	mov	qword ptr [rsp], r14
+;;   30:	 4d8b5e40             	mov	r11, qword ptr [r14 + 0x40]
+;;   34:	 418b03               	mov	eax, dword ptr [r11]
+;;   37:	 89442408             	mov	dword ptr [rsp + 8], eax
+;;   3b:	 b900000000           	mov	ecx, 0
+;;   40:	 4c89f2               	mov	rdx, r14
+;;   43:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
+;;   46:	 39d9                 	cmp	ecx, ebx
+;;   48:	 0f83da010000         	jae	0x228
+;;   4e:	 31f6                 	xor	esi, esi
+;;   50:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
+;;   54:	 4189cb               	mov	r11d, ecx
+;;   57:	 39d9                 	cmp	ecx, ebx
+;;   59:	 4c0f43de             	cmovae	r11, rsi
+;;   5d:	 4a8b04da             	mov	rax, qword ptr [rdx + r11*8]
+;;   61:	 4885c0               	test	rax, rax
+;;   64:	 0f8523000000         	jne	0x8d
+;;   6a:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   6e:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
+;;   72:	 4156                 	push	r14
+;;   74:	 51                   	push	rcx
+;;   75:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   7a:	 be00000000           	mov	esi, 0
+;;   7f:	 8b1424               	mov	edx, dword ptr [rsp]
+;;   82:	 ffd3                 	call	rbx
+;;   84:	 4883c410             	add	rsp, 0x10
+;;   88:	 e904000000           	jmp	0x91
+;;   8d:	 4883e0fe             	and	rax, 0xfffffffffffffffe
+;;   91:	 4885c0               	test	rax, rax
+;;   94:	 0f8490010000         	je	0x22a
+;;   9a:	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
+;;   9e:	 8b5018               	mov	edx, dword ptr [rax + 0x18]
+;;   a1:	 39d1                 	cmp	ecx, edx
+;;   a3:	 0f8583010000         	jne	0x22c
+;;   a9:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
+;;   ae:	 4153                 	push	r11
+;;   b0:	 50                   	push	rax
+;;   b1:	 59                   	pop	rcx
+;;   b2:	 488b5910             	mov	rbx, qword ptr [rcx + 0x10]
+;;   b6:	 488b4920             	mov	rcx, qword ptr [rcx + 0x20]
+;;   ba:	 51                   	push	rcx
+;;   bb:	 4156                 	push	r14
+;;   bd:	 4883ec08             	sub	rsp, 8
+;;   c1:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   c6:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   cb:	 8b542418             	mov	edx, dword ptr [rsp + 0x18]
+;;   cf:	 ffd3                 	call	rbx
+;;   d1:	 4c8b742420           	mov	r14, qword ptr [rsp + 0x20]
+;;   d6:	 4883c420             	add	rsp, 0x20
+;;   da:	 50                   	push	rax
+;;   db:	 b900000000           	mov	ecx, 0
+;;   e0:	 4c89f2               	mov	rdx, r14
+;;   e3:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
+;;   e6:	 39d9                 	cmp	ecx, ebx
+;;   e8:	 0f8340010000         	jae	0x22e
+;;   ee:	 31f6                 	xor	esi, esi
+;;   f0:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
+;;   f4:	 4189cb               	mov	r11d, ecx
+;;   f7:	 39d9                 	cmp	ecx, ebx
+;;   f9:	 4c0f43de             	cmovae	r11, rsi
+;;   fd:	 4a8b04da             	mov	rax, qword ptr [rdx + r11*8]
+;;  101:	 4885c0               	test	rax, rax
+;;  104:	 0f8528000000         	jne	0x132
+;;  10a:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;  10e:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
+;;  112:	 4156                 	push	r14
+;;  114:	 51                   	push	rcx
+;;  115:	 4883ec08             	sub	rsp, 8
+;;  119:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;  11e:	 be00000000           	mov	esi, 0
+;;  123:	 8b542408             	mov	edx, dword ptr [rsp + 8]
+;;  127:	 ffd3                 	call	rbx
+;;  129:	 4883c418             	add	rsp, 0x18
+;;  12d:	 e904000000           	jmp	0x136
+;;  132:	 4883e0fe             	and	rax, 0xfffffffffffffffe
+;;  136:	 4885c0               	test	rax, rax
+;;  139:	 0f84f1000000         	je	0x230
+;;  13f:	 8b4c2410             	mov	ecx, dword ptr [rsp + 0x10]
+;;  143:	 8b5018               	mov	edx, dword ptr [rax + 0x18]
+;;  146:	 39d1                 	cmp	ecx, edx
+;;  148:	 0f85e4000000         	jne	0x232
+;;  14e:	 50                   	push	rax
+;;  14f:	 59                   	pop	rcx
+;;  150:	 488b5910             	mov	rbx, qword ptr [rcx + 0x10]
+;;  154:	 488b4920             	mov	rcx, qword ptr [rcx + 0x20]
+;;  158:	 51                   	push	rcx
+;;  159:	 4156                 	push	r14
+;;  15b:	 4883ec08             	sub	rsp, 8
+;;  15f:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;  164:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;  169:	 8b542418             	mov	edx, dword ptr [rsp + 0x18]
+;;  16d:	 ffd3                 	call	rbx
+;;  16f:	 4c8b742420           	mov	r14, qword ptr [rsp + 0x20]
+;;  174:	 4883c420             	add	rsp, 0x20
+;;  178:	 50                   	push	rax
+;;  179:	 b901000000           	mov	ecx, 1
+;;  17e:	 4c89f2               	mov	rdx, r14
+;;  181:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
+;;  184:	 39d9                 	cmp	ecx, ebx
+;;  186:	 0f83a8000000         	jae	0x234
+;;  18c:	 31f6                 	xor	esi, esi
+;;  18e:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
+;;  192:	 4189cb               	mov	r11d, ecx
+;;  195:	 39d9                 	cmp	ecx, ebx
+;;  197:	 4c0f43de             	cmovae	r11, rsi
+;;  19b:	 4a8b04da             	mov	rax, qword ptr [rdx + r11*8]
+;;  19f:	 4885c0               	test	rax, rax
+;;  1a2:	 0f8528000000         	jne	0x1d0
+;;  1a8:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;  1ac:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
+;;  1b0:	 4156                 	push	r14
+;;  1b2:	 51                   	push	rcx
+;;  1b3:	 4883ec08             	sub	rsp, 8
+;;  1b7:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;  1bc:	 be00000000           	mov	esi, 0
+;;  1c1:	 8b542408             	mov	edx, dword ptr [rsp + 8]
+;;  1c5:	 ffd3                 	call	rbx
+;;  1c7:	 4883c418             	add	rsp, 0x18
+;;  1cb:	 e904000000           	jmp	0x1d4
+;;  1d0:	 4883e0fe             	and	rax, 0xfffffffffffffffe
+;;  1d4:	 4885c0               	test	rax, rax
+;;  1d7:	 0f8459000000         	je	0x236
+;;  1dd:	 4d8b5e40             	mov	r11, qword ptr [r14 + 0x40]
+;;  1e1:	 418b4b04             	mov	ecx, dword ptr [r11 + 4]
+;;  1e5:	 8b5018               	mov	edx, dword ptr [rax + 0x18]
+;;  1e8:	 39d1                 	cmp	ecx, edx
+;;  1ea:	 0f8548000000         	jne	0x238
+;;  1f0:	 50                   	push	rax
+;;  1f1:	 59                   	pop	rcx
+;;  1f2:	 488b5910             	mov	rbx, qword ptr [rcx + 0x10]
+;;  1f6:	 488b4920             	mov	rcx, qword ptr [rcx + 0x20]
+;;  1fa:	 51                   	push	rcx
+;;  1fb:	 4156                 	push	r14
+;;  1fd:	 4883ec08             	sub	rsp, 8
+;;  201:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;  206:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;  20b:	 48c7c201000000       	mov	rdx, 1
+;;  212:	 ffd3                 	call	rbx
+;;  214:	 4c8b742420           	mov	r14, qword ptr [rsp + 0x20]
+;;  219:	 4883c418             	add	rsp, 0x18
+;;  21d:	 58                   	pop	rax
+;;  21e:	 4883c410             	add	rsp, 0x10
+;;  222:	 5d                   	pop	rbp
+;;  223:	 c3                   	ret	
+;;  224:	 0f0b                 	ud2	
+;;  226:	 0f0b                 	ud2	
+;;  228:	 0f0b                 	ud2	
+;;  22a:	 0f0b                 	ud2	
+;;  22c:	 0f0b                 	ud2	
+;;  22e:	 0f0b                 	ud2	
+;;  230:	 0f0b                 	ud2	
+;;  232:	 0f0b                 	ud2	
+;;  234:	 0f0b                 	ud2	
+;;  236:	 0f0b                 	ud2	
+;;  238:	 0f0b                 	ud2	
@@ -8,18 +8,28 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f30f10051c000000     	movss	xmm0, dword ptr [rip + 0x1c]
-;;   14:	 41bbffffff7f         	mov	r11d, 0x7fffffff
-;;   1a:	 66450f6efb           	movd	xmm15, r11d
-;;   1f:	 410f54c7             	andps	xmm0, xmm15
-;;   23:	 4883c408             	add	rsp, 8
-;;   27:	 5d                   	pop	rbp
-;;   28:	 c3                   	ret	
-;;   29:	 0000                 	add	byte ptr [rax], al
-;;   2b:	 0000                 	add	byte ptr [rax], al
-;;   2d:	 0000                 	add	byte ptr [rax], al
-;;   2f:	 00c3                 	add	bl, al
-;;   31:	 f5                   	cmc	
-;;   32:	 a8bf                 	test	al, 0xbf
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f822c000000         	jb	0x47
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8225000000         	jb	0x49
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f30f10051c000000     	movss	xmm0, dword ptr [rip + 0x1c]
+;;   34:	 f3440f103d1b000000   	
+;; 				movss	xmm15, dword ptr [rip + 0x1b]
+;;   3d:	 410f54c7             	andps	xmm0, xmm15
+;;   41:	 4883c408             	add	rsp, 8
+;;   45:	 5d                   	pop	rbp
+;;   46:	 c3                   	ret	
+;;   47:	 0f0b                 	ud2	
+;;   49:	 0f0b                 	ud2	
+;;   4b:	 0000                 	add	byte ptr [rax], al
+;;   4d:	 0000                 	add	byte ptr [rax], al
+;;   4f:	 00c3                 	add	bl, al
+;;   51:	 f5                   	cmc	
+;;   52:	 a8bf                 	test	al, 0xbf
+;;   54:	 0000                 	add	byte ptr [rax], al
+;;   56:	 0000                 	add	byte ptr [rax], al
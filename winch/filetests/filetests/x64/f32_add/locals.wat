@@ -18,22 +18,29 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f824f000000         	jb	0x6a
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8248000000         	jb	0x6c
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c893424             	mov	qword ptr [rsp], r14
-;;   15:	 f30f100533000000     	movss	xmm0, dword ptr [rip + 0x33]
-;;   1d:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;   23:	 f30f10052d000000     	movss	xmm0, dword ptr [rip + 0x2d]
-;;   2b:	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
-;;   31:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
-;;   37:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
-;;   3d:	 f30f58c8             	addss	xmm1, xmm0
-;;   41:	 0f28c1               	movaps	xmm0, xmm1
-;;   44:	 4883c410             	add	rsp, 0x10
-;;   48:	 5d                   	pop	rbp
-;;   49:	 c3                   	ret	
-;;   4a:	 0000                 	add	byte ptr [rax], al
-;;   4c:	 0000                 	add	byte ptr [rax], al
-;;   4e:	 0000                 	add	byte ptr [rax], al
-;;   50:	 cdcc                 	int	0xcc
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 f30f100533000000     	movss	xmm0, dword ptr [rip + 0x33]
+;;   3d:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   43:	 f30f10052d000000     	movss	xmm0, dword ptr [rip + 0x2d]
+;;   4b:	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
+;;   51:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
+;;   57:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
+;;   5d:	 f30f58c8             	addss	xmm1, xmm0
+;;   61:	 0f28c1               	movaps	xmm0, xmm1
+;;   64:	 4883c410             	add	rsp, 0x10
+;;   68:	 5d                   	pop	rbp
+;;   69:	 c3                   	ret	
+;;   6a:	 0f0b                 	ud2	
+;;   6c:	 0f0b                 	ud2	
+;;   6e:	 0000                 	add	byte ptr [rax], al
+;;   70:	 cdcc                 	int	0xcc
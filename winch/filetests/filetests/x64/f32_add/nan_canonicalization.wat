@@ -0,0 +1,37 @@
+;;! target = "x86_64"
+;;! flags = ["enable_nan_canonicalization"]
+
+(module
+    (func (param f32 f32) (result f32)
+        (local.get 0)
+        (local.get 1)
+        (f32.add)
+    )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8247000000         	jb	0x62
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8240000000         	jb	0x64
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   2e:	 f30f114c2408         	movss	dword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
+;;   3e:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
+;;   44:	 f30f58c8             	addss	xmm1, xmm0
+;;   48:	 0f2ec9               	ucomiss	xmm1, xmm1
+;;   4b:	 0f8b08000000         	jnp	0x59
+;;   51:	 f30f100d0f000000     	movss	xmm1, dword ptr [rip + 0xf]
+;;   59:	 0f28c1               	movaps	xmm0, xmm1
+;;   5c:	 4883c410             	add	rsp, 0x10
+;;   60:	 5d                   	pop	rbp
+;;   61:	 c3                   	ret	
+;;   62:	 0f0b                 	ud2	
+;;   64:	 0f0b                 	ud2	
+;;   66:	 0000                 	add	byte ptr [rax], al
+;;   68:	 0000                 	add	byte ptr [rax], al
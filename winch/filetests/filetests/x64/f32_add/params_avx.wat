@@ -0,0 +1,32 @@
+;;! target = "x86_64"
+;;! flags = ["has_avx"]
+
+(module
+    (func (param f32) (param f32) (result f32)
+        (local.get 0)
+        (local.get 1)
+        (f32.add)
+    )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8236000000         	jb	0x51
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822f000000         	jb	0x53
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 c5fa1144240c         	vmovss	dword ptr [rsp + 0xc], xmm0
+;;   2e:	 c5fa114c2408         	vmovss	dword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 c5fa10442408         	vmovss	xmm0, dword ptr [rsp + 8]
+;;   3e:	 c5fa104c240c         	vmovss	xmm1, dword ptr [rsp + 0xc]
+;;   44:	 c5f258c8             	vaddss	xmm1, xmm1, xmm0
+;;   48:	 0f28c1               	movaps	xmm0, xmm1
+;;   4b:	 4883c410             	add	rsp, 0x10
+;;   4f:	 5d                   	pop	rbp
+;;   50:	 c3                   	ret	
+;;   51:	 0f0b                 	ud2	
+;;   53:	 0f0b                 	ud2	
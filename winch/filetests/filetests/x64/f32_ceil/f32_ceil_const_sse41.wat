@@ -9,13 +9,24 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f30f10050c000000     	movss	xmm0, dword ptr [rip + 0xc]
-;;   14:	 660f3a0ac002         	roundss	xmm0, xmm0, 2
-;;   1a:	 4883c408             	add	rsp, 8
-;;   1e:	 5d                   	pop	rbp
-;;   1f:	 c3                   	ret	
-;;   20:	 c3                   	ret	
-;;   21:	 f5                   	cmc	
-;;   22:	 a8bf                 	test	al, 0xbf
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8225000000         	jb	0x40
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f821e000000         	jb	0x42
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f30f100514000000     	movss	xmm0, dword ptr [rip + 0x14]
+;;   34:	 660f3a0ac002         	roundss	xmm0, xmm0, 2
+;;   3a:	 4883c408             	add	rsp, 8
+;;   3e:	 5d                   	pop	rbp
+;;   3f:	 c3                   	ret	
+;;   40:	 0f0b                 	ud2	
+;;   42:	 0f0b                 	ud2	
+;;   44:	 0000                 	add	byte ptr [rax], al
+;;   46:	 0000                 	add	byte ptr [rax], al
+;;   48:	 c3                   	ret	
+;;   49:	 f5                   	cmc	
+;;   4a:	 a8bf                 	test	al, 0xbf
@@ -9,11 +9,20 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;    e:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   13:	 f30f1044240c         	movss	xmm0, dword ptr [rsp + 0xc]
-;;   19:	 660f3a0ac002         	roundss	xmm0, xmm0, 2
-;;   1f:	 4883c410             	add	rsp, 0x10
-;;   23:	 5d                   	pop	rbp
-;;   24:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f822a000000         	jb	0x45
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8223000000         	jb	0x47
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   2e:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   33:	 f30f1044240c         	movss	xmm0, dword ptr [rsp + 0xc]
+;;   39:	 660f3a0ac002         	roundss	xmm0, xmm0, 2
+;;   3f:	 4883c410             	add	rsp, 0x10
+;;   43:	 5d                   	pop	rbp
+;;   44:	 c3                   	ret	
+;;   45:	 0f0b                 	ud2	
+;;   47:	 0f0b                 	ud2	
@@ -6,25 +6,50 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;    e:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   13:	 f30f1044240c         	movss	xmm0, dword ptr [rsp + 0xc]
-;;   19:	 4883c410             	add	rsp, 0x10
-;;   1d:	 5d                   	pop	rbp
-;;   1e:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8224000000         	jb	0x3f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f821d000000         	jb	0x41
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   2e:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   33:	 f30f1044240c         	movss	xmm0, dword ptr [rsp + 0xc]
+;;   39:	 4883c410             	add	rsp, 0x10
+;;   3d:	 5d                   	pop	rbp
+;;   3e:	 c3                   	ret	
+;;   3f:	 0f0b                 	ud2	
+;;   41:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883ec08             	sub	rsp, 8
-;;   10:	 f30f100510000000     	movss	xmm0, dword ptr [rip + 0x10]
-;;   18:	 e800000000           	call	0x1d
-;;   1d:	 4883c408             	add	rsp, 8
-;;   21:	 4883c408             	add	rsp, 8
-;;   25:	 5d                   	pop	rbp
-;;   26:	 c3                   	ret	
-;;   27:	 00c3                 	add	bl, al
-;;   29:	 f5                   	cmc	
-;;   2a:	 a83f                 	test	al, 0x3f
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f823a000000         	jb	0x55
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8233000000         	jb	0x57
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4156                 	push	r14
+;;   2e:	 4156                 	push	r14
+;;   30:	 4883ec08             	sub	rsp, 8
+;;   34:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   39:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   3e:	 f30f10051a000000     	movss	xmm0, dword ptr [rip + 0x1a]
+;;   46:	 e800000000           	call	0x4b
+;;   4b:	 4883c418             	add	rsp, 0x18
+;;   4f:	 4883c408             	add	rsp, 8
+;;   53:	 5d                   	pop	rbp
+;;   54:	 c3                   	ret	
+;;   55:	 0f0b                 	ud2	
+;;   57:	 0f0b                 	ud2	
+;;   59:	 0000                 	add	byte ptr [rax], al
+;;   5b:	 0000                 	add	byte ptr [rax], al
+;;   5d:	 0000                 	add	byte ptr [rax], al
+;;   5f:	 00c3                 	add	bl, al
+;;   61:	 f5                   	cmc	
+;;   62:	 a83f                 	test	al, 0x3f
@@ -0,0 +1,38 @@
+;;! target = "x86_64"
+
+(module
+    (func (result f32)
+        (f32.const 1.1)
+        (f32.const 1.1)
+        (f32.add)
+        (f32.const 1.1)
+        (f32.add)
+    )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f823a000000         	jb	0x55
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8233000000         	jb	0x57
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f30f10052c000000     	movss	xmm0, dword ptr [rip + 0x2c]
+;;   34:	 f30f100d24000000     	movss	xmm1, dword ptr [rip + 0x24]
+;;   3c:	 f30f58c8             	addss	xmm1, xmm0
+;;   40:	 f30f100518000000     	movss	xmm0, dword ptr [rip + 0x18]
+;;   48:	 f30f58c8             	addss	xmm1, xmm0
+;;   4c:	 0f28c1               	movaps	xmm0, xmm1
+;;   4f:	 4883c408             	add	rsp, 8
+;;   53:	 5d                   	pop	rbp
+;;   54:	 c3                   	ret	
+;;   55:	 0f0b                 	ud2	
+;;   57:	 0f0b                 	ud2	
+;;   59:	 0000                 	add	byte ptr [rax], al
+;;   5b:	 0000                 	add	byte ptr [rax], al
+;;   5d:	 0000                 	add	byte ptr [rax], al
+;;   5f:	 00cd                 	add	ch, cl
+;;   61:	 cc                   	int3	
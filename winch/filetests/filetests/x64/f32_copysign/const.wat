@@ -9,23 +9,35 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f30f10052c000000     	movss	xmm0, dword ptr [rip + 0x2c]
-;;   14:	 f30f100d2c000000     	movss	xmm1, dword ptr [rip + 0x2c]
-;;   1c:	 41bb00000080         	mov	r11d, 0x80000000
-;;   22:	 66450f6efb           	movd	xmm15, r11d
-;;   27:	 410f54c7             	andps	xmm0, xmm15
-;;   2b:	 440f55f9             	andnps	xmm15, xmm1
-;;   2f:	 410f28cf             	movaps	xmm1, xmm15
-;;   33:	 0f56c8               	orps	xmm1, xmm0
-;;   36:	 0f28c1               	movaps	xmm0, xmm1
-;;   39:	 4883c408             	add	rsp, 8
-;;   3d:	 5d                   	pop	rbp
-;;   3e:	 c3                   	ret	
-;;   3f:	 00cd                 	add	ch, cl
-;;   41:	 cc                   	int3	
-;;   42:	 0c40                 	or	al, 0x40
-;;   44:	 0000                 	add	byte ptr [rax], al
-;;   46:	 0000                 	add	byte ptr [rax], al
-;;   48:	 cdcc                 	int	0xcc
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8242000000         	jb	0x5d
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f823b000000         	jb	0x5f
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f30f100534000000     	movss	xmm0, dword ptr [rip + 0x34]
+;;   34:	 f30f100d34000000     	movss	xmm1, dword ptr [rip + 0x34]
+;;   3c:	 f3440f103d33000000   	
+;; 				movss	xmm15, dword ptr [rip + 0x33]
+;;   45:	 410f54c7             	andps	xmm0, xmm15
+;;   49:	 440f55f9             	andnps	xmm15, xmm1
+;;   4d:	 410f28cf             	movaps	xmm1, xmm15
+;;   51:	 0f56c8               	orps	xmm1, xmm0
+;;   54:	 0f28c1               	movaps	xmm0, xmm1
+;;   57:	 4883c408             	add	rsp, 8
+;;   5b:	 5d                   	pop	rbp
+;;   5c:	 c3                   	ret	
+;;   5d:	 0f0b                 	ud2	
+;;   5f:	 0f0b                 	ud2	
+;;   61:	 0000                 	add	byte ptr [rax], al
+;;   63:	 0000                 	add	byte ptr [rax], al
+;;   65:	 0000                 	add	byte ptr [rax], al
+;;   67:	 00cd                 	add	ch, cl
+;;   69:	 cc                   	int3	
+;;   6a:	 0c40                 	or	al, 0x40
+;;   6c:	 0000                 	add	byte ptr [rax], al
+;;   6e:	 0000                 	add	byte ptr [rax], al
+;;   70:	 cdcc                 	int	0xcc
@@ -18,24 +18,36 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8263000000         	jb	0x7e
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f825c000000         	jb	0x80
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c893424             	mov	qword ptr [rsp], r14
-;;   15:	 f30f100543000000     	movss	xmm0, dword ptr [rip + 0x43]
-;;   1d:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;   23:	 f30f10053d000000     	movss	xmm0, dword ptr [rip + 0x3d]
-;;   2b:	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
-;;   31:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
-;;   37:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
-;;   3d:	 41bb00000080         	mov	r11d, 0x80000000
-;;   43:	 66450f6efb           	movd	xmm15, r11d
-;;   48:	 410f54c7             	andps	xmm0, xmm15
-;;   4c:	 440f55f9             	andnps	xmm15, xmm1
-;;   50:	 410f28cf             	movaps	xmm1, xmm15
-;;   54:	 0f56c8               	orps	xmm1, xmm0
-;;   57:	 0f28c1               	movaps	xmm0, xmm1
-;;   5a:	 4883c410             	add	rsp, 0x10
-;;   5e:	 5d                   	pop	rbp
-;;   5f:	 c3                   	ret	
-;;   60:	 cdcc                 	int	0xcc
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 f30f10054b000000     	movss	xmm0, dword ptr [rip + 0x4b]
+;;   3d:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   43:	 f30f100545000000     	movss	xmm0, dword ptr [rip + 0x45]
+;;   4b:	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
+;;   51:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
+;;   57:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
+;;   5d:	 f3440f103d32000000   	
+;; 				movss	xmm15, dword ptr [rip + 0x32]
+;;   66:	 410f54c7             	andps	xmm0, xmm15
+;;   6a:	 440f55f9             	andnps	xmm15, xmm1
+;;   6e:	 410f28cf             	movaps	xmm1, xmm15
+;;   72:	 0f56c8               	orps	xmm1, xmm0
+;;   75:	 0f28c1               	movaps	xmm0, xmm1
+;;   78:	 4883c410             	add	rsp, 0x10
+;;   7c:	 5d                   	pop	rbp
+;;   7d:	 c3                   	ret	
+;;   7e:	 0f0b                 	ud2	
+;;   80:	 0f0b                 	ud2	
+;;   82:	 0000                 	add	byte ptr [rax], al
+;;   84:	 0000                 	add	byte ptr [rax], al
+;;   86:	 0000                 	add	byte ptr [rax], al
+;;   88:	 cdcc                 	int	0xcc
@@ -9,19 +9,33 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;    e:	 f30f114c2408         	movss	dword ptr [rsp + 8], xmm1
-;;   14:	 4c893424             	mov	qword ptr [rsp], r14
-;;   18:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
-;;   1e:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
-;;   24:	 41bb00000080         	mov	r11d, 0x80000000
-;;   2a:	 66450f6efb           	movd	xmm15, r11d
-;;   2f:	 410f54c7             	andps	xmm0, xmm15
-;;   33:	 440f55f9             	andnps	xmm15, xmm1
-;;   37:	 410f28cf             	movaps	xmm1, xmm15
-;;   3b:	 0f56c8               	orps	xmm1, xmm0
-;;   3e:	 0f28c1               	movaps	xmm0, xmm1
-;;   41:	 4883c410             	add	rsp, 0x10
-;;   45:	 5d                   	pop	rbp
-;;   46:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f824a000000         	jb	0x65
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8243000000         	jb	0x67
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   2e:	 f30f114c2408         	movss	dword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
+;;   3e:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
+;;   44:	 f3440f103d23000000   	
+;; 				movss	xmm15, dword ptr [rip + 0x23]
+;;   4d:	 410f54c7             	andps	xmm0, xmm15
+;;   51:	 440f55f9             	andnps	xmm15, xmm1
+;;   55:	 410f28cf             	movaps	xmm1, xmm15
+;;   59:	 0f56c8               	orps	xmm1, xmm0
+;;   5c:	 0f28c1               	movaps	xmm0, xmm1
+;;   5f:	 4883c410             	add	rsp, 0x10
+;;   63:	 5d                   	pop	rbp
+;;   64:	 c3                   	ret	
+;;   65:	 0f0b                 	ud2	
+;;   67:	 0f0b                 	ud2	
+;;   69:	 0000                 	add	byte ptr [rax], al
+;;   6b:	 0000                 	add	byte ptr [rax], al
+;;   6d:	 0000                 	add	byte ptr [rax], al
+;;   6f:	 0000                 	add	byte ptr [rax], al
+;;   71:	 0000                 	add	byte ptr [rax], al
@@ -9,14 +9,23 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;    e:	 f30f114c2408         	movss	dword ptr [rsp + 8], xmm1
-;;   14:	 4c893424             	mov	qword ptr [rsp], r14
-;;   18:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
-;;   1e:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
-;;   24:	 f30f5ec8             	divss	xmm1, xmm0
-;;   28:	 0f28c1               	movaps	xmm0, xmm1
-;;   2b:	 4883c410             	add	rsp, 0x10
-;;   2f:	 5d                   	pop	rbp
-;;   30:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8236000000         	jb	0x51
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822f000000         	jb	0x53
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   2e:	 f30f114c2408         	movss	dword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
+;;   3e:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
+;;   44:	 f30f5ec8             	divss	xmm1, xmm0
+;;   48:	 0f28c1               	movaps	xmm0, xmm1
+;;   4b:	 4883c410             	add	rsp, 0x10
+;;   4f:	 5d                   	pop	rbp
+;;   50:	 c3                   	ret	
+;;   51:	 0f0b                 	ud2	
+;;   53:	 0f0b                 	ud2	
@@ -9,18 +9,27 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;    e:	 f30f114c2408         	movss	dword ptr [rsp + 8], xmm1
-;;   14:	 4c893424             	mov	qword ptr [rsp], r14
-;;   18:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
-;;   1e:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
-;;   24:	 0f2ec8               	ucomiss	xmm1, xmm0
-;;   27:	 b800000000           	mov	eax, 0
-;;   2c:	 400f93c0             	setae	al
-;;   30:	 41bb00000000         	mov	r11d, 0
-;;   36:	 410f9bc3             	setnp	r11b
-;;   3a:	 4421d8               	and	eax, r11d
-;;   3d:	 4883c410             	add	rsp, 0x10
-;;   41:	 5d                   	pop	rbp
-;;   42:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8248000000         	jb	0x63
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8241000000         	jb	0x65
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   2e:	 f30f114c2408         	movss	dword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
+;;   3e:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
+;;   44:	 0f2ec8               	ucomiss	xmm1, xmm0
+;;   47:	 b800000000           	mov	eax, 0
+;;   4c:	 400f93c0             	setae	al
+;;   50:	 41bb00000000         	mov	r11d, 0
+;;   56:	 410f9bc3             	setnp	r11b
+;;   5a:	 4421d8               	and	eax, r11d
+;;   5d:	 4883c410             	add	rsp, 0x10
+;;   61:	 5d                   	pop	rbp
+;;   62:	 c3                   	ret	
+;;   63:	 0f0b                 	ud2	
+;;   65:	 0f0b                 	ud2	
@@ -9,24 +9,31 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f30f10052c000000     	movss	xmm0, dword ptr [rip + 0x2c]
-;;   14:	 f30f100d2c000000     	movss	xmm1, dword ptr [rip + 0x2c]
-;;   1c:	 0f2ec8               	ucomiss	xmm1, xmm0
-;;   1f:	 b800000000           	mov	eax, 0
-;;   24:	 400f97c0             	seta	al
-;;   28:	 41bb00000000         	mov	r11d, 0
-;;   2e:	 410f9bc3             	setnp	r11b
-;;   32:	 4421d8               	and	eax, r11d
-;;   35:	 4883c408             	add	rsp, 8
-;;   39:	 5d                   	pop	rbp
-;;   3a:	 c3                   	ret	
-;;   3b:	 0000                 	add	byte ptr [rax], al
-;;   3d:	 0000                 	add	byte ptr [rax], al
-;;   3f:	 00cd                 	add	ch, cl
-;;   41:	 cc                   	int3	
-;;   42:	 0c40                 	or	al, 0x40
-;;   44:	 0000                 	add	byte ptr [rax], al
-;;   46:	 0000                 	add	byte ptr [rax], al
-;;   48:	 cdcc                 	int	0xcc
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8240000000         	jb	0x5b
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8239000000         	jb	0x5d
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f30f10052c000000     	movss	xmm0, dword ptr [rip + 0x2c]
+;;   34:	 f30f100d2c000000     	movss	xmm1, dword ptr [rip + 0x2c]
+;;   3c:	 0f2ec8               	ucomiss	xmm1, xmm0
+;;   3f:	 b800000000           	mov	eax, 0
+;;   44:	 400f97c0             	seta	al
+;;   48:	 41bb00000000         	mov	r11d, 0
+;;   4e:	 410f9bc3             	setnp	r11b
+;;   52:	 4421d8               	and	eax, r11d
+;;   55:	 4883c408             	add	rsp, 8
+;;   59:	 5d                   	pop	rbp
+;;   5a:	 c3                   	ret	
+;;   5b:	 0f0b                 	ud2	
+;;   5d:	 0f0b                 	ud2	
+;;   5f:	 00cd                 	add	ch, cl
+;;   61:	 cc                   	int3	
+;;   62:	 0c40                 	or	al, 0x40
+;;   64:	 0000                 	add	byte ptr [rax], al
+;;   66:	 0000                 	add	byte ptr [rax], al
+;;   68:	 cdcc                 	int	0xcc
@@ -18,25 +18,32 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8261000000         	jb	0x7c
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f825a000000         	jb	0x7e
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c893424             	mov	qword ptr [rsp], r14
-;;   15:	 f30f100543000000     	movss	xmm0, dword ptr [rip + 0x43]
-;;   1d:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;   23:	 f30f10053d000000     	movss	xmm0, dword ptr [rip + 0x3d]
-;;   2b:	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
-;;   31:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
-;;   37:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
-;;   3d:	 0f2ec8               	ucomiss	xmm1, xmm0
-;;   40:	 b800000000           	mov	eax, 0
-;;   45:	 400f97c0             	seta	al
-;;   49:	 41bb00000000         	mov	r11d, 0
-;;   4f:	 410f9bc3             	setnp	r11b
-;;   53:	 4421d8               	and	eax, r11d
-;;   56:	 4883c410             	add	rsp, 0x10
-;;   5a:	 5d                   	pop	rbp
-;;   5b:	 c3                   	ret	
-;;   5c:	 0000                 	add	byte ptr [rax], al
-;;   5e:	 0000                 	add	byte ptr [rax], al
-;;   60:	 cdcc                 	int	0xcc
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 f30f100543000000     	movss	xmm0, dword ptr [rip + 0x43]
+;;   3d:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   43:	 f30f10053d000000     	movss	xmm0, dword ptr [rip + 0x3d]
+;;   4b:	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
+;;   51:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
+;;   57:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
+;;   5d:	 0f2ec8               	ucomiss	xmm1, xmm0
+;;   60:	 b800000000           	mov	eax, 0
+;;   65:	 400f97c0             	seta	al
+;;   69:	 41bb00000000         	mov	r11d, 0
+;;   6f:	 410f9bc3             	setnp	r11b
+;;   73:	 4421d8               	and	eax, r11d
+;;   76:	 4883c410             	add	rsp, 0x10
+;;   7a:	 5d                   	pop	rbp
+;;   7b:	 c3                   	ret	
+;;   7c:	 0f0b                 	ud2	
+;;   7e:	 0f0b                 	ud2	
+;;   80:	 cdcc                 	int	0xcc
@@ -9,19 +9,30 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f30f10051c000000     	movss	xmm0, dword ptr [rip + 0x1c]
-;;   14:	 f30f100d1c000000     	movss	xmm1, dword ptr [rip + 0x1c]
-;;   1c:	 0f2ec1               	ucomiss	xmm0, xmm1
-;;   1f:	 b800000000           	mov	eax, 0
-;;   24:	 400f93c0             	setae	al
-;;   28:	 4883c408             	add	rsp, 8
-;;   2c:	 5d                   	pop	rbp
-;;   2d:	 c3                   	ret	
-;;   2e:	 0000                 	add	byte ptr [rax], al
-;;   30:	 cdcc                 	int	0xcc
-;;   32:	 0c40                 	or	al, 0x40
-;;   34:	 0000                 	add	byte ptr [rax], al
-;;   36:	 0000                 	add	byte ptr [rax], al
-;;   38:	 cdcc                 	int	0xcc
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8233000000         	jb	0x4e
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822c000000         	jb	0x50
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f30f100524000000     	movss	xmm0, dword ptr [rip + 0x24]
+;;   34:	 f30f100d24000000     	movss	xmm1, dword ptr [rip + 0x24]
+;;   3c:	 0f2ec1               	ucomiss	xmm0, xmm1
+;;   3f:	 b800000000           	mov	eax, 0
+;;   44:	 400f93c0             	setae	al
+;;   48:	 4883c408             	add	rsp, 8
+;;   4c:	 5d                   	pop	rbp
+;;   4d:	 c3                   	ret	
+;;   4e:	 0f0b                 	ud2	
+;;   50:	 0f0b                 	ud2	
+;;   52:	 0000                 	add	byte ptr [rax], al
+;;   54:	 0000                 	add	byte ptr [rax], al
+;;   56:	 0000                 	add	byte ptr [rax], al
+;;   58:	 cdcc                 	int	0xcc
+;;   5a:	 0c40                 	or	al, 0x40
+;;   5c:	 0000                 	add	byte ptr [rax], al
+;;   5e:	 0000                 	add	byte ptr [rax], al
+;;   60:	 cdcc                 	int	0xcc
@@ -18,21 +18,32 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8254000000         	jb	0x6f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f824d000000         	jb	0x71
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c893424             	mov	qword ptr [rsp], r14
-;;   15:	 f30f100533000000     	movss	xmm0, dword ptr [rip + 0x33]
-;;   1d:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;   23:	 f30f10052d000000     	movss	xmm0, dword ptr [rip + 0x2d]
-;;   2b:	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
-;;   31:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
-;;   37:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
-;;   3d:	 0f2ec1               	ucomiss	xmm0, xmm1
-;;   40:	 b800000000           	mov	eax, 0
-;;   45:	 400f93c0             	setae	al
-;;   49:	 4883c410             	add	rsp, 0x10
-;;   4d:	 5d                   	pop	rbp
-;;   4e:	 c3                   	ret	
-;;   4f:	 00cd                 	add	ch, cl
-;;   51:	 cc                   	int3	
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 f30f10053b000000     	movss	xmm0, dword ptr [rip + 0x3b]
+;;   3d:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   43:	 f30f100535000000     	movss	xmm0, dword ptr [rip + 0x35]
+;;   4b:	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
+;;   51:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
+;;   57:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
+;;   5d:	 0f2ec1               	ucomiss	xmm0, xmm1
+;;   60:	 b800000000           	mov	eax, 0
+;;   65:	 400f93c0             	setae	al
+;;   69:	 4883c410             	add	rsp, 0x10
+;;   6d:	 5d                   	pop	rbp
+;;   6e:	 c3                   	ret	
+;;   6f:	 0f0b                 	ud2	
+;;   71:	 0f0b                 	ud2	
+;;   73:	 0000                 	add	byte ptr [rax], al
+;;   75:	 0000                 	add	byte ptr [rax], al
+;;   77:	 00cd                 	add	ch, cl
+;;   79:	 cc                   	int3	
@@ -9,27 +9,34 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f30f10053c000000     	movss	xmm0, dword ptr [rip + 0x3c]
-;;   14:	 f30f100d3c000000     	movss	xmm1, dword ptr [rip + 0x3c]
-;;   1c:	 0f2ec8               	ucomiss	xmm1, xmm0
-;;   1f:	 0f8518000000         	jne	0x3d
-;;   25:	 0f8a08000000         	jp	0x33
-;;   2b:	 0f54c8               	andps	xmm1, xmm0
-;;   2e:	 e90e000000           	jmp	0x41
-;;   33:	 f30f58c8             	addss	xmm1, xmm0
-;;   37:	 0f8a04000000         	jp	0x41
-;;   3d:	 f30f5fc8             	maxss	xmm1, xmm0
-;;   41:	 0f28c1               	movaps	xmm0, xmm1
-;;   44:	 4883c408             	add	rsp, 8
-;;   48:	 5d                   	pop	rbp
-;;   49:	 c3                   	ret	
-;;   4a:	 0000                 	add	byte ptr [rax], al
-;;   4c:	 0000                 	add	byte ptr [rax], al
-;;   4e:	 0000                 	add	byte ptr [rax], al
-;;   50:	 cdcc                 	int	0xcc
-;;   52:	 0c40                 	or	al, 0x40
-;;   54:	 0000                 	add	byte ptr [rax], al
-;;   56:	 0000                 	add	byte ptr [rax], al
-;;   58:	 cdcc                 	int	0xcc
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f824f000000         	jb	0x6a
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8248000000         	jb	0x6c
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f30f10053c000000     	movss	xmm0, dword ptr [rip + 0x3c]
+;;   34:	 f30f100d3c000000     	movss	xmm1, dword ptr [rip + 0x3c]
+;;   3c:	 0f2ec8               	ucomiss	xmm1, xmm0
+;;   3f:	 0f8518000000         	jne	0x5d
+;;   45:	 0f8a08000000         	jp	0x53
+;;   4b:	 0f54c8               	andps	xmm1, xmm0
+;;   4e:	 e90e000000           	jmp	0x61
+;;   53:	 f30f58c8             	addss	xmm1, xmm0
+;;   57:	 0f8a04000000         	jp	0x61
+;;   5d:	 f30f5fc8             	maxss	xmm1, xmm0
+;;   61:	 0f28c1               	movaps	xmm0, xmm1
+;;   64:	 4883c408             	add	rsp, 8
+;;   68:	 5d                   	pop	rbp
+;;   69:	 c3                   	ret	
+;;   6a:	 0f0b                 	ud2	
+;;   6c:	 0f0b                 	ud2	
+;;   6e:	 0000                 	add	byte ptr [rax], al
+;;   70:	 cdcc                 	int	0xcc
+;;   72:	 0c40                 	or	al, 0x40
+;;   74:	 0000                 	add	byte ptr [rax], al
+;;   76:	 0000                 	add	byte ptr [rax], al
+;;   78:	 cdcc                 	int	0xcc
@@ -9,21 +9,30 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;    e:	 f30f114c2408         	movss	dword ptr [rsp + 8], xmm1
-;;   14:	 4c893424             	mov	qword ptr [rsp], r14
-;;   18:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
-;;   1e:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
-;;   24:	 0f2ec8               	ucomiss	xmm1, xmm0
-;;   27:	 0f8518000000         	jne	0x45
-;;   2d:	 0f8a08000000         	jp	0x3b
-;;   33:	 0f54c8               	andps	xmm1, xmm0
-;;   36:	 e90e000000           	jmp	0x49
-;;   3b:	 f30f58c8             	addss	xmm1, xmm0
-;;   3f:	 0f8a04000000         	jp	0x49
-;;   45:	 f30f5fc8             	maxss	xmm1, xmm0
-;;   49:	 0f28c1               	movaps	xmm0, xmm1
-;;   4c:	 4883c410             	add	rsp, 0x10
-;;   50:	 5d                   	pop	rbp
-;;   51:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8257000000         	jb	0x72
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8250000000         	jb	0x74
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   2e:	 f30f114c2408         	movss	dword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
+;;   3e:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
+;;   44:	 0f2ec8               	ucomiss	xmm1, xmm0
+;;   47:	 0f8518000000         	jne	0x65
+;;   4d:	 0f8a08000000         	jp	0x5b
+;;   53:	 0f54c8               	andps	xmm1, xmm0
+;;   56:	 e90e000000           	jmp	0x69
+;;   5b:	 f30f58c8             	addss	xmm1, xmm0
+;;   5f:	 0f8a04000000         	jp	0x69
+;;   65:	 f30f5fc8             	maxss	xmm1, xmm0
+;;   69:	 0f28c1               	movaps	xmm0, xmm1
+;;   6c:	 4883c410             	add	rsp, 0x10
+;;   70:	 5d                   	pop	rbp
+;;   71:	 c3                   	ret	
+;;   72:	 0f0b                 	ud2	
+;;   74:	 0f0b                 	ud2	
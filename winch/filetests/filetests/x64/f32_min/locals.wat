@@ -18,29 +18,36 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8270000000         	jb	0x8b
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8269000000         	jb	0x8d
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c893424             	mov	qword ptr [rsp], r14
-;;   15:	 f30f100553000000     	movss	xmm0, dword ptr [rip + 0x53]
-;;   1d:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;   23:	 f30f10054d000000     	movss	xmm0, dword ptr [rip + 0x4d]
-;;   2b:	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
-;;   31:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
-;;   37:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
-;;   3d:	 0f2ec8               	ucomiss	xmm1, xmm0
-;;   40:	 0f8518000000         	jne	0x5e
-;;   46:	 0f8a08000000         	jp	0x54
-;;   4c:	 0f56c8               	orps	xmm1, xmm0
-;;   4f:	 e90e000000           	jmp	0x62
-;;   54:	 f30f58c8             	addss	xmm1, xmm0
-;;   58:	 0f8a04000000         	jp	0x62
-;;   5e:	 f30f5dc8             	minss	xmm1, xmm0
-;;   62:	 0f28c1               	movaps	xmm0, xmm1
-;;   65:	 4883c410             	add	rsp, 0x10
-;;   69:	 5d                   	pop	rbp
-;;   6a:	 c3                   	ret	
-;;   6b:	 0000                 	add	byte ptr [rax], al
-;;   6d:	 0000                 	add	byte ptr [rax], al
-;;   6f:	 00cd                 	add	ch, cl
-;;   71:	 cc                   	int3	
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 f30f100553000000     	movss	xmm0, dword ptr [rip + 0x53]
+;;   3d:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   43:	 f30f10054d000000     	movss	xmm0, dword ptr [rip + 0x4d]
+;;   4b:	 f30f11442408         	movss	dword ptr [rsp + 8], xmm0
+;;   51:	 f30f10442408         	movss	xmm0, dword ptr [rsp + 8]
+;;   57:	 f30f104c240c         	movss	xmm1, dword ptr [rsp + 0xc]
+;;   5d:	 0f2ec8               	ucomiss	xmm1, xmm0
+;;   60:	 0f8518000000         	jne	0x7e
+;;   66:	 0f8a08000000         	jp	0x74
+;;   6c:	 0f56c8               	orps	xmm1, xmm0
+;;   6f:	 e90e000000           	jmp	0x82
+;;   74:	 f30f58c8             	addss	xmm1, xmm0
+;;   78:	 0f8a04000000         	jp	0x82
+;;   7e:	 f30f5dc8             	minss	xmm1, xmm0
+;;   82:	 0f28c1               	movaps	xmm0, xmm1
+;;   85:	 4883c410             	add	rsp, 0x10
+;;   89:	 5d                   	pop	rbp
+;;   8a:	 c3                   	ret	
+;;   8b:	 0f0b                 	ud2	
+;;   8d:	 0f0b                 	ud2	
+;;   8f:	 00cd                 	add	ch, cl
+;;   91:	 cc                   	int3	
@@ -9,21 +9,28 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f30f10051c000000     	movss	xmm0, dword ptr [rip + 0x1c]
-;;   14:	 f30f100d1c000000     	movss	xmm1, dword ptr [rip + 0x1c]
-;;   1c:	 f30f59c8             	mulss	xmm1, xmm0
-;;   20:	 0f28c1               	movaps	xmm0, xmm1
-;;   23:	 4883c408             	add	rsp, 8
-;;   27:	 5d                   	pop	rbp
-;;   28:	 c3                   	ret	
-;;   29:	 0000                 	add	byte ptr [rax], al
-;;   2b:	 0000                 	add	byte ptr [rax], al
-;;   2d:	 0000                 	add	byte ptr [rax], al
-;;   2f:	 00cd                 	add	ch, cl
-;;   31:	 cc                   	int3	
-;;   32:	 0c40                 	or	al, 0x40
-;;   34:	 0000                 	add	byte ptr [rax], al
-;;   36:	 0000                 	add	byte ptr [rax], al
-;;   38:	 cdcc                 	int	0xcc
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f822e000000         	jb	0x49
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8227000000         	jb	0x4b
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f30f10051c000000     	movss	xmm0, dword ptr [rip + 0x1c]
+;;   34:	 f30f100d1c000000     	movss	xmm1, dword ptr [rip + 0x1c]
+;;   3c:	 f30f59c8             	mulss	xmm1, xmm0
+;;   40:	 0f28c1               	movaps	xmm0, xmm1
+;;   43:	 4883c408             	add	rsp, 8
+;;   47:	 5d                   	pop	rbp
+;;   48:	 c3                   	ret	
+;;   49:	 0f0b                 	ud2	
+;;   4b:	 0f0b                 	ud2	
+;;   4d:	 0000                 	add	byte ptr [rax], al
+;;   4f:	 00cd                 	add	ch, cl
+;;   51:	 cc                   	int3	
+;;   52:	 0c40                 	or	al, 0x40
+;;   54:	 0000                 	add	byte ptr [rax], al
+;;   56:	 0000                 	add	byte ptr [rax], al
+;;   58:	 cdcc                 	int	0xcc
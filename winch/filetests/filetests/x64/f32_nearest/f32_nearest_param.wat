@@ -8,11 +8,20 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;    e:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   13:	 f30f1044240c         	movss	xmm0, dword ptr [rsp + 0xc]
-;;   19:	 e800000000           	call	0x1e
-;;   1e:	 4883c410             	add	rsp, 0x10
-;;   22:	 5d                   	pop	rbp
-;;   23:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8229000000         	jb	0x44
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8222000000         	jb	0x46
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   2e:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   33:	 f30f1044240c         	movss	xmm0, dword ptr [rsp + 0xc]
+;;   39:	 e800000000           	call	0x3e
+;;   3e:	 4883c410             	add	rsp, 0x10
+;;   42:	 5d                   	pop	rbp
+;;   43:	 c3                   	ret	
+;;   44:	 0f0b                 	ud2	
+;;   46:	 0f0b                 	ud2	
@@ -0,0 +1,28 @@
+;;! target = "x86_64"
+;;! flags = ["has_sse41", "has_avx"]
+
+(module
+    (func (param f32) (result f32)
+        (local.get 0)
+        (f32.nearest)
+    )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f822a000000         	jb	0x45
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8223000000         	jb	0x47
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 c5fa1144240c         	vmovss	dword ptr [rsp + 0xc], xmm0
+;;   2e:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   33:	 c5fa1044240c         	vmovss	xmm0, dword ptr [rsp + 0xc]
+;;   39:	 c4e3790ac000         	vroundss	xmm0, xmm0, xmm0, 0
+;;   3f:	 4883c410             	add	rsp, 0x10
+;;   43:	 5d                   	pop	rbp
+;;   44:	 c3                   	ret	
+;;   45:	 0f0b                 	ud2	
+;;   47:	 0f0b                 	ud2	
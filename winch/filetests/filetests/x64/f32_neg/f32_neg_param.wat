@@ -8,13 +8,23 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
-;;    e:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   13:	 f30f1044240c         	movss	xmm0, dword ptr [rsp + 0xc]
-;;   19:	 41bb00000080         	mov	r11d, 0x80000000
-;;   1f:	 66450f6efb           	movd	xmm15, r11d
-;;   24:	 410f57c7             	xorps	xmm0, xmm15
-;;   28:	 4883c410             	add	rsp, 0x10
-;;   2c:	 5d                   	pop	rbp
-;;   2d:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8231000000         	jb	0x4c
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822a000000         	jb	0x4e
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f30f1144240c         	movss	dword ptr [rsp + 0xc], xmm0
+;;   2e:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   33:	 f30f1044240c         	movss	xmm0, dword ptr [rsp + 0xc]
+;;   39:	 f3440f103d0e000000   	
+;; 				movss	xmm15, dword ptr [rip + 0xe]
+;;   42:	 410f57c7             	xorps	xmm0, xmm15
+;;   46:	 4883c410             	add	rsp, 0x10
+;;   4a:	 5d                   	pop	rbp
+;;   4b:	 c3                   	ret	
+;;   4c:	 0f0b                 	ud2	
+;;   4e:	 0f0b                 	ud2	
+;;   50:	 0000                 	add	byte ptr [rax], al
@@ -8,14 +8,25 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f30f10050c000000     	movss	xmm0, dword ptr [rip + 0xc]
-;;   14:	 f30f51c0             	sqrtss	xmm0, xmm0
-;;   18:	 4883c408             	add	rsp, 8
-;;   1c:	 5d                   	pop	rbp
-;;   1d:	 c3                   	ret	
-;;   1e:	 0000                 	add	byte ptr [rax], al
-;;   20:	 c3                   	ret	
-;;   21:	 f5                   	cmc	
-;;   22:	 a83f                 	test	al, 0x3f
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8223000000         	jb	0x3e
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f821c000000         	jb	0x40
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f30f100514000000     	movss	xmm0, dword ptr [rip + 0x14]
+;;   34:	 f30f51c0             	sqrtss	xmm0, xmm0
+;;   38:	 4883c408             	add	rsp, 8
+;;   3c:	 5d                   	pop	rbp
+;;   3d:	 c3                   	ret	
+;;   3e:	 0f0b                 	ud2	
+;;   40:	 0f0b                 	ud2	
+;;   42:	 0000                 	add	byte ptr [rax], al
+;;   44:	 0000                 	add	byte ptr [rax], al
+;;   46:	 0000                 	add	byte ptr [rax], al
+;;   48:	 c3                   	ret	
+;;   49:	 f5                   	cmc	
+;;   4a:	 a83f                 	test	al, 0x3f
@@ -0,0 +1,48 @@
+;;! target = "x86_64"
+;;! flags = ["enable_nan_canonicalization", "has_ssse3", "has_sse41"]
+
+(module
+    (func (param v128 v128) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (f32x4.add)
+    )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c328000000       	add	r11, 0x28
+;;   15:	 0f8259000000         	jb	0x74
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8252000000         	jb	0x76
+;;   24:	 4883ec28             	sub	rsp, 0x28
+;;   28:	 f30f7f442418         	movdqu	xmmword ptr [rsp + 0x18], xmm0
+;;   2e:	 f30f7f4c2408         	movdqu	xmmword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 f30f6f442408         	movdqu	xmm0, xmmword ptr [rsp + 8]
+;;   3e:	 f30f6f4c2418         	movdqu	xmm1, xmmword ptr [rsp + 0x18]
+;;   44:	 0f58c8               	addps	xmm1, xmm0
+;;   47:	 660f6fc1             	movdqa	xmm0, xmm1
+;;   4b:	 0fc2c104             	cmpneqps	xmm0, xmm1
+;;   4f:	 f3440f6f3d28000000   	
+;; 				movdqu	xmm15, xmmword ptr [rip + 0x28]
+;;   58:	 66440fdbf8           	pand	xmm15, xmm0
+;;   5d:	 660fdfc1             	pandn	xmm0, xmm1
+;;   61:	 66410febc7           	por	xmm0, xmm15
+;;   66:	 660f6fc8             	movdqa	xmm1, xmm0
+;;   6a:	 660f6fc1             	movdqa	xmm0, xmm1
+;;   6e:	 4883c428             	add	rsp, 0x28
+;;   72:	 5d                   	pop	rbp
+;;   73:	 c3                   	ret	
+;;   74:	 0f0b                 	ud2	
+;;   76:	 0f0b                 	ud2	
+;;   78:	 0000                 	add	byte ptr [rax], al
+;;   7a:	 0000                 	add	byte ptr [rax], al
+;;   7c:	 0000                 	add	byte ptr [rax], al
+;;   7e:	 0000                 	add	byte ptr [rax], al
+;;   80:	 0000                 	add	byte ptr [rax], al
+;;   82:	 c07f0000             	sar	byte ptr [rdi], 0
+;;   86:	 c07f0000             	sar	byte ptr [rdi], 0
+;;   8a:	 c07f0000             	sar	byte ptr [rdi], 0
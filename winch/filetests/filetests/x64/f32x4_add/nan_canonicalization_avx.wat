@@ -0,0 +1,52 @@
+;;! target = "x86_64"
+;;! flags = ["enable_nan_canonicalization", "has_ssse3", "has_sse41", "has_avx"]
+
+(module
+    (func (param v128 v128) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (f32x4.add)
+    )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c328000000       	add	r11, 0x28
+;;   15:	 0f8254000000         	jb	0x6f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f824d000000         	jb	0x71
+;;   24:	 4883ec28             	sub	rsp, 0x28
+;;   28:	 c5fa7f442418         	vmovdqu	xmmword ptr [rsp + 0x18], xmm0
+;;   2e:	 c5fa7f4c2408         	vmovdqu	xmmword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 c5fa6f442408         	vmovdqu	xmm0, xmmword ptr [rsp + 8]
+;;   3e:	 c5fa6f4c2418         	vmovdqu	xmm1, xmmword ptr [rsp + 0x18]
+;;   44:	 c5f058c8             	vaddps	xmm1, xmm1, xmm0
+;;   48:	 c5f0c2c104           	vcmpneqps	xmm0, xmm1, xmm1
+;;   4d:	 c57a6f3d2b000000     	vmovdqu	xmm15, xmmword ptr [rip + 0x2b]
+;;   55:	 c501dbf8             	vpand	xmm15, xmm15, xmm0
+;;   59:	 c5f9dfc1             	vpandn	xmm0, xmm0, xmm1
+;;   5d:	 c581ebc0             	vpor	xmm0, xmm15, xmm0
+;;   61:	 660f6fc8             	movdqa	xmm1, xmm0
+;;   65:	 660f6fc1             	movdqa	xmm0, xmm1
+;;   69:	 4883c428             	add	rsp, 0x28
+;;   6d:	 5d                   	pop	rbp
+;;   6e:	 c3                   	ret	
+;;   6f:	 0f0b                 	ud2	
+;;   71:	 0f0b                 	ud2	
+;;   73:	 0000                 	add	byte ptr [rax], al
+;;   75:	 0000                 	add	byte ptr [rax], al
+;;   77:	 0000                 	add	byte ptr [rax], al
+;;   79:	 0000                 	add	byte ptr [rax], al
+;;   7b:	 0000                 	add	byte ptr [rax], al
+;;   7d:	 0000                 	add	byte ptr [rax], al
+;;   7f:	 0000                 	add	byte ptr [rax], al
+;;   81:	 00c0                 	add	al, al
+;;   83:	 7f00                 	jg	0x85
+;;   85:	 00c0                 	add	al, al
+;;   87:	 7f00                 	jg	0x89
+;;   89:	 00c0                 	add	al, al
+;;   8b:	 7f00                 	jg	0x8d
+;;   8d:	 00c0                 	add	al, al
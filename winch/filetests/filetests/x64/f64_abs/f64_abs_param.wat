@@ -8,14 +8,22 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
-;;    e:	 4c893424             	mov	qword ptr [rsp], r14
-;;   12:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
-;;   18:	 49bbffffffffffffff7f 	
-;; 				movabs	r11, 0x7fffffffffffffff
-;;   22:	 664d0f6efb           	movq	xmm15, r11
-;;   27:	 66410f54c7           	andpd	xmm0, xmm15
-;;   2c:	 4883c410             	add	rsp, 0x10
-;;   30:	 5d                   	pop	rbp
-;;   31:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8231000000         	jb	0x4c
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822a000000         	jb	0x4e
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
+;;   2e:	 4c893424             	mov	qword ptr [rsp], r14
+;;   32:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   38:	 f2440f103d0f000000   	
+;; 				movsd	xmm15, qword ptr [rip + 0xf]
+;;   41:	 66410f54c7           	andpd	xmm0, xmm15
+;;   46:	 4883c410             	add	rsp, 0x10
+;;   4a:	 5d                   	pop	rbp
+;;   4b:	 c3                   	ret	
+;;   4c:	 0f0b                 	ud2	
+;;   4e:	 0f0b                 	ud2	
@@ -9,14 +9,23 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
-;;    e:	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
-;;   14:	 4c893424             	mov	qword ptr [rsp], r14
-;;   18:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
-;;   1e:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
-;;   24:	 f20f58c8             	addsd	xmm1, xmm0
-;;   28:	 660f28c1             	movapd	xmm0, xmm1
-;;   2c:	 4883c418             	add	rsp, 0x18
-;;   30:	 5d                   	pop	rbp
-;;   31:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8237000000         	jb	0x52
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8230000000         	jb	0x54
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
+;;   2e:	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   3e:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
+;;   44:	 f20f58c8             	addsd	xmm1, xmm0
+;;   48:	 660f28c1             	movapd	xmm0, xmm1
+;;   4c:	 4883c418             	add	rsp, 0x18
+;;   50:	 5d                   	pop	rbp
+;;   51:	 c3                   	ret	
+;;   52:	 0f0b                 	ud2	
+;;   54:	 0f0b                 	ud2	
@@ -5,10 +5,19 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
-;;    e:	 4c893424             	mov	qword ptr [rsp], r14
-;;   12:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
-;;   18:	 4883c410             	add	rsp, 0x10
-;;   1c:	 5d                   	pop	rbp
-;;   1d:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8223000000         	jb	0x3e
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f821c000000         	jb	0x40
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
+;;   2e:	 4c893424             	mov	qword ptr [rsp], r14
+;;   32:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   38:	 4883c410             	add	rsp, 0x10
+;;   3c:	 5d                   	pop	rbp
+;;   3d:	 c3                   	ret	
+;;   3e:	 0f0b                 	ud2	
+;;   40:	 0f0b                 	ud2	
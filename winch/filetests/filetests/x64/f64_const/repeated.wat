@@ -0,0 +1,36 @@
+;;! target = "x86_64"
+
+(module
+    (func (result f64)
+        (f64.const 1.1)
+        (f64.const 1.1)
+        (f64.add)
+        (f64.const 1.1)
+        (f64.add)
+    )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f823b000000         	jb	0x56
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8234000000         	jb	0x58
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f20f10052c000000     	movsd	xmm0, qword ptr [rip + 0x2c]
+;;   34:	 f20f100d24000000     	movsd	xmm1, qword ptr [rip + 0x24]
+;;   3c:	 f20f58c8             	addsd	xmm1, xmm0
+;;   40:	 f20f100518000000     	movsd	xmm0, qword ptr [rip + 0x18]
+;;   48:	 f20f58c8             	addsd	xmm1, xmm0
+;;   4c:	 660f28c1             	movapd	xmm0, xmm1
+;;   50:	 4883c408             	add	rsp, 8
+;;   54:	 5d                   	pop	rbp
+;;   55:	 c3                   	ret	
+;;   56:	 0f0b                 	ud2	
+;;   58:	 0f0b                 	ud2	
+;;   5a:	 0000                 	add	byte ptr [rax], al
+;;   5c:	 0000                 	add	byte ptr [rax], al
+;;   5e:	 0000                 	add	byte ptr [rax], al
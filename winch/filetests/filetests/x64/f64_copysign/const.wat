@@ -9,18 +9,27 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f20f100534000000     	movsd	xmm0, qword ptr [rip + 0x34]
-;;   14:	 f20f100d34000000     	movsd	xmm1, qword ptr [rip + 0x34]
-;;   1c:	 49bb0000000000000080 	
-;; 				movabs	r11, 0x8000000000000000
-;;   26:	 664d0f6efb           	movq	xmm15, r11
-;;   2b:	 66410f54c7           	andpd	xmm0, xmm15
-;;   30:	 66440f55f9           	andnpd	xmm15, xmm1
-;;   35:	 66410f28cf           	movapd	xmm1, xmm15
-;;   3a:	 660f56c8             	orpd	xmm1, xmm0
-;;   3e:	 660f28c1             	movapd	xmm0, xmm1
-;;   42:	 4883c408             	add	rsp, 8
-;;   46:	 5d                   	pop	rbp
-;;   47:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8247000000         	jb	0x62
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8240000000         	jb	0x64
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f20f100534000000     	movsd	xmm0, qword ptr [rip + 0x34]
+;;   34:	 f20f100d34000000     	movsd	xmm1, qword ptr [rip + 0x34]
+;;   3c:	 f2440f103d33000000   	
+;; 				movsd	xmm15, qword ptr [rip + 0x33]
+;;   45:	 66410f54c7           	andpd	xmm0, xmm15
+;;   4a:	 66440f55f9           	andnpd	xmm15, xmm1
+;;   4f:	 66410f28cf           	movapd	xmm1, xmm15
+;;   54:	 660f56c8             	orpd	xmm1, xmm0
+;;   58:	 660f28c1             	movapd	xmm0, xmm1
+;;   5c:	 4883c408             	add	rsp, 8
+;;   60:	 5d                   	pop	rbp
+;;   61:	 c3                   	ret	
+;;   62:	 0f0b                 	ud2	
+;;   64:	 0f0b                 	ud2	
+;;   66:	 0000                 	add	byte ptr [rax], al
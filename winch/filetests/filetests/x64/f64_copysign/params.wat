@@ -9,20 +9,32 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
-;;    e:	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
-;;   14:	 4c893424             	mov	qword ptr [rsp], r14
-;;   18:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
-;;   1e:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
-;;   24:	 49bb0000000000000080 	
-;; 				movabs	r11, 0x8000000000000000
-;;   2e:	 664d0f6efb           	movq	xmm15, r11
-;;   33:	 66410f54c7           	andpd	xmm0, xmm15
-;;   38:	 66440f55f9           	andnpd	xmm15, xmm1
-;;   3d:	 66410f28cf           	movapd	xmm1, xmm15
-;;   42:	 660f56c8             	orpd	xmm1, xmm0
-;;   46:	 660f28c1             	movapd	xmm0, xmm1
-;;   4a:	 4883c418             	add	rsp, 0x18
-;;   4e:	 5d                   	pop	rbp
-;;   4f:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f824f000000         	jb	0x6a
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8248000000         	jb	0x6c
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
+;;   2e:	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   3e:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
+;;   44:	 f2440f103d23000000   	
+;; 				movsd	xmm15, qword ptr [rip + 0x23]
+;;   4d:	 66410f54c7           	andpd	xmm0, xmm15
+;;   52:	 66440f55f9           	andnpd	xmm15, xmm1
+;;   57:	 66410f28cf           	movapd	xmm1, xmm15
+;;   5c:	 660f56c8             	orpd	xmm1, xmm0
+;;   60:	 660f28c1             	movapd	xmm0, xmm1
+;;   64:	 4883c418             	add	rsp, 0x18
+;;   68:	 5d                   	pop	rbp
+;;   69:	 c3                   	ret	
+;;   6a:	 0f0b                 	ud2	
+;;   6c:	 0f0b                 	ud2	
+;;   6e:	 0000                 	add	byte ptr [rax], al
+;;   70:	 0000                 	add	byte ptr [rax], al
+;;   72:	 0000                 	add	byte ptr [rax], al
+;;   74:	 0000                 	add	byte ptr [rax], al
@@ -9,15 +9,22 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f20f10051c000000     	movsd	xmm0, qword ptr [rip + 0x1c]
-;;   14:	 f20f100d1c000000     	movsd	xmm1, qword ptr [rip + 0x1c]
-;;   1c:	 f20f5ec8             	divsd	xmm1, xmm0
-;;   20:	 660f28c1             	movapd	xmm0, xmm1
-;;   24:	 4883c408             	add	rsp, 8
-;;   28:	 5d                   	pop	rbp
-;;   29:	 c3                   	ret	
-;;   2a:	 0000                 	add	byte ptr [rax], al
-;;   2c:	 0000                 	add	byte ptr [rax], al
-;;   2e:	 0000                 	add	byte ptr [rax], al
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f822f000000         	jb	0x4a
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8228000000         	jb	0x4c
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f20f10051c000000     	movsd	xmm0, qword ptr [rip + 0x1c]
+;;   34:	 f20f100d1c000000     	movsd	xmm1, qword ptr [rip + 0x1c]
+;;   3c:	 f20f5ec8             	divsd	xmm1, xmm0
+;;   40:	 660f28c1             	movapd	xmm0, xmm1
+;;   44:	 4883c408             	add	rsp, 8
+;;   48:	 5d                   	pop	rbp
+;;   49:	 c3                   	ret	
+;;   4a:	 0f0b                 	ud2	
+;;   4c:	 0f0b                 	ud2	
+;;   4e:	 0000                 	add	byte ptr [rax], al
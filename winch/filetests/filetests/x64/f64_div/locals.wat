@@ -18,22 +18,33 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 4531db               	xor	r11d, r11d
-;;    b:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
-;;   10:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
-;;   15:	 4c893424             	mov	qword ptr [rsp], r14
-;;   19:	 f20f10052f000000     	movsd	xmm0, qword ptr [rip + 0x2f]
-;;   21:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
-;;   27:	 f20f100529000000     	movsd	xmm0, qword ptr [rip + 0x29]
-;;   2f:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
-;;   35:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
-;;   3b:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
-;;   41:	 f20f5ec8             	divsd	xmm1, xmm0
-;;   45:	 660f28c1             	movapd	xmm0, xmm1
-;;   49:	 4883c418             	add	rsp, 0x18
-;;   4d:	 5d                   	pop	rbp
-;;   4e:	 c3                   	ret	
-;;   4f:	 009a99999999         	add	byte ptr [rdx - 0x66666667], bl
-;;   55:	 99                   	cdq	
-;;   56:	 f1                   	int1	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8254000000         	jb	0x6f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f824d000000         	jb	0x71
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 4531db               	xor	r11d, r11d
+;;   2b:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
+;;   30:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
+;;   35:	 4c893424             	mov	qword ptr [rsp], r14
+;;   39:	 f20f100537000000     	movsd	xmm0, qword ptr [rip + 0x37]
+;;   41:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
+;;   47:	 f20f100531000000     	movsd	xmm0, qword ptr [rip + 0x31]
+;;   4f:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
+;;   55:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   5b:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
+;;   61:	 f20f5ec8             	divsd	xmm1, xmm0
+;;   65:	 660f28c1             	movapd	xmm0, xmm1
+;;   69:	 4883c418             	add	rsp, 0x18
+;;   6d:	 5d                   	pop	rbp
+;;   6e:	 c3                   	ret	
+;;   6f:	 0f0b                 	ud2	
+;;   71:	 0f0b                 	ud2	
+;;   73:	 0000                 	add	byte ptr [rax], al
+;;   75:	 0000                 	add	byte ptr [rax], al
+;;   77:	 009a99999999         	add	byte ptr [rdx - 0x66666667], bl
+;;   7d:	 99                   	cdq	
+;;   7e:	 f1                   	int1	
@@ -9,18 +9,25 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f20f10052c000000     	movsd	xmm0, qword ptr [rip + 0x2c]
-;;   14:	 f20f100d2c000000     	movsd	xmm1, qword ptr [rip + 0x2c]
-;;   1c:	 660f2ec8             	ucomisd	xmm1, xmm0
-;;   20:	 b800000000           	mov	eax, 0
-;;   25:	 400f94c0             	sete	al
-;;   29:	 41bb00000000         	mov	r11d, 0
-;;   2f:	 410f9bc3             	setnp	r11b
-;;   33:	 4c21d8               	and	rax, r11
-;;   36:	 4883c408             	add	rsp, 8
-;;   3a:	 5d                   	pop	rbp
-;;   3b:	 c3                   	ret	
-;;   3c:	 0000                 	add	byte ptr [rax], al
-;;   3e:	 0000                 	add	byte ptr [rax], al
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8241000000         	jb	0x5c
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f823a000000         	jb	0x5e
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f20f10052c000000     	movsd	xmm0, qword ptr [rip + 0x2c]
+;;   34:	 f20f100d2c000000     	movsd	xmm1, qword ptr [rip + 0x2c]
+;;   3c:	 660f2ec8             	ucomisd	xmm1, xmm0
+;;   40:	 b800000000           	mov	eax, 0
+;;   45:	 400f94c0             	sete	al
+;;   49:	 41bb00000000         	mov	r11d, 0
+;;   4f:	 410f9bc3             	setnp	r11b
+;;   53:	 4c21d8               	and	rax, r11
+;;   56:	 4883c408             	add	rsp, 8
+;;   5a:	 5d                   	pop	rbp
+;;   5b:	 c3                   	ret	
+;;   5c:	 0f0b                 	ud2	
+;;   5e:	 0f0b                 	ud2	
@@ -9,18 +9,27 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
-;;    e:	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
-;;   14:	 4c893424             	mov	qword ptr [rsp], r14
-;;   18:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
-;;   1e:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
-;;   24:	 660f2ec8             	ucomisd	xmm1, xmm0
-;;   28:	 b800000000           	mov	eax, 0
-;;   2d:	 400f94c0             	sete	al
-;;   31:	 41bb00000000         	mov	r11d, 0
-;;   37:	 410f9bc3             	setnp	r11b
-;;   3b:	 4c21d8               	and	rax, r11
-;;   3e:	 4883c418             	add	rsp, 0x18
-;;   42:	 5d                   	pop	rbp
-;;   43:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8249000000         	jb	0x64
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8242000000         	jb	0x66
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
+;;   2e:	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   3e:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
+;;   44:	 660f2ec8             	ucomisd	xmm1, xmm0
+;;   48:	 b800000000           	mov	eax, 0
+;;   4d:	 400f94c0             	sete	al
+;;   51:	 41bb00000000         	mov	r11d, 0
+;;   57:	 410f9bc3             	setnp	r11b
+;;   5b:	 4c21d8               	and	rax, r11
+;;   5e:	 4883c418             	add	rsp, 0x18
+;;   62:	 5d                   	pop	rbp
+;;   63:	 c3                   	ret	
+;;   64:	 0f0b                 	ud2	
+;;   66:	 0f0b                 	ud2	
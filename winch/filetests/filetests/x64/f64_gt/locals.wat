@@ -18,29 +18,36 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 4531db               	xor	r11d, r11d
-;;    b:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
-;;   10:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
-;;   15:	 4c893424             	mov	qword ptr [rsp], r14
-;;   19:	 f20f100547000000     	movsd	xmm0, qword ptr [rip + 0x47]
-;;   21:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
-;;   27:	 f20f100541000000     	movsd	xmm0, qword ptr [rip + 0x41]
-;;   2f:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
-;;   35:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
-;;   3b:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
-;;   41:	 660f2ec8             	ucomisd	xmm1, xmm0
-;;   45:	 b800000000           	mov	eax, 0
-;;   4a:	 400f97c0             	seta	al
-;;   4e:	 41bb00000000         	mov	r11d, 0
-;;   54:	 410f9bc3             	setnp	r11b
-;;   58:	 4c21d8               	and	rax, r11
-;;   5b:	 4883c418             	add	rsp, 0x18
-;;   5f:	 5d                   	pop	rbp
-;;   60:	 c3                   	ret	
-;;   61:	 0000                 	add	byte ptr [rax], al
-;;   63:	 0000                 	add	byte ptr [rax], al
-;;   65:	 0000                 	add	byte ptr [rax], al
-;;   67:	 009a99999999         	add	byte ptr [rdx - 0x66666667], bl
-;;   6d:	 99                   	cdq	
-;;   6e:	 f1                   	int1	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8266000000         	jb	0x81
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f825f000000         	jb	0x83
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 4531db               	xor	r11d, r11d
+;;   2b:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
+;;   30:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
+;;   35:	 4c893424             	mov	qword ptr [rsp], r14
+;;   39:	 f20f100547000000     	movsd	xmm0, qword ptr [rip + 0x47]
+;;   41:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
+;;   47:	 f20f100541000000     	movsd	xmm0, qword ptr [rip + 0x41]
+;;   4f:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
+;;   55:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   5b:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
+;;   61:	 660f2ec8             	ucomisd	xmm1, xmm0
+;;   65:	 b800000000           	mov	eax, 0
+;;   6a:	 400f97c0             	seta	al
+;;   6e:	 41bb00000000         	mov	r11d, 0
+;;   74:	 410f9bc3             	setnp	r11b
+;;   78:	 4c21d8               	and	rax, r11
+;;   7b:	 4883c418             	add	rsp, 0x18
+;;   7f:	 5d                   	pop	rbp
+;;   80:	 c3                   	ret	
+;;   81:	 0f0b                 	ud2	
+;;   83:	 0f0b                 	ud2	
+;;   85:	 0000                 	add	byte ptr [rax], al
+;;   87:	 009a99999999         	add	byte ptr [rdx - 0x66666667], bl
+;;   8d:	 99                   	cdq	
+;;   8e:	 f1                   	int1	
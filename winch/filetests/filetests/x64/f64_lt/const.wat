@@ -9,22 +9,33 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f20f10051c000000     	movsd	xmm0, qword ptr [rip + 0x1c]
-;;   14:	 f20f100d1c000000     	movsd	xmm1, qword ptr [rip + 0x1c]
-;;   1c:	 660f2ec1             	ucomisd	xmm0, xmm1
-;;   20:	 b800000000           	mov	eax, 0
-;;   25:	 400f97c0             	seta	al
-;;   29:	 4883c408             	add	rsp, 8
-;;   2d:	 5d                   	pop	rbp
-;;   2e:	 c3                   	ret	
-;;   2f:	 009a99999999         	add	byte ptr [rdx - 0x66666667], bl
-;;   35:	 99                   	cdq	
-;;   36:	 01409a               	add	dword ptr [rax - 0x66], eax
-;;   39:	 99                   	cdq	
-;;   3a:	 99                   	cdq	
-;;   3b:	 99                   	cdq	
-;;   3c:	 99                   	cdq	
-;;   3d:	 99                   	cdq	
-;;   3e:	 f1                   	int1	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8234000000         	jb	0x4f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822d000000         	jb	0x51
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f20f100524000000     	movsd	xmm0, qword ptr [rip + 0x24]
+;;   34:	 f20f100d24000000     	movsd	xmm1, qword ptr [rip + 0x24]
+;;   3c:	 660f2ec1             	ucomisd	xmm0, xmm1
+;;   40:	 b800000000           	mov	eax, 0
+;;   45:	 400f97c0             	seta	al
+;;   49:	 4883c408             	add	rsp, 8
+;;   4d:	 5d                   	pop	rbp
+;;   4e:	 c3                   	ret	
+;;   4f:	 0f0b                 	ud2	
+;;   51:	 0f0b                 	ud2	
+;;   53:	 0000                 	add	byte ptr [rax], al
+;;   55:	 0000                 	add	byte ptr [rax], al
+;;   57:	 009a99999999         	add	byte ptr [rdx - 0x66666667], bl
+;;   5d:	 99                   	cdq	
+;;   5e:	 01409a               	add	dword ptr [rax - 0x66], eax
+;;   61:	 99                   	cdq	
+;;   62:	 99                   	cdq	
+;;   63:	 99                   	cdq	
+;;   64:	 99                   	cdq	
+;;   65:	 99                   	cdq	
+;;   66:	 f1                   	int1	
@@ -18,22 +18,29 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 4531db               	xor	r11d, r11d
-;;    b:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
-;;   10:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
-;;   15:	 4c893424             	mov	qword ptr [rsp], r14
-;;   19:	 f20f100537000000     	movsd	xmm0, qword ptr [rip + 0x37]
-;;   21:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
-;;   27:	 f20f100531000000     	movsd	xmm0, qword ptr [rip + 0x31]
-;;   2f:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
-;;   35:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
-;;   3b:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
-;;   41:	 660f2ec1             	ucomisd	xmm0, xmm1
-;;   45:	 b800000000           	mov	eax, 0
-;;   4a:	 400f97c0             	seta	al
-;;   4e:	 4883c418             	add	rsp, 0x18
-;;   52:	 5d                   	pop	rbp
-;;   53:	 c3                   	ret	
-;;   54:	 0000                 	add	byte ptr [rax], al
-;;   56:	 0000                 	add	byte ptr [rax], al
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8259000000         	jb	0x74
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8252000000         	jb	0x76
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 4531db               	xor	r11d, r11d
+;;   2b:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
+;;   30:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
+;;   35:	 4c893424             	mov	qword ptr [rsp], r14
+;;   39:	 f20f100537000000     	movsd	xmm0, qword ptr [rip + 0x37]
+;;   41:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
+;;   47:	 f20f100531000000     	movsd	xmm0, qword ptr [rip + 0x31]
+;;   4f:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
+;;   55:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   5b:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
+;;   61:	 660f2ec1             	ucomisd	xmm0, xmm1
+;;   65:	 b800000000           	mov	eax, 0
+;;   6a:	 400f97c0             	seta	al
+;;   6e:	 4883c418             	add	rsp, 0x18
+;;   72:	 5d                   	pop	rbp
+;;   73:	 c3                   	ret	
+;;   74:	 0f0b                 	ud2	
+;;   76:	 0f0b                 	ud2	
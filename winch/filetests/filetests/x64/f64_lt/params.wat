@@ -9,15 +9,24 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
-;;    e:	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
-;;   14:	 4c893424             	mov	qword ptr [rsp], r14
-;;   18:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
-;;   1e:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
-;;   24:	 660f2ec1             	ucomisd	xmm0, xmm1
-;;   28:	 b800000000           	mov	eax, 0
-;;   2d:	 400f97c0             	seta	al
-;;   31:	 4883c418             	add	rsp, 0x18
-;;   35:	 5d                   	pop	rbp
-;;   36:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f823c000000         	jb	0x57
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8235000000         	jb	0x59
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
+;;   2e:	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   3e:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
+;;   44:	 660f2ec1             	ucomisd	xmm0, xmm1
+;;   48:	 b800000000           	mov	eax, 0
+;;   4d:	 400f97c0             	seta	al
+;;   51:	 4883c418             	add	rsp, 0x18
+;;   55:	 5d                   	pop	rbp
+;;   56:	 c3                   	ret	
+;;   57:	 0f0b                 	ud2	
+;;   59:	 0f0b                 	ud2	
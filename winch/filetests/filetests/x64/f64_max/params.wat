@@ -9,21 +9,30 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
-;;    e:	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
-;;   14:	 4c893424             	mov	qword ptr [rsp], r14
-;;   18:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
-;;   1e:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
-;;   24:	 660f2ec8             	ucomisd	xmm1, xmm0
-;;   28:	 0f8519000000         	jne	0x47
-;;   2e:	 0f8a09000000         	jp	0x3d
-;;   34:	 660f54c8             	andpd	xmm1, xmm0
-;;   38:	 e90e000000           	jmp	0x4b
-;;   3d:	 f20f58c8             	addsd	xmm1, xmm0
-;;   41:	 0f8a04000000         	jp	0x4b
-;;   47:	 f20f5fc8             	maxsd	xmm1, xmm0
-;;   4b:	 660f28c1             	movapd	xmm0, xmm1
-;;   4f:	 4883c418             	add	rsp, 0x18
-;;   53:	 5d                   	pop	rbp
-;;   54:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f825a000000         	jb	0x75
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8253000000         	jb	0x77
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
+;;   2e:	 f20f114c2408         	movsd	qword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   3e:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
+;;   44:	 660f2ec8             	ucomisd	xmm1, xmm0
+;;   48:	 0f8519000000         	jne	0x67
+;;   4e:	 0f8a09000000         	jp	0x5d
+;;   54:	 660f54c8             	andpd	xmm1, xmm0
+;;   58:	 e90e000000           	jmp	0x6b
+;;   5d:	 f20f58c8             	addsd	xmm1, xmm0
+;;   61:	 0f8a04000000         	jp	0x6b
+;;   67:	 f20f5fc8             	maxsd	xmm1, xmm0
+;;   6b:	 660f28c1             	movapd	xmm0, xmm1
+;;   6f:	 4883c418             	add	rsp, 0x18
+;;   73:	 5d                   	pop	rbp
+;;   74:	 c3                   	ret	
+;;   75:	 0f0b                 	ud2	
+;;   77:	 0f0b                 	ud2	
@@ -9,29 +9,40 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f20f10053c000000     	movsd	xmm0, qword ptr [rip + 0x3c]
-;;   14:	 f20f100d3c000000     	movsd	xmm1, qword ptr [rip + 0x3c]
-;;   1c:	 660f2ec8             	ucomisd	xmm1, xmm0
-;;   20:	 0f8519000000         	jne	0x3f
-;;   26:	 0f8a09000000         	jp	0x35
-;;   2c:	 660f56c8             	orpd	xmm1, xmm0
-;;   30:	 e90e000000           	jmp	0x43
-;;   35:	 f20f58c8             	addsd	xmm1, xmm0
-;;   39:	 0f8a04000000         	jp	0x43
-;;   3f:	 f20f5dc8             	minsd	xmm1, xmm0
-;;   43:	 660f28c1             	movapd	xmm0, xmm1
-;;   47:	 4883c408             	add	rsp, 8
-;;   4b:	 5d                   	pop	rbp
-;;   4c:	 c3                   	ret	
-;;   4d:	 0000                 	add	byte ptr [rax], al
-;;   4f:	 009a99999999         	add	byte ptr [rdx - 0x66666667], bl
-;;   55:	 99                   	cdq	
-;;   56:	 01409a               	add	dword ptr [rax - 0x66], eax
-;;   59:	 99                   	cdq	
-;;   5a:	 99                   	cdq	
-;;   5b:	 99                   	cdq	
-;;   5c:	 99                   	cdq	
-;;   5d:	 99                   	cdq	
-;;   5e:	 f1                   	int1	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8252000000         	jb	0x6d
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f824b000000         	jb	0x6f
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f20f100544000000     	movsd	xmm0, qword ptr [rip + 0x44]
+;;   34:	 f20f100d44000000     	movsd	xmm1, qword ptr [rip + 0x44]
+;;   3c:	 660f2ec8             	ucomisd	xmm1, xmm0
+;;   40:	 0f8519000000         	jne	0x5f
+;;   46:	 0f8a09000000         	jp	0x55
+;;   4c:	 660f56c8             	orpd	xmm1, xmm0
+;;   50:	 e90e000000           	jmp	0x63
+;;   55:	 f20f58c8             	addsd	xmm1, xmm0
+;;   59:	 0f8a04000000         	jp	0x63
+;;   5f:	 f20f5dc8             	minsd	xmm1, xmm0
+;;   63:	 660f28c1             	movapd	xmm0, xmm1
+;;   67:	 4883c408             	add	rsp, 8
+;;   6b:	 5d                   	pop	rbp
+;;   6c:	 c3                   	ret	
+;;   6d:	 0f0b                 	ud2	
+;;   6f:	 0f0b                 	ud2	
+;;   71:	 0000                 	add	byte ptr [rax], al
+;;   73:	 0000                 	add	byte ptr [rax], al
+;;   75:	 0000                 	add	byte ptr [rax], al
+;;   77:	 009a99999999         	add	byte ptr [rdx - 0x66666667], bl
+;;   7d:	 99                   	cdq	
+;;   7e:	 01409a               	add	dword ptr [rax - 0x66], eax
+;;   81:	 99                   	cdq	
+;;   82:	 99                   	cdq	
+;;   83:	 99                   	cdq	
+;;   84:	 99                   	cdq	
+;;   85:	 99                   	cdq	
+;;   86:	 f1                   	int1	
@@ -18,29 +18,36 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 4531db               	xor	r11d, r11d
-;;    b:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
-;;   10:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
-;;   15:	 4c893424             	mov	qword ptr [rsp], r14
-;;   19:	 f20f100557000000     	movsd	xmm0, qword ptr [rip + 0x57]
-;;   21:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
-;;   27:	 f20f100551000000     	movsd	xmm0, qword ptr [rip + 0x51]
-;;   2f:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
-;;   35:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
-;;   3b:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
-;;   41:	 660f2ec8             	ucomisd	xmm1, xmm0
-;;   45:	 0f8519000000         	jne	0x64
-;;   4b:	 0f8a09000000         	jp	0x5a
-;;   51:	 660f56c8             	orpd	xmm1, xmm0
-;;   55:	 e90e000000           	jmp	0x68
-;;   5a:	 f20f58c8             	addsd	xmm1, xmm0
-;;   5e:	 0f8a04000000         	jp	0x68
-;;   64:	 f20f5dc8             	minsd	xmm1, xmm0
-;;   68:	 660f28c1             	movapd	xmm0, xmm1
-;;   6c:	 4883c418             	add	rsp, 0x18
-;;   70:	 5d                   	pop	rbp
-;;   71:	 c3                   	ret	
-;;   72:	 0000                 	add	byte ptr [rax], al
-;;   74:	 0000                 	add	byte ptr [rax], al
-;;   76:	 0000                 	add	byte ptr [rax], al
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8277000000         	jb	0x92
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8270000000         	jb	0x94
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 4531db               	xor	r11d, r11d
+;;   2b:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
+;;   30:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
+;;   35:	 4c893424             	mov	qword ptr [rsp], r14
+;;   39:	 f20f100557000000     	movsd	xmm0, qword ptr [rip + 0x57]
+;;   41:	 f20f11442410         	movsd	qword ptr [rsp + 0x10], xmm0
+;;   47:	 f20f100551000000     	movsd	xmm0, qword ptr [rip + 0x51]
+;;   4f:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
+;;   55:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   5b:	 f20f104c2410         	movsd	xmm1, qword ptr [rsp + 0x10]
+;;   61:	 660f2ec8             	ucomisd	xmm1, xmm0
+;;   65:	 0f8519000000         	jne	0x84
+;;   6b:	 0f8a09000000         	jp	0x7a
+;;   71:	 660f56c8             	orpd	xmm1, xmm0
+;;   75:	 e90e000000           	jmp	0x88
+;;   7a:	 f20f58c8             	addsd	xmm1, xmm0
+;;   7e:	 0f8a04000000         	jp	0x88
+;;   84:	 f20f5dc8             	minsd	xmm1, xmm0
+;;   88:	 660f28c1             	movapd	xmm0, xmm1
+;;   8c:	 4883c418             	add	rsp, 0x18
+;;   90:	 5d                   	pop	rbp
+;;   91:	 c3                   	ret	
+;;   92:	 0f0b                 	ud2	
+;;   94:	 0f0b                 	ud2	
+;;   96:	 0000                 	add	byte ptr [rax], al
@@ -8,14 +8,23 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 f20f10051c000000     	movsd	xmm0, qword ptr [rip + 0x1c]
-;;   14:	 49bb0000000000000080 	
-;; 				movabs	r11, 0x8000000000000000
-;;   1e:	 664d0f6efb           	movq	xmm15, r11
-;;   23:	 66410f57c7           	xorpd	xmm0, xmm15
-;;   28:	 4883c408             	add	rsp, 8
-;;   2c:	 5d                   	pop	rbp
-;;   2d:	 c3                   	ret	
-;;   2e:	 0000                 	add	byte ptr [rax], al
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f822d000000         	jb	0x48
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8226000000         	jb	0x4a
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 f20f10051c000000     	movsd	xmm0, qword ptr [rip + 0x1c]
+;;   34:	 f2440f103d1b000000   	
+;; 				movsd	xmm15, qword ptr [rip + 0x1b]
+;;   3d:	 66410f57c7           	xorpd	xmm0, xmm15
+;;   42:	 4883c408             	add	rsp, 8
+;;   46:	 5d                   	pop	rbp
+;;   47:	 c3                   	ret	
+;;   48:	 0f0b                 	ud2	
+;;   4a:	 0f0b                 	ud2	
+;;   4c:	 0000                 	add	byte ptr [rax], al
+;;   4e:	 0000                 	add	byte ptr [rax], al
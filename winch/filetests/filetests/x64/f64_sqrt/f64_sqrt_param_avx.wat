@@ -0,0 +1,28 @@
+;;! target = "x86_64"
+;;! flags = ["has_avx"]
+
+(module
+    (func (param f64) (result f64)
+        (local.get 0)
+        (f64.sqrt)
+    )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8227000000         	jb	0x42
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8220000000         	jb	0x44
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 c5fb11442408         	vmovsd	qword ptr [rsp + 8], xmm0
+;;   2e:	 4c893424             	mov	qword ptr [rsp], r14
+;;   32:	 c5fb10442408         	vmovsd	xmm0, qword ptr [rsp + 8]
+;;   38:	 c5fb51c0             	vsqrtsd	xmm0, xmm0, xmm0
+;;   3c:	 4883c410             	add	rsp, 0x10
+;;   40:	 5d                   	pop	rbp
+;;   41:	 c3                   	ret	
+;;   42:	 0f0b                 	ud2	
+;;   44:	 0f0b                 	ud2	
@@ -0,0 +1,35 @@
+;;! target = "x86_64"
+;;! flags = ["enable_nan_canonicalization"]
+
+(module
+    (func (param f64) (result f64)
+        (local.get 0)
+        (f64.sqrt)
+    )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8239000000         	jb	0x54
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8232000000         	jb	0x56
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 f20f11442408         	movsd	qword ptr [rsp + 8], xmm0
+;;   2e:	 4c893424             	mov	qword ptr [rsp], r14
+;;   32:	 f20f10442408         	movsd	xmm0, qword ptr [rsp + 8]
+;;   38:	 f20f51c0             	sqrtsd	xmm0, xmm0
+;;   3c:	 660f2ec0             	ucomisd	xmm0, xmm0
+;;   40:	 0f8b08000000         	jnp	0x4e
+;;   46:	 f20f10050a000000     	movsd	xmm0, qword ptr [rip + 0xa]
+;;   4e:	 4883c410             	add	rsp, 0x10
+;;   52:	 5d                   	pop	rbp
+;;   53:	 c3                   	ret	
+;;   54:	 0f0b                 	ud2	
+;;   56:	 0f0b                 	ud2	
+;;   58:	 0000                 	add	byte ptr [rax], al
+;;   5a:	 0000                 	add	byte ptr [rax], al
+;;   5c:	 0000                 	add	byte ptr [rax], al
+;;   5e:	 f8                   	clc	
@@ -8,10 +8,19 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b8ffffff7f           	mov	eax, 0x7fffffff
-;;   11:	 83c001               	add	eax, 1
-;;   14:	 4883c408             	add	rsp, 8
-;;   18:	 5d                   	pop	rbp
-;;   19:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f821f000000         	jb	0x3a
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8218000000         	jb	0x3c
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b8ffffff7f           	mov	eax, 0x7fffffff
+;;   31:	 83c001               	add	eax, 1
+;;   34:	 4883c408             	add	rsp, 8
+;;   38:	 5d                   	pop	rbp
+;;   39:	 c3                   	ret	
+;;   3a:	 0f0b                 	ud2	
+;;   3c:	 0f0b                 	ud2	
@@ -9,14 +9,21 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   18:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   1c:	 01c1                 	add	ecx, eax
-;;   1e:	 89c8                 	mov	eax, ecx
-;;   20:	 4883c410             	add	rsp, 0x10
-;;   24:	 5d                   	pop	rbp
-;;   25:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8227000000         	jb	0x42
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8220000000         	jb	0x44
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   38:	 03442408             	add	eax, dword ptr [rsp + 8]
+;;   3c:	 4883c410             	add	rsp, 0x10
+;;   40:	 5d                   	pop	rbp
+;;   41:	 c3                   	ret	
+;;   42:	 0f0b                 	ud2	
+;;   44:	 0f0b                 	ud2	
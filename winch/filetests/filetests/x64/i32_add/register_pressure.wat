@@ -0,0 +1,109 @@
+;;! target = "x86_64"
+
+(module
+  (func (param i32) (result i32)
+    (i32.add (local.get 0) (i32.const 0))
+    (i32.add (local.get 0) (i32.const 1))
+    (i32.add (local.get 0) (i32.const 2))
+    (i32.add (local.get 0) (i32.const 3))
+    (i32.add (local.get 0) (i32.const 4))
+    (i32.add (local.get 0) (i32.const 5))
+    (i32.add (local.get 0) (i32.const 6))
+    (i32.add (local.get 0) (i32.const 7))
+    (i32.add (local.get 0) (i32.const 8))
+    (i32.add (local.get 0) (i32.const 9))
+    (i32.add (local.get 0) (i32.const 10))
+    (i32.add (local.get 0) (i32.const 11))
+    (i32.add (local.get 0) (i32.const 12))
+    (i32.add (local.get 0) (i32.const 13))
+    (i32.add (local.get 0) (i32.const 14))
+    (i32.add (local.get 0) (i32.const 15))
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+    i32.add
+  )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c330000000       	add	r11, 0x30
+;;   15:	 0f82c4000000         	jb	0xdf
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f82bd000000         	jb	0xe1
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   39:	 83c101               	add	ecx, 1
+;;   3c:	 8b54240c             	mov	edx, dword ptr [rsp + 0xc]
+;;   40:	 83c202               	add	edx, 2
+;;   43:	 8b5c240c             	mov	ebx, dword ptr [rsp + 0xc]
+;;   47:	 83c303               	add	ebx, 3
+;;   4a:	 8b74240c             	mov	esi, dword ptr [rsp + 0xc]
+;;   4e:	 83c604               	add	esi, 4
+;;   51:	 8b7c240c             	mov	edi, dword ptr [rsp + 0xc]
+;;   55:	 83c705               	add	edi, 5
+;;   58:	 448b44240c           	mov	r8d, dword ptr [rsp + 0xc]
+;;   5d:	 4183c006             	add	r8d, 6
+;;   61:	 448b4c240c           	mov	r9d, dword ptr [rsp + 0xc]
+;;   66:	 4183c107             	add	r9d, 7
+;;   6a:	 448b54240c           	mov	r10d, dword ptr [rsp + 0xc]
+;;   6f:	 4183c208             	add	r10d, 8
+;;   73:	 448b64240c           	mov	r12d, dword ptr [rsp + 0xc]
+;;   78:	 4183c409             	add	r12d, 9
+;;   7c:	 448b6c240c           	mov	r13d, dword ptr [rsp + 0xc]
+;;   81:	 4183c50a             	add	r13d, 0xa
+;;   85:	 448b7c240c           	mov	r15d, dword ptr [rsp + 0xc]
+;;   8a:	 4183c70b             	add	r15d, 0xb
+;;   8e:	 50                   	push	rax
+;;   8f:	 8b442414             	mov	eax, dword ptr [rsp + 0x14]
+;;   93:	 83c00c               	add	eax, 0xc
+;;   96:	 51                   	push	rcx
+;;   97:	 8b4c241c             	mov	ecx, dword ptr [rsp + 0x1c]
+;;   9b:	 83c10d               	add	ecx, 0xd
+;;   9e:	 52                   	push	rdx
+;;   9f:	 8b542424             	mov	edx, dword ptr [rsp + 0x24]
+;;   a3:	 83c20e               	add	edx, 0xe
+;;   a6:	 53                   	push	rbx
+;;   a7:	 8b5c242c             	mov	ebx, dword ptr [rsp + 0x2c]
+;;   ab:	 83c30f               	add	ebx, 0xf
+;;   ae:	 01da                 	add	edx, ebx
+;;   b0:	 01d1                 	add	ecx, edx
+;;   b2:	 01c8                 	add	eax, ecx
+;;   b4:	 4101c7               	add	r15d, eax
+;;   b7:	 4501fd               	add	r13d, r15d
+;;   ba:	 4501ec               	add	r12d, r13d
+;;   bd:	 4501e2               	add	r10d, r12d
+;;   c0:	 4501d1               	add	r9d, r10d
+;;   c3:	 4501c8               	add	r8d, r9d
+;;   c6:	 4401c7               	add	edi, r8d
+;;   c9:	 01fe                 	add	esi, edi
+;;   cb:	 58                   	pop	rax
+;;   cc:	 01f0                 	add	eax, esi
+;;   ce:	 59                   	pop	rcx
+;;   cf:	 01c1                 	add	ecx, eax
+;;   d1:	 58                   	pop	rax
+;;   d2:	 01c8                 	add	eax, ecx
+;;   d4:	 59                   	pop	rcx
+;;   d5:	 01c1                 	add	ecx, eax
+;;   d7:	 89c8                 	mov	eax, ecx
+;;   d9:	 4883c410             	add	rsp, 0x10
+;;   dd:	 5d                   	pop	rbp
+;;   de:	 c3                   	ret	
+;;   df:	 0f0b                 	ud2	
+;;   e1:	 0f0b                 	ud2	
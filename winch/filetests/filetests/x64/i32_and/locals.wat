@@ -18,18 +18,25 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f823a000000         	jb	0x55
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8233000000         	jb	0x57
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c893424             	mov	qword ptr [rsp], r14
-;;   15:	 b801000000           	mov	eax, 1
-;;   1a:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   1e:	 b802000000           	mov	eax, 2
-;;   23:	 89442408             	mov	dword ptr [rsp + 8], eax
-;;   27:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   2b:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   2f:	 21c1                 	and	ecx, eax
-;;   31:	 89c8                 	mov	eax, ecx
-;;   33:	 4883c410             	add	rsp, 0x10
-;;   37:	 5d                   	pop	rbp
-;;   38:	 c3                   	ret	
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 b801000000           	mov	eax, 1
+;;   3a:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   3e:	 b802000000           	mov	eax, 2
+;;   43:	 89442408             	mov	dword ptr [rsp + 8], eax
+;;   47:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   4b:	 23442408             	and	eax, dword ptr [rsp + 8]
+;;   4f:	 4883c410             	add	rsp, 0x10
+;;   53:	 5d                   	pop	rbp
+;;   54:	 c3                   	ret	
+;;   55:	 0f0b                 	ud2	
+;;   57:	 0f0b                 	ud2	
@@ -9,10 +9,19 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b801000000           	mov	eax, 1
-;;   11:	 f30fbdc0             	lzcnt	eax, eax
-;;   15:	 4883c408             	add	rsp, 8
-;;   19:	 5d                   	pop	rbp
-;;   1a:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8220000000         	jb	0x3b
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8219000000         	jb	0x3d
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b801000000           	mov	eax, 1
+;;   31:	 f30fbdc0             	lzcnt	eax, eax
+;;   35:	 4883c408             	add	rsp, 8
+;;   39:	 5d                   	pop	rbp
+;;   3a:	 c3                   	ret	
+;;   3b:	 0f0b                 	ud2	
+;;   3d:	 0f0b                 	ud2	
@@ -8,15 +8,23 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b801000000           	mov	eax, 1
-;;   11:	 0fbdc0               	bsr	eax, eax
-;;   14:	 41bb00000000         	mov	r11d, 0
-;;   1a:	 410f95c3             	setne	r11b
-;;   1e:	 f7d8                 	neg	eax
-;;   20:	 83c020               	add	eax, 0x20
-;;   23:	 4429d8               	sub	eax, r11d
-;;   26:	 4883c408             	add	rsp, 8
-;;   2a:	 5d                   	pop	rbp
-;;   2b:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f822e000000         	jb	0x49
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8227000000         	jb	0x4b
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b801000000           	mov	eax, 1
+;;   31:	 0fbdc0               	bsr	eax, eax
+;;   34:	 41bbffffffff         	mov	r11d, 0xffffffff
+;;   3a:	 410f44c3             	cmove	eax, r11d
+;;   3e:	 f7d8                 	neg	eax
+;;   40:	 83c01f               	add	eax, 0x1f
+;;   43:	 4883c408             	add	rsp, 8
+;;   47:	 5d                   	pop	rbp
+;;   48:	 c3                   	ret	
+;;   49:	 0f0b                 	ud2	
+;;   4b:	 0f0b                 	ud2	
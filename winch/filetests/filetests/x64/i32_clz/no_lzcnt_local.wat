@@ -13,19 +13,27 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8240000000         	jb	0x5b
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8239000000         	jb	0x5d
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   16:	 b802000000           	mov	eax, 2
-;;   1b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   1f:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   23:	 0fbdc0               	bsr	eax, eax
-;;   26:	 41bb00000000         	mov	r11d, 0
-;;   2c:	 410f95c3             	setne	r11b
-;;   30:	 f7d8                 	neg	eax
-;;   32:	 83c020               	add	eax, 0x20
-;;   35:	 4429d8               	sub	eax, r11d
-;;   38:	 4883c410             	add	rsp, 0x10
-;;   3c:	 5d                   	pop	rbp
-;;   3d:	 c3                   	ret	
+;;   31:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   36:	 b802000000           	mov	eax, 2
+;;   3b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   3f:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   43:	 0fbdc0               	bsr	eax, eax
+;;   46:	 41bbffffffff         	mov	r11d, 0xffffffff
+;;   4c:	 410f44c3             	cmove	eax, r11d
+;;   50:	 f7d8                 	neg	eax
+;;   52:	 83c01f               	add	eax, 0x1f
+;;   55:	 4883c410             	add	rsp, 0x10
+;;   59:	 5d                   	pop	rbp
+;;   5a:	 c3                   	ret	
+;;   5b:	 0f0b                 	ud2	
+;;   5d:	 0f0b                 	ud2	
@@ -8,16 +8,24 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   15:	 0fbdc0               	bsr	eax, eax
-;;   18:	 41bb00000000         	mov	r11d, 0
-;;   1e:	 410f95c3             	setne	r11b
-;;   22:	 f7d8                 	neg	eax
-;;   24:	 83c020               	add	eax, 0x20
-;;   27:	 4429d8               	sub	eax, r11d
-;;   2a:	 4883c410             	add	rsp, 0x10
-;;   2e:	 5d                   	pop	rbp
-;;   2f:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8232000000         	jb	0x4d
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822b000000         	jb	0x4f
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 0fbdc0               	bsr	eax, eax
+;;   38:	 41bbffffffff         	mov	r11d, 0xffffffff
+;;   3e:	 410f44c3             	cmove	eax, r11d
+;;   42:	 f7d8                 	neg	eax
+;;   44:	 83c01f               	add	eax, 0x1f
+;;   47:	 4883c410             	add	rsp, 0x10
+;;   4b:	 5d                   	pop	rbp
+;;   4c:	 c3                   	ret	
+;;   4d:	 0f0b                 	ud2	
+;;   4f:	 0f0b                 	ud2	
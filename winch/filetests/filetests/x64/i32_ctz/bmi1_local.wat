@@ -15,14 +15,23 @@
 
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8232000000         	jb	0x4d
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822b000000         	jb	0x4f
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   16:	 b802000000           	mov	eax, 2
-;;   1b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   1f:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   23:	 f30fbcc0             	tzcnt	eax, eax
-;;   27:	 4883c410             	add	rsp, 0x10
-;;   2b:	 5d                   	pop	rbp
-;;   2c:	 c3                   	ret	
+;;   31:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   36:	 b802000000           	mov	eax, 2
+;;   3b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   3f:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   43:	 f30fbcc0             	tzcnt	eax, eax
+;;   47:	 4883c410             	add	rsp, 0x10
+;;   4b:	 5d                   	pop	rbp
+;;   4c:	 c3                   	ret	
+;;   4d:	 0f0b                 	ud2	
+;;   4f:	 0f0b                 	ud2	
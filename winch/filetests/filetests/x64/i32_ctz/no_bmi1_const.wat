@@ -8,14 +8,21 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b801000000           	mov	eax, 1
-;;   11:	 0fbcc0               	bsf	eax, eax
-;;   14:	 41bb00000000         	mov	r11d, 0
-;;   1a:	 410f94c3             	sete	r11b
-;;   1e:	 41c1e305             	shl	r11d, 5
-;;   22:	 4401d8               	add	eax, r11d
-;;   25:	 4883c408             	add	rsp, 8
-;;   29:	 5d                   	pop	rbp
-;;   2a:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8229000000         	jb	0x44
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8222000000         	jb	0x46
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b801000000           	mov	eax, 1
+;;   31:	 0fbcc0               	bsf	eax, eax
+;;   34:	 41bb20000000         	mov	r11d, 0x20
+;;   3a:	 410f44c3             	cmove	eax, r11d
+;;   3e:	 4883c408             	add	rsp, 8
+;;   42:	 5d                   	pop	rbp
+;;   43:	 c3                   	ret	
+;;   44:	 0f0b                 	ud2	
+;;   46:	 0f0b                 	ud2	
@@ -13,18 +13,25 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f823b000000         	jb	0x56
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8234000000         	jb	0x58
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   16:	 b802000000           	mov	eax, 2
-;;   1b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   1f:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   23:	 0fbcc0               	bsf	eax, eax
-;;   26:	 41bb00000000         	mov	r11d, 0
-;;   2c:	 410f94c3             	sete	r11b
-;;   30:	 41c1e305             	shl	r11d, 5
-;;   34:	 4401d8               	add	eax, r11d
-;;   37:	 4883c410             	add	rsp, 0x10
-;;   3b:	 5d                   	pop	rbp
-;;   3c:	 c3                   	ret	
+;;   31:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   36:	 b802000000           	mov	eax, 2
+;;   3b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   3f:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   43:	 0fbcc0               	bsf	eax, eax
+;;   46:	 41bb20000000         	mov	r11d, 0x20
+;;   4c:	 410f44c3             	cmove	eax, r11d
+;;   50:	 4883c410             	add	rsp, 0x10
+;;   54:	 5d                   	pop	rbp
+;;   55:	 c3                   	ret	
+;;   56:	 0f0b                 	ud2	
+;;   58:	 0f0b                 	ud2	
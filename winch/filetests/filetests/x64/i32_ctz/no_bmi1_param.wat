@@ -8,15 +8,22 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   15:	 0fbcc0               	bsf	eax, eax
-;;   18:	 41bb00000000         	mov	r11d, 0
-;;   1e:	 410f94c3             	sete	r11b
-;;   22:	 41c1e305             	shl	r11d, 5
-;;   26:	 4401d8               	add	eax, r11d
-;;   29:	 4883c410             	add	rsp, 0x10
-;;   2d:	 5d                   	pop	rbp
-;;   2e:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f822d000000         	jb	0x48
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8226000000         	jb	0x4a
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 0fbcc0               	bsf	eax, eax
+;;   38:	 41bb20000000         	mov	r11d, 0x20
+;;   3e:	 410f44c3             	cmove	eax, r11d
+;;   42:	 4883c410             	add	rsp, 0x10
+;;   46:	 5d                   	pop	rbp
+;;   47:	 c3                   	ret	
+;;   48:	 0f0b                 	ud2	
+;;   4a:	 0f0b                 	ud2	
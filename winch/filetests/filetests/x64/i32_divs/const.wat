@@ -9,15 +9,24 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b90a000000           	mov	ecx, 0xa
-;;   11:	 b814000000           	mov	eax, 0x14
-;;   16:	 83f900               	cmp	ecx, 0
-;;   19:	 0f8409000000         	je	0x28
-;;   1f:	 99                   	cdq	
-;;   20:	 f7f9                 	idiv	ecx
-;;   22:	 4883c408             	add	rsp, 8
-;;   26:	 5d                   	pop	rbp
-;;   27:	 c3                   	ret	
-;;   28:	 0f0b                 	ud2	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f822d000000         	jb	0x48
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8226000000         	jb	0x4a
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b90a000000           	mov	ecx, 0xa
+;;   31:	 b814000000           	mov	eax, 0x14
+;;   36:	 83f900               	cmp	ecx, 0
+;;   39:	 0f840d000000         	je	0x4c
+;;   3f:	 99                   	cdq	
+;;   40:	 f7f9                 	idiv	ecx
+;;   42:	 4883c408             	add	rsp, 8
+;;   46:	 5d                   	pop	rbp
+;;   47:	 c3                   	ret	
+;;   48:	 0f0b                 	ud2	
+;;   4a:	 0f0b                 	ud2	
+;;   4c:	 0f0b                 	ud2	
@@ -9,17 +9,26 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
-;;   18:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   1c:	 83f900               	cmp	ecx, 0
-;;   1f:	 0f8409000000         	je	0x2e
-;;   25:	 99                   	cdq	
-;;   26:	 f7f9                 	idiv	ecx
-;;   28:	 4883c410             	add	rsp, 0x10
-;;   2c:	 5d                   	pop	rbp
-;;   2d:	 c3                   	ret	
-;;   2e:	 0f0b                 	ud2	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8233000000         	jb	0x4e
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822c000000         	jb	0x50
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
+;;   38:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   3c:	 83f900               	cmp	ecx, 0
+;;   3f:	 0f840d000000         	je	0x52
+;;   45:	 99                   	cdq	
+;;   46:	 f7f9                 	idiv	ecx
+;;   48:	 4883c410             	add	rsp, 0x10
+;;   4c:	 5d                   	pop	rbp
+;;   4d:	 c3                   	ret	
+;;   4e:	 0f0b                 	ud2	
+;;   50:	 0f0b                 	ud2	
+;;   52:	 0f0b                 	ud2	
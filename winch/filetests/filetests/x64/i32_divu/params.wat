@@ -9,14 +9,23 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
-;;   18:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   1c:	 31d2                 	xor	edx, edx
-;;   1e:	 f7f1                 	div	ecx
-;;   20:	 4883c410             	add	rsp, 0x10
-;;   24:	 5d                   	pop	rbp
-;;   25:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f822b000000         	jb	0x46
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8224000000         	jb	0x48
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
+;;   38:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   3c:	 31d2                 	xor	edx, edx
+;;   3e:	 f7f1                 	div	ecx
+;;   40:	 4883c410             	add	rsp, 0x10
+;;   44:	 5d                   	pop	rbp
+;;   45:	 c3                   	ret	
+;;   46:	 0f0b                 	ud2	
+;;   48:	 0f0b                 	ud2	
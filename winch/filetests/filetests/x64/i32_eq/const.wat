@@ -9,12 +9,21 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b802000000           	mov	eax, 2
-;;   11:	 83f803               	cmp	eax, 3
-;;   14:	 b800000000           	mov	eax, 0
-;;   19:	 400f94c0             	sete	al
-;;   1d:	 4883c408             	add	rsp, 8
-;;   21:	 5d                   	pop	rbp
-;;   22:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8228000000         	jb	0x43
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8221000000         	jb	0x45
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b802000000           	mov	eax, 2
+;;   31:	 83f803               	cmp	eax, 3
+;;   34:	 b800000000           	mov	eax, 0
+;;   39:	 400f94c0             	sete	al
+;;   3d:	 4883c408             	add	rsp, 8
+;;   41:	 5d                   	pop	rbp
+;;   42:	 c3                   	ret	
+;;   43:	 0f0b                 	ud2	
+;;   45:	 0f0b                 	ud2	
@@ -17,20 +17,29 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8247000000         	jb	0x62
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8240000000         	jb	0x64
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c893424             	mov	qword ptr [rsp], r14
-;;   15:	 b802000000           	mov	eax, 2
-;;   1a:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   1e:	 b803000000           	mov	eax, 3
-;;   23:	 89442408             	mov	dword ptr [rsp + 8], eax
-;;   27:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   2b:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   2f:	 39c1                 	cmp	ecx, eax
-;;   31:	 b900000000           	mov	ecx, 0
-;;   36:	 400f94c1             	sete	cl
-;;   3a:	 89c8                 	mov	eax, ecx
-;;   3c:	 4883c410             	add	rsp, 0x10
-;;   40:	 5d                   	pop	rbp
-;;   41:	 c3                   	ret	
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 b802000000           	mov	eax, 2
+;;   3a:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   3e:	 b803000000           	mov	eax, 3
+;;   43:	 89442408             	mov	dword ptr [rsp + 8], eax
+;;   47:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   4b:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   4f:	 39c1                 	cmp	ecx, eax
+;;   51:	 b900000000           	mov	ecx, 0
+;;   56:	 400f94c1             	sete	cl
+;;   5a:	 89c8                 	mov	eax, ecx
+;;   5c:	 4883c410             	add	rsp, 0x10
+;;   60:	 5d                   	pop	rbp
+;;   61:	 c3                   	ret	
+;;   62:	 0f0b                 	ud2	
+;;   64:	 0f0b                 	ud2	
@@ -8,12 +8,21 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b801000000           	mov	eax, 1
-;;   11:	 83f800               	cmp	eax, 0
-;;   14:	 b800000000           	mov	eax, 0
-;;   19:	 400f94c0             	sete	al
-;;   1d:	 4883c408             	add	rsp, 8
-;;   21:	 5d                   	pop	rbp
-;;   22:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8227000000         	jb	0x42
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8220000000         	jb	0x44
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b801000000           	mov	eax, 1
+;;   31:	 85c0                 	test	eax, eax
+;;   33:	 b800000000           	mov	eax, 0
+;;   38:	 400f94c0             	sete	al
+;;   3c:	 4883c408             	add	rsp, 8
+;;   40:	 5d                   	pop	rbp
+;;   41:	 c3                   	ret	
+;;   42:	 0f0b                 	ud2	
+;;   44:	 0f0b                 	ud2	
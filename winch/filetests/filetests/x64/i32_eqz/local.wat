@@ -13,16 +13,25 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8239000000         	jb	0x54
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8232000000         	jb	0x56
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   16:	 b802000000           	mov	eax, 2
-;;   1b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   1f:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   23:	 83f800               	cmp	eax, 0
-;;   26:	 b800000000           	mov	eax, 0
-;;   2b:	 400f94c0             	sete	al
-;;   2f:	 4883c410             	add	rsp, 0x10
-;;   33:	 5d                   	pop	rbp
-;;   34:	 c3                   	ret	
+;;   31:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   36:	 b802000000           	mov	eax, 2
+;;   3b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   3f:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   43:	 85c0                 	test	eax, eax
+;;   45:	 b800000000           	mov	eax, 0
+;;   4a:	 400f94c0             	sete	al
+;;   4e:	 4883c410             	add	rsp, 0x10
+;;   52:	 5d                   	pop	rbp
+;;   53:	 c3                   	ret	
+;;   54:	 0f0b                 	ud2	
+;;   56:	 0f0b                 	ud2	
@@ -8,13 +8,22 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   15:	 83f800               	cmp	eax, 0
-;;   18:	 b800000000           	mov	eax, 0
-;;   1d:	 400f94c0             	sete	al
-;;   21:	 4883c410             	add	rsp, 0x10
-;;   25:	 5d                   	pop	rbp
-;;   26:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f822b000000         	jb	0x46
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8224000000         	jb	0x48
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 85c0                 	test	eax, eax
+;;   37:	 b800000000           	mov	eax, 0
+;;   3c:	 400f94c0             	sete	al
+;;   40:	 4883c410             	add	rsp, 0x10
+;;   44:	 5d                   	pop	rbp
+;;   45:	 c3                   	ret	
+;;   46:	 0f0b                 	ud2	
+;;   48:	 0f0b                 	ud2	
@@ -9,16 +9,25 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   18:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   1c:	 39c1                 	cmp	ecx, eax
-;;   1e:	 b900000000           	mov	ecx, 0
-;;   23:	 400f93c1             	setae	cl
-;;   27:	 89c8                 	mov	eax, ecx
-;;   29:	 4883c410             	add	rsp, 0x10
-;;   2d:	 5d                   	pop	rbp
-;;   2e:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8234000000         	jb	0x4f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822d000000         	jb	0x51
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   38:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   3c:	 39c1                 	cmp	ecx, eax
+;;   3e:	 b900000000           	mov	ecx, 0
+;;   43:	 400f93c1             	setae	cl
+;;   47:	 89c8                 	mov	eax, ecx
+;;   49:	 4883c410             	add	rsp, 0x10
+;;   4d:	 5d                   	pop	rbp
+;;   4e:	 c3                   	ret	
+;;   4f:	 0f0b                 	ud2	
+;;   51:	 0f0b                 	ud2	
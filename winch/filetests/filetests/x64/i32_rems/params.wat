@@ -9,19 +9,28 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
-;;   18:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   1c:	 99                   	cdq	
-;;   1d:	 83f9ff               	cmp	ecx, -1
-;;   20:	 0f850a000000         	jne	0x30
-;;   26:	 ba00000000           	mov	edx, 0
-;;   2b:	 e902000000           	jmp	0x32
-;;   30:	 f7f9                 	idiv	ecx
-;;   32:	 89d0                 	mov	eax, edx
-;;   34:	 4883c410             	add	rsp, 0x10
-;;   38:	 5d                   	pop	rbp
-;;   39:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f823f000000         	jb	0x5a
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8238000000         	jb	0x5c
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
+;;   38:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   3c:	 99                   	cdq	
+;;   3d:	 83f9ff               	cmp	ecx, -1
+;;   40:	 0f850a000000         	jne	0x50
+;;   46:	 ba00000000           	mov	edx, 0
+;;   4b:	 e902000000           	jmp	0x52
+;;   50:	 f7f9                 	idiv	ecx
+;;   52:	 89d0                 	mov	eax, edx
+;;   54:	 4883c410             	add	rsp, 0x10
+;;   58:	 5d                   	pop	rbp
+;;   59:	 c3                   	ret	
+;;   5a:	 0f0b                 	ud2	
+;;   5c:	 0f0b                 	ud2	
@@ -9,17 +9,26 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b900000000           	mov	ecx, 0
-;;   11:	 b800000000           	mov	eax, 0
-;;   16:	 99                   	cdq	
-;;   17:	 83f9ff               	cmp	ecx, -1
-;;   1a:	 0f850a000000         	jne	0x2a
-;;   20:	 ba00000000           	mov	edx, 0
-;;   25:	 e902000000           	jmp	0x2c
-;;   2a:	 f7f9                 	idiv	ecx
-;;   2c:	 89d0                 	mov	eax, edx
-;;   2e:	 4883c408             	add	rsp, 8
-;;   32:	 5d                   	pop	rbp
-;;   33:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8239000000         	jb	0x54
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8232000000         	jb	0x56
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b900000000           	mov	ecx, 0
+;;   31:	 b800000000           	mov	eax, 0
+;;   36:	 99                   	cdq	
+;;   37:	 83f9ff               	cmp	ecx, -1
+;;   3a:	 0f850a000000         	jne	0x4a
+;;   40:	 ba00000000           	mov	edx, 0
+;;   45:	 e902000000           	jmp	0x4c
+;;   4a:	 f7f9                 	idiv	ecx
+;;   4c:	 89d0                 	mov	eax, edx
+;;   4e:	 4883c408             	add	rsp, 8
+;;   52:	 5d                   	pop	rbp
+;;   53:	 c3                   	ret	
+;;   54:	 0f0b                 	ud2	
+;;   56:	 0f0b                 	ud2	
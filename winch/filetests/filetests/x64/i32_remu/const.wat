@@ -9,13 +9,22 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b905000000           	mov	ecx, 5
-;;   11:	 b807000000           	mov	eax, 7
-;;   16:	 31d2                 	xor	edx, edx
-;;   18:	 f7f1                 	div	ecx
-;;   1a:	 89d0                 	mov	eax, edx
-;;   1c:	 4883c408             	add	rsp, 8
-;;   20:	 5d                   	pop	rbp
-;;   21:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8227000000         	jb	0x42
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8220000000         	jb	0x44
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b905000000           	mov	ecx, 5
+;;   31:	 b807000000           	mov	eax, 7
+;;   36:	 31d2                 	xor	edx, edx
+;;   38:	 f7f1                 	div	ecx
+;;   3a:	 89d0                 	mov	eax, edx
+;;   3c:	 4883c408             	add	rsp, 8
+;;   40:	 5d                   	pop	rbp
+;;   41:	 c3                   	ret	
+;;   42:	 0f0b                 	ud2	
+;;   44:	 0f0b                 	ud2	
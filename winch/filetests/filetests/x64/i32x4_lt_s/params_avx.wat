@@ -0,0 +1,32 @@
+;;! target = "x86_64"
+;;! flags = ["has_ssse3", "has_sse41", "has_avx"]
+
+(module
+    (func (param v128 v128) (result v128)
+        (local.get 0)
+        (local.get 1)
+        (i32x4.lt_s)
+    )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c328000000       	add	r11, 0x28
+;;   15:	 0f8237000000         	jb	0x52
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8230000000         	jb	0x54
+;;   24:	 4883ec28             	sub	rsp, 0x28
+;;   28:	 c5fa7f442418         	vmovdqu	xmmword ptr [rsp + 0x18], xmm0
+;;   2e:	 c5fa7f4c2408         	vmovdqu	xmmword ptr [rsp + 8], xmm1
+;;   34:	 4c893424             	mov	qword ptr [rsp], r14
+;;   38:	 c5fa6f442408         	vmovdqu	xmm0, xmmword ptr [rsp + 8]
+;;   3e:	 c5fa6f4c2418         	vmovdqu	xmm1, xmmword ptr [rsp + 0x18]
+;;   44:	 c5f966c9             	vpcmpgtd	xmm1, xmm0, xmm1
+;;   48:	 660f6fc1             	movdqa	xmm0, xmm1
+;;   4c:	 4883c428             	add	rsp, 0x28
+;;   50:	 5d                   	pop	rbp
+;;   51:	 c3                   	ret	
+;;   52:	 0f0b                 	ud2	
+;;   54:	 0f0b                 	ud2	
@@ -8,12 +8,21 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 48c7c001000000       	mov	rax, 1
-;;   13:	 49bbffffffffffffff7f 	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f822b000000         	jb	0x46
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8224000000         	jb	0x48
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 48c7c001000000       	mov	rax, 1
+;;   33:	 49bbffffffffffffff7f 	
 ;; 				movabs	r11, 0x7fffffffffffffff
-;;   1d:	 4c01d8               	add	rax, r11
-;;   20:	 4883c408             	add	rsp, 8
-;;   24:	 5d                   	pop	rbp
-;;   25:	 c3                   	ret	
+;;   3d:	 4c01d8               	add	rax, r11
+;;   40:	 4883c408             	add	rsp, 8
+;;   44:	 5d                   	pop	rbp
+;;   45:	 c3                   	ret	
+;;   46:	 0f0b                 	ud2	
+;;   48:	 0f0b                 	ud2	
@@ -9,14 +9,21 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 48897c2410           	mov	qword ptr [rsp + 0x10], rdi
-;;    d:	 4889742408           	mov	qword ptr [rsp + 8], rsi
-;;   12:	 4c893424             	mov	qword ptr [rsp], r14
-;;   16:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   1b:	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
-;;   20:	 4801c1               	add	rcx, rax
-;;   23:	 4889c8               	mov	rax, rcx
-;;   26:	 4883c418             	add	rsp, 0x18
-;;   2a:	 5d                   	pop	rbp
-;;   2b:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f822b000000         	jb	0x46
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8224000000         	jb	0x48
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 4889542410           	mov	qword ptr [rsp + 0x10], rdx
+;;   2d:	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;   32:	 4c893424             	mov	qword ptr [rsp], r14
+;;   36:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
+;;   3b:	 4803442408           	add	rax, qword ptr [rsp + 8]
+;;   40:	 4883c418             	add	rsp, 0x18
+;;   44:	 5d                   	pop	rbp
+;;   45:	 c3                   	ret	
+;;   46:	 0f0b                 	ud2	
+;;   48:	 0f0b                 	ud2	
@@ -9,10 +9,19 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 48c7c0ffffffff       	mov	rax, 0xffffffffffffffff
-;;   13:	 4883c0ff             	add	rax, -1
-;;   17:	 4883c408             	add	rsp, 8
-;;   1b:	 5d                   	pop	rbp
-;;   1c:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8222000000         	jb	0x3d
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f821b000000         	jb	0x3f
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 48c7c0ffffffff       	mov	rax, 0xffffffffffffffff
+;;   33:	 4883c0ff             	add	rax, -1
+;;   37:	 4883c408             	add	rsp, 8
+;;   3b:	 5d                   	pop	rbp
+;;   3c:	 c3                   	ret	
+;;   3d:	 0f0b                 	ud2	
+;;   3f:	 0f0b                 	ud2	
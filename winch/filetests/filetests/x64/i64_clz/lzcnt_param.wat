@@ -9,11 +9,20 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48897c2408           	mov	qword ptr [rsp + 8], rdi
-;;    d:	 4c893424             	mov	qword ptr [rsp], r14
-;;   11:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   16:	 f3480fbdc0           	lzcnt	rax, rax
-;;   1b:	 4883c410             	add	rsp, 0x10
-;;   1f:	 5d                   	pop	rbp
-;;   20:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8226000000         	jb	0x41
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f821f000000         	jb	0x43
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 4889542408           	mov	qword ptr [rsp + 8], rdx
+;;   2d:	 4c893424             	mov	qword ptr [rsp], r14
+;;   31:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   36:	 f3480fbdc0           	lzcnt	rax, rax
+;;   3b:	 4883c410             	add	rsp, 0x10
+;;   3f:	 5d                   	pop	rbp
+;;   40:	 c3                   	ret	
+;;   41:	 0f0b                 	ud2	
+;;   43:	 0f0b                 	ud2	
@@ -8,15 +8,23 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 48c7c001000000       	mov	rax, 1
-;;   13:	 480fbdc0             	bsr	rax, rax
-;;   17:	 41bb00000000         	mov	r11d, 0
-;;   1d:	 410f95c3             	setne	r11b
-;;   21:	 48f7d8               	neg	rax
-;;   24:	 4883c040             	add	rax, 0x40
-;;   28:	 4c29d8               	sub	rax, r11
-;;   2b:	 4883c408             	add	rsp, 8
-;;   2f:	 5d                   	pop	rbp
-;;   30:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8234000000         	jb	0x4f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822d000000         	jb	0x51
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 48c7c001000000       	mov	rax, 1
+;;   33:	 480fbdc0             	bsr	rax, rax
+;;   37:	 49c7c3ffffffff       	mov	r11, 0xffffffffffffffff
+;;   3e:	 490f44c3             	cmove	rax, r11
+;;   42:	 48f7d8               	neg	rax
+;;   45:	 4883c03f             	add	rax, 0x3f
+;;   49:	 4883c408             	add	rsp, 8
+;;   4d:	 5d                   	pop	rbp
+;;   4e:	 c3                   	ret	
+;;   4f:	 0f0b                 	ud2	
+;;   51:	 0f0b                 	ud2	
@@ -13,19 +13,27 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8247000000         	jb	0x62
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8240000000         	jb	0x64
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c893424             	mov	qword ptr [rsp], r14
-;;   15:	 48c7c002000000       	mov	rax, 2
-;;   1c:	 4889442408           	mov	qword ptr [rsp + 8], rax
-;;   21:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   26:	 480fbdc0             	bsr	rax, rax
-;;   2a:	 41bb00000000         	mov	r11d, 0
-;;   30:	 410f95c3             	setne	r11b
-;;   34:	 48f7d8               	neg	rax
-;;   37:	 4883c040             	add	rax, 0x40
-;;   3b:	 4c29d8               	sub	rax, r11
-;;   3e:	 4883c410             	add	rsp, 0x10
-;;   42:	 5d                   	pop	rbp
-;;   43:	 c3                   	ret	
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 48c7c002000000       	mov	rax, 2
+;;   3c:	 4889442408           	mov	qword ptr [rsp + 8], rax
+;;   41:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   46:	 480fbdc0             	bsr	rax, rax
+;;   4a:	 49c7c3ffffffff       	mov	r11, 0xffffffffffffffff
+;;   51:	 490f44c3             	cmove	rax, r11
+;;   55:	 48f7d8               	neg	rax
+;;   58:	 4883c03f             	add	rax, 0x3f
+;;   5c:	 4883c410             	add	rsp, 0x10
+;;   60:	 5d                   	pop	rbp
+;;   61:	 c3                   	ret	
+;;   62:	 0f0b                 	ud2	
+;;   64:	 0f0b                 	ud2	
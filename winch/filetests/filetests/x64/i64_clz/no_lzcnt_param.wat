@@ -8,16 +8,24 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48897c2408           	mov	qword ptr [rsp + 8], rdi
-;;    d:	 4c893424             	mov	qword ptr [rsp], r14
-;;   11:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   16:	 480fbdc0             	bsr	rax, rax
-;;   1a:	 41bb00000000         	mov	r11d, 0
-;;   20:	 410f95c3             	setne	r11b
-;;   24:	 48f7d8               	neg	rax
-;;   27:	 4883c040             	add	rax, 0x40
-;;   2b:	 4c29d8               	sub	rax, r11
-;;   2e:	 4883c410             	add	rsp, 0x10
-;;   32:	 5d                   	pop	rbp
-;;   33:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8237000000         	jb	0x52
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8230000000         	jb	0x54
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 4889542408           	mov	qword ptr [rsp + 8], rdx
+;;   2d:	 4c893424             	mov	qword ptr [rsp], r14
+;;   31:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   36:	 480fbdc0             	bsr	rax, rax
+;;   3a:	 49c7c3ffffffff       	mov	r11, 0xffffffffffffffff
+;;   41:	 490f44c3             	cmove	rax, r11
+;;   45:	 48f7d8               	neg	rax
+;;   48:	 4883c03f             	add	rax, 0x3f
+;;   4c:	 4883c410             	add	rsp, 0x10
+;;   50:	 5d                   	pop	rbp
+;;   51:	 c3                   	ret	
+;;   52:	 0f0b                 	ud2	
+;;   54:	 0f0b                 	ud2	
@@ -13,18 +13,25 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8240000000         	jb	0x5b
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8239000000         	jb	0x5d
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c893424             	mov	qword ptr [rsp], r14
-;;   15:	 48c7c002000000       	mov	rax, 2
-;;   1c:	 4889442408           	mov	qword ptr [rsp + 8], rax
-;;   21:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   26:	 480fbcc0             	bsf	rax, rax
-;;   2a:	 41bb00000000         	mov	r11d, 0
-;;   30:	 410f94c3             	sete	r11b
-;;   34:	 49c1e306             	shl	r11, 6
-;;   38:	 4c01d8               	add	rax, r11
-;;   3b:	 4883c410             	add	rsp, 0x10
-;;   3f:	 5d                   	pop	rbp
-;;   40:	 c3                   	ret	
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 48c7c002000000       	mov	rax, 2
+;;   3c:	 4889442408           	mov	qword ptr [rsp + 8], rax
+;;   41:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   46:	 480fbcc0             	bsf	rax, rax
+;;   4a:	 49c7c340000000       	mov	r11, 0x40
+;;   51:	 490f44c3             	cmove	rax, r11
+;;   55:	 4883c410             	add	rsp, 0x10
+;;   59:	 5d                   	pop	rbp
+;;   5a:	 c3                   	ret	
+;;   5b:	 0f0b                 	ud2	
+;;   5d:	 0f0b                 	ud2	
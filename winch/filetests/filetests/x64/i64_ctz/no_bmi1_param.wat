@@ -8,15 +8,22 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48897c2408           	mov	qword ptr [rsp + 8], rdi
-;;    d:	 4c893424             	mov	qword ptr [rsp], r14
-;;   11:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   16:	 480fbcc0             	bsf	rax, rax
-;;   1a:	 41bb00000000         	mov	r11d, 0
-;;   20:	 410f94c3             	sete	r11b
-;;   24:	 49c1e306             	shl	r11, 6
-;;   28:	 4c01d8               	add	rax, r11
-;;   2b:	 4883c410             	add	rsp, 0x10
-;;   2f:	 5d                   	pop	rbp
-;;   30:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8230000000         	jb	0x4b
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8229000000         	jb	0x4d
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 4889542408           	mov	qword ptr [rsp + 8], rdx
+;;   2d:	 4c893424             	mov	qword ptr [rsp], r14
+;;   31:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   36:	 480fbcc0             	bsf	rax, rax
+;;   3a:	 49c7c340000000       	mov	r11, 0x40
+;;   41:	 490f44c3             	cmove	rax, r11
+;;   45:	 4883c410             	add	rsp, 0x10
+;;   49:	 5d                   	pop	rbp
+;;   4a:	 c3                   	ret	
+;;   4b:	 0f0b                 	ud2	
+;;   4d:	 0f0b                 	ud2	
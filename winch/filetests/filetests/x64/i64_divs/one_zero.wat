@@ -9,15 +9,24 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 48c7c100000000       	mov	rcx, 0
-;;   13:	 48c7c001000000       	mov	rax, 1
-;;   1a:	 4883f900             	cmp	rcx, 0
-;;   1e:	 0f840b000000         	je	0x2f
-;;   24:	 4899                 	cqo	
-;;   26:	 48f7f9               	idiv	rcx
-;;   29:	 4883c408             	add	rsp, 8
-;;   2d:	 5d                   	pop	rbp
-;;   2e:	 c3                   	ret	
-;;   2f:	 0f0b                 	ud2	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8234000000         	jb	0x4f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822d000000         	jb	0x51
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 48c7c100000000       	mov	rcx, 0
+;;   33:	 48c7c001000000       	mov	rax, 1
+;;   3a:	 4883f900             	cmp	rcx, 0
+;;   3e:	 0f840f000000         	je	0x53
+;;   44:	 4899                 	cqo	
+;;   46:	 48f7f9               	idiv	rcx
+;;   49:	 4883c408             	add	rsp, 8
+;;   4d:	 5d                   	pop	rbp
+;;   4e:	 c3                   	ret	
+;;   4f:	 0f0b                 	ud2	
+;;   51:	 0f0b                 	ud2	
+;;   53:	 0f0b                 	ud2	
@@ -9,16 +9,25 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 48c7c1ffffffff       	mov	rcx, 0xffffffffffffffff
-;;   13:	 48b80000000000000080 	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8237000000         	jb	0x52
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8230000000         	jb	0x54
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 48c7c1ffffffff       	mov	rcx, 0xffffffffffffffff
+;;   33:	 48b80000000000000080 	
 ;; 				movabs	rax, 0x8000000000000000
-;;   1d:	 4883f900             	cmp	rcx, 0
-;;   21:	 0f840b000000         	je	0x32
-;;   27:	 4899                 	cqo	
-;;   29:	 48f7f9               	idiv	rcx
-;;   2c:	 4883c408             	add	rsp, 8
-;;   30:	 5d                   	pop	rbp
-;;   31:	 c3                   	ret	
-;;   32:	 0f0b                 	ud2	
+;;   3d:	 4883f900             	cmp	rcx, 0
+;;   41:	 0f840f000000         	je	0x56
+;;   47:	 4899                 	cqo	
+;;   49:	 48f7f9               	idiv	rcx
+;;   4c:	 4883c408             	add	rsp, 8
+;;   50:	 5d                   	pop	rbp
+;;   51:	 c3                   	ret	
+;;   52:	 0f0b                 	ud2	
+;;   54:	 0f0b                 	ud2	
+;;   56:	 0f0b                 	ud2	
@@ -9,17 +9,26 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 48897c2410           	mov	qword ptr [rsp + 0x10], rdi
-;;    d:	 4889742408           	mov	qword ptr [rsp + 8], rsi
-;;   12:	 4c893424             	mov	qword ptr [rsp], r14
-;;   16:	 488b4c2408           	mov	rcx, qword ptr [rsp + 8]
-;;   1b:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
-;;   20:	 4883f900             	cmp	rcx, 0
-;;   24:	 0f840b000000         	je	0x35
-;;   2a:	 4899                 	cqo	
-;;   2c:	 48f7f9               	idiv	rcx
-;;   2f:	 4883c418             	add	rsp, 0x18
-;;   33:	 5d                   	pop	rbp
-;;   34:	 c3                   	ret	
-;;   35:	 0f0b                 	ud2	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f823a000000         	jb	0x55
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8233000000         	jb	0x57
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 4889542410           	mov	qword ptr [rsp + 0x10], rdx
+;;   2d:	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;   32:	 4c893424             	mov	qword ptr [rsp], r14
+;;   36:	 488b4c2408           	mov	rcx, qword ptr [rsp + 8]
+;;   3b:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
+;;   40:	 4883f900             	cmp	rcx, 0
+;;   44:	 0f840f000000         	je	0x59
+;;   4a:	 4899                 	cqo	
+;;   4c:	 48f7f9               	idiv	rcx
+;;   4f:	 4883c418             	add	rsp, 0x18
+;;   53:	 5d                   	pop	rbp
+;;   54:	 c3                   	ret	
+;;   55:	 0f0b                 	ud2	
+;;   57:	 0f0b                 	ud2	
+;;   59:	 0f0b                 	ud2	
@@ -9,14 +9,23 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 48897c2410           	mov	qword ptr [rsp + 0x10], rdi
-;;    d:	 4889742408           	mov	qword ptr [rsp + 8], rsi
-;;   12:	 4c893424             	mov	qword ptr [rsp], r14
-;;   16:	 488b4c2408           	mov	rcx, qword ptr [rsp + 8]
-;;   1b:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
-;;   20:	 4831d2               	xor	rdx, rdx
-;;   23:	 48f7f1               	div	rcx
-;;   26:	 4883c418             	add	rsp, 0x18
-;;   2a:	 5d                   	pop	rbp
-;;   2b:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8231000000         	jb	0x4c
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822a000000         	jb	0x4e
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 4889542410           	mov	qword ptr [rsp + 0x10], rdx
+;;   2d:	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;   32:	 4c893424             	mov	qword ptr [rsp], r14
+;;   36:	 488b4c2408           	mov	rcx, qword ptr [rsp + 8]
+;;   3b:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
+;;   40:	 4831d2               	xor	rdx, rdx
+;;   43:	 48f7f1               	div	rcx
+;;   46:	 4883c418             	add	rsp, 0x18
+;;   4a:	 5d                   	pop	rbp
+;;   4b:	 c3                   	ret	
+;;   4c:	 0f0b                 	ud2	
+;;   4e:	 0f0b                 	ud2	
@@ -9,12 +9,21 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 48c7c1ffffffff       	mov	rcx, 0xffffffffffffffff
-;;   13:	 48c7c0ffffffff       	mov	rax, 0xffffffffffffffff
-;;   1a:	 4831d2               	xor	rdx, rdx
-;;   1d:	 48f7f1               	div	rcx
-;;   20:	 4883c408             	add	rsp, 8
-;;   24:	 5d                   	pop	rbp
-;;   25:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f822b000000         	jb	0x46
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8224000000         	jb	0x48
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 48c7c1ffffffff       	mov	rcx, 0xffffffffffffffff
+;;   33:	 48c7c0ffffffff       	mov	rax, 0xffffffffffffffff
+;;   3a:	 4831d2               	xor	rdx, rdx
+;;   3d:	 48f7f1               	div	rcx
+;;   40:	 4883c408             	add	rsp, 8
+;;   44:	 5d                   	pop	rbp
+;;   45:	 c3                   	ret	
+;;   46:	 0f0b                 	ud2	
+;;   48:	 0f0b                 	ud2	
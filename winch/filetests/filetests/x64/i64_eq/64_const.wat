@@ -9,15 +9,24 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 48b8feffffffffffff7f 	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8237000000         	jb	0x52
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8230000000         	jb	0x54
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 48b8feffffffffffff7f 	
 ;; 				movabs	rax, 0x7ffffffffffffffe
-;;   16:	 49bbffffffffffffff7f 	
+;;   36:	 49bbffffffffffffff7f 	
 ;; 				movabs	r11, 0x7fffffffffffffff
-;;   20:	 4c39d8               	cmp	rax, r11
-;;   23:	 b800000000           	mov	eax, 0
-;;   28:	 400f94c0             	sete	al
-;;   2c:	 4883c408             	add	rsp, 8
-;;   30:	 5d                   	pop	rbp
-;;   31:	 c3                   	ret	
+;;   40:	 4c39d8               	cmp	rax, r11
+;;   43:	 b800000000           	mov	eax, 0
+;;   48:	 400f94c0             	sete	al
+;;   4c:	 4883c408             	add	rsp, 8
+;;   50:	 5d                   	pop	rbp
+;;   51:	 c3                   	ret	
+;;   52:	 0f0b                 	ud2	
+;;   54:	 0f0b                 	ud2	
@@ -9,16 +9,25 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 48897c2410           	mov	qword ptr [rsp + 0x10], rdi
-;;    d:	 4889742408           	mov	qword ptr [rsp + 8], rsi
-;;   12:	 4c893424             	mov	qword ptr [rsp], r14
-;;   16:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   1b:	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
-;;   20:	 4839c1               	cmp	rcx, rax
-;;   23:	 b900000000           	mov	ecx, 0
-;;   28:	 400f94c1             	sete	cl
-;;   2c:	 4889c8               	mov	rax, rcx
-;;   2f:	 4883c418             	add	rsp, 0x18
-;;   33:	 5d                   	pop	rbp
-;;   34:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f823a000000         	jb	0x55
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8233000000         	jb	0x57
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 4889542410           	mov	qword ptr [rsp + 0x10], rdx
+;;   2d:	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;   32:	 4c893424             	mov	qword ptr [rsp], r14
+;;   36:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   3b:	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
+;;   40:	 4839c1               	cmp	rcx, rax
+;;   43:	 b900000000           	mov	ecx, 0
+;;   48:	 400f94c1             	sete	cl
+;;   4c:	 4889c8               	mov	rax, rcx
+;;   4f:	 4883c418             	add	rsp, 0x18
+;;   53:	 5d                   	pop	rbp
+;;   54:	 c3                   	ret	
+;;   55:	 0f0b                 	ud2	
+;;   57:	 0f0b                 	ud2	
@@ -13,16 +13,25 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f823d000000         	jb	0x58
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8236000000         	jb	0x5a
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c893424             	mov	qword ptr [rsp], r14
-;;   15:	 48c7c002000000       	mov	rax, 2
-;;   1c:	 4889442408           	mov	qword ptr [rsp + 8], rax
-;;   21:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   26:	 4883f800             	cmp	rax, 0
-;;   2a:	 b800000000           	mov	eax, 0
-;;   2f:	 400f94c0             	sete	al
-;;   33:	 4883c410             	add	rsp, 0x10
-;;   37:	 5d                   	pop	rbp
-;;   38:	 c3                   	ret	
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 48c7c002000000       	mov	rax, 2
+;;   3c:	 4889442408           	mov	qword ptr [rsp + 8], rax
+;;   41:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   46:	 4885c0               	test	rax, rax
+;;   49:	 b800000000           	mov	eax, 0
+;;   4e:	 400f94c0             	sete	al
+;;   52:	 4883c410             	add	rsp, 0x10
+;;   56:	 5d                   	pop	rbp
+;;   57:	 c3                   	ret	
+;;   58:	 0f0b                 	ud2	
+;;   5a:	 0f0b                 	ud2	
@@ -8,13 +8,22 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48897c2408           	mov	qword ptr [rsp + 8], rdi
-;;    d:	 4c893424             	mov	qword ptr [rsp], r14
-;;   11:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   16:	 4883f800             	cmp	rax, 0
-;;   1a:	 b800000000           	mov	eax, 0
-;;   1f:	 400f94c0             	sete	al
-;;   23:	 4883c410             	add	rsp, 0x10
-;;   27:	 5d                   	pop	rbp
-;;   28:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f822d000000         	jb	0x48
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8226000000         	jb	0x4a
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 4889542408           	mov	qword ptr [rsp + 8], rdx
+;;   2d:	 4c893424             	mov	qword ptr [rsp], r14
+;;   31:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   36:	 4885c0               	test	rax, rax
+;;   39:	 b800000000           	mov	eax, 0
+;;   3e:	 400f94c0             	sete	al
+;;   42:	 4883c410             	add	rsp, 0x10
+;;   46:	 5d                   	pop	rbp
+;;   47:	 c3                   	ret	
+;;   48:	 0f0b                 	ud2	
+;;   4a:	 0f0b                 	ud2	
@@ -18,19 +18,28 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 4531db               	xor	r11d, r11d
-;;    b:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
-;;   10:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
-;;   15:	 4c893424             	mov	qword ptr [rsp], r14
-;;   19:	 48c7c00a000000       	mov	rax, 0xa
-;;   20:	 4889442410           	mov	qword ptr [rsp + 0x10], rax
-;;   25:	 48c7c014000000       	mov	rax, 0x14
-;;   2c:	 4889442408           	mov	qword ptr [rsp + 8], rax
-;;   31:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   36:	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
-;;   3b:	 480fafc8             	imul	rcx, rax
-;;   3f:	 4889c8               	mov	rax, rcx
-;;   42:	 4883c418             	add	rsp, 0x18
-;;   46:	 5d                   	pop	rbp
-;;   47:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f824d000000         	jb	0x68
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8246000000         	jb	0x6a
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 4531db               	xor	r11d, r11d
+;;   2b:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
+;;   30:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
+;;   35:	 4c893424             	mov	qword ptr [rsp], r14
+;;   39:	 48c7c00a000000       	mov	rax, 0xa
+;;   40:	 4889442410           	mov	qword ptr [rsp + 0x10], rax
+;;   45:	 48c7c014000000       	mov	rax, 0x14
+;;   4c:	 4889442408           	mov	qword ptr [rsp + 8], rax
+;;   51:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   56:	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
+;;   5b:	 480fafc8             	imul	rcx, rax
+;;   5f:	 4889c8               	mov	rax, rcx
+;;   62:	 4883c418             	add	rsp, 0x18
+;;   66:	 5d                   	pop	rbp
+;;   67:	 c3                   	ret	
+;;   68:	 0f0b                 	ud2	
+;;   6a:	 0f0b                 	ud2	
@@ -8,32 +8,41 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 48c7c00f000000       	mov	rax, 0xf
-;;   13:	 4889c1               	mov	rcx, rax
-;;   16:	 48c1e801             	shr	rax, 1
-;;   1a:	 49bb5555555555555555 	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8278000000         	jb	0x93
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8271000000         	jb	0x95
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 48c7c00f000000       	mov	rax, 0xf
+;;   33:	 4889c1               	mov	rcx, rax
+;;   36:	 48c1e801             	shr	rax, 1
+;;   3a:	 49bb5555555555555555 	
 ;; 				movabs	r11, 0x5555555555555555
-;;   24:	 4c21d8               	and	rax, r11
-;;   27:	 4829c1               	sub	rcx, rax
-;;   2a:	 4889c8               	mov	rax, rcx
-;;   2d:	 49bb3333333333333333 	
+;;   44:	 4c21d8               	and	rax, r11
+;;   47:	 4829c1               	sub	rcx, rax
+;;   4a:	 4889c8               	mov	rax, rcx
+;;   4d:	 49bb3333333333333333 	
 ;; 				movabs	r11, 0x3333333333333333
-;;   37:	 4c21d8               	and	rax, r11
-;;   3a:	 48c1e902             	shr	rcx, 2
-;;   3e:	 4c21d9               	and	rcx, r11
-;;   41:	 4801c1               	add	rcx, rax
-;;   44:	 4889c8               	mov	rax, rcx
-;;   47:	 48c1e804             	shr	rax, 4
-;;   4b:	 4801c8               	add	rax, rcx
-;;   4e:	 49bb0f0f0f0f0f0f0f0f 	
+;;   57:	 4c21d8               	and	rax, r11
+;;   5a:	 48c1e902             	shr	rcx, 2
+;;   5e:	 4c21d9               	and	rcx, r11
+;;   61:	 4801c1               	add	rcx, rax
+;;   64:	 4889c8               	mov	rax, rcx
+;;   67:	 48c1e804             	shr	rax, 4
+;;   6b:	 4801c8               	add	rax, rcx
+;;   6e:	 49bb0f0f0f0f0f0f0f0f 	
 ;; 				movabs	r11, 0xf0f0f0f0f0f0f0f
-;;   58:	 4c21d8               	and	rax, r11
-;;   5b:	 49bb0101010101010101 	
+;;   78:	 4c21d8               	and	rax, r11
+;;   7b:	 49bb0101010101010101 	
 ;; 				movabs	r11, 0x101010101010101
-;;   65:	 490fafc3             	imul	rax, r11
-;;   69:	 48c1e838             	shr	rax, 0x38
-;;   6d:	 4883c408             	add	rsp, 8
-;;   71:	 5d                   	pop	rbp
-;;   72:	 c3                   	ret	
+;;   85:	 490fafc3             	imul	rax, r11
+;;   89:	 48c1e838             	shr	rax, 0x38
+;;   8d:	 4883c408             	add	rsp, 8
+;;   91:	 5d                   	pop	rbp
+;;   92:	 c3                   	ret	
+;;   93:	 0f0b                 	ud2	
+;;   95:	 0f0b                 	ud2	
@@ -9,17 +9,26 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 48c7c105000000       	mov	rcx, 5
-;;   13:	 48c7c007000000       	mov	rax, 7
-;;   1a:	 4899                 	cqo	
-;;   1c:	 4883f9ff             	cmp	rcx, -1
-;;   20:	 0f850a000000         	jne	0x30
-;;   26:	 ba00000000           	mov	edx, 0
-;;   2b:	 e903000000           	jmp	0x33
-;;   30:	 48f7f9               	idiv	rcx
-;;   33:	 4889d0               	mov	rax, rdx
-;;   36:	 4883c408             	add	rsp, 8
-;;   3a:	 5d                   	pop	rbp
-;;   3b:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8241000000         	jb	0x5c
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f823a000000         	jb	0x5e
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 48c7c105000000       	mov	rcx, 5
+;;   33:	 48c7c007000000       	mov	rax, 7
+;;   3a:	 4899                 	cqo	
+;;   3c:	 4883f9ff             	cmp	rcx, -1
+;;   40:	 0f850a000000         	jne	0x50
+;;   46:	 ba00000000           	mov	edx, 0
+;;   4b:	 e903000000           	jmp	0x53
+;;   50:	 48f7f9               	idiv	rcx
+;;   53:	 4889d0               	mov	rax, rdx
+;;   56:	 4883c408             	add	rsp, 8
+;;   5a:	 5d                   	pop	rbp
+;;   5b:	 c3                   	ret	
+;;   5c:	 0f0b                 	ud2	
+;;   5e:	 0f0b                 	ud2	
@@ -9,18 +9,27 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 48c7c1ffffffff       	mov	rcx, 0xffffffffffffffff
-;;   13:	 48b80000000000000080 	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8244000000         	jb	0x5f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f823d000000         	jb	0x61
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 48c7c1ffffffff       	mov	rcx, 0xffffffffffffffff
+;;   33:	 48b80000000000000080 	
 ;; 				movabs	rax, 0x8000000000000000
-;;   1d:	 4899                 	cqo	
-;;   1f:	 4883f9ff             	cmp	rcx, -1
-;;   23:	 0f850a000000         	jne	0x33
-;;   29:	 ba00000000           	mov	edx, 0
-;;   2e:	 e903000000           	jmp	0x36
-;;   33:	 48f7f9               	idiv	rcx
-;;   36:	 4889d0               	mov	rax, rdx
-;;   39:	 4883c408             	add	rsp, 8
-;;   3d:	 5d                   	pop	rbp
-;;   3e:	 c3                   	ret	
+;;   3d:	 4899                 	cqo	
+;;   3f:	 4883f9ff             	cmp	rcx, -1
+;;   43:	 0f850a000000         	jne	0x53
+;;   49:	 ba00000000           	mov	edx, 0
+;;   4e:	 e903000000           	jmp	0x56
+;;   53:	 48f7f9               	idiv	rcx
+;;   56:	 4889d0               	mov	rax, rdx
+;;   59:	 4883c408             	add	rsp, 8
+;;   5d:	 5d                   	pop	rbp
+;;   5e:	 c3                   	ret	
+;;   5f:	 0f0b                 	ud2	
+;;   61:	 0f0b                 	ud2	
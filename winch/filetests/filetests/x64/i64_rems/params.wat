@@ -9,19 +9,28 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 48897c2410           	mov	qword ptr [rsp + 0x10], rdi
-;;    d:	 4889742408           	mov	qword ptr [rsp + 8], rsi
-;;   12:	 4c893424             	mov	qword ptr [rsp], r14
-;;   16:	 488b4c2408           	mov	rcx, qword ptr [rsp + 8]
-;;   1b:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
-;;   20:	 4899                 	cqo	
-;;   22:	 4883f9ff             	cmp	rcx, -1
-;;   26:	 0f850a000000         	jne	0x36
-;;   2c:	 ba00000000           	mov	edx, 0
-;;   31:	 e903000000           	jmp	0x39
-;;   36:	 48f7f9               	idiv	rcx
-;;   39:	 4889d0               	mov	rax, rdx
-;;   3c:	 4883c418             	add	rsp, 0x18
-;;   40:	 5d                   	pop	rbp
-;;   41:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8247000000         	jb	0x62
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8240000000         	jb	0x64
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 4889542410           	mov	qword ptr [rsp + 0x10], rdx
+;;   2d:	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;   32:	 4c893424             	mov	qword ptr [rsp], r14
+;;   36:	 488b4c2408           	mov	rcx, qword ptr [rsp + 8]
+;;   3b:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
+;;   40:	 4899                 	cqo	
+;;   42:	 4883f9ff             	cmp	rcx, -1
+;;   46:	 0f850a000000         	jne	0x56
+;;   4c:	 ba00000000           	mov	edx, 0
+;;   51:	 e903000000           	jmp	0x59
+;;   56:	 48f7f9               	idiv	rcx
+;;   59:	 4889d0               	mov	rax, rdx
+;;   5c:	 4883c418             	add	rsp, 0x18
+;;   60:	 5d                   	pop	rbp
+;;   61:	 c3                   	ret	
+;;   62:	 0f0b                 	ud2	
+;;   64:	 0f0b                 	ud2	
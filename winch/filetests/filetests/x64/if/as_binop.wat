@@ -17,42 +17,78 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   18:	 85c0                 	test	eax, eax
-;;   1a:	 0f840f000000         	je	0x2f
-;;   20:	 e800000000           	call	0x25
-;;   25:	 b803000000           	mov	eax, 3
-;;   2a:	 e90a000000           	jmp	0x39
-;;   2f:	 e800000000           	call	0x34
-;;   34:	 b8fdffffff           	mov	eax, 0xfffffffd
-;;   39:	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
-;;   3d:	 50                   	push	rax
-;;   3e:	 85c9                 	test	ecx, ecx
-;;   40:	 0f8417000000         	je	0x5d
-;;   46:	 4883ec08             	sub	rsp, 8
-;;   4a:	 e800000000           	call	0x4f
-;;   4f:	 4883c408             	add	rsp, 8
-;;   53:	 b804000000           	mov	eax, 4
-;;   58:	 e912000000           	jmp	0x6f
-;;   5d:	 4883ec08             	sub	rsp, 8
-;;   61:	 e800000000           	call	0x66
-;;   66:	 4883c408             	add	rsp, 8
-;;   6a:	 b8fbffffff           	mov	eax, 0xfffffffb
-;;   6f:	 59                   	pop	rcx
-;;   70:	 0fafc8               	imul	ecx, eax
-;;   73:	 89c8                 	mov	eax, ecx
-;;   75:	 4883c410             	add	rsp, 0x10
-;;   79:	 5d                   	pop	rbp
-;;   7a:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c330000000       	add	r11, 0x30
+;;   15:	 0f82be000000         	jb	0xd9
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f82b7000000         	jb	0xdb
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   38:	 85c0                 	test	eax, eax
+;;   3a:	 0f8420000000         	je	0x60
+;;   40:	 4156                 	push	r14
+;;   42:	 4156                 	push	r14
+;;   44:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   49:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   4d:	 e800000000           	call	0x52
+;;   52:	 4883c410             	add	rsp, 0x10
+;;   56:	 b803000000           	mov	eax, 3
+;;   5b:	 e91b000000           	jmp	0x7b
+;;   60:	 4156                 	push	r14
+;;   62:	 4156                 	push	r14
+;;   64:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   69:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   6d:	 e800000000           	call	0x72
+;;   72:	 4883c410             	add	rsp, 0x10
+;;   76:	 b8fdffffff           	mov	eax, 0xfffffffd
+;;   7b:	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
+;;   7f:	 50                   	push	rax
+;;   80:	 85c9                 	test	ecx, ecx
+;;   82:	 0f8425000000         	je	0xad
+;;   88:	 4156                 	push	r14
+;;   8a:	 4156                 	push	r14
+;;   8c:	 4883ec08             	sub	rsp, 8
+;;   90:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   95:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   9a:	 e800000000           	call	0x9f
+;;   9f:	 4883c418             	add	rsp, 0x18
+;;   a3:	 b804000000           	mov	eax, 4
+;;   a8:	 e920000000           	jmp	0xcd
+;;   ad:	 4156                 	push	r14
+;;   af:	 4156                 	push	r14
+;;   b1:	 4883ec08             	sub	rsp, 8
+;;   b5:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   ba:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   bf:	 e800000000           	call	0xc4
+;;   c4:	 4883c418             	add	rsp, 0x18
+;;   c8:	 b8fbffffff           	mov	eax, 0xfffffffb
+;;   cd:	 59                   	pop	rcx
+;;   ce:	 0fafc8               	imul	ecx, eax
+;;   d1:	 89c8                 	mov	eax, ecx
+;;   d3:	 4883c410             	add	rsp, 0x10
+;;   d7:	 5d                   	pop	rbp
+;;   d8:	 c3                   	ret	
+;;   d9:	 0f0b                 	ud2	
+;;   db:	 0f0b                 	ud2	
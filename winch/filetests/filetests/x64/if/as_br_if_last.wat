@@ -16,33 +16,61 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   15:	 85c0                 	test	eax, eax
-;;   17:	 0f840f000000         	je	0x2c
-;;   1d:	 e800000000           	call	0x22
-;;   22:	 b801000000           	mov	eax, 1
-;;   27:	 e90a000000           	jmp	0x36
-;;   2c:	 e800000000           	call	0x31
-;;   31:	 b800000000           	mov	eax, 0
-;;   36:	 50                   	push	rax
-;;   37:	 59                   	pop	rcx
-;;   38:	 b802000000           	mov	eax, 2
-;;   3d:	 85c9                 	test	ecx, ecx
-;;   3f:	 0f850a000000         	jne	0x4f
-;;   45:	 50                   	push	rax
-;;   46:	 b803000000           	mov	eax, 3
-;;   4b:	 4883c408             	add	rsp, 8
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f827c000000         	jb	0x97
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8275000000         	jb	0x99
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 85c0                 	test	eax, eax
+;;   37:	 0f8420000000         	je	0x5d
+;;   3d:	 4156                 	push	r14
+;;   3f:	 4156                 	push	r14
+;;   41:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   46:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   4a:	 e800000000           	call	0x4f
 ;;   4f:	 4883c410             	add	rsp, 0x10
-;;   53:	 5d                   	pop	rbp
-;;   54:	 c3                   	ret	
+;;   53:	 b801000000           	mov	eax, 1
+;;   58:	 e91b000000           	jmp	0x78
+;;   5d:	 4156                 	push	r14
+;;   5f:	 4156                 	push	r14
+;;   61:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   66:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   6a:	 e800000000           	call	0x6f
+;;   6f:	 4883c410             	add	rsp, 0x10
+;;   73:	 b800000000           	mov	eax, 0
+;;   78:	 50                   	push	rax
+;;   79:	 59                   	pop	rcx
+;;   7a:	 b802000000           	mov	eax, 2
+;;   7f:	 85c9                 	test	ecx, ecx
+;;   81:	 0f850a000000         	jne	0x91
+;;   87:	 50                   	push	rax
+;;   88:	 b803000000           	mov	eax, 3
+;;   8d:	 4883c408             	add	rsp, 8
+;;   91:	 4883c410             	add	rsp, 0x10
+;;   95:	 5d                   	pop	rbp
+;;   96:	 c3                   	ret	
+;;   97:	 0f0b                 	ud2	
+;;   99:	 0f0b                 	ud2	
@@ -14,30 +14,58 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   15:	 85c0                 	test	eax, eax
-;;   17:	 0f840a000000         	je	0x27
-;;   1d:	 b801000000           	mov	eax, 1
-;;   22:	 e905000000           	jmp	0x2c
-;;   27:	 b800000000           	mov	eax, 0
-;;   2c:	 85c0                 	test	eax, eax
-;;   2e:	 0f840f000000         	je	0x43
-;;   34:	 e800000000           	call	0x39
-;;   39:	 b802000000           	mov	eax, 2
-;;   3e:	 e90a000000           	jmp	0x4d
-;;   43:	 e800000000           	call	0x48
-;;   48:	 b803000000           	mov	eax, 3
-;;   4d:	 4883c410             	add	rsp, 0x10
-;;   51:	 5d                   	pop	rbp
-;;   52:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f827a000000         	jb	0x95
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8273000000         	jb	0x97
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 85c0                 	test	eax, eax
+;;   37:	 0f840a000000         	je	0x47
+;;   3d:	 b801000000           	mov	eax, 1
+;;   42:	 e905000000           	jmp	0x4c
+;;   47:	 b800000000           	mov	eax, 0
+;;   4c:	 85c0                 	test	eax, eax
+;;   4e:	 0f8420000000         	je	0x74
+;;   54:	 4156                 	push	r14
+;;   56:	 4156                 	push	r14
+;;   58:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   5d:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   61:	 e800000000           	call	0x66
+;;   66:	 4883c410             	add	rsp, 0x10
+;;   6a:	 b802000000           	mov	eax, 2
+;;   6f:	 e91b000000           	jmp	0x8f
+;;   74:	 4156                 	push	r14
+;;   76:	 4156                 	push	r14
+;;   78:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   7d:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   81:	 e800000000           	call	0x86
+;;   86:	 4883c410             	add	rsp, 0x10
+;;   8a:	 b803000000           	mov	eax, 3
+;;   8f:	 4883c410             	add	rsp, 0x10
+;;   93:	 5d                   	pop	rbp
+;;   94:	 c3                   	ret	
+;;   95:	 0f0b                 	ud2	
+;;   97:	 0f0b                 	ud2	
@@ -12,28 +12,56 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   15:	 85c0                 	test	eax, eax
-;;   17:	 0f840f000000         	je	0x2c
-;;   1d:	 e800000000           	call	0x22
-;;   22:	 b80d000000           	mov	eax, 0xd
-;;   27:	 e90a000000           	jmp	0x36
-;;   2c:	 e800000000           	call	0x31
-;;   31:	 b800000000           	mov	eax, 0
-;;   36:	 83f800               	cmp	eax, 0
-;;   39:	 b800000000           	mov	eax, 0
-;;   3e:	 400f94c0             	sete	al
-;;   42:	 4883c410             	add	rsp, 0x10
-;;   46:	 5d                   	pop	rbp
-;;   47:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f826e000000         	jb	0x89
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8267000000         	jb	0x8b
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 85c0                 	test	eax, eax
+;;   37:	 0f8420000000         	je	0x5d
+;;   3d:	 4156                 	push	r14
+;;   3f:	 4156                 	push	r14
+;;   41:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   46:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   4a:	 e800000000           	call	0x4f
+;;   4f:	 4883c410             	add	rsp, 0x10
+;;   53:	 b80d000000           	mov	eax, 0xd
+;;   58:	 e91b000000           	jmp	0x78
+;;   5d:	 4156                 	push	r14
+;;   5f:	 4156                 	push	r14
+;;   61:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   66:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   6a:	 e800000000           	call	0x6f
+;;   6f:	 4883c410             	add	rsp, 0x10
+;;   73:	 b800000000           	mov	eax, 0
+;;   78:	 85c0                 	test	eax, eax
+;;   7a:	 b800000000           	mov	eax, 0
+;;   7f:	 400f94c0             	sete	al
+;;   83:	 4883c410             	add	rsp, 0x10
+;;   87:	 5d                   	pop	rbp
+;;   88:	 c3                   	ret	
+;;   89:	 0f0b                 	ud2	
+;;   8b:	 0f0b                 	ud2	
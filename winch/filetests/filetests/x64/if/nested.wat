@@ -24,56 +24,114 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   18:	 85c0                 	test	eax, eax
-;;   1a:	 0f8451000000         	je	0x71
-;;   20:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   24:	 85c0                 	test	eax, eax
-;;   26:	 0f8405000000         	je	0x31
-;;   2c:	 e800000000           	call	0x31
-;;   31:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   35:	 85c0                 	test	eax, eax
-;;   37:	 0f8405000000         	je	0x42
-;;   3d:	 e905000000           	jmp	0x47
-;;   42:	 e800000000           	call	0x47
-;;   47:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   4b:	 85c0                 	test	eax, eax
-;;   4d:	 0f840f000000         	je	0x62
-;;   53:	 e800000000           	call	0x58
-;;   58:	 b809000000           	mov	eax, 9
-;;   5d:	 e95b000000           	jmp	0xbd
-;;   62:	 e800000000           	call	0x67
-;;   67:	 b80a000000           	mov	eax, 0xa
-;;   6c:	 e94c000000           	jmp	0xbd
-;;   71:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   75:	 85c0                 	test	eax, eax
-;;   77:	 0f8405000000         	je	0x82
-;;   7d:	 e800000000           	call	0x82
-;;   82:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   86:	 85c0                 	test	eax, eax
-;;   88:	 0f8405000000         	je	0x93
-;;   8e:	 e905000000           	jmp	0x98
-;;   93:	 e800000000           	call	0x98
-;;   98:	 8b442408             	mov	eax, dword ptr [rsp + 8]
-;;   9c:	 85c0                 	test	eax, eax
-;;   9e:	 0f840f000000         	je	0xb3
-;;   a4:	 e800000000           	call	0xa9
-;;   a9:	 b80a000000           	mov	eax, 0xa
-;;   ae:	 e90a000000           	jmp	0xbd
-;;   b3:	 e800000000           	call	0xb8
-;;   b8:	 b80b000000           	mov	eax, 0xb
-;;   bd:	 4883c410             	add	rsp, 0x10
-;;   c1:	 5d                   	pop	rbp
-;;   c2:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f8250010000         	jb	0x16b
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8249010000         	jb	0x16d
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   38:	 85c0                 	test	eax, eax
+;;   3a:	 0f8495000000         	je	0xd5
+;;   40:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   44:	 85c0                 	test	eax, eax
+;;   46:	 0f8416000000         	je	0x62
+;;   4c:	 4156                 	push	r14
+;;   4e:	 4156                 	push	r14
+;;   50:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   55:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   59:	 e800000000           	call	0x5e
+;;   5e:	 4883c410             	add	rsp, 0x10
+;;   62:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   66:	 85c0                 	test	eax, eax
+;;   68:	 0f8405000000         	je	0x73
+;;   6e:	 e916000000           	jmp	0x89
+;;   73:	 4156                 	push	r14
+;;   75:	 4156                 	push	r14
+;;   77:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   7c:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   80:	 e800000000           	call	0x85
+;;   85:	 4883c410             	add	rsp, 0x10
+;;   89:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   8d:	 85c0                 	test	eax, eax
+;;   8f:	 0f8420000000         	je	0xb5
+;;   95:	 4156                 	push	r14
+;;   97:	 4156                 	push	r14
+;;   99:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   9e:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   a2:	 e800000000           	call	0xa7
+;;   a7:	 4883c410             	add	rsp, 0x10
+;;   ab:	 b809000000           	mov	eax, 9
+;;   b0:	 e9b0000000           	jmp	0x165
+;;   b5:	 4156                 	push	r14
+;;   b7:	 4156                 	push	r14
+;;   b9:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   be:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   c2:	 e800000000           	call	0xc7
+;;   c7:	 4883c410             	add	rsp, 0x10
+;;   cb:	 b80a000000           	mov	eax, 0xa
+;;   d0:	 e990000000           	jmp	0x165
+;;   d5:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   d9:	 85c0                 	test	eax, eax
+;;   db:	 0f8416000000         	je	0xf7
+;;   e1:	 4156                 	push	r14
+;;   e3:	 4156                 	push	r14
+;;   e5:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   ea:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   ee:	 e800000000           	call	0xf3
+;;   f3:	 4883c410             	add	rsp, 0x10
+;;   f7:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   fb:	 85c0                 	test	eax, eax
+;;   fd:	 0f8405000000         	je	0x108
+;;  103:	 e916000000           	jmp	0x11e
+;;  108:	 4156                 	push	r14
+;;  10a:	 4156                 	push	r14
+;;  10c:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;  111:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;  115:	 e800000000           	call	0x11a
+;;  11a:	 4883c410             	add	rsp, 0x10
+;;  11e:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;  122:	 85c0                 	test	eax, eax
+;;  124:	 0f8420000000         	je	0x14a
+;;  12a:	 4156                 	push	r14
+;;  12c:	 4156                 	push	r14
+;;  12e:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;  133:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;  137:	 e800000000           	call	0x13c
+;;  13c:	 4883c410             	add	rsp, 0x10
+;;  140:	 b80a000000           	mov	eax, 0xa
+;;  145:	 e91b000000           	jmp	0x165
+;;  14a:	 4156                 	push	r14
+;;  14c:	 4156                 	push	r14
+;;  14e:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;  153:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;  157:	 e800000000           	call	0x15c
+;;  15c:	 4883c410             	add	rsp, 0x10
+;;  160:	 b80b000000           	mov	eax, 0xb
+;;  165:	 4883c410             	add	rsp, 0x10
+;;  169:	 5d                   	pop	rbp
+;;  16a:	 c3                   	ret	
+;;  16b:	 0f0b                 	ud2	
+;;  16d:	 0f0b                 	ud2	
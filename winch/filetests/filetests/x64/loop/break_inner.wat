@@ -13,43 +13,52 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f828d000000         	jb	0xa8
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8286000000         	jb	0xaa
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   16:	 b800000000           	mov	eax, 0
-;;   1b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   1f:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;   24:	 4153                 	push	r11
-;;   26:	 b801000000           	mov	eax, 1
-;;   2b:	 59                   	pop	rcx
-;;   2c:	 01c1                 	add	ecx, eax
-;;   2e:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
-;;   32:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;   37:	 4153                 	push	r11
-;;   39:	 b802000000           	mov	eax, 2
-;;   3e:	 59                   	pop	rcx
-;;   3f:	 01c1                 	add	ecx, eax
-;;   41:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
-;;   45:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;   4a:	 4153                 	push	r11
-;;   4c:	 b804000000           	mov	eax, 4
-;;   51:	 59                   	pop	rcx
-;;   52:	 01c1                 	add	ecx, eax
-;;   54:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
-;;   58:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;   5d:	 4153                 	push	r11
-;;   5f:	 b808000000           	mov	eax, 8
-;;   64:	 59                   	pop	rcx
-;;   65:	 01c1                 	add	ecx, eax
-;;   67:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
-;;   6b:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;   70:	 4153                 	push	r11
-;;   72:	 b810000000           	mov	eax, 0x10
-;;   77:	 59                   	pop	rcx
-;;   78:	 01c1                 	add	ecx, eax
-;;   7a:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
-;;   7e:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   82:	 4883c410             	add	rsp, 0x10
-;;   86:	 5d                   	pop	rbp
-;;   87:	 c3                   	ret	
+;;   31:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   36:	 b800000000           	mov	eax, 0
+;;   3b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   3f:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
+;;   44:	 4153                 	push	r11
+;;   46:	 b801000000           	mov	eax, 1
+;;   4b:	 59                   	pop	rcx
+;;   4c:	 01c1                 	add	ecx, eax
+;;   4e:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
+;;   52:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
+;;   57:	 4153                 	push	r11
+;;   59:	 b802000000           	mov	eax, 2
+;;   5e:	 59                   	pop	rcx
+;;   5f:	 01c1                 	add	ecx, eax
+;;   61:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
+;;   65:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
+;;   6a:	 4153                 	push	r11
+;;   6c:	 b804000000           	mov	eax, 4
+;;   71:	 59                   	pop	rcx
+;;   72:	 01c1                 	add	ecx, eax
+;;   74:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
+;;   78:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
+;;   7d:	 4153                 	push	r11
+;;   7f:	 b808000000           	mov	eax, 8
+;;   84:	 59                   	pop	rcx
+;;   85:	 01c1                 	add	ecx, eax
+;;   87:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
+;;   8b:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
+;;   90:	 4153                 	push	r11
+;;   92:	 b810000000           	mov	eax, 0x10
+;;   97:	 59                   	pop	rcx
+;;   98:	 01c1                 	add	ecx, eax
+;;   9a:	 894c240c             	mov	dword ptr [rsp + 0xc], ecx
+;;   9e:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   a2:	 4883c410             	add	rsp, 0x10
+;;   a6:	 5d                   	pop	rbp
+;;   a7:	 c3                   	ret	
+;;   a8:	 0f0b                 	ud2	
+;;   aa:	 0f0b                 	ud2	
@@ -17,25 +17,34 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f825b000000         	jb	0x76
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8254000000         	jb	0x78
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   16:	 b801000000           	mov	eax, 1
-;;   1b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   1f:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   23:	 6bc003               	imul	eax, eax, 3
-;;   26:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   2a:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   2e:	 83e805               	sub	eax, 5
-;;   31:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   35:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   39:	 6bc007               	imul	eax, eax, 7
-;;   3c:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
-;;   40:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   44:	 83f8f2               	cmp	eax, -0xe
-;;   47:	 b800000000           	mov	eax, 0
-;;   4c:	 400f94c0             	sete	al
-;;   50:	 4883c410             	add	rsp, 0x10
-;;   54:	 5d                   	pop	rbp
-;;   55:	 c3                   	ret	
+;;   31:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   36:	 b801000000           	mov	eax, 1
+;;   3b:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   3f:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   43:	 6bc003               	imul	eax, eax, 3
+;;   46:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   4a:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   4e:	 83e805               	sub	eax, 5
+;;   51:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   55:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   59:	 6bc007               	imul	eax, eax, 7
+;;   5c:	 8944240c             	mov	dword ptr [rsp + 0xc], eax
+;;   60:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   64:	 83f8f2               	cmp	eax, -0xe
+;;   67:	 b800000000           	mov	eax, 0
+;;   6c:	 400f94c0             	sete	al
+;;   70:	 4883c410             	add	rsp, 0x10
+;;   74:	 5d                   	pop	rbp
+;;   75:	 c3                   	ret	
+;;   76:	 0f0b                 	ud2	
+;;   78:	 0f0b                 	ud2	
@@ -17,32 +17,41 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec20             	sub	rsp, 0x20
-;;    8:	 48897c2418           	mov	qword ptr [rsp + 0x18], rdi
-;;    d:	 4531db               	xor	r11d, r11d
-;;   10:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
-;;   15:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
-;;   1a:	 4c893424             	mov	qword ptr [rsp], r14
-;;   1e:	 48c7c001000000       	mov	rax, 1
-;;   25:	 4889442410           	mov	qword ptr [rsp + 0x10], rax
-;;   2a:	 48c7c002000000       	mov	rax, 2
-;;   31:	 4889442408           	mov	qword ptr [rsp + 8], rax
-;;   36:	 488b442418           	mov	rax, qword ptr [rsp + 0x18]
-;;   3b:	 488b4c2408           	mov	rcx, qword ptr [rsp + 8]
-;;   40:	 4839c1               	cmp	rcx, rax
-;;   43:	 b900000000           	mov	ecx, 0
-;;   48:	 400f97c1             	seta	cl
-;;   4c:	 85c9                 	test	ecx, ecx
-;;   4e:	 0f8526000000         	jne	0x7a
-;;   54:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   59:	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
-;;   5e:	 480fafc8             	imul	rcx, rax
-;;   62:	 48894c2410           	mov	qword ptr [rsp + 0x10], rcx
-;;   67:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   6c:	 4883c001             	add	rax, 1
-;;   70:	 4889442408           	mov	qword ptr [rsp + 8], rax
-;;   75:	 e9bcffffff           	jmp	0x36
-;;   7a:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
-;;   7f:	 4883c420             	add	rsp, 0x20
-;;   83:	 5d                   	pop	rbp
-;;   84:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f828a000000         	jb	0xa5
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8283000000         	jb	0xa7
+;;   24:	 4883ec20             	sub	rsp, 0x20
+;;   28:	 4889542418           	mov	qword ptr [rsp + 0x18], rdx
+;;   2d:	 4531db               	xor	r11d, r11d
+;;   30:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
+;;   35:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
+;;   3a:	 4c893424             	mov	qword ptr [rsp], r14
+;;   3e:	 48c7c001000000       	mov	rax, 1
+;;   45:	 4889442410           	mov	qword ptr [rsp + 0x10], rax
+;;   4a:	 48c7c002000000       	mov	rax, 2
+;;   51:	 4889442408           	mov	qword ptr [rsp + 8], rax
+;;   56:	 488b442418           	mov	rax, qword ptr [rsp + 0x18]
+;;   5b:	 488b4c2408           	mov	rcx, qword ptr [rsp + 8]
+;;   60:	 4839c1               	cmp	rcx, rax
+;;   63:	 b900000000           	mov	ecx, 0
+;;   68:	 400f97c1             	seta	cl
+;;   6c:	 85c9                 	test	ecx, ecx
+;;   6e:	 0f8526000000         	jne	0x9a
+;;   74:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   79:	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
+;;   7e:	 480fafc8             	imul	rcx, rax
+;;   82:	 48894c2410           	mov	qword ptr [rsp + 0x10], rcx
+;;   87:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   8c:	 4883c001             	add	rax, 1
+;;   90:	 4889442408           	mov	qword ptr [rsp + 8], rax
+;;   95:	 e9bcffffff           	jmp	0x56
+;;   9a:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
+;;   9f:	 4883c420             	add	rsp, 0x20
+;;   a3:	 5d                   	pop	rbp
+;;   a4:	 c3                   	ret	
+;;   a5:	 0f0b                 	ud2	
+;;   a7:	 0f0b                 	ud2	
@@ -9,38 +9,84 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883ec08             	sub	rsp, 8
-;;   10:	 e800000000           	call	0x15
-;;   15:	 4883c408             	add	rsp, 8
-;;   19:	 4883ec08             	sub	rsp, 8
-;;   1d:	 e800000000           	call	0x22
-;;   22:	 4883c408             	add	rsp, 8
-;;   26:	 4883ec08             	sub	rsp, 8
-;;   2a:	 e800000000           	call	0x2f
-;;   2f:	 4883c408             	add	rsp, 8
-;;   33:	 4883ec08             	sub	rsp, 8
-;;   37:	 e800000000           	call	0x3c
-;;   3c:	 4883c408             	add	rsp, 8
-;;   40:	 4883ec08             	sub	rsp, 8
-;;   44:	 e800000000           	call	0x49
-;;   49:	 4883c408             	add	rsp, 8
-;;   4d:	 4883ec08             	sub	rsp, 8
-;;   51:	 e800000000           	call	0x56
-;;   56:	 4883c408             	add	rsp, 8
-;;   5a:	 4883ec08             	sub	rsp, 8
-;;   5e:	 e800000000           	call	0x63
-;;   63:	 4883c408             	add	rsp, 8
-;;   67:	 b808000000           	mov	eax, 8
-;;   6c:	 4883c408             	add	rsp, 8
-;;   70:	 5d                   	pop	rbp
-;;   71:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f82d9000000         	jb	0xf4
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f82d2000000         	jb	0xf6
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4156                 	push	r14
+;;   2e:	 4156                 	push	r14
+;;   30:	 4883ec08             	sub	rsp, 8
+;;   34:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   39:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   3e:	 e800000000           	call	0x43
+;;   43:	 4883c418             	add	rsp, 0x18
+;;   47:	 4156                 	push	r14
+;;   49:	 4156                 	push	r14
+;;   4b:	 4883ec08             	sub	rsp, 8
+;;   4f:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   54:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   59:	 e800000000           	call	0x5e
+;;   5e:	 4883c418             	add	rsp, 0x18
+;;   62:	 4156                 	push	r14
+;;   64:	 4156                 	push	r14
+;;   66:	 4883ec08             	sub	rsp, 8
+;;   6a:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   6f:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   74:	 e800000000           	call	0x79
+;;   79:	 4883c418             	add	rsp, 0x18
+;;   7d:	 4156                 	push	r14
+;;   7f:	 4156                 	push	r14
+;;   81:	 4883ec08             	sub	rsp, 8
+;;   85:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   8a:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   8f:	 e800000000           	call	0x94
+;;   94:	 4883c418             	add	rsp, 0x18
+;;   98:	 4156                 	push	r14
+;;   9a:	 4156                 	push	r14
+;;   9c:	 4883ec08             	sub	rsp, 8
+;;   a0:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   a5:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   aa:	 e800000000           	call	0xaf
+;;   af:	 4883c418             	add	rsp, 0x18
+;;   b3:	 4156                 	push	r14
+;;   b5:	 4156                 	push	r14
+;;   b7:	 4883ec08             	sub	rsp, 8
+;;   bb:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   c0:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   c5:	 e800000000           	call	0xca
+;;   ca:	 4883c418             	add	rsp, 0x18
+;;   ce:	 4156                 	push	r14
+;;   d0:	 4156                 	push	r14
+;;   d2:	 4883ec08             	sub	rsp, 8
+;;   d6:	 488b7c2410           	mov	rdi, qword ptr [rsp + 0x10]
+;;   db:	 488b742408           	mov	rsi, qword ptr [rsp + 8]
+;;   e0:	 e800000000           	call	0xe5
+;;   e5:	 4883c418             	add	rsp, 0x18
+;;   e9:	 b808000000           	mov	eax, 8
+;;   ee:	 4883c408             	add	rsp, 8
+;;   f2:	 5d                   	pop	rbp
+;;   f3:	 c3                   	ret	
+;;   f4:	 0f0b                 	ud2	
+;;   f6:	 0f0b                 	ud2	
@@ -16,28 +16,37 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 48897c2410           	mov	qword ptr [rsp + 0x10], rdi
-;;    d:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8275000000         	jb	0x90
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f826e000000         	jb	0x92
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 4889542410           	mov	qword ptr [rsp + 0x10], rdx
+;;   2d:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   16:	 4c893424             	mov	qword ptr [rsp], r14
-;;   1a:	 48c7c001000000       	mov	rax, 1
-;;   21:	 4889442408           	mov	qword ptr [rsp + 8], rax
-;;   26:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
-;;   2b:	 4883f800             	cmp	rax, 0
-;;   2f:	 b800000000           	mov	eax, 0
-;;   34:	 400f94c0             	sete	al
-;;   38:	 85c0                 	test	eax, eax
-;;   3a:	 0f8526000000         	jne	0x66
-;;   40:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   45:	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
-;;   4a:	 480fafc8             	imul	rcx, rax
-;;   4e:	 48894c2408           	mov	qword ptr [rsp + 8], rcx
-;;   53:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
-;;   58:	 4883e801             	sub	rax, 1
-;;   5c:	 4889442410           	mov	qword ptr [rsp + 0x10], rax
-;;   61:	 e9c0ffffff           	jmp	0x26
-;;   66:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   6b:	 4883c418             	add	rsp, 0x18
-;;   6f:	 5d                   	pop	rbp
-;;   70:	 c3                   	ret	
+;;   36:	 4c893424             	mov	qword ptr [rsp], r14
+;;   3a:	 48c7c001000000       	mov	rax, 1
+;;   41:	 4889442408           	mov	qword ptr [rsp + 8], rax
+;;   46:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
+;;   4b:	 4885c0               	test	rax, rax
+;;   4e:	 b800000000           	mov	eax, 0
+;;   53:	 400f94c0             	sete	al
+;;   57:	 85c0                 	test	eax, eax
+;;   59:	 0f8526000000         	jne	0x85
+;;   5f:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   64:	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
+;;   69:	 480fafc8             	imul	rcx, rax
+;;   6d:	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;   72:	 488b442410           	mov	rax, qword ptr [rsp + 0x10]
+;;   77:	 4883e801             	sub	rax, 1
+;;   7b:	 4889442410           	mov	qword ptr [rsp + 0x10], rax
+;;   80:	 e9c1ffffff           	jmp	0x46
+;;   85:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   8a:	 4883c418             	add	rsp, 0x18
+;;   8e:	 5d                   	pop	rbp
+;;   8f:	 c3                   	ret	
+;;   90:	 0f0b                 	ud2	
+;;   92:	 0f0b                 	ud2	
@@ -0,0 +1,46 @@
+;;! target = "x86_64"
+(module
+  (memory 1)
+  (func (export "copy") (param i32 i32 i32)
+    (memory.copy (local.get 0) (local.get 1) (local.get 2)))
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c340000000       	add	r11, 0x40
+;;   15:	 0f826c000000         	jb	0x87
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8265000000         	jb	0x89
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;   2c:	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
+;;   30:	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
+;;   35:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   3a:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   3e:	 89c0                 	mov	eax, eax
+;;   40:	 8b4c2410             	mov	ecx, dword ptr [rsp + 0x10]
+;;   44:	 89c9                 	mov	ecx, ecx
+;;   46:	 8b542414             	mov	edx, dword ptr [rsp + 0x14]
+;;   4a:	 89d2                 	mov	edx, edx
+;;   4c:	 4156                 	push	r14
+;;   4e:	 52                   	push	rdx
+;;   4f:	 51                   	push	rcx
+;;   50:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   54:	 498b5b20             	mov	rbx, qword ptr [r11 + 0x20]
+;;   58:	 50                   	push	rax
+;;   59:	 4883ec08             	sub	rsp, 8
+;;   5d:	 488b7c2420           	mov	rdi, qword ptr [rsp + 0x20]
+;;   62:	 be00000000           	mov	esi, 0
+;;   67:	 488b542418           	mov	rdx, qword ptr [rsp + 0x18]
+;;   6c:	 b900000000           	mov	ecx, 0
+;;   71:	 4c8b442410           	mov	r8, qword ptr [rsp + 0x10]
+;;   76:	 4c8b4c2408           	mov	r9, qword ptr [rsp + 8]
+;;   7b:	 ffd3                 	call	rbx
+;;   7d:	 4883c428             	add	rsp, 0x28
+;;   81:	 4883c418             	add	rsp, 0x18
+;;   85:	 5d                   	pop	rbp
+;;   86:	 c3                   	ret	
+;;   87:	 0f0b                 	ud2	
+;;   89:	 0f0b                 	ud2	
@@ -0,0 +1,42 @@
+;;! target = "x86_64"
+;;! static_memory_bound = 0
+(module
+  (memory 1)
+  (func (export "store") (param i32 i64)
+    (i64.store offset=16 (local.get 0) (local.get 1)))
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f825e000000         	jb	0x79
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8257000000         	jb	0x7b
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;   2c:	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   3a:	 8b4c2414             	mov	ecx, dword ptr [rsp + 0x14]
+;;   3e:	 4c89f2               	mov	rdx, r14
+;;   41:	 89c9                 	mov	ecx, ecx
+;;   43:	 49c7c310000000       	mov	r11, 0x10
+;;   4a:	 4c01d9               	add	rcx, r11
+;;   4d:	 31f6                 	xor	esi, esi
+;;   4f:	 4989cb               	mov	r11, rcx
+;;   52:	 4983c308             	add	r11, 8
+;;   56:	 488b5a58             	mov	rbx, qword ptr [rdx + 0x58]
+;;   5a:	 4939db               	cmp	r11, rbx
+;;   5d:	 0f871a000000         	ja	0x7d
+;;   63:	 488b5250             	mov	rdx, qword ptr [rdx + 0x50]
+;;   67:	 480f47d6             	cmova	rdx, rsi
+;;   6b:	 480f47ce             	cmova	rcx, rsi
+;;   6f:	 4889040a             	mov	qword ptr [rdx + rcx], rax
+;;   73:	 4883c418             	add	rsp, 0x18
+;;   77:	 5d                   	pop	rbp
+;;   78:	 c3                   	ret	
+;;   79:	 0f0b                 	ud2	
+;;   7b:	 0f0b                 	ud2	
+;;   7d:	 0f0b                 	ud2	
@@ -0,0 +1,44 @@
+;;! target = "x86_64"
+(module
+  (memory 1)
+  (func (export "fill") (param i32 i32 i32)
+    (memory.fill (local.get 0) (local.get 1) (local.get 2)))
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c340000000       	add	r11, 0x40
+;;   15:	 0f8264000000         	jb	0x7f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f825d000000         	jb	0x81
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;   2c:	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
+;;   30:	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
+;;   35:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   3a:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   3e:	 89c0                 	mov	eax, eax
+;;   40:	 8b4c2410             	mov	ecx, dword ptr [rsp + 0x10]
+;;   44:	 8b542414             	mov	edx, dword ptr [rsp + 0x14]
+;;   48:	 89d2                 	mov	edx, edx
+;;   4a:	 4156                 	push	r14
+;;   4c:	 52                   	push	rdx
+;;   4d:	 51                   	push	rcx
+;;   4e:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   52:	 498b5b28             	mov	rbx, qword ptr [r11 + 0x28]
+;;   56:	 50                   	push	rax
+;;   57:	 4883ec08             	sub	rsp, 8
+;;   5b:	 488b7c2420           	mov	rdi, qword ptr [rsp + 0x20]
+;;   60:	 be00000000           	mov	esi, 0
+;;   65:	 488b542418           	mov	rdx, qword ptr [rsp + 0x18]
+;;   6a:	 8b4c2410             	mov	ecx, dword ptr [rsp + 0x10]
+;;   6e:	 4c8b442408           	mov	r8, qword ptr [rsp + 8]
+;;   73:	 ffd3                 	call	rbx
+;;   75:	 4883c428             	add	rsp, 0x28
+;;   79:	 4883c418             	add	rsp, 0x18
+;;   7d:	 5d                   	pop	rbp
+;;   7e:	 c3                   	ret	
+;;   7f:	 0f0b                 	ud2	
+;;   81:	 0f0b                 	ud2	
@@ -0,0 +1,34 @@
+;;! target = "x86_64"
+(module
+  (memory 1)
+  (func (export "grow") (param i32) (result i32)
+    (memory.grow (local.get 0)))
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f8240000000         	jb	0x5b
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8239000000         	jb	0x5d
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 89c0                 	mov	eax, eax
+;;   37:	 4156                 	push	r14
+;;   39:	 50                   	push	rax
+;;   3a:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   3e:	 498b0b               	mov	rcx, qword ptr [r11]
+;;   41:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   46:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   4a:	 ba00000000           	mov	edx, 0
+;;   4f:	 ffd1                 	call	rcx
+;;   51:	 4883c410             	add	rsp, 0x10
+;;   55:	 4883c410             	add	rsp, 0x10
+;;   59:	 5d                   	pop	rbp
+;;   5a:	 c3                   	ret	
+;;   5b:	 0f0b                 	ud2	
+;;   5d:	 0f0b                 	ud2	
@@ -0,0 +1,71 @@
+;;! target = "x86_64"
+(module
+  (memory 1)
+  (data $d "hello")
+  (func (export "init") (param i32 i32 i32)
+    (memory.init $d (local.get 0) (local.get 1) (local.get 2)))
+  (func (export "drop")
+    (data.drop $d))
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c340000000       	add	r11, 0x40
+;;   15:	 0f8268000000         	jb	0x83
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8261000000         	jb	0x85
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;   2c:	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
+;;   30:	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
+;;   35:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   3a:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   3e:	 8b4c2410             	mov	ecx, dword ptr [rsp + 0x10]
+;;   42:	 8b542414             	mov	edx, dword ptr [rsp + 0x14]
+;;   46:	 89d2                 	mov	edx, edx
+;;   48:	 4156                 	push	r14
+;;   4a:	 52                   	push	rdx
+;;   4b:	 51                   	push	rcx
+;;   4c:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   50:	 498b5b30             	mov	rbx, qword ptr [r11 + 0x30]
+;;   54:	 50                   	push	rax
+;;   55:	 4883ec08             	sub	rsp, 8
+;;   59:	 488b7c2420           	mov	rdi, qword ptr [rsp + 0x20]
+;;   5e:	 be00000000           	mov	esi, 0
+;;   63:	 ba00000000           	mov	edx, 0
+;;   68:	 488b4c2418           	mov	rcx, qword ptr [rsp + 0x18]
+;;   6d:	 448b442410           	mov	r8d, dword ptr [rsp + 0x10]
+;;   72:	 448b4c2408           	mov	r9d, dword ptr [rsp + 8]
+;;   77:	 ffd3                 	call	rbx
+;;   79:	 4883c428             	add	rsp, 0x28
+;;   7d:	 4883c418             	add	rsp, 0x18
+;;   81:	 5d                   	pop	rbp
+;;   82:	 c3                   	ret	
+;;   83:	 0f0b                 	ud2	
+;;   85:	 0f0b                 	ud2	
+;;
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8230000000         	jb	0x4b
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8229000000         	jb	0x4d
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   30:	 498b4340             	mov	rax, qword ptr [r11 + 0x40]
+;;   34:	 4156                 	push	r14
+;;   36:	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;   3a:	 be00000000           	mov	esi, 0
+;;   3f:	 ffd0                 	call	rax
+;;   41:	 4883c408             	add	rsp, 8
+;;   45:	 4883c408             	add	rsp, 8
+;;   49:	 5d                   	pop	rbp
+;;   4a:	 c3                   	ret	
+;;   4b:	 0f0b                 	ud2	
+;;   4d:	 0f0b                 	ud2	
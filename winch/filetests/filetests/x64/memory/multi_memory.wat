@@ -0,0 +1,110 @@
+;;! target = "x86_64"
+(module
+  (import "env" "m" (memory $imported 1))
+  (memory $a 1)
+  (memory $b 2)
+  (func (export "load-b") (param i32) (result i32)
+    (i32.load $b (local.get 0)))
+  (func (export "store-imported") (param i32 i32)
+    (i32.store $imported (local.get 0) (local.get 1)))
+  (func (export "size-b") (result i32)
+    (memory.size $b))
+  (func (export "grow-a") (param i32) (result i32)
+    (memory.grow $a (local.get 0)))
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f822f000000         	jb	0x4a
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8228000000         	jb	0x4c
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 4c89f1               	mov	rcx, r14
+;;   38:	 89c0                 	mov	eax, eax
+;;   3a:	 488b8980000000       	mov	rcx, qword ptr [rcx + 0x80]
+;;   41:	 8b0401               	mov	eax, dword ptr [rcx + rax]
+;;   44:	 4883c410             	add	rsp, 0x10
+;;   48:	 5d                   	pop	rbp
+;;   49:	 c3                   	ret	
+;;   4a:	 0f0b                 	ud2	
+;;   4c:	 0f0b                 	ud2	
+;;
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8233000000         	jb	0x4e
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822c000000         	jb	0x50
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b442408             	mov	eax, dword ptr [rsp + 8]
+;;   38:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   3c:	 498b5648             	mov	rdx, qword ptr [r14 + 0x48]
+;;   40:	 89c9                 	mov	ecx, ecx
+;;   42:	 488b12               	mov	rdx, qword ptr [rdx]
+;;   45:	 89040a               	mov	dword ptr [rdx + rcx], eax
+;;   48:	 4883c410             	add	rsp, 0x10
+;;   4c:	 5d                   	pop	rbp
+;;   4d:	 c3                   	ret	
+;;   4e:	 0f0b                 	ud2	
+;;   50:	 0f0b                 	ud2	
+;;
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8225000000         	jb	0x40
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f821e000000         	jb	0x42
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4d89f3               	mov	r11, r14
+;;   2f:	 498b8388000000       	mov	rax, qword ptr [r11 + 0x88]
+;;   36:	 48c1e810             	shr	rax, 0x10
+;;   3a:	 4883c408             	add	rsp, 8
+;;   3e:	 5d                   	pop	rbp
+;;   3f:	 c3                   	ret	
+;;   40:	 0f0b                 	ud2	
+;;   42:	 0f0b                 	ud2	
+;;
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f8240000000         	jb	0x5b
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8239000000         	jb	0x5d
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 89c0                 	mov	eax, eax
+;;   37:	 4156                 	push	r14
+;;   39:	 50                   	push	rax
+;;   3a:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   3e:	 498b0b               	mov	rcx, qword ptr [r11]
+;;   41:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   46:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   4a:	 ba01000000           	mov	edx, 1
+;;   4f:	 ffd1                 	call	rcx
+;;   51:	 4883c410             	add	rsp, 0x10
+;;   55:	 4883c410             	add	rsp, 0x10
+;;   59:	 5d                   	pop	rbp
+;;   5a:	 c3                   	ret	
+;;   5b:	 0f0b                 	ud2	
+;;   5d:	 0f0b                 	ud2	
@@ -0,0 +1,38 @@
+;;! target = "x86_64"
+;;! flags = ["enable_heap_access_spectre_mitigation=false"]
+;;! static_memory_bound = 0
+(module
+  (memory 1)
+  (func (export "load") (param i32) (result i32)
+    (i32.load offset=8 (local.get 0)))
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f824a000000         	jb	0x65
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8243000000         	jb	0x67
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 4c89f1               	mov	rcx, r14
+;;   38:	 89c0                 	mov	eax, eax
+;;   3a:	 49c7c308000000       	mov	r11, 8
+;;   41:	 4c01d8               	add	rax, r11
+;;   44:	 4989c3               	mov	r11, rax
+;;   47:	 4983c304             	add	r11, 4
+;;   4b:	 488b5158             	mov	rdx, qword ptr [rcx + 0x58]
+;;   4f:	 4939d3               	cmp	r11, rdx
+;;   52:	 0f8711000000         	ja	0x69
+;;   58:	 488b4950             	mov	rcx, qword ptr [rcx + 0x50]
+;;   5c:	 8b0401               	mov	eax, dword ptr [rcx + rax]
+;;   5f:	 4883c410             	add	rsp, 0x10
+;;   63:	 5d                   	pop	rbp
+;;   64:	 c3                   	ret	
+;;   65:	 0f0b                 	ud2	
+;;   67:	 0f0b                 	ud2	
+;;   69:	 0f0b                 	ud2	
@@ -0,0 +1,75 @@
+;;! target = "x86_64"
+(module
+  (memory 1)
+  ;; The offset reaches past the guard pages of the static heap, so the
+  ;; access is checked against the heap's bound.
+  (func (export "load") (param i32) (result i32)
+    (i32.load offset=0xfffffff0 (local.get 0)))
+  ;; Only the alignment of atomic accesses is checked.
+  (func (export "atomic-load") (param i32) (result i64)
+    (i64.atomic.load offset=8 (local.get 0)))
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f825d000000         	jb	0x78
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8256000000         	jb	0x7a
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 4c89f1               	mov	rcx, r14
+;;   38:	 89c0                 	mov	eax, eax
+;;   3a:	 49bbf0ffffff00000000 	
+;; 				movabs	r11, 0xfffffff0
+;;   44:	 4c01d8               	add	rax, r11
+;;   47:	 31db                 	xor	ebx, ebx
+;;   49:	 4989c3               	mov	r11, rax
+;;   4c:	 4983c304             	add	r11, 4
+;;   50:	 48ba0000000001000000 	
+;; 				movabs	rdx, 0x100000000
+;;   5a:	 4939d3               	cmp	r11, rdx
+;;   5d:	 0f8719000000         	ja	0x7c
+;;   63:	 488b4950             	mov	rcx, qword ptr [rcx + 0x50]
+;;   67:	 480f47cb             	cmova	rcx, rbx
+;;   6b:	 480f47c3             	cmova	rax, rbx
+;;   6f:	 8b0401               	mov	eax, dword ptr [rcx + rax]
+;;   72:	 4883c410             	add	rsp, 0x10
+;;   76:	 5d                   	pop	rbp
+;;   77:	 c3                   	ret	
+;;   78:	 0f0b                 	ud2	
+;;   7a:	 0f0b                 	ud2	
+;;   7c:	 0f0b                 	ud2	
+;;
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8247000000         	jb	0x62
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8240000000         	jb	0x64
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   35:	 4c89f1               	mov	rcx, r14
+;;   38:	 89c0                 	mov	eax, eax
+;;   3a:	 49c7c308000000       	mov	r11, 8
+;;   41:	 4c01d8               	add	rax, r11
+;;   44:	 49c7c307000000       	mov	r11, 7
+;;   4b:	 4c85d8               	test	rax, r11
+;;   4e:	 0f8512000000         	jne	0x66
+;;   54:	 488b4950             	mov	rcx, qword ptr [rcx + 0x50]
+;;   58:	 488b0401             	mov	rax, qword ptr [rcx + rax]
+;;   5c:	 4883c410             	add	rsp, 0x10
+;;   60:	 5d                   	pop	rbp
+;;   61:	 c3                   	ret	
+;;   62:	 0f0b                 	ud2	
+;;   64:	 0f0b                 	ud2	
+;;   66:	 0f0b                 	ud2	
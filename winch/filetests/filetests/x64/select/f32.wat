@@ -8,17 +8,26 @@
  
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 f30f11442414         	movss	dword ptr [rsp + 0x14], xmm0
-;;    e:	 f30f114c2410         	movss	dword ptr [rsp + 0x10], xmm1
-;;   14:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;   18:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   1d:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   21:	 f30f10442410         	movss	xmm0, dword ptr [rsp + 0x10]
-;;   27:	 f30f104c2414         	movss	xmm1, dword ptr [rsp + 0x14]
-;;   2d:	 83f800               	cmp	eax, 0
-;;   30:	 0f8404000000         	je	0x3a
-;;   36:	 f20f10c1             	movsd	xmm0, xmm1
-;;   3a:	 4883c418             	add	rsp, 0x18
-;;   3e:	 5d                   	pop	rbp
-;;   3f:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8244000000         	jb	0x5f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f823d000000         	jb	0x61
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 f30f11442414         	movss	dword ptr [rsp + 0x14], xmm0
+;;   2e:	 f30f114c2410         	movss	dword ptr [rsp + 0x10], xmm1
+;;   34:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   38:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   3d:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   41:	 f30f10442410         	movss	xmm0, dword ptr [rsp + 0x10]
+;;   47:	 f30f104c2414         	movss	xmm1, dword ptr [rsp + 0x14]
+;;   4d:	 85c0                 	test	eax, eax
+;;   4f:	 0f8404000000         	je	0x59
+;;   55:	 f20f10c1             	movsd	xmm0, xmm1
+;;   59:	 4883c418             	add	rsp, 0x18
+;;   5d:	 5d                   	pop	rbp
+;;   5e:	 c3                   	ret	
+;;   5f:	 0f0b                 	ud2	
+;;   61:	 0f0b                 	ud2	
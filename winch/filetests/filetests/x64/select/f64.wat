@@ -8,17 +8,26 @@
  
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec20             	sub	rsp, 0x20
-;;    8:	 f20f11442418         	movsd	qword ptr [rsp + 0x18], xmm0
-;;    e:	 f20f114c2410         	movsd	qword ptr [rsp + 0x10], xmm1
-;;   14:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;   18:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   1d:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   21:	 f20f10442410         	movsd	xmm0, qword ptr [rsp + 0x10]
-;;   27:	 f20f104c2418         	movsd	xmm1, qword ptr [rsp + 0x18]
-;;   2d:	 83f800               	cmp	eax, 0
-;;   30:	 0f8404000000         	je	0x3a
-;;   36:	 f20f10c1             	movsd	xmm0, xmm1
-;;   3a:	 4883c420             	add	rsp, 0x20
-;;   3e:	 5d                   	pop	rbp
-;;   3f:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f8244000000         	jb	0x5f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f823d000000         	jb	0x61
+;;   24:	 4883ec20             	sub	rsp, 0x20
+;;   28:	 f20f11442418         	movsd	qword ptr [rsp + 0x18], xmm0
+;;   2e:	 f20f114c2410         	movsd	qword ptr [rsp + 0x10], xmm1
+;;   34:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   38:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   3d:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   41:	 f20f10442410         	movsd	xmm0, qword ptr [rsp + 0x10]
+;;   47:	 f20f104c2418         	movsd	xmm1, qword ptr [rsp + 0x18]
+;;   4d:	 85c0                 	test	eax, eax
+;;   4f:	 0f8404000000         	je	0x59
+;;   55:	 f20f10c1             	movsd	xmm0, xmm1
+;;   59:	 4883c420             	add	rsp, 0x20
+;;   5d:	 5d                   	pop	rbp
+;;   5e:	 c3                   	ret	
+;;   5f:	 0f0b                 	ud2	
+;;   61:	 0f0b                 	ud2	
@@ -7,17 +7,26 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 897c2414             	mov	dword ptr [rsp + 0x14], edi
-;;    c:	 89742410             	mov	dword ptr [rsp + 0x10], esi
-;;   10:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
-;;   14:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   19:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   1d:	 8b4c2410             	mov	ecx, dword ptr [rsp + 0x10]
-;;   21:	 8b542414             	mov	edx, dword ptr [rsp + 0x14]
-;;   25:	 83f800               	cmp	eax, 0
-;;   28:	 0f45ca               	cmovne	ecx, edx
-;;   2b:	 89c8                 	mov	eax, ecx
-;;   2d:	 4883c418             	add	rsp, 0x18
-;;   31:	 5d                   	pop	rbp
-;;   32:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f8238000000         	jb	0x53
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8231000000         	jb	0x55
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;   2c:	 894c2410             	mov	dword ptr [rsp + 0x10], ecx
+;;   30:	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
+;;   35:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   3a:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   3e:	 8b4c2410             	mov	ecx, dword ptr [rsp + 0x10]
+;;   42:	 8b542414             	mov	edx, dword ptr [rsp + 0x14]
+;;   46:	 85c0                 	test	eax, eax
+;;   48:	 0f45ca               	cmovne	ecx, edx
+;;   4b:	 89c8                 	mov	eax, ecx
+;;   4d:	 4883c418             	add	rsp, 0x18
+;;   51:	 5d                   	pop	rbp
+;;   52:	 c3                   	ret	
+;;   53:	 0f0b                 	ud2	
+;;   55:	 0f0b                 	ud2	
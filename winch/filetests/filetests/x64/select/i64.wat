@@ -8,17 +8,26 @@
  
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec20             	sub	rsp, 0x20
-;;    8:	 48897c2418           	mov	qword ptr [rsp + 0x18], rdi
-;;    d:	 4889742410           	mov	qword ptr [rsp + 0x10], rsi
-;;   12:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
-;;   16:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   1b:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   1f:	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
-;;   24:	 488b542418           	mov	rdx, qword ptr [rsp + 0x18]
-;;   29:	 83f800               	cmp	eax, 0
-;;   2c:	 480f45ca             	cmovne	rcx, rdx
-;;   30:	 4889c8               	mov	rax, rcx
-;;   33:	 4883c420             	add	rsp, 0x20
-;;   37:	 5d                   	pop	rbp
-;;   38:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f823e000000         	jb	0x59
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8237000000         	jb	0x5b
+;;   24:	 4883ec20             	sub	rsp, 0x20
+;;   28:	 4889542418           	mov	qword ptr [rsp + 0x18], rdx
+;;   2d:	 48894c2410           	mov	qword ptr [rsp + 0x10], rcx
+;;   32:	 448944240c           	mov	dword ptr [rsp + 0xc], r8d
+;;   37:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   3c:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   40:	 488b4c2410           	mov	rcx, qword ptr [rsp + 0x10]
+;;   45:	 488b542418           	mov	rdx, qword ptr [rsp + 0x18]
+;;   4a:	 85c0                 	test	eax, eax
+;;   4c:	 480f45ca             	cmovne	rcx, rdx
+;;   50:	 4889c8               	mov	rax, rcx
+;;   53:	 4883c420             	add	rsp, 0x20
+;;   57:	 5d                   	pop	rbp
+;;   58:	 c3                   	ret	
+;;   59:	 0f0b                 	ud2	
+;;   5b:	 0f0b                 	ud2	
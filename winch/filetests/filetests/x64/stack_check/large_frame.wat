@@ -0,0 +1,4225 @@
+;;! target = "x86_64"
+
+;; Frames of at least 32KiB also check that adding their size to the stack
+;; limit doesn't overflow.
+(module
+    (func (local v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128 v128)
+    )
+)
+;;    0:	 55                   	push	rbp
+;;    1:	 4889e5               	mov	rbp, rsp
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c348830000       	add	r11, 0x8348
+;;   15:	 0f8233830000         	jb	0x834e
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f822c830000         	jb	0x8350
+;;   24:	 4881ec48830000       	sub	rsp, 0x8348
+;;   2b:	 4531db               	xor	r11d, r11d
+;;   2e:	 4c899c2440830000     	mov	qword ptr [rsp + 0x8340], r11
+;;   36:	 4c899c2438830000     	mov	qword ptr [rsp + 0x8338], r11
+;;   3e:	 4c899c2430830000     	mov	qword ptr [rsp + 0x8330], r11
+;;   46:	 4c899c2428830000     	mov	qword ptr [rsp + 0x8328], r11
+;;   4e:	 4c899c2420830000     	mov	qword ptr [rsp + 0x8320], r11
+;;   56:	 4c899c2418830000     	mov	qword ptr [rsp + 0x8318], r11
+;;   5e:	 4c899c2410830000     	mov	qword ptr [rsp + 0x8310], r11
+;;   66:	 4c899c2408830000     	mov	qword ptr [rsp + 0x8308], r11
+;;   6e:	 4c899c2400830000     	mov	qword ptr [rsp + 0x8300], r11
+;;   76:	 4c899c24f8820000     	mov	qword ptr [rsp + 0x82f8], r11
+;;   7e:	 4c899c24f0820000     	mov	qword ptr [rsp + 0x82f0], r11
+;;   86:	 4c899c24e8820000     	mov	qword ptr [rsp + 0x82e8], r11
+;;   8e:	 4c899c24e0820000     	mov	qword ptr [rsp + 0x82e0], r11
+;;   96:	 4c899c24d8820000     	mov	qword ptr [rsp + 0x82d8], r11
+;;   9e:	 4c899c24d0820000     	mov	qword ptr [rsp + 0x82d0], r11
+;;   a6:	 4c899c24c8820000     	mov	qword ptr [rsp + 0x82c8], r11
+;;   ae:	 4c899c24c0820000     	mov	qword ptr [rsp + 0x82c0], r11
+;;   b6:	 4c899c24b8820000     	mov	qword ptr [rsp + 0x82b8], r11
+;;   be:	 4c899c24b0820000     	mov	qword ptr [rsp + 0x82b0], r11
+;;   c6:	 4c899c24a8820000     	mov	qword ptr [rsp + 0x82a8], r11
+;;   ce:	 4c899c24a0820000     	mov	qword ptr [rsp + 0x82a0], r11
+;;   d6:	 4c899c2498820000     	mov	qword ptr [rsp + 0x8298], r11
+;;   de:	 4c899c2490820000     	mov	qword ptr [rsp + 0x8290], r11
+;;   e6:	 4c899c2488820000     	mov	qword ptr [rsp + 0x8288], r11
+;;   ee:	 4c899c2480820000     	mov	qword ptr [rsp + 0x8280], r11
+;;   f6:	 4c899c2478820000     	mov	qword ptr [rsp + 0x8278], r11
+;;   fe:	 4c899c2470820000     	mov	qword ptr [rsp + 0x8270], r11
+;;  106:	 4c899c2468820000     	mov	qword ptr [rsp + 0x8268], r11
+;;  10e:	 4c899c2460820000     	mov	qword ptr [rsp + 0x8260], r11
+;;  116:	 4c899c2458820000     	mov	qword ptr [rsp + 0x8258], r11
+;;  11e:	 4c899c2450820000     	mov	qword ptr [rsp + 0x8250], r11
+;;  126:	 4c899c2448820000     	mov	qword ptr [rsp + 0x8248], r11
+;;  12e:	 4c899c2440820000     	mov	qword ptr [rsp + 0x8240], r11
+;;  136:	 4c899c2438820000     	mov	qword ptr [rsp + 0x8238], r11
+;;  13e:	 4c899c2430820000     	mov	qword ptr [rsp + 0x8230], r11
+;;  146:	 4c899c2428820000     	mov	qword ptr [rsp + 0x8228], r11
+;;  14e:	 4c899c2420820000     	mov	qword ptr [rsp + 0x8220], r11
+;;  156:	 4c899c2418820000     	mov	qword ptr [rsp + 0x8218], r11
+;;  15e:	 4c899c2410820000     	mov	qword ptr [rsp + 0x8210], r11
+;;  166:	 4c899c2408820000     	mov	qword ptr [rsp + 0x8208], r11
+;;  16e:	 4c899c2400820000     	mov	qword ptr [rsp + 0x8200], r11
+;;  176:	 4c899c24f8810000     	mov	qword ptr [rsp + 0x81f8], r11
+;;  17e:	 4c899c24f0810000     	mov	qword ptr [rsp + 0x81f0], r11
+;;  186:	 4c899c24e8810000     	mov	qword ptr [rsp + 0x81e8], r11
+;;  18e:	 4c899c24e0810000     	mov	qword ptr [rsp + 0x81e0], r11
+;;  196:	 4c899c24d8810000     	mov	qword ptr [rsp + 0x81d8], r11
+;;  19e:	 4c899c24d0810000     	mov	qword ptr [rsp + 0x81d0], r11
+;;  1a6:	 4c899c24c8810000     	mov	qword ptr [rsp + 0x81c8], r11
+;;  1ae:	 4c899c24c0810000     	mov	qword ptr [rsp + 0x81c0], r11
+;;  1b6:	 4c899c24b8810000     	mov	qword ptr [rsp + 0x81b8], r11
+;;  1be:	 4c899c24b0810000     	mov	qword ptr [rsp + 0x81b0], r11
+;;  1c6:	 4c899c24a8810000     	mov	qword ptr [rsp + 0x81a8], r11
+;;  1ce:	 4c899c24a0810000     	mov	qword ptr [rsp + 0x81a0], r11
+;;  1d6:	 4c899c2498810000     	mov	qword ptr [rsp + 0x8198], r11
+;;  1de:	 4c899c2490810000     	mov	qword ptr [rsp + 0x8190], r11
+;;  1e6:	 4c899c2488810000     	mov	qword ptr [rsp + 0x8188], r11
+;;  1ee:	 4c899c2480810000     	mov	qword ptr [rsp + 0x8180], r11
+;;  1f6:	 4c899c2478810000     	mov	qword ptr [rsp + 0x8178], r11
+;;  1fe:	 4c899c2470810000     	mov	qword ptr [rsp + 0x8170], r11
+;;  206:	 4c899c2468810000     	mov	qword ptr [rsp + 0x8168], r11
+;;  20e:	 4c899c2460810000     	mov	qword ptr [rsp + 0x8160], r11
+;;  216:	 4c899c2458810000     	mov	qword ptr [rsp + 0x8158], r11
+;;  21e:	 4c899c2450810000     	mov	qword ptr [rsp + 0x8150], r11
+;;  226:	 4c899c2448810000     	mov	qword ptr [rsp + 0x8148], r11
+;;  22e:	 4c899c2440810000     	mov	qword ptr [rsp + 0x8140], r11
+;;  236:	 4c899c2438810000     	mov	qword ptr [rsp + 0x8138], r11
+;;  23e:	 4c899c2430810000     	mov	qword ptr [rsp + 0x8130], r11
+;;  246:	 4c899c2428810000     	mov	qword ptr [rsp + 0x8128], r11
+;;  24e:	 4c899c2420810000     	mov	qword ptr [rsp + 0x8120], r11
+;;  256:	 4c899c2418810000     	mov	qword ptr [rsp + 0x8118], r11
+;;  25e:	 4c899c2410810000     	mov	qword ptr [rsp + 0x8110], r11
+;;  266:	 4c899c2408810000     	mov	qword ptr [rsp + 0x8108], r11
+;;  26e:	 4c899c2400810000     	mov	qword ptr [rsp + 0x8100], r11
+;;  276:	 4c899c24f8800000     	mov	qword ptr [rsp + 0x80f8], r11
+;;  27e:	 4c899c24f0800000     	mov	qword ptr [rsp + 0x80f0], r11
+;;  286:	 4c899c24e8800000     	mov	qword ptr [rsp + 0x80e8], r11
+;;  28e:	 4c899c24e0800000     	mov	qword ptr [rsp + 0x80e0], r11
+;;  296:	 4c899c24d8800000     	mov	qword ptr [rsp + 0x80d8], r11
+;;  29e:	 4c899c24d0800000     	mov	qword ptr [rsp + 0x80d0], r11
+;;  2a6:	 4c899c24c8800000     	mov	qword ptr [rsp + 0x80c8], r11
+;;  2ae:	 4c899c24c0800000     	mov	qword ptr [rsp + 0x80c0], r11
+;;  2b6:	 4c899c24b8800000     	mov	qword ptr [rsp + 0x80b8], r11
+;;  2be:	 4c899c24b0800000     	mov	qword ptr [rsp + 0x80b0], r11
+;;  2c6:	 4c899c24a8800000     	mov	qword ptr [rsp + 0x80a8], r11
+;;  2ce:	 4c899c24a0800000     	mov	qword ptr [rsp + 0x80a0], r11
+;;  2d6:	 4c899c2498800000     	mov	qword ptr [rsp + 0x8098], r11
+;;  2de:	 4c899c2490800000     	mov	qword ptr [rsp + 0x8090], r11
+;;  2e6:	 4c899c2488800000     	mov	qword ptr [rsp + 0x8088], r11
+;;  2ee:	 4c899c2480800000     	mov	qword ptr [rsp + 0x8080], r11
+;;  2f6:	 4c899c2478800000     	mov	qword ptr [rsp + 0x8078], r11
+;;  2fe:	 4c899c2470800000     	mov	qword ptr [rsp + 0x8070], r11
+;;  306:	 4c899c2468800000     	mov	qword ptr [rsp + 0x8068], r11
+;;  30e:	 4c899c2460800000     	mov	qword ptr [rsp + 0x8060], r11
+;;  316:	 4c899c2458800000     	mov	qword ptr [rsp + 0x8058], r11
+;;  31e:	 4c899c2450800000     	mov	qword ptr [rsp + 0x8050], r11
+;;  326:	 4c899c2448800000     	mov	qword ptr [rsp + 0x8048], r11
+;;  32e:	 4c899c2440800000     	mov	qword ptr [rsp + 0x8040], r11
+;;  336:	 4c899c2438800000     	mov	qword ptr [rsp + 0x8038], r11
+;;  33e:	 4c899c2430800000     	mov	qword ptr [rsp + 0x8030], r11
+;;  346:	 4c899c2428800000     	mov	qword ptr [rsp + 0x8028], r11
+;;  34e:	 4c899c2420800000     	mov	qword ptr [rsp + 0x8020], r11
+;;  356:	 4c899c2418800000     	mov	qword ptr [rsp + 0x8018], r11
+;;  35e:	 4c899c2410800000     	mov	qword ptr [rsp + 0x8010], r11
+;;  366:	 4c899c2408800000     	mov	qword ptr [rsp + 0x8008], r11
+;;  36e:	 4c899c2400800000     	mov	qword ptr [rsp + 0x8000], r11
+;;  376:	 4c899c24f87f0000     	mov	qword ptr [rsp + 0x7ff8], r11
+;;  37e:	 4c899c24f07f0000     	mov	qword ptr [rsp + 0x7ff0], r11
+;;  386:	 4c899c24e87f0000     	mov	qword ptr [rsp + 0x7fe8], r11
+;;  38e:	 4c899c24e07f0000     	mov	qword ptr [rsp + 0x7fe0], r11
+;;  396:	 4c899c24d87f0000     	mov	qword ptr [rsp + 0x7fd8], r11
+;;  39e:	 4c899c24d07f0000     	mov	qword ptr [rsp + 0x7fd0], r11
+;;  3a6:	 4c899c24c87f0000     	mov	qword ptr [rsp + 0x7fc8], r11
+;;  3ae:	 4c899c24c07f0000     	mov	qword ptr [rsp + 0x7fc0], r11
+;;  3b6:	 4c899c24b87f0000     	mov	qword ptr [rsp + 0x7fb8], r11
+;;  3be:	 4c899c24b07f0000     	mov	qword ptr [rsp + 0x7fb0], r11
+;;  3c6:	 4c899c24a87f0000     	mov	qword ptr [rsp + 0x7fa8], r11
+;;  3ce:	 4c899c24a07f0000     	mov	qword ptr [rsp + 0x7fa0], r11
+;;  3d6:	 4c899c24987f0000     	mov	qword ptr [rsp + 0x7f98], r11
+;;  3de:	 4c899c24907f0000     	mov	qword ptr [rsp + 0x7f90], r11
+;;  3e6:	 4c899c24887f0000     	mov	qword ptr [rsp + 0x7f88], r11
+;;  3ee:	 4c899c24807f0000     	mov	qword ptr [rsp + 0x7f80], r11
+;;  3f6:	 4c899c24787f0000     	mov	qword ptr [rsp + 0x7f78], r11
+;;  3fe:	 4c899c24707f0000     	mov	qword ptr [rsp + 0x7f70], r11
+;;  406:	 4c899c24687f0000     	mov	qword ptr [rsp + 0x7f68], r11
+;;  40e:	 4c899c24607f0000     	mov	qword ptr [rsp + 0x7f60], r11
+;;  416:	 4c899c24587f0000     	mov	qword ptr [rsp + 0x7f58], r11
+;;  41e:	 4c899c24507f0000     	mov	qword ptr [rsp + 0x7f50], r11
+;;  426:	 4c899c24487f0000     	mov	qword ptr [rsp + 0x7f48], r11
+;;  42e:	 4c899c24407f0000     	mov	qword ptr [rsp + 0x7f40], r11
+;;  436:	 4c899c24387f0000     	mov	qword ptr [rsp + 0x7f38], r11
+;;  43e:	 4c899c24307f0000     	mov	qword ptr [rsp + 0x7f30], r11
+;;  446:	 4c899c24287f0000     	mov	qword ptr [rsp + 0x7f28], r11
+;;  44e:	 4c899c24207f0000     	mov	qword ptr [rsp + 0x7f20], r11
+;;  456:	 4c899c24187f0000     	mov	qword ptr [rsp + 0x7f18], r11
+;;  45e:	 4c899c24107f0000     	mov	qword ptr [rsp + 0x7f10], r11
+;;  466:	 4c899c24087f0000     	mov	qword ptr [rsp + 0x7f08], r11
+;;  46e:	 4c899c24007f0000     	mov	qword ptr [rsp + 0x7f00], r11
+;;  476:	 4c899c24f87e0000     	mov	qword ptr [rsp + 0x7ef8], r11
+;;  47e:	 4c899c24f07e0000     	mov	qword ptr [rsp + 0x7ef0], r11
+;;  486:	 4c899c24e87e0000     	mov	qword ptr [rsp + 0x7ee8], r11
+;;  48e:	 4c899c24e07e0000     	mov	qword ptr [rsp + 0x7ee0], r11
+;;  496:	 4c899c24d87e0000     	mov	qword ptr [rsp + 0x7ed8], r11
+;;  49e:	 4c899c24d07e0000     	mov	qword ptr [rsp + 0x7ed0], r11
+;;  4a6:	 4c899c24c87e0000     	mov	qword ptr [rsp + 0x7ec8], r11
+;;  4ae:	 4c899c24c07e0000     	mov	qword ptr [rsp + 0x7ec0], r11
+;;  4b6:	 4c899c24b87e0000     	mov	qword ptr [rsp + 0x7eb8], r11
+;;  4be:	 4c899c24b07e0000     	mov	qword ptr [rsp + 0x7eb0], r11
+;;  4c6:	 4c899c24a87e0000     	mov	qword ptr [rsp + 0x7ea8], r11
+;;  4ce:	 4c899c24a07e0000     	mov	qword ptr [rsp + 0x7ea0], r11
+;;  4d6:	 4c899c24987e0000     	mov	qword ptr [rsp + 0x7e98], r11
+;;  4de:	 4c899c24907e0000     	mov	qword ptr [rsp + 0x7e90], r11
+;;  4e6:	 4c899c24887e0000     	mov	qword ptr [rsp + 0x7e88], r11
+;;  4ee:	 4c899c24807e0000     	mov	qword ptr [rsp + 0x7e80], r11
+;;  4f6:	 4c899c24787e0000     	mov	qword ptr [rsp + 0x7e78], r11
+;;  4fe:	 4c899c24707e0000     	mov	qword ptr [rsp + 0x7e70], r11
+;;  506:	 4c899c24687e0000     	mov	qword ptr [rsp + 0x7e68], r11
+;;  50e:	 4c899c24607e0000     	mov	qword ptr [rsp + 0x7e60], r11
+;;  516:	 4c899c24587e0000     	mov	qword ptr [rsp + 0x7e58], r11
+;;  51e:	 4c899c24507e0000     	mov	qword ptr [rsp + 0x7e50], r11
+;;  526:	 4c899c24487e0000     	mov	qword ptr [rsp + 0x7e48], r11
+;;  52e:	 4c899c24407e0000     	mov	qword ptr [rsp + 0x7e40], r11
+;;  536:	 4c899c24387e0000     	mov	qword ptr [rsp + 0x7e38], r11
+;;  53e:	 4c899c24307e0000     	mov	qword ptr [rsp + 0x7e30], r11
+;;  546:	 4c899c24287e0000     	mov	qword ptr [rsp + 0x7e28], r11
+;;  54e:	 4c899c24207e0000     	mov	qword ptr [rsp + 0x7e20], r11
+;;  556:	 4c899c24187e0000     	mov	qword ptr [rsp + 0x7e18], r11
+;;  55e:	 4c899c24107e0000     	mov	qword ptr [rsp + 0x7e10], r11
+;;  566:	 4c899c24087e0000     	mov	qword ptr [rsp + 0x7e08], r11
+;;  56e:	 4c899c24007e0000     	mov	qword ptr [rsp + 0x7e00], r11
+;;  576:	 4c899c24f87d0000     	mov	qword ptr [rsp + 0x7df8], r11
+;;  57e:	 4c899c24f07d0000     	mov	qword ptr [rsp + 0x7df0], r11
+;;  586:	 4c899c24e87d0000     	mov	qword ptr [rsp + 0x7de8], r11
+;;  58e:	 4c899c24e07d0000     	mov	qword ptr [rsp + 0x7de0], r11
+;;  596:	 4c899c24d87d0000     	mov	qword ptr [rsp + 0x7dd8], r11
+;;  59e:	 4c899c24d07d0000     	mov	qword ptr [rsp + 0x7dd0], r11
+;;  5a6:	 4c899c24c87d0000     	mov	qword ptr [rsp + 0x7dc8], r11
+;;  5ae:	 4c899c24c07d0000     	mov	qword ptr [rsp + 0x7dc0], r11
+;;  5b6:	 4c899c24b87d0000     	mov	qword ptr [rsp + 0x7db8], r11
+;;  5be:	 4c899c24b07d0000     	mov	qword ptr [rsp + 0x7db0], r11
+;;  5c6:	 4c899c24a87d0000     	mov	qword ptr [rsp + 0x7da8], r11
+;;  5ce:	 4c899c24a07d0000     	mov	qword ptr [rsp + 0x7da0], r11
+;;  5d6:	 4c899c24987d0000     	mov	qword ptr [rsp + 0x7d98], r11
+;;  5de:	 4c899c24907d0000     	mov	qword ptr [rsp + 0x7d90], r11
+;;  5e6:	 4c899c24887d0000     	mov	qword ptr [rsp + 0x7d88], r11
+;;  5ee:	 4c899c24807d0000     	mov	qword ptr [rsp + 0x7d80], r11
+;;  5f6:	 4c899c24787d0000     	mov	qword ptr [rsp + 0x7d78], r11
+;;  5fe:	 4c899c24707d0000     	mov	qword ptr [rsp + 0x7d70], r11
+;;  606:	 4c899c24687d0000     	mov	qword ptr [rsp + 0x7d68], r11
+;;  60e:	 4c899c24607d0000     	mov	qword ptr [rsp + 0x7d60], r11
+;;  616:	 4c899c24587d0000     	mov	qword ptr [rsp + 0x7d58], r11
+;;  61e:	 4c899c24507d0000     	mov	qword ptr [rsp + 0x7d50], r11
+;;  626:	 4c899c24487d0000     	mov	qword ptr [rsp + 0x7d48], r11
+;;  62e:	 4c899c24407d0000     	mov	qword ptr [rsp + 0x7d40], r11
+;;  636:	 4c899c24387d0000     	mov	qword ptr [rsp + 0x7d38], r11
+;;  63e:	 4c899c24307d0000     	mov	qword ptr [rsp + 0x7d30], r11
+;;  646:	 4c899c24287d0000     	mov	qword ptr [rsp + 0x7d28], r11
+;;  64e:	 4c899c24207d0000     	mov	qword ptr [rsp + 0x7d20], r11
+;;  656:	 4c899c24187d0000     	mov	qword ptr [rsp + 0x7d18], r11
+;;  65e:	 4c899c24107d0000     	mov	qword ptr [rsp + 0x7d10], r11
+;;  666:	 4c899c24087d0000     	mov	qword ptr [rsp + 0x7d08], r11
+;;  66e:	 4c899c24007d0000     	mov	qword ptr [rsp + 0x7d00], r11
+;;  676:	 4c899c24f87c0000     	mov	qword ptr [rsp + 0x7cf8], r11
+;;  67e:	 4c899c24f07c0000     	mov	qword ptr [rsp + 0x7cf0], r11
+;;  686:	 4c899c24e87c0000     	mov	qword ptr [rsp + 0x7ce8], r11
+;;  68e:	 4c899c24e07c0000     	mov	qword ptr [rsp + 0x7ce0], r11
+;;  696:	 4c899c24d87c0000     	mov	qword ptr [rsp + 0x7cd8], r11
+;;  69e:	 4c899c24d07c0000     	mov	qword ptr [rsp + 0x7cd0], r11
+;;  6a6:	 4c899c24c87c0000     	mov	qword ptr [rsp + 0x7cc8], r11
+;;  6ae:	 4c899c24c07c0000     	mov	qword ptr [rsp + 0x7cc0], r11
+;;  6b6:	 4c899c24b87c0000     	mov	qword ptr [rsp + 0x7cb8], r11
+;;  6be:	 4c899c24b07c0000     	mov	qword ptr [rsp + 0x7cb0], r11
+;;  6c6:	 4c899c24a87c0000     	mov	qword ptr [rsp + 0x7ca8], r11
+;;  6ce:	 4c899c24a07c0000     	mov	qword ptr [rsp + 0x7ca0], r11
+;;  6d6:	 4c899c24987c0000     	mov	qword ptr [rsp + 0x7c98], r11
+;;  6de:	 4c899c24907c0000     	mov	qword ptr [rsp + 0x7c90], r11
+;;  6e6:	 4c899c24887c0000     	mov	qword ptr [rsp + 0x7c88], r11
+;;  6ee:	 4c899c24807c0000     	mov	qword ptr [rsp + 0x7c80], r11
+;;  6f6:	 4c899c24787c0000     	mov	qword ptr [rsp + 0x7c78], r11
+;;  6fe:	 4c899c24707c0000     	mov	qword ptr [rsp + 0x7c70], r11
+;;  706:	 4c899c24687c0000     	mov	qword ptr [rsp + 0x7c68], r11
+;;  70e:	 4c899c24607c0000     	mov	qword ptr [rsp + 0x7c60], r11
+;;  716:	 4c899c24587c0000     	mov	qword ptr [rsp + 0x7c58], r11
+;;  71e:	 4c899c24507c0000     	mov	qword ptr [rsp + 0x7c50], r11
+;;  726:	 4c899c24487c0000     	mov	qword ptr [rsp + 0x7c48], r11
+;;  72e:	 4c899c24407c0000     	mov	qword ptr [rsp + 0x7c40], r11
+;;  736:	 4c899c24387c0000     	mov	qword ptr [rsp + 0x7c38], r11
+;;  73e:	 4c899c24307c0000     	mov	qword ptr [rsp + 0x7c30], r11
+;;  746:	 4c899c24287c0000     	mov	qword ptr [rsp + 0x7c28], r11
+;;  74e:	 4c899c24207c0000     	mov	qword ptr [rsp + 0x7c20], r11
+;;  756:	 4c899c24187c0000     	mov	qword ptr [rsp + 0x7c18], r11
+;;  75e:	 4c899c24107c0000     	mov	qword ptr [rsp + 0x7c10], r11
+;;  766:	 4c899c24087c0000     	mov	qword ptr [rsp + 0x7c08], r11
+;;  76e:	 4c899c24007c0000     	mov	qword ptr [rsp + 0x7c00], r11
+;;  776:	 4c899c24f87b0000     	mov	qword ptr [rsp + 0x7bf8], r11
+;;  77e:	 4c899c24f07b0000     	mov	qword ptr [rsp + 0x7bf0], r11
+;;  786:	 4c899c24e87b0000     	mov	qword ptr [rsp + 0x7be8], r11
+;;  78e:	 4c899c24e07b0000     	mov	qword ptr [rsp + 0x7be0], r11
+;;  796:	 4c899c24d87b0000     	mov	qword ptr [rsp + 0x7bd8], r11
+;;  79e:	 4c899c24d07b0000     	mov	qword ptr [rsp + 0x7bd0], r11
+;;  7a6:	 4c899c24c87b0000     	mov	qword ptr [rsp + 0x7bc8], r11
+;;  7ae:	 4c899c24c07b0000     	mov	qword ptr [rsp + 0x7bc0], r11
+;;  7b6:	 4c899c24b87b0000     	mov	qword ptr [rsp + 0x7bb8], r11
+;;  7be:	 4c899c24b07b0000     	mov	qword ptr [rsp + 0x7bb0], r11
+;;  7c6:	 4c899c24a87b0000     	mov	qword ptr [rsp + 0x7ba8], r11
+;;  7ce:	 4c899c24a07b0000     	mov	qword ptr [rsp + 0x7ba0], r11
+;;  7d6:	 4c899c24987b0000     	mov	qword ptr [rsp + 0x7b98], r11
+;;  7de:	 4c899c24907b0000     	mov	qword ptr [rsp + 0x7b90], r11
+;;  7e6:	 4c899c24887b0000     	mov	qword ptr [rsp + 0x7b88], r11
+;;  7ee:	 4c899c24807b0000     	mov	qword ptr [rsp + 0x7b80], r11
+;;  7f6:	 4c899c24787b0000     	mov	qword ptr [rsp + 0x7b78], r11
+;;  7fe:	 4c899c24707b0000     	mov	qword ptr [rsp + 0x7b70], r11
+;;  806:	 4c899c24687b0000     	mov	qword ptr [rsp + 0x7b68], r11
+;;  80e:	 4c899c24607b0000     	mov	qword ptr [rsp + 0x7b60], r11
+;;  816:	 4c899c24587b0000     	mov	qword ptr [rsp + 0x7b58], r11
+;;  81e:	 4c899c24507b0000     	mov	qword ptr [rsp + 0x7b50], r11
+;;  826:	 4c899c24487b0000     	mov	qword ptr [rsp + 0x7b48], r11
+;;  82e:	 4c899c24407b0000     	mov	qword ptr [rsp + 0x7b40], r11
+;;  836:	 4c899c24387b0000     	mov	qword ptr [rsp + 0x7b38], r11
+;;  83e:	 4c899c24307b0000     	mov	qword ptr [rsp + 0x7b30], r11
+;;  846:	 4c899c24287b0000     	mov	qword ptr [rsp + 0x7b28], r11
+;;  84e:	 4c899c24207b0000     	mov	qword ptr [rsp + 0x7b20], r11
+;;  856:	 4c899c24187b0000     	mov	qword ptr [rsp + 0x7b18], r11
+;;  85e:	 4c899c24107b0000     	mov	qword ptr [rsp + 0x7b10], r11
+;;  866:	 4c899c24087b0000     	mov	qword ptr [rsp + 0x7b08], r11
+;;  86e:	 4c899c24007b0000     	mov	qword ptr [rsp + 0x7b00], r11
+;;  876:	 4c899c24f87a0000     	mov	qword ptr [rsp + 0x7af8], r11
+;;  87e:	 4c899c24f07a0000     	mov	qword ptr [rsp + 0x7af0], r11
+;;  886:	 4c899c24e87a0000     	mov	qword ptr [rsp + 0x7ae8], r11
+;;  88e:	 4c899c24e07a0000     	mov	qword ptr [rsp + 0x7ae0], r11
+;;  896:	 4c899c24d87a0000     	mov	qword ptr [rsp + 0x7ad8], r11
+;;  89e:	 4c899c24d07a0000     	mov	qword ptr [rsp + 0x7ad0], r11
+;;  8a6:	 4c899c24c87a0000     	mov	qword ptr [rsp + 0x7ac8], r11
+;;  8ae:	 4c899c24c07a0000     	mov	qword ptr [rsp + 0x7ac0], r11
+;;  8b6:	 4c899c24b87a0000     	mov	qword ptr [rsp + 0x7ab8], r11
+;;  8be:	 4c899c24b07a0000     	mov	qword ptr [rsp + 0x7ab0], r11
+;;  8c6:	 4c899c24a87a0000     	mov	qword ptr [rsp + 0x7aa8], r11
+;;  8ce:	 4c899c24a07a0000     	mov	qword ptr [rsp + 0x7aa0], r11
+;;  8d6:	 4c899c24987a0000     	mov	qword ptr [rsp + 0x7a98], r11
+;;  8de:	 4c899c24907a0000     	mov	qword ptr [rsp + 0x7a90], r11
+;;  8e6:	 4c899c24887a0000     	mov	qword ptr [rsp + 0x7a88], r11
+;;  8ee:	 4c899c24807a0000     	mov	qword ptr [rsp + 0x7a80], r11
+;;  8f6:	 4c899c24787a0000     	mov	qword ptr [rsp + 0x7a78], r11
+;;  8fe:	 4c899c24707a0000     	mov	qword ptr [rsp + 0x7a70], r11
+;;  906:	 4c899c24687a0000     	mov	qword ptr [rsp + 0x7a68], r11
+;;  90e:	 4c899c24607a0000     	mov	qword ptr [rsp + 0x7a60], r11
+;;  916:	 4c899c24587a0000     	mov	qword ptr [rsp + 0x7a58], r11
+;;  91e:	 4c899c24507a0000     	mov	qword ptr [rsp + 0x7a50], r11
+;;  926:	 4c899c24487a0000     	mov	qword ptr [rsp + 0x7a48], r11
+;;  92e:	 4c899c24407a0000     	mov	qword ptr [rsp + 0x7a40], r11
+;;  936:	 4c899c24387a0000     	mov	qword ptr [rsp + 0x7a38], r11
+;;  93e:	 4c899c24307a0000     	mov	qword ptr [rsp + 0x7a30], r11
+;;  946:	 4c899c24287a0000     	mov	qword ptr [rsp + 0x7a28], r11
+;;  94e:	 4c899c24207a0000     	mov	qword ptr [rsp + 0x7a20], r11
+;;  956:	 4c899c24187a0000     	mov	qword ptr [rsp + 0x7a18], r11
+;;  95e:	 4c899c24107a0000     	mov	qword ptr [rsp + 0x7a10], r11
+;;  966:	 4c899c24087a0000     	mov	qword ptr [rsp + 0x7a08], r11
+;;  96e:	 4c899c24007a0000     	mov	qword ptr [rsp + 0x7a00], r11
+;;  976:	 4c899c24f8790000     	mov	qword ptr [rsp + 0x79f8], r11
+;;  97e:	 4c899c24f0790000     	mov	qword ptr [rsp + 0x79f0], r11
+;;  986:	 4c899c24e8790000     	mov	qword ptr [rsp + 0x79e8], r11
+;;  98e:	 4c899c24e0790000     	mov	qword ptr [rsp + 0x79e0], r11
+;;  996:	 4c899c24d8790000     	mov	qword ptr [rsp + 0x79d8], r11
+;;  99e:	 4c899c24d0790000     	mov	qword ptr [rsp + 0x79d0], r11
+;;  9a6:	 4c899c24c8790000     	mov	qword ptr [rsp + 0x79c8], r11
+;;  9ae:	 4c899c24c0790000     	mov	qword ptr [rsp + 0x79c0], r11
+;;  9b6:	 4c899c24b8790000     	mov	qword ptr [rsp + 0x79b8], r11
+;;  9be:	 4c899c24b0790000     	mov	qword ptr [rsp + 0x79b0], r11
+;;  9c6:	 4c899c24a8790000     	mov	qword ptr [rsp + 0x79a8], r11
+;;  9ce:	 4c899c24a0790000     	mov	qword ptr [rsp + 0x79a0], r11
+;;  9d6:	 4c899c2498790000     	mov	qword ptr [rsp + 0x7998], r11
+;;  9de:	 4c899c2490790000     	mov	qword ptr [rsp + 0x7990], r11
+;;  9e6:	 4c899c2488790000     	mov	qword ptr [rsp + 0x7988], r11
+;;  9ee:	 4c899c2480790000     	mov	qword ptr [rsp + 0x7980], r11
+;;  9f6:	 4c899c2478790000     	mov	qword ptr [rsp + 0x7978], r11
+;;  9fe:	 4c899c2470790000     	mov	qword ptr [rsp + 0x7970], r11
+;;  a06:	 4c899c2468790000     	mov	qword ptr [rsp + 0x7968], r11
+;;  a0e:	 4c899c2460790000     	mov	qword ptr [rsp + 0x7960], r11
+;;  a16:	 4c899c2458790000     	mov	qword ptr [rsp + 0x7958], r11
+;;  a1e:	 4c899c2450790000     	mov	qword ptr [rsp + 0x7950], r11
+;;  a26:	 4c899c2448790000     	mov	qword ptr [rsp + 0x7948], r11
+;;  a2e:	 4c899c2440790000     	mov	qword ptr [rsp + 0x7940], r11
+;;  a36:	 4c899c2438790000     	mov	qword ptr [rsp + 0x7938], r11
+;;  a3e:	 4c899c2430790000     	mov	qword ptr [rsp + 0x7930], r11
+;;  a46:	 4c899c2428790000     	mov	qword ptr [rsp + 0x7928], r11
+;;  a4e:	 4c899c2420790000     	mov	qword ptr [rsp + 0x7920], r11
+;;  a56:	 4c899c2418790000     	mov	qword ptr [rsp + 0x7918], r11
+;;  a5e:	 4c899c2410790000     	mov	qword ptr [rsp + 0x7910], r11
+;;  a66:	 4c899c2408790000     	mov	qword ptr [rsp + 0x7908], r11
+;;  a6e:	 4c899c2400790000     	mov	qword ptr [rsp + 0x7900], r11
+;;  a76:	 4c899c24f8780000     	mov	qword ptr [rsp + 0x78f8], r11
+;;  a7e:	 4c899c24f0780000     	mov	qword ptr [rsp + 0x78f0], r11
+;;  a86:	 4c899c24e8780000     	mov	qword ptr [rsp + 0x78e8], r11
+;;  a8e:	 4c899c24e0780000     	mov	qword ptr [rsp + 0x78e0], r11
+;;  a96:	 4c899c24d8780000     	mov	qword ptr [rsp + 0x78d8], r11
+;;  a9e:	 4c899c24d0780000     	mov	qword ptr [rsp + 0x78d0], r11
+;;  aa6:	 4c899c24c8780000     	mov	qword ptr [rsp + 0x78c8], r11
+;;  aae:	 4c899c24c0780000     	mov	qword ptr [rsp + 0x78c0], r11
+;;  ab6:	 4c899c24b8780000     	mov	qword ptr [rsp + 0x78b8], r11
+;;  abe:	 4c899c24b0780000     	mov	qword ptr [rsp + 0x78b0], r11
+;;  ac6:	 4c899c24a8780000     	mov	qword ptr [rsp + 0x78a8], r11
+;;  ace:	 4c899c24a0780000     	mov	qword ptr [rsp + 0x78a0], r11
+;;  ad6:	 4c899c2498780000     	mov	qword ptr [rsp + 0x7898], r11
+;;  ade:	 4c899c2490780000     	mov	qword ptr [rsp + 0x7890], r11
+;;  ae6:	 4c899c2488780000     	mov	qword ptr [rsp + 0x7888], r11
+;;  aee:	 4c899c2480780000     	mov	qword ptr [rsp + 0x7880], r11
+;;  af6:	 4c899c2478780000     	mov	qword ptr [rsp + 0x7878], r11
+;;  afe:	 4c899c2470780000     	mov	qword ptr [rsp + 0x7870], r11
+;;  b06:	 4c899c2468780000     	mov	qword ptr [rsp + 0x7868], r11
+;;  b0e:	 4c899c2460780000     	mov	qword ptr [rsp + 0x7860], r11
+;;  b16:	 4c899c2458780000     	mov	qword ptr [rsp + 0x7858], r11
+;;  b1e:	 4c899c2450780000     	mov	qword ptr [rsp + 0x7850], r11
+;;  b26:	 4c899c2448780000     	mov	qword ptr [rsp + 0x7848], r11
+;;  b2e:	 4c899c2440780000     	mov	qword ptr [rsp + 0x7840], r11
+;;  b36:	 4c899c2438780000     	mov	qword ptr [rsp + 0x7838], r11
+;;  b3e:	 4c899c2430780000     	mov	qword ptr [rsp + 0x7830], r11
+;;  b46:	 4c899c2428780000     	mov	qword ptr [rsp + 0x7828], r11
+;;  b4e:	 4c899c2420780000     	mov	qword ptr [rsp + 0x7820], r11
+;;  b56:	 4c899c2418780000     	mov	qword ptr [rsp + 0x7818], r11
+;;  b5e:	 4c899c2410780000     	mov	qword ptr [rsp + 0x7810], r11
+;;  b66:	 4c899c2408780000     	mov	qword ptr [rsp + 0x7808], r11
+;;  b6e:	 4c899c2400780000     	mov	qword ptr [rsp + 0x7800], r11
+;;  b76:	 4c899c24f8770000     	mov	qword ptr [rsp + 0x77f8], r11
+;;  b7e:	 4c899c24f0770000     	mov	qword ptr [rsp + 0x77f0], r11
+;;  b86:	 4c899c24e8770000     	mov	qword ptr [rsp + 0x77e8], r11
+;;  b8e:	 4c899c24e0770000     	mov	qword ptr [rsp + 0x77e0], r11
+;;  b96:	 4c899c24d8770000     	mov	qword ptr [rsp + 0x77d8], r11
+;;  b9e:	 4c899c24d0770000     	mov	qword ptr [rsp + 0x77d0], r11
+;;  ba6:	 4c899c24c8770000     	mov	qword ptr [rsp + 0x77c8], r11
+;;  bae:	 4c899c24c0770000     	mov	qword ptr [rsp + 0x77c0], r11
+;;  bb6:	 4c899c24b8770000     	mov	qword ptr [rsp + 0x77b8], r11
+;;  bbe:	 4c899c24b0770000     	mov	qword ptr [rsp + 0x77b0], r11
+;;  bc6:	 4c899c24a8770000     	mov	qword ptr [rsp + 0x77a8], r11
+;;  bce:	 4c899c24a0770000     	mov	qword ptr [rsp + 0x77a0], r11
+;;  bd6:	 4c899c2498770000     	mov	qword ptr [rsp + 0x7798], r11
+;;  bde:	 4c899c2490770000     	mov	qword ptr [rsp + 0x7790], r11
+;;  be6:	 4c899c2488770000     	mov	qword ptr [rsp + 0x7788], r11
+;;  bee:	 4c899c2480770000     	mov	qword ptr [rsp + 0x7780], r11
+;;  bf6:	 4c899c2478770000     	mov	qword ptr [rsp + 0x7778], r11
+;;  bfe:	 4c899c2470770000     	mov	qword ptr [rsp + 0x7770], r11
+;;  c06:	 4c899c2468770000     	mov	qword ptr [rsp + 0x7768], r11
+;;  c0e:	 4c899c2460770000     	mov	qword ptr [rsp + 0x7760], r11
+;;  c16:	 4c899c2458770000     	mov	qword ptr [rsp + 0x7758], r11
+;;  c1e:	 4c899c2450770000     	mov	qword ptr [rsp + 0x7750], r11
+;;  c26:	 4c899c2448770000     	mov	qword ptr [rsp + 0x7748], r11
+;;  c2e:	 4c899c2440770000     	mov	qword ptr [rsp + 0x7740], r11
+;;  c36:	 4c899c2438770000     	mov	qword ptr [rsp + 0x7738], r11
+;;  c3e:	 4c899c2430770000     	mov	qword ptr [rsp + 0x7730], r11
+;;  c46:	 4c899c2428770000     	mov	qword ptr [rsp + 0x7728], r11
+;;  c4e:	 4c899c2420770000     	mov	qword ptr [rsp + 0x7720], r11
+;;  c56:	 4c899c2418770000     	mov	qword ptr [rsp + 0x7718], r11
+;;  c5e:	 4c899c2410770000     	mov	qword ptr [rsp + 0x7710], r11
+;;  c66:	 4c899c2408770000     	mov	qword ptr [rsp + 0x7708], r11
+;;  c6e:	 4c899c2400770000     	mov	qword ptr [rsp + 0x7700], r11
+;;  c76:	 4c899c24f8760000     	mov	qword ptr [rsp + 0x76f8], r11
+;;  c7e:	 4c899c24f0760000     	mov	qword ptr [rsp + 0x76f0], r11
+;;  c86:	 4c899c24e8760000     	mov	qword ptr [rsp + 0x76e8], r11
+;;  c8e:	 4c899c24e0760000     	mov	qword ptr [rsp + 0x76e0], r11
+;;  c96:	 4c899c24d8760000     	mov	qword ptr [rsp + 0x76d8], r11
+;;  c9e:	 4c899c24d0760000     	mov	qword ptr [rsp + 0x76d0], r11
+;;  ca6:	 4c899c24c8760000     	mov	qword ptr [rsp + 0x76c8], r11
+;;  cae:	 4c899c24c0760000     	mov	qword ptr [rsp + 0x76c0], r11
+;;  cb6:	 4c899c24b8760000     	mov	qword ptr [rsp + 0x76b8], r11
+;;  cbe:	 4c899c24b0760000     	mov	qword ptr [rsp + 0x76b0], r11
+;;  cc6:	 4c899c24a8760000     	mov	qword ptr [rsp + 0x76a8], r11
+;;  cce:	 4c899c24a0760000     	mov	qword ptr [rsp + 0x76a0], r11
+;;  cd6:	 4c899c2498760000     	mov	qword ptr [rsp + 0x7698], r11
+;;  cde:	 4c899c2490760000     	mov	qword ptr [rsp + 0x7690], r11
+;;  ce6:	 4c899c2488760000     	mov	qword ptr [rsp + 0x7688], r11
+;;  cee:	 4c899c2480760000     	mov	qword ptr [rsp + 0x7680], r11
+;;  cf6:	 4c899c2478760000     	mov	qword ptr [rsp + 0x7678], r11
+;;  cfe:	 4c899c2470760000     	mov	qword ptr [rsp + 0x7670], r11
+;;  d06:	 4c899c2468760000     	mov	qword ptr [rsp + 0x7668], r11
+;;  d0e:	 4c899c2460760000     	mov	qword ptr [rsp + 0x7660], r11
+;;  d16:	 4c899c2458760000     	mov	qword ptr [rsp + 0x7658], r11
+;;  d1e:	 4c899c2450760000     	mov	qword ptr [rsp + 0x7650], r11
+;;  d26:	 4c899c2448760000     	mov	qword ptr [rsp + 0x7648], r11
+;;  d2e:	 4c899c2440760000     	mov	qword ptr [rsp + 0x7640], r11
+;;  d36:	 4c899c2438760000     	mov	qword ptr [rsp + 0x7638], r11
+;;  d3e:	 4c899c2430760000     	mov	qword ptr [rsp + 0x7630], r11
+;;  d46:	 4c899c2428760000     	mov	qword ptr [rsp + 0x7628], r11
+;;  d4e:	 4c899c2420760000     	mov	qword ptr [rsp + 0x7620], r11
+;;  d56:	 4c899c2418760000     	mov	qword ptr [rsp + 0x7618], r11
+;;  d5e:	 4c899c2410760000     	mov	qword ptr [rsp + 0x7610], r11
+;;  d66:	 4c899c2408760000     	mov	qword ptr [rsp + 0x7608], r11
+;;  d6e:	 4c899c2400760000     	mov	qword ptr [rsp + 0x7600], r11
+;;  d76:	 4c899c24f8750000     	mov	qword ptr [rsp + 0x75f8], r11
+;;  d7e:	 4c899c24f0750000     	mov	qword ptr [rsp + 0x75f0], r11
+;;  d86:	 4c899c24e8750000     	mov	qword ptr [rsp + 0x75e8], r11
+;;  d8e:	 4c899c24e0750000     	mov	qword ptr [rsp + 0x75e0], r11
+;;  d96:	 4c899c24d8750000     	mov	qword ptr [rsp + 0x75d8], r11
+;;  d9e:	 4c899c24d0750000     	mov	qword ptr [rsp + 0x75d0], r11
+;;  da6:	 4c899c24c8750000     	mov	qword ptr [rsp + 0x75c8], r11
+;;  dae:	 4c899c24c0750000     	mov	qword ptr [rsp + 0x75c0], r11
+;;  db6:	 4c899c24b8750000     	mov	qword ptr [rsp + 0x75b8], r11
+;;  dbe:	 4c899c24b0750000     	mov	qword ptr [rsp + 0x75b0], r11
+;;  dc6:	 4c899c24a8750000     	mov	qword ptr [rsp + 0x75a8], r11
+;;  dce:	 4c899c24a0750000     	mov	qword ptr [rsp + 0x75a0], r11
+;;  dd6:	 4c899c2498750000     	mov	qword ptr [rsp + 0x7598], r11
+;;  dde:	 4c899c2490750000     	mov	qword ptr [rsp + 0x7590], r11
+;;  de6:	 4c899c2488750000     	mov	qword ptr [rsp + 0x7588], r11
+;;  dee:	 4c899c2480750000     	mov	qword ptr [rsp + 0x7580], r11
+;;  df6:	 4c899c2478750000     	mov	qword ptr [rsp + 0x7578], r11
+;;  dfe:	 4c899c2470750000     	mov	qword ptr [rsp + 0x7570], r11
+;;  e06:	 4c899c2468750000     	mov	qword ptr [rsp + 0x7568], r11
+;;  e0e:	 4c899c2460750000     	mov	qword ptr [rsp + 0x7560], r11
+;;  e16:	 4c899c2458750000     	mov	qword ptr [rsp + 0x7558], r11
+;;  e1e:	 4c899c2450750000     	mov	qword ptr [rsp + 0x7550], r11
+;;  e26:	 4c899c2448750000     	mov	qword ptr [rsp + 0x7548], r11
+;;  e2e:	 4c899c2440750000     	mov	qword ptr [rsp + 0x7540], r11
+;;  e36:	 4c899c2438750000     	mov	qword ptr [rsp + 0x7538], r11
+;;  e3e:	 4c899c2430750000     	mov	qword ptr [rsp + 0x7530], r11
+;;  e46:	 4c899c2428750000     	mov	qword ptr [rsp + 0x7528], r11
+;;  e4e:	 4c899c2420750000     	mov	qword ptr [rsp + 0x7520], r11
+;;  e56:	 4c899c2418750000     	mov	qword ptr [rsp + 0x7518], r11
+;;  e5e:	 4c899c2410750000     	mov	qword ptr [rsp + 0x7510], r11
+;;  e66:	 4c899c2408750000     	mov	qword ptr [rsp + 0x7508], r11
+;;  e6e:	 4c899c2400750000     	mov	qword ptr [rsp + 0x7500], r11
+;;  e76:	 4c899c24f8740000     	mov	qword ptr [rsp + 0x74f8], r11
+;;  e7e:	 4c899c24f0740000     	mov	qword ptr [rsp + 0x74f0], r11
+;;  e86:	 4c899c24e8740000     	mov	qword ptr [rsp + 0x74e8], r11
+;;  e8e:	 4c899c24e0740000     	mov	qword ptr [rsp + 0x74e0], r11
+;;  e96:	 4c899c24d8740000     	mov	qword ptr [rsp + 0x74d8], r11
+;;  e9e:	 4c899c24d0740000     	mov	qword ptr [rsp + 0x74d0], r11
+;;  ea6:	 4c899c24c8740000     	mov	qword ptr [rsp + 0x74c8], r11
+;;  eae:	 4c899c24c0740000     	mov	qword ptr [rsp + 0x74c0], r11
+;;  eb6:	 4c899c24b8740000     	mov	qword ptr [rsp + 0x74b8], r11
+;;  ebe:	 4c899c24b0740000     	mov	qword ptr [rsp + 0x74b0], r11
+;;  ec6:	 4c899c24a8740000     	mov	qword ptr [rsp + 0x74a8], r11
+;;  ece:	 4c899c24a0740000     	mov	qword ptr [rsp + 0x74a0], r11
+;;  ed6:	 4c899c2498740000     	mov	qword ptr [rsp + 0x7498], r11
+;;  ede:	 4c899c2490740000     	mov	qword ptr [rsp + 0x7490], r11
+;;  ee6:	 4c899c2488740000     	mov	qword ptr [rsp + 0x7488], r11
+;;  eee:	 4c899c2480740000     	mov	qword ptr [rsp + 0x7480], r11
+;;  ef6:	 4c899c2478740000     	mov	qword ptr [rsp + 0x7478], r11
+;;  efe:	 4c899c2470740000     	mov	qword ptr [rsp + 0x7470], r11
+;;  f06:	 4c899c2468740000     	mov	qword ptr [rsp + 0x7468], r11
+;;  f0e:	 4c899c2460740000     	mov	qword ptr [rsp + 0x7460], r11
+;;  f16:	 4c899c2458740000     	mov	qword ptr [rsp + 0x7458], r11
+;;  f1e:	 4c899c2450740000     	mov	qword ptr [rsp + 0x7450], r11
+;;  f26:	 4c899c2448740000     	mov	qword ptr [rsp + 0x7448], r11
+;;  f2e:	 4c899c2440740000     	mov	qword ptr [rsp + 0x7440], r11
+;;  f36:	 4c899c2438740000     	mov	qword ptr [rsp + 0x7438], r11
+;;  f3e:	 4c899c2430740000     	mov	qword ptr [rsp + 0x7430], r11
+;;  f46:	 4c899c2428740000     	mov	qword ptr [rsp + 0x7428], r11
+;;  f4e:	 4c899c2420740000     	mov	qword ptr [rsp + 0x7420], r11
+;;  f56:	 4c899c2418740000     	mov	qword ptr [rsp + 0x7418], r11
+;;  f5e:	 4c899c2410740000     	mov	qword ptr [rsp + 0x7410], r11
+;;  f66:	 4c899c2408740000     	mov	qword ptr [rsp + 0x7408], r11
+;;  f6e:	 4c899c2400740000     	mov	qword ptr [rsp + 0x7400], r11
+;;  f76:	 4c899c24f8730000     	mov	qword ptr [rsp + 0x73f8], r11
+;;  f7e:	 4c899c24f0730000     	mov	qword ptr [rsp + 0x73f0], r11
+;;  f86:	 4c899c24e8730000     	mov	qword ptr [rsp + 0x73e8], r11
+;;  f8e:	 4c899c24e0730000     	mov	qword ptr [rsp + 0x73e0], r11
+;;  f96:	 4c899c24d8730000     	mov	qword ptr [rsp + 0x73d8], r11
+;;  f9e:	 4c899c24d0730000     	mov	qword ptr [rsp + 0x73d0], r11
+;;  fa6:	 4c899c24c8730000     	mov	qword ptr [rsp + 0x73c8], r11
+;;  fae:	 4c899c24c0730000     	mov	qword ptr [rsp + 0x73c0], r11
+;;  fb6:	 4c899c24b8730000     	mov	qword ptr [rsp + 0x73b8], r11
+;;  fbe:	 4c899c24b0730000     	mov	qword ptr [rsp + 0x73b0], r11
+;;  fc6:	 4c899c24a8730000     	mov	qword ptr [rsp + 0x73a8], r11
+;;  fce:	 4c899c24a0730000     	mov	qword ptr [rsp + 0x73a0], r11
+;;  fd6:	 4c899c2498730000     	mov	qword ptr [rsp + 0x7398], r11
+;;  fde:	 4c899c2490730000     	mov	qword ptr [rsp + 0x7390], r11
+;;  fe6:	 4c899c2488730000     	mov	qword ptr [rsp + 0x7388], r11
+;;  fee:	 4c899c2480730000     	mov	qword ptr [rsp + 0x7380], r11
+;;  ff6:	 4c899c2478730000     	mov	qword ptr [rsp + 0x7378], r11
+;;  ffe:	 4c899c2470730000     	mov	qword ptr [rsp + 0x7370], r11
+;; 1006:	 4c899c2468730000     	mov	qword ptr [rsp + 0x7368], r11
+;; 100e:	 4c899c2460730000     	mov	qword ptr [rsp + 0x7360], r11
+;; 1016:	 4c899c2458730000     	mov	qword ptr [rsp + 0x7358], r11
+;; 101e:	 4c899c2450730000     	mov	qword ptr [rsp + 0x7350], r11
+;; 1026:	 4c899c2448730000     	mov	qword ptr [rsp + 0x7348], r11
+;; 102e:	 4c899c2440730000     	mov	qword ptr [rsp + 0x7340], r11
+;; 1036:	 4c899c2438730000     	mov	qword ptr [rsp + 0x7338], r11
+;; 103e:	 4c899c2430730000     	mov	qword ptr [rsp + 0x7330], r11
+;; 1046:	 4c899c2428730000     	mov	qword ptr [rsp + 0x7328], r11
+;; 104e:	 4c899c2420730000     	mov	qword ptr [rsp + 0x7320], r11
+;; 1056:	 4c899c2418730000     	mov	qword ptr [rsp + 0x7318], r11
+;; 105e:	 4c899c2410730000     	mov	qword ptr [rsp + 0x7310], r11
+;; 1066:	 4c899c2408730000     	mov	qword ptr [rsp + 0x7308], r11
+;; 106e:	 4c899c2400730000     	mov	qword ptr [rsp + 0x7300], r11
+;; 1076:	 4c899c24f8720000     	mov	qword ptr [rsp + 0x72f8], r11
+;; 107e:	 4c899c24f0720000     	mov	qword ptr [rsp + 0x72f0], r11
+;; 1086:	 4c899c24e8720000     	mov	qword ptr [rsp + 0x72e8], r11
+;; 108e:	 4c899c24e0720000     	mov	qword ptr [rsp + 0x72e0], r11
+;; 1096:	 4c899c24d8720000     	mov	qword ptr [rsp + 0x72d8], r11
+;; 109e:	 4c899c24d0720000     	mov	qword ptr [rsp + 0x72d0], r11
+;; 10a6:	 4c899c24c8720000     	mov	qword ptr [rsp + 0x72c8], r11
+;; 10ae:	 4c899c24c0720000     	mov	qword ptr [rsp + 0x72c0], r11
+;; 10b6:	 4c899c24b8720000     	mov	qword ptr [rsp + 0x72b8], r11
+;; 10be:	 4c899c24b0720000     	mov	qword ptr [rsp + 0x72b0], r11
+;; 10c6:	 4c899c24a8720000     	mov	qword ptr [rsp + 0x72a8], r11
+;; 10ce:	 4c899c24a0720000     	mov	qword ptr [rsp + 0x72a0], r11
+;; 10d6:	 4c899c2498720000     	mov	qword ptr [rsp + 0x7298], r11
+;; 10de:	 4c899c2490720000     	mov	qword ptr [rsp + 0x7290], r11
+;; 10e6:	 4c899c2488720000     	mov	qword ptr [rsp + 0x7288], r11
+;; 10ee:	 4c899c2480720000     	mov	qword ptr [rsp + 0x7280], r11
+;; 10f6:	 4c899c2478720000     	mov	qword ptr [rsp + 0x7278], r11
+;; 10fe:	 4c899c2470720000     	mov	qword ptr [rsp + 0x7270], r11
+;; 1106:	 4c899c2468720000     	mov	qword ptr [rsp + 0x7268], r11
+;; 110e:	 4c899c2460720000     	mov	qword ptr [rsp + 0x7260], r11
+;; 1116:	 4c899c2458720000     	mov	qword ptr [rsp + 0x7258], r11
+;; 111e:	 4c899c2450720000     	mov	qword ptr [rsp + 0x7250], r11
+;; 1126:	 4c899c2448720000     	mov	qword ptr [rsp + 0x7248], r11
+;; 112e:	 4c899c2440720000     	mov	qword ptr [rsp + 0x7240], r11
+;; 1136:	 4c899c2438720000     	mov	qword ptr [rsp + 0x7238], r11
+;; 113e:	 4c899c2430720000     	mov	qword ptr [rsp + 0x7230], r11
+;; 1146:	 4c899c2428720000     	mov	qword ptr [rsp + 0x7228], r11
+;; 114e:	 4c899c2420720000     	mov	qword ptr [rsp + 0x7220], r11
+;; 1156:	 4c899c2418720000     	mov	qword ptr [rsp + 0x7218], r11
+;; 115e:	 4c899c2410720000     	mov	qword ptr [rsp + 0x7210], r11
+;; 1166:	 4c899c2408720000     	mov	qword ptr [rsp + 0x7208], r11
+;; 116e:	 4c899c2400720000     	mov	qword ptr [rsp + 0x7200], r11
+;; 1176:	 4c899c24f8710000     	mov	qword ptr [rsp + 0x71f8], r11
+;; 117e:	 4c899c24f0710000     	mov	qword ptr [rsp + 0x71f0], r11
+;; 1186:	 4c899c24e8710000     	mov	qword ptr [rsp + 0x71e8], r11
+;; 118e:	 4c899c24e0710000     	mov	qword ptr [rsp + 0x71e0], r11
+;; 1196:	 4c899c24d8710000     	mov	qword ptr [rsp + 0x71d8], r11
+;; 119e:	 4c899c24d0710000     	mov	qword ptr [rsp + 0x71d0], r11
+;; 11a6:	 4c899c24c8710000     	mov	qword ptr [rsp + 0x71c8], r11
+;; 11ae:	 4c899c24c0710000     	mov	qword ptr [rsp + 0x71c0], r11
+;; 11b6:	 4c899c24b8710000     	mov	qword ptr [rsp + 0x71b8], r11
+;; 11be:	 4c899c24b0710000     	mov	qword ptr [rsp + 0x71b0], r11
+;; 11c6:	 4c899c24a8710000     	mov	qword ptr [rsp + 0x71a8], r11
+;; 11ce:	 4c899c24a0710000     	mov	qword ptr [rsp + 0x71a0], r11
+;; 11d6:	 4c899c2498710000     	mov	qword ptr [rsp + 0x7198], r11
+;; 11de:	 4c899c2490710000     	mov	qword ptr [rsp + 0x7190], r11
+;; 11e6:	 4c899c2488710000     	mov	qword ptr [rsp + 0x7188], r11
+;; 11ee:	 4c899c2480710000     	mov	qword ptr [rsp + 0x7180], r11
+;; 11f6:	 4c899c2478710000     	mov	qword ptr [rsp + 0x7178], r11
+;; 11fe:	 4c899c2470710000     	mov	qword ptr [rsp + 0x7170], r11
+;; 1206:	 4c899c2468710000     	mov	qword ptr [rsp + 0x7168], r11
+;; 120e:	 4c899c2460710000     	mov	qword ptr [rsp + 0x7160], r11
+;; 1216:	 4c899c2458710000     	mov	qword ptr [rsp + 0x7158], r11
+;; 121e:	 4c899c2450710000     	mov	qword ptr [rsp + 0x7150], r11
+;; 1226:	 4c899c2448710000     	mov	qword ptr [rsp + 0x7148], r11
+;; 122e:	 4c899c2440710000     	mov	qword ptr [rsp + 0x7140], r11
+;; 1236:	 4c899c2438710000     	mov	qword ptr [rsp + 0x7138], r11
+;; 123e:	 4c899c2430710000     	mov	qword ptr [rsp + 0x7130], r11
+;; 1246:	 4c899c2428710000     	mov	qword ptr [rsp + 0x7128], r11
+;; 124e:	 4c899c2420710000     	mov	qword ptr [rsp + 0x7120], r11
+;; 1256:	 4c899c2418710000     	mov	qword ptr [rsp + 0x7118], r11
+;; 125e:	 4c899c2410710000     	mov	qword ptr [rsp + 0x7110], r11
+;; 1266:	 4c899c2408710000     	mov	qword ptr [rsp + 0x7108], r11
+;; 126e:	 4c899c2400710000     	mov	qword ptr [rsp + 0x7100], r11
+;; 1276:	 4c899c24f8700000     	mov	qword ptr [rsp + 0x70f8], r11
+;; 127e:	 4c899c24f0700000     	mov	qword ptr [rsp + 0x70f0], r11
+;; 1286:	 4c899c24e8700000     	mov	qword ptr [rsp + 0x70e8], r11
+;; 128e:	 4c899c24e0700000     	mov	qword ptr [rsp + 0x70e0], r11
+;; 1296:	 4c899c24d8700000     	mov	qword ptr [rsp + 0x70d8], r11
+;; 129e:	 4c899c24d0700000     	mov	qword ptr [rsp + 0x70d0], r11
+;; 12a6:	 4c899c24c8700000     	mov	qword ptr [rsp + 0x70c8], r11
+;; 12ae:	 4c899c24c0700000     	mov	qword ptr [rsp + 0x70c0], r11
+;; 12b6:	 4c899c24b8700000     	mov	qword ptr [rsp + 0x70b8], r11
+;; 12be:	 4c899c24b0700000     	mov	qword ptr [rsp + 0x70b0], r11
+;; 12c6:	 4c899c24a8700000     	mov	qword ptr [rsp + 0x70a8], r11
+;; 12ce:	 4c899c24a0700000     	mov	qword ptr [rsp + 0x70a0], r11
+;; 12d6:	 4c899c2498700000     	mov	qword ptr [rsp + 0x7098], r11
+;; 12de:	 4c899c2490700000     	mov	qword ptr [rsp + 0x7090], r11
+;; 12e6:	 4c899c2488700000     	mov	qword ptr [rsp + 0x7088], r11
+;; 12ee:	 4c899c2480700000     	mov	qword ptr [rsp + 0x7080], r11
+;; 12f6:	 4c899c2478700000     	mov	qword ptr [rsp + 0x7078], r11
+;; 12fe:	 4c899c2470700000     	mov	qword ptr [rsp + 0x7070], r11
+;; 1306:	 4c899c2468700000     	mov	qword ptr [rsp + 0x7068], r11
+;; 130e:	 4c899c2460700000     	mov	qword ptr [rsp + 0x7060], r11
+;; 1316:	 4c899c2458700000     	mov	qword ptr [rsp + 0x7058], r11
+;; 131e:	 4c899c2450700000     	mov	qword ptr [rsp + 0x7050], r11
+;; 1326:	 4c899c2448700000     	mov	qword ptr [rsp + 0x7048], r11
+;; 132e:	 4c899c2440700000     	mov	qword ptr [rsp + 0x7040], r11
+;; 1336:	 4c899c2438700000     	mov	qword ptr [rsp + 0x7038], r11
+;; 133e:	 4c899c2430700000     	mov	qword ptr [rsp + 0x7030], r11
+;; 1346:	 4c899c2428700000     	mov	qword ptr [rsp + 0x7028], r11
+;; 134e:	 4c899c2420700000     	mov	qword ptr [rsp + 0x7020], r11
+;; 1356:	 4c899c2418700000     	mov	qword ptr [rsp + 0x7018], r11
+;; 135e:	 4c899c2410700000     	mov	qword ptr [rsp + 0x7010], r11
+;; 1366:	 4c899c2408700000     	mov	qword ptr [rsp + 0x7008], r11
+;; 136e:	 4c899c2400700000     	mov	qword ptr [rsp + 0x7000], r11
+;; 1376:	 4c899c24f86f0000     	mov	qword ptr [rsp + 0x6ff8], r11
+;; 137e:	 4c899c24f06f0000     	mov	qword ptr [rsp + 0x6ff0], r11
+;; 1386:	 4c899c24e86f0000     	mov	qword ptr [rsp + 0x6fe8], r11
+;; 138e:	 4c899c24e06f0000     	mov	qword ptr [rsp + 0x6fe0], r11
+;; 1396:	 4c899c24d86f0000     	mov	qword ptr [rsp + 0x6fd8], r11
+;; 139e:	 4c899c24d06f0000     	mov	qword ptr [rsp + 0x6fd0], r11
+;; 13a6:	 4c899c24c86f0000     	mov	qword ptr [rsp + 0x6fc8], r11
+;; 13ae:	 4c899c24c06f0000     	mov	qword ptr [rsp + 0x6fc0], r11
+;; 13b6:	 4c899c24b86f0000     	mov	qword ptr [rsp + 0x6fb8], r11
+;; 13be:	 4c899c24b06f0000     	mov	qword ptr [rsp + 0x6fb0], r11
+;; 13c6:	 4c899c24a86f0000     	mov	qword ptr [rsp + 0x6fa8], r11
+;; 13ce:	 4c899c24a06f0000     	mov	qword ptr [rsp + 0x6fa0], r11
+;; 13d6:	 4c899c24986f0000     	mov	qword ptr [rsp + 0x6f98], r11
+;; 13de:	 4c899c24906f0000     	mov	qword ptr [rsp + 0x6f90], r11
+;; 13e6:	 4c899c24886f0000     	mov	qword ptr [rsp + 0x6f88], r11
+;; 13ee:	 4c899c24806f0000     	mov	qword ptr [rsp + 0x6f80], r11
+;; 13f6:	 4c899c24786f0000     	mov	qword ptr [rsp + 0x6f78], r11
+;; 13fe:	 4c899c24706f0000     	mov	qword ptr [rsp + 0x6f70], r11
+;; 1406:	 4c899c24686f0000     	mov	qword ptr [rsp + 0x6f68], r11
+;; 140e:	 4c899c24606f0000     	mov	qword ptr [rsp + 0x6f60], r11
+;; 1416:	 4c899c24586f0000     	mov	qword ptr [rsp + 0x6f58], r11
+;; 141e:	 4c899c24506f0000     	mov	qword ptr [rsp + 0x6f50], r11
+;; 1426:	 4c899c24486f0000     	mov	qword ptr [rsp + 0x6f48], r11
+;; 142e:	 4c899c24406f0000     	mov	qword ptr [rsp + 0x6f40], r11
+;; 1436:	 4c899c24386f0000     	mov	qword ptr [rsp + 0x6f38], r11
+;; 143e:	 4c899c24306f0000     	mov	qword ptr [rsp + 0x6f30], r11
+;; 1446:	 4c899c24286f0000     	mov	qword ptr [rsp + 0x6f28], r11
+;; 144e:	 4c899c24206f0000     	mov	qword ptr [rsp + 0x6f20], r11
+;; 1456:	 4c899c24186f0000     	mov	qword ptr [rsp + 0x6f18], r11
+;; 145e:	 4c899c24106f0000     	mov	qword ptr [rsp + 0x6f10], r11
+;; 1466:	 4c899c24086f0000     	mov	qword ptr [rsp + 0x6f08], r11
+;; 146e:	 4c899c24006f0000     	mov	qword ptr [rsp + 0x6f00], r11
+;; 1476:	 4c899c24f86e0000     	mov	qword ptr [rsp + 0x6ef8], r11
+;; 147e:	 4c899c24f06e0000     	mov	qword ptr [rsp + 0x6ef0], r11
+;; 1486:	 4c899c24e86e0000     	mov	qword ptr [rsp + 0x6ee8], r11
+;; 148e:	 4c899c24e06e0000     	mov	qword ptr [rsp + 0x6ee0], r11
+;; 1496:	 4c899c24d86e0000     	mov	qword ptr [rsp + 0x6ed8], r11
+;; 149e:	 4c899c24d06e0000     	mov	qword ptr [rsp + 0x6ed0], r11
+;; 14a6:	 4c899c24c86e0000     	mov	qword ptr [rsp + 0x6ec8], r11
+;; 14ae:	 4c899c24c06e0000     	mov	qword ptr [rsp + 0x6ec0], r11
+;; 14b6:	 4c899c24b86e0000     	mov	qword ptr [rsp + 0x6eb8], r11
+;; 14be:	 4c899c24b06e0000     	mov	qword ptr [rsp + 0x6eb0], r11
+;; 14c6:	 4c899c24a86e0000     	mov	qword ptr [rsp + 0x6ea8], r11
+;; 14ce:	 4c899c24a06e0000     	mov	qword ptr [rsp + 0x6ea0], r11
+;; 14d6:	 4c899c24986e0000     	mov	qword ptr [rsp + 0x6e98], r11
+;; 14de:	 4c899c24906e0000     	mov	qword ptr [rsp + 0x6e90], r11
+;; 14e6:	 4c899c24886e0000     	mov	qword ptr [rsp + 0x6e88], r11
+;; 14ee:	 4c899c24806e0000     	mov	qword ptr [rsp + 0x6e80], r11
+;; 14f6:	 4c899c24786e0000     	mov	qword ptr [rsp + 0x6e78], r11
+;; 14fe:	 4c899c24706e0000     	mov	qword ptr [rsp + 0x6e70], r11
+;; 1506:	 4c899c24686e0000     	mov	qword ptr [rsp + 0x6e68], r11
+;; 150e:	 4c899c24606e0000     	mov	qword ptr [rsp + 0x6e60], r11
+;; 1516:	 4c899c24586e0000     	mov	qword ptr [rsp + 0x6e58], r11
+;; 151e:	 4c899c24506e0000     	mov	qword ptr [rsp + 0x6e50], r11
+;; 1526:	 4c899c24486e0000     	mov	qword ptr [rsp + 0x6e48], r11
+;; 152e:	 4c899c24406e0000     	mov	qword ptr [rsp + 0x6e40], r11
+;; 1536:	 4c899c24386e0000     	mov	qword ptr [rsp + 0x6e38], r11
+;; 153e:	 4c899c24306e0000     	mov	qword ptr [rsp + 0x6e30], r11
+;; 1546:	 4c899c24286e0000     	mov	qword ptr [rsp + 0x6e28], r11
+;; 154e:	 4c899c24206e0000     	mov	qword ptr [rsp + 0x6e20], r11
+;; 1556:	 4c899c24186e0000     	mov	qword ptr [rsp + 0x6e18], r11
+;; 155e:	 4c899c24106e0000     	mov	qword ptr [rsp + 0x6e10], r11
+;; 1566:	 4c899c24086e0000     	mov	qword ptr [rsp + 0x6e08], r11
+;; 156e:	 4c899c24006e0000     	mov	qword ptr [rsp + 0x6e00], r11
+;; 1576:	 4c899c24f86d0000     	mov	qword ptr [rsp + 0x6df8], r11
+;; 157e:	 4c899c24f06d0000     	mov	qword ptr [rsp + 0x6df0], r11
+;; 1586:	 4c899c24e86d0000     	mov	qword ptr [rsp + 0x6de8], r11
+;; 158e:	 4c899c24e06d0000     	mov	qword ptr [rsp + 0x6de0], r11
+;; 1596:	 4c899c24d86d0000     	mov	qword ptr [rsp + 0x6dd8], r11
+;; 159e:	 4c899c24d06d0000     	mov	qword ptr [rsp + 0x6dd0], r11
+;; 15a6:	 4c899c24c86d0000     	mov	qword ptr [rsp + 0x6dc8], r11
+;; 15ae:	 4c899c24c06d0000     	mov	qword ptr [rsp + 0x6dc0], r11
+;; 15b6:	 4c899c24b86d0000     	mov	qword ptr [rsp + 0x6db8], r11
+;; 15be:	 4c899c24b06d0000     	mov	qword ptr [rsp + 0x6db0], r11
+;; 15c6:	 4c899c24a86d0000     	mov	qword ptr [rsp + 0x6da8], r11
+;; 15ce:	 4c899c24a06d0000     	mov	qword ptr [rsp + 0x6da0], r11
+;; 15d6:	 4c899c24986d0000     	mov	qword ptr [rsp + 0x6d98], r11
+;; 15de:	 4c899c24906d0000     	mov	qword ptr [rsp + 0x6d90], r11
+;; 15e6:	 4c899c24886d0000     	mov	qword ptr [rsp + 0x6d88], r11
+;; 15ee:	 4c899c24806d0000     	mov	qword ptr [rsp + 0x6d80], r11
+;; 15f6:	 4c899c24786d0000     	mov	qword ptr [rsp + 0x6d78], r11
+;; 15fe:	 4c899c24706d0000     	mov	qword ptr [rsp + 0x6d70], r11
+;; 1606:	 4c899c24686d0000     	mov	qword ptr [rsp + 0x6d68], r11
+;; 160e:	 4c899c24606d0000     	mov	qword ptr [rsp + 0x6d60], r11
+;; 1616:	 4c899c24586d0000     	mov	qword ptr [rsp + 0x6d58], r11
+;; 161e:	 4c899c24506d0000     	mov	qword ptr [rsp + 0x6d50], r11
+;; 1626:	 4c899c24486d0000     	mov	qword ptr [rsp + 0x6d48], r11
+;; 162e:	 4c899c24406d0000     	mov	qword ptr [rsp + 0x6d40], r11
+;; 1636:	 4c899c24386d0000     	mov	qword ptr [rsp + 0x6d38], r11
+;; 163e:	 4c899c24306d0000     	mov	qword ptr [rsp + 0x6d30], r11
+;; 1646:	 4c899c24286d0000     	mov	qword ptr [rsp + 0x6d28], r11
+;; 164e:	 4c899c24206d0000     	mov	qword ptr [rsp + 0x6d20], r11
+;; 1656:	 4c899c24186d0000     	mov	qword ptr [rsp + 0x6d18], r11
+;; 165e:	 4c899c24106d0000     	mov	qword ptr [rsp + 0x6d10], r11
+;; 1666:	 4c899c24086d0000     	mov	qword ptr [rsp + 0x6d08], r11
+;; 166e:	 4c899c24006d0000     	mov	qword ptr [rsp + 0x6d00], r11
+;; 1676:	 4c899c24f86c0000     	mov	qword ptr [rsp + 0x6cf8], r11
+;; 167e:	 4c899c24f06c0000     	mov	qword ptr [rsp + 0x6cf0], r11
+;; 1686:	 4c899c24e86c0000     	mov	qword ptr [rsp + 0x6ce8], r11
+;; 168e:	 4c899c24e06c0000     	mov	qword ptr [rsp + 0x6ce0], r11
+;; 1696:	 4c899c24d86c0000     	mov	qword ptr [rsp + 0x6cd8], r11
+;; 169e:	 4c899c24d06c0000     	mov	qword ptr [rsp + 0x6cd0], r11
+;; 16a6:	 4c899c24c86c0000     	mov	qword ptr [rsp + 0x6cc8], r11
+;; 16ae:	 4c899c24c06c0000     	mov	qword ptr [rsp + 0x6cc0], r11
+;; 16b6:	 4c899c24b86c0000     	mov	qword ptr [rsp + 0x6cb8], r11
+;; 16be:	 4c899c24b06c0000     	mov	qword ptr [rsp + 0x6cb0], r11
+;; 16c6:	 4c899c24a86c0000     	mov	qword ptr [rsp + 0x6ca8], r11
+;; 16ce:	 4c899c24a06c0000     	mov	qword ptr [rsp + 0x6ca0], r11
+;; 16d6:	 4c899c24986c0000     	mov	qword ptr [rsp + 0x6c98], r11
+;; 16de:	 4c899c24906c0000     	mov	qword ptr [rsp + 0x6c90], r11
+;; 16e6:	 4c899c24886c0000     	mov	qword ptr [rsp + 0x6c88], r11
+;; 16ee:	 4c899c24806c0000     	mov	qword ptr [rsp + 0x6c80], r11
+;; 16f6:	 4c899c24786c0000     	mov	qword ptr [rsp + 0x6c78], r11
+;; 16fe:	 4c899c24706c0000     	mov	qword ptr [rsp + 0x6c70], r11
+;; 1706:	 4c899c24686c0000     	mov	qword ptr [rsp + 0x6c68], r11
+;; 170e:	 4c899c24606c0000     	mov	qword ptr [rsp + 0x6c60], r11
+;; 1716:	 4c899c24586c0000     	mov	qword ptr [rsp + 0x6c58], r11
+;; 171e:	 4c899c24506c0000     	mov	qword ptr [rsp + 0x6c50], r11
+;; 1726:	 4c899c24486c0000     	mov	qword ptr [rsp + 0x6c48], r11
+;; 172e:	 4c899c24406c0000     	mov	qword ptr [rsp + 0x6c40], r11
+;; 1736:	 4c899c24386c0000     	mov	qword ptr [rsp + 0x6c38], r11
+;; 173e:	 4c899c24306c0000     	mov	qword ptr [rsp + 0x6c30], r11
+;; 1746:	 4c899c24286c0000     	mov	qword ptr [rsp + 0x6c28], r11
+;; 174e:	 4c899c24206c0000     	mov	qword ptr [rsp + 0x6c20], r11
+;; 1756:	 4c899c24186c0000     	mov	qword ptr [rsp + 0x6c18], r11
+;; 175e:	 4c899c24106c0000     	mov	qword ptr [rsp + 0x6c10], r11
+;; 1766:	 4c899c24086c0000     	mov	qword ptr [rsp + 0x6c08], r11
+;; 176e:	 4c899c24006c0000     	mov	qword ptr [rsp + 0x6c00], r11
+;; 1776:	 4c899c24f86b0000     	mov	qword ptr [rsp + 0x6bf8], r11
+;; 177e:	 4c899c24f06b0000     	mov	qword ptr [rsp + 0x6bf0], r11
+;; 1786:	 4c899c24e86b0000     	mov	qword ptr [rsp + 0x6be8], r11
+;; 178e:	 4c899c24e06b0000     	mov	qword ptr [rsp + 0x6be0], r11
+;; 1796:	 4c899c24d86b0000     	mov	qword ptr [rsp + 0x6bd8], r11
+;; 179e:	 4c899c24d06b0000     	mov	qword ptr [rsp + 0x6bd0], r11
+;; 17a6:	 4c899c24c86b0000     	mov	qword ptr [rsp + 0x6bc8], r11
+;; 17ae:	 4c899c24c06b0000     	mov	qword ptr [rsp + 0x6bc0], r11
+;; 17b6:	 4c899c24b86b0000     	mov	qword ptr [rsp + 0x6bb8], r11
+;; 17be:	 4c899c24b06b0000     	mov	qword ptr [rsp + 0x6bb0], r11
+;; 17c6:	 4c899c24a86b0000     	mov	qword ptr [rsp + 0x6ba8], r11
+;; 17ce:	 4c899c24a06b0000     	mov	qword ptr [rsp + 0x6ba0], r11
+;; 17d6:	 4c899c24986b0000     	mov	qword ptr [rsp + 0x6b98], r11
+;; 17de:	 4c899c24906b0000     	mov	qword ptr [rsp + 0x6b90], r11
+;; 17e6:	 4c899c24886b0000     	mov	qword ptr [rsp + 0x6b88], r11
+;; 17ee:	 4c899c24806b0000     	mov	qword ptr [rsp + 0x6b80], r11
+;; 17f6:	 4c899c24786b0000     	mov	qword ptr [rsp + 0x6b78], r11
+;; 17fe:	 4c899c24706b0000     	mov	qword ptr [rsp + 0x6b70], r11
+;; 1806:	 4c899c24686b0000     	mov	qword ptr [rsp + 0x6b68], r11
+;; 180e:	 4c899c24606b0000     	mov	qword ptr [rsp + 0x6b60], r11
+;; 1816:	 4c899c24586b0000     	mov	qword ptr [rsp + 0x6b58], r11
+;; 181e:	 4c899c24506b0000     	mov	qword ptr [rsp + 0x6b50], r11
+;; 1826:	 4c899c24486b0000     	mov	qword ptr [rsp + 0x6b48], r11
+;; 182e:	 4c899c24406b0000     	mov	qword ptr [rsp + 0x6b40], r11
+;; 1836:	 4c899c24386b0000     	mov	qword ptr [rsp + 0x6b38], r11
+;; 183e:	 4c899c24306b0000     	mov	qword ptr [rsp + 0x6b30], r11
+;; 1846:	 4c899c24286b0000     	mov	qword ptr [rsp + 0x6b28], r11
+;; 184e:	 4c899c24206b0000     	mov	qword ptr [rsp + 0x6b20], r11
+;; 1856:	 4c899c24186b0000     	mov	qword ptr [rsp + 0x6b18], r11
+;; 185e:	 4c899c24106b0000     	mov	qword ptr [rsp + 0x6b10], r11
+;; 1866:	 4c899c24086b0000     	mov	qword ptr [rsp + 0x6b08], r11
+;; 186e:	 4c899c24006b0000     	mov	qword ptr [rsp + 0x6b00], r11
+;; 1876:	 4c899c24f86a0000     	mov	qword ptr [rsp + 0x6af8], r11
+;; 187e:	 4c899c24f06a0000     	mov	qword ptr [rsp + 0x6af0], r11
+;; 1886:	 4c899c24e86a0000     	mov	qword ptr [rsp + 0x6ae8], r11
+;; 188e:	 4c899c24e06a0000     	mov	qword ptr [rsp + 0x6ae0], r11
+;; 1896:	 4c899c24d86a0000     	mov	qword ptr [rsp + 0x6ad8], r11
+;; 189e:	 4c899c24d06a0000     	mov	qword ptr [rsp + 0x6ad0], r11
+;; 18a6:	 4c899c24c86a0000     	mov	qword ptr [rsp + 0x6ac8], r11
+;; 18ae:	 4c899c24c06a0000     	mov	qword ptr [rsp + 0x6ac0], r11
+;; 18b6:	 4c899c24b86a0000     	mov	qword ptr [rsp + 0x6ab8], r11
+;; 18be:	 4c899c24b06a0000     	mov	qword ptr [rsp + 0x6ab0], r11
+;; 18c6:	 4c899c24a86a0000     	mov	qword ptr [rsp + 0x6aa8], r11
+;; 18ce:	 4c899c24a06a0000     	mov	qword ptr [rsp + 0x6aa0], r11
+;; 18d6:	 4c899c24986a0000     	mov	qword ptr [rsp + 0x6a98], r11
+;; 18de:	 4c899c24906a0000     	mov	qword ptr [rsp + 0x6a90], r11
+;; 18e6:	 4c899c24886a0000     	mov	qword ptr [rsp + 0x6a88], r11
+;; 18ee:	 4c899c24806a0000     	mov	qword ptr [rsp + 0x6a80], r11
+;; 18f6:	 4c899c24786a0000     	mov	qword ptr [rsp + 0x6a78], r11
+;; 18fe:	 4c899c24706a0000     	mov	qword ptr [rsp + 0x6a70], r11
+;; 1906:	 4c899c24686a0000     	mov	qword ptr [rsp + 0x6a68], r11
+;; 190e:	 4c899c24606a0000     	mov	qword ptr [rsp + 0x6a60], r11
+;; 1916:	 4c899c24586a0000     	mov	qword ptr [rsp + 0x6a58], r11
+;; 191e:	 4c899c24506a0000     	mov	qword ptr [rsp + 0x6a50], r11
+;; 1926:	 4c899c24486a0000     	mov	qword ptr [rsp + 0x6a48], r11
+;; 192e:	 4c899c24406a0000     	mov	qword ptr [rsp + 0x6a40], r11
+;; 1936:	 4c899c24386a0000     	mov	qword ptr [rsp + 0x6a38], r11
+;; 193e:	 4c899c24306a0000     	mov	qword ptr [rsp + 0x6a30], r11
+;; 1946:	 4c899c24286a0000     	mov	qword ptr [rsp + 0x6a28], r11
+;; 194e:	 4c899c24206a0000     	mov	qword ptr [rsp + 0x6a20], r11
+;; 1956:	 4c899c24186a0000     	mov	qword ptr [rsp + 0x6a18], r11
+;; 195e:	 4c899c24106a0000     	mov	qword ptr [rsp + 0x6a10], r11
+;; 1966:	 4c899c24086a0000     	mov	qword ptr [rsp + 0x6a08], r11
+;; 196e:	 4c899c24006a0000     	mov	qword ptr [rsp + 0x6a00], r11
+;; 1976:	 4c899c24f8690000     	mov	qword ptr [rsp + 0x69f8], r11
+;; 197e:	 4c899c24f0690000     	mov	qword ptr [rsp + 0x69f0], r11
+;; 1986:	 4c899c24e8690000     	mov	qword ptr [rsp + 0x69e8], r11
+;; 198e:	 4c899c24e0690000     	mov	qword ptr [rsp + 0x69e0], r11
+;; 1996:	 4c899c24d8690000     	mov	qword ptr [rsp + 0x69d8], r11
+;; 199e:	 4c899c24d0690000     	mov	qword ptr [rsp + 0x69d0], r11
+;; 19a6:	 4c899c24c8690000     	mov	qword ptr [rsp + 0x69c8], r11
+;; 19ae:	 4c899c24c0690000     	mov	qword ptr [rsp + 0x69c0], r11
+;; 19b6:	 4c899c24b8690000     	mov	qword ptr [rsp + 0x69b8], r11
+;; 19be:	 4c899c24b0690000     	mov	qword ptr [rsp + 0x69b0], r11
+;; 19c6:	 4c899c24a8690000     	mov	qword ptr [rsp + 0x69a8], r11
+;; 19ce:	 4c899c24a0690000     	mov	qword ptr [rsp + 0x69a0], r11
+;; 19d6:	 4c899c2498690000     	mov	qword ptr [rsp + 0x6998], r11
+;; 19de:	 4c899c2490690000     	mov	qword ptr [rsp + 0x6990], r11
+;; 19e6:	 4c899c2488690000     	mov	qword ptr [rsp + 0x6988], r11
+;; 19ee:	 4c899c2480690000     	mov	qword ptr [rsp + 0x6980], r11
+;; 19f6:	 4c899c2478690000     	mov	qword ptr [rsp + 0x6978], r11
+;; 19fe:	 4c899c2470690000     	mov	qword ptr [rsp + 0x6970], r11
+;; 1a06:	 4c899c2468690000     	mov	qword ptr [rsp + 0x6968], r11
+;; 1a0e:	 4c899c2460690000     	mov	qword ptr [rsp + 0x6960], r11
+;; 1a16:	 4c899c2458690000     	mov	qword ptr [rsp + 0x6958], r11
+;; 1a1e:	 4c899c2450690000     	mov	qword ptr [rsp + 0x6950], r11
+;; 1a26:	 4c899c2448690000     	mov	qword ptr [rsp + 0x6948], r11
+;; 1a2e:	 4c899c2440690000     	mov	qword ptr [rsp + 0x6940], r11
+;; 1a36:	 4c899c2438690000     	mov	qword ptr [rsp + 0x6938], r11
+;; 1a3e:	 4c899c2430690000     	mov	qword ptr [rsp + 0x6930], r11
+;; 1a46:	 4c899c2428690000     	mov	qword ptr [rsp + 0x6928], r11
+;; 1a4e:	 4c899c2420690000     	mov	qword ptr [rsp + 0x6920], r11
+;; 1a56:	 4c899c2418690000     	mov	qword ptr [rsp + 0x6918], r11
+;; 1a5e:	 4c899c2410690000     	mov	qword ptr [rsp + 0x6910], r11
+;; 1a66:	 4c899c2408690000     	mov	qword ptr [rsp + 0x6908], r11
+;; 1a6e:	 4c899c2400690000     	mov	qword ptr [rsp + 0x6900], r11
+;; 1a76:	 4c899c24f8680000     	mov	qword ptr [rsp + 0x68f8], r11
+;; 1a7e:	 4c899c24f0680000     	mov	qword ptr [rsp + 0x68f0], r11
+;; 1a86:	 4c899c24e8680000     	mov	qword ptr [rsp + 0x68e8], r11
+;; 1a8e:	 4c899c24e0680000     	mov	qword ptr [rsp + 0x68e0], r11
+;; 1a96:	 4c899c24d8680000     	mov	qword ptr [rsp + 0x68d8], r11
+;; 1a9e:	 4c899c24d0680000     	mov	qword ptr [rsp + 0x68d0], r11
+;; 1aa6:	 4c899c24c8680000     	mov	qword ptr [rsp + 0x68c8], r11
+;; 1aae:	 4c899c24c0680000     	mov	qword ptr [rsp + 0x68c0], r11
+;; 1ab6:	 4c899c24b8680000     	mov	qword ptr [rsp + 0x68b8], r11
+;; 1abe:	 4c899c24b0680000     	mov	qword ptr [rsp + 0x68b0], r11
+;; 1ac6:	 4c899c24a8680000     	mov	qword ptr [rsp + 0x68a8], r11
+;; 1ace:	 4c899c24a0680000     	mov	qword ptr [rsp + 0x68a0], r11
+;; 1ad6:	 4c899c2498680000     	mov	qword ptr [rsp + 0x6898], r11
+;; 1ade:	 4c899c2490680000     	mov	qword ptr [rsp + 0x6890], r11
+;; 1ae6:	 4c899c2488680000     	mov	qword ptr [rsp + 0x6888], r11
+;; 1aee:	 4c899c2480680000     	mov	qword ptr [rsp + 0x6880], r11
+;; 1af6:	 4c899c2478680000     	mov	qword ptr [rsp + 0x6878], r11
+;; 1afe:	 4c899c2470680000     	mov	qword ptr [rsp + 0x6870], r11
+;; 1b06:	 4c899c2468680000     	mov	qword ptr [rsp + 0x6868], r11
+;; 1b0e:	 4c899c2460680000     	mov	qword ptr [rsp + 0x6860], r11
+;; 1b16:	 4c899c2458680000     	mov	qword ptr [rsp + 0x6858], r11
+;; 1b1e:	 4c899c2450680000     	mov	qword ptr [rsp + 0x6850], r11
+;; 1b26:	 4c899c2448680000     	mov	qword ptr [rsp + 0x6848], r11
+;; 1b2e:	 4c899c2440680000     	mov	qword ptr [rsp + 0x6840], r11
+;; 1b36:	 4c899c2438680000     	mov	qword ptr [rsp + 0x6838], r11
+;; 1b3e:	 4c899c2430680000     	mov	qword ptr [rsp + 0x6830], r11
+;; 1b46:	 4c899c2428680000     	mov	qword ptr [rsp + 0x6828], r11
+;; 1b4e:	 4c899c2420680000     	mov	qword ptr [rsp + 0x6820], r11
+;; 1b56:	 4c899c2418680000     	mov	qword ptr [rsp + 0x6818], r11
+;; 1b5e:	 4c899c2410680000     	mov	qword ptr [rsp + 0x6810], r11
+;; 1b66:	 4c899c2408680000     	mov	qword ptr [rsp + 0x6808], r11
+;; 1b6e:	 4c899c2400680000     	mov	qword ptr [rsp + 0x6800], r11
+;; 1b76:	 4c899c24f8670000     	mov	qword ptr [rsp + 0x67f8], r11
+;; 1b7e:	 4c899c24f0670000     	mov	qword ptr [rsp + 0x67f0], r11
+;; 1b86:	 4c899c24e8670000     	mov	qword ptr [rsp + 0x67e8], r11
+;; 1b8e:	 4c899c24e0670000     	mov	qword ptr [rsp + 0x67e0], r11
+;; 1b96:	 4c899c24d8670000     	mov	qword ptr [rsp + 0x67d8], r11
+;; 1b9e:	 4c899c24d0670000     	mov	qword ptr [rsp + 0x67d0], r11
+;; 1ba6:	 4c899c24c8670000     	mov	qword ptr [rsp + 0x67c8], r11
+;; 1bae:	 4c899c24c0670000     	mov	qword ptr [rsp + 0x67c0], r11
+;; 1bb6:	 4c899c24b8670000     	mov	qword ptr [rsp + 0x67b8], r11
+;; 1bbe:	 4c899c24b0670000     	mov	qword ptr [rsp + 0x67b0], r11
+;; 1bc6:	 4c899c24a8670000     	mov	qword ptr [rsp + 0x67a8], r11
+;; 1bce:	 4c899c24a0670000     	mov	qword ptr [rsp + 0x67a0], r11
+;; 1bd6:	 4c899c2498670000     	mov	qword ptr [rsp + 0x6798], r11
+;; 1bde:	 4c899c2490670000     	mov	qword ptr [rsp + 0x6790], r11
+;; 1be6:	 4c899c2488670000     	mov	qword ptr [rsp + 0x6788], r11
+;; 1bee:	 4c899c2480670000     	mov	qword ptr [rsp + 0x6780], r11
+;; 1bf6:	 4c899c2478670000     	mov	qword ptr [rsp + 0x6778], r11
+;; 1bfe:	 4c899c2470670000     	mov	qword ptr [rsp + 0x6770], r11
+;; 1c06:	 4c899c2468670000     	mov	qword ptr [rsp + 0x6768], r11
+;; 1c0e:	 4c899c2460670000     	mov	qword ptr [rsp + 0x6760], r11
+;; 1c16:	 4c899c2458670000     	mov	qword ptr [rsp + 0x6758], r11
+;; 1c1e:	 4c899c2450670000     	mov	qword ptr [rsp + 0x6750], r11
+;; 1c26:	 4c899c2448670000     	mov	qword ptr [rsp + 0x6748], r11
+;; 1c2e:	 4c899c2440670000     	mov	qword ptr [rsp + 0x6740], r11
+;; 1c36:	 4c899c2438670000     	mov	qword ptr [rsp + 0x6738], r11
+;; 1c3e:	 4c899c2430670000     	mov	qword ptr [rsp + 0x6730], r11
+;; 1c46:	 4c899c2428670000     	mov	qword ptr [rsp + 0x6728], r11
+;; 1c4e:	 4c899c2420670000     	mov	qword ptr [rsp + 0x6720], r11
+;; 1c56:	 4c899c2418670000     	mov	qword ptr [rsp + 0x6718], r11
+;; 1c5e:	 4c899c2410670000     	mov	qword ptr [rsp + 0x6710], r11
+;; 1c66:	 4c899c2408670000     	mov	qword ptr [rsp + 0x6708], r11
+;; 1c6e:	 4c899c2400670000     	mov	qword ptr [rsp + 0x6700], r11
+;; 1c76:	 4c899c24f8660000     	mov	qword ptr [rsp + 0x66f8], r11
+;; 1c7e:	 4c899c24f0660000     	mov	qword ptr [rsp + 0x66f0], r11
+;; 1c86:	 4c899c24e8660000     	mov	qword ptr [rsp + 0x66e8], r11
+;; 1c8e:	 4c899c24e0660000     	mov	qword ptr [rsp + 0x66e0], r11
+;; 1c96:	 4c899c24d8660000     	mov	qword ptr [rsp + 0x66d8], r11
+;; 1c9e:	 4c899c24d0660000     	mov	qword ptr [rsp + 0x66d0], r11
+;; 1ca6:	 4c899c24c8660000     	mov	qword ptr [rsp + 0x66c8], r11
+;; 1cae:	 4c899c24c0660000     	mov	qword ptr [rsp + 0x66c0], r11
+;; 1cb6:	 4c899c24b8660000     	mov	qword ptr [rsp + 0x66b8], r11
+;; 1cbe:	 4c899c24b0660000     	mov	qword ptr [rsp + 0x66b0], r11
+;; 1cc6:	 4c899c24a8660000     	mov	qword ptr [rsp + 0x66a8], r11
+;; 1cce:	 4c899c24a0660000     	mov	qword ptr [rsp + 0x66a0], r11
+;; 1cd6:	 4c899c2498660000     	mov	qword ptr [rsp + 0x6698], r11
+;; 1cde:	 4c899c2490660000     	mov	qword ptr [rsp + 0x6690], r11
+;; 1ce6:	 4c899c2488660000     	mov	qword ptr [rsp + 0x6688], r11
+;; 1cee:	 4c899c2480660000     	mov	qword ptr [rsp + 0x6680], r11
+;; 1cf6:	 4c899c2478660000     	mov	qword ptr [rsp + 0x6678], r11
+;; 1cfe:	 4c899c2470660000     	mov	qword ptr [rsp + 0x6670], r11
+;; 1d06:	 4c899c2468660000     	mov	qword ptr [rsp + 0x6668], r11
+;; 1d0e:	 4c899c2460660000     	mov	qword ptr [rsp + 0x6660], r11
+;; 1d16:	 4c899c2458660000     	mov	qword ptr [rsp + 0x6658], r11
+;; 1d1e:	 4c899c2450660000     	mov	qword ptr [rsp + 0x6650], r11
+;; 1d26:	 4c899c2448660000     	mov	qword ptr [rsp + 0x6648], r11
+;; 1d2e:	 4c899c2440660000     	mov	qword ptr [rsp + 0x6640], r11
+;; 1d36:	 4c899c2438660000     	mov	qword ptr [rsp + 0x6638], r11
+;; 1d3e:	 4c899c2430660000     	mov	qword ptr [rsp + 0x6630], r11
+;; 1d46:	 4c899c2428660000     	mov	qword ptr [rsp + 0x6628], r11
+;; 1d4e:	 4c899c2420660000     	mov	qword ptr [rsp + 0x6620], r11
+;; 1d56:	 4c899c2418660000     	mov	qword ptr [rsp + 0x6618], r11
+;; 1d5e:	 4c899c2410660000     	mov	qword ptr [rsp + 0x6610], r11
+;; 1d66:	 4c899c2408660000     	mov	qword ptr [rsp + 0x6608], r11
+;; 1d6e:	 4c899c2400660000     	mov	qword ptr [rsp + 0x6600], r11
+;; 1d76:	 4c899c24f8650000     	mov	qword ptr [rsp + 0x65f8], r11
+;; 1d7e:	 4c899c24f0650000     	mov	qword ptr [rsp + 0x65f0], r11
+;; 1d86:	 4c899c24e8650000     	mov	qword ptr [rsp + 0x65e8], r11
+;; 1d8e:	 4c899c24e0650000     	mov	qword ptr [rsp + 0x65e0], r11
+;; 1d96:	 4c899c24d8650000     	mov	qword ptr [rsp + 0x65d8], r11
+;; 1d9e:	 4c899c24d0650000     	mov	qword ptr [rsp + 0x65d0], r11
+;; 1da6:	 4c899c24c8650000     	mov	qword ptr [rsp + 0x65c8], r11
+;; 1dae:	 4c899c24c0650000     	mov	qword ptr [rsp + 0x65c0], r11
+;; 1db6:	 4c899c24b8650000     	mov	qword ptr [rsp + 0x65b8], r11
+;; 1dbe:	 4c899c24b0650000     	mov	qword ptr [rsp + 0x65b0], r11
+;; 1dc6:	 4c899c24a8650000     	mov	qword ptr [rsp + 0x65a8], r11
+;; 1dce:	 4c899c24a0650000     	mov	qword ptr [rsp + 0x65a0], r11
+;; 1dd6:	 4c899c2498650000     	mov	qword ptr [rsp + 0x6598], r11
+;; 1dde:	 4c899c2490650000     	mov	qword ptr [rsp + 0x6590], r11
+;; 1de6:	 4c899c2488650000     	mov	qword ptr [rsp + 0x6588], r11
+;; 1dee:	 4c899c2480650000     	mov	qword ptr [rsp + 0x6580], r11
+;; 1df6:	 4c899c2478650000     	mov	qword ptr [rsp + 0x6578], r11
+;; 1dfe:	 4c899c2470650000     	mov	qword ptr [rsp + 0x6570], r11
+;; 1e06:	 4c899c2468650000     	mov	qword ptr [rsp + 0x6568], r11
+;; 1e0e:	 4c899c2460650000     	mov	qword ptr [rsp + 0x6560], r11
+;; 1e16:	 4c899c2458650000     	mov	qword ptr [rsp + 0x6558], r11
+;; 1e1e:	 4c899c2450650000     	mov	qword ptr [rsp + 0x6550], r11
+;; 1e26:	 4c899c2448650000     	mov	qword ptr [rsp + 0x6548], r11
+;; 1e2e:	 4c899c2440650000     	mov	qword ptr [rsp + 0x6540], r11
+;; 1e36:	 4c899c2438650000     	mov	qword ptr [rsp + 0x6538], r11
+;; 1e3e:	 4c899c2430650000     	mov	qword ptr [rsp + 0x6530], r11
+;; 1e46:	 4c899c2428650000     	mov	qword ptr [rsp + 0x6528], r11
+;; 1e4e:	 4c899c2420650000     	mov	qword ptr [rsp + 0x6520], r11
+;; 1e56:	 4c899c2418650000     	mov	qword ptr [rsp + 0x6518], r11
+;; 1e5e:	 4c899c2410650000     	mov	qword ptr [rsp + 0x6510], r11
+;; 1e66:	 4c899c2408650000     	mov	qword ptr [rsp + 0x6508], r11
+;; 1e6e:	 4c899c2400650000     	mov	qword ptr [rsp + 0x6500], r11
+;; 1e76:	 4c899c24f8640000     	mov	qword ptr [rsp + 0x64f8], r11
+;; 1e7e:	 4c899c24f0640000     	mov	qword ptr [rsp + 0x64f0], r11
+;; 1e86:	 4c899c24e8640000     	mov	qword ptr [rsp + 0x64e8], r11
+;; 1e8e:	 4c899c24e0640000     	mov	qword ptr [rsp + 0x64e0], r11
+;; 1e96:	 4c899c24d8640000     	mov	qword ptr [rsp + 0x64d8], r11
+;; 1e9e:	 4c899c24d0640000     	mov	qword ptr [rsp + 0x64d0], r11
+;; 1ea6:	 4c899c24c8640000     	mov	qword ptr [rsp + 0x64c8], r11
+;; 1eae:	 4c899c24c0640000     	mov	qword ptr [rsp + 0x64c0], r11
+;; 1eb6:	 4c899c24b8640000     	mov	qword ptr [rsp + 0x64b8], r11
+;; 1ebe:	 4c899c24b0640000     	mov	qword ptr [rsp + 0x64b0], r11
+;; 1ec6:	 4c899c24a8640000     	mov	qword ptr [rsp + 0x64a8], r11
+;; 1ece:	 4c899c24a0640000     	mov	qword ptr [rsp + 0x64a0], r11
+;; 1ed6:	 4c899c2498640000     	mov	qword ptr [rsp + 0x6498], r11
+;; 1ede:	 4c899c2490640000     	mov	qword ptr [rsp + 0x6490], r11
+;; 1ee6:	 4c899c2488640000     	mov	qword ptr [rsp + 0x6488], r11
+;; 1eee:	 4c899c2480640000     	mov	qword ptr [rsp + 0x6480], r11
+;; 1ef6:	 4c899c2478640000     	mov	qword ptr [rsp + 0x6478], r11
+;; 1efe:	 4c899c2470640000     	mov	qword ptr [rsp + 0x6470], r11
+;; 1f06:	 4c899c2468640000     	mov	qword ptr [rsp + 0x6468], r11
+;; 1f0e:	 4c899c2460640000     	mov	qword ptr [rsp + 0x6460], r11
+;; 1f16:	 4c899c2458640000     	mov	qword ptr [rsp + 0x6458], r11
+;; 1f1e:	 4c899c2450640000     	mov	qword ptr [rsp + 0x6450], r11
+;; 1f26:	 4c899c2448640000     	mov	qword ptr [rsp + 0x6448], r11
+;; 1f2e:	 4c899c2440640000     	mov	qword ptr [rsp + 0x6440], r11
+;; 1f36:	 4c899c2438640000     	mov	qword ptr [rsp + 0x6438], r11
+;; 1f3e:	 4c899c2430640000     	mov	qword ptr [rsp + 0x6430], r11
+;; 1f46:	 4c899c2428640000     	mov	qword ptr [rsp + 0x6428], r11
+;; 1f4e:	 4c899c2420640000     	mov	qword ptr [rsp + 0x6420], r11
+;; 1f56:	 4c899c2418640000     	mov	qword ptr [rsp + 0x6418], r11
+;; 1f5e:	 4c899c2410640000     	mov	qword ptr [rsp + 0x6410], r11
+;; 1f66:	 4c899c2408640000     	mov	qword ptr [rsp + 0x6408], r11
+;; 1f6e:	 4c899c2400640000     	mov	qword ptr [rsp + 0x6400], r11
+;; 1f76:	 4c899c24f8630000     	mov	qword ptr [rsp + 0x63f8], r11
+;; 1f7e:	 4c899c24f0630000     	mov	qword ptr [rsp + 0x63f0], r11
+;; 1f86:	 4c899c24e8630000     	mov	qword ptr [rsp + 0x63e8], r11
+;; 1f8e:	 4c899c24e0630000     	mov	qword ptr [rsp + 0x63e0], r11
+;; 1f96:	 4c899c24d8630000     	mov	qword ptr [rsp + 0x63d8], r11
+;; 1f9e:	 4c899c24d0630000     	mov	qword ptr [rsp + 0x63d0], r11
+;; 1fa6:	 4c899c24c8630000     	mov	qword ptr [rsp + 0x63c8], r11
+;; 1fae:	 4c899c24c0630000     	mov	qword ptr [rsp + 0x63c0], r11
+;; 1fb6:	 4c899c24b8630000     	mov	qword ptr [rsp + 0x63b8], r11
+;; 1fbe:	 4c899c24b0630000     	mov	qword ptr [rsp + 0x63b0], r11
+;; 1fc6:	 4c899c24a8630000     	mov	qword ptr [rsp + 0x63a8], r11
+;; 1fce:	 4c899c24a0630000     	mov	qword ptr [rsp + 0x63a0], r11
+;; 1fd6:	 4c899c2498630000     	mov	qword ptr [rsp + 0x6398], r11
+;; 1fde:	 4c899c2490630000     	mov	qword ptr [rsp + 0x6390], r11
+;; 1fe6:	 4c899c2488630000     	mov	qword ptr [rsp + 0x6388], r11
+;; 1fee:	 4c899c2480630000     	mov	qword ptr [rsp + 0x6380], r11
+;; 1ff6:	 4c899c2478630000     	mov	qword ptr [rsp + 0x6378], r11
+;; 1ffe:	 4c899c2470630000     	mov	qword ptr [rsp + 0x6370], r11
+;; 2006:	 4c899c2468630000     	mov	qword ptr [rsp + 0x6368], r11
+;; 200e:	 4c899c2460630000     	mov	qword ptr [rsp + 0x6360], r11
+;; 2016:	 4c899c2458630000     	mov	qword ptr [rsp + 0x6358], r11
+;; 201e:	 4c899c2450630000     	mov	qword ptr [rsp + 0x6350], r11
+;; 2026:	 4c899c2448630000     	mov	qword ptr [rsp + 0x6348], r11
+;; 202e:	 4c899c2440630000     	mov	qword ptr [rsp + 0x6340], r11
+;; 2036:	 4c899c2438630000     	mov	qword ptr [rsp + 0x6338], r11
+;; 203e:	 4c899c2430630000     	mov	qword ptr [rsp + 0x6330], r11
+;; 2046:	 4c899c2428630000     	mov	qword ptr [rsp + 0x6328], r11
+;; 204e:	 4c899c2420630000     	mov	qword ptr [rsp + 0x6320], r11
+;; 2056:	 4c899c2418630000     	mov	qword ptr [rsp + 0x6318], r11
+;; 205e:	 4c899c2410630000     	mov	qword ptr [rsp + 0x6310], r11
+;; 2066:	 4c899c2408630000     	mov	qword ptr [rsp + 0x6308], r11
+;; 206e:	 4c899c2400630000     	mov	qword ptr [rsp + 0x6300], r11
+;; 2076:	 4c899c24f8620000     	mov	qword ptr [rsp + 0x62f8], r11
+;; 207e:	 4c899c24f0620000     	mov	qword ptr [rsp + 0x62f0], r11
+;; 2086:	 4c899c24e8620000     	mov	qword ptr [rsp + 0x62e8], r11
+;; 208e:	 4c899c24e0620000     	mov	qword ptr [rsp + 0x62e0], r11
+;; 2096:	 4c899c24d8620000     	mov	qword ptr [rsp + 0x62d8], r11
+;; 209e:	 4c899c24d0620000     	mov	qword ptr [rsp + 0x62d0], r11
+;; 20a6:	 4c899c24c8620000     	mov	qword ptr [rsp + 0x62c8], r11
+;; 20ae:	 4c899c24c0620000     	mov	qword ptr [rsp + 0x62c0], r11
+;; 20b6:	 4c899c24b8620000     	mov	qword ptr [rsp + 0x62b8], r11
+;; 20be:	 4c899c24b0620000     	mov	qword ptr [rsp + 0x62b0], r11
+;; 20c6:	 4c899c24a8620000     	mov	qword ptr [rsp + 0x62a8], r11
+;; 20ce:	 4c899c24a0620000     	mov	qword ptr [rsp + 0x62a0], r11
+;; 20d6:	 4c899c2498620000     	mov	qword ptr [rsp + 0x6298], r11
+;; 20de:	 4c899c2490620000     	mov	qword ptr [rsp + 0x6290], r11
+;; 20e6:	 4c899c2488620000     	mov	qword ptr [rsp + 0x6288], r11
+;; 20ee:	 4c899c2480620000     	mov	qword ptr [rsp + 0x6280], r11
+;; 20f6:	 4c899c2478620000     	mov	qword ptr [rsp + 0x6278], r11
+;; 20fe:	 4c899c2470620000     	mov	qword ptr [rsp + 0x6270], r11
+;; 2106:	 4c899c2468620000     	mov	qword ptr [rsp + 0x6268], r11
+;; 210e:	 4c899c2460620000     	mov	qword ptr [rsp + 0x6260], r11
+;; 2116:	 4c899c2458620000     	mov	qword ptr [rsp + 0x6258], r11
+;; 211e:	 4c899c2450620000     	mov	qword ptr [rsp + 0x6250], r11
+;; 2126:	 4c899c2448620000     	mov	qword ptr [rsp + 0x6248], r11
+;; 212e:	 4c899c2440620000     	mov	qword ptr [rsp + 0x6240], r11
+;; 2136:	 4c899c2438620000     	mov	qword ptr [rsp + 0x6238], r11
+;; 213e:	 4c899c2430620000     	mov	qword ptr [rsp + 0x6230], r11
+;; 2146:	 4c899c2428620000     	mov	qword ptr [rsp + 0x6228], r11
+;; 214e:	 4c899c2420620000     	mov	qword ptr [rsp + 0x6220], r11
+;; 2156:	 4c899c2418620000     	mov	qword ptr [rsp + 0x6218], r11
+;; 215e:	 4c899c2410620000     	mov	qword ptr [rsp + 0x6210], r11
+;; 2166:	 4c899c2408620000     	mov	qword ptr [rsp + 0x6208], r11
+;; 216e:	 4c899c2400620000     	mov	qword ptr [rsp + 0x6200], r11
+;; 2176:	 4c899c24f8610000     	mov	qword ptr [rsp + 0x61f8], r11
+;; 217e:	 4c899c24f0610000     	mov	qword ptr [rsp + 0x61f0], r11
+;; 2186:	 4c899c24e8610000     	mov	qword ptr [rsp + 0x61e8], r11
+;; 218e:	 4c899c24e0610000     	mov	qword ptr [rsp + 0x61e0], r11
+;; 2196:	 4c899c24d8610000     	mov	qword ptr [rsp + 0x61d8], r11
+;; 219e:	 4c899c24d0610000     	mov	qword ptr [rsp + 0x61d0], r11
+;; 21a6:	 4c899c24c8610000     	mov	qword ptr [rsp + 0x61c8], r11
+;; 21ae:	 4c899c24c0610000     	mov	qword ptr [rsp + 0x61c0], r11
+;; 21b6:	 4c899c24b8610000     	mov	qword ptr [rsp + 0x61b8], r11
+;; 21be:	 4c899c24b0610000     	mov	qword ptr [rsp + 0x61b0], r11
+;; 21c6:	 4c899c24a8610000     	mov	qword ptr [rsp + 0x61a8], r11
+;; 21ce:	 4c899c24a0610000     	mov	qword ptr [rsp + 0x61a0], r11
+;; 21d6:	 4c899c2498610000     	mov	qword ptr [rsp + 0x6198], r11
+;; 21de:	 4c899c2490610000     	mov	qword ptr [rsp + 0x6190], r11
+;; 21e6:	 4c899c2488610000     	mov	qword ptr [rsp + 0x6188], r11
+;; 21ee:	 4c899c2480610000     	mov	qword ptr [rsp + 0x6180], r11
+;; 21f6:	 4c899c2478610000     	mov	qword ptr [rsp + 0x6178], r11
+;; 21fe:	 4c899c2470610000     	mov	qword ptr [rsp + 0x6170], r11
+;; 2206:	 4c899c2468610000     	mov	qword ptr [rsp + 0x6168], r11
+;; 220e:	 4c899c2460610000     	mov	qword ptr [rsp + 0x6160], r11
+;; 2216:	 4c899c2458610000     	mov	qword ptr [rsp + 0x6158], r11
+;; 221e:	 4c899c2450610000     	mov	qword ptr [rsp + 0x6150], r11
+;; 2226:	 4c899c2448610000     	mov	qword ptr [rsp + 0x6148], r11
+;; 222e:	 4c899c2440610000     	mov	qword ptr [rsp + 0x6140], r11
+;; 2236:	 4c899c2438610000     	mov	qword ptr [rsp + 0x6138], r11
+;; 223e:	 4c899c2430610000     	mov	qword ptr [rsp + 0x6130], r11
+;; 2246:	 4c899c2428610000     	mov	qword ptr [rsp + 0x6128], r11
+;; 224e:	 4c899c2420610000     	mov	qword ptr [rsp + 0x6120], r11
+;; 2256:	 4c899c2418610000     	mov	qword ptr [rsp + 0x6118], r11
+;; 225e:	 4c899c2410610000     	mov	qword ptr [rsp + 0x6110], r11
+;; 2266:	 4c899c2408610000     	mov	qword ptr [rsp + 0x6108], r11
+;; 226e:	 4c899c2400610000     	mov	qword ptr [rsp + 0x6100], r11
+;; 2276:	 4c899c24f8600000     	mov	qword ptr [rsp + 0x60f8], r11
+;; 227e:	 4c899c24f0600000     	mov	qword ptr [rsp + 0x60f0], r11
+;; 2286:	 4c899c24e8600000     	mov	qword ptr [rsp + 0x60e8], r11
+;; 228e:	 4c899c24e0600000     	mov	qword ptr [rsp + 0x60e0], r11
+;; 2296:	 4c899c24d8600000     	mov	qword ptr [rsp + 0x60d8], r11
+;; 229e:	 4c899c24d0600000     	mov	qword ptr [rsp + 0x60d0], r11
+;; 22a6:	 4c899c24c8600000     	mov	qword ptr [rsp + 0x60c8], r11
+;; 22ae:	 4c899c24c0600000     	mov	qword ptr [rsp + 0x60c0], r11
+;; 22b6:	 4c899c24b8600000     	mov	qword ptr [rsp + 0x60b8], r11
+;; 22be:	 4c899c24b0600000     	mov	qword ptr [rsp + 0x60b0], r11
+;; 22c6:	 4c899c24a8600000     	mov	qword ptr [rsp + 0x60a8], r11
+;; 22ce:	 4c899c24a0600000     	mov	qword ptr [rsp + 0x60a0], r11
+;; 22d6:	 4c899c2498600000     	mov	qword ptr [rsp + 0x6098], r11
+;; 22de:	 4c899c2490600000     	mov	qword ptr [rsp + 0x6090], r11
+;; 22e6:	 4c899c2488600000     	mov	qword ptr [rsp + 0x6088], r11
+;; 22ee:	 4c899c2480600000     	mov	qword ptr [rsp + 0x6080], r11
+;; 22f6:	 4c899c2478600000     	mov	qword ptr [rsp + 0x6078], r11
+;; 22fe:	 4c899c2470600000     	mov	qword ptr [rsp + 0x6070], r11
+;; 2306:	 4c899c2468600000     	mov	qword ptr [rsp + 0x6068], r11
+;; 230e:	 4c899c2460600000     	mov	qword ptr [rsp + 0x6060], r11
+;; 2316:	 4c899c2458600000     	mov	qword ptr [rsp + 0x6058], r11
+;; 231e:	 4c899c2450600000     	mov	qword ptr [rsp + 0x6050], r11
+;; 2326:	 4c899c2448600000     	mov	qword ptr [rsp + 0x6048], r11
+;; 232e:	 4c899c2440600000     	mov	qword ptr [rsp + 0x6040], r11
+;; 2336:	 4c899c2438600000     	mov	qword ptr [rsp + 0x6038], r11
+;; 233e:	 4c899c2430600000     	mov	qword ptr [rsp + 0x6030], r11
+;; 2346:	 4c899c2428600000     	mov	qword ptr [rsp + 0x6028], r11
+;; 234e:	 4c899c2420600000     	mov	qword ptr [rsp + 0x6020], r11
+;; 2356:	 4c899c2418600000     	mov	qword ptr [rsp + 0x6018], r11
+;; 235e:	 4c899c2410600000     	mov	qword ptr [rsp + 0x6010], r11
+;; 2366:	 4c899c2408600000     	mov	qword ptr [rsp + 0x6008], r11
+;; 236e:	 4c899c2400600000     	mov	qword ptr [rsp + 0x6000], r11
+;; 2376:	 4c899c24f85f0000     	mov	qword ptr [rsp + 0x5ff8], r11
+;; 237e:	 4c899c24f05f0000     	mov	qword ptr [rsp + 0x5ff0], r11
+;; 2386:	 4c899c24e85f0000     	mov	qword ptr [rsp + 0x5fe8], r11
+;; 238e:	 4c899c24e05f0000     	mov	qword ptr [rsp + 0x5fe0], r11
+;; 2396:	 4c899c24d85f0000     	mov	qword ptr [rsp + 0x5fd8], r11
+;; 239e:	 4c899c24d05f0000     	mov	qword ptr [rsp + 0x5fd0], r11
+;; 23a6:	 4c899c24c85f0000     	mov	qword ptr [rsp + 0x5fc8], r11
+;; 23ae:	 4c899c24c05f0000     	mov	qword ptr [rsp + 0x5fc0], r11
+;; 23b6:	 4c899c24b85f0000     	mov	qword ptr [rsp + 0x5fb8], r11
+;; 23be:	 4c899c24b05f0000     	mov	qword ptr [rsp + 0x5fb0], r11
+;; 23c6:	 4c899c24a85f0000     	mov	qword ptr [rsp + 0x5fa8], r11
+;; 23ce:	 4c899c24a05f0000     	mov	qword ptr [rsp + 0x5fa0], r11
+;; 23d6:	 4c899c24985f0000     	mov	qword ptr [rsp + 0x5f98], r11
+;; 23de:	 4c899c24905f0000     	mov	qword ptr [rsp + 0x5f90], r11
+;; 23e6:	 4c899c24885f0000     	mov	qword ptr [rsp + 0x5f88], r11
+;; 23ee:	 4c899c24805f0000     	mov	qword ptr [rsp + 0x5f80], r11
+;; 23f6:	 4c899c24785f0000     	mov	qword ptr [rsp + 0x5f78], r11
+;; 23fe:	 4c899c24705f0000     	mov	qword ptr [rsp + 0x5f70], r11
+;; 2406:	 4c899c24685f0000     	mov	qword ptr [rsp + 0x5f68], r11
+;; 240e:	 4c899c24605f0000     	mov	qword ptr [rsp + 0x5f60], r11
+;; 2416:	 4c899c24585f0000     	mov	qword ptr [rsp + 0x5f58], r11
+;; 241e:	 4c899c24505f0000     	mov	qword ptr [rsp + 0x5f50], r11
+;; 2426:	 4c899c24485f0000     	mov	qword ptr [rsp + 0x5f48], r11
+;; 242e:	 4c899c24405f0000     	mov	qword ptr [rsp + 0x5f40], r11
+;; 2436:	 4c899c24385f0000     	mov	qword ptr [rsp + 0x5f38], r11
+;; 243e:	 4c899c24305f0000     	mov	qword ptr [rsp + 0x5f30], r11
+;; 2446:	 4c899c24285f0000     	mov	qword ptr [rsp + 0x5f28], r11
+;; 244e:	 4c899c24205f0000     	mov	qword ptr [rsp + 0x5f20], r11
+;; 2456:	 4c899c24185f0000     	mov	qword ptr [rsp + 0x5f18], r11
+;; 245e:	 4c899c24105f0000     	mov	qword ptr [rsp + 0x5f10], r11
+;; 2466:	 4c899c24085f0000     	mov	qword ptr [rsp + 0x5f08], r11
+;; 246e:	 4c899c24005f0000     	mov	qword ptr [rsp + 0x5f00], r11
+;; 2476:	 4c899c24f85e0000     	mov	qword ptr [rsp + 0x5ef8], r11
+;; 247e:	 4c899c24f05e0000     	mov	qword ptr [rsp + 0x5ef0], r11
+;; 2486:	 4c899c24e85e0000     	mov	qword ptr [rsp + 0x5ee8], r11
+;; 248e:	 4c899c24e05e0000     	mov	qword ptr [rsp + 0x5ee0], r11
+;; 2496:	 4c899c24d85e0000     	mov	qword ptr [rsp + 0x5ed8], r11
+;; 249e:	 4c899c24d05e0000     	mov	qword ptr [rsp + 0x5ed0], r11
+;; 24a6:	 4c899c24c85e0000     	mov	qword ptr [rsp + 0x5ec8], r11
+;; 24ae:	 4c899c24c05e0000     	mov	qword ptr [rsp + 0x5ec0], r11
+;; 24b6:	 4c899c24b85e0000     	mov	qword ptr [rsp + 0x5eb8], r11
+;; 24be:	 4c899c24b05e0000     	mov	qword ptr [rsp + 0x5eb0], r11
+;; 24c6:	 4c899c24a85e0000     	mov	qword ptr [rsp + 0x5ea8], r11
+;; 24ce:	 4c899c24a05e0000     	mov	qword ptr [rsp + 0x5ea0], r11
+;; 24d6:	 4c899c24985e0000     	mov	qword ptr [rsp + 0x5e98], r11
+;; 24de:	 4c899c24905e0000     	mov	qword ptr [rsp + 0x5e90], r11
+;; 24e6:	 4c899c24885e0000     	mov	qword ptr [rsp + 0x5e88], r11
+;; 24ee:	 4c899c24805e0000     	mov	qword ptr [rsp + 0x5e80], r11
+;; 24f6:	 4c899c24785e0000     	mov	qword ptr [rsp + 0x5e78], r11
+;; 24fe:	 4c899c24705e0000     	mov	qword ptr [rsp + 0x5e70], r11
+;; 2506:	 4c899c24685e0000     	mov	qword ptr [rsp + 0x5e68], r11
+;; 250e:	 4c899c24605e0000     	mov	qword ptr [rsp + 0x5e60], r11
+;; 2516:	 4c899c24585e0000     	mov	qword ptr [rsp + 0x5e58], r11
+;; 251e:	 4c899c24505e0000     	mov	qword ptr [rsp + 0x5e50], r11
+;; 2526:	 4c899c24485e0000     	mov	qword ptr [rsp + 0x5e48], r11
+;; 252e:	 4c899c24405e0000     	mov	qword ptr [rsp + 0x5e40], r11
+;; 2536:	 4c899c24385e0000     	mov	qword ptr [rsp + 0x5e38], r11
+;; 253e:	 4c899c24305e0000     	mov	qword ptr [rsp + 0x5e30], r11
+;; 2546:	 4c899c24285e0000     	mov	qword ptr [rsp + 0x5e28], r11
+;; 254e:	 4c899c24205e0000     	mov	qword ptr [rsp + 0x5e20], r11
+;; 2556:	 4c899c24185e0000     	mov	qword ptr [rsp + 0x5e18], r11
+;; 255e:	 4c899c24105e0000     	mov	qword ptr [rsp + 0x5e10], r11
+;; 2566:	 4c899c24085e0000     	mov	qword ptr [rsp + 0x5e08], r11
+;; 256e:	 4c899c24005e0000     	mov	qword ptr [rsp + 0x5e00], r11
+;; 2576:	 4c899c24f85d0000     	mov	qword ptr [rsp + 0x5df8], r11
+;; 257e:	 4c899c24f05d0000     	mov	qword ptr [rsp + 0x5df0], r11
+;; 2586:	 4c899c24e85d0000     	mov	qword ptr [rsp + 0x5de8], r11
+;; 258e:	 4c899c24e05d0000     	mov	qword ptr [rsp + 0x5de0], r11
+;; 2596:	 4c899c24d85d0000     	mov	qword ptr [rsp + 0x5dd8], r11
+;; 259e:	 4c899c24d05d0000     	mov	qword ptr [rsp + 0x5dd0], r11
+;; 25a6:	 4c899c24c85d0000     	mov	qword ptr [rsp + 0x5dc8], r11
+;; 25ae:	 4c899c24c05d0000     	mov	qword ptr [rsp + 0x5dc0], r11
+;; 25b6:	 4c899c24b85d0000     	mov	qword ptr [rsp + 0x5db8], r11
+;; 25be:	 4c899c24b05d0000     	mov	qword ptr [rsp + 0x5db0], r11
+;; 25c6:	 4c899c24a85d0000     	mov	qword ptr [rsp + 0x5da8], r11
+;; 25ce:	 4c899c24a05d0000     	mov	qword ptr [rsp + 0x5da0], r11
+;; 25d6:	 4c899c24985d0000     	mov	qword ptr [rsp + 0x5d98], r11
+;; 25de:	 4c899c24905d0000     	mov	qword ptr [rsp + 0x5d90], r11
+;; 25e6:	 4c899c24885d0000     	mov	qword ptr [rsp + 0x5d88], r11
+;; 25ee:	 4c899c24805d0000     	mov	qword ptr [rsp + 0x5d80], r11
+;; 25f6:	 4c899c24785d0000     	mov	qword ptr [rsp + 0x5d78], r11
+;; 25fe:	 4c899c24705d0000     	mov	qword ptr [rsp + 0x5d70], r11
+;; 2606:	 4c899c24685d0000     	mov	qword ptr [rsp + 0x5d68], r11
+;; 260e:	 4c899c24605d0000     	mov	qword ptr [rsp + 0x5d60], r11
+;; 2616:	 4c899c24585d0000     	mov	qword ptr [rsp + 0x5d58], r11
+;; 261e:	 4c899c24505d0000     	mov	qword ptr [rsp + 0x5d50], r11
+;; 2626:	 4c899c24485d0000     	mov	qword ptr [rsp + 0x5d48], r11
+;; 262e:	 4c899c24405d0000     	mov	qword ptr [rsp + 0x5d40], r11
+;; 2636:	 4c899c24385d0000     	mov	qword ptr [rsp + 0x5d38], r11
+;; 263e:	 4c899c24305d0000     	mov	qword ptr [rsp + 0x5d30], r11
+;; 2646:	 4c899c24285d0000     	mov	qword ptr [rsp + 0x5d28], r11
+;; 264e:	 4c899c24205d0000     	mov	qword ptr [rsp + 0x5d20], r11
+;; 2656:	 4c899c24185d0000     	mov	qword ptr [rsp + 0x5d18], r11
+;; 265e:	 4c899c24105d0000     	mov	qword ptr [rsp + 0x5d10], r11
+;; 2666:	 4c899c24085d0000     	mov	qword ptr [rsp + 0x5d08], r11
+;; 266e:	 4c899c24005d0000     	mov	qword ptr [rsp + 0x5d00], r11
+;; 2676:	 4c899c24f85c0000     	mov	qword ptr [rsp + 0x5cf8], r11
+;; 267e:	 4c899c24f05c0000     	mov	qword ptr [rsp + 0x5cf0], r11
+;; 2686:	 4c899c24e85c0000     	mov	qword ptr [rsp + 0x5ce8], r11
+;; 268e:	 4c899c24e05c0000     	mov	qword ptr [rsp + 0x5ce0], r11
+;; 2696:	 4c899c24d85c0000     	mov	qword ptr [rsp + 0x5cd8], r11
+;; 269e:	 4c899c24d05c0000     	mov	qword ptr [rsp + 0x5cd0], r11
+;; 26a6:	 4c899c24c85c0000     	mov	qword ptr [rsp + 0x5cc8], r11
+;; 26ae:	 4c899c24c05c0000     	mov	qword ptr [rsp + 0x5cc0], r11
+;; 26b6:	 4c899c24b85c0000     	mov	qword ptr [rsp + 0x5cb8], r11
+;; 26be:	 4c899c24b05c0000     	mov	qword ptr [rsp + 0x5cb0], r11
+;; 26c6:	 4c899c24a85c0000     	mov	qword ptr [rsp + 0x5ca8], r11
+;; 26ce:	 4c899c24a05c0000     	mov	qword ptr [rsp + 0x5ca0], r11
+;; 26d6:	 4c899c24985c0000     	mov	qword ptr [rsp + 0x5c98], r11
+;; 26de:	 4c899c24905c0000     	mov	qword ptr [rsp + 0x5c90], r11
+;; 26e6:	 4c899c24885c0000     	mov	qword ptr [rsp + 0x5c88], r11
+;; 26ee:	 4c899c24805c0000     	mov	qword ptr [rsp + 0x5c80], r11
+;; 26f6:	 4c899c24785c0000     	mov	qword ptr [rsp + 0x5c78], r11
+;; 26fe:	 4c899c24705c0000     	mov	qword ptr [rsp + 0x5c70], r11
+;; 2706:	 4c899c24685c0000     	mov	qword ptr [rsp + 0x5c68], r11
+;; 270e:	 4c899c24605c0000     	mov	qword ptr [rsp + 0x5c60], r11
+;; 2716:	 4c899c24585c0000     	mov	qword ptr [rsp + 0x5c58], r11
+;; 271e:	 4c899c24505c0000     	mov	qword ptr [rsp + 0x5c50], r11
+;; 2726:	 4c899c24485c0000     	mov	qword ptr [rsp + 0x5c48], r11
+;; 272e:	 4c899c24405c0000     	mov	qword ptr [rsp + 0x5c40], r11
+;; 2736:	 4c899c24385c0000     	mov	qword ptr [rsp + 0x5c38], r11
+;; 273e:	 4c899c24305c0000     	mov	qword ptr [rsp + 0x5c30], r11
+;; 2746:	 4c899c24285c0000     	mov	qword ptr [rsp + 0x5c28], r11
+;; 274e:	 4c899c24205c0000     	mov	qword ptr [rsp + 0x5c20], r11
+;; 2756:	 4c899c24185c0000     	mov	qword ptr [rsp + 0x5c18], r11
+;; 275e:	 4c899c24105c0000     	mov	qword ptr [rsp + 0x5c10], r11
+;; 2766:	 4c899c24085c0000     	mov	qword ptr [rsp + 0x5c08], r11
+;; 276e:	 4c899c24005c0000     	mov	qword ptr [rsp + 0x5c00], r11
+;; 2776:	 4c899c24f85b0000     	mov	qword ptr [rsp + 0x5bf8], r11
+;; 277e:	 4c899c24f05b0000     	mov	qword ptr [rsp + 0x5bf0], r11
+;; 2786:	 4c899c24e85b0000     	mov	qword ptr [rsp + 0x5be8], r11
+;; 278e:	 4c899c24e05b0000     	mov	qword ptr [rsp + 0x5be0], r11
+;; 2796:	 4c899c24d85b0000     	mov	qword ptr [rsp + 0x5bd8], r11
+;; 279e:	 4c899c24d05b0000     	mov	qword ptr [rsp + 0x5bd0], r11
+;; 27a6:	 4c899c24c85b0000     	mov	qword ptr [rsp + 0x5bc8], r11
+;; 27ae:	 4c899c24c05b0000     	mov	qword ptr [rsp + 0x5bc0], r11
+;; 27b6:	 4c899c24b85b0000     	mov	qword ptr [rsp + 0x5bb8], r11
+;; 27be:	 4c899c24b05b0000     	mov	qword ptr [rsp + 0x5bb0], r11
+;; 27c6:	 4c899c24a85b0000     	mov	qword ptr [rsp + 0x5ba8], r11
+;; 27ce:	 4c899c24a05b0000     	mov	qword ptr [rsp + 0x5ba0], r11
+;; 27d6:	 4c899c24985b0000     	mov	qword ptr [rsp + 0x5b98], r11
+;; 27de:	 4c899c24905b0000     	mov	qword ptr [rsp + 0x5b90], r11
+;; 27e6:	 4c899c24885b0000     	mov	qword ptr [rsp + 0x5b88], r11
+;; 27ee:	 4c899c24805b0000     	mov	qword ptr [rsp + 0x5b80], r11
+;; 27f6:	 4c899c24785b0000     	mov	qword ptr [rsp + 0x5b78], r11
+;; 27fe:	 4c899c24705b0000     	mov	qword ptr [rsp + 0x5b70], r11
+;; 2806:	 4c899c24685b0000     	mov	qword ptr [rsp + 0x5b68], r11
+;; 280e:	 4c899c24605b0000     	mov	qword ptr [rsp + 0x5b60], r11
+;; 2816:	 4c899c24585b0000     	mov	qword ptr [rsp + 0x5b58], r11
+;; 281e:	 4c899c24505b0000     	mov	qword ptr [rsp + 0x5b50], r11
+;; 2826:	 4c899c24485b0000     	mov	qword ptr [rsp + 0x5b48], r11
+;; 282e:	 4c899c24405b0000     	mov	qword ptr [rsp + 0x5b40], r11
+;; 2836:	 4c899c24385b0000     	mov	qword ptr [rsp + 0x5b38], r11
+;; 283e:	 4c899c24305b0000     	mov	qword ptr [rsp + 0x5b30], r11
+;; 2846:	 4c899c24285b0000     	mov	qword ptr [rsp + 0x5b28], r11
+;; 284e:	 4c899c24205b0000     	mov	qword ptr [rsp + 0x5b20], r11
+;; 2856:	 4c899c24185b0000     	mov	qword ptr [rsp + 0x5b18], r11
+;; 285e:	 4c899c24105b0000     	mov	qword ptr [rsp + 0x5b10], r11
+;; 2866:	 4c899c24085b0000     	mov	qword ptr [rsp + 0x5b08], r11
+;; 286e:	 4c899c24005b0000     	mov	qword ptr [rsp + 0x5b00], r11
+;; 2876:	 4c899c24f85a0000     	mov	qword ptr [rsp + 0x5af8], r11
+;; 287e:	 4c899c24f05a0000     	mov	qword ptr [rsp + 0x5af0], r11
+;; 2886:	 4c899c24e85a0000     	mov	qword ptr [rsp + 0x5ae8], r11
+;; 288e:	 4c899c24e05a0000     	mov	qword ptr [rsp + 0x5ae0], r11
+;; 2896:	 4c899c24d85a0000     	mov	qword ptr [rsp + 0x5ad8], r11
+;; 289e:	 4c899c24d05a0000     	mov	qword ptr [rsp + 0x5ad0], r11
+;; 28a6:	 4c899c24c85a0000     	mov	qword ptr [rsp + 0x5ac8], r11
+;; 28ae:	 4c899c24c05a0000     	mov	qword ptr [rsp + 0x5ac0], r11
+;; 28b6:	 4c899c24b85a0000     	mov	qword ptr [rsp + 0x5ab8], r11
+;; 28be:	 4c899c24b05a0000     	mov	qword ptr [rsp + 0x5ab0], r11
+;; 28c6:	 4c899c24a85a0000     	mov	qword ptr [rsp + 0x5aa8], r11
+;; 28ce:	 4c899c24a05a0000     	mov	qword ptr [rsp + 0x5aa0], r11
+;; 28d6:	 4c899c24985a0000     	mov	qword ptr [rsp + 0x5a98], r11
+;; 28de:	 4c899c24905a0000     	mov	qword ptr [rsp + 0x5a90], r11
+;; 28e6:	 4c899c24885a0000     	mov	qword ptr [rsp + 0x5a88], r11
+;; 28ee:	 4c899c24805a0000     	mov	qword ptr [rsp + 0x5a80], r11
+;; 28f6:	 4c899c24785a0000     	mov	qword ptr [rsp + 0x5a78], r11
+;; 28fe:	 4c899c24705a0000     	mov	qword ptr [rsp + 0x5a70], r11
+;; 2906:	 4c899c24685a0000     	mov	qword ptr [rsp + 0x5a68], r11
+;; 290e:	 4c899c24605a0000     	mov	qword ptr [rsp + 0x5a60], r11
+;; 2916:	 4c899c24585a0000     	mov	qword ptr [rsp + 0x5a58], r11
+;; 291e:	 4c899c24505a0000     	mov	qword ptr [rsp + 0x5a50], r11
+;; 2926:	 4c899c24485a0000     	mov	qword ptr [rsp + 0x5a48], r11
+;; 292e:	 4c899c24405a0000     	mov	qword ptr [rsp + 0x5a40], r11
+;; 2936:	 4c899c24385a0000     	mov	qword ptr [rsp + 0x5a38], r11
+;; 293e:	 4c899c24305a0000     	mov	qword ptr [rsp + 0x5a30], r11
+;; 2946:	 4c899c24285a0000     	mov	qword ptr [rsp + 0x5a28], r11
+;; 294e:	 4c899c24205a0000     	mov	qword ptr [rsp + 0x5a20], r11
+;; 2956:	 4c899c24185a0000     	mov	qword ptr [rsp + 0x5a18], r11
+;; 295e:	 4c899c24105a0000     	mov	qword ptr [rsp + 0x5a10], r11
+;; 2966:	 4c899c24085a0000     	mov	qword ptr [rsp + 0x5a08], r11
+;; 296e:	 4c899c24005a0000     	mov	qword ptr [rsp + 0x5a00], r11
+;; 2976:	 4c899c24f8590000     	mov	qword ptr [rsp + 0x59f8], r11
+;; 297e:	 4c899c24f0590000     	mov	qword ptr [rsp + 0x59f0], r11
+;; 2986:	 4c899c24e8590000     	mov	qword ptr [rsp + 0x59e8], r11
+;; 298e:	 4c899c24e0590000     	mov	qword ptr [rsp + 0x59e0], r11
+;; 2996:	 4c899c24d8590000     	mov	qword ptr [rsp + 0x59d8], r11
+;; 299e:	 4c899c24d0590000     	mov	qword ptr [rsp + 0x59d0], r11
+;; 29a6:	 4c899c24c8590000     	mov	qword ptr [rsp + 0x59c8], r11
+;; 29ae:	 4c899c24c0590000     	mov	qword ptr [rsp + 0x59c0], r11
+;; 29b6:	 4c899c24b8590000     	mov	qword ptr [rsp + 0x59b8], r11
+;; 29be:	 4c899c24b0590000     	mov	qword ptr [rsp + 0x59b0], r11
+;; 29c6:	 4c899c24a8590000     	mov	qword ptr [rsp + 0x59a8], r11
+;; 29ce:	 4c899c24a0590000     	mov	qword ptr [rsp + 0x59a0], r11
+;; 29d6:	 4c899c2498590000     	mov	qword ptr [rsp + 0x5998], r11
+;; 29de:	 4c899c2490590000     	mov	qword ptr [rsp + 0x5990], r11
+;; 29e6:	 4c899c2488590000     	mov	qword ptr [rsp + 0x5988], r11
+;; 29ee:	 4c899c2480590000     	mov	qword ptr [rsp + 0x5980], r11
+;; 29f6:	 4c899c2478590000     	mov	qword ptr [rsp + 0x5978], r11
+;; 29fe:	 4c899c2470590000     	mov	qword ptr [rsp + 0x5970], r11
+;; 2a06:	 4c899c2468590000     	mov	qword ptr [rsp + 0x5968], r11
+;; 2a0e:	 4c899c2460590000     	mov	qword ptr [rsp + 0x5960], r11
+;; 2a16:	 4c899c2458590000     	mov	qword ptr [rsp + 0x5958], r11
+;; 2a1e:	 4c899c2450590000     	mov	qword ptr [rsp + 0x5950], r11
+;; 2a26:	 4c899c2448590000     	mov	qword ptr [rsp + 0x5948], r11
+;; 2a2e:	 4c899c2440590000     	mov	qword ptr [rsp + 0x5940], r11
+;; 2a36:	 4c899c2438590000     	mov	qword ptr [rsp + 0x5938], r11
+;; 2a3e:	 4c899c2430590000     	mov	qword ptr [rsp + 0x5930], r11
+;; 2a46:	 4c899c2428590000     	mov	qword ptr [rsp + 0x5928], r11
+;; 2a4e:	 4c899c2420590000     	mov	qword ptr [rsp + 0x5920], r11
+;; 2a56:	 4c899c2418590000     	mov	qword ptr [rsp + 0x5918], r11
+;; 2a5e:	 4c899c2410590000     	mov	qword ptr [rsp + 0x5910], r11
+;; 2a66:	 4c899c2408590000     	mov	qword ptr [rsp + 0x5908], r11
+;; 2a6e:	 4c899c2400590000     	mov	qword ptr [rsp + 0x5900], r11
+;; 2a76:	 4c899c24f8580000     	mov	qword ptr [rsp + 0x58f8], r11
+;; 2a7e:	 4c899c24f0580000     	mov	qword ptr [rsp + 0x58f0], r11
+;; 2a86:	 4c899c24e8580000     	mov	qword ptr [rsp + 0x58e8], r11
+;; 2a8e:	 4c899c24e0580000     	mov	qword ptr [rsp + 0x58e0], r11
+;; 2a96:	 4c899c24d8580000     	mov	qword ptr [rsp + 0x58d8], r11
+;; 2a9e:	 4c899c24d0580000     	mov	qword ptr [rsp + 0x58d0], r11
+;; 2aa6:	 4c899c24c8580000     	mov	qword ptr [rsp + 0x58c8], r11
+;; 2aae:	 4c899c24c0580000     	mov	qword ptr [rsp + 0x58c0], r11
+;; 2ab6:	 4c899c24b8580000     	mov	qword ptr [rsp + 0x58b8], r11
+;; 2abe:	 4c899c24b0580000     	mov	qword ptr [rsp + 0x58b0], r11
+;; 2ac6:	 4c899c24a8580000     	mov	qword ptr [rsp + 0x58a8], r11
+;; 2ace:	 4c899c24a0580000     	mov	qword ptr [rsp + 0x58a0], r11
+;; 2ad6:	 4c899c2498580000     	mov	qword ptr [rsp + 0x5898], r11
+;; 2ade:	 4c899c2490580000     	mov	qword ptr [rsp + 0x5890], r11
+;; 2ae6:	 4c899c2488580000     	mov	qword ptr [rsp + 0x5888], r11
+;; 2aee:	 4c899c2480580000     	mov	qword ptr [rsp + 0x5880], r11
+;; 2af6:	 4c899c2478580000     	mov	qword ptr [rsp + 0x5878], r11
+;; 2afe:	 4c899c2470580000     	mov	qword ptr [rsp + 0x5870], r11
+;; 2b06:	 4c899c2468580000     	mov	qword ptr [rsp + 0x5868], r11
+;; 2b0e:	 4c899c2460580000     	mov	qword ptr [rsp + 0x5860], r11
+;; 2b16:	 4c899c2458580000     	mov	qword ptr [rsp + 0x5858], r11
+;; 2b1e:	 4c899c2450580000     	mov	qword ptr [rsp + 0x5850], r11
+;; 2b26:	 4c899c2448580000     	mov	qword ptr [rsp + 0x5848], r11
+;; 2b2e:	 4c899c2440580000     	mov	qword ptr [rsp + 0x5840], r11
+;; 2b36:	 4c899c2438580000     	mov	qword ptr [rsp + 0x5838], r11
+;; 2b3e:	 4c899c2430580000     	mov	qword ptr [rsp + 0x5830], r11
+;; 2b46:	 4c899c2428580000     	mov	qword ptr [rsp + 0x5828], r11
+;; 2b4e:	 4c899c2420580000     	mov	qword ptr [rsp + 0x5820], r11
+;; 2b56:	 4c899c2418580000     	mov	qword ptr [rsp + 0x5818], r11
+;; 2b5e:	 4c899c2410580000     	mov	qword ptr [rsp + 0x5810], r11
+;; 2b66:	 4c899c2408580000     	mov	qword ptr [rsp + 0x5808], r11
+;; 2b6e:	 4c899c2400580000     	mov	qword ptr [rsp + 0x5800], r11
+;; 2b76:	 4c899c24f8570000     	mov	qword ptr [rsp + 0x57f8], r11
+;; 2b7e:	 4c899c24f0570000     	mov	qword ptr [rsp + 0x57f0], r11
+;; 2b86:	 4c899c24e8570000     	mov	qword ptr [rsp + 0x57e8], r11
+;; 2b8e:	 4c899c24e0570000     	mov	qword ptr [rsp + 0x57e0], r11
+;; 2b96:	 4c899c24d8570000     	mov	qword ptr [rsp + 0x57d8], r11
+;; 2b9e:	 4c899c24d0570000     	mov	qword ptr [rsp + 0x57d0], r11
+;; 2ba6:	 4c899c24c8570000     	mov	qword ptr [rsp + 0x57c8], r11
+;; 2bae:	 4c899c24c0570000     	mov	qword ptr [rsp + 0x57c0], r11
+;; 2bb6:	 4c899c24b8570000     	mov	qword ptr [rsp + 0x57b8], r11
+;; 2bbe:	 4c899c24b0570000     	mov	qword ptr [rsp + 0x57b0], r11
+;; 2bc6:	 4c899c24a8570000     	mov	qword ptr [rsp + 0x57a8], r11
+;; 2bce:	 4c899c24a0570000     	mov	qword ptr [rsp + 0x57a0], r11
+;; 2bd6:	 4c899c2498570000     	mov	qword ptr [rsp + 0x5798], r11
+;; 2bde:	 4c899c2490570000     	mov	qword ptr [rsp + 0x5790], r11
+;; 2be6:	 4c899c2488570000     	mov	qword ptr [rsp + 0x5788], r11
+;; 2bee:	 4c899c2480570000     	mov	qword ptr [rsp + 0x5780], r11
+;; 2bf6:	 4c899c2478570000     	mov	qword ptr [rsp + 0x5778], r11
+;; 2bfe:	 4c899c2470570000     	mov	qword ptr [rsp + 0x5770], r11
+;; 2c06:	 4c899c2468570000     	mov	qword ptr [rsp + 0x5768], r11
+;; 2c0e:	 4c899c2460570000     	mov	qword ptr [rsp + 0x5760], r11
+;; 2c16:	 4c899c2458570000     	mov	qword ptr [rsp + 0x5758], r11
+;; 2c1e:	 4c899c2450570000     	mov	qword ptr [rsp + 0x5750], r11
+;; 2c26:	 4c899c2448570000     	mov	qword ptr [rsp + 0x5748], r11
+;; 2c2e:	 4c899c2440570000     	mov	qword ptr [rsp + 0x5740], r11
+;; 2c36:	 4c899c2438570000     	mov	qword ptr [rsp + 0x5738], r11
+;; 2c3e:	 4c899c2430570000     	mov	qword ptr [rsp + 0x5730], r11
+;; 2c46:	 4c899c2428570000     	mov	qword ptr [rsp + 0x5728], r11
+;; 2c4e:	 4c899c2420570000     	mov	qword ptr [rsp + 0x5720], r11
+;; 2c56:	 4c899c2418570000     	mov	qword ptr [rsp + 0x5718], r11
+;; 2c5e:	 4c899c2410570000     	mov	qword ptr [rsp + 0x5710], r11
+;; 2c66:	 4c899c2408570000     	mov	qword ptr [rsp + 0x5708], r11
+;; 2c6e:	 4c899c2400570000     	mov	qword ptr [rsp + 0x5700], r11
+;; 2c76:	 4c899c24f8560000     	mov	qword ptr [rsp + 0x56f8], r11
+;; 2c7e:	 4c899c24f0560000     	mov	qword ptr [rsp + 0x56f0], r11
+;; 2c86:	 4c899c24e8560000     	mov	qword ptr [rsp + 0x56e8], r11
+;; 2c8e:	 4c899c24e0560000     	mov	qword ptr [rsp + 0x56e0], r11
+;; 2c96:	 4c899c24d8560000     	mov	qword ptr [rsp + 0x56d8], r11
+;; 2c9e:	 4c899c24d0560000     	mov	qword ptr [rsp + 0x56d0], r11
+;; 2ca6:	 4c899c24c8560000     	mov	qword ptr [rsp + 0x56c8], r11
+;; 2cae:	 4c899c24c0560000     	mov	qword ptr [rsp + 0x56c0], r11
+;; 2cb6:	 4c899c24b8560000     	mov	qword ptr [rsp + 0x56b8], r11
+;; 2cbe:	 4c899c24b0560000     	mov	qword ptr [rsp + 0x56b0], r11
+;; 2cc6:	 4c899c24a8560000     	mov	qword ptr [rsp + 0x56a8], r11
+;; 2cce:	 4c899c24a0560000     	mov	qword ptr [rsp + 0x56a0], r11
+;; 2cd6:	 4c899c2498560000     	mov	qword ptr [rsp + 0x5698], r11
+;; 2cde:	 4c899c2490560000     	mov	qword ptr [rsp + 0x5690], r11
+;; 2ce6:	 4c899c2488560000     	mov	qword ptr [rsp + 0x5688], r11
+;; 2cee:	 4c899c2480560000     	mov	qword ptr [rsp + 0x5680], r11
+;; 2cf6:	 4c899c2478560000     	mov	qword ptr [rsp + 0x5678], r11
+;; 2cfe:	 4c899c2470560000     	mov	qword ptr [rsp + 0x5670], r11
+;; 2d06:	 4c899c2468560000     	mov	qword ptr [rsp + 0x5668], r11
+;; 2d0e:	 4c899c2460560000     	mov	qword ptr [rsp + 0x5660], r11
+;; 2d16:	 4c899c2458560000     	mov	qword ptr [rsp + 0x5658], r11
+;; 2d1e:	 4c899c2450560000     	mov	qword ptr [rsp + 0x5650], r11
+;; 2d26:	 4c899c2448560000     	mov	qword ptr [rsp + 0x5648], r11
+;; 2d2e:	 4c899c2440560000     	mov	qword ptr [rsp + 0x5640], r11
+;; 2d36:	 4c899c2438560000     	mov	qword ptr [rsp + 0x5638], r11
+;; 2d3e:	 4c899c2430560000     	mov	qword ptr [rsp + 0x5630], r11
+;; 2d46:	 4c899c2428560000     	mov	qword ptr [rsp + 0x5628], r11
+;; 2d4e:	 4c899c2420560000     	mov	qword ptr [rsp + 0x5620], r11
+;; 2d56:	 4c899c2418560000     	mov	qword ptr [rsp + 0x5618], r11
+;; 2d5e:	 4c899c2410560000     	mov	qword ptr [rsp + 0x5610], r11
+;; 2d66:	 4c899c2408560000     	mov	qword ptr [rsp + 0x5608], r11
+;; 2d6e:	 4c899c2400560000     	mov	qword ptr [rsp + 0x5600], r11
+;; 2d76:	 4c899c24f8550000     	mov	qword ptr [rsp + 0x55f8], r11
+;; 2d7e:	 4c899c24f0550000     	mov	qword ptr [rsp + 0x55f0], r11
+;; 2d86:	 4c899c24e8550000     	mov	qword ptr [rsp + 0x55e8], r11
+;; 2d8e:	 4c899c24e0550000     	mov	qword ptr [rsp + 0x55e0], r11
+;; 2d96:	 4c899c24d8550000     	mov	qword ptr [rsp + 0x55d8], r11
+;; 2d9e:	 4c899c24d0550000     	mov	qword ptr [rsp + 0x55d0], r11
+;; 2da6:	 4c899c24c8550000     	mov	qword ptr [rsp + 0x55c8], r11
+;; 2dae:	 4c899c24c0550000     	mov	qword ptr [rsp + 0x55c0], r11
+;; 2db6:	 4c899c24b8550000     	mov	qword ptr [rsp + 0x55b8], r11
+;; 2dbe:	 4c899c24b0550000     	mov	qword ptr [rsp + 0x55b0], r11
+;; 2dc6:	 4c899c24a8550000     	mov	qword ptr [rsp + 0x55a8], r11
+;; 2dce:	 4c899c24a0550000     	mov	qword ptr [rsp + 0x55a0], r11
+;; 2dd6:	 4c899c2498550000     	mov	qword ptr [rsp + 0x5598], r11
+;; 2dde:	 4c899c2490550000     	mov	qword ptr [rsp + 0x5590], r11
+;; 2de6:	 4c899c2488550000     	mov	qword ptr [rsp + 0x5588], r11
+;; 2dee:	 4c899c2480550000     	mov	qword ptr [rsp + 0x5580], r11
+;; 2df6:	 4c899c2478550000     	mov	qword ptr [rsp + 0x5578], r11
+;; 2dfe:	 4c899c2470550000     	mov	qword ptr [rsp + 0x5570], r11
+;; 2e06:	 4c899c2468550000     	mov	qword ptr [rsp + 0x5568], r11
+;; 2e0e:	 4c899c2460550000     	mov	qword ptr [rsp + 0x5560], r11
+;; 2e16:	 4c899c2458550000     	mov	qword ptr [rsp + 0x5558], r11
+;; 2e1e:	 4c899c2450550000     	mov	qword ptr [rsp + 0x5550], r11
+;; 2e26:	 4c899c2448550000     	mov	qword ptr [rsp + 0x5548], r11
+;; 2e2e:	 4c899c2440550000     	mov	qword ptr [rsp + 0x5540], r11
+;; 2e36:	 4c899c2438550000     	mov	qword ptr [rsp + 0x5538], r11
+;; 2e3e:	 4c899c2430550000     	mov	qword ptr [rsp + 0x5530], r11
+;; 2e46:	 4c899c2428550000     	mov	qword ptr [rsp + 0x5528], r11
+;; 2e4e:	 4c899c2420550000     	mov	qword ptr [rsp + 0x5520], r11
+;; 2e56:	 4c899c2418550000     	mov	qword ptr [rsp + 0x5518], r11
+;; 2e5e:	 4c899c2410550000     	mov	qword ptr [rsp + 0x5510], r11
+;; 2e66:	 4c899c2408550000     	mov	qword ptr [rsp + 0x5508], r11
+;; 2e6e:	 4c899c2400550000     	mov	qword ptr [rsp + 0x5500], r11
+;; 2e76:	 4c899c24f8540000     	mov	qword ptr [rsp + 0x54f8], r11
+;; 2e7e:	 4c899c24f0540000     	mov	qword ptr [rsp + 0x54f0], r11
+;; 2e86:	 4c899c24e8540000     	mov	qword ptr [rsp + 0x54e8], r11
+;; 2e8e:	 4c899c24e0540000     	mov	qword ptr [rsp + 0x54e0], r11
+;; 2e96:	 4c899c24d8540000     	mov	qword ptr [rsp + 0x54d8], r11
+;; 2e9e:	 4c899c24d0540000     	mov	qword ptr [rsp + 0x54d0], r11
+;; 2ea6:	 4c899c24c8540000     	mov	qword ptr [rsp + 0x54c8], r11
+;; 2eae:	 4c899c24c0540000     	mov	qword ptr [rsp + 0x54c0], r11
+;; 2eb6:	 4c899c24b8540000     	mov	qword ptr [rsp + 0x54b8], r11
+;; 2ebe:	 4c899c24b0540000     	mov	qword ptr [rsp + 0x54b0], r11
+;; 2ec6:	 4c899c24a8540000     	mov	qword ptr [rsp + 0x54a8], r11
+;; 2ece:	 4c899c24a0540000     	mov	qword ptr [rsp + 0x54a0], r11
+;; 2ed6:	 4c899c2498540000     	mov	qword ptr [rsp + 0x5498], r11
+;; 2ede:	 4c899c2490540000     	mov	qword ptr [rsp + 0x5490], r11
+;; 2ee6:	 4c899c2488540000     	mov	qword ptr [rsp + 0x5488], r11
+;; 2eee:	 4c899c2480540000     	mov	qword ptr [rsp + 0x5480], r11
+;; 2ef6:	 4c899c2478540000     	mov	qword ptr [rsp + 0x5478], r11
+;; 2efe:	 4c899c2470540000     	mov	qword ptr [rsp + 0x5470], r11
+;; 2f06:	 4c899c2468540000     	mov	qword ptr [rsp + 0x5468], r11
+;; 2f0e:	 4c899c2460540000     	mov	qword ptr [rsp + 0x5460], r11
+;; 2f16:	 4c899c2458540000     	mov	qword ptr [rsp + 0x5458], r11
+;; 2f1e:	 4c899c2450540000     	mov	qword ptr [rsp + 0x5450], r11
+;; 2f26:	 4c899c2448540000     	mov	qword ptr [rsp + 0x5448], r11
+;; 2f2e:	 4c899c2440540000     	mov	qword ptr [rsp + 0x5440], r11
+;; 2f36:	 4c899c2438540000     	mov	qword ptr [rsp + 0x5438], r11
+;; 2f3e:	 4c899c2430540000     	mov	qword ptr [rsp + 0x5430], r11
+;; 2f46:	 4c899c2428540000     	mov	qword ptr [rsp + 0x5428], r11
+;; 2f4e:	 4c899c2420540000     	mov	qword ptr [rsp + 0x5420], r11
+;; 2f56:	 4c899c2418540000     	mov	qword ptr [rsp + 0x5418], r11
+;; 2f5e:	 4c899c2410540000     	mov	qword ptr [rsp + 0x5410], r11
+;; 2f66:	 4c899c2408540000     	mov	qword ptr [rsp + 0x5408], r11
+;; 2f6e:	 4c899c2400540000     	mov	qword ptr [rsp + 0x5400], r11
+;; 2f76:	 4c899c24f8530000     	mov	qword ptr [rsp + 0x53f8], r11
+;; 2f7e:	 4c899c24f0530000     	mov	qword ptr [rsp + 0x53f0], r11
+;; 2f86:	 4c899c24e8530000     	mov	qword ptr [rsp + 0x53e8], r11
+;; 2f8e:	 4c899c24e0530000     	mov	qword ptr [rsp + 0x53e0], r11
+;; 2f96:	 4c899c24d8530000     	mov	qword ptr [rsp + 0x53d8], r11
+;; 2f9e:	 4c899c24d0530000     	mov	qword ptr [rsp + 0x53d0], r11
+;; 2fa6:	 4c899c24c8530000     	mov	qword ptr [rsp + 0x53c8], r11
+;; 2fae:	 4c899c24c0530000     	mov	qword ptr [rsp + 0x53c0], r11
+;; 2fb6:	 4c899c24b8530000     	mov	qword ptr [rsp + 0x53b8], r11
+;; 2fbe:	 4c899c24b0530000     	mov	qword ptr [rsp + 0x53b0], r11
+;; 2fc6:	 4c899c24a8530000     	mov	qword ptr [rsp + 0x53a8], r11
+;; 2fce:	 4c899c24a0530000     	mov	qword ptr [rsp + 0x53a0], r11
+;; 2fd6:	 4c899c2498530000     	mov	qword ptr [rsp + 0x5398], r11
+;; 2fde:	 4c899c2490530000     	mov	qword ptr [rsp + 0x5390], r11
+;; 2fe6:	 4c899c2488530000     	mov	qword ptr [rsp + 0x5388], r11
+;; 2fee:	 4c899c2480530000     	mov	qword ptr [rsp + 0x5380], r11
+;; 2ff6:	 4c899c2478530000     	mov	qword ptr [rsp + 0x5378], r11
+;; 2ffe:	 4c899c2470530000     	mov	qword ptr [rsp + 0x5370], r11
+;; 3006:	 4c899c2468530000     	mov	qword ptr [rsp + 0x5368], r11
+;; 300e:	 4c899c2460530000     	mov	qword ptr [rsp + 0x5360], r11
+;; 3016:	 4c899c2458530000     	mov	qword ptr [rsp + 0x5358], r11
+;; 301e:	 4c899c2450530000     	mov	qword ptr [rsp + 0x5350], r11
+;; 3026:	 4c899c2448530000     	mov	qword ptr [rsp + 0x5348], r11
+;; 302e:	 4c899c2440530000     	mov	qword ptr [rsp + 0x5340], r11
+;; 3036:	 4c899c2438530000     	mov	qword ptr [rsp + 0x5338], r11
+;; 303e:	 4c899c2430530000     	mov	qword ptr [rsp + 0x5330], r11
+;; 3046:	 4c899c2428530000     	mov	qword ptr [rsp + 0x5328], r11
+;; 304e:	 4c899c2420530000     	mov	qword ptr [rsp + 0x5320], r11
+;; 3056:	 4c899c2418530000     	mov	qword ptr [rsp + 0x5318], r11
+;; 305e:	 4c899c2410530000     	mov	qword ptr [rsp + 0x5310], r11
+;; 3066:	 4c899c2408530000     	mov	qword ptr [rsp + 0x5308], r11
+;; 306e:	 4c899c2400530000     	mov	qword ptr [rsp + 0x5300], r11
+;; 3076:	 4c899c24f8520000     	mov	qword ptr [rsp + 0x52f8], r11
+;; 307e:	 4c899c24f0520000     	mov	qword ptr [rsp + 0x52f0], r11
+;; 3086:	 4c899c24e8520000     	mov	qword ptr [rsp + 0x52e8], r11
+;; 308e:	 4c899c24e0520000     	mov	qword ptr [rsp + 0x52e0], r11
+;; 3096:	 4c899c24d8520000     	mov	qword ptr [rsp + 0x52d8], r11
+;; 309e:	 4c899c24d0520000     	mov	qword ptr [rsp + 0x52d0], r11
+;; 30a6:	 4c899c24c8520000     	mov	qword ptr [rsp + 0x52c8], r11
+;; 30ae:	 4c899c24c0520000     	mov	qword ptr [rsp + 0x52c0], r11
+;; 30b6:	 4c899c24b8520000     	mov	qword ptr [rsp + 0x52b8], r11
+;; 30be:	 4c899c24b0520000     	mov	qword ptr [rsp + 0x52b0], r11
+;; 30c6:	 4c899c24a8520000     	mov	qword ptr [rsp + 0x52a8], r11
+;; 30ce:	 4c899c24a0520000     	mov	qword ptr [rsp + 0x52a0], r11
+;; 30d6:	 4c899c2498520000     	mov	qword ptr [rsp + 0x5298], r11
+;; 30de:	 4c899c2490520000     	mov	qword ptr [rsp + 0x5290], r11
+;; 30e6:	 4c899c2488520000     	mov	qword ptr [rsp + 0x5288], r11
+;; 30ee:	 4c899c2480520000     	mov	qword ptr [rsp + 0x5280], r11
+;; 30f6:	 4c899c2478520000     	mov	qword ptr [rsp + 0x5278], r11
+;; 30fe:	 4c899c2470520000     	mov	qword ptr [rsp + 0x5270], r11
+;; 3106:	 4c899c2468520000     	mov	qword ptr [rsp + 0x5268], r11
+;; 310e:	 4c899c2460520000     	mov	qword ptr [rsp + 0x5260], r11
+;; 3116:	 4c899c2458520000     	mov	qword ptr [rsp + 0x5258], r11
+;; 311e:	 4c899c2450520000     	mov	qword ptr [rsp + 0x5250], r11
+;; 3126:	 4c899c2448520000     	mov	qword ptr [rsp + 0x5248], r11
+;; 312e:	 4c899c2440520000     	mov	qword ptr [rsp + 0x5240], r11
+;; 3136:	 4c899c2438520000     	mov	qword ptr [rsp + 0x5238], r11
+;; 313e:	 4c899c2430520000     	mov	qword ptr [rsp + 0x5230], r11
+;; 3146:	 4c899c2428520000     	mov	qword ptr [rsp + 0x5228], r11
+;; 314e:	 4c899c2420520000     	mov	qword ptr [rsp + 0x5220], r11
+;; 3156:	 4c899c2418520000     	mov	qword ptr [rsp + 0x5218], r11
+;; 315e:	 4c899c2410520000     	mov	qword ptr [rsp + 0x5210], r11
+;; 3166:	 4c899c2408520000     	mov	qword ptr [rsp + 0x5208], r11
+;; 316e:	 4c899c2400520000     	mov	qword ptr [rsp + 0x5200], r11
+;; 3176:	 4c899c24f8510000     	mov	qword ptr [rsp + 0x51f8], r11
+;; 317e:	 4c899c24f0510000     	mov	qword ptr [rsp + 0x51f0], r11
+;; 3186:	 4c899c24e8510000     	mov	qword ptr [rsp + 0x51e8], r11
+;; 318e:	 4c899c24e0510000     	mov	qword ptr [rsp + 0x51e0], r11
+;; 3196:	 4c899c24d8510000     	mov	qword ptr [rsp + 0x51d8], r11
+;; 319e:	 4c899c24d0510000     	mov	qword ptr [rsp + 0x51d0], r11
+;; 31a6:	 4c899c24c8510000     	mov	qword ptr [rsp + 0x51c8], r11
+;; 31ae:	 4c899c24c0510000     	mov	qword ptr [rsp + 0x51c0], r11
+;; 31b6:	 4c899c24b8510000     	mov	qword ptr [rsp + 0x51b8], r11
+;; 31be:	 4c899c24b0510000     	mov	qword ptr [rsp + 0x51b0], r11
+;; 31c6:	 4c899c24a8510000     	mov	qword ptr [rsp + 0x51a8], r11
+;; 31ce:	 4c899c24a0510000     	mov	qword ptr [rsp + 0x51a0], r11
+;; 31d6:	 4c899c2498510000     	mov	qword ptr [rsp + 0x5198], r11
+;; 31de:	 4c899c2490510000     	mov	qword ptr [rsp + 0x5190], r11
+;; 31e6:	 4c899c2488510000     	mov	qword ptr [rsp + 0x5188], r11
+;; 31ee:	 4c899c2480510000     	mov	qword ptr [rsp + 0x5180], r11
+;; 31f6:	 4c899c2478510000     	mov	qword ptr [rsp + 0x5178], r11
+;; 31fe:	 4c899c2470510000     	mov	qword ptr [rsp + 0x5170], r11
+;; 3206:	 4c899c2468510000     	mov	qword ptr [rsp + 0x5168], r11
+;; 320e:	 4c899c2460510000     	mov	qword ptr [rsp + 0x5160], r11
+;; 3216:	 4c899c2458510000     	mov	qword ptr [rsp + 0x5158], r11
+;; 321e:	 4c899c2450510000     	mov	qword ptr [rsp + 0x5150], r11
+;; 3226:	 4c899c2448510000     	mov	qword ptr [rsp + 0x5148], r11
+;; 322e:	 4c899c2440510000     	mov	qword ptr [rsp + 0x5140], r11
+;; 3236:	 4c899c2438510000     	mov	qword ptr [rsp + 0x5138], r11
+;; 323e:	 4c899c2430510000     	mov	qword ptr [rsp + 0x5130], r11
+;; 3246:	 4c899c2428510000     	mov	qword ptr [rsp + 0x5128], r11
+;; 324e:	 4c899c2420510000     	mov	qword ptr [rsp + 0x5120], r11
+;; 3256:	 4c899c2418510000     	mov	qword ptr [rsp + 0x5118], r11
+;; 325e:	 4c899c2410510000     	mov	qword ptr [rsp + 0x5110], r11
+;; 3266:	 4c899c2408510000     	mov	qword ptr [rsp + 0x5108], r11
+;; 326e:	 4c899c2400510000     	mov	qword ptr [rsp + 0x5100], r11
+;; 3276:	 4c899c24f8500000     	mov	qword ptr [rsp + 0x50f8], r11
+;; 327e:	 4c899c24f0500000     	mov	qword ptr [rsp + 0x50f0], r11
+;; 3286:	 4c899c24e8500000     	mov	qword ptr [rsp + 0x50e8], r11
+;; 328e:	 4c899c24e0500000     	mov	qword ptr [rsp + 0x50e0], r11
+;; 3296:	 4c899c24d8500000     	mov	qword ptr [rsp + 0x50d8], r11
+;; 329e:	 4c899c24d0500000     	mov	qword ptr [rsp + 0x50d0], r11
+;; 32a6:	 4c899c24c8500000     	mov	qword ptr [rsp + 0x50c8], r11
+;; 32ae:	 4c899c24c0500000     	mov	qword ptr [rsp + 0x50c0], r11
+;; 32b6:	 4c899c24b8500000     	mov	qword ptr [rsp + 0x50b8], r11
+;; 32be:	 4c899c24b0500000     	mov	qword ptr [rsp + 0x50b0], r11
+;; 32c6:	 4c899c24a8500000     	mov	qword ptr [rsp + 0x50a8], r11
+;; 32ce:	 4c899c24a0500000     	mov	qword ptr [rsp + 0x50a0], r11
+;; 32d6:	 4c899c2498500000     	mov	qword ptr [rsp + 0x5098], r11
+;; 32de:	 4c899c2490500000     	mov	qword ptr [rsp + 0x5090], r11
+;; 32e6:	 4c899c2488500000     	mov	qword ptr [rsp + 0x5088], r11
+;; 32ee:	 4c899c2480500000     	mov	qword ptr [rsp + 0x5080], r11
+;; 32f6:	 4c899c2478500000     	mov	qword ptr [rsp + 0x5078], r11
+;; 32fe:	 4c899c2470500000     	mov	qword ptr [rsp + 0x5070], r11
+;; 3306:	 4c899c2468500000     	mov	qword ptr [rsp + 0x5068], r11
+;; 330e:	 4c899c2460500000     	mov	qword ptr [rsp + 0x5060], r11
+;; 3316:	 4c899c2458500000     	mov	qword ptr [rsp + 0x5058], r11
+;; 331e:	 4c899c2450500000     	mov	qword ptr [rsp + 0x5050], r11
+;; 3326:	 4c899c2448500000     	mov	qword ptr [rsp + 0x5048], r11
+;; 332e:	 4c899c2440500000     	mov	qword ptr [rsp + 0x5040], r11
+;; 3336:	 4c899c2438500000     	mov	qword ptr [rsp + 0x5038], r11
+;; 333e:	 4c899c2430500000     	mov	qword ptr [rsp + 0x5030], r11
+;; 3346:	 4c899c2428500000     	mov	qword ptr [rsp + 0x5028], r11
+;; 334e:	 4c899c2420500000     	mov	qword ptr [rsp + 0x5020], r11
+;; 3356:	 4c899c2418500000     	mov	qword ptr [rsp + 0x5018], r11
+;; 335e:	 4c899c2410500000     	mov	qword ptr [rsp + 0x5010], r11
+;; 3366:	 4c899c2408500000     	mov	qword ptr [rsp + 0x5008], r11
+;; 336e:	 4c899c2400500000     	mov	qword ptr [rsp + 0x5000], r11
+;; 3376:	 4c899c24f84f0000     	mov	qword ptr [rsp + 0x4ff8], r11
+;; 337e:	 4c899c24f04f0000     	mov	qword ptr [rsp + 0x4ff0], r11
+;; 3386:	 4c899c24e84f0000     	mov	qword ptr [rsp + 0x4fe8], r11
+;; 338e:	 4c899c24e04f0000     	mov	qword ptr [rsp + 0x4fe0], r11
+;; 3396:	 4c899c24d84f0000     	mov	qword ptr [rsp + 0x4fd8], r11
+;; 339e:	 4c899c24d04f0000     	mov	qword ptr [rsp + 0x4fd0], r11
+;; 33a6:	 4c899c24c84f0000     	mov	qword ptr [rsp + 0x4fc8], r11
+;; 33ae:	 4c899c24c04f0000     	mov	qword ptr [rsp + 0x4fc0], r11
+;; 33b6:	 4c899c24b84f0000     	mov	qword ptr [rsp + 0x4fb8], r11
+;; 33be:	 4c899c24b04f0000     	mov	qword ptr [rsp + 0x4fb0], r11
+;; 33c6:	 4c899c24a84f0000     	mov	qword ptr [rsp + 0x4fa8], r11
+;; 33ce:	 4c899c24a04f0000     	mov	qword ptr [rsp + 0x4fa0], r11
+;; 33d6:	 4c899c24984f0000     	mov	qword ptr [rsp + 0x4f98], r11
+;; 33de:	 4c899c24904f0000     	mov	qword ptr [rsp + 0x4f90], r11
+;; 33e6:	 4c899c24884f0000     	mov	qword ptr [rsp + 0x4f88], r11
+;; 33ee:	 4c899c24804f0000     	mov	qword ptr [rsp + 0x4f80], r11
+;; 33f6:	 4c899c24784f0000     	mov	qword ptr [rsp + 0x4f78], r11
+;; 33fe:	 4c899c24704f0000     	mov	qword ptr [rsp + 0x4f70], r11
+;; 3406:	 4c899c24684f0000     	mov	qword ptr [rsp + 0x4f68], r11
+;; 340e:	 4c899c24604f0000     	mov	qword ptr [rsp + 0x4f60], r11
+;; 3416:	 4c899c24584f0000     	mov	qword ptr [rsp + 0x4f58], r11
+;; 341e:	 4c899c24504f0000     	mov	qword ptr [rsp + 0x4f50], r11
+;; 3426:	 4c899c24484f0000     	mov	qword ptr [rsp + 0x4f48], r11
+;; 342e:	 4c899c24404f0000     	mov	qword ptr [rsp + 0x4f40], r11
+;; 3436:	 4c899c24384f0000     	mov	qword ptr [rsp + 0x4f38], r11
+;; 343e:	 4c899c24304f0000     	mov	qword ptr [rsp + 0x4f30], r11
+;; 3446:	 4c899c24284f0000     	mov	qword ptr [rsp + 0x4f28], r11
+;; 344e:	 4c899c24204f0000     	mov	qword ptr [rsp + 0x4f20], r11
+;; 3456:	 4c899c24184f0000     	mov	qword ptr [rsp + 0x4f18], r11
+;; 345e:	 4c899c24104f0000     	mov	qword ptr [rsp + 0x4f10], r11
+;; 3466:	 4c899c24084f0000     	mov	qword ptr [rsp + 0x4f08], r11
+;; 346e:	 4c899c24004f0000     	mov	qword ptr [rsp + 0x4f00], r11
+;; 3476:	 4c899c24f84e0000     	mov	qword ptr [rsp + 0x4ef8], r11
+;; 347e:	 4c899c24f04e0000     	mov	qword ptr [rsp + 0x4ef0], r11
+;; 3486:	 4c899c24e84e0000     	mov	qword ptr [rsp + 0x4ee8], r11
+;; 348e:	 4c899c24e04e0000     	mov	qword ptr [rsp + 0x4ee0], r11
+;; 3496:	 4c899c24d84e0000     	mov	qword ptr [rsp + 0x4ed8], r11
+;; 349e:	 4c899c24d04e0000     	mov	qword ptr [rsp + 0x4ed0], r11
+;; 34a6:	 4c899c24c84e0000     	mov	qword ptr [rsp + 0x4ec8], r11
+;; 34ae:	 4c899c24c04e0000     	mov	qword ptr [rsp + 0x4ec0], r11
+;; 34b6:	 4c899c24b84e0000     	mov	qword ptr [rsp + 0x4eb8], r11
+;; 34be:	 4c899c24b04e0000     	mov	qword ptr [rsp + 0x4eb0], r11
+;; 34c6:	 4c899c24a84e0000     	mov	qword ptr [rsp + 0x4ea8], r11
+;; 34ce:	 4c899c24a04e0000     	mov	qword ptr [rsp + 0x4ea0], r11
+;; 34d6:	 4c899c24984e0000     	mov	qword ptr [rsp + 0x4e98], r11
+;; 34de:	 4c899c24904e0000     	mov	qword ptr [rsp + 0x4e90], r11
+;; 34e6:	 4c899c24884e0000     	mov	qword ptr [rsp + 0x4e88], r11
+;; 34ee:	 4c899c24804e0000     	mov	qword ptr [rsp + 0x4e80], r11
+;; 34f6:	 4c899c24784e0000     	mov	qword ptr [rsp + 0x4e78], r11
+;; 34fe:	 4c899c24704e0000     	mov	qword ptr [rsp + 0x4e70], r11
+;; 3506:	 4c899c24684e0000     	mov	qword ptr [rsp + 0x4e68], r11
+;; 350e:	 4c899c24604e0000     	mov	qword ptr [rsp + 0x4e60], r11
+;; 3516:	 4c899c24584e0000     	mov	qword ptr [rsp + 0x4e58], r11
+;; 351e:	 4c899c24504e0000     	mov	qword ptr [rsp + 0x4e50], r11
+;; 3526:	 4c899c24484e0000     	mov	qword ptr [rsp + 0x4e48], r11
+;; 352e:	 4c899c24404e0000     	mov	qword ptr [rsp + 0x4e40], r11
+;; 3536:	 4c899c24384e0000     	mov	qword ptr [rsp + 0x4e38], r11
+;; 353e:	 4c899c24304e0000     	mov	qword ptr [rsp + 0x4e30], r11
+;; 3546:	 4c899c24284e0000     	mov	qword ptr [rsp + 0x4e28], r11
+;; 354e:	 4c899c24204e0000     	mov	qword ptr [rsp + 0x4e20], r11
+;; 3556:	 4c899c24184e0000     	mov	qword ptr [rsp + 0x4e18], r11
+;; 355e:	 4c899c24104e0000     	mov	qword ptr [rsp + 0x4e10], r11
+;; 3566:	 4c899c24084e0000     	mov	qword ptr [rsp + 0x4e08], r11
+;; 356e:	 4c899c24004e0000     	mov	qword ptr [rsp + 0x4e00], r11
+;; 3576:	 4c899c24f84d0000     	mov	qword ptr [rsp + 0x4df8], r11
+;; 357e:	 4c899c24f04d0000     	mov	qword ptr [rsp + 0x4df0], r11
+;; 3586:	 4c899c24e84d0000     	mov	qword ptr [rsp + 0x4de8], r11
+;; 358e:	 4c899c24e04d0000     	mov	qword ptr [rsp + 0x4de0], r11
+;; 3596:	 4c899c24d84d0000     	mov	qword ptr [rsp + 0x4dd8], r11
+;; 359e:	 4c899c24d04d0000     	mov	qword ptr [rsp + 0x4dd0], r11
+;; 35a6:	 4c899c24c84d0000     	mov	qword ptr [rsp + 0x4dc8], r11
+;; 35ae:	 4c899c24c04d0000     	mov	qword ptr [rsp + 0x4dc0], r11
+;; 35b6:	 4c899c24b84d0000     	mov	qword ptr [rsp + 0x4db8], r11
+;; 35be:	 4c899c24b04d0000     	mov	qword ptr [rsp + 0x4db0], r11
+;; 35c6:	 4c899c24a84d0000     	mov	qword ptr [rsp + 0x4da8], r11
+;; 35ce:	 4c899c24a04d0000     	mov	qword ptr [rsp + 0x4da0], r11
+;; 35d6:	 4c899c24984d0000     	mov	qword ptr [rsp + 0x4d98], r11
+;; 35de:	 4c899c24904d0000     	mov	qword ptr [rsp + 0x4d90], r11
+;; 35e6:	 4c899c24884d0000     	mov	qword ptr [rsp + 0x4d88], r11
+;; 35ee:	 4c899c24804d0000     	mov	qword ptr [rsp + 0x4d80], r11
+;; 35f6:	 4c899c24784d0000     	mov	qword ptr [rsp + 0x4d78], r11
+;; 35fe:	 4c899c24704d0000     	mov	qword ptr [rsp + 0x4d70], r11
+;; 3606:	 4c899c24684d0000     	mov	qword ptr [rsp + 0x4d68], r11
+;; 360e:	 4c899c24604d0000     	mov	qword ptr [rsp + 0x4d60], r11
+;; 3616:	 4c899c24584d0000     	mov	qword ptr [rsp + 0x4d58], r11
+;; 361e:	 4c899c24504d0000     	mov	qword ptr [rsp + 0x4d50], r11
+;; 3626:	 4c899c24484d0000     	mov	qword ptr [rsp + 0x4d48], r11
+;; 362e:	 4c899c24404d0000     	mov	qword ptr [rsp + 0x4d40], r11
+;; 3636:	 4c899c24384d0000     	mov	qword ptr [rsp + 0x4d38], r11
+;; 363e:	 4c899c24304d0000     	mov	qword ptr [rsp + 0x4d30], r11
+;; 3646:	 4c899c24284d0000     	mov	qword ptr [rsp + 0x4d28], r11
+;; 364e:	 4c899c24204d0000     	mov	qword ptr [rsp + 0x4d20], r11
+;; 3656:	 4c899c24184d0000     	mov	qword ptr [rsp + 0x4d18], r11
+;; 365e:	 4c899c24104d0000     	mov	qword ptr [rsp + 0x4d10], r11
+;; 3666:	 4c899c24084d0000     	mov	qword ptr [rsp + 0x4d08], r11
+;; 366e:	 4c899c24004d0000     	mov	qword ptr [rsp + 0x4d00], r11
+;; 3676:	 4c899c24f84c0000     	mov	qword ptr [rsp + 0x4cf8], r11
+;; 367e:	 4c899c24f04c0000     	mov	qword ptr [rsp + 0x4cf0], r11
+;; 3686:	 4c899c24e84c0000     	mov	qword ptr [rsp + 0x4ce8], r11
+;; 368e:	 4c899c24e04c0000     	mov	qword ptr [rsp + 0x4ce0], r11
+;; 3696:	 4c899c24d84c0000     	mov	qword ptr [rsp + 0x4cd8], r11
+;; 369e:	 4c899c24d04c0000     	mov	qword ptr [rsp + 0x4cd0], r11
+;; 36a6:	 4c899c24c84c0000     	mov	qword ptr [rsp + 0x4cc8], r11
+;; 36ae:	 4c899c24c04c0000     	mov	qword ptr [rsp + 0x4cc0], r11
+;; 36b6:	 4c899c24b84c0000     	mov	qword ptr [rsp + 0x4cb8], r11
+;; 36be:	 4c899c24b04c0000     	mov	qword ptr [rsp + 0x4cb0], r11
+;; 36c6:	 4c899c24a84c0000     	mov	qword ptr [rsp + 0x4ca8], r11
+;; 36ce:	 4c899c24a04c0000     	mov	qword ptr [rsp + 0x4ca0], r11
+;; 36d6:	 4c899c24984c0000     	mov	qword ptr [rsp + 0x4c98], r11
+;; 36de:	 4c899c24904c0000     	mov	qword ptr [rsp + 0x4c90], r11
+;; 36e6:	 4c899c24884c0000     	mov	qword ptr [rsp + 0x4c88], r11
+;; 36ee:	 4c899c24804c0000     	mov	qword ptr [rsp + 0x4c80], r11
+;; 36f6:	 4c899c24784c0000     	mov	qword ptr [rsp + 0x4c78], r11
+;; 36fe:	 4c899c24704c0000     	mov	qword ptr [rsp + 0x4c70], r11
+;; 3706:	 4c899c24684c0000     	mov	qword ptr [rsp + 0x4c68], r11
+;; 370e:	 4c899c24604c0000     	mov	qword ptr [rsp + 0x4c60], r11
+;; 3716:	 4c899c24584c0000     	mov	qword ptr [rsp + 0x4c58], r11
+;; 371e:	 4c899c24504c0000     	mov	qword ptr [rsp + 0x4c50], r11
+;; 3726:	 4c899c24484c0000     	mov	qword ptr [rsp + 0x4c48], r11
+;; 372e:	 4c899c24404c0000     	mov	qword ptr [rsp + 0x4c40], r11
+;; 3736:	 4c899c24384c0000     	mov	qword ptr [rsp + 0x4c38], r11
+;; 373e:	 4c899c24304c0000     	mov	qword ptr [rsp + 0x4c30], r11
+;; 3746:	 4c899c24284c0000     	mov	qword ptr [rsp + 0x4c28], r11
+;; 374e:	 4c899c24204c0000     	mov	qword ptr [rsp + 0x4c20], r11
+;; 3756:	 4c899c24184c0000     	mov	qword ptr [rsp + 0x4c18], r11
+;; 375e:	 4c899c24104c0000     	mov	qword ptr [rsp + 0x4c10], r11
+;; 3766:	 4c899c24084c0000     	mov	qword ptr [rsp + 0x4c08], r11
+;; 376e:	 4c899c24004c0000     	mov	qword ptr [rsp + 0x4c00], r11
+;; 3776:	 4c899c24f84b0000     	mov	qword ptr [rsp + 0x4bf8], r11
+;; 377e:	 4c899c24f04b0000     	mov	qword ptr [rsp + 0x4bf0], r11
+;; 3786:	 4c899c24e84b0000     	mov	qword ptr [rsp + 0x4be8], r11
+;; 378e:	 4c899c24e04b0000     	mov	qword ptr [rsp + 0x4be0], r11
+;; 3796:	 4c899c24d84b0000     	mov	qword ptr [rsp + 0x4bd8], r11
+;; 379e:	 4c899c24d04b0000     	mov	qword ptr [rsp + 0x4bd0], r11
+;; 37a6:	 4c899c24c84b0000     	mov	qword ptr [rsp + 0x4bc8], r11
+;; 37ae:	 4c899c24c04b0000     	mov	qword ptr [rsp + 0x4bc0], r11
+;; 37b6:	 4c899c24b84b0000     	mov	qword ptr [rsp + 0x4bb8], r11
+;; 37be:	 4c899c24b04b0000     	mov	qword ptr [rsp + 0x4bb0], r11
+;; 37c6:	 4c899c24a84b0000     	mov	qword ptr [rsp + 0x4ba8], r11
+;; 37ce:	 4c899c24a04b0000     	mov	qword ptr [rsp + 0x4ba0], r11
+;; 37d6:	 4c899c24984b0000     	mov	qword ptr [rsp + 0x4b98], r11
+;; 37de:	 4c899c24904b0000     	mov	qword ptr [rsp + 0x4b90], r11
+;; 37e6:	 4c899c24884b0000     	mov	qword ptr [rsp + 0x4b88], r11
+;; 37ee:	 4c899c24804b0000     	mov	qword ptr [rsp + 0x4b80], r11
+;; 37f6:	 4c899c24784b0000     	mov	qword ptr [rsp + 0x4b78], r11
+;; 37fe:	 4c899c24704b0000     	mov	qword ptr [rsp + 0x4b70], r11
+;; 3806:	 4c899c24684b0000     	mov	qword ptr [rsp + 0x4b68], r11
+;; 380e:	 4c899c24604b0000     	mov	qword ptr [rsp + 0x4b60], r11
+;; 3816:	 4c899c24584b0000     	mov	qword ptr [rsp + 0x4b58], r11
+;; 381e:	 4c899c24504b0000     	mov	qword ptr [rsp + 0x4b50], r11
+;; 3826:	 4c899c24484b0000     	mov	qword ptr [rsp + 0x4b48], r11
+;; 382e:	 4c899c24404b0000     	mov	qword ptr [rsp + 0x4b40], r11
+;; 3836:	 4c899c24384b0000     	mov	qword ptr [rsp + 0x4b38], r11
+;; 383e:	 4c899c24304b0000     	mov	qword ptr [rsp + 0x4b30], r11
+;; 3846:	 4c899c24284b0000     	mov	qword ptr [rsp + 0x4b28], r11
+;; 384e:	 4c899c24204b0000     	mov	qword ptr [rsp + 0x4b20], r11
+;; 3856:	 4c899c24184b0000     	mov	qword ptr [rsp + 0x4b18], r11
+;; 385e:	 4c899c24104b0000     	mov	qword ptr [rsp + 0x4b10], r11
+;; 3866:	 4c899c24084b0000     	mov	qword ptr [rsp + 0x4b08], r11
+;; 386e:	 4c899c24004b0000     	mov	qword ptr [rsp + 0x4b00], r11
+;; 3876:	 4c899c24f84a0000     	mov	qword ptr [rsp + 0x4af8], r11
+;; 387e:	 4c899c24f04a0000     	mov	qword ptr [rsp + 0x4af0], r11
+;; 3886:	 4c899c24e84a0000     	mov	qword ptr [rsp + 0x4ae8], r11
+;; 388e:	 4c899c24e04a0000     	mov	qword ptr [rsp + 0x4ae0], r11
+;; 3896:	 4c899c24d84a0000     	mov	qword ptr [rsp + 0x4ad8], r11
+;; 389e:	 4c899c24d04a0000     	mov	qword ptr [rsp + 0x4ad0], r11
+;; 38a6:	 4c899c24c84a0000     	mov	qword ptr [rsp + 0x4ac8], r11
+;; 38ae:	 4c899c24c04a0000     	mov	qword ptr [rsp + 0x4ac0], r11
+;; 38b6:	 4c899c24b84a0000     	mov	qword ptr [rsp + 0x4ab8], r11
+;; 38be:	 4c899c24b04a0000     	mov	qword ptr [rsp + 0x4ab0], r11
+;; 38c6:	 4c899c24a84a0000     	mov	qword ptr [rsp + 0x4aa8], r11
+;; 38ce:	 4c899c24a04a0000     	mov	qword ptr [rsp + 0x4aa0], r11
+;; 38d6:	 4c899c24984a0000     	mov	qword ptr [rsp + 0x4a98], r11
+;; 38de:	 4c899c24904a0000     	mov	qword ptr [rsp + 0x4a90], r11
+;; 38e6:	 4c899c24884a0000     	mov	qword ptr [rsp + 0x4a88], r11
+;; 38ee:	 4c899c24804a0000     	mov	qword ptr [rsp + 0x4a80], r11
+;; 38f6:	 4c899c24784a0000     	mov	qword ptr [rsp + 0x4a78], r11
+;; 38fe:	 4c899c24704a0000     	mov	qword ptr [rsp + 0x4a70], r11
+;; 3906:	 4c899c24684a0000     	mov	qword ptr [rsp + 0x4a68], r11
+;; 390e:	 4c899c24604a0000     	mov	qword ptr [rsp + 0x4a60], r11
+;; 3916:	 4c899c24584a0000     	mov	qword ptr [rsp + 0x4a58], r11
+;; 391e:	 4c899c24504a0000     	mov	qword ptr [rsp + 0x4a50], r11
+;; 3926:	 4c899c24484a0000     	mov	qword ptr [rsp + 0x4a48], r11
+;; 392e:	 4c899c24404a0000     	mov	qword ptr [rsp + 0x4a40], r11
+;; 3936:	 4c899c24384a0000     	mov	qword ptr [rsp + 0x4a38], r11
+;; 393e:	 4c899c24304a0000     	mov	qword ptr [rsp + 0x4a30], r11
+;; 3946:	 4c899c24284a0000     	mov	qword ptr [rsp + 0x4a28], r11
+;; 394e:	 4c899c24204a0000     	mov	qword ptr [rsp + 0x4a20], r11
+;; 3956:	 4c899c24184a0000     	mov	qword ptr [rsp + 0x4a18], r11
+;; 395e:	 4c899c24104a0000     	mov	qword ptr [rsp + 0x4a10], r11
+;; 3966:	 4c899c24084a0000     	mov	qword ptr [rsp + 0x4a08], r11
+;; 396e:	 4c899c24004a0000     	mov	qword ptr [rsp + 0x4a00], r11
+;; 3976:	 4c899c24f8490000     	mov	qword ptr [rsp + 0x49f8], r11
+;; 397e:	 4c899c24f0490000     	mov	qword ptr [rsp + 0x49f0], r11
+;; 3986:	 4c899c24e8490000     	mov	qword ptr [rsp + 0x49e8], r11
+;; 398e:	 4c899c24e0490000     	mov	qword ptr [rsp + 0x49e0], r11
+;; 3996:	 4c899c24d8490000     	mov	qword ptr [rsp + 0x49d8], r11
+;; 399e:	 4c899c24d0490000     	mov	qword ptr [rsp + 0x49d0], r11
+;; 39a6:	 4c899c24c8490000     	mov	qword ptr [rsp + 0x49c8], r11
+;; 39ae:	 4c899c24c0490000     	mov	qword ptr [rsp + 0x49c0], r11
+;; 39b6:	 4c899c24b8490000     	mov	qword ptr [rsp + 0x49b8], r11
+;; 39be:	 4c899c24b0490000     	mov	qword ptr [rsp + 0x49b0], r11
+;; 39c6:	 4c899c24a8490000     	mov	qword ptr [rsp + 0x49a8], r11
+;; 39ce:	 4c899c24a0490000     	mov	qword ptr [rsp + 0x49a0], r11
+;; 39d6:	 4c899c2498490000     	mov	qword ptr [rsp + 0x4998], r11
+;; 39de:	 4c899c2490490000     	mov	qword ptr [rsp + 0x4990], r11
+;; 39e6:	 4c899c2488490000     	mov	qword ptr [rsp + 0x4988], r11
+;; 39ee:	 4c899c2480490000     	mov	qword ptr [rsp + 0x4980], r11
+;; 39f6:	 4c899c2478490000     	mov	qword ptr [rsp + 0x4978], r11
+;; 39fe:	 4c899c2470490000     	mov	qword ptr [rsp + 0x4970], r11
+;; 3a06:	 4c899c2468490000     	mov	qword ptr [rsp + 0x4968], r11
+;; 3a0e:	 4c899c2460490000     	mov	qword ptr [rsp + 0x4960], r11
+;; 3a16:	 4c899c2458490000     	mov	qword ptr [rsp + 0x4958], r11
+;; 3a1e:	 4c899c2450490000     	mov	qword ptr [rsp + 0x4950], r11
+;; 3a26:	 4c899c2448490000     	mov	qword ptr [rsp + 0x4948], r11
+;; 3a2e:	 4c899c2440490000     	mov	qword ptr [rsp + 0x4940], r11
+;; 3a36:	 4c899c2438490000     	mov	qword ptr [rsp + 0x4938], r11
+;; 3a3e:	 4c899c2430490000     	mov	qword ptr [rsp + 0x4930], r11
+;; 3a46:	 4c899c2428490000     	mov	qword ptr [rsp + 0x4928], r11
+;; 3a4e:	 4c899c2420490000     	mov	qword ptr [rsp + 0x4920], r11
+;; 3a56:	 4c899c2418490000     	mov	qword ptr [rsp + 0x4918], r11
+;; 3a5e:	 4c899c2410490000     	mov	qword ptr [rsp + 0x4910], r11
+;; 3a66:	 4c899c2408490000     	mov	qword ptr [rsp + 0x4908], r11
+;; 3a6e:	 4c899c2400490000     	mov	qword ptr [rsp + 0x4900], r11
+;; 3a76:	 4c899c24f8480000     	mov	qword ptr [rsp + 0x48f8], r11
+;; 3a7e:	 4c899c24f0480000     	mov	qword ptr [rsp + 0x48f0], r11
+;; 3a86:	 4c899c24e8480000     	mov	qword ptr [rsp + 0x48e8], r11
+;; 3a8e:	 4c899c24e0480000     	mov	qword ptr [rsp + 0x48e0], r11
+;; 3a96:	 4c899c24d8480000     	mov	qword ptr [rsp + 0x48d8], r11
+;; 3a9e:	 4c899c24d0480000     	mov	qword ptr [rsp + 0x48d0], r11
+;; 3aa6:	 4c899c24c8480000     	mov	qword ptr [rsp + 0x48c8], r11
+;; 3aae:	 4c899c24c0480000     	mov	qword ptr [rsp + 0x48c0], r11
+;; 3ab6:	 4c899c24b8480000     	mov	qword ptr [rsp + 0x48b8], r11
+;; 3abe:	 4c899c24b0480000     	mov	qword ptr [rsp + 0x48b0], r11
+;; 3ac6:	 4c899c24a8480000     	mov	qword ptr [rsp + 0x48a8], r11
+;; 3ace:	 4c899c24a0480000     	mov	qword ptr [rsp + 0x48a0], r11
+;; 3ad6:	 4c899c2498480000     	mov	qword ptr [rsp + 0x4898], r11
+;; 3ade:	 4c899c2490480000     	mov	qword ptr [rsp + 0x4890], r11
+;; 3ae6:	 4c899c2488480000     	mov	qword ptr [rsp + 0x4888], r11
+;; 3aee:	 4c899c2480480000     	mov	qword ptr [rsp + 0x4880], r11
+;; 3af6:	 4c899c2478480000     	mov	qword ptr [rsp + 0x4878], r11
+;; 3afe:	 4c899c2470480000     	mov	qword ptr [rsp + 0x4870], r11
+;; 3b06:	 4c899c2468480000     	mov	qword ptr [rsp + 0x4868], r11
+;; 3b0e:	 4c899c2460480000     	mov	qword ptr [rsp + 0x4860], r11
+;; 3b16:	 4c899c2458480000     	mov	qword ptr [rsp + 0x4858], r11
+;; 3b1e:	 4c899c2450480000     	mov	qword ptr [rsp + 0x4850], r11
+;; 3b26:	 4c899c2448480000     	mov	qword ptr [rsp + 0x4848], r11
+;; 3b2e:	 4c899c2440480000     	mov	qword ptr [rsp + 0x4840], r11
+;; 3b36:	 4c899c2438480000     	mov	qword ptr [rsp + 0x4838], r11
+;; 3b3e:	 4c899c2430480000     	mov	qword ptr [rsp + 0x4830], r11
+;; 3b46:	 4c899c2428480000     	mov	qword ptr [rsp + 0x4828], r11
+;; 3b4e:	 4c899c2420480000     	mov	qword ptr [rsp + 0x4820], r11
+;; 3b56:	 4c899c2418480000     	mov	qword ptr [rsp + 0x4818], r11
+;; 3b5e:	 4c899c2410480000     	mov	qword ptr [rsp + 0x4810], r11
+;; 3b66:	 4c899c2408480000     	mov	qword ptr [rsp + 0x4808], r11
+;; 3b6e:	 4c899c2400480000     	mov	qword ptr [rsp + 0x4800], r11
+;; 3b76:	 4c899c24f8470000     	mov	qword ptr [rsp + 0x47f8], r11
+;; 3b7e:	 4c899c24f0470000     	mov	qword ptr [rsp + 0x47f0], r11
+;; 3b86:	 4c899c24e8470000     	mov	qword ptr [rsp + 0x47e8], r11
+;; 3b8e:	 4c899c24e0470000     	mov	qword ptr [rsp + 0x47e0], r11
+;; 3b96:	 4c899c24d8470000     	mov	qword ptr [rsp + 0x47d8], r11
+;; 3b9e:	 4c899c24d0470000     	mov	qword ptr [rsp + 0x47d0], r11
+;; 3ba6:	 4c899c24c8470000     	mov	qword ptr [rsp + 0x47c8], r11
+;; 3bae:	 4c899c24c0470000     	mov	qword ptr [rsp + 0x47c0], r11
+;; 3bb6:	 4c899c24b8470000     	mov	qword ptr [rsp + 0x47b8], r11
+;; 3bbe:	 4c899c24b0470000     	mov	qword ptr [rsp + 0x47b0], r11
+;; 3bc6:	 4c899c24a8470000     	mov	qword ptr [rsp + 0x47a8], r11
+;; 3bce:	 4c899c24a0470000     	mov	qword ptr [rsp + 0x47a0], r11
+;; 3bd6:	 4c899c2498470000     	mov	qword ptr [rsp + 0x4798], r11
+;; 3bde:	 4c899c2490470000     	mov	qword ptr [rsp + 0x4790], r11
+;; 3be6:	 4c899c2488470000     	mov	qword ptr [rsp + 0x4788], r11
+;; 3bee:	 4c899c2480470000     	mov	qword ptr [rsp + 0x4780], r11
+;; 3bf6:	 4c899c2478470000     	mov	qword ptr [rsp + 0x4778], r11
+;; 3bfe:	 4c899c2470470000     	mov	qword ptr [rsp + 0x4770], r11
+;; 3c06:	 4c899c2468470000     	mov	qword ptr [rsp + 0x4768], r11
+;; 3c0e:	 4c899c2460470000     	mov	qword ptr [rsp + 0x4760], r11
+;; 3c16:	 4c899c2458470000     	mov	qword ptr [rsp + 0x4758], r11
+;; 3c1e:	 4c899c2450470000     	mov	qword ptr [rsp + 0x4750], r11
+;; 3c26:	 4c899c2448470000     	mov	qword ptr [rsp + 0x4748], r11
+;; 3c2e:	 4c899c2440470000     	mov	qword ptr [rsp + 0x4740], r11
+;; 3c36:	 4c899c2438470000     	mov	qword ptr [rsp + 0x4738], r11
+;; 3c3e:	 4c899c2430470000     	mov	qword ptr [rsp + 0x4730], r11
+;; 3c46:	 4c899c2428470000     	mov	qword ptr [rsp + 0x4728], r11
+;; 3c4e:	 4c899c2420470000     	mov	qword ptr [rsp + 0x4720], r11
+;; 3c56:	 4c899c2418470000     	mov	qword ptr [rsp + 0x4718], r11
+;; 3c5e:	 4c899c2410470000     	mov	qword ptr [rsp + 0x4710], r11
+;; 3c66:	 4c899c2408470000     	mov	qword ptr [rsp + 0x4708], r11
+;; 3c6e:	 4c899c2400470000     	mov	qword ptr [rsp + 0x4700], r11
+;; 3c76:	 4c899c24f8460000     	mov	qword ptr [rsp + 0x46f8], r11
+;; 3c7e:	 4c899c24f0460000     	mov	qword ptr [rsp + 0x46f0], r11
+;; 3c86:	 4c899c24e8460000     	mov	qword ptr [rsp + 0x46e8], r11
+;; 3c8e:	 4c899c24e0460000     	mov	qword ptr [rsp + 0x46e0], r11
+;; 3c96:	 4c899c24d8460000     	mov	qword ptr [rsp + 0x46d8], r11
+;; 3c9e:	 4c899c24d0460000     	mov	qword ptr [rsp + 0x46d0], r11
+;; 3ca6:	 4c899c24c8460000     	mov	qword ptr [rsp + 0x46c8], r11
+;; 3cae:	 4c899c24c0460000     	mov	qword ptr [rsp + 0x46c0], r11
+;; 3cb6:	 4c899c24b8460000     	mov	qword ptr [rsp + 0x46b8], r11
+;; 3cbe:	 4c899c24b0460000     	mov	qword ptr [rsp + 0x46b0], r11
+;; 3cc6:	 4c899c24a8460000     	mov	qword ptr [rsp + 0x46a8], r11
+;; 3cce:	 4c899c24a0460000     	mov	qword ptr [rsp + 0x46a0], r11
+;; 3cd6:	 4c899c2498460000     	mov	qword ptr [rsp + 0x4698], r11
+;; 3cde:	 4c899c2490460000     	mov	qword ptr [rsp + 0x4690], r11
+;; 3ce6:	 4c899c2488460000     	mov	qword ptr [rsp + 0x4688], r11
+;; 3cee:	 4c899c2480460000     	mov	qword ptr [rsp + 0x4680], r11
+;; 3cf6:	 4c899c2478460000     	mov	qword ptr [rsp + 0x4678], r11
+;; 3cfe:	 4c899c2470460000     	mov	qword ptr [rsp + 0x4670], r11
+;; 3d06:	 4c899c2468460000     	mov	qword ptr [rsp + 0x4668], r11
+;; 3d0e:	 4c899c2460460000     	mov	qword ptr [rsp + 0x4660], r11
+;; 3d16:	 4c899c2458460000     	mov	qword ptr [rsp + 0x4658], r11
+;; 3d1e:	 4c899c2450460000     	mov	qword ptr [rsp + 0x4650], r11
+;; 3d26:	 4c899c2448460000     	mov	qword ptr [rsp + 0x4648], r11
+;; 3d2e:	 4c899c2440460000     	mov	qword ptr [rsp + 0x4640], r11
+;; 3d36:	 4c899c2438460000     	mov	qword ptr [rsp + 0x4638], r11
+;; 3d3e:	 4c899c2430460000     	mov	qword ptr [rsp + 0x4630], r11
+;; 3d46:	 4c899c2428460000     	mov	qword ptr [rsp + 0x4628], r11
+;; 3d4e:	 4c899c2420460000     	mov	qword ptr [rsp + 0x4620], r11
+;; 3d56:	 4c899c2418460000     	mov	qword ptr [rsp + 0x4618], r11
+;; 3d5e:	 4c899c2410460000     	mov	qword ptr [rsp + 0x4610], r11
+;; 3d66:	 4c899c2408460000     	mov	qword ptr [rsp + 0x4608], r11
+;; 3d6e:	 4c899c2400460000     	mov	qword ptr [rsp + 0x4600], r11
+;; 3d76:	 4c899c24f8450000     	mov	qword ptr [rsp + 0x45f8], r11
+;; 3d7e:	 4c899c24f0450000     	mov	qword ptr [rsp + 0x45f0], r11
+;; 3d86:	 4c899c24e8450000     	mov	qword ptr [rsp + 0x45e8], r11
+;; 3d8e:	 4c899c24e0450000     	mov	qword ptr [rsp + 0x45e0], r11
+;; 3d96:	 4c899c24d8450000     	mov	qword ptr [rsp + 0x45d8], r11
+;; 3d9e:	 4c899c24d0450000     	mov	qword ptr [rsp + 0x45d0], r11
+;; 3da6:	 4c899c24c8450000     	mov	qword ptr [rsp + 0x45c8], r11
+;; 3dae:	 4c899c24c0450000     	mov	qword ptr [rsp + 0x45c0], r11
+;; 3db6:	 4c899c24b8450000     	mov	qword ptr [rsp + 0x45b8], r11
+;; 3dbe:	 4c899c24b0450000     	mov	qword ptr [rsp + 0x45b0], r11
+;; 3dc6:	 4c899c24a8450000     	mov	qword ptr [rsp + 0x45a8], r11
+;; 3dce:	 4c899c24a0450000     	mov	qword ptr [rsp + 0x45a0], r11
+;; 3dd6:	 4c899c2498450000     	mov	qword ptr [rsp + 0x4598], r11
+;; 3dde:	 4c899c2490450000     	mov	qword ptr [rsp + 0x4590], r11
+;; 3de6:	 4c899c2488450000     	mov	qword ptr [rsp + 0x4588], r11
+;; 3dee:	 4c899c2480450000     	mov	qword ptr [rsp + 0x4580], r11
+;; 3df6:	 4c899c2478450000     	mov	qword ptr [rsp + 0x4578], r11
+;; 3dfe:	 4c899c2470450000     	mov	qword ptr [rsp + 0x4570], r11
+;; 3e06:	 4c899c2468450000     	mov	qword ptr [rsp + 0x4568], r11
+;; 3e0e:	 4c899c2460450000     	mov	qword ptr [rsp + 0x4560], r11
+;; 3e16:	 4c899c2458450000     	mov	qword ptr [rsp + 0x4558], r11
+;; 3e1e:	 4c899c2450450000     	mov	qword ptr [rsp + 0x4550], r11
+;; 3e26:	 4c899c2448450000     	mov	qword ptr [rsp + 0x4548], r11
+;; 3e2e:	 4c899c2440450000     	mov	qword ptr [rsp + 0x4540], r11
+;; 3e36:	 4c899c2438450000     	mov	qword ptr [rsp + 0x4538], r11
+;; 3e3e:	 4c899c2430450000     	mov	qword ptr [rsp + 0x4530], r11
+;; 3e46:	 4c899c2428450000     	mov	qword ptr [rsp + 0x4528], r11
+;; 3e4e:	 4c899c2420450000     	mov	qword ptr [rsp + 0x4520], r11
+;; 3e56:	 4c899c2418450000     	mov	qword ptr [rsp + 0x4518], r11
+;; 3e5e:	 4c899c2410450000     	mov	qword ptr [rsp + 0x4510], r11
+;; 3e66:	 4c899c2408450000     	mov	qword ptr [rsp + 0x4508], r11
+;; 3e6e:	 4c899c2400450000     	mov	qword ptr [rsp + 0x4500], r11
+;; 3e76:	 4c899c24f8440000     	mov	qword ptr [rsp + 0x44f8], r11
+;; 3e7e:	 4c899c24f0440000     	mov	qword ptr [rsp + 0x44f0], r11
+;; 3e86:	 4c899c24e8440000     	mov	qword ptr [rsp + 0x44e8], r11
+;; 3e8e:	 4c899c24e0440000     	mov	qword ptr [rsp + 0x44e0], r11
+;; 3e96:	 4c899c24d8440000     	mov	qword ptr [rsp + 0x44d8], r11
+;; 3e9e:	 4c899c24d0440000     	mov	qword ptr [rsp + 0x44d0], r11
+;; 3ea6:	 4c899c24c8440000     	mov	qword ptr [rsp + 0x44c8], r11
+;; 3eae:	 4c899c24c0440000     	mov	qword ptr [rsp + 0x44c0], r11
+;; 3eb6:	 4c899c24b8440000     	mov	qword ptr [rsp + 0x44b8], r11
+;; 3ebe:	 4c899c24b0440000     	mov	qword ptr [rsp + 0x44b0], r11
+;; 3ec6:	 4c899c24a8440000     	mov	qword ptr [rsp + 0x44a8], r11
+;; 3ece:	 4c899c24a0440000     	mov	qword ptr [rsp + 0x44a0], r11
+;; 3ed6:	 4c899c2498440000     	mov	qword ptr [rsp + 0x4498], r11
+;; 3ede:	 4c899c2490440000     	mov	qword ptr [rsp + 0x4490], r11
+;; 3ee6:	 4c899c2488440000     	mov	qword ptr [rsp + 0x4488], r11
+;; 3eee:	 4c899c2480440000     	mov	qword ptr [rsp + 0x4480], r11
+;; 3ef6:	 4c899c2478440000     	mov	qword ptr [rsp + 0x4478], r11
+;; 3efe:	 4c899c2470440000     	mov	qword ptr [rsp + 0x4470], r11
+;; 3f06:	 4c899c2468440000     	mov	qword ptr [rsp + 0x4468], r11
+;; 3f0e:	 4c899c2460440000     	mov	qword ptr [rsp + 0x4460], r11
+;; 3f16:	 4c899c2458440000     	mov	qword ptr [rsp + 0x4458], r11
+;; 3f1e:	 4c899c2450440000     	mov	qword ptr [rsp + 0x4450], r11
+;; 3f26:	 4c899c2448440000     	mov	qword ptr [rsp + 0x4448], r11
+;; 3f2e:	 4c899c2440440000     	mov	qword ptr [rsp + 0x4440], r11
+;; 3f36:	 4c899c2438440000     	mov	qword ptr [rsp + 0x4438], r11
+;; 3f3e:	 4c899c2430440000     	mov	qword ptr [rsp + 0x4430], r11
+;; 3f46:	 4c899c2428440000     	mov	qword ptr [rsp + 0x4428], r11
+;; 3f4e:	 4c899c2420440000     	mov	qword ptr [rsp + 0x4420], r11
+;; 3f56:	 4c899c2418440000     	mov	qword ptr [rsp + 0x4418], r11
+;; 3f5e:	 4c899c2410440000     	mov	qword ptr [rsp + 0x4410], r11
+;; 3f66:	 4c899c2408440000     	mov	qword ptr [rsp + 0x4408], r11
+;; 3f6e:	 4c899c2400440000     	mov	qword ptr [rsp + 0x4400], r11
+;; 3f76:	 4c899c24f8430000     	mov	qword ptr [rsp + 0x43f8], r11
+;; 3f7e:	 4c899c24f0430000     	mov	qword ptr [rsp + 0x43f0], r11
+;; 3f86:	 4c899c24e8430000     	mov	qword ptr [rsp + 0x43e8], r11
+;; 3f8e:	 4c899c24e0430000     	mov	qword ptr [rsp + 0x43e0], r11
+;; 3f96:	 4c899c24d8430000     	mov	qword ptr [rsp + 0x43d8], r11
+;; 3f9e:	 4c899c24d0430000     	mov	qword ptr [rsp + 0x43d0], r11
+;; 3fa6:	 4c899c24c8430000     	mov	qword ptr [rsp + 0x43c8], r11
+;; 3fae:	 4c899c24c0430000     	mov	qword ptr [rsp + 0x43c0], r11
+;; 3fb6:	 4c899c24b8430000     	mov	qword ptr [rsp + 0x43b8], r11
+;; 3fbe:	 4c899c24b0430000     	mov	qword ptr [rsp + 0x43b0], r11
+;; 3fc6:	 4c899c24a8430000     	mov	qword ptr [rsp + 0x43a8], r11
+;; 3fce:	 4c899c24a0430000     	mov	qword ptr [rsp + 0x43a0], r11
+;; 3fd6:	 4c899c2498430000     	mov	qword ptr [rsp + 0x4398], r11
+;; 3fde:	 4c899c2490430000     	mov	qword ptr [rsp + 0x4390], r11
+;; 3fe6:	 4c899c2488430000     	mov	qword ptr [rsp + 0x4388], r11
+;; 3fee:	 4c899c2480430000     	mov	qword ptr [rsp + 0x4380], r11
+;; 3ff6:	 4c899c2478430000     	mov	qword ptr [rsp + 0x4378], r11
+;; 3ffe:	 4c899c2470430000     	mov	qword ptr [rsp + 0x4370], r11
+;; 4006:	 4c899c2468430000     	mov	qword ptr [rsp + 0x4368], r11
+;; 400e:	 4c899c2460430000     	mov	qword ptr [rsp + 0x4360], r11
+;; 4016:	 4c899c2458430000     	mov	qword ptr [rsp + 0x4358], r11
+;; 401e:	 4c899c2450430000     	mov	qword ptr [rsp + 0x4350], r11
+;; 4026:	 4c899c2448430000     	mov	qword ptr [rsp + 0x4348], r11
+;; 402e:	 4c899c2440430000     	mov	qword ptr [rsp + 0x4340], r11
+;; 4036:	 4c899c2438430000     	mov	qword ptr [rsp + 0x4338], r11
+;; 403e:	 4c899c2430430000     	mov	qword ptr [rsp + 0x4330], r11
+;; 4046:	 4c899c2428430000     	mov	qword ptr [rsp + 0x4328], r11
+;; 404e:	 4c899c2420430000     	mov	qword ptr [rsp + 0x4320], r11
+;; 4056:	 4c899c2418430000     	mov	qword ptr [rsp + 0x4318], r11
+;; 405e:	 4c899c2410430000     	mov	qword ptr [rsp + 0x4310], r11
+;; 4066:	 4c899c2408430000     	mov	qword ptr [rsp + 0x4308], r11
+;; 406e:	 4c899c2400430000     	mov	qword ptr [rsp + 0x4300], r11
+;; 4076:	 4c899c24f8420000     	mov	qword ptr [rsp + 0x42f8], r11
+;; 407e:	 4c899c24f0420000     	mov	qword ptr [rsp + 0x42f0], r11
+;; 4086:	 4c899c24e8420000     	mov	qword ptr [rsp + 0x42e8], r11
+;; 408e:	 4c899c24e0420000     	mov	qword ptr [rsp + 0x42e0], r11
+;; 4096:	 4c899c24d8420000     	mov	qword ptr [rsp + 0x42d8], r11
+;; 409e:	 4c899c24d0420000     	mov	qword ptr [rsp + 0x42d0], r11
+;; 40a6:	 4c899c24c8420000     	mov	qword ptr [rsp + 0x42c8], r11
+;; 40ae:	 4c899c24c0420000     	mov	qword ptr [rsp + 0x42c0], r11
+;; 40b6:	 4c899c24b8420000     	mov	qword ptr [rsp + 0x42b8], r11
+;; 40be:	 4c899c24b0420000     	mov	qword ptr [rsp + 0x42b0], r11
+;; 40c6:	 4c899c24a8420000     	mov	qword ptr [rsp + 0x42a8], r11
+;; 40ce:	 4c899c24a0420000     	mov	qword ptr [rsp + 0x42a0], r11
+;; 40d6:	 4c899c2498420000     	mov	qword ptr [rsp + 0x4298], r11
+;; 40de:	 4c899c2490420000     	mov	qword ptr [rsp + 0x4290], r11
+;; 40e6:	 4c899c2488420000     	mov	qword ptr [rsp + 0x4288], r11
+;; 40ee:	 4c899c2480420000     	mov	qword ptr [rsp + 0x4280], r11
+;; 40f6:	 4c899c2478420000     	mov	qword ptr [rsp + 0x4278], r11
+;; 40fe:	 4c899c2470420000     	mov	qword ptr [rsp + 0x4270], r11
+;; 4106:	 4c899c2468420000     	mov	qword ptr [rsp + 0x4268], r11
+;; 410e:	 4c899c2460420000     	mov	qword ptr [rsp + 0x4260], r11
+;; 4116:	 4c899c2458420000     	mov	qword ptr [rsp + 0x4258], r11
+;; 411e:	 4c899c2450420000     	mov	qword ptr [rsp + 0x4250], r11
+;; 4126:	 4c899c2448420000     	mov	qword ptr [rsp + 0x4248], r11
+;; 412e:	 4c899c2440420000     	mov	qword ptr [rsp + 0x4240], r11
+;; 4136:	 4c899c2438420000     	mov	qword ptr [rsp + 0x4238], r11
+;; 413e:	 4c899c2430420000     	mov	qword ptr [rsp + 0x4230], r11
+;; 4146:	 4c899c2428420000     	mov	qword ptr [rsp + 0x4228], r11
+;; 414e:	 4c899c2420420000     	mov	qword ptr [rsp + 0x4220], r11
+;; 4156:	 4c899c2418420000     	mov	qword ptr [rsp + 0x4218], r11
+;; 415e:	 4c899c2410420000     	mov	qword ptr [rsp + 0x4210], r11
+;; 4166:	 4c899c2408420000     	mov	qword ptr [rsp + 0x4208], r11
+;; 416e:	 4c899c2400420000     	mov	qword ptr [rsp + 0x4200], r11
+;; 4176:	 4c899c24f8410000     	mov	qword ptr [rsp + 0x41f8], r11
+;; 417e:	 4c899c24f0410000     	mov	qword ptr [rsp + 0x41f0], r11
+;; 4186:	 4c899c24e8410000     	mov	qword ptr [rsp + 0x41e8], r11
+;; 418e:	 4c899c24e0410000     	mov	qword ptr [rsp + 0x41e0], r11
+;; 4196:	 4c899c24d8410000     	mov	qword ptr [rsp + 0x41d8], r11
+;; 419e:	 4c899c24d0410000     	mov	qword ptr [rsp + 0x41d0], r11
+;; 41a6:	 4c899c24c8410000     	mov	qword ptr [rsp + 0x41c8], r11
+;; 41ae:	 4c899c24c0410000     	mov	qword ptr [rsp + 0x41c0], r11
+;; 41b6:	 4c899c24b8410000     	mov	qword ptr [rsp + 0x41b8], r11
+;; 41be:	 4c899c24b0410000     	mov	qword ptr [rsp + 0x41b0], r11
+;; 41c6:	 4c899c24a8410000     	mov	qword ptr [rsp + 0x41a8], r11
+;; 41ce:	 4c899c24a0410000     	mov	qword ptr [rsp + 0x41a0], r11
+;; 41d6:	 4c899c2498410000     	mov	qword ptr [rsp + 0x4198], r11
+;; 41de:	 4c899c2490410000     	mov	qword ptr [rsp + 0x4190], r11
+;; 41e6:	 4c899c2488410000     	mov	qword ptr [rsp + 0x4188], r11
+;; 41ee:	 4c899c2480410000     	mov	qword ptr [rsp + 0x4180], r11
+;; 41f6:	 4c899c2478410000     	mov	qword ptr [rsp + 0x4178], r11
+;; 41fe:	 4c899c2470410000     	mov	qword ptr [rsp + 0x4170], r11
+;; 4206:	 4c899c2468410000     	mov	qword ptr [rsp + 0x4168], r11
+;; 420e:	 4c899c2460410000     	mov	qword ptr [rsp + 0x4160], r11
+;; 4216:	 4c899c2458410000     	mov	qword ptr [rsp + 0x4158], r11
+;; 421e:	 4c899c2450410000     	mov	qword ptr [rsp + 0x4150], r11
+;; 4226:	 4c899c2448410000     	mov	qword ptr [rsp + 0x4148], r11
+;; 422e:	 4c899c2440410000     	mov	qword ptr [rsp + 0x4140], r11
+;; 4236:	 4c899c2438410000     	mov	qword ptr [rsp + 0x4138], r11
+;; 423e:	 4c899c2430410000     	mov	qword ptr [rsp + 0x4130], r11
+;; 4246:	 4c899c2428410000     	mov	qword ptr [rsp + 0x4128], r11
+;; 424e:	 4c899c2420410000     	mov	qword ptr [rsp + 0x4120], r11
+;; 4256:	 4c899c2418410000     	mov	qword ptr [rsp + 0x4118], r11
+;; 425e:	 4c899c2410410000     	mov	qword ptr [rsp + 0x4110], r11
+;; 4266:	 4c899c2408410000     	mov	qword ptr [rsp + 0x4108], r11
+;; 426e:	 4c899c2400410000     	mov	qword ptr [rsp + 0x4100], r11
+;; 4276:	 4c899c24f8400000     	mov	qword ptr [rsp + 0x40f8], r11
+;; 427e:	 4c899c24f0400000     	mov	qword ptr [rsp + 0x40f0], r11
+;; 4286:	 4c899c24e8400000     	mov	qword ptr [rsp + 0x40e8], r11
+;; 428e:	 4c899c24e0400000     	mov	qword ptr [rsp + 0x40e0], r11
+;; 4296:	 4c899c24d8400000     	mov	qword ptr [rsp + 0x40d8], r11
+;; 429e:	 4c899c24d0400000     	mov	qword ptr [rsp + 0x40d0], r11
+;; 42a6:	 4c899c24c8400000     	mov	qword ptr [rsp + 0x40c8], r11
+;; 42ae:	 4c899c24c0400000     	mov	qword ptr [rsp + 0x40c0], r11
+;; 42b6:	 4c899c24b8400000     	mov	qword ptr [rsp + 0x40b8], r11
+;; 42be:	 4c899c24b0400000     	mov	qword ptr [rsp + 0x40b0], r11
+;; 42c6:	 4c899c24a8400000     	mov	qword ptr [rsp + 0x40a8], r11
+;; 42ce:	 4c899c24a0400000     	mov	qword ptr [rsp + 0x40a0], r11
+;; 42d6:	 4c899c2498400000     	mov	qword ptr [rsp + 0x4098], r11
+;; 42de:	 4c899c2490400000     	mov	qword ptr [rsp + 0x4090], r11
+;; 42e6:	 4c899c2488400000     	mov	qword ptr [rsp + 0x4088], r11
+;; 42ee:	 4c899c2480400000     	mov	qword ptr [rsp + 0x4080], r11
+;; 42f6:	 4c899c2478400000     	mov	qword ptr [rsp + 0x4078], r11
+;; 42fe:	 4c899c2470400000     	mov	qword ptr [rsp + 0x4070], r11
+;; 4306:	 4c899c2468400000     	mov	qword ptr [rsp + 0x4068], r11
+;; 430e:	 4c899c2460400000     	mov	qword ptr [rsp + 0x4060], r11
+;; 4316:	 4c899c2458400000     	mov	qword ptr [rsp + 0x4058], r11
+;; 431e:	 4c899c2450400000     	mov	qword ptr [rsp + 0x4050], r11
+;; 4326:	 4c899c2448400000     	mov	qword ptr [rsp + 0x4048], r11
+;; 432e:	 4c899c2440400000     	mov	qword ptr [rsp + 0x4040], r11
+;; 4336:	 4c899c2438400000     	mov	qword ptr [rsp + 0x4038], r11
+;; 433e:	 4c899c2430400000     	mov	qword ptr [rsp + 0x4030], r11
+;; 4346:	 4c899c2428400000     	mov	qword ptr [rsp + 0x4028], r11
+;; 434e:	 4c899c2420400000     	mov	qword ptr [rsp + 0x4020], r11
+;; 4356:	 4c899c2418400000     	mov	qword ptr [rsp + 0x4018], r11
+;; 435e:	 4c899c2410400000     	mov	qword ptr [rsp + 0x4010], r11
+;; 4366:	 4c899c2408400000     	mov	qword ptr [rsp + 0x4008], r11
+;; 436e:	 4c899c2400400000     	mov	qword ptr [rsp + 0x4000], r11
+;; 4376:	 4c899c24f83f0000     	mov	qword ptr [rsp + 0x3ff8], r11
+;; 437e:	 4c899c24f03f0000     	mov	qword ptr [rsp + 0x3ff0], r11
+;; 4386:	 4c899c24e83f0000     	mov	qword ptr [rsp + 0x3fe8], r11
+;; 438e:	 4c899c24e03f0000     	mov	qword ptr [rsp + 0x3fe0], r11
+;; 4396:	 4c899c24d83f0000     	mov	qword ptr [rsp + 0x3fd8], r11
+;; 439e:	 4c899c24d03f0000     	mov	qword ptr [rsp + 0x3fd0], r11
+;; 43a6:	 4c899c24c83f0000     	mov	qword ptr [rsp + 0x3fc8], r11
+;; 43ae:	 4c899c24c03f0000     	mov	qword ptr [rsp + 0x3fc0], r11
+;; 43b6:	 4c899c24b83f0000     	mov	qword ptr [rsp + 0x3fb8], r11
+;; 43be:	 4c899c24b03f0000     	mov	qword ptr [rsp + 0x3fb0], r11
+;; 43c6:	 4c899c24a83f0000     	mov	qword ptr [rsp + 0x3fa8], r11
+;; 43ce:	 4c899c24a03f0000     	mov	qword ptr [rsp + 0x3fa0], r11
+;; 43d6:	 4c899c24983f0000     	mov	qword ptr [rsp + 0x3f98], r11
+;; 43de:	 4c899c24903f0000     	mov	qword ptr [rsp + 0x3f90], r11
+;; 43e6:	 4c899c24883f0000     	mov	qword ptr [rsp + 0x3f88], r11
+;; 43ee:	 4c899c24803f0000     	mov	qword ptr [rsp + 0x3f80], r11
+;; 43f6:	 4c899c24783f0000     	mov	qword ptr [rsp + 0x3f78], r11
+;; 43fe:	 4c899c24703f0000     	mov	qword ptr [rsp + 0x3f70], r11
+;; 4406:	 4c899c24683f0000     	mov	qword ptr [rsp + 0x3f68], r11
+;; 440e:	 4c899c24603f0000     	mov	qword ptr [rsp + 0x3f60], r11
+;; 4416:	 4c899c24583f0000     	mov	qword ptr [rsp + 0x3f58], r11
+;; 441e:	 4c899c24503f0000     	mov	qword ptr [rsp + 0x3f50], r11
+;; 4426:	 4c899c24483f0000     	mov	qword ptr [rsp + 0x3f48], r11
+;; 442e:	 4c899c24403f0000     	mov	qword ptr [rsp + 0x3f40], r11
+;; 4436:	 4c899c24383f0000     	mov	qword ptr [rsp + 0x3f38], r11
+;; 443e:	 4c899c24303f0000     	mov	qword ptr [rsp + 0x3f30], r11
+;; 4446:	 4c899c24283f0000     	mov	qword ptr [rsp + 0x3f28], r11
+;; 444e:	 4c899c24203f0000     	mov	qword ptr [rsp + 0x3f20], r11
+;; 4456:	 4c899c24183f0000     	mov	qword ptr [rsp + 0x3f18], r11
+;; 445e:	 4c899c24103f0000     	mov	qword ptr [rsp + 0x3f10], r11
+;; 4466:	 4c899c24083f0000     	mov	qword ptr [rsp + 0x3f08], r11
+;; 446e:	 4c899c24003f0000     	mov	qword ptr [rsp + 0x3f00], r11
+;; 4476:	 4c899c24f83e0000     	mov	qword ptr [rsp + 0x3ef8], r11
+;; 447e:	 4c899c24f03e0000     	mov	qword ptr [rsp + 0x3ef0], r11
+;; 4486:	 4c899c24e83e0000     	mov	qword ptr [rsp + 0x3ee8], r11
+;; 448e:	 4c899c24e03e0000     	mov	qword ptr [rsp + 0x3ee0], r11
+;; 4496:	 4c899c24d83e0000     	mov	qword ptr [rsp + 0x3ed8], r11
+;; 449e:	 4c899c24d03e0000     	mov	qword ptr [rsp + 0x3ed0], r11
+;; 44a6:	 4c899c24c83e0000     	mov	qword ptr [rsp + 0x3ec8], r11
+;; 44ae:	 4c899c24c03e0000     	mov	qword ptr [rsp + 0x3ec0], r11
+;; 44b6:	 4c899c24b83e0000     	mov	qword ptr [rsp + 0x3eb8], r11
+;; 44be:	 4c899c24b03e0000     	mov	qword ptr [rsp + 0x3eb0], r11
+;; 44c6:	 4c899c24a83e0000     	mov	qword ptr [rsp + 0x3ea8], r11
+;; 44ce:	 4c899c24a03e0000     	mov	qword ptr [rsp + 0x3ea0], r11
+;; 44d6:	 4c899c24983e0000     	mov	qword ptr [rsp + 0x3e98], r11
+;; 44de:	 4c899c24903e0000     	mov	qword ptr [rsp + 0x3e90], r11
+;; 44e6:	 4c899c24883e0000     	mov	qword ptr [rsp + 0x3e88], r11
+;; 44ee:	 4c899c24803e0000     	mov	qword ptr [rsp + 0x3e80], r11
+;; 44f6:	 4c899c24783e0000     	mov	qword ptr [rsp + 0x3e78], r11
+;; 44fe:	 4c899c24703e0000     	mov	qword ptr [rsp + 0x3e70], r11
+;; 4506:	 4c899c24683e0000     	mov	qword ptr [rsp + 0x3e68], r11
+;; 450e:	 4c899c24603e0000     	mov	qword ptr [rsp + 0x3e60], r11
+;; 4516:	 4c899c24583e0000     	mov	qword ptr [rsp + 0x3e58], r11
+;; 451e:	 4c899c24503e0000     	mov	qword ptr [rsp + 0x3e50], r11
+;; 4526:	 4c899c24483e0000     	mov	qword ptr [rsp + 0x3e48], r11
+;; 452e:	 4c899c24403e0000     	mov	qword ptr [rsp + 0x3e40], r11
+;; 4536:	 4c899c24383e0000     	mov	qword ptr [rsp + 0x3e38], r11
+;; 453e:	 4c899c24303e0000     	mov	qword ptr [rsp + 0x3e30], r11
+;; 4546:	 4c899c24283e0000     	mov	qword ptr [rsp + 0x3e28], r11
+;; 454e:	 4c899c24203e0000     	mov	qword ptr [rsp + 0x3e20], r11
+;; 4556:	 4c899c24183e0000     	mov	qword ptr [rsp + 0x3e18], r11
+;; 455e:	 4c899c24103e0000     	mov	qword ptr [rsp + 0x3e10], r11
+;; 4566:	 4c899c24083e0000     	mov	qword ptr [rsp + 0x3e08], r11
+;; 456e:	 4c899c24003e0000     	mov	qword ptr [rsp + 0x3e00], r11
+;; 4576:	 4c899c24f83d0000     	mov	qword ptr [rsp + 0x3df8], r11
+;; 457e:	 4c899c24f03d0000     	mov	qword ptr [rsp + 0x3df0], r11
+;; 4586:	 4c899c24e83d0000     	mov	qword ptr [rsp + 0x3de8], r11
+;; 458e:	 4c899c24e03d0000     	mov	qword ptr [rsp + 0x3de0], r11
+;; 4596:	 4c899c24d83d0000     	mov	qword ptr [rsp + 0x3dd8], r11
+;; 459e:	 4c899c24d03d0000     	mov	qword ptr [rsp + 0x3dd0], r11
+;; 45a6:	 4c899c24c83d0000     	mov	qword ptr [rsp + 0x3dc8], r11
+;; 45ae:	 4c899c24c03d0000     	mov	qword ptr [rsp + 0x3dc0], r11
+;; 45b6:	 4c899c24b83d0000     	mov	qword ptr [rsp + 0x3db8], r11
+;; 45be:	 4c899c24b03d0000     	mov	qword ptr [rsp + 0x3db0], r11
+;; 45c6:	 4c899c24a83d0000     	mov	qword ptr [rsp + 0x3da8], r11
+;; 45ce:	 4c899c24a03d0000     	mov	qword ptr [rsp + 0x3da0], r11
+;; 45d6:	 4c899c24983d0000     	mov	qword ptr [rsp + 0x3d98], r11
+;; 45de:	 4c899c24903d0000     	mov	qword ptr [rsp + 0x3d90], r11
+;; 45e6:	 4c899c24883d0000     	mov	qword ptr [rsp + 0x3d88], r11
+;; 45ee:	 4c899c24803d0000     	mov	qword ptr [rsp + 0x3d80], r11
+;; 45f6:	 4c899c24783d0000     	mov	qword ptr [rsp + 0x3d78], r11
+;; 45fe:	 4c899c24703d0000     	mov	qword ptr [rsp + 0x3d70], r11
+;; 4606:	 4c899c24683d0000     	mov	qword ptr [rsp + 0x3d68], r11
+;; 460e:	 4c899c24603d0000     	mov	qword ptr [rsp + 0x3d60], r11
+;; 4616:	 4c899c24583d0000     	mov	qword ptr [rsp + 0x3d58], r11
+;; 461e:	 4c899c24503d0000     	mov	qword ptr [rsp + 0x3d50], r11
+;; 4626:	 4c899c24483d0000     	mov	qword ptr [rsp + 0x3d48], r11
+;; 462e:	 4c899c24403d0000     	mov	qword ptr [rsp + 0x3d40], r11
+;; 4636:	 4c899c24383d0000     	mov	qword ptr [rsp + 0x3d38], r11
+;; 463e:	 4c899c24303d0000     	mov	qword ptr [rsp + 0x3d30], r11
+;; 4646:	 4c899c24283d0000     	mov	qword ptr [rsp + 0x3d28], r11
+;; 464e:	 4c899c24203d0000     	mov	qword ptr [rsp + 0x3d20], r11
+;; 4656:	 4c899c24183d0000     	mov	qword ptr [rsp + 0x3d18], r11
+;; 465e:	 4c899c24103d0000     	mov	qword ptr [rsp + 0x3d10], r11
+;; 4666:	 4c899c24083d0000     	mov	qword ptr [rsp + 0x3d08], r11
+;; 466e:	 4c899c24003d0000     	mov	qword ptr [rsp + 0x3d00], r11
+;; 4676:	 4c899c24f83c0000     	mov	qword ptr [rsp + 0x3cf8], r11
+;; 467e:	 4c899c24f03c0000     	mov	qword ptr [rsp + 0x3cf0], r11
+;; 4686:	 4c899c24e83c0000     	mov	qword ptr [rsp + 0x3ce8], r11
+;; 468e:	 4c899c24e03c0000     	mov	qword ptr [rsp + 0x3ce0], r11
+;; 4696:	 4c899c24d83c0000     	mov	qword ptr [rsp + 0x3cd8], r11
+;; 469e:	 4c899c24d03c0000     	mov	qword ptr [rsp + 0x3cd0], r11
+;; 46a6:	 4c899c24c83c0000     	mov	qword ptr [rsp + 0x3cc8], r11
+;; 46ae:	 4c899c24c03c0000     	mov	qword ptr [rsp + 0x3cc0], r11
+;; 46b6:	 4c899c24b83c0000     	mov	qword ptr [rsp + 0x3cb8], r11
+;; 46be:	 4c899c24b03c0000     	mov	qword ptr [rsp + 0x3cb0], r11
+;; 46c6:	 4c899c24a83c0000     	mov	qword ptr [rsp + 0x3ca8], r11
+;; 46ce:	 4c899c24a03c0000     	mov	qword ptr [rsp + 0x3ca0], r11
+;; 46d6:	 4c899c24983c0000     	mov	qword ptr [rsp + 0x3c98], r11
+;; 46de:	 4c899c24903c0000     	mov	qword ptr [rsp + 0x3c90], r11
+;; 46e6:	 4c899c24883c0000     	mov	qword ptr [rsp + 0x3c88], r11
+;; 46ee:	 4c899c24803c0000     	mov	qword ptr [rsp + 0x3c80], r11
+;; 46f6:	 4c899c24783c0000     	mov	qword ptr [rsp + 0x3c78], r11
+;; 46fe:	 4c899c24703c0000     	mov	qword ptr [rsp + 0x3c70], r11
+;; 4706:	 4c899c24683c0000     	mov	qword ptr [rsp + 0x3c68], r11
+;; 470e:	 4c899c24603c0000     	mov	qword ptr [rsp + 0x3c60], r11
+;; 4716:	 4c899c24583c0000     	mov	qword ptr [rsp + 0x3c58], r11
+;; 471e:	 4c899c24503c0000     	mov	qword ptr [rsp + 0x3c50], r11
+;; 4726:	 4c899c24483c0000     	mov	qword ptr [rsp + 0x3c48], r11
+;; 472e:	 4c899c24403c0000     	mov	qword ptr [rsp + 0x3c40], r11
+;; 4736:	 4c899c24383c0000     	mov	qword ptr [rsp + 0x3c38], r11
+;; 473e:	 4c899c24303c0000     	mov	qword ptr [rsp + 0x3c30], r11
+;; 4746:	 4c899c24283c0000     	mov	qword ptr [rsp + 0x3c28], r11
+;; 474e:	 4c899c24203c0000     	mov	qword ptr [rsp + 0x3c20], r11
+;; 4756:	 4c899c24183c0000     	mov	qword ptr [rsp + 0x3c18], r11
+;; 475e:	 4c899c24103c0000     	mov	qword ptr [rsp + 0x3c10], r11
+;; 4766:	 4c899c24083c0000     	mov	qword ptr [rsp + 0x3c08], r11
+;; 476e:	 4c899c24003c0000     	mov	qword ptr [rsp + 0x3c00], r11
+;; 4776:	 4c899c24f83b0000     	mov	qword ptr [rsp + 0x3bf8], r11
+;; 477e:	 4c899c24f03b0000     	mov	qword ptr [rsp + 0x3bf0], r11
+;; 4786:	 4c899c24e83b0000     	mov	qword ptr [rsp + 0x3be8], r11
+;; 478e:	 4c899c24e03b0000     	mov	qword ptr [rsp + 0x3be0], r11
+;; 4796:	 4c899c24d83b0000     	mov	qword ptr [rsp + 0x3bd8], r11
+;; 479e:	 4c899c24d03b0000     	mov	qword ptr [rsp + 0x3bd0], r11
+;; 47a6:	 4c899c24c83b0000     	mov	qword ptr [rsp + 0x3bc8], r11
+;; 47ae:	 4c899c24c03b0000     	mov	qword ptr [rsp + 0x3bc0], r11
+;; 47b6:	 4c899c24b83b0000     	mov	qword ptr [rsp + 0x3bb8], r11
+;; 47be:	 4c899c24b03b0000     	mov	qword ptr [rsp + 0x3bb0], r11
+;; 47c6:	 4c899c24a83b0000     	mov	qword ptr [rsp + 0x3ba8], r11
+;; 47ce:	 4c899c24a03b0000     	mov	qword ptr [rsp + 0x3ba0], r11
+;; 47d6:	 4c899c24983b0000     	mov	qword ptr [rsp + 0x3b98], r11
+;; 47de:	 4c899c24903b0000     	mov	qword ptr [rsp + 0x3b90], r11
+;; 47e6:	 4c899c24883b0000     	mov	qword ptr [rsp + 0x3b88], r11
+;; 47ee:	 4c899c24803b0000     	mov	qword ptr [rsp + 0x3b80], r11
+;; 47f6:	 4c899c24783b0000     	mov	qword ptr [rsp + 0x3b78], r11
+;; 47fe:	 4c899c24703b0000     	mov	qword ptr [rsp + 0x3b70], r11
+;; 4806:	 4c899c24683b0000     	mov	qword ptr [rsp + 0x3b68], r11
+;; 480e:	 4c899c24603b0000     	mov	qword ptr [rsp + 0x3b60], r11
+;; 4816:	 4c899c24583b0000     	mov	qword ptr [rsp + 0x3b58], r11
+;; 481e:	 4c899c24503b0000     	mov	qword ptr [rsp + 0x3b50], r11
+;; 4826:	 4c899c24483b0000     	mov	qword ptr [rsp + 0x3b48], r11
+;; 482e:	 4c899c24403b0000     	mov	qword ptr [rsp + 0x3b40], r11
+;; 4836:	 4c899c24383b0000     	mov	qword ptr [rsp + 0x3b38], r11
+;; 483e:	 4c899c24303b0000     	mov	qword ptr [rsp + 0x3b30], r11
+;; 4846:	 4c899c24283b0000     	mov	qword ptr [rsp + 0x3b28], r11
+;; 484e:	 4c899c24203b0000     	mov	qword ptr [rsp + 0x3b20], r11
+;; 4856:	 4c899c24183b0000     	mov	qword ptr [rsp + 0x3b18], r11
+;; 485e:	 4c899c24103b0000     	mov	qword ptr [rsp + 0x3b10], r11
+;; 4866:	 4c899c24083b0000     	mov	qword ptr [rsp + 0x3b08], r11
+;; 486e:	 4c899c24003b0000     	mov	qword ptr [rsp + 0x3b00], r11
+;; 4876:	 4c899c24f83a0000     	mov	qword ptr [rsp + 0x3af8], r11
+;; 487e:	 4c899c24f03a0000     	mov	qword ptr [rsp + 0x3af0], r11
+;; 4886:	 4c899c24e83a0000     	mov	qword ptr [rsp + 0x3ae8], r11
+;; 488e:	 4c899c24e03a0000     	mov	qword ptr [rsp + 0x3ae0], r11
+;; 4896:	 4c899c24d83a0000     	mov	qword ptr [rsp + 0x3ad8], r11
+;; 489e:	 4c899c24d03a0000     	mov	qword ptr [rsp + 0x3ad0], r11
+;; 48a6:	 4c899c24c83a0000     	mov	qword ptr [rsp + 0x3ac8], r11
+;; 48ae:	 4c899c24c03a0000     	mov	qword ptr [rsp + 0x3ac0], r11
+;; 48b6:	 4c899c24b83a0000     	mov	qword ptr [rsp + 0x3ab8], r11
+;; 48be:	 4c899c24b03a0000     	mov	qword ptr [rsp + 0x3ab0], r11
+;; 48c6:	 4c899c24a83a0000     	mov	qword ptr [rsp + 0x3aa8], r11
+;; 48ce:	 4c899c24a03a0000     	mov	qword ptr [rsp + 0x3aa0], r11
+;; 48d6:	 4c899c24983a0000     	mov	qword ptr [rsp + 0x3a98], r11
+;; 48de:	 4c899c24903a0000     	mov	qword ptr [rsp + 0x3a90], r11
+;; 48e6:	 4c899c24883a0000     	mov	qword ptr [rsp + 0x3a88], r11
+;; 48ee:	 4c899c24803a0000     	mov	qword ptr [rsp + 0x3a80], r11
+;; 48f6:	 4c899c24783a0000     	mov	qword ptr [rsp + 0x3a78], r11
+;; 48fe:	 4c899c24703a0000     	mov	qword ptr [rsp + 0x3a70], r11
+;; 4906:	 4c899c24683a0000     	mov	qword ptr [rsp + 0x3a68], r11
+;; 490e:	 4c899c24603a0000     	mov	qword ptr [rsp + 0x3a60], r11
+;; 4916:	 4c899c24583a0000     	mov	qword ptr [rsp + 0x3a58], r11
+;; 491e:	 4c899c24503a0000     	mov	qword ptr [rsp + 0x3a50], r11
+;; 4926:	 4c899c24483a0000     	mov	qword ptr [rsp + 0x3a48], r11
+;; 492e:	 4c899c24403a0000     	mov	qword ptr [rsp + 0x3a40], r11
+;; 4936:	 4c899c24383a0000     	mov	qword ptr [rsp + 0x3a38], r11
+;; 493e:	 4c899c24303a0000     	mov	qword ptr [rsp + 0x3a30], r11
+;; 4946:	 4c899c24283a0000     	mov	qword ptr [rsp + 0x3a28], r11
+;; 494e:	 4c899c24203a0000     	mov	qword ptr [rsp + 0x3a20], r11
+;; 4956:	 4c899c24183a0000     	mov	qword ptr [rsp + 0x3a18], r11
+;; 495e:	 4c899c24103a0000     	mov	qword ptr [rsp + 0x3a10], r11
+;; 4966:	 4c899c24083a0000     	mov	qword ptr [rsp + 0x3a08], r11
+;; 496e:	 4c899c24003a0000     	mov	qword ptr [rsp + 0x3a00], r11
+;; 4976:	 4c899c24f8390000     	mov	qword ptr [rsp + 0x39f8], r11
+;; 497e:	 4c899c24f0390000     	mov	qword ptr [rsp + 0x39f0], r11
+;; 4986:	 4c899c24e8390000     	mov	qword ptr [rsp + 0x39e8], r11
+;; 498e:	 4c899c24e0390000     	mov	qword ptr [rsp + 0x39e0], r11
+;; 4996:	 4c899c24d8390000     	mov	qword ptr [rsp + 0x39d8], r11
+;; 499e:	 4c899c24d0390000     	mov	qword ptr [rsp + 0x39d0], r11
+;; 49a6:	 4c899c24c8390000     	mov	qword ptr [rsp + 0x39c8], r11
+;; 49ae:	 4c899c24c0390000     	mov	qword ptr [rsp + 0x39c0], r11
+;; 49b6:	 4c899c24b8390000     	mov	qword ptr [rsp + 0x39b8], r11
+;; 49be:	 4c899c24b0390000     	mov	qword ptr [rsp + 0x39b0], r11
+;; 49c6:	 4c899c24a8390000     	mov	qword ptr [rsp + 0x39a8], r11
+;; 49ce:	 4c899c24a0390000     	mov	qword ptr [rsp + 0x39a0], r11
+;; 49d6:	 4c899c2498390000     	mov	qword ptr [rsp + 0x3998], r11
+;; 49de:	 4c899c2490390000     	mov	qword ptr [rsp + 0x3990], r11
+;; 49e6:	 4c899c2488390000     	mov	qword ptr [rsp + 0x3988], r11
+;; 49ee:	 4c899c2480390000     	mov	qword ptr [rsp + 0x3980], r11
+;; 49f6:	 4c899c2478390000     	mov	qword ptr [rsp + 0x3978], r11
+;; 49fe:	 4c899c2470390000     	mov	qword ptr [rsp + 0x3970], r11
+;; 4a06:	 4c899c2468390000     	mov	qword ptr [rsp + 0x3968], r11
+;; 4a0e:	 4c899c2460390000     	mov	qword ptr [rsp + 0x3960], r11
+;; 4a16:	 4c899c2458390000     	mov	qword ptr [rsp + 0x3958], r11
+;; 4a1e:	 4c899c2450390000     	mov	qword ptr [rsp + 0x3950], r11
+;; 4a26:	 4c899c2448390000     	mov	qword ptr [rsp + 0x3948], r11
+;; 4a2e:	 4c899c2440390000     	mov	qword ptr [rsp + 0x3940], r11
+;; 4a36:	 4c899c2438390000     	mov	qword ptr [rsp + 0x3938], r11
+;; 4a3e:	 4c899c2430390000     	mov	qword ptr [rsp + 0x3930], r11
+;; 4a46:	 4c899c2428390000     	mov	qword ptr [rsp + 0x3928], r11
+;; 4a4e:	 4c899c2420390000     	mov	qword ptr [rsp + 0x3920], r11
+;; 4a56:	 4c899c2418390000     	mov	qword ptr [rsp + 0x3918], r11
+;; 4a5e:	 4c899c2410390000     	mov	qword ptr [rsp + 0x3910], r11
+;; 4a66:	 4c899c2408390000     	mov	qword ptr [rsp + 0x3908], r11
+;; 4a6e:	 4c899c2400390000     	mov	qword ptr [rsp + 0x3900], r11
+;; 4a76:	 4c899c24f8380000     	mov	qword ptr [rsp + 0x38f8], r11
+;; 4a7e:	 4c899c24f0380000     	mov	qword ptr [rsp + 0x38f0], r11
+;; 4a86:	 4c899c24e8380000     	mov	qword ptr [rsp + 0x38e8], r11
+;; 4a8e:	 4c899c24e0380000     	mov	qword ptr [rsp + 0x38e0], r11
+;; 4a96:	 4c899c24d8380000     	mov	qword ptr [rsp + 0x38d8], r11
+;; 4a9e:	 4c899c24d0380000     	mov	qword ptr [rsp + 0x38d0], r11
+;; 4aa6:	 4c899c24c8380000     	mov	qword ptr [rsp + 0x38c8], r11
+;; 4aae:	 4c899c24c0380000     	mov	qword ptr [rsp + 0x38c0], r11
+;; 4ab6:	 4c899c24b8380000     	mov	qword ptr [rsp + 0x38b8], r11
+;; 4abe:	 4c899c24b0380000     	mov	qword ptr [rsp + 0x38b0], r11
+;; 4ac6:	 4c899c24a8380000     	mov	qword ptr [rsp + 0x38a8], r11
+;; 4ace:	 4c899c24a0380000     	mov	qword ptr [rsp + 0x38a0], r11
+;; 4ad6:	 4c899c2498380000     	mov	qword ptr [rsp + 0x3898], r11
+;; 4ade:	 4c899c2490380000     	mov	qword ptr [rsp + 0x3890], r11
+;; 4ae6:	 4c899c2488380000     	mov	qword ptr [rsp + 0x3888], r11
+;; 4aee:	 4c899c2480380000     	mov	qword ptr [rsp + 0x3880], r11
+;; 4af6:	 4c899c2478380000     	mov	qword ptr [rsp + 0x3878], r11
+;; 4afe:	 4c899c2470380000     	mov	qword ptr [rsp + 0x3870], r11
+;; 4b06:	 4c899c2468380000     	mov	qword ptr [rsp + 0x3868], r11
+;; 4b0e:	 4c899c2460380000     	mov	qword ptr [rsp + 0x3860], r11
+;; 4b16:	 4c899c2458380000     	mov	qword ptr [rsp + 0x3858], r11
+;; 4b1e:	 4c899c2450380000     	mov	qword ptr [rsp + 0x3850], r11
+;; 4b26:	 4c899c2448380000     	mov	qword ptr [rsp + 0x3848], r11
+;; 4b2e:	 4c899c2440380000     	mov	qword ptr [rsp + 0x3840], r11
+;; 4b36:	 4c899c2438380000     	mov	qword ptr [rsp + 0x3838], r11
+;; 4b3e:	 4c899c2430380000     	mov	qword ptr [rsp + 0x3830], r11
+;; 4b46:	 4c899c2428380000     	mov	qword ptr [rsp + 0x3828], r11
+;; 4b4e:	 4c899c2420380000     	mov	qword ptr [rsp + 0x3820], r11
+;; 4b56:	 4c899c2418380000     	mov	qword ptr [rsp + 0x3818], r11
+;; 4b5e:	 4c899c2410380000     	mov	qword ptr [rsp + 0x3810], r11
+;; 4b66:	 4c899c2408380000     	mov	qword ptr [rsp + 0x3808], r11
+;; 4b6e:	 4c899c2400380000     	mov	qword ptr [rsp + 0x3800], r11
+;; 4b76:	 4c899c24f8370000     	mov	qword ptr [rsp + 0x37f8], r11
+;; 4b7e:	 4c899c24f0370000     	mov	qword ptr [rsp + 0x37f0], r11
+;; 4b86:	 4c899c24e8370000     	mov	qword ptr [rsp + 0x37e8], r11
+;; 4b8e:	 4c899c24e0370000     	mov	qword ptr [rsp + 0x37e0], r11
+;; 4b96:	 4c899c24d8370000     	mov	qword ptr [rsp + 0x37d8], r11
+;; 4b9e:	 4c899c24d0370000     	mov	qword ptr [rsp + 0x37d0], r11
+;; 4ba6:	 4c899c24c8370000     	mov	qword ptr [rsp + 0x37c8], r11
+;; 4bae:	 4c899c24c0370000     	mov	qword ptr [rsp + 0x37c0], r11
+;; 4bb6:	 4c899c24b8370000     	mov	qword ptr [rsp + 0x37b8], r11
+;; 4bbe:	 4c899c24b0370000     	mov	qword ptr [rsp + 0x37b0], r11
+;; 4bc6:	 4c899c24a8370000     	mov	qword ptr [rsp + 0x37a8], r11
+;; 4bce:	 4c899c24a0370000     	mov	qword ptr [rsp + 0x37a0], r11
+;; 4bd6:	 4c899c2498370000     	mov	qword ptr [rsp + 0x3798], r11
+;; 4bde:	 4c899c2490370000     	mov	qword ptr [rsp + 0x3790], r11
+;; 4be6:	 4c899c2488370000     	mov	qword ptr [rsp + 0x3788], r11
+;; 4bee:	 4c899c2480370000     	mov	qword ptr [rsp + 0x3780], r11
+;; 4bf6:	 4c899c2478370000     	mov	qword ptr [rsp + 0x3778], r11
+;; 4bfe:	 4c899c2470370000     	mov	qword ptr [rsp + 0x3770], r11
+;; 4c06:	 4c899c2468370000     	mov	qword ptr [rsp + 0x3768], r11
+;; 4c0e:	 4c899c2460370000     	mov	qword ptr [rsp + 0x3760], r11
+;; 4c16:	 4c899c2458370000     	mov	qword ptr [rsp + 0x3758], r11
+;; 4c1e:	 4c899c2450370000     	mov	qword ptr [rsp + 0x3750], r11
+;; 4c26:	 4c899c2448370000     	mov	qword ptr [rsp + 0x3748], r11
+;; 4c2e:	 4c899c2440370000     	mov	qword ptr [rsp + 0x3740], r11
+;; 4c36:	 4c899c2438370000     	mov	qword ptr [rsp + 0x3738], r11
+;; 4c3e:	 4c899c2430370000     	mov	qword ptr [rsp + 0x3730], r11
+;; 4c46:	 4c899c2428370000     	mov	qword ptr [rsp + 0x3728], r11
+;; 4c4e:	 4c899c2420370000     	mov	qword ptr [rsp + 0x3720], r11
+;; 4c56:	 4c899c2418370000     	mov	qword ptr [rsp + 0x3718], r11
+;; 4c5e:	 4c899c2410370000     	mov	qword ptr [rsp + 0x3710], r11
+;; 4c66:	 4c899c2408370000     	mov	qword ptr [rsp + 0x3708], r11
+;; 4c6e:	 4c899c2400370000     	mov	qword ptr [rsp + 0x3700], r11
+;; 4c76:	 4c899c24f8360000     	mov	qword ptr [rsp + 0x36f8], r11
+;; 4c7e:	 4c899c24f0360000     	mov	qword ptr [rsp + 0x36f0], r11
+;; 4c86:	 4c899c24e8360000     	mov	qword ptr [rsp + 0x36e8], r11
+;; 4c8e:	 4c899c24e0360000     	mov	qword ptr [rsp + 0x36e0], r11
+;; 4c96:	 4c899c24d8360000     	mov	qword ptr [rsp + 0x36d8], r11
+;; 4c9e:	 4c899c24d0360000     	mov	qword ptr [rsp + 0x36d0], r11
+;; 4ca6:	 4c899c24c8360000     	mov	qword ptr [rsp + 0x36c8], r11
+;; 4cae:	 4c899c24c0360000     	mov	qword ptr [rsp + 0x36c0], r11
+;; 4cb6:	 4c899c24b8360000     	mov	qword ptr [rsp + 0x36b8], r11
+;; 4cbe:	 4c899c24b0360000     	mov	qword ptr [rsp + 0x36b0], r11
+;; 4cc6:	 4c899c24a8360000     	mov	qword ptr [rsp + 0x36a8], r11
+;; 4cce:	 4c899c24a0360000     	mov	qword ptr [rsp + 0x36a0], r11
+;; 4cd6:	 4c899c2498360000     	mov	qword ptr [rsp + 0x3698], r11
+;; 4cde:	 4c899c2490360000     	mov	qword ptr [rsp + 0x3690], r11
+;; 4ce6:	 4c899c2488360000     	mov	qword ptr [rsp + 0x3688], r11
+;; 4cee:	 4c899c2480360000     	mov	qword ptr [rsp + 0x3680], r11
+;; 4cf6:	 4c899c2478360000     	mov	qword ptr [rsp + 0x3678], r11
+;; 4cfe:	 4c899c2470360000     	mov	qword ptr [rsp + 0x3670], r11
+;; 4d06:	 4c899c2468360000     	mov	qword ptr [rsp + 0x3668], r11
+;; 4d0e:	 4c899c2460360000     	mov	qword ptr [rsp + 0x3660], r11
+;; 4d16:	 4c899c2458360000     	mov	qword ptr [rsp + 0x3658], r11
+;; 4d1e:	 4c899c2450360000     	mov	qword ptr [rsp + 0x3650], r11
+;; 4d26:	 4c899c2448360000     	mov	qword ptr [rsp + 0x3648], r11
+;; 4d2e:	 4c899c2440360000     	mov	qword ptr [rsp + 0x3640], r11
+;; 4d36:	 4c899c2438360000     	mov	qword ptr [rsp + 0x3638], r11
+;; 4d3e:	 4c899c2430360000     	mov	qword ptr [rsp + 0x3630], r11
+;; 4d46:	 4c899c2428360000     	mov	qword ptr [rsp + 0x3628], r11
+;; 4d4e:	 4c899c2420360000     	mov	qword ptr [rsp + 0x3620], r11
+;; 4d56:	 4c899c2418360000     	mov	qword ptr [rsp + 0x3618], r11
+;; 4d5e:	 4c899c2410360000     	mov	qword ptr [rsp + 0x3610], r11
+;; 4d66:	 4c899c2408360000     	mov	qword ptr [rsp + 0x3608], r11
+;; 4d6e:	 4c899c2400360000     	mov	qword ptr [rsp + 0x3600], r11
+;; 4d76:	 4c899c24f8350000     	mov	qword ptr [rsp + 0x35f8], r11
+;; 4d7e:	 4c899c24f0350000     	mov	qword ptr [rsp + 0x35f0], r11
+;; 4d86:	 4c899c24e8350000     	mov	qword ptr [rsp + 0x35e8], r11
+;; 4d8e:	 4c899c24e0350000     	mov	qword ptr [rsp + 0x35e0], r11
+;; 4d96:	 4c899c24d8350000     	mov	qword ptr [rsp + 0x35d8], r11
+;; 4d9e:	 4c899c24d0350000     	mov	qword ptr [rsp + 0x35d0], r11
+;; 4da6:	 4c899c24c8350000     	mov	qword ptr [rsp + 0x35c8], r11
+;; 4dae:	 4c899c24c0350000     	mov	qword ptr [rsp + 0x35c0], r11
+;; 4db6:	 4c899c24b8350000     	mov	qword ptr [rsp + 0x35b8], r11
+;; 4dbe:	 4c899c24b0350000     	mov	qword ptr [rsp + 0x35b0], r11
+;; 4dc6:	 4c899c24a8350000     	mov	qword ptr [rsp + 0x35a8], r11
+;; 4dce:	 4c899c24a0350000     	mov	qword ptr [rsp + 0x35a0], r11
+;; 4dd6:	 4c899c2498350000     	mov	qword ptr [rsp + 0x3598], r11
+;; 4dde:	 4c899c2490350000     	mov	qword ptr [rsp + 0x3590], r11
+;; 4de6:	 4c899c2488350000     	mov	qword ptr [rsp + 0x3588], r11
+;; 4dee:	 4c899c2480350000     	mov	qword ptr [rsp + 0x3580], r11
+;; 4df6:	 4c899c2478350000     	mov	qword ptr [rsp + 0x3578], r11
+;; 4dfe:	 4c899c2470350000     	mov	qword ptr [rsp + 0x3570], r11
+;; 4e06:	 4c899c2468350000     	mov	qword ptr [rsp + 0x3568], r11
+;; 4e0e:	 4c899c2460350000     	mov	qword ptr [rsp + 0x3560], r11
+;; 4e16:	 4c899c2458350000     	mov	qword ptr [rsp + 0x3558], r11
+;; 4e1e:	 4c899c2450350000     	mov	qword ptr [rsp + 0x3550], r11
+;; 4e26:	 4c899c2448350000     	mov	qword ptr [rsp + 0x3548], r11
+;; 4e2e:	 4c899c2440350000     	mov	qword ptr [rsp + 0x3540], r11
+;; 4e36:	 4c899c2438350000     	mov	qword ptr [rsp + 0x3538], r11
+;; 4e3e:	 4c899c2430350000     	mov	qword ptr [rsp + 0x3530], r11
+;; 4e46:	 4c899c2428350000     	mov	qword ptr [rsp + 0x3528], r11
+;; 4e4e:	 4c899c2420350000     	mov	qword ptr [rsp + 0x3520], r11
+;; 4e56:	 4c899c2418350000     	mov	qword ptr [rsp + 0x3518], r11
+;; 4e5e:	 4c899c2410350000     	mov	qword ptr [rsp + 0x3510], r11
+;; 4e66:	 4c899c2408350000     	mov	qword ptr [rsp + 0x3508], r11
+;; 4e6e:	 4c899c2400350000     	mov	qword ptr [rsp + 0x3500], r11
+;; 4e76:	 4c899c24f8340000     	mov	qword ptr [rsp + 0x34f8], r11
+;; 4e7e:	 4c899c24f0340000     	mov	qword ptr [rsp + 0x34f0], r11
+;; 4e86:	 4c899c24e8340000     	mov	qword ptr [rsp + 0x34e8], r11
+;; 4e8e:	 4c899c24e0340000     	mov	qword ptr [rsp + 0x34e0], r11
+;; 4e96:	 4c899c24d8340000     	mov	qword ptr [rsp + 0x34d8], r11
+;; 4e9e:	 4c899c24d0340000     	mov	qword ptr [rsp + 0x34d0], r11
+;; 4ea6:	 4c899c24c8340000     	mov	qword ptr [rsp + 0x34c8], r11
+;; 4eae:	 4c899c24c0340000     	mov	qword ptr [rsp + 0x34c0], r11
+;; 4eb6:	 4c899c24b8340000     	mov	qword ptr [rsp + 0x34b8], r11
+;; 4ebe:	 4c899c24b0340000     	mov	qword ptr [rsp + 0x34b0], r11
+;; 4ec6:	 4c899c24a8340000     	mov	qword ptr [rsp + 0x34a8], r11
+;; 4ece:	 4c899c24a0340000     	mov	qword ptr [rsp + 0x34a0], r11
+;; 4ed6:	 4c899c2498340000     	mov	qword ptr [rsp + 0x3498], r11
+;; 4ede:	 4c899c2490340000     	mov	qword ptr [rsp + 0x3490], r11
+;; 4ee6:	 4c899c2488340000     	mov	qword ptr [rsp + 0x3488], r11
+;; 4eee:	 4c899c2480340000     	mov	qword ptr [rsp + 0x3480], r11
+;; 4ef6:	 4c899c2478340000     	mov	qword ptr [rsp + 0x3478], r11
+;; 4efe:	 4c899c2470340000     	mov	qword ptr [rsp + 0x3470], r11
+;; 4f06:	 4c899c2468340000     	mov	qword ptr [rsp + 0x3468], r11
+;; 4f0e:	 4c899c2460340000     	mov	qword ptr [rsp + 0x3460], r11
+;; 4f16:	 4c899c2458340000     	mov	qword ptr [rsp + 0x3458], r11
+;; 4f1e:	 4c899c2450340000     	mov	qword ptr [rsp + 0x3450], r11
+;; 4f26:	 4c899c2448340000     	mov	qword ptr [rsp + 0x3448], r11
+;; 4f2e:	 4c899c2440340000     	mov	qword ptr [rsp + 0x3440], r11
+;; 4f36:	 4c899c2438340000     	mov	qword ptr [rsp + 0x3438], r11
+;; 4f3e:	 4c899c2430340000     	mov	qword ptr [rsp + 0x3430], r11
+;; 4f46:	 4c899c2428340000     	mov	qword ptr [rsp + 0x3428], r11
+;; 4f4e:	 4c899c2420340000     	mov	qword ptr [rsp + 0x3420], r11
+;; 4f56:	 4c899c2418340000     	mov	qword ptr [rsp + 0x3418], r11
+;; 4f5e:	 4c899c2410340000     	mov	qword ptr [rsp + 0x3410], r11
+;; 4f66:	 4c899c2408340000     	mov	qword ptr [rsp + 0x3408], r11
+;; 4f6e:	 4c899c2400340000     	mov	qword ptr [rsp + 0x3400], r11
+;; 4f76:	 4c899c24f8330000     	mov	qword ptr [rsp + 0x33f8], r11
+;; 4f7e:	 4c899c24f0330000     	mov	qword ptr [rsp + 0x33f0], r11
+;; 4f86:	 4c899c24e8330000     	mov	qword ptr [rsp + 0x33e8], r11
+;; 4f8e:	 4c899c24e0330000     	mov	qword ptr [rsp + 0x33e0], r11
+;; 4f96:	 4c899c24d8330000     	mov	qword ptr [rsp + 0x33d8], r11
+;; 4f9e:	 4c899c24d0330000     	mov	qword ptr [rsp + 0x33d0], r11
+;; 4fa6:	 4c899c24c8330000     	mov	qword ptr [rsp + 0x33c8], r11
+;; 4fae:	 4c899c24c0330000     	mov	qword ptr [rsp + 0x33c0], r11
+;; 4fb6:	 4c899c24b8330000     	mov	qword ptr [rsp + 0x33b8], r11
+;; 4fbe:	 4c899c24b0330000     	mov	qword ptr [rsp + 0x33b0], r11
+;; 4fc6:	 4c899c24a8330000     	mov	qword ptr [rsp + 0x33a8], r11
+;; 4fce:	 4c899c24a0330000     	mov	qword ptr [rsp + 0x33a0], r11
+;; 4fd6:	 4c899c2498330000     	mov	qword ptr [rsp + 0x3398], r11
+;; 4fde:	 4c899c2490330000     	mov	qword ptr [rsp + 0x3390], r11
+;; 4fe6:	 4c899c2488330000     	mov	qword ptr [rsp + 0x3388], r11
+;; 4fee:	 4c899c2480330000     	mov	qword ptr [rsp + 0x3380], r11
+;; 4ff6:	 4c899c2478330000     	mov	qword ptr [rsp + 0x3378], r11
+;; 4ffe:	 4c899c2470330000     	mov	qword ptr [rsp + 0x3370], r11
+;; 5006:	 4c899c2468330000     	mov	qword ptr [rsp + 0x3368], r11
+;; 500e:	 4c899c2460330000     	mov	qword ptr [rsp + 0x3360], r11
+;; 5016:	 4c899c2458330000     	mov	qword ptr [rsp + 0x3358], r11
+;; 501e:	 4c899c2450330000     	mov	qword ptr [rsp + 0x3350], r11
+;; 5026:	 4c899c2448330000     	mov	qword ptr [rsp + 0x3348], r11
+;; 502e:	 4c899c2440330000     	mov	qword ptr [rsp + 0x3340], r11
+;; 5036:	 4c899c2438330000     	mov	qword ptr [rsp + 0x3338], r11
+;; 503e:	 4c899c2430330000     	mov	qword ptr [rsp + 0x3330], r11
+;; 5046:	 4c899c2428330000     	mov	qword ptr [rsp + 0x3328], r11
+;; 504e:	 4c899c2420330000     	mov	qword ptr [rsp + 0x3320], r11
+;; 5056:	 4c899c2418330000     	mov	qword ptr [rsp + 0x3318], r11
+;; 505e:	 4c899c2410330000     	mov	qword ptr [rsp + 0x3310], r11
+;; 5066:	 4c899c2408330000     	mov	qword ptr [rsp + 0x3308], r11
+;; 506e:	 4c899c2400330000     	mov	qword ptr [rsp + 0x3300], r11
+;; 5076:	 4c899c24f8320000     	mov	qword ptr [rsp + 0x32f8], r11
+;; 507e:	 4c899c24f0320000     	mov	qword ptr [rsp + 0x32f0], r11
+;; 5086:	 4c899c24e8320000     	mov	qword ptr [rsp + 0x32e8], r11
+;; 508e:	 4c899c24e0320000     	mov	qword ptr [rsp + 0x32e0], r11
+;; 5096:	 4c899c24d8320000     	mov	qword ptr [rsp + 0x32d8], r11
+;; 509e:	 4c899c24d0320000     	mov	qword ptr [rsp + 0x32d0], r11
+;; 50a6:	 4c899c24c8320000     	mov	qword ptr [rsp + 0x32c8], r11
+;; 50ae:	 4c899c24c0320000     	mov	qword ptr [rsp + 0x32c0], r11
+;; 50b6:	 4c899c24b8320000     	mov	qword ptr [rsp + 0x32b8], r11
+;; 50be:	 4c899c24b0320000     	mov	qword ptr [rsp + 0x32b0], r11
+;; 50c6:	 4c899c24a8320000     	mov	qword ptr [rsp + 0x32a8], r11
+;; 50ce:	 4c899c24a0320000     	mov	qword ptr [rsp + 0x32a0], r11
+;; 50d6:	 4c899c2498320000     	mov	qword ptr [rsp + 0x3298], r11
+;; 50de:	 4c899c2490320000     	mov	qword ptr [rsp + 0x3290], r11
+;; 50e6:	 4c899c2488320000     	mov	qword ptr [rsp + 0x3288], r11
+;; 50ee:	 4c899c2480320000     	mov	qword ptr [rsp + 0x3280], r11
+;; 50f6:	 4c899c2478320000     	mov	qword ptr [rsp + 0x3278], r11
+;; 50fe:	 4c899c2470320000     	mov	qword ptr [rsp + 0x3270], r11
+;; 5106:	 4c899c2468320000     	mov	qword ptr [rsp + 0x3268], r11
+;; 510e:	 4c899c2460320000     	mov	qword ptr [rsp + 0x3260], r11
+;; 5116:	 4c899c2458320000     	mov	qword ptr [rsp + 0x3258], r11
+;; 511e:	 4c899c2450320000     	mov	qword ptr [rsp + 0x3250], r11
+;; 5126:	 4c899c2448320000     	mov	qword ptr [rsp + 0x3248], r11
+;; 512e:	 4c899c2440320000     	mov	qword ptr [rsp + 0x3240], r11
+;; 5136:	 4c899c2438320000     	mov	qword ptr [rsp + 0x3238], r11
+;; 513e:	 4c899c2430320000     	mov	qword ptr [rsp + 0x3230], r11
+;; 5146:	 4c899c2428320000     	mov	qword ptr [rsp + 0x3228], r11
+;; 514e:	 4c899c2420320000     	mov	qword ptr [rsp + 0x3220], r11
+;; 5156:	 4c899c2418320000     	mov	qword ptr [rsp + 0x3218], r11
+;; 515e:	 4c899c2410320000     	mov	qword ptr [rsp + 0x3210], r11
+;; 5166:	 4c899c2408320000     	mov	qword ptr [rsp + 0x3208], r11
+;; 516e:	 4c899c2400320000     	mov	qword ptr [rsp + 0x3200], r11
+;; 5176:	 4c899c24f8310000     	mov	qword ptr [rsp + 0x31f8], r11
+;; 517e:	 4c899c24f0310000     	mov	qword ptr [rsp + 0x31f0], r11
+;; 5186:	 4c899c24e8310000     	mov	qword ptr [rsp + 0x31e8], r11
+;; 518e:	 4c899c24e0310000     	mov	qword ptr [rsp + 0x31e0], r11
+;; 5196:	 4c899c24d8310000     	mov	qword ptr [rsp + 0x31d8], r11
+;; 519e:	 4c899c24d0310000     	mov	qword ptr [rsp + 0x31d0], r11
+;; 51a6:	 4c899c24c8310000     	mov	qword ptr [rsp + 0x31c8], r11
+;; 51ae:	 4c899c24c0310000     	mov	qword ptr [rsp + 0x31c0], r11
+;; 51b6:	 4c899c24b8310000     	mov	qword ptr [rsp + 0x31b8], r11
+;; 51be:	 4c899c24b0310000     	mov	qword ptr [rsp + 0x31b0], r11
+;; 51c6:	 4c899c24a8310000     	mov	qword ptr [rsp + 0x31a8], r11
+;; 51ce:	 4c899c24a0310000     	mov	qword ptr [rsp + 0x31a0], r11
+;; 51d6:	 4c899c2498310000     	mov	qword ptr [rsp + 0x3198], r11
+;; 51de:	 4c899c2490310000     	mov	qword ptr [rsp + 0x3190], r11
+;; 51e6:	 4c899c2488310000     	mov	qword ptr [rsp + 0x3188], r11
+;; 51ee:	 4c899c2480310000     	mov	qword ptr [rsp + 0x3180], r11
+;; 51f6:	 4c899c2478310000     	mov	qword ptr [rsp + 0x3178], r11
+;; 51fe:	 4c899c2470310000     	mov	qword ptr [rsp + 0x3170], r11
+;; 5206:	 4c899c2468310000     	mov	qword ptr [rsp + 0x3168], r11
+;; 520e:	 4c899c2460310000     	mov	qword ptr [rsp + 0x3160], r11
+;; 5216:	 4c899c2458310000     	mov	qword ptr [rsp + 0x3158], r11
+;; 521e:	 4c899c2450310000     	mov	qword ptr [rsp + 0x3150], r11
+;; 5226:	 4c899c2448310000     	mov	qword ptr [rsp + 0x3148], r11
+;; 522e:	 4c899c2440310000     	mov	qword ptr [rsp + 0x3140], r11
+;; 5236:	 4c899c2438310000     	mov	qword ptr [rsp + 0x3138], r11
+;; 523e:	 4c899c2430310000     	mov	qword ptr [rsp + 0x3130], r11
+;; 5246:	 4c899c2428310000     	mov	qword ptr [rsp + 0x3128], r11
+;; 524e:	 4c899c2420310000     	mov	qword ptr [rsp + 0x3120], r11
+;; 5256:	 4c899c2418310000     	mov	qword ptr [rsp + 0x3118], r11
+;; 525e:	 4c899c2410310000     	mov	qword ptr [rsp + 0x3110], r11
+;; 5266:	 4c899c2408310000     	mov	qword ptr [rsp + 0x3108], r11
+;; 526e:	 4c899c2400310000     	mov	qword ptr [rsp + 0x3100], r11
+;; 5276:	 4c899c24f8300000     	mov	qword ptr [rsp + 0x30f8], r11
+;; 527e:	 4c899c24f0300000     	mov	qword ptr [rsp + 0x30f0], r11
+;; 5286:	 4c899c24e8300000     	mov	qword ptr [rsp + 0x30e8], r11
+;; 528e:	 4c899c24e0300000     	mov	qword ptr [rsp + 0x30e0], r11
+;; 5296:	 4c899c24d8300000     	mov	qword ptr [rsp + 0x30d8], r11
+;; 529e:	 4c899c24d0300000     	mov	qword ptr [rsp + 0x30d0], r11
+;; 52a6:	 4c899c24c8300000     	mov	qword ptr [rsp + 0x30c8], r11
+;; 52ae:	 4c899c24c0300000     	mov	qword ptr [rsp + 0x30c0], r11
+;; 52b6:	 4c899c24b8300000     	mov	qword ptr [rsp + 0x30b8], r11
+;; 52be:	 4c899c24b0300000     	mov	qword ptr [rsp + 0x30b0], r11
+;; 52c6:	 4c899c24a8300000     	mov	qword ptr [rsp + 0x30a8], r11
+;; 52ce:	 4c899c24a0300000     	mov	qword ptr [rsp + 0x30a0], r11
+;; 52d6:	 4c899c2498300000     	mov	qword ptr [rsp + 0x3098], r11
+;; 52de:	 4c899c2490300000     	mov	qword ptr [rsp + 0x3090], r11
+;; 52e6:	 4c899c2488300000     	mov	qword ptr [rsp + 0x3088], r11
+;; 52ee:	 4c899c2480300000     	mov	qword ptr [rsp + 0x3080], r11
+;; 52f6:	 4c899c2478300000     	mov	qword ptr [rsp + 0x3078], r11
+;; 52fe:	 4c899c2470300000     	mov	qword ptr [rsp + 0x3070], r11
+;; 5306:	 4c899c2468300000     	mov	qword ptr [rsp + 0x3068], r11
+;; 530e:	 4c899c2460300000     	mov	qword ptr [rsp + 0x3060], r11
+;; 5316:	 4c899c2458300000     	mov	qword ptr [rsp + 0x3058], r11
+;; 531e:	 4c899c2450300000     	mov	qword ptr [rsp + 0x3050], r11
+;; 5326:	 4c899c2448300000     	mov	qword ptr [rsp + 0x3048], r11
+;; 532e:	 4c899c2440300000     	mov	qword ptr [rsp + 0x3040], r11
+;; 5336:	 4c899c2438300000     	mov	qword ptr [rsp + 0x3038], r11
+;; 533e:	 4c899c2430300000     	mov	qword ptr [rsp + 0x3030], r11
+;; 5346:	 4c899c2428300000     	mov	qword ptr [rsp + 0x3028], r11
+;; 534e:	 4c899c2420300000     	mov	qword ptr [rsp + 0x3020], r11
+;; 5356:	 4c899c2418300000     	mov	qword ptr [rsp + 0x3018], r11
+;; 535e:	 4c899c2410300000     	mov	qword ptr [rsp + 0x3010], r11
+;; 5366:	 4c899c2408300000     	mov	qword ptr [rsp + 0x3008], r11
+;; 536e:	 4c899c2400300000     	mov	qword ptr [rsp + 0x3000], r11
+;; 5376:	 4c899c24f82f0000     	mov	qword ptr [rsp + 0x2ff8], r11
+;; 537e:	 4c899c24f02f0000     	mov	qword ptr [rsp + 0x2ff0], r11
+;; 5386:	 4c899c24e82f0000     	mov	qword ptr [rsp + 0x2fe8], r11
+;; 538e:	 4c899c24e02f0000     	mov	qword ptr [rsp + 0x2fe0], r11
+;; 5396:	 4c899c24d82f0000     	mov	qword ptr [rsp + 0x2fd8], r11
+;; 539e:	 4c899c24d02f0000     	mov	qword ptr [rsp + 0x2fd0], r11
+;; 53a6:	 4c899c24c82f0000     	mov	qword ptr [rsp + 0x2fc8], r11
+;; 53ae:	 4c899c24c02f0000     	mov	qword ptr [rsp + 0x2fc0], r11
+;; 53b6:	 4c899c24b82f0000     	mov	qword ptr [rsp + 0x2fb8], r11
+;; 53be:	 4c899c24b02f0000     	mov	qword ptr [rsp + 0x2fb0], r11
+;; 53c6:	 4c899c24a82f0000     	mov	qword ptr [rsp + 0x2fa8], r11
+;; 53ce:	 4c899c24a02f0000     	mov	qword ptr [rsp + 0x2fa0], r11
+;; 53d6:	 4c899c24982f0000     	mov	qword ptr [rsp + 0x2f98], r11
+;; 53de:	 4c899c24902f0000     	mov	qword ptr [rsp + 0x2f90], r11
+;; 53e6:	 4c899c24882f0000     	mov	qword ptr [rsp + 0x2f88], r11
+;; 53ee:	 4c899c24802f0000     	mov	qword ptr [rsp + 0x2f80], r11
+;; 53f6:	 4c899c24782f0000     	mov	qword ptr [rsp + 0x2f78], r11
+;; 53fe:	 4c899c24702f0000     	mov	qword ptr [rsp + 0x2f70], r11
+;; 5406:	 4c899c24682f0000     	mov	qword ptr [rsp + 0x2f68], r11
+;; 540e:	 4c899c24602f0000     	mov	qword ptr [rsp + 0x2f60], r11
+;; 5416:	 4c899c24582f0000     	mov	qword ptr [rsp + 0x2f58], r11
+;; 541e:	 4c899c24502f0000     	mov	qword ptr [rsp + 0x2f50], r11
+;; 5426:	 4c899c24482f0000     	mov	qword ptr [rsp + 0x2f48], r11
+;; 542e:	 4c899c24402f0000     	mov	qword ptr [rsp + 0x2f40], r11
+;; 5436:	 4c899c24382f0000     	mov	qword ptr [rsp + 0x2f38], r11
+;; 543e:	 4c899c24302f0000     	mov	qword ptr [rsp + 0x2f30], r11
+;; 5446:	 4c899c24282f0000     	mov	qword ptr [rsp + 0x2f28], r11
+;; 544e:	 4c899c24202f0000     	mov	qword ptr [rsp + 0x2f20], r11
+;; 5456:	 4c899c24182f0000     	mov	qword ptr [rsp + 0x2f18], r11
+;; 545e:	 4c899c24102f0000     	mov	qword ptr [rsp + 0x2f10], r11
+;; 5466:	 4c899c24082f0000     	mov	qword ptr [rsp + 0x2f08], r11
+;; 546e:	 4c899c24002f0000     	mov	qword ptr [rsp + 0x2f00], r11
+;; 5476:	 4c899c24f82e0000     	mov	qword ptr [rsp + 0x2ef8], r11
+;; 547e:	 4c899c24f02e0000     	mov	qword ptr [rsp + 0x2ef0], r11
+;; 5486:	 4c899c24e82e0000     	mov	qword ptr [rsp + 0x2ee8], r11
+;; 548e:	 4c899c24e02e0000     	mov	qword ptr [rsp + 0x2ee0], r11
+;; 5496:	 4c899c24d82e0000     	mov	qword ptr [rsp + 0x2ed8], r11
+;; 549e:	 4c899c24d02e0000     	mov	qword ptr [rsp + 0x2ed0], r11
+;; 54a6:	 4c899c24c82e0000     	mov	qword ptr [rsp + 0x2ec8], r11
+;; 54ae:	 4c899c24c02e0000     	mov	qword ptr [rsp + 0x2ec0], r11
+;; 54b6:	 4c899c24b82e0000     	mov	qword ptr [rsp + 0x2eb8], r11
+;; 54be:	 4c899c24b02e0000     	mov	qword ptr [rsp + 0x2eb0], r11
+;; 54c6:	 4c899c24a82e0000     	mov	qword ptr [rsp + 0x2ea8], r11
+;; 54ce:	 4c899c24a02e0000     	mov	qword ptr [rsp + 0x2ea0], r11
+;; 54d6:	 4c899c24982e0000     	mov	qword ptr [rsp + 0x2e98], r11
+;; 54de:	 4c899c24902e0000     	mov	qword ptr [rsp + 0x2e90], r11
+;; 54e6:	 4c899c24882e0000     	mov	qword ptr [rsp + 0x2e88], r11
+;; 54ee:	 4c899c24802e0000     	mov	qword ptr [rsp + 0x2e80], r11
+;; 54f6:	 4c899c24782e0000     	mov	qword ptr [rsp + 0x2e78], r11
+;; 54fe:	 4c899c24702e0000     	mov	qword ptr [rsp + 0x2e70], r11
+;; 5506:	 4c899c24682e0000     	mov	qword ptr [rsp + 0x2e68], r11
+;; 550e:	 4c899c24602e0000     	mov	qword ptr [rsp + 0x2e60], r11
+;; 5516:	 4c899c24582e0000     	mov	qword ptr [rsp + 0x2e58], r11
+;; 551e:	 4c899c24502e0000     	mov	qword ptr [rsp + 0x2e50], r11
+;; 5526:	 4c899c24482e0000     	mov	qword ptr [rsp + 0x2e48], r11
+;; 552e:	 4c899c24402e0000     	mov	qword ptr [rsp + 0x2e40], r11
+;; 5536:	 4c899c24382e0000     	mov	qword ptr [rsp + 0x2e38], r11
+;; 553e:	 4c899c24302e0000     	mov	qword ptr [rsp + 0x2e30], r11
+;; 5546:	 4c899c24282e0000     	mov	qword ptr [rsp + 0x2e28], r11
+;; 554e:	 4c899c24202e0000     	mov	qword ptr [rsp + 0x2e20], r11
+;; 5556:	 4c899c24182e0000     	mov	qword ptr [rsp + 0x2e18], r11
+;; 555e:	 4c899c24102e0000     	mov	qword ptr [rsp + 0x2e10], r11
+;; 5566:	 4c899c24082e0000     	mov	qword ptr [rsp + 0x2e08], r11
+;; 556e:	 4c899c24002e0000     	mov	qword ptr [rsp + 0x2e00], r11
+;; 5576:	 4c899c24f82d0000     	mov	qword ptr [rsp + 0x2df8], r11
+;; 557e:	 4c899c24f02d0000     	mov	qword ptr [rsp + 0x2df0], r11
+;; 5586:	 4c899c24e82d0000     	mov	qword ptr [rsp + 0x2de8], r11
+;; 558e:	 4c899c24e02d0000     	mov	qword ptr [rsp + 0x2de0], r11
+;; 5596:	 4c899c24d82d0000     	mov	qword ptr [rsp + 0x2dd8], r11
+;; 559e:	 4c899c24d02d0000     	mov	qword ptr [rsp + 0x2dd0], r11
+;; 55a6:	 4c899c24c82d0000     	mov	qword ptr [rsp + 0x2dc8], r11
+;; 55ae:	 4c899c24c02d0000     	mov	qword ptr [rsp + 0x2dc0], r11
+;; 55b6:	 4c899c24b82d0000     	mov	qword ptr [rsp + 0x2db8], r11
+;; 55be:	 4c899c24b02d0000     	mov	qword ptr [rsp + 0x2db0], r11
+;; 55c6:	 4c899c24a82d0000     	mov	qword ptr [rsp + 0x2da8], r11
+;; 55ce:	 4c899c24a02d0000     	mov	qword ptr [rsp + 0x2da0], r11
+;; 55d6:	 4c899c24982d0000     	mov	qword ptr [rsp + 0x2d98], r11
+;; 55de:	 4c899c24902d0000     	mov	qword ptr [rsp + 0x2d90], r11
+;; 55e6:	 4c899c24882d0000     	mov	qword ptr [rsp + 0x2d88], r11
+;; 55ee:	 4c899c24802d0000     	mov	qword ptr [rsp + 0x2d80], r11
+;; 55f6:	 4c899c24782d0000     	mov	qword ptr [rsp + 0x2d78], r11
+;; 55fe:	 4c899c24702d0000     	mov	qword ptr [rsp + 0x2d70], r11
+;; 5606:	 4c899c24682d0000     	mov	qword ptr [rsp + 0x2d68], r11
+;; 560e:	 4c899c24602d0000     	mov	qword ptr [rsp + 0x2d60], r11
+;; 5616:	 4c899c24582d0000     	mov	qword ptr [rsp + 0x2d58], r11
+;; 561e:	 4c899c24502d0000     	mov	qword ptr [rsp + 0x2d50], r11
+;; 5626:	 4c899c24482d0000     	mov	qword ptr [rsp + 0x2d48], r11
+;; 562e:	 4c899c24402d0000     	mov	qword ptr [rsp + 0x2d40], r11
+;; 5636:	 4c899c24382d0000     	mov	qword ptr [rsp + 0x2d38], r11
+;; 563e:	 4c899c24302d0000     	mov	qword ptr [rsp + 0x2d30], r11
+;; 5646:	 4c899c24282d0000     	mov	qword ptr [rsp + 0x2d28], r11
+;; 564e:	 4c899c24202d0000     	mov	qword ptr [rsp + 0x2d20], r11
+;; 5656:	 4c899c24182d0000     	mov	qword ptr [rsp + 0x2d18], r11
+;; 565e:	 4c899c24102d0000     	mov	qword ptr [rsp + 0x2d10], r11
+;; 5666:	 4c899c24082d0000     	mov	qword ptr [rsp + 0x2d08], r11
+;; 566e:	 4c899c24002d0000     	mov	qword ptr [rsp + 0x2d00], r11
+;; 5676:	 4c899c24f82c0000     	mov	qword ptr [rsp + 0x2cf8], r11
+;; 567e:	 4c899c24f02c0000     	mov	qword ptr [rsp + 0x2cf0], r11
+;; 5686:	 4c899c24e82c0000     	mov	qword ptr [rsp + 0x2ce8], r11
+;; 568e:	 4c899c24e02c0000     	mov	qword ptr [rsp + 0x2ce0], r11
+;; 5696:	 4c899c24d82c0000     	mov	qword ptr [rsp + 0x2cd8], r11
+;; 569e:	 4c899c24d02c0000     	mov	qword ptr [rsp + 0x2cd0], r11
+;; 56a6:	 4c899c24c82c0000     	mov	qword ptr [rsp + 0x2cc8], r11
+;; 56ae:	 4c899c24c02c0000     	mov	qword ptr [rsp + 0x2cc0], r11
+;; 56b6:	 4c899c24b82c0000     	mov	qword ptr [rsp + 0x2cb8], r11
+;; 56be:	 4c899c24b02c0000     	mov	qword ptr [rsp + 0x2cb0], r11
+;; 56c6:	 4c899c24a82c0000     	mov	qword ptr [rsp + 0x2ca8], r11
+;; 56ce:	 4c899c24a02c0000     	mov	qword ptr [rsp + 0x2ca0], r11
+;; 56d6:	 4c899c24982c0000     	mov	qword ptr [rsp + 0x2c98], r11
+;; 56de:	 4c899c24902c0000     	mov	qword ptr [rsp + 0x2c90], r11
+;; 56e6:	 4c899c24882c0000     	mov	qword ptr [rsp + 0x2c88], r11
+;; 56ee:	 4c899c24802c0000     	mov	qword ptr [rsp + 0x2c80], r11
+;; 56f6:	 4c899c24782c0000     	mov	qword ptr [rsp + 0x2c78], r11
+;; 56fe:	 4c899c24702c0000     	mov	qword ptr [rsp + 0x2c70], r11
+;; 5706:	 4c899c24682c0000     	mov	qword ptr [rsp + 0x2c68], r11
+;; 570e:	 4c899c24602c0000     	mov	qword ptr [rsp + 0x2c60], r11
+;; 5716:	 4c899c24582c0000     	mov	qword ptr [rsp + 0x2c58], r11
+;; 571e:	 4c899c24502c0000     	mov	qword ptr [rsp + 0x2c50], r11
+;; 5726:	 4c899c24482c0000     	mov	qword ptr [rsp + 0x2c48], r11
+;; 572e:	 4c899c24402c0000     	mov	qword ptr [rsp + 0x2c40], r11
+;; 5736:	 4c899c24382c0000     	mov	qword ptr [rsp + 0x2c38], r11
+;; 573e:	 4c899c24302c0000     	mov	qword ptr [rsp + 0x2c30], r11
+;; 5746:	 4c899c24282c0000     	mov	qword ptr [rsp + 0x2c28], r11
+;; 574e:	 4c899c24202c0000     	mov	qword ptr [rsp + 0x2c20], r11
+;; 5756:	 4c899c24182c0000     	mov	qword ptr [rsp + 0x2c18], r11
+;; 575e:	 4c899c24102c0000     	mov	qword ptr [rsp + 0x2c10], r11
+;; 5766:	 4c899c24082c0000     	mov	qword ptr [rsp + 0x2c08], r11
+;; 576e:	 4c899c24002c0000     	mov	qword ptr [rsp + 0x2c00], r11
+;; 5776:	 4c899c24f82b0000     	mov	qword ptr [rsp + 0x2bf8], r11
+;; 577e:	 4c899c24f02b0000     	mov	qword ptr [rsp + 0x2bf0], r11
+;; 5786:	 4c899c24e82b0000     	mov	qword ptr [rsp + 0x2be8], r11
+;; 578e:	 4c899c24e02b0000     	mov	qword ptr [rsp + 0x2be0], r11
+;; 5796:	 4c899c24d82b0000     	mov	qword ptr [rsp + 0x2bd8], r11
+;; 579e:	 4c899c24d02b0000     	mov	qword ptr [rsp + 0x2bd0], r11
+;; 57a6:	 4c899c24c82b0000     	mov	qword ptr [rsp + 0x2bc8], r11
+;; 57ae:	 4c899c24c02b0000     	mov	qword ptr [rsp + 0x2bc0], r11
+;; 57b6:	 4c899c24b82b0000     	mov	qword ptr [rsp + 0x2bb8], r11
+;; 57be:	 4c899c24b02b0000     	mov	qword ptr [rsp + 0x2bb0], r11
+;; 57c6:	 4c899c24a82b0000     	mov	qword ptr [rsp + 0x2ba8], r11
+;; 57ce:	 4c899c24a02b0000     	mov	qword ptr [rsp + 0x2ba0], r11
+;; 57d6:	 4c899c24982b0000     	mov	qword ptr [rsp + 0x2b98], r11
+;; 57de:	 4c899c24902b0000     	mov	qword ptr [rsp + 0x2b90], r11
+;; 57e6:	 4c899c24882b0000     	mov	qword ptr [rsp + 0x2b88], r11
+;; 57ee:	 4c899c24802b0000     	mov	qword ptr [rsp + 0x2b80], r11
+;; 57f6:	 4c899c24782b0000     	mov	qword ptr [rsp + 0x2b78], r11
+;; 57fe:	 4c899c24702b0000     	mov	qword ptr [rsp + 0x2b70], r11
+;; 5806:	 4c899c24682b0000     	mov	qword ptr [rsp + 0x2b68], r11
+;; 580e:	 4c899c24602b0000     	mov	qword ptr [rsp + 0x2b60], r11
+;; 5816:	 4c899c24582b0000     	mov	qword ptr [rsp + 0x2b58], r11
+;; 581e:	 4c899c24502b0000     	mov	qword ptr [rsp + 0x2b50], r11
+;; 5826:	 4c899c24482b0000     	mov	qword ptr [rsp + 0x2b48], r11
+;; 582e:	 4c899c24402b0000     	mov	qword ptr [rsp + 0x2b40], r11
+;; 5836:	 4c899c24382b0000     	mov	qword ptr [rsp + 0x2b38], r11
+;; 583e:	 4c899c24302b0000     	mov	qword ptr [rsp + 0x2b30], r11
+;; 5846:	 4c899c24282b0000     	mov	qword ptr [rsp + 0x2b28], r11
+;; 584e:	 4c899c24202b0000     	mov	qword ptr [rsp + 0x2b20], r11
+;; 5856:	 4c899c24182b0000     	mov	qword ptr [rsp + 0x2b18], r11
+;; 585e:	 4c899c24102b0000     	mov	qword ptr [rsp + 0x2b10], r11
+;; 5866:	 4c899c24082b0000     	mov	qword ptr [rsp + 0x2b08], r11
+;; 586e:	 4c899c24002b0000     	mov	qword ptr [rsp + 0x2b00], r11
+;; 5876:	 4c899c24f82a0000     	mov	qword ptr [rsp + 0x2af8], r11
+;; 587e:	 4c899c24f02a0000     	mov	qword ptr [rsp + 0x2af0], r11
+;; 5886:	 4c899c24e82a0000     	mov	qword ptr [rsp + 0x2ae8], r11
+;; 588e:	 4c899c24e02a0000     	mov	qword ptr [rsp + 0x2ae0], r11
+;; 5896:	 4c899c24d82a0000     	mov	qword ptr [rsp + 0x2ad8], r11
+;; 589e:	 4c899c24d02a0000     	mov	qword ptr [rsp + 0x2ad0], r11
+;; 58a6:	 4c899c24c82a0000     	mov	qword ptr [rsp + 0x2ac8], r11
+;; 58ae:	 4c899c24c02a0000     	mov	qword ptr [rsp + 0x2ac0], r11
+;; 58b6:	 4c899c24b82a0000     	mov	qword ptr [rsp + 0x2ab8], r11
+;; 58be:	 4c899c24b02a0000     	mov	qword ptr [rsp + 0x2ab0], r11
+;; 58c6:	 4c899c24a82a0000     	mov	qword ptr [rsp + 0x2aa8], r11
+;; 58ce:	 4c899c24a02a0000     	mov	qword ptr [rsp + 0x2aa0], r11
+;; 58d6:	 4c899c24982a0000     	mov	qword ptr [rsp + 0x2a98], r11
+;; 58de:	 4c899c24902a0000     	mov	qword ptr [rsp + 0x2a90], r11
+;; 58e6:	 4c899c24882a0000     	mov	qword ptr [rsp + 0x2a88], r11
+;; 58ee:	 4c899c24802a0000     	mov	qword ptr [rsp + 0x2a80], r11
+;; 58f6:	 4c899c24782a0000     	mov	qword ptr [rsp + 0x2a78], r11
+;; 58fe:	 4c899c24702a0000     	mov	qword ptr [rsp + 0x2a70], r11
+;; 5906:	 4c899c24682a0000     	mov	qword ptr [rsp + 0x2a68], r11
+;; 590e:	 4c899c24602a0000     	mov	qword ptr [rsp + 0x2a60], r11
+;; 5916:	 4c899c24582a0000     	mov	qword ptr [rsp + 0x2a58], r11
+;; 591e:	 4c899c24502a0000     	mov	qword ptr [rsp + 0x2a50], r11
+;; 5926:	 4c899c24482a0000     	mov	qword ptr [rsp + 0x2a48], r11
+;; 592e:	 4c899c24402a0000     	mov	qword ptr [rsp + 0x2a40], r11
+;; 5936:	 4c899c24382a0000     	mov	qword ptr [rsp + 0x2a38], r11
+;; 593e:	 4c899c24302a0000     	mov	qword ptr [rsp + 0x2a30], r11
+;; 5946:	 4c899c24282a0000     	mov	qword ptr [rsp + 0x2a28], r11
+;; 594e:	 4c899c24202a0000     	mov	qword ptr [rsp + 0x2a20], r11
+;; 5956:	 4c899c24182a0000     	mov	qword ptr [rsp + 0x2a18], r11
+;; 595e:	 4c899c24102a0000     	mov	qword ptr [rsp + 0x2a10], r11
+;; 5966:	 4c899c24082a0000     	mov	qword ptr [rsp + 0x2a08], r11
+;; 596e:	 4c899c24002a0000     	mov	qword ptr [rsp + 0x2a00], r11
+;; 5976:	 4c899c24f8290000     	mov	qword ptr [rsp + 0x29f8], r11
+;; 597e:	 4c899c24f0290000     	mov	qword ptr [rsp + 0x29f0], r11
+;; 5986:	 4c899c24e8290000     	mov	qword ptr [rsp + 0x29e8], r11
+;; 598e:	 4c899c24e0290000     	mov	qword ptr [rsp + 0x29e0], r11
+;; 5996:	 4c899c24d8290000     	mov	qword ptr [rsp + 0x29d8], r11
+;; 599e:	 4c899c24d0290000     	mov	qword ptr [rsp + 0x29d0], r11
+;; 59a6:	 4c899c24c8290000     	mov	qword ptr [rsp + 0x29c8], r11
+;; 59ae:	 4c899c24c0290000     	mov	qword ptr [rsp + 0x29c0], r11
+;; 59b6:	 4c899c24b8290000     	mov	qword ptr [rsp + 0x29b8], r11
+;; 59be:	 4c899c24b0290000     	mov	qword ptr [rsp + 0x29b0], r11
+;; 59c6:	 4c899c24a8290000     	mov	qword ptr [rsp + 0x29a8], r11
+;; 59ce:	 4c899c24a0290000     	mov	qword ptr [rsp + 0x29a0], r11
+;; 59d6:	 4c899c2498290000     	mov	qword ptr [rsp + 0x2998], r11
+;; 59de:	 4c899c2490290000     	mov	qword ptr [rsp + 0x2990], r11
+;; 59e6:	 4c899c2488290000     	mov	qword ptr [rsp + 0x2988], r11
+;; 59ee:	 4c899c2480290000     	mov	qword ptr [rsp + 0x2980], r11
+;; 59f6:	 4c899c2478290000     	mov	qword ptr [rsp + 0x2978], r11
+;; 59fe:	 4c899c2470290000     	mov	qword ptr [rsp + 0x2970], r11
+;; 5a06:	 4c899c2468290000     	mov	qword ptr [rsp + 0x2968], r11
+;; 5a0e:	 4c899c2460290000     	mov	qword ptr [rsp + 0x2960], r11
+;; 5a16:	 4c899c2458290000     	mov	qword ptr [rsp + 0x2958], r11
+;; 5a1e:	 4c899c2450290000     	mov	qword ptr [rsp + 0x2950], r11
+;; 5a26:	 4c899c2448290000     	mov	qword ptr [rsp + 0x2948], r11
+;; 5a2e:	 4c899c2440290000     	mov	qword ptr [rsp + 0x2940], r11
+;; 5a36:	 4c899c2438290000     	mov	qword ptr [rsp + 0x2938], r11
+;; 5a3e:	 4c899c2430290000     	mov	qword ptr [rsp + 0x2930], r11
+;; 5a46:	 4c899c2428290000     	mov	qword ptr [rsp + 0x2928], r11
+;; 5a4e:	 4c899c2420290000     	mov	qword ptr [rsp + 0x2920], r11
+;; 5a56:	 4c899c2418290000     	mov	qword ptr [rsp + 0x2918], r11
+;; 5a5e:	 4c899c2410290000     	mov	qword ptr [rsp + 0x2910], r11
+;; 5a66:	 4c899c2408290000     	mov	qword ptr [rsp + 0x2908], r11
+;; 5a6e:	 4c899c2400290000     	mov	qword ptr [rsp + 0x2900], r11
+;; 5a76:	 4c899c24f8280000     	mov	qword ptr [rsp + 0x28f8], r11
+;; 5a7e:	 4c899c24f0280000     	mov	qword ptr [rsp + 0x28f0], r11
+;; 5a86:	 4c899c24e8280000     	mov	qword ptr [rsp + 0x28e8], r11
+;; 5a8e:	 4c899c24e0280000     	mov	qword ptr [rsp + 0x28e0], r11
+;; 5a96:	 4c899c24d8280000     	mov	qword ptr [rsp + 0x28d8], r11
+;; 5a9e:	 4c899c24d0280000     	mov	qword ptr [rsp + 0x28d0], r11
+;; 5aa6:	 4c899c24c8280000     	mov	qword ptr [rsp + 0x28c8], r11
+;; 5aae:	 4c899c24c0280000     	mov	qword ptr [rsp + 0x28c0], r11
+;; 5ab6:	 4c899c24b8280000     	mov	qword ptr [rsp + 0x28b8], r11
+;; 5abe:	 4c899c24b0280000     	mov	qword ptr [rsp + 0x28b0], r11
+;; 5ac6:	 4c899c24a8280000     	mov	qword ptr [rsp + 0x28a8], r11
+;; 5ace:	 4c899c24a0280000     	mov	qword ptr [rsp + 0x28a0], r11
+;; 5ad6:	 4c899c2498280000     	mov	qword ptr [rsp + 0x2898], r11
+;; 5ade:	 4c899c2490280000     	mov	qword ptr [rsp + 0x2890], r11
+;; 5ae6:	 4c899c2488280000     	mov	qword ptr [rsp + 0x2888], r11
+;; 5aee:	 4c899c2480280000     	mov	qword ptr [rsp + 0x2880], r11
+;; 5af6:	 4c899c2478280000     	mov	qword ptr [rsp + 0x2878], r11
+;; 5afe:	 4c899c2470280000     	mov	qword ptr [rsp + 0x2870], r11
+;; 5b06:	 4c899c2468280000     	mov	qword ptr [rsp + 0x2868], r11
+;; 5b0e:	 4c899c2460280000     	mov	qword ptr [rsp + 0x2860], r11
+;; 5b16:	 4c899c2458280000     	mov	qword ptr [rsp + 0x2858], r11
+;; 5b1e:	 4c899c2450280000     	mov	qword ptr [rsp + 0x2850], r11
+;; 5b26:	 4c899c2448280000     	mov	qword ptr [rsp + 0x2848], r11
+;; 5b2e:	 4c899c2440280000     	mov	qword ptr [rsp + 0x2840], r11
+;; 5b36:	 4c899c2438280000     	mov	qword ptr [rsp + 0x2838], r11
+;; 5b3e:	 4c899c2430280000     	mov	qword ptr [rsp + 0x2830], r11
+;; 5b46:	 4c899c2428280000     	mov	qword ptr [rsp + 0x2828], r11
+;; 5b4e:	 4c899c2420280000     	mov	qword ptr [rsp + 0x2820], r11
+;; 5b56:	 4c899c2418280000     	mov	qword ptr [rsp + 0x2818], r11
+;; 5b5e:	 4c899c2410280000     	mov	qword ptr [rsp + 0x2810], r11
+;; 5b66:	 4c899c2408280000     	mov	qword ptr [rsp + 0x2808], r11
+;; 5b6e:	 4c899c2400280000     	mov	qword ptr [rsp + 0x2800], r11
+;; 5b76:	 4c899c24f8270000     	mov	qword ptr [rsp + 0x27f8], r11
+;; 5b7e:	 4c899c24f0270000     	mov	qword ptr [rsp + 0x27f0], r11
+;; 5b86:	 4c899c24e8270000     	mov	qword ptr [rsp + 0x27e8], r11
+;; 5b8e:	 4c899c24e0270000     	mov	qword ptr [rsp + 0x27e0], r11
+;; 5b96:	 4c899c24d8270000     	mov	qword ptr [rsp + 0x27d8], r11
+;; 5b9e:	 4c899c24d0270000     	mov	qword ptr [rsp + 0x27d0], r11
+;; 5ba6:	 4c899c24c8270000     	mov	qword ptr [rsp + 0x27c8], r11
+;; 5bae:	 4c899c24c0270000     	mov	qword ptr [rsp + 0x27c0], r11
+;; 5bb6:	 4c899c24b8270000     	mov	qword ptr [rsp + 0x27b8], r11
+;; 5bbe:	 4c899c24b0270000     	mov	qword ptr [rsp + 0x27b0], r11
+;; 5bc6:	 4c899c24a8270000     	mov	qword ptr [rsp + 0x27a8], r11
+;; 5bce:	 4c899c24a0270000     	mov	qword ptr [rsp + 0x27a0], r11
+;; 5bd6:	 4c899c2498270000     	mov	qword ptr [rsp + 0x2798], r11
+;; 5bde:	 4c899c2490270000     	mov	qword ptr [rsp + 0x2790], r11
+;; 5be6:	 4c899c2488270000     	mov	qword ptr [rsp + 0x2788], r11
+;; 5bee:	 4c899c2480270000     	mov	qword ptr [rsp + 0x2780], r11
+;; 5bf6:	 4c899c2478270000     	mov	qword ptr [rsp + 0x2778], r11
+;; 5bfe:	 4c899c2470270000     	mov	qword ptr [rsp + 0x2770], r11
+;; 5c06:	 4c899c2468270000     	mov	qword ptr [rsp + 0x2768], r11
+;; 5c0e:	 4c899c2460270000     	mov	qword ptr [rsp + 0x2760], r11
+;; 5c16:	 4c899c2458270000     	mov	qword ptr [rsp + 0x2758], r11
+;; 5c1e:	 4c899c2450270000     	mov	qword ptr [rsp + 0x2750], r11
+;; 5c26:	 4c899c2448270000     	mov	qword ptr [rsp + 0x2748], r11
+;; 5c2e:	 4c899c2440270000     	mov	qword ptr [rsp + 0x2740], r11
+;; 5c36:	 4c899c2438270000     	mov	qword ptr [rsp + 0x2738], r11
+;; 5c3e:	 4c899c2430270000     	mov	qword ptr [rsp + 0x2730], r11
+;; 5c46:	 4c899c2428270000     	mov	qword ptr [rsp + 0x2728], r11
+;; 5c4e:	 4c899c2420270000     	mov	qword ptr [rsp + 0x2720], r11
+;; 5c56:	 4c899c2418270000     	mov	qword ptr [rsp + 0x2718], r11
+;; 5c5e:	 4c899c2410270000     	mov	qword ptr [rsp + 0x2710], r11
+;; 5c66:	 4c899c2408270000     	mov	qword ptr [rsp + 0x2708], r11
+;; 5c6e:	 4c899c2400270000     	mov	qword ptr [rsp + 0x2700], r11
+;; 5c76:	 4c899c24f8260000     	mov	qword ptr [rsp + 0x26f8], r11
+;; 5c7e:	 4c899c24f0260000     	mov	qword ptr [rsp + 0x26f0], r11
+;; 5c86:	 4c899c24e8260000     	mov	qword ptr [rsp + 0x26e8], r11
+;; 5c8e:	 4c899c24e0260000     	mov	qword ptr [rsp + 0x26e0], r11
+;; 5c96:	 4c899c24d8260000     	mov	qword ptr [rsp + 0x26d8], r11
+;; 5c9e:	 4c899c24d0260000     	mov	qword ptr [rsp + 0x26d0], r11
+;; 5ca6:	 4c899c24c8260000     	mov	qword ptr [rsp + 0x26c8], r11
+;; 5cae:	 4c899c24c0260000     	mov	qword ptr [rsp + 0x26c0], r11
+;; 5cb6:	 4c899c24b8260000     	mov	qword ptr [rsp + 0x26b8], r11
+;; 5cbe:	 4c899c24b0260000     	mov	qword ptr [rsp + 0x26b0], r11
+;; 5cc6:	 4c899c24a8260000     	mov	qword ptr [rsp + 0x26a8], r11
+;; 5cce:	 4c899c24a0260000     	mov	qword ptr [rsp + 0x26a0], r11
+;; 5cd6:	 4c899c2498260000     	mov	qword ptr [rsp + 0x2698], r11
+;; 5cde:	 4c899c2490260000     	mov	qword ptr [rsp + 0x2690], r11
+;; 5ce6:	 4c899c2488260000     	mov	qword ptr [rsp + 0x2688], r11
+;; 5cee:	 4c899c2480260000     	mov	qword ptr [rsp + 0x2680], r11
+;; 5cf6:	 4c899c2478260000     	mov	qword ptr [rsp + 0x2678], r11
+;; 5cfe:	 4c899c2470260000     	mov	qword ptr [rsp + 0x2670], r11
+;; 5d06:	 4c899c2468260000     	mov	qword ptr [rsp + 0x2668], r11
+;; 5d0e:	 4c899c2460260000     	mov	qword ptr [rsp + 0x2660], r11
+;; 5d16:	 4c899c2458260000     	mov	qword ptr [rsp + 0x2658], r11
+;; 5d1e:	 4c899c2450260000     	mov	qword ptr [rsp + 0x2650], r11
+;; 5d26:	 4c899c2448260000     	mov	qword ptr [rsp + 0x2648], r11
+;; 5d2e:	 4c899c2440260000     	mov	qword ptr [rsp + 0x2640], r11
+;; 5d36:	 4c899c2438260000     	mov	qword ptr [rsp + 0x2638], r11
+;; 5d3e:	 4c899c2430260000     	mov	qword ptr [rsp + 0x2630], r11
+;; 5d46:	 4c899c2428260000     	mov	qword ptr [rsp + 0x2628], r11
+;; 5d4e:	 4c899c2420260000     	mov	qword ptr [rsp + 0x2620], r11
+;; 5d56:	 4c899c2418260000     	mov	qword ptr [rsp + 0x2618], r11
+;; 5d5e:	 4c899c2410260000     	mov	qword ptr [rsp + 0x2610], r11
+;; 5d66:	 4c899c2408260000     	mov	qword ptr [rsp + 0x2608], r11
+;; 5d6e:	 4c899c2400260000     	mov	qword ptr [rsp + 0x2600], r11
+;; 5d76:	 4c899c24f8250000     	mov	qword ptr [rsp + 0x25f8], r11
+;; 5d7e:	 4c899c24f0250000     	mov	qword ptr [rsp + 0x25f0], r11
+;; 5d86:	 4c899c24e8250000     	mov	qword ptr [rsp + 0x25e8], r11
+;; 5d8e:	 4c899c24e0250000     	mov	qword ptr [rsp + 0x25e0], r11
+;; 5d96:	 4c899c24d8250000     	mov	qword ptr [rsp + 0x25d8], r11
+;; 5d9e:	 4c899c24d0250000     	mov	qword ptr [rsp + 0x25d0], r11
+;; 5da6:	 4c899c24c8250000     	mov	qword ptr [rsp + 0x25c8], r11
+;; 5dae:	 4c899c24c0250000     	mov	qword ptr [rsp + 0x25c0], r11
+;; 5db6:	 4c899c24b8250000     	mov	qword ptr [rsp + 0x25b8], r11
+;; 5dbe:	 4c899c24b0250000     	mov	qword ptr [rsp + 0x25b0], r11
+;; 5dc6:	 4c899c24a8250000     	mov	qword ptr [rsp + 0x25a8], r11
+;; 5dce:	 4c899c24a0250000     	mov	qword ptr [rsp + 0x25a0], r11
+;; 5dd6:	 4c899c2498250000     	mov	qword ptr [rsp + 0x2598], r11
+;; 5dde:	 4c899c2490250000     	mov	qword ptr [rsp + 0x2590], r11
+;; 5de6:	 4c899c2488250000     	mov	qword ptr [rsp + 0x2588], r11
+;; 5dee:	 4c899c2480250000     	mov	qword ptr [rsp + 0x2580], r11
+;; 5df6:	 4c899c2478250000     	mov	qword ptr [rsp + 0x2578], r11
+;; 5dfe:	 4c899c2470250000     	mov	qword ptr [rsp + 0x2570], r11
+;; 5e06:	 4c899c2468250000     	mov	qword ptr [rsp + 0x2568], r11
+;; 5e0e:	 4c899c2460250000     	mov	qword ptr [rsp + 0x2560], r11
+;; 5e16:	 4c899c2458250000     	mov	qword ptr [rsp + 0x2558], r11
+;; 5e1e:	 4c899c2450250000     	mov	qword ptr [rsp + 0x2550], r11
+;; 5e26:	 4c899c2448250000     	mov	qword ptr [rsp + 0x2548], r11
+;; 5e2e:	 4c899c2440250000     	mov	qword ptr [rsp + 0x2540], r11
+;; 5e36:	 4c899c2438250000     	mov	qword ptr [rsp + 0x2538], r11
+;; 5e3e:	 4c899c2430250000     	mov	qword ptr [rsp + 0x2530], r11
+;; 5e46:	 4c899c2428250000     	mov	qword ptr [rsp + 0x2528], r11
+;; 5e4e:	 4c899c2420250000     	mov	qword ptr [rsp + 0x2520], r11
+;; 5e56:	 4c899c2418250000     	mov	qword ptr [rsp + 0x2518], r11
+;; 5e5e:	 4c899c2410250000     	mov	qword ptr [rsp + 0x2510], r11
+;; 5e66:	 4c899c2408250000     	mov	qword ptr [rsp + 0x2508], r11
+;; 5e6e:	 4c899c2400250000     	mov	qword ptr [rsp + 0x2500], r11
+;; 5e76:	 4c899c24f8240000     	mov	qword ptr [rsp + 0x24f8], r11
+;; 5e7e:	 4c899c24f0240000     	mov	qword ptr [rsp + 0x24f0], r11
+;; 5e86:	 4c899c24e8240000     	mov	qword ptr [rsp + 0x24e8], r11
+;; 5e8e:	 4c899c24e0240000     	mov	qword ptr [rsp + 0x24e0], r11
+;; 5e96:	 4c899c24d8240000     	mov	qword ptr [rsp + 0x24d8], r11
+;; 5e9e:	 4c899c24d0240000     	mov	qword ptr [rsp + 0x24d0], r11
+;; 5ea6:	 4c899c24c8240000     	mov	qword ptr [rsp + 0x24c8], r11
+;; 5eae:	 4c899c24c0240000     	mov	qword ptr [rsp + 0x24c0], r11
+;; 5eb6:	 4c899c24b8240000     	mov	qword ptr [rsp + 0x24b8], r11
+;; 5ebe:	 4c899c24b0240000     	mov	qword ptr [rsp + 0x24b0], r11
+;; 5ec6:	 4c899c24a8240000     	mov	qword ptr [rsp + 0x24a8], r11
+;; 5ece:	 4c899c24a0240000     	mov	qword ptr [rsp + 0x24a0], r11
+;; 5ed6:	 4c899c2498240000     	mov	qword ptr [rsp + 0x2498], r11
+;; 5ede:	 4c899c2490240000     	mov	qword ptr [rsp + 0x2490], r11
+;; 5ee6:	 4c899c2488240000     	mov	qword ptr [rsp + 0x2488], r11
+;; 5eee:	 4c899c2480240000     	mov	qword ptr [rsp + 0x2480], r11
+;; 5ef6:	 4c899c2478240000     	mov	qword ptr [rsp + 0x2478], r11
+;; 5efe:	 4c899c2470240000     	mov	qword ptr [rsp + 0x2470], r11
+;; 5f06:	 4c899c2468240000     	mov	qword ptr [rsp + 0x2468], r11
+;; 5f0e:	 4c899c2460240000     	mov	qword ptr [rsp + 0x2460], r11
+;; 5f16:	 4c899c2458240000     	mov	qword ptr [rsp + 0x2458], r11
+;; 5f1e:	 4c899c2450240000     	mov	qword ptr [rsp + 0x2450], r11
+;; 5f26:	 4c899c2448240000     	mov	qword ptr [rsp + 0x2448], r11
+;; 5f2e:	 4c899c2440240000     	mov	qword ptr [rsp + 0x2440], r11
+;; 5f36:	 4c899c2438240000     	mov	qword ptr [rsp + 0x2438], r11
+;; 5f3e:	 4c899c2430240000     	mov	qword ptr [rsp + 0x2430], r11
+;; 5f46:	 4c899c2428240000     	mov	qword ptr [rsp + 0x2428], r11
+;; 5f4e:	 4c899c2420240000     	mov	qword ptr [rsp + 0x2420], r11
+;; 5f56:	 4c899c2418240000     	mov	qword ptr [rsp + 0x2418], r11
+;; 5f5e:	 4c899c2410240000     	mov	qword ptr [rsp + 0x2410], r11
+;; 5f66:	 4c899c2408240000     	mov	qword ptr [rsp + 0x2408], r11
+;; 5f6e:	 4c899c2400240000     	mov	qword ptr [rsp + 0x2400], r11
+;; 5f76:	 4c899c24f8230000     	mov	qword ptr [rsp + 0x23f8], r11
+;; 5f7e:	 4c899c24f0230000     	mov	qword ptr [rsp + 0x23f0], r11
+;; 5f86:	 4c899c24e8230000     	mov	qword ptr [rsp + 0x23e8], r11
+;; 5f8e:	 4c899c24e0230000     	mov	qword ptr [rsp + 0x23e0], r11
+;; 5f96:	 4c899c24d8230000     	mov	qword ptr [rsp + 0x23d8], r11
+;; 5f9e:	 4c899c24d0230000     	mov	qword ptr [rsp + 0x23d0], r11
+;; 5fa6:	 4c899c24c8230000     	mov	qword ptr [rsp + 0x23c8], r11
+;; 5fae:	 4c899c24c0230000     	mov	qword ptr [rsp + 0x23c0], r11
+;; 5fb6:	 4c899c24b8230000     	mov	qword ptr [rsp + 0x23b8], r11
+;; 5fbe:	 4c899c24b0230000     	mov	qword ptr [rsp + 0x23b0], r11
+;; 5fc6:	 4c899c24a8230000     	mov	qword ptr [rsp + 0x23a8], r11
+;; 5fce:	 4c899c24a0230000     	mov	qword ptr [rsp + 0x23a0], r11
+;; 5fd6:	 4c899c2498230000     	mov	qword ptr [rsp + 0x2398], r11
+;; 5fde:	 4c899c2490230000     	mov	qword ptr [rsp + 0x2390], r11
+;; 5fe6:	 4c899c2488230000     	mov	qword ptr [rsp + 0x2388], r11
+;; 5fee:	 4c899c2480230000     	mov	qword ptr [rsp + 0x2380], r11
+;; 5ff6:	 4c899c2478230000     	mov	qword ptr [rsp + 0x2378], r11
+;; 5ffe:	 4c899c2470230000     	mov	qword ptr [rsp + 0x2370], r11
+;; 6006:	 4c899c2468230000     	mov	qword ptr [rsp + 0x2368], r11
+;; 600e:	 4c899c2460230000     	mov	qword ptr [rsp + 0x2360], r11
+;; 6016:	 4c899c2458230000     	mov	qword ptr [rsp + 0x2358], r11
+;; 601e:	 4c899c2450230000     	mov	qword ptr [rsp + 0x2350], r11
+;; 6026:	 4c899c2448230000     	mov	qword ptr [rsp + 0x2348], r11
+;; 602e:	 4c899c2440230000     	mov	qword ptr [rsp + 0x2340], r11
+;; 6036:	 4c899c2438230000     	mov	qword ptr [rsp + 0x2338], r11
+;; 603e:	 4c899c2430230000     	mov	qword ptr [rsp + 0x2330], r11
+;; 6046:	 4c899c2428230000     	mov	qword ptr [rsp + 0x2328], r11
+;; 604e:	 4c899c2420230000     	mov	qword ptr [rsp + 0x2320], r11
+;; 6056:	 4c899c2418230000     	mov	qword ptr [rsp + 0x2318], r11
+;; 605e:	 4c899c2410230000     	mov	qword ptr [rsp + 0x2310], r11
+;; 6066:	 4c899c2408230000     	mov	qword ptr [rsp + 0x2308], r11
+;; 606e:	 4c899c2400230000     	mov	qword ptr [rsp + 0x2300], r11
+;; 6076:	 4c899c24f8220000     	mov	qword ptr [rsp + 0x22f8], r11
+;; 607e:	 4c899c24f0220000     	mov	qword ptr [rsp + 0x22f0], r11
+;; 6086:	 4c899c24e8220000     	mov	qword ptr [rsp + 0x22e8], r11
+;; 608e:	 4c899c24e0220000     	mov	qword ptr [rsp + 0x22e0], r11
+;; 6096:	 4c899c24d8220000     	mov	qword ptr [rsp + 0x22d8], r11
+;; 609e:	 4c899c24d0220000     	mov	qword ptr [rsp + 0x22d0], r11
+;; 60a6:	 4c899c24c8220000     	mov	qword ptr [rsp + 0x22c8], r11
+;; 60ae:	 4c899c24c0220000     	mov	qword ptr [rsp + 0x22c0], r11
+;; 60b6:	 4c899c24b8220000     	mov	qword ptr [rsp + 0x22b8], r11
+;; 60be:	 4c899c24b0220000     	mov	qword ptr [rsp + 0x22b0], r11
+;; 60c6:	 4c899c24a8220000     	mov	qword ptr [rsp + 0x22a8], r11
+;; 60ce:	 4c899c24a0220000     	mov	qword ptr [rsp + 0x22a0], r11
+;; 60d6:	 4c899c2498220000     	mov	qword ptr [rsp + 0x2298], r11
+;; 60de:	 4c899c2490220000     	mov	qword ptr [rsp + 0x2290], r11
+;; 60e6:	 4c899c2488220000     	mov	qword ptr [rsp + 0x2288], r11
+;; 60ee:	 4c899c2480220000     	mov	qword ptr [rsp + 0x2280], r11
+;; 60f6:	 4c899c2478220000     	mov	qword ptr [rsp + 0x2278], r11
+;; 60fe:	 4c899c2470220000     	mov	qword ptr [rsp + 0x2270], r11
+;; 6106:	 4c899c2468220000     	mov	qword ptr [rsp + 0x2268], r11
+;; 610e:	 4c899c2460220000     	mov	qword ptr [rsp + 0x2260], r11
+;; 6116:	 4c899c2458220000     	mov	qword ptr [rsp + 0x2258], r11
+;; 611e:	 4c899c2450220000     	mov	qword ptr [rsp + 0x2250], r11
+;; 6126:	 4c899c2448220000     	mov	qword ptr [rsp + 0x2248], r11
+;; 612e:	 4c899c2440220000     	mov	qword ptr [rsp + 0x2240], r11
+;; 6136:	 4c899c2438220000     	mov	qword ptr [rsp + 0x2238], r11
+;; 613e:	 4c899c2430220000     	mov	qword ptr [rsp + 0x2230], r11
+;; 6146:	 4c899c2428220000     	mov	qword ptr [rsp + 0x2228], r11
+;; 614e:	 4c899c2420220000     	mov	qword ptr [rsp + 0x2220], r11
+;; 6156:	 4c899c2418220000     	mov	qword ptr [rsp + 0x2218], r11
+;; 615e:	 4c899c2410220000     	mov	qword ptr [rsp + 0x2210], r11
+;; 6166:	 4c899c2408220000     	mov	qword ptr [rsp + 0x2208], r11
+;; 616e:	 4c899c2400220000     	mov	qword ptr [rsp + 0x2200], r11
+;; 6176:	 4c899c24f8210000     	mov	qword ptr [rsp + 0x21f8], r11
+;; 617e:	 4c899c24f0210000     	mov	qword ptr [rsp + 0x21f0], r11
+;; 6186:	 4c899c24e8210000     	mov	qword ptr [rsp + 0x21e8], r11
+;; 618e:	 4c899c24e0210000     	mov	qword ptr [rsp + 0x21e0], r11
+;; 6196:	 4c899c24d8210000     	mov	qword ptr [rsp + 0x21d8], r11
+;; 619e:	 4c899c24d0210000     	mov	qword ptr [rsp + 0x21d0], r11
+;; 61a6:	 4c899c24c8210000     	mov	qword ptr [rsp + 0x21c8], r11
+;; 61ae:	 4c899c24c0210000     	mov	qword ptr [rsp + 0x21c0], r11
+;; 61b6:	 4c899c24b8210000     	mov	qword ptr [rsp + 0x21b8], r11
+;; 61be:	 4c899c24b0210000     	mov	qword ptr [rsp + 0x21b0], r11
+;; 61c6:	 4c899c24a8210000     	mov	qword ptr [rsp + 0x21a8], r11
+;; 61ce:	 4c899c24a0210000     	mov	qword ptr [rsp + 0x21a0], r11
+;; 61d6:	 4c899c2498210000     	mov	qword ptr [rsp + 0x2198], r11
+;; 61de:	 4c899c2490210000     	mov	qword ptr [rsp + 0x2190], r11
+;; 61e6:	 4c899c2488210000     	mov	qword ptr [rsp + 0x2188], r11
+;; 61ee:	 4c899c2480210000     	mov	qword ptr [rsp + 0x2180], r11
+;; 61f6:	 4c899c2478210000     	mov	qword ptr [rsp + 0x2178], r11
+;; 61fe:	 4c899c2470210000     	mov	qword ptr [rsp + 0x2170], r11
+;; 6206:	 4c899c2468210000     	mov	qword ptr [rsp + 0x2168], r11
+;; 620e:	 4c899c2460210000     	mov	qword ptr [rsp + 0x2160], r11
+;; 6216:	 4c899c2458210000     	mov	qword ptr [rsp + 0x2158], r11
+;; 621e:	 4c899c2450210000     	mov	qword ptr [rsp + 0x2150], r11
+;; 6226:	 4c899c2448210000     	mov	qword ptr [rsp + 0x2148], r11
+;; 622e:	 4c899c2440210000     	mov	qword ptr [rsp + 0x2140], r11
+;; 6236:	 4c899c2438210000     	mov	qword ptr [rsp + 0x2138], r11
+;; 623e:	 4c899c2430210000     	mov	qword ptr [rsp + 0x2130], r11
+;; 6246:	 4c899c2428210000     	mov	qword ptr [rsp + 0x2128], r11
+;; 624e:	 4c899c2420210000     	mov	qword ptr [rsp + 0x2120], r11
+;; 6256:	 4c899c2418210000     	mov	qword ptr [rsp + 0x2118], r11
+;; 625e:	 4c899c2410210000     	mov	qword ptr [rsp + 0x2110], r11
+;; 6266:	 4c899c2408210000     	mov	qword ptr [rsp + 0x2108], r11
+;; 626e:	 4c899c2400210000     	mov	qword ptr [rsp + 0x2100], r11
+;; 6276:	 4c899c24f8200000     	mov	qword ptr [rsp + 0x20f8], r11
+;; 627e:	 4c899c24f0200000     	mov	qword ptr [rsp + 0x20f0], r11
+;; 6286:	 4c899c24e8200000     	mov	qword ptr [rsp + 0x20e8], r11
+;; 628e:	 4c899c24e0200000     	mov	qword ptr [rsp + 0x20e0], r11
+;; 6296:	 4c899c24d8200000     	mov	qword ptr [rsp + 0x20d8], r11
+;; 629e:	 4c899c24d0200000     	mov	qword ptr [rsp + 0x20d0], r11
+;; 62a6:	 4c899c24c8200000     	mov	qword ptr [rsp + 0x20c8], r11
+;; 62ae:	 4c899c24c0200000     	mov	qword ptr [rsp + 0x20c0], r11
+;; 62b6:	 4c899c24b8200000     	mov	qword ptr [rsp + 0x20b8], r11
+;; 62be:	 4c899c24b0200000     	mov	qword ptr [rsp + 0x20b0], r11
+;; 62c6:	 4c899c24a8200000     	mov	qword ptr [rsp + 0x20a8], r11
+;; 62ce:	 4c899c24a0200000     	mov	qword ptr [rsp + 0x20a0], r11
+;; 62d6:	 4c899c2498200000     	mov	qword ptr [rsp + 0x2098], r11
+;; 62de:	 4c899c2490200000     	mov	qword ptr [rsp + 0x2090], r11
+;; 62e6:	 4c899c2488200000     	mov	qword ptr [rsp + 0x2088], r11
+;; 62ee:	 4c899c2480200000     	mov	qword ptr [rsp + 0x2080], r11
+;; 62f6:	 4c899c2478200000     	mov	qword ptr [rsp + 0x2078], r11
+;; 62fe:	 4c899c2470200000     	mov	qword ptr [rsp + 0x2070], r11
+;; 6306:	 4c899c2468200000     	mov	qword ptr [rsp + 0x2068], r11
+;; 630e:	 4c899c2460200000     	mov	qword ptr [rsp + 0x2060], r11
+;; 6316:	 4c899c2458200000     	mov	qword ptr [rsp + 0x2058], r11
+;; 631e:	 4c899c2450200000     	mov	qword ptr [rsp + 0x2050], r11
+;; 6326:	 4c899c2448200000     	mov	qword ptr [rsp + 0x2048], r11
+;; 632e:	 4c899c2440200000     	mov	qword ptr [rsp + 0x2040], r11
+;; 6336:	 4c899c2438200000     	mov	qword ptr [rsp + 0x2038], r11
+;; 633e:	 4c899c2430200000     	mov	qword ptr [rsp + 0x2030], r11
+;; 6346:	 4c899c2428200000     	mov	qword ptr [rsp + 0x2028], r11
+;; 634e:	 4c899c2420200000     	mov	qword ptr [rsp + 0x2020], r11
+;; 6356:	 4c899c2418200000     	mov	qword ptr [rsp + 0x2018], r11
+;; 635e:	 4c899c2410200000     	mov	qword ptr [rsp + 0x2010], r11
+;; 6366:	 4c899c2408200000     	mov	qword ptr [rsp + 0x2008], r11
+;; 636e:	 4c899c2400200000     	mov	qword ptr [rsp + 0x2000], r11
+;; 6376:	 4c899c24f81f0000     	mov	qword ptr [rsp + 0x1ff8], r11
+;; 637e:	 4c899c24f01f0000     	mov	qword ptr [rsp + 0x1ff0], r11
+;; 6386:	 4c899c24e81f0000     	mov	qword ptr [rsp + 0x1fe8], r11
+;; 638e:	 4c899c24e01f0000     	mov	qword ptr [rsp + 0x1fe0], r11
+;; 6396:	 4c899c24d81f0000     	mov	qword ptr [rsp + 0x1fd8], r11
+;; 639e:	 4c899c24d01f0000     	mov	qword ptr [rsp + 0x1fd0], r11
+;; 63a6:	 4c899c24c81f0000     	mov	qword ptr [rsp + 0x1fc8], r11
+;; 63ae:	 4c899c24c01f0000     	mov	qword ptr [rsp + 0x1fc0], r11
+;; 63b6:	 4c899c24b81f0000     	mov	qword ptr [rsp + 0x1fb8], r11
+;; 63be:	 4c899c24b01f0000     	mov	qword ptr [rsp + 0x1fb0], r11
+;; 63c6:	 4c899c24a81f0000     	mov	qword ptr [rsp + 0x1fa8], r11
+;; 63ce:	 4c899c24a01f0000     	mov	qword ptr [rsp + 0x1fa0], r11
+;; 63d6:	 4c899c24981f0000     	mov	qword ptr [rsp + 0x1f98], r11
+;; 63de:	 4c899c24901f0000     	mov	qword ptr [rsp + 0x1f90], r11
+;; 63e6:	 4c899c24881f0000     	mov	qword ptr [rsp + 0x1f88], r11
+;; 63ee:	 4c899c24801f0000     	mov	qword ptr [rsp + 0x1f80], r11
+;; 63f6:	 4c899c24781f0000     	mov	qword ptr [rsp + 0x1f78], r11
+;; 63fe:	 4c899c24701f0000     	mov	qword ptr [rsp + 0x1f70], r11
+;; 6406:	 4c899c24681f0000     	mov	qword ptr [rsp + 0x1f68], r11
+;; 640e:	 4c899c24601f0000     	mov	qword ptr [rsp + 0x1f60], r11
+;; 6416:	 4c899c24581f0000     	mov	qword ptr [rsp + 0x1f58], r11
+;; 641e:	 4c899c24501f0000     	mov	qword ptr [rsp + 0x1f50], r11
+;; 6426:	 4c899c24481f0000     	mov	qword ptr [rsp + 0x1f48], r11
+;; 642e:	 4c899c24401f0000     	mov	qword ptr [rsp + 0x1f40], r11
+;; 6436:	 4c899c24381f0000     	mov	qword ptr [rsp + 0x1f38], r11
+;; 643e:	 4c899c24301f0000     	mov	qword ptr [rsp + 0x1f30], r11
+;; 6446:	 4c899c24281f0000     	mov	qword ptr [rsp + 0x1f28], r11
+;; 644e:	 4c899c24201f0000     	mov	qword ptr [rsp + 0x1f20], r11
+;; 6456:	 4c899c24181f0000     	mov	qword ptr [rsp + 0x1f18], r11
+;; 645e:	 4c899c24101f0000     	mov	qword ptr [rsp + 0x1f10], r11
+;; 6466:	 4c899c24081f0000     	mov	qword ptr [rsp + 0x1f08], r11
+;; 646e:	 4c899c24001f0000     	mov	qword ptr [rsp + 0x1f00], r11
+;; 6476:	 4c899c24f81e0000     	mov	qword ptr [rsp + 0x1ef8], r11
+;; 647e:	 4c899c24f01e0000     	mov	qword ptr [rsp + 0x1ef0], r11
+;; 6486:	 4c899c24e81e0000     	mov	qword ptr [rsp + 0x1ee8], r11
+;; 648e:	 4c899c24e01e0000     	mov	qword ptr [rsp + 0x1ee0], r11
+;; 6496:	 4c899c24d81e0000     	mov	qword ptr [rsp + 0x1ed8], r11
+;; 649e:	 4c899c24d01e0000     	mov	qword ptr [rsp + 0x1ed0], r11
+;; 64a6:	 4c899c24c81e0000     	mov	qword ptr [rsp + 0x1ec8], r11
+;; 64ae:	 4c899c24c01e0000     	mov	qword ptr [rsp + 0x1ec0], r11
+;; 64b6:	 4c899c24b81e0000     	mov	qword ptr [rsp + 0x1eb8], r11
+;; 64be:	 4c899c24b01e0000     	mov	qword ptr [rsp + 0x1eb0], r11
+;; 64c6:	 4c899c24a81e0000     	mov	qword ptr [rsp + 0x1ea8], r11
+;; 64ce:	 4c899c24a01e0000     	mov	qword ptr [rsp + 0x1ea0], r11
+;; 64d6:	 4c899c24981e0000     	mov	qword ptr [rsp + 0x1e98], r11
+;; 64de:	 4c899c24901e0000     	mov	qword ptr [rsp + 0x1e90], r11
+;; 64e6:	 4c899c24881e0000     	mov	qword ptr [rsp + 0x1e88], r11
+;; 64ee:	 4c899c24801e0000     	mov	qword ptr [rsp + 0x1e80], r11
+;; 64f6:	 4c899c24781e0000     	mov	qword ptr [rsp + 0x1e78], r11
+;; 64fe:	 4c899c24701e0000     	mov	qword ptr [rsp + 0x1e70], r11
+;; 6506:	 4c899c24681e0000     	mov	qword ptr [rsp + 0x1e68], r11
+;; 650e:	 4c899c24601e0000     	mov	qword ptr [rsp + 0x1e60], r11
+;; 6516:	 4c899c24581e0000     	mov	qword ptr [rsp + 0x1e58], r11
+;; 651e:	 4c899c24501e0000     	mov	qword ptr [rsp + 0x1e50], r11
+;; 6526:	 4c899c24481e0000     	mov	qword ptr [rsp + 0x1e48], r11
+;; 652e:	 4c899c24401e0000     	mov	qword ptr [rsp + 0x1e40], r11
+;; 6536:	 4c899c24381e0000     	mov	qword ptr [rsp + 0x1e38], r11
+;; 653e:	 4c899c24301e0000     	mov	qword ptr [rsp + 0x1e30], r11
+;; 6546:	 4c899c24281e0000     	mov	qword ptr [rsp + 0x1e28], r11
+;; 654e:	 4c899c24201e0000     	mov	qword ptr [rsp + 0x1e20], r11
+;; 6556:	 4c899c24181e0000     	mov	qword ptr [rsp + 0x1e18], r11
+;; 655e:	 4c899c24101e0000     	mov	qword ptr [rsp + 0x1e10], r11
+;; 6566:	 4c899c24081e0000     	mov	qword ptr [rsp + 0x1e08], r11
+;; 656e:	 4c899c24001e0000     	mov	qword ptr [rsp + 0x1e00], r11
+;; 6576:	 4c899c24f81d0000     	mov	qword ptr [rsp + 0x1df8], r11
+;; 657e:	 4c899c24f01d0000     	mov	qword ptr [rsp + 0x1df0], r11
+;; 6586:	 4c899c24e81d0000     	mov	qword ptr [rsp + 0x1de8], r11
+;; 658e:	 4c899c24e01d0000     	mov	qword ptr [rsp + 0x1de0], r11
+;; 6596:	 4c899c24d81d0000     	mov	qword ptr [rsp + 0x1dd8], r11
+;; 659e:	 4c899c24d01d0000     	mov	qword ptr [rsp + 0x1dd0], r11
+;; 65a6:	 4c899c24c81d0000     	mov	qword ptr [rsp + 0x1dc8], r11
+;; 65ae:	 4c899c24c01d0000     	mov	qword ptr [rsp + 0x1dc0], r11
+;; 65b6:	 4c899c24b81d0000     	mov	qword ptr [rsp + 0x1db8], r11
+;; 65be:	 4c899c24b01d0000     	mov	qword ptr [rsp + 0x1db0], r11
+;; 65c6:	 4c899c24a81d0000     	mov	qword ptr [rsp + 0x1da8], r11
+;; 65ce:	 4c899c24a01d0000     	mov	qword ptr [rsp + 0x1da0], r11
+;; 65d6:	 4c899c24981d0000     	mov	qword ptr [rsp + 0x1d98], r11
+;; 65de:	 4c899c24901d0000     	mov	qword ptr [rsp + 0x1d90], r11
+;; 65e6:	 4c899c24881d0000     	mov	qword ptr [rsp + 0x1d88], r11
+;; 65ee:	 4c899c24801d0000     	mov	qword ptr [rsp + 0x1d80], r11
+;; 65f6:	 4c899c24781d0000     	mov	qword ptr [rsp + 0x1d78], r11
+;; 65fe:	 4c899c24701d0000     	mov	qword ptr [rsp + 0x1d70], r11
+;; 6606:	 4c899c24681d0000     	mov	qword ptr [rsp + 0x1d68], r11
+;; 660e:	 4c899c24601d0000     	mov	qword ptr [rsp + 0x1d60], r11
+;; 6616:	 4c899c24581d0000     	mov	qword ptr [rsp + 0x1d58], r11
+;; 661e:	 4c899c24501d0000     	mov	qword ptr [rsp + 0x1d50], r11
+;; 6626:	 4c899c24481d0000     	mov	qword ptr [rsp + 0x1d48], r11
+;; 662e:	 4c899c24401d0000     	mov	qword ptr [rsp + 0x1d40], r11
+;; 6636:	 4c899c24381d0000     	mov	qword ptr [rsp + 0x1d38], r11
+;; 663e:	 4c899c24301d0000     	mov	qword ptr [rsp + 0x1d30], r11
+;; 6646:	 4c899c24281d0000     	mov	qword ptr [rsp + 0x1d28], r11
+;; 664e:	 4c899c24201d0000     	mov	qword ptr [rsp + 0x1d20], r11
+;; 6656:	 4c899c24181d0000     	mov	qword ptr [rsp + 0x1d18], r11
+;; 665e:	 4c899c24101d0000     	mov	qword ptr [rsp + 0x1d10], r11
+;; 6666:	 4c899c24081d0000     	mov	qword ptr [rsp + 0x1d08], r11
+;; 666e:	 4c899c24001d0000     	mov	qword ptr [rsp + 0x1d00], r11
+;; 6676:	 4c899c24f81c0000     	mov	qword ptr [rsp + 0x1cf8], r11
+;; 667e:	 4c899c24f01c0000     	mov	qword ptr [rsp + 0x1cf0], r11
+;; 6686:	 4c899c24e81c0000     	mov	qword ptr [rsp + 0x1ce8], r11
+;; 668e:	 4c899c24e01c0000     	mov	qword ptr [rsp + 0x1ce0], r11
+;; 6696:	 4c899c24d81c0000     	mov	qword ptr [rsp + 0x1cd8], r11
+;; 669e:	 4c899c24d01c0000     	mov	qword ptr [rsp + 0x1cd0], r11
+;; 66a6:	 4c899c24c81c0000     	mov	qword ptr [rsp + 0x1cc8], r11
+;; 66ae:	 4c899c24c01c0000     	mov	qword ptr [rsp + 0x1cc0], r11
+;; 66b6:	 4c899c24b81c0000     	mov	qword ptr [rsp + 0x1cb8], r11
+;; 66be:	 4c899c24b01c0000     	mov	qword ptr [rsp + 0x1cb0], r11
+;; 66c6:	 4c899c24a81c0000     	mov	qword ptr [rsp + 0x1ca8], r11
+;; 66ce:	 4c899c24a01c0000     	mov	qword ptr [rsp + 0x1ca0], r11
+;; 66d6:	 4c899c24981c0000     	mov	qword ptr [rsp + 0x1c98], r11
+;; 66de:	 4c899c24901c0000     	mov	qword ptr [rsp + 0x1c90], r11
+;; 66e6:	 4c899c24881c0000     	mov	qword ptr [rsp + 0x1c88], r11
+;; 66ee:	 4c899c24801c0000     	mov	qword ptr [rsp + 0x1c80], r11
+;; 66f6:	 4c899c24781c0000     	mov	qword ptr [rsp + 0x1c78], r11
+;; 66fe:	 4c899c24701c0000     	mov	qword ptr [rsp + 0x1c70], r11
+;; 6706:	 4c899c24681c0000     	mov	qword ptr [rsp + 0x1c68], r11
+;; 670e:	 4c899c24601c0000     	mov	qword ptr [rsp + 0x1c60], r11
+;; 6716:	 4c899c24581c0000     	mov	qword ptr [rsp + 0x1c58], r11
+;; 671e:	 4c899c24501c0000     	mov	qword ptr [rsp + 0x1c50], r11
+;; 6726:	 4c899c24481c0000     	mov	qword ptr [rsp + 0x1c48], r11
+;; 672e:	 4c899c24401c0000     	mov	qword ptr [rsp + 0x1c40], r11
+;; 6736:	 4c899c24381c0000     	mov	qword ptr [rsp + 0x1c38], r11
+;; 673e:	 4c899c24301c0000     	mov	qword ptr [rsp + 0x1c30], r11
+;; 6746:	 4c899c24281c0000     	mov	qword ptr [rsp + 0x1c28], r11
+;; 674e:	 4c899c24201c0000     	mov	qword ptr [rsp + 0x1c20], r11
+;; 6756:	 4c899c24181c0000     	mov	qword ptr [rsp + 0x1c18], r11
+;; 675e:	 4c899c24101c0000     	mov	qword ptr [rsp + 0x1c10], r11
+;; 6766:	 4c899c24081c0000     	mov	qword ptr [rsp + 0x1c08], r11
+;; 676e:	 4c899c24001c0000     	mov	qword ptr [rsp + 0x1c00], r11
+;; 6776:	 4c899c24f81b0000     	mov	qword ptr [rsp + 0x1bf8], r11
+;; 677e:	 4c899c24f01b0000     	mov	qword ptr [rsp + 0x1bf0], r11
+;; 6786:	 4c899c24e81b0000     	mov	qword ptr [rsp + 0x1be8], r11
+;; 678e:	 4c899c24e01b0000     	mov	qword ptr [rsp + 0x1be0], r11
+;; 6796:	 4c899c24d81b0000     	mov	qword ptr [rsp + 0x1bd8], r11
+;; 679e:	 4c899c24d01b0000     	mov	qword ptr [rsp + 0x1bd0], r11
+;; 67a6:	 4c899c24c81b0000     	mov	qword ptr [rsp + 0x1bc8], r11
+;; 67ae:	 4c899c24c01b0000     	mov	qword ptr [rsp + 0x1bc0], r11
+;; 67b6:	 4c899c24b81b0000     	mov	qword ptr [rsp + 0x1bb8], r11
+;; 67be:	 4c899c24b01b0000     	mov	qword ptr [rsp + 0x1bb0], r11
+;; 67c6:	 4c899c24a81b0000     	mov	qword ptr [rsp + 0x1ba8], r11
+;; 67ce:	 4c899c24a01b0000     	mov	qword ptr [rsp + 0x1ba0], r11
+;; 67d6:	 4c899c24981b0000     	mov	qword ptr [rsp + 0x1b98], r11
+;; 67de:	 4c899c24901b0000     	mov	qword ptr [rsp + 0x1b90], r11
+;; 67e6:	 4c899c24881b0000     	mov	qword ptr [rsp + 0x1b88], r11
+;; 67ee:	 4c899c24801b0000     	mov	qword ptr [rsp + 0x1b80], r11
+;; 67f6:	 4c899c24781b0000     	mov	qword ptr [rsp + 0x1b78], r11
+;; 67fe:	 4c899c24701b0000     	mov	qword ptr [rsp + 0x1b70], r11
+;; 6806:	 4c899c24681b0000     	mov	qword ptr [rsp + 0x1b68], r11
+;; 680e:	 4c899c24601b0000     	mov	qword ptr [rsp + 0x1b60], r11
+;; 6816:	 4c899c24581b0000     	mov	qword ptr [rsp + 0x1b58], r11
+;; 681e:	 4c899c24501b0000     	mov	qword ptr [rsp + 0x1b50], r11
+;; 6826:	 4c899c24481b0000     	mov	qword ptr [rsp + 0x1b48], r11
+;; 682e:	 4c899c24401b0000     	mov	qword ptr [rsp + 0x1b40], r11
+;; 6836:	 4c899c24381b0000     	mov	qword ptr [rsp + 0x1b38], r11
+;; 683e:	 4c899c24301b0000     	mov	qword ptr [rsp + 0x1b30], r11
+;; 6846:	 4c899c24281b0000     	mov	qword ptr [rsp + 0x1b28], r11
+;; 684e:	 4c899c24201b0000     	mov	qword ptr [rsp + 0x1b20], r11
+;; 6856:	 4c899c24181b0000     	mov	qword ptr [rsp + 0x1b18], r11
+;; 685e:	 4c899c24101b0000     	mov	qword ptr [rsp + 0x1b10], r11
+;; 6866:	 4c899c24081b0000     	mov	qword ptr [rsp + 0x1b08], r11
+;; 686e:	 4c899c24001b0000     	mov	qword ptr [rsp + 0x1b00], r11
+;; 6876:	 4c899c24f81a0000     	mov	qword ptr [rsp + 0x1af8], r11
+;; 687e:	 4c899c24f01a0000     	mov	qword ptr [rsp + 0x1af0], r11
+;; 6886:	 4c899c24e81a0000     	mov	qword ptr [rsp + 0x1ae8], r11
+;; 688e:	 4c899c24e01a0000     	mov	qword ptr [rsp + 0x1ae0], r11
+;; 6896:	 4c899c24d81a0000     	mov	qword ptr [rsp + 0x1ad8], r11
+;; 689e:	 4c899c24d01a0000     	mov	qword ptr [rsp + 0x1ad0], r11
+;; 68a6:	 4c899c24c81a0000     	mov	qword ptr [rsp + 0x1ac8], r11
+;; 68ae:	 4c899c24c01a0000     	mov	qword ptr [rsp + 0x1ac0], r11
+;; 68b6:	 4c899c24b81a0000     	mov	qword ptr [rsp + 0x1ab8], r11
+;; 68be:	 4c899c24b01a0000     	mov	qword ptr [rsp + 0x1ab0], r11
+;; 68c6:	 4c899c24a81a0000     	mov	qword ptr [rsp + 0x1aa8], r11
+;; 68ce:	 4c899c24a01a0000     	mov	qword ptr [rsp + 0x1aa0], r11
+;; 68d6:	 4c899c24981a0000     	mov	qword ptr [rsp + 0x1a98], r11
+;; 68de:	 4c899c24901a0000     	mov	qword ptr [rsp + 0x1a90], r11
+;; 68e6:	 4c899c24881a0000     	mov	qword ptr [rsp + 0x1a88], r11
+;; 68ee:	 4c899c24801a0000     	mov	qword ptr [rsp + 0x1a80], r11
+;; 68f6:	 4c899c24781a0000     	mov	qword ptr [rsp + 0x1a78], r11
+;; 68fe:	 4c899c24701a0000     	mov	qword ptr [rsp + 0x1a70], r11
+;; 6906:	 4c899c24681a0000     	mov	qword ptr [rsp + 0x1a68], r11
+;; 690e:	 4c899c24601a0000     	mov	qword ptr [rsp + 0x1a60], r11
+;; 6916:	 4c899c24581a0000     	mov	qword ptr [rsp + 0x1a58], r11
+;; 691e:	 4c899c24501a0000     	mov	qword ptr [rsp + 0x1a50], r11
+;; 6926:	 4c899c24481a0000     	mov	qword ptr [rsp + 0x1a48], r11
+;; 692e:	 4c899c24401a0000     	mov	qword ptr [rsp + 0x1a40], r11
+;; 6936:	 4c899c24381a0000     	mov	qword ptr [rsp + 0x1a38], r11
+;; 693e:	 4c899c24301a0000     	mov	qword ptr [rsp + 0x1a30], r11
+;; 6946:	 4c899c24281a0000     	mov	qword ptr [rsp + 0x1a28], r11
+;; 694e:	 4c899c24201a0000     	mov	qword ptr [rsp + 0x1a20], r11
+;; 6956:	 4c899c24181a0000     	mov	qword ptr [rsp + 0x1a18], r11
+;; 695e:	 4c899c24101a0000     	mov	qword ptr [rsp + 0x1a10], r11
+;; 6966:	 4c899c24081a0000     	mov	qword ptr [rsp + 0x1a08], r11
+;; 696e:	 4c899c24001a0000     	mov	qword ptr [rsp + 0x1a00], r11
+;; 6976:	 4c899c24f8190000     	mov	qword ptr [rsp + 0x19f8], r11
+;; 697e:	 4c899c24f0190000     	mov	qword ptr [rsp + 0x19f0], r11
+;; 6986:	 4c899c24e8190000     	mov	qword ptr [rsp + 0x19e8], r11
+;; 698e:	 4c899c24e0190000     	mov	qword ptr [rsp + 0x19e0], r11
+;; 6996:	 4c899c24d8190000     	mov	qword ptr [rsp + 0x19d8], r11
+;; 699e:	 4c899c24d0190000     	mov	qword ptr [rsp + 0x19d0], r11
+;; 69a6:	 4c899c24c8190000     	mov	qword ptr [rsp + 0x19c8], r11
+;; 69ae:	 4c899c24c0190000     	mov	qword ptr [rsp + 0x19c0], r11
+;; 69b6:	 4c899c24b8190000     	mov	qword ptr [rsp + 0x19b8], r11
+;; 69be:	 4c899c24b0190000     	mov	qword ptr [rsp + 0x19b0], r11
+;; 69c6:	 4c899c24a8190000     	mov	qword ptr [rsp + 0x19a8], r11
+;; 69ce:	 4c899c24a0190000     	mov	qword ptr [rsp + 0x19a0], r11
+;; 69d6:	 4c899c2498190000     	mov	qword ptr [rsp + 0x1998], r11
+;; 69de:	 4c899c2490190000     	mov	qword ptr [rsp + 0x1990], r11
+;; 69e6:	 4c899c2488190000     	mov	qword ptr [rsp + 0x1988], r11
+;; 69ee:	 4c899c2480190000     	mov	qword ptr [rsp + 0x1980], r11
+;; 69f6:	 4c899c2478190000     	mov	qword ptr [rsp + 0x1978], r11
+;; 69fe:	 4c899c2470190000     	mov	qword ptr [rsp + 0x1970], r11
+;; 6a06:	 4c899c2468190000     	mov	qword ptr [rsp + 0x1968], r11
+;; 6a0e:	 4c899c2460190000     	mov	qword ptr [rsp + 0x1960], r11
+;; 6a16:	 4c899c2458190000     	mov	qword ptr [rsp + 0x1958], r11
+;; 6a1e:	 4c899c2450190000     	mov	qword ptr [rsp + 0x1950], r11
+;; 6a26:	 4c899c2448190000     	mov	qword ptr [rsp + 0x1948], r11
+;; 6a2e:	 4c899c2440190000     	mov	qword ptr [rsp + 0x1940], r11
+;; 6a36:	 4c899c2438190000     	mov	qword ptr [rsp + 0x1938], r11
+;; 6a3e:	 4c899c2430190000     	mov	qword ptr [rsp + 0x1930], r11
+;; 6a46:	 4c899c2428190000     	mov	qword ptr [rsp + 0x1928], r11
+;; 6a4e:	 4c899c2420190000     	mov	qword ptr [rsp + 0x1920], r11
+;; 6a56:	 4c899c2418190000     	mov	qword ptr [rsp + 0x1918], r11
+;; 6a5e:	 4c899c2410190000     	mov	qword ptr [rsp + 0x1910], r11
+;; 6a66:	 4c899c2408190000     	mov	qword ptr [rsp + 0x1908], r11
+;; 6a6e:	 4c899c2400190000     	mov	qword ptr [rsp + 0x1900], r11
+;; 6a76:	 4c899c24f8180000     	mov	qword ptr [rsp + 0x18f8], r11
+;; 6a7e:	 4c899c24f0180000     	mov	qword ptr [rsp + 0x18f0], r11
+;; 6a86:	 4c899c24e8180000     	mov	qword ptr [rsp + 0x18e8], r11
+;; 6a8e:	 4c899c24e0180000     	mov	qword ptr [rsp + 0x18e0], r11
+;; 6a96:	 4c899c24d8180000     	mov	qword ptr [rsp + 0x18d8], r11
+;; 6a9e:	 4c899c24d0180000     	mov	qword ptr [rsp + 0x18d0], r11
+;; 6aa6:	 4c899c24c8180000     	mov	qword ptr [rsp + 0x18c8], r11
+;; 6aae:	 4c899c24c0180000     	mov	qword ptr [rsp + 0x18c0], r11
+;; 6ab6:	 4c899c24b8180000     	mov	qword ptr [rsp + 0x18b8], r11
+;; 6abe:	 4c899c24b0180000     	mov	qword ptr [rsp + 0x18b0], r11
+;; 6ac6:	 4c899c24a8180000     	mov	qword ptr [rsp + 0x18a8], r11
+;; 6ace:	 4c899c24a0180000     	mov	qword ptr [rsp + 0x18a0], r11
+;; 6ad6:	 4c899c2498180000     	mov	qword ptr [rsp + 0x1898], r11
+;; 6ade:	 4c899c2490180000     	mov	qword ptr [rsp + 0x1890], r11
+;; 6ae6:	 4c899c2488180000     	mov	qword ptr [rsp + 0x1888], r11
+;; 6aee:	 4c899c2480180000     	mov	qword ptr [rsp + 0x1880], r11
+;; 6af6:	 4c899c2478180000     	mov	qword ptr [rsp + 0x1878], r11
+;; 6afe:	 4c899c2470180000     	mov	qword ptr [rsp + 0x1870], r11
+;; 6b06:	 4c899c2468180000     	mov	qword ptr [rsp + 0x1868], r11
+;; 6b0e:	 4c899c2460180000     	mov	qword ptr [rsp + 0x1860], r11
+;; 6b16:	 4c899c2458180000     	mov	qword ptr [rsp + 0x1858], r11
+;; 6b1e:	 4c899c2450180000     	mov	qword ptr [rsp + 0x1850], r11
+;; 6b26:	 4c899c2448180000     	mov	qword ptr [rsp + 0x1848], r11
+;; 6b2e:	 4c899c2440180000     	mov	qword ptr [rsp + 0x1840], r11
+;; 6b36:	 4c899c2438180000     	mov	qword ptr [rsp + 0x1838], r11
+;; 6b3e:	 4c899c2430180000     	mov	qword ptr [rsp + 0x1830], r11
+;; 6b46:	 4c899c2428180000     	mov	qword ptr [rsp + 0x1828], r11
+;; 6b4e:	 4c899c2420180000     	mov	qword ptr [rsp + 0x1820], r11
+;; 6b56:	 4c899c2418180000     	mov	qword ptr [rsp + 0x1818], r11
+;; 6b5e:	 4c899c2410180000     	mov	qword ptr [rsp + 0x1810], r11
+;; 6b66:	 4c899c2408180000     	mov	qword ptr [rsp + 0x1808], r11
+;; 6b6e:	 4c899c2400180000     	mov	qword ptr [rsp + 0x1800], r11
+;; 6b76:	 4c899c24f8170000     	mov	qword ptr [rsp + 0x17f8], r11
+;; 6b7e:	 4c899c24f0170000     	mov	qword ptr [rsp + 0x17f0], r11
+;; 6b86:	 4c899c24e8170000     	mov	qword ptr [rsp + 0x17e8], r11
+;; 6b8e:	 4c899c24e0170000     	mov	qword ptr [rsp + 0x17e0], r11
+;; 6b96:	 4c899c24d8170000     	mov	qword ptr [rsp + 0x17d8], r11
+;; 6b9e:	 4c899c24d0170000     	mov	qword ptr [rsp + 0x17d0], r11
+;; 6ba6:	 4c899c24c8170000     	mov	qword ptr [rsp + 0x17c8], r11
+;; 6bae:	 4c899c24c0170000     	mov	qword ptr [rsp + 0x17c0], r11
+;; 6bb6:	 4c899c24b8170000     	mov	qword ptr [rsp + 0x17b8], r11
+;; 6bbe:	 4c899c24b0170000     	mov	qword ptr [rsp + 0x17b0], r11
+;; 6bc6:	 4c899c24a8170000     	mov	qword ptr [rsp + 0x17a8], r11
+;; 6bce:	 4c899c24a0170000     	mov	qword ptr [rsp + 0x17a0], r11
+;; 6bd6:	 4c899c2498170000     	mov	qword ptr [rsp + 0x1798], r11
+;; 6bde:	 4c899c2490170000     	mov	qword ptr [rsp + 0x1790], r11
+;; 6be6:	 4c899c2488170000     	mov	qword ptr [rsp + 0x1788], r11
+;; 6bee:	 4c899c2480170000     	mov	qword ptr [rsp + 0x1780], r11
+;; 6bf6:	 4c899c2478170000     	mov	qword ptr [rsp + 0x1778], r11
+;; 6bfe:	 4c899c2470170000     	mov	qword ptr [rsp + 0x1770], r11
+;; 6c06:	 4c899c2468170000     	mov	qword ptr [rsp + 0x1768], r11
+;; 6c0e:	 4c899c2460170000     	mov	qword ptr [rsp + 0x1760], r11
+;; 6c16:	 4c899c2458170000     	mov	qword ptr [rsp + 0x1758], r11
+;; 6c1e:	 4c899c2450170000     	mov	qword ptr [rsp + 0x1750], r11
+;; 6c26:	 4c899c2448170000     	mov	qword ptr [rsp + 0x1748], r11
+;; 6c2e:	 4c899c2440170000     	mov	qword ptr [rsp + 0x1740], r11
+;; 6c36:	 4c899c2438170000     	mov	qword ptr [rsp + 0x1738], r11
+;; 6c3e:	 4c899c2430170000     	mov	qword ptr [rsp + 0x1730], r11
+;; 6c46:	 4c899c2428170000     	mov	qword ptr [rsp + 0x1728], r11
+;; 6c4e:	 4c899c2420170000     	mov	qword ptr [rsp + 0x1720], r11
+;; 6c56:	 4c899c2418170000     	mov	qword ptr [rsp + 0x1718], r11
+;; 6c5e:	 4c899c2410170000     	mov	qword ptr [rsp + 0x1710], r11
+;; 6c66:	 4c899c2408170000     	mov	qword ptr [rsp + 0x1708], r11
+;; 6c6e:	 4c899c2400170000     	mov	qword ptr [rsp + 0x1700], r11
+;; 6c76:	 4c899c24f8160000     	mov	qword ptr [rsp + 0x16f8], r11
+;; 6c7e:	 4c899c24f0160000     	mov	qword ptr [rsp + 0x16f0], r11
+;; 6c86:	 4c899c24e8160000     	mov	qword ptr [rsp + 0x16e8], r11
+;; 6c8e:	 4c899c24e0160000     	mov	qword ptr [rsp + 0x16e0], r11
+;; 6c96:	 4c899c24d8160000     	mov	qword ptr [rsp + 0x16d8], r11
+;; 6c9e:	 4c899c24d0160000     	mov	qword ptr [rsp + 0x16d0], r11
+;; 6ca6:	 4c899c24c8160000     	mov	qword ptr [rsp + 0x16c8], r11
+;; 6cae:	 4c899c24c0160000     	mov	qword ptr [rsp + 0x16c0], r11
+;; 6cb6:	 4c899c24b8160000     	mov	qword ptr [rsp + 0x16b8], r11
+;; 6cbe:	 4c899c24b0160000     	mov	qword ptr [rsp + 0x16b0], r11
+;; 6cc6:	 4c899c24a8160000     	mov	qword ptr [rsp + 0x16a8], r11
+;; 6cce:	 4c899c24a0160000     	mov	qword ptr [rsp + 0x16a0], r11
+;; 6cd6:	 4c899c2498160000     	mov	qword ptr [rsp + 0x1698], r11
+;; 6cde:	 4c899c2490160000     	mov	qword ptr [rsp + 0x1690], r11
+;; 6ce6:	 4c899c2488160000     	mov	qword ptr [rsp + 0x1688], r11
+;; 6cee:	 4c899c2480160000     	mov	qword ptr [rsp + 0x1680], r11
+;; 6cf6:	 4c899c2478160000     	mov	qword ptr [rsp + 0x1678], r11
+;; 6cfe:	 4c899c2470160000     	mov	qword ptr [rsp + 0x1670], r11
+;; 6d06:	 4c899c2468160000     	mov	qword ptr [rsp + 0x1668], r11
+;; 6d0e:	 4c899c2460160000     	mov	qword ptr [rsp + 0x1660], r11
+;; 6d16:	 4c899c2458160000     	mov	qword ptr [rsp + 0x1658], r11
+;; 6d1e:	 4c899c2450160000     	mov	qword ptr [rsp + 0x1650], r11
+;; 6d26:	 4c899c2448160000     	mov	qword ptr [rsp + 0x1648], r11
+;; 6d2e:	 4c899c2440160000     	mov	qword ptr [rsp + 0x1640], r11
+;; 6d36:	 4c899c2438160000     	mov	qword ptr [rsp + 0x1638], r11
+;; 6d3e:	 4c899c2430160000     	mov	qword ptr [rsp + 0x1630], r11
+;; 6d46:	 4c899c2428160000     	mov	qword ptr [rsp + 0x1628], r11
+;; 6d4e:	 4c899c2420160000     	mov	qword ptr [rsp + 0x1620], r11
+;; 6d56:	 4c899c2418160000     	mov	qword ptr [rsp + 0x1618], r11
+;; 6d5e:	 4c899c2410160000     	mov	qword ptr [rsp + 0x1610], r11
+;; 6d66:	 4c899c2408160000     	mov	qword ptr [rsp + 0x1608], r11
+;; 6d6e:	 4c899c2400160000     	mov	qword ptr [rsp + 0x1600], r11
+;; 6d76:	 4c899c24f8150000     	mov	qword ptr [rsp + 0x15f8], r11
+;; 6d7e:	 4c899c24f0150000     	mov	qword ptr [rsp + 0x15f0], r11
+;; 6d86:	 4c899c24e8150000     	mov	qword ptr [rsp + 0x15e8], r11
+;; 6d8e:	 4c899c24e0150000     	mov	qword ptr [rsp + 0x15e0], r11
+;; 6d96:	 4c899c24d8150000     	mov	qword ptr [rsp + 0x15d8], r11
+;; 6d9e:	 4c899c24d0150000     	mov	qword ptr [rsp + 0x15d0], r11
+;; 6da6:	 4c899c24c8150000     	mov	qword ptr [rsp + 0x15c8], r11
+;; 6dae:	 4c899c24c0150000     	mov	qword ptr [rsp + 0x15c0], r11
+;; 6db6:	 4c899c24b8150000     	mov	qword ptr [rsp + 0x15b8], r11
+;; 6dbe:	 4c899c24b0150000     	mov	qword ptr [rsp + 0x15b0], r11
+;; 6dc6:	 4c899c24a8150000     	mov	qword ptr [rsp + 0x15a8], r11
+;; 6dce:	 4c899c24a0150000     	mov	qword ptr [rsp + 0x15a0], r11
+;; 6dd6:	 4c899c2498150000     	mov	qword ptr [rsp + 0x1598], r11
+;; 6dde:	 4c899c2490150000     	mov	qword ptr [rsp + 0x1590], r11
+;; 6de6:	 4c899c2488150000     	mov	qword ptr [rsp + 0x1588], r11
+;; 6dee:	 4c899c2480150000     	mov	qword ptr [rsp + 0x1580], r11
+;; 6df6:	 4c899c2478150000     	mov	qword ptr [rsp + 0x1578], r11
+;; 6dfe:	 4c899c2470150000     	mov	qword ptr [rsp + 0x1570], r11
+;; 6e06:	 4c899c2468150000     	mov	qword ptr [rsp + 0x1568], r11
+;; 6e0e:	 4c899c2460150000     	mov	qword ptr [rsp + 0x1560], r11
+;; 6e16:	 4c899c2458150000     	mov	qword ptr [rsp + 0x1558], r11
+;; 6e1e:	 4c899c2450150000     	mov	qword ptr [rsp + 0x1550], r11
+;; 6e26:	 4c899c2448150000     	mov	qword ptr [rsp + 0x1548], r11
+;; 6e2e:	 4c899c2440150000     	mov	qword ptr [rsp + 0x1540], r11
+;; 6e36:	 4c899c2438150000     	mov	qword ptr [rsp + 0x1538], r11
+;; 6e3e:	 4c899c2430150000     	mov	qword ptr [rsp + 0x1530], r11
+;; 6e46:	 4c899c2428150000     	mov	qword ptr [rsp + 0x1528], r11
+;; 6e4e:	 4c899c2420150000     	mov	qword ptr [rsp + 0x1520], r11
+;; 6e56:	 4c899c2418150000     	mov	qword ptr [rsp + 0x1518], r11
+;; 6e5e:	 4c899c2410150000     	mov	qword ptr [rsp + 0x1510], r11
+;; 6e66:	 4c899c2408150000     	mov	qword ptr [rsp + 0x1508], r11
+;; 6e6e:	 4c899c2400150000     	mov	qword ptr [rsp + 0x1500], r11
+;; 6e76:	 4c899c24f8140000     	mov	qword ptr [rsp + 0x14f8], r11
+;; 6e7e:	 4c899c24f0140000     	mov	qword ptr [rsp + 0x14f0], r11
+;; 6e86:	 4c899c24e8140000     	mov	qword ptr [rsp + 0x14e8], r11
+;; 6e8e:	 4c899c24e0140000     	mov	qword ptr [rsp + 0x14e0], r11
+;; 6e96:	 4c899c24d8140000     	mov	qword ptr [rsp + 0x14d8], r11
+;; 6e9e:	 4c899c24d0140000     	mov	qword ptr [rsp + 0x14d0], r11
+;; 6ea6:	 4c899c24c8140000     	mov	qword ptr [rsp + 0x14c8], r11
+;; 6eae:	 4c899c24c0140000     	mov	qword ptr [rsp + 0x14c0], r11
+;; 6eb6:	 4c899c24b8140000     	mov	qword ptr [rsp + 0x14b8], r11
+;; 6ebe:	 4c899c24b0140000     	mov	qword ptr [rsp + 0x14b0], r11
+;; 6ec6:	 4c899c24a8140000     	mov	qword ptr [rsp + 0x14a8], r11
+;; 6ece:	 4c899c24a0140000     	mov	qword ptr [rsp + 0x14a0], r11
+;; 6ed6:	 4c899c2498140000     	mov	qword ptr [rsp + 0x1498], r11
+;; 6ede:	 4c899c2490140000     	mov	qword ptr [rsp + 0x1490], r11
+;; 6ee6:	 4c899c2488140000     	mov	qword ptr [rsp + 0x1488], r11
+;; 6eee:	 4c899c2480140000     	mov	qword ptr [rsp + 0x1480], r11
+;; 6ef6:	 4c899c2478140000     	mov	qword ptr [rsp + 0x1478], r11
+;; 6efe:	 4c899c2470140000     	mov	qword ptr [rsp + 0x1470], r11
+;; 6f06:	 4c899c2468140000     	mov	qword ptr [rsp + 0x1468], r11
+;; 6f0e:	 4c899c2460140000     	mov	qword ptr [rsp + 0x1460], r11
+;; 6f16:	 4c899c2458140000     	mov	qword ptr [rsp + 0x1458], r11
+;; 6f1e:	 4c899c2450140000     	mov	qword ptr [rsp + 0x1450], r11
+;; 6f26:	 4c899c2448140000     	mov	qword ptr [rsp + 0x1448], r11
+;; 6f2e:	 4c899c2440140000     	mov	qword ptr [rsp + 0x1440], r11
+;; 6f36:	 4c899c2438140000     	mov	qword ptr [rsp + 0x1438], r11
+;; 6f3e:	 4c899c2430140000     	mov	qword ptr [rsp + 0x1430], r11
+;; 6f46:	 4c899c2428140000     	mov	qword ptr [rsp + 0x1428], r11
+;; 6f4e:	 4c899c2420140000     	mov	qword ptr [rsp + 0x1420], r11
+;; 6f56:	 4c899c2418140000     	mov	qword ptr [rsp + 0x1418], r11
+;; 6f5e:	 4c899c2410140000     	mov	qword ptr [rsp + 0x1410], r11
+;; 6f66:	 4c899c2408140000     	mov	qword ptr [rsp + 0x1408], r11
+;; 6f6e:	 4c899c2400140000     	mov	qword ptr [rsp + 0x1400], r11
+;; 6f76:	 4c899c24f8130000     	mov	qword ptr [rsp + 0x13f8], r11
+;; 6f7e:	 4c899c24f0130000     	mov	qword ptr [rsp + 0x13f0], r11
+;; 6f86:	 4c899c24e8130000     	mov	qword ptr [rsp + 0x13e8], r11
+;; 6f8e:	 4c899c24e0130000     	mov	qword ptr [rsp + 0x13e0], r11
+;; 6f96:	 4c899c24d8130000     	mov	qword ptr [rsp + 0x13d8], r11
+;; 6f9e:	 4c899c24d0130000     	mov	qword ptr [rsp + 0x13d0], r11
+;; 6fa6:	 4c899c24c8130000     	mov	qword ptr [rsp + 0x13c8], r11
+;; 6fae:	 4c899c24c0130000     	mov	qword ptr [rsp + 0x13c0], r11
+;; 6fb6:	 4c899c24b8130000     	mov	qword ptr [rsp + 0x13b8], r11
+;; 6fbe:	 4c899c24b0130000     	mov	qword ptr [rsp + 0x13b0], r11
+;; 6fc6:	 4c899c24a8130000     	mov	qword ptr [rsp + 0x13a8], r11
+;; 6fce:	 4c899c24a0130000     	mov	qword ptr [rsp + 0x13a0], r11
+;; 6fd6:	 4c899c2498130000     	mov	qword ptr [rsp + 0x1398], r11
+;; 6fde:	 4c899c2490130000     	mov	qword ptr [rsp + 0x1390], r11
+;; 6fe6:	 4c899c2488130000     	mov	qword ptr [rsp + 0x1388], r11
+;; 6fee:	 4c899c2480130000     	mov	qword ptr [rsp + 0x1380], r11
+;; 6ff6:	 4c899c2478130000     	mov	qword ptr [rsp + 0x1378], r11
+;; 6ffe:	 4c899c2470130000     	mov	qword ptr [rsp + 0x1370], r11
+;; 7006:	 4c899c2468130000     	mov	qword ptr [rsp + 0x1368], r11
+;; 700e:	 4c899c2460130000     	mov	qword ptr [rsp + 0x1360], r11
+;; 7016:	 4c899c2458130000     	mov	qword ptr [rsp + 0x1358], r11
+;; 701e:	 4c899c2450130000     	mov	qword ptr [rsp + 0x1350], r11
+;; 7026:	 4c899c2448130000     	mov	qword ptr [rsp + 0x1348], r11
+;; 702e:	 4c899c2440130000     	mov	qword ptr [rsp + 0x1340], r11
+;; 7036:	 4c899c2438130000     	mov	qword ptr [rsp + 0x1338], r11
+;; 703e:	 4c899c2430130000     	mov	qword ptr [rsp + 0x1330], r11
+;; 7046:	 4c899c2428130000     	mov	qword ptr [rsp + 0x1328], r11
+;; 704e:	 4c899c2420130000     	mov	qword ptr [rsp + 0x1320], r11
+;; 7056:	 4c899c2418130000     	mov	qword ptr [rsp + 0x1318], r11
+;; 705e:	 4c899c2410130000     	mov	qword ptr [rsp + 0x1310], r11
+;; 7066:	 4c899c2408130000     	mov	qword ptr [rsp + 0x1308], r11
+;; 706e:	 4c899c2400130000     	mov	qword ptr [rsp + 0x1300], r11
+;; 7076:	 4c899c24f8120000     	mov	qword ptr [rsp + 0x12f8], r11
+;; 707e:	 4c899c24f0120000     	mov	qword ptr [rsp + 0x12f0], r11
+;; 7086:	 4c899c24e8120000     	mov	qword ptr [rsp + 0x12e8], r11
+;; 708e:	 4c899c24e0120000     	mov	qword ptr [rsp + 0x12e0], r11
+;; 7096:	 4c899c24d8120000     	mov	qword ptr [rsp + 0x12d8], r11
+;; 709e:	 4c899c24d0120000     	mov	qword ptr [rsp + 0x12d0], r11
+;; 70a6:	 4c899c24c8120000     	mov	qword ptr [rsp + 0x12c8], r11
+;; 70ae:	 4c899c24c0120000     	mov	qword ptr [rsp + 0x12c0], r11
+;; 70b6:	 4c899c24b8120000     	mov	qword ptr [rsp + 0x12b8], r11
+;; 70be:	 4c899c24b0120000     	mov	qword ptr [rsp + 0x12b0], r11
+;; 70c6:	 4c899c24a8120000     	mov	qword ptr [rsp + 0x12a8], r11
+;; 70ce:	 4c899c24a0120000     	mov	qword ptr [rsp + 0x12a0], r11
+;; 70d6:	 4c899c2498120000     	mov	qword ptr [rsp + 0x1298], r11
+;; 70de:	 4c899c2490120000     	mov	qword ptr [rsp + 0x1290], r11
+;; 70e6:	 4c899c2488120000     	mov	qword ptr [rsp + 0x1288], r11
+;; 70ee:	 4c899c2480120000     	mov	qword ptr [rsp + 0x1280], r11
+;; 70f6:	 4c899c2478120000     	mov	qword ptr [rsp + 0x1278], r11
+;; 70fe:	 4c899c2470120000     	mov	qword ptr [rsp + 0x1270], r11
+;; 7106:	 4c899c2468120000     	mov	qword ptr [rsp + 0x1268], r11
+;; 710e:	 4c899c2460120000     	mov	qword ptr [rsp + 0x1260], r11
+;; 7116:	 4c899c2458120000     	mov	qword ptr [rsp + 0x1258], r11
+;; 711e:	 4c899c2450120000     	mov	qword ptr [rsp + 0x1250], r11
+;; 7126:	 4c899c2448120000     	mov	qword ptr [rsp + 0x1248], r11
+;; 712e:	 4c899c2440120000     	mov	qword ptr [rsp + 0x1240], r11
+;; 7136:	 4c899c2438120000     	mov	qword ptr [rsp + 0x1238], r11
+;; 713e:	 4c899c2430120000     	mov	qword ptr [rsp + 0x1230], r11
+;; 7146:	 4c899c2428120000     	mov	qword ptr [rsp + 0x1228], r11
+;; 714e:	 4c899c2420120000     	mov	qword ptr [rsp + 0x1220], r11
+;; 7156:	 4c899c2418120000     	mov	qword ptr [rsp + 0x1218], r11
+;; 715e:	 4c899c2410120000     	mov	qword ptr [rsp + 0x1210], r11
+;; 7166:	 4c899c2408120000     	mov	qword ptr [rsp + 0x1208], r11
+;; 716e:	 4c899c2400120000     	mov	qword ptr [rsp + 0x1200], r11
+;; 7176:	 4c899c24f8110000     	mov	qword ptr [rsp + 0x11f8], r11
+;; 717e:	 4c899c24f0110000     	mov	qword ptr [rsp + 0x11f0], r11
+;; 7186:	 4c899c24e8110000     	mov	qword ptr [rsp + 0x11e8], r11
+;; 718e:	 4c899c24e0110000     	mov	qword ptr [rsp + 0x11e0], r11
+;; 7196:	 4c899c24d8110000     	mov	qword ptr [rsp + 0x11d8], r11
+;; 719e:	 4c899c24d0110000     	mov	qword ptr [rsp + 0x11d0], r11
+;; 71a6:	 4c899c24c8110000     	mov	qword ptr [rsp + 0x11c8], r11
+;; 71ae:	 4c899c24c0110000     	mov	qword ptr [rsp + 0x11c0], r11
+;; 71b6:	 4c899c24b8110000     	mov	qword ptr [rsp + 0x11b8], r11
+;; 71be:	 4c899c24b0110000     	mov	qword ptr [rsp + 0x11b0], r11
+;; 71c6:	 4c899c24a8110000     	mov	qword ptr [rsp + 0x11a8], r11
+;; 71ce:	 4c899c24a0110000     	mov	qword ptr [rsp + 0x11a0], r11
+;; 71d6:	 4c899c2498110000     	mov	qword ptr [rsp + 0x1198], r11
+;; 71de:	 4c899c2490110000     	mov	qword ptr [rsp + 0x1190], r11
+;; 71e6:	 4c899c2488110000     	mov	qword ptr [rsp + 0x1188], r11
+;; 71ee:	 4c899c2480110000     	mov	qword ptr [rsp + 0x1180], r11
+;; 71f6:	 4c899c2478110000     	mov	qword ptr [rsp + 0x1178], r11
+;; 71fe:	 4c899c2470110000     	mov	qword ptr [rsp + 0x1170], r11
+;; 7206:	 4c899c2468110000     	mov	qword ptr [rsp + 0x1168], r11
+;; 720e:	 4c899c2460110000     	mov	qword ptr [rsp + 0x1160], r11
+;; 7216:	 4c899c2458110000     	mov	qword ptr [rsp + 0x1158], r11
+;; 721e:	 4c899c2450110000     	mov	qword ptr [rsp + 0x1150], r11
+;; 7226:	 4c899c2448110000     	mov	qword ptr [rsp + 0x1148], r11
+;; 722e:	 4c899c2440110000     	mov	qword ptr [rsp + 0x1140], r11
+;; 7236:	 4c899c2438110000     	mov	qword ptr [rsp + 0x1138], r11
+;; 723e:	 4c899c2430110000     	mov	qword ptr [rsp + 0x1130], r11
+;; 7246:	 4c899c2428110000     	mov	qword ptr [rsp + 0x1128], r11
+;; 724e:	 4c899c2420110000     	mov	qword ptr [rsp + 0x1120], r11
+;; 7256:	 4c899c2418110000     	mov	qword ptr [rsp + 0x1118], r11
+;; 725e:	 4c899c2410110000     	mov	qword ptr [rsp + 0x1110], r11
+;; 7266:	 4c899c2408110000     	mov	qword ptr [rsp + 0x1108], r11
+;; 726e:	 4c899c2400110000     	mov	qword ptr [rsp + 0x1100], r11
+;; 7276:	 4c899c24f8100000     	mov	qword ptr [rsp + 0x10f8], r11
+;; 727e:	 4c899c24f0100000     	mov	qword ptr [rsp + 0x10f0], r11
+;; 7286:	 4c899c24e8100000     	mov	qword ptr [rsp + 0x10e8], r11
+;; 728e:	 4c899c24e0100000     	mov	qword ptr [rsp + 0x10e0], r11
+;; 7296:	 4c899c24d8100000     	mov	qword ptr [rsp + 0x10d8], r11
+;; 729e:	 4c899c24d0100000     	mov	qword ptr [rsp + 0x10d0], r11
+;; 72a6:	 4c899c24c8100000     	mov	qword ptr [rsp + 0x10c8], r11
+;; 72ae:	 4c899c24c0100000     	mov	qword ptr [rsp + 0x10c0], r11
+;; 72b6:	 4c899c24b8100000     	mov	qword ptr [rsp + 0x10b8], r11
+;; 72be:	 4c899c24b0100000     	mov	qword ptr [rsp + 0x10b0], r11
+;; 72c6:	 4c899c24a8100000     	mov	qword ptr [rsp + 0x10a8], r11
+;; 72ce:	 4c899c24a0100000     	mov	qword ptr [rsp + 0x10a0], r11
+;; 72d6:	 4c899c2498100000     	mov	qword ptr [rsp + 0x1098], r11
+;; 72de:	 4c899c2490100000     	mov	qword ptr [rsp + 0x1090], r11
+;; 72e6:	 4c899c2488100000     	mov	qword ptr [rsp + 0x1088], r11
+;; 72ee:	 4c899c2480100000     	mov	qword ptr [rsp + 0x1080], r11
+;; 72f6:	 4c899c2478100000     	mov	qword ptr [rsp + 0x1078], r11
+;; 72fe:	 4c899c2470100000     	mov	qword ptr [rsp + 0x1070], r11
+;; 7306:	 4c899c2468100000     	mov	qword ptr [rsp + 0x1068], r11
+;; 730e:	 4c899c2460100000     	mov	qword ptr [rsp + 0x1060], r11
+;; 7316:	 4c899c2458100000     	mov	qword ptr [rsp + 0x1058], r11
+;; 731e:	 4c899c2450100000     	mov	qword ptr [rsp + 0x1050], r11
+;; 7326:	 4c899c2448100000     	mov	qword ptr [rsp + 0x1048], r11
+;; 732e:	 4c899c2440100000     	mov	qword ptr [rsp + 0x1040], r11
+;; 7336:	 4c899c2438100000     	mov	qword ptr [rsp + 0x1038], r11
+;; 733e:	 4c899c2430100000     	mov	qword ptr [rsp + 0x1030], r11
+;; 7346:	 4c899c2428100000     	mov	qword ptr [rsp + 0x1028], r11
+;; 734e:	 4c899c2420100000     	mov	qword ptr [rsp + 0x1020], r11
+;; 7356:	 4c899c2418100000     	mov	qword ptr [rsp + 0x1018], r11
+;; 735e:	 4c899c2410100000     	mov	qword ptr [rsp + 0x1010], r11
+;; 7366:	 4c899c2408100000     	mov	qword ptr [rsp + 0x1008], r11
+;; 736e:	 4c899c2400100000     	mov	qword ptr [rsp + 0x1000], r11
+;; 7376:	 4c899c24f80f0000     	mov	qword ptr [rsp + 0xff8], r11
+;; 737e:	 4c899c24f00f0000     	mov	qword ptr [rsp + 0xff0], r11
+;; 7386:	 4c899c24e80f0000     	mov	qword ptr [rsp + 0xfe8], r11
+;; 738e:	 4c899c24e00f0000     	mov	qword ptr [rsp + 0xfe0], r11
+;; 7396:	 4c899c24d80f0000     	mov	qword ptr [rsp + 0xfd8], r11
+;; 739e:	 4c899c24d00f0000     	mov	qword ptr [rsp + 0xfd0], r11
+;; 73a6:	 4c899c24c80f0000     	mov	qword ptr [rsp + 0xfc8], r11
+;; 73ae:	 4c899c24c00f0000     	mov	qword ptr [rsp + 0xfc0], r11
+;; 73b6:	 4c899c24b80f0000     	mov	qword ptr [rsp + 0xfb8], r11
+;; 73be:	 4c899c24b00f0000     	mov	qword ptr [rsp + 0xfb0], r11
+;; 73c6:	 4c899c24a80f0000     	mov	qword ptr [rsp + 0xfa8], r11
+;; 73ce:	 4c899c24a00f0000     	mov	qword ptr [rsp + 0xfa0], r11
+;; 73d6:	 4c899c24980f0000     	mov	qword ptr [rsp + 0xf98], r11
+;; 73de:	 4c899c24900f0000     	mov	qword ptr [rsp + 0xf90], r11
+;; 73e6:	 4c899c24880f0000     	mov	qword ptr [rsp + 0xf88], r11
+;; 73ee:	 4c899c24800f0000     	mov	qword ptr [rsp + 0xf80], r11
+;; 73f6:	 4c899c24780f0000     	mov	qword ptr [rsp + 0xf78], r11
+;; 73fe:	 4c899c24700f0000     	mov	qword ptr [rsp + 0xf70], r11
+;; 7406:	 4c899c24680f0000     	mov	qword ptr [rsp + 0xf68], r11
+;; 740e:	 4c899c24600f0000     	mov	qword ptr [rsp + 0xf60], r11
+;; 7416:	 4c899c24580f0000     	mov	qword ptr [rsp + 0xf58], r11
+;; 741e:	 4c899c24500f0000     	mov	qword ptr [rsp + 0xf50], r11
+;; 7426:	 4c899c24480f0000     	mov	qword ptr [rsp + 0xf48], r11
+;; 742e:	 4c899c24400f0000     	mov	qword ptr [rsp + 0xf40], r11
+;; 7436:	 4c899c24380f0000     	mov	qword ptr [rsp + 0xf38], r11
+;; 743e:	 4c899c24300f0000     	mov	qword ptr [rsp + 0xf30], r11
+;; 7446:	 4c899c24280f0000     	mov	qword ptr [rsp + 0xf28], r11
+;; 744e:	 4c899c24200f0000     	mov	qword ptr [rsp + 0xf20], r11
+;; 7456:	 4c899c24180f0000     	mov	qword ptr [rsp + 0xf18], r11
+;; 745e:	 4c899c24100f0000     	mov	qword ptr [rsp + 0xf10], r11
+;; 7466:	 4c899c24080f0000     	mov	qword ptr [rsp + 0xf08], r11
+;; 746e:	 4c899c24000f0000     	mov	qword ptr [rsp + 0xf00], r11
+;; 7476:	 4c899c24f80e0000     	mov	qword ptr [rsp + 0xef8], r11
+;; 747e:	 4c899c24f00e0000     	mov	qword ptr [rsp + 0xef0], r11
+;; 7486:	 4c899c24e80e0000     	mov	qword ptr [rsp + 0xee8], r11
+;; 748e:	 4c899c24e00e0000     	mov	qword ptr [rsp + 0xee0], r11
+;; 7496:	 4c899c24d80e0000     	mov	qword ptr [rsp + 0xed8], r11
+;; 749e:	 4c899c24d00e0000     	mov	qword ptr [rsp + 0xed0], r11
+;; 74a6:	 4c899c24c80e0000     	mov	qword ptr [rsp + 0xec8], r11
+;; 74ae:	 4c899c24c00e0000     	mov	qword ptr [rsp + 0xec0], r11
+;; 74b6:	 4c899c24b80e0000     	mov	qword ptr [rsp + 0xeb8], r11
+;; 74be:	 4c899c24b00e0000     	mov	qword ptr [rsp + 0xeb0], r11
+;; 74c6:	 4c899c24a80e0000     	mov	qword ptr [rsp + 0xea8], r11
+;; 74ce:	 4c899c24a00e0000     	mov	qword ptr [rsp + 0xea0], r11
+;; 74d6:	 4c899c24980e0000     	mov	qword ptr [rsp + 0xe98], r11
+;; 74de:	 4c899c24900e0000     	mov	qword ptr [rsp + 0xe90], r11
+;; 74e6:	 4c899c24880e0000     	mov	qword ptr [rsp + 0xe88], r11
+;; 74ee:	 4c899c24800e0000     	mov	qword ptr [rsp + 0xe80], r11
+;; 74f6:	 4c899c24780e0000     	mov	qword ptr [rsp + 0xe78], r11
+;; 74fe:	 4c899c24700e0000     	mov	qword ptr [rsp + 0xe70], r11
+;; 7506:	 4c899c24680e0000     	mov	qword ptr [rsp + 0xe68], r11
+;; 750e:	 4c899c24600e0000     	mov	qword ptr [rsp + 0xe60], r11
+;; 7516:	 4c899c24580e0000     	mov	qword ptr [rsp + 0xe58], r11
+;; 751e:	 4c899c24500e0000     	mov	qword ptr [rsp + 0xe50], r11
+;; 7526:	 4c899c24480e0000     	mov	qword ptr [rsp + 0xe48], r11
+;; 752e:	 4c899c24400e0000     	mov	qword ptr [rsp + 0xe40], r11
+;; 7536:	 4c899c24380e0000     	mov	qword ptr [rsp + 0xe38], r11
+;; 753e:	 4c899c24300e0000     	mov	qword ptr [rsp + 0xe30], r11
+;; 7546:	 4c899c24280e0000     	mov	qword ptr [rsp + 0xe28], r11
+;; 754e:	 4c899c24200e0000     	mov	qword ptr [rsp + 0xe20], r11
+;; 7556:	 4c899c24180e0000     	mov	qword ptr [rsp + 0xe18], r11
+;; 755e:	 4c899c24100e0000     	mov	qword ptr [rsp + 0xe10], r11
+;; 7566:	 4c899c24080e0000     	mov	qword ptr [rsp + 0xe08], r11
+;; 756e:	 4c899c24000e0000     	mov	qword ptr [rsp + 0xe00], r11
+;; 7576:	 4c899c24f80d0000     	mov	qword ptr [rsp + 0xdf8], r11
+;; 757e:	 4c899c24f00d0000     	mov	qword ptr [rsp + 0xdf0], r11
+;; 7586:	 4c899c24e80d0000     	mov	qword ptr [rsp + 0xde8], r11
+;; 758e:	 4c899c24e00d0000     	mov	qword ptr [rsp + 0xde0], r11
+;; 7596:	 4c899c24d80d0000     	mov	qword ptr [rsp + 0xdd8], r11
+;; 759e:	 4c899c24d00d0000     	mov	qword ptr [rsp + 0xdd0], r11
+;; 75a6:	 4c899c24c80d0000     	mov	qword ptr [rsp + 0xdc8], r11
+;; 75ae:	 4c899c24c00d0000     	mov	qword ptr [rsp + 0xdc0], r11
+;; 75b6:	 4c899c24b80d0000     	mov	qword ptr [rsp + 0xdb8], r11
+;; 75be:	 4c899c24b00d0000     	mov	qword ptr [rsp + 0xdb0], r11
+;; 75c6:	 4c899c24a80d0000     	mov	qword ptr [rsp + 0xda8], r11
+;; 75ce:	 4c899c24a00d0000     	mov	qword ptr [rsp + 0xda0], r11
+;; 75d6:	 4c899c24980d0000     	mov	qword ptr [rsp + 0xd98], r11
+;; 75de:	 4c899c24900d0000     	mov	qword ptr [rsp + 0xd90], r11
+;; 75e6:	 4c899c24880d0000     	mov	qword ptr [rsp + 0xd88], r11
+;; 75ee:	 4c899c24800d0000     	mov	qword ptr [rsp + 0xd80], r11
+;; 75f6:	 4c899c24780d0000     	mov	qword ptr [rsp + 0xd78], r11
+;; 75fe:	 4c899c24700d0000     	mov	qword ptr [rsp + 0xd70], r11
+;; 7606:	 4c899c24680d0000     	mov	qword ptr [rsp + 0xd68], r11
+;; 760e:	 4c899c24600d0000     	mov	qword ptr [rsp + 0xd60], r11
+;; 7616:	 4c899c24580d0000     	mov	qword ptr [rsp + 0xd58], r11
+;; 761e:	 4c899c24500d0000     	mov	qword ptr [rsp + 0xd50], r11
+;; 7626:	 4c899c24480d0000     	mov	qword ptr [rsp + 0xd48], r11
+;; 762e:	 4c899c24400d0000     	mov	qword ptr [rsp + 0xd40], r11
+;; 7636:	 4c899c24380d0000     	mov	qword ptr [rsp + 0xd38], r11
+;; 763e:	 4c899c24300d0000     	mov	qword ptr [rsp + 0xd30], r11
+;; 7646:	 4c899c24280d0000     	mov	qword ptr [rsp + 0xd28], r11
+;; 764e:	 4c899c24200d0000     	mov	qword ptr [rsp + 0xd20], r11
+;; 7656:	 4c899c24180d0000     	mov	qword ptr [rsp + 0xd18], r11
+;; 765e:	 4c899c24100d0000     	mov	qword ptr [rsp + 0xd10], r11
+;; 7666:	 4c899c24080d0000     	mov	qword ptr [rsp + 0xd08], r11
+;; 766e:	 4c899c24000d0000     	mov	qword ptr [rsp + 0xd00], r11
+;; 7676:	 4c899c24f80c0000     	mov	qword ptr [rsp + 0xcf8], r11
+;; 767e:	 4c899c24f00c0000     	mov	qword ptr [rsp + 0xcf0], r11
+;; 7686:	 4c899c24e80c0000     	mov	qword ptr [rsp + 0xce8], r11
+;; 768e:	 4c899c24e00c0000     	mov	qword ptr [rsp + 0xce0], r11
+;; 7696:	 4c899c24d80c0000     	mov	qword ptr [rsp + 0xcd8], r11
+;; 769e:	 4c899c24d00c0000     	mov	qword ptr [rsp + 0xcd0], r11
+;; 76a6:	 4c899c24c80c0000     	mov	qword ptr [rsp + 0xcc8], r11
+;; 76ae:	 4c899c24c00c0000     	mov	qword ptr [rsp + 0xcc0], r11
+;; 76b6:	 4c899c24b80c0000     	mov	qword ptr [rsp + 0xcb8], r11
+;; 76be:	 4c899c24b00c0000     	mov	qword ptr [rsp + 0xcb0], r11
+;; 76c6:	 4c899c24a80c0000     	mov	qword ptr [rsp + 0xca8], r11
+;; 76ce:	 4c899c24a00c0000     	mov	qword ptr [rsp + 0xca0], r11
+;; 76d6:	 4c899c24980c0000     	mov	qword ptr [rsp + 0xc98], r11
+;; 76de:	 4c899c24900c0000     	mov	qword ptr [rsp + 0xc90], r11
+;; 76e6:	 4c899c24880c0000     	mov	qword ptr [rsp + 0xc88], r11
+;; 76ee:	 4c899c24800c0000     	mov	qword ptr [rsp + 0xc80], r11
+;; 76f6:	 4c899c24780c0000     	mov	qword ptr [rsp + 0xc78], r11
+;; 76fe:	 4c899c24700c0000     	mov	qword ptr [rsp + 0xc70], r11
+;; 7706:	 4c899c24680c0000     	mov	qword ptr [rsp + 0xc68], r11
+;; 770e:	 4c899c24600c0000     	mov	qword ptr [rsp + 0xc60], r11
+;; 7716:	 4c899c24580c0000     	mov	qword ptr [rsp + 0xc58], r11
+;; 771e:	 4c899c24500c0000     	mov	qword ptr [rsp + 0xc50], r11
+;; 7726:	 4c899c24480c0000     	mov	qword ptr [rsp + 0xc48], r11
+;; 772e:	 4c899c24400c0000     	mov	qword ptr [rsp + 0xc40], r11
+;; 7736:	 4c899c24380c0000     	mov	qword ptr [rsp + 0xc38], r11
+;; 773e:	 4c899c24300c0000     	mov	qword ptr [rsp + 0xc30], r11
+;; 7746:	 4c899c24280c0000     	mov	qword ptr [rsp + 0xc28], r11
+;; 774e:	 4c899c24200c0000     	mov	qword ptr [rsp + 0xc20], r11
+;; 7756:	 4c899c24180c0000     	mov	qword ptr [rsp + 0xc18], r11
+;; 775e:	 4c899c24100c0000     	mov	qword ptr [rsp + 0xc10], r11
+;; 7766:	 4c899c24080c0000     	mov	qword ptr [rsp + 0xc08], r11
+;; 776e:	 4c899c24000c0000     	mov	qword ptr [rsp + 0xc00], r11
+;; 7776:	 4c899c24f80b0000     	mov	qword ptr [rsp + 0xbf8], r11
+;; 777e:	 4c899c24f00b0000     	mov	qword ptr [rsp + 0xbf0], r11
+;; 7786:	 4c899c24e80b0000     	mov	qword ptr [rsp + 0xbe8], r11
+;; 778e:	 4c899c24e00b0000     	mov	qword ptr [rsp + 0xbe0], r11
+;; 7796:	 4c899c24d80b0000     	mov	qword ptr [rsp + 0xbd8], r11
+;; 779e:	 4c899c24d00b0000     	mov	qword ptr [rsp + 0xbd0], r11
+;; 77a6:	 4c899c24c80b0000     	mov	qword ptr [rsp + 0xbc8], r11
+;; 77ae:	 4c899c24c00b0000     	mov	qword ptr [rsp + 0xbc0], r11
+;; 77b6:	 4c899c24b80b0000     	mov	qword ptr [rsp + 0xbb8], r11
+;; 77be:	 4c899c24b00b0000     	mov	qword ptr [rsp + 0xbb0], r11
+;; 77c6:	 4c899c24a80b0000     	mov	qword ptr [rsp + 0xba8], r11
+;; 77ce:	 4c899c24a00b0000     	mov	qword ptr [rsp + 0xba0], r11
+;; 77d6:	 4c899c24980b0000     	mov	qword ptr [rsp + 0xb98], r11
+;; 77de:	 4c899c24900b0000     	mov	qword ptr [rsp + 0xb90], r11
+;; 77e6:	 4c899c24880b0000     	mov	qword ptr [rsp + 0xb88], r11
+;; 77ee:	 4c899c24800b0000     	mov	qword ptr [rsp + 0xb80], r11
+;; 77f6:	 4c899c24780b0000     	mov	qword ptr [rsp + 0xb78], r11
+;; 77fe:	 4c899c24700b0000     	mov	qword ptr [rsp + 0xb70], r11
+;; 7806:	 4c899c24680b0000     	mov	qword ptr [rsp + 0xb68], r11
+;; 780e:	 4c899c24600b0000     	mov	qword ptr [rsp + 0xb60], r11
+;; 7816:	 4c899c24580b0000     	mov	qword ptr [rsp + 0xb58], r11
+;; 781e:	 4c899c24500b0000     	mov	qword ptr [rsp + 0xb50], r11
+;; 7826:	 4c899c24480b0000     	mov	qword ptr [rsp + 0xb48], r11
+;; 782e:	 4c899c24400b0000     	mov	qword ptr [rsp + 0xb40], r11
+;; 7836:	 4c899c24380b0000     	mov	qword ptr [rsp + 0xb38], r11
+;; 783e:	 4c899c24300b0000     	mov	qword ptr [rsp + 0xb30], r11
+;; 7846:	 4c899c24280b0000     	mov	qword ptr [rsp + 0xb28], r11
+;; 784e:	 4c899c24200b0000     	mov	qword ptr [rsp + 0xb20], r11
+;; 7856:	 4c899c24180b0000     	mov	qword ptr [rsp + 0xb18], r11
+;; 785e:	 4c899c24100b0000     	mov	qword ptr [rsp + 0xb10], r11
+;; 7866:	 4c899c24080b0000     	mov	qword ptr [rsp + 0xb08], r11
+;; 786e:	 4c899c24000b0000     	mov	qword ptr [rsp + 0xb00], r11
+;; 7876:	 4c899c24f80a0000     	mov	qword ptr [rsp + 0xaf8], r11
+;; 787e:	 4c899c24f00a0000     	mov	qword ptr [rsp + 0xaf0], r11
+;; 7886:	 4c899c24e80a0000     	mov	qword ptr [rsp + 0xae8], r11
+;; 788e:	 4c899c24e00a0000     	mov	qword ptr [rsp + 0xae0], r11
+;; 7896:	 4c899c24d80a0000     	mov	qword ptr [rsp + 0xad8], r11
+;; 789e:	 4c899c24d00a0000     	mov	qword ptr [rsp + 0xad0], r11
+;; 78a6:	 4c899c24c80a0000     	mov	qword ptr [rsp + 0xac8], r11
+;; 78ae:	 4c899c24c00a0000     	mov	qword ptr [rsp + 0xac0], r11
+;; 78b6:	 4c899c24b80a0000     	mov	qword ptr [rsp + 0xab8], r11
+;; 78be:	 4c899c24b00a0000     	mov	qword ptr [rsp + 0xab0], r11
+;; 78c6:	 4c899c24a80a0000     	mov	qword ptr [rsp + 0xaa8], r11
+;; 78ce:	 4c899c24a00a0000     	mov	qword ptr [rsp + 0xaa0], r11
+;; 78d6:	 4c899c24980a0000     	mov	qword ptr [rsp + 0xa98], r11
+;; 78de:	 4c899c24900a0000     	mov	qword ptr [rsp + 0xa90], r11
+;; 78e6:	 4c899c24880a0000     	mov	qword ptr [rsp + 0xa88], r11
+;; 78ee:	 4c899c24800a0000     	mov	qword ptr [rsp + 0xa80], r11
+;; 78f6:	 4c899c24780a0000     	mov	qword ptr [rsp + 0xa78], r11
+;; 78fe:	 4c899c24700a0000     	mov	qword ptr [rsp + 0xa70], r11
+;; 7906:	 4c899c24680a0000     	mov	qword ptr [rsp + 0xa68], r11
+;; 790e:	 4c899c24600a0000     	mov	qword ptr [rsp + 0xa60], r11
+;; 7916:	 4c899c24580a0000     	mov	qword ptr [rsp + 0xa58], r11
+;; 791e:	 4c899c24500a0000     	mov	qword ptr [rsp + 0xa50], r11
+;; 7926:	 4c899c24480a0000     	mov	qword ptr [rsp + 0xa48], r11
+;; 792e:	 4c899c24400a0000     	mov	qword ptr [rsp + 0xa40], r11
+;; 7936:	 4c899c24380a0000     	mov	qword ptr [rsp + 0xa38], r11
+;; 793e:	 4c899c24300a0000     	mov	qword ptr [rsp + 0xa30], r11
+;; 7946:	 4c899c24280a0000     	mov	qword ptr [rsp + 0xa28], r11
+;; 794e:	 4c899c24200a0000     	mov	qword ptr [rsp + 0xa20], r11
+;; 7956:	 4c899c24180a0000     	mov	qword ptr [rsp + 0xa18], r11
+;; 795e:	 4c899c24100a0000     	mov	qword ptr [rsp + 0xa10], r11
+;; 7966:	 4c899c24080a0000     	mov	qword ptr [rsp + 0xa08], r11
+;; 796e:	 4c899c24000a0000     	mov	qword ptr [rsp + 0xa00], r11
+;; 7976:	 4c899c24f8090000     	mov	qword ptr [rsp + 0x9f8], r11
+;; 797e:	 4c899c24f0090000     	mov	qword ptr [rsp + 0x9f0], r11
+;; 7986:	 4c899c24e8090000     	mov	qword ptr [rsp + 0x9e8], r11
+;; 798e:	 4c899c24e0090000     	mov	qword ptr [rsp + 0x9e0], r11
+;; 7996:	 4c899c24d8090000     	mov	qword ptr [rsp + 0x9d8], r11
+;; 799e:	 4c899c24d0090000     	mov	qword ptr [rsp + 0x9d0], r11
+;; 79a6:	 4c899c24c8090000     	mov	qword ptr [rsp + 0x9c8], r11
+;; 79ae:	 4c899c24c0090000     	mov	qword ptr [rsp + 0x9c0], r11
+;; 79b6:	 4c899c24b8090000     	mov	qword ptr [rsp + 0x9b8], r11
+;; 79be:	 4c899c24b0090000     	mov	qword ptr [rsp + 0x9b0], r11
+;; 79c6:	 4c899c24a8090000     	mov	qword ptr [rsp + 0x9a8], r11
+;; 79ce:	 4c899c24a0090000     	mov	qword ptr [rsp + 0x9a0], r11
+;; 79d6:	 4c899c2498090000     	mov	qword ptr [rsp + 0x998], r11
+;; 79de:	 4c899c2490090000     	mov	qword ptr [rsp + 0x990], r11
+;; 79e6:	 4c899c2488090000     	mov	qword ptr [rsp + 0x988], r11
+;; 79ee:	 4c899c2480090000     	mov	qword ptr [rsp + 0x980], r11
+;; 79f6:	 4c899c2478090000     	mov	qword ptr [rsp + 0x978], r11
+;; 79fe:	 4c899c2470090000     	mov	qword ptr [rsp + 0x970], r11
+;; 7a06:	 4c899c2468090000     	mov	qword ptr [rsp + 0x968], r11
+;; 7a0e:	 4c899c2460090000     	mov	qword ptr [rsp + 0x960], r11
+;; 7a16:	 4c899c2458090000     	mov	qword ptr [rsp + 0x958], r11
+;; 7a1e:	 4c899c2450090000     	mov	qword ptr [rsp + 0x950], r11
+;; 7a26:	 4c899c2448090000     	mov	qword ptr [rsp + 0x948], r11
+;; 7a2e:	 4c899c2440090000     	mov	qword ptr [rsp + 0x940], r11
+;; 7a36:	 4c899c2438090000     	mov	qword ptr [rsp + 0x938], r11
+;; 7a3e:	 4c899c2430090000     	mov	qword ptr [rsp + 0x930], r11
+;; 7a46:	 4c899c2428090000     	mov	qword ptr [rsp + 0x928], r11
+;; 7a4e:	 4c899c2420090000     	mov	qword ptr [rsp + 0x920], r11
+;; 7a56:	 4c899c2418090000     	mov	qword ptr [rsp + 0x918], r11
+;; 7a5e:	 4c899c2410090000     	mov	qword ptr [rsp + 0x910], r11
+;; 7a66:	 4c899c2408090000     	mov	qword ptr [rsp + 0x908], r11
+;; 7a6e:	 4c899c2400090000     	mov	qword ptr [rsp + 0x900], r11
+;; 7a76:	 4c899c24f8080000     	mov	qword ptr [rsp + 0x8f8], r11
+;; 7a7e:	 4c899c24f0080000     	mov	qword ptr [rsp + 0x8f0], r11
+;; 7a86:	 4c899c24e8080000     	mov	qword ptr [rsp + 0x8e8], r11
+;; 7a8e:	 4c899c24e0080000     	mov	qword ptr [rsp + 0x8e0], r11
+;; 7a96:	 4c899c24d8080000     	mov	qword ptr [rsp + 0x8d8], r11
+;; 7a9e:	 4c899c24d0080000     	mov	qword ptr [rsp + 0x8d0], r11
+;; 7aa6:	 4c899c24c8080000     	mov	qword ptr [rsp + 0x8c8], r11
+;; 7aae:	 4c899c24c0080000     	mov	qword ptr [rsp + 0x8c0], r11
+;; 7ab6:	 4c899c24b8080000     	mov	qword ptr [rsp + 0x8b8], r11
+;; 7abe:	 4c899c24b0080000     	mov	qword ptr [rsp + 0x8b0], r11
+;; 7ac6:	 4c899c24a8080000     	mov	qword ptr [rsp + 0x8a8], r11
+;; 7ace:	 4c899c24a0080000     	mov	qword ptr [rsp + 0x8a0], r11
+;; 7ad6:	 4c899c2498080000     	mov	qword ptr [rsp + 0x898], r11
+;; 7ade:	 4c899c2490080000     	mov	qword ptr [rsp + 0x890], r11
+;; 7ae6:	 4c899c2488080000     	mov	qword ptr [rsp + 0x888], r11
+;; 7aee:	 4c899c2480080000     	mov	qword ptr [rsp + 0x880], r11
+;; 7af6:	 4c899c2478080000     	mov	qword ptr [rsp + 0x878], r11
+;; 7afe:	 4c899c2470080000     	mov	qword ptr [rsp + 0x870], r11
+;; 7b06:	 4c899c2468080000     	mov	qword ptr [rsp + 0x868], r11
+;; 7b0e:	 4c899c2460080000     	mov	qword ptr [rsp + 0x860], r11
+;; 7b16:	 4c899c2458080000     	mov	qword ptr [rsp + 0x858], r11
+;; 7b1e:	 4c899c2450080000     	mov	qword ptr [rsp + 0x850], r11
+;; 7b26:	 4c899c2448080000     	mov	qword ptr [rsp + 0x848], r11
+;; 7b2e:	 4c899c2440080000     	mov	qword ptr [rsp + 0x840], r11
+;; 7b36:	 4c899c2438080000     	mov	qword ptr [rsp + 0x838], r11
+;; 7b3e:	 4c899c2430080000     	mov	qword ptr [rsp + 0x830], r11
+;; 7b46:	 4c899c2428080000     	mov	qword ptr [rsp + 0x828], r11
+;; 7b4e:	 4c899c2420080000     	mov	qword ptr [rsp + 0x820], r11
+;; 7b56:	 4c899c2418080000     	mov	qword ptr [rsp + 0x818], r11
+;; 7b5e:	 4c899c2410080000     	mov	qword ptr [rsp + 0x810], r11
+;; 7b66:	 4c899c2408080000     	mov	qword ptr [rsp + 0x808], r11
+;; 7b6e:	 4c899c2400080000     	mov	qword ptr [rsp + 0x800], r11
+;; 7b76:	 4c899c24f8070000     	mov	qword ptr [rsp + 0x7f8], r11
+;; 7b7e:	 4c899c24f0070000     	mov	qword ptr [rsp + 0x7f0], r11
+;; 7b86:	 4c899c24e8070000     	mov	qword ptr [rsp + 0x7e8], r11
+;; 7b8e:	 4c899c24e0070000     	mov	qword ptr [rsp + 0x7e0], r11
+;; 7b96:	 4c899c24d8070000     	mov	qword ptr [rsp + 0x7d8], r11
+;; 7b9e:	 4c899c24d0070000     	mov	qword ptr [rsp + 0x7d0], r11
+;; 7ba6:	 4c899c24c8070000     	mov	qword ptr [rsp + 0x7c8], r11
+;; 7bae:	 4c899c24c0070000     	mov	qword ptr [rsp + 0x7c0], r11
+;; 7bb6:	 4c899c24b8070000     	mov	qword ptr [rsp + 0x7b8], r11
+;; 7bbe:	 4c899c24b0070000     	mov	qword ptr [rsp + 0x7b0], r11
+;; 7bc6:	 4c899c24a8070000     	mov	qword ptr [rsp + 0x7a8], r11
+;; 7bce:	 4c899c24a0070000     	mov	qword ptr [rsp + 0x7a0], r11
+;; 7bd6:	 4c899c2498070000     	mov	qword ptr [rsp + 0x798], r11
+;; 7bde:	 4c899c2490070000     	mov	qword ptr [rsp + 0x790], r11
+;; 7be6:	 4c899c2488070000     	mov	qword ptr [rsp + 0x788], r11
+;; 7bee:	 4c899c2480070000     	mov	qword ptr [rsp + 0x780], r11
+;; 7bf6:	 4c899c2478070000     	mov	qword ptr [rsp + 0x778], r11
+;; 7bfe:	 4c899c2470070000     	mov	qword ptr [rsp + 0x770], r11
+;; 7c06:	 4c899c2468070000     	mov	qword ptr [rsp + 0x768], r11
+;; 7c0e:	 4c899c2460070000     	mov	qword ptr [rsp + 0x760], r11
+;; 7c16:	 4c899c2458070000     	mov	qword ptr [rsp + 0x758], r11
+;; 7c1e:	 4c899c2450070000     	mov	qword ptr [rsp + 0x750], r11
+;; 7c26:	 4c899c2448070000     	mov	qword ptr [rsp + 0x748], r11
+;; 7c2e:	 4c899c2440070000     	mov	qword ptr [rsp + 0x740], r11
+;; 7c36:	 4c899c2438070000     	mov	qword ptr [rsp + 0x738], r11
+;; 7c3e:	 4c899c2430070000     	mov	qword ptr [rsp + 0x730], r11
+;; 7c46:	 4c899c2428070000     	mov	qword ptr [rsp + 0x728], r11
+;; 7c4e:	 4c899c2420070000     	mov	qword ptr [rsp + 0x720], r11
+;; 7c56:	 4c899c2418070000     	mov	qword ptr [rsp + 0x718], r11
+;; 7c5e:	 4c899c2410070000     	mov	qword ptr [rsp + 0x710], r11
+;; 7c66:	 4c899c2408070000     	mov	qword ptr [rsp + 0x708], r11
+;; 7c6e:	 4c899c2400070000     	mov	qword ptr [rsp + 0x700], r11
+;; 7c76:	 4c899c24f8060000     	mov	qword ptr [rsp + 0x6f8], r11
+;; 7c7e:	 4c899c24f0060000     	mov	qword ptr [rsp + 0x6f0], r11
+;; 7c86:	 4c899c24e8060000     	mov	qword ptr [rsp + 0x6e8], r11
+;; 7c8e:	 4c899c24e0060000     	mov	qword ptr [rsp + 0x6e0], r11
+;; 7c96:	 4c899c24d8060000     	mov	qword ptr [rsp + 0x6d8], r11
+;; 7c9e:	 4c899c24d0060000     	mov	qword ptr [rsp + 0x6d0], r11
+;; 7ca6:	 4c899c24c8060000     	mov	qword ptr [rsp + 0x6c8], r11
+;; 7cae:	 4c899c24c0060000     	mov	qword ptr [rsp + 0x6c0], r11
+;; 7cb6:	 4c899c24b8060000     	mov	qword ptr [rsp + 0x6b8], r11
+;; 7cbe:	 4c899c24b0060000     	mov	qword ptr [rsp + 0x6b0], r11
+;; 7cc6:	 4c899c24a8060000     	mov	qword ptr [rsp + 0x6a8], r11
+;; 7cce:	 4c899c24a0060000     	mov	qword ptr [rsp + 0x6a0], r11
+;; 7cd6:	 4c899c2498060000     	mov	qword ptr [rsp + 0x698], r11
+;; 7cde:	 4c899c2490060000     	mov	qword ptr [rsp + 0x690], r11
+;; 7ce6:	 4c899c2488060000     	mov	qword ptr [rsp + 0x688], r11
+;; 7cee:	 4c899c2480060000     	mov	qword ptr [rsp + 0x680], r11
+;; 7cf6:	 4c899c2478060000     	mov	qword ptr [rsp + 0x678], r11
+;; 7cfe:	 4c899c2470060000     	mov	qword ptr [rsp + 0x670], r11
+;; 7d06:	 4c899c2468060000     	mov	qword ptr [rsp + 0x668], r11
+;; 7d0e:	 4c899c2460060000     	mov	qword ptr [rsp + 0x660], r11
+;; 7d16:	 4c899c2458060000     	mov	qword ptr [rsp + 0x658], r11
+;; 7d1e:	 4c899c2450060000     	mov	qword ptr [rsp + 0x650], r11
+;; 7d26:	 4c899c2448060000     	mov	qword ptr [rsp + 0x648], r11
+;; 7d2e:	 4c899c2440060000     	mov	qword ptr [rsp + 0x640], r11
+;; 7d36:	 4c899c2438060000     	mov	qword ptr [rsp + 0x638], r11
+;; 7d3e:	 4c899c2430060000     	mov	qword ptr [rsp + 0x630], r11
+;; 7d46:	 4c899c2428060000     	mov	qword ptr [rsp + 0x628], r11
+;; 7d4e:	 4c899c2420060000     	mov	qword ptr [rsp + 0x620], r11
+;; 7d56:	 4c899c2418060000     	mov	qword ptr [rsp + 0x618], r11
+;; 7d5e:	 4c899c2410060000     	mov	qword ptr [rsp + 0x610], r11
+;; 7d66:	 4c899c2408060000     	mov	qword ptr [rsp + 0x608], r11
+;; 7d6e:	 4c899c2400060000     	mov	qword ptr [rsp + 0x600], r11
+;; 7d76:	 4c899c24f8050000     	mov	qword ptr [rsp + 0x5f8], r11
+;; 7d7e:	 4c899c24f0050000     	mov	qword ptr [rsp + 0x5f0], r11
+;; 7d86:	 4c899c24e8050000     	mov	qword ptr [rsp + 0x5e8], r11
+;; 7d8e:	 4c899c24e0050000     	mov	qword ptr [rsp + 0x5e0], r11
+;; 7d96:	 4c899c24d8050000     	mov	qword ptr [rsp + 0x5d8], r11
+;; 7d9e:	 4c899c24d0050000     	mov	qword ptr [rsp + 0x5d0], r11
+;; 7da6:	 4c899c24c8050000     	mov	qword ptr [rsp + 0x5c8], r11
+;; 7dae:	 4c899c24c0050000     	mov	qword ptr [rsp + 0x5c0], r11
+;; 7db6:	 4c899c24b8050000     	mov	qword ptr [rsp + 0x5b8], r11
+;; 7dbe:	 4c899c24b0050000     	mov	qword ptr [rsp + 0x5b0], r11
+;; 7dc6:	 4c899c24a8050000     	mov	qword ptr [rsp + 0x5a8], r11
+;; 7dce:	 4c899c24a0050000     	mov	qword ptr [rsp + 0x5a0], r11
+;; 7dd6:	 4c899c2498050000     	mov	qword ptr [rsp + 0x598], r11
+;; 7dde:	 4c899c2490050000     	mov	qword ptr [rsp + 0x590], r11
+;; 7de6:	 4c899c2488050000     	mov	qword ptr [rsp + 0x588], r11
+;; 7dee:	 4c899c2480050000     	mov	qword ptr [rsp + 0x580], r11
+;; 7df6:	 4c899c2478050000     	mov	qword ptr [rsp + 0x578], r11
+;; 7dfe:	 4c899c2470050000     	mov	qword ptr [rsp + 0x570], r11
+;; 7e06:	 4c899c2468050000     	mov	qword ptr [rsp + 0x568], r11
+;; 7e0e:	 4c899c2460050000     	mov	qword ptr [rsp + 0x560], r11
+;; 7e16:	 4c899c2458050000     	mov	qword ptr [rsp + 0x558], r11
+;; 7e1e:	 4c899c2450050000     	mov	qword ptr [rsp + 0x550], r11
+;; 7e26:	 4c899c2448050000     	mov	qword ptr [rsp + 0x548], r11
+;; 7e2e:	 4c899c2440050000     	mov	qword ptr [rsp + 0x540], r11
+;; 7e36:	 4c899c2438050000     	mov	qword ptr [rsp + 0x538], r11
+;; 7e3e:	 4c899c2430050000     	mov	qword ptr [rsp + 0x530], r11
+;; 7e46:	 4c899c2428050000     	mov	qword ptr [rsp + 0x528], r11
+;; 7e4e:	 4c899c2420050000     	mov	qword ptr [rsp + 0x520], r11
+;; 7e56:	 4c899c2418050000     	mov	qword ptr [rsp + 0x518], r11
+;; 7e5e:	 4c899c2410050000     	mov	qword ptr [rsp + 0x510], r11
+;; 7e66:	 4c899c2408050000     	mov	qword ptr [rsp + 0x508], r11
+;; 7e6e:	 4c899c2400050000     	mov	qword ptr [rsp + 0x500], r11
+;; 7e76:	 4c899c24f8040000     	mov	qword ptr [rsp + 0x4f8], r11
+;; 7e7e:	 4c899c24f0040000     	mov	qword ptr [rsp + 0x4f0], r11
+;; 7e86:	 4c899c24e8040000     	mov	qword ptr [rsp + 0x4e8], r11
+;; 7e8e:	 4c899c24e0040000     	mov	qword ptr [rsp + 0x4e0], r11
+;; 7e96:	 4c899c24d8040000     	mov	qword ptr [rsp + 0x4d8], r11
+;; 7e9e:	 4c899c24d0040000     	mov	qword ptr [rsp + 0x4d0], r11
+;; 7ea6:	 4c899c24c8040000     	mov	qword ptr [rsp + 0x4c8], r11
+;; 7eae:	 4c899c24c0040000     	mov	qword ptr [rsp + 0x4c0], r11
+;; 7eb6:	 4c899c24b8040000     	mov	qword ptr [rsp + 0x4b8], r11
+;; 7ebe:	 4c899c24b0040000     	mov	qword ptr [rsp + 0x4b0], r11
+;; 7ec6:	 4c899c24a8040000     	mov	qword ptr [rsp + 0x4a8], r11
+;; 7ece:	 4c899c24a0040000     	mov	qword ptr [rsp + 0x4a0], r11
+;; 7ed6:	 4c899c2498040000     	mov	qword ptr [rsp + 0x498], r11
+;; 7ede:	 4c899c2490040000     	mov	qword ptr [rsp + 0x490], r11
+;; 7ee6:	 4c899c2488040000     	mov	qword ptr [rsp + 0x488], r11
+;; 7eee:	 4c899c2480040000     	mov	qword ptr [rsp + 0x480], r11
+;; 7ef6:	 4c899c2478040000     	mov	qword ptr [rsp + 0x478], r11
+;; 7efe:	 4c899c2470040000     	mov	qword ptr [rsp + 0x470], r11
+;; 7f06:	 4c899c2468040000     	mov	qword ptr [rsp + 0x468], r11
+;; 7f0e:	 4c899c2460040000     	mov	qword ptr [rsp + 0x460], r11
+;; 7f16:	 4c899c2458040000     	mov	qword ptr [rsp + 0x458], r11
+;; 7f1e:	 4c899c2450040000     	mov	qword ptr [rsp + 0x450], r11
+;; 7f26:	 4c899c2448040000     	mov	qword ptr [rsp + 0x448], r11
+;; 7f2e:	 4c899c2440040000     	mov	qword ptr [rsp + 0x440], r11
+;; 7f36:	 4c899c2438040000     	mov	qword ptr [rsp + 0x438], r11
+;; 7f3e:	 4c899c2430040000     	mov	qword ptr [rsp + 0x430], r11
+;; 7f46:	 4c899c2428040000     	mov	qword ptr [rsp + 0x428], r11
+;; 7f4e:	 4c899c2420040000     	mov	qword ptr [rsp + 0x420], r11
+;; 7f56:	 4c899c2418040000     	mov	qword ptr [rsp + 0x418], r11
+;; 7f5e:	 4c899c2410040000     	mov	qword ptr [rsp + 0x410], r11
+;; 7f66:	 4c899c2408040000     	mov	qword ptr [rsp + 0x408], r11
+;; 7f6e:	 4c899c2400040000     	mov	qword ptr [rsp + 0x400], r11
+;; 7f76:	 4c899c24f8030000     	mov	qword ptr [rsp + 0x3f8], r11
+;; 7f7e:	 4c899c24f0030000     	mov	qword ptr [rsp + 0x3f0], r11
+;; 7f86:	 4c899c24e8030000     	mov	qword ptr [rsp + 0x3e8], r11
+;; 7f8e:	 4c899c24e0030000     	mov	qword ptr [rsp + 0x3e0], r11
+;; 7f96:	 4c899c24d8030000     	mov	qword ptr [rsp + 0x3d8], r11
+;; 7f9e:	 4c899c24d0030000     	mov	qword ptr [rsp + 0x3d0], r11
+;; 7fa6:	 4c899c24c8030000     	mov	qword ptr [rsp + 0x3c8], r11
+;; 7fae:	 4c899c24c0030000     	mov	qword ptr [rsp + 0x3c0], r11
+;; 7fb6:	 4c899c24b8030000     	mov	qword ptr [rsp + 0x3b8], r11
+;; 7fbe:	 4c899c24b0030000     	mov	qword ptr [rsp + 0x3b0], r11
+;; 7fc6:	 4c899c24a8030000     	mov	qword ptr [rsp + 0x3a8], r11
+;; 7fce:	 4c899c24a0030000     	mov	qword ptr [rsp + 0x3a0], r11
+;; 7fd6:	 4c899c2498030000     	mov	qword ptr [rsp + 0x398], r11
+;; 7fde:	 4c899c2490030000     	mov	qword ptr [rsp + 0x390], r11
+;; 7fe6:	 4c899c2488030000     	mov	qword ptr [rsp + 0x388], r11
+;; 7fee:	 4c899c2480030000     	mov	qword ptr [rsp + 0x380], r11
+;; 7ff6:	 4c899c2478030000     	mov	qword ptr [rsp + 0x378], r11
+;; 7ffe:	 4c899c2470030000     	mov	qword ptr [rsp + 0x370], r11
+;; 8006:	 4c899c2468030000     	mov	qword ptr [rsp + 0x368], r11
+;; 800e:	 4c899c2460030000     	mov	qword ptr [rsp + 0x360], r11
+;; 8016:	 4c899c2458030000     	mov	qword ptr [rsp + 0x358], r11
+;; 801e:	 4c899c2450030000     	mov	qword ptr [rsp + 0x350], r11
+;; 8026:	 4c899c2448030000     	mov	qword ptr [rsp + 0x348], r11
+;; 802e:	 4c899c2440030000     	mov	qword ptr [rsp + 0x340], r11
+;; 8036:	 4c899c2438030000     	mov	qword ptr [rsp + 0x338], r11
+;; 803e:	 4c899c2430030000     	mov	qword ptr [rsp + 0x330], r11
+;; 8046:	 4c899c2428030000     	mov	qword ptr [rsp + 0x328], r11
+;; 804e:	 4c899c2420030000     	mov	qword ptr [rsp + 0x320], r11
+;; 8056:	 4c899c2418030000     	mov	qword ptr [rsp + 0x318], r11
+;; 805e:	 4c899c2410030000     	mov	qword ptr [rsp + 0x310], r11
+;; 8066:	 4c899c2408030000     	mov	qword ptr [rsp + 0x308], r11
+;; 806e:	 4c899c2400030000     	mov	qword ptr [rsp + 0x300], r11
+;; 8076:	 4c899c24f8020000     	mov	qword ptr [rsp + 0x2f8], r11
+;; 807e:	 4c899c24f0020000     	mov	qword ptr [rsp + 0x2f0], r11
+;; 8086:	 4c899c24e8020000     	mov	qword ptr [rsp + 0x2e8], r11
+;; 808e:	 4c899c24e0020000     	mov	qword ptr [rsp + 0x2e0], r11
+;; 8096:	 4c899c24d8020000     	mov	qword ptr [rsp + 0x2d8], r11
+;; 809e:	 4c899c24d0020000     	mov	qword ptr [rsp + 0x2d0], r11
+;; 80a6:	 4c899c24c8020000     	mov	qword ptr [rsp + 0x2c8], r11
+;; 80ae:	 4c899c24c0020000     	mov	qword ptr [rsp + 0x2c0], r11
+;; 80b6:	 4c899c24b8020000     	mov	qword ptr [rsp + 0x2b8], r11
+;; 80be:	 4c899c24b0020000     	mov	qword ptr [rsp + 0x2b0], r11
+;; 80c6:	 4c899c24a8020000     	mov	qword ptr [rsp + 0x2a8], r11
+;; 80ce:	 4c899c24a0020000     	mov	qword ptr [rsp + 0x2a0], r11
+;; 80d6:	 4c899c2498020000     	mov	qword ptr [rsp + 0x298], r11
+;; 80de:	 4c899c2490020000     	mov	qword ptr [rsp + 0x290], r11
+;; 80e6:	 4c899c2488020000     	mov	qword ptr [rsp + 0x288], r11
+;; 80ee:	 4c899c2480020000     	mov	qword ptr [rsp + 0x280], r11
+;; 80f6:	 4c899c2478020000     	mov	qword ptr [rsp + 0x278], r11
+;; 80fe:	 4c899c2470020000     	mov	qword ptr [rsp + 0x270], r11
+;; 8106:	 4c899c2468020000     	mov	qword ptr [rsp + 0x268], r11
+;; 810e:	 4c899c2460020000     	mov	qword ptr [rsp + 0x260], r11
+;; 8116:	 4c899c2458020000     	mov	qword ptr [rsp + 0x258], r11
+;; 811e:	 4c899c2450020000     	mov	qword ptr [rsp + 0x250], r11
+;; 8126:	 4c899c2448020000     	mov	qword ptr [rsp + 0x248], r11
+;; 812e:	 4c899c2440020000     	mov	qword ptr [rsp + 0x240], r11
+;; 8136:	 4c899c2438020000     	mov	qword ptr [rsp + 0x238], r11
+;; 813e:	 4c899c2430020000     	mov	qword ptr [rsp + 0x230], r11
+;; 8146:	 4c899c2428020000     	mov	qword ptr [rsp + 0x228], r11
+;; 814e:	 4c899c2420020000     	mov	qword ptr [rsp + 0x220], r11
+;; 8156:	 4c899c2418020000     	mov	qword ptr [rsp + 0x218], r11
+;; 815e:	 4c899c2410020000     	mov	qword ptr [rsp + 0x210], r11
+;; 8166:	 4c899c2408020000     	mov	qword ptr [rsp + 0x208], r11
+;; 816e:	 4c899c2400020000     	mov	qword ptr [rsp + 0x200], r11
+;; 8176:	 4c899c24f8010000     	mov	qword ptr [rsp + 0x1f8], r11
+;; 817e:	 4c899c24f0010000     	mov	qword ptr [rsp + 0x1f0], r11
+;; 8186:	 4c899c24e8010000     	mov	qword ptr [rsp + 0x1e8], r11
+;; 818e:	 4c899c24e0010000     	mov	qword ptr [rsp + 0x1e0], r11
+;; 8196:	 4c899c24d8010000     	mov	qword ptr [rsp + 0x1d8], r11
+;; 819e:	 4c899c24d0010000     	mov	qword ptr [rsp + 0x1d0], r11
+;; 81a6:	 4c899c24c8010000     	mov	qword ptr [rsp + 0x1c8], r11
+;; 81ae:	 4c899c24c0010000     	mov	qword ptr [rsp + 0x1c0], r11
+;; 81b6:	 4c899c24b8010000     	mov	qword ptr [rsp + 0x1b8], r11
+;; 81be:	 4c899c24b0010000     	mov	qword ptr [rsp + 0x1b0], r11
+;; 81c6:	 4c899c24a8010000     	mov	qword ptr [rsp + 0x1a8], r11
+;; 81ce:	 4c899c24a0010000     	mov	qword ptr [rsp + 0x1a0], r11
+;; 81d6:	 4c899c2498010000     	mov	qword ptr [rsp + 0x198], r11
+;; 81de:	 4c899c2490010000     	mov	qword ptr [rsp + 0x190], r11
+;; 81e6:	 4c899c2488010000     	mov	qword ptr [rsp + 0x188], r11
+;; 81ee:	 4c899c2480010000     	mov	qword ptr [rsp + 0x180], r11
+;; 81f6:	 4c899c2478010000     	mov	qword ptr [rsp + 0x178], r11
+;; 81fe:	 4c899c2470010000     	mov	qword ptr [rsp + 0x170], r11
+;; 8206:	 4c899c2468010000     	mov	qword ptr [rsp + 0x168], r11
+;; 820e:	 4c899c2460010000     	mov	qword ptr [rsp + 0x160], r11
+;; 8216:	 4c899c2458010000     	mov	qword ptr [rsp + 0x158], r11
+;; 821e:	 4c899c2450010000     	mov	qword ptr [rsp + 0x150], r11
+;; 8226:	 4c899c2448010000     	mov	qword ptr [rsp + 0x148], r11
+;; 822e:	 4c899c2440010000     	mov	qword ptr [rsp + 0x140], r11
+;; 8236:	 4c899c2438010000     	mov	qword ptr [rsp + 0x138], r11
+;; 823e:	 4c899c2430010000     	mov	qword ptr [rsp + 0x130], r11
+;; 8246:	 4c899c2428010000     	mov	qword ptr [rsp + 0x128], r11
+;; 824e:	 4c899c2420010000     	mov	qword ptr [rsp + 0x120], r11
+;; 8256:	 4c899c2418010000     	mov	qword ptr [rsp + 0x118], r11
+;; 825e:	 4c899c2410010000     	mov	qword ptr [rsp + 0x110], r11
+;; 8266:	 4c899c2408010000     	mov	qword ptr [rsp + 0x108], r11
+;; 826e:	 4c899c2400010000     	mov	qword ptr [rsp + 0x100], r11
+;; 8276:	 4c899c24f8000000     	mov	qword ptr [rsp + 0xf8], r11
+;; 827e:	 4c899c24f0000000     	mov	qword ptr [rsp + 0xf0], r11
+;; 8286:	 4c899c24e8000000     	mov	qword ptr [rsp + 0xe8], r11
+;; 828e:	 4c899c24e0000000     	mov	qword ptr [rsp + 0xe0], r11
+;; 8296:	 4c899c24d8000000     	mov	qword ptr [rsp + 0xd8], r11
+;; 829e:	 4c899c24d0000000     	mov	qword ptr [rsp + 0xd0], r11
+;; 82a6:	 4c899c24c8000000     	mov	qword ptr [rsp + 0xc8], r11
+;; 82ae:	 4c899c24c0000000     	mov	qword ptr [rsp + 0xc0], r11
+;; 82b6:	 4c899c24b8000000     	mov	qword ptr [rsp + 0xb8], r11
+;; 82be:	 4c899c24b0000000     	mov	qword ptr [rsp + 0xb0], r11
+;; 82c6:	 4c899c24a8000000     	mov	qword ptr [rsp + 0xa8], r11
+;; 82ce:	 4c899c24a0000000     	mov	qword ptr [rsp + 0xa0], r11
+;; 82d6:	 4c899c2498000000     	mov	qword ptr [rsp + 0x98], r11
+;; 82de:	 4c899c2490000000     	mov	qword ptr [rsp + 0x90], r11
+;; 82e6:	 4c899c2488000000     	mov	qword ptr [rsp + 0x88], r11
+;; 82ee:	 4c899c2480000000     	mov	qword ptr [rsp + 0x80], r11
+;; 82f6:	 4c895c2478           	mov	qword ptr [rsp + 0x78], r11
+;; 82fb:	 4c895c2470           	mov	qword ptr [rsp + 0x70], r11
+;; 8300:	 4c895c2468           	mov	qword ptr [rsp + 0x68], r11
+;; 8305:	 4c895c2460           	mov	qword ptr [rsp + 0x60], r11
+;; 830a:	 4c895c2458           	mov	qword ptr [rsp + 0x58], r11
+;; 830f:	 4c895c2450           	mov	qword ptr [rsp + 0x50], r11
+;; 8314:	 4c895c2448           	mov	qword ptr [rsp + 0x48], r11
+;; 8319:	 4c895c2440           	mov	qword ptr [rsp + 0x40], r11
+;; 831e:	 4c895c2438           	mov	qword ptr [rsp + 0x38], r11
+;; 8323:	 4c895c2430           	mov	qword ptr [rsp + 0x30], r11
+;; 8328:	 4c895c2428           	mov	qword ptr [rsp + 0x28], r11
+;; 832d:	 4c895c2420           	mov	qword ptr [rsp + 0x20], r11
+;; 8332:	 4c895c2418           	mov	qword ptr [rsp + 0x18], r11
+;; 8337:	 4c895c2410           	mov	qword ptr [rsp + 0x10], r11
+;; 833c:	 4c895c2408           	mov	qword ptr [rsp + 8], r11
+;; 8341:	 4c893424             	mov	qword ptr [rsp], r14
+;; 8345:	 4881c448830000       	add	rsp, 0x8348
+;; 834c:	 5d                   	pop	rbp
+;; 834d:	 c3                   	ret	
+;; 834e:	 0f0b                 	ud2	
+;; 8350:	 0f0b                 	ud2	
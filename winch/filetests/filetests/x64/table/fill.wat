@@ -21,77 +21,111 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec20             	sub	rsp, 0x20
-;;    8:	 897c241c             	mov	dword ptr [rsp + 0x1c], edi
-;;    c:	 89742418             	mov	dword ptr [rsp + 0x18], esi
-;;   10:	 89542414             	mov	dword ptr [rsp + 0x14], edx
-;;   14:	 c744241000000000     	mov	dword ptr [rsp + 0x10], 0
-;;   1c:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c330000000       	add	r11, 0x30
+;;   15:	 0f82bc000000         	jb	0xd7
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f82b5000000         	jb	0xd9
+;;   24:	 4883ec20             	sub	rsp, 0x20
+;;   28:	 8954241c             	mov	dword ptr [rsp + 0x1c], edx
+;;   2c:	 894c2418             	mov	dword ptr [rsp + 0x18], ecx
+;;   30:	 4489442414           	mov	dword ptr [rsp + 0x14], r8d
+;;   35:	 c744241000000000     	mov	dword ptr [rsp + 0x10], 0
+;;   3d:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   25:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   2a:	 8b4c2418             	mov	ecx, dword ptr [rsp + 0x18]
-;;   2e:	 4c89f2               	mov	rdx, r14
-;;   31:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
-;;   34:	 39d9                 	cmp	ecx, ebx
-;;   36:	 0f8381000000         	jae	0xbd
-;;   3c:	 4189cb               	mov	r11d, ecx
-;;   3f:	 4d6bdb08             	imul	r11, r11, 8
-;;   43:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
-;;   47:	 4889d6               	mov	rsi, rdx
-;;   4a:	 4c01da               	add	rdx, r11
-;;   4d:	 39d9                 	cmp	ecx, ebx
-;;   4f:	 480f43d6             	cmovae	rdx, rsi
-;;   53:	 488b02               	mov	rax, qword ptr [rdx]
-;;   56:	 4885c0               	test	rax, rax
-;;   59:	 0f8523000000         	jne	0x82
-;;   5f:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   63:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
-;;   67:	 4156                 	push	r14
-;;   69:	 51                   	push	rcx
-;;   6a:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
-;;   6f:	 be00000000           	mov	esi, 0
-;;   74:	 8b1424               	mov	edx, dword ptr [rsp]
-;;   77:	 ffd3                 	call	rbx
-;;   79:	 4883c410             	add	rsp, 0x10
-;;   7d:	 e904000000           	jmp	0x86
-;;   82:	 4883e0fe             	and	rax, 0xfffffffffffffffe
-;;   86:	 488944240c           	mov	qword ptr [rsp + 0xc], rax
-;;   8b:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   8f:	 498b4368             	mov	rax, qword ptr [r11 + 0x68]
-;;   93:	 4156                 	push	r14
-;;   95:	 4883ec08             	sub	rsp, 8
-;;   99:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
-;;   9e:	 be01000000           	mov	esi, 1
-;;   a3:	 8b54242c             	mov	edx, dword ptr [rsp + 0x2c]
-;;   a7:	 488b4c241c           	mov	rcx, qword ptr [rsp + 0x1c]
-;;   ac:	 448b442424           	mov	r8d, dword ptr [rsp + 0x24]
-;;   b1:	 ffd0                 	call	rax
-;;   b3:	 4883c410             	add	rsp, 0x10
-;;   b7:	 4883c420             	add	rsp, 0x20
-;;   bb:	 5d                   	pop	rbp
-;;   bc:	 c3                   	ret	
-;;   bd:	 0f0b                 	ud2	
+;;   46:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   4b:	 8b4c2418             	mov	ecx, dword ptr [rsp + 0x18]
+;;   4f:	 4c89f2               	mov	rdx, r14
+;;   52:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
+;;   55:	 39d9                 	cmp	ecx, ebx
+;;   57:	 0f837e000000         	jae	0xdb
+;;   5d:	 31f6                 	xor	esi, esi
+;;   5f:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
+;;   63:	 4189cb               	mov	r11d, ecx
+;;   66:	 39d9                 	cmp	ecx, ebx
+;;   68:	 4c0f43de             	cmovae	r11, rsi
+;;   6c:	 4a8b04da             	mov	rax, qword ptr [rdx + r11*8]
+;;   70:	 4885c0               	test	rax, rax
+;;   73:	 0f8523000000         	jne	0x9c
+;;   79:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   7d:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
+;;   81:	 4156                 	push	r14
+;;   83:	 51                   	push	rcx
+;;   84:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   89:	 be00000000           	mov	esi, 0
+;;   8e:	 8b1424               	mov	edx, dword ptr [rsp]
+;;   91:	 ffd3                 	call	rbx
+;;   93:	 4883c410             	add	rsp, 0x10
+;;   97:	 e904000000           	jmp	0xa0
+;;   9c:	 4883e0fe             	and	rax, 0xfffffffffffffffe
+;;   a0:	 488944240c           	mov	qword ptr [rsp + 0xc], rax
+;;   a5:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   a9:	 498b4368             	mov	rax, qword ptr [r11 + 0x68]
+;;   ad:	 4156                 	push	r14
+;;   af:	 4883ec08             	sub	rsp, 8
+;;   b3:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   b8:	 be01000000           	mov	esi, 1
+;;   bd:	 8b54242c             	mov	edx, dword ptr [rsp + 0x2c]
+;;   c1:	 488b4c241c           	mov	rcx, qword ptr [rsp + 0x1c]
+;;   c6:	 448b442424           	mov	r8d, dword ptr [rsp + 0x24]
+;;   cb:	 ffd0                 	call	rax
+;;   cd:	 4883c410             	add	rsp, 0x10
+;;   d1:	 4883c420             	add	rsp, 0x20
+;;   d5:	 5d                   	pop	rbp
+;;   d6:	 c3                   	ret	
+;;   d7:	 0f0b                 	ud2	
+;;   d9:	 0f0b                 	ud2	
+;;   db:	 0f0b                 	ud2	
@@ -11,44 +11,60 @@
 
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   15:	 4c89f2               	mov	rdx, r14
-;;   18:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
-;;   1b:	 39d9                 	cmp	ecx, ebx
-;;   1d:	 0f8350000000         	jae	0x73
-;;   23:	 4189cb               	mov	r11d, ecx
-;;   26:	 4d6bdb08             	imul	r11, r11, 8
-;;   2a:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
-;;   2e:	 4889d6               	mov	rsi, rdx
-;;   31:	 4c01da               	add	rdx, r11
-;;   34:	 39d9                 	cmp	ecx, ebx
-;;   36:	 480f43d6             	cmovae	rdx, rsi
-;;   3a:	 488b02               	mov	rax, qword ptr [rdx]
-;;   3d:	 4885c0               	test	rax, rax
-;;   40:	 0f8523000000         	jne	0x69
-;;   46:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   4a:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
-;;   4e:	 4156                 	push	r14
-;;   50:	 51                   	push	rcx
-;;   51:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
-;;   56:	 be00000000           	mov	esi, 0
-;;   5b:	 8b1424               	mov	edx, dword ptr [rsp]
-;;   5e:	 ffd3                 	call	rbx
-;;   60:	 4883c410             	add	rsp, 0x10
-;;   64:	 e904000000           	jmp	0x6d
-;;   69:	 4883e0fe             	and	rax, 0xfffffffffffffffe
-;;   6d:	 4883c410             	add	rsp, 0x10
-;;   71:	 5d                   	pop	rbp
-;;   72:	 c3                   	ret	
-;;   73:	 0f0b                 	ud2	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f8271000000         	jb	0x8c
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f826a000000         	jb	0x8e
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   35:	 4c89f2               	mov	rdx, r14
+;;   38:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
+;;   3b:	 39d9                 	cmp	ecx, ebx
+;;   3d:	 0f834d000000         	jae	0x90
+;;   43:	 31f6                 	xor	esi, esi
+;;   45:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
+;;   49:	 4189cb               	mov	r11d, ecx
+;;   4c:	 39d9                 	cmp	ecx, ebx
+;;   4e:	 4c0f43de             	cmovae	r11, rsi
+;;   52:	 4a8b04da             	mov	rax, qword ptr [rdx + r11*8]
+;;   56:	 4885c0               	test	rax, rax
+;;   59:	 0f8523000000         	jne	0x82
+;;   5f:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   63:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
+;;   67:	 4156                 	push	r14
+;;   69:	 51                   	push	rcx
+;;   6a:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   6f:	 be00000000           	mov	esi, 0
+;;   74:	 8b1424               	mov	edx, dword ptr [rsp]
+;;   77:	 ffd3                 	call	rbx
+;;   79:	 4883c410             	add	rsp, 0x10
+;;   7d:	 e904000000           	jmp	0x86
+;;   82:	 4883e0fe             	and	rax, 0xfffffffffffffffe
+;;   86:	 4883c410             	add	rsp, 0x10
+;;   8a:	 5d                   	pop	rbp
+;;   8b:	 c3                   	ret	
+;;   8c:	 0f0b                 	ud2	
+;;   8e:	 0f0b                 	ud2	
+;;   90:	 0f0b                 	ud2	
@@ -11,19 +11,28 @@
 
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48897c2408           	mov	qword ptr [rsp + 8], rdi
-;;    d:	 4c893424             	mov	qword ptr [rsp], r14
-;;   11:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   15:	 498b5b50             	mov	rbx, qword ptr [r11 + 0x50]
-;;   19:	 4156                 	push	r14
-;;   1b:	 4883ec08             	sub	rsp, 8
-;;   1f:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
-;;   24:	 be00000000           	mov	esi, 0
-;;   29:	 ba0a000000           	mov	edx, 0xa
-;;   2e:	 488b4c2418           	mov	rcx, qword ptr [rsp + 0x18]
-;;   33:	 ffd3                 	call	rbx
-;;   35:	 4883c410             	add	rsp, 0x10
-;;   39:	 4883c410             	add	rsp, 0x10
-;;   3d:	 5d                   	pop	rbp
-;;   3e:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f8244000000         	jb	0x5f
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f823d000000         	jb	0x61
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 4889542408           	mov	qword ptr [rsp + 8], rdx
+;;   2d:	 4c893424             	mov	qword ptr [rsp], r14
+;;   31:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   35:	 498b5b50             	mov	rbx, qword ptr [r11 + 0x50]
+;;   39:	 4156                 	push	r14
+;;   3b:	 4883ec08             	sub	rsp, 8
+;;   3f:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   44:	 be00000000           	mov	esi, 0
+;;   49:	 ba0a000000           	mov	edx, 0xa
+;;   4e:	 488b4c2418           	mov	rcx, qword ptr [rsp + 0x18]
+;;   53:	 ffd3                 	call	rbx
+;;   55:	 4883c410             	add	rsp, 0x10
+;;   59:	 4883c410             	add	rsp, 0x10
+;;   5d:	 5d                   	pop	rbp
+;;   5e:	 c3                   	ret	
+;;   5f:	 0f0b                 	ud2	
+;;   61:	 0f0b                 	ud2	
@@ -35,193 +35,261 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b805000000           	mov	eax, 5
-;;   11:	 4883c408             	add	rsp, 8
-;;   15:	 5d                   	pop	rbp
-;;   16:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f821c000000         	jb	0x37
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8215000000         	jb	0x39
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b805000000           	mov	eax, 5
+;;   31:	 4883c408             	add	rsp, 8
+;;   35:	 5d                   	pop	rbp
+;;   36:	 c3                   	ret	
+;;   37:	 0f0b                 	ud2	
+;;   39:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b806000000           	mov	eax, 6
-;;   11:	 4883c408             	add	rsp, 8
-;;   15:	 5d                   	pop	rbp
-;;   16:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f821c000000         	jb	0x37
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8215000000         	jb	0x39
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b806000000           	mov	eax, 6
+;;   31:	 4883c408             	add	rsp, 8
+;;   35:	 5d                   	pop	rbp
+;;   36:	 c3                   	ret	
+;;   37:	 0f0b                 	ud2	
+;;   39:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b807000000           	mov	eax, 7
-;;   11:	 4883c408             	add	rsp, 8
-;;   15:	 5d                   	pop	rbp
-;;   16:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f821c000000         	jb	0x37
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8215000000         	jb	0x39
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b807000000           	mov	eax, 7
+;;   31:	 4883c408             	add	rsp, 8
+;;   35:	 5d                   	pop	rbp
+;;   36:	 c3                   	ret	
+;;   37:	 0f0b                 	ud2	
+;;   39:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b808000000           	mov	eax, 8
-;;   11:	 4883c408             	add	rsp, 8
-;;   15:	 5d                   	pop	rbp
-;;   16:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f821c000000         	jb	0x37
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8215000000         	jb	0x39
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b808000000           	mov	eax, 8
+;;   31:	 4883c408             	add	rsp, 8
+;;   35:	 5d                   	pop	rbp
+;;   36:	 c3                   	ret	
+;;   37:	 0f0b                 	ud2	
+;;   39:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 b809000000           	mov	eax, 9
-;;   11:	 4883c408             	add	rsp, 8
-;;   15:	 5d                   	pop	rbp
-;;   16:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f821c000000         	jb	0x37
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8215000000         	jb	0x39
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 b809000000           	mov	eax, 9
+;;   31:	 4883c408             	add	rsp, 8
+;;   35:	 5d                   	pop	rbp
+;;   36:	 c3                   	ret	
+;;   37:	 0f0b                 	ud2	
+;;   39:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   10:	 498b4310             	mov	rax, qword ptr [r11 + 0x10]
-;;   14:	 4156                 	push	r14
-;;   16:	 488b3c24             	mov	rdi, qword ptr [rsp]
-;;   1a:	 be00000000           	mov	esi, 0
-;;   1f:	 ba01000000           	mov	edx, 1
-;;   24:	 b907000000           	mov	ecx, 7
-;;   29:	 41b800000000         	mov	r8d, 0
-;;   2f:	 41b904000000         	mov	r9d, 4
-;;   35:	 ffd0                 	call	rax
-;;   37:	 4883c408             	add	rsp, 8
-;;   3b:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   3f:	 498b4318             	mov	rax, qword ptr [r11 + 0x18]
-;;   43:	 4156                 	push	r14
-;;   45:	 488b3c24             	mov	rdi, qword ptr [rsp]
-;;   49:	 be01000000           	mov	esi, 1
-;;   4e:	 ffd0                 	call	rax
-;;   50:	 4883c408             	add	rsp, 8
-;;   54:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   58:	 498b4310             	mov	rax, qword ptr [r11 + 0x10]
-;;   5c:	 4156                 	push	r14
-;;   5e:	 488b3c24             	mov	rdi, qword ptr [rsp]
-;;   62:	 be00000000           	mov	esi, 0
-;;   67:	 ba03000000           	mov	edx, 3
-;;   6c:	 b90f000000           	mov	ecx, 0xf
-;;   71:	 41b801000000         	mov	r8d, 1
-;;   77:	 41b903000000         	mov	r9d, 3
-;;   7d:	 ffd0                 	call	rax
-;;   7f:	 4883c408             	add	rsp, 8
-;;   83:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   87:	 498b4318             	mov	rax, qword ptr [r11 + 0x18]
-;;   8b:	 4156                 	push	r14
-;;   8d:	 488b3c24             	mov	rdi, qword ptr [rsp]
-;;   91:	 be03000000           	mov	esi, 3
-;;   96:	 ffd0                 	call	rax
-;;   98:	 4883c408             	add	rsp, 8
-;;   9c:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   a0:	 498b4308             	mov	rax, qword ptr [r11 + 8]
-;;   a4:	 4156                 	push	r14
-;;   a6:	 488b3c24             	mov	rdi, qword ptr [rsp]
-;;   aa:	 be00000000           	mov	esi, 0
-;;   af:	 ba00000000           	mov	edx, 0
-;;   b4:	 b914000000           	mov	ecx, 0x14
-;;   b9:	 41b80f000000         	mov	r8d, 0xf
-;;   bf:	 41b905000000         	mov	r9d, 5
-;;   c5:	 ffd0                 	call	rax
-;;   c7:	 4883c408             	add	rsp, 8
-;;   cb:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   cf:	 498b4308             	mov	rax, qword ptr [r11 + 8]
-;;   d3:	 4156                 	push	r14
-;;   d5:	 488b3c24             	mov	rdi, qword ptr [rsp]
-;;   d9:	 be00000000           	mov	esi, 0
-;;   de:	 ba00000000           	mov	edx, 0
-;;   e3:	 b915000000           	mov	ecx, 0x15
-;;   e8:	 41b81d000000         	mov	r8d, 0x1d
-;;   ee:	 41b901000000         	mov	r9d, 1
-;;   f4:	 ffd0                 	call	rax
-;;   f6:	 4883c408             	add	rsp, 8
-;;   fa:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   fe:	 498b4308             	mov	rax, qword ptr [r11 + 8]
-;;  102:	 4156                 	push	r14
-;;  104:	 488b3c24             	mov	rdi, qword ptr [rsp]
-;;  108:	 be00000000           	mov	esi, 0
-;;  10d:	 ba00000000           	mov	edx, 0
-;;  112:	 b918000000           	mov	ecx, 0x18
-;;  117:	 41b80a000000         	mov	r8d, 0xa
-;;  11d:	 41b901000000         	mov	r9d, 1
-;;  123:	 ffd0                 	call	rax
-;;  125:	 4883c408             	add	rsp, 8
-;;  129:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;  12d:	 498b4308             	mov	rax, qword ptr [r11 + 8]
-;;  131:	 4156                 	push	r14
-;;  133:	 488b3c24             	mov	rdi, qword ptr [rsp]
-;;  137:	 be00000000           	mov	esi, 0
-;;  13c:	 ba00000000           	mov	edx, 0
-;;  141:	 b90d000000           	mov	ecx, 0xd
-;;  146:	 41b80b000000         	mov	r8d, 0xb
-;;  14c:	 41b904000000         	mov	r9d, 4
-;;  152:	 ffd0                 	call	rax
-;;  154:	 4883c408             	add	rsp, 8
-;;  158:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;  15c:	 498b4308             	mov	rax, qword ptr [r11 + 8]
-;;  160:	 4156                 	push	r14
-;;  162:	 488b3c24             	mov	rdi, qword ptr [rsp]
-;;  166:	 be00000000           	mov	esi, 0
-;;  16b:	 ba00000000           	mov	edx, 0
-;;  170:	 b913000000           	mov	ecx, 0x13
-;;  175:	 41b814000000         	mov	r8d, 0x14
-;;  17b:	 41b905000000         	mov	r9d, 5
-;;  181:	 ffd0                 	call	rax
-;;  183:	 4883c408             	add	rsp, 8
-;;  187:	 4883c408             	add	rsp, 8
-;;  18b:	 5d                   	pop	rbp
-;;  18c:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c310000000       	add	r11, 0x10
+;;   15:	 0f8292010000         	jb	0x1ad
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f828b010000         	jb	0x1af
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   30:	 498b4310             	mov	rax, qword ptr [r11 + 0x10]
+;;   34:	 4156                 	push	r14
+;;   36:	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;   3a:	 be00000000           	mov	esi, 0
+;;   3f:	 ba01000000           	mov	edx, 1
+;;   44:	 b907000000           	mov	ecx, 7
+;;   49:	 41b800000000         	mov	r8d, 0
+;;   4f:	 41b904000000         	mov	r9d, 4
+;;   55:	 ffd0                 	call	rax
+;;   57:	 4883c408             	add	rsp, 8
+;;   5b:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   5f:	 498b4318             	mov	rax, qword ptr [r11 + 0x18]
+;;   63:	 4156                 	push	r14
+;;   65:	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;   69:	 be01000000           	mov	esi, 1
+;;   6e:	 ffd0                 	call	rax
+;;   70:	 4883c408             	add	rsp, 8
+;;   74:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   78:	 498b4310             	mov	rax, qword ptr [r11 + 0x10]
+;;   7c:	 4156                 	push	r14
+;;   7e:	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;   82:	 be00000000           	mov	esi, 0
+;;   87:	 ba03000000           	mov	edx, 3
+;;   8c:	 b90f000000           	mov	ecx, 0xf
+;;   91:	 41b801000000         	mov	r8d, 1
+;;   97:	 41b903000000         	mov	r9d, 3
+;;   9d:	 ffd0                 	call	rax
+;;   9f:	 4883c408             	add	rsp, 8
+;;   a3:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   a7:	 498b4318             	mov	rax, qword ptr [r11 + 0x18]
+;;   ab:	 4156                 	push	r14
+;;   ad:	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;   b1:	 be03000000           	mov	esi, 3
+;;   b6:	 ffd0                 	call	rax
+;;   b8:	 4883c408             	add	rsp, 8
+;;   bc:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   c0:	 498b4308             	mov	rax, qword ptr [r11 + 8]
+;;   c4:	 4156                 	push	r14
+;;   c6:	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;   ca:	 be00000000           	mov	esi, 0
+;;   cf:	 ba00000000           	mov	edx, 0
+;;   d4:	 b914000000           	mov	ecx, 0x14
+;;   d9:	 41b80f000000         	mov	r8d, 0xf
+;;   df:	 41b905000000         	mov	r9d, 5
+;;   e5:	 ffd0                 	call	rax
+;;   e7:	 4883c408             	add	rsp, 8
+;;   eb:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   ef:	 498b4308             	mov	rax, qword ptr [r11 + 8]
+;;   f3:	 4156                 	push	r14
+;;   f5:	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;   f9:	 be00000000           	mov	esi, 0
+;;   fe:	 ba00000000           	mov	edx, 0
+;;  103:	 b915000000           	mov	ecx, 0x15
+;;  108:	 41b81d000000         	mov	r8d, 0x1d
+;;  10e:	 41b901000000         	mov	r9d, 1
+;;  114:	 ffd0                 	call	rax
+;;  116:	 4883c408             	add	rsp, 8
+;;  11a:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;  11e:	 498b4308             	mov	rax, qword ptr [r11 + 8]
+;;  122:	 4156                 	push	r14
+;;  124:	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;  128:	 be00000000           	mov	esi, 0
+;;  12d:	 ba00000000           	mov	edx, 0
+;;  132:	 b918000000           	mov	ecx, 0x18
+;;  137:	 41b80a000000         	mov	r8d, 0xa
+;;  13d:	 41b901000000         	mov	r9d, 1
+;;  143:	 ffd0                 	call	rax
+;;  145:	 4883c408             	add	rsp, 8
+;;  149:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;  14d:	 498b4308             	mov	rax, qword ptr [r11 + 8]
+;;  151:	 4156                 	push	r14
+;;  153:	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;  157:	 be00000000           	mov	esi, 0
+;;  15c:	 ba00000000           	mov	edx, 0
+;;  161:	 b90d000000           	mov	ecx, 0xd
+;;  166:	 41b80b000000         	mov	r8d, 0xb
+;;  16c:	 41b904000000         	mov	r9d, 4
+;;  172:	 ffd0                 	call	rax
+;;  174:	 4883c408             	add	rsp, 8
+;;  178:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;  17c:	 498b4308             	mov	rax, qword ptr [r11 + 8]
+;;  180:	 4156                 	push	r14
+;;  182:	 488b3c24             	mov	rdi, qword ptr [rsp]
+;;  186:	 be00000000           	mov	esi, 0
+;;  18b:	 ba00000000           	mov	edx, 0
+;;  190:	 b913000000           	mov	ecx, 0x13
+;;  195:	 41b814000000         	mov	r8d, 0x14
+;;  19b:	 41b905000000         	mov	r9d, 5
+;;  1a1:	 ffd0                 	call	rax
+;;  1a3:	 4883c408             	add	rsp, 8
+;;  1a7:	 4883c408             	add	rsp, 8
+;;  1ab:	 5d                   	pop	rbp
+;;  1ac:	 c3                   	ret	
+;;  1ad:	 0f0b                 	ud2	
+;;  1af:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   11:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   15:	 4c89f2               	mov	rdx, r14
-;;   18:	 8b9af0000000         	mov	ebx, dword ptr [rdx + 0xf0]
-;;   1e:	 39d9                 	cmp	ecx, ebx
-;;   20:	 0f8376000000         	jae	0x9c
-;;   26:	 4189cb               	mov	r11d, ecx
-;;   29:	 4d6bdb08             	imul	r11, r11, 8
-;;   2d:	 488b92e8000000       	mov	rdx, qword ptr [rdx + 0xe8]
-;;   34:	 4889d6               	mov	rsi, rdx
-;;   37:	 4c01da               	add	rdx, r11
-;;   3a:	 39d9                 	cmp	ecx, ebx
-;;   3c:	 480f43d6             	cmovae	rdx, rsi
-;;   40:	 488b02               	mov	rax, qword ptr [rdx]
-;;   43:	 4885c0               	test	rax, rax
-;;   46:	 0f8523000000         	jne	0x6f
-;;   4c:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   50:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
-;;   54:	 4156                 	push	r14
-;;   56:	 51                   	push	rcx
-;;   57:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
-;;   5c:	 be00000000           	mov	esi, 0
-;;   61:	 8b1424               	mov	edx, dword ptr [rsp]
-;;   64:	 ffd3                 	call	rbx
-;;   66:	 4883c410             	add	rsp, 0x10
-;;   6a:	 e904000000           	jmp	0x73
-;;   6f:	 4883e0fe             	and	rax, 0xfffffffffffffffe
-;;   73:	 4885c0               	test	rax, rax
-;;   76:	 0f8422000000         	je	0x9e
-;;   7c:	 4d8b5e40             	mov	r11, qword ptr [r14 + 0x40]
-;;   80:	 418b0b               	mov	ecx, dword ptr [r11]
-;;   83:	 8b5018               	mov	edx, dword ptr [rax + 0x18]
-;;   86:	 39d1                 	cmp	ecx, edx
-;;   88:	 0f8512000000         	jne	0xa0
-;;   8e:	 50                   	push	rax
-;;   8f:	 59                   	pop	rcx
-;;   90:	 488b5110             	mov	rdx, qword ptr [rcx + 0x10]
-;;   94:	 ffd2                 	call	rdx
-;;   96:	 4883c410             	add	rsp, 0x10
-;;   9a:	 5d                   	pop	rbp
-;;   9b:	 c3                   	ret	
-;;   9c:	 0f0b                 	ud2	
-;;   9e:	 0f0b                 	ud2	
-;;   a0:	 0f0b                 	ud2	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f82b3000000         	jb	0xce
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f82ac000000         	jb	0xd0
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   31:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   35:	 4c89f2               	mov	rdx, r14
+;;   38:	 8b9af0000000         	mov	ebx, dword ptr [rdx + 0xf0]
+;;   3e:	 39d9                 	cmp	ecx, ebx
+;;   40:	 0f838c000000         	jae	0xd2
+;;   46:	 31f6                 	xor	esi, esi
+;;   48:	 488b92e8000000       	mov	rdx, qword ptr [rdx + 0xe8]
+;;   4f:	 4189cb               	mov	r11d, ecx
+;;   52:	 39d9                 	cmp	ecx, ebx
+;;   54:	 4c0f43de             	cmovae	r11, rsi
+;;   58:	 4a8b04da             	mov	rax, qword ptr [rdx + r11*8]
+;;   5c:	 4885c0               	test	rax, rax
+;;   5f:	 0f8523000000         	jne	0x88
+;;   65:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   69:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
+;;   6d:	 4156                 	push	r14
+;;   6f:	 51                   	push	rcx
+;;   70:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   75:	 be00000000           	mov	esi, 0
+;;   7a:	 8b1424               	mov	edx, dword ptr [rsp]
+;;   7d:	 ffd3                 	call	rbx
+;;   7f:	 4883c410             	add	rsp, 0x10
+;;   83:	 e904000000           	jmp	0x8c
+;;   88:	 4883e0fe             	and	rax, 0xfffffffffffffffe
+;;   8c:	 4885c0               	test	rax, rax
+;;   8f:	 0f843f000000         	je	0xd4
+;;   95:	 4d8b5e40             	mov	r11, qword ptr [r14 + 0x40]
+;;   99:	 418b0b               	mov	ecx, dword ptr [r11]
+;;   9c:	 8b5018               	mov	edx, dword ptr [rax + 0x18]
+;;   9f:	 39d1                 	cmp	ecx, edx
+;;   a1:	 0f852f000000         	jne	0xd6
+;;   a7:	 50                   	push	rax
+;;   a8:	 59                   	pop	rcx
+;;   a9:	 488b5110             	mov	rdx, qword ptr [rcx + 0x10]
+;;   ad:	 488b4920             	mov	rcx, qword ptr [rcx + 0x20]
+;;   b1:	 51                   	push	rcx
+;;   b2:	 4156                 	push	r14
+;;   b4:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   b9:	 488b3424             	mov	rsi, qword ptr [rsp]
+;;   bd:	 ffd2                 	call	rdx
+;;   bf:	 4c8b742414           	mov	r14, qword ptr [rsp + 0x14]
+;;   c4:	 4883c410             	add	rsp, 0x10
+;;   c8:	 4883c410             	add	rsp, 0x10
+;;   cc:	 5d                   	pop	rbp
+;;   cd:	 c3                   	ret	
+;;   ce:	 0f0b                 	ud2	
+;;   d0:	 0f0b                 	ud2	
+;;   d2:	 0f0b                 	ud2	
+;;   d4:	 0f0b                 	ud2	
+;;   d6:	 0f0b                 	ud2	
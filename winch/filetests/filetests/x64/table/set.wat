@@ -16,86 +16,107 @@
 
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 4883c408             	add	rsp, 8
-;;   10:	 5d                   	pop	rbp
-;;   11:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8217000000         	jb	0x32
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8210000000         	jb	0x34
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 4883c408             	add	rsp, 8
+;;   30:	 5d                   	pop	rbp
+;;   31:	 c3                   	ret	
+;;   32:	 0f0b                 	ud2	
+;;   34:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec18             	sub	rsp, 0x18
-;;    8:	 897c2414             	mov	dword ptr [rsp + 0x14], edi
-;;    c:	 4889742408           	mov	qword ptr [rsp + 8], rsi
-;;   11:	 4c893424             	mov	qword ptr [rsp], r14
-;;   15:	 488b442408           	mov	rax, qword ptr [rsp + 8]
-;;   1a:	 8b4c2414             	mov	ecx, dword ptr [rsp + 0x14]
-;;   1e:	 4c89f2               	mov	rdx, r14
-;;   21:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
-;;   24:	 39d9                 	cmp	ecx, ebx
-;;   26:	 0f8324000000         	jae	0x50
-;;   2c:	 4189cb               	mov	r11d, ecx
-;;   2f:	 4d6bdb08             	imul	r11, r11, 8
-;;   33:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
-;;   37:	 4889d6               	mov	rsi, rdx
-;;   3a:	 4c01da               	add	rdx, r11
-;;   3d:	 39d9                 	cmp	ecx, ebx
-;;   3f:	 480f43d6             	cmovae	rdx, rsi
-;;   43:	 4883c801             	or	rax, 1
-;;   47:	 488902               	mov	qword ptr [rdx], rax
-;;   4a:	 4883c418             	add	rsp, 0x18
-;;   4e:	 5d                   	pop	rbp
-;;   4f:	 c3                   	ret	
-;;   50:	 0f0b                 	ud2	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f824e000000         	jb	0x69
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8247000000         	jb	0x6b
+;;   24:	 4883ec18             	sub	rsp, 0x18
+;;   28:	 89542414             	mov	dword ptr [rsp + 0x14], edx
+;;   2c:	 48894c2408           	mov	qword ptr [rsp + 8], rcx
+;;   31:	 4c893424             	mov	qword ptr [rsp], r14
+;;   35:	 488b442408           	mov	rax, qword ptr [rsp + 8]
+;;   3a:	 8b4c2414             	mov	ecx, dword ptr [rsp + 0x14]
+;;   3e:	 4c89f2               	mov	rdx, r14
+;;   41:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
+;;   44:	 39d9                 	cmp	ecx, ebx
+;;   46:	 0f8321000000         	jae	0x6d
+;;   4c:	 31f6                 	xor	esi, esi
+;;   4e:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
+;;   52:	 4189cb               	mov	r11d, ecx
+;;   55:	 39d9                 	cmp	ecx, ebx
+;;   57:	 4c0f43de             	cmovae	r11, rsi
+;;   5b:	 4883c801             	or	rax, 1
+;;   5f:	 4a8904da             	mov	qword ptr [rdx + r11*8], rax
+;;   63:	 4883c418             	add	rsp, 0x18
+;;   67:	 5d                   	pop	rbp
+;;   68:	 c3                   	ret	
+;;   69:	 0f0b                 	ud2	
+;;   6b:	 0f0b                 	ud2	
+;;   6d:	 0f0b                 	ud2	
 ;;
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 897c240c             	mov	dword ptr [rsp + 0xc], edi
-;;    c:	 89742408             	mov	dword ptr [rsp + 8], esi
-;;   10:	 4c893424             	mov	qword ptr [rsp], r14
-;;   14:	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
-;;   18:	 4c89f2               	mov	rdx, r14
-;;   1b:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
-;;   1e:	 39d9                 	cmp	ecx, ebx
-;;   20:	 0f8380000000         	jae	0xa6
-;;   26:	 4189cb               	mov	r11d, ecx
-;;   29:	 4d6bdb08             	imul	r11, r11, 8
-;;   2d:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
-;;   31:	 4889d6               	mov	rsi, rdx
-;;   34:	 4c01da               	add	rdx, r11
-;;   37:	 39d9                 	cmp	ecx, ebx
-;;   39:	 480f43d6             	cmovae	rdx, rsi
-;;   3d:	 488b02               	mov	rax, qword ptr [rdx]
-;;   40:	 4885c0               	test	rax, rax
-;;   43:	 0f8523000000         	jne	0x6c
-;;   49:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
-;;   4d:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
-;;   51:	 4156                 	push	r14
-;;   53:	 51                   	push	rcx
-;;   54:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
-;;   59:	 be00000000           	mov	esi, 0
-;;   5e:	 8b1424               	mov	edx, dword ptr [rsp]
-;;   61:	 ffd3                 	call	rbx
-;;   63:	 4883c410             	add	rsp, 0x10
-;;   67:	 e904000000           	jmp	0x70
-;;   6c:	 4883e0fe             	and	rax, 0xfffffffffffffffe
-;;   70:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
-;;   74:	 4c89f2               	mov	rdx, r14
-;;   77:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
-;;   7a:	 39d9                 	cmp	ecx, ebx
-;;   7c:	 0f8326000000         	jae	0xa8
-;;   82:	 4189cb               	mov	r11d, ecx
-;;   85:	 4d6bdb08             	imul	r11, r11, 8
-;;   89:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
-;;   8d:	 4889d6               	mov	rsi, rdx
-;;   90:	 4c01da               	add	rdx, r11
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c320000000       	add	r11, 0x20
+;;   15:	 0f829d000000         	jb	0xb8
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8296000000         	jb	0xba
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 8954240c             	mov	dword ptr [rsp + 0xc], edx
+;;   2c:	 894c2408             	mov	dword ptr [rsp + 8], ecx
+;;   30:	 4c893424             	mov	qword ptr [rsp], r14
+;;   34:	 8b4c2408             	mov	ecx, dword ptr [rsp + 8]
+;;   38:	 4c89f2               	mov	rdx, r14
+;;   3b:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
+;;   3e:	 39d9                 	cmp	ecx, ebx
+;;   40:	 0f8376000000         	jae	0xbc
+;;   46:	 31f6                 	xor	esi, esi
+;;   48:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
+;;   4c:	 4189cb               	mov	r11d, ecx
+;;   4f:	 39d9                 	cmp	ecx, ebx
+;;   51:	 4c0f43de             	cmovae	r11, rsi
+;;   55:	 4a8b04da             	mov	rax, qword ptr [rdx + r11*8]
+;;   59:	 4885c0               	test	rax, rax
+;;   5c:	 0f8523000000         	jne	0x85
+;;   62:	 4d8b5e38             	mov	r11, qword ptr [r14 + 0x38]
+;;   66:	 498b5b48             	mov	rbx, qword ptr [r11 + 0x48]
+;;   6a:	 4156                 	push	r14
+;;   6c:	 51                   	push	rcx
+;;   6d:	 488b7c2408           	mov	rdi, qword ptr [rsp + 8]
+;;   72:	 be00000000           	mov	esi, 0
+;;   77:	 8b1424               	mov	edx, dword ptr [rsp]
+;;   7a:	 ffd3                 	call	rbx
+;;   7c:	 4883c410             	add	rsp, 0x10
+;;   80:	 e904000000           	jmp	0x89
+;;   85:	 4883e0fe             	and	rax, 0xfffffffffffffffe
+;;   89:	 8b4c240c             	mov	ecx, dword ptr [rsp + 0xc]
+;;   8d:	 4c89f2               	mov	rdx, r14
+;;   90:	 8b5a50               	mov	ebx, dword ptr [rdx + 0x50]
 ;;   93:	 39d9                 	cmp	ecx, ebx
-;;   95:	 480f43d6             	cmovae	rdx, rsi
-;;   99:	 4883c801             	or	rax, 1
-;;   9d:	 488902               	mov	qword ptr [rdx], rax
-;;   a0:	 4883c410             	add	rsp, 0x10
-;;   a4:	 5d                   	pop	rbp
-;;   a5:	 c3                   	ret	
-;;   a6:	 0f0b                 	ud2	
-;;   a8:	 0f0b                 	ud2	
+;;   95:	 0f8323000000         	jae	0xbe
+;;   9b:	 31f6                 	xor	esi, esi
+;;   9d:	 488b5248             	mov	rdx, qword ptr [rdx + 0x48]
+;;   a1:	 4189cb               	mov	r11d, ecx
+;;   a4:	 39d9                 	cmp	ecx, ebx
+;;   a6:	 4c0f43de             	cmovae	r11, rsi
+;;   aa:	 4883c801             	or	rax, 1
+;;   ae:	 4a8904da             	mov	qword ptr [rdx + r11*8], rax
+;;   b2:	 4883c410             	add	rsp, 0x10
+;;   b6:	 5d                   	pop	rbp
+;;   b7:	 c3                   	ret	
+;;   b8:	 0f0b                 	ud2	
+;;   ba:	 0f0b                 	ud2	
+;;   bc:	 0f0b                 	ud2	
+;;   be:	 0f0b                 	ud2	
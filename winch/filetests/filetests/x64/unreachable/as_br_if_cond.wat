@@ -7,9 +7,18 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec08             	sub	rsp, 8
-;;    8:	 4c893424             	mov	qword ptr [rsp], r14
-;;    c:	 0f0b                 	ud2	
-;;    e:	 4883c408             	add	rsp, 8
-;;   12:	 5d                   	pop	rbp
-;;   13:	 c3                   	ret	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c308000000       	add	r11, 8
+;;   15:	 0f8219000000         	jb	0x34
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8212000000         	jb	0x36
+;;   24:	 4883ec08             	sub	rsp, 8
+;;   28:	 4c893424             	mov	qword ptr [rsp], r14
+;;   2c:	 0f0b                 	ud2	
+;;   2e:	 4883c408             	add	rsp, 8
+;;   32:	 5d                   	pop	rbp
+;;   33:	 c3                   	ret	
+;;   34:	 0f0b                 	ud2	
+;;   36:	 0f0b                 	ud2	
@@ -16,17 +16,26 @@
 )
 ;;    0:	 55                   	push	rbp
 ;;    1:	 4889e5               	mov	rbp, rsp
-;;    4:	 4883ec10             	sub	rsp, 0x10
-;;    8:	 48c744240800000000   	
+;;    4:	 4989fe               	mov	r14, rdi
+;;    7:	 4d8b5e08             	mov	r11, qword ptr [r14 + 8]
+;;    b:	 4d8b1b               	mov	r11, qword ptr [r11]
+;;    e:	 4981c318000000       	add	r11, 0x18
+;;   15:	 0f823a000000         	jb	0x55
+;;   1b:	 4c39dc               	cmp	rsp, r11
+;;   1e:	 0f8233000000         	jb	0x57
+;;   24:	 4883ec10             	sub	rsp, 0x10
+;;   28:	 48c744240800000000   	
 ;; 				mov	qword ptr [rsp + 8], 0
-;;   11:	 4c89742404           	mov	qword ptr [rsp + 4], r14
-;;   16:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
-;;   1a:	 85c0                 	test	eax, eax
-;;   1c:	 0f840d000000         	je	0x2f
-;;   22:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
-;;   27:	 4153                 	push	r11
-;;   29:	 0f0b                 	ud2	
-;;   2b:	 4883c408             	add	rsp, 8
-;;   2f:	 4883c410             	add	rsp, 0x10
-;;   33:	 5d                   	pop	rbp
-;;   34:	 c3                   	ret	
+;;   31:	 4c89742404           	mov	qword ptr [rsp + 4], r14
+;;   36:	 8b44240c             	mov	eax, dword ptr [rsp + 0xc]
+;;   3a:	 85c0                 	test	eax, eax
+;;   3c:	 0f840d000000         	je	0x4f
+;;   42:	 448b5c240c           	mov	r11d, dword ptr [rsp + 0xc]
+;;   47:	 4153                 	push	r11
+;;   49:	 0f0b                 	ud2	
+;;   4b:	 4883c408             	add	rsp, 8
+;;   4f:	 4883c410             	add	rsp, 0x10
+;;   53:	 5d                   	pop	rbp
+;;   54:	 c3                   	ret	
+;;   55:	 0f0b                 	ud2	
+;;   57:	 0f0b                 	ud2	
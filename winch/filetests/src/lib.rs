@@ -4,7 +4,7 @@ pub mod disasm;
 mod test {
     use super::disasm::disasm;
     use anyhow::Context;
-    use cranelift_codegen::settings::{self, Configurable};
+    use cranelift_codegen::settings::{self, Configurable, SetError};
     use serde_derive::{Deserialize, Serialize};
     use similar::TextDiff;
     use std::str::FromStr;
@@ -21,7 +21,34 @@ mod test {
     #[derive(Clone, Debug, Serialize, Deserialize)]
     struct TestConfig {
         target: String,
+        /// Settings to enable, either as `name`, which sets a boolean setting
+        /// to `true`, or as `name=value`. ISA-specific settings such as
+        /// `has_avx` take precedence over shared settings such as
+        /// `bb_padding_log2_minus_one`.
         flags: Option<Vec<String>>,
+        /// The static memory bound, in wasm pages, overriding the default of
+        /// the tunables. Memories which may grow past it are dynamic.
+        static_memory_bound: Option<u64>,
+    }
+
+    /// A helper function to build the ISA for a test configuration.
+    fn build_isa(config: &TestConfig) -> Box<dyn TargetIsa> {
+        let triple = Triple::from_str(&config.target).unwrap();
+        let mut shared_builder = settings::builder();
+        let mut isa_builder = lookup(triple).unwrap();
+        for flag in config.flags.iter().flatten() {
+            let (name, value) = flag.split_once('=').unwrap_or((flag, "true"));
+            let result = match isa_builder.set(name, value) {
+                Err(SetError::BadName(_)) => shared_builder.set(name, value),
+                result => result,
+            };
+            result
+                .with_context(|| format!("failed to set flag `{flag}`"))
+                .unwrap();
+        }
+        isa_builder
+            .finish(settings::Flags::new(shared_builder))
+            .unwrap()
     }
 
     /// A helper function to parse the test configuration from the top of the file.
@@ -89,22 +116,19 @@ mod test {
 
         let config = parse_config(wat);
         let wasm = wat::parse_str(&wat).unwrap();
-        let triple = Triple::from_str(&config.target).unwrap();
 
         let binding = parse_expected_result(wat);
         let expected = binding.as_str();
 
-        let shared_flags = settings::Flags::new(settings::builder());
-        let mut isa_builder = lookup(triple).unwrap();
-        for flag in config.flags.iter().flat_map(|f| f) {
-            isa_builder.set(&flag, "true").unwrap();
-        }
-        let isa = isa_builder.finish(shared_flags).unwrap();
+        let isa = build_isa(&config);
 
         let mut validator = Validator::new();
         let parser = WasmParser::new(0);
         let mut types = Default::default();
-        let tunables = Tunables::default();
+        let mut tunables = Tunables::default();
+        if let Some(bound) = config.static_memory_bound {
+            tunables.static_memory_bound = bound;
+        }
         let mut translation = ModuleEnvironment::new(&tunables, &mut validator, &mut types)
             .translate(parser, &wasm)
             .context("Failed to translate WebAssembly module")
@@ -171,6 +195,7 @@ mod test {
                 module_types,
                 &mut builtins,
                 &mut validator,
+                None,
             )
             .expect("Couldn't compile function");
 
@@ -1,12 +1,12 @@
 use anyhow::{Context, Result};
 use clap::Parser;
 use cranelift_codegen::settings;
-use std::{fs, path::PathBuf, str::FromStr};
+use std::{fs, path::PathBuf, str::FromStr, time::Instant};
 use target_lexicon::Triple;
 use wasmtime_environ::{
     wasmparser::{Parser as WasmParser, Validator},
-    DefinedFuncIndex, FunctionBodyData, ModuleEnvironment, ModuleTranslation, ModuleTypes,
-    Tunables, TypeConvert, VMOffsets,
+    DefinedFuncIndex, FunctionBodyData, FunctionCodegenStats, ModuleEnvironment, ModuleTranslation,
+    ModuleTypes, Tunables, TypeConvert, VMOffsets,
 };
 use winch_codegen::{lookup, BuiltinFunctions, TargetIsa};
 use winch_filetests::disasm::disasm;
@@ -19,6 +19,10 @@ pub struct Options {
     /// The target architecture.
     #[clap(long = "target")]
     target: String,
+
+    /// Print code generation statistics after each function.
+    #[clap(long = "stats")]
+    stats: bool,
 }
 
 pub fn run(opt: &Options) -> Result<()> {
@@ -41,7 +45,7 @@ pub fn run(opt: &Options) -> Result<()> {
 
     body_inputs
         .into_iter()
-        .try_for_each(|func| compile(&isa, &translation, &types, func))?;
+        .try_for_each(|func| compile(&isa, &translation, &types, func, opt.stats))?;
 
     Ok(())
 }
@@ -51,6 +55,7 @@ fn compile(
     translation: &ModuleTranslation,
     module_types: &ModuleTypes,
     f: (DefinedFuncIndex, FunctionBodyData<'_>),
+    print_stats: bool,
 ) -> Result<()> {
     let index = translation.module.func_index(f.0);
     let types = &translation.get_types();
@@ -60,6 +65,8 @@ fn compile(
     let vmoffsets = VMOffsets::new(isa.pointer_bytes(), &translation.module);
     let mut builtins = BuiltinFunctions::new(&vmoffsets, isa.wasmtime_call_conv());
     let mut validator = validator.into_validator(Default::default());
+    let mut stats = FunctionCodegenStats::default();
+    let start = Instant::now();
     let buffer = isa
         .compile_function(
             &sig,
@@ -68,13 +75,33 @@ fn compile(
             module_types,
             &mut builtins,
             &mut validator,
+            print_stats.then_some(&mut stats),
         )
         .expect("Couldn't compile function");
+    stats.compile_time = start.elapsed();
+    stats.machine_code_size = buffer.data().len() as u32;
+    stats.relocations = buffer.relocs().len() as u32;
 
     println!("Disassembly for function: {}", index.as_u32());
     disasm(buffer.data(), isa)?
         .iter()
         .for_each(|s| println!("{}", s));
 
+    if print_stats {
+        println!("Statistics for function: {}", index.as_u32());
+        for (opcode, s) in &stats.opcodes {
+            println!(
+                "  {:<24} count: {:<6} size: {}",
+                opcode, s.count, s.code_size
+            );
+        }
+        println!("  spills: {}, reloads: {}", stats.spills, stats.reloads);
+        println!(
+            "  machine code: {} bytes, constant pool: {} bytes, relocations: {}",
+            stats.machine_code_size, stats.constant_pool_size, stats.relocations
+        );
+        println!("  compile time: {:?}", stats.compile_time);
+    }
+
     Ok(())
 }